@@ -275,6 +275,7 @@ fn main() {
             bottom: 0.0,
             left: LEFT_PANE_LEFT_PADDING,
             right: SIDEBAR_SCROLLBAR_INSET,
+            ..Padding::ZERO
         },
     );
 
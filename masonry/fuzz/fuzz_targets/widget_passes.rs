@@ -0,0 +1,370 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fuzzes the widget passes with random widget trees and random sequences of events and
+//! mutations, and checks the invariants the passes should uphold after each step.
+
+#![no_main]
+
+use std::collections::HashSet;
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use masonry::accesskit::NodeId;
+use masonry::core::pointer::PointerButton;
+use masonry::core::{CollectionWidget, NewWidget, Widget, WidgetId, WidgetRef, WindowEvent};
+use masonry::dpi::PhysicalSize;
+use masonry::testing::TestHarness;
+use masonry::theme::default_property_set;
+use masonry::widgets::{Button, Flex, Label, TextArea};
+
+/// The maximum number of steps run for a single input, to keep iterations fast.
+const MAX_STEPS: usize = 64;
+
+/// A kind of widget added to the tree.
+#[derive(Arbitrary, Debug, Clone, Copy)]
+enum Kind {
+    Row,
+    Column,
+    Button,
+    Label,
+    TextArea,
+}
+
+/// A step of the fuzzed sequence.
+///
+/// Widgets are picked by index among the widgets in the tree (or among the `Flex`
+/// widgets for parents), modulo their count.
+#[derive(Arbitrary, Debug)]
+enum Step {
+    /// Appends a new widget to a `Flex`.
+    Add {
+        parent: u8,
+        kind: Kind,
+    },
+    /// Inserts a new widget in a `Flex`.
+    Insert {
+        parent: u8,
+        idx: u8,
+        kind: Kind,
+    },
+    /// Removes a child of a `Flex`.
+    Remove {
+        parent: u8,
+        idx: u8,
+    },
+    /// Removes every child of a `Flex`.
+    Clear {
+        parent: u8,
+    },
+    RequestLayout {
+        target: u8,
+    },
+    SetDisabled {
+        target: u8,
+        disabled: bool,
+    },
+    /// Focuses a widget, or clears focus.
+    Focus {
+        target: Option<u8>,
+    },
+    Tab {
+        shift: bool,
+    },
+    Click {
+        target: u8,
+    },
+    Type {
+        text: String,
+    },
+    Resize {
+        width: u16,
+        height: u16,
+    },
+    Animate {
+        ms: u8,
+    },
+}
+
+struct Fuzzer {
+    harness: TestHarness<Flex>,
+    /// The widgets which should be in the tree.
+    live: Vec<WidgetId>,
+    /// The widgets which were removed from the tree.
+    removed: HashSet<WidgetId>,
+    /// The `Flex` widgets laid out horizontally.
+    rows: HashSet<WidgetId>,
+}
+
+impl Fuzzer {
+    fn new() -> Self {
+        let harness = TestHarness::create(default_property_set(), NewWidget::new(Flex::column()));
+        let live = vec![harness.root_id()];
+        Self {
+            harness,
+            live,
+            removed: HashSet::new(),
+            rows: HashSet::new(),
+        }
+    }
+
+    fn pick(&self, idx: u8) -> WidgetId {
+        self.live[usize::from(idx) % self.live.len()]
+    }
+
+    fn pick_flex(&self, idx: u8) -> WidgetId {
+        let flexes: Vec<_> = self
+            .live
+            .iter()
+            .copied()
+            .filter(|id| {
+                self.harness
+                    .get_widget_with_id(*id)
+                    .downcast::<Flex>()
+                    .is_some()
+            })
+            .collect();
+        // The root is always a `Flex`.
+        flexes[usize::from(idx) % flexes.len()]
+    }
+
+    fn new_widget(&mut self, kind: Kind) -> NewWidget<dyn Widget> {
+        let widget = match kind {
+            Kind::Row => NewWidget::new(Flex::row()).erased(),
+            Kind::Column => NewWidget::new(Flex::column()).erased(),
+            Kind::Button => NewWidget::new(Button::with_text("Button")).erased(),
+            Kind::Label => NewWidget::new(Label::new("Some label text")).erased(),
+            Kind::TextArea => NewWidget::new(TextArea::new_editable("Some text")).erased(),
+        };
+        if matches!(kind, Kind::Row) {
+            self.rows.insert(widget.id());
+        }
+        self.live.push(widget.id());
+        widget
+    }
+
+    /// Returns the length of the main axis of the given `Flex`.
+    fn main_axis_length(&self, flex: WidgetId) -> f64 {
+        let size = self
+            .harness
+            .get_widget_with_id(flex)
+            .ctx()
+            .border_box_size();
+        if self.rows.contains(&flex) {
+            size.width
+        } else {
+            size.height
+        }
+    }
+
+    fn remove_child(&mut self, parent: WidgetId, idx: usize) {
+        let child = self.harness.get_widget_with_id(parent).children()[idx];
+        let mut removed = Vec::new();
+        collect_ids(child, &mut removed);
+
+        self.harness.edit_widget_with_id(parent, |mut widget| {
+            Flex::remove(&mut widget.downcast::<Flex>(), idx);
+        });
+        self.live.retain(|id| !removed.contains(id));
+        self.removed.extend(removed);
+    }
+
+    fn run(&mut self, step: Step) {
+        match step {
+            Step::Add { parent, kind } => {
+                let parent = self.pick_flex(parent);
+                let length_before = self.main_axis_length(parent);
+                let child = self.new_widget(kind);
+                self.harness.edit_widget_with_id(parent, |mut widget| {
+                    Flex::add_fixed(&mut widget.downcast::<Flex>(), child);
+                });
+                let length_after = self.main_axis_length(parent);
+                assert!(
+                    length_after >= length_before,
+                    "Appending a child shrank {parent} from {length_before} to {length_after}"
+                );
+            }
+            Step::Insert { parent, idx, kind } => {
+                let parent = self.pick_flex(parent);
+                let len = self.harness.get_widget_with_id(parent).children().len();
+                let idx = usize::from(idx) % (len + 1);
+                let child = self.new_widget(kind);
+                self.harness.edit_widget_with_id(parent, |mut widget| {
+                    Flex::insert_fixed(&mut widget.downcast::<Flex>(), idx, child);
+                });
+            }
+            Step::Remove { parent, idx } => {
+                let parent = self.pick_flex(parent);
+                let len = self.harness.get_widget_with_id(parent).children().len();
+                if len > 0 {
+                    self.remove_child(parent, usize::from(idx) % len);
+                }
+            }
+            Step::Clear { parent } => {
+                let parent = self.pick_flex(parent);
+                let len = self.harness.get_widget_with_id(parent).children().len();
+                for idx in (0..len).rev() {
+                    self.remove_child(parent, idx);
+                }
+            }
+            Step::RequestLayout { target } => {
+                let target = self.pick(target);
+                self.harness
+                    .edit_widget_with_id(target, |mut widget| widget.ctx.request_layout());
+            }
+            Step::SetDisabled { target, disabled } => {
+                let target = self.pick(target);
+                self.harness
+                    .edit_widget_with_id(target, |mut widget| widget.ctx.set_disabled(disabled));
+            }
+            Step::Focus { target } => {
+                let target = target.map(|target| self.pick(target));
+                if let Some(id) = target {
+                    let widget = self.harness.get_widget_with_id(id);
+                    if widget.ctx().is_disabled() || widget.ctx().is_stashed() {
+                        return;
+                    }
+                }
+                self.harness.focus_on(target);
+            }
+            Step::Tab { shift } => self.harness.press_tab_key(shift),
+            Step::Click { target } => {
+                let target = self.pick(target);
+                if self.harness.get_widget_with_id(target).ctx().is_stashed() {
+                    return;
+                }
+                self.harness.mouse_move_to_unchecked(target);
+                self.harness.mouse_button_press(PointerButton::Primary);
+                self.harness.mouse_button_release(PointerButton::Primary);
+            }
+            Step::Type { text } => {
+                let text: String = text.chars().take(16).collect();
+                self.harness.keyboard_type_chars(&text);
+            }
+            Step::Resize { width, height } => {
+                self.harness
+                    .process_window_event(WindowEvent::Resize(PhysicalSize::new(
+                        width.into(),
+                        height.into(),
+                    )));
+            }
+            Step::Animate { ms } => self.harness.animate_ms(ms.into()),
+        }
+    }
+
+    fn check_invariants(&mut self) {
+        self.harness.redraw();
+
+        let mut seen = HashSet::new();
+        let root = self.harness.root_widget().as_dyn();
+        check_widget(&self.harness, root, false, &self.removed, &mut seen);
+
+        // No stale ids.
+        let live: HashSet<_> = self.live.iter().copied().collect();
+        assert_eq!(
+            seen, live,
+            "The widget tree doesn't contain the expected widgets"
+        );
+        for id in &self.removed {
+            assert!(
+                self.harness.try_get_widget(*id).is_none(),
+                "Removed widget {id} is still in the tree"
+            );
+            assert!(
+                self.harness.access_node(*id).is_none(),
+                "Removed widget {id} is still in the accessibility tree"
+            );
+        }
+
+        if let Some(focused) = self.harness.focused_widget() {
+            let id = focused.id();
+            assert!(live.contains(&id), "Focused widget {id} isn't in the tree");
+            assert!(
+                !focused.ctx().is_disabled(),
+                "Focused widget {id} is disabled"
+            );
+            assert!(
+                !focused.ctx().is_stashed(),
+                "Focused widget {id} is stashed"
+            );
+        }
+        if let Some(id) = self.harness.pointer_capture_target_id() {
+            assert!(
+                live.contains(&id),
+                "Pointer capture target {id} isn't in the tree"
+            );
+        }
+    }
+}
+
+/// Checks the invariants of `widget` and its descendants, and adds their ids to `seen`.
+fn check_widget(
+    harness: &TestHarness<Flex>,
+    widget: WidgetRef<'_, dyn Widget>,
+    parent_disabled: bool,
+    removed: &HashSet<WidgetId>,
+    seen: &mut HashSet<WidgetId>,
+) {
+    let id = widget.id();
+    let ctx = widget.ctx();
+    assert!(seen.insert(id), "Widget {id} appears twice in the tree");
+    assert!(!removed.contains(&id), "Removed widget {id} is in the tree");
+
+    if parent_disabled {
+        assert!(
+            ctx.is_disabled(),
+            "Widget {id} has a disabled parent but isn't disabled"
+        );
+    }
+
+    let size = ctx.border_box_size();
+    assert!(
+        size.width.is_finite() && size.height.is_finite(),
+        "Widget {id} has a non-finite size {size}"
+    );
+    assert!(
+        size.width >= 0. && size.height >= 0.,
+        "Widget {id} has a negative size {size}"
+    );
+    let origin = ctx.window_origin();
+    assert!(
+        origin.x.is_finite() && origin.y.is_finite(),
+        "Widget {id} has a non-finite origin {origin}"
+    );
+
+    if !ctx.is_stashed() {
+        let node = harness
+            .access_node(id)
+            .unwrap_or_else(|| panic!("Widget {id} has no accessibility node"));
+        let node_children: Vec<NodeId> = node.child_ids().collect();
+        let children: Vec<NodeId> = widget
+            .children()
+            .iter()
+            .filter(|child| !child.ctx().is_stashed())
+            .map(|child| child.id().into())
+            .collect();
+        assert_eq!(
+            node_children, children,
+            "The accessibility node of widget {id} doesn't have the widget's children"
+        );
+    }
+
+    for child in widget.children() {
+        check_widget(harness, child, ctx.is_disabled(), removed, seen);
+    }
+}
+
+fn collect_ids(widget: WidgetRef<'_, dyn Widget>, ids: &mut Vec<WidgetId>) {
+    ids.push(widget.id());
+    for child in widget.children() {
+        collect_ids(child, ids);
+    }
+}
+
+fuzz_target!(|steps: Vec<Step>| {
+    let mut fuzzer = Fuzzer::new();
+    for step in steps.into_iter().take(MAX_STEPS) {
+        fuzzer.run(step);
+        fuzzer.check_invariants();
+    }
+});
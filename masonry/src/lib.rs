@@ -86,7 +86,7 @@
 //!                 TextAction::Changed(new_text) => {
 //!                     self.next_task = new_text.clone();
 //!                 }
-//!                 TextAction::Entered(_) => {}
+//!                 _ => {}
 //!             }
 //!         }
 //!     }
@@ -165,6 +165,10 @@
 //!
 //! - A rudimentary widget inspector - toggled by the F11 key.
 //! - A debug mode painting widget layout rectangles - toggled by the F12 key.
+//! - Frame diagnostics recording which widgets requested each frame, and from where - toggled by the F10 key.
+//!   While they're enabled, the widget inspector outlines the widgets which requested the last frame.
+//! - Widget profiling recording how much time each widget takes to lay out and paint - toggled by the F9 key.
+//!   While it's enabled, the widget inspector highlights the most expensive widgets of the last frame.
 //! - Optional automatic registration of a [tracing] subscriber, which outputs to the console and to a file in the dev profile.
 //!
 //! If you want to use your own subscriber, simply set it before starting masonry - in this case masonry will not set a subscriber.
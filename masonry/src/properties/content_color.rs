@@ -5,6 +5,7 @@ use std::any::TypeId;
 
 use crate::core::{Property, UpdateCtx};
 use crate::peniko::color::{AlphaColor, Srgb};
+use crate::util::contrast_ratio;
 
 /// The color of a widget's content, often text and text decorations.
 ///
@@ -55,6 +56,76 @@ impl Property for DisabledContentColor {
     }
 }
 
+/// How [`Label`] and [`TextArea`] pick the color of their text.
+///
+/// With [`Auto`](Self::Auto), text stays readable whatever it's placed on: it's dark on
+/// light backgrounds and light on dark ones.
+/// The background is the one returned by [`PaintCtx::effective_background`], which
+/// composites the background colors of the widget and its ancestors.
+///
+/// [`Label`]: crate::widgets::Label
+/// [`TextArea`]: crate::widgets::TextArea
+/// [`PaintCtx::effective_background`]: crate::core::PaintCtx::effective_background
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TextColor {
+    /// The text has the widget's [`ContentColor`], or its [`DisabledContentColor`]
+    /// when disabled.
+    #[default]
+    Content,
+    /// The text is `dark` or `light`, whichever contrasts most with the background.
+    ///
+    /// If the background is unknown, e.g. because it's a gradient, the text has the
+    /// widget's [`ContentColor`] instead.
+    Auto {
+        /// The color of text on light backgrounds.
+        dark: AlphaColor<Srgb>,
+        /// The color of text on dark backgrounds.
+        light: AlphaColor<Srgb>,
+    },
+}
+
+impl Property for TextColor {
+    fn static_default() -> &'static Self {
+        static DEFAULT: TextColor = TextColor::Content;
+        &DEFAULT
+    }
+}
+
+impl TextColor {
+    /// Black text on light backgrounds, and white text on dark ones.
+    pub const AUTO: Self = Self::Auto {
+        dark: AlphaColor::BLACK,
+        light: AlphaColor::WHITE,
+    };
+
+    /// Returns the color of text painted over `background`, for a widget whose content
+    /// color is `content_color`.
+    pub fn resolve(
+        &self,
+        content_color: AlphaColor<Srgb>,
+        background: Option<AlphaColor<Srgb>>,
+    ) -> AlphaColor<Srgb> {
+        match (*self, background) {
+            (Self::Auto { dark, light }, Some(background)) => {
+                if contrast_ratio(dark, background) >= contrast_ratio(light, background) {
+                    dark
+                } else {
+                    light
+                }
+            }
+            _ => content_color,
+        }
+    }
+
+    /// Helper function to be called in [`Widget::property_changed`](crate::core::Widget::property_changed).
+    pub fn prop_changed(ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if property_type != TypeId::of::<Self>() {
+            return;
+        }
+        ctx.request_paint_only();
+    }
+}
+
 // ---
 
 impl Default for ContentColor {
@@ -10,10 +10,13 @@ mod content_color;
 mod gap;
 mod line_breaking;
 mod object_fit;
+mod overscroll;
 mod placeholder_color;
 mod progress_bar;
+mod selected_background;
 mod selection;
 mod slider;
+mod spelling_error_color;
 mod switch;
 
 pub mod types;
@@ -23,10 +26,13 @@ pub use content_color::*;
 pub use gap::*;
 pub use line_breaking::*;
 pub use object_fit::*;
+pub use overscroll::*;
 pub use placeholder_color::*;
 pub use progress_bar::*;
+pub use selected_background::*;
 pub use selection::*;
 pub use slider::*;
+pub use spelling_error_color::*;
 pub use switch::*;
 
 pub use masonry_core::properties::*;
@@ -0,0 +1,80 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use crate::core::{Property, UpdateCtx};
+use crate::peniko::Color;
+
+/// The feedback a scrolling container gives when the user scrolls past its content's bounds.
+///
+/// Default value is [`Self::None`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverscrollEffect {
+    /// Scrolling past the bounds has no visible effect.
+    None,
+    /// The content follows the scroll past its bounds with increasing resistance,
+    /// then springs back into place.
+    ///
+    /// If the user [prefers reduced motion], this behaves like [`Self::Glow`] instead.
+    ///
+    /// [prefers reduced motion]: crate::core::EventCtx::prefers_reduced_motion
+    Bounce,
+    /// A glow appears at the edges scrolled past, then fades out.
+    ///
+    /// The color of the glow is set by [`OverscrollGlowColor`].
+    Glow,
+}
+
+impl Property for OverscrollEffect {
+    fn static_default() -> &'static Self {
+        &Self::None
+    }
+}
+
+impl Default for OverscrollEffect {
+    fn default() -> Self {
+        *Self::static_default()
+    }
+}
+
+impl OverscrollEffect {
+    /// Helper function to be called in [`Widget::property_changed`](crate::core::Widget::property_changed).
+    pub fn prop_changed(ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if property_type != TypeId::of::<Self>() {
+            return;
+        }
+        ctx.request_compose();
+        ctx.request_post_paint();
+    }
+}
+
+/// The color of the edge glow shown by [`OverscrollEffect::Glow`].
+///
+/// The glow is drawn with this color at its edge, fading to transparent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OverscrollGlowColor(pub Color);
+
+impl Property for OverscrollGlowColor {
+    fn static_default() -> &'static Self {
+        static DEFAULT: OverscrollGlowColor =
+            OverscrollGlowColor(Color::from_rgba8(0x80, 0xa0, 0xff, 0x60));
+        &DEFAULT
+    }
+}
+
+impl Default for OverscrollGlowColor {
+    fn default() -> Self {
+        *Self::static_default()
+    }
+}
+
+impl OverscrollGlowColor {
+    /// Helper function to be called in [`Widget::property_changed`](crate::core::Widget::property_changed).
+    pub fn prop_changed(ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if property_type != TypeId::of::<Self>() {
+            return;
+        }
+        ctx.request_post_paint();
+    }
+}
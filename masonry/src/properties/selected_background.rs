@@ -0,0 +1,38 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use crate::core::{Property, UpdateCtx};
+use crate::peniko::color::AlphaColor;
+use crate::properties::Background;
+
+/// The background color/gradient of the selected items of a widget (e.g., the rows of a [`List`]).
+///
+/// [`List`]: crate::widgets::List
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectedBackground(pub Background);
+
+impl Property for SelectedBackground {
+    fn static_default() -> &'static Self {
+        static DEFAULT: SelectedBackground =
+            SelectedBackground(Background::Color(AlphaColor::TRANSPARENT));
+        &DEFAULT
+    }
+}
+
+impl Default for SelectedBackground {
+    fn default() -> Self {
+        Self::static_default().clone()
+    }
+}
+
+impl SelectedBackground {
+    /// Helper function to be called in [`Widget::property_changed`](crate::core::Widget::property_changed).
+    pub fn prop_changed(ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if property_type != TypeId::of::<Self>() {
+            return;
+        }
+        ctx.request_paint_only();
+    }
+}
@@ -5,6 +5,7 @@ use std::any::TypeId;
 
 use crate::core::{Property, UpdateCtx};
 use crate::peniko::color::{AlphaColor, Srgb};
+use crate::util::Duration;
 
 /// The color of a [`TextArea`]'s cursor.
 ///
@@ -24,6 +25,45 @@ impl Property for CaretColor {
     }
 }
 
+/// The width of a [`TextArea`]'s cursor, in logical pixels.
+///
+/// [`TextArea`]: crate::widgets::TextArea
+#[expect(missing_docs, reason = "field names are self-descriptive")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CaretWidth {
+    pub width: f64,
+}
+
+impl Property for CaretWidth {
+    fn static_default() -> &'static Self {
+        static DEFAULT: CaretWidth = CaretWidth { width: 1.5 };
+        &DEFAULT
+    }
+}
+
+/// Whether a [`TextArea`]'s cursor blinks.
+///
+/// Whichever the option, the cursor stops blinking while the user types, and after
+/// ten seconds without input.
+///
+/// [`TextArea`]: crate::widgets::TextArea
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CaretBlink {
+    /// The cursor follows the user's setting, as reported by the platform with
+    /// [`WindowEvent::CaretBlinkChanged`](crate::core::WindowEvent::CaretBlinkChanged).
+    System,
+    /// The cursor blinks, staying shown then hidden for the given duration.
+    Interval(Duration),
+    /// The cursor doesn't blink.
+    Off,
+}
+
+impl Property for CaretBlink {
+    fn static_default() -> &'static Self {
+        &Self::System
+    }
+}
+
 /// The background color of a [`TextArea`]'s selection.
 ///
 /// Need to contrast the [`ContentColor`].
@@ -83,6 +123,56 @@ impl CaretColor {
 
 // ---
 
+impl Default for CaretWidth {
+    fn default() -> Self {
+        *Self::static_default()
+    }
+}
+
+impl CaretWidth {
+    /// Helper function to be called in [`Widget::property_changed`](crate::core::Widget::property_changed).
+    pub fn prop_changed(ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if property_type != TypeId::of::<Self>() {
+            return;
+        }
+        ctx.request_paint_only();
+    }
+}
+
+// ---
+
+impl Default for CaretBlink {
+    fn default() -> Self {
+        *Self::static_default()
+    }
+}
+
+impl CaretBlink {
+    /// Returns how long the cursor stays shown, then hidden, if it blinks.
+    ///
+    /// `system_interval` is the user's setting, from
+    /// [`caret_blink_interval`](crate::core::UpdateCtx::caret_blink_interval).
+    pub fn interval(&self, system_interval: Option<Duration>) -> Option<Duration> {
+        match *self {
+            Self::System => system_interval,
+            Self::Interval(interval) => Some(interval),
+            Self::Off => None,
+        }
+    }
+
+    /// Helper function to be called in [`Widget::property_changed`](crate::core::Widget::property_changed).
+    pub fn prop_changed(ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if property_type != TypeId::of::<Self>() {
+            return;
+        }
+        // Restarts or stops the blink animation.
+        ctx.request_anim_frame();
+        ctx.request_paint_only();
+    }
+}
+
+// ---
+
 impl Default for SelectionColor {
     fn default() -> Self {
         *Self::static_default()
@@ -0,0 +1,51 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use crate::core::{Property, UpdateCtx};
+use crate::peniko::color::{AlphaColor, Srgb};
+
+/// The color of the wavy line under the misspelled words of a [`TextArea`].
+///
+/// See [`SpellChecker`] for details.
+///
+/// [`SpellChecker`]: crate::widgets::SpellChecker
+/// [`TextArea`]: crate::widgets::TextArea
+#[expect(missing_docs, reason = "field names are self-descriptive")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpellingErrorColor {
+    pub color: AlphaColor<Srgb>,
+}
+
+impl Property for SpellingErrorColor {
+    fn static_default() -> &'static Self {
+        static DEFAULT: SpellingErrorColor = SpellingErrorColor {
+            color: AlphaColor::from_rgb8(0xe5, 0x48, 0x4d),
+        };
+        &DEFAULT
+    }
+}
+
+impl SpellingErrorColor {
+    /// Creates new `SpellingErrorColor` with given value.
+    pub const fn new(color: AlphaColor<Srgb>) -> Self {
+        Self { color }
+    }
+
+    /// Helper function to be called in [`Widget::property_changed`](crate::core::Widget::property_changed).
+    pub fn prop_changed(ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if property_type != TypeId::of::<Self>() {
+            return;
+        }
+        ctx.request_paint_only();
+    }
+}
+
+// ---
+
+impl Default for SpellingErrorColor {
+    fn default() -> Self {
+        *Self::static_default()
+    }
+}
@@ -1,13 +1,17 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use accesskit::NodeId;
+use accesskit::{Live, NodeId};
 use assert_matches::assert_matches;
 use masonry_testing::{ModularWidget, Record, TestHarness, TestWidgetExt, assert_any, assert_none};
 
-use crate::core::{NewWidget, Widget, WidgetTag};
-use crate::theme::test_property_set;
-use crate::widgets::SizedBox;
+use crate::app::AuditIssueKind;
+use crate::core::{AnnouncementPriority, NewWidget, Widget, WidgetOptions, WidgetTag};
+use crate::layout::AsUnit;
+use crate::peniko::Color;
+use crate::properties::{ContentColor, Dimensions};
+use crate::theme::{audit_options, test_property_set};
+use crate::widgets::{Button, Flex, Label, SizedBox};
 
 #[test]
 fn request_accessibility() {
@@ -84,3 +88,95 @@ fn access_node_children() {
         vec![id_1, id_3]
     );
 }
+
+#[test]
+fn announce() {
+    let target_tag = WidgetTag::named("target");
+    let widget = NewWidget::new_with_tag(SizedBox::empty(), target_tag);
+
+    let mut harness = TestHarness::create(test_property_set(), widget);
+    let _ = harness.render();
+
+    let announcements = |harness: &TestHarness<_>, live| {
+        let tree = harness.access_tree().state();
+        let region = tree
+            .root()
+            .children()
+            .find(|node| node.live() == live)
+            .unwrap();
+        region
+            .children()
+            .map(|node| (node.id(), node.value().unwrap()))
+            .collect::<Vec<_>>()
+    };
+    assert!(announcements(&harness, Live::Polite).is_empty());
+
+    harness.edit_widget(target_tag, |mut widget| {
+        widget
+            .ctx
+            .announce("3 results found", AnnouncementPriority::Polite);
+    });
+    let _ = harness.render();
+    let first = announcements(&harness, Live::Polite);
+    assert_matches!(&first[..], [(_, text)] if text == "3 results found");
+    assert!(announcements(&harness, Live::Assertive).is_empty());
+
+    // Announcing the same text again replaces the node, so that it's announced again.
+    harness.edit_widget(target_tag, |mut widget| {
+        widget
+            .ctx
+            .announce("3 results found", AnnouncementPriority::Polite);
+    });
+    let _ = harness.render();
+    let second = announcements(&harness, Live::Polite);
+    assert_matches!(&second[..], [(_, text)] if text == "3 results found");
+    assert_ne!(first[0].0, second[0].0);
+}
+
+#[test]
+fn accessibility_audit() {
+    let button_tag = WidgetTag::named("button");
+    let label_tag = WidgetTag::named("label");
+    let button = NewWidget::new_with(
+        Button::with_text(""),
+        Some(button_tag),
+        WidgetOptions::default(),
+        Dimensions::fixed(10.px(), 10.px()),
+    );
+    let label = NewWidget::new_with(
+        Label::new("Faint text"),
+        Some(label_tag),
+        WidgetOptions::default(),
+        ContentColor::new(Color::from_rgb8(0x30, 0x30, 0x30)),
+    );
+    let root = NewWidget::new(Flex::column().with_fixed(button).with_fixed(label));
+
+    let mut harness = TestHarness::create(test_property_set(), root);
+    let button_id = harness.get_widget(button_tag).id();
+    let label_id = harness.get_widget(label_tag).id();
+    assert!(harness.accessibility_audit().is_none());
+
+    harness.set_accessibility_audit(Some(audit_options()));
+    let _ = harness.render();
+    let audit = harness.accessibility_audit().unwrap();
+    let issues = |id| audit.widget(id).map(|issue| issue.kind).collect::<Vec<_>>();
+    assert_matches!(
+        issues(button_id)[..],
+        [
+            AuditIssueKind::MissingLabel,
+            AuditIssueKind::SmallHitTarget { .. }
+        ]
+    );
+    assert_matches!(
+        issues(label_id)[..],
+        [AuditIssueKind::LowContrast { ratio }] if ratio < 4.5
+    );
+    assert!(
+        audit
+            .to_string()
+            .contains("interactive widget without a label")
+    );
+
+    harness.set_accessibility_audit(None);
+    assert!(harness.accessibility_audit().is_none());
+}
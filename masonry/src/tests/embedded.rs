@@ -0,0 +1,96 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use dpi::{PhysicalPosition, PhysicalSize};
+use masonry_testing::PRIMARY_MOUSE;
+
+use crate::app::{EmbeddedRoot, EmbedderHost, RenderRootOptions, WindowSizePolicy};
+use crate::core::{
+    ErasedAction, FromDynWidget, ImePurpose, NewWidget, PointerButton, PointerButtonEvent,
+    PointerEvent, PointerState, PointerUpdate, Widget, WidgetId, WidgetTag,
+};
+use crate::kurbo::Point;
+use crate::theme::test_property_set;
+use crate::widgets::{Button, ButtonPress, Flex, TextInput};
+
+#[derive(Default)]
+struct RecordingHost {
+    actions: Vec<(ErasedAction, WidgetId)>,
+    ime: Option<ImePurpose>,
+}
+
+impl EmbedderHost for RecordingHost {
+    fn on_action(&mut self, action: ErasedAction, widget_id: WidgetId) {
+        self.actions.push((action, widget_id));
+    }
+
+    fn start_ime(&mut self, purpose: ImePurpose) {
+        self.ime = Some(purpose);
+    }
+
+    fn end_ime(&mut self) {
+        self.ime = None;
+    }
+}
+
+fn widget_center<W: Widget + FromDynWidget + ?Sized>(
+    root: &mut EmbeddedRoot<RecordingHost>,
+    tag: WidgetTag<W>,
+) -> Point {
+    let widget = root.render_root().get_widget_with_tag(tag).unwrap();
+    widget.ctx().window_origin() + (widget.ctx().border_box_size() / 2.).to_vec2()
+}
+
+fn click(root: &mut EmbeddedRoot<RecordingHost>, Point { x, y }: Point) {
+    let state = PointerState {
+        position: PhysicalPosition { x, y },
+        ..Default::default()
+    };
+    root.handle_pointer_event(PointerEvent::Move(PointerUpdate {
+        pointer: PRIMARY_MOUSE,
+        current: state.clone(),
+        coalesced: vec![],
+        predicted: vec![],
+    }));
+    for event in [PointerEvent::Down, PointerEvent::Up] {
+        root.handle_pointer_event(event(PointerButtonEvent {
+            pointer: PRIMARY_MOUSE,
+            button: Some(PointerButton::Primary),
+            state: state.clone(),
+        }));
+    }
+}
+
+#[test]
+fn embedded_root_forwards_signals_to_host() {
+    let button_tag = WidgetTag::named("button");
+    let input_tag = WidgetTag::named("input");
+    let root_widget = Flex::column()
+        .with_fixed(NewWidget::new_with_tag(
+            Button::with_text("Hello"),
+            button_tag,
+        ))
+        .with_fixed(NewWidget::new_with_tag(TextInput::new(""), input_tag));
+    let mut root = EmbeddedRoot::new(
+        NewWidget::new(root_widget),
+        RenderRootOptions {
+            default_properties: Arc::new(test_property_set()),
+            use_system_fonts: false,
+            size_policy: WindowSizePolicy::User,
+            size: PhysicalSize::new(200, 200),
+            scale_factor: 1.0,
+            test_font: None,
+        },
+        RecordingHost::default(),
+    );
+    let button = widget_center(&mut root, button_tag);
+    click(&mut root, button);
+    let (action, _) = root.host_mut().actions.pop().unwrap();
+    assert!(action.downcast::<ButtonPress>().is_ok());
+
+    let input = widget_center(&mut root, input_tag);
+    click(&mut root, input);
+    assert_eq!(root.host().ime, Some(ImePurpose::Normal));
+}
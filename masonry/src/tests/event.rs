@@ -5,11 +5,12 @@ use accesskit::ActionRequest;
 use assert_matches::assert_matches;
 use dpi::PhysicalPosition;
 
+use crate::app::EventTrace;
 use crate::core::keyboard::{Key, NamedKey};
 use crate::core::pointer::{PointerButton, PointerEvent, PointerInfo, PointerType};
 use crate::core::{
-    AccessEvent, NewWidget, PointerButtonEvent, PointerId, PointerState, PointerUpdate, TextEvent,
-    Update, Widget, WidgetId, WidgetTag,
+    AccessEvent, Modifiers, NewWidget, PointerButtonEvent, PointerId, PointerState, PointerUpdate,
+    RovingFocus, RovingLayout, TextEvent, Update, Widget, WidgetId, WidgetTag,
 };
 use crate::kurbo::Point;
 use crate::layout::AsUnit;
@@ -271,6 +272,68 @@ fn click_anchors_focus() {
     assert_eq!(harness.focused_widget_id(), None);
 }
 
+#[test]
+fn roving_focus_group_is_one_tab_stop() {
+    let group_tag = WidgetTag::named("group");
+    let before = WidgetTag::named("before");
+    let after = WidgetTag::named("after");
+
+    let roving = RovingFocus::new(RovingLayout::Horizontal);
+    let items = (0..3)
+        .map(|_| NewWidget::new(Button::with_text("")))
+        .collect();
+    let group = ModularWidget::new_multi_parent(items)
+        .roving_focus_group(true)
+        .text_event_fn(move |items, ctx, _, event| {
+            let ids: Vec<_> = items.iter().map(|item| item.id()).collect();
+            roving.on_text_event(ctx, event, &ids);
+        });
+    let parent = Flex::column()
+        .with_fixed(NewWidget::new_with_tag(Button::with_text(""), before))
+        .with_fixed(NewWidget::new_with_tag(group, group_tag))
+        .with_fixed(NewWidget::new_with_tag(Button::with_text(""), after))
+        .with_auto_id();
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+    let before_id = harness.get_widget(before).id();
+    let after_id = harness.get_widget(after).id();
+    let item_ids: Vec<_> = harness
+        .get_widget(group_tag)
+        .inner()
+        .state
+        .iter()
+        .map(|item| item.id())
+        .collect();
+
+    let mut shift_tab = TextEvent::key_down(Key::Named(NamedKey::Tab));
+    if let TextEvent::Keyboard(event) = &mut shift_tab {
+        event.modifiers = Modifiers::SHIFT;
+    }
+
+    // Tab enters the group through its first item, and then leaves it.
+    harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::Tab)));
+    assert_eq!(harness.focused_widget_id(), Some(before_id));
+    harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::Tab)));
+    assert_eq!(harness.focused_widget_id(), Some(item_ids[0]));
+
+    // Arrow keys move the focus inside the group.
+    harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowRight)));
+    assert_eq!(harness.focused_widget_id(), Some(item_ids[1]));
+    harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::End)));
+    assert_eq!(harness.focused_widget_id(), Some(item_ids[2]));
+    harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowLeft)));
+    assert_eq!(harness.focused_widget_id(), Some(item_ids[1]));
+
+    harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::Tab)));
+    assert_eq!(harness.focused_widget_id(), Some(after_id));
+
+    // Coming back focuses the item which was focused last.
+    harness.process_text_event(shift_tab.clone());
+    assert_eq!(harness.focused_widget_id(), Some(item_ids[1]));
+    harness.process_text_event(shift_tab);
+    assert_eq!(harness.focused_widget_id(), Some(before_id));
+}
+
 #[track_caller]
 fn make_pointer_info(pos: impl Into<Point>, id: u64) -> (PointerInfo, PointerState) {
     let pointer_id = PointerId::new(id).unwrap();
@@ -614,6 +677,64 @@ fn tab_focus() {
     assert_eq!(harness.focused_widget_id(), Some(child_5_id));
 }
 
+// EVENT TRACES
+
+#[test]
+fn replay_recorded_events() {
+    let button_tag = WidgetTag::named("button");
+    let text_area_tag = WidgetTag::named("text_area");
+
+    let make_harness = || {
+        let parent = Flex::column()
+            .with_fixed(NewWidget::new_with_tag(
+                Button::with_text("button"),
+                button_tag,
+            ))
+            .with_fixed(NewWidget::new_with_tag(
+                TextArea::new_editable(""),
+                text_area_tag,
+            ))
+            .with_auto_id();
+        TestHarness::create(test_property_set(), parent)
+    };
+
+    let mut harness = make_harness();
+    let button_id = harness.get_widget(button_tag).id();
+    let text_area_id = harness.get_widget(text_area_tag).id();
+
+    harness.start_recording_events();
+    harness.mouse_click_on(button_id);
+    harness.mouse_click_on(text_area_id);
+    harness.keyboard_type_chars("hello");
+    let trace = harness.stop_recording_events().unwrap();
+
+    assert_matches!(harness.pop_action::<ButtonPress>(), Some((_, _)));
+    assert!(harness.stop_recording_events().is_none());
+
+    // The trace survives serialization unchanged.
+    let json = trace.to_json_lines();
+    let trace = EventTrace::from_json_lines(&json).unwrap();
+    assert_eq!(trace.to_json_lines(), json);
+
+    // Replaying the trace in a fresh window reproduces the interaction.
+    let mut replayed = make_harness();
+    replayed.replay_events(&trace);
+
+    assert_matches!(replayed.pop_action::<ButtonPress>(), Some((_, _)));
+    assert_eq!(
+        replayed.focused_widget_id(),
+        Some(replayed.get_widget(text_area_tag).id())
+    );
+    assert_eq!(
+        replayed
+            .get_widget(text_area_tag)
+            .inner()
+            .text()
+            .to_string(),
+        "hello"
+    );
+}
+
 // ACCESS EVENTS
 
 #[test]
@@ -2,14 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use assert_matches::assert_matches;
+use dpi::PhysicalSize;
 
-use crate::core::{NewWidget, Widget, WidgetOptions, WidgetTag};
+use crate::core::{NewWidget, Widget, WidgetOptions, WidgetTag, WindowEvent};
 use crate::kurbo::{Insets, Point, Rect, Size};
-use crate::layout::{AsUnit, Length, SizeDef};
+use crate::layout::{AsUnit, Dim, Length, LengthUnit, SizeDef};
 use crate::properties::{BorderWidth, Dimensions, Padding};
 use crate::testing::{ModularWidget, TestHarness, TestWidgetExt, assert_debug_panics};
 use crate::theme::test_property_set;
-use crate::widgets::{Button, ChildAlignment, Flex, Portal, SizedBox, ZStack};
+use crate::widgets::{Button, ChildAlignment, Flex, Label, Portal, SizedBox, ZStack};
 
 #[test]
 fn layout_simple() {
@@ -267,6 +268,86 @@ fn layout_insets() {
     assert_eq!(parent_bounding_rect.y1, BOX_WIDTH + 20.0);
 }
 
+#[test]
+fn relative_units() {
+    let rem_tag = WidgetTag::named("rem");
+    let viewport_tag = WidgetTag::named("viewport");
+
+    let rem_box = NewWidget::new_with(
+        SizedBox::empty(),
+        Some(rem_tag),
+        WidgetOptions::default(),
+        (
+            Dimensions::new(Dim::percent(10.), Dim::Rem(4.)),
+            Padding::all(0.5).with_unit(LengthUnit::Rem),
+        ),
+    );
+    let viewport_box = NewWidget::new_with(
+        SizedBox::empty(),
+        Some(viewport_tag),
+        WidgetOptions::default(),
+        Dimensions::new(Dim::Vw(50.), Dim::Vmin(10.)),
+    );
+    let widget = Flex::column()
+        .with_fixed(rem_box)
+        .with_fixed(viewport_box)
+        .with_auto_id();
+
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), widget, Size::new(400., 200.));
+
+    let rem_box = harness.get_widget(rem_tag);
+    assert_eq!(rem_box.ctx().border_box_size(), Size::new(40., 64.));
+    assert_eq!(rem_box.ctx().content_box_size(), Size::new(24., 48.));
+    let viewport_box = harness.get_widget(viewport_tag);
+    assert_eq!(viewport_box.ctx().border_box_size(), Size::new(200., 20.));
+
+    // Rem lengths follow the root font size.
+    harness.set_root_font_size(20.);
+    let rem_box = harness.get_widget(rem_tag);
+    assert_eq!(rem_box.ctx().border_box_size(), Size::new(40., 80.));
+    assert_eq!(rem_box.ctx().content_box_size(), Size::new(20., 60.));
+
+    // Viewport lengths follow the window size.
+    harness.process_window_event(WindowEvent::Resize(PhysicalSize::new(300, 400)));
+    let viewport_box = harness.get_widget(viewport_tag);
+    assert_eq!(viewport_box.ctx().border_box_size(), Size::new(150., 30.));
+}
+
+#[test]
+fn text_scale() {
+    let label_tag = WidgetTag::named("label");
+    let rem_tag = WidgetTag::named("rem");
+
+    let rem_box = NewWidget::new_with(
+        SizedBox::empty(),
+        Some(rem_tag),
+        WidgetOptions::default(),
+        Dimensions::rem(2., 1.),
+    );
+    let widget = Flex::column()
+        .with_fixed(NewWidget::new_with_tag(Label::new("Hello"), label_tag))
+        .with_fixed(rem_box)
+        .with_auto_id();
+
+    let mut harness = TestHarness::create(test_property_set(), widget);
+    let label_size = harness.get_widget(label_tag).ctx().border_box_size();
+    let rem_size = harness.get_widget(rem_tag).ctx().border_box_size();
+    assert_eq!(rem_size, Size::new(32., 16.));
+
+    harness.set_app_text_scale(1.5);
+    let scaled_label_size = harness.get_widget(label_tag).ctx().border_box_size();
+    assert!(scaled_label_size.width > label_size.width * 1.4);
+    assert!(scaled_label_size.height > label_size.height * 1.4);
+    let rem_size = harness.get_widget(rem_tag).ctx().border_box_size();
+    assert_eq!(rem_size, Size::new(48., 24.));
+
+    // The user's text size preference multiplies the app's text scale.
+    harness.process_window_event(WindowEvent::TextScaleChanged(2.));
+    let rem_size = harness.get_widget(rem_tag).ctx().border_box_size();
+    assert_eq!(rem_size, Size::new(96., 48.));
+}
+
 #[test]
 fn content_box() {
     let tag = WidgetTag::named("hero");
@@ -278,6 +359,7 @@ fn content_box() {
             right: 2.,
             top: 3.,
             bottom: 4.,
+            ..Padding::ZERO
         },
         BorderWidth::all(1.),
     );
@@ -8,9 +8,12 @@
 mod accessibility;
 mod anim;
 mod compose;
+mod embedded;
 mod event;
 mod layout;
 mod mutate;
 mod paint;
+mod print;
 mod update;
+mod visual_regression;
 mod widget_tag;
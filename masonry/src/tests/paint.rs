@@ -1,20 +1,36 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use assert_matches::assert_matches;
+use dpi::PhysicalSize;
 
-use crate::core::{NewWidget, Properties, Widget, WidgetTag};
-use crate::kurbo::{Affine, Circle, Dashes, Point, Size, Stroke, Vec2};
+use crate::app::{FrameRequestKind, OverlayItem, RenderedImage};
+use crate::core::pointer::PointerButton;
+use crate::core::{
+    CollectionWidget, NewWidget, Properties, WgpuEffect, Widget, WidgetOptions, WidgetTag,
+};
+use crate::kurbo::{Affine, Circle, Dashes, Point, Rect, Size, Stroke, Vec2};
 use crate::layout::{AsUnit, Length, SizeDef, UnitPoint};
 use crate::palette::css::{BLUE, GREEN, RED};
-use crate::peniko::Color;
 use crate::peniko::color::{AlphaColor, Srgb};
+use crate::peniko::{Color, ImageAlphaType, ImageBrush, ImageData, ImageFormat, ImageQuality};
 use crate::properties::types::MainAxisAlignment;
-use crate::properties::{Background, Dimensions, Gap, Padding};
-use crate::testing::{ModularWidget, Record, TestHarness, TestWidgetExt, assert_render_snapshot};
+use crate::properties::{
+    Background, BackgroundImage, CornerRadius, Dimensions, Elevation, Gap, HoveredElevation,
+    Padding, PressFeedback, TextColor, ZIndex,
+};
+use crate::testing::{
+    ModularWidget, Record, TestHarness, TestHarnessParams, TestWidgetExt, assert_render_snapshot,
+};
 use crate::theme::test_property_set;
-use crate::util::{fill, stroke};
-use crate::widgets::{Align, ChildAlignment, Flex, Grid, GridParams, Label, SizedBox, ZStack};
+use crate::util::{Duration, fill, stroke};
+use crate::vello::{AaConfig, wgpu};
+use crate::widgets::{
+    Align, Button, ChildAlignment, Flex, Grid, GridParams, Label, SizedBox, ZStack,
+};
 
 #[test]
 fn request_paint() {
@@ -58,6 +74,110 @@ fn request_paint() {
     assert_matches!(harness.take_records_of(target_tag)[..], []);
 }
 
+#[test]
+fn damage() {
+    const SIZE: Length = Length::const_px(20.);
+    let target_tag = WidgetTag::named("target");
+    let target = NewWidget::new_with_tag(SizedBox::empty().width(SIZE).height(SIZE), target_tag);
+    let other = NewWidget::new(SizedBox::empty().width(SIZE).height(SIZE));
+    let root = NewWidget::new(Flex::column().with_fixed(target).with_fixed(other));
+
+    let mut harness = TestHarness::create(test_property_set(), root);
+
+    // Nothing changed since the harness was created.
+    let _ = harness.render();
+    assert_eq!(harness.damage(), []);
+
+    // Only the repainted widget is damaged, grown by a pixel for antialiasing.
+    harness.edit_widget(target_tag, |mut widget| {
+        widget.ctx.request_paint_only();
+    });
+    let _ = harness.render();
+    let bounding_box = harness.get_widget(target_tag).ctx().bounding_box();
+    assert_eq!(harness.damage(), [bounding_box.inflate(1., 1.)]);
+}
+
+#[test]
+fn frame_diagnostics() {
+    let target_tag = WidgetTag::named("target");
+    let target = NewWidget::new_with_tag(SizedBox::empty(), target_tag);
+    let root = NewWidget::new(ModularWidget::new_parent(target));
+
+    let mut harness = TestHarness::create(test_property_set(), root);
+    let target_id = harness.get_widget(target_tag).id();
+    assert!(harness.frame_diagnostics().is_none());
+
+    harness.set_frame_diagnostics(true);
+    let _ = harness.render();
+
+    // Requests are attributed to the widget and the line which made them.
+    let line = line!() + 2;
+    harness.edit_widget(target_tag, |mut widget| {
+        widget.ctx.request_layout();
+        widget.ctx.request_paint_only();
+    });
+    let _ = harness.render();
+
+    let diagnostics = harness.frame_diagnostics().unwrap();
+    let last_frame = diagnostics.last_frame();
+    assert_eq!(last_frame.len(), 2);
+    assert_eq!(last_frame[0].widget, target_id);
+    assert_eq!(last_frame[0].kind, FrameRequestKind::Layout);
+    let location = last_frame[0].location.unwrap();
+    assert_eq!(location.file(), file!());
+    assert_eq!(location.line(), line);
+    assert_eq!(last_frame[1].kind, FrameRequestKind::Paint);
+
+    // A frame nobody requested has no requests, but still counts towards the report.
+    let _ = harness.render();
+    let diagnostics = harness.frame_diagnostics().unwrap();
+    assert_eq!(diagnostics.last_frame(), []);
+    assert_eq!(diagnostics.frame_count(), 3);
+    let report = diagnostics.report_for(target_id);
+    assert_eq!(report.len(), 2);
+    assert!(report.iter().all(|summary| summary.frames == 1));
+
+    harness.set_frame_diagnostics(false);
+    assert!(harness.frame_diagnostics().is_none());
+}
+
+#[test]
+fn widget_profile() {
+    let target_tag = WidgetTag::named("target");
+    let target = NewWidget::new_with_tag(SizedBox::empty(), target_tag);
+    let root = NewWidget::new(ModularWidget::new_parent(target));
+
+    let mut harness = TestHarness::create(test_property_set(), root);
+    let target_id = harness.get_widget(target_tag).id();
+    assert!(harness.widget_profile().is_none());
+
+    harness.set_widget_profiling(true);
+    let _ = harness.render();
+
+    // Only the widgets which did work in a frame are timed.
+    harness.edit_widget(target_tag, |mut widget| widget.ctx.request_paint_only());
+    let _ = harness.render();
+    let profile = harness.widget_profile().unwrap();
+    let last_frame = profile.last_frame();
+    assert_eq!(last_frame.len(), 1);
+    assert_eq!(last_frame[0].widget, target_id);
+    assert_eq!(last_frame[0].layout, Duration::ZERO);
+
+    // Laying out a widget lays out its ancestors.
+    harness.edit_widget(target_tag, |mut widget| widget.ctx.request_layout());
+    let _ = harness.render();
+    let profile = harness.widget_profile().unwrap();
+    assert_eq!(profile.frame_count(), 3);
+    assert_eq!(profile.top(1).len(), 1);
+    let widgets: Vec<_> = profile.last_frame().iter().map(|t| t.widget).collect();
+    assert!(widgets.contains(&target_id));
+    assert!(widgets.contains(&harness.root_id()));
+    assert!(profile.widget(target_id).is_some());
+
+    harness.set_widget_profiling(false);
+    assert!(harness.widget_profile().is_none());
+}
+
 #[test]
 fn paint_order() {
     const SQUARE_SIZE: f64 = 30.;
@@ -235,3 +355,455 @@ fn paint_transparency() {
 
     assert_render_snapshot!(harness, "paint_transparency");
 }
+
+#[test]
+fn paint_background_image() {
+    // A 6x6 image with green corners, blue edges, and a white center,
+    // so that each of the nine slices is easy to tell apart.
+    let data = (0..6)
+        .flat_map(|y| (0..6).map(move |x| (x, y)))
+        .flat_map(|(x, y)| {
+            let on_edge_x = !(2..4).contains(&x);
+            let on_edge_y = !(2..4).contains(&y);
+            let color = match (on_edge_x, on_edge_y) {
+                (true, true) => GREEN,
+                (true, false) | (false, true) => BLUE,
+                (false, false) => Color::WHITE,
+            };
+            color.to_rgba8().to_u8_array()
+        })
+        .collect::<Vec<_>>();
+    let image = ImageBrush::new(ImageData {
+        data: data.into(),
+        format: ImageFormat::Rgba8,
+        alpha_type: ImageAlphaType::Alpha,
+        width: 6,
+        height: 6,
+    })
+    .with_quality(ImageQuality::Low);
+
+    let child = |background_image| {
+        NewWidget::new_with_props(
+            SizedBox::empty().width(60.px()).height(40.px()),
+            (background_image, CornerRadius::all(6.)),
+        )
+    };
+
+    let root = Flex::row()
+        .with_fixed(child(BackgroundImage::stretch(image.clone())))
+        .with_fixed(child(BackgroundImage::tile(image.clone())))
+        .with_fixed(child(BackgroundImage::nine_patch(image, 2.)))
+        .with_props((Padding::all(10.), Gap::new(10.px())));
+
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), root, Size::new(230., 60.));
+
+    // From left to right:
+    // - The whole image stretched, with nearest neighbor sampling.
+    // - The image repeated at its natural size.
+    // - The image 9-patch sliced: 2px green corners, 2px blue edges, and a white center.
+    assert_render_snapshot!(harness, "paint_background_image");
+}
+
+#[test]
+fn paint_elevation() {
+    let child = |elevation| {
+        NewWidget::new_with_props(
+            SizedBox::empty().width(40.px()).height(40.px()),
+            (
+                Background::Color(Color::WHITE),
+                CornerRadius::all(4.),
+                Elevation(elevation),
+            ),
+        )
+    };
+
+    let root = Flex::row()
+        .with_fixed(child(0))
+        .with_fixed(child(1))
+        .with_fixed(child(2))
+        .with_fixed(child(3))
+        .with_fixed(child(4))
+        .with_fixed(child(5))
+        .with_props((
+            Background::Color(Color::from_rgb8(0xee, 0xee, 0xee)),
+            Padding::all(20.),
+            Gap::new(20.px()),
+        ));
+
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), root, Size::new(380., 100.));
+
+    // From left to right, elevations 0 (no shadow) to 5.
+    assert_render_snapshot!(harness, "paint_elevation");
+}
+
+#[test]
+fn hovered_elevation() {
+    let button_tag = WidgetTag::named("button");
+    let button = |props: Properties| {
+        NewWidget::new_with(
+            Button::with_text("Hover"),
+            Some(button_tag),
+            WidgetOptions::default(),
+            props,
+        )
+    };
+    let size = Size::new(100., 60.);
+
+    let hovered_image = {
+        let root = Align::centered(button(
+            Properties::new()
+                .with(Elevation(0))
+                .with(HoveredElevation(Elevation(3))),
+        ));
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), root.with_auto_id(), size);
+        harness.mouse_move_to(harness.get_widget(button_tag).id());
+        harness.render()
+    };
+
+    let raised_image = {
+        let root = Align::centered(button(Properties::new().with(Elevation(3))));
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), root.with_auto_id(), size);
+        harness.mouse_move_to(harness.get_widget(button_tag).id());
+        harness.render()
+    };
+
+    // We don't use assert_eq because we don't want rich assert
+    assert!(hovered_image == raised_image);
+}
+
+#[test]
+fn paint_z_index() {
+    let square = |color, z_index| {
+        NewWidget::new_with_props(
+            SizedBox::empty().width(60.px()).height(60.px()),
+            (Background::Color(color), ZIndex(z_index)),
+        )
+    };
+
+    let root = ZStack::new()
+        .with(square(RED, 2), UnitPoint::TOP_LEFT)
+        .with(square(GREEN, 1), UnitPoint::CENTER)
+        .with(square(BLUE, 0), UnitPoint::BOTTOM_RIGHT)
+        .with_auto_id();
+
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), root, Size::new(100., 100.));
+
+    // Red is painted above green, which is painted above blue,
+    // even though they come in the opposite order in children_ids.
+    assert_render_snapshot!(harness, "paint_z_index");
+}
+
+#[test]
+fn z_index_hit_test() {
+    let first_tag = WidgetTag::named("first");
+    let second_tag = WidgetTag::named("second");
+    let button = |tag, z_index| {
+        NewWidget::new_with(
+            Button::with_text(""),
+            Some(tag),
+            WidgetOptions::default(),
+            (Dimensions::fixed(50.px(), 50.px()), ZIndex(z_index)),
+        )
+    };
+
+    let root = ZStack::new()
+        .with(button(first_tag, 1), ChildAlignment::ParentAligned)
+        .with(button(second_tag, 0), ChildAlignment::ParentAligned)
+        .with_auto_id();
+
+    let mut harness = TestHarness::create_with_size(test_property_set(), root, Size::new(50., 50.));
+
+    // The first button has a higher z-index, so it's on top.
+    harness.mouse_move((25., 25.));
+    assert!(harness.get_widget(first_tag).ctx().is_hovered());
+    assert!(!harness.get_widget(second_tag).ctx().is_hovered());
+
+    harness.edit_widget(second_tag, |mut button| {
+        button.insert_prop(ZIndex(2));
+    });
+    harness.mouse_move((26., 26.));
+    assert!(!harness.get_widget(first_tag).ctx().is_hovered());
+    assert!(harness.get_widget(second_tag).ctx().is_hovered());
+}
+
+#[test]
+fn occluded_widgets() {
+    let cover_tag = WidgetTag::named("cover");
+    let hidden = NewWidget::new_with_props(
+        SizedBox::empty().width(20.px()).height(20.px()),
+        Background::Color(RED),
+    );
+    let cover = NewWidget::new_with(
+        SizedBox::empty().width(50.px()).height(50.px()),
+        Some(cover_tag),
+        WidgetOptions::default(),
+        Background::Color(BLUE),
+    );
+    let root = ZStack::new()
+        .with(hidden, UnitPoint::CENTER)
+        .with(cover, ChildAlignment::ParentAligned)
+        .with_auto_id();
+
+    let mut harness = TestHarness::create_with_size(test_property_set(), root, Size::new(50., 50.));
+
+    // The red square is fully behind the opaque cover, so it isn't encoded.
+    let image = harness.render();
+    assert_eq!(image.get_pixel(25, 25).0, [0, 0, 255, 255]);
+
+    // Once the cover is translucent, the red square shows through.
+    harness.edit_widget(cover_tag, |mut cover| {
+        cover.insert_prop(Background::Color(BLUE.with_alpha(0.5)));
+    });
+    let image = harness.render();
+    let [red, _, _, _] = image.get_pixel(25, 25).0;
+    assert!(red > 0);
+}
+
+#[test]
+fn render_to_image() {
+    let target_tag = WidgetTag::named("target");
+    let target = NewWidget::new_with(
+        SizedBox::empty(),
+        Some(target_tag),
+        WidgetOptions::default(),
+        (
+            Dimensions::fixed(40.px(), 20.px()),
+            Background::Color(GREEN),
+        ),
+    );
+    let root = Flex::row().with_fixed(target).with_props(Padding::all(10.));
+
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), root, Size::new(100., 100.));
+    let target_id = harness.get_widget(target_tag).id();
+
+    harness.edit_widget(target_tag, |mut widget| {
+        widget.ctx.request_render_to_image(2.);
+    });
+    let (rendered, widget_id) = harness.pop_action::<RenderedImage>().unwrap();
+    assert_eq!(widget_id, target_id);
+    assert_eq!(rendered.scale, 2.);
+
+    // The image only covers the target, at twice its logical size.
+    let image = rendered.image;
+    assert_eq!((image.width, image.height), (80, 40));
+    let center = 4 * (20 * 80 + 40);
+    assert_eq!(
+        &image.data.data()[center..center + 4],
+        GREEN.to_rgba8().to_u8_array()
+    );
+}
+
+#[test]
+fn wgpu_effect() {
+    let applied_sizes = Rc::new(RefCell::new(Vec::new()));
+    let sizes_in_apply = applied_sizes.clone();
+    // An effect replacing the subtree by a blue fill.
+    let effect = WgpuEffect::new(move |target| {
+        sizes_in_apply.borrow_mut().push(target.size);
+        let mut encoder = target
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: None,
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: target.view,
+                depth_slice: None,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::BLUE),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        target.queue.submit([encoder.finish()]);
+    });
+
+    let child = NewWidget::new_with_props(
+        SizedBox::empty().width(20.px()).height(20.px()),
+        Background::Color(GREEN),
+    );
+    let parent = ModularWidget::new_parent(child).paint_fn(move |_, ctx, _, _| {
+        ctx.set_wgpu_effect(Some(effect.clone()));
+    });
+    let mut harness = TestHarness::create_with_size(
+        test_property_set(),
+        NewWidget::new(parent),
+        Size::new(20., 20.),
+    );
+    let image = harness.render();
+
+    // Hack: If we are using `SKIP_RENDER_TESTS`, nothing is rendered.
+    if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+        // The effect's result is drawn instead of the green child.
+        assert_eq!(*applied_sizes.borrow(), [PhysicalSize::new(20, 20)]);
+        assert_eq!(image.get_pixel(10, 10).0, [0, 0, 255, 255]);
+    }
+}
+
+#[test]
+fn antialiasing_fallback() {
+    let child = NewWidget::new_with_props(
+        SizedBox::empty().width(20.px()).height(20.px()),
+        Background::Color(GREEN),
+    );
+    let parent = ModularWidget::new_parent(child).paint_fn(|_, ctx, _, _| {
+        ctx.set_antialiasing(Some(AaConfig::Msaa8));
+    });
+    let root = Flex::row()
+        .with_fixed(NewWidget::new(parent))
+        .with_auto_id();
+
+    let mut harness = TestHarness::create_with_size(test_property_set(), root, Size::new(40., 40.));
+    let area_image = harness.render();
+    assert_eq!(area_image.get_pixel(10, 10).0, [0, 128, 0, 255]);
+
+    // The harness only supports area anti-aliasing, so MSAA falls back to it,
+    // for the window as well as for the subtree.
+    harness.set_antialiasing(AaConfig::Msaa16);
+    assert_eq!(harness.render(), area_image);
+}
+
+#[test]
+fn overlay() {
+    let target_tag = WidgetTag::named("target");
+    let target = NewWidget::new_with_tag(SizedBox::empty(), target_tag);
+    let root = NewWidget::new(Flex::row().with_fixed(target));
+
+    let mut harness = TestHarness::create_with_size(test_property_set(), root, Size::new(50., 50.));
+    let _ = harness.render();
+
+    // The item is painted above the widgets, and damages its bounds.
+    let bounds = Rect::new(10., 10., 20., 20.);
+    let id = harness.edit_widget(target_tag, |mut widget| {
+        widget
+            .ctx
+            .add_overlay(OverlayItem::new(bounds, |scene, bounds, _| {
+                fill(scene, &bounds, RED);
+            }))
+    });
+    let image = harness.render();
+    assert_eq!(image.get_pixel(15, 15).0, [255, 0, 0, 255]);
+    assert_eq!(harness.damage(), [bounds.inflate(1., 1.)]);
+
+    // Painting is clipped to the bounds of the item.
+    let moved = Rect::new(30., 30., 40., 40.);
+    harness.edit_widget(target_tag, |mut widget| {
+        widget.ctx.set_overlay_bounds(id, moved);
+    });
+    let image = harness.render();
+    assert_ne!(image.get_pixel(15, 15).0, [255, 0, 0, 255]);
+    assert_eq!(image.get_pixel(35, 35).0, [255, 0, 0, 255]);
+
+    // Removing the widget removes its items.
+    harness.edit_root_widget(|mut root| {
+        Flex::remove(&mut root, 0);
+    });
+    let image = harness.render();
+    assert_ne!(image.get_pixel(35, 35).0, [255, 0, 0, 255]);
+}
+
+#[test]
+fn overlay_lifetime() {
+    let target_tag = WidgetTag::named("target");
+    let target = NewWidget::new_with_tag(SizedBox::empty(), target_tag);
+
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), target, Size::new(50., 50.));
+    let background = harness.render().get_pixel(25, 25).0;
+
+    harness.edit_widget(target_tag, |mut widget| {
+        let item = OverlayItem::new(Rect::new(0., 0., 50., 50.), |scene, bounds, age| {
+            // Fade out over the lifetime of the item.
+            let alpha = 1. - age.as_secs_f32();
+            fill(scene, &bounds, RED.with_alpha(alpha));
+        })
+        .with_lifetime(Duration::from_secs(1));
+        widget.ctx.add_overlay(item);
+    });
+    let image = harness.render();
+    assert_eq!(image.get_pixel(25, 25).0, [255, 0, 0, 255]);
+
+    harness.animate_ms(500);
+    let image = harness.render();
+    let pixel = image.get_pixel(25, 25).0;
+    assert_ne!(pixel, [255, 0, 0, 255]);
+    assert_ne!(pixel, background);
+
+    // The item is removed once its lifetime has elapsed.
+    harness.animate_ms(500);
+    let image = harness.render();
+    assert_eq!(image.get_pixel(25, 25).0, background);
+}
+
+#[test]
+fn press_feedback() {
+    let button = Button::with_text("").with_props((
+        Dimensions::fixed(40.px(), 40.px()),
+        PressFeedback::flash(RED),
+    ));
+
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), button, Size::new(40., 40.));
+    let background = harness.render().get_pixel(5, 20).0;
+
+    // The flash covers the button when it's pressed.
+    harness.mouse_move((20., 20.));
+    harness.mouse_button_press(PointerButton::Primary);
+    let image = harness.render();
+    assert_eq!(image.get_pixel(5, 20).0, [255, 0, 0, 255]);
+
+    // It fades out over its duration, even once the press ends.
+    harness.mouse_button_release(PointerButton::Primary);
+    harness.animate_ms(200);
+    let pixel = harness.render().get_pixel(5, 20).0;
+    assert_ne!(pixel, [255, 0, 0, 255]);
+    assert_ne!(pixel, background);
+
+    harness.animate_ms(300);
+    assert_eq!(harness.render().get_pixel(5, 20).0, background);
+}
+
+#[test]
+fn effective_background() {
+    let target_tag = WidgetTag::named("target");
+    let target = ModularWidget::new(None::<Color>).paint_fn(|background, ctx, _, _| {
+        *background = ctx.effective_background();
+    });
+    let target = NewWidget::new_with_tag(target, target_tag);
+    let parent_tag = WidgetTag::named("parent");
+    let parent = NewWidget::new_with(
+        ModularWidget::new_parent(target),
+        Some(parent_tag),
+        WidgetOptions::default(),
+        Background::Color(Color::WHITE.with_alpha(0.5)),
+    );
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+    let _ = harness.render();
+
+    // A translucent background is composited over the base color.
+    let base = TestHarnessParams::DEFAULT_BACKGROUND_COLOR;
+    let background = harness.get_widget(target_tag).inner().state.unwrap();
+    assert!(background.components[0] > base.components[0]);
+    assert_eq!(background.components[3], 1.);
+    assert_eq!(TextColor::AUTO.resolve(RED, Some(background)), Color::BLACK);
+    assert_eq!(TextColor::Content.resolve(RED, Some(background)), RED);
+
+    // Changing an ancestor's background repaints its descendants.
+    harness.edit_widget(parent_tag, |mut parent| {
+        parent.insert_prop(Background::Color(Color::BLACK));
+    });
+    let _ = harness.render();
+    let background = harness.get_widget(target_tag).inner().state;
+    assert_eq!(background, Some(Color::BLACK));
+    assert_eq!(TextColor::AUTO.resolve(RED, background), Color::WHITE);
+}
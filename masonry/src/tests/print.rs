@@ -0,0 +1,45 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use dpi::PhysicalSize;
+
+use crate::app::{PageSetup, PrintedDocument, RenderRootOptions, WindowSizePolicy};
+use crate::core::{NewWidget, Widget};
+use crate::kurbo::{Insets, Rect, Size};
+use crate::layout::AsUnit;
+use crate::properties::Gap;
+use crate::theme::test_property_set;
+use crate::widgets::{Flex, Portal, SizedBox};
+
+#[test]
+fn scrolled_content_is_split_into_pages() {
+    let mut rows = Flex::column();
+    for _ in 0..10 {
+        rows = rows.with_fixed(SizedBox::empty().height(30.px()).with_auto_id());
+    }
+    let portal = Portal::new(rows.with_props(Gap::ZERO));
+    let setup = PageSetup {
+        size: Size::new(200., 140.),
+        margins: Insets::uniform(20.),
+    };
+    let document = PrintedDocument::new(
+        NewWidget::new(portal),
+        RenderRootOptions {
+            default_properties: Arc::new(test_property_set()),
+            use_system_fonts: false,
+            size_policy: WindowSizePolicy::User,
+            size: PhysicalSize::new(100, 100),
+            scale_factor: 2.0,
+            test_font: None,
+        },
+        setup,
+    );
+
+    // The whole content of the portal is laid out, and the pages break between rows.
+    assert_eq!(document.page_count(), 4);
+    assert_eq!(document.page_content(0), Rect::new(0., 0., 160., 90.));
+    assert_eq!(document.page_content(1), Rect::new(0., 90., 160., 180.));
+    assert_eq!(document.page_content(3), Rect::new(0., 270., 160., 300.));
+}
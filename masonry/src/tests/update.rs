@@ -11,11 +11,12 @@ use masonry_testing::{
 
 use crate::core::pointer::{PointerButton, PointerEvent};
 use crate::core::{
-    CursorIcon, Ime, NewWidget, Properties, TextEvent, Update, Widget, WidgetId, WidgetPod,
-    WidgetTag,
+    CursorIcon, Ime, NewWidget, Properties, TextEvent, TimerToken, Update, Widget, WidgetId,
+    WidgetPod, WidgetTag,
 };
 use crate::layout::Length;
 use crate::theme::test_property_set;
+use crate::util::Duration;
 use crate::widgets::{Button, Flex, Label, SizedBox, TextArea};
 
 // TREE
@@ -551,6 +552,44 @@ fn ime_start_stop() {
 
 // SCROLL
 
+// TIMERS
+
+#[test]
+fn timers_fire_in_order() {
+    let widget_tag = WidgetTag::named("widget");
+    // The state holds the tokens of the requested timers, and of the fired ones.
+    let widget = ModularWidget::new((Vec::new(), Vec::new())).update_fn(
+        |(requested, fired): &mut (Vec<TimerToken>, Vec<TimerToken>), ctx, _, event| match event {
+            Update::WidgetAdded => {
+                requested.push(ctx.request_timer(Duration::from_secs(5)));
+                requested.push(ctx.request_timer(Duration::from_millis(100)));
+            }
+            Update::Timer(token) => fired.push(*token),
+            _ => {}
+        },
+    );
+    let widget = NewWidget::new_with_tag(widget, widget_tag);
+
+    let mut harness = TestHarness::create(test_property_set(), widget);
+    let requested = harness.get_widget(widget_tag).inner().state.0.clone();
+    assert_eq!(requested.len(), 2);
+
+    harness.run_timers_ms(0);
+    assert!(harness.get_widget(widget_tag).inner().state.1.is_empty());
+
+    harness.run_timers_ms(1_000);
+    assert_eq!(
+        harness.get_widget(widget_tag).inner().state.1,
+        [requested[1]]
+    );
+
+    harness.run_timers_ms(10_000);
+    assert_eq!(
+        harness.get_widget(widget_tag).inner().state.1,
+        [requested[1], requested[0]]
+    );
+}
+
 // POINTER
 
 fn create_icon_widget() -> ModularWidget<()> {
@@ -0,0 +1,79 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::core::Widget;
+use crate::kurbo::Size;
+use crate::testing::{RegressionOptions, SnapshotStatus, VisualSuite};
+use crate::theme::test_property_set;
+use crate::widgets::Label;
+
+fn label_suite(text: &'static str) -> VisualSuite {
+    VisualSuite::new()
+        .with_theme("test", test_property_set)
+        .with_window_size(Size::new(100., 40.))
+        .with_window_size(Size::new(60., 40.))
+        .with_screen("label", move || Label::new(text).with_auto_id())
+}
+
+fn statuses(suite: &VisualSuite, options: &RegressionOptions) -> Vec<SnapshotStatus> {
+    let report = suite.run(options).unwrap();
+    let names: Vec<&str> = report
+        .snapshots
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect();
+    assert_eq!(names, ["label-test-100x40", "label-test-60x40"]);
+    report.snapshots.iter().map(|(_, status)| *status).collect()
+}
+
+#[test]
+fn screens_are_compared_with_baseline() {
+    let dir =
+        std::env::temp_dir().join(format!("masonry-visual-regression-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let mut options = RegressionOptions::new(dir.join("baseline"), dir.join("output"));
+
+    // Without a baseline, the screenshots are missing, and only saved in the output directory.
+    let suite = label_suite("Hello");
+    assert_eq!(statuses(&suite, &options), [SnapshotStatus::Missing; 2]);
+    assert!(dir.join("output/label-test-100x40.png").exists());
+    assert!(!dir.join("baseline").exists());
+
+    options.bless = true;
+    assert_eq!(statuses(&suite, &options), [SnapshotStatus::Blessed; 2]);
+    options.bless = false;
+    assert_eq!(statuses(&suite, &options), [SnapshotStatus::Matched; 2]);
+
+    // Hack: If we are using `SKIP_RENDER_TESTS`, the screenshots are a 1x1 white pixel.
+    if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+        let suite = label_suite("World");
+        assert_eq!(statuses(&suite, &options), [SnapshotStatus::Changed; 2]);
+        assert!(dir.join("output/label-test-60x40.diff.png").exists());
+    }
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn regression_options_from_args() {
+    let args = [
+        "--baseline",
+        "shots",
+        "--bless",
+        "--tolerance",
+        "4",
+        "--perceptual-tolerance",
+        "2",
+    ]
+    .map(String::from);
+    let options = RegressionOptions::from_args(args).unwrap();
+    assert_eq!(options.baseline_dir.to_str(), Some("shots"));
+    assert!(options.bless);
+    assert_eq!(options.tolerance, 4);
+    assert_eq!(options.perceptual_tolerance, 2);
+
+    let args = ["--tolerance", "high"].map(String::from);
+    assert!(RegressionOptions::from_args(args).is_err());
+    let args = ["--frobnicate"].map(String::from);
+    assert!(RegressionOptions::from_args(args).is_err());
+}
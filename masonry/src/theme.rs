@@ -2,23 +2,32 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Default values used by various widgets in their paint methods.
+//!
+//! Theme colors are in sRGB, and hosts convert them to the color space of each window's display,
+//! such as with `masonry_winit`'s `OutputColorSpace`.
 
 #![allow(missing_docs, reason = "Names are self-explanatory.")]
 
+use std::any::Any;
+
 use parley::{GenericFamily, LineHeight};
 
-use crate::core::{DefaultProperties, StyleProperty, StyleSet};
+use crate::app::AuditOptions;
+use crate::core::{DefaultProperties, PropertiesRef, StyleProperty, StyleSet, Widget};
 use crate::layout::Length;
 use crate::peniko::Color;
+use crate::peniko::color::{AlphaColor, Oklch, Srgb};
 use crate::properties::{
-    ActiveBackground, Background, BarColor, BorderColor, BorderWidth, CaretColor, CheckmarkColor,
-    CheckmarkStrokeWidth, ContentColor, CornerRadius, DisabledBackground, DisabledCheckmarkColor,
-    DisabledContentColor, FocusedBorderColor, Gap, HoveredBorderColor, Padding, PlaceholderColor,
-    SelectionColor, ThumbColor, ThumbRadius, ToggledBackground, TrackThickness,
-    UnfocusedSelectionColor,
+    ActiveBackground, Background, BarColor, BorderColor, BorderWidth, CaretColor, CaretWidth,
+    CheckmarkColor, CheckmarkStrokeWidth, ContentColor, CornerRadius, DisabledBackground,
+    DisabledCheckmarkColor, DisabledContentColor, FocusedBorderColor, Gap, HoveredBorderColor,
+    Padding, PlaceholderColor, PressFeedback, SelectedBackground, SelectionColor, TextColor,
+    ThumbColor, ThumbRadius, ToggledBackground, TrackThickness, UnfocusedSelectionColor,
 };
 use crate::widgets::{
-    Button, Checkbox, Divider, Flex, Grid, Label, ProgressBar, Spinner, Switch, TextArea, TextInput,
+    Avatar, Badge, Button, Calendar, Checkbox, CommandPalette, Divider, DockArea, Flex, Gauge,
+    GraphCanvas, Grid, Heatmap, Label, List, PageIndicator, ProgressBar, Rating, SegmentedControl,
+    Skeleton, Spinner, StepIndicator, Switch, TextArea, TextInput, TokenInput, Waterfall,
 };
 
 /// Default color for the app background.
@@ -28,6 +37,7 @@ use crate::widgets::{
 pub const BACKGROUND_COLOR: Color = Color::from_rgb8(0x1D, 0x1D, 0x1D);
 
 pub const BORDER_WIDTH: f64 = 1.;
+pub const CARET_WIDTH: f64 = 1.5;
 
 // Zync color variations from https://tailwindcss.com/docs/colors
 pub const ZYNC_900: Color = Color::from_rgb8(0x18, 0x18, 0x1b);
@@ -37,11 +47,24 @@ pub const ZYNC_600: Color = Color::from_rgb8(0x52, 0x52, 0x5b);
 pub const ZYNC_500: Color = Color::from_rgb8(0x71, 0x71, 0x7a);
 
 pub const ACCENT_COLOR: Color = Color::from_rgb8(0x3b, 0x7e, 0xe4);
+pub const ACCENT_ACTIVE_COLOR: Color = Color::from_rgb8(0x2c, 0x66, 0xc2);
+pub const DESTRUCTIVE_COLOR: Color = Color::from_rgb8(0xdc, 0x26, 0x26);
+pub const DESTRUCTIVE_ACTIVE_COLOR: Color = Color::from_rgb8(0xb9, 0x1c, 0x1c);
+pub const DESTRUCTIVE_HOVER_COLOR: Color = Color::from_rgb8(0xf8, 0x71, 0x71);
+pub const ONLINE_COLOR: Color = Color::from_rgb8(0x22, 0xc5, 0x5e);
+pub const AWAY_COLOR: Color = Color::from_rgb8(0xf5, 0x9e, 0x0b);
+pub const RATING_COLOR: Color = Color::from_rgb8(0xfa, 0xcc, 0x15);
 pub const TEXT_COLOR: Color = Color::from_rgb8(0xf2, 0xf2, 0xf2);
 pub const DISABLED_TEXT_COLOR: Color = Color::from_rgb8(0xa0, 0xa0, 0x9a);
 pub const PLACEHOLDER_COLOR: Color = Color::from_rgba8(0xFF, 0xFF, 0xFF, 0x8F);
 pub const TEXT_BACKGROUND_COLOR: Color = Color::from_rgb8(0x16, 0x16, 0x16);
 pub const FOCUS_COLOR: Color = Color::from_rgb8(0xff, 0xff, 0xff);
+pub const LINK_COLOR: Color = Color::from_rgb8(0x6c, 0xa8, 0xff);
+/// Text which picks the theme's dark or light text color, whichever is most readable.
+pub const AUTO_TEXT_COLOR: TextColor = TextColor::Auto {
+    dark: ZYNC_900,
+    light: TEXT_COLOR,
+};
 
 // TODO: The following constants are not being used in properties
 pub const TEXT_SIZE_NORMAL: f32 = 15.0;
@@ -57,6 +80,7 @@ pub const SCROLLBAR_EDGE_WIDTH: f64 = 1.;
 pub const DEFAULT_GAP: Length = Length::const_px(10.0);
 pub const DEFAULT_SPACER_LEN: Length = Length::const_px(10.0);
 pub const WIDGET_CONTROL_COMPONENT_PADDING: Length = Length::const_px(4.0);
+pub const AVATAR_DIAMETER: f64 = 32.;
 
 pub fn default_property_set() -> DefaultProperties {
     let mut properties = DefaultProperties::new();
@@ -70,10 +94,12 @@ pub fn default_property_set() -> DefaultProperties {
 
     properties.insert::<Button, _>(Background::Color(ZYNC_800));
     properties.insert::<Button, _>(ActiveBackground(Background::Color(ZYNC_700)));
+    properties.insert::<Button, _>(PressFeedback::ripple(ZYNC_500.with_alpha(0.5)));
     properties.insert::<Button, _>(DisabledBackground(Background::Color(Color::BLACK)));
     properties.insert::<Button, _>(BorderColor { color: ZYNC_700 });
     properties.insert::<Button, _>(HoveredBorderColor(BorderColor { color: ZYNC_500 }));
     properties.insert::<Button, _>(FocusedBorderColor(BorderColor { color: FOCUS_COLOR }));
+    properties.insert::<Button, _>(Gap::new(Length::const_px(6.0)));
 
     // Checkbox
     properties.insert::<Checkbox, _>(CornerRadius { radius: 4. });
@@ -114,12 +140,44 @@ pub fn default_property_set() -> DefaultProperties {
     properties.insert::<Switch, _>(ThumbRadius(8.0));
     properties.insert::<Switch, _>(TrackThickness(20.0));
 
+    // SegmentedControl
+    properties.insert::<SegmentedControl, _>(Padding::all(2.));
+    properties.insert::<SegmentedControl, _>(CornerRadius { radius: 6. });
+    properties.insert::<SegmentedControl, _>(BorderWidth {
+        width: BORDER_WIDTH,
+    });
+    properties.insert::<SegmentedControl, _>(Background::Color(ZYNC_800));
+    properties.insert::<SegmentedControl, _>(DisabledBackground(Background::Color(Color::BLACK)));
+    properties.insert::<SegmentedControl, _>(ToggledBackground(Background::Color(ACCENT_COLOR)));
+    properties.insert::<SegmentedControl, _>(BorderColor { color: ZYNC_700 });
+    properties.insert::<SegmentedControl, _>(HoveredBorderColor(BorderColor { color: ZYNC_500 }));
+    properties
+        .insert::<SegmentedControl, _>(FocusedBorderColor(BorderColor { color: FOCUS_COLOR }));
+
+    // List
+    properties.insert::<List, _>(SelectedBackground(Background::Color(ACCENT_COLOR)));
+    properties.insert::<List, _>(FocusedBorderColor(BorderColor { color: FOCUS_COLOR }));
+    properties.insert::<List, _>(SelectionColor {
+        color: ACCENT_COLOR,
+    });
+
+    // Avatar
+    properties.insert::<Avatar, _>(Background::Color(ZYNC_600));
+
+    // Badge
+    properties.insert::<Badge, _>(Padding::from_vh(1., 5.));
+    properties.insert::<Badge, _>(CornerRadius { radius: 9. });
+    properties.insert::<Badge, _>(Background::Color(DESTRUCTIVE_COLOR));
+
     // Flex
     properties.insert::<Flex, _>(Gap::new(DEFAULT_GAP));
 
     // Grid
     properties.insert::<Grid, _>(Gap::ZERO);
 
+    // Waterfall
+    properties.insert::<Waterfall, _>(Gap::new(DEFAULT_GAP));
+
     // TextInput
     properties.insert::<TextInput, _>(Padding::from_vh(6., 12.));
     properties.insert::<TextInput, _>(CornerRadius { radius: 4. });
@@ -130,6 +188,7 @@ pub fn default_property_set() -> DefaultProperties {
     properties.insert::<TextInput, _>(FocusedBorderColor(BorderColor { color: FOCUS_COLOR }));
     properties.insert::<TextInput, _>(PlaceholderColor::new(PLACEHOLDER_COLOR));
     properties.insert::<TextInput, _>(CaretColor { color: TEXT_COLOR });
+    properties.insert::<TextInput, _>(CaretWidth { width: CARET_WIDTH });
     properties.insert::<TextInput, _>(SelectionColor {
         color: ACCENT_COLOR,
     });
@@ -139,11 +198,24 @@ pub fn default_property_set() -> DefaultProperties {
     properties.insert::<TextInput, _>(Background::Color(TEXT_BACKGROUND_COLOR));
     properties.insert::<TextInput, _>(DisabledBackground(Background::Color(TEXT_BACKGROUND_COLOR)));
 
+    // TokenInput
+    properties.insert::<TokenInput, _>(Padding::from_vh(4., 6.));
+    properties.insert::<TokenInput, _>(CornerRadius { radius: 4. });
+    properties.insert::<TokenInput, _>(BorderWidth {
+        width: BORDER_WIDTH,
+    });
+    properties.insert::<TokenInput, _>(BorderColor { color: ZYNC_600 });
+    properties.insert::<TokenInput, _>(FocusedBorderColor(BorderColor { color: FOCUS_COLOR }));
+    properties.insert::<TokenInput, _>(Background::Color(TEXT_BACKGROUND_COLOR));
+    properties
+        .insert::<TokenInput, _>(DisabledBackground(Background::Color(TEXT_BACKGROUND_COLOR)));
+
     // TextArea
     properties.insert::<TextArea<false>, _>(ContentColor::new(TEXT_COLOR));
     properties
         .insert::<TextArea<false>, _>(DisabledContentColor(ContentColor::new(DISABLED_TEXT_COLOR)));
     properties.insert::<TextArea<false>, _>(CaretColor { color: TEXT_COLOR });
+    properties.insert::<TextArea<false>, _>(CaretWidth { width: CARET_WIDTH });
     properties.insert::<TextArea<false>, _>(SelectionColor {
         color: ACCENT_COLOR,
     });
@@ -154,6 +226,7 @@ pub fn default_property_set() -> DefaultProperties {
     properties
         .insert::<TextArea<true>, _>(DisabledContentColor(ContentColor::new(DISABLED_TEXT_COLOR)));
     properties.insert::<TextArea<true>, _>(CaretColor { color: TEXT_COLOR });
+    properties.insert::<TextArea<true>, _>(CaretWidth { width: CARET_WIDTH });
     properties.insert::<TextArea<true>, _>(SelectionColor {
         color: ACCENT_COLOR,
     });
@@ -175,9 +248,46 @@ pub fn default_property_set() -> DefaultProperties {
     properties.insert::<ProgressBar, _>(BorderColor { color: ZYNC_800 });
     properties.insert::<ProgressBar, _>(BarColor(ACCENT_COLOR));
 
+    // Rating
+    properties.insert::<Rating, _>(BarColor(RATING_COLOR));
+    properties.insert::<Rating, _>(ContentColor::new(ZYNC_600));
+
+    // Skeleton
+    properties.insert::<Skeleton, _>(ContentColor::new(ZYNC_700));
+
     // Spinner
     properties.insert::<Spinner, _>(ContentColor::new(TEXT_COLOR));
 
+    // StepIndicator
+    properties.insert::<StepIndicator, _>(BarColor(ACCENT_COLOR));
+    properties.insert::<StepIndicator, _>(ContentColor::new(ZYNC_600));
+
+    // Gauge
+    properties.insert::<Gauge, _>(BarColor(ACCENT_COLOR));
+    properties.insert::<Gauge, _>(ContentColor::new(ZYNC_700));
+
+    // Heatmap
+    properties.insert::<Heatmap, _>(ContentColor::new(TEXT_COLOR));
+
+    // CommandPalette
+    properties.insert::<CommandPalette, _>(BarColor(ZYNC_700));
+
+    // DockArea
+    properties.insert::<DockArea, _>(BarColor(ACCENT_COLOR));
+
+    // Calendar
+    properties.insert::<Calendar, _>(BarColor(ACCENT_COLOR));
+    properties.insert::<Calendar, _>(ContentColor::new(ZYNC_800));
+
+    // PageIndicator
+    properties.insert::<PageIndicator, _>(ContentColor::new(TEXT_COLOR));
+
+    // GraphCanvas
+    properties.insert::<GraphCanvas, _>(ContentColor::new(ZYNC_500));
+    properties.insert::<GraphCanvas, _>(SelectionColor {
+        color: ACCENT_COLOR,
+    });
+
     properties
 }
 
@@ -187,6 +297,211 @@ pub fn default_text_styles(styles: &mut StyleSet) {
     styles.insert(GenericFamily::SystemUi.into());
 }
 
+// --- MARK: COLOR UTILITIES
+
+/// The OKLCH chroma below which a color is considered gray, and its hue meaningless.
+const ACHROMATIC_CHROMA: f32 = 1e-4;
+
+/// How much lighter or darker a hovered element is than its base color, in OKLCH lightness.
+const HOVER_LIGHTNESS_STEP: f32 = 0.05;
+
+/// How much lighter or darker a pressed element is than its base color, in OKLCH lightness.
+const PRESSED_LIGHTNESS_STEP: f32 = 0.1;
+
+/// Returns `color` with its OKLCH lightness raised by `amount`, on a scale from 0 to 1.
+///
+/// Unlike mixing with white, this keeps the perceived hue and colorfulness of `color`.
+/// The chroma is reduced if needed for the result to be in the sRGB gamut.
+pub fn lighten(color: Color, amount: f32) -> Color {
+    let [lightness, chroma, hue, alpha] = color.convert::<Oklch>().components;
+    from_oklch(lightness + amount, chroma, hue, alpha)
+}
+
+/// Returns `color` with its OKLCH lightness lowered by `amount`, on a scale from 0 to 1.
+///
+/// See [`lighten`].
+pub fn darken(color: Color, amount: f32) -> Color {
+    lighten(color, -amount)
+}
+
+/// Returns the color `t` of the way from `a` to `b`, interpolated in OKLCH.
+///
+/// The hue goes around the shorter arc between the hues of the two colors, and grays take
+/// the hue of the other color, so that mixing a color with gray or white doesn't shift its hue.
+/// `t` is clamped between 0 and 1.
+pub fn mix(a: Color, b: Color, t: f32) -> Color {
+    let t = t.clamp(0., 1.);
+    let [lightness_a, chroma_a, hue_a, alpha_a] = a.convert::<Oklch>().components;
+    let [lightness_b, chroma_b, hue_b, alpha_b] = b.convert::<Oklch>().components;
+    let (hue_a, hue_b) = match (chroma_a < ACHROMATIC_CHROMA, chroma_b < ACHROMATIC_CHROMA) {
+        (true, false) => (hue_b, hue_b),
+        (false, true) => (hue_a, hue_a),
+        _ => (hue_a, hue_b),
+    };
+    let mut hue_delta = (hue_b - hue_a).rem_euclid(360.);
+    if hue_delta > 180. {
+        hue_delta -= 360.;
+    }
+    let lerp = |a: f32, b: f32| a + (b - a) * t;
+    from_oklch(
+        lerp(lightness_a, lightness_b),
+        lerp(chroma_a, chroma_b),
+        hue_a + hue_delta * t,
+        lerp(alpha_a, alpha_b),
+    )
+}
+
+/// Converts an OKLCH color to sRGB, reducing its chroma until it's in the sRGB gamut.
+fn from_oklch(lightness: f32, chroma: f32, hue: f32, alpha: f32) -> Color {
+    let lightness = lightness.clamp(0., 1.);
+    let hue = hue.rem_euclid(360.);
+    let to_srgb =
+        |chroma: f32| AlphaColor::<Oklch>::new([lightness, chroma, hue, alpha]).convert::<Srgb>();
+    let in_gamut = |color: Color| {
+        color.components[..3]
+            .iter()
+            .all(|component| (-1e-4..=1. + 1e-4).contains(component))
+    };
+
+    let mut color = to_srgb(chroma.max(0.));
+    if !in_gamut(color) {
+        // Binary search for the highest chroma in the gamut.
+        let (mut low, mut high) = (0., chroma);
+        for _ in 0..16 {
+            let middle = (low + high) / 2.;
+            if in_gamut(to_srgb(middle)) {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        color = to_srgb(low);
+    }
+    let [r, g, b, alpha] = color.components;
+    Color::new([
+        r.clamp(0., 1.),
+        g.clamp(0., 1.),
+        b.clamp(0., 1.),
+        alpha.clamp(0., 1.),
+    ])
+}
+
+/// The colors of an interactive element, such as the background of a button, in its
+/// different states.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StateShades {
+    /// The color of the element at rest.
+    pub base: Color,
+    /// The color of the element while hovered.
+    pub hovered: Color,
+    /// The color of the element while pressed.
+    pub pressed: Color,
+}
+
+impl StateShades {
+    /// Derives the hovered and pressed shades of `base`.
+    ///
+    /// Light colors get darker when hovered and pressed, and dark colors get lighter,
+    /// so that the shades stay distinct from the base color.
+    pub fn from_base(base: Color) -> Self {
+        let [lightness, ..] = base.convert::<Oklch>().components;
+        let direction = if lightness > 0.5 { -1. } else { 1. };
+        Self {
+            base,
+            hovered: lighten(base, direction * HOVER_LIGHTNESS_STEP),
+            pressed: lighten(base, direction * PRESSED_LIGHTNESS_STEP),
+        }
+    }
+}
+
+/// Tones of a seed color, from black to white, which all have the hue of the seed.
+///
+/// Tone 0 is black, tone 100 is white, and each tone in between has that OKLCH lightness,
+/// in percent.
+/// Themes can be authored from a handful of seed colors, such as a brand color, by picking
+/// backgrounds, borders and text from the tones of their palettes.
+/// Tones which are too dark or too light to have the seed's chroma in sRGB are less colorful.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TonalPalette {
+    chroma: f32,
+    hue: f32,
+}
+
+impl TonalPalette {
+    /// The tones commonly used by themes.
+    pub const TONES: [u8; 13] = [0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99, 100];
+
+    /// The chroma of [neutral](Self::neutral) palettes.
+    const NEUTRAL_CHROMA: f32 = 0.02;
+
+    /// Creates the palette of `seed`'s hue and chroma.
+    pub fn from_seed(seed: Color) -> Self {
+        let [_, chroma, hue, _] = seed.convert::<Oklch>().components;
+        Self { chroma, hue }
+    }
+
+    /// Creates a palette of nearly grays tinted with `seed`'s hue, for surfaces and text
+    /// which go along with the seed's palette.
+    pub fn neutral(seed: Color) -> Self {
+        let [_, chroma, hue, _] = seed.convert::<Oklch>().components;
+        Self {
+            chroma: chroma.min(Self::NEUTRAL_CHROMA),
+            hue,
+        }
+    }
+
+    /// Returns the color of the given tone, from 0 for black to 100 for white.
+    ///
+    /// Tones above 100 are white.
+    pub fn tone(&self, tone: u8) -> Color {
+        let lightness = f32::from(tone.min(100)) / 100.;
+        from_oklch(lightness, self.chroma, self.hue, 1.)
+    }
+
+    /// Returns the colors of the [commonly used tones](Self::TONES).
+    pub fn tones(&self) -> [Color; 13] {
+        Self::TONES.map(|tone| self.tone(tone))
+    }
+}
+
+// ---
+
+/// Returns the options of the [accessibility audit] for apps using the default theme.
+///
+/// These check the contrast of the text of [`Label`] and [`TextArea`] widgets.
+///
+/// [accessibility audit]: crate::app::RenderRoot::set_accessibility_audit
+pub fn audit_options() -> AuditOptions {
+    AuditOptions {
+        base_color: BACKGROUND_COLOR,
+        text_color: Some(audit_text_color),
+        ..AuditOptions::DEFAULT
+    }
+}
+
+/// Returns the text color of [`Label`] and [`TextArea`] widgets, for the accessibility audit.
+///
+/// Widgets with an automatic [`TextColor`] are skipped, since their text color is picked
+/// for contrast.
+pub fn audit_text_color(
+    widget: &dyn Widget,
+    props: &PropertiesRef<'_>,
+    is_disabled: bool,
+) -> Option<Color> {
+    let widget = widget as &dyn Any;
+    if !(widget.is::<Label>() || widget.is::<TextArea<false>>() || widget.is::<TextArea<true>>()) {
+        return None;
+    }
+    if *props.get::<TextColor>() != TextColor::Content {
+        return None;
+    }
+    if is_disabled {
+        Some(props.get::<DisabledContentColor>().0.color)
+    } else {
+        Some(props.get::<ContentColor>().color)
+    }
+}
+
 /// Set of default properties used in unit tests.
 ///
 /// This lets us change default properties without having to reset all screenshots every time.
@@ -202,6 +517,68 @@ pub(crate) fn test_property_set() -> DefaultProperties {
     properties.insert::<TextArea<false>, _>(CaretColor { color: TEXT_COLOR });
     properties.insert::<Label, _>(ContentColor::new(TEXT_COLOR));
     properties.insert::<Spinner, _>(ContentColor::new(TEXT_COLOR));
+    properties.insert::<PageIndicator, _>(ContentColor::new(TEXT_COLOR));
 
     properties
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lightness(color: Color) -> f32 {
+        color.convert::<Oklch>().components[0]
+    }
+
+    #[test]
+    fn lighten_and_darken() {
+        assert!(lightness(lighten(ACCENT_COLOR, 0.1)) > lightness(ACCENT_COLOR) + 0.09);
+        assert!(lightness(darken(ACCENT_COLOR, 0.1)) < lightness(ACCENT_COLOR) - 0.09);
+        assert_eq!(
+            lighten(Color::WHITE, 0.5).to_rgba8(),
+            Color::WHITE.to_rgba8()
+        );
+        assert_eq!(
+            darken(Color::BLACK, 0.5).to_rgba8(),
+            Color::BLACK.to_rgba8()
+        );
+    }
+
+    #[test]
+    fn mix_colors() {
+        let accent = ACCENT_COLOR.to_rgba8();
+        assert_eq!(mix(ACCENT_COLOR, Color::WHITE, 0.).to_rgba8(), accent);
+        // Mixing with white doesn't shift the hue.
+        let hue = |color: Color| color.convert::<Oklch>().components[2];
+        let mixed = mix(ACCENT_COLOR, Color::WHITE, 0.5);
+        assert!((hue(mixed) - hue(ACCENT_COLOR)).abs() < 1.);
+        assert!(lightness(mixed) > lightness(ACCENT_COLOR));
+    }
+
+    #[test]
+    fn state_shades() {
+        let shades = StateShades::from_base(ACCENT_COLOR);
+        assert!(lightness(shades.pressed) < lightness(shades.hovered));
+        let shades = StateShades::from_base(ZYNC_900);
+        assert!(lightness(shades.pressed) > lightness(shades.hovered));
+    }
+
+    #[test]
+    fn tonal_palette() {
+        let palette = TonalPalette::from_seed(ACCENT_COLOR);
+        let tones = palette.tones();
+        assert_eq!(tones[0].to_rgba8(), Color::BLACK.to_rgba8());
+        assert_eq!(tones[12].to_rgba8(), Color::WHITE.to_rgba8());
+        assert!(
+            tones
+                .windows(2)
+                .all(|pair| lightness(pair[0]) < lightness(pair[1]))
+        );
+        assert!(
+            tones
+                .iter()
+                .flat_map(|tone| &tone.components)
+                .all(|component| (0. ..=1.).contains(component))
+        );
+    }
+}
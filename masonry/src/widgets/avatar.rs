@@ -0,0 +1,368 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ArcStr, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PropertiesRef, RegisterCtx, StyleProperty, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Circle, Point, Size, Vec2};
+use crate::layout::{LenReq, SizeDef};
+use crate::peniko::Color;
+use crate::properties::Background;
+use crate::theme;
+use crate::util::{fill, stroke};
+use crate::widgets::Label;
+
+/// The size of the initials, relative to the diameter of the avatar.
+const INITIALS_SCALE: f64 = 0.4;
+/// The diameter of the status dot, relative to the diameter of the avatar.
+const STATUS_DOT_SCALE: f64 = 0.3;
+
+/// The presence of the person shown by an [`Avatar`], shown as a dot in its corner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AvatarStatus {
+    /// The person is available.
+    Online,
+    /// The person is connected but inactive.
+    Away,
+    /// The person doesn't want to be disturbed.
+    Busy,
+    /// The person isn't connected.
+    Offline,
+}
+
+impl AvatarStatus {
+    /// Returns the color of the status dot, from the [theme](crate::theme).
+    pub fn color(self) -> Color {
+        match self {
+            Self::Online => theme::ONLINE_COLOR,
+            Self::Away => theme::AWAY_COLOR,
+            Self::Busy => theme::DESTRUCTIVE_COLOR,
+            Self::Offline => theme::ZYNC_500,
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::Online => "Online",
+            Self::Away => "Away",
+            Self::Busy => "Busy",
+            Self::Offline => "Offline",
+        }
+    }
+}
+
+/// A circular picture of a person, such as the author of a message in a chat.
+///
+/// The avatar shows the initials of the person's name on its [`Background`],
+/// and the [picture](Self::with_picture) on top of them, clipped to a circle.
+/// The initials are visible until the picture is loaded, or if there's no picture.
+///
+/// The avatar can also show the [status](AvatarStatus) of the person as a dot
+/// in its bottom right corner.
+pub struct Avatar {
+    name: ArcStr,
+    initials: WidgetPod<Label>,
+    picture: Option<WidgetPod<dyn Widget>>,
+    status: Option<AvatarStatus>,
+    diameter: f64,
+}
+
+// --- MARK: BUILDERS
+impl Avatar {
+    /// Creates an avatar for the person called `name`, showing their initials.
+    pub fn new(name: impl Into<ArcStr>) -> Self {
+        let name = name.into();
+        let initials = Label::new(initials(&name)).with_style(StyleProperty::FontSize(
+            (theme::AVATAR_DIAMETER * INITIALS_SCALE) as f32,
+        ));
+        Self {
+            name,
+            initials: NewWidget::new(initials).to_pod(),
+            picture: None,
+            status: None,
+            diameter: theme::AVATAR_DIAMETER,
+        }
+    }
+
+    /// Builder-style method to show a picture, such as an [`Image`](crate::widgets::Image),
+    /// instead of the initials.
+    ///
+    /// The picture is given the size of the avatar, and should cover it.
+    pub fn with_picture(mut self, picture: NewWidget<impl Widget + ?Sized>) -> Self {
+        self.picture = Some(picture.erased().to_pod());
+        self
+    }
+
+    /// Builder-style method to show the status of the person.
+    pub fn with_status(mut self, status: AvatarStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Builder-style method to set the diameter of the avatar, in logical pixels.
+    ///
+    /// The initials and the status dot are scaled with it.
+    /// The default is [`AVATAR_DIAMETER`](theme::AVATAR_DIAMETER).
+    pub fn with_diameter(mut self, diameter: f64) -> Self {
+        self.diameter = diameter;
+        self
+    }
+
+    /// Returns the name of the person.
+    pub fn name(&self) -> &ArcStr {
+        &self.name
+    }
+
+    /// Returns the status of the person, if it's shown.
+    pub fn status(&self) -> Option<AvatarStatus> {
+        self.status
+    }
+
+    /// Returns the diameter of the avatar.
+    pub fn diameter(&self) -> f64 {
+        self.diameter
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Avatar {
+    /// Sets the name of the person, and the initials shown for it.
+    pub fn set_name(this: &mut WidgetMut<'_, Self>, name: impl Into<ArcStr>) {
+        let name = name.into();
+        {
+            let mut label = this.ctx.get_mut(&mut this.widget.initials);
+            Label::set_text(&mut label, initials(&name));
+        }
+        this.widget.name = name;
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Replaces the picture, or removes it if `picture` is `None`.
+    pub fn set_picture(
+        this: &mut WidgetMut<'_, Self>,
+        picture: Option<NewWidget<impl Widget + ?Sized>>,
+    ) {
+        if let Some(old) = this.widget.picture.take() {
+            this.ctx.remove_child(old);
+        }
+        this.widget.picture = picture.map(|picture| picture.erased().to_pod());
+        this.ctx.children_changed();
+    }
+
+    /// Returns a mutable reference to the picture, if there is one.
+    pub fn picture_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> Option<WidgetMut<'t, dyn Widget>> {
+        let picture = this.widget.picture.as_mut()?;
+        Some(this.ctx.get_mut(picture))
+    }
+
+    /// Sets the status of the person, or hides it if `status` is `None`.
+    pub fn set_status(this: &mut WidgetMut<'_, Self>, status: Option<AvatarStatus>) {
+        if this.widget.status == status {
+            return;
+        }
+        this.widget.status = status;
+        this.ctx.request_paint_only();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets the diameter of the avatar, in logical pixels.
+    pub fn set_diameter(this: &mut WidgetMut<'_, Self>, diameter: f64) {
+        if this.widget.diameter == diameter {
+            return;
+        }
+        this.widget.diameter = diameter;
+        {
+            let mut label = this.ctx.get_mut(&mut this.widget.initials);
+            Label::insert_style(
+                &mut label,
+                StyleProperty::FontSize((diameter * INITIALS_SCALE) as f32),
+            );
+        }
+        this.ctx.request_layout();
+    }
+}
+
+/// Returns the uppercase first letters of the first two words of `name`.
+fn initials(name: &str) -> String {
+    name.split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .take(2)
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Avatar {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.initials);
+        if let Some(picture) = &mut self.picture {
+            ctx.register_child(picture);
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if property_type == TypeId::of::<Background>() {
+            ctx.request_paint_only();
+        }
+    }
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _axis: Axis,
+        _len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        self.diameter
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let initials_size = ctx.compute_size(&mut self.initials, SizeDef::fit(size), size.into());
+        ctx.run_layout(&mut self.initials, initials_size);
+        ctx.place_child(
+            &mut self.initials,
+            ((size - initials_size).to_vec2() * 0.5).to_point(),
+        );
+
+        if let Some(picture) = &mut self.picture {
+            ctx.run_layout(picture, size);
+            ctx.place_child(picture, Point::ORIGIN);
+        }
+
+        let diameter = size.width.min(size.height);
+        ctx.set_clip_path(Circle::new(size.to_rect().center(), diameter / 2.));
+    }
+
+    fn pre_paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _scene: &mut Scene,
+    ) {
+        // The background is painted in `paint`, where it's clipped to a circle.
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let rect = ctx.content_box_size().to_rect();
+        let brush = props.get::<Background>().get_peniko_brush_for_rect(rect);
+        fill(scene, &rect, &brush);
+    }
+
+    fn post_paint(
+        &mut self,
+        ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        scene: &mut Scene,
+    ) {
+        let Some(status) = self.status else {
+            return;
+        };
+        // The dot sits on the circle, at its bottom right.
+        let size = ctx.content_box_size();
+        let radius = size.width.min(size.height) / 2.;
+        let offset = radius * std::f64::consts::FRAC_1_SQRT_2;
+        let center = size.to_rect().center() + Vec2::new(offset, offset);
+        let dot = Circle::new(center, radius * STATUS_DOT_SCALE);
+        fill(scene, &dot, status.color());
+        // Separate the dot from the picture with a ring of the app background color.
+        stroke(scene, &dot, theme::BACKGROUND_COLOR, 2.);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Image
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_label(&*self.name);
+        if let Some(status) = self.status {
+            node.set_description(status.description());
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        let mut ids = ChildrenIds::from_slice(&[self.initials.id()]);
+        ids.extend(self.picture.as_ref().map(|picture| picture.id()));
+        ids
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Avatar", id = id.trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(self.name.to_string())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::WidgetTag;
+    use crate::layout::AsUnit;
+    use crate::properties::types::{CrossAxisAlignment, MainAxisAlignment};
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::{Flex, SizedBox};
+
+    #[test]
+    fn initials_of_names() {
+        assert_eq!(initials("Ada Lovelace"), "AL");
+        assert_eq!(initials("  grace  brewster hopper "), "GB");
+        assert_eq!(initials("Émile"), "É");
+        assert_eq!(initials(""), "");
+    }
+
+    #[test]
+    fn avatar_has_its_diameter() {
+        let avatar_tag = WidgetTag::named("avatar");
+        let picture_tag = WidgetTag::named("picture");
+        let avatar = Avatar::new("Ada Lovelace")
+            .with_status(AvatarStatus::Online)
+            .with_diameter(48.);
+
+        // Wrap in Flex with Start alignment so it doesn't stretch the avatar
+        let flex = Flex::row()
+            .with_fixed(NewWidget::new_with_tag(avatar, avatar_tag))
+            .main_axis_alignment(MainAxisAlignment::Start)
+            .cross_axis_alignment(CrossAxisAlignment::Start);
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            flex.with_auto_id(),
+            Size::new(100., 100.),
+        );
+        let size =
+            |harness: &TestHarness<Flex>| harness.get_widget(avatar_tag).ctx().border_box_size();
+        assert_eq!(size(&harness), Size::new(48., 48.));
+
+        harness.edit_widget(avatar_tag, |mut avatar| {
+            Avatar::set_diameter(&mut avatar, 24.);
+            Avatar::set_picture(
+                &mut avatar,
+                Some(NewWidget::new_with_tag(
+                    SizedBox::empty().size(10.px(), 10.px()),
+                    picture_tag,
+                )),
+            );
+        });
+        assert_eq!(size(&harness), Size::new(24., 24.));
+        // The picture covers the avatar.
+        let picture = harness.get_widget(picture_tag);
+        assert_eq!(picture.ctx().border_box_size(), Size::new(24., 24.));
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ArcStr, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PropertiesRef, RegisterCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Size};
+use crate::layout::{LayoutSize, LenReq, SizeDef};
+use crate::widgets::Label;
+
+/// The largest count shown by [`Badge::count`], larger counts are shown as "99+".
+const MAX_COUNT: u64 = 99;
+
+/// A small pill showing a count or a short label, such as the number of unread messages.
+///
+/// The badge is usually shown in a corner of another widget, by putting both in a
+/// [`ZStack`](crate::widgets::ZStack) and [aligning](crate::widgets::ChildAlignment)
+/// the badge to that corner.
+///
+/// The badge is at least as wide as it is high, so that a single digit is shown in a circle.
+pub struct Badge {
+    label: WidgetPod<Label>,
+}
+
+// --- MARK: BUILDERS
+impl Badge {
+    /// Creates a badge showing `text`.
+    pub fn new(text: impl Into<ArcStr>) -> Self {
+        Self {
+            label: NewWidget::new(Label::new(text)).to_pod(),
+        }
+    }
+
+    /// Creates a badge showing `count`, or "99+" if it's larger than 99.
+    pub fn count(count: u64) -> Self {
+        Self::new(count_text(count))
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Badge {
+    /// Sets the text shown by the badge.
+    pub fn set_text(this: &mut WidgetMut<'_, Self>, text: impl Into<ArcStr>) {
+        Label::set_text(&mut Self::label_mut(this), text);
+    }
+
+    /// Sets the count shown by the badge, as with [`Badge::count`].
+    pub fn set_count(this: &mut WidgetMut<'_, Self>, count: u64) {
+        Self::set_text(this, count_text(count));
+    }
+
+    /// Returns a mutable reference to the label of the badge.
+    pub fn label_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, Label> {
+        this.ctx.get_mut(&mut this.widget.label)
+    }
+}
+
+fn count_text(count: u64) -> String {
+    if count > MAX_COUNT {
+        format!("{MAX_COUNT}+")
+    } else {
+        count.to_string()
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Badge {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.label);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let auto_length = len_req.into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        let length = ctx.compute_length(
+            &mut self.label,
+            auto_length,
+            context_size,
+            axis,
+            cross_length,
+        );
+        match axis {
+            Axis::Horizontal => {
+                let height = ctx.compute_length(
+                    &mut self.label,
+                    auto_length,
+                    LayoutSize::NONE,
+                    Axis::Vertical,
+                    None,
+                );
+                length.max(height)
+            }
+            Axis::Vertical => length,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let label_size = ctx.compute_size(&mut self.label, SizeDef::fit(size), size.into());
+        ctx.run_layout(&mut self.label, label_size);
+        let origin = ((size - label_size).to_vec2() * 0.5).to_point();
+        ctx.place_child(&mut self.label, origin);
+
+        let label_baseline = ctx.child_baseline_offset(&self.label);
+        let bottom_gap = size.height - origin.y - label_size.height;
+        ctx.set_baseline_offset(label_baseline + bottom_gap);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::Status
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.label.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Badge", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::UnitPoint;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::ZStack;
+
+    #[test]
+    fn count_is_capped() {
+        assert_eq!(count_text(7), "7");
+        assert_eq!(count_text(99), "99");
+        assert_eq!(count_text(100), "99+");
+    }
+
+    #[test]
+    fn badge_anchors_to_corner() {
+        let badge = NewWidget::new(Badge::count(3));
+        let badge_id = badge.id();
+        let stack = ZStack::new()
+            .with(
+                NewWidget::new(Label::new("Inbox with a long name")),
+                UnitPoint::CENTER,
+            )
+            .with(badge, UnitPoint::TOP_RIGHT);
+        let harness = TestHarness::create_with_size(
+            test_property_set(),
+            stack.with_auto_id(),
+            Size::new(300., 100.),
+        );
+
+        let badge = harness.get_widget_with_id(badge_id);
+        let size = badge.ctx().border_box_size();
+        assert!(
+            size.width >= size.height,
+            "badge should be a pill: {size:?}"
+        );
+        assert_eq!(badge.ctx().window_origin().y, 0.);
+        assert_eq!(badge.ctx().window_origin().x + size.width, 300.);
+    }
+}
@@ -13,19 +13,29 @@ use crate::core::MeasureCtx;
 use crate::core::keyboard::{Key, NamedKey};
 use crate::core::pointer::PointerButton;
 use crate::core::{
-    AccessCtx, AccessEvent, ChildrenIds, EventCtx, LayoutCtx, NewWidget, PaintCtx,
-    PointerButtonEvent, PointerEvent, PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, Update,
-    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+    AccessCtx, AccessEvent, ChildrenIds, EventCtx, HasProperty, LayoutCtx, NewWidget, PaintCtx,
+    PointerButtonEvent, PointerEvent, Properties, PropertiesMut, PropertiesRef, RegisterCtx,
+    TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
 };
-use crate::kurbo::{Axis, Size};
+use crate::kurbo::{Axis, Point, Size};
 use crate::layout::{LayoutSize, LenReq, SizeDef};
+use crate::peniko::Color;
+use crate::properties::{ActiveBackground, Background, BorderColor, Gap, HoveredBorderColor};
 use crate::theme;
-use crate::widgets::Label;
+use crate::widgets::{Label, Spinner};
 
 /// A button with a child widget.
 ///
 /// Emits [`ButtonPress`] when pressed.
 ///
+/// The button can have [leading](Self::with_leading_icon) and [trailing](Self::with_trailing_icon)
+/// icons on each side of its child, separated from it by the [`Gap`] property.
+/// While it is [loading](Self::with_loading), the child is replaced by a [`Spinner`]
+/// and the button doesn't emit any action.
+///
+/// The look of the button can be changed to one of the [`ButtonVariant`]s by giving it the
+/// [properties](ButtonVariant::properties) of that variant.
+///
 #[doc = concat!(
     "![Button with text label](",
     include_doc_path!("screenshots/button_hello.png"),
@@ -33,6 +43,24 @@ use crate::widgets::Label;
 )]
 pub struct Button {
     child: WidgetPod<dyn Widget>,
+    leading_icon: Option<WidgetPod<dyn Widget>>,
+    trailing_icon: Option<WidgetPod<dyn Widget>>,
+    /// The spinner shown instead of the child, if the button is loading.
+    spinner: Option<WidgetPod<Spinner>>,
+}
+
+/// The style variants of a [`Button`], whose colors are resolved from the [theme](crate::theme).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ButtonVariant {
+    /// The main action of a view, filled with the accent color.
+    Primary,
+    /// The default look of buttons, as set by the default properties.
+    #[default]
+    Secondary,
+    /// A button without background or border until it is hovered or pressed.
+    Ghost,
+    /// An action which destroys data, such as deleting a file.
+    Destructive,
 }
 
 // --- MARK: BUILDERS
@@ -53,6 +81,9 @@ impl Button {
     pub fn new(child: NewWidget<impl Widget + ?Sized>) -> Self {
         Self {
             child: child.erased().to_pod(),
+            leading_icon: None,
+            trailing_icon: None,
+            spinner: None,
         }
     }
 
@@ -69,6 +100,94 @@ impl Button {
     pub fn with_text(text: impl Into<Arc<str>>) -> Self {
         Self::new(Label::new(text).with_auto_id())
     }
+
+    /// Builder-style method to add an icon before the child.
+    ///
+    /// Like the child, the icon shouldn't be interactive.
+    pub fn with_leading_icon(mut self, icon: NewWidget<impl Widget + ?Sized>) -> Self {
+        self.leading_icon = Some(icon.erased().to_pod());
+        self
+    }
+
+    /// Builder-style method to add an icon after the child.
+    ///
+    /// Like the child, the icon shouldn't be interactive.
+    pub fn with_trailing_icon(mut self, icon: NewWidget<impl Widget + ?Sized>) -> Self {
+        self.trailing_icon = Some(icon.erased().to_pod());
+        self
+    }
+
+    /// Builder-style method to set whether the button is loading.
+    ///
+    /// A loading button shows a [`Spinner`] instead of its child, and ignores presses.
+    /// It keeps the size it has with its child, so that the layout doesn't jump.
+    pub fn with_loading(mut self, loading: bool) -> Self {
+        self.spinner = loading.then(|| NewWidget::new(Spinner::new()).to_pod());
+        self
+    }
+
+    /// Returns whether the button is [loading](Self::with_loading).
+    pub fn is_loading(&self) -> bool {
+        self.spinner.is_some()
+    }
+}
+
+impl ButtonVariant {
+    /// The properties which give a [`Button`] the look of this variant.
+    ///
+    /// These are the [`Background`], [`ActiveBackground`], [`BorderColor`] and
+    /// [`HoveredBorderColor`] of the variant.
+    /// They are empty for [`Secondary`](Self::Secondary), which uses the default properties.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use masonry::core::{NewWidget, Widget};
+    /// use masonry::widgets::{Button, ButtonVariant};
+    ///
+    /// let button = NewWidget::new_with_props(
+    ///     Button::with_text("Delete"),
+    ///     ButtonVariant::Destructive.properties(),
+    /// );
+    /// ```
+    pub fn properties(self) -> Properties {
+        let Some((background, active_background, border, hovered_border)) = self.colors() else {
+            return Properties::new();
+        };
+        Properties::new()
+            .with(Background::Color(background))
+            .with(ActiveBackground(Background::Color(active_background)))
+            .with(BorderColor { color: border })
+            .with(HoveredBorderColor(BorderColor {
+                color: hovered_border,
+            }))
+    }
+
+    /// The background, active background, border and hovered border colors of this variant,
+    /// or `None` for the default look.
+    fn colors(self) -> Option<(Color, Color, Color, Color)> {
+        match self {
+            Self::Primary => Some((
+                theme::ACCENT_COLOR,
+                theme::ACCENT_ACTIVE_COLOR,
+                theme::ACCENT_COLOR,
+                theme::LINK_COLOR,
+            )),
+            Self::Secondary => None,
+            Self::Ghost => Some((
+                Color::TRANSPARENT,
+                theme::ZYNC_800,
+                Color::TRANSPARENT,
+                theme::ZYNC_700,
+            )),
+            Self::Destructive => Some((
+                theme::DESTRUCTIVE_COLOR,
+                theme::DESTRUCTIVE_ACTIVE_COLOR,
+                theme::DESTRUCTIVE_COLOR,
+                theme::DESTRUCTIVE_HOVER_COLOR,
+            )),
+        }
+    }
 }
 
 // --- MARK: WIDGETMUT
@@ -85,6 +204,85 @@ impl Button {
     pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
         this.ctx.get_mut(&mut this.widget.child)
     }
+
+    /// Returns a mutable reference to the leading icon, if there is one.
+    pub fn leading_icon_mut<'t>(
+        this: &'t mut WidgetMut<'_, Self>,
+    ) -> Option<WidgetMut<'t, dyn Widget>> {
+        let icon = this.widget.leading_icon.as_mut()?;
+        Some(this.ctx.get_mut(icon))
+    }
+
+    /// Returns a mutable reference to the trailing icon, if there is one.
+    pub fn trailing_icon_mut<'t>(
+        this: &'t mut WidgetMut<'_, Self>,
+    ) -> Option<WidgetMut<'t, dyn Widget>> {
+        let icon = this.widget.trailing_icon.as_mut()?;
+        Some(this.ctx.get_mut(icon))
+    }
+
+    /// Replaces the leading icon, or removes it if `icon` is `None`.
+    pub fn set_leading_icon(
+        this: &mut WidgetMut<'_, Self>,
+        icon: Option<NewWidget<impl Widget + ?Sized>>,
+    ) {
+        if let Some(old) = this.widget.leading_icon.take() {
+            this.ctx.remove_child(old);
+        }
+        this.widget.leading_icon = icon.map(|icon| icon.erased().to_pod());
+        this.ctx.children_changed();
+    }
+
+    /// Replaces the trailing icon, or removes it if `icon` is `None`.
+    pub fn set_trailing_icon(
+        this: &mut WidgetMut<'_, Self>,
+        icon: Option<NewWidget<impl Widget + ?Sized>>,
+    ) {
+        if let Some(old) = this.widget.trailing_icon.take() {
+            this.ctx.remove_child(old);
+        }
+        this.widget.trailing_icon = icon.map(|icon| icon.erased().to_pod());
+        this.ctx.children_changed();
+    }
+
+    /// Sets whether the button is loading.
+    ///
+    /// The runtime equivalent of [`with_loading`](Self::with_loading).
+    pub fn set_loading(this: &mut WidgetMut<'_, Self>, loading: bool) {
+        if this.widget.is_loading() == loading {
+            return;
+        }
+        if let Some(spinner) = this.widget.spinner.take() {
+            this.ctx.remove_child(spinner);
+        } else {
+            this.widget.spinner = Some(NewWidget::new(Spinner::new()).to_pod());
+        }
+        this.ctx.children_changed();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Gives the button the look of `variant`.
+    ///
+    /// This replaces the [properties](ButtonVariant::properties) set by the previous variant,
+    /// or by the app.
+    pub fn set_variant(this: &mut WidgetMut<'_, Self>, variant: ButtonVariant) {
+        match variant.colors() {
+            Some((background, active_background, border, hovered_border)) => {
+                this.insert_prop(Background::Color(background));
+                this.insert_prop(ActiveBackground(Background::Color(active_background)));
+                this.insert_prop(BorderColor { color: border });
+                this.insert_prop(HoveredBorderColor(BorderColor {
+                    color: hovered_border,
+                }));
+            }
+            None => {
+                this.remove_prop::<Background>();
+                this.remove_prop::<ActiveBackground>();
+                this.remove_prop::<BorderColor>();
+                this.remove_prop::<HoveredBorderColor>();
+            }
+        }
+    }
 }
 
 /// A button was pressed.
@@ -96,6 +294,8 @@ pub struct ButtonPress {
     pub button: Option<PointerButton>,
 }
 
+impl HasProperty<Gap> for Button {}
+
 // --- MARK: IMPL WIDGET
 impl Widget for Button {
     type Action = ButtonPress;
@@ -115,7 +315,7 @@ impl Widget for Button {
                 trace!("Button {:?} pressed", ctx.widget_id());
             }
             PointerEvent::Up(PointerButtonEvent { button, .. }) => {
-                if ctx.is_active() && ctx.is_hovered() {
+                if ctx.is_active() && ctx.is_hovered() && !self.is_loading() {
                     ctx.submit_action::<Self::Action>(ButtonPress { button: *button });
                     trace!("Button {:?} released", ctx.widget_id());
                 }
@@ -133,12 +333,13 @@ impl Widget for Button {
         event: &TextEvent,
     ) {
         match event {
-            TextEvent::Keyboard(event) if event.state.is_up() => {
-                if matches!(&event.key, Key::Character(c) if c == " ")
-                    || event.key == Key::Named(NamedKey::Enter)
-                {
-                    ctx.submit_action::<Self::Action>(ButtonPress { button: None });
-                }
+            TextEvent::Keyboard(event)
+                if event.state.is_up()
+                    && !self.is_loading()
+                    && (matches!(&event.key, Key::Character(c) if c == " ")
+                        || event.key == Key::Named(NamedKey::Enter)) =>
+            {
+                ctx.submit_action::<Self::Action>(ButtonPress { button: None });
             }
             _ => (),
         }
@@ -151,7 +352,7 @@ impl Widget for Button {
         event: &AccessEvent,
     ) {
         match event.action {
-            accesskit::Action::Click => {
+            accesskit::Action::Click if !self.is_loading() => {
                 ctx.submit_action::<Self::Action>(ButtonPress { button: None });
             }
             _ => {}
@@ -168,14 +369,25 @@ impl Widget for Button {
 
     fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
         ctx.register_child(&mut self.child);
+        for icon in [&mut self.leading_icon, &mut self.trailing_icon]
+            .into_iter()
+            .flatten()
+        {
+            ctx.register_child(icon);
+        }
+        if let Some(spinner) = &mut self.spinner {
+            ctx.register_child(spinner);
+        }
     }
 
-    fn property_changed(&mut self, _ctx: &mut UpdateCtx<'_>, _property_type: TypeId) {}
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        Gap::prop_changed(ctx, property_type);
+    }
 
     fn measure(
         &mut self,
         ctx: &mut MeasureCtx<'_>,
-        _props: &PropertiesRef<'_>,
+        props: &PropertiesRef<'_>,
         axis: Axis,
         len_req: LenReq,
         cross_length: Option<f64>,
@@ -186,7 +398,9 @@ impl Widget for Button {
 
         let auto_length = len_req.into();
         let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        let gap = props.get::<Gap>().gap.dp(scale);
 
+        // The child is measured even while the button is loading, to keep its size.
         let child_length = ctx.compute_length(
             &mut self.child,
             auto_length,
@@ -195,7 +409,18 @@ impl Widget for Button {
             cross_length,
         );
 
-        let length = child_length;
+        let mut length = child_length;
+        for icon in [&mut self.leading_icon, &mut self.trailing_icon]
+            .into_iter()
+            .flatten()
+        {
+            let icon_length =
+                ctx.compute_length(icon, auto_length, context_size, axis, cross_length);
+            length = match axis {
+                Axis::Horizontal => length + gap + icon_length,
+                Axis::Vertical => length.max(icon_length),
+            };
+        }
 
         // TODO - Add MinimumSize property.
         // HACK: to make sure we look okay at default sizes when beside a text input,
@@ -206,11 +431,63 @@ impl Widget for Button {
         }
     }
 
-    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
-        let child_size = ctx.compute_size(&mut self.child, SizeDef::fit(size), size.into());
-        ctx.run_layout(&mut self.child, child_size);
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, props: &PropertiesRef<'_>, size: Size) {
+        // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
+        //       https://github.com/linebender/xilem/issues/1264
+        let scale = 1.0;
+
+        let gap = props.get::<Gap>().gap.dp(scale);
+
+        // The icons get their preferred size, and the child gets the remaining width.
+        let mut icons_width = 0.;
+        let mut icon_sizes = [Size::ZERO; 2];
+        for (icon, icon_size) in [&mut self.leading_icon, &mut self.trailing_icon]
+            .into_iter()
+            .zip(&mut icon_sizes)
+        {
+            if let Some(icon) = icon {
+                *icon_size = ctx.compute_size(icon, SizeDef::fit(size), size.into());
+                ctx.run_layout(icon, *icon_size);
+                icons_width += icon_size.width + gap;
+            }
+        }
+
+        let child_space = Size::new((size.width - icons_width).max(0.), size.height);
+        let child_size = ctx.compute_size(
+            &mut self.child,
+            SizeDef::fit(child_space),
+            child_space.into(),
+        );
+
+        // The icons and the child are centered together.
+        let mut x = (size.width - icons_width - child_size.width) * 0.5;
+        if let Some(icon) = &mut self.leading_icon {
+            let icon_size = icon_sizes[0];
+            ctx.place_child(icon, Point::new(x, (size.height - icon_size.height) * 0.5));
+            x += icon_size.width + gap;
+        }
+        let child_origin = Point::new(x, (size.height - child_size.height) * 0.5);
+        x += child_size.width + gap;
+        if let Some(icon) = &mut self.trailing_icon {
+            let icon_size = icon_sizes[1];
+            ctx.place_child(icon, Point::new(x, (size.height - icon_size.height) * 0.5));
+        }
+
+        // TODO: move set_stashed to a different layout pass when possible
+        ctx.set_stashed(&mut self.child, self.spinner.is_some());
+        if let Some(spinner) = &mut self.spinner {
+            // The spinner is a square in the middle of the place of the child.
+            let side = child_size.width.min(child_size.height);
+            let spinner_size = Size::new(side, side);
+            ctx.run_layout(spinner, spinner_size);
+            ctx.place_child(
+                spinner,
+                child_origin + (child_size - spinner_size).to_vec2() * 0.5,
+            );
+            return;
+        }
 
-        let child_origin = ((size - child_size).to_vec2() * 0.5).to_point();
+        ctx.run_layout(&mut self.child, child_size);
         ctx.place_child(&mut self.child, child_origin);
 
         let child_baseline = ctx.child_baseline_offset(&self.child);
@@ -232,10 +509,21 @@ impl Widget for Button {
         node: &mut Node,
     ) {
         node.add_action(accesskit::Action::Click);
+        if self.is_loading() {
+            node.set_busy();
+        }
     }
 
     fn children_ids(&self) -> ChildrenIds {
-        ChildrenIds::from_slice(&[self.child.id()])
+        let mut ids = ChildrenIds::from_slice(&[self.child.id()]);
+        ids.extend(
+            [&self.leading_icon, &self.trailing_icon]
+                .into_iter()
+                .flatten()
+                .map(|icon| icon.id()),
+        );
+        ids.extend(self.spinner.as_ref().map(|spinner| spinner.id()));
+        ids
     }
 
     fn accepts_focus(&self) -> bool {
@@ -259,7 +547,7 @@ mod tests {
     use masonry_testing::{TestHarnessParams, assert_failing_render_snapshot};
 
     use super::*;
-    use crate::core::{CollectionWidget, PointerButton, Properties, StyleProperty};
+    use crate::core::{CollectionWidget, PointerButton, Properties, StyleProperty, WidgetTag};
     use crate::layout::AsUnit;
     use crate::properties::{
         BorderColor, BorderWidth, BoxShadow, ContentColor, CornerRadius, Gap, Padding,
@@ -456,6 +744,87 @@ mod tests {
         assert_failing_render_snapshot!(harness, "button_shadows");
     }
 
+    #[test]
+    fn icons_around_child() {
+        let leading_tag = WidgetTag::named("leading");
+        let child_tag = WidgetTag::named("child");
+        let trailing_tag = WidgetTag::named("trailing");
+
+        let button = Button::new(NewWidget::new_with_tag(
+            SizedBox::empty().size(30.px(), 10.px()),
+            child_tag,
+        ))
+        .with_leading_icon(NewWidget::new_with_tag(
+            SizedBox::empty().size(10.px(), 10.px()),
+            leading_tag,
+        ))
+        .with_trailing_icon(NewWidget::new_with_tag(
+            SizedBox::empty().size(12.px(), 12.px()),
+            trailing_tag,
+        ));
+        let button = NewWidget::new_with_props(button, Gap::new(5.px()));
+
+        let harness =
+            TestHarness::create_with_size(test_property_set(), button, Size::new(200., 40.));
+        let origin = |tag: WidgetTag<SizedBox>| harness.get_widget(tag).ctx().window_origin();
+
+        let leading = origin(leading_tag);
+        let child = origin(child_tag);
+        let trailing = origin(trailing_tag);
+        assert_eq!(child.x, leading.x + 10. + 5.);
+        assert_eq!(trailing.x, child.x + 30. + 5.);
+        assert_eq!(leading.y, child.y);
+        assert_eq!(trailing.y, child.y - 1.);
+    }
+
+    #[test]
+    fn loading_ignores_presses() {
+        let button = NewWidget::new(Button::with_text("Save").with_loading(true));
+        let mut harness = TestHarness::create(test_property_set(), button);
+        let button_id = harness.root_id();
+
+        harness.mouse_click_on(button_id);
+        harness.process_text_event(TextEvent::key_up(Key::Named(NamedKey::Enter)));
+        assert!(harness.pop_action::<ButtonPress>().is_none());
+
+        harness.edit_root_widget(|mut button| {
+            Button::set_loading(&mut button, false);
+        });
+        assert!(!harness.root_widget().is_loading());
+        harness.mouse_click_on(button_id);
+        assert!(harness.pop_action::<ButtonPress>().is_some());
+    }
+
+    #[test]
+    fn set_variant_replaces_properties() {
+        let button = NewWidget::new_with_props(
+            Button::with_text("Delete"),
+            ButtonVariant::Destructive.properties(),
+        );
+        let mut harness = TestHarness::create(test_property_set(), button);
+        assert_eq!(
+            harness.root_widget().get_prop::<Background>(),
+            &Background::Color(theme::DESTRUCTIVE_COLOR)
+        );
+
+        harness.edit_root_widget(|mut button| {
+            Button::set_variant(&mut button, ButtonVariant::Primary);
+        });
+        assert_eq!(
+            harness.root_widget().get_prop::<Background>(),
+            &Background::Color(ACCENT_COLOR)
+        );
+
+        // The secondary variant falls back to the default properties.
+        harness.edit_root_widget(|mut button| {
+            Button::set_variant(&mut button, ButtonVariant::Secondary);
+        });
+        assert_eq!(
+            harness.root_widget().get_prop::<Background>(),
+            &Background::Color(theme::ZYNC_800)
+        );
+    }
+
     /// We document that several other non-interactive widgets in Masonry can be correctly used as children in buttons.
     ///
     /// We validate that each of these actually are correctly supported.
@@ -0,0 +1,825 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::TextAlign;
+use crate::core::{
+    AccessCtx, ArcStr, ChildrenIds, EventCtx, HasProperty, LayoutCtx, MeasureCtx, NewWidget,
+    PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PointerUpdate, PropertiesMut,
+    PropertiesRef, RegisterCtx, Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Line, Point, Rect, Size};
+use crate::layout::{LenReq, SizeDef};
+use crate::properties::{BarColor, ContentColor};
+use crate::theme;
+use crate::util::{fill, stroke};
+use crate::widgets::Label;
+
+/// The height of the row of weekday names.
+const HEADER_HEIGHT: f64 = 24.;
+/// The width of the column of week numbers, when shown.
+const WEEK_COLUMN_WIDTH: f64 = 32.;
+/// The smallest width and height of a day cell.
+const MIN_CELL_SIZE: f64 = 36.;
+/// The space between the edges of a day cell and its content.
+const CELL_PADDING: f64 = 2.;
+/// The number of weeks shown, which is enough for any month.
+const WEEKS: usize = 6;
+/// The opacity of the selection between its first and last days.
+const RANGE_ALPHA: f32 = 0.3;
+
+/// Converts a number of days within the shown weeks to the type used for date arithmetic.
+fn days(n: usize) -> i64 {
+    n.try_into().expect("the shown weeks have few days")
+}
+
+/// A day of the week.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    /// Monday.
+    Monday,
+    /// Tuesday.
+    Tuesday,
+    /// Wednesday.
+    Wednesday,
+    /// Thursday.
+    Thursday,
+    /// Friday.
+    Friday,
+    /// Saturday.
+    Saturday,
+    /// Sunday.
+    Sunday,
+}
+
+impl Weekday {
+    const ALL: [Self; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
+    /// Returns the number of days from the previous Monday, i.e. 0 for Monday and 6 for Sunday.
+    pub fn days_from_monday(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the day `days` after this one.
+    pub fn add_days(self, days: usize) -> Self {
+        Self::ALL[(self.days_from_monday() + days) % 7]
+    }
+}
+
+/// A date in the Gregorian calendar.
+///
+/// Dates are ordered chronologically.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl CalendarDate {
+    /// Creates a date from a year, a month from 1 to 12, and a day of that month
+    /// starting from 1.
+    ///
+    /// Returns `None` if there is no such date.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > Self::days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// Returns the year.
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting from 1.
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Returns the number of days of `month` in `year`.
+    ///
+    /// `month` must be from 1 to 12.
+    pub fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Returns the day of the week.
+    pub fn weekday(self) -> Weekday {
+        // The first of January 1970 was a Thursday.
+        Weekday::ALL[(self.to_days() + 3).rem_euclid(7) as usize]
+    }
+
+    /// Returns the date `days` after this one, or before it if `days` is negative.
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Returns the ISO 8601 week number, from 1 to 53.
+    ///
+    /// Weeks start on Monday, and the first week of a year is the one with its first Thursday.
+    pub fn iso_week(self) -> u8 {
+        let thursday = self.add_days(3 - days(self.weekday().days_from_monday()));
+        let new_year = Self {
+            year: thursday.year,
+            month: 1,
+            day: 1,
+        };
+        ((thursday.to_days() - new_year.to_days()) / 7 + 1) as u8
+    }
+
+    /// Returns the number of days since the first of January 1970.
+    fn to_days(self) -> i64 {
+        // The algorithm of Howard Hinnant's `days_from_civil`, with years starting in March
+        // so that leap days are at the end of the year.
+        let month = i64::from(self.month);
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Returns the date `days` after the first of January 1970.
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month = if month_from_march < 10 {
+            month_from_march + 3
+        } else {
+            month_from_march - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Self {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+/// A day shown by a [`Calendar`], given to its [`CellBuilder`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CalendarDay {
+    /// The date of the day.
+    pub date: CalendarDate,
+    /// Whether the day is part of the shown month, rather than one of the days of the
+    /// previous or next months which fill the first and last weeks.
+    pub in_month: bool,
+}
+
+/// A function creating the content of a day cell of a [`Calendar`], such as chips for the
+/// events of that day.
+pub type CellBuilder = Box<dyn Fn(CalendarDay) -> NewWidget<dyn Widget>>;
+
+/// The action type emitted by [`Calendar`] when the user selects days.
+///
+/// Both dates are included in the range, and `start` is never after `end`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DateRangeSelected {
+    /// The first selected day.
+    pub start: CalendarDate,
+    /// The last selected day.
+    pub end: CalendarDate,
+}
+
+/// A month grid, showing the days of a month by week.
+///
+/// The weeks start on the [first weekday](Self::with_first_weekday), which depends on
+/// the locale, and the names of the weekdays can be [localized](Self::with_weekday_names)
+/// as well.
+/// Each day is shown in a cell, which by default contains the day number.
+/// A [`CellBuilder`] can create other content, such as the events of each day.
+/// The first and last weeks are filled with days of the previous and next months.
+///
+/// The user can select a range of days by dragging over them, or a single day by
+/// clicking it, after which [`DateRangeSelected`] is emitted.
+/// The selection is filled with the [`BarColor`] property, and the lines between
+/// the weeks are painted with the [`ContentColor`] property.
+pub struct Calendar {
+    year: i32,
+    month: u8,
+    first_weekday: Weekday,
+    /// The names of the weekdays, starting from Monday.
+    weekday_names: [ArcStr; 7],
+    cell_builder: Option<CellBuilder>,
+    /// The weekday names, in the order they are shown.
+    weekday_labels: Vec<WidgetPod<Label>>,
+    /// The week numbers, which are empty when they are not shown.
+    week_labels: Vec<WidgetPod<Label>>,
+    cells: Vec<WidgetPod<dyn Widget>>,
+    selection: Option<(CalendarDate, CalendarDate)>,
+    /// The day a drag started on.
+    drag_anchor: Option<CalendarDate>,
+}
+
+// --- MARK: BUILDERS
+impl Calendar {
+    /// Creates a calendar showing `month`, from 1 to 12, of `year`.
+    ///
+    /// Weeks start on Monday.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month` isn't from 1 to 12.
+    pub fn new(year: i32, month: u8) -> Self {
+        assert!(
+            (1..=12).contains(&month),
+            "Created a Calendar with invalid month {month}"
+        );
+        let weekday_names = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"].map(ArcStr::from);
+        let mut calendar = Self {
+            year,
+            month,
+            first_weekday: Weekday::Monday,
+            weekday_names,
+            cell_builder: None,
+            weekday_labels: Vec::new(),
+            week_labels: Vec::new(),
+            cells: Vec::new(),
+            selection: None,
+            drag_anchor: None,
+        };
+        calendar.weekday_labels = (0..7)
+            .map(|column| Self::muted_label(calendar.weekday_name(column)))
+            .collect();
+        calendar.cells = calendar.days().map(|day| calendar.new_cell(day)).collect();
+        calendar
+    }
+
+    /// Returns `self` with weeks starting on `first_weekday`.
+    pub fn with_first_weekday(mut self, first_weekday: Weekday) -> Self {
+        self.first_weekday = first_weekday;
+        self.weekday_labels = (0..7)
+            .map(|column| Self::muted_label(self.weekday_name(column)))
+            .collect();
+        self.rebuild_week_labels();
+        self.cells = self.days().map(|day| self.new_cell(day)).collect();
+        self
+    }
+
+    /// Returns `self` with the given names of the weekdays, starting from Monday.
+    pub fn with_weekday_names(mut self, names: [impl Into<ArcStr>; 7]) -> Self {
+        self.weekday_names = names.map(Into::into);
+        self.weekday_labels = (0..7)
+            .map(|column| Self::muted_label(self.weekday_name(column)))
+            .collect();
+        self
+    }
+
+    /// Returns `self` with a column of ISO 8601 week numbers before the days, if `show` is `true`.
+    pub fn with_week_numbers(mut self, show: bool) -> Self {
+        self.week_labels = if show {
+            self.week_number_texts().map(Self::muted_label).collect()
+        } else {
+            Vec::new()
+        };
+        self
+    }
+
+    /// Returns `self` with the content of each day cell created by `cell_builder`.
+    pub fn with_cell_builder(
+        mut self,
+        cell_builder: impl Fn(CalendarDay) -> NewWidget<dyn Widget> + 'static,
+    ) -> Self {
+        self.cell_builder = Some(Box::new(cell_builder));
+        self.cells = self.days().map(|day| self.new_cell(day)).collect();
+        self
+    }
+
+    /// Returns `self` with the days from `start` to `end`, both included, selected.
+    pub fn with_selection(mut self, start: CalendarDate, end: CalendarDate) -> Self {
+        self.selection = Some((start.min(end), start.max(end)));
+        self
+    }
+
+    /// Returns the shown `(year, month)`.
+    pub fn month(&self) -> (i32, u8) {
+        (self.year, self.month)
+    }
+
+    /// Returns the first and last selected days, if any.
+    pub fn selection(&self) -> Option<(CalendarDate, CalendarDate)> {
+        self.selection
+    }
+
+    /// Returns the first day of the first week shown.
+    pub fn first_shown_day(&self) -> CalendarDate {
+        let first = CalendarDate {
+            year: self.year,
+            month: self.month,
+            day: 1,
+        };
+        let offset =
+            (first.weekday().days_from_monday() + 7 - self.first_weekday.days_from_monday()) % 7;
+        first.add_days(-days(offset))
+    }
+
+    fn muted_label(text: impl Into<ArcStr>) -> WidgetPod<Label> {
+        let label = Label::new(text).with_text_alignment(TextAlign::Center);
+        NewWidget::new_with_props(label, ContentColor::new(theme::ZYNC_500)).to_pod()
+    }
+
+    /// Returns the name of the weekday in `column`.
+    fn weekday_name(&self, column: usize) -> ArcStr {
+        let weekday = self.first_weekday.add_days(column);
+        self.weekday_names[weekday.days_from_monday()].clone()
+    }
+
+    /// Returns the shown days, in order.
+    fn days(&self) -> impl Iterator<Item = CalendarDay> + use<> {
+        let first = self.first_shown_day();
+        let month = self.month;
+        (0..WEEKS * 7).map(move |idx| {
+            let date = first.add_days(days(idx));
+            CalendarDay {
+                date,
+                in_month: date.month == month,
+            }
+        })
+    }
+
+    fn new_cell(&self, day: CalendarDay) -> WidgetPod<dyn Widget> {
+        if let Some(cell_builder) = &self.cell_builder {
+            return cell_builder(day).to_pod();
+        }
+        let label = Label::new(day.date.day.to_string()).with_text_alignment(TextAlign::Center);
+        if day.in_month {
+            NewWidget::new(label).erased().to_pod()
+        } else {
+            NewWidget::new_with_props(label, ContentColor::new(theme::ZYNC_500))
+                .erased()
+                .to_pod()
+        }
+    }
+
+    fn week_number_texts(&self) -> impl Iterator<Item = String> + use<> {
+        let first = self.first_shown_day();
+        (0..WEEKS).map(move |week| {
+            // A shown week can span two ISO weeks, which is decided by its Thursday.
+            let start = first.add_days(days(week * 7));
+            let thursday = (0..7)
+                .map(|idx| start.add_days(idx))
+                .find(|date| date.weekday() == Weekday::Thursday)
+                .unwrap_or(start);
+            thursday.iso_week().to_string()
+        })
+    }
+
+    /// Recreates the week numbers, if they are shown.
+    fn rebuild_week_labels(&mut self) {
+        if !self.week_labels.is_empty() {
+            self.week_labels = self.week_number_texts().map(Self::muted_label).collect();
+        }
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Calendar {
+    /// Shows `month`, from 1 to 12, of `year`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month` isn't from 1 to 12.
+    pub fn set_month(this: &mut WidgetMut<'_, Self>, year: i32, month: u8) {
+        assert!(
+            (1..=12).contains(&month),
+            "Called set_month with invalid month {month}"
+        );
+        this.widget.year = year;
+        this.widget.month = month;
+        Self::rebuild_days(this);
+    }
+
+    /// Starts the weeks on `first_weekday`.
+    pub fn set_first_weekday(this: &mut WidgetMut<'_, Self>, first_weekday: Weekday) {
+        this.widget.first_weekday = first_weekday;
+        Self::update_weekday_labels(this);
+        Self::rebuild_days(this);
+    }
+
+    /// Sets the names of the weekdays, starting from Monday.
+    pub fn set_weekday_names(this: &mut WidgetMut<'_, Self>, names: [impl Into<ArcStr>; 7]) {
+        this.widget.weekday_names = names.map(Into::into);
+        Self::update_weekday_labels(this);
+    }
+
+    /// Shows or hides the column of week numbers.
+    pub fn set_week_numbers(this: &mut WidgetMut<'_, Self>, show: bool) {
+        if show != this.widget.week_labels.is_empty() {
+            return;
+        }
+        if show {
+            let texts = this.widget.week_number_texts();
+            this.widget.week_labels = texts.map(Self::muted_label).collect();
+        } else {
+            for label in std::mem::take(&mut this.widget.week_labels) {
+                this.ctx.remove_child(label);
+            }
+        }
+        this.ctx.children_changed();
+    }
+
+    /// Creates the content of each day cell with `cell_builder`, or shows the day numbers
+    /// if it's `None`.
+    pub fn set_cell_builder(this: &mut WidgetMut<'_, Self>, cell_builder: Option<CellBuilder>) {
+        this.widget.cell_builder = cell_builder;
+        Self::rebuild_days(this);
+    }
+
+    /// Recreates the content of each day cell, such as after the events of the month changed.
+    pub fn rebuild_days(this: &mut WidgetMut<'_, Self>) {
+        let cells: Vec<_> = this
+            .widget
+            .days()
+            .map(|day| this.widget.new_cell(day))
+            .collect();
+        for cell in std::mem::replace(&mut this.widget.cells, cells) {
+            this.ctx.remove_child(cell);
+        }
+        let texts = this.widget.week_number_texts();
+        for (label, text) in this.widget.week_labels.iter_mut().zip(texts) {
+            Label::set_text(&mut this.ctx.get_mut(label), text);
+        }
+        this.ctx.children_changed();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Selects the days from `start` to `end`, both included, or clears the selection.
+    pub fn set_selection(
+        this: &mut WidgetMut<'_, Self>,
+        selection: Option<(CalendarDate, CalendarDate)>,
+    ) {
+        this.widget.selection = selection.map(|(start, end)| (start.min(end), start.max(end)));
+        this.ctx.request_paint_only();
+    }
+
+    /// Returns a mutable reference to the content of the cell of `date`, if it's shown.
+    pub fn cell_mut<'t>(
+        this: &'t mut WidgetMut<'_, Self>,
+        date: CalendarDate,
+    ) -> Option<WidgetMut<'t, dyn Widget>> {
+        let idx = date.to_days() - this.widget.first_shown_day().to_days();
+        let idx = usize::try_from(idx).ok()?;
+        let cell = this.widget.cells.get_mut(idx)?;
+        Some(this.ctx.get_mut(cell))
+    }
+
+    fn update_weekday_labels(this: &mut WidgetMut<'_, Self>) {
+        for column in 0..7 {
+            let name = this.widget.weekday_name(column);
+            Label::set_text(
+                &mut this.ctx.get_mut(&mut this.widget.weekday_labels[column]),
+                name,
+            );
+        }
+    }
+}
+
+// --- MARK: HELPERS
+impl Calendar {
+    fn week_column_width(&self) -> f64 {
+        if self.week_labels.is_empty() {
+            0.
+        } else {
+            WEEK_COLUMN_WIDTH
+        }
+    }
+
+    /// Returns the size of each day cell.
+    fn cell_size(&self, size: Size) -> Size {
+        Size::new(
+            ((size.width - self.week_column_width()) / 7.).max(0.),
+            ((size.height - HEADER_HEIGHT) / WEEKS as f64).max(0.),
+        )
+    }
+
+    /// Returns the rectangle of the cell at `idx`.
+    fn cell_rect(&self, idx: usize, size: Size) -> Rect {
+        let cell_size = self.cell_size(size);
+        let origin = Point::new(
+            self.week_column_width() + (idx % 7) as f64 * cell_size.width,
+            HEADER_HEIGHT + (idx / 7) as f64 * cell_size.height,
+        );
+        Rect::from_origin_size(origin, cell_size)
+    }
+
+    /// Returns the day at `pos`, clamped to the shown days.
+    fn date_at(&self, pos: Point, size: Size) -> CalendarDate {
+        let cell_size = self.cell_size(size);
+        let column = ((pos.x - self.week_column_width()) / cell_size.width).clamp(0., 6.) as usize;
+        let row = ((pos.y - HEADER_HEIGHT) / cell_size.height).clamp(0., (WEEKS - 1) as f64);
+        let idx = row as usize * 7 + column;
+        self.first_shown_day().add_days(days(idx))
+    }
+
+    fn is_in_grid(&self, pos: Point, size: Size) -> bool {
+        pos.x >= self.week_column_width()
+            && pos.x < size.width
+            && pos.y >= HEADER_HEIGHT
+            && pos.y < size.height
+    }
+
+    fn set_dragged_range(&mut self, ctx: &mut EventCtx<'_>, date: CalendarDate) {
+        if let Some(anchor) = self.drag_anchor {
+            let selection = Some((anchor.min(date), anchor.max(date)));
+            if selection != self.selection {
+                self.selection = selection;
+                ctx.request_paint_only();
+            }
+        }
+    }
+}
+
+impl HasProperty<BarColor> for Calendar {}
+impl HasProperty<ContentColor> for Calendar {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Calendar {
+    type Action = DateRangeSelected;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        let size = ctx.content_box_size();
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                let local_pos = ctx.local_position(state.position);
+                if self.is_in_grid(local_pos, size) {
+                    let date = self.date_at(local_pos, size);
+                    self.drag_anchor = Some(date);
+                    self.set_dragged_range(ctx, date);
+                    ctx.capture_pointer();
+                }
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) if ctx.is_active() => {
+                let date = self.date_at(ctx.local_position(current.position), size);
+                self.set_dragged_range(ctx, date);
+            }
+            PointerEvent::Up(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                state,
+                ..
+            }) if ctx.is_active() => {
+                let date = self.date_at(ctx.local_position(state.position), size);
+                self.set_dragged_range(ctx, date);
+                if self.drag_anchor.take().is_some()
+                    && let Some((start, end)) = self.selection
+                {
+                    ctx.submit_action::<DateRangeSelected>(DateRangeSelected { start, end });
+                }
+            }
+            PointerEvent::Cancel(..) => {
+                self.drag_anchor = None;
+            }
+            _ => (),
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::DisabledChanged(_) = event {
+            ctx.request_paint_only();
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for label in &mut self.weekday_labels {
+            ctx.register_child(label);
+        }
+        for label in &mut self.week_labels {
+            ctx.register_child(label);
+        }
+        for cell in &mut self.cells {
+            ctx.register_child(cell);
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        BarColor::prop_changed(ctx, property_type);
+        ContentColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        let min_length = match axis {
+            Axis::Horizontal => self.week_column_width() + 7. * MIN_CELL_SIZE,
+            Axis::Vertical => HEADER_HEIGHT + WEEKS as f64 * MIN_CELL_SIZE,
+        };
+        // The grid grows to fill the available space, so that cells have room for events.
+        match len_req {
+            LenReq::FitContent(space) => space.max(min_length),
+            LenReq::MinContent | LenReq::MaxContent => min_length,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let cell_size = self.cell_size(size);
+        let week_column_width = self.week_column_width();
+
+        let header_slot = Size::new(cell_size.width, HEADER_HEIGHT);
+        for (column, label) in self.weekday_labels.iter_mut().enumerate() {
+            let label_size = ctx.compute_size(label, SizeDef::fit(header_slot), header_slot.into());
+            ctx.run_layout(label, label_size);
+            let origin = Point::new(
+                week_column_width + (column as f64 + 0.5) * cell_size.width
+                    - label_size.width * 0.5,
+                (HEADER_HEIGHT - label_size.height) * 0.5,
+            );
+            ctx.place_child(label, origin);
+        }
+
+        let week_slot = Size::new(week_column_width, cell_size.height);
+        for (week, label) in self.week_labels.iter_mut().enumerate() {
+            let label_size = ctx.compute_size(label, SizeDef::fit(week_slot), week_slot.into());
+            ctx.run_layout(label, label_size);
+            let origin = Point::new(
+                (week_column_width - label_size.width) * 0.5,
+                HEADER_HEIGHT + week as f64 * cell_size.height + CELL_PADDING,
+            );
+            ctx.place_child(label, origin);
+        }
+
+        let content_size = Size::new(
+            (cell_size.width - 2. * CELL_PADDING).max(0.),
+            (cell_size.height - 2. * CELL_PADDING).max(0.),
+        );
+        for idx in 0..self.cells.len() {
+            let origin = self.cell_rect(idx, size).origin() + (CELL_PADDING, CELL_PADDING);
+            let cell = &mut self.cells[idx];
+            ctx.run_layout(cell, content_size);
+            ctx.place_child(cell, origin);
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let size = ctx.content_box_size();
+        let bar_color = props.get::<BarColor>().0;
+        let content_color = props.get::<ContentColor>().color;
+
+        if let Some((start, end)) = self.selection {
+            for (idx, day) in self.days().enumerate() {
+                if day.date < start || day.date > end {
+                    continue;
+                }
+                let rect = self.cell_rect(idx, size).inset(-1.);
+                let color = if day.date == start || day.date == end {
+                    bar_color
+                } else {
+                    bar_color.multiply_alpha(RANGE_ALPHA)
+                };
+                fill(scene, &rect, color);
+            }
+        }
+
+        for week in 0..=WEEKS {
+            let y = HEADER_HEIGHT + week as f64 * self.cell_size(size).height;
+            let line = Line::new((0., y), (size.width, y));
+            stroke(scene, &line, content_color, 1.);
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Group
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_label(format!("{}-{:02}", self.year, self.month));
+        if let Some((start, end)) = self.selection {
+            node.set_value(format!(
+                "{}-{:02}-{:02} to {}-{:02}-{:02}",
+                start.year, start.month, start.day, end.year, end.month, end.day
+            ));
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.weekday_labels
+            .iter()
+            .chain(&self.week_labels)
+            .map(|label| label.id())
+            .chain(self.cells.iter().map(|cell| cell.id()))
+            .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Calendar", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+
+    fn date(year: i32, month: u8, day: u8) -> CalendarDate {
+        CalendarDate::new(year, month, day).unwrap()
+    }
+
+    /// Returns the center of the cell at `row` and `column` of a 280 by 264 calendar.
+    fn cell_center(row: usize, column: usize) -> Point {
+        Point::new(
+            column as f64 * 40. + 20.,
+            HEADER_HEIGHT + row as f64 * 40. + 20.,
+        )
+    }
+
+    #[test]
+    fn date_arithmetic() {
+        assert_eq!(date(1970, 1, 1).weekday(), Weekday::Thursday);
+        assert_eq!(date(2026, 3, 1).weekday(), Weekday::Sunday);
+        assert_eq!(date(2024, 2, 28).add_days(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 12, 31).add_days(-365), date(2022, 12, 31));
+        assert_eq!(CalendarDate::new(2025, 2, 29), None);
+        // The first of January 2027 is a Friday, so it's still in the last week of 2026.
+        assert_eq!(date(2027, 1, 1).iso_week(), 53);
+        assert_eq!(date(2026, 1, 1).iso_week(), 1);
+    }
+
+    #[test]
+    fn first_weekday() {
+        let monday_first = Calendar::new(2026, 3);
+        assert_eq!(monday_first.first_shown_day(), date(2026, 2, 23));
+
+        let sunday_first = Calendar::new(2026, 3).with_first_weekday(Weekday::Sunday);
+        assert_eq!(sunday_first.first_shown_day(), date(2026, 3, 1));
+        assert_eq!(sunday_first.weekday_name(0).as_ref(), "Su");
+    }
+
+    #[test]
+    fn drag_selects_range() {
+        let widget = Calendar::new(2026, 3).with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(280., 264.));
+        let id = harness.root_id();
+
+        // Drag backwards, from Wednesday the 4th to Monday the 2nd.
+        harness.mouse_move(cell_center(1, 2));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move(cell_center(1, 0));
+        harness.mouse_button_release(PointerButton::Primary);
+
+        let range = DateRangeSelected {
+            start: date(2026, 3, 2),
+            end: date(2026, 3, 4),
+        };
+        assert_eq!(harness.pop_action::<DateRangeSelected>(), Some((range, id)));
+        assert_eq!(
+            harness.root_widget().selection(),
+            Some((range.start, range.end))
+        );
+    }
+}
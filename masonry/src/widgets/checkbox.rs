@@ -13,7 +13,7 @@ use crate::core::{
     AccessCtx, AccessEvent, ArcStr, ChildrenIds, EventCtx, HasProperty, LayoutCtx, MeasureCtx,
     NewWidget, PaintCtx, PointerEvent, PrePaintProps, PropertiesMut, PropertiesRef, Property,
     RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
-    paint_background, paint_box_shadow,
+    paint_background, paint_background_image, paint_box_shadow, paint_elevation,
 };
 use crate::kurbo::{Affine, Axis, BezPath, Cap, Dashes, Join, Point, Size, Stroke};
 use crate::layout::{LayoutSize, LenReq, SizeDef};
@@ -112,11 +112,9 @@ impl Widget for Checkbox {
                 ctx.capture_pointer();
                 trace!("Checkbox {:?} pressed", ctx.widget_id());
             }
-            PointerEvent::Up { .. } => {
-                if ctx.is_active() && ctx.is_hovered() {
-                    ctx.submit_action::<Self::Action>(CheckboxToggled(!self.checked));
-                    trace!("Checkbox {:?} released", ctx.widget_id());
-                }
+            PointerEvent::Up { .. } if ctx.is_active() && ctx.is_hovered() => {
+                ctx.submit_action::<Self::Action>(CheckboxToggled(!self.checked));
+                trace!("Checkbox {:?} released", ctx.widget_id());
             }
             _ => (),
         }
@@ -259,8 +257,22 @@ impl Widget for Checkbox {
         let bbox = ctx.border_box();
         let p = PrePaintProps::fetch(ctx, props);
 
+        paint_elevation(
+            scene,
+            bbox,
+            p.elevation,
+            p.elevation_shadows,
+            p.corner_radius,
+        );
         paint_box_shadow(scene, bbox, p.box_shadow, p.corner_radius);
         paint_background(scene, bbox, p.background, p.border_width, p.corner_radius);
+        paint_background_image(
+            scene,
+            bbox,
+            p.background_image,
+            p.border_width,
+            p.corner_radius,
+        );
 
         // Paint focus indicator around the entire widget (box + label)
         if ctx.is_focus_target() || ctx.is_hovered() {
@@ -0,0 +1,214 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::mem;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ChildrenIds, ClipShape, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PropertiesRef, RegisterCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::LenReq;
+
+/// A container that clips its child to an arbitrary [`ClipShape`].
+///
+/// Both the painting of the child and the pointer hit-testing are clipped to the shape,
+/// so e.g. the corners outside of a circular avatar won't register as hovering it.
+///
+/// The shape is in this widget's content-box coordinate space.
+///
+/// It reports the child's length as its own in [`measure`], syncing its size with the child's.
+/// Ensure that `Clip` has [`Dimensions`] set via props to [`Dimensions::MAX`].
+///
+/// [`measure`]: Widget::measure
+/// [`Dimensions`]: crate::properties::Dimensions
+/// [`Dimensions::MAX`]: crate::properties::Dimensions::MAX
+pub struct Clip {
+    child: WidgetPod<dyn Widget>,
+    shape: ClipShape,
+}
+
+// --- MARK: BUILDERS
+impl Clip {
+    /// Creates a new `Clip` which clips `child` to `shape`.
+    pub fn new(child: NewWidget<impl Widget + ?Sized>, shape: impl Into<ClipShape>) -> Self {
+        Self {
+            child: child.erased().to_pod(),
+            shape: shape.into(),
+        }
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Clip {
+    /// Replaces the child widget with a new one.
+    pub fn set_child(this: &mut WidgetMut<'_, Self>, child: NewWidget<impl Widget + ?Sized>) {
+        let old_child = mem::replace(&mut this.widget.child, child.erased().to_pod());
+        this.ctx.remove_child(old_child);
+    }
+
+    /// Sets the shape the child is clipped to.
+    pub fn set_shape(this: &mut WidgetMut<'_, Self>, shape: impl Into<ClipShape>) {
+        this.widget.shape = shape.into();
+        // The clip path is set in `layout`.
+        this.ctx.request_layout();
+    }
+
+    /// Returns mutable reference to the child widget.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Clip {
+    type Action = NoAction;
+
+    fn accepts_pointer_interaction(&self) -> bool {
+        false
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        ctx.redirect_measurement(&mut self.child, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.child, size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+
+        let child_baseline = ctx.child_baseline_offset(&self.child);
+        ctx.set_baseline_offset(child_baseline);
+
+        ctx.set_clip_path(self.shape.clone());
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Clip", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{WidgetOptions, WidgetTag};
+    use crate::kurbo::{BezPath, Circle, RoundedRect};
+    use crate::layout::AsUnit;
+    use crate::palette;
+    use crate::properties::{Background, Dimensions, Gap, Padding};
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::{Button, Flex, SizedBox};
+
+    fn square() -> NewWidget<SizedBox> {
+        SizedBox::empty()
+            .width(40.px())
+            .height(40.px())
+            .with_props(Background::Color(palette::css::ORANGE))
+    }
+
+    #[test]
+    fn clip_shapes() {
+        let mut triangle = BezPath::new();
+        triangle.move_to((20., 0.));
+        triangle.line_to((40., 40.));
+        triangle.line_to((0., 40.));
+        triangle.close_path();
+
+        let widget = Flex::row()
+            .with_fixed(
+                Clip::new(square(), RoundedRect::new(0., 0., 40., 40., 10.))
+                    .with_props(Dimensions::MAX),
+            )
+            .with_fixed(
+                Clip::new(square(), Circle::new((20., 20.), 20.)).with_props(Dimensions::MAX),
+            )
+            .with_fixed(Clip::new(square(), triangle).with_props(Dimensions::MAX))
+            .with_props((Padding::all(10.), Gap::new(10.px())));
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(160., 60.));
+        assert_render_snapshot!(harness, "clip_shapes");
+    }
+
+    #[test]
+    fn clip_hit_test() {
+        let button_tag = WidgetTag::named("button");
+        let button = NewWidget::new_with(
+            Button::with_text(""),
+            Some(button_tag),
+            WidgetOptions::default(),
+            Dimensions::fixed(40.px(), 40.px()),
+        );
+        let widget = Clip::new(button, Circle::new((20., 20.), 20.)).with_props(Dimensions::MAX);
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(40., 40.));
+
+        // The center of the circle hovers the button.
+        harness.mouse_move((20., 20.));
+        assert!(harness.get_widget(button_tag).ctx().is_hovered());
+
+        // The corner of the square is outside the circle.
+        harness.mouse_move((2., 2.));
+        assert!(!harness.get_widget(button_tag).ctx().is_hovered());
+    }
+
+    #[test]
+    fn edit_clip_shape() {
+        let image_1 = {
+            let widget =
+                Clip::new(square(), Circle::new((20., 20.), 20.)).with_props(Dimensions::MAX);
+            let mut harness =
+                TestHarness::create_with_size(test_property_set(), widget, Size::new(40., 40.));
+            harness.render()
+        };
+
+        let image_2 = {
+            let widget = Clip::new(square(), RoundedRect::new(0., 0., 40., 40., 5.))
+                .with_props(Dimensions::MAX);
+            let mut harness =
+                TestHarness::create_with_size(test_property_set(), widget, Size::new(40., 40.));
+            harness.edit_root_widget(|mut clip| {
+                Clip::set_shape(&mut clip, Circle::new((20., 20.), 20.));
+            });
+            harness.render()
+        };
+
+        // We don't use assert_eq because we don't want rich assert
+        assert!(image_1 == image_2);
+    }
+}
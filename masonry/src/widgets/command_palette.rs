@@ -0,0 +1,848 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+use std::cmp::Reverse;
+use std::ops::Range;
+
+use accesskit::{Node, Role};
+use parley::FontWeight;
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::keyboard::{Key, KeyState, NamedKey};
+use crate::core::{
+    AccessCtx, ArcStr, ChildrenIds, EventCtx, HasProperty, LayoutCtx, MeasureCtx, NewWidget,
+    PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PointerUpdate, PropertiesMut,
+    PropertiesRef, QueryCtx, RegisterCtx, StyleProperty, TextEvent, Update, UpdateCtx, Widget,
+    WidgetId, WidgetMut, WidgetPod, WidgetRef, find_widget_under_pointer,
+};
+use crate::kurbo::{Axis, Point, Rect, Size};
+use crate::layout::{LayoutSize, LenReq};
+use crate::peniko::Color;
+use crate::properties::BarColor;
+use crate::theme;
+use crate::util::{fill, stroke};
+use crate::widgets::{InsertNewline, RichText, TextArea};
+
+/// The widest the panel gets.
+const PANEL_MAX_WIDTH: f64 = 520.;
+/// The smallest space between the panel and the edges of the palette.
+const PANEL_MARGIN: f64 = 24.;
+/// The distance from the top of the palette to the panel, as a fraction of the palette's height.
+const PANEL_TOP: f64 = 0.15;
+/// The space between the edges of the panel and its content.
+const PANEL_PADDING: f64 = 8.;
+/// The space between the edges of the search field and its text.
+const SEARCH_PADDING: f64 = 6.;
+/// The corner radius of the panel.
+const PANEL_RADIUS: f64 = 8.;
+/// The space around the title of each command.
+const ROW_PADDING: f64 = 6.;
+/// The corner radius of the selected command.
+const ROW_RADIUS: f64 = 4.;
+/// The most commands listed at once.
+const MAX_ROWS: usize = 8;
+/// The color dimming the content under the palette.
+const SCRIM_COLOR: Color = Color::from_rgba8(0, 0, 0, 0x80);
+
+/// The score of each matched character.
+const MATCH_SCORE: i64 = 1;
+/// The bonus for a character matched right after the previous one.
+const CONSECUTIVE_BONUS: i64 = 4;
+/// The bonus for a character matched at the start of a word.
+const WORD_START_BONUS: i64 = 6;
+/// The largest penalty for the characters skipped before a match.
+const MAX_GAP_PENALTY: i64 = 3;
+
+/// A search field over a list of commands, such as the command palette of a code editor.
+///
+/// The palette covers all the space it is given, dimming the content under it, with a panel
+/// in the upper part holding the search field and the commands.
+/// It is meant to be stacked over the rest of the app, such as in a [`ZStack`](super::ZStack),
+/// and is only shown once [opened](Self::set_open).
+/// While closed, it doesn't paint anything and lets the pointer through.
+///
+/// The commands are fuzzy matched against the text of the search field: each character
+/// typed must appear in the title of a command, in order, but not necessarily next to each
+/// other.
+/// The best matches are listed first, favouring characters matched at the start of words
+/// or next to each other, and the matched characters are shown in bold.
+/// Commands chosen recently are listed before the others when the search field is empty,
+/// and before equally good matches otherwise.
+///
+/// The user moves through the commands with `ArrowUp` <kbd>↑</kbd> and `ArrowDown` <kbd>↓</kbd>,
+/// or by hovering them.
+/// Pressing Enter <kbd>↵</kbd> or clicking a command sends
+/// [`CommandPaletteAction::Chosen`], and pressing Escape or clicking outside the panel sends
+/// [`CommandPaletteAction::Dismissed`].
+/// Note that the palette does not close itself, and so one of the responses to both actions
+/// is to call [`CommandPalette::set_open`] on the originating widget.
+///
+/// You can customize the highlight of the selected command with the [`BarColor`] property.
+/// The inner text area sends the [`TextAction`](crate::widgets::TextAction)s of the search
+/// field, and its ID can be accessed using [`area_id`](Self::area_id).
+pub struct CommandPalette {
+    search: WidgetPod<TextArea<true>>,
+    rows: Vec<WidgetPod<RichText>>,
+    commands: Vec<ArcStr>,
+    /// The titles of the commands chosen recently, most recent first.
+    recent: Vec<ArcStr>,
+    open: bool,
+    /// The text of the search field when the matches were last updated.
+    query: String,
+    /// The commands matching the query, best match first.
+    matches: Vec<Match>,
+    /// The index in `matches` of the selected command.
+    selected: usize,
+    /// The index in `matches` of the command shown in the first row.
+    first_row: usize,
+    /// The row the pointer was pressed on.
+    pressed: Option<usize>,
+    /// The rectangle of the panel, set during layout.
+    panel: Rect,
+    /// The rectangle of the search field, set during layout.
+    search_rect: Rect,
+    /// The rectangles of the rows, set during layout.
+    row_rects: Vec<Rect>,
+}
+
+/// A command matching the query of a [`CommandPalette`].
+struct Match {
+    /// The index of the command.
+    index: usize,
+    /// The byte ranges of the matched characters in the title of the command.
+    ranges: Vec<Range<usize>>,
+}
+
+/// The action type emitted by [`CommandPalette`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CommandPaletteAction {
+    /// The user chose the command at this index, by pressing Enter <kbd>↵</kbd> or clicking it.
+    Chosen(usize),
+    /// The user pressed Escape or clicked outside the panel.
+    Dismissed,
+}
+
+// --- MARK: BUILDERS
+impl CommandPalette {
+    /// Creates a closed palette listing commands with the given titles.
+    pub fn new(commands: impl IntoIterator<Item = impl Into<ArcStr>>) -> Self {
+        let search = TextArea::new_editable("")
+            .with_word_wrap(false)
+            .with_insert_newline(InsertNewline::Never)
+            .with_forward_list_keys(true);
+        Self {
+            search: NewWidget::new(search).to_pod(),
+            rows: Vec::new(),
+            commands: commands.into_iter().map(Into::into).collect(),
+            recent: Vec::new(),
+            open: false,
+            query: String::new(),
+            matches: Vec::new(),
+            selected: 0,
+            first_row: 0,
+            pressed: None,
+            panel: Rect::ZERO,
+            search_rect: Rect::ZERO,
+            row_rects: Vec::new(),
+        }
+    }
+
+    /// Returns `self` opened or closed.
+    ///
+    /// To open or close an active palette, use [`set_open`](Self::set_open).
+    pub fn with_open(mut self, open: bool) -> Self {
+        self.open = open;
+        self.with_new_matches()
+    }
+
+    /// Returns `self` with the given commands marked as recently chosen, most recent first.
+    ///
+    /// This lets apps keep the recently used commands between runs.
+    pub fn with_recent(mut self, titles: impl IntoIterator<Item = impl Into<ArcStr>>) -> Self {
+        self.recent = titles.into_iter().map(Into::into).collect();
+        self.with_new_matches()
+    }
+
+    /// Returns `self` with the matches of the empty search field, before it is added.
+    fn with_new_matches(mut self) -> Self {
+        self.matches = if self.open {
+            rank(&self.commands, &self.recent, "")
+        } else {
+            Vec::new()
+        };
+        self.rows = self
+            .visible_matches()
+            .map(|(title, ranges)| NewWidget::new(new_row(title, ranges)).to_pod())
+            .collect();
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl CommandPalette {
+    /// Returns whether the palette is shown.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Returns the titles of the commands.
+    pub fn commands(&self) -> &[ArcStr] {
+        &self.commands
+    }
+
+    /// Returns the titles of the commands chosen recently, most recent first.
+    pub fn recent(&self) -> &[ArcStr] {
+        &self.recent
+    }
+
+    /// Returns the indices of the commands matching the search field, best match first.
+    pub fn matches(&self) -> impl Iterator<Item = usize> + '_ {
+        self.matches.iter().map(|m| m.index)
+    }
+
+    /// Returns the index of the selected command, if any command matches.
+    pub fn selected(&self) -> Option<usize> {
+        self.matches.get(self.selected).map(|m| m.index)
+    }
+
+    /// Returns the ID of the text area of the search field, which sends its text actions.
+    pub fn area_id(&self) -> WidgetId {
+        self.search.id()
+    }
+
+    /// Returns the titles and matched ranges of the commands shown in the rows.
+    fn visible_matches(&self) -> impl Iterator<Item = (&ArcStr, &[Range<usize>])> {
+        self.matches
+            .iter()
+            .skip(self.first_row)
+            .take(MAX_ROWS)
+            .map(|m| (&self.commands[m.index], m.ranges.as_slice()))
+    }
+
+    /// Returns the index of the row at `pos`.
+    fn row_at(&self, pos: Point) -> Option<usize> {
+        self.row_rects.iter().position(|rect| rect.contains(pos))
+    }
+
+    /// Selects the match at `index`, scrolling the rows to show it.
+    fn select(&mut self, ctx: &mut EventCtx<'_>, index: usize) {
+        self.selected = index;
+        let first_row = if index < self.first_row {
+            index
+        } else if index >= self.first_row + MAX_ROWS {
+            index + 1 - MAX_ROWS
+        } else {
+            self.first_row
+        };
+        if first_row != self.first_row {
+            self.first_row = first_row;
+            ctx.mutate_self_later(|mut palette| {
+                Self::update_rows(&mut palette.downcast());
+            });
+        }
+        ctx.request_render();
+    }
+
+    /// Sends the selected command as chosen, and marks it as the most recent one.
+    fn choose_selected(&mut self, ctx: &mut EventCtx<'_>) {
+        let Some(index) = self.selected() else {
+            return;
+        };
+        let title = self.commands[index].clone();
+        self.recent.retain(|recent| *recent != title);
+        self.recent.insert(0, title);
+        ctx.submit_action::<CommandPaletteAction>(CommandPaletteAction::Chosen(index));
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl CommandPalette {
+    /// Edits the text area of the search field.
+    ///
+    /// Used to modify most properties of the text.
+    pub fn text_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, TextArea<true>> {
+        this.ctx.get_mut(&mut this.widget.search)
+    }
+
+    /// Opens or closes the palette.
+    ///
+    /// Opening the palette clears the search field and focuses it.
+    pub fn set_open(this: &mut WidgetMut<'_, Self>, open: bool) {
+        if this.widget.open == open {
+            return;
+        }
+        this.widget.open = open;
+        this.ctx.set_stashed(&mut this.widget.search, !open);
+        if open {
+            TextArea::reset_text(&mut Self::text_mut(this), "");
+            let area_id = this.widget.search.id();
+            this.ctx.set_focus(area_id);
+        }
+        Self::update_matches(this);
+        this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Replaces the commands.
+    pub fn set_commands(
+        this: &mut WidgetMut<'_, Self>,
+        commands: impl IntoIterator<Item = impl Into<ArcStr>>,
+    ) {
+        this.widget.commands = commands.into_iter().map(Into::into).collect();
+        Self::update_matches(this);
+    }
+
+    /// Marks the given commands as recently chosen, most recent first.
+    ///
+    /// The runtime equivalent of [`with_recent`](Self::with_recent).
+    pub fn set_recent(
+        this: &mut WidgetMut<'_, Self>,
+        titles: impl IntoIterator<Item = impl Into<ArcStr>>,
+    ) {
+        this.widget.recent = titles.into_iter().map(Into::into).collect();
+        Self::update_matches(this);
+    }
+
+    /// Matches the commands with the search field, and selects the best match.
+    fn update_matches(this: &mut WidgetMut<'_, Self>) {
+        let query = Self::text_mut(this).widget.text().to_string();
+        this.widget.matches = if this.widget.open {
+            rank(&this.widget.commands, &this.widget.recent, &query)
+        } else {
+            Vec::new()
+        };
+        this.widget.query = query;
+        this.widget.selected = 0;
+        this.widget.first_row = 0;
+        Self::update_rows(this);
+    }
+
+    /// Shows the visible matches in the rows.
+    fn update_rows(this: &mut WidgetMut<'_, Self>) {
+        let visible = this
+            .widget
+            .visible_matches()
+            .map(|(title, ranges)| (title.clone(), ranges.to_vec()))
+            .collect::<Vec<_>>();
+        for (idx, (title, ranges)) in visible.iter().enumerate() {
+            if let Some(row) = this.widget.rows.get_mut(idx) {
+                let mut row = this.ctx.get_mut(row);
+                RichText::set_text(&mut row, title.clone());
+                RichText::set_span_styles(&mut row, bold_spans(ranges));
+            } else {
+                let row = NewWidget::new(new_row(title, ranges)).to_pod();
+                this.widget.rows.push(row);
+            }
+        }
+        for row in this.widget.rows.drain(visible.len()..) {
+            this.ctx.remove_child(row);
+        }
+        this.ctx.children_changed();
+        this.ctx.request_render();
+    }
+}
+
+impl HasProperty<BarColor> for CommandPalette {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for CommandPalette {
+    type Action = CommandPaletteAction;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if !self.open {
+            return;
+        }
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                let local_pos = ctx.local_position(state.position);
+                if !self.panel.contains(local_pos) {
+                    ctx.submit_action::<CommandPaletteAction>(CommandPaletteAction::Dismissed);
+                    ctx.set_handled();
+                    return;
+                }
+                self.pressed = self.row_at(local_pos);
+                if self.pressed.is_some() {
+                    ctx.capture_pointer();
+                }
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) if !ctx.is_active() => {
+                let local_pos = ctx.local_position(current.position);
+                if let Some(row) = self.row_at(local_pos)
+                    && self.first_row + row != self.selected
+                {
+                    self.select(ctx, self.first_row + row);
+                }
+            }
+            PointerEvent::Up(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                state,
+                ..
+            }) if ctx.is_active() => {
+                let local_pos = ctx.local_position(state.position);
+                if let Some(row) = self.pressed.take()
+                    && self.row_at(local_pos) == Some(row)
+                {
+                    self.selected = self.first_row + row;
+                    self.choose_selected(ctx);
+                }
+            }
+            PointerEvent::Cancel(..) => {
+                self.pressed = None;
+            }
+            _ => (),
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::Keyboard(event) = event else {
+            return;
+        };
+        if !self.open || event.state != KeyState::Down {
+            return;
+        }
+        let count = self.matches.len();
+        match &event.key {
+            Key::Named(NamedKey::ArrowDown) if count > 0 => {
+                self.select(ctx, (self.selected + 1) % count);
+            }
+            Key::Named(NamedKey::ArrowUp) if count > 0 => {
+                self.select(ctx, (self.selected + count - 1) % count);
+            }
+            Key::Named(NamedKey::Enter) => self.choose_selected(ctx),
+            Key::Named(NamedKey::Escape) => {
+                ctx.submit_action::<CommandPaletteAction>(CommandPaletteAction::Dismissed);
+            }
+            _ => return,
+        }
+        ctx.set_handled();
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.search);
+        for row in &mut self.rows {
+            ctx.register_child(row);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::WidgetAdded = event {
+            ctx.set_stashed(&mut self.search, !self.open);
+            if self.open {
+                ctx.set_focus(self.search.id());
+            }
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        BarColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        match (axis, len_req) {
+            // The palette covers all the space it is given.
+            (_, LenReq::FitContent(space)) => space,
+            _ if !self.open => 0.,
+            (Axis::Horizontal, _) => PANEL_MAX_WIDTH + 2. * PANEL_MARGIN,
+            (Axis::Vertical, _) => {
+                let width = cross_length.unwrap_or(PANEL_MAX_WIDTH + 2. * PANEL_MARGIN);
+                let inner_width = inner_width(width);
+                let search_height = ctx.compute_length(
+                    &mut self.search,
+                    LenReq::MaxContent.into(),
+                    LayoutSize::NONE,
+                    Axis::Vertical,
+                    Some(inner_width - 2. * SEARCH_PADDING),
+                ) + 2. * SEARCH_PADDING;
+                let rows_height = self
+                    .rows
+                    .iter_mut()
+                    .map(|row| {
+                        ctx.compute_length(
+                            row,
+                            LenReq::MaxContent.into(),
+                            LayoutSize::NONE,
+                            Axis::Vertical,
+                            Some(inner_width - 2. * ROW_PADDING),
+                        ) + 2. * ROW_PADDING
+                    })
+                    .sum::<f64>();
+                let list_gap = if self.rows.is_empty() {
+                    0.
+                } else {
+                    PANEL_PADDING
+                };
+                search_height + list_gap + rows_height + 2. * (PANEL_PADDING + PANEL_MARGIN)
+            }
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        if !self.open {
+            self.panel = Rect::ZERO;
+            self.search_rect = Rect::ZERO;
+            self.row_rects.clear();
+            return;
+        }
+
+        // Edits of the search field request a layout, so this is where we notice them.
+        // As in `TokenInput`, we read the text of the text area directly.
+        let (search, _) = ctx.get_raw(&mut self.search);
+        if search.text().to_string() != self.query {
+            ctx.mutate_self_later(|mut palette| {
+                Self::update_matches(&mut palette.downcast());
+            });
+        }
+
+        let inner_width = inner_width(size.width);
+        let text_width = (inner_width - 2. * SEARCH_PADDING).max(0.);
+        let text_height = ctx.compute_length(
+            &mut self.search,
+            LenReq::MaxContent.into(),
+            LayoutSize::NONE,
+            Axis::Vertical,
+            Some(text_width),
+        );
+        let search_height = text_height + 2. * SEARCH_PADDING;
+        let row_heights = self
+            .rows
+            .iter_mut()
+            .map(|row| {
+                ctx.compute_length(
+                    row,
+                    LenReq::MaxContent.into(),
+                    LayoutSize::NONE,
+                    Axis::Vertical,
+                    Some(inner_width - 2. * ROW_PADDING),
+                )
+            })
+            .collect::<Vec<_>>();
+        let list_gap = if self.rows.is_empty() {
+            0.
+        } else {
+            PANEL_PADDING
+        };
+        let panel_height = search_height
+            + list_gap
+            + row_heights
+                .iter()
+                .map(|h| h + 2. * ROW_PADDING)
+                .sum::<f64>()
+            + 2. * PANEL_PADDING;
+        let panel_width = inner_width + 2. * PANEL_PADDING;
+        let panel_y = (size.height * PANEL_TOP).min((size.height - panel_height).max(0.));
+        self.panel = Rect::from_origin_size(
+            ((size.width - panel_width) / 2., panel_y),
+            (panel_width, panel_height),
+        );
+
+        let x = self.panel.x0 + PANEL_PADDING;
+        let mut y = self.panel.y0 + PANEL_PADDING;
+        self.search_rect = Rect::from_origin_size((x, y), (inner_width, search_height));
+        ctx.run_layout(&mut self.search, Size::new(text_width, text_height));
+        ctx.place_child(
+            &mut self.search,
+            Point::new(x + SEARCH_PADDING, y + SEARCH_PADDING),
+        );
+        y += search_height + list_gap;
+
+        self.row_rects.clear();
+        for (row, height) in self.rows.iter_mut().zip(row_heights) {
+            let text_width = (inner_width - 2. * ROW_PADDING).max(0.);
+            ctx.run_layout(row, Size::new(text_width, height));
+            ctx.place_child(row, Point::new(x + ROW_PADDING, y + ROW_PADDING));
+            let row_height = height + 2. * ROW_PADDING;
+            self.row_rects
+                .push(Rect::from_origin_size((x, y), (inner_width, row_height)));
+            y += row_height;
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        if !self.open {
+            return;
+        }
+        fill(scene, &ctx.content_box_size().to_rect(), SCRIM_COLOR);
+        let panel = self.panel.to_rounded_rect(PANEL_RADIUS);
+        fill(scene, &panel, theme::ZYNC_900);
+        stroke(scene, &panel, theme::ZYNC_700, 1.);
+        let search = self.search_rect.to_rounded_rect(ROW_RADIUS);
+        fill(scene, &search, theme::TEXT_BACKGROUND_COLOR);
+
+        if let Some(row) = self
+            .selected
+            .checked_sub(self.first_row)
+            .and_then(|row| self.row_rects.get(row))
+        {
+            let bar_color = props.get::<BarColor>().0;
+            fill(scene, &row.to_rounded_rect(ROW_RADIUS), bar_color);
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Dialog
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        if !self.open {
+            node.set_hidden();
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        [self.search.id()]
+            .into_iter()
+            .chain(self.rows.iter().map(|row| row.id()))
+            .collect()
+    }
+
+    fn find_widget_under_pointer<'c>(
+        &'c self,
+        ctx: QueryCtx<'c>,
+        pos: Point,
+    ) -> Option<WidgetRef<'c, dyn Widget>> {
+        // While closed, the pointer goes through to the content under the palette.
+        if !self.open {
+            return None;
+        }
+        find_widget_under_pointer(self, ctx, pos)
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("CommandPalette", id = id.trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(self.query.clone())
+    }
+}
+
+// --- MARK: HELPERS
+/// Returns the width of the content of the panel, in a palette of the given width.
+fn inner_width(width: f64) -> f64 {
+    ((width - 2. * PANEL_MARGIN).min(PANEL_MAX_WIDTH) - 2. * PANEL_PADDING).max(0.)
+}
+
+fn new_row(title: &ArcStr, ranges: &[Range<usize>]) -> RichText {
+    bold_spans(ranges)
+        .into_iter()
+        .fold(RichText::new(title.clone()), |text, (range, style)| {
+            text.with_span_style(range, style)
+        })
+}
+
+fn bold_spans(ranges: &[Range<usize>]) -> Vec<(Range<usize>, StyleProperty)> {
+    ranges
+        .iter()
+        .map(|range| (range.clone(), StyleProperty::FontWeight(FontWeight::BOLD)))
+        .collect()
+}
+
+/// Returns the commands matching `query`, best match first.
+///
+/// Equally good matches are ordered by how recently they were chosen, then in the order
+/// of `commands`.
+fn rank(commands: &[ArcStr], recent: &[ArcStr], query: &str) -> Vec<Match> {
+    let mut matches = commands
+        .iter()
+        .enumerate()
+        .filter_map(|(index, title)| {
+            let (score, ranges) = fuzzy_match(query, title)?;
+            let recency = recent
+                .iter()
+                .position(|recent| recent == title)
+                .unwrap_or(usize::MAX);
+            Some((Reverse(score), recency, Match { index, ranges }))
+        })
+        .collect::<Vec<_>>();
+    matches.sort_by_key(|(score, recency, m)| (*score, *recency, m.index));
+    matches.into_iter().map(|(_, _, m)| m).collect()
+}
+
+/// Matches `query` against `text`, ignoring case and the whitespace of the query.
+///
+/// Each character of the query must appear in the text, in order.
+/// Returns the score of the match, higher being better, and the byte ranges of the
+/// matched characters, or `None` if the text doesn't match.
+fn fuzzy_match(query: &str, text: &str) -> Option<(i64, Vec<Range<usize>>)> {
+    let mut wanted = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .peekable();
+    let mut score = 0;
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut gap = 0;
+    let mut prev = None;
+    for (idx, c) in text.char_indices() {
+        let Some(&next) = wanted.peek() else {
+            break;
+        };
+        if c.to_lowercase().next() == Some(next) {
+            wanted.next();
+            score += MATCH_SCORE;
+            let end = idx + c.len_utf8();
+            match ranges.last_mut() {
+                Some(last) if last.end == idx => {
+                    last.end = end;
+                    score += CONSECUTIVE_BONUS;
+                }
+                _ => {
+                    ranges.push(idx..end);
+                    score -= gap.min(MAX_GAP_PENALTY);
+                }
+            }
+            if is_word_start(prev, c) {
+                score += WORD_START_BONUS;
+            }
+            gap = 0;
+        } else {
+            gap += 1;
+        }
+        prev = Some(c);
+    }
+    if wanted.peek().is_some() {
+        return None;
+    }
+    Some((score, ranges))
+}
+
+/// Returns whether `c` starts a word, after the character `prev`.
+fn is_word_start(prev: Option<char>, c: char) -> bool {
+    match prev {
+        None => true,
+        Some(prev) => !prev.is_alphanumeric() || (prev.is_lowercase() && c.is_uppercase()),
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+
+    const COMMANDS: [&str; 4] = ["Close Panel", "Open File", "Open Recent", "Toggle Theme"];
+
+    #[test]
+    fn fuzzy_matching() {
+        assert_eq!(fuzzy_match("opf", "Open File").unwrap().1, vec![0..2, 5..6]);
+        assert_eq!(fuzzy_match("OPEN", "open file").unwrap().1, vec![0..4]);
+        assert!(fuzzy_match("fo", "Open File").is_none());
+
+        // Matches at the start of words beat matches in the middle of them.
+        let word_start = fuzzy_match("op", "Open File").unwrap().0;
+        let middle = fuzzy_match("op", "Close Panel").unwrap().0;
+        assert!(word_start > middle);
+
+        let commands = COMMANDS.map(ArcStr::from);
+        let ranked = |query| {
+            rank(&commands, &[], query)
+                .iter()
+                .map(|m| m.index)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ranked("op"), [1, 2, 0]);
+        assert_eq!(ranked("th"), [3]);
+        assert_eq!(ranked(""), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn keyboard_navigation_and_recent() {
+        let widget = CommandPalette::new(COMMANDS).with_open(true);
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            widget.with_auto_id(),
+            Size::new(600., 400.),
+        );
+        let area_id = harness.root_widget().area_id();
+        assert_eq!(harness.focused_widget_id(), Some(area_id));
+
+        harness.keyboard_type_chars("open");
+        assert_eq!(harness.root_widget().matches().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(harness.root_widget().selected(), Some(1));
+        // Ignore the text actions of the search field.
+        while harness.pop_action_erased().is_some() {}
+
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowDown)));
+        assert_eq!(harness.root_widget().selected(), Some(2));
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowDown)));
+        assert_eq!(harness.root_widget().selected(), Some(1));
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowUp)));
+        assert_eq!(harness.root_widget().selected(), Some(2));
+
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::Enter)));
+        assert_eq!(
+            harness.pop_action::<CommandPaletteAction>(),
+            Some((CommandPaletteAction::Chosen(2), harness.root_id()))
+        );
+
+        // The chosen command comes first when the palette is opened again.
+        harness.edit_root_widget(|mut palette| {
+            CommandPalette::set_open(&mut palette, false);
+            CommandPalette::set_open(&mut palette, true);
+        });
+        assert_eq!(
+            harness.root_widget().matches().collect::<Vec<_>>(),
+            [2, 0, 1, 3]
+        );
+
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::Escape)));
+        assert_eq!(
+            harness.pop_action::<CommandPaletteAction>(),
+            Some((CommandPaletteAction::Dismissed, harness.root_id()))
+        );
+    }
+
+    #[test]
+    fn closed_palette_lets_pointer_through() {
+        let widget = CommandPalette::new(COMMANDS);
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            widget.with_auto_id(),
+            Size::new(600., 400.),
+        );
+        assert!(harness.focused_widget_id().is_none());
+
+        harness.mouse_move((300., 200.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert!(harness.pop_action::<CommandPaletteAction>().is_none());
+
+        harness.edit_root_widget(|mut palette| CommandPalette::set_open(&mut palette, true));
+        harness.mouse_move((5., 5.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(
+            harness.pop_action::<CommandPaletteAction>(),
+            Some((CommandPaletteAction::Dismissed, harness.root_id()))
+        );
+    }
+}
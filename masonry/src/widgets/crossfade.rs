@@ -0,0 +1,247 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ChildrenIds, ComposeCtx, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PropertiesMut, PropertiesRef, RegisterCtx, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::LenReq;
+use crate::util::Duration;
+
+/// How long children take to fade in and out, by default.
+const DEFAULT_DURATION: Duration = Duration::from_millis(200);
+
+/// A widget with a single child, which fades into a new child when the child is replaced.
+///
+/// The child is replaced with [`Crossfade::set_child`]: the previous child fades out while
+/// the new one fades in, and is removed once the transition is over.
+/// If the user [prefers reduced motion], the child is replaced without fading.
+///
+/// The crossfade takes the size of its current child.
+///
+/// [prefers reduced motion]: crate::core::EventCtx::prefers_reduced_motion
+pub struct Crossfade {
+    child: WidgetPod<dyn Widget>,
+    /// The replaced child, kept until it finishes fading out.
+    leaving: Option<WidgetPod<dyn Widget>>,
+    /// How far the transition is, from 0 to 1.
+    progress: Option<f64>,
+    duration: Duration,
+}
+
+// --- MARK: BUILDERS
+impl Crossfade {
+    /// Creates a new crossfade with the given child.
+    pub fn new(child: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            child: child.erased().to_pod(),
+            leaving: None,
+            progress: None,
+            duration: DEFAULT_DURATION,
+        }
+    }
+
+    /// Builder-style method to set how long children take to fade in and out.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl Crossfade {
+    /// Returns the id of the current child.
+    pub fn child_id(&self) -> WidgetId {
+        self.child.id()
+    }
+
+    /// Returns `true` if the previous child is fading out.
+    pub fn is_transitioning(&self) -> bool {
+        self.progress.is_some()
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Crossfade {
+    /// Replaces the child, fading the previous child out and the new one in.
+    ///
+    /// If a previous child was still fading out, it is removed at once.
+    pub fn set_child(this: &mut WidgetMut<'_, Self>, child: NewWidget<impl Widget + ?Sized>) {
+        if let Some(leaving) = this.widget.leaving.take() {
+            this.ctx.remove_child(leaving);
+        }
+        let previous = std::mem::replace(&mut this.widget.child, child.erased().to_pod());
+        this.widget.leaving = Some(previous);
+        this.widget.progress = Some(0.);
+        // The children fade in `on_anim_frame`.
+        this.ctx.request_anim_frame();
+        this.ctx.children_changed();
+    }
+
+    /// Returns a mutable reference to the current child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Sets how long children take to fade in and out.
+    pub fn set_duration(this: &mut WidgetMut<'_, Self>, duration: Duration) {
+        this.widget.duration = duration;
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Crossfade {
+    type Action = NoAction;
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let Some(progress) = &mut self.progress else {
+            return;
+        };
+        let duration = self.duration.as_nanos() as f64;
+        *progress = if ctx.prefers_reduced_motion() || duration == 0. {
+            1.
+        } else {
+            (*progress + interval as f64 / duration).min(1.)
+        };
+        if *progress >= 1. {
+            self.progress = None;
+            if let Some(leaving) = self.leaving.take() {
+                ctx.remove_child(leaving);
+            }
+        } else {
+            ctx.request_anim_frame();
+        }
+        ctx.request_compose();
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+        if let Some(leaving) = &mut self.leaving {
+            ctx.register_child(leaving);
+        }
+    }
+
+    fn property_changed(&mut self, _ctx: &mut UpdateCtx<'_>, _property_type: TypeId) {}
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        ctx.redirect_measurement(&mut self.child, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.child, size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        if let Some(leaving) = &mut self.leaving {
+            ctx.run_layout(leaving, size);
+            ctx.place_child(leaving, Point::ORIGIN);
+        }
+
+        let child_baseline = ctx.child_baseline_offset(&self.child);
+        ctx.set_baseline_offset(child_baseline);
+    }
+
+    fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
+        let progress = self.progress.unwrap_or(1.) as f32;
+        ctx.set_child_opacity(&mut self.child, progress);
+        if let Some(leaving) = &mut self.leaving {
+            ctx.set_child_opacity(leaving, 1. - progress);
+        }
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        // The leaving child is first, so the new child is painted over it.
+        self.leaving
+            .iter()
+            .chain([&self.child])
+            .map(|child| child.id())
+            .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Crossfade", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{WidgetOptions, WidgetTag, WindowEvent};
+    use crate::properties::Dimensions;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::Label;
+
+    #[test]
+    fn fades_to_new_child() {
+        let tag = WidgetTag::named("crossfade");
+        let widget = NewWidget::new_with(
+            Crossfade::new(Label::new("A").with_auto_id()),
+            Some(tag),
+            WidgetOptions::default(),
+            Dimensions::MAX,
+        );
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(50., 50.));
+
+        harness.edit_widget(tag, |mut crossfade| {
+            Crossfade::set_child(&mut crossfade, Label::new("B").with_auto_id());
+        });
+        harness.animate_ms(50);
+        {
+            let crossfade = harness.get_widget(tag);
+            assert!(crossfade.inner().is_transitioning());
+            // Both children are shown while they fade.
+            assert_eq!(crossfade.children().len(), 2);
+        }
+
+        // The previous child is removed once it has faded out.
+        harness.animate_ms(1000);
+        let crossfade = harness.get_widget(tag);
+        assert!(!crossfade.inner().is_transitioning());
+        assert_eq!(crossfade.children().len(), 1);
+        assert_eq!(crossfade.children()[0].id(), crossfade.inner().child_id());
+
+        // With reduced motion, the child is replaced at once.
+        harness.process_window_event(WindowEvent::ReducedMotionChanged(true));
+        harness.edit_widget(tag, |mut crossfade| {
+            Crossfade::set_child(&mut crossfade, Label::new("C").with_auto_id());
+        });
+        harness.animate_ms(16);
+        let crossfade = harness.get_widget(tag);
+        assert!(!crossfade.inner().is_transitioning());
+        assert_eq!(crossfade.children().len(), 1);
+    }
+}
@@ -13,7 +13,7 @@ use vello::Scene;
 use crate::core::{
     AccessCtx, ArcStr, ChildrenIds, HasProperty, LayoutCtx, MeasureCtx, NewWidget, NoAction,
     PaintCtx, PropertiesMut, PropertiesRef, Property, RegisterCtx, Update, UpdateCtx, Widget,
-    WidgetId, WidgetPod,
+    WidgetId, WidgetMut, WidgetPod,
 };
 use crate::kurbo::{Affine, Axis, Cap, Join, Line, Size, Stroke};
 use crate::layout::LenReq;
@@ -29,12 +29,17 @@ use crate::widgets::Label;
 ///
 /// There is support for a [`label`] and even arbitrary [`content`].
 ///
+/// The line can be kept away from its start and end edges with [`start_inset`] and [`end_inset`],
+/// e.g. to align a list separator with the text of the list items rather than with their icons.
+///
 /// It has the following properties:
 /// * [`ContentColor`] - defines the color of the dividing line.
 ///
 /// [`dash_pattern`]: Self::dash_pattern
 /// [`label`]: Self::label
 /// [`content`]: Self::content
+/// [`start_inset`]: Self::start_inset
+/// [`end_inset`]: Self::end_inset
 /// [`ContentColor`]: ContentColor
 ///
 #[doc = concat!(
@@ -53,6 +58,8 @@ pub struct Divider {
     placement: Placement,
     content: Option<WidgetPod<dyn Widget>>,
     pad: Length,
+    start_inset: Length,
+    end_inset: Length,
     lines: SmallVec<[LineLayout; 2]>,
 }
 
@@ -119,7 +126,7 @@ pub enum DashFit {
 }
 
 /// Describes the strategy where to place the divider's content.
-#[derive(Default, Copy, Clone, Debug)]
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Placement {
     /// Place the content at the start.
     ///
@@ -157,6 +164,8 @@ impl Divider {
             placement: Placement::default(),
             content: None,
             pad: Length::const_px(5.),
+            start_inset: Length::ZERO,
+            end_inset: Length::ZERO,
             lines: SmallVec::default(),
         }
     }
@@ -297,6 +306,92 @@ impl Divider {
         self.pad = pad;
         self
     }
+
+    /// Returns `self` with the given `inset` used both for start and end.
+    ///
+    /// Use [`start_inset`] or [`end_inset`] to set different insets.
+    ///
+    /// [`start_inset`]: Self::start_inset
+    /// [`end_inset`]: Self::end_inset
+    pub fn inset(mut self, inset: Length) -> Self {
+        self.start_inset = inset;
+        self.end_inset = inset;
+        self
+    }
+
+    /// Returns `self` with the given `inset` between the start edge and the divider.
+    ///
+    /// Defaults to zero.
+    pub fn start_inset(mut self, inset: Length) -> Self {
+        self.start_inset = inset;
+        self
+    }
+
+    /// Returns `self` with the given `inset` between the divider and the end edge.
+    ///
+    /// Defaults to zero.
+    pub fn end_inset(mut self, inset: Length) -> Self {
+        self.end_inset = inset;
+        self
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Divider {
+    /// Sets the axis the divider is parallel with.
+    pub fn set_axis(this: &mut WidgetMut<'_, Self>, axis: Axis) {
+        this.widget.axis = axis;
+        this.ctx.request_layout();
+    }
+
+    /// Sets the line `thickness`, or hairline if `None`.
+    pub fn set_thickness(this: &mut WidgetMut<'_, Self>, thickness: Option<Length>) {
+        this.widget.thickness = thickness;
+        this.ctx.request_layout();
+    }
+
+    /// Sets the content `placement`.
+    pub fn set_placement(this: &mut WidgetMut<'_, Self>, placement: Placement) {
+        this.widget.placement = placement;
+        this.ctx.request_layout();
+    }
+
+    /// Sets the inset between the start edge and the divider.
+    pub fn set_start_inset(this: &mut WidgetMut<'_, Self>, inset: Length) {
+        this.widget.start_inset = inset;
+        this.ctx.request_layout();
+    }
+
+    /// Sets the inset between the divider and the end edge.
+    pub fn set_end_inset(this: &mut WidgetMut<'_, Self>, inset: Length) {
+        this.widget.end_inset = inset;
+        this.ctx.request_layout();
+    }
+
+    /// Replaces the content, or removes it if `content` is `None`.
+    ///
+    /// For simple text labels use [`set_label`](Self::set_label).
+    pub fn set_content(
+        this: &mut WidgetMut<'_, Self>,
+        content: Option<NewWidget<impl Widget + ?Sized>>,
+    ) {
+        if let Some(old) = this.widget.content.take() {
+            this.ctx.remove_child(old);
+        }
+        this.widget.content = content.map(|content| content.erased().to_pod());
+        this.ctx.children_changed();
+    }
+
+    /// Replaces the content with a `text` label, or removes it if `text` is `None`.
+    pub fn set_label(this: &mut WidgetMut<'_, Self>, text: Option<impl Into<ArcStr>>) {
+        Self::set_content(this, text.map(|text| Label::new(text).with_auto_id()));
+    }
+
+    /// Returns a mutable reference to the content, if there is one.
+    pub fn content_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> Option<WidgetMut<'t, dyn Widget>> {
+        let content = this.widget.content.as_mut()?;
+        Some(this.ctx.get_mut(content))
+    }
 }
 
 /// Returns `(length, gap_counts)`.
@@ -494,10 +589,11 @@ impl Widget for Divider {
         };
 
         if axis == self.axis {
+            let insets = self.start_inset.dp(scale) + self.end_inset.dp(scale);
             match len_req {
-                LenReq::MinContent => content_length,
-                LenReq::MaxContent => (DEFAULT_LENGTH * scale).max(content_length),
-                LenReq::FitContent(space) => space.max(content_length),
+                LenReq::MinContent => content_length + insets,
+                LenReq::MaxContent => (DEFAULT_LENGTH * scale).max(content_length + insets),
+                LenReq::FitContent(space) => space.max(content_length + insets),
             }
         } else {
             thickness.max(content_length)
@@ -515,6 +611,7 @@ impl Widget for Divider {
         /// Stores the line layout info for painting.
         fn lay_out_line(
             this: &mut Divider,
+            inset: f64,
             start: f64,
             end: f64,
             cross_pos: f64,
@@ -525,8 +622,8 @@ impl Widget for Divider {
                 Cap::Butt => 0.,
                 Cap::Square | Cap::Round => thickness * 0.5,
             };
-            let p1 = this.axis.pack_point(cap_offset + start, cross_pos);
-            let p2 = this.axis.pack_point(cap_offset + end, cross_pos);
+            let p1 = this.axis.pack_point(inset + cap_offset + start, cross_pos);
+            let p2 = this.axis.pack_point(inset + cap_offset + end, cross_pos);
             this.lines.push(LineLayout {
                 line: Line::new(p1, p2),
                 dashes,
@@ -535,11 +632,20 @@ impl Widget for Divider {
 
         let thickness = self.thickness.map(|t| t.dp(scale)).unwrap_or(1.);
         let cross_pos = size.get_coord(self.axis.cross()) * 0.5;
+        // The line and the content are laid out in the space between the insets.
+        let start_inset = self.start_inset.dp(scale);
+        let inset_length =
+            (size.get_coord(self.axis) - start_inset - self.end_inset.dp(scale)).max(0.);
+        let inset_size = self
+            .axis
+            .pack_size(inset_length, size.get_coord(self.axis.cross()));
+        let inset_origin = self.axis.pack_point(start_inset, 0.);
         let mut dashes: SmallVec<[f64; 4]> =
             self.dash_pattern.iter().map(|l| l.dp(scale)).collect();
 
         if let Some(content) = &mut self.content {
-            let content_size = ctx.compute_size(content, SizeDef::fit(size), size.into());
+            let content_size =
+                ctx.compute_size(content, SizeDef::fit(inset_size), inset_size.into());
             ctx.run_layout(content, content_size);
 
             let placement = match self.placement {
@@ -553,7 +659,8 @@ impl Widget for Divider {
                     Axis::Vertical => UnitPoint::BOTTOM,
                 },
             };
-            let content_origin = placement.resolve((size - content_size).to_rect());
+            let content_origin =
+                placement.resolve((inset_size - content_size).to_rect()) + inset_origin.to_vec2();
             ctx.place_child(content, content_origin);
 
             let content_baseline = ctx.child_baseline_offset(content);
@@ -562,7 +669,7 @@ impl Widget for Divider {
             ctx.set_baseline_offset(content_baseline + bottom_gap);
 
             let pad = self.pad.dp(scale);
-            let mut line_space = size.get_coord(self.axis)
+            let mut line_space = inset_length
                 - self.total_cap_overhang(thickness)
                 - content_size.get_coord(self.axis)
                 - pad;
@@ -585,10 +692,18 @@ impl Widget for Divider {
                     line_space = ((line_space - pad) * 0.5).max(0.);
                     let (start, end) = line_length(line_space, &mut dashes, self.dash_fit);
                     // Lay out the first line at the start
-                    lay_out_line(self, start, end, cross_pos, dashes.clone(), thickness);
+                    lay_out_line(
+                        self,
+                        start_inset,
+                        start,
+                        end,
+                        cross_pos,
+                        dashes.clone(),
+                        thickness,
+                    );
                     // Lay out the second line on the other side of the content,
                     // using the general code path.
-                    let offset = content_origin.get_coord(self.axis)
+                    let offset = content_origin.get_coord(self.axis) - start_inset
                         + content_size.get_coord(self.axis)
                         + pad;
                     (start + offset, end + offset)
@@ -597,18 +712,18 @@ impl Widget for Divider {
                 Placement::End => line_length(line_space, &mut dashes, self.dash_fit),
             };
 
-            lay_out_line(self, start, end, cross_pos, dashes, thickness);
+            lay_out_line(self, start_inset, start, end, cross_pos, dashes, thickness);
         } else {
             // Single line, no content
             ctx.clear_baseline_offset();
 
-            let line_space = size.get_coord(self.axis) - self.total_cap_overhang(thickness);
+            let line_space = inset_length - self.total_cap_overhang(thickness);
             if line_space < 0. {
                 // No space for line drawing
                 return;
             }
             let (start, end) = line_length(line_space, &mut dashes, self.dash_fit);
-            lay_out_line(self, start, end, cross_pos, dashes, thickness);
+            lay_out_line(self, start_inset, start, end, cross_pos, dashes, thickness);
         }
     }
 
@@ -665,6 +780,7 @@ impl Widget for Divider {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::WidgetTag;
     use crate::layout::AsUnit;
     use crate::palette;
     use crate::properties::types::{CrossAxisAlignment, MainAxisAlignment};
@@ -850,6 +966,24 @@ mod tests {
         assert_render_snapshot!(harness, "divider_label");
     }
 
+    #[test]
+    fn inset() {
+        let label_tag = WidgetTag::named("label");
+        let root = Divider::horizontal()
+            .start_inset(20.px())
+            .end_inset(10.px())
+            .placement(Placement::Start)
+            .content(NewWidget::new_with_tag(Label::new("Inset"), label_tag))
+            .with_props(Dimensions::STRETCH);
+
+        let harness =
+            TestHarness::create_with_size(test_property_set(), root, Size::new(200., 40.));
+
+        assert_eq!(harness.get_widget(label_tag).ctx().window_origin().x, 20.);
+        let line = harness.root_widget().lines[0].line;
+        assert_eq!(line.p1.x, 190.);
+    }
+
     #[test]
     fn content() {
         let content = Spinner::new().with_props(Dimensions::fixed(30.px(), 30.px()));
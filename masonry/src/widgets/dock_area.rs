@@ -0,0 +1,1104 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use serde::{Deserialize, Serialize};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ArcStr, ChildrenIds, CollectionWidget, CursorIcon, EventCtx, HasProperty, LayoutCtx,
+    MeasureCtx, NewWidget, PaintCtx, PointerButton, PointerButtonEvent, PointerEvent,
+    PointerUpdate, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, UpdateCtx, Widget,
+    WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Rect, Size};
+use crate::layout::{LenReq, Length, SizeDef};
+use crate::properties::BarColor;
+use crate::theme;
+use crate::util::{fill, stroke};
+use crate::widgets::Label;
+
+/// The preferred width and height of the area.
+const DEFAULT_LENGTH: Length = Length::const_px(600.);
+/// The height of the tab strip at the top of each group.
+const TAB_HEIGHT: f64 = 28.;
+/// The space between the edges of a tab and its title.
+const TAB_PADDING: f64 = 10.;
+/// The height of the line under the shown tab of each group.
+const TAB_UNDERLINE: f64 = 2.;
+/// The thickness of the dividers between the two sides of a split.
+const DIVIDER_THICKNESS: f64 = 4.;
+/// How far from a divider the pointer can be to drag it.
+const DIVIDER_HIT_SLOP: f64 = 2.;
+/// The smallest length users can drag a side of a split down to.
+const MIN_SIDE_LENGTH: f64 = 48.;
+/// How far the pointer must move with a tab pressed before the tab is dragged.
+const DRAG_THRESHOLD: f64 = 6.;
+/// The fraction of a group's content near each edge where dropping a panel docks it to
+/// that side, instead of adding it as a tab.
+const EDGE_ZONE: f64 = 0.25;
+
+/// The arrangement of the panels of a [`DockArea`].
+///
+/// The layout is a tree of splits, whose leaves are groups of panels shown as tabs.
+/// Panels are referred to by their key.
+///
+/// Layouts can be serialized, so that apps can restore the arrangement chosen by the user.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DockLayout {
+    /// A group of panels stacked as tabs, of which one is shown.
+    Tabs {
+        /// The keys of the panels, in tab order.
+        panels: Vec<String>,
+        /// The index in `panels` of the shown panel.
+        active: usize,
+    },
+    /// Two layouts side by side.
+    Row {
+        /// The fraction of the width given to `first`, from 0 to 1.
+        ratio: f64,
+        /// The layout on the left.
+        first: Box<Self>,
+        /// The layout on the right.
+        second: Box<Self>,
+    },
+    /// Two layouts on top of each other.
+    Column {
+        /// The fraction of the height given to `first`, from 0 to 1.
+        ratio: f64,
+        /// The layout at the top.
+        first: Box<Self>,
+        /// The layout at the bottom.
+        second: Box<Self>,
+    },
+}
+
+/// Where to dock a panel relative to a group of panels, see [`DockLayout::dock`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DockPosition {
+    /// In a new group on the left of the group.
+    Left,
+    /// In a new group on the right of the group.
+    Right,
+    /// In a new group above the group.
+    Top,
+    /// In a new group below the group.
+    Bottom,
+    /// As the last tab of the group.
+    Tab,
+}
+
+/// Parameters for a panel in a [`DockArea`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DockPanel {
+    /// The key the [`DockLayout`] refers to the panel by.
+    pub key: String,
+    /// The title shown in the panel's tab.
+    pub title: ArcStr,
+}
+
+impl DockPanel {
+    /// Creates new parameters.
+    pub fn new(key: impl Into<String>, title: impl Into<ArcStr>) -> Self {
+        Self {
+            key: key.into(),
+            title: title.into(),
+        }
+    }
+}
+
+/// The user rearranged the panels of a [`DockArea`], which now has this layout.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DockLayoutChanged(pub DockLayout);
+
+struct Panel {
+    params: DockPanel,
+    tab: WidgetPod<Label>,
+    content: WidgetPod<dyn Widget>,
+}
+
+/// A group of panels shown as tabs, as placed by the last layout pass.
+struct Group {
+    /// The indices of the group's panels, in tab order.
+    panels: Vec<usize>,
+    /// The index in `panels` of the shown panel.
+    active: usize,
+    rect: Rect,
+    tabs: Vec<Rect>,
+}
+
+/// The divider between the two sides of a split, as placed by the last layout pass.
+struct Divider {
+    /// The path from the root of the layout to the split, with `true` going to `second`.
+    path: Vec<bool>,
+    axis: Axis,
+    /// The area of the whole split.
+    span: Rect,
+    rect: Rect,
+}
+
+/// Where a dragged panel would be docked if dropped.
+struct DropTarget {
+    /// The index of a panel of the group to dock next to.
+    target: usize,
+    position: DockPosition,
+    /// The area the panel would take.
+    preview: Rect,
+}
+
+enum Drag {
+    Divider(usize),
+    Tab {
+        panel: usize,
+        start: Point,
+        moved: bool,
+        drop: Option<DropTarget>,
+    },
+}
+
+/// An area split into groups of panels, which users can rearrange, such as the tool windows
+/// of an IDE.
+///
+/// Each panel is a child widget with a key and a title, placed by a [`DockLayout`].
+/// Each group shows the titles of its panels as tabs, and the content of the selected one.
+///
+/// Users can:
+/// - Click a tab to show its panel.
+/// - Drag the dividers between groups to resize them.
+/// - Drag a tab onto another group, to add it to the group's tabs, or near an edge
+///   of the group, to dock it to that side.
+///   The place the panel would go is highlighted while dragging.
+///
+/// The area applies these changes to its layout, and emits [`DockLayoutChanged`] with the
+/// new layout so that apps can persist it.
+/// Panels missing from the layout are hidden, and keys of the layout without a panel are ignored.
+///
+/// You can customize the color of the line under the shown tabs and of the drop highlight
+/// with the [`BarColor`] property.
+pub struct DockArea {
+    panels: Vec<Panel>,
+    layout: DockLayout,
+    groups: Vec<Group>,
+    dividers: Vec<Divider>,
+    drag: Option<Drag>,
+}
+
+// --- MARK: IMPL DOCKLAYOUT
+impl DockLayout {
+    /// Creates a single group with the given panels, showing the first one.
+    pub fn tabs(panels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self::Tabs {
+            panels: panels.into_iter().map(Into::into).collect(),
+            active: 0,
+        }
+    }
+
+    /// Creates a split with `first` on the left of `second`, giving `ratio` of the width to `first`.
+    pub fn row(ratio: f64, first: Self, second: Self) -> Self {
+        Self::Row {
+            ratio,
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    /// Creates a split with `first` above `second`, giving `ratio` of the height to `first`.
+    pub fn column(ratio: f64, first: Self, second: Self) -> Self {
+        Self::Column {
+            ratio,
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    /// Returns the keys of all panels, from the top left group to the bottom right one.
+    pub fn panels(&self) -> Vec<&str> {
+        let mut keys = Vec::new();
+        self.collect_panels(&mut keys);
+        keys
+    }
+
+    /// Returns whether the layout has a panel with this key.
+    pub fn contains(&self, key: &str) -> bool {
+        self.panels().contains(&key)
+    }
+
+    /// Shows the panel with this key in its group.
+    ///
+    /// Returns `false` if the layout has no panel with this key.
+    pub fn activate(&mut self, key: &str) -> bool {
+        match self.group_of(key) {
+            Some(Self::Tabs { panels, active }) => {
+                *active = panels
+                    .iter()
+                    .position(|panel| panel == key)
+                    .unwrap_or(*active);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Removes the panel with this key.
+    ///
+    /// Groups left empty are removed, and the other side of their split takes its place.
+    /// Returns `false` if the layout has no panel with this key.
+    pub fn remove(&mut self, key: &str) -> bool {
+        match self {
+            Self::Tabs { panels, active } => {
+                let Some(idx) = panels.iter().position(|panel| panel == key) else {
+                    return false;
+                };
+                panels.remove(idx);
+                if *active > idx {
+                    *active -= 1;
+                }
+                *active = (*active).min(panels.len().saturating_sub(1));
+                true
+            }
+            Self::Row { first, second, .. } | Self::Column { first, second, .. } => {
+                if !first.remove(key) && !second.remove(key) {
+                    return false;
+                }
+                if first.is_empty() {
+                    *self = std::mem::take(&mut **second);
+                } else if second.is_empty() {
+                    *self = std::mem::take(&mut **first);
+                }
+                true
+            }
+        }
+    }
+
+    /// Moves the panel `key` next to the group of the panel `target`, adding it if the layout
+    /// doesn't have it yet.
+    ///
+    /// With [`DockPosition::Tab`], the panel is added as the last tab of the group.
+    /// Otherwise, the space of the group is split in half between the group and a new group
+    /// with the panel, on the given side.
+    /// Either way, the panel is shown.
+    ///
+    /// Returns `false`, leaving the layout unchanged, if `target` is `key`
+    /// or isn't in the layout.
+    pub fn dock(&mut self, key: &str, target: &str, position: DockPosition) -> bool {
+        if key == target || !self.contains(target) {
+            return false;
+        }
+        self.remove(key);
+        let Some(group) = self.group_of(target) else {
+            return false;
+        };
+        let panel = Self::tabs([key]);
+        match position {
+            DockPosition::Left => *group = Self::row(0.5, panel, std::mem::take(group)),
+            DockPosition::Right => *group = Self::row(0.5, std::mem::take(group), panel),
+            DockPosition::Top => *group = Self::column(0.5, panel, std::mem::take(group)),
+            DockPosition::Bottom => *group = Self::column(0.5, std::mem::take(group), panel),
+            DockPosition::Tab => {
+                if let Self::Tabs { panels, active } = group {
+                    panels.push(key.to_string());
+                    *active = panels.len() - 1;
+                }
+            }
+        }
+        true
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, Self::Tabs { panels, .. } if panels.is_empty())
+    }
+
+    fn collect_panels<'a>(&'a self, keys: &mut Vec<&'a str>) {
+        match self {
+            Self::Tabs { panels, .. } => keys.extend(panels.iter().map(String::as_str)),
+            Self::Row { first, second, .. } | Self::Column { first, second, .. } => {
+                first.collect_panels(keys);
+                second.collect_panels(keys);
+            }
+        }
+    }
+
+    /// Returns the group with the panel `key`.
+    fn group_of(&mut self, key: &str) -> Option<&mut Self> {
+        if let Self::Tabs { panels, .. } = &*self {
+            return panels.iter().any(|panel| panel == key).then_some(self);
+        }
+        match self {
+            Self::Tabs { .. } => None,
+            Self::Row { first, second, .. } | Self::Column { first, second, .. } => {
+                first.group_of(key).or_else(|| second.group_of(key))
+            }
+        }
+    }
+
+    /// Returns the node at the end of `path`, with `true` going to `second`.
+    fn node_mut(&mut self, path: &[bool]) -> Option<&mut Self> {
+        let Some((&is_second, rest)) = path.split_first() else {
+            return Some(self);
+        };
+        match self {
+            Self::Tabs { .. } => None,
+            Self::Row { first, second, .. } | Self::Column { first, second, .. } => {
+                if is_second {
+                    second.node_mut(rest)
+                } else {
+                    first.node_mut(rest)
+                }
+            }
+        }
+    }
+}
+
+impl Default for DockLayout {
+    fn default() -> Self {
+        Self::Tabs {
+            panels: Vec::new(),
+            active: 0,
+        }
+    }
+}
+
+// --- MARK: BUILDERS
+impl DockArea {
+    /// Creates an area without panels, arranged by `layout` once they are added.
+    pub fn new(layout: DockLayout) -> Self {
+        Self {
+            panels: Vec::new(),
+            layout,
+            groups: Vec::new(),
+            dividers: Vec::new(),
+            drag: None,
+        }
+    }
+
+    /// Builder-style method to add a panel.
+    pub fn with(mut self, content: NewWidget<impl Widget + ?Sized>, panel: DockPanel) -> Self {
+        self.panels.push(Panel::new(panel, content));
+        self
+    }
+}
+
+impl Panel {
+    fn new(params: DockPanel, content: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            tab: NewWidget::new(Label::new(params.title.clone())).to_pod(),
+            content: content.erased().to_pod(),
+            params,
+        }
+    }
+}
+
+// --- MARK: METHODS
+impl DockArea {
+    /// Returns the layout of the panels.
+    pub fn layout(&self) -> &DockLayout {
+        &self.layout
+    }
+
+    /// Returns the parameters of the panel at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn panel(&self, idx: usize) -> &DockPanel {
+        &self.panels[idx].params
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl DockArea {
+    /// Replaces the layout of the panels.
+    ///
+    /// This cancels any drag in progress.
+    pub fn set_layout(this: &mut WidgetMut<'_, Self>, layout: DockLayout) {
+        this.widget.layout = layout;
+        this.widget.drag = None;
+        this.ctx.request_layout();
+        this.ctx.request_post_paint();
+    }
+}
+
+// --- MARK: COLLECTIONWIDGET
+impl CollectionWidget<DockPanel> for DockArea {
+    /// Returns the number of panels.
+    fn len(&self) -> usize {
+        self.panels.len()
+    }
+
+    /// Returns `true` if there are no panels.
+    fn is_empty(&self) -> bool {
+        self.panels.is_empty()
+    }
+
+    /// Returns a mutable reference to the content of the panel at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn get_mut<'t>(this: &'t mut WidgetMut<'_, Self>, idx: usize) -> WidgetMut<'t, dyn Widget> {
+        let content = &mut this.widget.panels[idx].content;
+        this.ctx.get_mut(content)
+    }
+
+    /// Appends a panel.
+    fn add(
+        this: &mut WidgetMut<'_, Self>,
+        content: NewWidget<impl Widget + ?Sized>,
+        params: impl Into<DockPanel>,
+    ) {
+        this.widget.panels.push(Panel::new(params.into(), content));
+        this.widget.drag = None;
+        this.ctx.children_changed();
+    }
+
+    /// Inserts a panel at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the number of panels.
+    fn insert(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        content: NewWidget<impl Widget + ?Sized>,
+        params: impl Into<DockPanel>,
+    ) {
+        this.widget
+            .panels
+            .insert(idx, Panel::new(params.into(), content));
+        this.widget.drag = None;
+        this.ctx.children_changed();
+    }
+
+    /// Replaces the panel at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn set(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        content: NewWidget<impl Widget + ?Sized>,
+        params: impl Into<DockPanel>,
+    ) {
+        let panel = Panel::new(params.into(), content);
+        let old_panel = std::mem::replace(&mut this.widget.panels[idx], panel);
+        this.widget.drag = None;
+        this.ctx.remove_child(old_panel.tab);
+        this.ctx.remove_child(old_panel.content);
+    }
+
+    /// Sets the key and title of the panel at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn set_params(this: &mut WidgetMut<'_, Self>, idx: usize, params: impl Into<DockPanel>) {
+        let params = params.into();
+        {
+            let mut tab = this.ctx.get_mut(&mut this.widget.panels[idx].tab);
+            Label::set_text(&mut tab, params.title.clone());
+        }
+        this.widget.panels[idx].params = params;
+        this.ctx.request_layout();
+    }
+
+    /// Swaps the index of two panels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    fn swap(this: &mut WidgetMut<'_, Self>, a: usize, b: usize) {
+        this.widget.panels.swap(a, b);
+        this.widget.drag = None;
+        this.ctx.children_changed();
+    }
+
+    /// Removes the panel at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn remove(this: &mut WidgetMut<'_, Self>, idx: usize) {
+        let panel = this.widget.panels.remove(idx);
+        this.widget.drag = None;
+        this.ctx.remove_child(panel.tab);
+        this.ctx.remove_child(panel.content);
+    }
+
+    /// Removes all panels.
+    fn clear(this: &mut WidgetMut<'_, Self>) {
+        this.widget.drag = None;
+        for panel in this.widget.panels.drain(..) {
+            this.ctx.remove_child(panel.tab);
+            this.ctx.remove_child(panel.content);
+        }
+    }
+}
+
+// --- MARK: HELPERS
+impl DockArea {
+    fn divider_at(&self, pos: Point) -> Option<usize> {
+        self.dividers.iter().position(|divider| {
+            divider
+                .rect
+                .inflate(DIVIDER_HIT_SLOP, DIVIDER_HIT_SLOP)
+                .contains(pos)
+        })
+    }
+
+    /// Returns the index of the group and of the tab in the group under `pos`.
+    fn tab_at(&self, pos: Point) -> Option<(usize, usize)> {
+        self.groups
+            .iter()
+            .enumerate()
+            .find_map(|(group_idx, group)| {
+                let tab = group.tabs.iter().position(|tab| tab.contains(pos))?;
+                Some((group_idx, tab))
+            })
+    }
+
+    fn submit_layout(&self, ctx: &mut EventCtx<'_>) {
+        ctx.submit_action::<DockLayoutChanged>(DockLayoutChanged(self.layout.clone()));
+    }
+}
+
+/// Places the groups and dividers of `layout` in `rect`.
+fn arrange(
+    layout: &DockLayout,
+    rect: Rect,
+    panels: &[Panel],
+    path: &mut Vec<bool>,
+    groups: &mut Vec<Group>,
+    dividers: &mut Vec<Divider>,
+) {
+    let (axis, ratio, first, second) = match layout {
+        DockLayout::Tabs {
+            panels: keys,
+            active,
+        } => {
+            let mut indices = Vec::new();
+            let mut shown = 0;
+            for (key_idx, key) in keys.iter().enumerate() {
+                let Some(idx) = panels.iter().position(|panel| panel.params.key == *key) else {
+                    continue;
+                };
+                // A panel can only be shown once.
+                if indices.contains(&idx) || groups.iter().any(|group| group.panels.contains(&idx))
+                {
+                    continue;
+                }
+                if key_idx == *active {
+                    shown = indices.len();
+                }
+                indices.push(idx);
+            }
+            groups.push(Group {
+                panels: indices,
+                active: shown,
+                rect,
+                tabs: Vec::new(),
+            });
+            return;
+        }
+        DockLayout::Row {
+            ratio,
+            first,
+            second,
+        } => (Axis::Horizontal, *ratio, first, second),
+        DockLayout::Column {
+            ratio,
+            first,
+            second,
+        } => (Axis::Vertical, *ratio, first, second),
+    };
+
+    let space = (rect.size().get_coord(axis) - DIVIDER_THICKNESS).max(0.);
+    let first_length = space * ratio.clamp(0., 1.);
+    let (first_rect, divider_rect, second_rect) = match axis {
+        Axis::Horizontal => {
+            let x = rect.x0 + first_length;
+            (
+                Rect::new(rect.x0, rect.y0, x, rect.y1),
+                Rect::new(x, rect.y0, x + DIVIDER_THICKNESS, rect.y1),
+                Rect::new(x + DIVIDER_THICKNESS, rect.y0, rect.x1, rect.y1),
+            )
+        }
+        Axis::Vertical => {
+            let y = rect.y0 + first_length;
+            (
+                Rect::new(rect.x0, rect.y0, rect.x1, y),
+                Rect::new(rect.x0, y, rect.x1, y + DIVIDER_THICKNESS),
+                Rect::new(rect.x0, y + DIVIDER_THICKNESS, rect.x1, rect.y1),
+            )
+        }
+    };
+    dividers.push(Divider {
+        path: path.clone(),
+        axis,
+        span: rect,
+        rect: divider_rect,
+    });
+
+    path.push(false);
+    arrange(first, first_rect, panels, path, groups, dividers);
+    path.pop();
+    path.push(true);
+    arrange(second, second_rect, panels, path, groups, dividers);
+    path.pop();
+}
+
+/// Finds where the panel `dragged` would be docked if dropped at `pos`.
+fn drop_target(groups: &[Group], dragged: usize, pos: Point) -> Option<DropTarget> {
+    let group = groups.iter().find(|group| group.rect.contains(pos))?;
+    // The panel is docked next to another panel of the group.
+    let target = *group.panels.iter().find(|&&panel| panel != dragged)?;
+
+    let rect = group.rect;
+    let content_top = (rect.y0 + TAB_HEIGHT).min(rect.y1);
+    let position = if pos.y < content_top {
+        DockPosition::Tab
+    } else {
+        let x = (pos.x - rect.x0) / rect.width().max(1.);
+        let y = (pos.y - content_top) / (rect.y1 - content_top).max(1.);
+        // The nearest edge wins, if the pointer is close enough to it.
+        let (distance, position) = [
+            (x, DockPosition::Left),
+            (1. - x, DockPosition::Right),
+            (y, DockPosition::Top),
+            (1. - y, DockPosition::Bottom),
+        ]
+        .into_iter()
+        .min_by(|a, b| a.0.total_cmp(&b.0))?;
+        if distance < EDGE_ZONE {
+            position
+        } else {
+            DockPosition::Tab
+        }
+    };
+    if position == DockPosition::Tab && group.panels.contains(&dragged) {
+        return None;
+    }
+
+    let center = rect.center();
+    let preview = match position {
+        DockPosition::Left => Rect::new(rect.x0, rect.y0, center.x, rect.y1),
+        DockPosition::Right => Rect::new(center.x, rect.y0, rect.x1, rect.y1),
+        DockPosition::Top => Rect::new(rect.x0, rect.y0, rect.x1, center.y),
+        DockPosition::Bottom => Rect::new(rect.x0, center.y, rect.x1, rect.y1),
+        DockPosition::Tab => rect,
+    };
+    Some(DropTarget {
+        target,
+        position,
+        preview,
+    })
+}
+
+impl HasProperty<BarColor> for DockArea {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for DockArea {
+    type Action = DockLayoutChanged;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: None | Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                // Let interactive widgets inside panels handle their own presses.
+                if ctx.pointer_capture_target_id().is_some() {
+                    return;
+                }
+                let pos = ctx.local_position(state.position);
+                if let Some(divider) = self.divider_at(pos) {
+                    self.drag = Some(Drag::Divider(divider));
+                } else if let Some((group_idx, tab)) = self.tab_at(pos) {
+                    let group = &self.groups[group_idx];
+                    let panel = group.panels[tab];
+                    if group.active != tab {
+                        self.layout.activate(&self.panels[panel].params.key);
+                        self.submit_layout(ctx);
+                        ctx.request_layout();
+                    }
+                    self.drag = Some(Drag::Tab {
+                        panel,
+                        start: pos,
+                        moved: false,
+                        drop: None,
+                    });
+                } else {
+                    return;
+                }
+                ctx.capture_pointer();
+                ctx.set_handled();
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) if ctx.is_active() => {
+                let pos = ctx.local_position(current.position);
+                match &mut self.drag {
+                    Some(Drag::Divider(idx)) => {
+                        let Some(divider) = self.dividers.get(*idx) else {
+                            return;
+                        };
+                        let space = divider.span.size().get_coord(divider.axis) - DIVIDER_THICKNESS;
+                        if space <= 0. {
+                            return;
+                        }
+                        let start = divider.span.origin().get_coord(divider.axis);
+                        let min_ratio = (MIN_SIDE_LENGTH / space).min(0.5);
+                        let new_ratio =
+                            ((pos.get_coord(divider.axis) - start - DIVIDER_THICKNESS / 2.)
+                                / space)
+                                .clamp(min_ratio, 1. - min_ratio);
+                        if let Some(
+                            DockLayout::Row { ratio, .. } | DockLayout::Column { ratio, .. },
+                        ) = self.layout.node_mut(&divider.path)
+                        {
+                            *ratio = new_ratio;
+                            ctx.request_layout();
+                        }
+                    }
+                    Some(Drag::Tab {
+                        panel,
+                        start,
+                        moved,
+                        drop,
+                    }) => {
+                        if !*moved && (pos - *start).hypot() < DRAG_THRESHOLD {
+                            return;
+                        }
+                        *moved = true;
+                        *drop = drop_target(&self.groups, *panel, pos);
+                        ctx.request_post_paint();
+                    }
+                    None => {}
+                }
+            }
+            PointerEvent::Up(..) => match self.drag.take() {
+                Some(Drag::Divider(_)) => self.submit_layout(ctx),
+                Some(Drag::Tab {
+                    panel,
+                    drop: Some(drop),
+                    ..
+                }) => {
+                    let key = &self.panels[panel].params.key;
+                    let target = &self.panels[drop.target].params.key;
+                    if self.layout.dock(key, target, drop.position) {
+                        self.submit_layout(ctx);
+                        ctx.request_layout();
+                    }
+                    ctx.request_post_paint();
+                }
+                _ => {}
+            },
+            PointerEvent::Cancel(..) => {
+                if let Some(Drag::Divider(_)) = self.drag.take() {
+                    self.submit_layout(ctx);
+                }
+                ctx.request_post_paint();
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for panel in &mut self.panels {
+            ctx.register_child(&mut panel.tab);
+            ctx.register_child(&mut panel.content);
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        BarColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _axis: Axis,
+        len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
+        //       https://github.com/linebender/xilem/issues/1264
+        let scale = 1.0;
+
+        // We use all the available space or fall back to our const preferred size.
+        match len_req {
+            LenReq::FitContent(space) => space,
+            _ => DEFAULT_LENGTH.dp(scale),
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let mut groups = Vec::new();
+        let mut dividers = Vec::new();
+        arrange(
+            &self.layout,
+            size.to_rect(),
+            &self.panels,
+            &mut Vec::new(),
+            &mut groups,
+            &mut dividers,
+        );
+
+        // TODO: move set_stashed to a different layout pass when possible
+        let mut placed = vec![false; self.panels.len()];
+        for group in &mut groups {
+            let rect = group.rect;
+            let strip_bottom = (rect.y0 + TAB_HEIGHT).min(rect.y1);
+            // Tabs share the width of the group, and are as wide as their title needs.
+            let tab_width = rect.width() / group.panels.len().max(1) as f64;
+            let slot = Size::new(
+                (tab_width - 2. * TAB_PADDING).max(0.),
+                strip_bottom - rect.y0,
+            );
+            let mut x = rect.x0;
+            for (tab_idx, &idx) in group.panels.iter().enumerate() {
+                let panel = &mut self.panels[idx];
+                placed[idx] = true;
+
+                ctx.set_stashed(&mut panel.tab, false);
+                let title_size = ctx.compute_size(&mut panel.tab, SizeDef::fit(slot), slot.into());
+                ctx.run_layout(&mut panel.tab, title_size);
+                let title_origin = Point::new(
+                    x + TAB_PADDING,
+                    rect.y0 + (slot.height - title_size.height) * 0.5,
+                );
+                ctx.place_child(&mut panel.tab, title_origin);
+                let tab_right = x + title_size.width + 2. * TAB_PADDING;
+                group
+                    .tabs
+                    .push(Rect::new(x, rect.y0, tab_right, strip_bottom));
+                x = tab_right;
+
+                let shown = tab_idx == group.active;
+                ctx.set_stashed(&mut panel.content, !shown);
+                if shown {
+                    let content_size = Size::new(rect.width(), rect.y1 - strip_bottom);
+                    ctx.run_layout(&mut panel.content, content_size);
+                    ctx.place_child(&mut panel.content, Point::new(rect.x0, strip_bottom));
+                }
+            }
+        }
+        for (panel, placed) in self.panels.iter_mut().zip(placed) {
+            if !placed {
+                ctx.set_stashed(&mut panel.tab, true);
+                ctx.set_stashed(&mut panel.content, true);
+            }
+        }
+
+        self.groups = groups;
+        self.dividers = dividers;
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let bar_color = props.get::<BarColor>().0;
+        for group in &self.groups {
+            let rect = group.rect;
+            let strip = Rect::new(
+                rect.x0,
+                rect.y0,
+                rect.x1,
+                (rect.y0 + TAB_HEIGHT).min(rect.y1),
+            );
+            fill(scene, &strip, theme::ZYNC_800);
+            if let Some(tab) = group.tabs.get(group.active) {
+                fill(scene, tab, theme::ZYNC_900);
+                let underline = Rect::new(tab.x0, tab.y1 - TAB_UNDERLINE, tab.x1, tab.y1);
+                fill(scene, &underline, bar_color);
+            }
+        }
+        for divider in &self.dividers {
+            fill(scene, &divider.rect, theme::ZYNC_700);
+        }
+    }
+
+    fn post_paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        props: &PropertiesRef<'_>,
+        scene: &mut Scene,
+    ) {
+        // The drop highlight is painted above the panels.
+        if let Some(Drag::Tab {
+            drop: Some(drop), ..
+        }) = &self.drag
+        {
+            let color = props.get::<BarColor>().0;
+            fill(scene, &drop.preview, color.with_alpha(0.25));
+            stroke(scene, &drop.preview.inset(-1.), color, 2.);
+        }
+    }
+
+    fn get_cursor(&self, ctx: &QueryCtx<'_>, pos: Point) -> CursorIcon {
+        let axis = match &self.drag {
+            Some(Drag::Divider(idx)) => self.dividers.get(*idx).map(|divider| divider.axis),
+            Some(Drag::Tab { moved: true, .. }) => return CursorIcon::Grabbing,
+            _ => self
+                .divider_at(ctx.to_local(pos))
+                .map(|idx| self.dividers[idx].axis),
+        };
+        match axis {
+            Some(Axis::Horizontal) => CursorIcon::EwResize,
+            Some(Axis::Vertical) => CursorIcon::NsResize,
+            None => CursorIcon::Default,
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Group
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.panels
+            .iter()
+            .flat_map(|panel| [panel.tab.id(), panel.content.id()])
+            .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("DockArea", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+
+    fn two_panels() -> NewWidget<DockArea> {
+        DockArea::new(DockLayout::tabs(["files", "search"]))
+            .with(
+                Label::new("Files").with_auto_id(),
+                DockPanel::new("files", "Files"),
+            )
+            .with(
+                Label::new("Search").with_auto_id(),
+                DockPanel::new("search", "Search"),
+            )
+            .with_auto_id()
+    }
+
+    fn drag(harness: &mut TestHarness<DockArea>, from: Point, to: Point) {
+        harness.mouse_move(from);
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move(to);
+        harness.mouse_button_release(PointerButton::Primary);
+    }
+
+    #[test]
+    fn dock_and_remove_panels() {
+        let mut layout = DockLayout::tabs(["files", "search", "git"]);
+        assert!(layout.dock("git", "files", DockPosition::Right));
+        assert_eq!(
+            layout,
+            DockLayout::row(
+                0.5,
+                DockLayout::tabs(["files", "search"]),
+                DockLayout::tabs(["git"]),
+            )
+        );
+        assert!(!layout.dock("git", "git", DockPosition::Tab));
+        assert!(!layout.dock("git", "terminal", DockPosition::Tab));
+
+        assert!(layout.dock("search", "git", DockPosition::Tab));
+        assert_eq!(layout.panels(), ["files", "git", "search"]);
+        assert!(layout.remove("files"));
+        assert_eq!(
+            layout,
+            DockLayout::Tabs {
+                panels: vec!["git".into(), "search".into()],
+                active: 1
+            }
+        );
+        assert!(!layout.remove("files"));
+    }
+
+    #[test]
+    fn click_tab_shows_panel() {
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), two_panels(), Size::new(400., 300.));
+        let id = harness.root_id();
+
+        let tab = harness.root_widget().inner().groups[0].tabs[1];
+        harness.mouse_move(tab.center());
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+
+        let layout = DockLayout::Tabs {
+            panels: vec!["files".into(), "search".into()],
+            active: 1,
+        };
+        assert_eq!(
+            harness.pop_action::<DockLayoutChanged>(),
+            Some((DockLayoutChanged(layout), id))
+        );
+    }
+
+    #[test]
+    fn drag_tab_to_edge_docks_panel() {
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), two_panels(), Size::new(400., 300.));
+
+        // Drag the shown tab, so that pressing it doesn't change the layout.
+        let tab = harness.root_widget().inner().groups[0].tabs[0];
+        drag(&mut harness, tab.center(), Point::new(390., 150.));
+
+        let layout = DockLayout::row(
+            0.5,
+            DockLayout::tabs(["search"]),
+            DockLayout::tabs(["files"]),
+        );
+        assert_eq!(harness.root_widget().inner().layout(), &layout);
+        let (DockLayoutChanged(changed), _) = harness.pop_action::<DockLayoutChanged>().unwrap();
+        assert_eq!(changed, layout);
+    }
+
+    #[test]
+    fn drag_divider_resizes_groups() {
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), two_panels(), Size::new(400., 300.));
+        harness.edit_root_widget(|mut area| {
+            let layout = DockLayout::row(
+                0.5,
+                DockLayout::tabs(["files"]),
+                DockLayout::tabs(["search"]),
+            );
+            DockArea::set_layout(&mut area, layout);
+        });
+
+        let divider = harness.root_widget().inner().dividers[0].rect;
+        drag(
+            &mut harness,
+            divider.center(),
+            Point::new(100., divider.center().y),
+        );
+
+        let DockLayout::Row { ratio, .. } = harness.root_widget().inner().layout() else {
+            panic!("The layout should still be a row");
+        };
+        assert!((ratio - 98. / 396.).abs() < 1e-9);
+        assert!(harness.pop_action::<DockLayoutChanged>().is_some());
+    }
+}
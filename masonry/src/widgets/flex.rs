@@ -236,7 +236,7 @@ impl Flex {
 
     // --- MARK: Modified ---
     /// Builder-style method for setting the right to left direction of the app.
-    /// 
+    ///
     /// This will influence whether the flex row items will be
     /// position and placed from the right side to the left side.
     pub fn with_rtl(mut self, right_to_left: bool) -> Self {
@@ -761,7 +761,9 @@ impl Widget for Flex {
             // Basis is always resolved with a MaxContent fallback
             let main_auto = LenDef::MaxContent;
 
-            for child in LanguageAwareIter::iter(&mut self.children, self.direction, self.right_to_left) {
+            for child in
+                LanguageAwareIter::iter(&mut self.children, self.direction, self.right_to_left)
+            {
                 match child {
                     Child::Widget {
                         widget,
@@ -804,7 +806,9 @@ impl Widget for Flex {
             let mut flex_fraction: f64 = 0.;
             let main_auto = len_req.into();
 
-            for child in LanguageAwareIter::iter(&mut self.children, self.direction, self.right_to_left) {
+            for child in
+                LanguageAwareIter::iter(&mut self.children, self.direction, self.right_to_left)
+            {
                 let desired_flex_fraction = match child {
                     Child::Widget {
                         widget,
@@ -876,7 +880,9 @@ impl Widget for Flex {
             let flex_fraction = main_space.map(|mut main_space| {
                 // Sum flex factors and subtract bases from main space.
                 let mut flex_sum = 0.;
-                for child in LanguageAwareIter::iter(&mut self.children, self.direction, self.right_to_left) {
+                for child in
+                    LanguageAwareIter::iter(&mut self.children, self.direction, self.right_to_left)
+                {
                     match child {
                         Child::Widget {
                             flex,
@@ -906,7 +912,9 @@ impl Widget for Flex {
             });
 
             // Calculate the total space needed for all children
-            for child in LanguageAwareIter::iter(&mut self.children, self.direction, self.right_to_left) {
+            for child in
+                LanguageAwareIter::iter(&mut self.children, self.direction, self.right_to_left)
+            {
                 match child {
                     Child::Widget {
                         widget,
@@ -1008,7 +1016,8 @@ impl Widget for Flex {
 
         // Sum flex factors, resolve bases, subtract bases from main space,
         // and lay out inflexible widgets.
-        for child in LanguageAwareIter::iter(&mut self.children, self.direction, self.right_to_left) {
+        for child in LanguageAwareIter::iter(&mut self.children, self.direction, self.right_to_left)
+        {
             match child {
                 Child::Widget {
                     widget,
@@ -1073,7 +1082,8 @@ impl Widget for Flex {
         };
 
         // Offer the available space to flexible children
-        for child in LanguageAwareIter::iter(&mut self.children, self.direction, self.right_to_left) {
+        for child in LanguageAwareIter::iter(&mut self.children, self.direction, self.right_to_left)
+        {
             match child {
                 Child::Widget {
                     widget,
@@ -1119,7 +1129,8 @@ impl Widget for Flex {
         // Distribute free space and place children
         let mut main_offset = space_before;
         let mut previous_was_widget = false;
-        for child in LanguageAwareIter::iter(&mut self.children, self.direction, self.right_to_left) {
+        for child in LanguageAwareIter::iter(&mut self.children, self.direction, self.right_to_left)
+        {
             match child {
                 Child::Widget {
                     widget, alignment, ..
@@ -1215,16 +1226,16 @@ impl Widget for Flex {
 // --- MARK: Modified ---
 enum LanguageAwareIter<'a> {
     Forward(std::slice::IterMut<'a, Child>),
-    Reverse(std::iter::Rev<std::slice::IterMut<'a, Child>>)
+    Reverse(std::iter::Rev<std::slice::IterMut<'a, Child>>),
 }
 
 impl<'a> LanguageAwareIter<'a> {
     /// A method to provide an iterator for the children of flex.
-    /// 
+    ///
     /// When flex is a row and the app language is right to left,
     /// then we iterate in reverse to position the children from the right
     /// side to the left side.
-    fn iter(children: &'a mut Vec<Child>, direction: Axis, right_to_left: bool) -> Self {
+    fn iter(children: &'a mut [Child], direction: Axis, right_to_left: bool) -> Self {
         if direction == Axis::Horizontal && right_to_left {
             LanguageAwareIter::Reverse(children.iter_mut().rev())
         } else {
@@ -0,0 +1,362 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ChildrenIds, ComposeCtx, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PropertiesRef, RegisterCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Size, Vec2};
+use crate::layout::{LayoutSize, LenReq, SizeDef};
+
+/// A container whose leading rows and columns stay in view while its body scrolls.
+///
+/// The pane is laid out as a two by two grid:
+///
+/// - the [frozen rows](Self::with_frozen_rows) are above the body, and are as wide as it,
+/// - the [frozen columns](Self::with_frozen_columns) are left of the body, and are as tall as it,
+/// - the [corner](Self::with_corner) fills the top left cell.
+///
+/// The `FrozenPane` doesn't scroll by itself: it's meant to be the child of a
+/// [`Portal`](crate::widgets::Portal) scrolling along both axes.
+/// When the portal scrolls the pane past its top or left edge, the frozen rows and
+/// columns are shifted so that they stay at that edge.
+/// They still move with the body along the other axis, so the frozen rows stay aligned with
+/// the columns of the body, and the frozen columns with its rows.
+/// The corner doesn't move at all.
+///
+/// The frozen parts are painted over the body, so they should have an opaque
+/// [`Background`](crate::properties::Background).
+///
+/// This is the building block of tables with headers and pinned columns.
+pub struct FrozenPane {
+    body: WidgetPod<dyn Widget>,
+    frozen_rows: Option<WidgetPod<dyn Widget>>,
+    frozen_columns: Option<WidgetPod<dyn Widget>>,
+    corner: Option<WidgetPod<dyn Widget>>,
+    /// The size of the corner cell, i.e. the height of the frozen rows and the width of
+    /// the frozen columns.
+    corner_size: Size,
+}
+
+// --- MARK: BUILDERS
+impl FrozenPane {
+    /// Creates a pane with the given scrolling `body`, without any frozen rows or columns.
+    pub fn new(body: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            body: body.erased().to_pod(),
+            frozen_rows: None,
+            frozen_columns: None,
+            corner: None,
+            corner_size: Size::ZERO,
+        }
+    }
+
+    /// Builder-style method for setting the widget shown above the body, which stays in view
+    /// when scrolling vertically.
+    pub fn with_frozen_rows(mut self, rows: NewWidget<impl Widget + ?Sized>) -> Self {
+        self.frozen_rows = Some(rows.erased().to_pod());
+        self
+    }
+
+    /// Builder-style method for setting the widget shown left of the body, which stays in view
+    /// when scrolling horizontally.
+    pub fn with_frozen_columns(mut self, columns: NewWidget<impl Widget + ?Sized>) -> Self {
+        self.frozen_columns = Some(columns.erased().to_pod());
+        self
+    }
+
+    /// Builder-style method for setting the widget in the top left corner, which always
+    /// stays in view.
+    pub fn with_corner(mut self, corner: NewWidget<impl Widget + ?Sized>) -> Self {
+        self.corner = Some(corner.erased().to_pod());
+        self
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl FrozenPane {
+    /// Returns a mutable reference to the body.
+    pub fn body_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.body)
+    }
+
+    /// Returns a mutable reference to the frozen rows, if there are any.
+    pub fn frozen_rows_mut<'t>(
+        this: &'t mut WidgetMut<'_, Self>,
+    ) -> Option<WidgetMut<'t, dyn Widget>> {
+        let rows = this.widget.frozen_rows.as_mut()?;
+        Some(this.ctx.get_mut(rows))
+    }
+
+    /// Returns a mutable reference to the frozen columns, if there are any.
+    pub fn frozen_columns_mut<'t>(
+        this: &'t mut WidgetMut<'_, Self>,
+    ) -> Option<WidgetMut<'t, dyn Widget>> {
+        let columns = this.widget.frozen_columns.as_mut()?;
+        Some(this.ctx.get_mut(columns))
+    }
+
+    /// Returns a mutable reference to the corner, if there is one.
+    pub fn corner_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> Option<WidgetMut<'t, dyn Widget>> {
+        let corner = this.widget.corner.as_mut()?;
+        Some(this.ctx.get_mut(corner))
+    }
+
+    /// Replaces the body.
+    pub fn set_body(this: &mut WidgetMut<'_, Self>, body: NewWidget<impl Widget + ?Sized>) {
+        let old = std::mem::replace(&mut this.widget.body, body.erased().to_pod());
+        this.ctx.remove_child(old);
+        this.ctx.children_changed();
+    }
+
+    /// Replaces the frozen rows, or removes them if `rows` is `None`.
+    pub fn set_frozen_rows(
+        this: &mut WidgetMut<'_, Self>,
+        rows: Option<NewWidget<impl Widget + ?Sized>>,
+    ) {
+        if let Some(old) = this.widget.frozen_rows.take() {
+            this.ctx.remove_child(old);
+        }
+        this.widget.frozen_rows = rows.map(|rows| rows.erased().to_pod());
+        this.ctx.children_changed();
+    }
+
+    /// Replaces the frozen columns, or removes them if `columns` is `None`.
+    pub fn set_frozen_columns(
+        this: &mut WidgetMut<'_, Self>,
+        columns: Option<NewWidget<impl Widget + ?Sized>>,
+    ) {
+        if let Some(old) = this.widget.frozen_columns.take() {
+            this.ctx.remove_child(old);
+        }
+        this.widget.frozen_columns = columns.map(|columns| columns.erased().to_pod());
+        this.ctx.children_changed();
+    }
+
+    /// Replaces the corner, or removes it if `corner` is `None`.
+    pub fn set_corner(
+        this: &mut WidgetMut<'_, Self>,
+        corner: Option<NewWidget<impl Widget + ?Sized>>,
+    ) {
+        if let Some(old) = this.widget.corner.take() {
+            this.ctx.remove_child(old);
+        }
+        this.widget.corner = corner.map(|corner| corner.erased().to_pod());
+        this.ctx.children_changed();
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for FrozenPane {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.body);
+        for part in [
+            &mut self.frozen_rows,
+            &mut self.frozen_columns,
+            &mut self.corner,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            ctx.register_child(part);
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        let auto_length = len_req.into();
+        let context_size = LayoutSize::NONE;
+
+        // Along the horizontal axis, the frozen columns share the leading cell with the corner
+        // and the frozen rows share the trailing cell with the body, and vice versa.
+        let (leading, trailing) = match axis {
+            Axis::Horizontal => (&mut self.frozen_columns, &mut self.frozen_rows),
+            Axis::Vertical => (&mut self.frozen_rows, &mut self.frozen_columns),
+        };
+        let mut leading_length: f64 = 0.;
+        for part in [leading, &mut self.corner].into_iter().flatten() {
+            let length = ctx.compute_length(part, auto_length, context_size, axis, None);
+            leading_length = leading_length.max(length);
+        }
+        let mut trailing_length =
+            ctx.compute_length(&mut self.body, auto_length, context_size, axis, None);
+        if let Some(part) = trailing {
+            let length = ctx.compute_length(part, auto_length, context_size, axis, None);
+            trailing_length = trailing_length.max(length);
+        }
+
+        leading_length + trailing_length
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let auto_size = SizeDef::fit(size);
+        let context_size = size.into();
+
+        let mut corner_size = Size::ZERO;
+        if let Some(corner) = &mut self.corner {
+            corner_size = ctx.compute_size(corner, auto_size, context_size);
+        }
+        if let Some(rows) = &mut self.frozen_rows {
+            let rows_size = ctx.compute_size(rows, auto_size, context_size);
+            corner_size.height = corner_size.height.max(rows_size.height);
+        }
+        if let Some(columns) = &mut self.frozen_columns {
+            let columns_size = ctx.compute_size(columns, auto_size, context_size);
+            corner_size.width = corner_size.width.max(columns_size.width);
+        }
+        let corner_size = Size::new(
+            corner_size.width.min(size.width),
+            corner_size.height.min(size.height),
+        );
+        let body_size = size - corner_size;
+        self.corner_size = corner_size;
+
+        ctx.run_layout(&mut self.body, body_size);
+        ctx.place_child(
+            &mut self.body,
+            Point::new(corner_size.width, corner_size.height),
+        );
+        if let Some(rows) = &mut self.frozen_rows {
+            ctx.run_layout(rows, Size::new(body_size.width, corner_size.height));
+            ctx.place_child(rows, Point::new(corner_size.width, 0.));
+        }
+        if let Some(columns) = &mut self.frozen_columns {
+            ctx.run_layout(columns, Size::new(corner_size.width, body_size.height));
+            ctx.place_child(columns, Point::new(0., corner_size.height));
+        }
+        if let Some(corner) = &mut self.corner {
+            ctx.run_layout(corner, corner_size);
+            ctx.place_child(corner, Point::ORIGIN);
+        }
+    }
+
+    fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
+        // This is called whenever an ancestor scrolls, as that changes our transform.
+        let offset = match ctx.viewport() {
+            Some(viewport) => {
+                let visible = ctx
+                    .window_transform()
+                    .inverse()
+                    .transform_rect_bbox(viewport);
+                // The frozen parts can't be pushed past the end of the body.
+                let max_offset = ctx.content_box_size() - self.corner_size;
+                Vec2::new(
+                    visible.x0.clamp(0., max_offset.width.max(0.)),
+                    visible.y0.clamp(0., max_offset.height.max(0.)),
+                )
+            }
+            None => Vec2::ZERO,
+        };
+
+        if let Some(rows) = &mut self.frozen_rows {
+            ctx.set_child_scroll_translation(rows, Vec2::new(0., offset.y));
+        }
+        if let Some(columns) = &mut self.frozen_columns {
+            ctx.set_child_scroll_translation(columns, Vec2::new(offset.x, 0.));
+        }
+        if let Some(corner) = &mut self.corner {
+            ctx.set_child_scroll_translation(corner, offset);
+        }
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        // The frozen parts are painted over the body, and the corner over everything else.
+        let mut ids = ChildrenIds::from_slice(&[self.body.id()]);
+        ids.extend(
+            [&self.frozen_rows, &self.frozen_columns, &self.corner]
+                .into_iter()
+                .flatten()
+                .map(WidgetPod::id),
+        );
+        ids
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("FrozenPane", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::WidgetTag;
+    use crate::layout::AsUnit;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::{Portal, SizedBox};
+
+    #[test]
+    fn frozen_parts_stay_in_view() {
+        let portal_tag = WidgetTag::named("portal");
+        let body_tag = WidgetTag::named("body");
+        let rows_tag = WidgetTag::named("rows");
+        let columns_tag = WidgetTag::named("columns");
+        let corner_tag = WidgetTag::named("corner");
+
+        let pane = FrozenPane::new(NewWidget::new_with_tag(
+            SizedBox::empty().size(400.px(), 400.px()),
+            body_tag,
+        ))
+        .with_frozen_rows(NewWidget::new_with_tag(
+            SizedBox::empty().height(20.px()),
+            rows_tag,
+        ))
+        .with_frozen_columns(NewWidget::new_with_tag(
+            SizedBox::empty().width(30.px()),
+            columns_tag,
+        ))
+        .with_corner(NewWidget::new_with_tag(SizedBox::empty(), corner_tag));
+        let portal = NewWidget::new_with_tag(Portal::new(pane.with_auto_id()), portal_tag);
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), portal, Size::new(100., 100.));
+        let origin = |harness: &TestHarness<_>, tag: WidgetTag<SizedBox>| {
+            harness.get_widget(tag).ctx().window_origin()
+        };
+
+        assert_eq!(origin(&harness, body_tag), Point::new(30., 20.));
+        assert_eq!(origin(&harness, rows_tag), Point::new(30., 0.));
+        assert_eq!(origin(&harness, columns_tag), Point::new(0., 20.));
+        assert_eq!(
+            harness.get_widget(rows_tag).ctx().border_box_size(),
+            Size::new(400., 20.)
+        );
+
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::set_viewport_pos(&mut portal, Point::new(50., 70.));
+        });
+
+        // The body scrolls along both axes, and each frozen part along one axis.
+        assert_eq!(origin(&harness, body_tag), Point::new(-20., -50.));
+        assert_eq!(origin(&harness, rows_tag), Point::new(-20., 0.));
+        assert_eq!(origin(&harness, columns_tag), Point::new(0., -50.));
+        assert_eq!(origin(&harness, corner_tag), Point::ORIGIN);
+    }
+}
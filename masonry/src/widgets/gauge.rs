@@ -0,0 +1,463 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+use std::f64::consts::PI;
+use std::ops::RangeInclusive;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ChildrenIds, HasProperty, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PropertiesMut, PropertiesRef, RegisterCtx, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+    WidgetPod,
+};
+use crate::kurbo::{Affine, Arc, Axis, Cap, Circle, Line, Point, Size, Stroke, Vec2};
+use crate::layout::{LenReq, SizeDef};
+use crate::peniko::Color;
+use crate::properties::{BarColor, ContentColor};
+use crate::util::{Easing, fill};
+
+/// How long the gauge takes to move to a new value.
+const VALUE_DURATION_NANOS: u64 = 400_000_000;
+/// The diameter of a gauge, unless it's given another size.
+const DEFAULT_DIAMETER: f64 = 120.;
+/// The width of the arc of a [`Gauge`], unless [another one](Gauge::with_thickness) is chosen.
+pub const DEFAULT_GAUGE_THICKNESS: f64 = 10.;
+/// The start and sweep angles of a [`Gauge`], unless [others](Gauge::with_angles) are chosen.
+///
+/// The arc is open at the bottom, starting at the bottom left and sweeping
+/// three quarters of a turn.
+pub const DEFAULT_GAUGE_ANGLES: (f64, f64) = (0.75 * PI, 1.5 * PI);
+/// The width of the needle.
+const NEEDLE_WIDTH: f64 = 3.;
+/// The opacity of the color bands along the track.
+const BAND_ALPHA: f32 = 0.35;
+
+/// A range of values of a [`Gauge`] shown in its own color, such as a warning zone.
+///
+/// A band starts at its `from` value, and ends at the start of the next band or at the
+/// end of the gauge.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GaugeBand {
+    /// The value the band starts at.
+    pub from: f64,
+    /// The color of the band, and of the value arc while the value is in the band.
+    pub color: Color,
+}
+
+/// An arc showing a value within a range, such as a speedometer on a dashboard,
+/// or the progress of a task when it's a [full circle](Self::radial).
+///
+/// The arc starts at the [start angle](Self::with_angles), and its track sweeps over
+/// the sweep angle, clockwise for positive angles.
+/// The track is painted with the [`ContentColor`] property, and the part of the track up
+/// to the value is filled with the [`BarColor`] property.
+/// [Bands](GaugeBand) color ranges of values along the track, and the value arc takes the
+/// color of the band the value is in.
+/// A needle can also point at the value.
+///
+/// When the value changes, the arc and needle move smoothly to the new value.
+/// A [label](Self::with_label), such as the value as text, can be shown in the center.
+pub struct Gauge {
+    min: f64,
+    max: f64,
+    value: f64,
+    /// The value currently painted, which differs from `value` while animating.
+    shown_value: f64,
+    value_anim: Option<ValueAnimation>,
+    start_angle: f64,
+    sweep_angle: f64,
+    thickness: f64,
+    needle: bool,
+    /// The bands, sorted by their start values.
+    bands: Vec<GaugeBand>,
+    label: Option<WidgetPod<dyn Widget>>,
+}
+
+/// A move of a [`Gauge`] to a new value.
+struct ValueAnimation {
+    from: f64,
+    to: f64,
+    elapsed_nanos: u64,
+}
+
+// --- MARK: BUILDERS
+impl Gauge {
+    /// Creates a gauge showing `value` within `range`, with the [default angles](DEFAULT_GAUGE_ANGLES).
+    ///
+    /// The value is clamped to the range.
+    pub fn new(value: f64, range: RangeInclusive<f64>) -> Self {
+        let (min, max) = (*range.start(), *range.end());
+        let value = clamp_value(value, min, max);
+        Self {
+            min,
+            max,
+            value,
+            shown_value: value,
+            value_anim: None,
+            start_angle: DEFAULT_GAUGE_ANGLES.0,
+            sweep_angle: DEFAULT_GAUGE_ANGLES.1,
+            thickness: DEFAULT_GAUGE_THICKNESS,
+            needle: false,
+            bands: Vec::new(),
+            label: None,
+        }
+    }
+
+    /// Creates a circular progress indicator, showing `progress` from 0 to 1 as a ring which
+    /// fills clockwise from the top.
+    pub fn radial(progress: f64) -> Self {
+        Self::new(progress, 0.0..=1.0).with_angles(-0.5 * PI, 2. * PI)
+    }
+
+    /// Returns `self` with the arc starting at `start_angle` and sweeping over `sweep_angle`.
+    ///
+    /// Angles are in radians, clockwise from the right.
+    pub fn with_angles(mut self, start_angle: f64, sweep_angle: f64) -> Self {
+        self.start_angle = start_angle;
+        self.sweep_angle = sweep_angle;
+        self
+    }
+
+    /// Returns `self` with an arc `thickness` logical pixels wide.
+    pub fn with_thickness(mut self, thickness: f64) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Returns `self` with a needle pointing at the value, if `needle` is `true`.
+    pub fn with_needle(mut self, needle: bool) -> Self {
+        self.needle = needle;
+        self
+    }
+
+    /// Returns `self` with a band of `color` starting at the value `from`.
+    pub fn with_band(mut self, from: f64, color: Color) -> Self {
+        self.bands.push(GaugeBand { from, color });
+        self.bands.sort_by(|a, b| a.from.total_cmp(&b.from));
+        self
+    }
+
+    /// Returns `self` with `label` shown in the center.
+    pub fn with_label(mut self, label: NewWidget<impl Widget + ?Sized>) -> Self {
+        self.label = Some(label.erased().to_pod());
+        self
+    }
+
+    /// Returns the value, which is where the gauge is moving to if it's animating.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the range of values.
+    pub fn range(&self) -> RangeInclusive<f64> {
+        self.min..=self.max
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Gauge {
+    /// Sets the value, to which the gauge moves smoothly.
+    ///
+    /// The value is clamped to the range.
+    pub fn set_value(this: &mut WidgetMut<'_, Self>, value: f64) {
+        let value = clamp_value(value, this.widget.min, this.widget.max);
+        if this.widget.value == value {
+            return;
+        }
+        this.widget.value = value;
+        this.widget.value_anim = Some(ValueAnimation {
+            from: this.widget.shown_value,
+            to: value,
+            elapsed_nanos: 0,
+        });
+        this.ctx.request_anim_frame();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets the range of values, and clamps the value to it.
+    pub fn set_range(this: &mut WidgetMut<'_, Self>, range: RangeInclusive<f64>) {
+        this.widget.min = *range.start();
+        this.widget.max = *range.end();
+        let value = clamp_value(this.widget.value, this.widget.min, this.widget.max);
+        this.widget.value = value;
+        this.widget.shown_value = value;
+        this.widget.value_anim = None;
+        this.ctx.request_paint_only();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets the angle the arc starts at, and the angle it sweeps over.
+    ///
+    /// The runtime equivalent of [`with_angles`](Self::with_angles).
+    pub fn set_angles(this: &mut WidgetMut<'_, Self>, start_angle: f64, sweep_angle: f64) {
+        this.widget.start_angle = start_angle;
+        this.widget.sweep_angle = sweep_angle;
+        this.ctx.request_paint_only();
+    }
+
+    /// Sets the width of the arc, in logical pixels.
+    pub fn set_thickness(this: &mut WidgetMut<'_, Self>, thickness: f64) {
+        this.widget.thickness = thickness;
+        this.ctx.request_layout();
+    }
+
+    /// Shows or hides the needle.
+    pub fn set_needle(this: &mut WidgetMut<'_, Self>, needle: bool) {
+        this.widget.needle = needle;
+        this.ctx.request_paint_only();
+    }
+
+    /// Replaces the bands.
+    pub fn set_bands(this: &mut WidgetMut<'_, Self>, bands: impl IntoIterator<Item = GaugeBand>) {
+        this.widget.bands = bands.into_iter().collect();
+        this.widget.bands.sort_by(|a, b| a.from.total_cmp(&b.from));
+        this.ctx.request_paint_only();
+    }
+
+    /// Replaces the label in the center, or removes it if `label` is `None`.
+    pub fn set_label(
+        this: &mut WidgetMut<'_, Self>,
+        label: Option<NewWidget<impl Widget + ?Sized>>,
+    ) {
+        if let Some(old) = this.widget.label.take() {
+            this.ctx.remove_child(old);
+        }
+        this.widget.label = label.map(|label| label.erased().to_pod());
+        this.ctx.children_changed();
+    }
+
+    /// Returns a mutable reference to the label in the center, if there is one.
+    pub fn label_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> Option<WidgetMut<'t, dyn Widget>> {
+        let label = this.widget.label.as_mut()?;
+        Some(this.ctx.get_mut(label))
+    }
+}
+
+// --- MARK: HELPERS
+impl Gauge {
+    /// Returns the angle of the arc at `value`.
+    fn angle_at(&self, value: f64) -> f64 {
+        let fraction = if self.max > self.min {
+            ((value - self.min) / (self.max - self.min)).clamp(0., 1.)
+        } else {
+            0.
+        };
+        self.start_angle + self.sweep_angle * fraction
+    }
+
+    /// Returns the color of the value arc at `value`.
+    fn color_at(&self, value: f64, bar_color: Color) -> Color {
+        self.bands
+            .iter()
+            .rev()
+            .find(|band| band.from <= value)
+            .map_or(bar_color, |band| band.color)
+    }
+
+    /// Returns the arc between the values `from` and `to`.
+    fn arc(&self, center: Point, radius: f64, from: f64, to: f64) -> Arc {
+        let start = self.angle_at(from);
+        let sweep = self.angle_at(to) - start;
+        Arc::new(center, Vec2::new(radius, radius), start, sweep, 0.)
+    }
+}
+
+/// Clamps `value` to the range from `min` to `max`, or returns `min` for an invalid range.
+fn clamp_value(value: f64, min: f64, max: f64) -> f64 {
+    if max > min {
+        value.clamp(min, max)
+    } else {
+        min
+    }
+}
+
+impl HasProperty<BarColor> for Gauge {}
+impl HasProperty<ContentColor> for Gauge {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Gauge {
+    type Action = NoAction;
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let Some(anim) = &mut self.value_anim else {
+            return;
+        };
+        anim.elapsed_nanos = anim.elapsed_nanos.saturating_add(interval);
+        if ctx.prefers_reduced_motion() || anim.elapsed_nanos >= VALUE_DURATION_NANOS {
+            self.shown_value = anim.to;
+            self.value_anim = None;
+        } else {
+            let progress = anim.elapsed_nanos as f64 / VALUE_DURATION_NANOS as f64;
+            let eased = Easing::EaseInOut.apply(progress);
+            self.shown_value = anim.from + (anim.to - anim.from) * eased;
+            ctx.request_anim_frame();
+        }
+        ctx.request_paint_only();
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::DisabledChanged(_) = event {
+            ctx.request_paint_only();
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        if let Some(label) = &mut self.label {
+            ctx.register_child(label);
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        BarColor::prop_changed(ctx, property_type);
+        ContentColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _axis: Axis,
+        _len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        DEFAULT_DIAMETER
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        if let Some(label) = &mut self.label {
+            // The label fits in the square inside the track.
+            let inner_diameter = (size.width.min(size.height) - 2. * self.thickness).max(0.);
+            let side = inner_diameter * std::f64::consts::FRAC_1_SQRT_2;
+            let space = Size::new(side, side);
+            let label_size = ctx.compute_size(label, SizeDef::fit(space), space.into());
+            ctx.run_layout(label, label_size);
+            let origin = ((size - label_size).to_vec2() * 0.5).to_point();
+            ctx.place_child(label, origin);
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let size = ctx.content_box_size();
+        let center = Point::new(size.width / 2., size.height / 2.);
+        let radius = (size.width.min(size.height) - self.thickness) / 2.;
+        if radius <= 0. {
+            return;
+        }
+        let bar_color = props.get::<BarColor>().0;
+        let content_color = props.get::<ContentColor>().color;
+        let track_stroke = Stroke::new(self.thickness).with_caps(Cap::Butt);
+
+        let track = self.arc(center, radius, self.min, self.max);
+        scene.stroke(&track_stroke, Affine::IDENTITY, content_color, None, &track);
+        for (idx, band) in self.bands.iter().enumerate() {
+            let end = self.bands.get(idx + 1).map_or(self.max, |next| next.from);
+            let arc = self.arc(center, radius, band.from, end);
+            let color = band.color.multiply_alpha(BAND_ALPHA);
+            scene.stroke(&track_stroke, Affine::IDENTITY, color, None, &arc);
+        }
+
+        let value_color = self.color_at(self.shown_value, bar_color);
+        if self.shown_value > self.min {
+            let arc = self.arc(center, radius, self.min, self.shown_value);
+            scene.stroke(&track_stroke, Affine::IDENTITY, value_color, None, &arc);
+        }
+
+        if self.needle {
+            let tip = center + Vec2::from_angle(self.angle_at(self.shown_value)) * radius;
+            let needle = Line::new(center, tip);
+            let needle_stroke = Stroke::new(NEEDLE_WIDTH).with_caps(Cap::Round);
+            scene.stroke(&needle_stroke, Affine::IDENTITY, value_color, None, &needle);
+            fill(scene, &Circle::new(center, NEEDLE_WIDTH * 1.5), value_color);
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Meter
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_numeric_value(self.value);
+        node.set_min_numeric_value(self.min);
+        node.set_max_numeric_value(self.max);
+        node.set_read_only();
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.label.iter().map(|label| label.id()).collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Gauge", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::WidgetTag;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::Label;
+
+    #[test]
+    fn value_animates() {
+        let widget = Gauge::new(0., 0.0..=100.).with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(120., 120.));
+
+        harness.edit_root_widget(|mut gauge| Gauge::set_value(&mut gauge, 150.));
+        assert_eq!(harness.root_widget().value(), 100.);
+        harness.animate_ms(100);
+        let shown = harness.root_widget().shown_value;
+        assert!(
+            shown > 0. && shown < 100.,
+            "gauge should be moving, at {shown}"
+        );
+
+        harness.animate_ms(400);
+        assert_eq!(harness.root_widget().shown_value, 100.);
+    }
+
+    #[test]
+    fn bands_color_value() {
+        let warning = Color::from_rgb8(0xff, 0xaa, 0x00);
+        let danger = Color::from_rgb8(0xff, 0x00, 0x00);
+        let gauge = Gauge::new(50., 0.0..=100.)
+            .with_band(90., danger)
+            .with_band(70., warning);
+        assert_eq!(gauge.color_at(50., Color::WHITE), Color::WHITE);
+        assert_eq!(gauge.color_at(75., Color::WHITE), warning);
+        assert_eq!(gauge.color_at(95., Color::WHITE), danger);
+    }
+
+    #[test]
+    fn label_is_centered() {
+        let label_tag = WidgetTag::named("label");
+        let gauge =
+            Gauge::radial(0.5).with_label(NewWidget::new_with_tag(Label::new("50%"), label_tag));
+        let harness = TestHarness::create_with_size(
+            test_property_set(),
+            gauge.with_auto_id(),
+            Size::new(120., 120.),
+        );
+
+        let label = harness.get_widget(label_tag);
+        let center = label.ctx().window_origin() + label.ctx().border_box_size().to_vec2() * 0.5;
+        assert_eq!(center, Point::new(60., 60.));
+    }
+}
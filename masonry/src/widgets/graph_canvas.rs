@@ -0,0 +1,1208 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+use std::collections::BTreeSet;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{Affine, Circle, CubicBez, ParamCurveNearest, Point, Rect, Size, Vec2};
+use vello::peniko::Fill;
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::pointer::{PointerButton, PointerGesture, PointerGestureEvent};
+use crate::core::{
+    AccessCtx, ChildrenIds, CollectionWidget, ComposeCtx, EventCtx, HasProperty, LayoutCtx,
+    MeasureCtx, NewWidget, PaintCtx, PointerButtonEvent, PointerEvent, PointerScrollEvent,
+    PointerUpdate, PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, UpdateCtx, Widget,
+    WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::Axis;
+use crate::layout::{LayoutSize, LenReq, Length, SizeDef};
+use crate::properties::{ContentColor, SelectionColor};
+use crate::util::{fill, stroke};
+
+/// The preferred size of the square canvas.
+const DEFAULT_LENGTH: Length = Length::const_px(400.);
+/// The radius of the port circles, at a zoom of 1.
+const PORT_RADIUS: f64 = 5.;
+/// How close to a port the pointer must be to start or finish a connection.
+const PORT_HIT_RADIUS: f64 = 9.;
+/// The width of edges, at a zoom of 1.
+const EDGE_WIDTH: f64 = 2.;
+/// How close to an edge the pointer must be to select it.
+const EDGE_HIT_DISTANCE: f64 = 6.;
+/// The width of the outline of selected nodes and of the selection box.
+const SELECTION_WIDTH: f64 = 2.;
+/// The smallest zoom level users can zoom out to.
+const MIN_ZOOM: f64 = 0.25;
+/// The largest zoom level users can zoom in to.
+const MAX_ZOOM: f64 = 4.;
+/// How much the zoom changes per pixel of scrolling while holding the zoom modifier.
+const ZOOM_PER_PIXEL: f64 = 0.002;
+
+/// Identifies a port of a node in a [`GraphCanvas`].
+///
+/// Whether this is an input or an output port depends on where it is used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PortId {
+    /// The index of the node.
+    pub node: usize,
+    /// The index of the port among the node's inputs or outputs.
+    pub port: usize,
+}
+
+impl PortId {
+    /// Creates a new `PortId`.
+    pub fn new(node: usize, port: usize) -> Self {
+        Self { node, port }
+    }
+}
+
+/// A connection from an output port to an input port in a [`GraphCanvas`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GraphEdge {
+    /// The output port the edge starts at.
+    pub from: PortId,
+    /// The input port the edge ends at.
+    pub to: PortId,
+}
+
+impl GraphEdge {
+    /// Creates a new edge from the output port `from` to the input port `to`.
+    pub fn new(from: PortId, to: PortId) -> Self {
+        Self { from, to }
+    }
+}
+
+/// Parameters for a node in a [`GraphCanvas`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GraphNodeParams {
+    /// The position of the node's top-left corner, in graph coordinates.
+    pub position: Point,
+    /// The number of input ports, placed along the node's left edge.
+    pub inputs: usize,
+    /// The number of output ports, placed along the node's right edge.
+    pub outputs: usize,
+}
+
+impl GraphNodeParams {
+    /// Creates new parameters.
+    pub fn new(position: Point, inputs: usize, outputs: usize) -> Self {
+        Self {
+            position,
+            inputs,
+            outputs,
+        }
+    }
+}
+
+impl From<Point> for GraphNodeParams {
+    fn from(position: Point) -> Self {
+        Self {
+            position,
+            ..Default::default()
+        }
+    }
+}
+
+/// An edit requested by the user in a [`GraphCanvas`].
+///
+/// Apart from moving nodes and selecting, the canvas doesn't apply these edits itself:
+/// the app is expected to update its graph, and then the canvas' nodes and edges.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GraphAction {
+    /// The user dragged from an output port to an input port of another node.
+    Connect(GraphEdge),
+    /// The user finished dragging nodes to new positions, given in graph coordinates.
+    NodesMoved(Vec<(usize, Point)>),
+    /// The user asked to delete the selected nodes and edges.
+    ///
+    /// Edges connected to deleted nodes aren't included unless they were selected.
+    Delete {
+        /// The indices of the nodes to delete, in increasing order.
+        nodes: Vec<usize>,
+        /// The indices of the edges to delete, in increasing order.
+        edges: Vec<usize>,
+    },
+    /// The selection changed.
+    SelectionChanged {
+        /// The indices of the selected nodes, in increasing order.
+        nodes: Vec<usize>,
+        /// The indices of the selected edges, in increasing order.
+        edges: Vec<usize>,
+    },
+}
+
+struct GraphNode {
+    child: WidgetPod<dyn Widget>,
+    params: GraphNodeParams,
+    /// The border-box size of the child, at a zoom of 1.
+    size: Size,
+    /// The zoom last applied to the child's transform.
+    applied_zoom: f64,
+}
+
+enum Interaction {
+    MovingNodes {
+        start: Point,
+        origins: Vec<(usize, Point)>,
+        moved: bool,
+    },
+    Connecting {
+        from: PortId,
+        pointer: Point,
+    },
+    BoxSelecting {
+        start: Point,
+        pointer: Point,
+    },
+    Panning {
+        last: Point,
+    },
+}
+
+/// A canvas of nodes connected by edges, for building node-based editors.
+///
+/// Each node is an arbitrary child widget, laid out at its preferred size at a position
+/// in graph coordinates.
+/// Nodes can have input ports along their left edge and output ports along their right edge,
+/// and [edges](GraphEdge) are drawn as bezier curves from an output port to an input port.
+///
+/// Users can:
+/// - Drag nodes around with the primary button.
+/// - Drag from an output port to an input port to [connect](GraphAction::Connect) them.
+/// - Click nodes and edges to select them (holding <kbd>Shift</kbd> to add to the selection),
+///   or drag on the background to select nodes with a box.
+/// - Press <kbd>Delete</kbd> or <kbd>Backspace</kbd> to [delete](GraphAction::Delete) the selection,
+///   and <kbd>Escape</kbd> to clear it.
+/// - Pan by dragging with the secondary or middle button, or by scrolling.
+/// - Zoom by scrolling while holding <kbd>Ctrl</kbd> (or <kbd>Cmd</kbd> on macOS), or by pinching.
+///
+/// Edits are emitted as [`GraphAction`]s.
+/// Interactive widgets inside nodes (such as buttons) keep working, as the canvas ignores
+/// presses which they have captured.
+///
+/// You can customize the color of edges and ports with the [`ContentColor`] property,
+/// and the color of the selection with the [`SelectionColor`] property.
+pub struct GraphCanvas {
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+    pan: Vec2,
+    zoom: f64,
+    selected_nodes: BTreeSet<usize>,
+    selected_edges: BTreeSet<usize>,
+    interaction: Option<Interaction>,
+}
+
+// --- MARK: BUILDERS
+impl GraphCanvas {
+    /// Creates an empty canvas.
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            pan: Vec2::ZERO,
+            zoom: 1.,
+            selected_nodes: BTreeSet::new(),
+            selected_edges: BTreeSet::new(),
+            interaction: None,
+        }
+    }
+
+    /// Builder-style method to add a node.
+    pub fn with(
+        mut self,
+        node: NewWidget<impl Widget + ?Sized>,
+        params: impl Into<GraphNodeParams>,
+    ) -> Self {
+        self.nodes.push(GraphNode::new(node, params.into()));
+        self
+    }
+
+    /// Builder-style method to add an edge.
+    pub fn with_edge(mut self, edge: GraphEdge) -> Self {
+        self.edges.push(edge);
+        self
+    }
+
+    /// Builder-style method to set the pan offset and the zoom.
+    ///
+    /// See [`set_viewport`](Self::set_viewport) for details.
+    pub fn with_viewport(mut self, pan: Vec2, zoom: f64) -> Self {
+        self.pan = pan;
+        self.zoom = zoom.clamp(MIN_ZOOM, MAX_ZOOM);
+        self
+    }
+}
+
+impl Default for GraphCanvas {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GraphNode {
+    fn new(child: NewWidget<impl Widget + ?Sized>, params: GraphNodeParams) -> Self {
+        Self {
+            child: child.erased().to_pod(),
+            params,
+            size: Size::ZERO,
+            applied_zoom: 1.,
+        }
+    }
+}
+
+// --- MARK: METHODS
+impl GraphCanvas {
+    /// Returns the edges.
+    pub fn edges(&self) -> &[GraphEdge] {
+        &self.edges
+    }
+
+    /// Returns the parameters of the node at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn node_params(&self, idx: usize) -> GraphNodeParams {
+        self.nodes[idx].params
+    }
+
+    /// Returns the indices of the selected nodes, in increasing order.
+    pub fn selected_nodes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.selected_nodes.iter().copied()
+    }
+
+    /// Returns the indices of the selected edges, in increasing order.
+    pub fn selected_edges(&self) -> impl Iterator<Item = usize> + '_ {
+        self.selected_edges.iter().copied()
+    }
+
+    /// Returns the pan offset, which is where the graph's origin is shown in the canvas.
+    pub fn pan(&self) -> Vec2 {
+        self.pan
+    }
+
+    /// Returns the zoom level.
+    pub fn zoom(&self) -> f64 {
+        self.zoom
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl GraphCanvas {
+    /// Replaces all edges.
+    ///
+    /// This clears the edge selection.
+    pub fn set_edges(this: &mut WidgetMut<'_, Self>, edges: Vec<GraphEdge>) {
+        if this.widget.edges == edges {
+            return;
+        }
+        this.widget.edges = edges;
+        this.widget.selected_edges.clear();
+        this.ctx.request_paint_only();
+        this.ctx.request_post_paint();
+    }
+
+    /// Sets the pan offset, which is where the graph's origin is shown in the canvas,
+    /// and the zoom level.
+    ///
+    /// The zoom is clamped between 0.25 and 4.
+    pub fn set_viewport(this: &mut WidgetMut<'_, Self>, pan: Vec2, zoom: f64) {
+        this.widget.pan = pan;
+        this.widget.zoom = zoom.clamp(MIN_ZOOM, MAX_ZOOM);
+        this.ctx.request_layout();
+    }
+
+    /// Clears the selection, without emitting an action.
+    pub fn clear_selection(this: &mut WidgetMut<'_, Self>) {
+        this.widget.selected_nodes.clear();
+        this.widget.selected_edges.clear();
+        this.ctx.request_paint_only();
+        this.ctx.request_post_paint();
+    }
+}
+
+// --- MARK: COLLECTIONWIDGET
+impl CollectionWidget<GraphNodeParams> for GraphCanvas {
+    /// Returns the number of nodes.
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if there are no nodes.
+    fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns a mutable reference to the node widget at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn get_mut<'t>(this: &'t mut WidgetMut<'_, Self>, idx: usize) -> WidgetMut<'t, dyn Widget> {
+        let child = &mut this.widget.nodes[idx].child;
+        this.ctx.get_mut(child)
+    }
+
+    /// Appends a node.
+    fn add(
+        this: &mut WidgetMut<'_, Self>,
+        child: NewWidget<impl Widget + ?Sized>,
+        params: impl Into<GraphNodeParams>,
+    ) {
+        this.widget.nodes.push(GraphNode::new(child, params.into()));
+        this.ctx.children_changed();
+    }
+
+    /// Inserts a node at the given index.
+    ///
+    /// Edges and the selection are adjusted to keep referring to the same nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the number of nodes.
+    fn insert(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        child: NewWidget<impl Widget + ?Sized>,
+        params: impl Into<GraphNodeParams>,
+    ) {
+        this.widget
+            .nodes
+            .insert(idx, GraphNode::new(child, params.into()));
+        let shift = |node: usize| if node >= idx { node + 1 } else { node };
+        for edge in &mut this.widget.edges {
+            edge.from.node = shift(edge.from.node);
+            edge.to.node = shift(edge.to.node);
+        }
+        this.widget.selected_nodes = this
+            .widget
+            .selected_nodes
+            .iter()
+            .map(|&node| shift(node))
+            .collect();
+        this.ctx.children_changed();
+    }
+
+    /// Replaces the node at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn set(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        child: NewWidget<impl Widget + ?Sized>,
+        params: impl Into<GraphNodeParams>,
+    ) {
+        let node = std::mem::replace(
+            &mut this.widget.nodes[idx],
+            GraphNode::new(child, params.into()),
+        );
+        this.ctx.remove_child(node.child);
+    }
+
+    /// Sets the parameters of the node at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn set_params(this: &mut WidgetMut<'_, Self>, idx: usize, params: impl Into<GraphNodeParams>) {
+        this.widget.nodes[idx].params = params.into();
+        this.ctx.request_layout();
+    }
+
+    /// Swaps the index of two nodes.
+    ///
+    /// Edges and the selection are adjusted to keep referring to the same nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    fn swap(this: &mut WidgetMut<'_, Self>, a: usize, b: usize) {
+        this.widget.nodes.swap(a, b);
+        let swap = |node: usize| match node {
+            _ if node == a => b,
+            _ if node == b => a,
+            _ => node,
+        };
+        for edge in &mut this.widget.edges {
+            edge.from.node = swap(edge.from.node);
+            edge.to.node = swap(edge.to.node);
+        }
+        this.widget.selected_nodes = this
+            .widget
+            .selected_nodes
+            .iter()
+            .map(|&node| swap(node))
+            .collect();
+        this.ctx.children_changed();
+    }
+
+    /// Removes the node at the given index.
+    ///
+    /// Edges connected to the node are removed, and other edges and the selection are
+    /// adjusted to keep referring to the same nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn remove(this: &mut WidgetMut<'_, Self>, idx: usize) {
+        let node = this.widget.nodes.remove(idx);
+        this.ctx.remove_child(node.child);
+
+        let shift = |node: usize| if node > idx { node - 1 } else { node };
+        this.widget
+            .edges
+            .retain(|edge| edge.from.node != idx && edge.to.node != idx);
+        for edge in &mut this.widget.edges {
+            edge.from.node = shift(edge.from.node);
+            edge.to.node = shift(edge.to.node);
+        }
+        this.widget.selected_nodes = this
+            .widget
+            .selected_nodes
+            .iter()
+            .filter(|&&node| node != idx)
+            .map(|&node| shift(node))
+            .collect();
+        this.widget.selected_edges.clear();
+        this.widget.interaction = None;
+    }
+
+    /// Removes all nodes and edges.
+    fn clear(this: &mut WidgetMut<'_, Self>) {
+        for node in this.widget.nodes.drain(..) {
+            this.ctx.remove_child(node.child);
+        }
+        this.widget.edges.clear();
+        this.widget.selected_nodes.clear();
+        this.widget.selected_edges.clear();
+        this.widget.interaction = None;
+    }
+}
+
+// --- MARK: HELPERS
+impl GraphCanvas {
+    /// Converts a point from graph coordinates to the canvas' content-box coordinates.
+    fn to_canvas(&self, point: Point) -> Point {
+        (point.to_vec2() * self.zoom + self.offset()).to_point()
+    }
+
+    /// Converts a point from the canvas' content-box coordinates to graph coordinates.
+    fn to_graph(&self, point: Point) -> Point {
+        ((point.to_vec2() - self.offset()) / self.zoom).to_point()
+    }
+
+    /// Returns the pan offset, rounded like the scroll translation of the nodes.
+    fn offset(&self) -> Vec2 {
+        self.pan.round()
+    }
+
+    /// Returns the position of an input port, in graph coordinates.
+    fn input_position(&self, port: PortId) -> Option<Point> {
+        let node = self.nodes.get(port.node)?;
+        (port.port < node.params.inputs).then(|| {
+            let y = node.size.height * (port.port + 1) as f64 / (node.params.inputs + 1) as f64;
+            node.params.position + Vec2::new(0., y)
+        })
+    }
+
+    /// Returns the position of an output port, in graph coordinates.
+    fn output_position(&self, port: PortId) -> Option<Point> {
+        let node = self.nodes.get(port.node)?;
+        (port.port < node.params.outputs).then(|| {
+            let y = node.size.height * (port.port + 1) as f64 / (node.params.outputs + 1) as f64;
+            node.params.position + Vec2::new(node.size.width, y)
+        })
+    }
+
+    /// Returns the port whose center is closest to `point` and within reach,
+    /// given a function returning the port positions.
+    fn port_at(
+        &self,
+        point: Point,
+        count: impl Fn(&GraphNode) -> usize,
+        position: impl Fn(PortId) -> Option<Point>,
+    ) -> Option<PortId> {
+        let reach = PORT_HIT_RADIUS * self.zoom.max(1.);
+        self.nodes
+            .iter()
+            .enumerate()
+            .flat_map(|(node_idx, node)| {
+                (0..count(node)).map(move |port| PortId::new(node_idx, port))
+            })
+            .filter_map(|port| {
+                let distance = self.to_canvas(position(port)?).distance(point);
+                (distance <= reach).then_some((port, distance))
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(port, _)| port)
+    }
+
+    /// Returns the topmost node under `point`, in content-box coordinates.
+    fn node_at(&self, point: Point) -> Option<usize> {
+        let point = self.to_graph(point);
+        self.nodes.iter().rposition(|node| {
+            Rect::from_origin_size(node.params.position, node.size).contains(point)
+        })
+    }
+
+    /// Returns the curve of an edge, in content-box coordinates.
+    fn edge_curve(&self, from: Point, to: Point) -> CubicBez {
+        let from = self.to_canvas(from);
+        let to = self.to_canvas(to);
+        let handle = ((to.x - from.x).abs() / 2.).max(40. * self.zoom);
+        CubicBez::new(
+            from,
+            from + Vec2::new(handle, 0.),
+            to - Vec2::new(handle, 0.),
+            to,
+        )
+    }
+
+    fn edge_curves(&self) -> impl Iterator<Item = (usize, CubicBez)> + '_ {
+        self.edges.iter().enumerate().filter_map(|(idx, edge)| {
+            let from = self.output_position(edge.from)?;
+            let to = self.input_position(edge.to)?;
+            Some((idx, self.edge_curve(from, to)))
+        })
+    }
+
+    /// Returns the edge closest to `point` and within reach.
+    fn edge_at(&self, point: Point) -> Option<usize> {
+        self.edge_curves()
+            .map(|(idx, curve)| (idx, curve.nearest(point, 0.1).distance_sq))
+            .filter(|(_, distance_sq)| *distance_sq <= EDGE_HIT_DISTANCE * EDGE_HIT_DISTANCE)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(idx, _)| idx)
+    }
+
+    fn has_selection(&self) -> bool {
+        !self.selected_nodes.is_empty() || !self.selected_edges.is_empty()
+    }
+
+    fn submit_selection(&self, ctx: &mut EventCtx<'_>) {
+        ctx.submit_action::<GraphAction>(GraphAction::SelectionChanged {
+            nodes: self.selected_nodes.iter().copied().collect(),
+            edges: self.selected_edges.iter().copied().collect(),
+        });
+        ctx.request_paint_only();
+        ctx.request_post_paint();
+    }
+
+    /// Zooms by `factor`, keeping `anchor` in place.
+    fn zoom_around(&mut self, ctx: &mut EventCtx<'_>, anchor: Point, factor: f64) {
+        let zoom = (self.zoom * factor).clamp(MIN_ZOOM, MAX_ZOOM);
+        if zoom == self.zoom {
+            return;
+        }
+        let anchor_in_graph = self.to_graph(anchor);
+        self.zoom = zoom;
+        self.pan = anchor.to_vec2() - anchor_in_graph.to_vec2() * zoom;
+        ctx.request_layout();
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl HasProperty<ContentColor> for GraphCanvas {}
+impl HasProperty<SelectionColor> for GraphCanvas {}
+
+impl Widget for GraphCanvas {
+    type Action = GraphAction;
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(PointerButtonEvent { button, state, .. }) => {
+                // Let interactive widgets inside nodes handle their own presses.
+                if ctx.pointer_capture_target_id().is_some() {
+                    return;
+                }
+                let point = ctx.local_position(state.position);
+                ctx.request_focus();
+                ctx.capture_pointer();
+
+                if !matches!(button, None | Some(PointerButton::Primary)) {
+                    self.interaction = Some(Interaction::Panning { last: point });
+                    return;
+                }
+                let extend = state.modifiers.shift();
+                if let Some(from) = self.port_at(
+                    point,
+                    |node| node.params.outputs,
+                    |port| self.output_position(port),
+                ) {
+                    self.interaction = Some(Interaction::Connecting {
+                        from,
+                        pointer: point,
+                    });
+                } else if let Some(node) = self.node_at(point) {
+                    if !self.selected_nodes.contains(&node) {
+                        if !extend {
+                            self.selected_nodes.clear();
+                            self.selected_edges.clear();
+                        }
+                        self.selected_nodes.insert(node);
+                        self.submit_selection(ctx);
+                    } else if extend {
+                        self.selected_nodes.remove(&node);
+                        self.submit_selection(ctx);
+                        return;
+                    }
+                    let origins = self
+                        .selected_nodes
+                        .iter()
+                        .map(|&idx| (idx, self.nodes[idx].params.position))
+                        .collect();
+                    self.interaction = Some(Interaction::MovingNodes {
+                        start: point,
+                        origins,
+                        moved: false,
+                    });
+                } else if let Some(edge) = self.edge_at(point) {
+                    if !extend {
+                        self.selected_nodes.clear();
+                        self.selected_edges.clear();
+                    }
+                    if !self.selected_edges.remove(&edge) {
+                        self.selected_edges.insert(edge);
+                    }
+                    self.submit_selection(ctx);
+                } else {
+                    if !extend && self.has_selection() {
+                        self.selected_nodes.clear();
+                        self.selected_edges.clear();
+                        self.submit_selection(ctx);
+                    }
+                    self.interaction = Some(Interaction::BoxSelecting {
+                        start: point,
+                        pointer: point,
+                    });
+                }
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) if ctx.is_active() => {
+                let point = ctx.local_position(current.position);
+                match &mut self.interaction {
+                    Some(Interaction::MovingNodes {
+                        start,
+                        origins,
+                        moved,
+                    }) => {
+                        let delta = (point - *start) / self.zoom;
+                        for &(idx, origin) in origins.iter() {
+                            self.nodes[idx].params.position = origin + delta;
+                        }
+                        *moved = true;
+                        ctx.request_layout();
+                    }
+                    Some(
+                        Interaction::Connecting { pointer, .. }
+                        | Interaction::BoxSelecting { pointer, .. },
+                    ) => {
+                        *pointer = point;
+                        ctx.request_paint_only();
+                        ctx.request_post_paint();
+                    }
+                    Some(Interaction::Panning { last }) => {
+                        self.pan += point - *last;
+                        *last = point;
+                        ctx.request_compose();
+                        ctx.request_paint_only();
+                        ctx.request_post_paint();
+                    }
+                    None => {}
+                }
+            }
+            PointerEvent::Up(PointerButtonEvent { state, .. }) => {
+                let point = ctx.local_position(state.position);
+                match self.interaction.take() {
+                    Some(Interaction::MovingNodes {
+                        origins,
+                        moved: true,
+                        ..
+                    }) => {
+                        let positions = origins
+                            .into_iter()
+                            .map(|(idx, _)| (idx, self.nodes[idx].params.position))
+                            .collect();
+                        ctx.submit_action::<GraphAction>(GraphAction::NodesMoved(positions));
+                    }
+                    Some(Interaction::Connecting { from, .. }) => {
+                        let to = self.port_at(
+                            point,
+                            |node| node.params.inputs,
+                            |port| self.input_position(port),
+                        );
+                        if let Some(to) = to
+                            && to.node != from.node
+                        {
+                            ctx.submit_action::<GraphAction>(GraphAction::Connect(GraphEdge::new(
+                                from, to,
+                            )));
+                        }
+                        ctx.request_paint_only();
+                        ctx.request_post_paint();
+                    }
+                    Some(Interaction::BoxSelecting { start, .. }) => {
+                        let area = Rect::from_points(self.to_graph(start), self.to_graph(point));
+                        let mut changed = false;
+                        for (idx, node) in self.nodes.iter().enumerate() {
+                            let rect = Rect::from_origin_size(node.params.position, node.size);
+                            if area.overlaps(rect) {
+                                changed |= self.selected_nodes.insert(idx);
+                            }
+                        }
+                        if changed {
+                            self.submit_selection(ctx);
+                        }
+                        ctx.request_paint_only();
+                        ctx.request_post_paint();
+                    }
+                    _ => {}
+                }
+            }
+            PointerEvent::Cancel(..) => {
+                if let Some(Interaction::MovingNodes { origins, .. }) = self.interaction.take() {
+                    for (idx, origin) in origins {
+                        self.nodes[idx].params.position = origin;
+                    }
+                    ctx.request_layout();
+                }
+                ctx.request_paint_only();
+                ctx.request_post_paint();
+            }
+            PointerEvent::Scroll(PointerScrollEvent { delta, state, .. }) => {
+                let delta = ctx.scroll_config().logical_delta(
+                    *delta,
+                    ctx.content_box_size(),
+                    ctx.get_scale_factor(),
+                    state.modifiers,
+                );
+
+                let zoom_modifier = if cfg!(target_os = "macos") {
+                    state.modifiers.meta()
+                } else {
+                    state.modifiers.ctrl()
+                };
+                if zoom_modifier {
+                    let anchor = ctx.local_position(state.position);
+                    self.zoom_around(ctx, anchor, (delta.y * ZOOM_PER_PIXEL).exp());
+                } else {
+                    // A positive delta scrolls towards the top-left, which moves the graph
+                    // towards the bottom-right.
+                    self.pan += delta;
+                    ctx.request_compose();
+                    ctx.request_paint_only();
+                    ctx.request_post_paint();
+                }
+                ctx.set_handled();
+            }
+            PointerEvent::Gesture(PointerGestureEvent {
+                gesture: PointerGesture::Pinch(delta),
+                state,
+                ..
+            }) => {
+                let anchor = ctx.local_position(state.position);
+                self.zoom_around(ctx, anchor, 1. + f64::from(*delta));
+                ctx.set_handled();
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if !ctx.is_focus_target() {
+            return;
+        }
+        if let TextEvent::Keyboard(key_event) = event
+            && key_event.state.is_down()
+        {
+            match &key_event.key {
+                Key::Named(NamedKey::Delete | NamedKey::Backspace) if self.has_selection() => {
+                    ctx.submit_action::<GraphAction>(GraphAction::Delete {
+                        nodes: self.selected_nodes.iter().copied().collect(),
+                        edges: self.selected_edges.iter().copied().collect(),
+                    });
+                }
+                Key::Named(NamedKey::Escape) if self.has_selection() => {
+                    self.selected_nodes.clear();
+                    self.selected_edges.clear();
+                    self.submit_selection(ctx);
+                }
+                _ => return,
+            }
+            ctx.set_handled();
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for node in &mut self.nodes {
+            ctx.register_child(&mut node.child);
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if property_type == TypeId::of::<ContentColor>()
+            || property_type == TypeId::of::<SelectionColor>()
+        {
+            ctx.request_paint_only();
+            ctx.request_post_paint();
+        }
+    }
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _axis: Axis,
+        len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
+        //       https://github.com/linebender/xilem/issues/1264
+        let scale = 1.0;
+
+        // We use all the available space or fall back to our const preferred size.
+        match len_req {
+            LenReq::FitContent(space) => space,
+            _ => DEFAULT_LENGTH.dp(scale),
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let zoom = self.zoom;
+        for node in &mut self.nodes {
+            // Nodes are laid out at their preferred size, and then scaled by the zoom.
+            let child_size = ctx.compute_size(&mut node.child, SizeDef::MAX, LayoutSize::NONE);
+            ctx.run_layout(&mut node.child, child_size);
+            node.size = child_size;
+            let origin = (node.params.position.to_vec2() * zoom).to_point();
+            ctx.place_child(&mut node.child, origin);
+
+            if node.applied_zoom != zoom {
+                node.applied_zoom = zoom;
+                ctx.mutate_later(&mut node.child, move |mut child| {
+                    child.set_transform(Affine::scale(zoom));
+                });
+            }
+        }
+        ctx.set_clip_path(size.to_rect());
+    }
+
+    fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
+        let offset = self.offset();
+        for node in &mut self.nodes {
+            ctx.set_child_scroll_translation(&mut node.child, offset);
+        }
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let color = props.get::<ContentColor>().color;
+        let selection_color = props.get::<SelectionColor>().color;
+        let width = EDGE_WIDTH * self.zoom;
+
+        // Edges are painted below the nodes.
+        for (idx, curve) in self.edge_curves() {
+            let color = if self.selected_edges.contains(&idx) {
+                selection_color
+            } else {
+                color
+            };
+            stroke(scene, &curve, color, width);
+        }
+        if let Some(Interaction::Connecting { from, pointer }) = &self.interaction
+            && let Some(from) = self.output_position(*from)
+        {
+            let curve = self.edge_curve(from, self.to_graph(*pointer));
+            stroke(scene, &curve, color.multiply_alpha(0.6), width);
+        }
+    }
+
+    fn post_paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let color = props.get::<ContentColor>().color;
+        let selection_color = props.get::<SelectionColor>().color;
+        let size = ctx.content_box_size();
+
+        // Ports and the selection are painted above the nodes, inside our clip.
+        scene.push_clip_layer(Fill::NonZero, Affine::IDENTITY, &size.to_rect());
+        for &idx in &self.selected_nodes {
+            let node = &self.nodes[idx];
+            let rect = Rect::from_points(
+                self.to_canvas(node.params.position),
+                self.to_canvas(node.params.position + node.size.to_vec2()),
+            );
+            stroke(
+                scene,
+                &rect.inflate(SELECTION_WIDTH, SELECTION_WIDTH),
+                selection_color,
+                SELECTION_WIDTH,
+            );
+        }
+        let radius = PORT_RADIUS * self.zoom;
+        for (node_idx, node) in self.nodes.iter().enumerate() {
+            let inputs = (0..node.params.inputs)
+                .filter_map(|port| self.input_position(PortId::new(node_idx, port)));
+            let outputs = (0..node.params.outputs)
+                .filter_map(|port| self.output_position(PortId::new(node_idx, port)));
+            for position in inputs.chain(outputs) {
+                fill(scene, &Circle::new(self.to_canvas(position), radius), color);
+            }
+        }
+        if let Some(Interaction::BoxSelecting { start, pointer }) = &self.interaction {
+            let rect = Rect::from_points(*start, *pointer);
+            fill(scene, &rect, selection_color.multiply_alpha(0.2));
+            stroke(scene, &rect, selection_color, 1.);
+        }
+        scene.pop_layer();
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Canvas
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_clips_children();
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.nodes.iter().map(|node| node.child.id()).collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("GraphCanvas", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use dpi::PhysicalPosition;
+
+    use super::*;
+    use crate::core::pointer::PointerState;
+    use crate::core::{Modifiers, WidgetOptions, WidgetTag};
+    use crate::layout::AsUnit;
+    use crate::palette;
+    use crate::peniko::Color;
+    use crate::properties::{Background, Dimensions};
+    use crate::testing::{PRIMARY_MOUSE, TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::SizedBox;
+
+    fn node(color: Color) -> NewWidget<SizedBox> {
+        SizedBox::empty().with_props((
+            Background::Color(color),
+            Dimensions::fixed(80.px(), 40.px()),
+        ))
+    }
+
+    /// Two nodes, with an output port at (100, 40) and an input port at (200, 120).
+    fn two_nodes(tag: WidgetTag<GraphCanvas>) -> NewWidget<GraphCanvas> {
+        let canvas = GraphCanvas::new()
+            .with(
+                node(palette::css::ORANGE),
+                GraphNodeParams::new(Point::new(20., 20.), 0, 1),
+            )
+            .with(
+                node(palette::css::TEAL),
+                GraphNodeParams::new(Point::new(200., 100.), 1, 0),
+            );
+        NewWidget::new_with(
+            canvas,
+            Some(tag),
+            WidgetOptions::default(),
+            Dimensions::STRETCH,
+        )
+    }
+
+    #[test]
+    fn connect_and_move_nodes() {
+        let tag = WidgetTag::named("canvas");
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            two_nodes(tag),
+            Size::new(400., 300.),
+        );
+
+        harness.mouse_move((101., 41.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move((150., 80.));
+        harness.mouse_move((199., 119.));
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(
+            harness
+                .pop_action::<GraphAction>()
+                .map(|(action, _)| action),
+            Some(GraphAction::Connect(GraphEdge::new(
+                PortId::new(0, 0),
+                PortId::new(1, 0)
+            )))
+        );
+        assert_eq!(harness.pop_action::<GraphAction>(), None);
+
+        harness.mouse_move((50., 30.));
+        harness.mouse_button_press(PointerButton::Primary);
+        assert_eq!(
+            harness
+                .pop_action::<GraphAction>()
+                .map(|(action, _)| action),
+            Some(GraphAction::SelectionChanged {
+                nodes: vec![0],
+                edges: vec![],
+            })
+        );
+        harness.mouse_move((70., 50.));
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(
+            harness
+                .pop_action::<GraphAction>()
+                .map(|(action, _)| action),
+            Some(GraphAction::NodesMoved(vec![(0, Point::new(40., 40.))]))
+        );
+        let canvas = harness.get_widget(tag);
+        assert_eq!(canvas.inner().node_params(0).position, Point::new(40., 40.));
+        let node = canvas.children()[0];
+        assert_eq!(node.ctx().window_origin(), Point::new(40., 40.));
+    }
+
+    #[test]
+    fn box_select_and_delete() {
+        let tag = WidgetTag::named("canvas");
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            two_nodes(tag),
+            Size::new(400., 300.),
+        );
+        harness.edit_widget(tag, |mut canvas| {
+            GraphCanvas::set_edges(
+                &mut canvas,
+                vec![GraphEdge::new(PortId::new(0, 0), PortId::new(1, 0))],
+            );
+        });
+
+        harness.mouse_move((10., 10.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move((250., 120.));
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(
+            harness
+                .pop_action::<GraphAction>()
+                .map(|(action, _)| action),
+            Some(GraphAction::SelectionChanged {
+                nodes: vec![0, 1],
+                edges: vec![],
+            })
+        );
+
+        // Shift-clicking the edge adds it to the selection.
+        harness.mouse_move((150., 80.));
+        harness.process_pointer_event(PointerEvent::Down(PointerButtonEvent {
+            pointer: PRIMARY_MOUSE,
+            button: Some(PointerButton::Primary),
+            state: PointerState {
+                position: PhysicalPosition::new(150., 80.),
+                modifiers: Modifiers::SHIFT,
+                ..Default::default()
+            },
+        }));
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(
+            harness
+                .pop_action::<GraphAction>()
+                .map(|(action, _)| action),
+            Some(GraphAction::SelectionChanged {
+                nodes: vec![0, 1],
+                edges: vec![0],
+            })
+        );
+
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::Delete)));
+        assert_eq!(
+            harness
+                .pop_action::<GraphAction>()
+                .map(|(action, _)| action),
+            Some(GraphAction::Delete {
+                nodes: vec![0, 1],
+                edges: vec![0],
+            })
+        );
+
+        // Removing a node removes its edges.
+        harness.edit_widget(tag, |mut canvas| GraphCanvas::remove(&mut canvas, 0));
+        let canvas = harness.get_widget(tag);
+        assert!(canvas.inner().edges().is_empty());
+        assert_eq!(canvas.inner().selected_nodes().collect::<Vec<_>>(), [0]);
+    }
+
+    #[test]
+    fn pan_and_zoom() {
+        let tag = WidgetTag::named("canvas");
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            two_nodes(tag),
+            Size::new(400., 300.),
+        );
+
+        // Scrolling pans the graph.
+        harness.mouse_move((300., 250.));
+        harness.mouse_wheel(Vec2::new(-10., -30.));
+        let canvas = harness.get_widget(tag);
+        assert_eq!(canvas.inner().pan(), Vec2::new(-10., -30.));
+        assert_eq!(
+            canvas.children()[1].ctx().window_origin(),
+            Point::new(190., 70.)
+        );
+
+        harness.edit_widget(tag, |mut canvas| {
+            GraphCanvas::set_viewport(&mut canvas, Vec2::new(10., 0.), 2.);
+        });
+        let canvas = harness.get_widget(tag);
+        assert_eq!(
+            canvas.children()[1].ctx().window_origin(),
+            Point::new(410., 200.)
+        );
+    }
+
+    #[test]
+    fn graph_screenshot() {
+        let tag = WidgetTag::named("canvas");
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            two_nodes(tag),
+            Size::new(320., 180.),
+        );
+        harness.edit_widget(tag, |mut canvas| {
+            GraphCanvas::set_edges(
+                &mut canvas,
+                vec![GraphEdge::new(PortId::new(0, 0), PortId::new(1, 0))],
+            );
+        });
+        harness.mouse_move((50., 30.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+
+        assert_render_snapshot!(harness, "graph_canvas_selected_node");
+    }
+}
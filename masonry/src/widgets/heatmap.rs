@@ -0,0 +1,516 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+use std::ops::RangeInclusive;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ChildrenIds, EventCtx, HasProperty, LayerType, LayoutCtx, MeasureCtx, NewWidget,
+    PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PointerUpdate, PropertiesMut,
+    PropertiesRef, RegisterCtx, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+};
+use crate::kurbo::{Axis, Point, Rect, Size, Vec2};
+use crate::layers::Tooltip;
+use crate::layout::LenReq;
+use crate::peniko::Color;
+use crate::properties::ContentColor;
+use crate::theme;
+use crate::util::{fill, stroke};
+use crate::widgets::Label;
+
+/// The width and height of the cells of a [`Heatmap`], unless [another size](Heatmap::with_cell_size)
+/// is chosen.
+pub const DEFAULT_HEATMAP_CELL_SIZE: f64 = 12.;
+/// The space between the cells of a [`Heatmap`], unless [another one](Heatmap::with_gap) is chosen.
+pub const DEFAULT_HEATMAP_GAP: f64 = 3.;
+/// The radius of the corners of each cell.
+const CELL_RADIUS: f64 = 2.;
+/// How long the pointer rests on a cell before its value is shown in a tooltip.
+const TOOLTIP_DELAY_NANOS: u64 = 500_000_000;
+/// The space between a cell and its tooltip.
+const TOOLTIP_GAP: f64 = 4.;
+
+/// The colors of the values of a [`Heatmap`], from the lowest value to the highest.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorScale {
+    colors: Vec<Color>,
+    stepped: bool,
+}
+
+impl ColorScale {
+    /// Creates a scale which blends smoothly between `colors`, which are evenly spread
+    /// from the lowest value to the highest.
+    pub fn gradient(colors: impl IntoIterator<Item = Color>) -> Self {
+        Self {
+            colors: colors.into_iter().collect(),
+            stepped: false,
+        }
+    }
+
+    /// Creates a scale which splits the values into as many even steps as there are `colors`,
+    /// such as the few shades of a contribution graph.
+    pub fn steps(colors: impl IntoIterator<Item = Color>) -> Self {
+        Self {
+            colors: colors.into_iter().collect(),
+            stepped: true,
+        }
+    }
+
+    /// Returns the color at `t`, from 0 for the lowest value to 1 for the highest.
+    pub fn color_at(&self, t: f64) -> Color {
+        let t = if t.is_nan() { 0. } else { t.clamp(0., 1.) };
+        match self.colors.as_slice() {
+            [] => Color::TRANSPARENT,
+            [color] => *color,
+            colors if self.stepped => {
+                let idx = (t * colors.len() as f64) as usize;
+                colors[idx.min(colors.len() - 1)]
+            }
+            colors => {
+                let pos = t * (colors.len() - 1) as f64;
+                let idx = (pos as usize).min(colors.len() - 2);
+                colors[idx].lerp_rect(colors[idx + 1], (pos - idx as f64) as f32)
+            }
+        }
+    }
+}
+
+impl Default for ColorScale {
+    fn default() -> Self {
+        Self::gradient([theme::ZYNC_800, theme::ACCENT_COLOR])
+    }
+}
+
+/// The action type emitted by [`Heatmap`] when the user clicks a cell with a value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct HeatmapCellClicked {
+    /// The row of the cell.
+    pub row: usize,
+    /// The column of the cell.
+    pub column: usize,
+}
+
+/// A grid of small cells colored by their values, such as a contribution graph or
+/// the activity of each hour of each day of the week.
+///
+/// The values are given by row, and missing or NaN values leave their cells empty.
+/// The colors come from a [`ColorScale`], which maps the range of the values, from its
+/// lowest to its highest, to colors.
+/// The range is that of the values, unless [another one](Self::with_range) is chosen, such
+/// as to compare several heatmaps.
+///
+/// Resting the pointer on a cell shows its value in a tooltip, and the hovered cell is
+/// outlined with the [`ContentColor`] property.
+/// Emits [`HeatmapCellClicked`] when the user clicks a cell with a value.
+pub struct Heatmap {
+    values: Vec<Vec<f64>>,
+    range: Option<(f64, f64)>,
+    scale: ColorScale,
+    cell_size: f64,
+    gap: f64,
+    /// The `(row, column)` of the cell under the pointer.
+    hovered: Option<(usize, usize)>,
+    /// How long the pointer has rested on the hovered cell, until its tooltip is shown.
+    hover_nanos: Option<u64>,
+    /// The `(row, column)` of the cell the pointer was pressed on.
+    pressed: Option<(usize, usize)>,
+}
+
+// --- MARK: BUILDERS
+impl Heatmap {
+    /// Creates a heatmap of `values`, given by row.
+    pub fn new(values: Vec<Vec<f64>>) -> Self {
+        Self {
+            values,
+            range: None,
+            scale: ColorScale::default(),
+            cell_size: DEFAULT_HEATMAP_CELL_SIZE,
+            gap: DEFAULT_HEATMAP_GAP,
+            hovered: None,
+            hover_nanos: None,
+            pressed: None,
+        }
+    }
+
+    /// Returns `self` with the colors of the scale spread over `range` instead of the range
+    /// of the values.
+    pub fn with_range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.range = Some((*range.start(), *range.end()));
+        self
+    }
+
+    /// Returns `self` with the given color scale.
+    pub fn with_scale(mut self, scale: ColorScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Returns `self` with cells `cell_size` logical pixels wide and high.
+    pub fn with_cell_size(mut self, cell_size: f64) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Returns `self` with `gap` logical pixels between cells.
+    pub fn with_gap(mut self, gap: f64) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Returns the value of the cell at `row` and `column`, if it has one.
+    pub fn value(&self, row: usize, column: usize) -> Option<f64> {
+        let value = *self.values.get(row)?.get(column)?;
+        (!value.is_nan()).then_some(value)
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the number of columns, which is the length of the longest row.
+    pub fn columns(&self) -> usize {
+        self.values.iter().map(Vec::len).max().unwrap_or(0)
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Heatmap {
+    /// Replaces the values, given by row.
+    pub fn set_values(this: &mut WidgetMut<'_, Self>, values: Vec<Vec<f64>>) {
+        this.widget.values = values;
+        this.widget.hovered = None;
+        this.widget.hover_nanos = None;
+        this.widget.pressed = None;
+        this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Spreads the colors of the scale over `range`, or over the range of the values if it's `None`.
+    pub fn set_range(this: &mut WidgetMut<'_, Self>, range: Option<RangeInclusive<f64>>) {
+        this.widget.range = range.map(|range| (*range.start(), *range.end()));
+        this.ctx.request_paint_only();
+    }
+
+    /// Sets the color scale.
+    pub fn set_scale(this: &mut WidgetMut<'_, Self>, scale: ColorScale) {
+        this.widget.scale = scale;
+        this.ctx.request_paint_only();
+    }
+
+    /// Sets the width and height of the cells, in logical pixels.
+    pub fn set_cell_size(this: &mut WidgetMut<'_, Self>, cell_size: f64) {
+        this.widget.cell_size = cell_size;
+        this.ctx.request_layout();
+    }
+
+    /// Sets the space between cells, in logical pixels.
+    pub fn set_gap(this: &mut WidgetMut<'_, Self>, gap: f64) {
+        this.widget.gap = gap;
+        this.ctx.request_layout();
+    }
+}
+
+// --- MARK: HELPERS
+impl Heatmap {
+    /// Returns the lowest and highest values the colors are spread over.
+    fn value_range(&self) -> (f64, f64) {
+        if let Some(range) = self.range {
+            return range;
+        }
+        self.values
+            .iter()
+            .flatten()
+            .filter(|value| !value.is_nan())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+                (min.min(value), max.max(value))
+            })
+    }
+
+    /// Returns the color of `value`.
+    fn color_of(&self, value: f64) -> Color {
+        let (min, max) = self.value_range();
+        let t = if max > min {
+            (value - min) / (max - min)
+        } else {
+            1.
+        };
+        self.scale.color_at(t)
+    }
+
+    fn cell_rect(&self, row: usize, column: usize) -> Rect {
+        let step = self.cell_size + self.gap;
+        let origin = Point::new(column as f64 * step, row as f64 * step);
+        Rect::from_origin_size(origin, Size::new(self.cell_size, self.cell_size))
+    }
+
+    /// Returns the `(row, column)` of the cell with a value at `pos`, if any.
+    fn cell_at(&self, pos: Point) -> Option<(usize, usize)> {
+        let step = self.cell_size + self.gap;
+        if pos.x < 0. || pos.y < 0. || step <= 0. {
+            return None;
+        }
+        let (row, column) = ((pos.y / step) as usize, (pos.x / step) as usize);
+        let in_cell = self.cell_rect(row, column).contains(pos);
+        (in_cell && self.value(row, column).is_some()).then_some((row, column))
+    }
+
+    fn set_hovered(&mut self, ctx: &mut EventCtx<'_>, hovered: Option<(usize, usize)>) {
+        if hovered == self.hovered {
+            return;
+        }
+        self.hovered = hovered;
+        self.hover_nanos = hovered.map(|_| 0);
+        if hovered.is_some() {
+            ctx.request_anim_frame();
+        }
+        ctx.request_paint_only();
+    }
+}
+
+impl HasProperty<ContentColor> for Heatmap {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Heatmap {
+    type Action = HeatmapCellClicked;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        match event {
+            PointerEvent::Move(PointerUpdate { current, .. }) => {
+                let cell = self.cell_at(ctx.local_position(current.position));
+                self.set_hovered(ctx, cell);
+            }
+            PointerEvent::Down(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                self.pressed = self.cell_at(ctx.local_position(state.position));
+                // Pressing a cell means the user found it, so its tooltip isn't needed.
+                self.hover_nanos = None;
+                ctx.capture_pointer();
+            }
+            PointerEvent::Up(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                state,
+                ..
+            }) if ctx.is_active() => {
+                let released = self.cell_at(ctx.local_position(state.position));
+                if let Some((row, column)) = self.pressed.take()
+                    && released == Some((row, column))
+                {
+                    ctx.submit_action::<HeatmapCellClicked>(HeatmapCellClicked { row, column });
+                }
+            }
+            PointerEvent::Cancel(..) => {
+                self.pressed = None;
+            }
+            _ => (),
+        }
+    }
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let Some(nanos) = self.hover_nanos else {
+            return;
+        };
+        let nanos = nanos.saturating_add(interval);
+        if nanos < TOOLTIP_DELAY_NANOS {
+            self.hover_nanos = Some(nanos);
+            ctx.request_anim_frame();
+            return;
+        }
+        self.hover_nanos = None;
+        let Some((row, column)) = self.hovered else {
+            return;
+        };
+        let Some(value) = self.value(row, column) else {
+            return;
+        };
+        // The tooltip is shown below the cell, which the pointer would otherwise cover.
+        let cell = self.cell_rect(row, column);
+        let position = ctx.window_origin()
+            + ctx.border_box_translation()
+            + Vec2::new(cell.x0, cell.y1 + TOOLTIP_GAP);
+        let text = value.to_string();
+        let tooltip = Tooltip::new(NewWidget::new(Label::new(text.clone())));
+        ctx.create_layer(LayerType::Tooltip(text), NewWidget::new(tooltip), position);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::HoveredChanged(false) => {
+                self.hovered = None;
+                self.hover_nanos = None;
+                ctx.request_paint_only();
+            }
+            Update::DisabledChanged(_) => {
+                ctx.request_paint_only();
+            }
+            _ => (),
+        }
+    }
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        ContentColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        let count = match axis {
+            Axis::Horizontal => self.columns(),
+            Axis::Vertical => self.rows(),
+        };
+        if count == 0 {
+            return 0.;
+        }
+        count as f64 * self.cell_size + (count - 1) as f64 * self.gap
+    }
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, _size: Size) {}
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        for (row, values) in self.values.iter().enumerate() {
+            for (column, &value) in values.iter().enumerate() {
+                if value.is_nan() {
+                    continue;
+                }
+                let cell = self.cell_rect(row, column).to_rounded_rect(CELL_RADIUS);
+                fill(scene, &cell, self.color_of(value));
+            }
+        }
+
+        if let Some((row, column)) = self.hovered
+            && !ctx.is_disabled()
+        {
+            let outline = self
+                .cell_rect(row, column)
+                .inflate(1., 1.)
+                .to_rounded_rect(CELL_RADIUS + 1.);
+            stroke(scene, &outline, props.get::<ContentColor>().color, 1.);
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Grid
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_row_count(self.rows());
+        node.set_column_count(self.columns());
+        if let Some((row, column)) = self.hovered
+            && let Some(value) = self.value(row, column)
+        {
+            node.set_value(value.to_string());
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::new()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Heatmap", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+
+    /// Returns the center of the cell at `row` and `column`, with the default sizes.
+    fn cell_center(row: usize, column: usize) -> Point {
+        let step = DEFAULT_HEATMAP_CELL_SIZE + DEFAULT_HEATMAP_GAP;
+        Point::new(
+            column as f64 * step + DEFAULT_HEATMAP_CELL_SIZE / 2.,
+            row as f64 * step + DEFAULT_HEATMAP_CELL_SIZE / 2.,
+        )
+    }
+
+    #[test]
+    fn color_scale() {
+        let scale = ColorScale::steps([Color::BLACK, Color::WHITE]);
+        assert_eq!(scale.color_at(0.4), Color::BLACK);
+        assert_eq!(scale.color_at(0.6), Color::WHITE);
+
+        let scale = ColorScale::gradient([Color::BLACK, Color::WHITE]);
+        assert_eq!(scale.color_at(0.), Color::BLACK);
+        assert_eq!(scale.color_at(1.), Color::WHITE);
+        assert_eq!(
+            scale.color_at(0.5),
+            Color::BLACK.lerp_rect(Color::WHITE, 0.5)
+        );
+    }
+
+    #[test]
+    fn click_cells_with_values() {
+        let values = vec![vec![1., 2., 3.], vec![4., f64::NAN]];
+        let widget = Heatmap::new(values).with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(100., 100.));
+        let id = harness.root_id();
+        assert_eq!(harness.root_widget().columns(), 3);
+
+        for (row, column) in [(1, 0), (1, 1), (1, 2)] {
+            harness.mouse_move(cell_center(row, column));
+            harness.mouse_button_press(PointerButton::Primary);
+            harness.mouse_button_release(PointerButton::Primary);
+        }
+        // Only the first cell has a value.
+        assert_eq!(
+            harness.pop_action::<HeatmapCellClicked>(),
+            Some((HeatmapCellClicked { row: 1, column: 0 }, id))
+        );
+        assert_eq!(harness.pop_action::<HeatmapCellClicked>(), None);
+    }
+
+    #[test]
+    fn hover_starts_tooltip_delay() {
+        let widget = Heatmap::new(vec![vec![1., 2.]]).with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(100., 100.));
+
+        harness.mouse_move(cell_center(0, 1));
+        assert_eq!(harness.root_widget().hovered, Some((0, 1)));
+        assert_eq!(harness.root_widget().hover_nanos, Some(0));
+
+        harness.animate_ms(600);
+        assert_eq!(harness.root_widget().hover_nanos, None);
+
+        // Moving off the cells hides the outline.
+        harness.mouse_move(Point::new(90., 90.));
+        assert_eq!(harness.root_widget().hovered, None);
+    }
+}
@@ -2,19 +2,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::any::TypeId;
+use std::sync::Arc;
+use std::time::Duration;
 
 use accesskit::{Node, Role};
 use tracing::{Span, trace_span};
 use vello::Scene;
 
 use crate::core::{
-    AccessCtx, ArcStr, ChildrenIds, HasProperty, LayoutCtx, MeasureCtx, NoAction, PaintCtx,
-    PropertiesMut, PropertiesRef, RegisterCtx, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+    AccessCtx, ArcStr, ChildrenIds, ComposeCtx, HasProperty, LayoutCtx, MeasureCtx, NoAction,
+    PaintCtx, PropertiesMut, PropertiesRef, RegisterCtx, Update, UpdateCtx, Widget, WidgetId,
+    WidgetMut,
 };
 use crate::kurbo::{Affine, Axis, Size};
 use crate::layout::LenReq;
-use crate::peniko::{BlendMode, Fill, ImageBrush};
+use crate::peniko::{BlendMode, Fill, ImageAlphaType, ImageBrush, ImageData, ImageFormat};
 use crate::properties::ObjectFit;
+use crate::widgets::{ImageCache, ImageDecodeError, ImagePriority, ImageSource, ImageStatus};
 
 // TODO: Make this a configurable option of the widget.
 /// The scale that the image is native to.
@@ -38,12 +42,93 @@ const IMAGE_SCALE: f64 = 1.0;
 /// than the image size).
 ///
 /// You can change the sizing of the image with the [`ObjectFit`] property.
+///
+/// Images can also be [loaded](Self::from_source) through an [`ImageCache`].
+/// They are then decoded in the background when they first become visible,
+/// at the size they are displayed at.
+/// Until then, their preferred size is zero.
+///
+/// [Animated images](Self::animated), such as GIFs, are played while the widget is visible,
+/// unless the user [prefers reduced motion](UpdateCtx::prefers_reduced_motion).
+/// Their frames are decoded when they are first shown.
 pub struct Image {
     image_data: ImageBrush,
+    source: Option<CachedSource>,
+    animation: Option<Animation>,
     decorative: bool,
     alt_text: Option<ArcStr>,
 }
 
+/// An image loaded through an [`ImageCache`].
+struct CachedSource {
+    cache: ImageCache,
+    source: ImageSource,
+    state: LoadState,
+    /// The size of the image before down-sampling, once it has been decoded.
+    natural_size: Option<(u32, u32)>,
+    is_visible: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LoadState {
+    Idle,
+    Loading { max_size: Option<(u32, u32)> },
+    Loaded { max_size: Option<(u32, u32)> },
+    Failed,
+}
+
+/// A frame of an [animated](Image::animated) image.
+#[derive(Clone, Debug)]
+pub struct AnimationFrame {
+    /// The pixels of the frame.
+    pub image: ImageData,
+    /// How long the frame is shown for.
+    ///
+    /// As in browsers, delays shorter than 20ms are treated as 100ms,
+    /// since many GIFs expect that.
+    pub delay: Duration,
+}
+
+/// Decodes the frames of an animated image, such as a GIF or an animated WebP, one at a time.
+///
+/// Frames are decoded when the app is idle or as they are first shown, whichever comes first,
+/// and kept for the following loops.
+pub trait FrameDecoder: 'static {
+    /// Decodes the next frame, or returns `None` after the last one.
+    fn next_frame(&mut self) -> Option<Result<AnimationFrame, ImageDecodeError>>;
+}
+
+impl<D: FrameDecoder + ?Sized> FrameDecoder for Box<D> {
+    fn next_frame(&mut self) -> Option<Result<AnimationFrame, ImageDecodeError>> {
+        (**self).next_frame()
+    }
+}
+
+/// Opens encoded animated images, for example to create an [`Image::animated`] from an [`ImageSource`].
+///
+/// Like [`ImageDecoder`](crate::widgets::ImageDecoder), this is usually implemented with
+/// a crate such as [`image`](https://docs.rs/image).
+pub trait AnimationDecoder: Send + Sync + 'static {
+    /// Starts decoding the frames of the encoded animation `bytes`.
+    fn open(&self, bytes: Arc<[u8]>) -> Result<Box<dyn FrameDecoder>, ImageDecodeError>;
+}
+
+/// The playback state of an animated image.
+struct Animation {
+    /// `None` once all frames have been decoded.
+    decoder: Option<Box<dyn FrameDecoder>>,
+    frames: Vec<AnimationFrame>,
+    current: usize,
+    /// How long the current frame has been shown for, in nanoseconds.
+    elapsed: u64,
+    is_playing: bool,
+    is_visible: bool,
+    /// Whether an animation frame has been requested.
+    is_running: bool,
+    /// Whether the next frame is queued to be decoded when the app is idle.
+    is_decode_queued: bool,
+}
+
 // --- MARK: BUILDERS
 impl Image {
     /// Creates an image drawing widget from an image buffer.
@@ -54,11 +139,56 @@ impl Image {
     pub fn new(image_data: impl Into<ImageBrush>) -> Self {
         Self {
             image_data: image_data.into(),
+            source: None,
+            animation: None,
+            decorative: false,
+            alt_text: None,
+        }
+    }
+
+    /// Creates an image drawing widget which loads `source` through `cache`.
+    ///
+    /// The image is requested when the widget first becomes [visible](ComposeCtx::is_in_viewport),
+    /// and is down-sampled to the displayed size.
+    /// It is requested again if the widget grows larger than that size.
+    pub fn from_source(cache: ImageCache, source: ImageSource) -> Self {
+        Self {
+            image_data: empty_image().into(),
+            source: Some(CachedSource::new(cache, source)),
+            animation: None,
             decorative: false,
             alt_text: None,
         }
     }
 
+    /// Creates an image drawing widget which plays the frames from `decoder`.
+    ///
+    /// The first frame is decoded immediately, and the following ones when the app is idle,
+    /// or when they are due if it's busy.
+    /// The animation loops once all frames have been shown.
+    /// It is paused while the widget isn't [visible](ComposeCtx::is_in_viewport),
+    /// or when the user [prefers reduced motion](UpdateCtx::prefers_reduced_motion).
+    pub fn animated(decoder: impl FrameDecoder) -> Self {
+        let animation = Animation::new(Box::new(decoder));
+        Self {
+            image_data: animation.image(),
+            source: None,
+            animation: Some(animation),
+            decorative: false,
+            alt_text: None,
+        }
+    }
+
+    /// Sets whether an [animated](Self::animated) image is playing.
+    ///
+    /// Animations are playing by default.
+    pub fn with_playing(mut self, is_playing: bool) -> Self {
+        if let Some(animation) = &mut self.animation {
+            animation.is_playing = is_playing;
+        }
+        self
+    }
+
     /// Specifies whether the image is decorative, meaning it doesn't have meaningful content
     /// and is only for visual presentation.
     ///
@@ -82,15 +212,85 @@ impl Image {
     }
 }
 
+// --- MARK: METHODS
+impl Image {
+    /// Returns the source this image is loaded from, if it was created with [`Image::from_source`].
+    pub fn source(&self) -> Option<&ImageSource> {
+        self.source.as_ref().map(|source| &source.source)
+    }
+
+    /// Returns whether the image has been decoded.
+    ///
+    /// This is always `true` for images created from an `ImageBrush`.
+    pub fn is_loaded(&self) -> bool {
+        self.source
+            .as_ref()
+            .is_none_or(|source| source.natural_size.is_some())
+    }
+
+    /// Returns whether the image is an [animation](Self::animated) which is playing.
+    ///
+    /// This is still `true` while the animation is paused because the widget is hidden.
+    pub fn is_playing(&self) -> bool {
+        self.animation
+            .as_ref()
+            .is_some_and(|animation| animation.is_playing)
+    }
+}
+
 // --- MARK: WIDGETMUT
 impl Image {
     /// Sets new `ImageBrush`.
+    ///
+    /// This replaces the [source](Image::from_source) or [animation](Image::animated)
+    /// of the image, if any.
     #[inline]
     pub fn set_image_data(this: &mut WidgetMut<'_, Self>, image_data: impl Into<ImageBrush>) {
         this.widget.image_data = image_data.into();
+        this.widget.source = None;
+        this.widget.animation = None;
         this.ctx.request_layout();
     }
 
+    /// Sets a new source to load the image from.
+    ///
+    /// The current image is cleared until the new one is decoded.
+    /// See [`Image::from_source`] for details.
+    pub fn set_source(this: &mut WidgetMut<'_, Self>, cache: ImageCache, source: ImageSource) {
+        this.widget.image_data = empty_image().into();
+        this.widget.source = Some(CachedSource::new(cache, source));
+        this.widget.animation = None;
+        this.ctx.request_layout();
+    }
+
+    /// Replaces the image with an animation playing the frames from `decoder`.
+    ///
+    /// See [`Image::animated`] for details.
+    pub fn set_animation(this: &mut WidgetMut<'_, Self>, decoder: impl FrameDecoder) {
+        let mut animation = Animation::new(Box::new(decoder));
+        if let Some(old) = &this.widget.animation {
+            animation.is_playing = old.is_playing;
+        }
+        this.widget.image_data = animation.image();
+        this.widget.source = None;
+        this.widget.animation = Some(animation);
+        this.ctx.request_layout();
+        this.ctx.request_compose();
+    }
+
+    /// Plays or pauses an [animated](Image::animated) image.
+    ///
+    /// Pausing keeps the current frame, and playing resumes from it.
+    pub fn set_playing(this: &mut WidgetMut<'_, Self>, is_playing: bool) {
+        if let Some(animation) = &mut this.widget.animation
+            && animation.is_playing != is_playing
+        {
+            animation.is_playing = is_playing;
+            // The animation is started in `compose`.
+            this.ctx.request_compose();
+        }
+    }
+
     /// Sets whether the image is decorative, meaning it doesn't have meaningful content
     /// and is only for visual presentation.
     ///
@@ -121,11 +321,160 @@ impl Image {
     ///
     /// Basically it provides logical pixels in device pixel space.
     fn preferred_length(&self, axis: Axis, scale: f64) -> f64 {
+        let (width, height) = self.natural_size();
         match axis {
-            Axis::Horizontal => self.image_data.image.width as f64 * scale / IMAGE_SCALE,
-            Axis::Vertical => self.image_data.image.height as f64 * scale / IMAGE_SCALE,
+            Axis::Horizontal => width as f64 * scale / IMAGE_SCALE,
+            Axis::Vertical => height as f64 * scale / IMAGE_SCALE,
         }
     }
+
+    /// Returns the size of the image before it was down-sampled by an [`ImageCache`].
+    fn natural_size(&self) -> (u32, u32) {
+        self.source
+            .as_ref()
+            .and_then(|source| source.natural_size)
+            .unwrap_or((self.image_data.image.width, self.image_data.image.height))
+    }
+}
+
+impl CachedSource {
+    fn new(cache: ImageCache, source: ImageSource) -> Self {
+        Self {
+            cache,
+            source,
+            state: LoadState::Idle,
+            natural_size: None,
+            is_visible: false,
+        }
+    }
+
+    fn priority(&self) -> ImagePriority {
+        if self.is_visible {
+            ImagePriority::Visible
+        } else {
+            ImagePriority::Background
+        }
+    }
+
+    /// Whether the image should be requested at `max_size`.
+    fn needs_request(&self, max_size: Option<(u32, u32)>) -> bool {
+        match self.state {
+            LoadState::Idle => true,
+            LoadState::Loaded {
+                max_size: Some(loaded),
+            } => {
+                // We request the image again when displayed larger than it was decoded for,
+                // unless it was already decoded at its natural size.
+                let is_natural_size = self
+                    .natural_size
+                    .is_some_and(|(width, height)| width <= loaded.0 && height <= loaded.1);
+                max_size.is_some_and(|max_size| max_size.0 > loaded.0 || max_size.1 > loaded.1)
+                    && !is_natural_size
+            }
+            LoadState::Loaded { max_size: None }
+            | LoadState::Loading { .. }
+            | LoadState::Failed => false,
+        }
+    }
+}
+
+impl Animation {
+    fn new(mut decoder: Box<dyn FrameDecoder>) -> Self {
+        let mut frames = Vec::new();
+        let decoder = match decoder.next_frame() {
+            Some(Ok(frame)) => {
+                frames.push(frame);
+                Some(decoder)
+            }
+            Some(Err(err)) => {
+                tracing::warn!("Failed to decode the first frame of an animated image: {err}");
+                None
+            }
+            None => None,
+        };
+        Self {
+            decoder,
+            frames,
+            current: 0,
+            elapsed: 0,
+            is_playing: true,
+            is_visible: false,
+            is_running: false,
+            is_decode_queued: false,
+        }
+    }
+
+    fn image(&self) -> ImageBrush {
+        self.frames
+            .get(self.current)
+            .map_or_else(empty_image, |frame| frame.image.clone())
+            .into()
+    }
+
+    /// Whether there are several frames to show, now or once they are decoded.
+    fn has_several_frames(&self) -> bool {
+        self.frames.len() > 1 || (self.decoder.is_some() && !self.frames.is_empty())
+    }
+
+    fn should_run(&self, prefers_reduced_motion: bool) -> bool {
+        self.is_playing && self.is_visible && !prefers_reduced_motion && self.has_several_frames()
+    }
+
+    /// Returns how long the current frame is shown for, in nanoseconds.
+    fn current_delay(&self) -> u64 {
+        let delay = self.frames[self.current].delay;
+        let delay = if delay < Duration::from_millis(20) {
+            Duration::from_millis(100)
+        } else {
+            delay
+        };
+        delay.as_nanos().try_into().unwrap_or(u64::MAX)
+    }
+
+    /// Whether the frame after the current one still has to be decoded.
+    fn needs_decode(&self) -> bool {
+        self.decoder.is_some() && self.current + 1 >= self.frames.len()
+    }
+
+    /// Decodes the frame after the last decoded one, returning false if there is none.
+    fn decode_next(&mut self) -> bool {
+        let Some(decoder) = &mut self.decoder else {
+            return false;
+        };
+        match decoder.next_frame() {
+            Some(Ok(frame)) => {
+                self.frames.push(frame);
+                return true;
+            }
+            Some(Err(err)) => {
+                // We keep looping over the frames that could be decoded.
+                tracing::warn!("Failed to decode a frame of an animated image: {err}");
+                self.decoder = None;
+            }
+            None => self.decoder = None,
+        }
+        false
+    }
+
+    /// Moves to the next frame, decoding it if needed.
+    fn advance(&mut self) {
+        let next = self.current + 1;
+        if next < self.frames.len() || self.decode_next() {
+            self.current = next;
+        } else {
+            self.current = 0;
+        }
+    }
+}
+
+fn empty_image() -> ImageData {
+    ImageData {
+        data: Vec::new().into(),
+        format: ImageFormat::Rgba8,
+        alpha_type: ImageAlphaType::Alpha,
+        width: 0,
+        height: 0,
+    }
 }
 
 impl HasProperty<ObjectFit> for Image {}
@@ -140,12 +489,85 @@ impl Widget for Image {
         ObjectFit::prop_changed(ctx, property_type);
     }
 
-    fn update(
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::StashedChanged(_) = event {
+            // Images are loaded and animated depending on their visibility.
+            ctx.request_compose();
+        }
+    }
+
+    fn on_anim_frame(
         &mut self,
-        _ctx: &mut UpdateCtx<'_>,
+        ctx: &mut UpdateCtx<'_>,
         _props: &mut PropertiesMut<'_>,
-        _event: &Update,
+        interval: u64,
     ) {
+        if let Some(animation) = &mut self.animation {
+            animation.is_running = false;
+            if !animation.should_run(ctx.prefers_reduced_motion()) {
+                return;
+            }
+            animation.elapsed += interval;
+            let delay = animation.current_delay();
+            if animation.elapsed >= delay {
+                animation.advance();
+                // We show every frame at least once, even when frames are late.
+                animation.elapsed = (animation.elapsed - delay).min(animation.current_delay());
+                let previous = &self.image_data.image;
+                let image_data = animation.image();
+                if (image_data.image.width, image_data.image.height)
+                    != (previous.width, previous.height)
+                {
+                    ctx.request_layout();
+                }
+                self.image_data = image_data;
+                ctx.request_render();
+            }
+            if animation.should_run(ctx.prefers_reduced_motion()) {
+                animation.is_running = true;
+                ctx.request_anim_frame();
+                if animation.needs_decode() && !animation.is_decode_queued {
+                    // We decode the next frame ahead of time, so that showing it doesn't
+                    // delay a frame. If the app is too busy, it's decoded when it's due.
+                    animation.is_decode_queued = true;
+                    let time_left = animation.current_delay().saturating_sub(animation.elapsed);
+                    ctx.run_when_idle(Duration::from_nanos(time_left), |mut image| {
+                        let image = image.downcast::<Self>();
+                        if let Some(animation) = &mut image.widget.animation {
+                            animation.is_decode_queued = false;
+                            if animation.needs_decode() {
+                                animation.decode_next();
+                            }
+                        }
+                    });
+                }
+            }
+            return;
+        }
+        let Some(source) = &mut self.source else {
+            return;
+        };
+        let LoadState::Loading { max_size } = source.state else {
+            return;
+        };
+        // Decoding happens on the cache's threads, so we poll it until it's done.
+        match source
+            .cache
+            .request(&source.source, max_size, source.priority())
+        {
+            ImageStatus::Pending => ctx.request_anim_frame(),
+            ImageStatus::Ready(decoded) => {
+                let natural_size = Some((decoded.natural_width, decoded.natural_height));
+                if source.natural_size != natural_size {
+                    source.natural_size = natural_size;
+                    ctx.request_layout();
+                }
+                source.state = LoadState::Loaded { max_size };
+                self.image_data = decoded.image.into();
+                ctx.request_render();
+            }
+            ImageStatus::Failed => source.state = LoadState::Failed,
+        }
     }
 
     fn measure(
@@ -163,9 +585,10 @@ impl Widget for Image {
         let object_fit = props.get::<ObjectFit>();
 
         let ar = {
+            let (width, height) = self.natural_size();
             let (numerator, denominator) = match axis {
-                Axis::Horizontal => (self.image_data.image.width, self.image_data.image.height),
-                Axis::Vertical => (self.image_data.image.height, self.image_data.image.width),
+                Axis::Horizontal => (width, height),
+                Axis::Vertical => (height, width),
             };
             if denominator > 0 {
                 numerator as f64 / denominator as f64
@@ -216,6 +639,43 @@ impl Widget for Image {
 
     fn layout(&mut self, _ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, _size: Size) {}
 
+    fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
+        if let Some(animation) = &mut self.animation {
+            animation.is_visible = ctx.is_in_viewport();
+            // The animation stops itself in `on_anim_frame` when it shouldn't run.
+            if !animation.is_running && animation.should_run(ctx.prefers_reduced_motion()) {
+                animation.is_running = true;
+                ctx.request_anim_frame();
+            }
+            return;
+        }
+        let Some(source) = &mut self.source else {
+            return;
+        };
+        let was_visible = source.is_visible;
+        source.is_visible = ctx.is_in_viewport();
+
+        // TODO: Use the scale factor, like in `measure`.
+        let size = ctx.content_box_size();
+        let max_size = (size.width > 0. && size.height > 0.)
+            .then(|| (size.width.ceil() as u32, size.height.ceil() as u32));
+
+        if source.is_visible && source.needs_request(max_size) {
+            source.state = LoadState::Loading { max_size };
+            source
+                .cache
+                .request(&source.source, max_size, ImagePriority::Visible);
+            ctx.request_anim_frame();
+        } else if let LoadState::Loading { max_size } = source.state
+            && source.is_visible != was_visible
+        {
+            // Update the priority of the queued request.
+            source
+                .cache
+                .request(&source.source, max_size, source.priority());
+        }
+    }
+
     fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
         let content_box = ctx.content_box();
         let object_fit = props.get::<ObjectFit>();
@@ -225,7 +685,16 @@ impl Widget for Image {
             self.image_data.image.width as f64,
             self.image_data.image.height as f64,
         );
-        let transform = object_fit.affine(content_box.size(), image_size);
+        // Images down-sampled by the cache are fitted as if they had their natural size.
+        let (natural_width, natural_height) = self.natural_size();
+        let natural_size = Size::new(natural_width as f64, natural_height as f64);
+        let mut transform = object_fit.affine(content_box.size(), natural_size);
+        if image_size.width > 0. && image_size.height > 0. && image_size != natural_size {
+            transform *= Affine::scale_non_uniform(
+                natural_size.width / image_size.width,
+                natural_size.height / image_size.height,
+            );
+        }
 
         scene.push_layer(
             Fill::NonZero,
@@ -270,11 +739,95 @@ impl Widget for Image {
 // --- MARK: TESTS
 #[cfg(test)]
 mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::time::{Duration, Instant};
+
     use super::*;
-    use crate::core::NewWidget;
+    use crate::core::{NewWidget, WidgetTag, WindowEvent};
+    use crate::kurbo::Point;
+    use crate::layout::AsUnit;
     use crate::peniko::{ImageAlphaType, ImageData, ImageFormat};
+    use crate::properties::Gap;
     use crate::testing::{TestHarness, assert_render_snapshot};
     use crate::theme::test_property_set;
+    use crate::widgets::{Flex, ImageDecodeError, ImageDecoder, Portal, SizedBox};
+
+    /// Decodes `[width, height]` into a white image.
+    struct WhiteDecoder;
+
+    impl ImageDecoder for WhiteDecoder {
+        fn decode(&self, bytes: &[u8]) -> Result<ImageData, ImageDecodeError> {
+            let [width, height] = bytes else {
+                return Err("bad image".into());
+            };
+            let (width, height) = (u32::from(*width), u32::from(*height));
+            Ok(ImageData {
+                data: vec![255; 4 * width as usize * height as usize].into(),
+                format: ImageFormat::Rgba8,
+                alpha_type: ImageAlphaType::Alpha,
+                width,
+                height,
+            })
+        }
+    }
+
+    /// Yields single-pixel frames with the given delays, counting the calls to `next_frame`.
+    struct TestFrames {
+        delays: Vec<u64>,
+        calls: Rc<Cell<usize>>,
+    }
+
+    impl TestFrames {
+        fn new(delays: &[u64]) -> (Self, Rc<Cell<usize>>) {
+            let calls = Rc::new(Cell::new(0));
+            let frames = Self {
+                delays: delays.to_vec(),
+                calls: calls.clone(),
+            };
+            (frames, calls)
+        }
+    }
+
+    impl FrameDecoder for TestFrames {
+        fn next_frame(&mut self) -> Option<Result<AnimationFrame, ImageDecodeError>> {
+            let idx = self.calls.get();
+            self.calls.set(idx + 1);
+            let delay = *self.delays.get(idx)?;
+            Some(Ok(AnimationFrame {
+                image: ImageData {
+                    data: vec![idx as u8; 4].into(),
+                    format: ImageFormat::Rgba8,
+                    alpha_type: ImageAlphaType::Alpha,
+                    width: 1,
+                    height: 1,
+                },
+                delay: Duration::from_millis(delay),
+            }))
+        }
+    }
+
+    fn current_frame(harness: &TestHarness<impl Widget>, tag: WidgetTag<Image>) -> usize {
+        harness
+            .get_widget(tag)
+            .inner()
+            .animation
+            .as_ref()
+            .unwrap()
+            .current
+    }
+
+    fn wait_until_loaded(harness: &mut TestHarness<impl Widget>, tag: WidgetTag<Image>) {
+        let start = Instant::now();
+        while !harness.get_widget(tag).inner().is_loaded() {
+            assert!(
+                start.elapsed() < Duration::from_secs(10),
+                "image took too long to load"
+            );
+            std::thread::sleep(Duration::from_millis(1));
+            harness.animate_ms(16);
+        }
+    }
 
     /// Painting an empty image shouldn't crash.
     #[test]
@@ -429,4 +982,175 @@ mod tests {
         });
         assert_render_snapshot!(harness, "image_layout_stretch");
     }
+
+    #[test]
+    fn loads_source_at_displayed_size() {
+        let cache = ImageCache::with_thread_count(WhiteDecoder, 1);
+        let source = ImageSource::bytes("large", vec![200, 100]);
+        let tag = WidgetTag::named("image");
+        let image_widget = NewWidget::new_with_tag(Image::from_source(cache.clone(), source), tag);
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), image_widget, Size::new(40., 20.));
+        wait_until_loaded(&mut harness, tag);
+
+        let image = harness.get_widget(tag);
+        assert_eq!(image.inner().natural_size(), (200, 100));
+        let data = &image.inner().image_data.image;
+        assert_eq!((data.width, data.height), (40, 20));
+        assert_eq!(cache.memory_used(), 40 * 20 * 4);
+    }
+
+    #[test]
+    fn offscreen_source_is_lazy() {
+        let cache = ImageCache::with_thread_count(WhiteDecoder, 1);
+        let tag = WidgetTag::named("image");
+        let image_widget = NewWidget::new_with_tag(
+            Image::from_source(cache.clone(), ImageSource::bytes("image", vec![20, 20])),
+            tag,
+        );
+        let flex = Flex::column()
+            .with_fixed(SizedBox::empty().height(300.px()).with_auto_id())
+            .with_fixed(
+                SizedBox::new(image_widget)
+                    .width(20.px())
+                    .height(20.px())
+                    .with_auto_id(),
+            )
+            .with_props(Gap::ZERO);
+        let portal_tag = WidgetTag::named("portal");
+        let portal = NewWidget::new_with_tag(Portal::new(flex), portal_tag);
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), portal, Size::new(100., 100.));
+
+        for _ in 0..5 {
+            harness.animate_ms(16);
+        }
+        assert!(!harness.get_widget(tag).inner().is_loaded());
+        assert_eq!(cache.memory_used(), 0);
+
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::set_viewport_pos(&mut portal, Point::new(0., 250.));
+        });
+        wait_until_loaded(&mut harness, tag);
+        assert_eq!(cache.memory_used(), 20 * 20 * 4);
+    }
+
+    #[test]
+    fn animation_follows_frame_delays() {
+        let (frames, calls) = TestFrames::new(&[50, 100, 10]);
+        let tag = WidgetTag::named("image");
+        let image_widget = NewWidget::new_with_tag(Image::animated(frames), tag);
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), image_widget, Size::new(20., 20.));
+        assert_eq!(current_frame(&harness, tag), 0);
+        assert_eq!(calls.get(), 1);
+
+        harness.animate_ms(30);
+        assert_eq!(current_frame(&harness, tag), 0);
+        harness.animate_ms(30);
+        assert_eq!(current_frame(&harness, tag), 1);
+        assert_eq!(calls.get(), 2);
+
+        // The 10ms left over from the first frame count towards the second.
+        harness.animate_ms(80);
+        assert_eq!(current_frame(&harness, tag), 1);
+        harness.animate_ms(20);
+        assert_eq!(current_frame(&harness, tag), 2);
+
+        // Delays under 20ms are treated as 100ms.
+        harness.animate_ms(80);
+        assert_eq!(current_frame(&harness, tag), 2);
+        harness.animate_ms(20);
+        assert_eq!(current_frame(&harness, tag), 0);
+        assert_eq!(calls.get(), 4);
+
+        // Frames are only decoded once.
+        harness.animate_ms(60);
+        assert_eq!(current_frame(&harness, tag), 1);
+        assert_eq!(calls.get(), 4);
+    }
+
+    #[test]
+    fn animation_decodes_frames_when_idle() {
+        let (frames, calls) = TestFrames::new(&[1000, 1000, 1000]);
+        let tag = WidgetTag::named("image");
+        let image_widget = NewWidget::new_with_tag(Image::animated(frames), tag);
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), image_widget, Size::new(20., 20.));
+        harness.animate_ms(10);
+        assert_eq!(calls.get(), 1);
+
+        // The next frame is decoded ahead of time.
+        harness.run_idle_callbacks(Duration::from_secs(1));
+        assert_eq!(calls.get(), 2);
+        assert_eq!(current_frame(&harness, tag), 0);
+
+        // Showing it doesn't decode it again.
+        harness.animate_ms(1000);
+        assert_eq!(current_frame(&harness, tag), 1);
+        assert_eq!(calls.get(), 2);
+
+        // Without time to spare, the frame after it waits.
+        harness.run_idle_callbacks(Duration::ZERO);
+        assert_eq!(calls.get(), 2);
+        harness.run_idle_callbacks(Duration::from_secs(1));
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn animation_pauses() {
+        let (frames, _) = TestFrames::new(&[50, 50, 50]);
+        let tag = WidgetTag::named("image");
+        let image_widget = NewWidget::new_with_tag(Image::animated(frames), tag);
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), image_widget, Size::new(20., 20.));
+
+        harness.edit_widget(tag, |mut image| Image::set_playing(&mut image, false));
+        assert!(!harness.get_widget(tag).inner().is_playing());
+        harness.animate_ms(200);
+        assert_eq!(current_frame(&harness, tag), 0);
+
+        harness.edit_widget(tag, |mut image| Image::set_playing(&mut image, true));
+        harness.animate_ms(60);
+        assert_eq!(current_frame(&harness, tag), 1);
+
+        harness.process_window_event(WindowEvent::ReducedMotionChanged(true));
+        harness.animate_ms(200);
+        assert_eq!(current_frame(&harness, tag), 1);
+
+        harness.process_window_event(WindowEvent::ReducedMotionChanged(false));
+        harness.animate_ms(50);
+        assert_eq!(current_frame(&harness, tag), 2);
+    }
+
+    #[test]
+    fn offscreen_animation_is_paused() {
+        let (frames, calls) = TestFrames::new(&[50, 50]);
+        let tag = WidgetTag::named("image");
+        let image_widget = NewWidget::new_with_tag(Image::animated(frames), tag);
+        let flex = Flex::column()
+            .with_fixed(SizedBox::empty().height(300.px()).with_auto_id())
+            .with_fixed(
+                SizedBox::new(image_widget)
+                    .width(20.px())
+                    .height(20.px())
+                    .with_auto_id(),
+            )
+            .with_props(Gap::ZERO);
+        let portal_tag = WidgetTag::named("portal");
+        let portal = NewWidget::new_with_tag(Portal::new(flex), portal_tag);
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), portal, Size::new(100., 100.));
+
+        harness.animate_ms(200);
+        assert_eq!(current_frame(&harness, tag), 0);
+        assert_eq!(calls.get(), 1);
+
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::set_viewport_pos(&mut portal, Point::new(0., 250.));
+        });
+        harness.animate_ms(60);
+        assert_eq!(current_frame(&harness, tag), 1);
+    }
 }
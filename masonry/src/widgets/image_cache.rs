@@ -0,0 +1,711 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+use crate::core::ArcStr;
+use crate::peniko::{ImageData, ImageFormat};
+
+/// The default [memory budget](ImageCache::set_memory_budget) of an [`ImageCache`], in bytes.
+pub const DEFAULT_IMAGE_MEMORY_BUDGET: usize = 256 * 1024 * 1024;
+
+/// The error type returned by an [`ImageDecoder`].
+pub type ImageDecodeError = Box<dyn Error + Send + Sync>;
+
+/// Decodes encoded image data for an [`ImageCache`].
+///
+/// Masonry doesn't include any image codecs, so this is usually implemented with
+/// a crate such as [`image`](https://docs.rs/image).
+///
+/// This is called on the cache's decode threads.
+pub trait ImageDecoder: Send + Sync + 'static {
+    /// Decodes `bytes` into an image at its natural size.
+    fn decode(&self, bytes: &[u8]) -> Result<ImageData, ImageDecodeError>;
+}
+
+/// Encoded image data which can be loaded through an [`ImageCache`].
+///
+/// Sources are identified by their key, which is their path for files.
+/// Two sources with the same key are considered equal, even if their bytes differ.
+#[derive(Clone)]
+pub enum ImageSource {
+    /// Encoded bytes, such as an image downloaded from the network.
+    Bytes {
+        /// The key identifying the image in the cache, e.g. its URL.
+        key: ArcStr,
+        /// The encoded image.
+        bytes: Arc<[u8]>,
+    },
+    /// A file, which is read on a decode thread.
+    File(PathBuf),
+}
+
+impl ImageSource {
+    /// Creates a source from encoded bytes, identified by `key` in the cache.
+    pub fn bytes(key: impl Into<ArcStr>, bytes: impl Into<Arc<[u8]>>) -> Self {
+        Self::Bytes {
+            key: key.into(),
+            bytes: bytes.into(),
+        }
+    }
+
+    /// Creates a source reading the file at `path`.
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        Self::File(path.into())
+    }
+
+    /// Returns the encoded bytes of the image, reading them from the file if needed.
+    pub fn read(&self) -> std::io::Result<Arc<[u8]>> {
+        match self {
+            Self::Bytes { bytes, .. } => Ok(bytes.clone()),
+            Self::File(path) => Ok(std::fs::read(path)?.into()),
+        }
+    }
+
+    fn key(&self) -> SourceKey {
+        match self {
+            Self::Bytes { key, .. } => SourceKey::Bytes(key.clone()),
+            Self::File(path) => SourceKey::File(path.clone()),
+        }
+    }
+}
+
+impl PartialEq for ImageSource {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl fmt::Debug for ImageSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bytes { key, bytes } => f
+                .debug_struct("Bytes")
+                .field("key", key)
+                .field("len", &bytes.len())
+                .finish(),
+            Self::File(path) => f.debug_tuple("File").field(path).finish(),
+        }
+    }
+}
+
+/// How urgently an [`ImageCache`] should decode an image.
+///
+/// Requests are decoded in order of priority, and then in the order they were made.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ImagePriority {
+    /// The image isn't shown yet, e.g. because it was scrolled out of view.
+    Background,
+    /// The image is visible.
+    Visible,
+}
+
+/// An image decoded by an [`ImageCache`].
+#[derive(Clone, Debug)]
+pub struct DecodedImage {
+    /// The decoded pixels, which may be down-sampled.
+    pub image: ImageData,
+    /// The width of the image before down-sampling.
+    pub natural_width: u32,
+    /// The height of the image before down-sampling.
+    pub natural_height: u32,
+}
+
+/// The state of an image requested from an [`ImageCache`].
+#[derive(Clone, Debug)]
+pub enum ImageStatus {
+    /// The image is waiting to be decoded, or being decoded.
+    Pending,
+    /// The image has been decoded.
+    Ready(DecodedImage),
+    /// The image couldn't be read or decoded.
+    Failed,
+}
+
+/// A shared pipeline decoding images on background threads.
+///
+/// Images are requested with a [priority](ImagePriority) and an optional target size.
+/// Decoded images larger than the target are down-sampled to the smallest size which
+/// covers it, to save memory.
+/// Once the decoded images use more memory than the [budget](Self::set_memory_budget),
+/// the least recently requested ones are evicted.
+/// Evicted images stay alive as long as a widget displays them.
+///
+/// The [`Image`](crate::widgets::Image) widget uses this for images created with
+/// [`Image::from_source`](crate::widgets::Image::from_source): it requests the image when
+/// it first becomes visible, at its displayed size.
+///
+/// This type is a cheap handle, which can be cloned to share the cache.
+/// The decode threads stop when the last handle is dropped.
+#[derive(Clone)]
+pub struct ImageCache {
+    handle: Arc<Handle>,
+}
+
+struct Handle {
+    shared: Arc<Shared>,
+}
+
+struct Shared {
+    decoder: Box<dyn ImageDecoder>,
+    state: Mutex<CacheState>,
+    wakeup: Condvar,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum SourceKey {
+    Bytes(ArcStr),
+    File(PathBuf),
+}
+
+struct CacheState {
+    /// The decoded variants of each source, at different sizes.
+    entries: HashMap<SourceKey, Vec<Entry>>,
+    memory_budget: usize,
+    memory_used: usize,
+    /// Incremented on every request, used for ordering requests and eviction.
+    clock: u64,
+    is_shut_down: bool,
+}
+
+struct Entry {
+    max_size: Option<(u32, u32)>,
+    state: EntryState,
+    last_used: u64,
+}
+
+enum EntryState {
+    Queued {
+        source: ImageSource,
+        priority: ImagePriority,
+        requested: u64,
+    },
+    Decoding,
+    Ready(DecodedImage),
+    Failed,
+}
+
+struct Job {
+    key: SourceKey,
+    source: ImageSource,
+    max_size: Option<(u32, u32)>,
+}
+
+impl ImageCache {
+    /// Creates a cache using `decoder`, with a decode thread for each available core, up to four.
+    pub fn new(decoder: impl ImageDecoder) -> Self {
+        let thread_count = thread::available_parallelism().map_or(1, |count| count.get().min(4));
+        Self::with_thread_count(decoder, thread_count)
+    }
+
+    /// Creates a cache using `decoder`, with `thread_count` decode threads.
+    ///
+    /// # Panics
+    ///
+    /// If `thread_count` is zero.
+    pub fn with_thread_count(decoder: impl ImageDecoder, thread_count: usize) -> Self {
+        assert!(thread_count > 0, "an ImageCache needs at least one thread");
+        let shared = Arc::new(Shared {
+            decoder: Box::new(decoder),
+            state: Mutex::new(CacheState {
+                entries: HashMap::new(),
+                memory_budget: DEFAULT_IMAGE_MEMORY_BUDGET,
+                memory_used: 0,
+                clock: 0,
+                is_shut_down: false,
+            }),
+            wakeup: Condvar::new(),
+        });
+        for idx in 0..thread_count {
+            let shared = shared.clone();
+            thread::Builder::new()
+                .name(format!("masonry-image-decode-{idx}"))
+                .spawn(move || run_worker(&shared))
+                .expect("failed to spawn image decode thread");
+        }
+        Self {
+            handle: Arc::new(Handle { shared }),
+        }
+    }
+
+    /// Requests the image from `source`, returning its current status.
+    ///
+    /// If `max_size` is given, the image is down-sampled to the smallest size which covers it,
+    /// in pixels.
+    /// An already decoded image is returned if it's at least that large.
+    ///
+    /// If the image isn't decoded yet, it's queued with the given `priority`, or the priority
+    /// of the queued request is updated.
+    /// Call this method again to check whether it is done.
+    pub fn request(
+        &self,
+        source: &ImageSource,
+        max_size: Option<(u32, u32)>,
+        priority: ImagePriority,
+    ) -> ImageStatus {
+        let mut state = self.handle.shared.state.lock().unwrap();
+        state.clock += 1;
+        let clock = state.clock;
+        let entries = state.entries.entry(source.key()).or_default();
+
+        if let Some(entry) = entries
+            .iter_mut()
+            .find(|entry| entry.max_size == max_size || entry.covers(max_size))
+        {
+            entry.last_used = clock;
+            return match &mut entry.state {
+                EntryState::Queued {
+                    priority: queued, ..
+                } => {
+                    *queued = priority;
+                    ImageStatus::Pending
+                }
+                EntryState::Decoding => ImageStatus::Pending,
+                EntryState::Ready(image) => ImageStatus::Ready(image.clone()),
+                EntryState::Failed => ImageStatus::Failed,
+            };
+        }
+
+        entries.push(Entry {
+            max_size,
+            state: EntryState::Queued {
+                source: source.clone(),
+                priority,
+                requested: clock,
+            },
+            last_used: clock,
+        });
+        drop(state);
+        self.handle.shared.wakeup.notify_one();
+        ImageStatus::Pending
+    }
+
+    /// Sets the amount of memory decoded images can use before being evicted, in bytes.
+    ///
+    /// The default is [`DEFAULT_IMAGE_MEMORY_BUDGET`].
+    pub fn set_memory_budget(&self, memory_budget: usize) {
+        let mut state = self.handle.shared.state.lock().unwrap();
+        state.memory_budget = memory_budget;
+        state.evict(None);
+    }
+
+    /// Returns the amount of memory decoded images can use before being evicted, in bytes.
+    pub fn memory_budget(&self) -> usize {
+        self.handle.shared.state.lock().unwrap().memory_budget
+    }
+
+    /// Returns the amount of memory used by the decoded images in the cache, in bytes.
+    pub fn memory_used(&self) -> usize {
+        self.handle.shared.state.lock().unwrap().memory_used
+    }
+
+    /// Removes all decoded images and queued requests from the cache.
+    ///
+    /// Images which are currently being decoded are still added once they are done.
+    pub fn clear(&self) {
+        let mut state = self.handle.shared.state.lock().unwrap();
+        for entries in state.entries.values_mut() {
+            entries.retain(|entry| matches!(entry.state, EntryState::Decoding));
+        }
+        state.entries.retain(|_, entries| !entries.is_empty());
+        state.memory_used = 0;
+    }
+}
+
+impl PartialEq for ImageCache {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.handle, &other.handle)
+    }
+}
+
+impl fmt::Debug for ImageCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.handle.shared.state.lock().unwrap();
+        f.debug_struct("ImageCache")
+            .field("sources", &state.entries.len())
+            .field("memory_used", &state.memory_used)
+            .field("memory_budget", &state.memory_budget)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap().is_shut_down = true;
+        self.shared.wakeup.notify_all();
+    }
+}
+
+impl Entry {
+    /// Whether this decoded image can be used for a request with `max_size`.
+    fn covers(&self, max_size: Option<(u32, u32)>) -> bool {
+        let EntryState::Ready(decoded) = &self.state else {
+            return false;
+        };
+        let is_natural_size = decoded.image.width == decoded.natural_width
+            && decoded.image.height == decoded.natural_height;
+        match max_size {
+            None => is_natural_size,
+            Some((width, height)) => {
+                is_natural_size || (decoded.image.width >= width && decoded.image.height >= height)
+            }
+        }
+    }
+}
+
+impl CacheState {
+    /// Takes the queued request with the highest priority, which was made first.
+    fn take_job(&mut self) -> Option<Job> {
+        let mut best: Option<(ImagePriority, u64, &SourceKey, usize)> = None;
+        for (key, entries) in &self.entries {
+            for (idx, entry) in entries.iter().enumerate() {
+                if let EntryState::Queued {
+                    priority,
+                    requested,
+                    ..
+                } = &entry.state
+                {
+                    let is_better = best.is_none_or(|(best_priority, best_requested, ..)| {
+                        (*priority, std::cmp::Reverse(*requested))
+                            > (best_priority, std::cmp::Reverse(best_requested))
+                    });
+                    if is_better {
+                        best = Some((*priority, *requested, key, idx));
+                    }
+                }
+            }
+        }
+        let (_, _, key, idx) = best?;
+        let key = key.clone();
+        let entry = &mut self.entries.get_mut(&key).unwrap()[idx];
+        let EntryState::Queued { source, .. } =
+            std::mem::replace(&mut entry.state, EntryState::Decoding)
+        else {
+            unreachable!()
+        };
+        Some(Job {
+            key,
+            source,
+            max_size: entry.max_size,
+        })
+    }
+
+    fn finish(&mut self, job: Job, result: Option<DecodedImage>) {
+        // The entry may have been cleared while decoding.
+        let entries = self.entries.entry(job.key.clone()).or_default();
+        let idx = match entries
+            .iter()
+            .position(|entry| entry.max_size == job.max_size)
+        {
+            Some(idx) => idx,
+            None => {
+                entries.push(Entry {
+                    max_size: job.max_size,
+                    state: EntryState::Decoding,
+                    last_used: self.clock,
+                });
+                entries.len() - 1
+            }
+        };
+        let entry = &mut entries[idx];
+        match result {
+            Some(decoded) => {
+                self.memory_used += decoded.image.data.len();
+                entry.state = EntryState::Ready(decoded);
+                self.evict(Some((&job.key, job.max_size)));
+            }
+            None => entry.state = EntryState::Failed,
+        }
+    }
+
+    /// Evicts the least recently used images until the budget is met, except for `keep`.
+    fn evict(&mut self, keep: Option<(&SourceKey, Option<(u32, u32)>)>) {
+        while self.memory_used > self.memory_budget {
+            let mut oldest: Option<(u64, SourceKey, usize)> = None;
+            for (key, entries) in &self.entries {
+                for (idx, entry) in entries.iter().enumerate() {
+                    let is_kept = keep.is_some_and(|(keep_key, keep_size)| {
+                        keep_key == key && keep_size == entry.max_size
+                    });
+                    if matches!(entry.state, EntryState::Ready(_))
+                        && !is_kept
+                        && oldest
+                            .as_ref()
+                            .is_none_or(|(last_used, ..)| entry.last_used < *last_used)
+                    {
+                        oldest = Some((entry.last_used, key.clone(), idx));
+                    }
+                }
+            }
+            let Some((_, key, idx)) = oldest else {
+                // Only the kept image is left; we allow it to exceed the budget,
+                // as evicting it would make its widget request it again.
+                break;
+            };
+            let entries = self.entries.get_mut(&key).unwrap();
+            if let EntryState::Ready(decoded) = entries.remove(idx).state {
+                self.memory_used -= decoded.image.data.len();
+            }
+            if entries.is_empty() {
+                self.entries.remove(&key);
+            }
+        }
+    }
+}
+
+fn run_worker(shared: &Shared) {
+    loop {
+        let job = {
+            let mut state = shared.state.lock().unwrap();
+            loop {
+                if state.is_shut_down {
+                    return;
+                }
+                if let Some(job) = state.take_job() {
+                    break job;
+                }
+                state = shared.wakeup.wait(state).unwrap();
+            }
+        };
+        let result = decode(&*shared.decoder, &job);
+        let result = match result {
+            Ok(decoded) => Some(decoded),
+            Err(err) => {
+                tracing::warn!("Failed to load image {:?}: {err}", job.source);
+                None
+            }
+        };
+        shared.state.lock().unwrap().finish(job, result);
+    }
+}
+
+fn decode(decoder: &dyn ImageDecoder, job: &Job) -> Result<DecodedImage, ImageDecodeError> {
+    let image = decoder.decode(&job.source.read()?)?;
+    let (natural_width, natural_height) = (image.width, image.height);
+    let image = match job.max_size {
+        Some(max_size) => downsample(&image, max_size),
+        None => image,
+    };
+    Ok(DecodedImage {
+        image,
+        natural_width,
+        natural_height,
+    })
+}
+
+/// Down-samples `image` to the smallest size with the same aspect ratio which covers
+/// `max_size`, averaging the source pixels.
+///
+/// Images which already fit are returned unchanged.
+fn downsample(image: &ImageData, max_size: (u32, u32)) -> ImageData {
+    let (width, height) = (image.width, image.height);
+    let (max_width, max_height) = (max_size.0.max(1), max_size.1.max(1));
+    let scale = (max_width as f64 / width as f64).max(max_height as f64 / height as f64);
+    // We average each 8-bit channel independently, which works for any channel order.
+    let is_four_channel = matches!(image.format, ImageFormat::Rgba8 | ImageFormat::Bgra8)
+        && image.format.size_in_bytes(width, height) == Some(image.data.len());
+    if scale >= 1. || !is_four_channel {
+        return image.clone();
+    }
+    let new_width = ((width as f64 * scale).ceil() as u32).clamp(1, width);
+    let new_height = ((height as f64 * scale).ceil() as u32).clamp(1, height);
+
+    let src = image.data.data();
+    let mut data = Vec::with_capacity(new_width as usize * new_height as usize * 4);
+    for y in 0..new_height {
+        let y0 = (y as u64 * height as u64 / new_height as u64) as usize;
+        let y1 =
+            (((y + 1) as u64 * height as u64).div_ceil(new_height as u64) as usize).max(y0 + 1);
+        for x in 0..new_width {
+            let x0 = (x as u64 * width as u64 / new_width as u64) as usize;
+            let x1 =
+                (((x + 1) as u64 * width as u64).div_ceil(new_width as u64) as usize).max(x0 + 1);
+            let mut sum = [0_u32; 4];
+            for row in y0..y1 {
+                let start = (row * width as usize + x0) * 4;
+                for pixel in src[start..start + (x1 - x0) * 4].chunks_exact(4) {
+                    for (channel, value) in sum.iter_mut().zip(pixel) {
+                        *channel += u32::from(*value);
+                    }
+                }
+            }
+            let count = ((y1 - y0) * (x1 - x0)) as u32;
+            data.extend(sum.map(|channel| ((channel + count / 2) / count) as u8));
+        }
+    }
+    ImageData {
+        data: data.into(),
+        format: image.format,
+        alpha_type: image.alpha_type,
+        width: new_width,
+        height: new_height,
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::peniko::ImageAlphaType;
+
+    /// Decodes `[width, height, r, g, b, a]` into a solid image.
+    struct SolidDecoder;
+
+    impl ImageDecoder for SolidDecoder {
+        fn decode(&self, bytes: &[u8]) -> Result<ImageData, ImageDecodeError> {
+            let [width, height, rgba @ ..] = bytes else {
+                return Err("bad image".into());
+            };
+            let rgba: [u8; 4] = rgba.try_into()?;
+            Ok(solid_image(u32::from(*width), u32::from(*height), rgba))
+        }
+    }
+
+    fn solid_image(width: u32, height: u32, rgba: [u8; 4]) -> ImageData {
+        ImageData {
+            data: rgba.repeat(width as usize * height as usize).into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width,
+            height,
+        }
+    }
+
+    fn wait_until_done(
+        cache: &ImageCache,
+        source: &ImageSource,
+        max_size: Option<(u32, u32)>,
+    ) -> ImageStatus {
+        let start = Instant::now();
+        loop {
+            let status = cache.request(source, max_size, ImagePriority::Visible);
+            if !matches!(status, ImageStatus::Pending) {
+                return status;
+            }
+            assert!(
+                start.elapsed() < Duration::from_secs(10),
+                "image took too long to decode"
+            );
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn downsample_covers_target() {
+        let mut data = Vec::new();
+        for y in 0..2 {
+            for x in 0..4_u8 {
+                data.extend([x * 10, y * 100, 0, 255]);
+            }
+        }
+        let image = ImageData {
+            data: data.into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 4,
+            height: 2,
+        };
+
+        // The height needs to be kept to cover the target.
+        let same = downsample(&image, (1, 2));
+        assert_eq!((same.width, same.height), (4, 2));
+
+        let small = downsample(&image, (2, 1));
+        assert_eq!((small.width, small.height), (2, 1));
+        assert_eq!(small.data.data(), &[5, 50, 0, 255, 25, 50, 0, 255]);
+    }
+
+    #[test]
+    fn decodes_and_downsamples() {
+        let cache = ImageCache::with_thread_count(SolidDecoder, 1);
+        let source = ImageSource::bytes("red", vec![8, 4, 255, 0, 0, 255]);
+
+        let ImageStatus::Ready(decoded) = wait_until_done(&cache, &source, Some((2, 2))) else {
+            panic!("image should have been decoded");
+        };
+        assert_eq!((decoded.image.width, decoded.image.height), (4, 2));
+        assert_eq!((decoded.natural_width, decoded.natural_height), (8, 4));
+        assert_eq!(cache.memory_used(), 4 * 2 * 4);
+
+        // The down-sampled image is reused for smaller sizes.
+        let status = cache.request(&source, Some((1, 1)), ImagePriority::Visible);
+        assert!(matches!(status, ImageStatus::Ready(_)));
+
+        let broken = ImageSource::bytes("broken", vec![1]);
+        assert!(matches!(
+            wait_until_done(&cache, &broken, None),
+            ImageStatus::Failed
+        ));
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = ImageCache::with_thread_count(SolidDecoder, 1);
+        // Each image uses 64 bytes.
+        cache.set_memory_budget(128);
+        let sources = ["a", "b", "c"].map(|key| ImageSource::bytes(key, vec![4, 4, 0, 0, 0, 255]));
+
+        wait_until_done(&cache, &sources[0], None);
+        wait_until_done(&cache, &sources[1], None);
+        // Use the first image again, so that the second one is evicted.
+        wait_until_done(&cache, &sources[0], None);
+        wait_until_done(&cache, &sources[2], None);
+        assert_eq!(cache.memory_used(), 128);
+
+        let status = cache.request(&sources[0], None, ImagePriority::Visible);
+        assert!(matches!(status, ImageStatus::Ready(_)));
+        let status = cache.request(&sources[1], None, ImagePriority::Visible);
+        assert!(matches!(status, ImageStatus::Pending));
+    }
+
+    #[test]
+    fn visible_images_first() {
+        /// Records the decoded keys, after waiting for the test to enqueue its requests.
+        struct OrderDecoder {
+            start: Mutex<mpsc::Receiver<()>>,
+            order: Mutex<mpsc::Sender<u8>>,
+        }
+
+        impl ImageDecoder for OrderDecoder {
+            fn decode(&self, bytes: &[u8]) -> Result<ImageData, ImageDecodeError> {
+                if bytes[0] == 0 {
+                    self.start.lock().unwrap().recv()?;
+                }
+                self.order.lock().unwrap().send(bytes[0])?;
+                Ok(solid_image(1, 1, [0; 4]))
+            }
+        }
+
+        let (start_tx, start_rx) = mpsc::channel();
+        let (order_tx, order_rx) = mpsc::channel();
+        let decoder = OrderDecoder {
+            start: Mutex::new(start_rx),
+            order: Mutex::new(order_tx),
+        };
+        let cache = ImageCache::with_thread_count(decoder, 1);
+        let source = |idx: u8| ImageSource::bytes(idx.to_string(), vec![idx]);
+
+        // This blocks the only thread until all the other requests are queued.
+        cache.request(&source(0), None, ImagePriority::Visible);
+        assert_eq!(order_rx.recv_timeout(Duration::from_millis(10)).ok(), None);
+        cache.request(&source(1), None, ImagePriority::Background);
+        cache.request(&source(2), None, ImagePriority::Visible);
+        cache.request(&source(3), None, ImagePriority::Background);
+        // Requesting an image again updates its priority.
+        cache.request(&source(3), None, ImagePriority::Visible);
+        start_tx.send(()).unwrap();
+
+        let order: Vec<_> = (0..4)
+            .map(|_| order_rx.recv_timeout(Duration::from_secs(10)).unwrap())
+            .collect();
+        assert_eq!(order, [0, 2, 3, 1]);
+    }
+}
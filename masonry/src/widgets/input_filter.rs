@@ -0,0 +1,306 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::core::ArcStr;
+
+/// The character which stands for one input character in an [input mask](InputFilter::with_mask).
+pub const MASK_SLOT: char = '#';
+
+type CharPredicate = Arc<dyn Fn(char) -> bool + Send + Sync>;
+type Transform = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+/// Restricts and reformats the text entered into a [`TextArea`](crate::widgets::TextArea).
+///
+/// The filter applies to all text entered by the user, whether it was typed, pasted, or
+/// committed by an IME.
+/// Text set by the app, such as with [`TextArea::reset_text`](crate::widgets::TextArea::reset_text),
+/// isn't filtered.
+///
+/// The entered text goes through each step in order:
+///
+/// 1. The [transform](Self::with_transform), e.g. to make it uppercase.
+/// 2. The [character whitelist](Self::with_allowed_chars), which removes the other characters.
+/// 3. The [input mask](Self::with_mask), which inserts separators such as `-` or `/`.
+/// 4. The [maximum length](Self::with_max_length), which truncates the entered text
+///    so that the whole text fits.
+///
+/// If nothing is left of the entered text, the edit is rejected, and the text and selection
+/// are left unchanged.
+/// Deleting text isn't filtered.
+///
+/// # Examples
+///
+/// ```
+/// use masonry::widgets::InputFilter;
+///
+/// let phone_number = InputFilter::digits().with_mask("(###) ###-####");
+/// let licence_plate = InputFilter::new()
+///     .with_transform(str::to_uppercase)
+///     .with_allowed_chars(|c| c.is_ascii_alphanumeric())
+///     .with_max_length(8);
+/// ```
+#[derive(Clone, Default)]
+pub struct InputFilter {
+    transform: Option<Transform>,
+    allowed: Option<CharPredicate>,
+    mask: Option<ArcStr>,
+    max_length: Option<usize>,
+}
+
+/// The result of [`InputFilter::apply`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct FilteredEdit {
+    /// The whole text after the edit.
+    pub(crate) text: String,
+    /// The byte index of the caret after the edit.
+    pub(crate) caret: usize,
+}
+
+// --- MARK: BUILDERS
+impl InputFilter {
+    /// Creates a filter which accepts all text.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a filter which only accepts ASCII digits.
+    pub fn digits() -> Self {
+        Self::new().with_allowed_chars(|c| c.is_ascii_digit())
+    }
+
+    /// Sets a function which rewrites the entered text, such as [`str::to_uppercase`].
+    pub fn with_transform(
+        mut self,
+        transform: impl Fn(&str) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.transform = Some(Arc::new(transform));
+        self
+    }
+
+    /// Only accepts the characters for which `allowed` returns `true`.
+    pub fn with_allowed_chars(
+        mut self,
+        allowed: impl Fn(char) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.allowed = Some(Arc::new(allowed));
+        self
+    }
+
+    /// Formats the text with an input mask, such as `"##/##/####"` for dates.
+    ///
+    /// Each [`MASK_SLOT`] (`#`) in the mask stands for one entered grapheme, and the other
+    /// characters are separators, which are inserted automatically once the user types past them.
+    /// Separators in the entered text are skipped, so pasting an already formatted value works.
+    /// Text which doesn't fit in the mask is dropped.
+    ///
+    /// The text is formatted again whenever text is entered, so that the separators stay in place
+    /// when editing the middle of the text.
+    pub fn with_mask(mut self, mask: impl Into<ArcStr>) -> Self {
+        self.mask = Some(mask.into());
+        self
+    }
+
+    /// Limits the text to `max_length` graphemes, i.e. user-perceived characters.
+    ///
+    /// When entering text would exceed this, the entered text is truncated on a grapheme boundary.
+    /// With a [mask](Self::with_mask), this counts the graphemes filling the mask's slots, and
+    /// not the separators.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl InputFilter {
+    /// Returns the edit replacing `selection` in `text` with the entered text `inserted`,
+    /// or `None` if the edit is rejected.
+    pub(crate) fn apply(
+        &self,
+        text: &str,
+        selection: Range<usize>,
+        inserted: &str,
+    ) -> Option<FilteredEdit> {
+        let mut inserted = match &self.transform {
+            Some(transform) => transform(inserted),
+            None => inserted.to_string(),
+        };
+        if let Some(allowed) = &self.allowed {
+            inserted.retain(|c| allowed(c));
+        }
+        let before = &text[..selection.start];
+        let after = &text[selection.end..];
+        if let Some(mask) = &self.mask {
+            return self.apply_mask(mask, before, &inserted, after);
+        }
+
+        if let Some(max_length) = self.max_length {
+            let kept = before.graphemes(true).count() + after.graphemes(true).count();
+            let room = max_length.saturating_sub(kept);
+            if let Some((end, _)) = inserted.grapheme_indices(true).nth(room) {
+                inserted.truncate(end);
+            }
+        }
+        if inserted.is_empty() {
+            return None;
+        }
+        Some(FilteredEdit {
+            text: format!("{before}{inserted}{after}"),
+            caret: before.len() + inserted.len(),
+        })
+    }
+
+    fn apply_mask(
+        &self,
+        mask: &str,
+        before: &str,
+        inserted: &str,
+        after: &str,
+    ) -> Option<FilteredEdit> {
+        // The graphemes of `text` which fill the mask's slots, without separators.
+        let slots = |text| {
+            UnicodeSegmentation::graphemes(text, true)
+                .filter(|grapheme| !grapheme.chars().all(|c| c != MASK_SLOT && mask.contains(c)))
+                .collect::<Vec<_>>()
+        };
+        let before = slots(before);
+        let mut inserted = slots(inserted);
+        let after = slots(after);
+
+        let capacity = mask
+            .chars()
+            .filter(|c| *c == MASK_SLOT)
+            .count()
+            .min(self.max_length.unwrap_or(usize::MAX));
+        inserted.truncate(capacity.saturating_sub(before.len() + after.len()));
+        if inserted.is_empty() {
+            return None;
+        }
+
+        // The caret goes after the last inserted grapheme.
+        let caret_slot = before.len() + inserted.len();
+        let mut input = before.into_iter().chain(inserted).chain(after).peekable();
+        let mut text = String::new();
+        let mut caret = 0;
+        let mut filled = 0;
+        for c in mask.chars() {
+            // Separators are only inserted before slots that will be filled.
+            if input.peek().is_none() {
+                break;
+            }
+            if c == MASK_SLOT {
+                text.push_str(input.next().unwrap());
+                filled += 1;
+                if filled == caret_slot {
+                    caret = text.len();
+                }
+            } else {
+                text.push(c);
+            }
+        }
+        Some(FilteredEdit { text, caret })
+    }
+}
+
+impl PartialEq for InputFilter {
+    fn eq(&self, other: &Self) -> bool {
+        fn same<T: ?Sized>(a: &Option<Arc<T>>, b: &Option<Arc<T>>) -> bool {
+            match (a, b) {
+                (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+        }
+        same(&self.transform, &other.transform)
+            && same(&self.allowed, &other.allowed)
+            && self.mask == other.mask
+            && self.max_length == other.max_length
+    }
+}
+
+impl fmt::Debug for InputFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InputFilter")
+            .field("transform", &self.transform.is_some())
+            .field("allowed", &self.allowed.is_some())
+            .field("mask", &self.mask)
+            .field("max_length", &self.max_length)
+            .finish()
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(
+        filter: &InputFilter,
+        text: &str,
+        selection: Range<usize>,
+        inserted: &str,
+    ) -> Option<(String, usize)> {
+        filter
+            .apply(text, selection, inserted)
+            .map(|edit| (edit.text, edit.caret))
+    }
+
+    #[test]
+    fn whitelist_and_transform() {
+        let filter = InputFilter::new()
+            .with_transform(str::to_uppercase)
+            .with_allowed_chars(|c| c.is_ascii_uppercase());
+        assert_eq!(apply(&filter, "AB", 2..2, "c-d"), Some(("ABCD".into(), 4)));
+        assert_eq!(apply(&filter, "AB", 1..1, "x"), Some(("AXB".into(), 2)));
+        // Rejected edits don't delete the selection.
+        assert_eq!(apply(&filter, "AB", 0..2, "1"), None);
+    }
+
+    #[test]
+    fn max_length_keeps_whole_graphemes() {
+        let filter = InputFilter::new().with_max_length(3);
+        assert_eq!(apply(&filter, "ab", 2..2, "cd"), Some(("abc".into(), 3)));
+        // "e" followed by a combining acute accent is one grapheme.
+        assert_eq!(
+            apply(&filter, "a", 1..1, "e\u{301}fg"),
+            Some(("ae\u{301}f".into(), 5))
+        );
+        assert_eq!(apply(&filter, "abc", 3..3, "d"), None);
+        // Replacing a selection frees up room.
+        assert_eq!(apply(&filter, "abc", 1..3, "xyz"), Some(("axy".into(), 3)));
+    }
+
+    #[test]
+    fn mask_inserts_separators() {
+        let filter = InputFilter::digits().with_mask("(###) ###-####");
+        assert_eq!(apply(&filter, "", 0..0, "5"), Some(("(5".into(), 2)));
+        assert_eq!(
+            apply(&filter, "(555", 4..4, "1"),
+            Some(("(555) 1".into(), 7))
+        );
+        // Pasting a formatted number, and dropping what doesn't fit.
+        assert_eq!(
+            apply(&filter, "", 0..0, "555-123-45678"),
+            Some(("(555) 123-4567".into(), 14))
+        );
+        assert_eq!(apply(&filter, "(555) 123-4567", 14..14, "8"), None);
+    }
+
+    #[test]
+    fn mask_reformats_edits_in_the_middle() {
+        let filter = InputFilter::digits().with_mask("##/##/####");
+        assert_eq!(apply(&filter, "12/4", 3..3, "3"), Some(("12/34".into(), 4)));
+        assert_eq!(
+            apply(&filter, "12/34/2026", 0..2, "0"),
+            Some(("03/42/026".into(), 1))
+        );
+        let filter = InputFilter::new().with_mask("##-##").with_max_length(3);
+        assert_eq!(apply(&filter, "ab-", 3..3, "cd"), Some(("ab-c".into(), 4)));
+    }
+}
@@ -17,7 +17,7 @@ use crate::core::{
 };
 use crate::kurbo::{Affine, Axis, Point, Size};
 use crate::layout::LenReq;
-use crate::properties::{ContentColor, DisabledContentColor, LineBreaking};
+use crate::properties::{ContentColor, DisabledContentColor, LineBreaking, TextColor};
 use crate::theme::default_text_styles;
 use crate::util::debug_panic;
 use crate::{TextAlign, TextAlignOptions, theme};
@@ -28,7 +28,7 @@ use crate::{TextAlign, TextAlignOptions, theme};
 /// need support for displaying text, such as a button.
 ///
 /// You can customize the look of this label with the
-/// [`LineBreaking`], [`ContentColor`] and [`DisabledContentColor`] properties.
+/// [`LineBreaking`], [`ContentColor`], [`DisabledContentColor`] and [`TextColor`] properties.
 ///
 #[doc = concat!(
     "![Styled label](",
@@ -260,6 +260,7 @@ impl Label {
         font_ctx: &mut FontContext,
         layout_ctx: &mut LayoutContext<BrushIndex>,
         fonts_changed: bool,
+        text_scale: f32,
         max_advance: Option<f32>,
         commit: bool,
     ) {
@@ -272,18 +273,14 @@ impl Label {
         let styles_changed = self.styles_changed || fonts_changed;
         if styles_changed {
             {
-                // TODO: Should we use a different scale?
-                // See https://github.com/linebender/xilem/issues/1264
-                let mut builder = layout_ctx.ranged_builder(font_ctx, &self.text, 1.0, true);
+                let mut builder = layout_ctx.ranged_builder(font_ctx, &self.text, text_scale, true);
                 for prop in self.styles.inner().values() {
                     builder.push_default(prop.to_owned());
                 }
                 builder.build_into(&mut self.measure_text_layout.layout, &self.text);
             }
             if commit {
-                // TODO: Should we use a different scale?
-                // See https://github.com/linebender/xilem/issues/1264
-                let mut builder = layout_ctx.ranged_builder(font_ctx, &self.text, 1.0, true);
+                let mut builder = layout_ctx.ranged_builder(font_ctx, &self.text, text_scale, true);
                 for prop in self.styles.inner().values() {
                     builder.push_default(prop.to_owned());
                 }
@@ -310,6 +307,7 @@ impl Label {
 impl HasProperty<ContentColor> for Label {}
 impl HasProperty<DisabledContentColor> for Label {}
 impl HasProperty<LineBreaking> for Label {}
+impl HasProperty<TextColor> for Label {}
 
 // --- MARK: IMPL WIDGET
 impl Widget for Label {
@@ -325,6 +323,7 @@ impl Widget for Label {
         LineBreaking::prop_changed(ctx, property_type);
         ContentColor::prop_changed(ctx, property_type);
         DisabledContentColor::prop_changed(ctx, property_type);
+        TextColor::prop_changed(ctx, property_type);
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
@@ -383,8 +382,16 @@ impl Widget for Label {
         .map(|v| v as f32);
 
         let fonts_changed = ctx.fonts_changed();
+        let text_scale = ctx.text_scale() as f32;
         let (font_ctx, layout_ctx) = ctx.text_contexts();
-        self.build_and_break(font_ctx, layout_ctx, fonts_changed, max_advance, false);
+        self.build_and_break(
+            font_ctx,
+            layout_ctx,
+            fonts_changed,
+            text_scale,
+            max_advance,
+            false,
+        );
 
         let length = if axis == inline {
             self.measure_text_layout.layout.width() // Inline length
@@ -415,8 +422,16 @@ impl Widget for Label {
         };
 
         let fonts_changed = ctx.fonts_changed();
+        let text_scale = ctx.text_scale() as f32;
         let (font_ctx, layout_ctx) = ctx.text_contexts();
-        self.build_and_break(font_ctx, layout_ctx, fonts_changed, max_advance, true);
+        self.build_and_break(
+            font_ctx,
+            layout_ctx,
+            fonts_changed,
+            text_scale,
+            max_advance,
+            true,
+        );
 
         if self.text_layout.needs_text_alignment {
             self.text_layout.layout.align(
@@ -439,19 +454,22 @@ impl Widget for Label {
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
-        let text_color = if ctx.is_disabled()
+        let content_color = if ctx.is_disabled()
             && let Some(dc) = props.get_defined::<DisabledContentColor>()
         {
             &dc.0
         } else {
             props.get::<ContentColor>()
         };
+        let text_color = props
+            .get::<TextColor>()
+            .resolve(content_color.color, ctx.effective_background());
 
         render_text(
             scene,
             Affine::IDENTITY,
             &self.text_layout.layout,
-            &[text_color.color.into()],
+            &[text_color.into()],
             self.hint,
         );
     }
@@ -0,0 +1,315 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::pointer::PointerButton;
+use crate::core::{
+    AccessCtx, AccessEvent, ArcStr, ChildrenIds, CursorIcon, EventCtx, LayoutCtx, MeasureCtx,
+    NewWidget, PaintCtx, PointerButtonEvent, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx,
+    RegisterCtx, StyleProperty, TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+    WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::{LayoutSize, LenReq, SizeDef};
+use crate::properties::ContentColor;
+use crate::theme;
+use crate::widgets::Label;
+
+/// A hyperlink, shown as text which is underlined when hovered or focused.
+///
+/// Emits [`LinkActivated`] when clicked with the primary or middle mouse button, or when
+/// Enter <kbd>↵</kbd> is pressed while it is focused.
+/// The link doesn't open its URL itself: the app decides whether to navigate in the app
+/// or to open the URL with [`open_url`](crate::core::EventCtx::open_url).
+pub struct Link {
+    label: WidgetPod<Label>,
+    url: ArcStr,
+    /// Whether the label is currently underlined.
+    underlined: bool,
+}
+
+// --- MARK: BUILDERS
+impl Link {
+    /// Creates a new link with the given text and URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use masonry::widgets::Link;
+    ///
+    /// let link = Link::new("Xilem", "https://github.com/linebender/xilem");
+    /// ```
+    pub fn new(text: impl Into<ArcStr>, url: impl Into<ArcStr>) -> Self {
+        let label =
+            NewWidget::new_with_props(Label::new(text), ContentColor::new(theme::LINK_COLOR));
+        Self {
+            label: label.to_pod(),
+            url: url.into(),
+            underlined: false,
+        }
+    }
+}
+
+// --- MARK: METHODS
+impl Link {
+    /// Returns the URL of this link.
+    pub fn url(&self) -> &ArcStr {
+        &self.url
+    }
+
+    fn activate(&self, ctx: &mut EventCtx<'_>, button: Option<PointerButton>) {
+        ctx.submit_action::<LinkActivated>(LinkActivated {
+            url: self.url.clone(),
+            button,
+        });
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Link {
+    /// Sets the URL of this link.
+    pub fn set_url(this: &mut WidgetMut<'_, Self>, url: impl Into<ArcStr>) {
+        this.widget.url = url.into();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Returns a mutable reference to the label showing the text.
+    pub fn label_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, Label> {
+        this.ctx.get_mut(&mut this.widget.label)
+    }
+}
+
+/// A link was activated.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LinkActivated {
+    /// The URL of the link.
+    pub url: ArcStr,
+    /// The pointer button used to activate the link.
+    ///
+    /// This is `None` when using the keyboard or an accessibility action.
+    /// Apps usually open the URL in a new tab or window when this is
+    /// [`PointerButton::Auxiliary`], i.e. the middle mouse button.
+    pub button: Option<PointerButton>,
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Link {
+    type Action = LinkActivated;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: Some(PointerButton::Primary | PointerButton::Auxiliary),
+                ..
+            }) => {
+                ctx.request_focus();
+                ctx.capture_pointer();
+            }
+            PointerEvent::Up(PointerButtonEvent {
+                button: button @ Some(PointerButton::Primary | PointerButton::Auxiliary),
+                ..
+            }) if ctx.is_active() && ctx.is_hovered() => {
+                self.activate(ctx, *button);
+            }
+            _ => (),
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        match event {
+            // Unlike buttons, links aren't activated with Space.
+            TextEvent::Keyboard(event)
+                if event.state.is_up() && event.key == Key::Named(NamedKey::Enter) =>
+            {
+                self.activate(ctx, None);
+            }
+            _ => (),
+        }
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        match event.action {
+            accesskit::Action::Click => {
+                self.activate(ctx, None);
+            }
+            _ => {}
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::HoveredChanged(_) | Update::FocusChanged(_) => {
+                let underlined = ctx.is_hovered() || ctx.is_focus_target();
+                if underlined != self.underlined {
+                    self.underlined = underlined;
+                    ctx.mutate_self_later(move |mut link| {
+                        let mut link = link.downcast::<Self>();
+                        let mut label = Self::label_mut(&mut link);
+                        Label::insert_style(&mut label, StyleProperty::Underline(underlined));
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.label);
+    }
+
+    fn property_changed(&mut self, _ctx: &mut UpdateCtx<'_>, _property_type: TypeId) {}
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        ctx.compute_length(
+            &mut self.label,
+            len_req.into(),
+            context_size,
+            axis,
+            cross_length,
+        )
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let label_size = ctx.compute_size(&mut self.label, SizeDef::fit(size), size.into());
+        ctx.run_layout(&mut self.label, label_size);
+        ctx.place_child(&mut self.label, Point::ORIGIN);
+
+        let label_baseline = ctx.child_baseline_offset(&self.label);
+        ctx.set_baseline_offset(label_baseline + size.height - label_size.height);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn get_cursor(&self, _ctx: &QueryCtx<'_>, _pos: Point) -> CursorIcon {
+        CursorIcon::Pointer
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Link
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_url(self.url.to_string());
+        node.add_action(accesskit::Action::Click);
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.label.id()])
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Link", id = id.trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(self.url.to_string())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use masonry_testing::TestHarnessParams;
+
+    use super::*;
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+
+    const URL: &str = "https://linebender.org";
+
+    const HARNESS_PARAMS: TestHarnessParams = {
+        let mut params = TestHarnessParams::DEFAULT;
+        params.window_size = Size::new(120.0, 40.0);
+        params
+    };
+
+    #[test]
+    fn link_is_underlined_on_hover() {
+        let link = NewWidget::new(Link::new("Linebender", URL));
+        let mut harness = TestHarness::create_with(test_property_set(), link, HARNESS_PARAMS);
+        let link_id = harness.root_id();
+
+        assert_render_snapshot!(harness, "link_default");
+        harness.mouse_move_to(link_id);
+        assert_render_snapshot!(harness, "link_hovered");
+        assert_eq!(harness.cursor_icon(), CursorIcon::Pointer);
+    }
+
+    #[test]
+    fn activate_link() {
+        let link = NewWidget::new(Link::new("Linebender", URL));
+        let mut harness = TestHarness::create_with(test_property_set(), link, HARNESS_PARAMS);
+        let link_id = harness.root_id();
+        let activated = |button| LinkActivated {
+            url: URL.into(),
+            button,
+        };
+
+        harness.mouse_click_on(link_id);
+        assert_eq!(
+            harness.pop_action::<LinkActivated>(),
+            Some((activated(Some(PointerButton::Primary)), link_id))
+        );
+
+        harness.mouse_button_press(PointerButton::Auxiliary);
+        harness.mouse_button_release(PointerButton::Auxiliary);
+        assert_eq!(
+            harness.pop_action::<LinkActivated>(),
+            Some((activated(Some(PointerButton::Auxiliary)), link_id))
+        );
+
+        // The secondary button is left for context menus.
+        harness.mouse_button_press(PointerButton::Secondary);
+        harness.mouse_button_release(PointerButton::Secondary);
+        assert_eq!(harness.pop_action::<LinkActivated>(), None);
+
+        harness.focus_on(Some(link_id));
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::Enter)));
+        harness.process_text_event(TextEvent::key_up(Key::Named(NamedKey::Enter)));
+        assert_eq!(
+            harness.pop_action::<LinkActivated>(),
+            Some((activated(None), link_id))
+        );
+
+        // The link doesn't open the URL itself.
+        assert!(harness.take_opened_urls().is_empty());
+    }
+}
@@ -0,0 +1,714 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{
+    AccessCtx, ChildrenIds, CollectionWidget, EventCtx, HasProperty, LayoutCtx, MeasureCtx,
+    NewWidget, PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PointerUpdate,
+    PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId,
+    WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Rect, Size};
+use crate::layout::{LayoutSize, LenDef, LenReq};
+use crate::properties::{FocusedBorderColor, SelectedBackground, SelectionColor};
+use crate::util::{fill, stroke};
+use crate::widgets::{SelectionChanged, SelectionMode, SelectionModel};
+
+/// The space between the top and bottom edges of a row and its content.
+const ROW_PADDING_V: f64 = 4.;
+/// The space between the left and right edges of a row and its content.
+const ROW_PADDING_H: f64 = 8.;
+
+struct Row {
+    key: String,
+    widget: WidgetPod<dyn Widget>,
+    /// The area of the row, as placed by the last layout pass.
+    rect: Rect,
+}
+
+/// A rubber band being dragged from `start` to `pointer`.
+struct Band {
+    start: Point,
+    pointer: Point,
+}
+
+/// A vertical list of rows which users can select, such as the files of a folder.
+///
+/// Each row is a child widget with a key, which identifies it in the selection
+/// even when rows are added, removed or reordered.
+/// The selection follows the [`SelectionMode`] of the list:
+/// - Clicking a row selects it.
+/// - In [`Multiple`](SelectionMode::Multiple) mode, <kbd>Ctrl</kbd> (or <kbd>Cmd</kbd>
+///   on macOS) clicks toggle rows, <kbd>Shift</kbd> clicks select ranges of rows,
+///   and dragging from the space below the rows selects the rows covered by a rubber band.
+/// - The arrow keys, <kbd>Home</kbd> and <kbd>End</kbd> move the selection, and extend it
+///   with <kbd>Shift</kbd>.
+///   <kbd>Ctrl</kbd>+<kbd>A</kbd> selects all rows.
+///
+/// The list emits [`SelectionChanged`] with the keys of the selected rows each time the
+/// user changes the selection.
+/// The selection is kept in a [`SelectionModel`], which other widgets can use to select
+/// their items the same way.
+///
+/// You can customize the background of the selected rows with the [`SelectedBackground`]
+/// property, and the color of the rubber band with the [`SelectionColor`] property.
+pub struct List {
+    rows: Vec<Row>,
+    selection: SelectionModel<String>,
+    /// The row that keyboard navigation starts from.
+    cursor: usize,
+    band: Option<Band>,
+}
+
+// --- MARK: BUILDERS
+impl List {
+    /// Creates an empty list with the given selection mode.
+    pub fn new(mode: SelectionMode) -> Self {
+        Self {
+            rows: Vec::new(),
+            selection: SelectionModel::new(mode),
+            cursor: 0,
+            band: None,
+        }
+    }
+
+    /// Builder-style method to add a row with the given key.
+    pub fn with(mut self, child: NewWidget<impl Widget + ?Sized>, key: impl Into<String>) -> Self {
+        self.rows.push(Row::new(key.into(), child));
+        self
+    }
+
+    /// Builder-style method to select the rows with the given keys.
+    ///
+    /// In [`Single`](SelectionMode::Single) mode, only the first key is selected.
+    pub fn with_selected(mut self, keys: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.selection
+            .set_selected(keys.into_iter().map(Into::into));
+        self
+    }
+}
+
+impl Row {
+    fn new(key: String, child: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            key,
+            widget: child.erased().to_pod(),
+            rect: Rect::ZERO,
+        }
+    }
+}
+
+// --- MARK: METHODS
+impl List {
+    /// Returns the selected rows.
+    pub fn selection(&self) -> &SelectionModel<String> {
+        &self.selection
+    }
+
+    /// Returns the key of the row at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn key(&self, idx: usize) -> &str {
+        &self.rows[idx].key
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl List {
+    /// Sets how many rows can be selected, and deselects the rows which no longer fit.
+    ///
+    /// This doesn't emit [`SelectionChanged`].
+    pub fn set_selection_mode(this: &mut WidgetMut<'_, Self>, mode: SelectionMode) {
+        this.widget.band = None;
+        this.widget.selection.set_mode(mode);
+        this.ctx.request_render();
+    }
+
+    /// Selects the rows with the given keys.
+    ///
+    /// In [`Single`](SelectionMode::Single) mode, only the first key is selected.
+    /// This doesn't emit [`SelectionChanged`].
+    pub fn set_selected(
+        this: &mut WidgetMut<'_, Self>,
+        keys: impl IntoIterator<Item = impl Into<String>>,
+    ) {
+        this.widget.band = None;
+        if this
+            .widget
+            .selection
+            .set_selected(keys.into_iter().map(Into::into))
+        {
+            this.ctx.request_render();
+        }
+    }
+}
+
+// --- MARK: COLLECTIONWIDGET
+impl CollectionWidget<String> for List {
+    /// Returns the number of rows.
+    fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns `true` if there are no rows.
+    fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns a mutable reference to the content of the row at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn get_mut<'t>(this: &'t mut WidgetMut<'_, Self>, idx: usize) -> WidgetMut<'t, dyn Widget> {
+        let widget = &mut this.widget.rows[idx].widget;
+        this.ctx.get_mut(widget)
+    }
+
+    /// Appends a row with the given key.
+    fn add(
+        this: &mut WidgetMut<'_, Self>,
+        child: NewWidget<impl Widget + ?Sized>,
+        key: impl Into<String>,
+    ) {
+        this.widget.rows.push(Row::new(key.into(), child));
+        this.ctx.children_changed();
+    }
+
+    /// Inserts a row with the given key at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the number of rows.
+    fn insert(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        child: NewWidget<impl Widget + ?Sized>,
+        key: impl Into<String>,
+    ) {
+        this.widget.rows.insert(idx, Row::new(key.into(), child));
+        this.ctx.children_changed();
+    }
+
+    /// Replaces the row at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn set(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        child: NewWidget<impl Widget + ?Sized>,
+        key: impl Into<String>,
+    ) {
+        let row = Row::new(key.into(), child);
+        let old_row = std::mem::replace(&mut this.widget.rows[idx], row);
+        this.ctx.remove_child(old_row.widget);
+        Self::forget_removed_keys(this);
+    }
+
+    /// Sets the key of the row at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn set_params(this: &mut WidgetMut<'_, Self>, idx: usize, key: impl Into<String>) {
+        this.widget.rows[idx].key = key.into();
+        Self::forget_removed_keys(this);
+        this.ctx.request_render();
+    }
+
+    /// Swaps the index of two rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    fn swap(this: &mut WidgetMut<'_, Self>, a: usize, b: usize) {
+        this.widget.rows.swap(a, b);
+        this.ctx.children_changed();
+    }
+
+    /// Removes the row at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn remove(this: &mut WidgetMut<'_, Self>, idx: usize) {
+        let row = this.widget.rows.remove(idx);
+        this.ctx.remove_child(row.widget);
+        Self::forget_removed_keys(this);
+    }
+
+    /// Removes all rows.
+    fn clear(this: &mut WidgetMut<'_, Self>) {
+        for row in this.widget.rows.drain(..) {
+            this.ctx.remove_child(row.widget);
+        }
+        Self::forget_removed_keys(this);
+    }
+}
+
+// --- MARK: HELPERS
+impl List {
+    fn keys(&self) -> Vec<String> {
+        self.rows.iter().map(|row| row.key.clone()).collect()
+    }
+
+    fn row_at(&self, pos: Point) -> Option<usize> {
+        self.rows.iter().position(|row| row.rect.contains(pos))
+    }
+
+    fn band_rect(&self) -> Option<Rect> {
+        self.band
+            .as_ref()
+            .map(|band| Rect::from_points(band.start, band.pointer))
+    }
+
+    fn submit_selection(&self, ctx: &mut EventCtx<'_>) {
+        let selected = self.selection.selected_in(&self.keys());
+        ctx.submit_action::<SelectionChanged<String>>(SelectionChanged { selected });
+        ctx.request_render();
+    }
+
+    /// Deselects the keys which no longer have a row.
+    ///
+    /// This doesn't emit [`SelectionChanged`], as the app removed the rows.
+    fn forget_removed_keys(this: &mut WidgetMut<'_, Self>) {
+        let list = &mut *this.widget;
+        list.band = None;
+        list.cursor = list.cursor.min(list.rows.len().saturating_sub(1));
+        let rows = &list.rows;
+        list.selection
+            .retain(|key| rows.iter().any(|row| row.key == *key));
+    }
+}
+
+impl HasProperty<SelectedBackground> for List {}
+impl HasProperty<SelectionColor> for List {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for List {
+    type Action = SelectionChanged<String>;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: None | Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                // Let interactive widgets inside rows handle their own presses.
+                if ctx.pointer_capture_target_id().is_some() {
+                    return;
+                }
+                ctx.request_focus();
+                let pos = ctx.local_position(state.position);
+                if let Some(idx) = self.row_at(pos) {
+                    self.cursor = idx;
+                    let key = self.rows[idx].key.clone();
+                    if self.selection.click(&self.keys(), &key, state.modifiers) {
+                        self.submit_selection(ctx);
+                    }
+                    ctx.request_paint_only();
+                } else if self.selection.mode() == SelectionMode::Multiple {
+                    let extend = state.modifiers.shift()
+                        || if cfg!(target_os = "macos") {
+                            state.modifiers.meta()
+                        } else {
+                            state.modifiers.ctrl()
+                        };
+                    if self.selection.begin_band(extend) {
+                        self.submit_selection(ctx);
+                    }
+                    self.band = Some(Band {
+                        start: pos,
+                        pointer: pos,
+                    });
+                    ctx.capture_pointer();
+                    ctx.request_edge_auto_scroll();
+                }
+                ctx.set_handled();
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) if ctx.is_active() => {
+                let Some(band) = &mut self.band else {
+                    return;
+                };
+                band.pointer = ctx.local_position(current.position);
+                let rect = Rect::from_points(band.start, band.pointer);
+                let covered = self
+                    .rows
+                    .iter()
+                    // Rows span the whole width, so only the vertical extent matters.
+                    .filter(|row| row.rect.y0 <= rect.y1 && rect.y0 < row.rect.y1)
+                    .map(|row| row.key.clone());
+                if self.selection.update_band(covered) {
+                    self.submit_selection(ctx);
+                }
+                ctx.request_post_paint();
+            }
+            PointerEvent::Up(..) | PointerEvent::Cancel(..) if self.band.take().is_some() => {
+                self.selection.end_band();
+                ctx.request_post_paint();
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if !ctx.is_focus_target() || self.rows.is_empty() {
+            return;
+        }
+        let TextEvent::Keyboard(key_event) = event else {
+            return;
+        };
+        if key_event.state.is_up() {
+            return;
+        }
+        let keys = self.keys();
+        let modifiers = key_event.modifiers;
+        let ctrl = if cfg!(target_os = "macos") {
+            modifiers.meta()
+        } else {
+            modifiers.ctrl()
+        };
+        let last = self.rows.len() - 1;
+        let current = self.cursor.min(last);
+        let target = match &key_event.key {
+            Key::Character(c) if ctrl && c.eq_ignore_ascii_case("a") => {
+                if self.selection.select_all(&keys) {
+                    self.submit_selection(ctx);
+                }
+                ctx.set_handled();
+                return;
+            }
+            Key::Named(NamedKey::ArrowUp) => current.saturating_sub(1),
+            Key::Named(NamedKey::ArrowDown) => (current + 1).min(last),
+            Key::Named(NamedKey::Home) => 0,
+            Key::Named(NamedKey::End) => last,
+            _ => return,
+        };
+        self.cursor = target;
+        let changed = if modifiers.shift() {
+            self.selection.select_range(&keys, &keys[target], false)
+        } else {
+            self.selection.select(keys[target].clone())
+        };
+        if changed {
+            self.submit_selection(ctx);
+        }
+        ctx.request_paint_only();
+        ctx.set_handled();
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::FocusChanged(_) = event {
+            ctx.request_paint_only();
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for row in &mut self.rows {
+            ctx.register_child(&mut row.widget);
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        SelectedBackground::prop_changed(ctx, property_type);
+        if property_type == TypeId::of::<SelectionColor>() {
+            ctx.request_paint_only();
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let mut measure_width = |ctx: &mut MeasureCtx<'_>, len_req: LenReq| {
+            let auto_length = len_req.into();
+            let widest = self
+                .rows
+                .iter_mut()
+                .map(|row| {
+                    ctx.compute_length(
+                        &mut row.widget,
+                        auto_length,
+                        LayoutSize::NONE,
+                        Axis::Horizontal,
+                        None,
+                    )
+                })
+                .fold(0., f64::max);
+            widest + 2. * ROW_PADDING_H
+        };
+
+        match axis {
+            Axis::Horizontal => measure_width(ctx, len_req),
+            Axis::Vertical => {
+                let width = match cross_length {
+                    Some(width) => width,
+                    None => measure_width(ctx, LenReq::MaxContent),
+                };
+                let inner_width = (width - 2. * ROW_PADDING_H).max(0.);
+                let context_size = LayoutSize::one(Axis::Horizontal, inner_width);
+                self.rows
+                    .iter_mut()
+                    .map(|row| {
+                        ctx.compute_length(
+                            &mut row.widget,
+                            LenDef::MaxContent,
+                            context_size,
+                            Axis::Vertical,
+                            Some(inner_width),
+                        ) + 2. * ROW_PADDING_V
+                    })
+                    .sum()
+            }
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let inner_width = (size.width - 2. * ROW_PADDING_H).max(0.);
+        let context_size = LayoutSize::one(Axis::Horizontal, inner_width);
+        let mut y = 0.;
+        for row in &mut self.rows {
+            let height = ctx.compute_length(
+                &mut row.widget,
+                LenDef::MaxContent,
+                context_size,
+                Axis::Vertical,
+                Some(inner_width),
+            );
+            ctx.run_layout(&mut row.widget, Size::new(inner_width, height));
+            ctx.place_child(
+                &mut row.widget,
+                Point::new(ROW_PADDING_H, y + ROW_PADDING_V),
+            );
+            let bottom = y + height + 2. * ROW_PADDING_V;
+            row.rect = Rect::new(0., y, size.width, bottom);
+            y = bottom;
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let background = props.get::<SelectedBackground>();
+        for row in &self.rows {
+            if self.selection.is_selected(&row.key) {
+                fill(
+                    scene,
+                    &row.rect,
+                    &background.0.get_peniko_brush_for_rect(row.rect),
+                );
+            }
+        }
+
+        if ctx.is_focus_target()
+            && let Some(row) = self.rows.get(self.cursor)
+            && let Some(focus) = props.get_defined::<FocusedBorderColor>()
+        {
+            stroke(scene, &row.rect.inset(-0.5), focus.0.color, 1.);
+        }
+    }
+
+    fn post_paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        props: &PropertiesRef<'_>,
+        scene: &mut Scene,
+    ) {
+        // The rubber band is painted above the rows.
+        if let Some(rect) = self.band_rect() {
+            let color = props.get::<SelectionColor>().color;
+            fill(scene, &rect, color.with_alpha(0.25));
+            stroke(scene, &rect, color, 1.);
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::ListBox
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        if self.selection.mode() == SelectionMode::Multiple {
+            node.set_multiselectable();
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.rows.iter().map(|row| row.widget.id()).collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("List", id = id.trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(format!("{:?}", self.selection.selected_in(&self.keys())))
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use dpi::PhysicalPosition;
+
+    use super::*;
+    use crate::core::Modifiers;
+    use crate::core::pointer::PointerState;
+    use crate::testing::{PRIMARY_MOUSE, TestHarness};
+    use crate::theme::test_property_set;
+    use crate::widgets::Label;
+
+    fn three_rows(mode: SelectionMode) -> NewWidget<List> {
+        List::new(mode)
+            .with(Label::new("Apples").with_auto_id(), "apples")
+            .with(Label::new("Pears").with_auto_id(), "pears")
+            .with(Label::new("Plums").with_auto_id(), "plums")
+            .with_auto_id()
+    }
+
+    fn click_with(harness: &mut TestHarness<List>, pos: Point, modifiers: Modifiers) {
+        harness.mouse_move(pos);
+        harness.process_pointer_event(PointerEvent::Down(PointerButtonEvent {
+            pointer: PRIMARY_MOUSE,
+            button: Some(PointerButton::Primary),
+            state: PointerState {
+                position: PhysicalPosition::new(pos.x, pos.y),
+                modifiers,
+                ..Default::default()
+            },
+        }));
+        harness.mouse_button_release(PointerButton::Primary);
+    }
+
+    fn row_center(harness: &TestHarness<List>, idx: usize) -> Point {
+        harness.root_widget().inner().rows[idx].rect.center()
+    }
+
+    fn selected(harness: &mut TestHarness<List>) -> Option<Vec<String>> {
+        harness
+            .pop_action::<SelectionChanged<String>>()
+            .map(|(action, _)| action.selected)
+    }
+
+    #[test]
+    fn click_selects_rows() {
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            three_rows(SelectionMode::Multiple),
+            Size::new(200., 200.),
+        );
+
+        let pears = row_center(&harness, 1);
+        click_with(&mut harness, pears, Modifiers::empty());
+        assert_eq!(selected(&mut harness), Some(vec!["pears".into()]));
+
+        let plums = row_center(&harness, 2);
+        click_with(&mut harness, plums, Modifiers::SHIFT);
+        assert_eq!(
+            selected(&mut harness),
+            Some(vec!["pears".into(), "plums".into()])
+        );
+
+        click_with(&mut harness, plums, Modifiers::empty());
+        assert_eq!(selected(&mut harness), Some(vec!["plums".into()]));
+        // Clicking the only selected row again changes nothing.
+        click_with(&mut harness, plums, Modifiers::empty());
+        assert_eq!(selected(&mut harness), None);
+    }
+
+    #[test]
+    fn rubber_band_selects_covered_rows() {
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            three_rows(SelectionMode::Multiple),
+            Size::new(200., 200.),
+        );
+
+        // Drag from the empty space below the rows up to the second row.
+        harness.mouse_move((100., 190.));
+        harness.mouse_button_press(PointerButton::Primary);
+        let pears = row_center(&harness, 1);
+        harness.mouse_move(pears);
+        harness.mouse_button_release(PointerButton::Primary);
+
+        let mut last = None;
+        while let Some(selection) = selected(&mut harness) {
+            last = Some(selection);
+        }
+        assert_eq!(last, Some(vec!["pears".into(), "plums".into()]));
+        assert!(!harness.root_widget().inner().selection().is_banding());
+    }
+
+    #[test]
+    fn keyboard_moves_selection() {
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            three_rows(SelectionMode::Multiple),
+            Size::new(200., 200.),
+        );
+        let apples = row_center(&harness, 0);
+        click_with(&mut harness, apples, Modifiers::empty());
+        let _ = selected(&mut harness);
+
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowDown)));
+        assert_eq!(selected(&mut harness), Some(vec!["pears".into()]));
+
+        let mut end = TextEvent::key_down(Key::Named(NamedKey::End));
+        if let TextEvent::Keyboard(event) = &mut end {
+            event.modifiers = Modifiers::SHIFT;
+        }
+        harness.process_text_event(end);
+        assert_eq!(
+            selected(&mut harness),
+            Some(vec!["pears".into(), "plums".into()])
+        );
+    }
+
+    #[test]
+    fn removed_rows_are_deselected() {
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            three_rows(SelectionMode::Multiple),
+            Size::new(200., 200.),
+        );
+        harness.edit_root_widget(|mut list| {
+            List::set_selected(&mut list, ["apples", "plums"]);
+            List::remove(&mut list, 0);
+        });
+
+        let list = harness.root_widget().inner();
+        assert_eq!(list.selection().selected_in(&list.keys()), ["plums"]);
+        assert_eq!(list.key(0), "pears");
+    }
+}
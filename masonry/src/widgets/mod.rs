@@ -4,58 +4,126 @@
 //! Common widgets.
 
 mod align;
+mod avatar;
+mod badge;
 mod button;
+mod calendar;
 mod canvas;
 mod checkbox;
+mod clip;
+mod command_palette;
+mod crossfade;
 mod divider;
+mod dock_area;
 mod flex;
+mod frozen_pane;
+mod gauge;
+mod graph_canvas;
 mod grid;
+mod heatmap;
 mod image;
+mod image_cache;
 mod indexed_stack;
+mod input_filter;
 mod label;
+mod link;
+mod list;
+mod navigation_stack;
+mod page_indicator;
+mod pager;
 mod passthrough;
+mod password_input;
 mod portal;
 mod progress_bar;
 mod prose;
+mod pull_to_refresh;
+mod rating;
 mod resize_observer;
+mod rich_text;
 mod scroll_bar;
+mod section_header;
+mod segmented_control;
+mod selection_model;
 mod sized_box;
+mod skeleton;
 mod slider;
+mod spell_checker;
 mod spinner;
 mod split;
+mod step_indicator;
 mod switch;
 mod text_area;
+mod text_decoration;
 mod text_input;
+mod token_input;
 mod variable_label;
 mod virtual_scroll;
+mod visibility_observer;
+mod waterfall;
+mod web_view;
+mod wgpu_surface_view;
 mod zstack;
 
 // TODO - Split off widgets and other exports?
 // (e.g. actions, param types)
 
 pub use self::align::*;
+pub use self::avatar::*;
+pub use self::badge::*;
 pub use self::button::*;
+pub use self::calendar::*;
 pub use self::canvas::*;
 pub use self::checkbox::*;
+pub use self::clip::*;
+pub use self::command_palette::*;
+pub use self::crossfade::*;
 pub use self::divider::*;
+pub use self::dock_area::*;
 pub use self::flex::*;
+pub use self::frozen_pane::*;
+pub use self::gauge::*;
+pub use self::graph_canvas::*;
 pub use self::grid::*;
+pub use self::heatmap::*;
 pub use self::image::*;
+pub use self::image_cache::*;
 pub use self::indexed_stack::*;
+pub use self::input_filter::*;
 pub use self::label::*;
+pub use self::link::*;
+pub use self::list::*;
+pub use self::navigation_stack::*;
+pub use self::page_indicator::*;
+pub use self::pager::*;
 pub use self::passthrough::*;
+pub use self::password_input::*;
 pub use self::portal::*;
 pub use self::progress_bar::*;
 pub use self::prose::*;
+pub use self::pull_to_refresh::*;
+pub use self::rating::*;
 pub use self::resize_observer::*;
+pub use self::rich_text::*;
 pub use self::scroll_bar::*;
+pub use self::section_header::*;
+pub use self::segmented_control::*;
+pub use self::selection_model::*;
 pub use self::sized_box::*;
+pub use self::skeleton::*;
 pub use self::slider::*;
+pub use self::spell_checker::*;
 pub use self::spinner::*;
 pub use self::split::*;
+pub use self::step_indicator::*;
 pub use self::switch::*;
 pub use self::text_area::*;
+pub use self::text_decoration::*;
 pub use self::text_input::*;
+pub use self::token_input::*;
 pub use self::variable_label::*;
 pub use self::virtual_scroll::*;
+pub use self::visibility_observer::*;
+pub use self::waterfall::*;
+pub use self::web_view::*;
+pub use self::wgpu_surface_view::*;
 pub use self::zstack::*;
@@ -0,0 +1,517 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::pointer::PointerButton;
+use crate::core::{
+    AccessCtx, ChildrenIds, ComposeCtx, EventCtx, LayoutCtx, MeasureCtx, NewWidget, PaintCtx,
+    PointerButtonEvent, PointerEvent, PropertiesMut, PropertiesRef, RegisterCtx, TextEvent,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Size, Vec2};
+use crate::layout::{LayoutSize, LenReq};
+
+/// How long pages take to slide in or out.
+const TRANSITION_NANOS: f64 = 250_000_000.;
+/// The width of the area along the left edge where a back swipe can start.
+const EDGE_WIDTH: f64 = 24.;
+/// The fraction of the width a back swipe must cover to go back.
+const SWIPE_THRESHOLD: f64 = 0.3;
+
+/// A stack of full-size pages, of which only the top one is shown.
+///
+/// Pages are pushed with [`NavigationStack::push`], which slides the new page in from the
+/// right, and popped with [`NavigationStack::pop`], which slides the top page out.
+/// If the user [prefers reduced motion], pages change without sliding.
+///
+/// The pages below the top one are kept in the tree, stashed, so they keep their state
+/// (such as scroll positions and text being edited) until they are popped.
+///
+/// When there is more than one page, the stack emits [`NavigateBack`] when the user asks to
+/// go back, by:
+/// - pressing <kbd>Esc</kbd> while a widget in the stack is focused, unless that widget
+///   handles the key itself;
+/// - pressing the "back" button of their mouse, or the system back button on Android
+///   (which is delivered as the [`BrowserBack`](NamedKey::BrowserBack) key);
+/// - swiping right from the left edge.
+///
+/// The stack doesn't pop the page itself: it's up to the app to decide whether to go back.
+///
+/// [prefers reduced motion]: crate::core::EventCtx::prefers_reduced_motion
+#[derive(Default)]
+pub struct NavigationStack {
+    pages: Vec<WidgetPod<dyn Widget>>,
+    /// A popped page, kept until it finishes sliding out.
+    leaving: Option<WidgetPod<dyn Widget>>,
+    transition: Option<Transition>,
+    /// The pointer position where the current back swipe started.
+    swipe_start: Option<f64>,
+    width: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Transition {
+    kind: TransitionKind,
+    /// How far the transition is, from 0 to 1.
+    progress: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TransitionKind {
+    Push,
+    Pop,
+}
+
+/// The action emitted by [`NavigationStack`] when the user asks to go back.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct NavigateBack;
+
+// --- MARK: BUILDERS
+impl NavigationStack {
+    /// Creates a new stack with no pages.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder-style method to push a page, without sliding it in.
+    pub fn with(mut self, page: NewWidget<impl Widget + ?Sized>) -> Self {
+        self.pages.push(page.erased().to_pod());
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl NavigationStack {
+    /// Returns the number of pages.
+    pub fn len(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Returns `true` if there are no pages.
+    pub fn is_empty(&self) -> bool {
+        self.pages.is_empty()
+    }
+
+    /// Returns `true` if pages are sliding in or out.
+    pub fn is_transitioning(&self) -> bool {
+        self.transition.is_some()
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl NavigationStack {
+    /// Pushes a page on top of the stack, and slides it in.
+    pub fn push(this: &mut WidgetMut<'_, Self>, page: NewWidget<impl Widget + ?Sized>) {
+        if let Some(leaving) = this.widget.leaving.take() {
+            this.ctx.remove_child(leaving);
+        }
+        this.widget.pages.push(page.erased().to_pod());
+        Self::start_transition(this, TransitionKind::Push);
+        this.ctx.children_changed();
+    }
+
+    /// Pops the top page, and slides it out.
+    ///
+    /// Does nothing if there are no pages.
+    pub fn pop(this: &mut WidgetMut<'_, Self>) {
+        Self::truncate(this, this.widget.pages.len().saturating_sub(1));
+    }
+
+    /// Pops pages until there are at most `len` pages left.
+    ///
+    /// The top page slides out, and the others are removed at once.
+    pub fn truncate(this: &mut WidgetMut<'_, Self>, len: usize) {
+        if len >= this.widget.pages.len() {
+            return;
+        }
+        if let Some(leaving) = this.widget.leaving.take() {
+            this.ctx.remove_child(leaving);
+        }
+        this.widget.leaving = this.widget.pages.pop();
+        for page in this.widget.pages.drain(len..) {
+            this.ctx.remove_child(page);
+        }
+        Self::start_transition(this, TransitionKind::Pop);
+        this.ctx.children_changed();
+    }
+
+    /// Returns a mutable reference to the page at the given index, starting from the bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn page_mut<'t>(
+        this: &'t mut WidgetMut<'_, Self>,
+        idx: usize,
+    ) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.pages[idx])
+    }
+
+    fn start_transition(this: &mut WidgetMut<'_, Self>, kind: TransitionKind) {
+        this.widget.transition = Some(Transition { kind, progress: 0. });
+        this.widget.swipe_start = None;
+        // The pages slide in `on_anim_frame`.
+        this.ctx.request_anim_frame();
+    }
+}
+
+// --- MARK: HELPERS
+impl NavigationStack {
+    /// Returns the indices of the pages which are visible, from the bottom one.
+    fn visible_pages(&self) -> impl Iterator<Item = usize> {
+        let len = self.pages.len();
+        // While a page is pushed, the page below it is visible until it is covered.
+        let below = match self.transition {
+            Some(Transition {
+                kind: TransitionKind::Push,
+                ..
+            }) => len.checked_sub(2),
+            _ => None,
+        };
+        below.into_iter().chain(len.checked_sub(1))
+    }
+
+    /// Emits [`NavigateBack`] if there's a page to go back to.
+    fn navigate_back(&mut self, ctx: &mut EventCtx<'_>) {
+        if self.pages.len() > 1 {
+            ctx.submit_action::<NavigateBack>(NavigateBack);
+            ctx.set_handled();
+        }
+    }
+}
+
+/// The eased offset of a page sliding in, from 1 (a full width away) to 0.
+fn ease_out(progress: f64) -> f64 {
+    (1. - progress).powi(3)
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for NavigationStack {
+    type Action = NavigateBack;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                state,
+                ..
+            }) if self.pages.len() > 1 => {
+                let x = ctx.local_position(state.position).x;
+                if x < EDGE_WIDTH {
+                    self.swipe_start = Some(x);
+                    ctx.capture_pointer();
+                }
+            }
+            PointerEvent::Up(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                if let Some(start_x) = self.swipe_start.take() {
+                    let x = ctx.local_position(state.position).x;
+                    if x - start_x > self.width * SWIPE_THRESHOLD {
+                        self.navigate_back(ctx);
+                    }
+                }
+            }
+            PointerEvent::Cancel(..) => {
+                self.swipe_start = None;
+            }
+            PointerEvent::Up(PointerButtonEvent {
+                button: Some(PointerButton::X1),
+                ..
+            }) => {
+                self.navigate_back(ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if let TextEvent::Keyboard(key_event) = event
+            && key_event.state.is_down()
+            && matches!(
+                key_event.key,
+                Key::Named(NamedKey::Escape | NamedKey::BrowserBack)
+            )
+        {
+            self.navigate_back(ctx);
+        }
+    }
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let Some(transition) = &mut self.transition else {
+            return;
+        };
+        transition.progress = if ctx.prefers_reduced_motion() {
+            1.
+        } else {
+            (transition.progress + interval as f64 / TRANSITION_NANOS).min(1.)
+        };
+        if transition.progress >= 1. {
+            self.transition = None;
+            if let Some(leaving) = self.leaving.take() {
+                ctx.remove_child(leaving);
+            }
+            // The page below the top one is stashed again.
+            ctx.request_layout();
+        } else {
+            ctx.request_anim_frame();
+        }
+        ctx.request_compose();
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for page in self.pages.iter_mut() {
+            ctx.register_child(page);
+        }
+        if let Some(leaving) = &mut self.leaving {
+            ctx.register_child(leaving);
+        }
+    }
+
+    fn property_changed(&mut self, _ctx: &mut UpdateCtx<'_>, _property_type: TypeId) {}
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        if let LenReq::FitContent(space) = len_req {
+            return space;
+        }
+        let Some(top) = self.pages.last_mut() else {
+            return 0.;
+        };
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        ctx.compute_length(top, len_req.into(), context_size, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        self.width = size.width;
+
+        // TODO: move set_stashed to a different layout pass when possible
+        let visible_pages: Vec<usize> = self.visible_pages().collect();
+        for (idx, page) in self.pages.iter_mut().enumerate() {
+            let visible = visible_pages.contains(&idx);
+            ctx.set_stashed(page, !visible);
+            if visible {
+                ctx.run_layout(page, size);
+                ctx.place_child(page, Point::ORIGIN);
+            }
+        }
+        if let Some(leaving) = &mut self.leaving {
+            ctx.run_layout(leaving, size);
+            ctx.place_child(leaving, Point::ORIGIN);
+        }
+
+        ctx.set_clip_path(size.to_rect());
+    }
+
+    fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
+        let offset = self
+            .transition
+            .map_or(0., |transition| self.width * ease_out(transition.progress));
+        match self.transition.map(|transition| transition.kind) {
+            Some(TransitionKind::Push) => {
+                if let Some(top) = self.pages.last_mut() {
+                    ctx.set_child_scroll_translation(top, Vec2::new(offset, 0.));
+                }
+            }
+            Some(TransitionKind::Pop) => {
+                if let Some(leaving) = &mut self.leaving {
+                    let translation = Vec2::new(self.width - offset, 0.);
+                    ctx.set_child_scroll_translation(leaving, translation);
+                }
+            }
+            None => {
+                if let Some(top) = self.pages.last_mut() {
+                    ctx.set_child_scroll_translation(top, Vec2::ZERO);
+                }
+            }
+        }
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_clips_children();
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        // The leaving page is last, to be painted over the page it reveals.
+        self.pages
+            .iter()
+            .chain(&self.leaving)
+            .map(|page| page.id())
+            .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("NavigationStack", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{WidgetOptions, WidgetTag, WindowEvent};
+    use crate::palette;
+    use crate::properties::{Background, Dimensions};
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::{SizedBox, TextInput};
+
+    fn page(color: crate::peniko::Color) -> NewWidget<SizedBox> {
+        SizedBox::empty().with_props(Background::Color(color))
+    }
+
+    fn stack(tag: WidgetTag<NavigationStack>) -> NewWidget<NavigationStack> {
+        NewWidget::new_with(
+            NavigationStack::new().with(page(palette::css::ORANGE)),
+            Some(tag),
+            WidgetOptions::default(),
+            Dimensions::STRETCH,
+        )
+    }
+
+    #[test]
+    fn push_and_pop() {
+        let tag = WidgetTag::named("stack");
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), stack(tag), Size::new(100., 100.));
+
+        harness.edit_widget(tag, |mut stack| {
+            NavigationStack::push(&mut stack, page(palette::css::TEAL));
+        });
+        harness.animate_ms(50);
+        {
+            let stack = harness.get_widget(tag);
+            assert!(stack.inner().is_transitioning());
+            // The page below stays visible while the new one slides in.
+            assert!(!stack.children()[0].ctx().is_stashed());
+        }
+        harness.animate_ms(1000);
+        {
+            let stack = harness.get_widget(tag);
+            assert!(!stack.inner().is_transitioning());
+            // The page below is kept, but stashed.
+            assert!(stack.children()[0].ctx().is_stashed());
+            assert!(!stack.children()[1].ctx().is_stashed());
+        }
+
+        harness.edit_widget(tag, |mut stack| {
+            NavigationStack::pop(&mut stack);
+        });
+        // The popped page is removed once it has slid out.
+        assert_eq!(harness.get_widget(tag).children().len(), 2);
+        harness.animate_ms(1000);
+        let stack = harness.get_widget(tag);
+        assert_eq!(stack.children().len(), 1);
+        assert_eq!(stack.inner().len(), 1);
+        assert!(!stack.children()[0].ctx().is_stashed());
+
+        // With reduced motion, pages change at once.
+        harness.process_window_event(WindowEvent::ReducedMotionChanged(true));
+        harness.edit_widget(tag, |mut stack| {
+            NavigationStack::push(&mut stack, page(palette::css::TEAL));
+        });
+        harness.animate_ms(16);
+        assert!(!harness.get_widget(tag).inner().is_transitioning());
+    }
+
+    #[test]
+    fn navigate_back() {
+        let tag = WidgetTag::named("stack");
+        let input_tag = WidgetTag::named("input");
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), stack(tag), Size::new(100., 100.));
+        let stack_id = harness.get_widget(tag).id();
+
+        // There's nothing to go back to.
+        harness.mouse_move((5., 50.));
+        harness.mouse_button_press(PointerButton::X1);
+        harness.mouse_button_release(PointerButton::X1);
+        assert_eq!(harness.pop_action::<NavigateBack>(), None);
+
+        harness.edit_widget(tag, |mut stack| {
+            let input = NewWidget::new_with_tag(TextInput::new(""), input_tag);
+            NavigationStack::push(&mut stack, input);
+        });
+        harness.animate_ms(1000);
+
+        harness.mouse_button_press(PointerButton::X1);
+        harness.mouse_button_release(PointerButton::X1);
+        assert_eq!(
+            harness.pop_action::<NavigateBack>(),
+            Some((NavigateBack, stack_id))
+        );
+
+        // Swiping from the left edge.
+        harness.mouse_move((5., 50.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move((60., 50.));
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(
+            harness.pop_action::<NavigateBack>(),
+            Some((NavigateBack, stack_id))
+        );
+
+        // A short swipe doesn't go back.
+        harness.mouse_move((5., 50.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move((20., 50.));
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(harness.pop_action::<NavigateBack>(), None);
+
+        // Pressing Escape in the page.
+        let input_id = harness.get_widget(input_tag).id();
+        harness.focus_on(Some(input_id));
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::Escape)));
+        assert_eq!(
+            harness.pop_action::<NavigateBack>(),
+            Some((NavigateBack, stack_id))
+        );
+
+        // Pressing the Android back button.
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::BrowserBack)));
+        assert_eq!(
+            harness.pop_action::<NavigateBack>(),
+            Some((NavigateBack, stack_id))
+        );
+    }
+}
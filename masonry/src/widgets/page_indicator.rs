@@ -0,0 +1,158 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, AllowRawMut, ChildrenIds, HasProperty, LayoutCtx, MeasureCtx, NoAction, PaintCtx,
+    PropertiesRef, RegisterCtx, UpdateCtx, Widget, WidgetId, WidgetMut,
+};
+use crate::kurbo::{Axis, Circle, Point, Size};
+use crate::layout::LenReq;
+use crate::properties::ContentColor;
+use crate::util::fill;
+
+/// The diameter of each dot.
+const DOT_SIZE: f64 = 8.;
+/// The space between two dots.
+const DOT_SPACING: f64 = 8.;
+/// The opacity of the dots of pages other than the current one.
+const INACTIVE_ALPHA: f32 = 0.35;
+
+/// A row of dots showing how many pages there are, and which one is current.
+///
+/// This is usually shown by a [`Pager`](crate::widgets::Pager), which keeps it in sync,
+/// but can also be used on its own.
+///
+/// You can customize the color of the dots with the [`ContentColor`] property.
+pub struct PageIndicator {
+    pub(crate) page_count: usize,
+    pub(crate) current_page: usize,
+}
+
+// --- MARK: BUILDERS
+impl PageIndicator {
+    /// Creates an indicator for `page_count` pages, with `current_page` highlighted.
+    pub fn new(page_count: usize, current_page: usize) -> Self {
+        Self {
+            page_count,
+            current_page,
+        }
+    }
+
+    /// Returns the number of pages.
+    pub fn page_count(&self) -> usize {
+        self.page_count
+    }
+
+    /// Returns the index of the highlighted page.
+    pub fn current_page(&self) -> usize {
+        self.current_page
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl PageIndicator {
+    /// Sets the number of pages.
+    pub fn set_page_count(this: &mut WidgetMut<'_, Self>, page_count: usize) {
+        if this.widget.page_count == page_count {
+            return;
+        }
+        this.widget.page_count = page_count;
+        this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets the index of the highlighted page.
+    pub fn set_current_page(this: &mut WidgetMut<'_, Self>, current_page: usize) {
+        if this.widget.current_page == current_page {
+            return;
+        }
+        this.widget.current_page = current_page;
+        this.ctx.request_render();
+    }
+}
+
+impl HasProperty<ContentColor> for PageIndicator {}
+
+impl AllowRawMut for PageIndicator {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for PageIndicator {
+    type Action = NoAction;
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        ContentColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        match axis {
+            Axis::Horizontal => {
+                let count = self.page_count as f64;
+                (count * DOT_SIZE + (count - 1.) * DOT_SPACING).max(0.)
+            }
+            Axis::Vertical => DOT_SIZE,
+        }
+    }
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, _size: Size) {}
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let color = props.get::<ContentColor>().color;
+        let size = ctx.content_box_size();
+
+        // Center the dots, in case we were given more space than we asked for.
+        let count = self.page_count as f64;
+        let width = count * DOT_SIZE + (count - 1.) * DOT_SPACING;
+        let x0 = (size.width - width) / 2. + DOT_SIZE / 2.;
+        let y = size.height / 2.;
+
+        for page in 0..self.page_count {
+            let center = Point::new(x0 + page as f64 * (DOT_SIZE + DOT_SPACING), y);
+            let color = if page == self.current_page {
+                color
+            } else {
+                color.multiply_alpha(INACTIVE_ALPHA)
+            };
+            fill(scene, &Circle::new(center, DOT_SIZE / 2.), color);
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Image
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_label(format!(
+            "Page {} of {}",
+            self.current_page + 1,
+            self.page_count
+        ));
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::new()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("PageIndicator", id = id.trace())
+    }
+}
@@ -0,0 +1,800 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+use std::ops::Range;
+
+use accesskit::{Node, Orientation, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::pointer::PointerButton;
+use crate::core::{
+    AccessCtx, AccessEvent, ChildrenIds, CollectionWidget, ComposeCtx, EventCtx, HasProperty,
+    LayoutCtx, MeasureCtx, NewWidget, PaintCtx, PointerButtonEvent, PointerEvent,
+    PointerScrollEvent, PointerUpdate, PropertiesMut, PropertiesRef, RegisterCtx, TextEvent,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Size, Vec2};
+use crate::layout::{LayoutSize, LenReq, SizeDef};
+use crate::properties::Gap;
+use crate::widgets::PageIndicator;
+
+/// The fraction of a page a swipe must cover to move to the next page.
+const SWIPE_THRESHOLD: f64 = 0.2;
+/// How long without horizontal scroll events before a swipe is considered released.
+///
+/// Scroll wheels and touchpads don't have a "release" event, so we need a timeout.
+const RELEASE_DELAY_NANOS: u64 = 150_000_000;
+/// How fast pages slide into place, in 1/seconds.
+const SPRING_RATE: f64 = 14.;
+/// The space between the page indicator and the bottom edge.
+const INDICATOR_MARGIN: f64 = 12.;
+
+/// A container showing one full-size page at a time, which users can swipe between horizontally.
+///
+/// Users change pages by dragging the pages with a pointer, by scrolling horizontally
+/// (e.g. with a touchpad), or with the arrow keys when the pager is focused.
+/// When a swipe is released, the pages slide to the nearest page in the direction of
+/// the swipe, and the pager emits [`PageChanged`] if the page changed.
+///
+/// Pages can also be changed programmatically with [`Pager::set_current_page`],
+/// which slides to the new page. If the user [prefers reduced motion], pages change
+/// without sliding.
+///
+/// With a [peek](Self::peek), pages are narrower than the pager, and the edges of the
+/// adjacent pages are visible on both sides.
+/// The space between pages is set with the [`Gap`] property.
+///
+/// A [`PageIndicator`] showing the current page can be displayed over the bottom edge
+/// with [`Pager::show_indicator`].
+///
+/// [prefers reduced motion]: crate::core::EventCtx::prefers_reduced_motion
+pub struct Pager {
+    pages: Vec<WidgetPod<dyn Widget>>,
+    indicator: WidgetPod<PageIndicator>,
+    show_indicator: bool,
+    peek: f64,
+    /// The page the pager is at, or sliding to.
+    current_page: usize,
+    /// The page being shown, as a fractional index.
+    ///
+    /// For instance, 1.5 means that the pager is halfway between the second and third pages.
+    position: f64,
+    /// The distance between the starts of two adjacent pages, computed in layout.
+    stride: f64,
+    /// The pages which were laid out, i.e. which weren't stashed, in the last layout.
+    laid_out_pages: Range<usize>,
+    /// The pointer position and page position when the current drag started.
+    drag_start: Option<(f64, f64)>,
+    /// The time since the last horizontal scroll event, while the user is swiping with it.
+    scroll_idle_nanos: Option<u64>,
+}
+
+/// The action emitted by [`Pager`] when the user changes the current page.
+///
+/// This contains the index of the new page.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct PageChanged(pub usize);
+
+// --- MARK: BUILDERS
+impl Pager {
+    /// Creates a new pager with no pages.
+    pub fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            indicator: WidgetPod::new(PageIndicator::new(0, 0)),
+            show_indicator: false,
+            peek: 0.,
+            current_page: 0,
+            position: 0.,
+            stride: 0.,
+            laid_out_pages: 0..0,
+            drag_start: None,
+            scroll_idle_nanos: None,
+        }
+    }
+
+    /// Builder-style method to add a page.
+    pub fn with(mut self, page: NewWidget<impl Widget + ?Sized>) -> Self {
+        self.pages.push(page.erased().to_pod());
+        self.indicator = WidgetPod::new(PageIndicator::new(self.pages.len(), self.current_page));
+        self
+    }
+
+    /// Builder-style method to set the current page, without sliding to it.
+    ///
+    /// Index must be a valid index into the pages, or 0 if there are no pages.
+    pub fn with_current_page(mut self, page: usize) -> Self {
+        assert!(
+            page < self.pages.len().max(1),
+            "Called with_current_page with invalid index {page}"
+        );
+        self.current_page = page;
+        self.position = page as f64;
+        self.indicator = WidgetPod::new(PageIndicator::new(self.pages.len(), page));
+        self
+    }
+
+    /// Builder-style method to set how much of the adjacent pages is visible on each side.
+    ///
+    /// The default is 0.
+    pub fn peek(mut self, peek: f64) -> Self {
+        self.peek = peek;
+        self
+    }
+
+    /// Builder-style method to set whether a [`PageIndicator`] is shown over the bottom edge.
+    ///
+    /// The default is `false`.
+    pub fn show_indicator(mut self, show: bool) -> Self {
+        self.show_indicator = show;
+        self
+    }
+}
+
+impl Default for Pager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// --- MARK: METHODS
+impl Pager {
+    /// Returns the index of the current page.
+    ///
+    /// While the pages are sliding, this is the page they are sliding to.
+    pub fn current_page(&self) -> usize {
+        self.current_page
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Pager {
+    /// Slides to the given page.
+    ///
+    /// This doesn't emit [`PageChanged`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is larger than the number of pages.
+    /// If there are no pages, the index 0 is accepted.
+    pub fn set_current_page(this: &mut WidgetMut<'_, Self>, page: usize) {
+        assert!(
+            page < this.widget.pages.len().max(1),
+            "Called set_current_page with invalid index {page}"
+        );
+        this.widget.current_page = page;
+        PageIndicator::set_current_page(&mut this.ctx.get_mut(&mut this.widget.indicator), page);
+        // The pages slide to the new page in `on_anim_frame`.
+        this.ctx.request_anim_frame();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets how much of the adjacent pages is visible on each side.
+    ///
+    /// See [`Pager::peek`] for more details.
+    pub fn set_peek(this: &mut WidgetMut<'_, Self>, peek: f64) {
+        this.widget.peek = peek;
+        this.ctx.request_layout();
+    }
+
+    /// Sets whether a [`PageIndicator`] is shown over the bottom edge.
+    pub fn set_show_indicator(this: &mut WidgetMut<'_, Self>, show: bool) {
+        this.widget.show_indicator = show;
+        this.ctx.request_layout();
+    }
+
+    fn sync_page_count(this: &mut WidgetMut<'_, Self>) {
+        let page_count = this.widget.pages.len();
+        PageIndicator::set_page_count(
+            &mut this.ctx.get_mut(&mut this.widget.indicator),
+            page_count,
+        );
+        let current_page = this.widget.current_page.min(page_count.saturating_sub(1));
+        if current_page != this.widget.current_page {
+            Self::set_current_page(this, current_page);
+        }
+        this.ctx.children_changed();
+    }
+}
+
+// --- MARK: COLLECTIONWIDGET
+impl CollectionWidget<()> for Pager {
+    /// Returns the number of pages.
+    fn len(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Returns `true` if there are no pages.
+    fn is_empty(&self) -> bool {
+        self.pages.is_empty()
+    }
+
+    /// Returns a mutable reference to the page at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn get_mut<'t>(this: &'t mut WidgetMut<'_, Self>, idx: usize) -> WidgetMut<'t, dyn Widget> {
+        let page = &mut this.widget.pages[idx];
+        this.ctx.get_mut(page)
+    }
+
+    /// Appends a page.
+    fn add(
+        this: &mut WidgetMut<'_, Self>,
+        child: NewWidget<impl Widget + ?Sized>,
+        _params: impl Into<()>,
+    ) {
+        this.widget.pages.push(child.erased().to_pod());
+        Self::sync_page_count(this);
+    }
+
+    /// Inserts a page at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the number of pages.
+    fn insert(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        child: NewWidget<impl Widget + ?Sized>,
+        _params: impl Into<()>,
+    ) {
+        this.widget.pages.insert(idx, child.erased().to_pod());
+        if this.widget.pages.len() > 1 && this.widget.current_page >= idx {
+            // Adjust the index to keep the same page current.
+            this.widget.current_page += 1;
+            this.widget.position += 1.;
+        }
+        Self::sync_page_count(this);
+    }
+
+    /// Replaces the page at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn set(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        child: NewWidget<impl Widget + ?Sized>,
+        _params: impl Into<()>,
+    ) {
+        let old_page = std::mem::replace(&mut this.widget.pages[idx], child.erased().to_pod());
+        this.ctx.remove_child(old_page);
+    }
+
+    /// Not applicable.
+    fn set_params(_this: &mut WidgetMut<'_, Self>, _idx: usize, _params: impl Into<()>) {}
+
+    /// Swaps the index of two pages.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    fn swap(this: &mut WidgetMut<'_, Self>, a: usize, b: usize) {
+        this.widget.pages.swap(a, b);
+        this.ctx.children_changed();
+    }
+
+    /// Removes the page at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn remove(this: &mut WidgetMut<'_, Self>, idx: usize) {
+        let page = this.widget.pages.remove(idx);
+        if this.widget.current_page > idx {
+            // Adjust the index to keep the same page current.
+            this.widget.current_page -= 1;
+            this.widget.position -= 1.;
+        }
+        this.ctx.remove_child(page);
+        Self::sync_page_count(this);
+    }
+
+    /// Removes all pages.
+    fn clear(this: &mut WidgetMut<'_, Self>) {
+        for page in this.widget.pages.drain(..) {
+            this.ctx.remove_child(page);
+        }
+        Self::sync_page_count(this);
+    }
+}
+
+// --- MARK: HELPERS
+impl Pager {
+    fn last_page(&self) -> usize {
+        self.pages.len().saturating_sub(1)
+    }
+
+    /// Returns the pages which may be visible at the current position.
+    fn visible_pages(&self) -> Range<usize> {
+        if self.pages.is_empty() {
+            return 0..0;
+        }
+        // The adjacent pages are visible when peeking, or while sliding.
+        let first = (self.position - 1.).floor().max(0.) as usize;
+        let last = ((self.position + 1.).ceil() as usize).min(self.last_page());
+        first..last + 1
+    }
+
+    /// Moves the shown position.
+    ///
+    /// Returns `true` if other pages must be laid out to show it.
+    fn set_position(&mut self, position: f64) -> bool {
+        self.position = position.clamp(0., self.last_page() as f64);
+        self.visible_pages() != self.laid_out_pages
+    }
+
+    /// Makes `page` the current page, and emits [`PageChanged`] if it changed.
+    fn go_to_page(&mut self, ctx: &mut EventCtx<'_>, page: usize) {
+        let page = page.min(self.last_page());
+        if page != self.current_page {
+            self.current_page = page;
+            let (indicator, mut indicator_ctx) = ctx.get_raw_mut(&mut self.indicator);
+            indicator.current_page = page;
+            indicator_ctx.request_render();
+            drop(indicator_ctx);
+            ctx.submit_action::<PageChanged>(PageChanged(page));
+            ctx.request_accessibility_update();
+        }
+        ctx.request_anim_frame();
+    }
+
+    /// Returns the page to slide to once a swipe is released.
+    fn settled_page(&self) -> usize {
+        let swiped = self.position - self.current_page as f64;
+        let page = if swiped.abs() >= 1. {
+            self.position.round() as usize
+        } else if swiped > SWIPE_THRESHOLD {
+            self.current_page + 1
+        } else if swiped < -SWIPE_THRESHOLD {
+            self.current_page.saturating_sub(1)
+        } else {
+            self.current_page
+        };
+        page.min(self.last_page())
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl HasProperty<Gap> for Pager {}
+
+impl Widget for Pager {
+    type Action = PageChanged;
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if self.pages.is_empty() {
+            return;
+        }
+        let stride = self.stride.max(1.);
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                ctx.capture_pointer();
+                let x = ctx.local_position(state.position).x;
+                self.drag_start = Some((x, self.position));
+                self.scroll_idle_nanos = None;
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) if ctx.is_active() => {
+                if let Some((start_x, start_position)) = self.drag_start {
+                    let x = ctx.local_position(current.position).x;
+                    if self.set_position(start_position - (x - start_x) / stride) {
+                        ctx.request_layout();
+                    }
+                    ctx.request_compose();
+                }
+            }
+            PointerEvent::Up(..) if self.drag_start.take().is_some() => {
+                self.go_to_page(ctx, self.settled_page());
+            }
+            PointerEvent::Cancel(..) if self.drag_start.take().is_some() => {
+                // Go back to where we were.
+                ctx.request_anim_frame();
+            }
+            PointerEvent::Scroll(PointerScrollEvent { delta, state, .. })
+                if self.drag_start.is_none() =>
+            {
+                let Vec2 { x, .. } = ctx.scroll_config().logical_delta(
+                    *delta,
+                    ctx.content_box_size(),
+                    ctx.get_scale_factor(),
+                    state.modifiers,
+                );
+                // Leave vertical scrolling to our parents.
+                if x == 0. {
+                    return;
+                }
+                // A positive delta scrolls towards the left.
+                if self.set_position(self.position - x / stride) {
+                    ctx.request_layout();
+                }
+                ctx.request_compose();
+                self.scroll_idle_nanos = Some(0);
+                ctx.request_anim_frame();
+                ctx.set_handled();
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if !ctx.is_focus_target() {
+            return;
+        }
+        if let TextEvent::Keyboard(key_event) = event
+            && key_event.state.is_down()
+        {
+            let page = match &key_event.key {
+                Key::Named(NamedKey::ArrowLeft) => self.current_page.saturating_sub(1),
+                Key::Named(NamedKey::ArrowRight) => self.current_page + 1,
+                Key::Named(NamedKey::Home) => 0,
+                Key::Named(NamedKey::End) => self.last_page(),
+                _ => return,
+            };
+            self.go_to_page(ctx, page);
+            ctx.set_handled();
+        }
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        let page = match event.action {
+            accesskit::Action::ScrollLeft => self.current_page.saturating_sub(1),
+            accesskit::Action::ScrollRight => self.current_page + 1,
+            _ => return,
+        };
+        self.go_to_page(ctx, page);
+        ctx.set_handled();
+    }
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        if self.drag_start.is_some() {
+            return;
+        }
+        if let Some(idle_nanos) = &mut self.scroll_idle_nanos {
+            *idle_nanos += interval;
+            if *idle_nanos < RELEASE_DELAY_NANOS {
+                ctx.request_anim_frame();
+                return;
+            }
+            // The user has released the swipe.
+            self.scroll_idle_nanos = None;
+            let page = self.settled_page();
+            if page != self.current_page {
+                self.current_page = page;
+                let (indicator, mut indicator_ctx) = ctx.get_raw_mut(&mut self.indicator);
+                indicator.current_page = page;
+                indicator_ctx.request_render();
+                drop(indicator_ctx);
+                ctx.submit_action::<PageChanged>(PageChanged(page));
+                ctx.request_accessibility_update();
+            }
+        }
+
+        let target = self.current_page as f64;
+        let position = if ctx.prefers_reduced_motion() {
+            target
+        } else {
+            let t = 1. - (-SPRING_RATE * interval as f64 * 1e-9).exp();
+            let position = self.position + (target - self.position) * t;
+            if (target - position).abs() < 1e-3 {
+                target
+            } else {
+                position
+            }
+        };
+        if self.set_position(position) {
+            ctx.request_layout();
+        }
+        ctx.request_compose();
+        if self.position != target {
+            ctx.request_anim_frame();
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for page in self.pages.iter_mut() {
+            ctx.register_child(page);
+        }
+        ctx.register_child(&mut self.indicator);
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if property_type == TypeId::of::<Gap>() {
+            ctx.request_layout();
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        if let LenReq::FitContent(space) = len_req {
+            return space;
+        }
+        if self.pages.is_empty() {
+            return 0.;
+        }
+        let peeks = 2. * self.peek;
+        let cross_length = match axis {
+            Axis::Horizontal => cross_length,
+            Axis::Vertical => cross_length.map(|width| (width - peeks).max(0.)),
+        };
+        let auto_length = len_req.into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+
+        // Like `IndexedStack`, we size ourselves after the current page.
+        let length = ctx.compute_length(
+            &mut self.pages[self.current_page],
+            auto_length,
+            context_size,
+            axis,
+            cross_length,
+        );
+        match axis {
+            Axis::Horizontal => length + peeks,
+            Axis::Vertical => length,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, props: &PropertiesRef<'_>, size: Size) {
+        // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
+        //       https://github.com/linebender/xilem/issues/1264
+        let scale = 1.0;
+
+        let peek = self.peek.clamp(0., size.width / 2.);
+        let page_size = Size::new(size.width - 2. * peek, size.height);
+        let gap = props.get::<Gap>().gap.dp(scale);
+        self.stride = page_size.width + gap;
+
+        // TODO: move set_stashed to a different layout pass when possible
+        let visible_pages = self.visible_pages();
+        for (idx, page) in self.pages.iter_mut().enumerate() {
+            let visible = visible_pages.contains(&idx);
+            ctx.set_stashed(page, !visible);
+            if visible {
+                ctx.run_layout(page, page_size);
+                ctx.place_child(page, Point::new(peek + idx as f64 * self.stride, 0.));
+            }
+        }
+        self.laid_out_pages = visible_pages;
+
+        ctx.set_stashed(&mut self.indicator, !self.show_indicator);
+        if self.show_indicator {
+            let indicator_size =
+                ctx.compute_size(&mut self.indicator, SizeDef::fit(size), size.into());
+            ctx.run_layout(&mut self.indicator, indicator_size);
+            ctx.place_child(
+                &mut self.indicator,
+                Point::new(
+                    (size.width - indicator_size.width) / 2.,
+                    size.height - indicator_size.height - INDICATOR_MARGIN,
+                ),
+            );
+        }
+
+        ctx.set_clip_path(size.to_rect());
+    }
+
+    fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
+        let translation = Vec2::new(-self.position * self.stride, 0.);
+        for idx in self.laid_out_pages.clone() {
+            ctx.set_child_scroll_translation(&mut self.pages[idx], translation);
+        }
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_clips_children();
+        node.set_orientation(Orientation::Horizontal);
+        if self.current_page > 0 {
+            node.add_action(accesskit::Action::ScrollLeft);
+        }
+        if self.current_page < self.last_page() {
+            node.add_action(accesskit::Action::ScrollRight);
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.pages
+            .iter()
+            .map(|page| page.id())
+            .chain([self.indicator.id()])
+            .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Pager", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{WidgetOptions, WidgetTag, WindowEvent};
+    use crate::layout::AsUnit;
+    use crate::palette;
+    use crate::properties::{Background, Dimensions};
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::SizedBox;
+
+    fn with_pages(pager: Pager, tag: WidgetTag<Pager>) -> NewWidget<Pager> {
+        let pager = [
+            palette::css::ORANGE,
+            palette::css::TEAL,
+            palette::css::PURPLE,
+        ]
+        .into_iter()
+        .fold(pager, |pager, color| {
+            pager.with(SizedBox::empty().with_props(Background::Color(color)))
+        });
+        NewWidget::new_with(
+            pager,
+            Some(tag),
+            WidgetOptions::default(),
+            Dimensions::STRETCH,
+        )
+    }
+
+    fn indicator_page(harness: &TestHarness<Pager>) -> usize {
+        let pager = harness.root_widget();
+        let indicator = pager.children()[3].downcast::<PageIndicator>().unwrap();
+        indicator.inner().current_page()
+    }
+
+    #[test]
+    fn drag_to_next_page() {
+        let tag = WidgetTag::named("pager");
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            with_pages(Pager::new(), tag),
+            Size::new(100., 100.),
+        );
+        let id = harness.get_widget(tag).id();
+
+        // A short drag springs back.
+        harness.mouse_move((80., 50.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move((70., 50.));
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(harness.pop_action::<PageChanged>(), None);
+        harness.animate_ms(1000);
+        assert_eq!(harness.get_widget(tag).inner().position, 0.);
+
+        // A longer one moves to the next page.
+        harness.mouse_move((80., 50.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move((40., 50.));
+        assert_eq!(harness.get_widget(tag).inner().position, 0.4);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(
+            harness.pop_action::<PageChanged>(),
+            Some((PageChanged(1), id))
+        );
+        assert_eq!(indicator_page(&harness), 1);
+        harness.animate_ms(1000);
+        let pager = harness.get_widget(tag);
+        assert_eq!(pager.inner().current_page(), 1);
+        assert_eq!(pager.inner().position, 1.);
+        // Only the current page and its neighbors are laid out.
+        assert!(!pager.children()[2].ctx().is_stashed());
+    }
+
+    #[test]
+    fn scroll_to_next_page() {
+        let tag = WidgetTag::named("pager");
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            with_pages(Pager::new(), tag),
+            Size::new(100., 100.),
+        );
+        let id = harness.get_widget(tag).id();
+
+        // Vertical scrolling is left to our parents.
+        harness.mouse_move((50., 50.));
+        harness.mouse_wheel(Vec2::new(0., -60.));
+        assert_eq!(harness.get_widget(tag).inner().position, 0.);
+
+        harness.mouse_wheel(Vec2::new(-60., 0.));
+        assert_eq!(harness.pop_action::<PageChanged>(), None);
+        harness.animate_ms(200);
+        assert_eq!(
+            harness.pop_action::<PageChanged>(),
+            Some((PageChanged(1), id))
+        );
+    }
+
+    #[test]
+    fn programmatic_page_change() {
+        let tag = WidgetTag::named("pager");
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            with_pages(Pager::new(), tag),
+            Size::new(100., 100.),
+        );
+
+        harness.edit_widget(tag, |mut pager| {
+            Pager::set_current_page(&mut pager, 2);
+        });
+        assert_eq!(indicator_page(&harness), 2);
+        harness.animate_ms(50);
+        let position = harness.get_widget(tag).inner().position;
+        assert!(position > 0. && position < 2.);
+        harness.animate_ms(1000);
+        assert_eq!(harness.get_widget(tag).inner().position, 2.);
+        assert_eq!(harness.pop_action::<PageChanged>(), None);
+
+        // With reduced motion, the page changes at once.
+        harness.process_window_event(WindowEvent::ReducedMotionChanged(true));
+        harness.edit_widget(tag, |mut pager| {
+            Pager::set_current_page(&mut pager, 0);
+        });
+        harness.animate_ms(16);
+        assert_eq!(harness.get_widget(tag).inner().position, 0.);
+    }
+
+    #[test]
+    fn peek_and_indicator() {
+        let tag = WidgetTag::named("pager");
+        let pager = Pager::new().peek(12.).show_indicator(true);
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            with_pages(pager, tag),
+            Size::new(150., 100.),
+        );
+        harness.edit_widget(tag, |mut pager| {
+            pager.insert_prop(Gap::new(6.px()));
+            Pager::set_current_page(&mut pager, 1);
+        });
+        harness.animate_ms(1000);
+
+        assert_render_snapshot!(harness, "pager_peek_indicator");
+    }
+}
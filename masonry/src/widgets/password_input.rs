@@ -0,0 +1,561 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::mem;
+
+use accesskit::{Node, Role, Toggled};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{
+    AccessCtx, AccessEvent, ChildrenIds, CursorIcon, EventCtx, LayoutCtx, MeasureCtx, NewWidget,
+    NoAction, PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PropertiesMut,
+    PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId,
+    WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, BezPath, Circle, Line, Point, Size};
+use crate::layout::{LayoutSize, LenReq};
+use crate::theme;
+use crate::util::{fill, stroke};
+use crate::widgets::{TextArea, TextInput};
+
+/// A text input for passwords, with a button to reveal the password.
+///
+/// The text is shown as bullets until the user presses the eye button next to the input,
+/// which can also be done with [`set_revealed`](Self::set_revealed).
+///
+/// The text area of the input is a [password field](TextArea::with_password):
+/// copying its text is disabled by default, its accessibility node is marked as protected,
+/// and the platform's IME is asked not to show predictions or learn from the text.
+///
+/// Like [`TextInput`], this widget itself does not emit any actions.
+/// The [`TextAction`](crate::widgets::TextAction)s are sent by the inner text area,
+/// and contain the actual text.
+/// The ID of the text area can be accessed using [`area_id`](Self::area_id).
+pub struct PasswordInput {
+    input: WidgetPod<TextInput>,
+    area_id: WidgetId,
+    toggle: WidgetPod<RevealToggle>,
+}
+
+/// The eye button of a [`PasswordInput`].
+struct RevealToggle {
+    is_revealed: bool,
+    /// Whether the button has been clicked since the password input last checked.
+    clicked: bool,
+}
+
+// --- MARK: BUILDERS
+impl PasswordInput {
+    /// Creates a new `PasswordInput` with the given text.
+    pub fn new(text: &str) -> Self {
+        Self::from_text_area(TextArea::new_editable(text).with_auto_id())
+    }
+
+    /// Creates a new `PasswordInput` from a styled text area.
+    ///
+    /// The text area is made a [password field](TextArea::with_password) if it isn't one already.
+    pub fn from_text_area(mut text: NewWidget<TextArea<true>>) -> Self {
+        if text.widget.password().is_none() {
+            text.widget = Box::new((*text.widget).with_password(true));
+        }
+        Self::from_text_input(TextInput::from_text_area(text).with_auto_id())
+    }
+
+    /// Creates a new `PasswordInput` from a text input, which is useful to set a placeholder
+    /// or other text input options.
+    ///
+    /// The text area of the input should be a [password field](TextArea::with_password).
+    /// Otherwise, it is made one once the widget is added, and its text is briefly visible.
+    pub fn from_text_input(input: NewWidget<TextInput>) -> Self {
+        Self {
+            area_id: input.widget.area_pod().id(),
+            input: input.to_pod(),
+            toggle: NewWidget::new(RevealToggle {
+                is_revealed: false,
+                clicked: false,
+            })
+            .to_pod(),
+        }
+    }
+}
+
+// --- MARK: METHODS
+impl PasswordInput {
+    /// Returns the ID of the underlying text area, which sends the actions of the password input.
+    pub fn area_id(&self) -> WidgetId {
+        self.area_id
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl PasswordInput {
+    /// Edits the underlying text input.
+    ///
+    /// Most properties of the text are modified with [`TextInput::text_mut`].
+    pub fn input_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, TextInput> {
+        this.ctx.get_mut(&mut this.widget.input)
+    }
+
+    /// Sets whether the password is shown instead of bullets.
+    pub fn set_revealed(this: &mut WidgetMut<'_, Self>, is_revealed: bool) {
+        {
+            let mut input = Self::input_mut(this);
+            TextArea::set_password_revealed(&mut TextInput::text_mut(&mut input), is_revealed);
+        }
+        let mut toggle = this.ctx.get_mut(&mut this.widget.toggle);
+        toggle.widget.is_revealed = is_revealed;
+        toggle.ctx.request_render();
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for PasswordInput {
+    type Action = NoAction;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if ctx.target() == self.toggle.id() && matches!(event, PointerEvent::Up(..)) {
+            Self::toggle_if_clicked(ctx);
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+        if ctx.target() == self.toggle.id() {
+            Self::toggle_if_clicked(ctx);
+        }
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+        if ctx.target() == self.toggle.id() {
+            Self::toggle_if_clicked(ctx);
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.input);
+        ctx.register_child(&mut self.toggle);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::WidgetAdded = event {
+            ctx.mutate_self_later(|mut input| {
+                let mut input = input.downcast::<Self>();
+                let is_revealed = {
+                    let mut input = Self::input_mut(&mut input);
+                    let mut area = TextInput::text_mut(&mut input);
+                    TextArea::set_password(&mut area, true);
+                    area.widget.is_password_revealed()
+                };
+                let mut toggle = input.ctx.get_mut(&mut input.widget.toggle);
+                toggle.widget.is_revealed = is_revealed;
+                toggle.ctx.request_render();
+            });
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let (toggle_side, gap) = toggle_metrics();
+        let other_length = match axis {
+            Axis::Horizontal => toggle_side + gap,
+            Axis::Vertical => 0.,
+        };
+        let cross = axis.cross();
+        let cross_space = match cross {
+            Axis::Horizontal => cross_length.map(|length| (length - toggle_side - gap).max(0.)),
+            Axis::Vertical => cross_length,
+        };
+
+        let auto_length = len_req.reduce(other_length).into();
+        let context_size = LayoutSize::maybe(cross, cross_space);
+        let input_length = ctx.compute_length(
+            &mut self.input,
+            auto_length,
+            context_size,
+            axis,
+            cross_space,
+        );
+
+        match axis {
+            Axis::Horizontal => input_length + other_length,
+            Axis::Vertical => input_length.max(toggle_side),
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let (toggle_side, gap) = toggle_metrics();
+
+        let input_size = Size::new((size.width - toggle_side - gap).max(0.), size.height);
+        ctx.run_layout(&mut self.input, input_size);
+        ctx.place_child(&mut self.input, Point::ORIGIN);
+
+        ctx.run_layout(&mut self.toggle, Size::new(toggle_side, toggle_side));
+        let toggle_origin = Point::new(
+            size.width - toggle_side,
+            ((size.height - toggle_side) / 2.).max(0.),
+        );
+        ctx.place_child(&mut self.toggle, toggle_origin);
+
+        let input_baseline = ctx.child_baseline_offset(&self.input);
+        ctx.set_baseline_offset(input_baseline);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.input.id(), self.toggle.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("PasswordInput", id = id.trace())
+    }
+}
+
+impl PasswordInput {
+    /// Toggles the password if the reveal button has been clicked.
+    fn toggle_if_clicked(ctx: &mut EventCtx<'_>) {
+        ctx.mutate_self_later(|mut input| {
+            let mut input = input.downcast::<Self>();
+            let toggle = input.ctx.get_mut(&mut input.widget.toggle);
+            let is_revealed = toggle.widget.is_revealed;
+            if mem::take(&mut toggle.widget.clicked) {
+                drop(toggle);
+                Self::set_revealed(&mut input, !is_revealed);
+            }
+        });
+    }
+}
+
+/// Returns the side length of the reveal button, and the gap between it and the text input.
+fn toggle_metrics() -> (f64, f64) {
+    // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
+    //       https://github.com/linebender/xilem/issues/1264
+    let scale = 1.0;
+    (
+        theme::BORDERED_WIDGET_HEIGHT,
+        theme::WIDGET_CONTROL_COMPONENT_PADDING.dp(scale),
+    )
+}
+
+// --- MARK: IMPL TOGGLE
+impl Widget for RevealToggle {
+    type Action = NoAction;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: None | Some(PointerButton::Primary),
+                ..
+            }) => {
+                ctx.capture_pointer();
+            }
+            PointerEvent::Up(PointerButtonEvent {
+                button: None | Some(PointerButton::Primary),
+                ..
+            }) if ctx.is_active() && ctx.is_hovered() => {
+                self.clicked = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if let TextEvent::Keyboard(event) = event
+            && event.state.is_up()
+            && match &event.key {
+                Key::Named(NamedKey::Enter) => true,
+                Key::Character(c) => c == " ",
+                _ => false,
+            }
+        {
+            self.clicked = true;
+        }
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if event.action == accesskit::Action::Click {
+            self.clicked = true;
+        }
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::HoveredChanged(_)
+            | Update::ActiveChanged(_)
+            | Update::FocusChanged(_)
+            | Update::DisabledChanged(_) => {
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _axis: Axis,
+        _len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        toggle_metrics().0
+    }
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, _size: Size) {}
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let size = ctx.content_box_size();
+        let center = size.to_rect().center();
+        let half_width = size.width * 0.35;
+        let half_height = size.height * 0.2;
+
+        let color = if ctx.is_disabled() {
+            theme::DISABLED_TEXT_COLOR
+        } else if ctx.is_hovered() {
+            theme::FOCUS_COLOR
+        } else {
+            theme::TEXT_COLOR
+        };
+
+        // The outline of the eye, made of two arcs meeting at the corners.
+        let left = Point::new(center.x - half_width, center.y);
+        let right = Point::new(center.x + half_width, center.y);
+        let mut eye = BezPath::new();
+        eye.move_to(left);
+        eye.quad_to(Point::new(center.x, center.y - 2. * half_height), right);
+        eye.quad_to(Point::new(center.x, center.y + 2. * half_height), left);
+        eye.close_path();
+        stroke(scene, &eye, color, 1.5);
+        fill(scene, &Circle::new(center, half_height * 0.6), color);
+
+        // The eye is crossed out while the password is hidden.
+        if !self.is_revealed {
+            let slash = Line::new(
+                (center.x + half_width * 0.8, center.y - half_width * 0.8),
+                (center.x - half_width * 0.8, center.y + half_width * 0.8),
+            );
+            stroke(scene, &slash, color, 1.5);
+        }
+
+        if ctx.is_focus_target() && !ctx.is_disabled() {
+            let focus_rect = ctx.border_box().inset(-1.0);
+            let focus_color =
+                theme::FOCUS_COLOR.with_alpha(if ctx.is_active() { 1.0 } else { 0.5 });
+            stroke(scene, &focus_rect.to_rounded_rect(4.0), focus_color, 1.0);
+        }
+    }
+
+    fn get_cursor(&self, _ctx: &QueryCtx<'_>, _pos: Point) -> CursorIcon {
+        CursorIcon::Pointer
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Button
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_label("Show password");
+        node.add_action(accesskit::Action::Click);
+        if self.is_revealed {
+            node.set_toggled(Toggled::True);
+        } else {
+            node.set_toggled(Toggled::False);
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::new()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("RevealToggle", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use masonry_testing::TestHarnessParams;
+
+    use super::*;
+    use crate::core::keyboard::KeyState;
+    use crate::core::{ImePurpose, KeyboardEvent, Modifiers, StyleProperty};
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::TextAction;
+
+    const HARNESS_PARAMS: TestHarnessParams = {
+        let mut params = TestHarnessParams::DEFAULT;
+        params.window_size = Size::new(180.0, 40.0);
+        params.root_padding = 8;
+        params
+    };
+
+    fn press(harness: &mut TestHarness<PasswordInput>, key: Key, modifiers: Modifiers) {
+        harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+            key,
+            modifiers,
+            ..Default::default()
+        }));
+    }
+
+    fn last_change(harness: &mut TestHarness<PasswordInput>) -> Option<String> {
+        let mut last_change = None;
+        while let Some((TextAction::Changed(text), _)) = harness.pop_action::<TextAction>() {
+            last_change = Some(text);
+        }
+        last_change
+    }
+
+    #[test]
+    fn hides_text_but_reports_it_in_actions() {
+        let input = NewWidget::new(PasswordInput::new(""));
+        let mut harness = TestHarness::create_with(test_property_set(), input, HARNESS_PARAMS);
+        let area_id = harness.root_widget().area_id();
+        harness.focus_on(Some(area_id));
+        assert_eq!(harness.ime_purpose(), ImePurpose::Password);
+
+        harness.keyboard_type_chars("hunter2");
+        let area = harness.get_widget_with_id(area_id);
+        let area = area.downcast::<TextArea<true>>().unwrap();
+        assert_eq!(area.inner().text().to_string(), "•••••••");
+        assert_eq!(area.inner().password(), Some("hunter2"));
+        assert_eq!(last_change(&mut harness).as_deref(), Some("hunter2"));
+
+        // Deletions in the middle of the bullets apply to the actual text.
+        press(
+            &mut harness,
+            Key::Named(NamedKey::ArrowLeft),
+            Modifiers::default(),
+        );
+        press(
+            &mut harness,
+            Key::Named(NamedKey::Backspace),
+            Modifiers::default(),
+        );
+        assert_eq!(last_change(&mut harness).as_deref(), Some("hunte2"));
+        harness.keyboard_type_chars("r");
+        assert_eq!(last_change(&mut harness).as_deref(), Some("hunter2"));
+
+        let node = harness.access_node(area_id).unwrap();
+        assert_eq!(node.role(), Role::PasswordInput);
+    }
+
+    #[test]
+    fn copy_is_disabled_by_default() {
+        let input = NewWidget::new(PasswordInput::new("secret"));
+        let mut harness = TestHarness::create_with(test_property_set(), input, HARNESS_PARAMS);
+        let area_id = harness.root_widget().area_id();
+        harness.focus_on(Some(area_id));
+
+        press(&mut harness, Key::Character("a".into()), Modifiers::CONTROL);
+        press(&mut harness, Key::Character("c".into()), Modifiers::CONTROL);
+        press(&mut harness, Key::Character("x".into()), Modifiers::CONTROL);
+        assert_eq!(harness.clipboard_contents(), "");
+        assert_eq!(last_change(&mut harness), None);
+
+        harness.edit_root_widget(|mut input| {
+            let mut input = PasswordInput::input_mut(&mut input);
+            TextArea::set_password_copy(&mut TextInput::text_mut(&mut input), true);
+        });
+        press(&mut harness, Key::Character("c".into()), Modifiers::CONTROL);
+        assert_eq!(harness.clipboard_contents(), "secret");
+    }
+
+    #[test]
+    fn toggle_reveals_password() {
+        let input = NewWidget::new(PasswordInput::from_text_area(
+            TextArea::new_editable("secret")
+                .with_style(StyleProperty::FontSize(14.0))
+                .with_auto_id(),
+        ));
+        let mut harness = TestHarness::create_with(test_property_set(), input, HARNESS_PARAMS);
+        let area_id = harness.root_widget().area_id();
+        let toggle_id = harness.root_widget().children()[1].id();
+
+        assert_render_snapshot!(harness, "password_input_hidden");
+
+        harness.mouse_click_on(toggle_id);
+        let area = harness.get_widget_with_id(area_id);
+        let area = area.downcast::<TextArea<true>>().unwrap();
+        assert_eq!(area.inner().text().to_string(), "secret");
+        assert!(area.inner().is_password_revealed());
+
+        assert_render_snapshot!(harness, "password_input_revealed");
+        assert_eq!(
+            harness.access_node(toggle_id).unwrap().toggled(),
+            Some(Toggled::True)
+        );
+
+        // The toggle can also be used with the keyboard.
+        harness.focus_on(Some(toggle_id));
+        harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+            key: Key::Character(" ".into()),
+            state: KeyState::Up,
+            ..Default::default()
+        }));
+        let area = harness.get_widget_with_id(area_id);
+        let area = area.downcast::<TextArea<true>>().unwrap();
+        assert_eq!(area.inner().text().to_string(), "••••••");
+    }
+}
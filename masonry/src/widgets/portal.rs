@@ -1,23 +1,48 @@
 // Copyright 2020 the Xilem Authors and the Druid Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::any::TypeId;
 use std::ops::Range;
 
 use accesskit::{Node, Role};
-use dpi::PhysicalPosition;
 use tracing::{Span, trace_span};
 use vello::Scene;
 
+use crate::core::pointer::PointerType;
 use crate::core::{
-    AccessCtx, AccessEvent, ChildrenIds, ComposeCtx, EventCtx, FromDynWidget, LayoutCtx,
-    MeasureCtx, NewWidget, NoAction, PaintCtx, PointerEvent, PointerScrollEvent, PropertiesMut,
-    PropertiesRef, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
-    WidgetPod,
+    AccessCtx, AccessEvent, ChildrenIds, ComposeCtx, EventCtx, FromDynWidget, HasProperty,
+    LayoutCtx, MeasureCtx, NewWidget, PaintCtx, PointerButtonEvent, PointerEvent,
+    PointerScrollEvent, PointerUpdate, PropertiesMut, PropertiesRef, RegisterCtx, ScrollConfig,
+    TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
 };
 use crate::kurbo::{Axis, Point, Rect, Size, Vec2};
 use crate::layout::{LayoutSize, LenDef, LenReq, SizeDef};
+use crate::peniko::{Color, Gradient};
+use crate::properties::{OverscrollEffect, OverscrollGlowColor};
+use crate::util::{Duration, Easing, fill};
 use crate::widgets::ScrollBar;
 
+/// The furthest the content can bounce, which the rubber band approaches asymptotically.
+const MAX_BOUNCE: f64 = 120.;
+/// How stiff the rubber band feels: lower values need more scrolling for the same offset.
+const RUBBER_BAND_STIFFNESS: f64 = 0.55;
+/// The overscroll distance at which the edge glow is at full intensity.
+const GLOW_SATURATION: f64 = 120.;
+/// How far the edge glow extends into the viewport at full intensity.
+const GLOW_DEPTH: f64 = 36.;
+/// How long without overscroll events before the overscroll is considered released.
+///
+/// Scroll wheels and touchpads don't have a "release" event, so we need a timeout.
+const RELEASE_DELAY_NANOS: u64 = 150_000_000;
+/// How fast the overscroll decays once released, in 1/seconds.
+const SPRING_RATE: f64 = 12.;
+/// How fast the viewport approaches a widget focused with the keyboard, in 1/seconds.
+const FOCUS_PAN_RATE: f64 = 18.;
+/// How close to the edges of the viewport a drag starts scrolling it.
+const EDGE_AUTO_SCROLL_ZONE: f64 = 40.;
+/// How fast a drag scrolls the viewport once the pointer reaches its edge, in pixels/second.
+const EDGE_AUTO_SCROLL_MAX_SPEED: f64 = 1500.;
+
 // TODO - refactor - see https://github.com/linebender/xilem/issues/366
 // TODO - rename "Portal" to "ScrollPortal"?
 // TODO - Document which cases need request_layout, request_compose and request_render
@@ -26,18 +51,73 @@ use crate::widgets::ScrollBar;
 
 /// A scrolling container with scrollbars and a child widget.
 ///
+/// Unless the child is [constrained](Portal::constrain_vertical) along an axis, the portal
+/// scrolls along both axes at once, e.g. for a spreadsheet: both scrollbars are shown when
+/// the child overflows in both directions, and touch drags pan diagonally.
+/// A [corner widget](Portal::with_corner) can fill the square between the two scrollbars.
+/// User scrolling along one axis can be [locked](Portal::lock_axis), while leaving the
+/// other axis free.
+///
 /// ## Keyboard and accessibility
 ///
 /// - Exposes an accessibility node with [`accesskit::Role::ScrollView`], including `scroll_x/y`
 ///   and their ranges.
 /// - Handles `accesskit` scroll actions (`ScrollUp`/`ScrollDown`/`ScrollLeft`/`ScrollRight`) by
 ///   scrolling the viewport.
-/// - When this widget is focused, it handles basic keyboard scrolling (arrow keys, PageUp/Down,
-///   Home/End) *if the event wasn't already handled by a child*.
+/// - When this widget or a descendant is focused, it handles basic keyboard scrolling
+///   (arrow keys, PageUp/Down, Home/End) *if the event wasn't already handled by a child*,
+///   unless the focused descendant accepts text input.
+///   Space and Shift+Space scroll by a page too, but only when this widget itself is focused,
+///   as descendants such as buttons are activated with Space.
+/// - Clicking the track of a scrollbar scrolls by a page towards the click.
+///
+/// A page is a [fraction](Portal::page_fraction) of the viewport's height, or of its
+/// width for scrollbars along the horizontal axis.
 ///
 /// When nested inside another scrolling container, child scroll widgets should call
 /// [`EventCtx::set_handled`](crate::core::EventCtx::set_handled) after scrolling to prevent
 /// accidental double-scrolling due to event bubbling.
+///
+/// ## Infinite scrolling
+///
+/// When a [near-end threshold](Portal::near_end_threshold) is set, this widget emits
+/// [`PortalAction::NearEnd`] once the remaining scroll distance drops to the threshold or below,
+/// so that apps can load more items.
+///
+/// ## Scroll progress
+///
+/// When [reporting scroll progress](Portal::report_scroll_progress) is enabled, this widget
+/// emits [`PortalAction::Scrolled`] whenever the viewport moves or the content is resized.
+/// This can drive scroll-linked effects, such as collapsing headers, parallax backgrounds
+/// or reading progress indicators, without polling the viewport position.
+///
+/// ## Overscroll
+///
+/// The [`OverscrollEffect`] property picks the feedback given when the user scrolls
+/// past the content's bounds: a rubber-band bounce, an edge glow colored by
+/// [`OverscrollGlowColor`], or nothing (the default).
+/// Scroll events that only cause overscroll feedback are handled, so they don't reach
+/// a parent scroll container, or a [`PullToRefresh`](crate::widgets::PullToRefresh).
+///
+/// ## Focus
+///
+/// When a descendant is focused with the keyboard (e.g. with Tab), the portal smoothly
+/// scrolls it into view, keeping a [margin](Portal::focus_scroll_margin) around it.
+/// If the user prefers reduced motion, the portal scrolls at once.
+///
+/// ## Edge auto-scroll
+///
+/// During drags which [request edge auto-scroll], such as selecting text, the portal scrolls
+/// while the pointer is near its edges, faster the closer the pointer gets.
+///
+/// [request edge auto-scroll]: crate::core::EventCtx::request_edge_auto_scroll
+///
+/// ## Smooth scrolling
+///
+/// [`Portal::smooth_scroll_to`] animates the viewport to a position, e.g. for a "back to top"
+/// button or to navigate to an anchor.
+/// The animation is canceled when the user scrolls, or when the viewport is moved by another
+/// means.
 pub struct Portal<W: Widget + ?Sized> {
     child: WidgetPod<W>,
     content_size: Size,
@@ -58,6 +138,100 @@ pub struct Portal<W: Widget + ?Sized> {
     /// The direction of the app language. If it's right to left,
     /// the vertical scrollbar will be placed at the left side of the portal.
     right_to_left: bool,
+    near_end_threshold: Option<f64>,
+    /// Whether [`PortalAction::NearEnd`] will be emitted when the viewport gets near the end.
+    ///
+    /// This is unset when the action is emitted, and set again once the viewport
+    /// moves away from the end, so that the action is emitted once per crossing.
+    near_end_armed: bool,
+    report_scroll_progress: bool,
+    /// The last progress emitted with [`PortalAction::Scrolled`].
+    reported_progress: Option<ScrollProgress>,
+    /// The effect currently shown for overscroll.
+    ///
+    /// This is the [`OverscrollEffect`] property, adjusted for reduced motion.
+    overscroll_effect: OverscrollEffect,
+    /// How far the user has scrolled past the content's bounds, in each direction.
+    ///
+    /// Negative values are past the start of the content, positive values past its end.
+    overscroll: Vec2,
+    /// The time since the last overscroll event, if the user is still scrolling past the bounds.
+    overscroll_idle_nanos: Option<u64>,
+    /// The space kept around a focused descendant when scrolling it into view.
+    focus_scroll_margin: f64,
+    /// The viewport position being animated to, to show a focused descendant.
+    focus_pan_target: Option<Point>,
+    /// The animation started by [`Portal::smooth_scroll_to`], if it's still running.
+    smooth_scroll: Option<SmoothScroll>,
+    /// The widget filling the square between the scrollbars, when both are visible.
+    corner: Option<WidgetPod<dyn Widget>>,
+    /// The axis along which the user can't scroll.
+    locked_axis: Option<Axis>,
+    /// The velocity at which a drag near the edges scrolls the viewport, in pixels/second.
+    edge_auto_scroll: Vec2,
+    /// The fraction of the viewport scrolled by a page.
+    page_fraction: f64,
+    /// How scroll events move the viewport, if it differs from the window's configuration.
+    scroll_config: Option<ScrollConfig>,
+    /// The touch position and viewport position when the current touch pan started.
+    touch_pan: Option<(Point, Point)>,
+}
+
+/// An animation of the viewport to a position, see [`Portal::smooth_scroll_to`].
+struct SmoothScroll {
+    from: Point,
+    to: Point,
+    easing: Easing,
+    duration_nanos: u64,
+    elapsed_nanos: u64,
+}
+
+/// The actions emitted by [`Portal`].
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum PortalAction {
+    /// The viewport got near the end of the content.
+    ///
+    /// See [`Portal::near_end_threshold`] for details.
+    NearEnd,
+    /// The viewport moved, or the content was resized.
+    ///
+    /// See [`Portal::report_scroll_progress`] for details.
+    Scrolled(ScrollProgress),
+}
+
+/// How far a [`Portal`] is scrolled through its content.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct ScrollProgress {
+    /// The position of the viewport in the content, between zero and [`max_pos`](Self::max_pos).
+    pub viewport_pos: Point,
+    /// The largest position the viewport can be scrolled to.
+    ///
+    /// This is zero along the axes where the content fits in the viewport.
+    pub max_pos: Point,
+}
+
+impl ScrollProgress {
+    /// Returns how far the viewport is scrolled horizontally, from 0 at the start to 1 at the end.
+    ///
+    /// This is 0 if the content can't be scrolled horizontally.
+    pub fn horizontal(&self) -> f64 {
+        Self::fraction(self.viewport_pos.x, self.max_pos.x)
+    }
+
+    /// Returns how far the viewport is scrolled vertically, from 0 at the top to 1 at the bottom.
+    ///
+    /// This is 0 if the content can't be scrolled vertically.
+    pub fn vertical(&self) -> f64 {
+        Self::fraction(self.viewport_pos.y, self.max_pos.y)
+    }
+
+    fn fraction(pos: f64, max: f64) -> f64 {
+        if max > 0. {
+            (pos / max).clamp(0., 1.)
+        } else {
+            0.
+        }
+    }
 }
 
 // --- MARK: BUILDERS
@@ -78,6 +252,22 @@ impl<W: Widget + ?Sized> Portal<W> {
             scrollbar_vertical_visible: false,
             // --- MARK: Modified ---
             right_to_left: false,
+            near_end_threshold: None,
+            near_end_armed: true,
+            report_scroll_progress: false,
+            reported_progress: None,
+            overscroll_effect: OverscrollEffect::None,
+            overscroll: Vec2::ZERO,
+            overscroll_idle_nanos: None,
+            focus_scroll_margin: 0.,
+            focus_pan_target: None,
+            smooth_scroll: None,
+            corner: None,
+            locked_axis: None,
+            edge_auto_scroll: Vec2::ZERO,
+            page_fraction: 1.,
+            scroll_config: None,
+            touch_pan: None,
         }
     }
 
@@ -125,13 +315,97 @@ impl<W: Widget + ?Sized> Portal<W> {
 
     // --- MARK: Modified ---
     /// Builder-style method for setting the right to left direction of the app.
-    /// 
+    ///
     /// This will influence whether the vertical scrollbar is placed to the right
     /// side or to the left side of the portal.
     pub fn with_rtl(mut self, right_to_left: bool) -> Self {
         self.right_to_left = right_to_left;
         self
     }
+
+    /// Builder-style method for setting the distance from the end at which
+    /// [`PortalAction::NearEnd`] is emitted.
+    ///
+    /// The default is `None`, which never emits it.
+    ///
+    /// The action is emitted once the remaining scroll distance drops to `threshold`
+    /// or below, including when the content is too small to scroll at all.
+    /// It isn't emitted again until the remaining distance has grown above `threshold`,
+    /// e.g. because more content was appended, and then dropped below it again.
+    ///
+    /// The distance is measured vertically, or horizontally if the child is
+    /// [constrained vertically](Self::constrain_vertical).
+    pub fn near_end_threshold(mut self, threshold: Option<f64>) -> Self {
+        self.near_end_threshold = threshold;
+        self
+    }
+
+    /// Builder-style method for setting whether [`PortalAction::Scrolled`] is emitted.
+    ///
+    /// The default is `false`.
+    ///
+    /// When enabled, the action is emitted with the new [`ScrollProgress`] whenever the
+    /// viewport moves, whether the user scrolled or the viewport was moved programmatically,
+    /// and whenever a layout changes how far the content can be scrolled.
+    /// It's also emitted after the first layout.
+    pub fn report_scroll_progress(mut self, report: bool) -> Self {
+        self.report_scroll_progress = report;
+        self
+    }
+
+    /// Builder-style method for setting the widget filling the square between the scrollbars.
+    ///
+    /// The corner widget is only shown when both scrollbars are visible.
+    /// It's as wide as the vertical scrollbar and as tall as the horizontal one,
+    /// which are shortened to make room for it.
+    pub fn with_corner(mut self, corner: NewWidget<impl Widget + ?Sized>) -> Self {
+        self.corner = Some(corner.erased().to_pod());
+        self
+    }
+
+    /// Builder-style method for locking user scrolling along `axis`.
+    ///
+    /// The default is `None`, which lets the user scroll along both axes.
+    ///
+    /// When an axis is locked, scroll wheels, touch drags, the keyboard and accessibility
+    /// actions don't move the viewport along it, and its scrollbar is hidden.
+    /// Unlike [`constrain_horizontal`](Self::constrain_horizontal) and
+    /// [`constrain_vertical`](Self::constrain_vertical), this doesn't change the layout of the
+    /// child, and the viewport can still be moved along the locked axis by the app, e.g.
+    /// with [`set_viewport_pos`](Self::set_viewport_pos).
+    pub fn lock_axis(mut self, axis: Option<Axis>) -> Self {
+        self.locked_axis = axis;
+        self
+    }
+
+    /// Builder-style method for setting the space kept around a descendant focused with
+    /// the keyboard, when it is scrolled into view.
+    ///
+    /// The default is 0.
+    pub fn focus_scroll_margin(mut self, margin: f64) -> Self {
+        self.focus_scroll_margin = margin;
+        self
+    }
+
+    /// Builder-style method for setting the fraction of the viewport scrolled by a page,
+    /// with PageUp/PageDown, Space, or a click on the track of a scrollbar.
+    ///
+    /// The default is 1, which scrolls by a whole viewport.
+    /// Smaller values keep some of the previous content in view, as context.
+    pub fn page_fraction(mut self, fraction: f64) -> Self {
+        self.page_fraction = fraction;
+        self
+    }
+
+    /// Builder-style method for setting how mouse wheels and touchpads scroll this portal.
+    ///
+    /// The default is `None`, which uses the [configuration of the window].
+    ///
+    /// [configuration of the window]: crate::core::EventCtx::scroll_config
+    pub fn scroll_config(mut self, config: Option<ScrollConfig>) -> Self {
+        self.scroll_config = config;
+        self
+    }
 }
 
 pub(crate) fn compute_pan_range(mut viewport: Range<f64>, target: Range<f64>) -> Range<f64> {
@@ -198,12 +472,23 @@ impl<W: Widget + ?Sized> Portal<W> {
         ctx: &mut EventCtx<'_>,
         portal_size: Size,
         content_size: Size,
-        pos: Point,
+        mut pos: Point,
     ) -> bool {
+        match self.locked_axis {
+            Some(Axis::Horizontal) => pos.x = self.viewport_pos.x,
+            Some(Axis::Vertical) => pos.y = self.viewport_pos.y,
+            None => {}
+        }
         let changed = self.set_viewport_pos_raw(portal_size, content_size, pos);
         if changed {
+            // The user scrolled, so we stop animating the viewport.
+            self.focus_pan_target = None;
+            self.smooth_scroll = None;
             ctx.request_compose();
             self.update_scrollbars_from_viewport(ctx, portal_size, content_size);
+            for action in self.pending_actions(portal_size, content_size) {
+                ctx.submit_action::<PortalAction>(action);
+            }
         }
         changed
     }
@@ -262,18 +547,94 @@ impl<W: Widget + ?Sized> Portal<W> {
         }
 
         if changed {
+            self.focus_pan_target = None;
+            self.smooth_scroll = None;
             ctx.request_compose();
             self.update_scrollbars_from_viewport(ctx, portal_size, content_size);
+            for action in self.pending_actions(portal_size, content_size) {
+                ctx.submit_action::<PortalAction>(action);
+            }
         }
 
         changed
     }
 
+    /// Returns the actions to emit after the viewport moved or the content was resized.
+    fn pending_actions(
+        &mut self,
+        portal_size: Size,
+        content_size: Size,
+    ) -> impl Iterator<Item = PortalAction> + use<W> {
+        let scrolled = self
+            .check_scroll_progress(portal_size, content_size)
+            .map(PortalAction::Scrolled);
+        let near_end = self
+            .check_near_end(portal_size, content_size)
+            .then_some(PortalAction::NearEnd);
+        [scrolled, near_end].into_iter().flatten()
+    }
+
+    /// Returns the current scroll progress if it should be emitted, i.e. if it changed
+    /// since it was last emitted.
+    fn check_scroll_progress(
+        &mut self,
+        portal_size: Size,
+        content_size: Size,
+    ) -> Option<ScrollProgress> {
+        if !self.report_scroll_progress {
+            return None;
+        }
+        let scroll_range = (content_size - portal_size).max(Size::ZERO);
+        let progress = ScrollProgress {
+            viewport_pos: self.viewport_pos,
+            max_pos: Point::new(scroll_range.width, scroll_range.height),
+        };
+        if self.reported_progress == Some(progress) {
+            return None;
+        }
+        self.reported_progress = Some(progress);
+        Some(progress)
+    }
+
+    /// Updates whether the viewport is near the end, and returns `true` if
+    /// [`PortalAction::NearEnd`] should be emitted.
+    fn check_near_end(&mut self, portal_size: Size, content_size: Size) -> bool {
+        let Some(threshold) = self.near_end_threshold else {
+            return false;
+        };
+        let scroll_range = (content_size - portal_size).max(Size::ZERO);
+        let remaining = if self.constrain_vertical {
+            scroll_range.width - self.viewport_pos.x
+        } else {
+            scroll_range.height - self.viewport_pos.y
+        };
+
+        if remaining > threshold {
+            self.near_end_armed = true;
+            false
+        } else if self.near_end_armed {
+            self.near_end_armed = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn is_scrollbar(&self, id: WidgetId) -> bool {
+        id == self.scrollbar_vertical.id() || id == self.scrollbar_horizontal.id()
+    }
+
     /// Returns the scrolling "position" of the container.
     pub fn get_viewport_pos(&self) -> Point {
         self.viewport_pos
     }
 
+    /// Returns `true` if an animation started by [`smooth_scroll_to`](Self::smooth_scroll_to)
+    /// is still running.
+    pub fn is_smooth_scrolling(&self) -> bool {
+        self.smooth_scroll.is_some()
+    }
+
     // TODO - rename
     fn set_viewport_pos_raw(&mut self, portal_size: Size, content_size: Size, pos: Point) -> bool {
         let viewport_max_pos = (content_size - portal_size).max(Size::ZERO);
@@ -294,6 +655,12 @@ impl<W: Widget + ?Sized> Portal<W> {
     // TODO - Merge with pan_viewport_to
     // Right now these functions are just different enough to be a pain to merge.
     fn pan_viewport_to_raw(&mut self, portal_size: Size, content_size: Size, target: Rect) -> bool {
+        let pos = self.pan_target_pos(portal_size, target);
+        self.set_viewport_pos_raw(portal_size, content_size, pos)
+    }
+
+    /// Returns the viewport position which scrolls `target` into view, in child coordinates.
+    fn pan_target_pos(&self, portal_size: Size, target: Rect) -> Point {
         let viewport = Rect::from_origin_size(self.viewport_pos, portal_size);
 
         let new_pos_x = compute_pan_range(
@@ -306,8 +673,58 @@ impl<W: Widget + ?Sized> Portal<W> {
             target.min_y()..target.max_y(),
         )
         .start;
+        Point::new(new_pos_x, new_pos_y)
+    }
+
+    /// Updates the scrollbars and requests a compose pass after the viewport was moved
+    /// during an update.
+    /// Returns the velocity at which a drag at `pos` scrolls the viewport.
+    fn edge_auto_scroll_velocity(&self, portal_size: Size, pos: Point) -> Vec2 {
+        let speed = |pos: f64, length: f64| {
+            let zone = EDGE_AUTO_SCROLL_ZONE.min(length / 3.);
+            if zone <= 0. {
+                return 0.;
+            }
+            // The speed grows linearly as the pointer gets closer to an edge,
+            // and is the highest past it.
+            let towards_start = ((zone - pos) / zone).clamp(0., 1.);
+            let towards_end = ((pos - (length - zone)) / zone).clamp(0., 1.);
+            (towards_end - towards_start) * EDGE_AUTO_SCROLL_MAX_SPEED
+        };
+        let mut velocity = Vec2::new(
+            speed(pos.x, portal_size.width),
+            speed(pos.y, portal_size.height),
+        );
+        if self.constrain_horizontal || self.locked_axis == Some(Axis::Horizontal) {
+            velocity.x = 0.;
+        }
+        if self.constrain_vertical || self.locked_axis == Some(Axis::Vertical) {
+            velocity.y = 0.;
+        }
+        velocity
+    }
+
+    fn viewport_moved(&mut self, ctx: &mut UpdateCtx<'_>, portal_size: Size, content_size: Size) {
+        ctx.request_compose();
+
+        // TODO - There's a lot of code here that's duplicated from the `MouseWheel`
+        // event in `on_pointer_event`.
+        // Because this code directly manipulates child widgets, it's hard to factor
+        // it out.
+        let (scrollbar, mut scrollbar_ctx) = ctx.get_raw_mut(&mut self.scrollbar_vertical);
+        scrollbar.cursor_progress = self.viewport_pos.y / (content_size - portal_size).height;
+        scrollbar_ctx.request_render();
+
+        drop(scrollbar_ctx);
 
-        self.set_viewport_pos_raw(portal_size, content_size, Point::new(new_pos_x, new_pos_y))
+        let (scrollbar, mut scrollbar_ctx) = ctx.get_raw_mut(&mut self.scrollbar_horizontal);
+        scrollbar.cursor_progress = self.viewport_pos.x / (content_size - portal_size).width;
+        scrollbar_ctx.request_render();
+        drop(scrollbar_ctx);
+
+        for action in self.pending_actions(portal_size, content_size) {
+            ctx.submit_action::<PortalAction>(action);
+        }
     }
 }
 
@@ -324,6 +741,37 @@ impl<W: Widget + FromDynWidget + ?Sized> Portal<W> {
         this.ctx.get_mut(&mut this.widget.child)
     }
 
+    /// Returns mutable reference to the corner widget, if there is one.
+    pub fn corner_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> Option<WidgetMut<'t, dyn Widget>> {
+        let corner = this.widget.corner.as_mut()?;
+        Some(this.ctx.get_mut(corner))
+    }
+
+    /// Replaces the corner widget, or removes it if `corner` is `None`.
+    ///
+    /// See [`Portal::with_corner`] for more details.
+    pub fn set_corner(
+        this: &mut WidgetMut<'_, Self>,
+        corner: Option<NewWidget<impl Widget + ?Sized>>,
+    ) {
+        if let Some(corner) = this.widget.corner.take() {
+            this.ctx.remove_child(corner);
+        }
+        this.widget.corner = corner.map(|corner| corner.erased().to_pod());
+        this.ctx.children_changed();
+        this.ctx.request_layout();
+    }
+
+    /// Sets the axis along which the user can't scroll.
+    ///
+    /// See [`Portal::lock_axis`] for more details.
+    pub fn set_locked_axis(this: &mut WidgetMut<'_, Self>, axis: Option<Axis>) {
+        this.widget.locked_axis = axis;
+        // Scrollbars are hidden in `layout`.
+        this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
+    }
+
     /// Returns mutable reference to the horizontal scrollbar.
     pub fn horizontal_scrollbar_mut<'t>(
         this: &'t mut WidgetMut<'_, Self>,
@@ -365,6 +813,55 @@ impl<W: Widget + FromDynWidget + ?Sized> Portal<W> {
         this.ctx.request_layout();
     }
 
+    /// Sets the distance from the end at which [`PortalAction::NearEnd`] is emitted.
+    ///
+    /// This re-arms the action, so it will be emitted after the next layout if the
+    /// viewport is already near the end.
+    ///
+    /// See [`Portal::near_end_threshold`] for more details.
+    pub fn set_near_end_threshold(this: &mut WidgetMut<'_, Self>, threshold: Option<f64>) {
+        this.widget.near_end_threshold = threshold;
+        this.widget.near_end_armed = true;
+        // The threshold is checked in `layout`.
+        this.ctx.request_layout();
+    }
+
+    /// Sets whether [`PortalAction::Scrolled`] is emitted.
+    ///
+    /// When enabled, the current progress will be emitted after the next layout.
+    ///
+    /// See [`Portal::report_scroll_progress`] for more details.
+    pub fn set_report_scroll_progress(this: &mut WidgetMut<'_, Self>, report: bool) {
+        this.widget.report_scroll_progress = report;
+        this.widget.reported_progress = None;
+        // The progress is checked in `layout`.
+        this.ctx.request_layout();
+    }
+
+    /// Sets the space kept around a descendant focused with the keyboard,
+    /// when it is scrolled into view.
+    ///
+    /// See [`Portal::focus_scroll_margin`] for more details.
+    pub fn set_focus_scroll_margin(this: &mut WidgetMut<'_, Self>, margin: f64) {
+        this.widget.focus_scroll_margin = margin;
+    }
+
+    /// Sets the fraction of the viewport scrolled by a page.
+    ///
+    /// See [`Portal::page_fraction`] for more details.
+    pub fn set_page_fraction(this: &mut WidgetMut<'_, Self>, fraction: f64) {
+        this.widget.page_fraction = fraction;
+        // The scrollbars get the fraction in `layout`.
+        this.ctx.request_layout();
+    }
+
+    /// Sets how mouse wheels and touchpads scroll this portal.
+    ///
+    /// See [`Portal::scroll_config`] for more details.
+    pub fn set_scroll_config(this: &mut WidgetMut<'_, Self>, config: Option<ScrollConfig>) {
+        this.widget.scroll_config = config;
+    }
+
     /// Sets the scrolling "position" of the container.
     ///
     /// A position of zero means no scrolling at all.
@@ -375,6 +872,9 @@ impl<W: Widget + FromDynWidget + ?Sized> Portal<W> {
         let pos_changed = this
             .widget
             .set_viewport_pos_raw(portal_size, content_size, position);
+        // The viewport was moved explicitly, so we stop animating it.
+        this.widget.focus_pan_target = None;
+        this.widget.smooth_scroll = None;
         if pos_changed {
             let progress_x = this.widget.viewport_pos.x / (content_size - portal_size).width;
             Self::horizontal_scrollbar_mut(this).widget.cursor_progress = progress_x;
@@ -387,6 +887,32 @@ impl<W: Widget + FromDynWidget + ?Sized> Portal<W> {
         pos_changed
     }
 
+    /// Smoothly scrolls the container to `position`, over `duration`.
+    ///
+    /// `easing` shapes how the viewport speeds up and slows down.
+    /// The position is clamped to the scrollable range.
+    ///
+    /// The animation is canceled when the user scrolls, or when the viewport is moved
+    /// with [`set_viewport_pos`](Self::set_viewport_pos) or to show a child.
+    /// If the user prefers reduced motion, the viewport jumps to `position` on the next
+    /// animation frame.
+    pub fn smooth_scroll_to(
+        this: &mut WidgetMut<'_, Self>,
+        position: Point,
+        duration: Duration,
+        easing: Easing,
+    ) {
+        this.widget.focus_pan_target = None;
+        this.widget.smooth_scroll = Some(SmoothScroll {
+            from: this.widget.viewport_pos,
+            to: position,
+            easing,
+            duration_nanos: duration.as_nanos().try_into().unwrap_or(u64::MAX),
+            elapsed_nanos: 0,
+        });
+        this.ctx.request_anim_frame();
+    }
+
     /// Translates the scrolling "position" of the container.
     pub fn pan_viewport_by(this: &mut WidgetMut<'_, Self>, translation: Vec2) -> bool {
         Self::set_viewport_pos(this, this.widget.viewport_pos + translation)
@@ -415,35 +941,136 @@ impl<W: Widget + FromDynWidget + ?Sized> Portal<W> {
     }
 }
 
+// --- MARK: OVERSCROLL
+/// Returns how far the content is displaced for a given overscroll distance.
+fn rubber_band(overscroll: f64) -> f64 {
+    let distance = overscroll.abs();
+    let offset = MAX_BOUNCE * (1. - 1. / (distance * RUBBER_BAND_STIFFNESS / MAX_BOUNCE + 1.));
+    offset.copysign(overscroll)
+}
+
+impl<W: Widget + ?Sized> Portal<W> {
+    /// Takes the part of `delta` which scrolls back from an ongoing bounce, and returns the rest.
+    ///
+    /// The content must get back into place before it can scroll again.
+    fn absorb_bounce(&mut self, mut delta: Vec2) -> Vec2 {
+        if self.overscroll_effect != OverscrollEffect::Bounce {
+            return delta;
+        }
+        for (overscroll, delta) in [
+            (&mut self.overscroll.x, &mut delta.x),
+            (&mut self.overscroll.y, &mut delta.y),
+        ] {
+            if *overscroll == 0. || overscroll.signum() == delta.signum() {
+                continue;
+            }
+            let remaining = *overscroll + *delta;
+            if remaining.signum() == overscroll.signum() {
+                *overscroll = remaining;
+                *delta = 0.;
+            } else {
+                *overscroll = 0.;
+                *delta = remaining;
+            }
+        }
+        delta
+    }
+
+    /// Adds the part of a scroll to `target` which goes past the content's bounds to the overscroll.
+    ///
+    /// Returns `true` if the overscroll changed.
+    fn add_overscroll(&mut self, portal_size: Size, content_size: Size, target: Point) -> bool {
+        if self.overscroll_effect == OverscrollEffect::None {
+            return false;
+        }
+        let max = (content_size - portal_size).max(Size::ZERO);
+        let mut excess = Vec2::new(
+            target.x - target.x.clamp(0., max.width),
+            target.y - target.y.clamp(0., max.height),
+        );
+        if self.constrain_horizontal {
+            excess.x = 0.;
+        }
+        if self.constrain_vertical {
+            excess.y = 0.;
+        }
+        if excess == Vec2::ZERO {
+            return false;
+        }
+        self.overscroll += excess;
+        true
+    }
+
+    /// Returns how far the content is displaced by the bounce.
+    fn bounce_offset(&self) -> Vec2 {
+        if self.overscroll_effect != OverscrollEffect::Bounce {
+            return Vec2::ZERO;
+        }
+        Vec2::new(
+            rubber_band(self.overscroll.x),
+            rubber_band(self.overscroll.y),
+        )
+    }
+}
+
 // --- MARK: IMPL WIDGET
+impl<W: Widget + ?Sized> HasProperty<OverscrollEffect> for Portal<W> {}
+impl<W: Widget + ?Sized> HasProperty<OverscrollGlowColor> for Portal<W> {}
+
 impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
-    type Action = NoAction;
+    type Action = PortalAction;
 
     fn on_pointer_event(
         &mut self,
         ctx: &mut EventCtx<'_>,
-        _props: &mut PropertiesMut<'_>,
+        props: &mut PropertiesMut<'_>,
         event: &PointerEvent,
     ) {
         let portal_size = ctx.content_box_size();
         let content_size = self.content_size;
 
         match *event {
-            PointerEvent::Scroll(PointerScrollEvent { delta, .. }) => {
-                // TODO - Remove reference to scale factor.
-                // See https://github.com/linebender/xilem/issues/1264
-                let scale_factor = ctx.get_scale_factor();
-                let line_px = PhysicalPosition {
-                    x: 120.0 * scale_factor,
-                    y: 120.0 * scale_factor,
-                };
-                let page_px = PhysicalPosition {
-                    x: portal_size.width * scale_factor,
-                    y: portal_size.height * scale_factor,
-                };
-                let delta_px = delta.to_pixel_delta(line_px, page_px);
-                let dpi::LogicalPosition { x, y } = delta_px.to_logical::<f64>(scale_factor);
-                let mut delta = -Vec2 { x, y };
+            PointerEvent::Down(PointerButtonEvent {
+                ref pointer,
+                ref state,
+                ..
+            }) if pointer.pointer_type == PointerType::Touch
+                && !self.is_scrollbar(ctx.target()) =>
+            {
+                // Touching the content stops any animation of the viewport.
+                self.focus_pan_target = None;
+                self.smooth_scroll = None;
+                let touch_pos = ctx.local_position(state.position);
+                self.touch_pan = Some((touch_pos, self.viewport_pos));
+            }
+            PointerEvent::Move(PointerUpdate {
+                ref pointer,
+                ref current,
+                ..
+            }) if pointer.pointer_type == PointerType::Touch => {
+                if let Some((start_touch_pos, start_viewport_pos)) = self.touch_pan {
+                    // The content follows the finger, along both axes at once.
+                    let touch_pos = ctx.local_position(current.position);
+                    let target = start_viewport_pos - (touch_pos - start_touch_pos);
+                    let delta = target - self.viewport_pos;
+                    if self.pan_viewport_by_event_ctx(ctx, portal_size, content_size, delta) {
+                        ctx.set_handled();
+                    }
+                }
+            }
+            PointerEvent::Up(..) | PointerEvent::Cancel(..) => {
+                self.touch_pan = None;
+            }
+            PointerEvent::Scroll(PointerScrollEvent {
+                delta, ref state, ..
+            }) => {
+                let config = self.scroll_config.unwrap_or_else(|| ctx.scroll_config());
+                let mut delta = -config.logical_delta(
+                    delta,
+                    portal_size,
+                    ctx.get_scale_factor(),
+                    state.modifiers,
+                );
 
                 // Ignore scroll deltas in directions that are constrained
                 if self.constrain_horizontal {
@@ -453,7 +1080,31 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
                     delta.y = 0.;
                 }
 
-                if self.pan_viewport_by_event_ctx(ctx, portal_size, content_size, delta) {
+                let effect = match *props.get::<OverscrollEffect>() {
+                    OverscrollEffect::Bounce if ctx.prefers_reduced_motion() => {
+                        OverscrollEffect::Glow
+                    }
+                    effect => effect,
+                };
+                if effect != self.overscroll_effect {
+                    self.overscroll_effect = effect;
+                    self.overscroll = Vec2::ZERO;
+                }
+
+                let delta = self.absorb_bounce(delta);
+                let target = self.viewport_pos + delta;
+                let mut handled =
+                    self.pan_viewport_by_event_ctx(ctx, portal_size, content_size, delta);
+                let overscrolled = self.add_overscroll(portal_size, content_size, target);
+                if overscrolled || self.overscroll_idle_nanos.is_some() {
+                    // Keep the overscroll going while the user is scrolling.
+                    self.overscroll_idle_nanos = Some(0);
+                    ctx.request_anim_frame();
+                    ctx.request_compose();
+                    ctx.request_post_paint();
+                    handled = true;
+                }
+                if handled {
                     ctx.set_handled();
                 };
             }
@@ -481,21 +1132,37 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
 
         if let TextEvent::Keyboard(event) = event
             && event.state.is_down()
+            && !ctx.is_handled()
             // Avoid scrolling the portal when the focused widget is one of its scrollbars.
             // Scrollbars are focusable for keyboard users, and in that case they should own
             // the arrow/page/home/end keys.
             && !scrollbar_target
+            // Text inputs use these keys to move their caret.
+            && !ctx.focus_target_accepts_text_input()
         {
             // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
             //       https://github.com/linebender/xilem/issues/1264
             let scale = 1.0;
 
             let line = 120.0 * scale;
-            let page_y = portal_size.height * scale;
+            let page_y = portal_size.height * self.page_fraction * scale;
 
             use crate::core::keyboard::{Key, NamedKey};
             let mut did_scroll = false;
             match &event.key {
+                Key::Character(c) if c == " " && ctx.is_focus_target() => {
+                    let page_y = if event.modifiers.shift() {
+                        -page_y
+                    } else {
+                        page_y
+                    };
+                    did_scroll |= self.pan_viewport_by_event_ctx(
+                        ctx,
+                        portal_size,
+                        content_size,
+                        Vec2::new(0.0, page_y),
+                    );
+                }
                 Key::Named(NamedKey::PageDown) => {
                     did_scroll |= self.pan_viewport_by_event_ctx(
                         ctx,
@@ -636,10 +1303,97 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
         }
     }
 
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        if let Some(scroll) = &mut self.smooth_scroll {
+            let portal_size = ctx.content_box_size();
+            let content_size = self.content_size;
+            scroll.elapsed_nanos = scroll.elapsed_nanos.saturating_add(interval);
+            let finished =
+                ctx.prefers_reduced_motion() || scroll.elapsed_nanos >= scroll.duration_nanos;
+            let pos = if finished {
+                let target = scroll.to;
+                self.smooth_scroll = None;
+                target
+            } else {
+                ctx.request_anim_frame();
+                let progress = scroll.elapsed_nanos as f64 / scroll.duration_nanos as f64;
+                scroll.from.lerp(scroll.to, scroll.easing.apply(progress))
+            };
+            if self.set_viewport_pos_raw(portal_size, content_size, pos) {
+                self.viewport_moved(ctx, portal_size, content_size);
+            }
+        }
+
+        if let Some(target) = self.focus_pan_target {
+            let portal_size = ctx.content_box_size();
+            let content_size = self.content_size;
+            let progress = 1. - (-FOCUS_PAN_RATE * interval as f64 * 1e-9).exp();
+            let pos = if (target - self.viewport_pos).hypot() < 0.5 {
+                self.focus_pan_target = None;
+                target
+            } else {
+                ctx.request_anim_frame();
+                self.viewport_pos.lerp(target, progress)
+            };
+            if self.set_viewport_pos_raw(portal_size, content_size, pos) {
+                self.viewport_moved(ctx, portal_size, content_size);
+            }
+        }
+
+        if self.edge_auto_scroll != Vec2::ZERO {
+            if ctx.pointer_capture_target_id().is_none() {
+                // The drag ended without telling us.
+                self.edge_auto_scroll = Vec2::ZERO;
+            } else {
+                let portal_size = ctx.content_box_size();
+                let content_size = self.content_size;
+                let pos = self.viewport_pos + self.edge_auto_scroll * (interval as f64 * 1e-9);
+                if self.set_viewport_pos_raw(portal_size, content_size, pos) {
+                    self.focus_pan_target = None;
+                    self.smooth_scroll = None;
+                    self.viewport_moved(ctx, portal_size, content_size);
+                    ctx.request_anim_frame();
+                }
+            }
+        }
+
+        if let Some(idle_nanos) = &mut self.overscroll_idle_nanos {
+            *idle_nanos += interval;
+            if *idle_nanos < RELEASE_DELAY_NANOS {
+                ctx.request_anim_frame();
+                return;
+            }
+            // The user has stopped scrolling past the bounds.
+            self.overscroll_idle_nanos = None;
+        }
+
+        self.overscroll *= (-SPRING_RATE * interval as f64 * 1e-9).exp();
+        if self.overscroll.hypot() < 0.5 {
+            self.overscroll = Vec2::ZERO;
+        } else {
+            ctx.request_anim_frame();
+        }
+        ctx.request_compose();
+        ctx.request_post_paint();
+    }
+
     fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
         ctx.register_child(&mut self.child);
         ctx.register_child(&mut self.scrollbar_horizontal);
         ctx.register_child(&mut self.scrollbar_vertical);
+        if let Some(corner) = &mut self.corner {
+            ctx.register_child(corner);
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        OverscrollEffect::prop_changed(ctx, property_type);
+        OverscrollGlowColor::prop_changed(ctx, property_type);
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
@@ -648,25 +1402,41 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
                 let portal_size = ctx.content_box_size();
                 let content_size = self.content_size;
 
+                self.focus_pan_target = None;
+                self.smooth_scroll = None;
                 self.pan_viewport_to_raw(portal_size, content_size, *target);
-                ctx.request_compose();
-
-                // TODO - There's a lot of code here that's duplicated from the `MouseWheel`
-                // event in `on_pointer_event`.
-                // Because this code directly manipulates child widgets, it's hard to factor
-                // it out.
-                let (scrollbar, mut scrollbar_ctx) = ctx.get_raw_mut(&mut self.scrollbar_vertical);
-                scrollbar.cursor_progress =
-                    self.viewport_pos.y / (content_size - portal_size).height;
-                scrollbar_ctx.request_render();
-
-                drop(scrollbar_ctx);
-
-                let (scrollbar, mut scrollbar_ctx) =
-                    ctx.get_raw_mut(&mut self.scrollbar_horizontal);
-                scrollbar.cursor_progress =
-                    self.viewport_pos.x / (content_size - portal_size).width;
-                scrollbar_ctx.request_render();
+                self.viewport_moved(ctx, portal_size, content_size);
+            }
+            Update::RequestPanToFocusedChild(target) => {
+                let portal_size = ctx.content_box_size();
+                let content_size = self.content_size;
+
+                let margin = self.focus_scroll_margin;
+                let target = target.inflate(margin, margin);
+                let max_pos = (content_size - portal_size).max(Size::ZERO);
+                let pos = self.pan_target_pos(portal_size, target);
+                let pos = Point::new(
+                    pos.x.clamp(0., max_pos.width),
+                    pos.y.clamp(0., max_pos.height),
+                );
+                self.smooth_scroll = None;
+                if ctx.prefers_reduced_motion() {
+                    self.focus_pan_target = None;
+                    if self.set_viewport_pos_raw(portal_size, content_size, pos) {
+                        self.viewport_moved(ctx, portal_size, content_size);
+                    }
+                } else if (pos - self.viewport_pos).hypot2() > 1e-12 {
+                    self.focus_pan_target = Some(pos);
+                    ctx.request_anim_frame();
+                }
+            }
+            Update::EdgeAutoScroll(pos) => {
+                self.edge_auto_scroll = pos.map_or(Vec2::ZERO, |pos| {
+                    self.edge_auto_scroll_velocity(ctx.content_box_size(), pos)
+                });
+                if self.edge_auto_scroll != Vec2::ZERO {
+                    ctx.request_anim_frame();
+                }
             }
             _ => {}
         }
@@ -731,74 +1501,181 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
         self.set_viewport_pos_raw(size, content_size, self.viewport_pos);
         // TODO - recompute portal progress
 
+        // The content may have grown or shrunk, e.g. because more items were loaded.
+        for action in self.pending_actions(size, content_size) {
+            ctx.submit_action::<PortalAction>(action);
+        }
+
         ctx.set_clip_path(size.to_rect());
+        ctx.set_viewport(size.to_rect());
 
         ctx.place_child(&mut self.child, Point::ZERO);
 
-        self.scrollbar_horizontal_visible =
-            !self.constrain_horizontal && size.width < content_size.width;
-        self.scrollbar_vertical_visible =
-            !self.constrain_vertical && size.height < content_size.height;
+        self.scrollbar_horizontal_visible = !self.constrain_horizontal
+            && self.locked_axis != Some(Axis::Horizontal)
+            && size.width < content_size.width;
+        self.scrollbar_vertical_visible = !self.constrain_vertical
+            && self.locked_axis != Some(Axis::Vertical)
+            && size.height < content_size.height;
 
         ctx.set_stashed(
             &mut self.scrollbar_horizontal,
             !self.scrollbar_horizontal_visible,
         );
+        let mut horizontal_size = Size::ZERO;
         if self.scrollbar_horizontal_visible {
             let (scrollbar, mut sb_ctx) = ctx.get_raw_mut(&mut self.scrollbar_horizontal);
             scrollbar.portal_size = size.width;
             scrollbar.content_size = content_size.width;
+            scrollbar.page_fraction = self.page_fraction;
             sb_ctx.request_render();
             drop(sb_ctx);
 
-            let scrollbar_size = ctx.compute_size(
+            horizontal_size = ctx.compute_size(
                 &mut self.scrollbar_horizontal,
                 SizeDef::fit(size),
                 size.into(),
             );
-            ctx.run_layout(&mut self.scrollbar_horizontal, scrollbar_size);
-            ctx.place_child(
-                &mut self.scrollbar_horizontal,
-                Point::new(0.0, size.height - scrollbar_size.height),
-            );
         }
 
         ctx.set_stashed(
             &mut self.scrollbar_vertical,
             !self.scrollbar_vertical_visible,
         );
+        let mut vertical_size = Size::ZERO;
         if self.scrollbar_vertical_visible {
             let (scrollbar, mut sb_ctx) = ctx.get_raw_mut(&mut self.scrollbar_vertical);
             scrollbar.portal_size = size.height;
             scrollbar.content_size = content_size.height;
+            scrollbar.page_fraction = self.page_fraction;
             sb_ctx.request_render();
             drop(sb_ctx);
 
-            let scrollbar_size = ctx.compute_size(
+            vertical_size = ctx.compute_size(
                 &mut self.scrollbar_vertical,
                 SizeDef::fit(size),
                 size.into(),
             );
+        }
+
+        // The scrollbars are shortened to leave room for the corner widget.
+        let show_corner = self.scrollbar_horizontal_visible && self.scrollbar_vertical_visible;
+        let corner_size = match &mut self.corner {
+            Some(corner) => {
+                ctx.set_stashed(corner, !show_corner);
+                if show_corner {
+                    Size::new(vertical_size.width, horizontal_size.height)
+                } else {
+                    Size::ZERO
+                }
+            }
+            None => Size::ZERO,
+        };
+        if self.scrollbar_horizontal_visible {
+            let scrollbar_size = Size::new(
+                (horizontal_size.width - corner_size.width).max(0.),
+                horizontal_size.height,
+            );
+            ctx.run_layout(&mut self.scrollbar_horizontal, scrollbar_size);
+            let x_position = if self.right_to_left {
+                corner_size.width
+            } else {
+                0.0
+            };
+            ctx.place_child(
+                &mut self.scrollbar_horizontal,
+                Point::new(x_position, size.height - scrollbar_size.height),
+            );
+        }
+
+        if self.scrollbar_vertical_visible {
+            let scrollbar_size = Size::new(
+                vertical_size.width,
+                (vertical_size.height - corner_size.height).max(0.),
+            );
             ctx.run_layout(&mut self.scrollbar_vertical, scrollbar_size);
-            let x_position = if self.right_to_left { 0.0 } else {
+            let x_position = if self.right_to_left {
+                0.0
+            } else {
                 size.width - scrollbar_size.width
             };
+            ctx.place_child(&mut self.scrollbar_vertical, Point::new(x_position, 0.0));
+        }
+
+        if let Some(corner) = &mut self.corner
+            && show_corner
+        {
+            // In right to left mode, the corner is at the bottom left, below the vertical scrollbar.
+            let corner_x = if self.right_to_left {
+                0.0
+            } else {
+                size.width - corner_size.width
+            };
+            ctx.run_layout(corner, corner_size);
             ctx.place_child(
-                &mut self.scrollbar_vertical,
-                Point::new(x_position, 0.0),
+                corner,
+                Point::new(corner_x, size.height - corner_size.height),
             );
         }
     }
 
     fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
+        let bounce_offset = self.bounce_offset();
         ctx.set_child_scroll_translation(
             &mut self.child,
-            Vec2::new(-self.viewport_pos.x, -self.viewport_pos.y),
+            Vec2::new(-self.viewport_pos.x, -self.viewport_pos.y) - bounce_offset,
         );
     }
 
     fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
 
+    fn post_paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        if self.overscroll_effect != OverscrollEffect::Glow || self.overscroll == Vec2::ZERO {
+            return;
+        }
+        let color = props.get::<OverscrollGlowColor>().0;
+        let bounds = ctx.content_box();
+
+        // Each glow fades from the edge scrolled past towards the center of the viewport.
+        for (overscroll, axis) in [
+            (self.overscroll.x, Axis::Horizontal),
+            (self.overscroll.y, Axis::Vertical),
+        ] {
+            if overscroll == 0. {
+                continue;
+            }
+            let intensity = (overscroll.abs() / GLOW_SATURATION).min(1.);
+            let depth = GLOW_DEPTH * intensity;
+            let (rect, edge, inner) = match (axis, overscroll < 0.) {
+                (Axis::Horizontal, true) => (
+                    bounds.with_size((depth, bounds.height())),
+                    Point::new(bounds.x0, bounds.y0),
+                    Point::new(bounds.x0 + depth, bounds.y0),
+                ),
+                (Axis::Horizontal, false) => (
+                    bounds.with_origin((bounds.x1 - depth, bounds.y0)),
+                    Point::new(bounds.x1, bounds.y0),
+                    Point::new(bounds.x1 - depth, bounds.y0),
+                ),
+                (Axis::Vertical, true) => (
+                    bounds.with_size((bounds.width(), depth)),
+                    Point::new(bounds.x0, bounds.y0),
+                    Point::new(bounds.x0, bounds.y0 + depth),
+                ),
+                (Axis::Vertical, false) => (
+                    bounds.with_origin((bounds.x0, bounds.y1 - depth)),
+                    Point::new(bounds.x0, bounds.y1),
+                    Point::new(bounds.x0, bounds.y1 - depth),
+                ),
+            };
+            let gradient = Gradient::new_linear(edge, inner).with_stops([
+                (0., color.multiply_alpha(intensity as f32)),
+                (1., Color::TRANSPARENT),
+            ]);
+            fill(scene, &rect, &gradient);
+        }
+    }
+
     fn accessibility_role(&self) -> Role {
         Role::ScrollView
     }
@@ -815,8 +1692,12 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
         let content_size = self.content_size;
         let scroll_range = (content_size - portal_size).max(Size::ZERO);
 
-        let can_scroll_x = !self.constrain_horizontal && scroll_range.width > 1e-12;
-        let can_scroll_y = !self.constrain_vertical && scroll_range.height > 1e-12;
+        let can_scroll_x = !self.constrain_horizontal
+            && self.locked_axis != Some(Axis::Horizontal)
+            && scroll_range.width > 1e-12;
+        let can_scroll_y = !self.constrain_vertical
+            && self.locked_axis != Some(Axis::Vertical)
+            && scroll_range.height > 1e-12;
 
         if can_scroll_x {
             node.set_scroll_x_min(0.0);
@@ -852,11 +1733,13 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
     }
 
     fn children_ids(&self) -> ChildrenIds {
-        ChildrenIds::from_slice(&[
+        let mut ids = ChildrenIds::from_slice(&[
             self.child.id(),
             self.scrollbar_vertical.id(),
             self.scrollbar_horizontal.id(),
-        ])
+        ]);
+        ids.extend(self.corner.as_ref().map(WidgetPod::id));
+        ids
     }
 
     fn make_trace_span(&self, id: WidgetId) -> Span {
@@ -871,12 +1754,15 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
 // --- MARK: TESTS
 #[cfg(test)]
 mod tests {
+    use dpi::PhysicalPosition;
+
     use super::*;
     use crate::core::keyboard::{Key, NamedKey};
-    use crate::core::{WidgetOptions, WidgetTag};
+    use crate::core::pointer::{PointerButton, PointerId, PointerInfo, PointerState};
+    use crate::core::{Modifiers, ScrollDelta, WidgetOptions, WidgetTag, WindowEvent};
     use crate::layout::AsUnit;
     use crate::properties::Dimensions;
-    use crate::testing::{ModularWidget, TestHarness, assert_render_snapshot};
+    use crate::testing::{ModularWidget, PRIMARY_MOUSE, TestHarness, assert_render_snapshot};
     use crate::theme::test_property_set;
     use crate::widgets::{Button, Flex, SizedBox};
 
@@ -981,6 +1867,302 @@ mod tests {
         assert_render_snapshot!(harness, "portal_scrolled_button_into_view");
     }
 
+    #[test]
+    fn tab_focus_scrolls_into_view() {
+        let portal_tag = WidgetTag::named("portal");
+        let first_tag = WidgetTag::named("first");
+        let last_tag = WidgetTag::named("last");
+
+        let portal = Portal::new(
+            Flex::column()
+                .with_fixed(NewWidget::new_with_tag(
+                    Button::with_text("First"),
+                    first_tag,
+                ))
+                .with_fixed_spacer(500.px())
+                .with_fixed(NewWidget::new_with_tag(Button::with_text("Last"), last_tag))
+                .with_fixed_spacer(500.px())
+                .with_auto_id(),
+        )
+        .focus_scroll_margin(10.);
+        let widget = NewWidget::new_with_tag(portal, portal_tag);
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(200., 200.));
+        let first_id = harness.get_widget(first_tag).id();
+        let last_id = harness.get_widget(last_tag).id();
+
+        harness.focus_on(Some(first_id));
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::Tab)));
+        assert_eq!(harness.focused_widget_id(), Some(last_id));
+
+        // The portal scrolls smoothly to the focused button, keeping the margin below it.
+        harness.animate_ms(16);
+        let viewport_y = harness.get_widget(portal_tag).inner().get_viewport_pos().y;
+        assert!(viewport_y > 0.);
+        harness.animate_ms(1000);
+        assert!(harness.get_widget(portal_tag).inner().get_viewport_pos().y > viewport_y);
+
+        let button = harness.get_widget(last_tag);
+        let button_bottom = button.ctx().window_origin().y + button.ctx().border_box_size().height;
+        assert!((button_bottom - 190.).abs() < 1.);
+    }
+
+    #[test]
+    fn smooth_scroll_to() {
+        let portal_tag = WidgetTag::named("portal");
+        let content = SizedBox::empty().size(100.px(), 500.px()).with_auto_id();
+        let portal = NewWidget::new_with_tag(Portal::new(content), portal_tag);
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), portal, Size::new(100.0, 100.0));
+        let viewport_y =
+            |harness: &TestHarness<_>| harness.get_widget(portal_tag).inner().get_viewport_pos().y;
+
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::smooth_scroll_to(
+                &mut portal,
+                Point::new(0., 400.),
+                Duration::from_millis(100),
+                Easing::Linear,
+            );
+        });
+        assert_eq!(viewport_y(&harness), 0.);
+        harness.animate_ms(50);
+        assert_eq!(viewport_y(&harness), 200.);
+        harness.animate_ms(50);
+        assert_eq!(viewport_y(&harness), 400.);
+        assert!(!harness.get_widget(portal_tag).inner().is_smooth_scrolling());
+
+        // Scrolling with the mouse wheel cancels the animation.
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::smooth_scroll_to(
+                &mut portal,
+                Point::ORIGIN,
+                Duration::from_millis(100),
+                Easing::EaseInOut,
+            );
+        });
+        harness.animate_ms(50);
+        assert_eq!(viewport_y(&harness), 200.);
+        harness.mouse_move((50., 50.));
+        harness.mouse_wheel(Vec2::new(0., -20.));
+        harness.animate_ms(50);
+        assert_eq!(viewport_y(&harness), 220.);
+    }
+
+    fn touch(pos: (f64, f64)) -> (PointerInfo, PointerState) {
+        let pointer = PointerInfo {
+            pointer_id: PointerId::new(1),
+            persistent_device_id: None,
+            pointer_type: PointerType::Touch,
+        };
+        let state = PointerState {
+            position: PhysicalPosition { x: pos.0, y: pos.1 },
+            ..Default::default()
+        };
+        (pointer, state)
+    }
+
+    fn touch_down(pos: (f64, f64)) -> PointerEvent {
+        let (pointer, state) = touch(pos);
+        PointerEvent::Down(PointerButtonEvent {
+            button: Some(PointerButton::Primary),
+            pointer,
+            state,
+        })
+    }
+
+    fn touch_move(pos: (f64, f64)) -> PointerEvent {
+        let (pointer, current) = touch(pos);
+        PointerEvent::Move(PointerUpdate {
+            pointer,
+            current,
+            coalesced: vec![],
+            predicted: vec![],
+        })
+    }
+
+    fn touch_up(pos: (f64, f64)) -> PointerEvent {
+        let (pointer, state) = touch(pos);
+        PointerEvent::Up(PointerButtonEvent {
+            button: Some(PointerButton::Primary),
+            pointer,
+            state,
+        })
+    }
+
+    #[test]
+    fn edge_auto_scroll() {
+        let portal_tag = WidgetTag::named("portal");
+        let content_tag = WidgetTag::named("content");
+        // The content counts the move events it gets.
+        let content = ModularWidget::new(0_u32)
+            .measure_fn(|_, _, _, axis, _, _| match axis {
+                Axis::Horizontal => 100.,
+                Axis::Vertical => 500.,
+            })
+            .pointer_event_fn(|moves, ctx, _, event| match event {
+                PointerEvent::Down(..) => {
+                    ctx.capture_pointer();
+                    ctx.request_edge_auto_scroll();
+                }
+                PointerEvent::Move(..) => *moves += 1,
+                _ => {}
+            });
+        let portal = NewWidget::new_with_tag(
+            Portal::new(NewWidget::new_with_tag(content, content_tag)),
+            portal_tag,
+        );
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), portal, Size::new(100.0, 100.0));
+        let viewport_y =
+            |harness: &TestHarness<_>| harness.get_widget(portal_tag).inner().get_viewport_pos().y;
+        let moves = |harness: &TestHarness<_>| harness.get_widget(content_tag).inner().state;
+
+        // Far from the edges, the viewport doesn't move.
+        harness.mouse_move((50., 50.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move((50., 55.));
+        harness.animate_ms(100);
+        assert_eq!(viewport_y(&harness), 0.);
+        // The content got the hover move before the press, and the move after it.
+        assert_eq!(moves(&harness), 2);
+
+        // Near the bottom edge, it scrolls, and the content gets the last move again.
+        harness.mouse_move((50., 90.));
+        harness.animate_ms(100);
+        let scrolled = viewport_y(&harness);
+        assert!(scrolled > 0.);
+        assert!(moves(&harness) > 3);
+
+        // Past the edge, it scrolls faster.
+        harness.mouse_move((50., 120.));
+        harness.animate_ms(100);
+        assert!(viewport_y(&harness) - scrolled > scrolled);
+
+        // Releasing the pointer stops it.
+        harness.mouse_button_release(PointerButton::Primary);
+        let scrolled = viewport_y(&harness);
+        harness.animate_ms(100);
+        assert_eq!(viewport_y(&harness), scrolled);
+    }
+
+    fn wheel_lines(lines: f32, modifiers: Modifiers) -> PointerEvent {
+        PointerEvent::Scroll(PointerScrollEvent {
+            pointer: PRIMARY_MOUSE,
+            delta: ScrollDelta::LineDelta(0., lines),
+            state: PointerState {
+                position: PhysicalPosition { x: 50., y: 50. },
+                modifiers,
+                ..Default::default()
+            },
+        })
+    }
+
+    #[test]
+    fn scroll_config() {
+        let portal_tag = WidgetTag::named("portal");
+        let content = SizedBox::empty().size(500.px(), 500.px()).with_auto_id();
+        let portal = NewWidget::new_with_tag(Portal::new(content), portal_tag);
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), portal, Size::new(100.0, 100.0));
+        let viewport_pos =
+            |harness: &TestHarness<_>| harness.get_widget(portal_tag).inner().get_viewport_pos();
+        harness.set_scroll_config(ScrollConfig {
+            line_length: 40.,
+            shift_scrolls_horizontally: true,
+            ..ScrollConfig::DEFAULT
+        });
+
+        harness.process_pointer_event(wheel_lines(-1., Modifiers::empty()));
+        assert_eq!(viewport_pos(&harness), Point::new(0., 40.));
+
+        // Shift turns vertical scrolling into horizontal scrolling.
+        harness.process_pointer_event(wheel_lines(-2., Modifiers::SHIFT));
+        assert_eq!(viewport_pos(&harness), Point::new(80., 40.));
+
+        // The portal's configuration overrides the window's.
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::set_scroll_config(
+                &mut portal,
+                Some(ScrollConfig {
+                    line_length: 10.,
+                    invert_vertical: true,
+                    ..ScrollConfig::DEFAULT
+                }),
+            );
+        });
+        harness.process_pointer_event(wheel_lines(1., Modifiers::empty()));
+        assert_eq!(viewport_pos(&harness), Point::new(80., 50.));
+    }
+
+    #[test]
+    fn touch_pan_and_lock_axis() {
+        let portal_tag = WidgetTag::named("portal");
+        let content = SizedBox::empty().size(500.px(), 500.px()).with_auto_id();
+        let portal = NewWidget::new_with_tag(Portal::new(content), portal_tag);
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), portal, Size::new(100.0, 100.0));
+        let viewport_pos =
+            |harness: &TestHarness<_>| harness.get_widget(portal_tag).inner().get_viewport_pos();
+
+        // Touch drags pan along both axes at once.
+        harness.process_pointer_event(touch_down((80., 80.)));
+        harness.process_pointer_event(touch_move((30., 50.)));
+        assert_eq!(viewport_pos(&harness), Point::new(50., 30.));
+
+        // A locked axis doesn't follow the finger anymore.
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::set_locked_axis(&mut portal, Some(Axis::Vertical));
+        });
+        harness.process_pointer_event(touch_move((10., 10.)));
+        assert_eq!(viewport_pos(&harness), Point::new(70., 30.));
+        harness.process_pointer_event(touch_up((10., 10.)));
+
+        harness.mouse_move((50., 50.));
+        harness.mouse_wheel(Vec2::new(-10., -10.));
+        assert_eq!(viewport_pos(&harness), Point::new(80., 30.));
+
+        // The app can still move the viewport along the locked axis.
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::set_viewport_pos(&mut portal, Point::new(80., 60.));
+        });
+        assert_eq!(viewport_pos(&harness), Point::new(80., 60.));
+    }
+
+    #[test]
+    fn corner_between_scrollbars() {
+        let portal_tag = WidgetTag::named("portal");
+        let corner_tag = WidgetTag::named("corner");
+        let content = SizedBox::empty().size(500.px(), 500.px()).with_auto_id();
+        let portal = NewWidget::new_with_tag(
+            Portal::new(content)
+                .with_corner(NewWidget::new_with_tag(SizedBox::empty(), corner_tag)),
+            portal_tag,
+        );
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), portal, Size::new(100.0, 100.0));
+
+        let corner = harness.get_widget(corner_tag);
+        let corner_size = corner.ctx().border_box_size();
+        assert!(corner_size.width > 0. && corner_size.height > 0.);
+        assert_eq!(
+            corner.ctx().window_origin(),
+            Point::new(100. - corner_size.width, 100. - corner_size.height)
+        );
+
+        // The corner is hidden unless both scrollbars are visible.
+        harness.edit_widget(portal_tag, |mut portal| {
+            SizedBox::set_height(&mut Portal::child_mut(&mut portal), 50.px());
+        });
+        assert!(harness.get_widget(corner_tag).ctx().is_stashed());
+    }
+
     #[test]
     fn portal_accessibility_node_exposes_scroll() {
         let portal_tag = WidgetTag::named("portal");
@@ -1035,6 +2217,233 @@ mod tests {
         assert!(node.data().scroll_y().unwrap_or(0.0) > 0.0);
     }
 
+    #[test]
+    fn scrollbar_track_click_pages() {
+        let portal_tag = WidgetTag::named("portal");
+        let content = SizedBox::empty().size(100.px(), 500.px()).with_auto_id();
+        let portal = NewWidget::new_with_tag(Portal::new(content).page_fraction(0.5), portal_tag);
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), portal, Size::new(100.0, 100.0));
+        let viewport_y =
+            |harness: &TestHarness<_>| harness.get_widget(portal_tag).inner().get_viewport_pos().y;
+
+        // Clicking the track below the cursor scrolls down by half a viewport.
+        harness.mouse_move((96., 90.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(viewport_y(&harness), 50.);
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(viewport_y(&harness), 100.);
+
+        // Clicking above the cursor scrolls back up.
+        harness.mouse_move((96., 2.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(viewport_y(&harness), 50.);
+    }
+
+    #[test]
+    fn space_scrolls_focused_portal() {
+        let portal_tag = WidgetTag::named("portal");
+        let content = SizedBox::empty().size(100.px(), 500.px()).with_auto_id();
+        let portal = NewWidget::new_with_tag(Portal::new(content).page_fraction(0.8), portal_tag);
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), portal, Size::new(100.0, 100.0));
+        let viewport_y =
+            |harness: &TestHarness<_>| harness.get_widget(portal_tag).inner().get_viewport_pos().y;
+        harness.focus_on(Some(harness.get_widget(portal_tag).id()));
+
+        let space = || TextEvent::key_down(Key::Character(" ".into()));
+        harness.process_text_event(space());
+        harness.process_text_event(space());
+        assert_eq!(viewport_y(&harness), 160.);
+
+        let mut shift_space = space();
+        if let TextEvent::Keyboard(event) = &mut shift_space {
+            event.modifiers = Modifiers::SHIFT;
+        }
+        harness.process_text_event(shift_space);
+        assert_eq!(viewport_y(&harness), 80.);
+
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::PageDown)));
+        assert_eq!(viewport_y(&harness), 160.);
+    }
+
+    #[test]
+    fn scroll_near_end() {
+        let portal_tag = WidgetTag::named("portal");
+        let content = SizedBox::empty().size(100.px(), 500.px()).with_auto_id();
+        let portal = NewWidget::new_with_tag(
+            Portal::new(content).near_end_threshold(Some(50.)),
+            portal_tag,
+        );
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), portal, Size::new(100.0, 100.0));
+        let portal_id = harness.get_widget(portal_tag).id();
+        assert_eq!(harness.pop_action::<PortalAction>(), None);
+
+        // Crossing the threshold emits the action once.
+        harness.mouse_move((50., 50.));
+        harness.mouse_wheel(Vec2::new(0., -370.));
+        assert_eq!(
+            harness.pop_action::<PortalAction>(),
+            Some((PortalAction::NearEnd, portal_id))
+        );
+        harness.mouse_wheel(Vec2::new(0., -20.));
+        assert_eq!(harness.pop_action::<PortalAction>(), None);
+
+        // Moving away from the end re-arms it.
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::set_viewport_pos(&mut portal, Point::ORIGIN);
+        });
+        assert_eq!(harness.pop_action::<PortalAction>(), None);
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::set_viewport_pos(&mut portal, Point::new(0., 400.));
+        });
+        assert_eq!(
+            harness.pop_action::<PortalAction>(),
+            Some((PortalAction::NearEnd, portal_id))
+        );
+
+        // Appending content re-arms it, and shrinking it emits the action again.
+        harness.edit_widget(portal_tag, |mut portal| {
+            SizedBox::set_height(&mut Portal::child_mut(&mut portal), 1000.px());
+        });
+        assert_eq!(harness.pop_action::<PortalAction>(), None);
+        harness.edit_widget(portal_tag, |mut portal| {
+            SizedBox::set_height(&mut Portal::child_mut(&mut portal), 520.px());
+        });
+        assert_eq!(
+            harness.pop_action::<PortalAction>(),
+            Some((PortalAction::NearEnd, portal_id))
+        );
+    }
+
+    #[test]
+    fn scroll_progress() {
+        let portal_tag = WidgetTag::named("portal");
+        let content = SizedBox::empty().size(100.px(), 500.px()).with_auto_id();
+        let portal = NewWidget::new_with_tag(
+            Portal::new(content).report_scroll_progress(true),
+            portal_tag,
+        );
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), portal, Size::new(100.0, 100.0));
+        let portal_id = harness.get_widget(portal_tag).id();
+        let progress = |y: f64, max_y: f64| {
+            PortalAction::Scrolled(ScrollProgress {
+                viewport_pos: Point::new(0., y),
+                max_pos: Point::new(0., max_y),
+            })
+        };
+
+        // The initial progress is emitted after the first layout.
+        assert_eq!(
+            harness.pop_action::<PortalAction>(),
+            Some((progress(0., 400.), portal_id))
+        );
+        assert_eq!(harness.pop_action::<PortalAction>(), None);
+
+        harness.mouse_move((50., 50.));
+        harness.mouse_wheel(Vec2::new(0., -100.));
+        let Some((PortalAction::Scrolled(scrolled), _)) = harness.pop_action::<PortalAction>()
+        else {
+            panic!("Scrolling should emit the scroll progress");
+        };
+        assert_eq!(
+            scrolled,
+            ScrollProgress {
+                viewport_pos: Point::new(0., 100.),
+                max_pos: Point::new(0., 400.),
+            }
+        );
+        assert_eq!(scrolled.vertical(), 0.25);
+        assert_eq!(scrolled.horizontal(), 0.);
+
+        // Resizing the content changes the progress, even though the viewport didn't move.
+        harness.edit_widget(portal_tag, |mut portal| {
+            SizedBox::set_height(&mut Portal::child_mut(&mut portal), 300.px());
+        });
+        assert_eq!(
+            harness.pop_action::<PortalAction>(),
+            Some((progress(100., 200.), portal_id))
+        );
+
+        // Nothing is emitted if the progress didn't change.
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::set_viewport_pos(&mut portal, Point::new(0., 100.));
+        });
+        assert_eq!(harness.pop_action::<PortalAction>(), None);
+    }
+
+    #[test]
+    fn overscroll_bounce() {
+        let portal_tag = WidgetTag::named("portal");
+        let content = SizedBox::empty().size(100.px(), 500.px()).with_auto_id();
+        let portal = NewWidget::new_with(
+            Portal::new(content),
+            Some(portal_tag),
+            WidgetOptions::default(),
+            OverscrollEffect::Bounce,
+        );
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), portal, Size::new(100.0, 100.0));
+
+        // Scrolling past the top displaces the content downwards.
+        harness.mouse_move((50., 50.));
+        harness.mouse_wheel(Vec2::new(0., 50.));
+        let offset = harness.get_widget(portal_tag).inner().bounce_offset();
+        assert!(offset.y < 0. && offset.y > -50.);
+
+        // Scrolling back first brings the content back into place.
+        harness.mouse_wheel(Vec2::new(0., -20.));
+        let portal = harness.get_widget(portal_tag);
+        assert!(portal.inner().bounce_offset().y > offset.y);
+        assert_eq!(portal.inner().get_viewport_pos(), Point::ORIGIN);
+
+        // Once released, the content springs back.
+        harness.animate_ms(1000);
+        let portal = harness.get_widget(portal_tag);
+        assert_eq!(portal.inner().bounce_offset(), Vec2::ZERO);
+        assert_eq!(portal.inner().get_viewport_pos(), Point::ORIGIN);
+    }
+
+    #[test]
+    fn overscroll_reduced_motion() {
+        let portal_tag = WidgetTag::named("portal");
+        let content = SizedBox::empty().size(100.px(), 500.px()).with_auto_id();
+        let portal = NewWidget::new_with(
+            Portal::new(content),
+            Some(portal_tag),
+            WidgetOptions::default(),
+            OverscrollEffect::Bounce,
+        );
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), portal, Size::new(100.0, 100.0));
+        harness.process_window_event(WindowEvent::ReducedMotionChanged(true));
+
+        // The content doesn't move, and a glow is shown instead.
+        harness.mouse_move((50., 50.));
+        harness.mouse_wheel(Vec2::new(0., -500.));
+        let portal = harness.get_widget(portal_tag);
+        assert_eq!(portal.inner().overscroll_effect, OverscrollEffect::Glow);
+        assert_eq!(portal.inner().bounce_offset(), Vec2::ZERO);
+        assert_render_snapshot!(harness, "portal_overscroll_glow");
+
+        harness.animate_ms(1000);
+        assert_eq!(
+            harness.get_widget(portal_tag).inner().overscroll,
+            Vec2::ZERO
+        );
+    }
+
     // Helper function for panning tests
     fn make_range(repr: &str) -> Range<f64> {
         let repr = &repr[repr.find('_').unwrap()..];
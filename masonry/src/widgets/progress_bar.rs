@@ -11,8 +11,8 @@ use vello::Scene;
 use crate::core::{
     AccessCtx, ArcStr, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
     PrePaintProps, Properties, PropertiesMut, PropertiesRef, Property, RegisterCtx, Update,
-    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod, paint_background, paint_border,
-    paint_box_shadow,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod, paint_background, paint_background_image,
+    paint_border, paint_box_shadow, paint_elevation,
 };
 use crate::kurbo::{Axis, Size};
 use crate::layout::{LayoutSize, LenReq, SizeDef};
@@ -183,8 +183,22 @@ impl Widget for ProgressBar {
         let bbox = ctx.border_box();
         let p = PrePaintProps::fetch(ctx, props);
 
+        paint_elevation(
+            scene,
+            bbox,
+            p.elevation,
+            p.elevation_shadows,
+            p.corner_radius,
+        );
         paint_box_shadow(scene, bbox, p.box_shadow, p.corner_radius);
         paint_background(scene, bbox, p.background, p.border_width, p.corner_radius);
+        paint_background_image(
+            scene,
+            bbox,
+            p.background_image,
+            p.border_width,
+            p.corner_radius,
+        );
         // We need to delay painting the border until after we paint the filled bar area.
     }
 
@@ -0,0 +1,418 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::mem;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ChildrenIds, ComposeCtx, EventCtx, LayoutCtx, MeasureCtx, NewWidget, PaintCtx,
+    PointerEvent, PointerScrollEvent, PropertiesMut, PropertiesRef, RegisterCtx, UpdateCtx, Widget,
+    WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Size, Vec2};
+use crate::layout::LenReq;
+use crate::widgets::Spinner;
+
+/// The size of the refresh indicator.
+const INDICATOR_SIZE: f64 = 32.;
+/// The distance the content rests at while refreshing.
+const REFRESHING_OFFSET: f64 = INDICATOR_SIZE + 16.;
+/// The furthest the content can be pulled, which the rubber band approaches asymptotically.
+const MAX_PULL: f64 = 160.;
+/// How stiff the rubber band feels: lower values need more scrolling for the same offset.
+const RUBBER_BAND_STIFFNESS: f64 = 0.55;
+/// How long without overscroll events before the pull is considered released.
+///
+/// Scroll wheels and touchpads don't have a "release" event, so we need a timeout.
+const RELEASE_DELAY_NANOS: u64 = 150_000_000;
+/// How fast the content springs back to its resting offset, in 1/seconds.
+const SPRING_RATE: f64 = 12.;
+
+/// A container which lets users refresh its content by pulling it down past the top.
+///
+/// The child is normally a [`Portal`](crate::widgets::Portal).
+/// When the user keeps scrolling up once the child can't scroll any further, the content
+/// is pulled down with a rubber-band effect, revealing an animated indicator.
+/// If the content is released past the [threshold](Self::threshold), this widget
+/// emits [`RefreshRequested`] and the indicator stays visible until the app calls
+/// [`PullToRefresh::set_refreshing`] with `false`. Otherwise, the content springs back.
+///
+/// Only scroll events which weren't handled by the child are used for pulling,
+/// so the content is only pulled while it's scrolled to the top.
+///
+/// It reports the child's length as its own in [`measure`], syncing its size with the child's.
+/// Ensure that `PullToRefresh` has [`Dimensions`] set via props to [`Dimensions::MAX`].
+///
+/// [`measure`]: Widget::measure
+/// [`Dimensions`]: crate::properties::Dimensions
+/// [`Dimensions::MAX`]: crate::properties::Dimensions::MAX
+pub struct PullToRefresh {
+    child: WidgetPod<dyn Widget>,
+    indicator: WidgetPod<Spinner>,
+    threshold: f64,
+    /// How far the user has scrolled past the top, before the rubber band is applied.
+    overscroll: f64,
+    /// How far the content is currently pulled down.
+    offset: f64,
+    /// The time since the last overscroll event, while the user is pulling.
+    idle_nanos: Option<u64>,
+    refreshing: bool,
+}
+
+/// The action emitted by [`PullToRefresh`] when the user has pulled its content past
+/// the threshold.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct RefreshRequested;
+
+// --- MARK: BUILDERS
+impl PullToRefresh {
+    /// Creates a new `PullToRefresh` around the given child, usually a [`Portal`](crate::widgets::Portal).
+    pub fn new(child: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            child: child.erased().to_pod(),
+            indicator: WidgetPod::new(Spinner::new()),
+            threshold: 64.,
+            overscroll: 0.,
+            offset: 0.,
+            idle_nanos: None,
+            refreshing: false,
+        }
+    }
+
+    /// Builder-style method for setting how far the content must be pulled down
+    /// to request a refresh.
+    ///
+    /// The default is 64 logical pixels.
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Builder-style method for setting whether the content is refreshing.
+    ///
+    /// See [`PullToRefresh::set_refreshing`] for details.
+    pub fn refreshing(mut self, refreshing: bool) -> Self {
+        self.refreshing = refreshing;
+        self.offset = if refreshing { REFRESHING_OFFSET } else { 0. };
+        self
+    }
+
+    /// Returns whether the content is refreshing.
+    pub fn is_refreshing(&self) -> bool {
+        self.refreshing
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl PullToRefresh {
+    /// Replaces the child widget with a new one.
+    pub fn set_child(this: &mut WidgetMut<'_, Self>, child: NewWidget<impl Widget + ?Sized>) {
+        let old_child = mem::replace(&mut this.widget.child, child.erased().to_pod());
+        this.ctx.remove_child(old_child);
+    }
+
+    /// Sets how far the content must be pulled down to request a refresh.
+    ///
+    /// See [`PullToRefresh::threshold`] for more details.
+    pub fn set_threshold(this: &mut WidgetMut<'_, Self>, threshold: f64) {
+        this.widget.threshold = threshold;
+    }
+
+    /// Sets whether the content is refreshing.
+    ///
+    /// While refreshing, the indicator stays visible above the content.
+    /// The widget starts refreshing on its own when it emits [`RefreshRequested`],
+    /// so apps should call this with `false` once they're done refreshing.
+    pub fn set_refreshing(this: &mut WidgetMut<'_, Self>, refreshing: bool) {
+        if this.widget.refreshing == refreshing {
+            return;
+        }
+        this.widget.refreshing = refreshing;
+        // The content springs to its new resting offset in `on_anim_frame`.
+        this.ctx.request_anim_frame();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Returns mutable reference to the child widget.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+}
+
+// --- MARK: HELPERS
+/// Returns how far the content is pulled for a given overscroll distance.
+fn rubber_band(overscroll: f64) -> f64 {
+    MAX_PULL * (1. - 1. / (overscroll * RUBBER_BAND_STIFFNESS / MAX_PULL + 1.))
+}
+
+/// The inverse of [`rubber_band`].
+fn inverse_rubber_band(offset: f64) -> f64 {
+    let offset = offset.min(MAX_PULL - 1.);
+    MAX_PULL * (1. / (1. - offset / MAX_PULL) - 1.) / RUBBER_BAND_STIFFNESS
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for PullToRefresh {
+    type Action = RefreshRequested;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        // Scroll events handled by the child (i.e. which scrolled it) don't reach us.
+        let PointerEvent::Scroll(PointerScrollEvent { delta, state, .. }) = event else {
+            return;
+        };
+
+        let Vec2 { y, .. } = ctx.scroll_config().logical_delta(
+            *delta,
+            ctx.content_box_size(),
+            ctx.get_scale_factor(),
+            state.modifiers,
+        );
+
+        // A positive delta scrolls towards the top.
+        if y <= 0. && self.idle_nanos.is_none() {
+            return;
+        }
+        if self.idle_nanos.is_none() {
+            // Start pulling from wherever the content currently is.
+            self.overscroll = inverse_rubber_band(self.offset);
+        }
+        self.overscroll = (self.overscroll + y).max(0.);
+        let old_offset = self.offset;
+        self.offset = rubber_band(self.overscroll);
+        self.idle_nanos = Some(0);
+
+        if (old_offset == 0.) != (self.offset == 0.) {
+            // The indicator is shown or hidden in `layout`.
+            ctx.request_layout();
+        }
+        ctx.request_compose();
+        ctx.request_anim_frame();
+        ctx.set_handled();
+    }
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        if let Some(idle_nanos) = &mut self.idle_nanos {
+            *idle_nanos += interval;
+            if *idle_nanos < RELEASE_DELAY_NANOS {
+                ctx.request_anim_frame();
+                return;
+            }
+            // The user has released the content.
+            self.idle_nanos = None;
+            if !self.refreshing && self.offset >= self.threshold {
+                self.refreshing = true;
+                ctx.submit_action::<RefreshRequested>(RefreshRequested);
+                ctx.request_accessibility_update();
+            }
+        }
+
+        let target = if self.refreshing {
+            REFRESHING_OFFSET
+        } else {
+            0.
+        };
+        let old_offset = self.offset;
+        let t = 1. - (-SPRING_RATE * interval as f64 * 1e-9).exp();
+        self.offset += (target - self.offset) * t;
+        if (target - self.offset).abs() < 0.5 {
+            self.offset = target;
+        } else {
+            ctx.request_anim_frame();
+        }
+        if (old_offset == 0.) != (self.offset == 0.) {
+            // The indicator is shown or hidden in `layout`.
+            ctx.request_layout();
+        }
+        ctx.request_compose();
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+        ctx.register_child(&mut self.indicator);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        ctx.redirect_measurement(&mut self.child, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.child, size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+
+        let child_baseline = ctx.child_baseline_offset(&self.child);
+        ctx.set_baseline_offset(child_baseline);
+
+        // The indicator is placed just above the top edge, and revealed by the scroll
+        // translation set in `compose`.
+        let show_indicator = self.offset > 0.;
+        ctx.set_stashed(&mut self.indicator, !show_indicator);
+        if show_indicator {
+            ctx.run_layout(
+                &mut self.indicator,
+                Size::new(INDICATOR_SIZE, INDICATOR_SIZE),
+            );
+            ctx.place_child(
+                &mut self.indicator,
+                Point::new((size.width - INDICATOR_SIZE) / 2., -INDICATOR_SIZE),
+            );
+        }
+
+        ctx.set_clip_path(size.to_rect());
+    }
+
+    fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
+        ctx.set_child_scroll_translation(&mut self.child, Vec2::new(0., self.offset));
+        // Keep the indicator centered in the revealed area.
+        ctx.set_child_scroll_translation(
+            &mut self.indicator,
+            Vec2::new(0., (self.offset + INDICATOR_SIZE) / 2.),
+        );
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        if self.refreshing {
+            node.set_busy();
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id(), self.indicator.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("PullToRefresh", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{WidgetOptions, WidgetTag};
+    use crate::layout::AsUnit;
+    use crate::palette;
+    use crate::properties::{Background, Dimensions};
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::{Portal, SizedBox};
+
+    fn pull_to_refresh(tag: WidgetTag<PullToRefresh>) -> NewWidget<PullToRefresh> {
+        let content = SizedBox::empty()
+            .size(100.px(), 300.px())
+            .with_props(Background::Color(palette::css::ORANGE));
+        let portal = Portal::new(content).with_auto_id();
+        NewWidget::new_with(
+            PullToRefresh::new(portal),
+            Some(tag),
+            WidgetOptions::default(),
+            Dimensions::MAX,
+        )
+    }
+
+    #[test]
+    fn rubber_band_inverse() {
+        for offset in [0., 10., 64., 120.] {
+            assert!((rubber_band(inverse_rubber_band(offset)) - offset).abs() < 1e-9);
+        }
+        // The rubber band never lets the content go past its maximum.
+        assert!(rubber_band(1e9) < MAX_PULL);
+    }
+
+    #[test]
+    fn pull_past_threshold() {
+        let tag = WidgetTag::named("pull_to_refresh");
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            pull_to_refresh(tag),
+            Size::new(100., 100.),
+        );
+        let id = harness.get_widget(tag).id();
+
+        harness.mouse_move((50., 50.));
+        harness.mouse_wheel(Vec2::new(0., 200.));
+        assert_render_snapshot!(harness, "pull_to_refresh_pulled");
+
+        // Releasing past the threshold requests a refresh, and the indicator stays.
+        harness.animate_ms(200);
+        assert_eq!(
+            harness.pop_action::<RefreshRequested>(),
+            Some((RefreshRequested, id))
+        );
+        harness.animate_ms(1000);
+        assert!(harness.get_widget(tag).inner().is_refreshing());
+        assert!(!harness.get_widget(tag).children()[1].ctx().is_stashed());
+
+        // Completing the refresh hides the indicator.
+        harness.edit_widget(tag, |mut widget| {
+            PullToRefresh::set_refreshing(&mut widget, false);
+        });
+        harness.animate_ms(1000);
+        assert!(harness.get_widget(tag).children()[1].ctx().is_stashed());
+        assert_eq!(harness.pop_action::<RefreshRequested>(), None);
+    }
+
+    #[test]
+    fn pull_below_threshold() {
+        let tag = WidgetTag::named("pull_to_refresh");
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            pull_to_refresh(tag),
+            Size::new(100., 100.),
+        );
+
+        harness.mouse_move((50., 50.));
+        harness.mouse_wheel(Vec2::new(0., 20.));
+        harness.animate_ms(200);
+        harness.animate_ms(1000);
+        assert_eq!(harness.pop_action::<RefreshRequested>(), None);
+        assert!(harness.get_widget(tag).children()[1].ctx().is_stashed());
+    }
+
+    #[test]
+    fn scrolled_portal_is_not_pulled() {
+        let tag = WidgetTag::named("pull_to_refresh");
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            pull_to_refresh(tag),
+            Size::new(100., 100.),
+        );
+
+        // Scroll the portal down, then back up: the portal handles the whole scroll.
+        harness.mouse_move((50., 50.));
+        harness.mouse_wheel(Vec2::new(0., -150.));
+        harness.mouse_wheel(Vec2::new(0., 150.));
+        harness.animate_ms(200);
+        assert_eq!(harness.pop_action::<RefreshRequested>(), None);
+    }
+}
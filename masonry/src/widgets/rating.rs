@@ -0,0 +1,493 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use accesskit::{ActionData, Node, Orientation, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::pointer::PointerButton;
+use crate::core::{
+    AccessCtx, AccessEvent, ChildrenIds, EventCtx, HasProperty, LayoutCtx, MeasureCtx, PaintCtx,
+    PointerButtonEvent, PointerEvent, PointerUpdate, PropertiesMut, PropertiesRef, RegisterCtx,
+    TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+};
+use crate::kurbo::{Affine, Axis, BezPath, Point, Rect, Size, Vec2};
+use crate::layout::LenReq;
+use crate::peniko::Fill;
+use crate::properties::{BarColor, ContentColor};
+use crate::theme;
+use crate::util::stroke;
+
+/// The size of each icon, in logical pixels.
+const ICON_SIZE: f64 = 20.;
+/// The space between two icons.
+const ICON_GAP: f64 = 4.;
+
+/// A widget for choosing a rating, shown as a row of stars.
+///
+/// The rating is a value between zero and the number of icons, in steps of one,
+/// or one half with [`with_half_steps`](Self::with_half_steps).
+/// Hovering the icons previews the rating under the pointer, and clicking sets it.
+/// Clicking the current rating again clears it.
+/// When focused, the arrow keys change the rating by one step, and Home and End
+/// set it to zero and the maximum.
+///
+/// A [read-only](Self::with_read_only) rating only displays its value, e.g. to show
+/// the average rating of a product.
+///
+/// The icon can be changed to any path with [`with_icon`](Self::with_icon).
+/// The filled part of the icons is painted with the [`BarColor`] property and
+/// the rest with the [`ContentColor`] property.
+///
+/// Emits an `f64` with the new rating when it's changed by the user.
+pub struct Rating {
+    value: f64,
+    count: usize,
+    half_steps: bool,
+    read_only: bool,
+    /// The icon, in a unit square.
+    icon: BezPath,
+    /// The rating under the pointer, shown instead of the value.
+    preview: Option<f64>,
+}
+
+// --- MARK: BUILDERS
+impl Rating {
+    /// Creates a rating of `count` stars showing `value`.
+    ///
+    /// The `count` is also the highest rating.
+    pub fn new(count: usize, value: f64) -> Self {
+        Self {
+            value: value.clamp(0., count as f64),
+            count,
+            half_steps: false,
+            read_only: false,
+            icon: star(),
+            preview: None,
+        }
+    }
+
+    /// Returns `self` with half icons allowed when choosing a rating.
+    ///
+    /// Any value can be displayed, so this has no effect on read-only ratings.
+    pub fn with_half_steps(mut self, half_steps: bool) -> Self {
+        self.half_steps = half_steps;
+        self
+    }
+
+    /// Returns `self` as read-only, only displaying its value.
+    ///
+    /// This can't be changed once the widget is added, because read-only ratings can't be focused.
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Returns `self` with the given icon instead of stars.
+    ///
+    /// The `icon` is scaled from a unit square, i.e. from `(0, 0)` to `(1, 1)`, to
+    /// the size of each icon.
+    pub fn with_icon(mut self, icon: BezPath) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// Returns the current rating.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+// --- MARK: METHODS
+impl Rating {
+    fn step(&self) -> f64 {
+        if self.half_steps { 0.5 } else { 1. }
+    }
+
+    /// Returns the rating chosen by clicking at `x`.
+    fn value_at(&self, x: f64) -> f64 {
+        let slot = ICON_SIZE + ICON_GAP;
+        let index = (x / slot).floor().max(0.);
+        let within = (x - index * slot) / ICON_SIZE;
+        let value = if self.half_steps && within <= 0.5 {
+            index + 0.5
+        } else {
+            index + 1.
+        };
+        value.clamp(self.step(), self.count as f64)
+    }
+
+    /// Sets the rating chosen by the user and emits it, returning whether it changed.
+    fn choose(&mut self, ctx: &mut EventCtx<'_>, value: f64) -> bool {
+        let step = self.step();
+        let value = ((value / step).round() * step).clamp(0., self.count as f64);
+        if value == self.value {
+            return false;
+        }
+        self.value = value;
+        ctx.submit_action::<f64>(value);
+        ctx.request_render();
+        true
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Rating {
+    /// Sets the current rating.
+    pub fn set_value(this: &mut WidgetMut<'_, Self>, value: f64) {
+        let value = value.clamp(0., this.widget.count as f64);
+        if value != this.widget.value {
+            this.widget.value = value;
+            this.ctx.request_render();
+        }
+    }
+
+    /// Sets the number of icons, i.e. the highest rating.
+    pub fn set_count(this: &mut WidgetMut<'_, Self>, count: usize) {
+        this.widget.count = count;
+        this.widget.value = this.widget.value.clamp(0., count as f64);
+        this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets whether half icons are allowed when choosing a rating.
+    pub fn set_half_steps(this: &mut WidgetMut<'_, Self>, half_steps: bool) {
+        this.widget.half_steps = half_steps;
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets the icon, in a unit square, or restores the default star if `icon` is `None`.
+    pub fn set_icon(this: &mut WidgetMut<'_, Self>, icon: Option<BezPath>) {
+        this.widget.icon = icon.unwrap_or_else(star);
+        this.ctx.request_paint_only();
+    }
+}
+
+/// Returns a five-pointed star in a unit square.
+fn star() -> BezPath {
+    const INNER_RADIUS: f64 = 0.2;
+    const OUTER_RADIUS: f64 = 0.5;
+    let center = Point::new(0.5, 0.5);
+    let mut path = BezPath::new();
+    for i in 0..10 {
+        let radius = if i % 2 == 0 {
+            OUTER_RADIUS
+        } else {
+            INNER_RADIUS
+        };
+        let angle = i as f64 * PI / 5. - FRAC_PI_2;
+        let point = center + radius * Vec2::from_angle(angle);
+        if i == 0 {
+            path.move_to(point);
+        } else {
+            path.line_to(point);
+        }
+    }
+    path.close_path();
+    path
+}
+
+impl HasProperty<BarColor> for Rating {}
+impl HasProperty<ContentColor> for Rating {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Rating {
+    type Action = f64;
+
+    fn accepts_focus(&self) -> bool {
+        !self.read_only
+    }
+
+    fn accepts_pointer_interaction(&self) -> bool {
+        !self.read_only
+    }
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if ctx.is_disabled() || self.read_only {
+            return;
+        }
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                ctx.request_focus();
+                let value = self.value_at(ctx.local_position(state.position).x);
+                // Clicking the current rating clears it.
+                if !self.choose(ctx, value) {
+                    self.choose(ctx, 0.);
+                }
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) => {
+                let preview = Some(self.value_at(ctx.local_position(current.position).x));
+                if preview != self.preview {
+                    self.preview = preview;
+                    ctx.request_paint_only();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if ctx.is_disabled() || self.read_only || !ctx.is_focus_target() {
+            return;
+        }
+        let TextEvent::Keyboard(key_event) = event else {
+            return;
+        };
+        if key_event.state.is_up() {
+            return;
+        }
+        let value = match &key_event.key {
+            Key::Named(NamedKey::ArrowLeft | NamedKey::ArrowDown) => self.value - self.step(),
+            Key::Named(NamedKey::ArrowRight | NamedKey::ArrowUp) => self.value + self.step(),
+            Key::Named(NamedKey::Home) => 0.,
+            Key::Named(NamedKey::End) => self.count as f64,
+            _ => return,
+        };
+        self.choose(ctx, value);
+        ctx.set_handled();
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if ctx.is_disabled() || self.read_only {
+            return;
+        }
+        let value = match event.action {
+            accesskit::Action::Increment => self.value + self.step(),
+            accesskit::Action::Decrement => self.value - self.step(),
+            accesskit::Action::SetValue => match &event.data {
+                Some(ActionData::NumericValue(value)) => *value,
+                Some(ActionData::Value(value)) => match value.parse() {
+                    Ok(value) => value,
+                    Err(_) => return,
+                },
+                _ => return,
+            },
+            _ => return,
+        };
+        self.choose(ctx, value);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::HoveredChanged(false) => {
+                self.preview = None;
+                ctx.request_paint_only();
+            }
+            Update::FocusChanged(_) | Update::DisabledChanged(_) => {
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        BarColor::prop_changed(ctx, property_type);
+        ContentColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
+        //       https://github.com/linebender/xilem/issues/1264
+        let scale = 1.0;
+
+        match axis {
+            Axis::Horizontal => {
+                let count = self.count as f64;
+                (count * ICON_SIZE + (count - 1.).max(0.) * ICON_GAP) * scale
+            }
+            Axis::Vertical => ICON_SIZE * scale,
+        }
+    }
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, _size: Size) {}
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let filled_color = props.get::<BarColor>().0;
+        let empty_color = props.get::<ContentColor>().color;
+        let shown = self.preview.unwrap_or(self.value);
+        let y = (ctx.content_box_size().height - ICON_SIZE) / 2.;
+
+        // Push semitransparent layer if disabled
+        if ctx.is_disabled() {
+            const DISABLED_ALPHA: f32 = 0.4;
+            scene.push_layer(
+                Fill::NonZero,
+                crate::peniko::Mix::Normal,
+                DISABLED_ALPHA,
+                Affine::IDENTITY,
+                &ctx.border_box(),
+            );
+        }
+
+        for i in 0..self.count {
+            let x = i as f64 * (ICON_SIZE + ICON_GAP);
+            let transform = Affine::translate((x, y)) * Affine::scale(ICON_SIZE);
+            scene.fill(Fill::NonZero, transform, empty_color, None, &self.icon);
+
+            // Partially filled icons are clipped to the filled fraction.
+            let fraction = (shown - i as f64).clamp(0., 1.);
+            if fraction > 0. {
+                let filled = Rect::new(x, y, x + ICON_SIZE * fraction, y + ICON_SIZE);
+                scene.push_clip_layer(Fill::NonZero, Affine::IDENTITY, &filled);
+                scene.fill(Fill::NonZero, transform, filled_color, None, &self.icon);
+                scene.pop_layer();
+            }
+        }
+
+        // Paint focus ring
+        if ctx.is_focus_target() && !ctx.is_disabled() {
+            // TODO: Either stop painting the focus outside border-box bounds
+            //       or correctly set paint insets in layout.
+            let focus_rect = ctx.border_box().inset(2.0);
+            stroke(
+                scene,
+                &focus_rect.to_rounded_rect(4.0),
+                theme::FOCUS_COLOR,
+                1.0,
+            );
+        }
+
+        // Pop the semitransparent layer
+        if ctx.is_disabled() {
+            scene.pop_layer();
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        if self.read_only {
+            Role::Meter
+        } else {
+            Role::Slider
+        }
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_value(format!("{} of {}", self.value, self.count));
+        node.set_numeric_value(self.value);
+        node.set_min_numeric_value(0.);
+        node.set_max_numeric_value(self.count as f64);
+        if self.read_only {
+            node.set_read_only();
+            return;
+        }
+        node.set_orientation(Orientation::Horizontal);
+        node.set_numeric_value_step(self.step());
+        node.add_action(accesskit::Action::SetValue);
+        node.add_action(accesskit::Action::Increment);
+        node.add_action(accesskit::Action::Decrement);
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::new()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Rating", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+
+    fn harness(rating: Rating) -> TestHarness<Rating> {
+        TestHarness::create_with_size(
+            test_property_set(),
+            rating.with_auto_id(),
+            Size::new(5. * ICON_SIZE + 4. * ICON_GAP, ICON_SIZE),
+        )
+    }
+
+    /// Returns the position in the middle of the `index`th icon, offset by `fraction` of the icon.
+    fn icon_point(index: usize, fraction: f64) -> Point {
+        let x = index as f64 * (ICON_SIZE + ICON_GAP) + ICON_SIZE * fraction;
+        Point::new(x, ICON_SIZE / 2.)
+    }
+
+    #[test]
+    fn click_sets_rating() {
+        let mut harness = harness(Rating::new(5, 1.));
+
+        harness.mouse_move(icon_point(3, 0.5));
+        assert_eq!(harness.root_widget().preview, Some(4.));
+        assert_eq!(harness.root_widget().value(), 1.);
+
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(harness.pop_action::<f64>(), Some((4., harness.root_id())));
+        assert_eq!(harness.root_widget().value(), 4.);
+
+        // Clicking the current rating clears it.
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(harness.pop_action::<f64>(), Some((0., harness.root_id())));
+    }
+
+    #[test]
+    fn half_steps() {
+        let mut harness = harness(Rating::new(5, 0.).with_half_steps(true));
+
+        harness.mouse_move(icon_point(2, 0.25));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(harness.root_widget().value(), 2.5);
+
+        harness.focus_on(Some(harness.root_id()));
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowRight)));
+        assert_eq!(harness.root_widget().value(), 3.);
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::End)));
+        assert_eq!(harness.root_widget().value(), 5.);
+    }
+
+    #[test]
+    fn read_only_ignores_input() {
+        let mut harness = harness(Rating::new(5, 3.7).with_read_only(true));
+
+        harness.mouse_move(icon_point(0, 0.5));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(harness.pop_action::<f64>(), None);
+        assert_eq!(harness.root_widget().value(), 3.7);
+        assert_eq!(harness.root_widget().preview, None);
+    }
+}
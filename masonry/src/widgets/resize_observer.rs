@@ -4,18 +4,22 @@
 use std::mem;
 
 use crate::core::{
-    AccessCtx, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, PaintCtx, PropertiesRef, RegisterCtx,
-    Widget, WidgetMut, WidgetPod,
+    AccessCtx, ChildrenIds, ComposeCtx, LayoutCtx, MeasureCtx, NewWidget, PaintCtx, PropertiesRef,
+    RegisterCtx, Widget, WidgetMut, WidgetPod,
 };
 use crate::kurbo::{Axis, Point, Size};
 use crate::layout::LenReq;
 
 /// A widget which sends a [`LayoutChanged`] whenever its size changes.
 ///
+/// With [position tracking](Self::with_position_tracking), it also sends one whenever its
+/// position in the window changes, e.g. because an ancestor scrolled.
+/// At most one action is sent per layout or compose pass.
+///
 /// It reports the child's length as its own in [`measure`], syncing its size with the child's.
 ///
 /// The size of this widget can be accessed through [`MutateCtx`] methods like
-/// [`border_box_size`] and [`content_box_size`].
+/// [`border_box_size`] and [`content_box_size`], and its position through [`window_origin`].
 ///
 /// Ensure that `ResizeObserver` has [`Dimensions`] set via props to [`Dimensions::MAX`].
 /// Max preferred size of `ResizeObserver` means that the question of size
@@ -45,6 +49,7 @@ use crate::layout::LenReq;
 /// [`MutateCtx`]: crate::core::MutateCtx
 /// [`border_box_size`]: crate::core::MutateCtx::border_box_size
 /// [`content_box_size`]: crate::core::MutateCtx::content_box_size
+/// [`window_origin`]: crate::core::MutateCtx::window_origin
 // TODO: It would be nice to at least catch these loops.
 // We could see how many times layout is executed without us being painted, and setting a threshold.
 // The response if that gets too high (100?) could be debug_panicking, then stopping
@@ -55,6 +60,8 @@ use crate::layout::LenReq;
 pub struct ResizeObserver {
     child: WidgetPod<dyn Widget>,
     last_size: Option<Size>,
+    track_position: bool,
+    last_origin: Option<Point>,
 }
 
 // --- MARK: BUILDERS
@@ -68,8 +75,19 @@ impl ResizeObserver {
         Self {
             child: child.erased().to_pod(),
             last_size: None,
+            track_position: false,
+            last_origin: None,
         }
     }
+
+    /// Builder-style method to also send [`LayoutChanged`] when the position
+    /// of this widget in the window changes.
+    ///
+    /// The default is `false`.
+    pub fn with_position_tracking(mut self, track_position: bool) -> Self {
+        self.track_position = track_position;
+        self
+    }
 }
 
 // --- MARK: WIDGETMUT
@@ -88,6 +106,16 @@ impl ResizeObserver {
         this.ctx.request_layout();
     }
 
+    /// Sets whether [`LayoutChanged`] is also sent when the position
+    /// of this widget in the window changes.
+    pub fn set_position_tracking(this: &mut WidgetMut<'_, Self>, track_position: bool) {
+        this.widget.track_position = track_position;
+        if !track_position {
+            this.widget.last_origin = None;
+        }
+        this.ctx.request_compose();
+    }
+
     /// Returns mutable reference to the child widget.
     pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
         this.ctx.get_mut(&mut this.widget.child)
@@ -136,9 +164,15 @@ impl Widget for ResizeObserver {
 
         let child_baseline = ctx.child_baseline_offset(&self.child);
         ctx.set_baseline_offset(child_baseline);
+    }
 
-        if self.last_size.is_none_or(|it| it != size) {
+    fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
+        // We wait until compose to send the action, so that our position is final.
+        let size = ctx.content_box_size();
+        let origin = self.track_position.then(|| ctx.window_origin());
+        if self.last_size.is_none_or(|it| it != size) || self.last_origin != origin {
             self.last_size = Some(size);
+            self.last_origin = origin;
             ctx.submit_action::<Self::Action>(LayoutChanged);
         }
     }
@@ -175,11 +209,11 @@ mod tests {
     use masonry_testing::TestHarness;
 
     use crate::core::{NewWidget, Widget, WidgetTag, WindowEvent};
-    use crate::kurbo::Size;
+    use crate::kurbo::{Point, Size};
     use crate::layout::AsUnit;
-    use crate::properties::Dimensions;
+    use crate::properties::{Dimensions, Gap};
     use crate::theme::default_property_set;
-    use crate::widgets::{Flex, LayoutChanged, ResizeObserver, SizedBox};
+    use crate::widgets::{Flex, LayoutChanged, Portal, ResizeObserver, SizedBox};
 
     #[test]
     fn detects_inner_resizing() {
@@ -285,4 +319,51 @@ mod tests {
         // The size hasn't changed, so no event.
         assert!(harness.pop_action::<LayoutChanged>().is_none());
     }
+
+    #[test]
+    fn tracks_position() {
+        let spacer_tag = WidgetTag::named("spacer");
+        let spacer = NewWidget::new_with_tag(SizedBox::empty().height(50.px()), spacer_tag);
+        let inner_box = SizedBox::empty()
+            .width(100.px())
+            .height(100.px())
+            .with_auto_id();
+        let observer = ResizeObserver::new(inner_box)
+            .with_position_tracking(true)
+            .with_props(Dimensions::MAX);
+        let observer_id = observer.id();
+        let flex = Flex::column()
+            .with_fixed(spacer)
+            .with_fixed(observer)
+            .with_fixed(SizedBox::empty().height(500.px()).with_auto_id())
+            .with_props(Gap::ZERO);
+        let portal_tag = WidgetTag::named("portal");
+        let portal = NewWidget::new_with_tag(Portal::new(flex), portal_tag);
+        let mut harness =
+            TestHarness::create_with_size(default_property_set(), portal, Size::new(200., 200.));
+        let (LayoutChanged, action_id) = harness.pop_action::<LayoutChanged>().unwrap();
+        assert_eq!(action_id, observer_id);
+        let origin = |harness: &TestHarness<_>| {
+            harness
+                .get_widget_with_id(observer_id)
+                .ctx()
+                .window_origin()
+        };
+        assert_eq!(origin(&harness).y, 50.);
+        assert!(harness.pop_action::<LayoutChanged>().is_none());
+
+        // Moving the observer without resizing it.
+        harness.edit_widget(spacer_tag, |mut it| SizedBox::set_height(&mut it, 80.px()));
+        let (LayoutChanged, _) = harness.pop_action::<LayoutChanged>().unwrap();
+        assert_eq!(origin(&harness).y, 80.);
+        assert!(harness.pop_action::<LayoutChanged>().is_none());
+
+        // Scrolling an ancestor.
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::set_viewport_pos(&mut portal, Point::new(0., 30.));
+        });
+        let (LayoutChanged, _) = harness.pop_action::<LayoutChanged>().unwrap();
+        assert_eq!(origin(&harness).y, 50.);
+        assert!(harness.pop_action::<LayoutChanged>().is_none());
+    }
 }
@@ -0,0 +1,607 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+use std::ops::Range;
+
+use accesskit::{Node, Role};
+use parley::{
+    FontContext, InlineBox, Layout, LayoutAccessibility, LayoutContext, PositionedLayoutItem,
+};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ArcStr, BrushIndex, ChildrenIds, HasProperty, LayoutCtx, MeasureCtx, NewWidget,
+    NoAction, PaintCtx, PropertiesMut, PropertiesRef, RegisterCtx, StyleProperty, StyleSet, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod, render_text,
+};
+use crate::kurbo::{Affine, Axis, Point, Size};
+use crate::layout::{LayoutSize, LenReq};
+use crate::properties::{ContentColor, DisabledContentColor};
+use crate::theme::default_text_styles;
+use crate::util::debug_panic;
+use crate::widgets::DecorationSpan;
+use crate::widgets::text_decoration::{paint_highlights, paint_underlines};
+use crate::{TextAlign, TextAlignOptions, theme};
+
+/// A widget displaying non-interactive rich text, which can contain styled spans,
+/// [decorations](crate::widgets::TextDecoration) and inline widgets.
+///
+/// Styled spans change the layout of parts of the text, e.g. to make a mention bold.
+/// Decorations are painted over parts of the text, e.g. to highlight search results.
+/// Inline widgets, such as a small icon or chip, are placed at a byte index of the text
+/// and participate in line breaking as if they were a single character.
+/// They are given their max-content size, and sit on the baseline of their line.
+///
+/// The text is always wrapped to the available width.
+/// You can customize the color of the text with the [`ContentColor`] and
+/// [`DisabledContentColor`] properties.
+pub struct RichText {
+    layout: Layout<BrushIndex>,
+    accessibility: LayoutAccessibility,
+
+    text: ArcStr,
+    styles: StyleSet,
+    span_styles: Vec<(Range<usize>, StyleProperty)>,
+    decorations: Vec<DecorationSpan>,
+    inline_widgets: Vec<InlineWidget>,
+    /// Whether the text, styles or sizes of the inline widgets have been updated since
+    /// `layout` was built.
+    layout_changed: bool,
+
+    text_alignment: TextAlign,
+
+    /// Whether to hint whilst drawing the text.
+    hint: bool,
+}
+
+struct InlineWidget {
+    /// The byte index of the text where the widget is placed.
+    index: usize,
+    widget: WidgetPod<dyn Widget>,
+    /// The size of the widget when `layout` was built.
+    size: Size,
+}
+
+// --- MARK: BUILDERS
+impl RichText {
+    /// Creates a new rich text widget with the given text.
+    ///
+    // This is written out fully to appease rust-analyzer; StyleProperty is imported but not recognised.
+    /// To change the font size, use `with_style`, setting [`StyleProperty::FontSize`](parley::StyleProperty::FontSize).
+    pub fn new(text: impl Into<ArcStr>) -> Self {
+        let mut styles = StyleSet::new(theme::TEXT_SIZE_NORMAL);
+        default_text_styles(&mut styles);
+        Self {
+            layout: Layout::new(),
+            accessibility: LayoutAccessibility::default(),
+            text: text.into(),
+            styles,
+            span_styles: Vec::new(),
+            decorations: Vec::new(),
+            inline_widgets: Vec::new(),
+            layout_changed: true,
+            text_alignment: TextAlign::Start,
+            hint: true,
+        }
+    }
+
+    /// Sets a style property for the whole text.
+    ///
+    /// Setting [`StyleProperty::Brush`](parley::StyleProperty::Brush) is not supported.
+    /// Use [`ContentColor`] and [`DisabledContentColor`] properties instead.
+    ///
+    /// To set a style property on an active widget, use [`insert_style`](Self::insert_style).
+    pub fn with_style(mut self, property: impl Into<StyleProperty>) -> Self {
+        let property = property.into();
+        check_brush(&property);
+        self.styles.insert(property);
+        self
+    }
+
+    /// Sets a style property for the byte range `range` of the text, e.g. to make a word bold.
+    ///
+    /// Setting [`StyleProperty::Brush`](parley::StyleProperty::Brush) is not supported.
+    /// To color parts of the text, use [decorations](Self::with_decoration) instead.
+    ///
+    /// To set the span styles of an active widget, use [`set_span_styles`](Self::set_span_styles).
+    pub fn with_span_style(
+        mut self,
+        range: Range<usize>,
+        property: impl Into<StyleProperty>,
+    ) -> Self {
+        let property = property.into();
+        check_brush(&property);
+        self.span_styles.push((range, property));
+        self
+    }
+
+    /// Adds a [decoration](crate::widgets::TextDecoration) painted over a range of the text.
+    ///
+    /// To set the decorations of an active widget, use [`set_decorations`](Self::set_decorations).
+    pub fn with_decoration(mut self, decoration: DecorationSpan) -> Self {
+        self.decorations.push(decoration);
+        self
+    }
+
+    /// Adds a widget placed in the text at the byte index `index`.
+    ///
+    /// To add an inline widget to an active widget, use
+    /// [`add_inline_widget`](Self::add_inline_widget).
+    pub fn with_inline_widget(
+        mut self,
+        index: usize,
+        widget: NewWidget<impl Widget + ?Sized>,
+    ) -> Self {
+        self.inline_widgets.push(InlineWidget {
+            index,
+            widget: widget.erased().to_pod(),
+            size: Size::ZERO,
+        });
+        self
+    }
+
+    /// Sets the alignment of the text.
+    ///
+    /// To modify this on an active widget, use [`set_text_alignment`](Self::set_text_alignment).
+    pub fn with_text_alignment(mut self, text_alignment: TextAlign) -> Self {
+        self.text_alignment = text_alignment;
+        self
+    }
+
+    /// Sets whether [hinting](https://en.wikipedia.org/wiki/Font_hinting) will be used for this text.
+    ///
+    /// See [`Label::with_hint`](crate::widgets::Label::with_hint) for details.
+    ///
+    /// To modify this on an active widget, use [`set_hint`](Self::set_hint).
+    pub fn with_hint(mut self, hint: bool) -> Self {
+        self.hint = hint;
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl RichText {
+    /// Returns a reference to the current text.
+    pub fn text(&self) -> &ArcStr {
+        &self.text
+    }
+
+    /// Returns the decorations painted over the text.
+    pub fn decorations(&self) -> &[DecorationSpan] {
+        &self.decorations
+    }
+
+    /// Returns the number of inline widgets.
+    pub fn inline_widget_count(&self) -> usize {
+        self.inline_widgets.len()
+    }
+
+    /// Records the sizes of the inline widgets, given by `measure`.
+    fn measure_inline_widgets(
+        &mut self,
+        mut measure: impl FnMut(&mut WidgetPod<dyn Widget>, Axis, Option<f64>) -> f64,
+    ) {
+        for inline in &mut self.inline_widgets {
+            let width = measure(&mut inline.widget, Axis::Horizontal, None);
+            let height = measure(&mut inline.widget, Axis::Vertical, Some(width));
+            let size = Size::new(width, height);
+            if inline.size != size {
+                inline.size = size;
+                self.layout_changed = true;
+            }
+        }
+    }
+
+    /// Builds the text layout from the text, styles and inline widget sizes.
+    fn build_layout(
+        &mut self,
+        font_ctx: &mut FontContext,
+        layout_ctx: &mut LayoutContext<BrushIndex>,
+        text_scale: f32,
+    ) {
+        let mut builder = layout_ctx.ranged_builder(font_ctx, &self.text, text_scale, true);
+        for prop in self.styles.inner().values() {
+            builder.push_default(prop.to_owned());
+        }
+        for (range, prop) in &self.span_styles {
+            let range = range.start.min(self.text.len())..range.end.min(self.text.len());
+            builder.push(prop.to_owned(), range);
+        }
+        for (id, inline) in self.inline_widgets.iter().enumerate() {
+            builder.push_inline_box(InlineBox {
+                id: id as u64,
+                index: floor_char_boundary(&self.text, inline.index),
+                width: inline.size.width as f32,
+                height: inline.size.height as f32,
+            });
+        }
+        builder.build_into(&mut self.layout, &self.text);
+        self.layout_changed = false;
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl RichText {
+    /// Replaces the text of this widget.
+    ///
+    /// The span styles, decorations and inline widgets are kept, at the same byte indices.
+    pub fn set_text(this: &mut WidgetMut<'_, Self>, new_text: impl Into<ArcStr>) {
+        this.widget.text = new_text.into();
+        this.widget.layout_changed = true;
+        this.ctx.request_layout();
+    }
+
+    /// Sets a style property for the whole text.
+    ///
+    /// The runtime equivalent of [`with_style`](Self::with_style).
+    pub fn insert_style(
+        this: &mut WidgetMut<'_, Self>,
+        property: impl Into<StyleProperty>,
+    ) -> Option<StyleProperty> {
+        let property = property.into();
+        check_brush(&property);
+        let old = this.widget.styles.insert(property);
+        this.widget.layout_changed = true;
+        this.ctx.request_layout();
+        old
+    }
+
+    /// Replaces the styles of ranges of the text.
+    ///
+    /// See [`with_span_style`](Self::with_span_style) for details.
+    pub fn set_span_styles(
+        this: &mut WidgetMut<'_, Self>,
+        span_styles: Vec<(Range<usize>, StyleProperty)>,
+    ) {
+        span_styles
+            .iter()
+            .for_each(|(_, property)| check_brush(property));
+        this.widget.span_styles = span_styles;
+        this.widget.layout_changed = true;
+        this.ctx.request_layout();
+    }
+
+    /// Replaces the decorations painted over the text.
+    ///
+    /// This doesn't lay out the text again.
+    pub fn set_decorations(this: &mut WidgetMut<'_, Self>, decorations: Vec<DecorationSpan>) {
+        this.widget.decorations = decorations;
+        this.ctx.request_paint_only();
+    }
+
+    /// Adds a widget placed in the text at the byte index `index`.
+    ///
+    /// The runtime equivalent of [`with_inline_widget`](Self::with_inline_widget).
+    pub fn add_inline_widget(
+        this: &mut WidgetMut<'_, Self>,
+        index: usize,
+        widget: NewWidget<impl Widget + ?Sized>,
+    ) {
+        this.widget.inline_widgets.push(InlineWidget {
+            index,
+            widget: widget.erased().to_pod(),
+            size: Size::ZERO,
+        });
+        this.widget.layout_changed = true;
+        this.ctx.children_changed();
+    }
+
+    /// Returns a mutable reference to the inline widget at `idx`, in the order they were added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn inline_widget_mut<'t>(
+        this: &'t mut WidgetMut<'_, Self>,
+        idx: usize,
+    ) -> WidgetMut<'t, dyn Widget> {
+        this.ctx
+            .get_mut(&mut this.widget.inline_widgets[idx].widget)
+    }
+
+    /// Removes the inline widget at `idx`, in the order they were added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn remove_inline_widget(this: &mut WidgetMut<'_, Self>, idx: usize) {
+        let inline = this.widget.inline_widgets.remove(idx);
+        this.ctx.remove_child(inline.widget);
+        this.widget.layout_changed = true;
+    }
+
+    /// The runtime equivalent of [`with_text_alignment`](Self::with_text_alignment).
+    pub fn set_text_alignment(this: &mut WidgetMut<'_, Self>, text_alignment: TextAlign) {
+        this.widget.text_alignment = text_alignment;
+        this.ctx.request_layout();
+    }
+
+    /// The runtime equivalent of [`with_hint`](Self::with_hint).
+    pub fn set_hint(this: &mut WidgetMut<'_, Self>, hint: bool) {
+        this.widget.hint = hint;
+        this.ctx.request_paint_only();
+    }
+}
+
+fn check_brush(property: &StyleProperty) {
+    if let StyleProperty::Brush(idx @ BrushIndex(1..))
+    | StyleProperty::UnderlineBrush(Some(idx @ BrushIndex(1..)))
+    | StyleProperty::StrikethroughBrush(Some(idx @ BrushIndex(1..))) = property
+    {
+        debug_panic!(
+            "Can't set a non-zero brush index ({idx:?}) on a `RichText`, use decorations to color the text instead."
+        );
+    }
+}
+
+/// Returns the largest char boundary of `text` which is not after `index`.
+fn floor_char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+impl HasProperty<ContentColor> for RichText {}
+impl HasProperty<DisabledContentColor> for RichText {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for RichText {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for inline in &mut self.inline_widgets {
+            ctx.register_child(&mut inline.widget);
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        ContentColor::prop_changed(ctx, property_type);
+        DisabledContentColor::prop_changed(ctx, property_type);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::DisabledChanged(_) => {
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        // As in `Label`, we only support the horizontal-tb writing mode.
+        let max_advance = if axis == Axis::Horizontal {
+            match len_req {
+                LenReq::MinContent => Some(0.),
+                LenReq::MaxContent => None,
+                LenReq::FitContent(space) => Some(space),
+            }
+        } else {
+            match len_req {
+                LenReq::MinContent => cross_length.or(Some(0.)),
+                LenReq::MaxContent | LenReq::FitContent(_) => cross_length,
+            }
+        }
+        .map(|v| v as f32);
+
+        self.measure_inline_widgets(|widget, axis, cross_length| {
+            ctx.compute_length(
+                widget,
+                LenReq::MaxContent.into(),
+                LayoutSize::NONE,
+                axis,
+                cross_length,
+            )
+        });
+        if self.layout_changed || ctx.fonts_changed() {
+            let text_scale = ctx.text_scale() as f32;
+            let (font_ctx, layout_ctx) = ctx.text_contexts();
+            self.build_layout(font_ctx, layout_ctx, text_scale);
+        }
+        self.layout.break_all_lines(max_advance);
+
+        let length = if axis == Axis::Horizontal {
+            self.layout.width()
+        } else {
+            self.layout.height()
+        };
+        length.into()
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        self.measure_inline_widgets(|widget, axis, cross_length| {
+            ctx.compute_length(
+                widget,
+                LenReq::MaxContent.into(),
+                LayoutSize::NONE,
+                axis,
+                cross_length,
+            )
+        });
+        if self.layout_changed || ctx.fonts_changed() {
+            let text_scale = ctx.text_scale() as f32;
+            let (font_ctx, layout_ctx) = ctx.text_contexts();
+            self.build_layout(font_ctx, layout_ctx, text_scale);
+        }
+        // Measurement may have broken the lines for another width, so we always do it again.
+        let inline_space = size.width as f32;
+        self.layout.break_all_lines(Some(inline_space));
+        self.layout.align(
+            Some(inline_space),
+            self.text_alignment,
+            TextAlignOptions::default(),
+        );
+
+        for inline in &mut self.inline_widgets {
+            ctx.run_layout(&mut inline.widget, inline.size);
+        }
+        for line in self.layout.lines() {
+            for item in line.items() {
+                let PositionedLayoutItem::InlineBox(inline_box) = item else {
+                    continue;
+                };
+                let inline = &mut self.inline_widgets[inline_box.id as usize];
+                let origin = Point::new(inline_box.x.into(), inline_box.y.into());
+                ctx.place_child(&mut inline.widget, origin);
+            }
+        }
+
+        let baseline = self
+            .layout
+            .lines()
+            .last()
+            .map_or(0., |line| line.metrics().baseline);
+        ctx.set_baseline_offset(size.height - f64::from(baseline));
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let text_color = if ctx.is_disabled()
+            && let Some(dc) = props.get_defined::<DisabledContentColor>()
+        {
+            &dc.0
+        } else {
+            props.get::<ContentColor>()
+        };
+
+        paint_highlights(scene, &self.layout, &self.text, &self.decorations);
+        render_text(
+            scene,
+            Affine::IDENTITY,
+            &self.layout,
+            &[text_color.color.into()],
+            self.hint,
+        );
+        paint_underlines(scene, &self.layout, &self.text, &self.decorations);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Paragraph
+    }
+
+    fn accessibility(
+        &mut self,
+        ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        let text_origin_in_border_box_space = Point::ORIGIN + ctx.border_box_translation();
+
+        self.accessibility.build_nodes(
+            self.text.as_ref(),
+            &self.layout,
+            ctx.tree_update(),
+            node,
+            AccessCtx::next_node_id,
+            text_origin_in_border_box_space.x,
+            text_origin_in_border_box_space.y,
+        );
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.inline_widgets
+            .iter()
+            .map(|inline| inline.widget.id())
+            .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("RichText", id = id.trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(self.text.to_string())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use masonry_testing::TestHarnessParams;
+    use parley::FontWeight;
+
+    use super::*;
+    use crate::layout::AsUnit;
+    use crate::palette;
+    use crate::properties::Background;
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::{Label, SizedBox, TextDecoration, UnderlineStyle};
+
+    const HARNESS_PARAMS: TestHarnessParams = {
+        let mut params = TestHarnessParams::DEFAULT;
+        params.window_size = Size::new(200.0, 80.0);
+        params
+    };
+
+    #[test]
+    fn decorations_and_inline_widgets() {
+        let text = "Hi @ada, see the wavy and dotted lines";
+        let icon = SizedBox::empty()
+            .width(12.px())
+            .height(12.px())
+            .with_props(Background::Color(palette::css::ORANGE));
+        let icon_id = icon.id();
+        let rich_text = RichText::new(text)
+            .with_span_style(3..7, StyleProperty::FontWeight(FontWeight::BOLD))
+            .with_decoration(DecorationSpan::new(
+                3..7,
+                TextDecoration::Highlight(palette::css::DARK_SLATE_BLUE),
+            ))
+            .with_decoration(DecorationSpan::new(
+                17..21,
+                TextDecoration::Underline {
+                    style: UnderlineStyle::Wavy,
+                    color: palette::css::RED,
+                },
+            ))
+            .with_decoration(DecorationSpan::new(
+                26..32,
+                TextDecoration::Underline {
+                    style: UnderlineStyle::Dotted,
+                    color: palette::css::LIGHT_GREEN,
+                },
+            ))
+            .with_inline_widget(3, icon)
+            .with_auto_id();
+
+        let mut harness = TestHarness::create_with(test_property_set(), rich_text, HARNESS_PARAMS);
+        assert_render_snapshot!(harness, "rich_text_decorations");
+
+        // The icon sits in the first line, after "Hi ".
+        let icon = harness.get_widget_with_id(icon_id);
+        assert_eq!(icon.ctx().border_box_size(), Size::new(12., 12.));
+        let origin = icon.ctx().window_origin();
+        assert!(origin.x > 0.);
+        assert!(origin.y < 20.);
+    }
+
+    #[test]
+    fn edit_inline_widgets() {
+        let rich_text = NewWidget::new(
+            RichText::new("A B").with_inline_widget(1, Label::new("x").with_auto_id()),
+        );
+        let mut harness = TestHarness::create_with(test_property_set(), rich_text, HARNESS_PARAMS);
+        assert_eq!(harness.root_widget().children().len(), 1);
+
+        harness.edit_root_widget(|mut rich_text| {
+            RichText::add_inline_widget(&mut rich_text, 2, Label::new("y").with_auto_id());
+            RichText::remove_inline_widget(&mut rich_text, 0);
+            // An index past the end of the text places the widget at the end.
+            RichText::set_text(&mut rich_text, "A");
+        });
+        assert_eq!(harness.root_widget().inline_widget_count(), 1);
+        let children = harness.root_widget().children();
+        assert_eq!(children[0].get_debug_text().as_deref(), Some("y"));
+    }
+}
@@ -40,12 +40,17 @@ use crate::util::{fill_color, stroke};
 ///
 /// Scrollbars are focusable and support basic keyboard navigation (arrow keys, PageUp/Down,
 /// Home/End depending on axis), and expose scroll state in the accessibility tree.
+///
+/// Clicking the track outside of the cursor moves by a page towards the click, while
+/// the cursor itself can be dragged.
 pub struct ScrollBar {
     axis: Axis,
     pub(crate) cursor_progress: f64,
     pub(crate) moved: bool,
     pub(crate) portal_size: f64,
     pub(crate) content_size: f64,
+    /// The fraction of `portal_size` moved by a page.
+    pub(crate) page_fraction: f64,
     grab_anchor: Option<f64>,
 }
 
@@ -62,6 +67,7 @@ impl ScrollBar {
             moved: false,
             portal_size,
             content_size,
+            page_fraction: 1.,
             grab_anchor: None,
         }
     }
@@ -122,6 +128,10 @@ impl ScrollBar {
         new_cursor_progress.clamp(0.0, 1.0)
     }
 
+    fn page_length(&self) -> f64 {
+        self.portal_size * self.page_fraction
+    }
+
     fn scroll_range(&self) -> f64 {
         (self.content_size - self.portal_size).max(0.0)
     }
@@ -187,15 +197,15 @@ impl Widget for ScrollBar {
                 let cursor_rect = self.cursor_rect(size, cursor_min_length);
                 let mouse_pos = ctx.local_position(state.position);
                 let mut changed = false;
+                let (c0, c1) = cursor_rect.get_coords(self.axis);
+                let mouse_major = mouse_pos.get_coord(self.axis);
                 if cursor_rect.contains(mouse_pos) {
-                    let (c0, c1) = cursor_rect.get_coords(self.axis);
-                    let mouse_major = mouse_pos.get_coord(self.axis);
                     self.grab_anchor = Some((mouse_major - c0) / (c1 - c0));
                 } else {
-                    let progress =
-                        self.progress_from_mouse_pos(size, cursor_min_length, 0.5, mouse_pos);
-                    changed |= self.set_cursor_progress(progress);
-                    self.grab_anchor = Some(0.5);
+                    // Clicking the track moves by a page towards the click.
+                    // TODO - Repeat while the button is held, until the cursor reaches the pointer.
+                    let page = self.page_length();
+                    changed |= self.adjust_by_pixels(if mouse_major < c0 { -page } else { page });
                 };
                 if changed {
                     ctx.request_render();
@@ -242,7 +252,7 @@ impl Widget for ScrollBar {
         //       https://github.com/linebender/xilem/issues/1264
         let scale = 1.0;
         let line = 120.0 * scale;
-        let page = self.portal_size * scale;
+        let page = self.page_length() * scale;
 
         let mut changed = false;
         match (&event.key, self.axis) {
@@ -321,7 +331,7 @@ impl Widget for ScrollBar {
             accesskit::ScrollUnit::Item
         };
         let line = 120.0 * scale;
-        let page = self.portal_size * scale;
+        let page = self.page_length() * scale;
         let amount = match unit {
             accesskit::ScrollUnit::Item => line,
             accesskit::ScrollUnit::Page => page,
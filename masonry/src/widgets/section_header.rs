@@ -0,0 +1,243 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ArcStr, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PropertiesRef, RegisterCtx, StyleProperty, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::{LayoutSize, LenReq, SizeDef};
+use crate::properties::ContentColor;
+use crate::theme;
+use crate::widgets::Label;
+
+/// The font size of section headers, smaller than body text.
+const TEXT_SIZE: f32 = 12.;
+/// The space between the letters of section headers, which makes uppercase text easier to read.
+const LETTER_SPACING: f32 = 0.8;
+
+/// The space above and below a [`SectionHeader`].
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SectionSpacing {
+    /// Little space, for dense lists such as menus.
+    Compact,
+    /// Some space above the header, to set the section apart from the previous one.
+    #[default]
+    Regular,
+    /// A lot of space above the header, for settings pages and other sparse layouts.
+    Relaxed,
+}
+
+impl SectionSpacing {
+    /// Returns the space `(above, below)` the header text.
+    pub fn space(self) -> (f64, f64) {
+        match self {
+            Self::Compact => (4., 2.),
+            Self::Regular => (16., 4.),
+            Self::Relaxed => (28., 8.),
+        }
+    }
+}
+
+/// The title of a section of a list or a form, shown as small uppercase text.
+///
+/// The text is uppercased by the widget, so it should be given in its usual case, which is
+/// also what screen readers announce.
+/// The [spacing](SectionSpacing) sets how much room there is above and below the text.
+///
+/// The color of the text can be changed through [`label_mut`](Self::label_mut).
+pub struct SectionHeader {
+    text: ArcStr,
+    label: WidgetPod<Label>,
+    spacing: SectionSpacing,
+}
+
+// --- MARK: BUILDERS
+impl SectionHeader {
+    /// Creates a section header with the given title.
+    pub fn new(text: impl Into<ArcStr>) -> Self {
+        let text = text.into();
+        let label = Label::new(text.to_uppercase())
+            .with_style(StyleProperty::FontSize(TEXT_SIZE))
+            .with_style(StyleProperty::LetterSpacing(LETTER_SPACING));
+        Self {
+            text,
+            label: NewWidget::new_with_props(label, ContentColor::new(theme::ZYNC_500)).to_pod(),
+            spacing: SectionSpacing::default(),
+        }
+    }
+
+    /// Returns `self` with the given `spacing`.
+    pub fn with_spacing(mut self, spacing: SectionSpacing) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Returns the title, in its original case.
+    pub fn text(&self) -> &ArcStr {
+        &self.text
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl SectionHeader {
+    /// Sets the title.
+    pub fn set_text(this: &mut WidgetMut<'_, Self>, text: impl Into<ArcStr>) {
+        let text = text.into();
+        Label::set_text(&mut Self::label_mut(this), text.to_uppercase());
+        this.widget.text = text;
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets the space above and below the title.
+    pub fn set_spacing(this: &mut WidgetMut<'_, Self>, spacing: SectionSpacing) {
+        this.widget.spacing = spacing;
+        this.ctx.request_layout();
+    }
+
+    /// Returns a mutable reference to the label showing the title.
+    pub fn label_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, Label> {
+        this.ctx.get_mut(&mut this.widget.label)
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for SectionHeader {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.label);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let (above, below) = self.spacing.space();
+        let cross_length = match axis {
+            Axis::Horizontal => cross_length.map(|height| (height - above - below).max(0.)),
+            Axis::Vertical => cross_length,
+        };
+        let auto_length = match axis {
+            Axis::Horizontal => len_req,
+            Axis::Vertical => len_req.reduce(above + below),
+        };
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        let length = ctx.compute_length(
+            &mut self.label,
+            auto_length.into(),
+            context_size,
+            axis,
+            cross_length,
+        );
+        match axis {
+            Axis::Horizontal => length,
+            Axis::Vertical => length + above + below,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let (above, below) = self.spacing.space();
+        let space = Size::new(size.width, (size.height - above - below).max(0.));
+        let label_size = ctx.compute_size(&mut self.label, SizeDef::fit(space), space.into());
+        ctx.run_layout(&mut self.label, label_size);
+        // The label sits on the bottom spacing, so that the header stays close to its section.
+        let origin = Point::new(0., (size.height - below - label_size.height).max(0.));
+        ctx.place_child(&mut self.label, origin);
+
+        let label_baseline = ctx.child_baseline_offset(&self.label);
+        let bottom_gap = size.height - origin.y - label_size.height;
+        ctx.set_baseline_offset(label_baseline + bottom_gap);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::Heading
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_label(self.text.as_ref());
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.label.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("SectionHeader", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::WidgetTag;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::Flex;
+
+    #[test]
+    fn text_is_uppercased() {
+        let header_tag = WidgetTag::named("header");
+        let header = NewWidget::new_with_tag(SectionHeader::new("Recent files"), header_tag);
+        let label_id = header.widget.label.id();
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            Flex::column().with_fixed(header).with_auto_id(),
+            Size::new(200., 100.),
+        );
+
+        let label = harness.get_widget_with_id(label_id);
+        assert_eq!(
+            label.downcast::<Label>().unwrap().text().as_ref(),
+            "RECENT FILES"
+        );
+        assert_eq!(
+            harness.get_widget(header_tag).text().as_ref(),
+            "Recent files"
+        );
+
+        harness.edit_widget(header_tag, |mut header| {
+            SectionHeader::set_text(&mut header, "Older");
+        });
+        let label = harness.get_widget_with_id(label_id);
+        assert_eq!(label.downcast::<Label>().unwrap().text().as_ref(), "OLDER");
+    }
+
+    #[test]
+    fn spacing_adds_height() {
+        let heights = [SectionSpacing::Compact, SectionSpacing::Relaxed].map(|spacing| {
+            let header = SectionHeader::new("Title").with_spacing(spacing);
+            let harness = TestHarness::create_with_size(
+                test_property_set(),
+                Flex::column()
+                    .with_fixed(NewWidget::new(header))
+                    .with_auto_id(),
+                Size::new(200., 100.),
+            );
+            harness.root_widget().children()[0]
+                .ctx()
+                .border_box_size()
+                .height
+        });
+        let (compact_above, compact_below) = SectionSpacing::Compact.space();
+        let (relaxed_above, relaxed_below) = SectionSpacing::Relaxed.space();
+        let extra = relaxed_above + relaxed_below - compact_above - compact_below;
+        assert_eq!(heights[1] - heights[0], extra);
+    }
+}
@@ -0,0 +1,679 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{
+    AccessCtx, AccessEvent, ArcStr, ChildrenIds, EventCtx, HasProperty, LayoutCtx, MeasureCtx,
+    NewWidget, PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PropertiesMut,
+    PropertiesRef, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+    WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Rect, Size};
+use crate::layout::{LayoutSize, LenReq, SizeDef};
+use crate::properties::{CornerRadius, FocusedBorderColor, ToggledBackground};
+use crate::util::{Easing, fill, stroke};
+use crate::widgets::Label;
+
+/// How long the selection indicator takes to slide to a newly selected segment.
+const INDICATOR_DURATION_NANOS: u64 = 150_000_000;
+
+/// A row of segments, of which one (or optionally several) can be selected.
+///
+/// Each segment shows a text label.
+/// The selected segments are highlighted with the [`ToggledBackground`] property.
+/// With a single selection, the highlight slides between segments when the selection changes.
+///
+/// When the control is focused, the arrow keys, <kbd>Home</kbd> and <kbd>End</kbd> move
+/// between segments.
+/// With a single selection, moving to a segment selects it.
+/// With [multiple selection](Self::with_multiple), the focused segment is outlined with the
+/// [`FocusedBorderColor`], and <kbd>Space</kbd> toggles it.
+///
+/// Emits [`SegmentsSelected`] when the user changes the selection.
+/// Note that the selection does not automatically change, and so one of
+/// the responses to a `SegmentsSelected` is to call [`SegmentedControl::set_selection`]
+/// on the originating widget.
+pub struct SegmentedControl {
+    segments: Vec<WidgetPod<Label>>,
+    /// The texts of the segments, for accessibility.
+    texts: Vec<ArcStr>,
+    /// The indices of the selected segments, sorted and without duplicates.
+    selection: Vec<usize>,
+    multiple: bool,
+    /// The segment that keyboard navigation starts from.
+    focused_segment: usize,
+    /// The position of the selection indicator, in segments from the start.
+    indicator_pos: f64,
+    indicator_anim: Option<IndicatorAnimation>,
+}
+
+/// A slide of the selection indicator of a [`SegmentedControl`].
+struct IndicatorAnimation {
+    from: f64,
+    to: f64,
+    elapsed_nanos: u64,
+}
+
+/// The action type emitted by [`SegmentedControl`] when the user changes the selection.
+///
+/// The field is the requested selection: the sorted indices of the segments which would
+/// like to become selected.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SegmentsSelected(pub Vec<usize>);
+
+// --- MARK: BUILDERS
+impl SegmentedControl {
+    /// Creates a control with a segment for each of the given labels.
+    ///
+    /// The first segment is selected.
+    pub fn new(segments: impl IntoIterator<Item = impl Into<ArcStr>>) -> Self {
+        let texts: Vec<ArcStr> = segments.into_iter().map(Into::into).collect();
+        let segments = texts.iter().cloned().map(Self::new_segment).collect();
+        let selection = if texts.is_empty() { vec![] } else { vec![0] };
+        Self {
+            segments,
+            texts,
+            selection,
+            multiple: false,
+            focused_segment: 0,
+            indicator_pos: 0.,
+            indicator_anim: None,
+        }
+    }
+
+    /// Builder-style method to select the segments at the given indices.
+    ///
+    /// Unless the control allows [multiple selection](Self::with_multiple),
+    /// only the first valid index is kept.
+    pub fn with_selection(mut self, selection: impl IntoIterator<Item = usize>) -> Self {
+        self.selection = self.sanitize_selection(selection);
+        self.focused_segment = self.selection.first().copied().unwrap_or(0);
+        self.indicator_pos = self.focused_segment as f64;
+        self
+    }
+
+    /// Builder-style method to set whether several segments can be selected at once.
+    ///
+    /// Set this before [`with_selection`](Self::with_selection).
+    pub fn with_multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        let selection = std::mem::take(&mut self.selection);
+        self.selection = self.sanitize_selection(selection);
+        self
+    }
+
+    /// Returns the number of segments.
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Returns `true` if the control has no segments.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Returns the sorted indices of the selected segments.
+    pub fn selection(&self) -> &[usize] {
+        &self.selection
+    }
+
+    /// Returns whether several segments can be selected at once.
+    pub fn is_multiple(&self) -> bool {
+        self.multiple
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl SegmentedControl {
+    /// Replaces the labels of the segments.
+    ///
+    /// The selected indices which are out of range are deselected.
+    pub fn set_segments(
+        this: &mut WidgetMut<'_, Self>,
+        segments: impl IntoIterator<Item = impl Into<ArcStr>>,
+    ) {
+        let texts: Vec<ArcStr> = segments.into_iter().map(Into::into).collect();
+        for (idx, text) in texts.iter().enumerate() {
+            if let Some(segment) = this.widget.segments.get_mut(idx) {
+                let mut label = this.ctx.get_mut(segment);
+                Label::set_text(&mut label, text.clone());
+            } else {
+                this.widget.segments.push(Self::new_segment(text.clone()));
+            }
+        }
+        for segment in this.widget.segments.drain(texts.len()..) {
+            this.ctx.remove_child(segment);
+        }
+        this.widget.texts = texts;
+        this.ctx.children_changed();
+
+        let len = this.widget.segments.len();
+        this.widget.selection.retain(|&segment| segment < len);
+        this.widget.focused_segment = this.widget.focused_segment.min(len.saturating_sub(1));
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Selects the segments at the given indices.
+    ///
+    /// Unless the control allows [multiple selection](Self::with_multiple),
+    /// only the first valid index is kept, and the selection indicator slides to it.
+    pub fn set_selection(
+        this: &mut WidgetMut<'_, Self>,
+        selection: impl IntoIterator<Item = usize>,
+    ) {
+        let selection = this.widget.sanitize_selection(selection);
+        if this.widget.selection == selection {
+            return;
+        }
+        this.widget.selection = selection;
+        if !this.widget.multiple
+            && let Some(&selected) = this.widget.selection.first()
+        {
+            this.widget.focused_segment = selected;
+            this.widget.indicator_anim = Some(IndicatorAnimation {
+                from: this.widget.indicator_pos,
+                to: selected as f64,
+                elapsed_nanos: 0,
+            });
+            this.ctx.request_anim_frame();
+        }
+        this.ctx.request_paint_only();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets whether several segments can be selected at once.
+    ///
+    /// The runtime equivalent of [`with_multiple`](Self::with_multiple).
+    pub fn set_multiple(this: &mut WidgetMut<'_, Self>, multiple: bool) {
+        if this.widget.multiple == multiple {
+            return;
+        }
+        this.widget.multiple = multiple;
+        let selection = std::mem::take(&mut this.widget.selection);
+        this.widget.selection = this.widget.sanitize_selection(selection);
+        if let Some(&selected) = this.widget.selection.first() {
+            this.widget.indicator_pos = selected as f64;
+        }
+        this.widget.indicator_anim = None;
+        this.ctx.request_paint_only();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Returns a mutable reference to the label of the segment at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of range.
+    pub fn segment_mut<'t>(this: &'t mut WidgetMut<'_, Self>, idx: usize) -> WidgetMut<'t, Label> {
+        this.ctx.get_mut(&mut this.widget.segments[idx])
+    }
+}
+
+// --- MARK: HELPERS
+impl SegmentedControl {
+    fn new_segment(text: ArcStr) -> WidgetPod<Label> {
+        NewWidget::new(Label::new(text)).to_pod()
+    }
+
+    /// Sorts `selection`, removes the out of range indices and duplicates,
+    /// and keeps only the first index without multiple selection.
+    fn sanitize_selection(&self, selection: impl IntoIterator<Item = usize>) -> Vec<usize> {
+        let len = self.segments.len();
+        let mut selection: Vec<_> = selection.into_iter().filter(|&idx| idx < len).collect();
+        if !self.multiple {
+            selection.truncate(1);
+        }
+        selection.sort_unstable();
+        selection.dedup();
+        selection
+    }
+
+    /// Returns the selection which results from the user activating the segment at `idx`.
+    fn toggled_selection(&self, idx: usize) -> Vec<usize> {
+        if !self.multiple {
+            return vec![idx];
+        }
+        let mut selection = self.selection.clone();
+        match selection.binary_search(&idx) {
+            Ok(pos) => {
+                selection.remove(pos);
+            }
+            Err(pos) => selection.insert(pos, idx),
+        }
+        selection
+    }
+
+    /// Returns the index of the segment at the horizontal position `x`, in content-box coordinates.
+    fn segment_at(&self, x: f64, width: f64) -> Option<usize> {
+        if self.segments.is_empty() || x < 0. || x >= width {
+            return None;
+        }
+        let segment_width = width / self.segments.len() as f64;
+        Some(((x / segment_width) as usize).min(self.segments.len() - 1))
+    }
+
+    /// Focuses the segment at `idx`, selecting it without multiple selection.
+    fn move_focus(&mut self, ctx: &mut EventCtx<'_>, idx: usize) {
+        if idx == self.focused_segment {
+            return;
+        }
+        self.focused_segment = idx;
+        if !self.multiple && self.selection != [idx] {
+            ctx.submit_action::<SegmentsSelected>(SegmentsSelected(vec![idx]));
+        }
+        ctx.request_paint_only();
+    }
+}
+
+impl HasProperty<ToggledBackground> for SegmentedControl {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for SegmentedControl {
+    type Action = SegmentsSelected;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                ..
+            }) => {
+                ctx.request_focus();
+                ctx.capture_pointer();
+            }
+            PointerEvent::Up(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                state,
+                ..
+            }) if ctx.is_active() && ctx.is_hovered() => {
+                let local_pos = ctx.local_position(state.position);
+                let width = ctx.content_box_size().width;
+                if let Some(idx) = self.segment_at(local_pos.x, width) {
+                    self.focused_segment = idx;
+                    if self.multiple || self.selection != [idx] {
+                        let selection = self.toggled_selection(idx);
+                        ctx.submit_action::<SegmentsSelected>(SegmentsSelected(selection));
+                    }
+                    ctx.request_paint_only();
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if ctx.is_disabled() || !ctx.is_focus_target() || self.segments.is_empty() {
+            return;
+        }
+        let TextEvent::Keyboard(key_event) = event else {
+            return;
+        };
+        let last = self.segments.len() - 1;
+        let current = self.focused_segment.min(last);
+        if key_event.state.is_up() {
+            // Space toggles the focused segment, like a checkbox.
+            if matches!(&key_event.key, Key::Character(c) if c == " ") {
+                if self.multiple || self.selection != [current] {
+                    let selection = self.toggled_selection(current);
+                    ctx.submit_action::<SegmentsSelected>(SegmentsSelected(selection));
+                }
+                ctx.set_handled();
+            }
+            return;
+        }
+        let target = match &key_event.key {
+            Key::Named(NamedKey::ArrowLeft | NamedKey::ArrowUp) => current.saturating_sub(1),
+            Key::Named(NamedKey::ArrowRight | NamedKey::ArrowDown) => (current + 1).min(last),
+            Key::Named(NamedKey::Home) => 0,
+            Key::Named(NamedKey::End) => last,
+            _ => return,
+        };
+        self.move_focus(ctx, target);
+        ctx.set_handled();
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if ctx.is_disabled() || self.segments.is_empty() {
+            return;
+        }
+        let last = self.segments.len() - 1;
+        match event.action {
+            accesskit::Action::Increment => {
+                self.move_focus(ctx, (self.focused_segment + 1).min(last));
+            }
+            accesskit::Action::Decrement => {
+                self.move_focus(ctx, self.focused_segment.saturating_sub(1));
+            }
+            _ => {}
+        }
+    }
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let Some(anim) = &mut self.indicator_anim else {
+            return;
+        };
+        anim.elapsed_nanos = anim.elapsed_nanos.saturating_add(interval);
+        if ctx.prefers_reduced_motion() || anim.elapsed_nanos >= INDICATOR_DURATION_NANOS {
+            self.indicator_pos = anim.to;
+            self.indicator_anim = None;
+        } else {
+            let progress = anim.elapsed_nanos as f64 / INDICATOR_DURATION_NANOS as f64;
+            let eased = Easing::EaseOut.apply(progress);
+            self.indicator_pos = anim.from + (anim.to - anim.from) * eased;
+            ctx.request_anim_frame();
+        }
+        ctx.request_paint_only();
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::FocusChanged(_) | Update::DisabledChanged(_) => {
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for segment in &mut self.segments {
+            ctx.register_child(segment);
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        ToggledBackground::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let count = self.segments.len() as f64;
+        // The segments all have the width of the widest one.
+        let cross_length = match axis {
+            Axis::Horizontal => cross_length,
+            Axis::Vertical => cross_length.map(|width| width / count),
+        };
+        let auto_length = len_req.into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        let longest = self
+            .segments
+            .iter_mut()
+            .map(|segment| {
+                ctx.compute_length(segment, auto_length, context_size, axis, cross_length)
+            })
+            .fold(0., f64::max);
+
+        match axis {
+            Axis::Horizontal => longest * count,
+            Axis::Vertical => longest,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let segment_width = size.width / self.segments.len().max(1) as f64;
+        let slot = Size::new(segment_width, size.height);
+        let mut first_bottom = None;
+        for (idx, segment) in self.segments.iter_mut().enumerate() {
+            let segment_size = ctx.compute_size(segment, SizeDef::fit(slot), slot.into());
+            ctx.run_layout(segment, segment_size);
+            let origin = Point::new(
+                idx as f64 * segment_width + (segment_width - segment_size.width) * 0.5,
+                (size.height - segment_size.height) * 0.5,
+            );
+            ctx.place_child(segment, origin);
+            first_bottom.get_or_insert(origin.y + segment_size.height);
+        }
+
+        if let (Some(first), Some(first_bottom)) = (self.segments.first(), first_bottom) {
+            let baseline = ctx.child_baseline_offset(first);
+            ctx.set_baseline_offset(baseline + size.height - first_bottom);
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        if self.segments.is_empty() {
+            return;
+        }
+        let size = ctx.content_box_size();
+        let segment_width = size.width / self.segments.len() as f64;
+        let corner_radius = props
+            .get::<CornerRadius>()
+            .radius
+            .min(size.height / 2.)
+            .min(segment_width / 2.);
+        let segment_rect = |pos: f64| {
+            Rect::new(
+                pos * segment_width,
+                0.,
+                (pos + 1.) * segment_width,
+                size.height,
+            )
+            .to_rounded_rect(corner_radius)
+        };
+
+        let background = props.get::<ToggledBackground>();
+        if self.multiple {
+            for &idx in &self.selection {
+                let rect = segment_rect(idx as f64);
+                fill(
+                    scene,
+                    &rect,
+                    &background.0.get_peniko_brush_for_rect(rect.rect()),
+                );
+            }
+        } else if !self.selection.is_empty() {
+            let rect = segment_rect(self.indicator_pos);
+            fill(
+                scene,
+                &rect,
+                &background.0.get_peniko_brush_for_rect(rect.rect()),
+            );
+        }
+
+        // With a single selection, the focused segment is the selected one.
+        if self.multiple
+            && ctx.is_focus_target()
+            && let Some(focus) = props.get_defined::<FocusedBorderColor>()
+        {
+            let rect = segment_rect(self.focused_segment as f64);
+            stroke(scene, &rect, focus.0.color, 1.);
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        if self.multiple {
+            Role::Group
+        } else {
+            Role::RadioGroup
+        }
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.add_action(accesskit::Action::Increment);
+        node.add_action(accesskit::Action::Decrement);
+        let selected: Vec<_> = self
+            .selection
+            .iter()
+            .map(|&idx| self.texts[idx].to_string())
+            .collect();
+        node.set_value(selected.join(", "));
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.segments.iter().map(|segment| segment.id()).collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("SegmentedControl", id = id.trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(format!("{:?}", self.selection))
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::TextEvent;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+
+    fn click_segment(harness: &mut TestHarness<SegmentedControl>, idx: usize) {
+        let rect = harness.root_widget().ctx().bounding_box();
+        let segment_width = rect.width() / harness.root_widget().inner().len() as f64;
+        let pos = Point::new(
+            rect.x0 + (idx as f64 + 0.5) * segment_width,
+            rect.center().y,
+        );
+        harness.mouse_move(pos);
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+    }
+
+    #[test]
+    fn click_selects_segment() {
+        let widget = SegmentedControl::new(["Day", "Week", "Month"]).with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(300., 40.));
+        let id = harness.root_id();
+
+        click_segment(&mut harness, 2);
+        assert_eq!(
+            harness.pop_action::<SegmentsSelected>(),
+            Some((SegmentsSelected(vec![2]), id))
+        );
+
+        // Clicking the selected segment doesn't emit anything.
+        click_segment(&mut harness, 0);
+        assert!(harness.pop_action::<SegmentsSelected>().is_none());
+    }
+
+    #[test]
+    fn multiple_selection_toggles() {
+        let widget = SegmentedControl::new(["Bold", "Italic", "Underline"])
+            .with_multiple(true)
+            .with_selection([2, 0])
+            .with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(300., 40.));
+        let id = harness.root_id();
+        assert_eq!(harness.root_widget().inner().selection(), [0, 2]);
+
+        click_segment(&mut harness, 0);
+        assert_eq!(
+            harness.pop_action::<SegmentsSelected>(),
+            Some((SegmentsSelected(vec![2]), id))
+        );
+        click_segment(&mut harness, 1);
+        assert_eq!(
+            harness.pop_action::<SegmentsSelected>(),
+            Some((SegmentsSelected(vec![0, 1, 2]), id))
+        );
+    }
+
+    #[test]
+    fn arrow_keys_move_selection() {
+        let widget = SegmentedControl::new(["Day", "Week", "Month"]).with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(300., 40.));
+        let id = harness.root_id();
+        harness.focus_on(Some(id));
+
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowRight)));
+        assert_eq!(
+            harness.pop_action::<SegmentsSelected>(),
+            Some((SegmentsSelected(vec![1]), id))
+        );
+        harness.edit_root_widget(|mut control| SegmentedControl::set_selection(&mut control, [1]));
+
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::End)));
+        assert_eq!(
+            harness.pop_action::<SegmentsSelected>(),
+            Some((SegmentsSelected(vec![2]), id))
+        );
+
+        // Moving past the last segment does nothing.
+        harness.edit_root_widget(|mut control| SegmentedControl::set_selection(&mut control, [2]));
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowRight)));
+        assert!(harness.pop_action::<SegmentsSelected>().is_none());
+    }
+
+    #[test]
+    fn indicator_slides_to_selection() {
+        let widget = SegmentedControl::new(["Day", "Week", "Month"]).with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(300., 40.));
+
+        harness.edit_root_widget(|mut control| SegmentedControl::set_selection(&mut control, [2]));
+        harness.animate_ms(50);
+        let pos = harness.root_widget().inner().indicator_pos;
+        assert!(
+            pos > 0. && pos < 2.,
+            "indicator should be sliding, at {pos}"
+        );
+
+        harness.animate_ms(200);
+        assert_eq!(harness.root_widget().inner().indicator_pos, 2.);
+    }
+
+    #[test]
+    fn set_segments_drops_selection() {
+        let widget = SegmentedControl::new(["A", "B", "C"])
+            .with_selection([2])
+            .with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(300., 40.));
+
+        harness.edit_root_widget(|mut control| {
+            SegmentedControl::set_segments(&mut control, ["A", "B"]);
+        });
+        let control = harness.root_widget();
+        assert_eq!(control.inner().len(), 2);
+        assert!(control.inner().selection().is_empty());
+        assert_eq!(control.children().len(), 2);
+    }
+}
@@ -0,0 +1,394 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::core::Modifiers;
+
+/// How many items of a [`SelectionModel`] can be selected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SelectionMode {
+    /// Items can't be selected.
+    None,
+    /// At most one item is selected.
+    #[default]
+    Single,
+    /// Any number of items can be selected, with <kbd>Ctrl</kbd> and <kbd>Shift</kbd>
+    /// clicks and with a rubber band.
+    Multiple,
+}
+
+/// The action type emitted by widgets using a [`SelectionModel`] when the user changes
+/// the selection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectionChanged<K> {
+    /// The keys of the selected items, in the order of the items.
+    pub selected: Vec<K>,
+}
+
+/// The selected items of a list, table or tree, identified by keys which stay the same
+/// when items are added, removed or reordered.
+///
+/// Widgets call [`click`](Self::click) when an item is pressed, and the
+/// [`begin_band`](Self::begin_band) methods for rubber-band selection.
+/// Methods which depend on the order of the items, such as <kbd>Shift</kbd> ranges,
+/// are given the keys of all items in order.
+///
+/// Each method returns whether the selection changed, so that widgets know when to
+/// emit [`SelectionChanged`].
+#[derive(Clone, Debug)]
+pub struct SelectionModel<K> {
+    mode: SelectionMode,
+    selected: HashSet<K>,
+    /// The item <kbd>Shift</kbd> ranges start from.
+    anchor: Option<K>,
+    /// The selection when the current rubber band started, which the band adds to.
+    band_base: Option<HashSet<K>>,
+}
+
+impl<K: Clone + Eq + Hash> SelectionModel<K> {
+    /// Creates an empty selection.
+    pub fn new(mode: SelectionMode) -> Self {
+        Self {
+            mode,
+            selected: HashSet::new(),
+            anchor: None,
+            band_base: None,
+        }
+    }
+
+    /// Returns how many items can be selected.
+    pub fn mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    /// Returns `true` if the item with the given key is selected.
+    pub fn is_selected(&self, key: &K) -> bool {
+        self.selected.contains(key)
+    }
+
+    /// Returns the number of selected items.
+    pub fn len(&self) -> usize {
+        self.selected.len()
+    }
+
+    /// Returns `true` if no item is selected.
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+
+    /// Returns the keys of the selected items, in no particular order.
+    pub fn selected(&self) -> impl Iterator<Item = &K> + '_ {
+        self.selected.iter()
+    }
+
+    /// Returns the keys of the selected items in `order`, in that order.
+    pub fn selected_in(&self, order: &[K]) -> Vec<K> {
+        order
+            .iter()
+            .filter(|key| self.selected.contains(*key))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the key of the item <kbd>Shift</kbd> ranges start from, which is usually
+    /// the last item clicked.
+    pub fn anchor(&self) -> Option<&K> {
+        self.anchor.as_ref()
+    }
+
+    /// Sets how many items can be selected, and deselects the items which no longer fit.
+    pub fn set_mode(&mut self, mode: SelectionMode) -> bool {
+        self.mode = mode;
+        self.band_base = None;
+        match mode {
+            SelectionMode::None => self.clear(),
+            SelectionMode::Single if self.selected.len() > 1 => {
+                let keep = self
+                    .anchor
+                    .clone()
+                    .filter(|anchor| self.selected.contains(anchor))
+                    .or_else(|| self.selected.iter().next().cloned());
+                self.replace(keep)
+            }
+            _ => false,
+        }
+    }
+
+    /// Selects only the item with the given key.
+    pub fn select(&mut self, key: K) -> bool {
+        if self.mode == SelectionMode::None {
+            return false;
+        }
+        self.anchor = Some(key.clone());
+        self.replace(Some(key))
+    }
+
+    /// Replaces the selection with the given keys, without moving the anchor unless it's
+    /// no longer selected.
+    ///
+    /// In [`Single`](SelectionMode::Single) mode, only the first key is selected.
+    pub fn set_selected(&mut self, keys: impl IntoIterator<Item = K>) -> bool {
+        let limit = match self.mode {
+            SelectionMode::None => 0,
+            SelectionMode::Single => 1,
+            SelectionMode::Multiple => usize::MAX,
+        };
+        let selected: HashSet<K> = keys.into_iter().take(limit).collect();
+        if self
+            .anchor
+            .as_ref()
+            .is_some_and(|anchor| !selected.contains(anchor))
+        {
+            self.anchor = selected.iter().next().cloned();
+        }
+        self.band_base = None;
+        let changed = selected != self.selected;
+        self.selected = selected;
+        changed
+    }
+
+    /// Adds the item with the given key to the selection, or removes it if it's selected.
+    ///
+    /// In [`Single`](SelectionMode::Single) mode, this selects only that item, or clears
+    /// the selection.
+    pub fn toggle(&mut self, key: K) -> bool {
+        match self.mode {
+            SelectionMode::None => false,
+            SelectionMode::Single if self.selected.contains(&key) => self.clear(),
+            SelectionMode::Single => self.select(key),
+            SelectionMode::Multiple => {
+                if !self.selected.remove(&key) {
+                    self.selected.insert(key.clone());
+                }
+                self.anchor = Some(key);
+                true
+            }
+        }
+    }
+
+    /// Selects the items between the anchor and the item with the given key, both included.
+    ///
+    /// If `extend` is `true`, the range is added to the selection instead of replacing it.
+    /// Without an anchor, or outside of [`Multiple`](SelectionMode::Multiple) mode,
+    /// this selects only that item.
+    /// The anchor doesn't move, so that further ranges start from the same item.
+    pub fn select_range(&mut self, order: &[K], to: &K, extend: bool) -> bool {
+        let from = self
+            .anchor
+            .as_ref()
+            .and_then(|anchor| order.iter().position(|key| key == anchor));
+        let to_idx = order.iter().position(|key| key == to);
+        let (Some(from), Some(to_idx), SelectionMode::Multiple) = (from, to_idx, self.mode) else {
+            return self.select(to.clone());
+        };
+        let range = &order[from.min(to_idx)..=from.max(to_idx)];
+        if extend {
+            let len = self.selected.len();
+            self.selected.extend(range.iter().cloned());
+            self.selected.len() != len
+        } else {
+            let selected: HashSet<K> = range.iter().cloned().collect();
+            let changed = selected != self.selected;
+            self.selected = selected;
+            changed
+        }
+    }
+
+    /// Selects all items, in [`Multiple`](SelectionMode::Multiple) mode.
+    pub fn select_all(&mut self, order: &[K]) -> bool {
+        if self.mode != SelectionMode::Multiple {
+            return false;
+        }
+        self.set_selected(order.iter().cloned())
+    }
+
+    /// Deselects all items.
+    pub fn clear(&mut self) -> bool {
+        self.anchor = None;
+        self.replace(None)
+    }
+
+    /// Applies a click on the item with the given key, following the usual conventions:
+    ///
+    /// - A plain click selects only that item.
+    /// - <kbd>Ctrl</kbd> (or <kbd>Cmd</kbd> on macOS) toggles the item.
+    /// - <kbd>Shift</kbd> selects the range from the anchor to the item, which is added
+    ///   to the selection if <kbd>Ctrl</kbd> is also held.
+    pub fn click(&mut self, order: &[K], key: &K, modifiers: Modifiers) -> bool {
+        let toggle = if cfg!(target_os = "macos") {
+            modifiers.meta()
+        } else {
+            modifiers.ctrl()
+        };
+        if modifiers.shift() {
+            self.select_range(order, key, toggle)
+        } else if toggle {
+            self.toggle(key.clone())
+        } else {
+            self.select(key.clone())
+        }
+    }
+
+    /// Starts a rubber-band selection, in [`Multiple`](SelectionMode::Multiple) mode.
+    ///
+    /// If `extend` is `true`, the band adds to the current selection instead of
+    /// replacing it.
+    pub fn begin_band(&mut self, extend: bool) -> bool {
+        if self.mode != SelectionMode::Multiple {
+            return false;
+        }
+        let changed = !extend && self.clear();
+        self.band_base = Some(self.selected.clone());
+        changed
+    }
+
+    /// Selects the items with the given keys, which are the ones the rubber band covers,
+    /// in addition to the selection when the band started.
+    ///
+    /// Does nothing if no band was started.
+    pub fn update_band(&mut self, keys: impl IntoIterator<Item = K>) -> bool {
+        let Some(base) = &self.band_base else {
+            return false;
+        };
+        let mut selected = base.clone();
+        selected.extend(keys);
+        let changed = selected != self.selected;
+        self.selected = selected;
+        changed
+    }
+
+    /// Ends the rubber-band selection.
+    pub fn end_band(&mut self) {
+        self.band_base = None;
+    }
+
+    /// Returns `true` if a rubber-band selection is in progress.
+    pub fn is_banding(&self) -> bool {
+        self.band_base.is_some()
+    }
+
+    /// Deselects the items for which `f` returns `false`, such as items which were removed.
+    pub fn retain(&mut self, mut f: impl FnMut(&K) -> bool) -> bool {
+        if self.anchor.as_ref().is_some_and(|anchor| !f(anchor)) {
+            self.anchor = None;
+        }
+        if let Some(base) = &mut self.band_base {
+            base.retain(&mut f);
+        }
+        let len = self.selected.len();
+        self.selected.retain(f);
+        self.selected.len() != len
+    }
+
+    fn replace(&mut self, key: Option<K>) -> bool {
+        let changed = match &key {
+            Some(key) => self.selected.len() != 1 || !self.selected.contains(key),
+            None => !self.selected.is_empty(),
+        };
+        self.selected.clear();
+        self.selected.extend(key);
+        changed
+    }
+}
+
+impl<K: Clone + Eq + Hash> Default for SelectionModel<K> {
+    fn default() -> Self {
+        Self::new(SelectionMode::default())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITEMS: [&str; 5] = ["a", "b", "c", "d", "e"];
+
+    fn ctrl() -> Modifiers {
+        if cfg!(target_os = "macos") {
+            Modifiers::META
+        } else {
+            Modifiers::CONTROL
+        }
+    }
+
+    #[test]
+    fn single_selection() {
+        let mut model = SelectionModel::new(SelectionMode::Single);
+        assert!(model.click(&ITEMS, &"b", Modifiers::empty()));
+        assert!(!model.click(&ITEMS, &"b", Modifiers::empty()));
+        assert!(model.click(&ITEMS, &"d", Modifiers::SHIFT));
+        assert_eq!(model.selected_in(&ITEMS), ["d"]);
+
+        // Ctrl deselects the only selected item.
+        assert!(model.click(&ITEMS, &"d", ctrl()));
+        assert!(model.is_empty());
+
+        assert!(!model.begin_band(false));
+        assert!(!model.update_band(["a", "b"]));
+        assert!(model.is_empty());
+    }
+
+    #[test]
+    fn ctrl_and_shift_clicks() {
+        let mut model = SelectionModel::new(SelectionMode::Multiple);
+        model.click(&ITEMS, &"b", Modifiers::empty());
+        model.click(&ITEMS, &"d", Modifiers::SHIFT);
+        assert_eq!(model.selected_in(&ITEMS), ["b", "c", "d"]);
+
+        // Ranges start from the anchor, which Shift clicks don't move.
+        model.click(&ITEMS, &"a", Modifiers::SHIFT);
+        assert_eq!(model.selected_in(&ITEMS), ["a", "b"]);
+
+        // Ctrl toggles single items, and moves the anchor.
+        model.click(&ITEMS, &"e", ctrl());
+        model.click(&ITEMS, &"a", ctrl());
+        assert_eq!(model.selected_in(&ITEMS), ["b", "e"]);
+        assert_eq!(model.anchor(), Some(&"a"));
+
+        // Ctrl + Shift adds the range.
+        model.click(&ITEMS, &"c", ctrl() | Modifiers::SHIFT);
+        assert_eq!(model.selected_in(&ITEMS), ["a", "b", "c", "e"]);
+
+        assert!(model.click(&ITEMS, &"c", Modifiers::empty()));
+        assert_eq!(model.selected_in(&ITEMS), ["c"]);
+    }
+
+    #[test]
+    fn rubber_band() {
+        let mut model = SelectionModel::new(SelectionMode::Multiple);
+        model.select("e");
+
+        // The band replaces the selection, unless extending.
+        assert!(model.begin_band(false));
+        assert!(model.update_band(["a", "b"]));
+        assert!(model.update_band(["b"]));
+        assert!(!model.update_band(["b"]));
+        model.end_band();
+        assert_eq!(model.selected_in(&ITEMS), ["b"]);
+        assert!(!model.update_band(["c"]));
+
+        assert!(!model.begin_band(true));
+        assert!(model.update_band(["c", "d"]));
+        model.end_band();
+        assert_eq!(model.selected_in(&ITEMS), ["b", "c", "d"]);
+    }
+
+    #[test]
+    fn keys_stay_selected_when_items_change() {
+        let mut model = SelectionModel::new(SelectionMode::Multiple);
+        model.select_all(&ITEMS);
+
+        // Items are reordered and some are removed.
+        let items = ["e", "c", "a"];
+        assert!(model.retain(|key| items.contains(key)));
+        assert_eq!(model.selected_in(&items), ["e", "c", "a"]);
+
+        assert!(model.set_mode(SelectionMode::Single));
+        assert_eq!(model.len(), 1);
+        assert!(model.set_mode(SelectionMode::None));
+        assert!(!model.select("a"));
+    }
+}
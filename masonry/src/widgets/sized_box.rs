@@ -236,7 +236,7 @@ impl Widget for SizedBox {
         let scale = 1.0;
 
         let border = props.get::<BorderWidth>();
-        let padding = props.get::<Padding>();
+        let padding = props.get::<Padding>().resolve(&ctx.unit_context());
 
         let border_length = border.length(axis).dp(scale);
         let padding_length = padding.length(axis).dp(scale);
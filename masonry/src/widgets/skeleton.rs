@@ -0,0 +1,315 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ChildrenIds, HasProperty, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PropertiesMut, PropertiesRef, RegisterCtx, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+    WidgetPod,
+};
+use crate::kurbo::{Axis, Circle, Point, Rect, Size};
+use crate::layout::{LayoutSize, LenReq};
+use crate::peniko::{Color, Gradient};
+use crate::properties::ContentColor;
+use crate::util::fill;
+
+/// The height of each line of [`SkeletonShape::Lines`].
+const LINE_HEIGHT: f64 = 12.;
+/// The space between two lines of [`SkeletonShape::Lines`].
+const LINE_GAP: f64 = 8.;
+/// The width of the last line of a paragraph, relative to the others.
+const LAST_LINE_WIDTH: f64 = 0.6;
+/// The preferred width of a skeleton without footprint.
+const DEFAULT_WIDTH: f64 = 160.;
+/// The preferred height of a [`SkeletonShape::Rect`] without footprint.
+const DEFAULT_RECT_HEIGHT: f64 = 48.;
+/// The preferred diameter of a [`SkeletonShape::Circle`] without footprint.
+const DEFAULT_CIRCLE_DIAMETER: f64 = 32.;
+/// The corner radius of lines and rectangles.
+const CORNER_RADIUS: f64 = 4.;
+/// How long the shimmer takes to sweep across the skeleton, in seconds.
+const SHIMMER_PERIOD: f64 = 1.5;
+/// The width of the shimmer, relative to the width of the skeleton.
+const SHIMMER_WIDTH: f64 = 0.5;
+/// How much the shimmer lightens the shapes.
+const SHIMMER_ALPHA: f32 = 0.5;
+
+/// The shapes painted by a [`Skeleton`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SkeletonShape {
+    /// A paragraph of the given number of lines, the last of which is shorter.
+    Lines(usize),
+    /// A circle, such as an avatar.
+    Circle,
+    /// A rectangle with rounded corners, such as an image.
+    Rect,
+}
+
+/// A placeholder for content which is being loaded.
+///
+/// The skeleton paints the approximate [shapes](SkeletonShape) of the content with the
+/// [`ContentColor`] property, and animates a shimmer across them.
+/// The shimmer doesn't move when the user [prefers reduced motion](UpdateCtx::prefers_reduced_motion).
+///
+/// A skeleton created with [`Skeleton::of`] takes the size of a footprint widget,
+/// usually the content it stands in for, without showing it.
+pub struct Skeleton {
+    shape: SkeletonShape,
+    footprint: Option<WidgetPod<dyn Widget>>,
+    /// The progress of the shimmer across the skeleton, between 0 and 1.
+    shimmer: f64,
+}
+
+// --- MARK: BUILDERS
+impl Skeleton {
+    /// Creates a skeleton painting `shape`.
+    pub fn new(shape: SkeletonShape) -> Self {
+        Self {
+            shape,
+            footprint: None,
+            shimmer: 0.,
+        }
+    }
+
+    /// Creates a skeleton with the size of `footprint`, painting `shape`.
+    ///
+    /// The footprint is measured, but never laid out or shown.
+    pub fn of(footprint: NewWidget<impl Widget + ?Sized>, shape: SkeletonShape) -> Self {
+        Self {
+            shape,
+            footprint: Some(footprint.erased().to_pod()),
+            shimmer: 0.,
+        }
+    }
+
+    /// Returns the painted shape.
+    pub fn shape(&self) -> SkeletonShape {
+        self.shape
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Skeleton {
+    /// Sets the painted shape.
+    pub fn set_shape(this: &mut WidgetMut<'_, Self>, shape: SkeletonShape) {
+        if this.widget.shape == shape {
+            return;
+        }
+        this.widget.shape = shape;
+        this.ctx.request_layout();
+    }
+
+    /// Returns a mutable reference to the footprint, if there is one.
+    pub fn footprint_mut<'t>(
+        this: &'t mut WidgetMut<'_, Self>,
+    ) -> Option<WidgetMut<'t, dyn Widget>> {
+        let footprint = this.widget.footprint.as_mut()?;
+        Some(this.ctx.get_mut(footprint))
+    }
+}
+
+impl HasProperty<ContentColor> for Skeleton {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Skeleton {
+    type Action = NoAction;
+
+    fn accepts_pointer_interaction(&self) -> bool {
+        false
+    }
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        if ctx.prefers_reduced_motion() {
+            return;
+        }
+        self.shimmer = (self.shimmer + interval as f64 * 1e-9 / SHIMMER_PERIOD).rem_euclid(1.);
+        ctx.request_anim_frame();
+        ctx.request_paint_only();
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::WidgetAdded => {
+                ctx.request_anim_frame();
+            }
+            _ => (),
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        if let Some(footprint) = &mut self.footprint {
+            ctx.register_child(footprint);
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        ContentColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        if let Some(footprint) = &mut self.footprint {
+            let auto_length = len_req.into();
+            let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+            return ctx.compute_length(footprint, auto_length, context_size, axis, cross_length);
+        }
+
+        match (self.shape, axis) {
+            (SkeletonShape::Circle, _) => DEFAULT_CIRCLE_DIAMETER,
+            (_, Axis::Horizontal) => DEFAULT_WIDTH,
+            (SkeletonShape::Lines(count), Axis::Vertical) => {
+                let count = count as f64;
+                (count * LINE_HEIGHT + (count - 1.) * LINE_GAP).max(0.)
+            }
+            (SkeletonShape::Rect, Axis::Vertical) => DEFAULT_RECT_HEIGHT,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, _size: Size) {
+        if let Some(footprint) = &mut self.footprint {
+            // TODO: move set_stashed to a different layout pass when possible
+            ctx.set_stashed(footprint, true);
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let size = ctx.content_box_size();
+        let color = props.get::<ContentColor>().color;
+
+        // The shimmer is a lighter band, which enters on the left and leaves on the right.
+        let band = size.width * SHIMMER_WIDTH;
+        let x = -band + self.shimmer * (size.width + band);
+        let highlight = Color::WHITE.multiply_alpha(SHIMMER_ALPHA);
+        let brush = Gradient::new_linear((x, 0.), (x + band, 0.)).with_stops([
+            (0., color),
+            (0.5, mix(color, highlight)),
+            (1., color),
+        ]);
+
+        match self.shape {
+            SkeletonShape::Lines(count) => {
+                // Each line is centered in an equal share of the height.
+                let pitch = size.height / count.max(1) as f64;
+                let thickness = LINE_HEIGHT.min(pitch * LINE_HEIGHT / (LINE_HEIGHT + LINE_GAP));
+                for line in 0..count {
+                    let width = if count > 1 && line == count - 1 {
+                        size.width * LAST_LINE_WIDTH
+                    } else {
+                        size.width
+                    };
+                    let y = line as f64 * pitch + (pitch - thickness) / 2.;
+                    let rect = Rect::new(0., y, width, y + thickness)
+                        .to_rounded_rect(CORNER_RADIUS.min(thickness / 2.));
+                    fill(scene, &rect, &brush);
+                }
+            }
+            SkeletonShape::Circle => {
+                let center = Point::new(size.width / 2., size.height / 2.);
+                let circle = Circle::new(center, size.width.min(size.height) / 2.);
+                fill(scene, &circle, &brush);
+            }
+            SkeletonShape::Rect => {
+                let rect = size.to_rect().to_rounded_rect(CORNER_RADIUS);
+                fill(scene, &rect, &brush);
+            }
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::ProgressIndicator
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_busy();
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.footprint.iter().map(WidgetPod::id).collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Skeleton", id = id.trace())
+    }
+}
+
+/// Returns `color` with `highlight` painted over it.
+fn mix(color: Color, highlight: Color) -> Color {
+    let alpha = highlight.components[3];
+    color.lerp_rect(highlight.with_alpha(1.), alpha)
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::WidgetTag;
+    use crate::layout::AsUnit;
+    use crate::properties::types::{CrossAxisAlignment, MainAxisAlignment};
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::{Flex, SizedBox};
+
+    #[test]
+    fn skeleton_takes_footprint_size() {
+        let skeleton_tag = WidgetTag::named("skeleton");
+        let footprint_tag = WidgetTag::named("footprint");
+        let footprint =
+            NewWidget::new_with_tag(SizedBox::empty().size(70.px(), 30.px()), footprint_tag);
+        let skeleton = Skeleton::of(footprint, SkeletonShape::Lines(2));
+
+        // Wrap in Flex with Start alignment so it doesn't stretch the skeleton
+        let flex = Flex::row()
+            .with_fixed(NewWidget::new_with_tag(skeleton, skeleton_tag))
+            .main_axis_alignment(MainAxisAlignment::Start)
+            .cross_axis_alignment(CrossAxisAlignment::Start);
+        let harness = TestHarness::create_with_size(
+            test_property_set(),
+            flex.with_auto_id(),
+            Size::new(200., 100.),
+        );
+
+        let skeleton = harness.get_widget(skeleton_tag);
+        assert_eq!(skeleton.ctx().border_box_size(), Size::new(70., 30.));
+        assert!(harness.get_widget(footprint_tag).ctx().is_stashed());
+    }
+
+    #[test]
+    fn lines_height() {
+        let skeleton = Skeleton::new(SkeletonShape::Lines(3));
+        let flex = Flex::row()
+            .with_fixed(NewWidget::new(skeleton))
+            .cross_axis_alignment(CrossAxisAlignment::Start);
+        let harness = TestHarness::create_with_size(
+            test_property_set(),
+            flex.with_auto_id(),
+            Size::new(400., 100.),
+        );
+
+        let skeleton = harness.root_widget().children()[0];
+        assert_eq!(
+            skeleton.ctx().border_box_size().height,
+            3. * LINE_HEIGHT + 2. * LINE_GAP
+        );
+    }
+}
@@ -176,19 +176,17 @@ impl Widget for Slider {
                     ctx.submit_action::<f64>(self.value);
                 }
             }
-            PointerEvent::Move(PointerUpdate { current, .. }) => {
-                if ctx.is_active() {
-                    let local_pos = ctx.local_position(current.position);
-                    if self.update_value_from_position(
-                        local_pos.x,
-                        ctx.content_box_size().width,
-                        *props.get(),
-                        ctx.is_focus_target(),
-                    ) {
-                        ctx.submit_action::<f64>(self.value);
-                    }
-                    ctx.request_render();
+            PointerEvent::Move(PointerUpdate { current, .. }) if ctx.is_active() => {
+                let local_pos = ctx.local_position(current.position);
+                if self.update_value_from_position(
+                    local_pos.x,
+                    ctx.content_box_size().width,
+                    *props.get(),
+                    ctx.is_focus_target(),
+                ) {
+                    ctx.submit_action::<f64>(self.value);
                 }
+                ctx.request_render();
             }
             _ => {}
         }
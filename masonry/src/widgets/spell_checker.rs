@@ -0,0 +1,64 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Finds the misspelled words in the text of a [`TextArea`](crate::widgets::TextArea).
+///
+/// Apps supply their own checker, e.g. backed by a dictionary or by the platform's
+/// spellchecking service, and set it with
+/// [`TextArea::with_spell_checker`](crate::widgets::TextArea::with_spell_checker).
+/// The text area underlines the misspelled words with a wavy line, and apps can show
+/// the [suggestions](crate::widgets::TextArea::spelling_suggestions) for a word and
+/// [apply](crate::widgets::TextArea::apply_spelling_suggestion) one of them.
+///
+/// The text is checked again whenever it changes, so checkers should be fast.
+///
+/// # Examples
+///
+/// ```
+/// use masonry::widgets::SpellChecker;
+///
+/// struct WordList(Vec<&'static str>);
+///
+/// impl SpellChecker for WordList {
+///     fn is_correct(&self, word: &str) -> bool {
+///         self.0.iter().any(|known| known.eq_ignore_ascii_case(word))
+///     }
+///
+///     fn suggestions(&self, word: &str) -> Vec<String> {
+///         self.0
+///             .iter()
+///             .filter(|known| known.len() == word.len())
+///             .map(|known| known.to_string())
+///             .collect()
+///     }
+/// }
+///
+/// let checker = WordList(vec!["hello", "world"]);
+/// assert_eq!(checker.misspelled_ranges("hello wrold"), [6..11]);
+/// ```
+pub trait SpellChecker: Send + Sync + 'static {
+    /// Returns whether `word` is spelled correctly.
+    fn is_correct(&self, word: &str) -> bool;
+
+    /// Returns the replacements to suggest for the misspelled `word`, best first.
+    fn suggestions(&self, word: &str) -> Vec<String> {
+        let _ = word;
+        Vec::new()
+    }
+
+    /// Returns the byte ranges of the misspelled words in `text`, in order.
+    ///
+    /// By default, this checks each word found by the Unicode word boundary rules
+    /// with [`is_correct`](Self::is_correct).
+    /// Checkers which need the context of the words, e.g. for grammar, can override this.
+    fn misspelled_ranges(&self, text: &str) -> Vec<Range<usize>> {
+        text.unicode_word_indices()
+            .filter(|(_, word)| !self.is_correct(word))
+            .map(|(start, word)| start..start + word.len())
+            .collect()
+    }
+}
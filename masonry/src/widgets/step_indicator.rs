@@ -0,0 +1,468 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ArcStr, ChildrenIds, EventCtx, HasProperty, LayoutCtx, MeasureCtx, NewWidget,
+    PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PropertiesMut, PropertiesRef,
+    RegisterCtx, Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Circle, Line, Point, Size};
+use crate::layout::{LayoutSize, LenReq, SizeDef};
+use crate::peniko::Color;
+use crate::properties::{BarColor, ContentColor};
+use crate::util::{fill, stroke};
+use crate::widgets::Label;
+
+/// The diameter of the circle around the number of each step.
+const CIRCLE_DIAMETER: f64 = 24.;
+/// The space between the circle of each step and its title.
+const TITLE_GAP: f64 = 4.;
+/// The space between a connecting line and the circles it connects.
+const LINE_GAP: f64 = 4.;
+/// The opacity of disabled steps.
+const DISABLED_ALPHA: f32 = 0.4;
+/// The text shown in the circle of completed steps.
+const CHECK_MARK: &str = "✓";
+
+/// A row of numbered steps, such as the progress through an onboarding or checkout flow.
+///
+/// Each step shows its number in a circle, with its title below, and connecting lines
+/// lead from each step to the next.
+/// The steps before the active one are completed: their circles are filled with the
+/// [`BarColor`] property and show a check mark.
+/// The active step is outlined with the `BarColor`, and the lines and circles of the
+/// following steps are painted with the [`ContentColor`] property.
+/// Disabled steps are faded.
+///
+/// Emits [`StepSelected`] when the user clicks a completed step which isn't disabled,
+/// to go back to it.
+/// Note that the active step does not automatically change, and so one of the responses
+/// to a `StepSelected` is to call [`StepIndicator::set_active`] on the originating widget.
+pub struct StepIndicator {
+    numbers: Vec<WidgetPod<Label>>,
+    titles: Vec<WidgetPod<Label>>,
+    /// The texts of the titles, for accessibility.
+    texts: Vec<ArcStr>,
+    disabled: Vec<bool>,
+    /// The index of the active step, which is the number of steps if all are completed.
+    active: usize,
+    /// The step the pointer was pressed on.
+    pressed: Option<usize>,
+}
+
+/// The action type emitted by [`StepIndicator`] when the user clicks a completed step.
+///
+/// The field is the index of the step.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct StepSelected(pub usize);
+
+// --- MARK: BUILDERS
+impl StepIndicator {
+    /// Creates an indicator with a step for each of the given titles.
+    ///
+    /// The first step is active.
+    pub fn new(titles: impl IntoIterator<Item = impl Into<ArcStr>>) -> Self {
+        let texts: Vec<ArcStr> = titles.into_iter().map(Into::into).collect();
+        Self {
+            numbers: (0..texts.len())
+                .map(|idx| Self::new_label(Self::number_text(idx, 0)))
+                .collect(),
+            titles: texts.iter().cloned().map(Self::new_label).collect(),
+            disabled: vec![false; texts.len()],
+            texts,
+            active: 0,
+            pressed: None,
+        }
+    }
+
+    /// Returns `self` with the step at `index` active, and the previous steps completed.
+    ///
+    /// An `index` equal to the number of steps marks all the steps as completed.
+    pub fn with_active(mut self, index: usize) -> Self {
+        self.active = index.min(self.len());
+        self.numbers = (0..self.len())
+            .map(|idx| Self::new_label(Self::number_text(idx, self.active)))
+            .collect();
+        self
+    }
+
+    /// Returns `self` with the step at `index` disabled or enabled.
+    ///
+    /// Disabled steps can't be selected.
+    pub fn with_disabled(mut self, index: usize, disabled: bool) -> Self {
+        if let Some(step) = self.disabled.get_mut(index) {
+            *step = disabled;
+        }
+        self
+    }
+
+    /// Returns the number of steps.
+    pub fn len(&self) -> usize {
+        self.titles.len()
+    }
+
+    /// Returns `true` if there are no steps.
+    pub fn is_empty(&self) -> bool {
+        self.titles.is_empty()
+    }
+
+    /// Returns the index of the active step.
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    fn new_label(text: impl Into<ArcStr>) -> WidgetPod<Label> {
+        NewWidget::new(Label::new(text)).to_pod()
+    }
+
+    fn number_text(index: usize, active: usize) -> String {
+        if index < active {
+            CHECK_MARK.to_string()
+        } else {
+            (index + 1).to_string()
+        }
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl StepIndicator {
+    /// Replaces the steps with a step for each of the given titles.
+    ///
+    /// Added steps are enabled.
+    pub fn set_steps(
+        this: &mut WidgetMut<'_, Self>,
+        titles: impl IntoIterator<Item = impl Into<ArcStr>>,
+    ) {
+        let texts: Vec<ArcStr> = titles.into_iter().map(Into::into).collect();
+        for (idx, text) in texts.iter().enumerate() {
+            if let Some(title) = this.widget.titles.get_mut(idx) {
+                Label::set_text(&mut this.ctx.get_mut(title), text.clone());
+            } else {
+                let number = Self::number_text(idx, this.widget.active);
+                this.widget.numbers.push(Self::new_label(number));
+                this.widget.titles.push(Self::new_label(text.clone()));
+            }
+        }
+        for number in this.widget.numbers.drain(texts.len()..) {
+            this.ctx.remove_child(number);
+        }
+        for title in this.widget.titles.drain(texts.len()..) {
+            this.ctx.remove_child(title);
+        }
+        this.widget.disabled.resize(texts.len(), false);
+        this.widget.texts = texts;
+        this.ctx.children_changed();
+
+        let active = this.widget.active.min(this.widget.len());
+        Self::set_active(this, active);
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Makes the step at `index` active, and the previous steps completed.
+    ///
+    /// An `index` equal to the number of steps marks all the steps as completed.
+    pub fn set_active(this: &mut WidgetMut<'_, Self>, index: usize) {
+        let active = index.min(this.widget.len());
+        this.widget.active = active;
+        for (idx, number) in this.widget.numbers.iter_mut().enumerate() {
+            Label::set_text(
+                &mut this.ctx.get_mut(number),
+                Self::number_text(idx, active),
+            );
+        }
+        this.ctx.request_paint_only();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Disables or enables the step at `index`.
+    pub fn set_disabled(this: &mut WidgetMut<'_, Self>, index: usize, disabled: bool) {
+        if let Some(step) = this.widget.disabled.get_mut(index) {
+            *step = disabled;
+            this.ctx.request_paint_only();
+        }
+    }
+}
+
+// --- MARK: HELPERS
+impl StepIndicator {
+    /// Returns the index of the step at `x`.
+    fn step_at(&self, x: f64, width: f64) -> Option<usize> {
+        if self.is_empty() || x < 0. || x >= width {
+            return None;
+        }
+        let step_width = width / self.len() as f64;
+        Some(((x / step_width) as usize).min(self.len() - 1))
+    }
+
+    /// Returns whether the user can go back to the step at `index`.
+    fn is_selectable(&self, index: usize) -> bool {
+        index < self.active && !self.disabled[index]
+    }
+}
+
+impl HasProperty<BarColor> for StepIndicator {}
+impl HasProperty<ContentColor> for StepIndicator {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for StepIndicator {
+    type Action = StepSelected;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        let width = ctx.content_box_size().width;
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                let local_pos = ctx.local_position(state.position);
+                self.pressed = self.step_at(local_pos.x, width);
+                ctx.capture_pointer();
+            }
+            PointerEvent::Up(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                state,
+                ..
+            }) if ctx.is_active() => {
+                let local_pos = ctx.local_position(state.position);
+                let released = self.step_at(local_pos.x, width);
+                if let Some(idx) = self.pressed.take()
+                    && released == Some(idx)
+                    && self.is_selectable(idx)
+                {
+                    ctx.submit_action::<StepSelected>(StepSelected(idx));
+                }
+            }
+            PointerEvent::Cancel(..) => {
+                self.pressed = None;
+            }
+            _ => (),
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::DisabledChanged(_) = event {
+            ctx.request_paint_only();
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for number in &mut self.numbers {
+            ctx.register_child(number);
+        }
+        for title in &mut self.titles {
+            ctx.register_child(title);
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        BarColor::prop_changed(ctx, property_type);
+        ContentColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let count = self.len() as f64;
+        // The steps all have the width of the widest one.
+        let cross_length = match axis {
+            Axis::Horizontal => cross_length,
+            Axis::Vertical => cross_length.map(|width| width / count),
+        };
+        let auto_length = len_req.into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        let longest = self
+            .titles
+            .iter_mut()
+            .map(|title| ctx.compute_length(title, auto_length, context_size, axis, cross_length))
+            .fold(0., f64::max);
+
+        match axis {
+            Axis::Horizontal => longest.max(CIRCLE_DIAMETER) * count,
+            Axis::Vertical => CIRCLE_DIAMETER + TITLE_GAP + longest,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let step_width = size.width / self.len().max(1) as f64;
+        let title_slot = Size::new(
+            step_width,
+            (size.height - CIRCLE_DIAMETER - TITLE_GAP).max(0.),
+        );
+        let circle_slot = Size::new(CIRCLE_DIAMETER, CIRCLE_DIAMETER);
+        for (idx, (number, title)) in self.numbers.iter_mut().zip(&mut self.titles).enumerate() {
+            let center_x = (idx as f64 + 0.5) * step_width;
+
+            let number_size =
+                ctx.compute_size(number, SizeDef::fit(circle_slot), circle_slot.into());
+            ctx.run_layout(number, number_size);
+            let origin = Point::new(
+                center_x - number_size.width * 0.5,
+                (CIRCLE_DIAMETER - number_size.height) * 0.5,
+            );
+            ctx.place_child(number, origin);
+
+            let title_size = ctx.compute_size(title, SizeDef::fit(title_slot), title_slot.into());
+            ctx.run_layout(title, title_size);
+            let origin = Point::new(
+                center_x - title_size.width * 0.5,
+                CIRCLE_DIAMETER + TITLE_GAP,
+            );
+            ctx.place_child(title, origin);
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        if self.is_empty() {
+            return;
+        }
+        let bar_color = props.get::<BarColor>().0;
+        let content_color = props.get::<ContentColor>().color;
+        let step_width = ctx.content_box_size().width / self.len() as f64;
+        let radius = CIRCLE_DIAMETER / 2.;
+        let fade = |color: Color, disabled: bool| {
+            if disabled || ctx.is_disabled() {
+                color.multiply_alpha(DISABLED_ALPHA)
+            } else {
+                color
+            }
+        };
+
+        for idx in 0..self.len() {
+            let center = Point::new((idx as f64 + 0.5) * step_width, radius);
+            let disabled = self.disabled[idx];
+
+            // The line to the next step is filled once this step is completed.
+            if idx + 1 < self.len() {
+                let line = Line::new(
+                    (center.x + radius + LINE_GAP, center.y),
+                    (center.x + step_width - radius - LINE_GAP, center.y),
+                );
+                let color = if idx < self.active {
+                    bar_color
+                } else {
+                    content_color
+                };
+                if line.p1.x > line.p0.x {
+                    stroke(scene, &line, fade(color, disabled), 2.);
+                }
+            }
+
+            let circle = Circle::new(center, radius - 1.);
+            if idx < self.active {
+                fill(scene, &circle, fade(bar_color, disabled));
+            } else if idx == self.active {
+                stroke(scene, &circle, fade(bar_color, disabled), 2.);
+            } else {
+                stroke(scene, &circle, fade(content_color, disabled), 1.);
+            }
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Group
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        if let Some(title) = self.texts.get(self.active) {
+            node.set_value(format!(
+                "Step {} of {}: {title}",
+                self.active + 1,
+                self.len()
+            ));
+        } else {
+            node.set_value("Completed");
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.numbers
+            .iter()
+            .chain(&self.titles)
+            .map(|label| label.id())
+            .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("StepIndicator", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+
+    fn step_center(index: usize) -> Point {
+        Point::new((index as f64 + 0.5) * 100., CIRCLE_DIAMETER / 2.)
+    }
+
+    #[test]
+    fn completed_steps_show_check_marks() {
+        let widget = StepIndicator::new(["Account", "Address", "Payment"])
+            .with_active(1)
+            .with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(300., 60.));
+
+        let number_text = |harness: &TestHarness<StepIndicator>, idx: usize| {
+            let number = harness.root_widget().children()[idx];
+            number.downcast::<Label>().unwrap().text().to_string()
+        };
+        assert_eq!(number_text(&harness, 0), CHECK_MARK);
+        assert_eq!(number_text(&harness, 1), "2");
+
+        harness.edit_root_widget(|mut indicator| {
+            StepIndicator::set_active(&mut indicator, 3);
+        });
+        assert_eq!(number_text(&harness, 2), CHECK_MARK);
+    }
+
+    #[test]
+    fn only_completed_steps_are_selectable() {
+        let widget = StepIndicator::new(["A", "B", "C"])
+            .with_active(2)
+            .with_disabled(0, true)
+            .with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(300., 60.));
+        let id = harness.root_id();
+
+        for idx in 0..3 {
+            harness.mouse_move(step_center(idx));
+            harness.mouse_button_press(PointerButton::Primary);
+            harness.mouse_button_release(PointerButton::Primary);
+        }
+        assert_eq!(
+            harness.pop_action::<StepSelected>(),
+            Some((StepSelected(1), id))
+        );
+        assert_eq!(harness.pop_action::<StepSelected>(), None);
+    }
+}
@@ -120,11 +120,9 @@ impl Widget for Switch {
                 ctx.capture_pointer();
                 trace!("Switch {:?} pressed", ctx.widget_id());
             }
-            PointerEvent::Up { .. } => {
-                if ctx.is_active() && ctx.is_hovered() {
-                    ctx.submit_action::<Self::Action>(SwitchToggled(!self.on));
-                    trace!("Switch {:?} released", ctx.widget_id());
-                }
+            PointerEvent::Up { .. } if ctx.is_active() && ctx.is_hovered() => {
+                ctx.submit_action::<Self::Action>(SwitchToggled(!self.on));
+                trace!("Switch {:?} released", ctx.widget_id());
             }
             _ => (),
         }
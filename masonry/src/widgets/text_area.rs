@@ -3,29 +3,35 @@
 
 use std::any::TypeId;
 use std::mem::Discriminant;
+use std::ops::Range;
+use std::sync::Arc;
 
 use accesskit::{Node, Role};
-use parley::PlainEditor;
 use parley::editing::{Generation, SplitString};
+use parley::{FontContext, LayoutContext, PlainEditor};
 use tracing::{Span, trace_span};
 use vello::Scene;
 
 use crate::core::keyboard::{Key, KeyState, NamedKey};
 use crate::core::{
-    AccessCtx, AccessEvent, BrushIndex, ChildrenIds, CursorIcon, EventCtx, Ime, LayoutCtx,
-    MeasureCtx, PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PointerUpdate,
-    PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, StyleProperty, TextEvent, Update,
-    UpdateCtx, Widget, WidgetId, WidgetMut, render_text,
+    AccessCtx, AccessEvent, BrushIndex, ChildrenIds, CursorIcon, EventCtx, Ime, ImePurpose,
+    LayoutCtx, MeasureCtx, PaintCtx, PointerButton, PointerButtonEvent, PointerEvent,
+    PointerUpdate, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, StyleProperty, TextEvent,
+    Update, UpdateCtx, Widget, WidgetId, WidgetMut, render_text,
 };
 use crate::kurbo::{Affine, Axis, Point, Rect, Size};
 use crate::layout::LenReq;
 use crate::peniko::Fill;
 use crate::properties::{
-    CaretColor, ContentColor, DisabledContentColor, SelectionColor, UnfocusedSelectionColor,
+    CaretBlink, CaretColor, CaretWidth, ContentColor, DisabledContentColor, SelectionColor,
+    SpellingErrorColor, TextColor, UnfocusedSelectionColor,
 };
 use crate::theme::default_text_styles;
 use crate::util::bounding_box_to_rect;
 use crate::util::debug_panic;
+use crate::widgets::input_filter::FilteredEdit;
+use crate::widgets::text_decoration::{paint_highlights, paint_underlines};
+use crate::widgets::{DecorationSpan, InputFilter, SpellChecker, TextDecoration, UnderlineStyle};
 use crate::{TextAlign, theme};
 
 /// `TextArea` implements the core of interactive text.
@@ -65,6 +71,8 @@ pub struct TextArea<const USER_EDITABLE: bool> {
     ///
     /// If it has changed, we need to re-perform line-breaking.
     last_max_advance: Option<f32>,
+    /// The [text scale](crate::core::LayoutCtx::text_scale) `editor` lays out text with.
+    text_scale: f32,
 
     /// Whether to hint whilst drawing the text.
     ///
@@ -77,6 +85,40 @@ pub struct TextArea<const USER_EDITABLE: bool> {
     /// If this is set to `InsertNewline::OnEnter` then `Enter` will insert a newline and _not_ trigger a [`TextAction::Entered`] event.
     insert_newline: InsertNewline,
 
+    /// Restricts and reformats the text entered by the user.
+    /// Can be set using [`set_input_filter`](Self::set_input_filter).
+    input_filter: Option<InputFilter>,
+
+    /// The hidden text, if this text area is a password field.
+    /// Can be set using [`set_password`](Self::set_password).
+    password: Option<Password>,
+
+    /// The spell checker and the misspelled words it found.
+    /// Can be set using [`set_spell_checker`](Self::set_spell_checker).
+    spelling: Option<Spelling>,
+
+    /// The decorations painted over the text.
+    /// Can be set using [`set_decorations`](Self::set_decorations).
+    decorations: Vec<DecorationSpan>,
+
+    /// Whether to send [`TextAction::FocusChanged`] when the text area gains or loses focus.
+    /// Can be set using [`set_report_focus`](Self::set_report_focus).
+    report_focus: bool,
+
+    /// Whether to send [`TextAction::SelectionChanged`] when the user changes the selection.
+    /// Can be set using [`set_report_selection`](Self::set_report_selection).
+    report_selection: bool,
+
+    /// Whether `ArrowUp`, `ArrowDown` and Enter are left to the parent widget.
+    /// Can be set using [`set_forward_list_keys`](Self::set_forward_list_keys).
+    forward_list_keys: bool,
+
+    /// The selection when it was last checked for changes.
+    reported_selection: Range<usize>,
+
+    /// The selection set by [`with_selection`](Self::with_selection), applied in the first layout.
+    initial_selection: Option<Range<usize>>,
+
     /// Whether to show the cursor, used for the blink animation.
     anim_cursor_visible: bool,
 
@@ -85,6 +127,13 @@ pub struct TextArea<const USER_EDITABLE: bool> {
 
     /// Time elapsed (ms) to calculate the timeout of the cursor's blink animation.
     anim_elapsed: u64,
+
+    /// Remaining time (ms) during which the cursor stays solid because the user is typing.
+    anim_typing_pause: u64,
+
+    /// The selection started by a double- or triple-click, which dragging extends
+    /// by whole words or paragraphs.
+    unit_selection: Option<UnitSelection>,
 }
 
 // --- MARK: BUILDERS
@@ -119,16 +168,29 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         let mut editor = PlainEditor::new(theme::TEXT_SIZE_NORMAL);
         default_text_styles(editor.edit_styles());
         editor.set_text(text);
+        let reported_selection = editor.raw_selection().text_range();
         Self {
             editor,
             rendered_generation: Generation::default(),
             word_wrap: true,
             last_max_advance: None,
+            text_scale: 1.,
             hint: true,
             insert_newline: InsertNewline::default(),
+            input_filter: None,
+            password: None,
+            spelling: None,
+            decorations: Vec::new(),
+            report_focus: false,
+            report_selection: false,
+            forward_list_keys: false,
+            reported_selection,
+            initial_selection: None,
             anim_cursor_visible: true,
             anim_prev_interval: 0,
             anim_elapsed: 0,
+            anim_typing_pause: 0,
+            unit_selection: None,
         }
     }
 
@@ -212,6 +274,100 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         self
     }
 
+    /// Sets the [`InputFilter`] applied to the text typed, pasted or committed by an IME.
+    ///
+    /// To modify this on an active text area, use [`set_input_filter`](Self::set_input_filter).
+    pub fn with_input_filter(mut self, input_filter: InputFilter) -> Self {
+        self.input_filter = Some(input_filter);
+        self
+    }
+
+    /// Makes this text area a password field.
+    ///
+    /// See [`set_password`](Self::set_password) for details.
+    pub fn with_password(mut self, is_password: bool) -> Self {
+        self.password = is_password.then(|| Password::hide(&mut self.editor));
+        self
+    }
+
+    /// Sets whether the user can copy and cut the text of a password field.
+    ///
+    /// This is disabled by default.
+    /// It has no effect unless the text area is a [password field](Self::with_password).
+    ///
+    /// To modify this on an active text area, use [`set_password_copy`](Self::set_password_copy).
+    pub fn with_password_copy(mut self, allow_copy: bool) -> Self {
+        if let Some(password) = &mut self.password {
+            password.allow_copy = allow_copy;
+        }
+        self
+    }
+
+    /// Sets the [`SpellChecker`] used to underline misspelled words.
+    ///
+    /// To modify this on an active text area, use [`set_spell_checker`](Self::set_spell_checker).
+    pub fn with_spell_checker(mut self, checker: Arc<dyn SpellChecker>) -> Self {
+        self.spelling = Some(Spelling::new(checker));
+        self
+    }
+
+    /// Sets the [decorations](TextDecoration) painted over ranges of the text, such as
+    /// search highlights.
+    ///
+    /// To modify this on an active text area, use [`set_decorations`](Self::set_decorations).
+    pub fn with_decorations(mut self, decorations: Vec<DecorationSpan>) -> Self {
+        self.decorations = decorations;
+        self
+    }
+
+    /// Sets whether a [`TextAction::FocusChanged`] is sent when the text area gains or
+    /// loses focus.
+    ///
+    /// This is disabled by default.
+    ///
+    /// To modify this on an active text area, use [`set_report_focus`](Self::set_report_focus).
+    pub fn with_report_focus(mut self, report_focus: bool) -> Self {
+        self.report_focus = report_focus;
+        self
+    }
+
+    /// Sets whether a [`TextAction::SelectionChanged`] is sent when the user changes the
+    /// selection or moves the caret.
+    ///
+    /// This is disabled by default.
+    ///
+    /// To modify this on an active text area, use [`set_report_selection`](Self::set_report_selection).
+    pub fn with_report_selection(mut self, report_selection: bool) -> Self {
+        self.report_selection = report_selection;
+        self
+    }
+
+    /// Sets whether `ArrowUp` <kbd>↑</kbd>, `ArrowDown` <kbd>↓</kbd> and Enter <kbd>↵</kbd>
+    /// are left unhandled, so that they bubble up to the parent widget.
+    ///
+    /// This lets a parent use the text area to filter a list of items, such as the suggestions
+    /// of a [`CommandPalette`](super::CommandPalette), and move through them with the keyboard.
+    /// When enabled, these keys don't move the caret, insert a newline, or send a
+    /// [`TextAction::Entered`].
+    ///
+    /// This is disabled by default.
+    ///
+    /// To modify this on an active text area, use [`set_forward_list_keys`](Self::set_forward_list_keys).
+    pub fn with_forward_list_keys(mut self, forward_list_keys: bool) -> Self {
+        self.forward_list_keys = forward_list_keys;
+        self
+    }
+
+    /// Sets the initial selection to the given byte range of the displayed text.
+    ///
+    /// No-op if either index is not a char boundary.
+    ///
+    /// To modify this on an active text area, use [`select_byte_range`](Self::select_byte_range).
+    pub fn with_selection(mut self, selection: Range<usize>) -> Self {
+        self.initial_selection = Some(selection);
+        self
+    }
+
     /// Shared logic between `with_style` and `insert_style`
     #[track_caller]
     fn insert_style_inner(&mut self, property: StyleProperty) -> Option<StyleProperty> {
@@ -237,15 +393,198 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
     /// To update the text of an active text area, use [`reset_text`](Self::reset_text).
     ///
     /// The return value is not just `&str` to handle IME preedits.
+    ///
+    /// For a [password field](Self::with_password) whose text is hidden,
+    /// this returns the bullets shown in its place.
+    /// Use [`password`](Self::password) to get the actual text.
     pub fn text(&self) -> SplitString<'_> {
         self.editor.text()
     }
 
+    /// Returns the byte range of the selection in the displayed [text](Self::text).
+    ///
+    /// The range is empty when there is only a caret.
+    ///
+    /// To set the selection of an active text area, use [`select_byte_range`](Self::select_byte_range).
+    pub fn selection(&self) -> Range<usize> {
+        self.editor.raw_selection().text_range()
+    }
+
+    /// Returns the actual text of this text area if it is a [password field](Self::with_password).
+    pub fn password(&self) -> Option<&str> {
+        let password = self.password.as_ref()?;
+        if password.is_revealed {
+            Some(self.editor.raw_text())
+        } else {
+            Some(&password.text)
+        }
+    }
+
+    /// Returns whether this is a password field whose text is shown instead of bullets.
+    pub fn is_password_revealed(&self) -> bool {
+        self.password
+            .as_ref()
+            .is_some_and(|password| password.is_revealed)
+    }
+
     /// Check if this text area holds nothing, including IME preedit content.
     pub fn is_empty(&self) -> bool {
         self.editor.raw_text().is_empty()
     }
 
+    /// Returns whether the IME is composing text, i.e. whether the text contains a preedit.
+    pub fn is_composing(&self) -> bool {
+        self.editor.is_composing()
+    }
+
+    /// Returns the [decorations](Self::with_decorations) painted over the text.
+    pub fn decorations(&self) -> &[DecorationSpan] {
+        &self.decorations
+    }
+
+    /// Returns the byte ranges of the words which the [spell checker](Self::with_spell_checker)
+    /// found to be misspelled.
+    ///
+    /// These are computed during layout, so they may be out of date if the text has just changed.
+    pub fn misspelled_ranges(&self) -> &[Range<usize>] {
+        self.spelling
+            .as_ref()
+            .map_or(&[], |spelling| spelling.misspelled.as_slice())
+    }
+
+    /// Returns the range of the misspelled word containing `byte_index`, with the
+    /// replacements suggested by the [spell checker](Self::with_spell_checker).
+    ///
+    /// Returns `None` if there is no misspelled word at `byte_index`.
+    /// This is intended for use in a context menu, and the chosen suggestion can be
+    /// applied with [`apply_spelling_suggestion`](Self::apply_spelling_suggestion).
+    pub fn spelling_suggestions(&self, byte_index: usize) -> Option<(Range<usize>, Vec<String>)> {
+        let spelling = self.spelling.as_ref()?;
+        let range = spelling
+            .misspelled
+            .iter()
+            .find(|range| range.start <= byte_index && byte_index <= range.end)?
+            .clone();
+        let word = self.editor.raw_text().get(range.clone())?;
+        Some((range, spelling.checker.suggestions(word)))
+    }
+
+    /// Replaces the selection with `text` entered by the user, after applying the
+    /// [input filter](Self::with_input_filter).
+    ///
+    /// Returns whether the text was changed.
+    fn insert_entered_text(
+        &mut self,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+        text: &str,
+    ) -> bool {
+        let hidden = self.hidden_password();
+        if self.input_filter.is_none() && hidden.is_none() {
+            self.editor
+                .driver(fctx, lctx)
+                .insert_or_replace_selection(text);
+            return true;
+        }
+        let selection = self.editor.raw_selection().text_range();
+        let (current, selection) = match hidden {
+            Some(password) => (password, password_range(password, selection)),
+            None => (self.editor.raw_text(), selection),
+        };
+        let edit = match &self.input_filter {
+            Some(filter) => filter.apply(current, selection, text),
+            None => Some(FilteredEdit {
+                text: format!(
+                    "{}{text}{}",
+                    &current[..selection.start],
+                    &current[selection.end..]
+                ),
+                caret: selection.start + text.len(),
+            }),
+        };
+        let Some(edit) = edit else {
+            return false;
+        };
+        if let Some(password) = self.password.as_mut().filter(|p| !p.is_revealed) {
+            let caret = edit.text[..edit.caret].chars().count();
+            self.editor.set_text(&bullets(edit.text.chars().count()));
+            password.text = edit.text;
+            self.editor
+                .driver(fctx, lctx)
+                .move_to_byte(caret * PASSWORD_BULLET.len_utf8());
+        } else {
+            self.editor.set_text(&edit.text);
+            self.editor.driver(fctx, lctx).move_to_byte(edit.caret);
+        }
+        true
+    }
+
+    /// Returns the actual text if this is a password field whose text is hidden.
+    fn hidden_password(&self) -> Option<&str> {
+        self.password
+            .as_ref()
+            .filter(|password| !password.is_revealed)
+            .map(|password| password.text.as_str())
+    }
+
+    /// Removes the characters which have just been deleted from the bullets
+    /// from the actual text of a hidden password.
+    ///
+    /// `prev_len` is the number of bullets before the deletion.
+    fn delete_from_hidden_password(&mut self, prev_len: usize) {
+        let Some(password) = self.password.as_mut().filter(|p| !p.is_revealed) else {
+            return;
+        };
+        let start = self.editor.raw_selection().text_range().start / PASSWORD_BULLET.len_utf8();
+        let deleted = prev_len.saturating_sub(self.editor.raw_text().chars().count());
+        let range = password_range(
+            &password.text,
+            start * PASSWORD_BULLET.len_utf8()..(start + deleted) * PASSWORD_BULLET.len_utf8(),
+        );
+        password.text.replace_range(range, "");
+    }
+
+    /// Returns the selected text to put in the clipboard when copying or cutting,
+    /// or `None` if it can't be copied.
+    fn selected_clipboard_text(&self) -> Option<String> {
+        let selected = self
+            .editor
+            .selected_text()
+            .filter(|text| !text.is_empty())?;
+        match &self.password {
+            None => Some(selected.to_string()),
+            Some(password) if !password.allow_copy => None,
+            Some(password) if password.is_revealed => Some(selected.to_string()),
+            Some(password) => {
+                let selection = self.editor.raw_selection().text_range();
+                Some(password.text[password_range(&password.text, selection)].to_string())
+            }
+        }
+    }
+
+    /// Returns a [`TextAction::SelectionChanged`] if the selection changed since it was last
+    /// checked and the selection is [reported](Self::with_report_selection).
+    fn selection_action(&mut self) -> Option<TextAction> {
+        if self.editor.is_composing() {
+            return None;
+        }
+        let selection = self.selection();
+        if selection == self.reported_selection {
+            return None;
+        }
+        self.reported_selection = selection.clone();
+        self.report_selection
+            .then_some(TextAction::SelectionChanged(selection))
+    }
+
+    /// The text sent in [`TextAction`]s, which is the actual text for password fields.
+    fn action_text(&self) -> String {
+        match self.hidden_password() {
+            Some(password) => password.to_string(),
+            None => self.text().into_iter().collect(),
+        }
+    }
+
     /// Returns the IME area from the editor, accounting for padding.
     ///
     /// This should only be called when the editor layout is available.
@@ -256,6 +595,15 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         );
         bounding_box_to_rect(self.editor.ime_cursor_area())
     }
+
+    /// Makes the editor lay out text with `text_scale`, if it changed.
+    fn update_text_scale(&mut self, text_scale: f64) {
+        let text_scale = text_scale as f32;
+        if self.text_scale != text_scale {
+            self.text_scale = text_scale;
+            self.editor.set_scale(text_scale);
+        }
+    }
 }
 
 // --- MARK: WIDGETMUT
@@ -329,10 +677,19 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
             let (fctx, lctx) = this.ctx.text_contexts();
             this.widget.editor.driver(fctx, lctx).clear_compose();
         }
-        this.widget.editor.set_text(new_text);
+        if let Some(password) = this.widget.password.as_mut().filter(|p| !p.is_revealed) {
+            password.text = new_text.to_string();
+            this.widget
+                .editor
+                .set_text(&bullets(new_text.chars().count()));
+        } else {
+            this.widget.editor.set_text(new_text);
+        }
 
         let (fctx, lctx) = this.ctx.text_contexts();
         this.widget.editor.driver(fctx, lctx).move_to_text_end();
+        this.widget.initial_selection = None;
+        this.widget.reported_selection = this.widget.selection();
 
         this.ctx.request_layout();
     }
@@ -376,18 +733,190 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         this.ctx.request_paint_only();
     }
 
+    /// Sets the [`InputFilter`] applied to the text entered by the user.
+    ///
+    /// The current text isn't filtered again.
+    ///
+    /// The runtime equivalent of [`with_input_filter`](Self::with_input_filter).
+    pub fn set_input_filter(this: &mut WidgetMut<'_, Self>, input_filter: Option<InputFilter>) {
+        this.widget.input_filter = input_filter;
+    }
+
+    /// Sets whether this text area is a password field.
+    ///
+    /// The text of a password field is shown as bullets, unless it is
+    /// [revealed](Self::set_password_revealed).
+    /// Copying and cutting its text is disabled unless [allowed](Self::set_password_copy),
+    /// its accessibility node has the [`PasswordInput`](Role::PasswordInput) role, which marks
+    /// it as protected, and the platform's IME is asked not to show predictions or learn from
+    /// the text.
+    /// The [`TextAction`]s sent by the text area contain the actual text.
+    ///
+    /// The runtime equivalent of [`with_password`](Self::with_password).
+    pub fn set_password(this: &mut WidgetMut<'_, Self>, is_password: bool) {
+        if this.widget.password.is_some() == is_password {
+            return;
+        }
+        Self::set_password_revealed(this, !is_password);
+        this.widget.password = if is_password {
+            Some(Password::hide(&mut this.widget.editor))
+        } else {
+            None
+        };
+        let (fctx, lctx) = this.ctx.text_contexts();
+        this.widget.editor.driver(fctx, lctx).move_to_text_end();
+        this.widget.reported_selection = this.widget.selection();
+        this.ctx.request_layout();
+    }
+
+    /// Sets whether the text of a password field is shown instead of bullets.
+    ///
+    /// The caret is kept at the same character.
+    /// This has no effect unless the text area is a [password field](Self::set_password).
+    pub fn set_password_revealed(this: &mut WidgetMut<'_, Self>, is_revealed: bool) {
+        let Some(password) = &mut this.widget.password else {
+            return;
+        };
+        if password.is_revealed == is_revealed {
+            return;
+        }
+        let editor = &mut this.widget.editor;
+        let (fctx, lctx) = this.ctx.text_contexts();
+        if editor.is_composing() {
+            editor.driver(fctx, lctx).clear_compose();
+        }
+        let caret = editor.raw_text()[..editor.raw_selection().text_range().start]
+            .chars()
+            .count();
+        if is_revealed {
+            editor.set_text(&password.text);
+        } else {
+            *password = Password {
+                allow_copy: password.allow_copy,
+                ..Password::hide(editor)
+            };
+        }
+        password.is_revealed = is_revealed;
+        let caret = editor
+            .raw_text()
+            .char_indices()
+            .nth(caret)
+            .map_or(editor.raw_text().len(), |(idx, _)| idx);
+        editor.driver(fctx, lctx).move_to_byte(caret);
+        this.widget.reported_selection = this.widget.selection();
+        this.ctx.request_layout();
+    }
+
+    /// Sets whether the user can copy and cut the text of a password field.
+    ///
+    /// The runtime equivalent of [`with_password_copy`](Self::with_password_copy).
+    pub fn set_password_copy(this: &mut WidgetMut<'_, Self>, allow_copy: bool) {
+        if let Some(password) = &mut this.widget.password {
+            password.allow_copy = allow_copy;
+        }
+    }
+
+    /// Sets the [`SpellChecker`] used to underline misspelled words, or `None` to disable
+    /// spell checking.
+    ///
+    /// The text of password fields is never checked.
+    ///
+    /// The runtime equivalent of [`with_spell_checker`](Self::with_spell_checker).
+    pub fn set_spell_checker(
+        this: &mut WidgetMut<'_, Self>,
+        checker: Option<Arc<dyn SpellChecker>>,
+    ) {
+        this.widget.spelling = checker.map(Spelling::new);
+        this.ctx.request_layout();
+    }
+
+    /// Sets the [decorations](TextDecoration) painted over ranges of the text.
+    ///
+    /// The ranges are byte ranges of the displayed text, so they aren't updated when
+    /// the text is edited.
+    ///
+    /// The runtime equivalent of [`with_decorations`](Self::with_decorations).
+    pub fn set_decorations(this: &mut WidgetMut<'_, Self>, decorations: Vec<DecorationSpan>) {
+        this.widget.decorations = decorations;
+        this.ctx.request_paint_only();
+    }
+
+    /// Replaces the misspelled word at `range` with `replacement`, as if the user typed it.
+    ///
+    /// The `range` is usually one returned by [`spelling_suggestions`](Self::spelling_suggestions).
+    /// The [input filter](Self::set_input_filter) is applied, and a [`TextAction::Changed`]
+    /// is sent if the text changed.
+    ///
+    /// No-op if either end of `range` is not a char boundary, or if the IME is composing.
+    pub fn apply_spelling_suggestion(
+        this: &mut WidgetMut<'_, Self>,
+        range: Range<usize>,
+        replacement: &str,
+    ) {
+        let text = this.widget.editor.raw_text();
+        if this.widget.editor.is_composing()
+            || range.start > range.end
+            || !text.is_char_boundary(range.start)
+            || !text.is_char_boundary(range.end)
+        {
+            return;
+        }
+        let (fctx, lctx) = this.ctx.text_contexts();
+        this.widget
+            .editor
+            .driver(fctx, lctx)
+            .select_byte_range(range.start, range.end);
+        if this.widget.insert_entered_text(fctx, lctx, replacement) {
+            let action_text = this.widget.action_text();
+            this.ctx
+                .submit_action::<TextAction>(TextAction::Changed(action_text));
+        }
+        if let Some(action) = this.widget.selection_action() {
+            this.ctx.submit_action::<TextAction>(action);
+        }
+        this.ctx.request_layout();
+    }
+
     /// Sets the selection to the given byte range.
     ///
     /// No-op if either index is not a char boundary.
+    ///
+    /// Like the other changes made by the app, this doesn't send a [`TextAction::SelectionChanged`].
     pub fn select_byte_range(this: &mut WidgetMut<'_, Self>, start: usize, end: usize) {
         let (fctx, lctx) = this.ctx.text_contexts();
         this.widget
             .editor
             .driver(fctx, lctx)
             .select_byte_range(start, end);
+        this.widget.initial_selection = None;
+        this.widget.reported_selection = this.widget.selection();
         this.ctx.request_render();
     }
 
+    /// Sets whether a [`TextAction::FocusChanged`] is sent when the text area gains or
+    /// loses focus.
+    ///
+    /// The runtime equivalent of [`with_report_focus`](Self::with_report_focus).
+    pub fn set_report_focus(this: &mut WidgetMut<'_, Self>, report_focus: bool) {
+        this.widget.report_focus = report_focus;
+    }
+
+    /// Sets whether a [`TextAction::SelectionChanged`] is sent when the user changes the
+    /// selection or moves the caret.
+    ///
+    /// The runtime equivalent of [`with_report_selection`](Self::with_report_selection).
+    pub fn set_report_selection(this: &mut WidgetMut<'_, Self>, report_selection: bool) {
+        this.widget.report_selection = report_selection;
+    }
+
+    /// Sets whether `ArrowUp` <kbd>↑</kbd>, `ArrowDown` <kbd>↓</kbd> and Enter <kbd>↵</kbd>
+    /// are left unhandled, so that they bubble up to the parent widget.
+    ///
+    /// The runtime equivalent of [`with_forward_list_keys`](Self::with_forward_list_keys).
+    pub fn set_forward_list_keys(this: &mut WidgetMut<'_, Self>, forward_list_keys: bool) {
+        this.widget.forward_list_keys = forward_list_keys;
+    }
+
     /// Sets the selection to the first instance of the given text.
     ///
     /// This is mostly useful for testing.
@@ -402,7 +931,8 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
     }
 }
 
-/// Text in a text area has been changed or submitted with enter.
+/// Text in a text area has been changed or submitted with enter, or the text area's
+/// focus or selection has changed.
 #[derive(PartialEq, Debug)]
 // TODO: Should this be two different structs?
 pub enum TextAction {
@@ -413,7 +943,16 @@ pub enum TextAction {
     /// Whether this action gets emitted depends on the [`InsertNewline`] setting
     /// and with [`InsertNewline::OnShiftEnter`] also on if the shift key is pressed.
     Entered(String),
-    // TODO: TextCursor changed, ImeChanged
+    /// The text area has gained (`true`) or lost (`false`) focus.
+    ///
+    /// This is only emitted if [enabled](TextArea::with_report_focus).
+    FocusChanged(bool),
+    /// The user has changed the selection or moved the caret.
+    ///
+    /// The value is the new [selection](TextArea::selection).
+    /// This is only emitted if [enabled](TextArea::with_report_selection).
+    SelectionChanged(Range<usize>),
+    // TODO: ImeChanged
 }
 
 // --- MARK: IMPL WIDGET
@@ -423,41 +962,49 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
     fn on_anim_frame(
         &mut self,
         ctx: &mut UpdateCtx<'_>,
-        _props: &mut PropertiesMut<'_>,
+        props: &mut PropertiesMut<'_>,
         interval: u64,
     ) {
-        /// The time for a complete blink cycle, in milliseconds.
-        /// For the first half (i.e. currently 0.5s) the cursor is shown, and for the second half,
-        /// it is hidden.
-        const CURSOR_BLINK_TIME: u64 = 1000;
-
         /// The timeout, in milliseconds, after which the cursor will stop blinking (i.e. stay
         /// solid).
         const CURSOR_BLINK_TIMEOUT: u64 = 10_000; // 10 seconds
 
-        // TODO: These should be reading from the system settings, but we currently
-        // aren't aware of a robust way to read that cross-platform.
-
         if ctx.is_window_focused() && ctx.is_focus_target() {
-            if self.anim_elapsed < CURSOR_BLINK_TIMEOUT {
+            // The time the cursor is shown, then hidden, in milliseconds.
+            let blink_time = props
+                .get::<CaretBlink>()
+                .interval(ctx.caret_blink_interval())
+                .map(|interval| {
+                    u64::try_from(interval.as_millis())
+                        .unwrap_or(u64::MAX)
+                        .max(1)
+                });
+            if let Some(blink_time) = blink_time
+                && self.anim_elapsed < CURSOR_BLINK_TIMEOUT
+            {
                 let interval_ms = interval / 1_000_000; // ns to ms
-                self.anim_prev_interval += interval_ms;
                 self.anim_elapsed += interval_ms;
+                if self.anim_typing_pause > 0 {
+                    // The blink cycle starts over once the user stops typing.
+                    self.anim_typing_pause = self.anim_typing_pause.saturating_sub(interval_ms);
+                    self.anim_prev_interval = 0;
+                } else {
+                    self.anim_prev_interval += interval_ms;
+                }
 
-                if self.anim_prev_interval >= CURSOR_BLINK_TIME {
-                    self.anim_prev_interval = self.anim_prev_interval.rem_euclid(CURSOR_BLINK_TIME);
+                let blink_cycle = blink_time.saturating_mul(2);
+                if self.anim_prev_interval >= blink_cycle {
+                    self.anim_prev_interval = self.anim_prev_interval.rem_euclid(blink_cycle);
                 }
 
                 // TODO: request timer here
                 ctx.request_anim_frame();
 
                 // Request paint only if changed.
-                if self.anim_prev_interval < CURSOR_BLINK_TIME / 2 && !self.anim_cursor_visible {
+                if self.anim_prev_interval < blink_time && !self.anim_cursor_visible {
                     self.anim_cursor_visible = true;
                     ctx.request_paint_only();
-                } else if self.anim_prev_interval >= CURSOR_BLINK_TIME / 2
-                    && self.anim_cursor_visible
-                {
+                } else if self.anim_prev_interval >= blink_time && self.anim_cursor_visible {
                     self.anim_cursor_visible = false;
                     ctx.request_paint_only();
                 }
@@ -501,6 +1048,15 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                         }
                     }
                 }
+                let unit = match state.count {
+                    2 => Some(SelectionUnit::Word),
+                    3 => Some(SelectionUnit::Paragraph),
+                    _ => None,
+                };
+                self.unit_selection = unit.map(|unit| UnitSelection {
+                    unit,
+                    anchor: self.editor.raw_selection().text_range(),
+                });
                 let new_generation = self.editor.generation();
                 if new_generation != self.rendered_generation {
                     ctx.request_render();
@@ -509,24 +1065,42 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 }
                 ctx.request_focus();
                 ctx.capture_pointer();
+                ctx.request_edge_auto_scroll();
             }
-            PointerEvent::Move(PointerUpdate { current, .. }) => {
-                if ctx.is_active() {
-                    let cursor_pos = ctx.local_position(current.position);
+            PointerEvent::Move(PointerUpdate { current, .. }) if ctx.is_active() => {
+                let cursor_pos = ctx.local_position(current.position);
+                let (x, y) = (cursor_pos.x as f32, cursor_pos.y as f32);
+                let (fctx, lctx) = ctx.text_contexts();
+                let mut drv = self.editor.driver(fctx, lctx);
+                if let Some(UnitSelection { unit, anchor }) = &self.unit_selection {
+                    // The selection covers the clicked unit and every unit up to the pointer.
+                    match unit {
+                        SelectionUnit::Word => drv.select_word_at_point(x, y),
+                        SelectionUnit::Paragraph => drv.select_hard_line_at_point(x, y),
+                    }
+                    let pointed = self.editor.raw_selection().text_range();
                     let (fctx, lctx) = ctx.text_contexts();
-                    self.editor
-                        .driver(fctx, lctx)
-                        .extend_selection_to_point(cursor_pos.x as f32, cursor_pos.y as f32);
-                    let new_generation = self.editor.generation();
-                    if new_generation != self.rendered_generation {
-                        ctx.request_render();
-                        ctx.set_ime_area(self.ime_area());
-                        self.rendered_generation = new_generation;
+                    let mut drv = self.editor.driver(fctx, lctx);
+                    if pointed.start < anchor.start {
+                        drv.select_byte_range(anchor.end, pointed.start);
+                    } else {
+                        drv.select_byte_range(anchor.start, pointed.end.max(anchor.end));
                     }
+                } else {
+                    drv.extend_selection_to_point(x, y);
+                }
+                let new_generation = self.editor.generation();
+                if new_generation != self.rendered_generation {
+                    ctx.request_render();
+                    ctx.set_ime_area(self.ime_area());
+                    self.rendered_generation = new_generation;
                 }
             }
             _ => {}
         }
+        if let Some(action) = self.selection_action() {
+            ctx.submit_action::<Self::Action>(action);
+        }
     }
 
     fn on_text_event(
@@ -535,9 +1109,15 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
         _props: &mut PropertiesMut<'_>,
         event: &TextEvent,
     ) {
+        /// The time, in milliseconds, the cursor stays solid after the user types.
+        const TYPING_PAUSE: u64 = 500;
+
         // Reset the blink animation.
         self.anim_prev_interval = 0;
         self.anim_elapsed = 0;
+        if !matches!(event, TextEvent::WindowFocusChange(_)) {
+            self.anim_typing_pause = TYPING_PAUSE;
+        }
         ctx.request_anim_frame();
 
         match event {
@@ -557,16 +1137,21 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 // Whether the text was changed.
                 let mut edited = false;
                 match &key_event.key {
+                    Key::Named(NamedKey::ArrowUp | NamedKey::ArrowDown | NamedKey::Enter)
+                        if self.forward_list_keys =>
+                    {
+                        // Don't set as handled, so that the parent can move through its list.
+                        return;
+                    }
                     // Cut
                     #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
                     Key::Character(x)
                         if EDITABLE && action_mod && x.as_str().eq_ignore_ascii_case("x") =>
                     {
-                        if let Some(text) = self.editor.selected_text()
-                            && !text.is_empty()
-                        {
-                            let text = text.to_string();
+                        if let Some(text) = self.selected_clipboard_text() {
+                            let prev_len = self.editor.raw_text().chars().count();
                             self.editor.driver(fctx, lctx).delete_selection();
+                            self.delete_from_hidden_password(prev_len);
                             edited = true;
                             ctx.set_clipboard(text);
                         }
@@ -574,10 +1159,8 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                     // Copy
                     #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
                     Key::Character(c) if action_mod && c.as_str().eq_ignore_ascii_case("c") => {
-                        if let Some(text) = self.editor.selected_text()
-                            && !text.is_empty()
-                        {
-                            ctx.set_clipboard(text.to_string());
+                        if let Some(text) = self.selected_clipboard_text() {
+                            ctx.set_clipboard(text);
                         }
                     }
                     Key::Character(a) if action_mod && a.as_str().eq_ignore_ascii_case("a") => {
@@ -662,30 +1245,36 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                         }
                     }
                     Key::Named(NamedKey::Delete) if EDITABLE => {
+                        let prev_len = self.editor.raw_text().chars().count();
                         let mut drv = self.editor.driver(fctx, lctx);
                         if action_mod {
                             drv.delete_word();
                         } else {
                             drv.delete();
                         }
+                        self.delete_from_hidden_password(prev_len);
 
                         edited = true;
                     }
                     Key::Named(NamedKey::Backspace) if EDITABLE => {
+                        if self.is_empty() {
+                            // Don't set as handled, so that parents such as `TokenInput`
+                            // can handle Backspace in an empty text area.
+                            return;
+                        }
+                        let prev_len = self.editor.raw_text().chars().count();
                         let mut drv = self.editor.driver(fctx, lctx);
                         if action_mod {
                             drv.backdelete_word();
                         } else {
                             drv.backdelete();
                         }
+                        self.delete_from_hidden_password(prev_len);
 
                         edited = true;
                     }
                     Key::Character(sp) if EDITABLE && sp.as_str() == " " => {
-                        self.editor
-                            .driver(fctx, lctx)
-                            .insert_or_replace_selection(" ");
-                        edited = true;
+                        edited = self.insert_entered_text(fctx, lctx, " ");
                     }
                     Key::Named(NamedKey::Enter) => {
                         let insert_newline = match self.insert_newline {
@@ -695,13 +1284,10 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                         };
                         if insert_newline {
                             let (fctx, lctx) = ctx.text_contexts();
-                            self.editor
-                                .driver(fctx, lctx)
-                                .insert_or_replace_selection("\n");
-                            edited = true;
+                            edited = self.insert_entered_text(fctx, lctx, "\n");
                         } else {
                             ctx.submit_action::<Self::Action>(TextAction::Entered(
-                                self.text().to_string(),
+                                self.action_text(),
                             ));
                         }
                     }
@@ -712,10 +1298,7 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                         return;
                     }
                     Key::Character(text) if EDITABLE => {
-                        self.editor
-                            .driver(fctx, lctx)
-                            .insert_or_replace_selection(text);
-                        edited = true;
+                        edited = self.insert_entered_text(fctx, lctx, text);
                     }
                     _ => {
                         // Do nothing, don't set as handled.
@@ -726,9 +1309,7 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 let new_generation = self.editor.generation();
                 if new_generation != self.rendered_generation {
                     if edited {
-                        ctx.submit_action::<Self::Action>(TextAction::Changed(
-                            self.text().into_iter().collect(),
-                        ));
+                        ctx.submit_action::<Self::Action>(TextAction::Changed(self.action_text()));
                         ctx.request_layout();
                     } else {
                         ctx.request_render();
@@ -763,17 +1344,14 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                         }
                     }
                     Ime::Commit(text) => {
-                        self.editor
-                            .driver(fctx, lctx)
-                            .insert_or_replace_selection(text);
-                        edited = true;
+                        edited = self.insert_entered_text(fctx, lctx, text);
                     }
                     Ime::Enabled => {}
                 }
 
                 ctx.set_handled();
                 if edited {
-                    let text = self.text().into_iter().collect();
+                    let text = self.action_text();
                     ctx.submit_action::<Self::Action>(TextAction::Changed(text));
                 }
 
@@ -787,22 +1365,21 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
             TextEvent::ClipboardPaste(text) => {
                 if EDITABLE {
                     let (fctx, lctx) = ctx.text_contexts();
-                    self.editor
-                        .driver(fctx, lctx)
-                        .insert_or_replace_selection(text);
+                    self.insert_entered_text(fctx, lctx, text);
 
                     // TODO - Factor out with other branches
                     let new_generation = self.editor.generation();
                     if new_generation != self.rendered_generation {
-                        ctx.submit_action::<Self::Action>(TextAction::Changed(
-                            self.text().into_iter().collect(),
-                        ));
+                        ctx.submit_action::<Self::Action>(TextAction::Changed(self.action_text()));
                         ctx.request_layout();
                         self.rendered_generation = new_generation;
                     }
                 }
             }
         }
+        if let Some(action) = self.selection_action() {
+            ctx.submit_action::<Self::Action>(action);
+        }
     }
 
     fn accepts_focus(&self) -> bool {
@@ -813,6 +1390,14 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
         EDITABLE
     }
 
+    fn ime_purpose(&self) -> ImePurpose {
+        if self.password.is_some() {
+            ImePurpose::Password
+        } else {
+            ImePurpose::Normal
+        }
+    }
+
     fn on_access_event(
         &mut self,
         ctx: &mut EventCtx<'_>,
@@ -835,6 +1420,9 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                     ctx.set_ime_area(self.ime_area());
                     self.rendered_generation = new_generation;
                 }
+                if let Some(action) = self.selection_action() {
+                    ctx.submit_action::<Self::Action>(action);
+                }
             }
         }
     }
@@ -842,16 +1430,23 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
     fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
 
     fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        CaretBlink::prop_changed(ctx, property_type);
         CaretColor::prop_changed(ctx, property_type);
+        CaretWidth::prop_changed(ctx, property_type);
         ContentColor::prop_changed(ctx, property_type);
         DisabledContentColor::prop_changed(ctx, property_type);
         SelectionColor::prop_changed(ctx, property_type);
+        SpellingErrorColor::prop_changed(ctx, property_type);
+        TextColor::prop_changed(ctx, property_type);
         UnfocusedSelectionColor::prop_changed(ctx, property_type);
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
         match event {
-            Update::FocusChanged(_) => {
+            Update::FocusChanged(focused) => {
+                if self.report_focus {
+                    ctx.submit_action::<Self::Action>(TextAction::FocusChanged(*focused));
+                }
                 ctx.request_render();
             }
             Update::DisabledChanged(_) => {
@@ -920,6 +1515,7 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
         //       but that's potentially wasted work for measure.
         //       Should probably split up that PlainEditor method.
 
+        self.update_text_scale(ctx.text_scale());
         // TODO: Don't trigger style change multiple times per layout pass for font changes,
         //       by storing some marker that states we've already dealt with it this pass.
         if ctx.fonts_changed() {
@@ -964,11 +1560,20 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
             self.last_max_advance = max_advance;
         }
 
+        if let Some(selection) = self.initial_selection.take() {
+            let (fctx, lctx) = ctx.text_contexts();
+            self.editor
+                .driver(fctx, lctx)
+                .select_byte_range(selection.start, selection.end);
+            self.reported_selection = self.selection();
+        }
+
         let new_generation = self.editor.generation();
         if new_generation != self.rendered_generation {
             self.rendered_generation = new_generation;
         }
 
+        self.update_text_scale(ctx.text_scale());
         // TODO: Don't trigger style change multiple times per layout pass for font changes,
         //       by storing some marker that states we've already dealt with it this pass.
         if ctx.fonts_changed() {
@@ -980,6 +1585,10 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
         let (fctx, lctx) = ctx.text_contexts();
         self.editor.layout(fctx, lctx);
 
+        if let Some(spelling) = &mut self.spelling {
+            spelling.check(&self.editor, self.password.is_some());
+        }
+
         ctx.set_ime_area(self.ime_area());
     }
 
@@ -993,8 +1602,11 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
             self.editor.refresh_layout(fctx, lctx);
             self.editor.try_layout().unwrap()
         };
+        let text = self.editor.raw_text();
+        paint_highlights(scene, layout, text, &self.decorations);
         if ctx.is_focus_target() {
             let caret_color = props.get::<CaretColor>().color;
+            let caret_width = props.get::<CaretWidth>().width;
             let selection_color = if !ctx.is_window_focused()
                 && let Some(us) = props.get_defined::<UnfocusedSelectionColor>()
             {
@@ -1011,7 +1623,7 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                     &bounding_box_to_rect(*rect),
                 );
             }
-            if let Some(cursor) = self.editor.cursor_geometry(1.5)
+            if let Some(cursor) = self.editor.cursor_geometry(caret_width as f32)
                 && self.anim_cursor_visible
                 && ctx.is_window_focused()
             {
@@ -1025,21 +1637,38 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
             };
         }
 
-        let text_color = if ctx.is_disabled()
+        let content_color = if ctx.is_disabled()
             && let Some(dc) = props.get_defined::<DisabledContentColor>()
         {
             &dc.0
         } else {
             props.get::<ContentColor>()
         };
+        let text_color = props
+            .get::<TextColor>()
+            .resolve(content_color.color, ctx.effective_background());
 
         render_text(
             scene,
             Affine::IDENTITY,
             layout,
-            &[text_color.color.into()],
+            &[text_color.into()],
             self.hint,
         );
+
+        paint_underlines(scene, layout, text, &self.decorations);
+        if let Some(spelling) = &self.spelling {
+            let decoration = TextDecoration::Underline {
+                style: UnderlineStyle::Wavy,
+                color: props.get::<SpellingErrorColor>().color,
+            };
+            let spans: Vec<_> = spelling
+                .misspelled
+                .iter()
+                .map(|range| DecorationSpan::new(range.clone(), decoration))
+                .collect();
+            paint_underlines(scene, layout, text, &spans);
+        }
     }
 
     fn get_cursor(&self, _ctx: &QueryCtx<'_>, _pos: Point) -> CursorIcon {
@@ -1047,7 +1676,10 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
     }
 
     fn accessibility_role(&self) -> Role {
-        if EDITABLE {
+        if self.password.is_some() {
+            // This is how AccessKit marks the value as protected.
+            Role::PasswordInput
+        } else if EDITABLE {
             match self.insert_newline {
                 InsertNewline::OnShiftEnter | InsertNewline::OnEnter => Role::MultilineTextInput,
                 _ => Role::TextInput,
@@ -1090,6 +1722,103 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
     }
 }
 
+/// The character shown in place of each character of a hidden password.
+const PASSWORD_BULLET: char = '•';
+
+/// The state of a password field.
+struct Password {
+    /// The actual text, while the editor holds bullets in its place.
+    ///
+    /// This isn't kept up to date while the password is revealed.
+    text: String,
+    is_revealed: bool,
+    allow_copy: bool,
+}
+
+impl Password {
+    /// Replaces the text of `editor` with bullets, and returns the hidden password.
+    fn hide(editor: &mut PlainEditor<BrushIndex>) -> Self {
+        let text = editor.raw_text().to_string();
+        editor.set_text(&bullets(text.chars().count()));
+        Self {
+            text,
+            is_revealed: false,
+            allow_copy: false,
+        }
+    }
+}
+
+fn bullets(count: usize) -> String {
+    std::iter::repeat_n(PASSWORD_BULLET, count).collect()
+}
+
+/// Maps a byte range in the bullets of a hidden password to a byte range in `password`.
+fn password_range(password: &str, bullets: Range<usize>) -> Range<usize> {
+    let byte_index = |bullet_idx: usize| {
+        password
+            .char_indices()
+            .nth(bullet_idx / PASSWORD_BULLET.len_utf8())
+            .map_or(password.len(), |(idx, _)| idx)
+    };
+    byte_index(bullets.start)..byte_index(bullets.end)
+}
+
+/// The spell checking state of a text area.
+struct Spelling {
+    checker: Arc<dyn SpellChecker>,
+    /// The byte ranges of the misspelled words.
+    misspelled: Vec<Range<usize>>,
+    /// The generation of the editor whose text was checked.
+    checked_generation: Option<Generation>,
+}
+
+impl Spelling {
+    fn new(checker: Arc<dyn SpellChecker>) -> Self {
+        Self {
+            checker,
+            misspelled: Vec::new(),
+            checked_generation: None,
+        }
+    }
+
+    /// Checks the text of `editor` again if it has changed since it was last checked.
+    fn check(&mut self, editor: &PlainEditor<BrushIndex>, is_password: bool) {
+        let generation = editor.generation();
+        if self.checked_generation == Some(generation) {
+            return;
+        }
+        self.checked_generation = Some(generation);
+        if is_password {
+            self.misspelled.clear();
+            return;
+        }
+        let text = editor.raw_text();
+        self.misspelled = self.checker.misspelled_ranges(text);
+        // The ranges come from app code, so we make sure they can be used to index the text.
+        self.misspelled.retain(|range| {
+            range.start < range.end
+                && text.is_char_boundary(range.start)
+                && text.is_char_boundary(range.end)
+        });
+    }
+}
+
+/// The unit a [`UnitSelection`] is made of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SelectionUnit {
+    /// A word, as split by Unicode word boundaries.
+    Word,
+    /// A paragraph, i.e. the text between two hard line breaks.
+    Paragraph,
+}
+
+/// A selection started by double- or triple-clicking.
+struct UnitSelection {
+    unit: SelectionUnit,
+    /// The byte range of the unit which was clicked, which stays selected while dragging.
+    anchor: Range<usize>,
+}
+
 /// When to insert a newline in a text area.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum InsertNewline {
@@ -1111,14 +1840,17 @@ pub enum InsertNewline {
 // --- MARK: TESTS
 #[cfg(test)]
 mod tests {
+    use dpi::PhysicalPosition;
     use masonry_testing::TestHarnessParams;
 
     use super::*;
-    use crate::core::{KeyboardEvent, Modifiers, NewWidget, Properties};
+    use crate::core::pointer::PointerState;
+    use crate::core::{KeyboardEvent, Modifiers, NewWidget, Properties, WindowEvent};
     use crate::kurbo::Size;
     use crate::palette;
-    use crate::testing::TestHarness;
+    use crate::testing::{PRIMARY_MOUSE, TestHarness};
     use crate::theme::test_property_set;
+    use crate::util::Duration;
     // Tests of alignment happen in Prose.
 
     #[test]
@@ -1297,4 +2029,236 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn input_filter_applies_to_all_entered_text() {
+        let filter = InputFilter::digits().with_mask("##/##/####");
+        let area = NewWidget::new(TextArea::new_editable("").with_input_filter(filter));
+
+        let mut harness = TestHarness::create(test_property_set(), area);
+        let text_id = harness.root_id();
+        harness.focus_on(Some(text_id));
+
+        // Typing.
+        harness.keyboard_type_chars("1a4");
+        assert_eq!(harness.root_widget().text().to_string(), "14");
+        harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+            key: Key::Character("1".into()),
+            ..Default::default()
+        }));
+        assert_eq!(harness.root_widget().text().to_string(), "14/1");
+
+        // Pasting.
+        harness.process_text_event(TextEvent::ClipboardPaste("0-2026 and more".into()));
+        assert_eq!(harness.root_widget().text().to_string(), "14/10/2026");
+        let mut last_change = None;
+        while let Some((action, _)) = harness.pop_action::<TextAction>() {
+            last_change = Some(action);
+        }
+        assert_eq!(
+            last_change,
+            Some(TextAction::Changed("14/10/2026".to_string()))
+        );
+
+        // Rejected text doesn't send an action.
+        harness.keyboard_type_chars("9");
+        assert_eq!(harness.root_widget().text().to_string(), "14/10/2026");
+        assert!(harness.pop_action::<TextAction>().is_none());
+    }
+
+    #[test]
+    fn focus_and_selection_are_reported() {
+        let area = NewWidget::new(
+            TextArea::new_editable("hello world")
+                .with_selection(0..5)
+                .with_report_focus(true)
+                .with_report_selection(true),
+        );
+
+        let mut harness = TestHarness::create(test_property_set(), area);
+        let text_id = harness.root_id();
+        assert_eq!(harness.root_widget().selection(), 0..5);
+
+        harness.focus_on(Some(text_id));
+        assert_eq!(
+            harness.pop_action::<TextAction>().map(|(action, _)| action),
+            Some(TextAction::FocusChanged(true))
+        );
+
+        harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+            key: Key::Named(NamedKey::End),
+            ..Default::default()
+        }));
+        assert_eq!(
+            harness.pop_action::<TextAction>().map(|(action, _)| action),
+            Some(TextAction::SelectionChanged(11..11))
+        );
+
+        // Selections made by the app aren't reported.
+        harness.edit_root_widget(|mut area| {
+            TextArea::select_byte_range(&mut area, 6, 11);
+        });
+        assert_eq!(harness.root_widget().selection(), 6..11);
+        assert!(harness.pop_action::<TextAction>().is_none());
+
+        harness.focus_on(None);
+        assert_eq!(
+            harness.pop_action::<TextAction>().map(|(action, _)| action),
+            Some(TextAction::FocusChanged(false))
+        );
+    }
+
+    #[test]
+    fn caret_blink() {
+        let area = NewWidget::new(TextArea::new_editable(""));
+
+        let mut harness = TestHarness::create(test_property_set(), area);
+        let text_id = harness.root_id();
+        harness.focus_on(Some(text_id));
+
+        // The cursor stays solid while the user types, then starts blinking.
+        harness.keyboard_type_chars("a");
+        harness.animate_ms(500 + 1);
+        assert!(harness.root_widget().anim_cursor_visible);
+        harness.animate_ms(500);
+        assert!(!harness.root_widget().anim_cursor_visible);
+
+        // The user turned blinking off.
+        harness.process_window_event(WindowEvent::CaretBlinkChanged(None));
+        harness.animate_ms(16);
+        assert!(harness.root_widget().anim_cursor_visible);
+
+        // The property overrides the user's setting.
+        harness.edit_root_widget(|mut area| {
+            area.insert_prop(CaretBlink::Interval(Duration::from_millis(200)));
+        });
+        harness.animate_ms(200 + 1);
+        assert!(!harness.root_widget().anim_cursor_visible);
+    }
+
+    /// Returns the center of the text in `range`, as selected by the root text area.
+    fn text_center(harness: &mut TestHarness<TextArea<true>>, range: Range<usize>) -> Point {
+        harness.edit_root_widget(|mut area| {
+            TextArea::select_byte_range(&mut area, range.start, range.end);
+        });
+        let (rect, _) = harness.root_widget().editor.selection_geometry()[0];
+        bounding_box_to_rect(rect).center()
+    }
+
+    fn multi_click(harness: &mut TestHarness<TextArea<true>>, position: Point, count: u8) {
+        harness.mouse_move(position);
+        harness.process_pointer_event(PointerEvent::Down(PointerButtonEvent {
+            pointer: PRIMARY_MOUSE,
+            button: Some(PointerButton::Primary),
+            state: PointerState {
+                position: PhysicalPosition::new(position.x, position.y),
+                count,
+                ..Default::default()
+            },
+        }));
+    }
+
+    #[test]
+    fn multi_click_drag_extends_by_unit() {
+        let area = NewWidget::new(TextArea::new_editable("hello world\nsecond line"));
+
+        let mut harness = TestHarness::create(test_property_set(), area);
+        let hello = text_center(&mut harness, 0..5);
+        let world = text_center(&mut harness, 6..11);
+        let second = text_center(&mut harness, 12..18);
+
+        // Dragging after a double-click selects whole words.
+        multi_click(&mut harness, hello, 2);
+        assert_eq!(harness.root_widget().selection(), 0..5);
+        harness.mouse_move(world);
+        assert_eq!(harness.root_widget().selection(), 0..11);
+        harness.mouse_button_release(PointerButton::Primary);
+
+        // Including backwards.
+        multi_click(&mut harness, world, 2);
+        assert_eq!(harness.root_widget().selection(), 6..11);
+        harness.mouse_move(hello);
+        assert_eq!(harness.root_widget().selection(), 0..11);
+        harness.mouse_button_release(PointerButton::Primary);
+
+        // Dragging after a triple-click selects whole paragraphs.
+        multi_click(&mut harness, world, 3);
+        harness.mouse_move(second);
+        assert_eq!(harness.root_widget().selection(), 0..23);
+        harness.mouse_button_release(PointerButton::Primary);
+    }
+
+    struct WordList(&'static [&'static str]);
+
+    impl SpellChecker for WordList {
+        fn is_correct(&self, word: &str) -> bool {
+            self.0.contains(&word)
+        }
+
+        fn suggestions(&self, word: &str) -> Vec<String> {
+            let mut word: Vec<char> = word.chars().collect();
+            word.sort_unstable();
+            self.0
+                .iter()
+                .filter(|known| {
+                    let mut known: Vec<char> = known.chars().collect();
+                    known.sort_unstable();
+                    known == word
+                })
+                .map(|known| known.to_string())
+                .collect()
+        }
+    }
+
+    #[test]
+    fn spelling_suggestions_replace_misspelled_word() {
+        let checker = Arc::new(WordList(&["hello", "world"]));
+        let area =
+            NewWidget::new(TextArea::new_editable("hello wrold").with_spell_checker(checker));
+
+        let mut harness = TestHarness::create(test_property_set(), area);
+        assert_eq!(
+            harness.root_widget().misspelled_ranges().to_vec(),
+            vec![6..11]
+        );
+        assert_eq!(harness.root_widget().spelling_suggestions(2), None);
+        let (range, suggestions) = harness.root_widget().spelling_suggestions(8).unwrap();
+        assert_eq!(range, 6..11);
+        assert_eq!(suggestions, ["world"]);
+
+        harness.edit_root_widget(|mut area| {
+            TextArea::apply_spelling_suggestion(&mut area, range, &suggestions[0]);
+        });
+        assert_eq!(harness.root_widget().text().to_string(), "hello world");
+        assert!(harness.root_widget().misspelled_ranges().is_empty());
+        assert_eq!(
+            harness.pop_action::<TextAction>().map(|(action, _)| action),
+            Some(TextAction::Changed("hello world".to_string()))
+        );
+
+        // The words typed by the user are checked.
+        harness.focus_on(Some(harness.root_id()));
+        harness.keyboard_type_chars(" hlelo");
+        assert_eq!(
+            harness.root_widget().misspelled_ranges().to_vec(),
+            vec![12..17]
+        );
+    }
+
+    #[test]
+    fn password_is_not_spell_checked() {
+        let checker = Arc::new(WordList(&[]));
+        let area = NewWidget::new(
+            TextArea::new_editable("secret")
+                .with_password(true)
+                .with_spell_checker(checker),
+        );
+
+        let mut harness = TestHarness::create(test_property_set(), area);
+        assert!(harness.root_widget().misspelled_ranges().is_empty());
+        harness.edit_root_widget(|mut area| {
+            TextArea::set_password_revealed(&mut area, true);
+        });
+        assert!(harness.root_widget().misspelled_ranges().is_empty());
+    }
 }
@@ -0,0 +1,168 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::Range;
+
+use parley::editing::{Cursor, Selection};
+use parley::{Affinity, Layout};
+use vello::Scene;
+
+use crate::core::BrushIndex;
+use crate::kurbo::{BezPath, Circle, Line, Rect};
+use crate::peniko::Color;
+use crate::util::{bounding_box_to_rect, fill_color, stroke};
+
+/// The line style of a [`TextDecoration::Underline`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnderlineStyle {
+    /// A straight line.
+    #[default]
+    Solid,
+    /// A wavy line, as commonly used for spelling errors.
+    Wavy,
+    /// A line of dots.
+    Dotted,
+}
+
+/// A decoration painted over a range of text, without changing its layout.
+///
+/// Unlike [`StyleProperty::Underline`](parley::StyleProperty::Underline), decorations can
+/// have their own colors, and can be changed without laying out the text again.
+/// This makes them suitable for search highlighting and marking mentions.
+///
+/// Decorations are applied to a text with [`DecorationSpan`]s.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TextDecoration {
+    /// A line under the text.
+    Underline {
+        /// The line style.
+        style: UnderlineStyle,
+        /// The color of the line.
+        color: Color,
+    },
+    /// A background color behind the text.
+    Highlight(Color),
+}
+
+/// A [`TextDecoration`] applied to a byte range of a text.
+///
+/// Ranges which don't start and end on char boundaries of the text are ignored.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecorationSpan {
+    /// The byte range of the decorated text.
+    pub range: Range<usize>,
+    /// The decoration.
+    pub decoration: TextDecoration,
+}
+
+impl DecorationSpan {
+    /// Creates a decoration span for the given range.
+    pub fn new(range: Range<usize>, decoration: TextDecoration) -> Self {
+        Self { range, decoration }
+    }
+}
+
+/// Paints the highlights of `spans`, which should be done before the text itself.
+pub(crate) fn paint_highlights(
+    scene: &mut Scene,
+    layout: &Layout<BrushIndex>,
+    text: &str,
+    spans: &[DecorationSpan],
+) {
+    for span in spans {
+        let TextDecoration::Highlight(color) = span.decoration else {
+            continue;
+        };
+        for (rect, _) in span_geometry(layout, text, &span.range) {
+            fill_color(scene, &rect, color);
+        }
+    }
+}
+
+/// Paints the underlines of `spans`, which should be done after the text itself.
+pub(crate) fn paint_underlines(
+    scene: &mut Scene,
+    layout: &Layout<BrushIndex>,
+    text: &str,
+    spans: &[DecorationSpan],
+) {
+    for span in spans {
+        let TextDecoration::Underline { style, color } = span.decoration else {
+            continue;
+        };
+        for (rect, line_idx) in span_geometry(layout, text, &span.range) {
+            // We draw the line halfway through the descent, so that it doesn't touch the
+            // glyphs which sit on the baseline.
+            let y = layout.get(line_idx).map_or(rect.y1 - 1.5, |line| {
+                let metrics = line.metrics();
+                (metrics.baseline + metrics.descent / 2.).into()
+            });
+            paint_underline(scene, style, color, rect.x0, rect.x1, y);
+        }
+    }
+}
+
+/// Paints an underline from `x0` to `x1`, centered on `y`.
+fn paint_underline(
+    scene: &mut Scene,
+    style: UnderlineStyle,
+    color: Color,
+    x0: f64,
+    x1: f64,
+    y: f64,
+) {
+    match style {
+        UnderlineStyle::Solid => stroke(scene, &Line::new((x0, y), (x1, y)), color, 1.),
+        UnderlineStyle::Wavy => stroke(scene, &wavy_line(x0, x1, y), color, 1.),
+        UnderlineStyle::Dotted => {
+            const SPACING: f64 = 3.;
+            let mut x = x0 + 1.;
+            while x < x1 {
+                fill_color(scene, &Circle::new((x, y), 0.75), color);
+                x += SPACING;
+            }
+        }
+    }
+}
+
+/// Returns the rectangle of each line of the text in `range`, with the index of the line.
+fn span_geometry(
+    layout: &Layout<BrushIndex>,
+    text: &str,
+    range: &Range<usize>,
+) -> Vec<(Rect, usize)> {
+    if range.start >= range.end
+        || !text.is_char_boundary(range.start)
+        || !text.is_char_boundary(range.end)
+    {
+        return Vec::new();
+    }
+    let selection = Selection::new(
+        Cursor::from_byte_index(layout, range.start, Affinity::Downstream),
+        Cursor::from_byte_index(layout, range.end, Affinity::Upstream),
+    );
+    selection
+        .geometry(layout)
+        .into_iter()
+        .map(|(rect, line_idx)| (bounding_box_to_rect(rect), line_idx))
+        .collect()
+}
+
+/// Builds a wavy line from `x0` to `x1`, centered on `y`.
+fn wavy_line(x0: f64, x1: f64, y: f64) -> BezPath {
+    const HALF_WAVELENGTH: f64 = 2.;
+    const AMPLITUDE: f64 = 1.;
+
+    let mut path = BezPath::new();
+    path.move_to((x0, y));
+    let mut x = x0;
+    let mut up = true;
+    while x < x1 {
+        let next = (x + HALF_WAVELENGTH).min(x1);
+        let peak = if up { y - AMPLITUDE } else { y + AMPLITUDE };
+        path.quad_to(((x + next) / 2., peak), (next, y));
+        x = next;
+        up = !up;
+    }
+    path
+}
@@ -12,13 +12,14 @@ use crate::core::{
     AccessCtx, ArcStr, ChildrenIds, EventCtx, HasProperty, LayoutCtx, MeasureCtx, NewWidget,
     NoAction, PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PrePaintProps,
     PropertiesMut, PropertiesRef, RegisterCtx, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
-    WidgetPod, paint_background, paint_border, paint_box_shadow,
+    WidgetPod, paint_background, paint_background_image, paint_border, paint_box_shadow,
+    paint_elevation,
 };
 use crate::kurbo::{Axis, Point, Size};
 use crate::layout::{LayoutSize, LenReq};
 use crate::properties::{
-    CaretColor, ContentColor, FocusedBorderColor, LineBreaking, PlaceholderColor, SelectionColor,
-    UnfocusedSelectionColor,
+    CaretBlink, CaretColor, CaretWidth, ContentColor, FocusedBorderColor, LineBreaking,
+    PlaceholderColor, SelectionColor, UnfocusedSelectionColor,
 };
 use crate::widgets::{Label, TextArea};
 
@@ -148,7 +149,9 @@ impl TextInput {
     }
 }
 
+impl HasProperty<CaretBlink> for TextInput {}
 impl HasProperty<CaretColor> for TextInput {}
+impl HasProperty<CaretWidth> for TextInput {}
 impl HasProperty<PlaceholderColor> for TextInput {}
 impl HasProperty<SelectionColor> for TextInput {}
 impl HasProperty<UnfocusedSelectionColor> for TextInput {}
@@ -190,6 +193,20 @@ impl Widget for TextInput {
                 let mut text_area = Self::text_mut(&mut input);
                 text_area.insert_prop(color);
             });
+        } else if property_type == TypeId::of::<CaretWidth>() {
+            ctx.mutate_self_later(|mut input| {
+                let mut input = input.downcast::<Self>();
+                let width = *input.get_prop::<CaretWidth>();
+                let mut text_area = Self::text_mut(&mut input);
+                text_area.insert_prop(width);
+            });
+        } else if property_type == TypeId::of::<CaretBlink>() {
+            ctx.mutate_self_later(|mut input| {
+                let mut input = input.downcast::<Self>();
+                let blink = *input.get_prop::<CaretBlink>();
+                let mut text_area = Self::text_mut(&mut input);
+                text_area.insert_prop(blink);
+            });
         } else if property_type == TypeId::of::<SelectionColor>() {
             ctx.mutate_self_later(|mut input| {
                 let mut input = input.downcast::<Self>();
@@ -224,6 +241,18 @@ impl Widget for TextInput {
                     let mut text_area = Self::text_mut(&mut input);
                     text_area.insert_prop(color);
                 });
+                ctx.mutate_self_later(|mut input| {
+                    let mut input = input.downcast::<Self>();
+                    let width = *input.get_prop::<CaretWidth>();
+                    let mut text_area = Self::text_mut(&mut input);
+                    text_area.insert_prop(width);
+                });
+                ctx.mutate_self_later(|mut input| {
+                    let mut input = input.downcast::<Self>();
+                    let blink = *input.get_prop::<CaretBlink>();
+                    let mut text_area = Self::text_mut(&mut input);
+                    text_area.insert_prop(blink);
+                });
                 ctx.mutate_self_later(|mut input| {
                     let mut input = input.downcast::<Self>();
                     let color = *input.get_prop::<SelectionColor>();
@@ -313,8 +342,22 @@ impl Widget for TextInput {
             p.border_color = &fb.0;
         }
 
+        paint_elevation(
+            scene,
+            bbox,
+            p.elevation,
+            p.elevation_shadows,
+            p.corner_radius,
+        );
         paint_box_shadow(scene, bbox, p.box_shadow, p.corner_radius);
         paint_background(scene, bbox, p.background, p.border_width, p.corner_radius);
+        paint_background_image(
+            scene,
+            bbox,
+            p.background_image,
+            p.border_width,
+            p.corner_radius,
+        );
         paint_border(scene, bbox, p.border_color, p.border_width, p.corner_radius);
     }
 
@@ -350,6 +393,8 @@ impl Widget for TextInput {
 // TODO - Add more tests
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use masonry_testing::TestHarnessParams;
 
     use super::*;
@@ -357,7 +402,7 @@ mod tests {
     use crate::kurbo::Size;
     use crate::testing::{TestHarness, assert_render_snapshot};
     use crate::theme::test_property_set;
-    use crate::widgets::TextArea;
+    use crate::widgets::{SpellChecker, TextArea};
 
     const HARNESS_PARAMS: TestHarnessParams = {
         let mut params = TestHarnessParams::DEFAULT;
@@ -429,4 +474,25 @@ mod tests {
 
         assert_render_snapshot!(harness, "text_input_clip");
     }
+
+    #[test]
+    fn spelling_errors_are_underlined() {
+        struct Hello;
+
+        impl SpellChecker for Hello {
+            fn is_correct(&self, word: &str) -> bool {
+                word == "Hello"
+            }
+        }
+
+        let text_input = NewWidget::new(TextInput::from_text_area(
+            TextArea::new_editable("Hello wrold")
+                .with_style(StyleProperty::FontSize(14.0))
+                .with_spell_checker(Arc::new(Hello))
+                .with_auto_id(),
+        ));
+        let mut harness = TestHarness::create_with(test_property_set(), text_input, HARNESS_PARAMS);
+
+        assert_render_snapshot!(harness, "text_input_spelling");
+    }
 }
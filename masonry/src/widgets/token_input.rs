@@ -0,0 +1,795 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::mem;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::keyboard::{Key, KeyState, NamedKey};
+use crate::core::{
+    AccessCtx, AccessEvent, AllowRawMut, ChildrenIds, CursorIcon, EventCtx, LayoutCtx, MeasureCtx,
+    NewWidget, NoAction, PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PrePaintProps,
+    PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget,
+    WidgetId, WidgetMut, WidgetPod, paint_background, paint_background_image, paint_border,
+    paint_box_shadow, paint_elevation,
+};
+use crate::kurbo::{Axis, Line, Point, Rect, Size, Vec2};
+use crate::layout::{LayoutSize, LenReq};
+use crate::properties::FocusedBorderColor;
+use crate::theme;
+use crate::util::{fill, stroke};
+use crate::widgets::{InsertNewline, Label, TextArea};
+
+/// The characters which commit the entered text as a token by default.
+pub const DEFAULT_TOKEN_SEPARATORS: &[char] = &[',', ';', '\n'];
+
+/// The smallest width given to the text area before it wraps to a new line.
+const TEXT_MIN_WIDTH: f64 = 60.;
+
+/// A text input which turns the entered values into removable chips,
+/// such as email recipients or tags.
+///
+/// The entered text is committed as a token when the user types one of the
+/// [separators](Self::with_separators), presses Enter <kbd>↵</kbd>, or moves the focus
+/// out of the token input.
+/// Pasted text is split on the separators, and each part but the last becomes a token;
+/// the last part is left in the text area to be edited.
+/// Tokens are trimmed, and empty tokens are ignored.
+///
+/// A token is removed when the user clicks the cross of its chip, and pressing Backspace
+/// in the empty text area removes the last token.
+///
+/// The chips are laid out in rows followed by the text area, wrapping to a new line
+/// when they don't fit.
+///
+/// This widget sends a [`TokensChanged`] action with all the tokens whenever they change.
+/// The inner text area sends the [`TextAction`](crate::widgets::TextAction)s for the text
+/// which hasn't been committed yet.
+/// Its ID can be accessed using [`area_id`](Self::area_id).
+pub struct TokenInput {
+    tokens: Vec<String>,
+    chips: Vec<WidgetPod<Chip>>,
+    text: WidgetPod<TextArea<true>>,
+    separators: Vec<char>,
+}
+
+/// The chip showing one token of a [`TokenInput`], with a button to remove it.
+struct Chip {
+    label: WidgetPod<Label>,
+    /// Whether the remove button was clicked since the token input last checked.
+    remove_clicked: bool,
+}
+
+// --- MARK: BUILDERS
+impl TokenInput {
+    /// Creates a new `TokenInput` without any tokens.
+    pub fn new() -> Self {
+        Self::from_text_area(TextArea::new_editable("").with_auto_id())
+    }
+
+    /// Creates a new `TokenInput` from a styled text area.
+    ///
+    /// Word wrapping is disabled on the text area, as the token input wraps its chips instead,
+    /// and Enter <kbd>↵</kbd> inserts a newline, which commits the text if it is a separator.
+    pub fn from_text_area(mut text: NewWidget<TextArea<true>>) -> Self {
+        text.widget = Box::new(
+            (*text.widget)
+                .with_word_wrap(false)
+                .with_insert_newline(InsertNewline::OnEnter),
+        );
+        Self {
+            tokens: Vec::new(),
+            chips: Vec::new(),
+            text: text.to_pod(),
+            separators: DEFAULT_TOKEN_SEPARATORS.to_vec(),
+        }
+    }
+
+    /// Sets the initial tokens.
+    ///
+    /// To modify this on an active token input, use [`set_tokens`](Self::set_tokens).
+    pub fn with_tokens(mut self, tokens: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.tokens = tokens.into_iter().map(Into::into).collect();
+        self.chips = self.tokens.iter().map(|token| Chip::new(token)).collect();
+        self
+    }
+
+    /// Sets the characters which commit the entered text as a token.
+    ///
+    /// The default is [`DEFAULT_TOKEN_SEPARATORS`].
+    ///
+    /// To modify this on an active token input, use [`set_separators`](Self::set_separators).
+    pub fn with_separators(mut self, separators: impl Into<Vec<char>>) -> Self {
+        self.separators = separators.into();
+        self
+    }
+}
+
+impl Default for TokenInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// --- MARK: METHODS
+impl TokenInput {
+    /// Returns the committed tokens.
+    pub fn tokens(&self) -> &[String] {
+        &self.tokens
+    }
+
+    /// Returns the ID of the inner text area, which sends the text actions of this widget.
+    pub fn area_id(&self) -> WidgetId {
+        self.text.id()
+    }
+
+    fn remove_token(&mut self, ctx: &mut EventCtx<'_>, idx: usize) {
+        self.tokens.remove(idx);
+        ctx.remove_child(self.chips.remove(idx));
+        ctx.submit_action::<<Self as Widget>::Action>(TokensChanged(self.tokens.clone()));
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl TokenInput {
+    /// Edits the underlying text area.
+    ///
+    /// Used to modify most properties of the text.
+    pub fn text_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, TextArea<true>> {
+        this.ctx.get_mut(&mut this.widget.text)
+    }
+
+    /// Replaces the tokens.
+    ///
+    /// The runtime equivalent of [`with_tokens`](Self::with_tokens).
+    pub fn set_tokens(
+        this: &mut WidgetMut<'_, Self>,
+        tokens: impl IntoIterator<Item = impl Into<String>>,
+    ) {
+        for chip in this.widget.chips.drain(..) {
+            this.ctx.remove_child(chip);
+        }
+        this.widget.tokens = tokens.into_iter().map(Into::into).collect();
+        this.widget.chips = this.widget.tokens.iter().map(|t| Chip::new(t)).collect();
+        this.ctx.children_changed();
+    }
+
+    /// Sets the characters which commit the entered text as a token.
+    ///
+    /// The runtime equivalent of [`with_separators`](Self::with_separators).
+    pub fn set_separators(this: &mut WidgetMut<'_, Self>, separators: impl Into<Vec<char>>) {
+        this.widget.separators = separators.into();
+        this.ctx.request_layout();
+    }
+
+    /// Commits the entered text before its last separator as tokens,
+    /// or all the entered text if `commit_all` is true.
+    fn commit_text(this: &mut WidgetMut<'_, Self>, commit_all: bool) {
+        let text = Self::text_mut(this).widget.text().to_string();
+        let separators = this.widget.separators.as_slice();
+        let (committed, rest) = if commit_all {
+            (text.as_str(), "")
+        } else {
+            let Some(end) = text.rfind(separators) else {
+                return;
+            };
+            let separator_len = text[end..].chars().next().map_or(0, char::len_utf8);
+            (&text[..end], &text[end + separator_len..])
+        };
+        let new_tokens = committed
+            .split(separators)
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .map(String::from)
+            .collect::<Vec<_>>();
+        let rest = rest.trim_start().to_string();
+
+        if rest != text {
+            TextArea::reset_text(&mut Self::text_mut(this), &rest);
+        }
+        if !new_tokens.is_empty() {
+            this.widget
+                .chips
+                .extend(new_tokens.iter().map(|token| Chip::new(token)));
+            this.widget.tokens.extend(new_tokens);
+            this.ctx.children_changed();
+            let tokens = this.widget.tokens.clone();
+            this.ctx
+                .submit_action::<<Self as Widget>::Action>(TokensChanged(tokens));
+        }
+    }
+}
+
+/// The [action](Widget::Action) sent when the tokens of a [`TokenInput`] change.
+///
+/// It contains all the tokens, in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokensChanged(pub Vec<String>);
+
+// --- MARK: IMPL WIDGET
+impl Widget for TokenInput {
+    type Action = TokensChanged;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: None | Some(PointerButton::Primary),
+                ..
+            }) => {
+                // Like `TextInput`, we focus the text area when clicking anywhere in the field.
+                ctx.set_focus(self.text.id());
+            }
+            PointerEvent::Up(..) => {
+                let clicked = self
+                    .chips
+                    .iter_mut()
+                    .position(|chip| mem::take(&mut ctx.get_raw_mut(chip).0.remove_clicked));
+                if let Some(idx) = clicked {
+                    self.remove_token(ctx, idx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        // The text area doesn't handle Backspace when it is empty, so it bubbles up to us.
+        if let TextEvent::Keyboard(event) = event
+            && event.state == KeyState::Down
+            && event.key == Key::Named(NamedKey::Backspace)
+            && ctx.target() == self.text.id()
+            && !self.tokens.is_empty()
+        {
+            self.remove_token(ctx, self.tokens.len() - 1);
+            ctx.set_handled();
+        }
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if event.action != accesskit::Action::Click {
+            return;
+        }
+        let target = ctx.target();
+        if let Some(idx) = self.chips.iter().position(|chip| chip.id() == target) {
+            self.remove_token(ctx, idx);
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for chip in &mut self.chips {
+            ctx.register_child(chip);
+        }
+        ctx.register_child(&mut self.text);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            // As in `TextInput`, we show a focus border while the text area is focused.
+            Update::ChildFocusChanged(has_focus) => {
+                ctx.request_pre_paint();
+                if !has_focus {
+                    ctx.mutate_self_later(|mut input| {
+                        Self::commit_text(&mut input.downcast(), true);
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let text_height = ctx.compute_length(
+            &mut self.text,
+            LenReq::MaxContent.into(),
+            LayoutSize::NONE,
+            Axis::Vertical,
+            None,
+        );
+        let sizes = item_sizes(&mut self.chips, text_height, |chip, axis| {
+            ctx.compute_length(
+                chip,
+                LenReq::MaxContent.into(),
+                LayoutSize::NONE,
+                axis,
+                None,
+            )
+        });
+
+        let gap = chip_gap();
+        match (axis, len_req) {
+            (Axis::Horizontal, LenReq::MinContent) => {
+                sizes.iter().map(|size| size.width).fold(0., f64::max)
+            }
+            (Axis::Horizontal, LenReq::MaxContent) => {
+                sizes.iter().map(|size| size.width).sum::<f64>() + gap * self.chips.len() as f64
+            }
+            // Like `TextInput`, we use all the offered width.
+            (Axis::Horizontal, LenReq::FitContent(space)) => space,
+            (Axis::Vertical, _) => flow(&sizes, cross_length.unwrap_or(f64::INFINITY), gap).height,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        // Edits of the text area request a layout, so this is where we notice the separators.
+        // As in `TextInput`, we read the text of the child directly.
+        let (text_area, _) = ctx.get_raw(&mut self.text);
+        if !text_area.is_composing()
+            && text_area
+                .text()
+                .to_string()
+                .contains(self.separators.as_slice())
+        {
+            ctx.mutate_self_later(|mut input| {
+                Self::commit_text(&mut input.downcast(), false);
+            });
+        }
+
+        let text_height = ctx.compute_length(
+            &mut self.text,
+            LenReq::MaxContent.into(),
+            LayoutSize::NONE,
+            Axis::Vertical,
+            None,
+        );
+        let sizes = item_sizes(&mut self.chips, text_height, |chip, axis| {
+            ctx.compute_length(
+                chip,
+                LenReq::MaxContent.into(),
+                LayoutSize::NONE,
+                axis,
+                None,
+            )
+        });
+
+        let flow = flow(&sizes, size.width, chip_gap());
+        for (chip, rect) in self.chips.iter_mut().zip(&flow.rects) {
+            ctx.run_layout(chip, rect.size());
+            ctx.place_child(chip, rect.origin());
+        }
+        let text_rect = flow.rects[self.chips.len()];
+        ctx.run_layout(&mut self.text, text_rect.size());
+        ctx.place_child(&mut self.text, text_rect.origin());
+
+        // The text area is on the last line, so we use its baseline.
+        let text_baseline = ctx.child_baseline_offset(&self.text);
+        ctx.set_baseline_offset(text_baseline + size.height - text_rect.max_y());
+        ctx.set_clip_path(size.to_rect());
+    }
+
+    fn pre_paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let bbox = ctx.border_box();
+        let mut p = PrePaintProps::fetch(ctx, props);
+
+        // We want to show a focus border if our child TextArea is focused
+        if ctx.has_focus_target()
+            && let Some(fb) = props.get_defined::<FocusedBorderColor>()
+        {
+            p.border_color = &fb.0;
+        }
+
+        paint_elevation(
+            scene,
+            bbox,
+            p.elevation,
+            p.elevation_shadows,
+            p.corner_radius,
+        );
+        paint_box_shadow(scene, bbox, p.box_shadow, p.corner_radius);
+        paint_background(scene, bbox, p.background, p.border_width, p.corner_radius);
+        paint_background_image(
+            scene,
+            bbox,
+            p.background_image,
+            p.border_width,
+            p.corner_radius,
+        );
+        paint_border(scene, bbox, p.border_color, p.border_width, p.corner_radius);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::List
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.chips
+            .iter()
+            .map(|chip| chip.id())
+            .chain([self.text.id()])
+            .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("TokenInput", id = id.trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(self.tokens.join(", "))
+    }
+}
+
+fn chip_gap() -> f64 {
+    // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
+    //       https://github.com/linebender/xilem/issues/1264
+    let scale = 1.0;
+    theme::WIDGET_CONTROL_COMPONENT_PADDING.dp(scale)
+}
+
+/// Returns the sizes of the chips, followed by the smallest size of the text area.
+///
+/// Chips always get their preferred size, which is measured with `length`.
+fn item_sizes(
+    chips: &mut [WidgetPod<Chip>],
+    text_height: f64,
+    mut length: impl FnMut(&mut WidgetPod<Chip>, Axis) -> f64,
+) -> Vec<Size> {
+    chips
+        .iter_mut()
+        .map(|chip| Size::new(length(chip, Axis::Horizontal), length(chip, Axis::Vertical)))
+        .chain([Size::new(TEXT_MIN_WIDTH, text_height)])
+        .collect()
+}
+
+/// The result of [`flow`].
+struct Flow {
+    /// The rectangles of the chips, followed by the rectangle of the text area.
+    rects: Vec<Rect>,
+    height: f64,
+}
+
+/// Lays out items with the given sizes in rows of `width`, wrapping when they don't fit.
+///
+/// The last item is the text area, which fills the rest of its row.
+/// Items are vertically centered in their row.
+fn flow(sizes: &[Size], width: f64, gap: f64) -> Flow {
+    fn center_row(row: &mut [Rect], row_height: f64) {
+        for rect in row {
+            *rect = *rect + Vec2::new(0., (row_height - rect.height()) / 2.);
+        }
+    }
+
+    let mut rects: Vec<Rect> = Vec::with_capacity(sizes.len());
+    let mut row_start = 0;
+    let mut row_y = 0.;
+    let mut row_height: f64 = 0.;
+    let mut x = 0.;
+    for (idx, size) in sizes.iter().enumerate() {
+        if x > 0. && x + size.width > width {
+            center_row(&mut rects[row_start..], row_height);
+            row_start = idx;
+            row_y += row_height + gap;
+            row_height = 0.;
+            x = 0.;
+        }
+        let is_text = idx == sizes.len() - 1;
+        let item_width = if is_text && width.is_finite() {
+            (width - x).max(0.)
+        } else {
+            size.width.min(width)
+        };
+        rects.push(Rect::from_origin_size(
+            (x, row_y),
+            (item_width, size.height),
+        ));
+        x += item_width + gap;
+        row_height = row_height.max(size.height);
+    }
+    center_row(&mut rects[row_start..], row_height);
+
+    Flow {
+        rects,
+        height: row_y + row_height,
+    }
+}
+
+// --- MARK: CHIP
+impl Chip {
+    /// The horizontal padding around the label.
+    const PADDING: f64 = 8.;
+    /// The vertical padding around the label.
+    const V_PADDING: f64 = 2.;
+    /// The side of the cross of the remove button.
+    const CROSS_SIDE: f64 = 8.;
+
+    fn new(token: &str) -> WidgetPod<Self> {
+        let chip = Self {
+            label: Label::new(token).with_auto_id().to_pod(),
+            remove_clicked: false,
+        };
+        NewWidget::new(chip).to_pod()
+    }
+
+    /// Returns the rectangle of the painted cross.
+    fn cross(size: Size) -> Rect {
+        let x = size.width - Self::PADDING - Self::CROSS_SIDE;
+        let y = (size.height - Self::CROSS_SIDE) / 2.;
+        Rect::from_origin_size((x, y), (Self::CROSS_SIDE, Self::CROSS_SIDE))
+    }
+
+    /// Returns whether `pos` hits the remove button.
+    ///
+    /// The hit area is larger than the cross, to be easier to hit.
+    fn hits_remove_button(size: Size, pos: Point) -> bool {
+        Self::cross(size).inflate(4., 4.).contains(pos)
+    }
+
+    /// The width of the chip around its label.
+    fn extra_width() -> f64 {
+        2. * Self::PADDING + chip_gap() + Self::CROSS_SIDE
+    }
+}
+
+// The token input checks whether the remove button of its chips was clicked.
+impl AllowRawMut for Chip {}
+
+impl Widget for Chip {
+    type Action = NoAction;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(PointerButtonEvent { state, .. }) => {
+                let pos = ctx.local_position(state.position);
+                if Self::hits_remove_button(ctx.content_box_size(), pos) {
+                    ctx.capture_pointer();
+                }
+            }
+            PointerEvent::Up(PointerButtonEvent { state, .. }) if ctx.is_active() => {
+                let pos = ctx.local_position(state.position);
+                if Self::hits_remove_button(ctx.content_box_size(), pos) {
+                    self.remove_clicked = true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.label);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::HoveredChanged(_) | Update::ActiveChanged(_) => {
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        // Chips are always as large as their token.
+        let label_length = ctx.compute_length(
+            &mut self.label,
+            LenReq::MaxContent.into(),
+            LayoutSize::NONE,
+            axis,
+            None,
+        );
+        match axis {
+            Axis::Horizontal => label_length + Self::extra_width(),
+            Axis::Vertical => label_length.max(Self::CROSS_SIDE) + 2. * Self::V_PADDING,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let label_size = Size::new(
+            (size.width - Self::extra_width()).max(0.),
+            (size.height - 2. * Self::V_PADDING).max(0.),
+        );
+        ctx.run_layout(&mut self.label, label_size);
+        ctx.place_child(&mut self.label, Point::new(Self::PADDING, Self::V_PADDING));
+
+        let label_baseline = ctx.child_baseline_offset(&self.label);
+        ctx.set_baseline_offset(label_baseline + Self::V_PADDING);
+        // The label is clipped if the chip is wider than the token input.
+        ctx.set_clip_path(size.to_rect());
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let size = ctx.content_box_size();
+        let background = size.to_rect().to_rounded_rect(size.height / 2.);
+        fill(scene, &background, theme::ZYNC_700);
+
+        let color = if ctx.is_disabled() {
+            theme::DISABLED_TEXT_COLOR
+        } else if ctx.is_hovered() {
+            theme::FOCUS_COLOR
+        } else {
+            theme::TEXT_COLOR
+        };
+        let cross = Self::cross(size);
+        for line in [
+            Line::new((cross.x0, cross.y0), (cross.x1, cross.y1)),
+            Line::new((cross.x1, cross.y0), (cross.x0, cross.y1)),
+        ] {
+            stroke(scene, &line, color, 1.5);
+        }
+    }
+
+    fn get_cursor(&self, ctx: &QueryCtx<'_>, pos: Point) -> CursorIcon {
+        if Self::hits_remove_button(ctx.content_box_size(), ctx.to_local(pos)) {
+            CursorIcon::Pointer
+        } else {
+            CursorIcon::Default
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::ListItem
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        // Clicking the chip with assistive technologies removes its token.
+        node.add_action(accesskit::Action::Click);
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.label.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Chip", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{TestHarness, TestHarnessParams, assert_render_snapshot};
+    use crate::theme::test_property_set;
+
+    const HARNESS_PARAMS: TestHarnessParams = {
+        let mut params = TestHarnessParams::DEFAULT;
+        params.window_size = Size::new(200.0, 90.0);
+        params.root_padding = 8;
+        params
+    };
+
+    fn harness(input: TokenInput) -> TestHarness<TokenInput> {
+        TestHarness::create_with(test_property_set(), NewWidget::new(input), HARNESS_PARAMS)
+    }
+
+    fn last_tokens(harness: &mut TestHarness<TokenInput>) -> Option<Vec<String>> {
+        // The text area also sends text actions, which we skip.
+        let mut last_tokens = None;
+        while let Some((action, _)) = harness.pop_action_erased() {
+            if let Ok(action) = action.downcast::<TokensChanged>() {
+                last_tokens = Some(action.0);
+            }
+        }
+        last_tokens
+    }
+
+    #[test]
+    fn separators_and_paste_commit_tokens() {
+        let mut harness = harness(TokenInput::new());
+        let area_id = harness.root_widget().area_id();
+        harness.focus_on(Some(area_id));
+
+        harness.keyboard_type_chars("ada@example.com,");
+        assert_eq!(
+            last_tokens(&mut harness),
+            Some(vec!["ada@example.com".into()])
+        );
+        let area = harness.get_widget_with_id(area_id);
+        assert!(
+            area.downcast::<TextArea<true>>()
+                .unwrap()
+                .inner()
+                .is_empty()
+        );
+
+        // The text after the last separator is left to be edited.
+        harness.process_text_event(TextEvent::ClipboardPaste(" bob ; carol\nda".into()));
+        assert_eq!(
+            harness.root_widget().tokens(),
+            ["ada@example.com", "bob", "carol"]
+        );
+        assert!(last_tokens(&mut harness).is_some());
+        let area = harness.get_widget_with_id(area_id);
+        let area = area.downcast::<TextArea<true>>().unwrap();
+        assert_eq!(area.inner().text().to_string(), "da");
+
+        harness.keyboard_type_chars("ve");
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::Enter)));
+        assert_eq!(harness.root_widget().tokens().len(), 4);
+        assert!(last_tokens(&mut harness).is_some());
+
+        // Separators alone don't create empty tokens.
+        harness.keyboard_type_chars(" ;");
+        assert_eq!(last_tokens(&mut harness), None);
+
+        // The remaining text is committed when the focus leaves.
+        harness.keyboard_type_chars("eve");
+        harness.focus_on(None);
+        assert_eq!(harness.root_widget().tokens()[4], "eve");
+    }
+
+    #[test]
+    fn backspace_removes_last_token() {
+        let mut harness = harness(TokenInput::new().with_tokens(["rust", "ui"]));
+        let area_id = harness.root_widget().area_id();
+        harness.focus_on(Some(area_id));
+
+        // Backspace only removes a token once the text was already empty.
+        harness.keyboard_type_chars("x");
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::Backspace)));
+        assert_eq!(last_tokens(&mut harness), None);
+
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::Backspace)));
+        assert_eq!(last_tokens(&mut harness), Some(vec!["rust".into()]));
+    }
+
+    #[test]
+    fn chips_wrap_and_can_be_removed() {
+        let input = TokenInput::new().with_tokens(["alpha", "beta", "gamma", "delta"]);
+        let mut harness = harness(input);
+
+        assert_render_snapshot!(harness, "token_input_wrapped");
+
+        // Click the cross of the first chip.
+        let chip = harness.root_widget().children()[0];
+        let chip_id = chip.id();
+        let size = chip.ctx().content_box_size();
+        let cross_center = chip.ctx().window_origin() + Chip::cross(size).center().to_vec2();
+        harness.mouse_move(cross_center);
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(
+            last_tokens(&mut harness),
+            Some(vec!["beta".into(), "gamma".into(), "delta".into()])
+        );
+        assert!(harness.try_get_widget(chip_id).is_none());
+    }
+}
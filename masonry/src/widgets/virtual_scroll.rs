@@ -6,8 +6,6 @@
 use std::collections::HashMap;
 use std::ops::Range;
 
-use dpi::PhysicalPosition;
-
 use crate::core::keyboard::{Key, KeyState, NamedKey};
 use crate::core::{
     AccessCtx, AccessEvent, ChildrenIds, ComposeCtx, EventCtx, KeyboardEvent, LayoutCtx,
@@ -535,22 +533,16 @@ impl Widget for VirtualScroll {
         event: &PointerEvent,
     ) {
         match event {
-            PointerEvent::Scroll(PointerScrollEvent { delta, .. }) => {
-                let size = ctx.content_box_size();
-                // TODO - Remove reference to scale factor.
-                // See https://github.com/linebender/xilem/issues/1264
-                let scale_factor = ctx.get_scale_factor();
-                let line_px = PhysicalPosition {
-                    x: 120.0 * scale_factor,
-                    y: 120.0 * scale_factor,
-                };
-                let page_px = PhysicalPosition {
-                    x: size.width * scale_factor,
-                    y: size.height * scale_factor,
-                };
-
-                let delta_px = delta.to_pixel_delta(line_px, page_px);
-                let delta = -delta_px.to_logical::<f64>(scale_factor).y;
+            PointerEvent::Scroll(PointerScrollEvent { delta, state, .. }) => {
+                let delta = -ctx
+                    .scroll_config()
+                    .logical_delta(
+                        *delta,
+                        ctx.content_box_size(),
+                        ctx.get_scale_factor(),
+                        state.modifiers,
+                    )
+                    .y;
                 self.scroll_offset_from_anchor += delta;
                 self.event_post_scroll(ctx);
             }
@@ -636,7 +628,7 @@ impl Widget for VirtualScroll {
 
     fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
         match event {
-            Update::RequestPanToChild(target) => {
+            Update::RequestPanToChild(target) | Update::RequestPanToFocusedChild(target) => {
                 let new_pos_y = super::portal::compute_pan_range(
                     0.0..ctx.content_box_size().height,
                     target.min_y()..target.max_y(),
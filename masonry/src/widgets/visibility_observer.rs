@@ -0,0 +1,217 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::mem;
+
+use crate::core::{
+    AccessCtx, ChildrenIds, ComposeCtx, LayoutCtx, MeasureCtx, NewWidget, PaintCtx, PropertiesMut,
+    PropertiesRef, RegisterCtx, Update, UpdateCtx, Widget, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::LenReq;
+
+/// A widget which sends a [`VisibilityChanged`] when it enters or leaves the viewport
+/// of its ancestors, such as a [`Portal`](crate::widgets::Portal).
+///
+/// The widget is visible when part of it is inside the [viewport](ComposeCtx::viewport)
+/// and it isn't stashed.
+/// Without any ancestor viewport, it is visible as long as it isn't stashed.
+///
+/// It starts out hidden, so the first action is sent when it first becomes visible.
+///
+/// It reports the child's length as its own in [`measure`], syncing its size with the child's.
+///
+/// This can be used to lazy-load content when it is scrolled into view,
+/// to pause animations which can't be seen, or to log impressions.
+///
+/// [`measure`]: Widget::measure
+pub struct VisibilityObserver {
+    child: WidgetPod<dyn Widget>,
+    is_visible: bool,
+}
+
+// --- MARK: BUILDERS
+impl VisibilityObserver {
+    /// Creates a new visibility observer wrapping `child`.
+    pub fn new(child: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            child: child.erased().to_pod(),
+            is_visible: false,
+        }
+    }
+}
+
+// --- MARK: METHODS
+impl VisibilityObserver {
+    /// Returns whether this widget was visible the last time it was composed.
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl VisibilityObserver {
+    /// Replaces the child widget with a new one.
+    pub fn set_child(this: &mut WidgetMut<'_, Self>, child: NewWidget<impl Widget + ?Sized>) {
+        let old_child = mem::replace(&mut this.widget.child, child.erased().to_pod());
+        this.ctx.remove_child(old_child);
+    }
+
+    /// Returns mutable reference to the child widget.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+}
+
+/// The [action](Widget::Action) sent when a [`VisibilityObserver`] enters or leaves the viewport.
+///
+/// The value is `true` if the widget became visible, and `false` if it stopped being visible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VisibilityChanged(pub bool);
+
+// --- MARK: IMPL WIDGET
+impl Widget for VisibilityObserver {
+    type Action = VisibilityChanged;
+
+    fn accepts_pointer_interaction(&self) -> bool {
+        false
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::StashedChanged(_) = event {
+            ctx.request_compose();
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        ctx.redirect_measurement(&mut self.child, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.child, size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+
+        let child_baseline = ctx.child_baseline_offset(&self.child);
+        ctx.set_baseline_offset(child_baseline);
+    }
+
+    fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
+        let is_visible = ctx.is_in_viewport();
+        if is_visible != self.is_visible {
+            self.is_visible = is_visible;
+            ctx.submit_action::<Self::Action>(VisibilityChanged(is_visible));
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _scene: &mut vello::Scene,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> accesskit::Role {
+        accesskit::Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut accesskit::Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use masonry_testing::TestHarness;
+
+    use crate::core::{NewWidget, Widget, WidgetTag};
+    use crate::kurbo::{Point, Size};
+    use crate::layout::AsUnit;
+    use crate::properties::{Dimensions, Gap};
+    use crate::theme::default_property_set;
+    use crate::widgets::{Flex, Portal, SizedBox, VisibilityChanged, VisibilityObserver};
+
+    #[test]
+    fn reports_scrolling_in_and_out() {
+        let inner_box = SizedBox::empty()
+            .width(100.px())
+            .height(100.px())
+            .with_auto_id();
+        let observer = VisibilityObserver::new(inner_box).with_props(Dimensions::MAX);
+        let observer_id = observer.id();
+        let flex = Flex::column()
+            .with_fixed(SizedBox::empty().height(300.px()).with_auto_id())
+            .with_fixed(observer)
+            .with_fixed(SizedBox::empty().height(300.px()).with_auto_id())
+            .with_props(Gap::ZERO);
+        let portal_tag = WidgetTag::named("portal");
+        let portal = NewWidget::new_with_tag(Portal::new(flex), portal_tag);
+        let mut harness =
+            TestHarness::create_with_size(default_property_set(), portal, Size::new(200., 200.));
+
+        // The observer starts below the viewport.
+        assert!(harness.pop_action::<VisibilityChanged>().is_none());
+
+        // Only touching the edge of the viewport doesn't count.
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::set_viewport_pos(&mut portal, Point::new(0., 100.));
+        });
+        assert!(harness.pop_action::<VisibilityChanged>().is_none());
+
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::set_viewport_pos(&mut portal, Point::new(0., 150.));
+        });
+        let (action, action_id) = harness.pop_action::<VisibilityChanged>().unwrap();
+        assert_eq!(action, VisibilityChanged(true));
+        assert_eq!(action_id, observer_id);
+        assert!(harness.pop_action::<VisibilityChanged>().is_none());
+
+        // Scrolling while staying in view.
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::set_viewport_pos(&mut portal, Point::new(0., 250.));
+        });
+        assert!(harness.pop_action::<VisibilityChanged>().is_none());
+
+        harness.edit_widget(portal_tag, |mut portal| {
+            Portal::set_viewport_pos(&mut portal, Point::new(0., 450.));
+        });
+        let (action, _) = harness.pop_action::<VisibilityChanged>().unwrap();
+        assert_eq!(action, VisibilityChanged(false));
+        assert!(harness.pop_action::<VisibilityChanged>().is_none());
+    }
+
+    #[test]
+    fn visible_without_viewport() {
+        let tag = WidgetTag::named("observer");
+        let observer = NewWidget::new_with_tag(
+            VisibilityObserver::new(SizedBox::empty().with_auto_id()),
+            tag,
+        );
+        let mut harness = TestHarness::create(default_property_set(), observer);
+
+        let (action, _) = harness.pop_action::<VisibilityChanged>().unwrap();
+        assert_eq!(action, VisibilityChanged(true));
+        assert!(harness.get_widget(tag).inner().is_visible());
+        assert!(harness.pop_action::<VisibilityChanged>().is_none());
+    }
+}
@@ -0,0 +1,585 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+use std::ops::Range;
+
+use accesskit::{Node, Role};
+use include_doc_path::include_doc_path;
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ChildrenIds, CollectionWidget, HasProperty, LayoutCtx, MeasureCtx, NewWidget,
+    PaintCtx, PropertiesRef, RegisterCtx, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Rect, Size};
+use crate::layout::{LayoutSize, LenDef, LenReq};
+use crate::properties::Gap;
+
+/// The action emitted by [`Waterfall`] when the range of items which aren't
+/// stashed changes, because of its [viewport](Waterfall::set_viewport).
+///
+/// This contains the range of indices from the first to the last item
+/// intersecting the viewport.
+/// When it gets close to the end of the items, it's usually time to
+/// load more of them.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct VisibleItemsChanged(pub Range<usize>);
+
+/// A layout container which packs children of varying heights into columns,
+/// like a "waterfall" or Pinterest-style feed.
+///
+/// All columns have the same width, and each child is given the full width of a column,
+/// with its preferred height.
+/// Children are placed in order, each in the column which is currently the shortest,
+/// which keeps the columns at similar heights.
+/// As children are only ever placed after the ones before them, appending children
+/// doesn't move the existing ones, and only the new children need to be measured.
+///
+/// The space between columns and between the children in a column is set
+/// with the [`Gap`] property.
+///
+/// For very long feeds, you can [set the viewport](Self::set_viewport), i.e. the part of
+/// the waterfall which is visible, usually because it is inside a [`Portal`](super::Portal).
+/// Children outside of the viewport are then [stashed], and keep the height they had
+/// when they were last laid out.
+/// The waterfall emits [`VisibleItemsChanged`] whenever the range of items in the
+/// viewport changes.
+///
+#[doc = concat!(
+    "![Waterfall with boxes of various heights](",
+    include_doc_path!("screenshots/waterfall_three_columns.png"),
+    ")",
+)]
+///
+/// [stashed]: crate::core::MutateCtx::set_stashed
+pub struct Waterfall {
+    items: Vec<Item>,
+    column_count: usize,
+    viewport: Option<Rect>,
+    /// The column width the items' heights were measured with.
+    column_width: f64,
+    visible: Range<usize>,
+}
+
+struct Item {
+    widget: WidgetPod<dyn Widget>,
+    /// The border-box height of the child, when it was last measured.
+    height: f64,
+    /// Whether `height` was measured with the current column width.
+    measured: bool,
+    stashed: bool,
+}
+
+impl Item {
+    fn new(child: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            widget: child.erased().to_pod(),
+            height: 0.,
+            measured: false,
+            stashed: false,
+        }
+    }
+}
+
+// --- MARK: BUILDERS
+impl Waterfall {
+    /// Creates a new waterfall with the given number of columns.
+    ///
+    /// There is always at least one column.
+    pub fn new(column_count: usize) -> Self {
+        Self {
+            items: Vec::new(),
+            column_count: column_count.max(1),
+            viewport: None,
+            column_width: 0.,
+            visible: 0..0,
+        }
+    }
+
+    /// Builder-style method to add a child widget.
+    pub fn with(mut self, child: NewWidget<impl Widget + ?Sized>) -> Self {
+        self.items.push(Item::new(child));
+        self
+    }
+
+    /// Builder-style method to set the visible part of the waterfall.
+    ///
+    /// See [`set_viewport`](Self::set_viewport) for details.
+    pub fn with_viewport(mut self, viewport: Rect) -> Self {
+        self.viewport = Some(viewport);
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl Waterfall {
+    /// Returns the number of columns.
+    pub fn column_count(&self) -> usize {
+        self.column_count
+    }
+
+    /// Returns the range of items which were in the viewport at the last layout.
+    ///
+    /// Without a viewport, this contains all items.
+    pub fn visible_items(&self) -> Range<usize> {
+        self.visible.clone()
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Waterfall {
+    /// Sets the number of columns.
+    ///
+    /// There is always at least one column.
+    pub fn set_column_count(this: &mut WidgetMut<'_, Self>, column_count: usize) {
+        this.widget.column_count = column_count.max(1);
+        this.ctx.request_layout();
+    }
+
+    /// Sets the visible part of the waterfall, in its content-box coordinate space.
+    ///
+    /// Children which don't intersect the viewport vertically are stashed, so they
+    /// aren't laid out or painted.
+    /// The viewport should usually extend a bit beyond what's actually visible,
+    /// so that children are already laid out when they're scrolled into view.
+    ///
+    /// If `None`, which is the default, all children are laid out.
+    pub fn set_viewport(this: &mut WidgetMut<'_, Self>, viewport: Option<Rect>) {
+        if this.widget.viewport != viewport {
+            this.widget.viewport = viewport;
+            this.ctx.request_layout();
+        }
+    }
+}
+
+// --- MARK: COLLECTIONWIDGET
+impl CollectionWidget<()> for Waterfall {
+    /// Returns the number of children.
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if there are no children.
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns a mutable reference to the child widget at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn get_mut<'t>(this: &'t mut WidgetMut<'_, Self>, idx: usize) -> WidgetMut<'t, dyn Widget> {
+        let child = &mut this.widget.items[idx].widget;
+        this.ctx.get_mut(child)
+    }
+
+    /// Appends a child widget to the collection.
+    fn add(
+        this: &mut WidgetMut<'_, Self>,
+        child: NewWidget<impl Widget + ?Sized>,
+        _params: impl Into<()>,
+    ) {
+        this.widget.items.push(Item::new(child));
+        this.ctx.children_changed();
+    }
+
+    /// Inserts a child widget at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the number of children.
+    fn insert(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        child: NewWidget<impl Widget + ?Sized>,
+        _params: impl Into<()>,
+    ) {
+        this.widget.items.insert(idx, Item::new(child));
+        this.ctx.children_changed();
+    }
+
+    /// Replaces the child widget at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn set(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        child: NewWidget<impl Widget + ?Sized>,
+        _params: impl Into<()>,
+    ) {
+        let old_item = std::mem::replace(&mut this.widget.items[idx], Item::new(child));
+        this.ctx.remove_child(old_item.widget);
+    }
+
+    /// Not applicable.
+    fn set_params(_this: &mut WidgetMut<'_, Self>, _idx: usize, _params: impl Into<()>) {}
+
+    /// Swaps the index of two children.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    fn swap(this: &mut WidgetMut<'_, Self>, a: usize, b: usize) {
+        this.widget.items.swap(a, b);
+        this.ctx.children_changed();
+    }
+
+    /// Removes the child at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn remove(this: &mut WidgetMut<'_, Self>, idx: usize) {
+        let item = this.widget.items.remove(idx);
+        this.ctx.remove_child(item.widget);
+    }
+
+    /// Removes all children.
+    fn clear(this: &mut WidgetMut<'_, Self>) {
+        for item in this.widget.items.drain(..) {
+            this.ctx.remove_child(item.widget);
+        }
+    }
+}
+
+// --- MARK: HELPERS
+impl Waterfall {
+    /// Returns the width of each column, given the width of the waterfall.
+    fn column_width(&self, width: f64, gap: f64) -> f64 {
+        let columns = self.column_count as f64;
+        ((width - gap * (columns - 1.)) / columns).max(0.)
+    }
+
+    /// Returns whether an item at `y` with the given `height` is in the viewport.
+    fn in_viewport(&self, y: f64, height: f64) -> bool {
+        self.viewport
+            .is_none_or(|viewport| y < viewport.y1 && y + height >= viewport.y0)
+    }
+}
+
+/// Returns the index of the shortest column, preferring the leftmost one.
+fn shortest_column(column_ends: &[f64]) -> usize {
+    let mut shortest = 0;
+    for (column, end) in column_ends.iter().enumerate() {
+        if *end < column_ends[shortest] {
+            shortest = column;
+        }
+    }
+    shortest
+}
+
+/// Returns the height of the tallest column, given where the next item would go in each column.
+fn content_height(column_ends: &[f64], gap: f64) -> f64 {
+    let end = column_ends.iter().copied().fold(0., f64::max);
+    (end - gap).max(0.)
+}
+
+impl HasProperty<Gap> for Waterfall {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Waterfall {
+    type Action = VisibleItemsChanged;
+
+    fn accepts_pointer_interaction(&self) -> bool {
+        false
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for item in self.items.iter_mut() {
+            ctx.register_child(&mut item.widget);
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        Gap::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
+        //       https://github.com/linebender/xilem/issues/1264
+        let scale = 1.0;
+
+        let gap = props.get::<Gap>().gap.dp(scale);
+        let columns = self.column_count as f64;
+
+        let mut measure_width = |ctx: &mut MeasureCtx<'_>, len_req: LenReq| {
+            let auto_length = len_req.into();
+            let mut column_width: f64 = 0.;
+            for item in self.items.iter_mut().filter(|item| !item.stashed) {
+                let width = ctx.compute_length(
+                    &mut item.widget,
+                    auto_length,
+                    LayoutSize::NONE,
+                    Axis::Horizontal,
+                    None,
+                );
+                column_width = column_width.max(width);
+            }
+            columns * column_width + gap * (columns - 1.)
+        };
+
+        match axis {
+            Axis::Horizontal => match len_req {
+                LenReq::FitContent(space) => space,
+                LenReq::MinContent | LenReq::MaxContent => measure_width(ctx, len_req),
+            },
+            Axis::Vertical => {
+                let width = match cross_length {
+                    Some(width) => width,
+                    None => measure_width(ctx, LenReq::MaxContent),
+                };
+                let column_width = self.column_width(width, gap);
+                let context_size = LayoutSize::one(Axis::Horizontal, column_width);
+                let mut column_ends = vec![0.; self.column_count];
+                for item in &mut self.items {
+                    // Stashed items keep the height they were last laid out with.
+                    let height = if item.stashed {
+                        item.height
+                    } else {
+                        ctx.compute_length(
+                            &mut item.widget,
+                            LenDef::MaxContent,
+                            context_size,
+                            Axis::Vertical,
+                            Some(column_width),
+                        )
+                    };
+                    let column = shortest_column(&column_ends);
+                    column_ends[column] += height + gap;
+                }
+                content_height(&column_ends, gap)
+            }
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, props: &PropertiesRef<'_>, size: Size) {
+        // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
+        //       https://github.com/linebender/xilem/issues/1264
+        let scale = 1.0;
+
+        let gap = props.get::<Gap>().gap.dp(scale);
+        let column_width = self.column_width(size.width, gap);
+        if column_width != self.column_width {
+            self.column_width = column_width;
+            for item in &mut self.items {
+                item.measured = false;
+            }
+        }
+        let context_size = LayoutSize::one(Axis::Horizontal, column_width);
+
+        let mut column_ends = vec![0.; self.column_count];
+        let mut visible: Option<Range<usize>> = None;
+        for idx in 0..self.items.len() {
+            // An item's position only depends on the items before it,
+            // so we know where it goes before knowing its height.
+            let column = shortest_column(&column_ends);
+            let origin = Point::new(column as f64 * (column_width + gap), column_ends[column]);
+
+            let in_viewport = self.in_viewport(origin.y, self.items[idx].height);
+            let item = &mut self.items[idx];
+            if ctx.child_needs_layout(&item.widget) {
+                item.measured = false;
+            }
+            // Items which are stashed and stay out of the viewport keep their previous height.
+            if !item.measured && (!item.stashed || in_viewport) {
+                item.height = ctx.compute_length(
+                    &mut item.widget,
+                    LenDef::MaxContent,
+                    context_size,
+                    Axis::Vertical,
+                    Some(column_width),
+                );
+                item.measured = true;
+            }
+
+            let height = item.height;
+            let stashed = !self.in_viewport(origin.y, height);
+            let item = &mut self.items[idx];
+            if item.stashed != stashed {
+                item.stashed = stashed;
+                ctx.set_stashed(&mut item.widget, stashed);
+            }
+            if !stashed {
+                ctx.run_layout(&mut item.widget, Size::new(column_width, height));
+                ctx.place_child(&mut item.widget, origin);
+                let range = visible.get_or_insert(idx..idx);
+                range.end = idx + 1;
+            }
+            column_ends[column] += height + gap;
+        }
+
+        let visible = visible.unwrap_or(0..0);
+        if self.visible != visible {
+            self.visible = visible.clone();
+            if self.viewport.is_some() {
+                ctx.submit_action::<Self::Action>(VisibleItemsChanged(visible));
+            }
+        }
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.items.iter().map(|item| item.widget.id()).collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Waterfall", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::AsUnit;
+    use crate::palette;
+    use crate::peniko::Color;
+    use crate::properties::{Background, Dimensions};
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::SizedBox;
+
+    fn tile(height: f64) -> NewWidget<SizedBox> {
+        tile_with_color(height, palette::css::STEEL_BLUE)
+    }
+
+    fn tile_with_color(height: f64, color: Color) -> NewWidget<SizedBox> {
+        SizedBox::empty().with_props((Background::Color(color), Dimensions::height(height.px())))
+    }
+
+    fn origins(harness: &TestHarness<Waterfall>) -> Vec<Point> {
+        harness
+            .root_widget()
+            .children()
+            .iter()
+            .map(|child| child.ctx().window_origin())
+            .collect()
+    }
+
+    #[test]
+    fn packs_into_shortest_column() {
+        let waterfall = Waterfall::new(2)
+            .with(tile(50.))
+            .with(tile(20.))
+            .with(tile(20.))
+            .with(tile(20.));
+        let waterfall =
+            NewWidget::new_with_props(waterfall, (Gap::new(10.px()), Dimensions::STRETCH));
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), waterfall, Size::new(210., 200.));
+
+        assert_eq!(
+            origins(&harness),
+            [
+                Point::new(0., 0.),
+                Point::new(110., 0.),
+                Point::new(110., 30.),
+                Point::new(0., 60.),
+            ]
+        );
+        assert_eq!(
+            harness.root_widget().children()[0].ctx().border_box_size(),
+            Size::new(100., 50.)
+        );
+
+        // Appending doesn't move the existing items.
+        harness.edit_root_widget(|mut waterfall| {
+            Waterfall::add(&mut waterfall, tile(20.), ());
+        });
+        assert_eq!(
+            origins(&harness)[..4],
+            [
+                Point::new(0., 0.),
+                Point::new(110., 0.),
+                Point::new(110., 30.),
+                Point::new(0., 60.),
+            ]
+        );
+        assert_eq!(origins(&harness)[4], Point::new(110., 60.));
+
+        // Changing the number of columns repacks everything.
+        harness.edit_root_widget(|mut waterfall| {
+            Waterfall::set_column_count(&mut waterfall, 1);
+        });
+        assert_eq!(origins(&harness)[3], Point::new(0., 120.));
+    }
+
+    #[test]
+    fn viewport_stashes_items() {
+        let mut waterfall = Waterfall::new(1).with_viewport(Rect::new(0., 0., 100., 100.));
+        for _ in 0..10 {
+            waterfall = waterfall.with(tile(40.));
+        }
+        let waterfall = NewWidget::new_with_props(waterfall, (Gap::ZERO, Dimensions::STRETCH));
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), waterfall, Size::new(100., 400.));
+
+        assert_eq!(harness.root_widget().inner().visible_items(), 0..3);
+        let (action, _) = harness.pop_action::<VisibleItemsChanged>().unwrap();
+        assert_eq!(action, VisibleItemsChanged(0..3));
+        let stashed: Vec<bool> = harness
+            .root_widget()
+            .children()
+            .iter()
+            .map(|child| child.ctx().is_stashed())
+            .collect();
+        assert_eq!(stashed[..4], [false, false, false, true]);
+
+        harness.edit_root_widget(|mut waterfall| {
+            Waterfall::set_viewport(&mut waterfall, Some(Rect::new(0., 250., 100., 350.)));
+        });
+        let (action, _) = harness.pop_action::<VisibleItemsChanged>().unwrap();
+        assert_eq!(action, VisibleItemsChanged(6..9));
+        // Stashed items keep their place.
+        assert_eq!(origins(&harness)[6], Point::new(0., 240.));
+    }
+
+    #[test]
+    fn waterfall_screenshot() {
+        let colors = [
+            palette::css::ORANGE,
+            palette::css::TEAL,
+            palette::css::STEEL_BLUE,
+            palette::css::CRIMSON,
+            palette::css::GOLD,
+            palette::css::SEA_GREEN,
+            palette::css::SLATE_BLUE,
+            palette::css::CORAL,
+        ];
+        let heights = [60., 100., 40., 80., 50., 90., 70., 30.];
+        let mut waterfall = Waterfall::new(3);
+        for (color, height) in colors.into_iter().zip(heights) {
+            waterfall = waterfall.with(tile_with_color(height, color));
+        }
+        let waterfall = NewWidget::new_with_props(waterfall, Dimensions::STRETCH);
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), waterfall, Size::new(320., 240.));
+
+        assert_render_snapshot!(harness, "waterfall_three_columns");
+    }
+}
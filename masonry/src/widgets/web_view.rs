@@ -0,0 +1,345 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ChildrenIds, LayoutCtx, MeasureCtx, NoAction, PaintCtx, PropertiesMut,
+    PropertiesRef, RegisterCtx, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+};
+use crate::kurbo::{Axis, Rect, Size};
+use crate::layout::{LenReq, Length};
+
+/// The preferred width of a web view, matching the default size of an HTML `iframe`.
+const DEFAULT_WIDTH: Length = Length::const_px(300.);
+/// The preferred height of a web view.
+const DEFAULT_HEIGHT: Length = Length::const_px(150.);
+
+/// Something which happened in the page shown by a [`WebView`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WebViewEvent {
+    /// The page navigated to a new URL, either on its own or because of a [`WebViewCommand`].
+    Navigated(String),
+    /// The page's script sent a message to the app.
+    ///
+    /// How scripts send these depends on the backend, e.g. `window.ipc.postMessage` with wry.
+    Message(String),
+}
+
+/// The callback a [`WebViewBackend`] calls for each [`WebViewEvent`].
+///
+/// This may be called from any thread.
+pub type WebViewEventHandler = Box<dyn Fn(WebViewEvent) + Send + Sync + 'static>;
+
+/// An instruction for the page shown by a [`WebView`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WebViewCommand {
+    /// Navigates to the given URL.
+    LoadUrl(String),
+    /// Goes back to the previous page in the history, if any.
+    GoBack,
+    /// Goes forward to the next page in the history, if any.
+    GoForward,
+    /// Reloads the current page.
+    Reload,
+    /// Sends a message to the page's script.
+    ///
+    /// How scripts receive these depends on the backend.
+    PostMessage(String),
+}
+
+/// The platform web view embedded by a [`WebView`] widget.
+///
+/// Masonry doesn't bundle a browser engine, so this is implemented by apps on top of
+/// a library such as wry, or of the platform's APIs.
+/// The platform web view is usually a child window or view of the app's window,
+/// drawn above Masonry's content.
+///
+/// The platform web view should be destroyed when the backend is dropped, which happens
+/// when the `WebView` widget is removed from the tree.
+pub trait WebViewBackend: 'static {
+    /// Moves and resizes the platform web view.
+    ///
+    /// `bounds` is in logical pixels, in the coordinate space of the window's content area.
+    fn set_bounds(&mut self, bounds: Rect);
+
+    /// Shows or hides the platform web view.
+    fn set_visible(&mut self, visible: bool);
+
+    /// Sets the callback to be called for each event from the page.
+    ///
+    /// This replaces the previous callback, if any.
+    fn set_event_handler(&mut self, handler: WebViewEventHandler);
+
+    /// Runs a command on the page.
+    fn run_command(&mut self, command: &WebViewCommand);
+}
+
+/// A widget which embeds a web page, using a platform web view.
+///
+/// The page is shown by a [`WebViewBackend`], which is given the bounds of the widget
+/// in the window whenever they change, and hidden while the widget is [stashed].
+/// Events from the page, such as messages sent by its scripts, are delivered to the
+/// backend's [event handler](WebViewBackend::set_event_handler), rather than as actions.
+///
+/// As the backend's window is drawn above the Masonry window, the page doesn't respect
+/// clipping or the painting order of widgets, and can't be transformed.
+///
+/// [stashed]: crate::core::MutateCtx::set_stashed
+pub struct WebView {
+    backend: Box<dyn WebViewBackend>,
+    url: String,
+    pending_commands: Vec<WebViewCommand>,
+    bounds: Option<Rect>,
+}
+
+// --- MARK: BUILDERS
+impl WebView {
+    /// Creates a web view showing the page at `url`, using the given backend.
+    pub fn new(backend: impl WebViewBackend, url: impl Into<String>) -> Self {
+        Self {
+            backend: Box::new(backend),
+            url: url.into(),
+            pending_commands: Vec::new(),
+            bounds: None,
+        }
+    }
+
+    /// Builder-style method to run commands on the page once it has started loading.
+    pub fn with_commands(mut self, commands: impl IntoIterator<Item = WebViewCommand>) -> Self {
+        self.pending_commands.extend(commands);
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl WebView {
+    /// Returns the URL of the current page.
+    ///
+    /// Navigations done by the page itself are only included once they
+    /// have been [recorded](Self::record_navigation).
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl WebView {
+    /// Runs a command on the page.
+    pub fn run_command(this: &mut WidgetMut<'_, Self>, command: WebViewCommand) {
+        if let WebViewCommand::LoadUrl(url) = &command {
+            this.widget.url.clone_from(url);
+            this.ctx.request_accessibility_update();
+        }
+        this.widget.backend.run_command(&command);
+    }
+
+    /// Navigates to the given URL.
+    pub fn load_url(this: &mut WidgetMut<'_, Self>, url: impl Into<String>) {
+        Self::run_command(this, WebViewCommand::LoadUrl(url.into()));
+    }
+
+    /// Records that the page navigated to `url` on its own, e.g. when the user followed a link.
+    ///
+    /// This updates [`url`](Self::url) without loading the page again, and should be called
+    /// when handling [`WebViewEvent::Navigated`].
+    pub fn record_navigation(this: &mut WidgetMut<'_, Self>, url: impl Into<String>) {
+        this.widget.url = url.into();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets the callback to be called for each event from the page.
+    pub fn set_event_handler(this: &mut WidgetMut<'_, Self>, handler: WebViewEventHandler) {
+        this.widget.backend.set_event_handler(handler);
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for WebView {
+    type Action = NoAction;
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::WidgetAdded => {
+                self.backend
+                    .run_command(&WebViewCommand::LoadUrl(self.url.clone()));
+                for command in std::mem::take(&mut self.pending_commands) {
+                    self.backend.run_command(&command);
+                }
+            }
+            Update::StashedChanged(stashed) => {
+                self.backend.set_visible(!stashed);
+                if !stashed {
+                    // Our position may have changed while we were hidden.
+                    self.bounds = None;
+                    ctx.request_paint_only();
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
+        //       https://github.com/linebender/xilem/issues/1264
+        let scale = 1.0;
+
+        // We use all the available space or fall back to our const preferred size.
+        match (len_req, axis) {
+            (LenReq::FitContent(space), _) => space,
+            (_, Axis::Horizontal) => DEFAULT_WIDTH.dp(scale),
+            (_, Axis::Vertical) => DEFAULT_HEIGHT.dp(scale),
+        }
+    }
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, _size: Size) {}
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {
+        // Paint is re-run whenever our window transform changes, including when
+        // an ancestor scrolls, so this is where we keep the platform web view in place.
+        let bounds = ctx
+            .window_transform()
+            .transform_rect_bbox(ctx.content_box_size().to_rect());
+        if self.bounds != Some(bounds) {
+            self.bounds = Some(bounds);
+            self.backend.set_bounds(bounds);
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::WebView
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_url(self.url.clone());
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::new()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("WebView", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::core::{CollectionWidget, NewWidget, WidgetOptions, WidgetTag};
+    use crate::properties::Dimensions;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::{IndexedStack, SizedBox};
+
+    #[derive(Debug, PartialEq)]
+    enum Call {
+        Bounds(Rect),
+        Visible(bool),
+        Command(WebViewCommand),
+        Dropped,
+    }
+
+    struct RecordingBackend(Arc<Mutex<Vec<Call>>>);
+
+    impl WebViewBackend for RecordingBackend {
+        fn set_bounds(&mut self, bounds: Rect) {
+            self.0.lock().unwrap().push(Call::Bounds(bounds));
+        }
+
+        fn set_visible(&mut self, visible: bool) {
+            self.0.lock().unwrap().push(Call::Visible(visible));
+        }
+
+        fn set_event_handler(&mut self, _handler: WebViewEventHandler) {}
+
+        fn run_command(&mut self, command: &WebViewCommand) {
+            self.0.lock().unwrap().push(Call::Command(command.clone()));
+        }
+    }
+
+    impl Drop for RecordingBackend {
+        fn drop(&mut self) {
+            self.0.lock().unwrap().push(Call::Dropped);
+        }
+    }
+
+    #[test]
+    fn backend_lifecycle() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let tag = WidgetTag::named("web_view");
+        let web_view = NewWidget::new_with(
+            WebView::new(RecordingBackend(calls.clone()), "https://example.com")
+                .with_commands([WebViewCommand::PostMessage("hello".into())]),
+            Some(tag),
+            WidgetOptions::default(),
+            Dimensions::STRETCH,
+        );
+        let stack = IndexedStack::new()
+            .with(web_view)
+            .with(SizedBox::empty().with_auto_id());
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            NewWidget::new(stack),
+            Size::new(200., 100.),
+        );
+        let _ = harness.render();
+        assert_eq!(
+            std::mem::take(&mut *calls.lock().unwrap()),
+            [
+                Call::Command(WebViewCommand::LoadUrl("https://example.com".into())),
+                Call::Command(WebViewCommand::PostMessage("hello".into())),
+                Call::Bounds(Rect::new(0., 0., 200., 100.)),
+            ]
+        );
+
+        harness.edit_widget(tag, |mut web_view| {
+            WebView::load_url(&mut web_view, "https://example.org");
+            WebView::run_command(&mut web_view, WebViewCommand::GoBack);
+        });
+        assert_eq!(harness.get_widget(tag).inner().url(), "https://example.org");
+        harness.edit_widget(tag, |mut web_view| {
+            WebView::record_navigation(&mut web_view, "https://example.org/about");
+        });
+        assert_eq!(
+            harness.get_widget(tag).inner().url(),
+            "https://example.org/about"
+        );
+
+        harness.edit_root_widget(|mut stack| IndexedStack::set_active_child(&mut stack, 1));
+        harness.edit_root_widget(|mut stack| IndexedStack::set_active_child(&mut stack, 0));
+        let _ = harness.render();
+        assert_eq!(
+            std::mem::take(&mut *calls.lock().unwrap()),
+            [
+                Call::Command(WebViewCommand::LoadUrl("https://example.org".into())),
+                Call::Command(WebViewCommand::GoBack),
+                Call::Visible(false),
+                Call::Visible(true),
+                Call::Bounds(Rect::new(0., 0., 200., 100.)),
+            ]
+        );
+
+        harness.edit_root_widget(|mut stack| IndexedStack::remove(&mut stack, 0));
+        assert_eq!(*calls.lock().unwrap(), [Call::Dropped]);
+    }
+}
@@ -0,0 +1,273 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ChildrenIds, EventCtx, KeyboardEvent, LayoutCtx, MeasureCtx, PaintCtx, PointerEvent,
+    PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, Update, UpdateCtx, WgpuSurface,
+    WgpuSurfaceTarget, Widget, WidgetId, WidgetMut,
+};
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::{LenReq, Length};
+
+/// The preferred width of a surface view.
+const DEFAULT_WIDTH: Length = Length::const_px(300.);
+/// The preferred height of a surface view.
+const DEFAULT_HEIGHT: Length = Length::const_px(150.);
+
+/// An input event received by a [`WgpuSurfaceView`], forwarded to the app as an action.
+#[derive(Clone, Debug)]
+pub enum SurfaceInput {
+    /// A pointer event over the view, or captured by it.
+    Pointer {
+        /// The event.
+        event: PointerEvent,
+        /// The position of the pointer in the view's coordinate space, in logical pixels,
+        /// if the event has one.
+        position: Option<Point>,
+    },
+    /// A keyboard event, received while the view is focused.
+    Keyboard(KeyboardEvent),
+}
+
+/// A widget showing content rendered by app code with `wgpu`, such as a 3D scene.
+///
+/// Each time the view is painted, its render function is called with Masonry's `wgpu`
+/// device and queue, and a texture the size of the view in physical pixels.
+/// The texture is drawn like an image, so the content is clipped, transformed and layered
+/// like the rest of the widget tree: a menu or tooltip opened above the view hides it.
+///
+/// By default, the content is only rendered again when the view is resized, or when
+/// [`request_render`](Self::request_render) is called.
+/// [Continuous](Self::continuous) views are rendered on every animation frame instead.
+///
+/// The view can be focused, and forwards pointer and keyboard events to the app as
+/// [`SurfaceInput`] actions, e.g. to move a camera.
+/// The pointer is captured while a button is pressed.
+pub struct WgpuSurfaceView {
+    surface: WgpuSurface,
+    continuous: bool,
+}
+
+// --- MARK: BUILDERS
+impl WgpuSurfaceView {
+    /// Creates a view whose content is rendered by `render`.
+    pub fn new(render: impl FnMut(&WgpuSurfaceTarget<'_>) + 'static) -> Self {
+        Self {
+            surface: WgpuSurface::new(render),
+            continuous: false,
+        }
+    }
+
+    /// Builder-style method to render the content on every animation frame.
+    pub fn continuous(mut self, continuous: bool) -> Self {
+        self.continuous = continuous;
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl WgpuSurfaceView {
+    /// Returns `true` if the content is rendered on every animation frame.
+    pub fn is_continuous(&self) -> bool {
+        self.continuous
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl WgpuSurfaceView {
+    /// Replaces the function rendering the content, and renders it again.
+    pub fn set_render(
+        this: &mut WidgetMut<'_, Self>,
+        render: impl FnMut(&WgpuSurfaceTarget<'_>) + 'static,
+    ) {
+        this.widget.surface.set_render(render);
+        this.ctx.request_paint_only();
+    }
+
+    /// Sets whether the content is rendered on every animation frame.
+    pub fn set_continuous(this: &mut WidgetMut<'_, Self>, continuous: bool) {
+        this.widget.continuous = continuous;
+        if continuous {
+            this.ctx.request_anim_frame();
+        }
+    }
+
+    /// Renders the content again in the next frame.
+    pub fn request_render(this: &mut WidgetMut<'_, Self>) {
+        this.ctx.request_paint_only();
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for WgpuSurfaceView {
+    type Action = SurfaceInput;
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        let position = match event {
+            PointerEvent::Down(button_event) => {
+                ctx.capture_pointer();
+                ctx.request_focus();
+                Some(button_event.state.position)
+            }
+            PointerEvent::Up(button_event) => Some(button_event.state.position),
+            PointerEvent::Move(update) => Some(update.current.position),
+            PointerEvent::Scroll(scroll_event) => Some(scroll_event.state.position),
+            PointerEvent::Gesture(gesture_event) => Some(gesture_event.state.position),
+            _ => None,
+        };
+        ctx.submit_action::<SurfaceInput>(SurfaceInput::Pointer {
+            event: event.clone(),
+            position: position.map(|position| ctx.local_position(position)),
+        });
+        ctx.set_handled();
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if let TextEvent::Keyboard(key_event) = event
+            && ctx.is_focus_target()
+        {
+            ctx.submit_action::<SurfaceInput>(SurfaceInput::Keyboard(key_event.clone()));
+            ctx.set_handled();
+        }
+    }
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::WidgetAdded if self.continuous => ctx.request_anim_frame(),
+            Update::StashedChanged(false) if self.continuous => ctx.request_anim_frame(),
+            _ => (),
+        }
+    }
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        _interval: u64,
+    ) {
+        if self.continuous && !ctx.is_stashed() {
+            ctx.request_paint_only();
+            ctx.request_anim_frame();
+        }
+    }
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
+        //       https://github.com/linebender/xilem/issues/1264
+        let scale = 1.0;
+
+        // We use all the available space or fall back to our const preferred size.
+        match (len_req, axis) {
+            (LenReq::FitContent(space), _) => space,
+            (_, Axis::Horizontal) => DEFAULT_WIDTH.dp(scale),
+            (_, Axis::Vertical) => DEFAULT_HEIGHT.dp(scale),
+        }
+    }
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, _size: Size) {}
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let rect = ctx.content_box_size().to_rect();
+        ctx.paint_wgpu_surface(scene, &self.surface, rect);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Canvas
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::new()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("WgpuSurfaceView", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use dpi::PhysicalSize;
+
+    use super::*;
+    use crate::core::{NewWidget, PointerButton, WidgetTag};
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+
+    #[test]
+    fn forwards_input() {
+        let tag = WidgetTag::named("surface");
+        let view = NewWidget::new_with_tag(WgpuSurfaceView::new(|_| {}), tag);
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), view, Size::new(200., 100.));
+        let id = harness.get_widget(tag).id();
+
+        harness.mouse_move((20., 30.));
+        let Some((SurfaceInput::Pointer { position, .. }, _)) =
+            harness.pop_action::<SurfaceInput>()
+        else {
+            panic!("expected a pointer event");
+        };
+        assert_eq!(position, Some(Point::new(20., 30.)));
+
+        harness.mouse_button_press(PointerButton::Primary);
+        assert_eq!(harness.focused_widget_id(), Some(id));
+    }
+
+    #[test]
+    fn renders_at_physical_size() {
+        let sizes = Rc::new(RefCell::new(Vec::new()));
+        let sizes_in_render = sizes.clone();
+        let view = WgpuSurfaceView::new(move |target| {
+            sizes_in_render.borrow_mut().push(target.size);
+        });
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            NewWidget::new(view),
+            Size::new(200., 100.),
+        );
+        let _ = harness.render();
+        // Hack: If we are using `SKIP_RENDER_TESTS`, nothing is rendered.
+        if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            assert_eq!(*sizes.borrow(), [PhysicalSize::new(200, 100)]);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+#![expect(missing_docs, reason = "Bench crate")]
+
+use divan::Bencher;
+use masonry_bench::{BlurPaint, DeepNestingRebuild, TextRelayout, VirtualListScroll, Workload};
+
+fn bench_frames<W: Workload>(bencher: Bencher<'_, '_>) {
+    let mut workload = W::setup();
+    bencher.bench_local(|| workload.frame());
+}
+
+#[divan::bench]
+fn virtual_list_scroll(bencher: Bencher<'_, '_>) {
+    bench_frames::<VirtualListScroll>(bencher);
+}
+
+#[divan::bench]
+fn deep_nesting_rebuild(bencher: Bencher<'_, '_>) {
+    bench_frames::<DeepNestingRebuild>(bencher);
+}
+
+#[divan::bench]
+fn text_relayout(bencher: Bencher<'_, '_>) {
+    bench_frames::<TextRelayout>(bencher);
+}
+
+#[divan::bench]
+fn blur_paint(bencher: Bencher<'_, '_>) {
+    bench_frames::<BlurPaint>(bencher);
+}
+
+// ---
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
@@ -0,0 +1,91 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Prints the time spent in each Masonry pass for the benchmark workloads, as JSON.
+//!
+//! ```text
+//! Usage: pass_timings [--frames <N>] [--output <PATH>] [WORKLOAD...]
+//! ```
+//!
+//! By default, all workloads are run for 100 frames and the result is printed to stdout.
+
+use std::process::ExitCode;
+
+use masonry_bench::{
+    BlurPaint, DeepNestingRebuild, TextRelayout, VirtualListScroll, Workload, time_passes,
+};
+use serde_json::{Map, Value};
+
+const WORKLOADS: &[&str] = &[
+    VirtualListScroll::NAME,
+    DeepNestingRebuild::NAME,
+    TextRelayout::NAME,
+    BlurPaint::NAME,
+];
+
+fn run(name: &str, frames: u64) -> Value {
+    match name {
+        VirtualListScroll::NAME => time_passes::<VirtualListScroll>(frames),
+        DeepNestingRebuild::NAME => time_passes::<DeepNestingRebuild>(frames),
+        TextRelayout::NAME => time_passes::<TextRelayout>(frames),
+        BlurPaint::NAME => time_passes::<BlurPaint>(frames),
+        _ => unreachable!("workload names are checked when parsing arguments"),
+    }
+}
+
+fn main() -> ExitCode {
+    let mut frames = 100;
+    let mut output = None;
+    let mut workloads = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--frames" => match args.next().and_then(|frames| frames.parse().ok()) {
+                Some(value) => frames = value,
+                None => {
+                    eprintln!("--frames expects a number of frames");
+                    return ExitCode::FAILURE;
+                }
+            },
+            "--output" => match args.next() {
+                Some(path) => output = Some(path),
+                None => {
+                    eprintln!("--output expects a file path");
+                    return ExitCode::FAILURE;
+                }
+            },
+            name if WORKLOADS.contains(&name) => workloads.push(arg),
+            _ => {
+                eprintln!(
+                    "Unknown argument '{arg}'. Usage: pass_timings [--frames <N>] [--output <PATH>] [WORKLOAD...]"
+                );
+                eprintln!("Workloads: {}", WORKLOADS.join(", "));
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    if workloads.is_empty() {
+        workloads = WORKLOADS.iter().map(|name| (*name).to_string()).collect();
+    }
+
+    let results: Map<String, Value> = workloads
+        .into_iter()
+        .map(|name| {
+            let timings = run(&name, frames);
+            (name, timings)
+        })
+        .collect();
+    let json = serde_json::to_string_pretty(&Value::Object(results)).unwrap();
+
+    match output {
+        Some(path) => {
+            if let Err(err) = std::fs::write(&path, json) {
+                eprintln!("Failed to write '{path}': {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+        None => println!("{json}"),
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,391 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Representative workloads for benchmarking Masonry's pass pipeline.
+//!
+//! Each [`Workload`] builds a widget tree in a [`TestHarness`] and then runs frames
+//! which exercise a specific part of the pipeline:
+//!
+//! - [`VirtualListScroll`] scrolls through a virtual list of 10,000 rows.
+//! - [`DeepNestingRebuild`] replaces a deeply nested subtree on every frame.
+//! - [`TextRelayout`] re-wraps paragraphs of text as the window is resized.
+//! - [`BlurPaint`] repaints hundreds of widgets with blurred box shadows.
+//!
+//! The `pass_pipeline` benchmark measures full frames of each workload.
+//! The `pass_timings` binary runs the workloads and prints the time spent in
+//! each pass as JSON, using [`PassTimings`]:
+//!
+//! ```sh
+//! cargo run --release -p masonry_bench --bin pass_timings -- --frames 200
+//! ```
+
+#![expect(
+    missing_debug_implementations,
+    reason = "Workloads hold a TestHarness, which doesn't implement Debug"
+)]
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use masonry::core::{CollectionWidget, NewWidget, Widget, WidgetMut, WindowEvent};
+use masonry::dpi::PhysicalSize;
+use masonry::kurbo::{Size, Vec2};
+use masonry::layout::AsUnit;
+use masonry::palette::css::{BLACK, WHITE};
+use masonry::properties::{Background, BoxShadow, CornerRadius};
+use masonry::testing::TestHarness;
+use masonry::theme::default_property_set;
+use masonry::widgets::{Flex, Label, Prose, SizedBox, VirtualScroll, VirtualScrollAction};
+use serde_json::{Map, Value, json};
+use tracing::Subscriber;
+use tracing::span::Id;
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+
+/// A widget tree and a way to run frames on it.
+pub trait Workload {
+    /// The name of the workload, used in benchmark output.
+    const NAME: &'static str;
+
+    /// Builds the widget tree and runs the first frame.
+    fn setup() -> Self;
+
+    /// Runs the next frame of the workload.
+    fn frame(&mut self);
+}
+
+// --- MARK: VIRTUAL LIST
+/// Scrolls through a [`VirtualScroll`] of [`VirtualListScroll::ROWS`] labels.
+///
+/// Each frame scrolls by a mouse wheel step and loads the rows which come into view.
+pub struct VirtualListScroll {
+    harness: TestHarness<VirtualScroll>,
+    frames: u64,
+}
+
+impl VirtualListScroll {
+    /// The number of rows in the list.
+    pub const ROWS: i64 = 10_000;
+
+    /// Adds and removes rows as requested by the list, until it has all the rows it needs.
+    fn load_rows(&mut self) {
+        while let Some((action, _)) = self.harness.pop_action::<VirtualScrollAction>() {
+            self.harness.edit_root_widget(|mut scroll| {
+                VirtualScroll::will_handle_action(&mut scroll, &action);
+                for idx in action.old_active.clone() {
+                    if !action.target.contains(&idx) {
+                        VirtualScroll::remove_child(&mut scroll, idx);
+                    }
+                }
+                for idx in action.target.clone() {
+                    if !action.old_active.contains(&idx) {
+                        let label = Label::new(format!("Row {idx}"));
+                        VirtualScroll::add_child(&mut scroll, idx, NewWidget::new(label).erased());
+                    }
+                }
+            });
+        }
+    }
+}
+
+impl Workload for VirtualListScroll {
+    const NAME: &'static str = "virtual_list_scroll";
+
+    fn setup() -> Self {
+        let list = VirtualScroll::new(0).with_valid_range(0..Self::ROWS);
+        let harness = TestHarness::create_with_size(
+            default_property_set(),
+            NewWidget::new(list),
+            Size::new(400., 800.),
+        );
+        let mut workload = Self { harness, frames: 0 };
+        let list_id = workload.harness.root_id();
+        workload.harness.mouse_move_to(list_id);
+        workload.load_rows();
+        workload.harness.redraw();
+        workload
+    }
+
+    fn frame(&mut self) {
+        // Scroll back and forth, so that long benchmark runs don't reach the end of the list.
+        let step = if (self.frames / 1_000).is_multiple_of(2) {
+            120.
+        } else {
+            -120.
+        };
+        self.frames += 1;
+        self.harness.mouse_wheel(Vec2::new(0., step));
+        self.load_rows();
+        self.harness.redraw();
+    }
+}
+
+// --- MARK: DEEP NESTING
+/// Replaces a subtree nested [`DeepNestingRebuild::DEPTH`] levels deep on every frame.
+pub struct DeepNestingRebuild {
+    harness: TestHarness<SizedBox>,
+}
+
+impl DeepNestingRebuild {
+    /// The number of containers around the innermost label.
+    pub const DEPTH: usize = 100;
+
+    fn nested_tree() -> NewWidget<dyn Widget> {
+        let mut widget = NewWidget::new(Label::new("Innermost")).erased();
+        for level in 0..Self::DEPTH {
+            widget = if level.is_multiple_of(2) {
+                NewWidget::new(SizedBox::new(widget)).erased()
+            } else {
+                NewWidget::new(Flex::column().with_fixed(widget)).erased()
+            };
+        }
+        widget
+    }
+}
+
+impl Workload for DeepNestingRebuild {
+    const NAME: &'static str = "deep_nesting_rebuild";
+
+    fn setup() -> Self {
+        let root = NewWidget::new(SizedBox::new(Self::nested_tree()));
+        let mut harness = TestHarness::create(default_property_set(), root);
+        harness.redraw();
+        Self { harness }
+    }
+
+    fn frame(&mut self) {
+        self.harness.edit_root_widget(|mut root| {
+            SizedBox::set_child(&mut root, Self::nested_tree());
+        });
+        self.harness.redraw();
+    }
+}
+
+// --- MARK: TEXT RELAYOUT
+/// Re-wraps [`TextRelayout::PARAGRAPHS`] paragraphs of text, alternating between two window widths.
+pub struct TextRelayout {
+    harness: TestHarness<Flex>,
+    wide: bool,
+}
+
+impl TextRelayout {
+    /// The number of paragraphs.
+    pub const PARAGRAPHS: usize = 100;
+
+    const TEXT: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod \
+        tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam, quis \
+        nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo consequat. Duis aute \
+        irure dolor in reprehenderit in voluptate velit esse cillum dolore eu fugiat nulla \
+        pariatur.";
+}
+
+impl Workload for TextRelayout {
+    const NAME: &'static str = "text_relayout";
+
+    fn setup() -> Self {
+        let mut column = Flex::column();
+        for _ in 0..Self::PARAGRAPHS {
+            column = column.with_fixed(NewWidget::new(Prose::new(Self::TEXT)));
+        }
+        let mut harness = TestHarness::create_with_size(
+            default_property_set(),
+            NewWidget::new(column),
+            Size::new(400., 800.),
+        );
+        harness.redraw();
+        Self {
+            harness,
+            wide: false,
+        }
+    }
+
+    fn frame(&mut self) {
+        self.wide = !self.wide;
+        let width = if self.wide { 600 } else { 400 };
+        self.harness
+            .process_window_event(WindowEvent::Resize(PhysicalSize::new(width, 800)));
+        self.harness.redraw();
+    }
+}
+
+// --- MARK: BLUR PAINT
+/// Repaints a grid of [`BlurPaint::ROWS`] by [`BlurPaint::COLUMNS`] boxes with blurred shadows.
+///
+/// Unlike the other workloads, this renders each frame, as blurs are mostly expensive to rasterize.
+pub struct BlurPaint {
+    harness: TestHarness<Flex>,
+}
+
+impl BlurPaint {
+    /// The number of rows of boxes.
+    pub const ROWS: usize = 20;
+    /// The number of boxes in each row.
+    pub const COLUMNS: usize = 20;
+}
+
+impl Workload for BlurPaint {
+    const NAME: &'static str = "blur_paint";
+
+    fn setup() -> Self {
+        let mut grid = Flex::column();
+        for _ in 0..Self::ROWS {
+            let mut row = Flex::row();
+            for _ in 0..Self::COLUMNS {
+                let shadowed_box = NewWidget::new_with_props(
+                    SizedBox::empty().size(24.px(), 24.px()),
+                    (
+                        Background::Color(WHITE),
+                        BoxShadow::new(BLACK, (2., 2.)).blur(8.),
+                        CornerRadius { radius: 6. },
+                    ),
+                );
+                row = row.with_fixed(shadowed_box);
+            }
+            grid = grid.with_fixed(NewWidget::new(row));
+        }
+        let mut harness = TestHarness::create_with_size(
+            default_property_set(),
+            NewWidget::new(grid),
+            Size::new(800., 800.),
+        );
+        let _ = harness.render();
+        Self { harness }
+    }
+
+    fn frame(&mut self) {
+        self.harness.edit_root_widget(|mut grid| {
+            for row_idx in 0..Self::ROWS {
+                let mut row = Flex::get_mut(&mut grid, row_idx);
+                let mut row = row.downcast::<Flex>();
+                request_pre_paint_of_children(&mut row, Self::COLUMNS);
+            }
+        });
+        let _ = self.harness.render();
+    }
+}
+
+fn request_pre_paint_of_children(row: &mut WidgetMut<'_, Flex>, count: usize) {
+    for idx in 0..count {
+        Flex::get_mut(row, idx).ctx.request_pre_paint();
+    }
+}
+
+// --- MARK: PASS TIMINGS
+/// The time spent in each pass, recorded from Masonry's tracing spans.
+///
+/// Use [`PassTimings::layer`] to install the recorder in a `tracing` subscriber.
+#[derive(Clone, Debug, Default)]
+pub struct PassTimings {
+    spans: Arc<Mutex<BTreeMap<&'static str, Vec<Duration>>>>,
+}
+
+impl PassTimings {
+    /// Creates an empty recorder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a [`Layer`] which records the duration of each Masonry span into `self`.
+    pub fn layer<S>(&self) -> impl Layer<S> + use<S>
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        TimingLayer {
+            timings: self.clone(),
+        }
+    }
+
+    /// Returns the recorded timings as JSON, keyed by pass name.
+    ///
+    /// For each pass, this contains the number of times the pass ran and the total,
+    /// mean and maximum time it took, in microseconds.
+    pub fn to_json(&self) -> Value {
+        let spans = self.spans.lock().unwrap();
+        let passes: Map<String, Value> = spans
+            .iter()
+            .map(|(name, durations)| {
+                let total: Duration = durations.iter().sum();
+                let max = durations.iter().max().copied().unwrap_or_default();
+                let runs = durations.len();
+                #[expect(
+                    clippy::cast_precision_loss,
+                    reason = "Run counts are far below f64's precision"
+                )]
+                let mean_us = total.as_secs_f64() * 1e6 / runs as f64;
+                let stats = json!({
+                    "runs": runs,
+                    "total_us": total.as_secs_f64() * 1e6,
+                    "mean_us": mean_us,
+                    "max_us": max.as_secs_f64() * 1e6,
+                });
+                ((*name).to_string(), stats)
+            })
+            .collect();
+        Value::Object(passes)
+    }
+
+    fn record(&self, name: &'static str, duration: Duration) {
+        self.spans
+            .lock()
+            .unwrap()
+            .entry(name)
+            .or_default()
+            .push(duration);
+    }
+}
+
+struct TimingLayer {
+    timings: PassTimings,
+}
+
+/// When the span was last entered.
+struct EnteredAt(Instant);
+
+impl<S> Layer<S> for TimingLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().replace(EnteredAt(Instant::now()));
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        // Skip spans from dependencies, such as wgpu's.
+        if !span.metadata().target().starts_with("masonry") {
+            return;
+        }
+        if let Some(EnteredAt(entered_at)) = span.extensions_mut().remove::<EnteredAt>() {
+            self.timings.record(span.name(), entered_at.elapsed());
+        }
+    }
+}
+
+/// Runs `frames` frames of the workload `W`, and returns the time spent in each pass as JSON.
+///
+/// Setting up the workload isn't included in the timings.
+pub fn time_passes<W: Workload>(frames: u64) -> Value {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    let mut workload = W::setup();
+    let timings = PassTimings::new();
+    let subscriber = tracing_subscriber::registry().with(timings.layer());
+    let start = Instant::now();
+    tracing::subscriber::with_default(subscriber, || {
+        for _ in 0..frames {
+            workload.frame();
+        }
+    });
+    let elapsed = start.elapsed();
+    json!({
+        "frames": frames,
+        "total_us": elapsed.as_secs_f64() * 1e6,
+        "passes": timings.to_json(),
+    })
+}
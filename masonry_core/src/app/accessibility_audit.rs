@@ -0,0 +1,392 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::fmt;
+
+use accesskit::{Action, Node, NodeId, Role, TreeUpdate};
+use tracing::info_span;
+use vello::kurbo::{Rect, Size};
+use vello::peniko::Color;
+
+use crate::app::RenderRoot;
+use crate::core::{PropertiesRef, Widget, WidgetId};
+use crate::properties::Background;
+use crate::util::{composite_over, contrast_ratio};
+
+/// A function returning the color of the text a widget paints, or `None` if it doesn't
+/// paint text.
+///
+/// The last argument is whether the widget is disabled.
+pub type AuditTextColorFn = fn(&dyn Widget, &PropertiesRef<'_>, bool) -> Option<Color>;
+
+/// The settings of the [accessibility audit](crate::app::RenderRoot::set_accessibility_audit).
+#[derive(Clone, Copy, Debug)]
+pub struct AuditOptions {
+    /// The color the window is cleared with, below the backgrounds of widgets.
+    pub base_color: Color,
+    /// Returns the color of the text painted by a widget.
+    ///
+    /// Masonry Core doesn't know which widgets paint text, so contrast is only checked
+    /// if this is set.
+    pub text_color: Option<AuditTextColorFn>,
+    /// The minimum contrast ratio between text and its background.
+    ///
+    /// This is 4.5 by default, as required for normal text by the WCAG level AA.
+    pub min_contrast_ratio: f64,
+    /// The minimum width and height of interactive widgets, in logical pixels.
+    ///
+    /// This is 24 by default, as required by the WCAG 2.2 level AA.
+    pub min_target_size: f64,
+}
+
+impl AuditOptions {
+    /// The default options, which don't check contrast.
+    pub const DEFAULT: Self = Self {
+        base_color: Color::BLACK,
+        text_color: None,
+        min_contrast_ratio: 4.5,
+        min_target_size: 24.,
+    };
+}
+
+impl Default for AuditOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The kind of an [`AuditIssue`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AuditIssueKind {
+    /// An interactive widget has no label, so screen readers can't tell what it does.
+    MissingLabel,
+    /// The text of the widget has too little contrast with its background.
+    LowContrast {
+        /// The contrast ratio between the text and its background.
+        ratio: f64,
+    },
+    /// An interactive widget is smaller than the minimum target size.
+    SmallHitTarget {
+        /// The size of the widget's border box.
+        size: Size,
+    },
+    /// Tab moves the focus to this widget from a widget below it or to its right,
+    /// which differs from the reading order.
+    FocusOrder {
+        /// The widget focused before this one.
+        previous: WidgetId,
+    },
+}
+
+/// An accessibility issue found by the [`AccessibilityAudit`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AuditIssue {
+    /// The widget with the issue.
+    pub widget: WidgetId,
+    /// The short type name of the widget.
+    pub widget_name: &'static str,
+    /// What the issue is.
+    pub kind: AuditIssueKind,
+}
+
+impl fmt::Display for AuditIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: ", self.widget_name, self.widget)?;
+        match self.kind {
+            AuditIssueKind::MissingLabel => write!(f, "interactive widget without a label"),
+            AuditIssueKind::LowContrast { ratio } => {
+                write!(f, "text contrast ratio of {ratio:.2}:1 with its background")
+            }
+            AuditIssueKind::SmallHitTarget { size } => {
+                write!(
+                    f,
+                    "hit target of {}x{} logical pixels",
+                    size.width, size.height
+                )
+            }
+            AuditIssueKind::FocusOrder { previous } => {
+                write!(
+                    f,
+                    "focused after {previous}, which comes later in reading order"
+                )
+            }
+        }
+    }
+}
+
+/// The accessibility issues of a widget tree.
+///
+/// The audit reports interactive widgets without labels, text with too little contrast
+/// with the colors of the backgrounds below it, interactive widgets smaller than the
+/// minimum target size, and widgets which Tab focuses out of reading order.
+/// It's a heuristic: it doesn't know about text painted on images or custom paint code,
+/// and an empty report doesn't mean the app is accessible.
+///
+/// Its [`Display`](fmt::Display) implementation prints a report listing the issues.
+///
+/// See [`RenderRoot::set_accessibility_audit`](crate::app::RenderRoot::set_accessibility_audit).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AccessibilityAudit {
+    issues: Vec<AuditIssue>,
+}
+
+impl AccessibilityAudit {
+    /// Returns the issues found, in the order of the widget tree.
+    pub fn issues(&self) -> &[AuditIssue] {
+        &self.issues
+    }
+
+    /// Returns the issues found for the given widget.
+    pub fn widget(&self, widget: WidgetId) -> impl Iterator<Item = &AuditIssue> {
+        self.issues
+            .iter()
+            .filter(move |issue| issue.widget == widget)
+    }
+}
+
+impl fmt::Display for AccessibilityAudit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.issues.is_empty() {
+            return writeln!(f, "No accessibility issues found.");
+        }
+        writeln!(f, "{} accessibility issues found:", self.issues.len())?;
+        for issue in &self.issues {
+            writeln!(f, "- {issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The state of the accessibility audit, while it's enabled.
+pub(crate) struct AuditState {
+    pub(crate) options: AuditOptions,
+    /// The last accessibility node of each widget and of their text runs.
+    nodes: HashMap<NodeId, Node>,
+    pub(crate) audit: AccessibilityAudit,
+}
+
+impl AuditState {
+    pub(crate) fn new(options: AuditOptions) -> Self {
+        Self {
+            options,
+            nodes: HashMap::new(),
+            audit: AccessibilityAudit::default(),
+        }
+    }
+}
+
+/// A widget visited by the audit.
+struct AuditedWidget {
+    id: WidgetId,
+    /// The border box, in window coordinates.
+    rect: Rect,
+}
+
+/// Roles which don't take their name from their contents, such as the text of a text input.
+fn is_input_role(role: Role) -> bool {
+    matches!(
+        role,
+        Role::TextInput
+            | Role::MultilineTextInput
+            | Role::PasswordInput
+            | Role::SearchInput
+            | Role::SpinButton
+            | Role::Slider
+            | Role::ComboBox
+    )
+}
+
+fn is_interactive(node: &Node) -> bool {
+    node.supports_action(Action::Click) || is_input_role(node.role())
+}
+
+fn is_non_empty(text: Option<&str>) -> bool {
+    text.is_some_and(|text| !text.trim().is_empty())
+}
+
+/// Returns whether the node or its descendants have text which names the node.
+fn has_text(nodes: &HashMap<NodeId, Node>, id: NodeId) -> bool {
+    let Some(node) = nodes.get(&id) else {
+        return false;
+    };
+    is_non_empty(node.label())
+        || is_non_empty(node.value())
+        || node.children().iter().any(|child| has_text(nodes, *child))
+}
+
+fn has_name(nodes: &HashMap<NodeId, Node>, node: &Node) -> bool {
+    if is_non_empty(node.label()) || !node.labelled_by().is_empty() {
+        return true;
+    }
+    !is_input_role(node.role()) && node.children().iter().any(|child| has_text(nodes, *child))
+}
+
+/// Moves the nodes reachable from `id` from `old` to `new`, dropping the nodes of
+/// removed widgets.
+fn keep_reachable(old: &mut HashMap<NodeId, Node>, new: &mut HashMap<NodeId, Node>, id: NodeId) {
+    let Some(node) = old.remove(&id) else {
+        return;
+    };
+    for child in node.children() {
+        keep_reachable(old, new, *child);
+    }
+    new.insert(id, node);
+}
+
+/// Returns the color of the backgrounds below the content of the widget.
+///
+/// Returns `None` if one of them is a gradient.
+fn effective_background(root: &RenderRoot, id: WidgetId, base_color: Color) -> Option<Color> {
+    let mut colors = Vec::new();
+    let mut current = Some(id);
+    while let Some(id) = current {
+        let node = root.widget_arena.get_node(id);
+        let widget = &*node.item.widget;
+        let props = PropertiesRef {
+            map: &node.item.properties,
+            default_map: root.default_properties.for_widget(widget.type_id()),
+        };
+        match props.get::<Background>() {
+            Background::Color(color) => {
+                colors.push(*color);
+                if color.components[3] >= 1. {
+                    break;
+                }
+            }
+            Background::Gradient(_) => return None,
+        }
+        current = root.widget_arena.parent_of(id);
+    }
+    Some(
+        colors
+            .into_iter()
+            .rev()
+            .fold(base_color, |below, color| composite_over(color, below)),
+    )
+}
+
+fn visit_widget(
+    root: &RenderRoot,
+    id: WidgetId,
+    in_roving_group: bool,
+    widgets: &mut Vec<AuditedWidget>,
+    focus_stops: &mut Vec<AuditedWidget>,
+) {
+    let node = root.widget_arena.get_node(id);
+    let state = &node.item.state;
+    if state.is_stashed {
+        return;
+    }
+    let rect = Rect::from_origin_size(state.border_box_window_origin(), state.border_box_size());
+    widgets.push(AuditedWidget { id, rect });
+
+    // A roving focus group is a single Tab stop.
+    if !in_roving_group
+        && !state.is_disabled
+        && (state.accepts_focus || state.is_roving_focus_group)
+    {
+        focus_stops.push(AuditedWidget { id, rect });
+    }
+    let in_roving_group = in_roving_group || state.is_roving_focus_group;
+    for child in node.item.widget.children_ids() {
+        visit_widget(root, child, in_roving_group, widgets, focus_stops);
+    }
+}
+
+/// Returns whether `rect` is before `previous` in reading order, i.e. above it,
+/// or to its left on the same row.
+fn is_before(rect: Rect, previous: Rect) -> bool {
+    let same_row = rect.y0 < previous.y1 && previous.y0 < rect.y1;
+    if same_row {
+        rect.x1 <= previous.x0
+    } else {
+        rect.y1 <= previous.y0
+    }
+}
+
+// --- MARK: RUN AUDIT
+/// Audits the widget tree, using the accessibility nodes of `tree_update` and of
+/// previous updates.
+pub(crate) fn run_accessibility_audit(root: &mut RenderRoot, tree_update: &TreeUpdate) {
+    let Some(mut audit_state) = root.global_state.accessibility_audit.take() else {
+        return;
+    };
+    let _span = info_span!("accessibility_audit").entered();
+
+    let mut nodes = std::mem::take(&mut audit_state.nodes);
+    nodes.extend(tree_update.nodes.iter().cloned());
+    keep_reachable(&mut nodes, &mut audit_state.nodes, root.root_id().into());
+    let nodes = &audit_state.nodes;
+    let options = audit_state.options;
+
+    let mut widgets = Vec::new();
+    let mut focus_stops = Vec::new();
+    visit_widget(root, root.root_id(), false, &mut widgets, &mut focus_stops);
+
+    let mut issues = Vec::new();
+    for widget in &widgets {
+        let arena_node = root.widget_arena.get_node(widget.id);
+        let item = &arena_node.item;
+        let name = item.widget.short_type_name();
+        let mut push = |kind| {
+            issues.push(AuditIssue {
+                widget: widget.id,
+                widget_name: name,
+                kind,
+            });
+        };
+
+        if let Some(node) = nodes.get(&NodeId::from(widget.id))
+            && is_interactive(node)
+            && !item.state.is_disabled
+        {
+            if !has_name(nodes, node) {
+                push(AuditIssueKind::MissingLabel);
+            }
+            let size = widget.rect.size();
+            if size.width < options.min_target_size || size.height < options.min_target_size {
+                push(AuditIssueKind::SmallHitTarget { size });
+            }
+        }
+
+        if let Some(text_color) = options.text_color {
+            let props = PropertiesRef {
+                map: &item.properties,
+                default_map: root.default_properties.for_widget((*item.widget).type_id()),
+            };
+            if let Some(color) = text_color(&*item.widget, &props, item.state.is_disabled)
+                && let Some(background) = effective_background(root, widget.id, options.base_color)
+            {
+                let ratio = contrast_ratio(composite_over(color, background), background);
+                if ratio < options.min_contrast_ratio {
+                    push(AuditIssueKind::LowContrast { ratio });
+                }
+            }
+        }
+    }
+
+    for pair in focus_stops.windows(2) {
+        let [previous, stop] = pair else {
+            unreachable!()
+        };
+        if is_before(stop.rect, previous.rect) {
+            let name = root
+                .widget_arena
+                .get_node(stop.id)
+                .item
+                .widget
+                .short_type_name();
+            issues.push(AuditIssue {
+                widget: stop.id,
+                widget_name: name,
+                kind: AuditIssueKind::FocusOrder {
+                    previous: previous.id,
+                },
+            });
+        }
+    }
+
+    audit_state.audit = AccessibilityAudit { issues };
+    root.global_state.accessibility_audit = Some(audit_state);
+}
@@ -0,0 +1,317 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::mpsc::{self, Receiver};
+
+use accesskit::{ActionRequest, TreeUpdate};
+use dpi::{LogicalPosition, LogicalSize};
+use tracing::info_span;
+use vello::kurbo::Affine;
+use vello::peniko::Color;
+use vello::wgpu::{Device, Queue, TextureView};
+use vello::{AaSupport, RenderParams, Renderer, RendererOptions, Scene};
+
+use crate::app::{RenderRoot, RenderRootOptions, RenderRootSignal, RenderedImage};
+use crate::core::{
+    CursorIcon, ErasedAction, Handled, ImePurpose, NewWidget, PointerEvent, TextEvent, Widget,
+    WidgetId, WindowEvent,
+};
+use crate::util::Instant;
+
+/// The platform side of an [`EmbeddedRoot`], implemented by the app or engine embedding it.
+///
+/// An [`EmbeddedRoot`] calls these methods when the UI needs something from the platform.
+/// All methods have a default implementation which ignores the request, so hosts only
+/// implement what they support.
+pub trait EmbedderHost {
+    /// A widget has emitted an action.
+    fn on_action(&mut self, action: ErasedAction, widget_id: WidgetId) {
+        _ = (action, widget_id);
+    }
+
+    /// The UI should be rendered again, with [`EmbeddedRoot::render_to_texture`].
+    ///
+    /// This is also requested while animations are running.
+    fn request_redraw(&mut self) {}
+
+    /// The mouse cursor should change.
+    fn set_cursor(&mut self, cursor: CursorIcon) {
+        _ = cursor;
+    }
+
+    /// A text input has been focused: the platform should start an IME session,
+    /// e.g. by showing the on-screen keyboard.
+    ///
+    /// IME events should then be sent with [`EmbeddedRoot::handle_text_event`].
+    fn start_ime(&mut self, purpose: ImePurpose) {
+        _ = purpose;
+    }
+
+    /// The IME session has ended.
+    fn end_ime(&mut self) {}
+
+    /// The area of the focused text input has moved, in logical pixels relative to the
+    /// top-left corner of the rendered texture.
+    ///
+    /// IME candidate windows should be placed next to this area.
+    fn set_ime_area(&mut self, position: LogicalPosition<f64>, size: LogicalSize<f64>) {
+        _ = (position, size);
+    }
+
+    /// The user has copied `text`.
+    fn set_clipboard(&mut self, text: String) {
+        _ = text;
+    }
+
+    /// The URL should be opened with the platform's default handler.
+    fn open_url(&mut self, url: String) {
+        _ = url;
+    }
+
+    /// The accessibility tree has changed.
+    ///
+    /// This is only called once the access tree has been enabled with
+    /// [`WindowEvent::EnableAccessTree`].
+    fn update_accessibility(&mut self, update: TreeUpdate) {
+        _ = update;
+    }
+
+    /// Any other signal, such as [`RenderRootSignal::SetTitle`] or [`RenderRootSignal::Exit`],
+    /// which only makes sense for some hosts.
+    fn on_signal(&mut self, signal: RenderRootSignal) {
+        _ = signal;
+    }
+}
+
+/// A Masonry UI embedded in an existing `wgpu` render loop, such as a game engine or a
+/// visualizer, without a winit event loop.
+///
+/// The host owns the window, the input and the GPU device: it translates its input events
+/// into Masonry events, sends them with [`handle_pointer_event`](Self::handle_pointer_event)
+/// and friends, and calls [`render_to_texture`](Self::render_to_texture) when the UI needs
+/// to be redrawn, usually once per frame.
+/// The texture can then be composited over the host's own content.
+///
+/// Requests from the UI, such as cursor changes or IME sessions, are passed to the
+/// [`EmbedderHost`] given on creation.
+/// Layers (such as tooltips and menus) are handled by the embedded root itself.
+///
+/// This is a thin wrapper around a [`RenderRoot`], which can be accessed with
+/// [`render_root`](Self::render_root) to edit the widget tree.
+pub struct EmbeddedRoot<H> {
+    render_root: RenderRoot,
+    signal_receiver: Receiver<RenderRootSignal>,
+    host: H,
+    base_color: Color,
+    renderer: Option<Renderer>,
+    /// The [`RenderRootSignal::RenderToImage`] requests waiting for a device.
+    pending_images: Vec<(WidgetId, f64)>,
+    last_anim: Option<Instant>,
+}
+
+impl<H: EmbedderHost> EmbeddedRoot<H> {
+    /// Creates a new embedded UI with the given root widget.
+    ///
+    /// `options.size` should be the size of the texture the UI will be rendered to.
+    pub fn new(
+        root_widget: NewWidget<impl Widget + ?Sized>,
+        options: RenderRootOptions,
+        host: H,
+    ) -> Self {
+        let (signal_sender, signal_receiver) = mpsc::channel();
+        let render_root = RenderRoot::new(
+            root_widget,
+            move |signal| signal_sender.send(signal).unwrap(),
+            options,
+        );
+        let mut root = Self {
+            render_root,
+            signal_receiver,
+            host,
+            base_color: Color::TRANSPARENT,
+            renderer: None,
+            pending_images: Vec::new(),
+            last_anim: None,
+        };
+        root.handle_signals();
+        root
+    }
+
+    /// Builder-style method to set the color the texture is cleared with before rendering.
+    ///
+    /// The default is transparent, so the host's content shows through.
+    pub fn with_base_color(mut self, base_color: Color) -> Self {
+        self.base_color = base_color;
+        self
+    }
+
+    /// Sets the color the texture is cleared with before rendering.
+    pub fn set_base_color(&mut self, base_color: Color) {
+        self.base_color = base_color;
+    }
+
+    /// Returns the underlying [`RenderRoot`].
+    ///
+    /// Signals emitted while using it are passed to the host with the next call to an
+    /// event method or to [`render_to_texture`](Self::render_to_texture).
+    pub fn render_root(&mut self) -> &mut RenderRoot {
+        &mut self.render_root
+    }
+
+    /// Returns the host.
+    pub fn host(&self) -> &H {
+        &self.host
+    }
+
+    /// Returns the host, mutably.
+    pub fn host_mut(&mut self) -> &mut H {
+        &mut self.host
+    }
+
+    /// Sends a window event, such as [`WindowEvent::Resize`] when the texture's size changes.
+    pub fn handle_window_event(&mut self, event: WindowEvent) -> Handled {
+        let handled = self.render_root.handle_window_event(event);
+        self.handle_signals();
+        handled
+    }
+
+    /// Sends a pointer event, with positions relative to the top-left corner of the texture.
+    pub fn handle_pointer_event(&mut self, event: PointerEvent) -> Handled {
+        let handled = self.render_root.handle_pointer_event(event);
+        self.handle_signals();
+        handled
+    }
+
+    /// Sends a keyboard or IME event.
+    pub fn handle_text_event(&mut self, event: TextEvent) -> Handled {
+        let handled = self.render_root.handle_text_event(event);
+        self.handle_signals();
+        handled
+    }
+
+    /// Sends an accessibility action request.
+    pub fn handle_access_event(&mut self, event: ActionRequest) {
+        self.render_root.handle_access_event(event);
+        self.handle_signals();
+    }
+
+    /// Renders the UI into `view`.
+    ///
+    /// The texture must be the size given with [`WindowEvent::Resize`] (or on creation),
+    /// use the `Rgba8Unorm` format, and have the `STORAGE_BINDING` usage, as required
+    /// by Vello.
+    /// The same `device` must be used for every call, since the renderer is created with
+    /// it on the first call.
+    ///
+    /// This also advances animations, by the time elapsed since the last call.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of Vello if the renderer can't be created or the scene
+    /// can't be rendered.
+    pub fn render_to_texture(
+        &mut self,
+        view: &TextureView,
+        device: &Device,
+        queue: &Queue,
+    ) -> Result<(), vello::Error> {
+        let _span = info_span!("render_to_texture").entered();
+
+        let now = Instant::now();
+        let elapsed = self
+            .last_anim
+            .take()
+            .map(|last| now.duration_since(last))
+            .unwrap_or_default();
+        self.render_root
+            .handle_window_event(WindowEvent::AnimFrame(elapsed));
+        // If a new animation starts, then it will have zero reported elapsed time.
+        self.last_anim = self.render_root.needs_anim().then_some(now);
+
+        self.render_root.set_base_color(self.base_color);
+        let (scene, tree_update) = self.render_root.redraw();
+        if let Some(tree_update) = tree_update {
+            self.host.update_accessibility(tree_update);
+        }
+
+        let renderer = match &mut self.renderer {
+            Some(renderer) => renderer,
+            None => self.renderer.insert(Renderer::new(
+                device,
+                RendererOptions {
+                    antialiasing_support: AaSupport::area_only(),
+                    ..Default::default()
+                },
+            )?),
+        };
+
+        let scale_factor = self.render_root.global_state.scale_factor;
+        let mut scaled_scene;
+        let scene = if scale_factor == 1.0 {
+            &scene
+        } else {
+            scaled_scene = Scene::new();
+            scaled_scene.append(&scene, Some(Affine::scale(scale_factor)));
+            &scaled_scene
+        };
+        let size = self.render_root.size();
+        let render_params = RenderParams {
+            base_color: self.base_color,
+            width: size.width,
+            height: size.height,
+            antialiasing_method: self.render_root.antialiasing(),
+        };
+        self.render_root
+            .render_wgpu_surfaces(device, queue, renderer);
+        renderer.render_to_texture(device, queue, scene, view, &render_params)?;
+
+        for (widget_id, scale) in std::mem::take(&mut self.pending_images) {
+            let Some(image) = self
+                .render_root
+                .render_to_image(widget_id, scale, device, queue, renderer)
+            else {
+                continue;
+            };
+            self.host
+                .on_action(Box::new(RenderedImage { image, scale }), widget_id);
+        }
+
+        self.handle_signals();
+        Ok(())
+    }
+
+    /// Passes the signals emitted by the render root to the host.
+    fn handle_signals(&mut self) {
+        while let Ok(signal) = self.signal_receiver.try_recv() {
+            match signal {
+                RenderRootSignal::Action(action, widget_id) => {
+                    self.host.on_action(action, widget_id);
+                }
+                RenderRootSignal::RequestRedraw | RenderRootSignal::RequestAnimFrame => {
+                    self.host.request_redraw();
+                }
+                RenderRootSignal::SetCursor(cursor) => self.host.set_cursor(cursor),
+                RenderRootSignal::StartIme(purpose) => self.host.start_ime(purpose),
+                RenderRootSignal::EndIme => self.host.end_ime(),
+                RenderRootSignal::ImeMoved(position, size) => {
+                    self.host.set_ime_area(position, size);
+                }
+                RenderRootSignal::ClipboardStore(text) => self.host.set_clipboard(text),
+                RenderRootSignal::OpenUrl(url) => self.host.open_url(url),
+                RenderRootSignal::NewLayer(_type, root, pos) => {
+                    self.render_root.add_layer(root, pos);
+                }
+                RenderRootSignal::RemoveLayer(root_id) => self.render_root.remove_layer(root_id),
+                RenderRootSignal::RepositionLayer(root_id, pos) => {
+                    self.render_root.reposition_layer(root_id, pos);
+                }
+                RenderRootSignal::RenderToImage(widget_id, scale) => {
+                    // The image is rendered with the device of the next frame.
+                    self.pending_images.push((widget_id, scale));
+                    self.host.request_redraw();
+                }
+                signal => self.host.on_signal(signal),
+            }
+        }
+    }
+}
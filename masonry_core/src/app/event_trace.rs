@@ -0,0 +1,514 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+use ui_events::keyboard::{Code, Key, KeyState, Location};
+
+use crate::core::{
+    Ime, KeyboardEvent, Modifiers, PointerButton, PointerButtonEvent, PointerEvent, PointerGesture,
+    PointerGestureEvent, PointerId, PointerInfo, PointerScrollEvent, PointerState, PointerType,
+    PointerUpdate, ScrollDelta, TextEvent, WindowEvent,
+};
+use crate::dpi::{PhysicalPosition, PhysicalSize};
+use crate::util::Duration;
+
+/// A recording of the input events received by a [`RenderRoot`](crate::app::RenderRoot).
+///
+/// Traces are recorded with [`RenderRoot::start_recording_events`] and fed back with
+/// [`RenderRoot::replay_events`].
+/// As animation frames are recorded with their duration, replaying a trace doesn't depend on
+/// the wall clock, which makes it suitable for reproducing interaction bugs and for regression tests.
+///
+/// Traces are stored as JSON, with one event per line.
+/// Accessibility events aren't recorded, and neither are pointer buttons beyond the primary,
+/// secondary, auxiliary, back, forward and eraser buttons.
+///
+/// [`RenderRoot::start_recording_events`]: crate::app::RenderRoot::start_recording_events
+/// [`RenderRoot::replay_events`]: crate::app::RenderRoot::replay_events
+#[derive(Clone, Debug, Default)]
+pub struct EventTrace {
+    /// The recorded events, in the order they were received.
+    pub events: Vec<TracedEvent>,
+}
+
+/// An event of an [`EventTrace`].
+#[derive(Clone, Debug)]
+pub struct TracedEvent {
+    /// The time at which the event was received, since the start of the recording.
+    pub time: Duration,
+    /// The event.
+    pub event: TraceEvent,
+}
+
+/// An input event of an [`EventTrace`].
+#[derive(Clone, Debug)]
+pub enum TraceEvent {
+    /// A pointer event.
+    Pointer(PointerEvent),
+    /// A text event.
+    Text(TextEvent),
+    /// A window event.
+    Window(WindowEvent),
+}
+
+impl EventTrace {
+    /// Creates an empty trace.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `event`, received at `time` since the start of the recording.
+    pub fn push(&mut self, time: Duration, event: TraceEvent) {
+        self.events.push(TracedEvent { time, event });
+    }
+
+    /// Serializes the trace as JSON, with one event per line.
+    pub fn to_json_lines(&self) -> String {
+        let mut pointer_ids = Vec::new();
+        let mut json = String::new();
+        for event in &self.events {
+            let record = EventRecord {
+                time_us: u64::try_from(event.time.as_micros()).unwrap_or(u64::MAX),
+                event: EventKindRecord::new(&event.event, &mut pointer_ids),
+            };
+            json.push_str(&serde_json::to_string(&record).unwrap());
+            json.push('\n');
+        }
+        json
+    }
+
+    /// Parses a trace serialized with [`to_json_lines`](Self::to_json_lines).
+    ///
+    /// Empty lines are ignored.
+    pub fn from_json_lines(json: &str) -> Result<Self, serde_json::Error> {
+        let mut trace = Self::new();
+        for line in json.lines().filter(|line| !line.trim().is_empty()) {
+            let record: EventRecord = serde_json::from_str(line)?;
+            trace.push(
+                Duration::from_micros(record.time_us),
+                record.event.into_event(),
+            );
+        }
+        Ok(trace)
+    }
+}
+
+// --- MARK: RECORDS
+// Most event types come from `ui-events` and don't implement `Serialize`,
+// so we serialize them through these mirror types.
+
+#[derive(Serialize, Deserialize)]
+struct EventRecord {
+    time_us: u64,
+    event: EventKindRecord,
+}
+
+#[derive(Serialize, Deserialize)]
+enum EventKindRecord {
+    Pointer(PointerRecord),
+    Text(TextRecord),
+    Window(WindowRecord),
+}
+
+impl EventKindRecord {
+    fn new(event: &TraceEvent, pointer_ids: &mut Vec<PointerId>) -> Self {
+        match event {
+            TraceEvent::Pointer(event) => Self::Pointer(PointerRecord::new(event, pointer_ids)),
+            TraceEvent::Text(event) => Self::Text(TextRecord::new(event)),
+            TraceEvent::Window(event) => Self::Window(WindowRecord::new(event)),
+        }
+    }
+
+    fn into_event(self) -> TraceEvent {
+        match self {
+            Self::Pointer(record) => TraceEvent::Pointer(record.into_event()),
+            Self::Text(record) => TraceEvent::Text(record.into_event()),
+            Self::Window(record) => TraceEvent::Window(record.into_event()),
+        }
+    }
+}
+
+// --- MARK: POINTER
+#[derive(Serialize, Deserialize)]
+enum PointerRecord {
+    Down(PointerInfoRecord, Option<ButtonRecord>, PointerStateRecord),
+    Up(PointerInfoRecord, Option<ButtonRecord>, PointerStateRecord),
+    Move(PointerInfoRecord, PointerStateRecord),
+    Cancel(PointerInfoRecord),
+    Enter(PointerInfoRecord),
+    Leave(PointerInfoRecord),
+    Scroll(PointerInfoRecord, ScrollDeltaRecord, PointerStateRecord),
+    Gesture(PointerInfoRecord, GestureRecord, PointerStateRecord),
+}
+
+impl PointerRecord {
+    fn new(event: &PointerEvent, pointer_ids: &mut Vec<PointerId>) -> Self {
+        let mut info = |info: &PointerInfo| PointerInfoRecord::new(info, pointer_ids);
+        match event {
+            PointerEvent::Down(event) => Self::Down(
+                info(&event.pointer),
+                event.button.and_then(ButtonRecord::new),
+                PointerStateRecord::new(&event.state),
+            ),
+            PointerEvent::Up(event) => Self::Up(
+                info(&event.pointer),
+                event.button.and_then(ButtonRecord::new),
+                PointerStateRecord::new(&event.state),
+            ),
+            PointerEvent::Move(event) => Self::Move(
+                info(&event.pointer),
+                PointerStateRecord::new(&event.current),
+            ),
+            PointerEvent::Cancel(pointer) => Self::Cancel(info(pointer)),
+            PointerEvent::Enter(pointer) => Self::Enter(info(pointer)),
+            PointerEvent::Leave(pointer) => Self::Leave(info(pointer)),
+            PointerEvent::Scroll(event) => Self::Scroll(
+                info(&event.pointer),
+                ScrollDeltaRecord::new(&event.delta),
+                PointerStateRecord::new(&event.state),
+            ),
+            PointerEvent::Gesture(event) => Self::Gesture(
+                info(&event.pointer),
+                GestureRecord::new(&event.gesture),
+                PointerStateRecord::new(&event.state),
+            ),
+        }
+    }
+
+    fn into_event(self) -> PointerEvent {
+        match self {
+            Self::Down(pointer, button, state) => PointerEvent::Down(PointerButtonEvent {
+                pointer: pointer.into_info(),
+                button: button.map(ButtonRecord::into_button),
+                state: state.into_state(),
+            }),
+            Self::Up(pointer, button, state) => PointerEvent::Up(PointerButtonEvent {
+                pointer: pointer.into_info(),
+                button: button.map(ButtonRecord::into_button),
+                state: state.into_state(),
+            }),
+            Self::Move(pointer, state) => PointerEvent::Move(PointerUpdate {
+                pointer: pointer.into_info(),
+                current: state.into_state(),
+                coalesced: vec![],
+                predicted: vec![],
+            }),
+            Self::Cancel(pointer) => PointerEvent::Cancel(pointer.into_info()),
+            Self::Enter(pointer) => PointerEvent::Enter(pointer.into_info()),
+            Self::Leave(pointer) => PointerEvent::Leave(pointer.into_info()),
+            Self::Scroll(pointer, delta, state) => PointerEvent::Scroll(PointerScrollEvent {
+                pointer: pointer.into_info(),
+                delta: delta.into_delta(),
+                state: state.into_state(),
+            }),
+            Self::Gesture(pointer, gesture, state) => PointerEvent::Gesture(PointerGestureEvent {
+                pointer: pointer.into_info(),
+                gesture: gesture.into_gesture(),
+                state: state.into_state(),
+            }),
+        }
+    }
+}
+
+/// The pointer id is stored as `1` for the primary pointer, and as the order in which
+/// the pointer first appeared in the trace (starting at `2`) otherwise.
+#[derive(Serialize, Deserialize)]
+struct PointerInfoRecord {
+    id: Option<u64>,
+    pointer_type: PointerTypeRecord,
+}
+
+impl PointerInfoRecord {
+    fn new(info: &PointerInfo, pointer_ids: &mut Vec<PointerId>) -> Self {
+        let id = info.pointer_id.map(|pointer_id| {
+            if pointer_id == PointerId::PRIMARY {
+                return 1;
+            }
+            let idx = match pointer_ids.iter().position(|id| *id == pointer_id) {
+                Some(idx) => idx,
+                None => {
+                    pointer_ids.push(pointer_id);
+                    pointer_ids.len() - 1
+                }
+            };
+            idx as u64 + 2
+        });
+        let pointer_type = match info.pointer_type {
+            PointerType::Mouse => PointerTypeRecord::Mouse,
+            PointerType::Touch => PointerTypeRecord::Touch,
+            PointerType::Pen => PointerTypeRecord::Pen,
+            _ => PointerTypeRecord::Unknown,
+        };
+        Self { id, pointer_type }
+    }
+
+    fn into_info(self) -> PointerInfo {
+        let pointer_id = self.id.and_then(|id| match id {
+            1 => Some(PointerId::PRIMARY),
+            id => PointerId::new(id),
+        });
+        let pointer_type = match self.pointer_type {
+            PointerTypeRecord::Mouse => PointerType::Mouse,
+            PointerTypeRecord::Touch => PointerType::Touch,
+            PointerTypeRecord::Pen => PointerType::Pen,
+            PointerTypeRecord::Unknown => PointerType::Unknown,
+        };
+        PointerInfo {
+            pointer_id,
+            persistent_device_id: None,
+            pointer_type,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+enum PointerTypeRecord {
+    Mouse,
+    Touch,
+    Pen,
+    Unknown,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+enum ButtonRecord {
+    Primary,
+    Secondary,
+    Auxiliary,
+    X1,
+    X2,
+    PenEraser,
+}
+
+impl ButtonRecord {
+    const ALL: [Self; 6] = [
+        Self::Primary,
+        Self::Secondary,
+        Self::Auxiliary,
+        Self::X1,
+        Self::X2,
+        Self::PenEraser,
+    ];
+
+    fn new(button: PointerButton) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|record| record.into_button() == button)
+    }
+
+    fn into_button(self) -> PointerButton {
+        match self {
+            Self::Primary => PointerButton::Primary,
+            Self::Secondary => PointerButton::Secondary,
+            Self::Auxiliary => PointerButton::Auxiliary,
+            Self::X1 => PointerButton::X1,
+            Self::X2 => PointerButton::X2,
+            Self::PenEraser => PointerButton::PenEraser,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct PointerStateRecord {
+    time: u64,
+    x: f64,
+    y: f64,
+    buttons: Vec<ButtonRecord>,
+    modifiers: Modifiers,
+    count: u8,
+    pressure: f32,
+    scale_factor: f64,
+}
+
+impl PointerStateRecord {
+    fn new(state: &PointerState) -> Self {
+        Self {
+            time: state.time,
+            x: state.position.x,
+            y: state.position.y,
+            buttons: ButtonRecord::ALL
+                .into_iter()
+                .filter(|button| state.buttons.contains(button.into_button()))
+                .collect(),
+            modifiers: state.modifiers,
+            count: state.count,
+            pressure: state.pressure,
+            scale_factor: state.scale_factor,
+        }
+    }
+
+    fn into_state(self) -> PointerState {
+        let mut state = PointerState {
+            time: self.time,
+            position: PhysicalPosition::new(self.x, self.y),
+            modifiers: self.modifiers,
+            count: self.count,
+            pressure: self.pressure,
+            scale_factor: self.scale_factor,
+            ..PointerState::default()
+        };
+        for button in self.buttons {
+            state.buttons.insert(button.into_button());
+        }
+        state
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+enum ScrollDeltaRecord {
+    Page(f32, f32),
+    Line(f32, f32),
+    Pixel(f64, f64),
+}
+
+impl ScrollDeltaRecord {
+    fn new(delta: &ScrollDelta) -> Self {
+        match *delta {
+            ScrollDelta::PageDelta(x, y) => Self::Page(x, y),
+            ScrollDelta::LineDelta(x, y) => Self::Line(x, y),
+            ScrollDelta::PixelDelta(position) => Self::Pixel(position.x, position.y),
+        }
+    }
+
+    fn into_delta(self) -> ScrollDelta {
+        match self {
+            Self::Page(x, y) => ScrollDelta::PageDelta(x, y),
+            Self::Line(x, y) => ScrollDelta::LineDelta(x, y),
+            Self::Pixel(x, y) => ScrollDelta::PixelDelta(PhysicalPosition::new(x, y)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+enum GestureRecord {
+    Pinch(f32),
+    Rotate(f32),
+}
+
+impl GestureRecord {
+    fn new(gesture: &PointerGesture) -> Self {
+        match *gesture {
+            PointerGesture::Pinch(delta) => Self::Pinch(delta),
+            PointerGesture::Rotate(delta) => Self::Rotate(delta),
+        }
+    }
+
+    fn into_gesture(self) -> PointerGesture {
+        match self {
+            Self::Pinch(delta) => PointerGesture::Pinch(delta),
+            Self::Rotate(delta) => PointerGesture::Rotate(delta),
+        }
+    }
+}
+
+// --- MARK: TEXT
+#[derive(Serialize, Deserialize)]
+enum TextRecord {
+    Keyboard(KeyboardRecord),
+    ImeEnabled,
+    ImePreedit(String, Option<(usize, usize)>),
+    ImeCommit(String),
+    ImeDisabled,
+    WindowFocusChange(bool),
+    ClipboardPaste(String),
+}
+
+impl TextRecord {
+    fn new(event: &TextEvent) -> Self {
+        match event {
+            TextEvent::Keyboard(event) => Self::Keyboard(KeyboardRecord {
+                state: event.state,
+                key: event.key.clone(),
+                code: event.code,
+                location: event.location,
+                modifiers: event.modifiers,
+                repeat: event.repeat,
+                is_composing: event.is_composing,
+            }),
+            TextEvent::Ime(Ime::Enabled) => Self::ImeEnabled,
+            TextEvent::Ime(Ime::Preedit(text, cursor)) => Self::ImePreedit(text.clone(), *cursor),
+            TextEvent::Ime(Ime::Commit(text)) => Self::ImeCommit(text.clone()),
+            TextEvent::Ime(Ime::Disabled) => Self::ImeDisabled,
+            TextEvent::WindowFocusChange(focused) => Self::WindowFocusChange(*focused),
+            TextEvent::ClipboardPaste(text) => Self::ClipboardPaste(text.clone()),
+        }
+    }
+
+    fn into_event(self) -> TextEvent {
+        match self {
+            Self::Keyboard(record) => TextEvent::Keyboard(KeyboardEvent {
+                state: record.state,
+                key: record.key,
+                code: record.code,
+                location: record.location,
+                modifiers: record.modifiers,
+                repeat: record.repeat,
+                is_composing: record.is_composing,
+            }),
+            Self::ImeEnabled => TextEvent::Ime(Ime::Enabled),
+            Self::ImePreedit(text, cursor) => TextEvent::Ime(Ime::Preedit(text, cursor)),
+            Self::ImeCommit(text) => TextEvent::Ime(Ime::Commit(text)),
+            Self::ImeDisabled => TextEvent::Ime(Ime::Disabled),
+            Self::WindowFocusChange(focused) => TextEvent::WindowFocusChange(focused),
+            Self::ClipboardPaste(text) => TextEvent::ClipboardPaste(text),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeyboardRecord {
+    state: KeyState,
+    key: Key,
+    code: Code,
+    location: Location,
+    modifiers: Modifiers,
+    repeat: bool,
+    is_composing: bool,
+}
+
+// --- MARK: WINDOW
+#[derive(Serialize, Deserialize)]
+enum WindowRecord {
+    Rescale(f64),
+    Resize(u32, u32),
+    AnimFrame { nanos: u64 },
+    EnableAccessTree,
+    DisableAccessTree,
+    ReducedMotionChanged(bool),
+    CaretBlinkChanged { nanos: Option<u64> },
+    TextScaleChanged(f64),
+}
+
+impl WindowRecord {
+    fn new(event: &WindowEvent) -> Self {
+        match *event {
+            WindowEvent::Rescale(scale_factor) => Self::Rescale(scale_factor),
+            WindowEvent::Resize(size) => Self::Resize(size.width, size.height),
+            WindowEvent::AnimFrame(duration) => Self::AnimFrame {
+                nanos: u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX),
+            },
+            WindowEvent::EnableAccessTree => Self::EnableAccessTree,
+            WindowEvent::DisableAccessTree => Self::DisableAccessTree,
+            WindowEvent::ReducedMotionChanged(reduced) => Self::ReducedMotionChanged(reduced),
+            WindowEvent::CaretBlinkChanged(interval) => Self::CaretBlinkChanged {
+                nanos: interval
+                    .map(|interval| u64::try_from(interval.as_nanos()).unwrap_or(u64::MAX)),
+            },
+            WindowEvent::TextScaleChanged(scale) => Self::TextScaleChanged(scale),
+        }
+    }
+
+    fn into_event(self) -> WindowEvent {
+        match self {
+            Self::Rescale(scale_factor) => WindowEvent::Rescale(scale_factor),
+            Self::Resize(width, height) => WindowEvent::Resize(PhysicalSize::new(width, height)),
+            Self::AnimFrame { nanos } => WindowEvent::AnimFrame(Duration::from_nanos(nanos)),
+            Self::EnableAccessTree => WindowEvent::EnableAccessTree,
+            Self::DisableAccessTree => WindowEvent::DisableAccessTree,
+            Self::ReducedMotionChanged(reduced) => WindowEvent::ReducedMotionChanged(reduced),
+            Self::CaretBlinkChanged { nanos } => {
+                WindowEvent::CaretBlinkChanged(nanos.map(Duration::from_nanos))
+            }
+            Self::TextScaleChanged(scale) => WindowEvent::TextScaleChanged(scale),
+        }
+    }
+}
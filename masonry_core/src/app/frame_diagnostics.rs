@@ -0,0 +1,160 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt;
+use std::panic::Location;
+
+use tracing::debug;
+
+use crate::core::WidgetId;
+
+/// The kind of work a widget requested, which schedules a new frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FrameRequestKind {
+    /// A [`layout`](crate::core::Widget::layout) pass.
+    Layout,
+    /// A [`compose`](crate::core::Widget::compose) pass.
+    Compose,
+    /// A paint pass, for any of the paint methods.
+    Paint,
+    /// An [`accessibility`](crate::core::Widget::accessibility) pass.
+    Accessibility,
+    /// An animation frame.
+    AnimFrame,
+    /// A repaint on every animation frame, because the widget or one of its ancestors
+    /// [redraws continuously](crate::core::EventCtx::set_continuous_redraw).
+    ContinuousRedraw,
+}
+
+/// A request for work made by a widget while [frame diagnostics] were enabled.
+///
+/// [frame diagnostics]: crate::app::RenderRoot::set_frame_diagnostics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrameRequest {
+    /// The widget the work was requested for.
+    pub widget: WidgetId,
+    /// The kind of work requested.
+    pub kind: FrameRequestKind,
+    /// Where the context method making the request was called.
+    ///
+    /// This is `None` for work Masonry schedules on the widget's behalf, such as
+    /// continuous redraws.
+    pub location: Option<&'static Location<'static>>,
+}
+
+/// How often a given [`FrameRequest`] was made, as summarized by [`FrameDiagnostics::report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameRequestSummary {
+    /// The request.
+    pub request: FrameRequest,
+    /// The number of frames in which the request was made.
+    pub frames: u64,
+}
+
+/// Records which widgets requested work for each frame, and where they requested it from.
+///
+/// This helps answer questions like "why is my app repainting at 60fps?".
+/// Requests made through the context methods (such as [`request_layout`] or
+/// [`request_anim_frame`]) are attributed to the frame rendered after them.
+/// Each request is also logged at the `DEBUG` level with its call site.
+///
+/// See [`RenderRoot::set_frame_diagnostics`](crate::app::RenderRoot::set_frame_diagnostics).
+///
+/// [`request_layout`]: crate::core::EventCtx::request_layout
+/// [`request_anim_frame`]: crate::core::EventCtx::request_anim_frame
+#[derive(Debug, Default)]
+pub struct FrameDiagnostics {
+    /// The requests made since the last frame.
+    pending: Vec<FrameRequest>,
+    /// The requests which led to the last frame.
+    last_frame: Vec<FrameRequest>,
+    /// The number of frames in which each request was made.
+    counts: HashMap<FrameRequest, u64>,
+    frame_count: u64,
+}
+
+impl FrameDiagnostics {
+    pub(crate) fn record(&mut self, request: FrameRequest) {
+        debug!(
+            widget = %request.widget,
+            kind = ?request.kind,
+            location = request.location.map(tracing::field::display),
+            "frame requested"
+        );
+        // Requests are counted at most once per frame.
+        if !self.pending.contains(&request) {
+            self.pending.push(request);
+        }
+    }
+
+    pub(crate) fn finish_frame(&mut self) {
+        self.frame_count += 1;
+        for request in &self.pending {
+            *self.counts.entry(*request).or_default() += 1;
+        }
+        self.last_frame = std::mem::take(&mut self.pending);
+    }
+
+    /// Returns the number of frames recorded.
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Returns the requests which led to the last frame.
+    pub fn last_frame(&self) -> &[FrameRequest] {
+        &self.last_frame
+    }
+
+    /// Returns how often each request was made over the recorded frames, most frequent first.
+    pub fn report(&self) -> Vec<FrameRequestSummary> {
+        let mut report: Vec<_> = self
+            .counts
+            .iter()
+            .map(|(request, frames)| FrameRequestSummary {
+                request: *request,
+                frames: *frames,
+            })
+            .collect();
+        report.sort_by_key(|summary| {
+            let location = summary.request.location;
+            (
+                Reverse(summary.frames),
+                summary.request.widget.to_raw(),
+                summary.request.kind,
+                location.map(|location| (location.file(), location.line(), location.column())),
+            )
+        });
+        report
+    }
+
+    /// Returns the [report](Self::report) for a single widget.
+    pub fn report_for(&self, widget: WidgetId) -> Vec<FrameRequestSummary> {
+        let mut report = self.report();
+        report.retain(|summary| summary.request.widget == widget);
+        report
+    }
+
+    /// Discards the recorded frames.
+    pub fn clear(&mut self) {
+        self.last_frame.clear();
+        self.counts.clear();
+        self.frame_count = 0;
+    }
+}
+
+impl fmt::Display for FrameRequestSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let FrameRequest {
+            widget,
+            kind,
+            location,
+        } = self.request;
+        write!(f, "{widget} requested {kind:?} in {} frames", self.frames)?;
+        if let Some(location) = location {
+            write!(f, ", at {location}")?;
+        }
+        Ok(())
+    }
+}
@@ -3,14 +3,37 @@
 
 //! Types needed for running a Masonry app.
 
+mod accessibility_audit;
+mod embedded;
+mod event_trace;
+mod frame_diagnostics;
 mod layer_stack;
+mod overlay;
+mod print;
 mod render_root;
+mod render_to_image;
 mod tracing_backend;
+mod widget_profile;
 
+pub use accessibility_audit::{
+    AccessibilityAudit, AuditIssue, AuditIssueKind, AuditOptions, AuditTextColorFn,
+};
+pub use embedded::{EmbeddedRoot, EmbedderHost};
+pub use event_trace::{EventTrace, TraceEvent, TracedEvent};
+pub use frame_diagnostics::{
+    FrameDiagnostics, FrameRequest, FrameRequestKind, FrameRequestSummary,
+};
+pub use overlay::{OverlayId, OverlayItem};
+pub use print::{PageSetup, PrintedDocument};
 pub use render_root::{RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy};
+pub use render_to_image::RenderedImage;
 pub use tracing_backend::{
     TracingSubscriberHasBeenSetError, default_tracing_subscriber, try_init_test_tracing,
     try_init_tracing,
 };
+pub use widget_profile::{ProfiledPass, WidgetProfile, WidgetTiming};
 
-pub(crate) use render_root::{MutateCallback, RenderRootState};
+pub(crate) use render_root::{
+    EdgeAutoScroll, IdleCallback, MutateCallback, RenderRootState, Timer,
+};
+pub(crate) use render_to_image::image_size;
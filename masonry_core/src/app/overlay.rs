@@ -0,0 +1,166 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use vello::Scene;
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::Fill;
+
+use crate::core::WidgetId;
+use crate::util::Duration;
+
+/// The identifier of an item of the window overlay.
+///
+/// See [`add_overlay`](crate::core::EventCtx::add_overlay).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OverlayId(u64);
+
+type PaintFn = Box<dyn FnMut(&mut Scene, Rect, Duration)>;
+
+/// A transient visual painted above every widget of the window, such as a drag preview,
+/// a ripple or a highlight.
+///
+/// Items are added with [`add_overlay`] and stay until they're removed with
+/// [`remove_overlay`], their [lifetime](Self::with_lifetime) ends, or the widget which added
+/// them is removed.
+///
+/// [`add_overlay`]: crate::core::EventCtx::add_overlay
+/// [`remove_overlay`]: crate::core::EventCtx::remove_overlay
+pub struct OverlayItem {
+    bounds: Rect,
+    lifetime: Option<Duration>,
+    paint: PaintFn,
+}
+
+impl OverlayItem {
+    /// Creates an item covering `bounds`, in window coordinates, painted by `paint`.
+    ///
+    /// `paint` is called on every frame with the scene to paint into, the bounds of the item
+    /// and the time elapsed since it was added.
+    /// Painting is clipped to the bounds.
+    pub fn new(bounds: Rect, paint: impl FnMut(&mut Scene, Rect, Duration) + 'static) -> Self {
+        Self {
+            bounds,
+            lifetime: None,
+            paint: Box::new(paint),
+        }
+    }
+
+    /// Removes the item once `lifetime` has elapsed.
+    ///
+    /// Items with a lifetime request animation frames, so that they can animate
+    /// based on the elapsed time.
+    pub fn with_lifetime(mut self, lifetime: Duration) -> Self {
+        self.lifetime = Some(lifetime);
+        self
+    }
+}
+
+impl fmt::Debug for OverlayItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OverlayItem")
+            .field("bounds", &self.bounds)
+            .field("lifetime", &self.lifetime)
+            .finish_non_exhaustive()
+    }
+}
+
+struct OverlayEntry {
+    id: OverlayId,
+    owner: WidgetId,
+    age: Duration,
+    item: OverlayItem,
+}
+
+/// The items painted above every widget, in the order they were added.
+#[derive(Default)]
+pub(crate) struct Overlay {
+    entries: Vec<OverlayEntry>,
+    next_id: u64,
+    /// The bounds of the items last painted, in window coordinates.
+    painted_bounds: Vec<Rect>,
+    /// Whether the items changed since they were last painted.
+    pub(crate) needs_paint: bool,
+}
+
+impl Overlay {
+    pub(crate) fn add(&mut self, owner: WidgetId, item: OverlayItem) -> OverlayId {
+        let id = OverlayId(self.next_id);
+        self.next_id += 1;
+        self.entries.push(OverlayEntry {
+            id,
+            owner,
+            age: Duration::ZERO,
+            item,
+        });
+        self.needs_paint = true;
+        id
+    }
+
+    pub(crate) fn set_bounds(&mut self, id: OverlayId, bounds: Rect) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == id)
+            && entry.item.bounds != bounds
+        {
+            entry.item.bounds = bounds;
+            self.needs_paint = true;
+        }
+    }
+
+    pub(crate) fn remove(&mut self, id: OverlayId) {
+        self.retain(|entry| entry.id != id);
+    }
+
+    /// Removes the items added by `owner`.
+    pub(crate) fn remove_owned_by(&mut self, owner: WidgetId) {
+        self.retain(|entry| entry.owner != owner);
+    }
+
+    /// Ages the items by `elapsed`, and removes the ones whose lifetime has ended.
+    pub(crate) fn advance(&mut self, elapsed: Duration) {
+        for entry in &mut self.entries {
+            if entry.item.lifetime.is_some() {
+                entry.age += elapsed;
+                self.needs_paint = true;
+            }
+        }
+        self.retain(|entry| {
+            entry
+                .item
+                .lifetime
+                .is_none_or(|lifetime| entry.age < lifetime)
+        });
+    }
+
+    /// Whether some items are waiting for an animation frame.
+    pub(crate) fn needs_anim(&self) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| entry.item.lifetime.is_some())
+    }
+
+    /// Paints the items into `scene`, and adds the regions which changed to `damage`.
+    pub(crate) fn paint(&mut self, scene: &mut Scene, damage: &mut Vec<Rect>) {
+        for entry in &mut self.entries {
+            let bounds = entry.item.bounds;
+            scene.push_clip_layer(Fill::NonZero, Affine::IDENTITY, &bounds);
+            (entry.item.paint)(scene, bounds, entry.age);
+            scene.pop_layer();
+        }
+
+        if self.needs_paint {
+            self.needs_paint = false;
+            damage.append(&mut self.painted_bounds);
+            self.painted_bounds = self.entries.iter().map(|entry| entry.item.bounds).collect();
+            damage.extend(self.painted_bounds.iter().copied());
+        }
+    }
+
+    fn retain(&mut self, f: impl FnMut(&OverlayEntry) -> bool) {
+        let len = self.entries.len();
+        self.entries.retain(f);
+        if self.entries.len() != len {
+            self.needs_paint = true;
+        }
+    }
+}
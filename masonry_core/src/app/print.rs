@@ -0,0 +1,404 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Write as _;
+
+use dpi::PhysicalSize;
+use tracing::warn;
+use vello::kurbo::{Affine, Insets, Rect, Size, Vec2};
+use vello::peniko::{Color, Fill, ImageData};
+use vello::wgpu::{Device, Queue};
+use vello::{Renderer, Scene};
+
+use crate::app::render_to_image::rasterize;
+use crate::app::{RenderRoot, RenderRootOptions, WindowSizePolicy};
+use crate::core::{NewWidget, Widget, WidgetId};
+
+/// The number of PDF points per logical pixel: a logical pixel is 1/96 of an inch when
+/// printed, and a point is 1/72 of an inch.
+const POINTS_PER_PIXEL: f64 = 0.75;
+
+/// The size and margins of the pages a [`PrintedDocument`] is laid out on.
+///
+/// Lengths are in logical pixels, which are 1/96 of an inch when printed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PageSetup {
+    /// The size of the pages.
+    pub size: Size,
+    /// The space between the edges of the pages and the widgets.
+    pub margins: Insets,
+}
+
+impl PageSetup {
+    /// A4 pages, 210 by 297 millimeters, with half-inch margins.
+    pub const A4: Self = Self {
+        size: Size::new(793.7, 1122.5),
+        margins: Insets::uniform(48.),
+    };
+
+    /// US Letter pages, 8.5 by 11 inches, with half-inch margins.
+    pub const LETTER: Self = Self {
+        size: Size::new(816., 1056.),
+        margins: Insets::uniform(48.),
+    };
+
+    /// The default page setup, which is [`A4`](Self::A4).
+    pub const DEFAULT: Self = Self::A4;
+
+    /// Returns the size of the area within the margins, which the widgets are laid out in.
+    pub fn content_size(&self) -> Size {
+        Size::new(
+            (self.size.width - self.margins.x0 - self.margins.x1).max(1.),
+            (self.size.height - self.margins.y0 - self.margins.y1).max(1.),
+        )
+    }
+}
+
+impl Default for PageSetup {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A widget tree laid out on pages, to be printed or exported to PDF.
+///
+/// The tree is laid out once, at the width of the pages' [content area](PageSetup::content_size)
+/// and at the height its content needs, with [`WindowSizePolicy::ContentHeight`].
+/// Scrolling containers such as `Portal` are measured at the size of their content,
+/// so the whole content is printed, across as many pages as needed.
+///
+/// The tree is then split into pages.
+/// Pages break above widgets without children which would straddle the bottom of the page,
+/// so that lines of text and images aren't cut in half.
+/// Widgets taller than a page are cut at the bottom of the page.
+///
+/// This lets report-style apps produce documents from the same widgets they show.
+/// Masonry doesn't drive the platform's print dialog: apps can hand the PDF from
+/// [`to_pdf`](Self::to_pdf) to it, or render each [`page`](Self::page) themselves.
+pub struct PrintedDocument {
+    root: RenderRoot,
+    scene: Scene,
+    setup: PageSetup,
+    /// The vertical positions the pages start at in the laid out tree, followed by its height.
+    breaks: Vec<f64>,
+}
+
+impl PrintedDocument {
+    /// Lays out `root_widget` on pages with the given `setup`.
+    ///
+    /// The `size_policy`, `size` and `scale_factor` of `options` are overridden to fit
+    /// the pages, and the widgets are painted on a white background.
+    pub fn new(
+        root_widget: NewWidget<impl Widget + ?Sized>,
+        options: RenderRootOptions,
+        setup: PageSetup,
+    ) -> Self {
+        let content_size = setup.content_size();
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "Page sizes are far below u32::MAX pixels"
+        )]
+        let size = PhysicalSize::new(
+            content_size.width.floor() as u32,
+            content_size.height.floor() as u32,
+        );
+        let mut root = RenderRoot::new(
+            root_widget,
+            |_| {},
+            RenderRootOptions {
+                size_policy: WindowSizePolicy::ContentHeight,
+                size,
+                scale_factor: 1.,
+                ..options
+            },
+        );
+        root.set_base_color(Color::WHITE);
+        let (scene, _) = root.redraw();
+
+        let root_id = root.root_id();
+        let height = root
+            .widget_arena
+            .get_state(root_id)
+            .border_box_size()
+            .height;
+        let mut leaves = Vec::new();
+        collect_leaves(&root, root_id, &mut leaves);
+        let breaks = page_breaks(height, content_size.height, &leaves);
+        Self {
+            root,
+            scene,
+            setup,
+            breaks,
+        }
+    }
+
+    /// Returns the page setup the document was laid out with.
+    pub fn setup(&self) -> PageSetup {
+        self.setup
+    }
+
+    /// Returns the number of pages.
+    pub fn page_count(&self) -> usize {
+        self.breaks.len() - 1
+    }
+
+    /// Returns the part of the laid out widget tree shown on the page at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn page_content(&self, index: usize) -> Rect {
+        let width = self.setup.content_size().width;
+        Rect::new(0., self.breaks[index], width, self.breaks[index + 1])
+    }
+
+    /// Returns the scene of the page at `index`, in logical pixels from the top left
+    /// corner of the page, on a white background.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn page(&self, index: usize) -> Scene {
+        let content = self.page_content(index);
+        let origin = Vec2::new(self.setup.margins.x0, self.setup.margins.y0);
+        let mut scene = Scene::new();
+        scene.fill(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            Color::WHITE,
+            None,
+            &self.setup.size.to_rect(),
+        );
+        scene.push_clip_layer(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            &(content.with_origin((0., 0.)) + origin),
+        );
+        scene.append(
+            &self.scene,
+            Some(Affine::translate(origin - Vec2::new(0., content.y0))),
+        );
+        scene.pop_layer();
+        scene
+    }
+
+    /// Renders the pages and returns them as a PDF file.
+    ///
+    /// Each page is rendered to an image with `scale` pixels per logical pixel, e.g. `3.`
+    /// for 288 DPI, with the Vello renderer and the `wgpu` device it was created with.
+    ///
+    /// Returns `None` if a page couldn't be rendered.
+    pub fn to_pdf(
+        &mut self,
+        scale: f64,
+        device: &Device,
+        queue: &Queue,
+        renderer: &mut Renderer,
+    ) -> Option<Vec<u8>> {
+        // The effects in the tree are drawn from their result.
+        self.root.render_wgpu_surfaces(device, queue, renderer);
+        let bounds = self.setup.size.to_rect();
+        let mut images = Vec::with_capacity(self.page_count());
+        for index in 0..self.page_count() {
+            match rasterize(&self.page(index), bounds, scale, device, queue, renderer) {
+                Ok(image) => images.push(image),
+                Err(err) => {
+                    warn!("Couldn't render page {index} of a printed document: {err}");
+                    return None;
+                }
+            }
+        }
+        Some(write_pdf(self.setup.size, &images))
+    }
+}
+
+impl std::fmt::Debug for PrintedDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PrintedDocument")
+            .field("setup", &self.setup)
+            .field("breaks", &self.breaks)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Pushes the vertical extent of the widgets without children in the subtree of `id`.
+fn collect_leaves(root: &RenderRoot, id: WidgetId, leaves: &mut Vec<(f64, f64)>) {
+    let node = root.widget_arena.get_node(id);
+    let state = &node.item.state;
+    if state.is_stashed {
+        return;
+    }
+    let children = node.item.widget.children_ids();
+    if children.is_empty() {
+        leaves.push((state.bounding_box.y0, state.bounding_box.y1));
+    }
+    for child in children {
+        collect_leaves(root, child, leaves);
+    }
+}
+
+/// Returns the vertical positions pages start at, followed by `height`, for content of that
+/// `height` split into pages of `page_height`.
+///
+/// Pages break above the `leaves` which would straddle the bottom of a page, unless they
+/// start at the top of that page.
+fn page_breaks(height: f64, page_height: f64, leaves: &[(f64, f64)]) -> Vec<f64> {
+    let mut breaks = vec![0.];
+    let mut start = 0.;
+    while height - start > page_height {
+        let limit = start + page_height;
+        start = leaves
+            .iter()
+            .filter(|(top, bottom)| *top > start && *top < limit && *bottom > limit)
+            .map(|(top, _)| *top)
+            .fold(limit, f64::min);
+        breaks.push(start);
+    }
+    breaks.push(height);
+    breaks
+}
+
+/// Returns a PDF file with a page of `page_size` logical pixels for each of the `images`,
+/// which are stretched to fill their page.
+fn write_pdf(page_size: Size, images: &[ImageData]) -> Vec<u8> {
+    let (width, height) = (
+        page_size.width * POINTS_PER_PIXEL,
+        page_size.height * POINTS_PER_PIXEL,
+    );
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::new();
+    let mut write_object = |pdf: &mut Vec<u8>, dict: String, stream: Option<&[u8]>| {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n{dict}\n", offsets.len()).as_bytes());
+        if let Some(stream) = stream {
+            pdf.extend_from_slice(b"stream\n");
+            pdf.extend_from_slice(stream);
+            pdf.extend_from_slice(b"\nendstream\n");
+        }
+        pdf.extend_from_slice(b"endobj\n");
+    };
+
+    // Objects 1 and 2 are the catalog and the page tree, then each page takes three objects:
+    // the page, its content stream and its image.
+    let kids = (0..images.len()).fold(String::new(), |mut kids, index| {
+        let _ = write!(kids, "{} 0 R ", 3 + 3 * index);
+        kids
+    });
+    write_object(&mut pdf, "<< /Type /Catalog /Pages 2 0 R >>".into(), None);
+    write_object(
+        &mut pdf,
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids.trim_end(),
+            images.len()
+        ),
+        None,
+    );
+    for (index, image) in images.iter().enumerate() {
+        let page = 3 + 3 * index;
+        write_object(
+            &mut pdf,
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {width} {height}] \
+                /Resources << /XObject << /Im0 {} 0 R >> >> /Contents {} 0 R >>",
+                page + 2,
+                page + 1
+            ),
+            None,
+        );
+        let content = format!("q {width} 0 0 {height} 0 0 cm /Im0 Do Q");
+        write_object(
+            &mut pdf,
+            format!("<< /Length {} >>", content.len()),
+            Some(content.as_bytes()),
+        );
+        // Pages are opaque, so the alpha channel is dropped.
+        let rgb: Vec<u8> = image
+            .data
+            .data()
+            .chunks_exact(4)
+            .flat_map(|pixel| &pixel[..3])
+            .copied()
+            .collect();
+        let compressed = miniz_oxide::deflate::compress_to_vec_zlib(&rgb, 6);
+        write_object(
+            &mut pdf,
+            format!(
+                "<< /Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceRGB \
+                /BitsPerComponent 8 /Filter /FlateDecode /Length {} >>",
+                image.width,
+                image.height,
+                compressed.len()
+            ),
+            Some(&compressed),
+        );
+    }
+
+    let xref_offset = pdf.len();
+    let mut xref = format!("xref\n0 {}\n0000000000 65535 f \n", offsets.len() + 1);
+    for offset in &offsets {
+        let _ = writeln!(xref, "{offset:010} 00000 n ");
+    }
+    let _ = write!(
+        xref,
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+        offsets.len() + 1
+    );
+    pdf.extend_from_slice(xref.as_bytes());
+    pdf
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use vello::peniko::{Blob, ImageAlphaType, ImageFormat};
+
+    use super::*;
+
+    #[test]
+    fn pages_break_above_straddling_leaves() {
+        // Content fitting in a page isn't split.
+        assert_eq!(page_breaks(80., 100., &[(0., 80.)]), [0., 80.]);
+
+        // The second line would straddle the bottom of the first page.
+        let lines = [(0., 60.), (60., 120.), (120., 180.), (180., 240.)];
+        assert_eq!(page_breaks(240., 100., &lines), [0., 60., 120., 180., 240.]);
+
+        // A leaf taller than a page is cut.
+        let leaves = [(0., 10.), (10., 250.), (250., 260.)];
+        assert_eq!(
+            page_breaks(260., 100., &leaves),
+            [0., 10., 110., 210., 260.]
+        );
+    }
+
+    #[test]
+    fn pdf_has_a_page_per_image() {
+        let image = ImageData {
+            data: Blob::new(Arc::new(vec![255; 2 * 3 * 4])),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 2,
+            height: 3,
+        };
+        let pdf = write_pdf(Size::new(200., 300.), &[image.clone(), image]);
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.starts_with("%PDF-1.4\n"));
+        assert!(text.ends_with("%%EOF\n"));
+        assert!(text.contains("/Kids [3 0 R 6 0 R] /Count 2"));
+        assert!(text.contains("/MediaBox [0 0 150 225]"));
+        assert!(text.contains("/Width 2 /Height 3"));
+
+        // The cross-reference table points at each object.
+        let startxref = text.rfind("startxref\n").unwrap() + "startxref\n".len();
+        let xref_offset: usize = text[startxref..].lines().next().unwrap().parse().unwrap();
+        let xref = std::str::from_utf8(&pdf[xref_offset..]).unwrap();
+        assert!(xref.starts_with("xref\n0 9\n"));
+        for (index, line) in xref.lines().skip(3).take(8).enumerate() {
+            let offset: usize = line[..10].parse().unwrap();
+            assert!(pdf[offset..].starts_with(format!("{} 0 obj\n", index + 1).as_bytes()));
+        }
+    }
+}
@@ -11,37 +11,54 @@ use parley::fontique::{Blob, Collection, CollectionOptions, FamilyId, FontInfo,
 use parley::{FontContext, LayoutContext};
 use tracing::{debug, info_span, warn};
 use tree_arena::{ArenaMut, TreeArena};
-use vello::Scene;
-use vello::kurbo::{Point, Rect, Size};
+use vello::kurbo::{Affine, Point, Rect, Size};
+use vello::peniko::{Color, ImageData};
+use vello::wgpu::{Device, Queue};
+use vello::{AaConfig, AaSupport, Renderer, Scene};
 
+use crate::app::accessibility_audit::{
+    AccessibilityAudit, AuditOptions, AuditState, run_accessibility_audit,
+};
+use crate::app::event_trace::{EventTrace, TraceEvent};
+use crate::app::frame_diagnostics::FrameDiagnostics;
 use crate::app::layer_stack::LayerStack;
+use crate::app::overlay::Overlay;
+use crate::app::render_to_image::rasterize;
+use crate::app::widget_profile::WidgetProfile;
 use crate::core::{
-    AccessCtx, AccessEvent, BrushIndex, CursorIcon, DefaultProperties, ErasedAction, FromDynWidget,
-    Handled, Ime, LayerType, NewWidget, PointerEvent, PropertiesRef, QueryCtx, ResizeDirection,
-    TextEvent, Widget, WidgetArena, WidgetArenaNode, WidgetId, WidgetMut, WidgetPod, WidgetRef,
-    WidgetState, WidgetTag, WidgetTagInner, WindowEvent,
+    AccessCtx, AccessEvent, AnnouncementPriority, BrushIndex, CursorIcon, DefaultProperties,
+    ErasedAction, FromDynWidget, Handled, Ime, ImePurpose, LayerType, NewWidget, PointerEvent,
+    PropertiesRef, QueryCtx, ResizeDirection, ScrollConfig, StyleSet, TextEvent, TimerToken,
+    WgpuEffect, WgpuSurface, Widget, WidgetArena, WidgetArenaNode, WidgetId, WidgetMut, WidgetPod,
+    WidgetRef, WidgetState, WidgetTag, WidgetTagInner, WindowEvent, render_text,
 };
+use crate::layout::UnitContext;
 use crate::passes::accessibility::run_accessibility_pass;
 use crate::passes::anim::run_update_anim_pass;
 use crate::passes::compose::run_compose_pass;
 use crate::passes::event::{
-    run_on_access_event_pass, run_on_pointer_event_pass, run_on_text_event_pass,
+    run_edge_auto_scroll_pass, run_on_access_event_pass, run_on_pointer_event_pass,
+    run_on_text_event_pass,
 };
 use crate::passes::layout::run_layout_pass;
-use crate::passes::mutate::{mutate_widget, run_mutate_pass};
-use crate::passes::paint::run_paint_pass;
+use crate::passes::mutate::{mutate_widget, run_idle_pass, run_mutate_pass};
+use crate::passes::paint::{run_paint_pass, run_paint_pass_for_subtree};
 use crate::passes::update::{
     run_update_disabled_pass, run_update_focus_pass, run_update_focusable_pass,
     run_update_pointer_pass, run_update_scroll_pass, run_update_stashed_pass,
-    run_update_widget_tree_pass,
+    run_update_timer_pass, run_update_widget_tree_pass,
 };
 use crate::passes::{PassTracing, recurse_on_children};
 use crate::properties::Dimensions;
+use crate::util::{Duration, Instant};
 
 /// We ensure that any valid initial IME area is sent to the platform by storing an invalid initial
 /// IME area as the `last_sent_ime_area`.
 const INVALID_IME_AREA: Rect = Rect::new(f64::NAN, f64::NAN, f64::NAN, f64::NAN);
 
+/// How long a blinking caret stays shown, then hidden, unless the platform reports otherwise.
+const DEFAULT_CARET_BLINK_INTERVAL: Duration = Duration::from_millis(500);
+
 // --- MARK: STRUCTS
 
 /// The composition root of Masonry.
@@ -59,6 +76,13 @@ pub struct RenderRoot {
     /// This is the id of that node.
     pub(crate) window_node_id: NodeId,
 
+    /// The ids of the hidden live regions of the window node, one per
+    /// [`AnnouncementPriority`], which announcements are made in.
+    pub(crate) live_region_ids: [NodeId; 2],
+
+    /// The nodes of the last announcements made in each live region.
+    pub(crate) announcement_ids: [Vec<NodeId>; 2],
+
     /// Whether the window size should be determined by the content or the user.
     pub(crate) size_policy: WindowSizePolicy,
 
@@ -76,6 +100,9 @@ pub struct RenderRoot {
 
     /// The widget tree; stores widgets and their states.
     pub(crate) widget_arena: WidgetArena,
+
+    /// The start time and events of the ongoing event recording, if any.
+    pub(crate) event_recording: Option<(Instant, EventTrace)>,
 }
 
 /// State shared between passes.
@@ -111,6 +138,15 @@ pub(crate) struct RenderRootState {
     /// in its border-box coordinate space.
     pub(crate) scroll_request_targets: Vec<(WidgetId, Rect)>,
 
+    /// Whether the focused widget should be scrolled into view once focus has been updated.
+    ///
+    /// This is set when focus moves through keyboard navigation.
+    pub(crate) scroll_to_focused: bool,
+
+    /// The drag which scrolls the scroll containers around it when the pointer gets near
+    /// their edges, if any.
+    pub(crate) edge_auto_scroll: Option<EdgeAutoScroll>,
+
     /// List of ancestors of the currently hovered widget.
     pub(crate) hovered_path: Vec<WidgetId>,
 
@@ -140,6 +176,9 @@ pub(crate) struct RenderRootState {
     /// List of callbacks that will run in the next `mutate` pass.
     pub(crate) mutate_callbacks: Vec<MutateCallback>,
 
+    /// List of callbacks that will run when the app is idle, in order.
+    pub(crate) idle_callbacks: Vec<IdleCallback>,
+
     /// Whether an IME session is active.
     pub(crate) is_ime_active: bool,
 
@@ -149,6 +188,21 @@ pub(crate) struct RenderRootState {
     /// Scene cache for the widget tree.
     pub(crate) scene_cache: HashMap<WidgetId, (Scene, Scene, Scene)>,
 
+    /// The bounding box each widget was last painted with, in window coordinates.
+    pub(crate) painted_bounds: HashMap<WidgetId, Rect>,
+
+    /// The regions painted differently since the last frame, in window coordinates.
+    pub(crate) damage: Vec<Rect>,
+
+    /// The damage of the last frame returned by [`RenderRoot::redraw`].
+    pub(crate) frame_damage: Vec<Rect>,
+
+    /// The transient visuals painted above every widget.
+    pub(crate) overlay: Overlay,
+
+    /// The timers requested by widgets which haven't fired yet.
+    pub(crate) timers: Vec<Timer>,
+
     pub(crate) widget_tags: HashMap<WidgetTagInner, WidgetId>,
 
     /// Whether data set in the pointer pass has been invalidated.
@@ -160,6 +214,15 @@ pub(crate) struct RenderRootState {
     /// Internal state of the widget inspector.
     pub(crate) inspector_state: InspectorState,
 
+    /// The frame diagnostics being recorded, if enabled.
+    pub(crate) frame_diagnostics: Option<FrameDiagnostics>,
+
+    /// The widget timings being recorded, if enabled.
+    pub(crate) widget_profile: Option<WidgetProfile>,
+
+    /// The accessibility audit, if enabled.
+    pub(crate) accessibility_audit: Option<AuditState>,
+
     /// Whether the next accessibility pass tree should be updated during `render()`.
     pub(crate) access_tree_active: bool,
 
@@ -170,6 +233,73 @@ pub(crate) struct RenderRootState {
 
     /// Whether to paint widget's bounding boxes and other visual helpers.
     pub(crate) debug_paint: bool,
+
+    /// Whether the user asked for non-essential motion to be minimized.
+    pub(crate) prefers_reduced_motion: bool,
+
+    /// How long a blinking caret stays shown, then hidden, if carets blink.
+    pub(crate) caret_blink_interval: Option<Duration>,
+
+    /// How scroll events move scrollable widgets.
+    pub(crate) scroll_config: ScrollConfig,
+
+    /// The announcements to make to screen readers in the next accessibility update.
+    pub(crate) pending_announcements: Vec<(String, AnnouncementPriority)>,
+
+    /// The color the window is cleared with, if known.
+    pub(crate) base_color: Option<Color>,
+
+    /// The values relative length units are resolved against.
+    ///
+    /// The `rem` unit is the root font size multiplied by the text scale.
+    pub(crate) units: UnitContext,
+    /// The root font size, in logical pixels, before text scaling.
+    pub(crate) root_font_size: f64,
+    /// The factor the app scales text by, such as a zoom level.
+    pub(crate) app_text_scale: f64,
+    /// The user's text size preference, as a factor.
+    pub(crate) system_text_scale: f64,
+    /// Whether a widget's dimensions were resolved with viewport-relative units,
+    /// in which case every widget is laid out again when the window is resized.
+    pub(crate) uses_viewport_units: bool,
+
+    /// The anti-aliasing method the window is rendered with.
+    pub(crate) antialiasing: AaConfig,
+    /// The anti-aliasing methods supported by the platform's renderer.
+    pub(crate) antialiasing_support: AaSupport,
+
+    /// The surfaces painted since they were last rendered.
+    pub(crate) painted_wgpu_surfaces: Vec<WgpuSurface>,
+    /// The surfaces whose texture is drawn by the renderer.
+    pub(crate) active_wgpu_surfaces: Vec<WgpuSurface>,
+    /// The effects painted since they were last rendered, innermost first.
+    pub(crate) painted_wgpu_effects: Vec<WgpuEffect>,
+    /// The effects whose result is drawn by the renderer.
+    pub(crate) active_wgpu_effects: Vec<WgpuEffect>,
+}
+
+/// A drag which [requested edge auto-scroll](crate::core::EventCtx::request_edge_auto_scroll).
+pub(crate) struct EdgeAutoScroll {
+    /// The widget being dragged, which has pointer capture.
+    pub(crate) source: WidgetId,
+    /// The last position of the pointer, in window coordinates.
+    pub(crate) position: Option<Point>,
+    /// Whether the pointer is released, in which case the scroll containers stop scrolling.
+    pub(crate) ended: bool,
+    /// Whether the ancestors of `source` haven't been sent the latest position yet.
+    pub(crate) needs_update: bool,
+    /// The last move event of the drag, sent again when `source` moves under the pointer.
+    pub(crate) last_move: Option<PointerEvent>,
+    /// The window transform of `source` when `last_move` was sent.
+    pub(crate) source_transform: Affine,
+}
+
+/// A timer [requested](crate::core::EventCtx::request_timer) by a widget.
+pub(crate) struct Timer {
+    /// The widget which gets [`Update::Timer`](crate::core::Update::Timer) when it fires.
+    pub(crate) widget: WidgetId,
+    pub(crate) token: TimerToken,
+    pub(crate) deadline: Instant,
 }
 
 pub(crate) struct MutateCallback {
@@ -177,6 +307,13 @@ pub(crate) struct MutateCallback {
     pub(crate) callback: Box<dyn FnOnce(WidgetMut<'_, dyn Widget>)>,
 }
 
+/// A callback queued with [`run_when_idle`](crate::core::EventCtx::run_when_idle).
+pub(crate) struct IdleCallback {
+    /// The time after which the callback runs even if the app isn't idle.
+    pub(crate) timeout: Instant,
+    pub(crate) callback: MutateCallback,
+}
+
 /// Defines how a window's size is determined.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub enum WindowSizePolicy {
@@ -184,6 +321,11 @@ pub enum WindowSizePolicy {
     ///
     /// The window size will match the root widget's maximum preferred size.
     Content,
+    /// Use the provided window width, and measure the content to determine the window height.
+    ///
+    /// The window height will match the root widget's maximum preferred height at that width.
+    /// This is how documents are laid out for [printing](crate::app::PrintedDocument).
+    ContentHeight,
     /// Use the provided window size.
     #[default]
     User,
@@ -226,14 +368,16 @@ pub struct RenderRootOptions {
 pub enum RenderRootSignal {
     /// A widget has emitted an action.
     Action(ErasedAction, WidgetId),
-    /// An IME session has been started.
-    StartIme,
+    /// An IME session has been started, for a widget with the given purpose.
+    StartIme(ImePurpose),
     /// The IME session has ended.
     EndIme,
     /// The IME area has been moved.
     ImeMoved(LogicalPosition<f64>, LogicalSize<f64>),
     /// A user interaction has sent something to the clipboard.
     ClipboardStore(String),
+    /// The URL should be opened with the platform's default handler.
+    OpenUrl(String),
     /// The window needs to be redrawn.
     RequestRedraw,
     /// The window should be redrawn for an animation frame. Currently this isn't really different from `RequestRedraw`.
@@ -287,6 +431,11 @@ pub enum RenderRootSignal {
     ///
     /// The given [`Point`] must be in the window's coordinate space.
     RepositionLayer(WidgetId, Point),
+    /// The widget's subtree should be rendered into an image, at the given scale.
+    ///
+    /// The platform should call [`RenderRoot::render_to_image`], and send the result back as a
+    /// [`RenderedImage`](crate::app::RenderedImage) action of the widget.
+    RenderToImage(WidgetId, f64),
 }
 
 /// State of the widget inspector. Useful for debugging.
@@ -295,6 +444,14 @@ pub enum RenderRootSignal {
 pub(crate) struct InspectorState {
     pub(crate) is_picking_widget: bool,
     pub(crate) hovered_widget: Option<WidgetId>,
+    /// The rect of the hovered widget overlay in the last frame, if any.
+    pub(crate) painted_hover_rect: Option<Rect>,
+    /// The rects of the frame request overlay in the last frame.
+    pub(crate) painted_request_rects: Vec<Rect>,
+    /// The rects of the widget profile overlay in the last frame.
+    pub(crate) painted_profile_rects: Vec<Rect>,
+    /// The rects of the accessibility audit overlay in the last frame.
+    pub(crate) painted_audit_rects: Vec<Rect>,
 }
 
 impl RenderRoot {
@@ -307,7 +464,10 @@ impl RenderRoot {
     ///
     /// Note that this doesn't create a window or start an event loop.
     /// The `masonry` crate doesn't provide a way to do that:
-    /// look for `masonry_winit::app::run` instead.
+    /// look for `masonry_winit::app::run` instead, or use an [`EmbeddedRoot`]
+    /// to render into an existing `wgpu` render loop.
+    ///
+    /// [`EmbeddedRoot`]: crate::app::EmbeddedRoot
     pub fn new(
         root_widget: NewWidget<impl Widget + ?Sized>,
         signal_sink: impl FnMut(RenderRootSignal) + 'static,
@@ -332,6 +492,8 @@ impl RenderRoot {
         let mut root = Self {
             layer_stack,
             window_node_id: AccessCtx::next_node_id(),
+            live_region_ids: [AccessCtx::next_node_id(), AccessCtx::next_node_id()],
+            announcement_ids: [Vec::new(), Vec::new()],
             size_policy,
             size,
             last_mouse_pos: None,
@@ -345,6 +507,8 @@ impl RenderRoot {
                 focus_fallback: None,
                 window_focused: true,
                 scroll_request_targets: Vec::new(),
+                scroll_to_focused: false,
+                edge_auto_scroll: None,
                 hovered_path: Vec::new(),
                 active_path: Vec::new(),
                 pointer_capture_target: None,
@@ -359,23 +523,53 @@ impl RenderRoot {
                 fonts_changed: false,
                 text_layout_context: LayoutContext::new(),
                 mutate_callbacks: Vec::new(),
+                idle_callbacks: Vec::new(),
                 is_ime_active: false,
                 last_sent_ime_area: INVALID_IME_AREA,
                 scene_cache: HashMap::new(),
+                painted_bounds: HashMap::new(),
+                damage: Vec::new(),
+                frame_damage: Vec::new(),
+                overlay: Overlay::default(),
+                timers: Vec::new(),
                 widget_tags: HashMap::new(),
                 needs_pointer_pass: false,
                 trace: PassTracing::from_env(),
                 inspector_state: InspectorState {
                     is_picking_widget: false,
                     hovered_widget: None,
+                    painted_hover_rect: None,
+                    painted_request_rects: Vec::new(),
+                    painted_profile_rects: Vec::new(),
+                    painted_audit_rects: Vec::new(),
                 },
+                frame_diagnostics: None,
+                widget_profile: None,
+                accessibility_audit: None,
                 access_tree_active: false,
                 scale_factor,
                 debug_paint,
+                prefers_reduced_motion: false,
+                caret_blink_interval: Some(DEFAULT_CARET_BLINK_INTERVAL),
+                scroll_config: ScrollConfig::DEFAULT,
+                pending_announcements: Vec::new(),
+                base_color: None,
+                units: UnitContext::DEFAULT,
+                root_font_size: UnitContext::DEFAULT.rem,
+                app_text_scale: 1.,
+                system_text_scale: 1.,
+                uses_viewport_units: false,
+                antialiasing: AaConfig::Area,
+                antialiasing_support: AaSupport::area_only(),
+                painted_wgpu_surfaces: Vec::new(),
+                active_wgpu_surfaces: Vec::new(),
+                painted_wgpu_effects: Vec::new(),
+                active_wgpu_effects: Vec::new(),
             },
             widget_arena: WidgetArena {
                 nodes: TreeArena::new(),
             },
+            event_recording: None,
         };
 
         if let Some(test_font_data) = test_font {
@@ -444,6 +638,7 @@ impl RenderRoot {
     // --- MARK: WINDOW_EVENT
     /// Handles a window event.
     pub fn handle_window_event(&mut self, event: WindowEvent) -> Handled {
+        self.record_event(|| TraceEvent::Window(event.clone()));
         match event {
             WindowEvent::Rescale(scale_factor) => {
                 self.global_state.scale_factor = scale_factor;
@@ -452,9 +647,14 @@ impl RenderRoot {
             }
             WindowEvent::Resize(size) => {
                 self.size = size;
-                self.root_state_mut().request_layout = true;
-                self.root_state_mut().set_needs_layout(true);
-                self.run_rewrite_passes();
+                if self.global_state.uses_viewport_units {
+                    // The measurements cached by widgets may depend on the viewport size.
+                    self.request_layout_all();
+                } else {
+                    self.root_state_mut().request_layout = true;
+                    self.root_state_mut().set_needs_layout(true);
+                    self.run_rewrite_passes();
+                }
                 Handled::Yes
             }
             WindowEvent::AnimFrame(duration) => {
@@ -476,6 +676,32 @@ impl RenderRoot {
                 self.global_state.access_tree_active = false;
                 Handled::Yes
             }
+            WindowEvent::TextScaleChanged(system_text_scale) => {
+                if !system_text_scale.is_finite() || system_text_scale <= 0. {
+                    debug_panic!("Invalid text scale '{system_text_scale}'");
+                    return Handled::Yes;
+                }
+                if self.global_state.system_text_scale != system_text_scale {
+                    self.global_state.system_text_scale = system_text_scale;
+                    self.text_scale_changed();
+                }
+                Handled::Yes
+            }
+            WindowEvent::ReducedMotionChanged(prefers_reduced_motion) => {
+                if self.global_state.prefers_reduced_motion != prefers_reduced_motion {
+                    self.global_state.prefers_reduced_motion = prefers_reduced_motion;
+                    // Lets widgets start or stop their animations.
+                    self.request_compose_all();
+                }
+                Handled::Yes
+            }
+            WindowEvent::CaretBlinkChanged(caret_blink_interval) => {
+                if self.global_state.caret_blink_interval != caret_blink_interval {
+                    self.global_state.caret_blink_interval = caret_blink_interval;
+                    self.request_render_all();
+                }
+                Handled::Yes
+            }
         }
     }
 
@@ -483,6 +709,7 @@ impl RenderRoot {
     /// Handles a pointer event.
     pub fn handle_pointer_event(&mut self, event: PointerEvent) -> Handled {
         let _span = info_span!("pointer_event");
+        self.record_event(|| TraceEvent::Pointer(event.clone()));
         let handled = run_on_pointer_event_pass(self, &event);
         run_update_pointer_pass(self);
         self.run_rewrite_passes();
@@ -493,6 +720,7 @@ impl RenderRoot {
     /// Handles a text event.
     pub fn handle_text_event(&mut self, event: TextEvent) -> Handled {
         let _span = info_span!("text_event");
+        self.record_event(|| TraceEvent::Text(event.clone()));
         let handled = run_on_text_event_pass(self, &event);
         run_update_focus_pass(self);
 
@@ -522,6 +750,72 @@ impl RenderRoot {
         self.run_rewrite_passes();
     }
 
+    /// Fires the timers requested by widgets which are due at `now`.
+    ///
+    /// The platform should call this at the time returned by
+    /// [`next_timer_deadline`](Self::next_timer_deadline).
+    pub fn run_timers(&mut self, now: Instant) {
+        let _span = info_span!("timers");
+        run_update_timer_pass(self, now);
+        self.run_rewrite_passes();
+    }
+
+    /// Runs the callbacks widgets queued to run when the app is idle, until `deadline`.
+    ///
+    /// The platform should call this when it has time to spare before the next frame,
+    /// with the time at which it must stop.
+    /// The callbacks run in the order they were queued, and the ones which timed out run
+    /// even past `deadline`.
+    pub fn run_idle_callbacks(&mut self, deadline: Instant) {
+        let _span = info_span!("idle_callbacks");
+        run_idle_pass(self, deadline);
+        self.run_rewrite_passes();
+    }
+
+    /// Starts recording the pointer, text and window events this handles into an [`EventTrace`].
+    ///
+    /// If a recording is already ongoing, it is discarded.
+    pub fn start_recording_events(&mut self) {
+        self.event_recording = Some((Instant::now(), EventTrace::new()));
+    }
+
+    /// Stops recording events, and returns the recorded trace.
+    ///
+    /// Returns `None` if no recording was ongoing.
+    pub fn stop_recording_events(&mut self) -> Option<EventTrace> {
+        self.event_recording.take().map(|(_, trace)| trace)
+    }
+
+    /// Returns `true` if events are being recorded.
+    pub fn is_recording_events(&self) -> bool {
+        self.event_recording.is_some()
+    }
+
+    fn record_event(&mut self, event: impl FnOnce() -> TraceEvent) {
+        if let Some((start, trace)) = &mut self.event_recording {
+            trace.push(start.elapsed(), event());
+        }
+    }
+
+    /// Handles every event of `trace`, in order.
+    ///
+    /// Animation frames are replayed with their recorded duration, so the result
+    /// doesn't depend on how long replaying takes.
+    pub fn replay_events(&mut self, trace: &EventTrace) {
+        for traced in &trace.events {
+            self.replay_event(traced.event.clone());
+        }
+    }
+
+    /// Handles a single event of an [`EventTrace`].
+    pub fn replay_event(&mut self, event: TraceEvent) -> Handled {
+        match event {
+            TraceEvent::Pointer(event) => self.handle_pointer_event(event),
+            TraceEvent::Text(event) => self.handle_text_event(event),
+            TraceEvent::Window(event) => self.handle_window_event(event),
+        }
+    }
+
     /// Registers all fonts that exist in the given data.
     ///
     /// Returns a list of pairs each containing the family identifier and fonts
@@ -537,6 +831,37 @@ impl RenderRoot {
         ret
     }
 
+    /// Lays out `text` with `styles`, and returns a scene painting it.
+    ///
+    /// This loads the fonts the text needs and fills the shaping caches, so that widgets
+    /// laying out text with the same styles don't stall the first time they do.
+    /// Platforms can render the returned scene to warm up the renderer as well.
+    pub fn preshape_text(&mut self, styles: &StyleSet, text: &str) -> Scene {
+        let _span = info_span!("preshape_text");
+        let text_scale = self.global_state.text_scale() as f32;
+        let mut builder = self.global_state.text_layout_context.ranged_builder(
+            &mut self.global_state.font_context,
+            text,
+            text_scale,
+            true,
+        );
+        for prop in styles.inner().values() {
+            builder.push_default(prop.to_owned());
+        }
+        let mut layout = builder.build(text);
+        layout.break_all_lines(None);
+
+        let mut scene = Scene::new();
+        render_text(
+            &mut scene,
+            Affine::IDENTITY,
+            &layout,
+            &[Color::BLACK.into()],
+            false,
+        );
+        scene
+    }
+
     /// Redraws the window.
     ///
     /// Returns an update to the accessibility tree and a Vello scene representing
@@ -546,11 +871,346 @@ impl RenderRoot {
 
         let access_tree_active = self.global_state.access_tree_active;
 
-        // TODO - Handle invalidation regions
+        if let Some(diagnostics) = &mut self.global_state.frame_diagnostics {
+            diagnostics.finish_frame();
+        }
+        // The audit reads the accessibility tree, and its issues are painted by the inspector,
+        // so the tree is updated before painting when it is enabled.
+        let audit_enabled = self.global_state.accessibility_audit.is_some();
+        let mut tree_update = None;
+        if audit_enabled {
+            let update = run_accessibility_pass(self, self.global_state.scale_factor);
+            run_accessibility_audit(self, &update);
+            tree_update = Some(update);
+        }
         let scene = run_paint_pass(self);
-        let tree_update = access_tree_active
-            .then(|| run_accessibility_pass(self, self.global_state.scale_factor));
-        (scene, tree_update)
+        if access_tree_active && !audit_enabled {
+            tree_update = Some(run_accessibility_pass(self, self.global_state.scale_factor));
+        }
+        if !access_tree_active {
+            // No assistive technology is listening.
+            self.global_state.pending_announcements.clear();
+        }
+        (scene, tree_update.filter(|_| access_tree_active))
+    }
+
+    /// Returns the regions of the window which look different in the last frame returned by
+    /// [`redraw`](Self::redraw) than in the frame before it, in logical pixels.
+    ///
+    /// Everything outside of these rects was painted identically, so platforms which support
+    /// partial presentation can only render and recomposite these regions.
+    /// This is empty if nothing changed, e.g. if the redraw was requested by the platform.
+    ///
+    /// The damage doesn't account for changes made outside of the widget tree, such as a new
+    /// base color or a resized surface: platforms should redraw everything in these cases.
+    pub fn frame_damage(&self) -> &[Rect] {
+        &self.global_state.frame_damage
+    }
+
+    /// Sets whether to record which widgets request work for each frame, and why.
+    ///
+    /// While enabled, the widget inspector (toggled by F11) outlines the widgets which
+    /// requested the last frame, and the recorded data is available through
+    /// [`frame_diagnostics`](Self::frame_diagnostics).
+    /// Frame diagnostics can also be toggled with the F10 key.
+    ///
+    /// Disabling them discards the recorded data.
+    pub fn set_frame_diagnostics(&mut self, enabled: bool) {
+        if enabled != self.global_state.frame_diagnostics.is_some() {
+            self.global_state.frame_diagnostics = enabled.then(FrameDiagnostics::default);
+            self.root_state_mut().needs_paint = true;
+        }
+    }
+
+    /// Returns the recorded frame diagnostics, or `None` if they're disabled.
+    ///
+    /// See [`set_frame_diagnostics`](Self::set_frame_diagnostics).
+    pub fn frame_diagnostics(&self) -> Option<&FrameDiagnostics> {
+        self.global_state.frame_diagnostics.as_ref()
+    }
+
+    /// Sets whether to record how much time each widget takes in the layout and paint passes.
+    ///
+    /// While enabled, the widget inspector (toggled by F11) highlights the most expensive
+    /// widgets of the last frame, and the recorded data is available through
+    /// [`widget_profile`](Self::widget_profile).
+    /// Widget profiling can also be toggled with the F9 key.
+    ///
+    /// Disabling it discards the recorded data.
+    pub fn set_widget_profiling(&mut self, enabled: bool) {
+        if enabled != self.global_state.widget_profile.is_some() {
+            self.global_state.widget_profile = enabled.then(WidgetProfile::default);
+            self.root_state_mut().needs_paint = true;
+        }
+    }
+
+    /// Returns the recorded widget timings, or `None` if widget profiling is disabled.
+    ///
+    /// See [`set_widget_profiling`](Self::set_widget_profiling).
+    pub fn widget_profile(&self) -> Option<&WidgetProfile> {
+        self.global_state.widget_profile.as_ref()
+    }
+
+    /// Enables the accessibility audit with the given options, or disables it if `None`.
+    ///
+    /// While enabled, the widget tree is audited after each frame, and the issues found are
+    /// available through [`accessibility_audit`](Self::accessibility_audit).
+    /// The widget inspector (toggled by F11) outlines the widgets with issues in magenta.
+    /// The accessibility tree is kept up to date while the audit is enabled, even if no
+    /// assistive technology is listening.
+    pub fn set_accessibility_audit(&mut self, options: Option<AuditOptions>) {
+        match (&mut self.global_state.accessibility_audit, options) {
+            (Some(audit_state), Some(options)) => audit_state.options = options,
+            (audit_state, options) => *audit_state = options.map(AuditState::new),
+        }
+        self.request_access_all();
+        self.root_state_mut().needs_paint = true;
+    }
+
+    /// Returns the issues found by the last accessibility audit, or `None` if the audit
+    /// is disabled.
+    ///
+    /// See [`set_accessibility_audit`](Self::set_accessibility_audit).
+    pub fn accessibility_audit(&self) -> Option<&AccessibilityAudit> {
+        Some(&self.global_state.accessibility_audit.as_ref()?.audit)
+    }
+
+    /// Sets how scroll events from mouse wheels and touchpads move scrollable widgets.
+    ///
+    /// This applies to the scroll events received after the call.
+    /// Some widgets, such as `Portal`, can override it.
+    pub fn set_scroll_config(&mut self, config: ScrollConfig) {
+        self.global_state.scroll_config = config;
+    }
+
+    /// Returns how scroll events move scrollable widgets.
+    ///
+    /// See [`set_scroll_config`](Self::set_scroll_config).
+    pub fn scroll_config(&self) -> ScrollConfig {
+        self.global_state.scroll_config
+    }
+
+    /// Sets the color the window is cleared with, below the widgets.
+    ///
+    /// Widgets use it to know the color of their background, see
+    /// [`PaintCtx::effective_background`].
+    /// Platforms should call this with the base color they render the window with.
+    /// Translucent base colors are ignored, since what's below the window is unknown.
+    ///
+    /// [`PaintCtx::effective_background`]: crate::core::PaintCtx::effective_background
+    pub fn set_base_color(&mut self, base_color: Color) {
+        let base_color = (base_color.components[3] >= 1.).then_some(base_color);
+        if self.global_state.base_color != base_color {
+            self.global_state.base_color = base_color;
+            self.root_state_mut().needs_paint = true;
+        }
+    }
+
+    /// Sets the root font size, in logical pixels, which [`rem`] lengths are multiples of.
+    ///
+    /// Apps should set it to the text size of their theme, so that layouts expressed
+    /// in `rem` match their text.
+    /// The `rem` unit is this size multiplied by the [text scale](Self::text_scale).
+    /// Every widget is laid out again if it changed.
+    /// Defaults to 16 logical pixels.
+    ///
+    /// [`rem`]: crate::layout::LengthUnit::Rem
+    pub fn set_root_font_size(&mut self, root_font_size: f64) {
+        if !root_font_size.is_finite() || root_font_size <= 0. {
+            debug_panic!("Invalid root font size '{root_font_size}'");
+            return;
+        }
+        if self.global_state.root_font_size != root_font_size {
+            self.global_state.root_font_size = root_font_size;
+            self.global_state.units.rem = root_font_size * self.global_state.text_scale();
+            self.request_layout_all();
+        }
+    }
+
+    /// Returns the root font size, in logical pixels, before text scaling.
+    pub fn root_font_size(&self) -> f64 {
+        self.global_state.root_font_size
+    }
+
+    /// Sets the factor the app scales text by, such as a zoom level chosen by the user.
+    ///
+    /// Font sizes and [`rem`] lengths are multiplied by this factor and by the user's
+    /// text size preference, which platforms report with [`WindowEvent::TextScaleChanged`].
+    /// Every widget is laid out again if it changed.
+    /// Defaults to `1.0`.
+    ///
+    /// [`rem`]: crate::layout::LengthUnit::Rem
+    pub fn set_app_text_scale(&mut self, app_text_scale: f64) {
+        if !app_text_scale.is_finite() || app_text_scale <= 0. {
+            debug_panic!("Invalid text scale '{app_text_scale}'");
+            return;
+        }
+        if self.global_state.app_text_scale != app_text_scale {
+            self.global_state.app_text_scale = app_text_scale;
+            self.text_scale_changed();
+        }
+    }
+
+    /// Returns the factor the app scales text by.
+    pub fn app_text_scale(&self) -> f64 {
+        self.global_state.app_text_scale
+    }
+
+    /// Returns the factor font sizes are multiplied by, which is the app's text scale
+    /// multiplied by the user's text size preference.
+    pub fn text_scale(&self) -> f64 {
+        self.global_state.text_scale()
+    }
+
+    /// Lays out every widget again with the new text scale.
+    fn text_scale_changed(&mut self) {
+        let global_state = &mut self.global_state;
+        global_state.units.rem = global_state.root_font_size * global_state.text_scale();
+        // Makes widgets rebuild their text layouts.
+        global_state.fonts_changed = true;
+        self.request_layout_all();
+    }
+
+    /// Sets the anti-aliasing method the window is rendered with, and re-renders it
+    /// if it changed.
+    ///
+    /// Widgets can override it for their subtree with [`PaintCtx::set_antialiasing`].
+    /// Methods which the renderer doesn't support fall back to [`AaConfig::Area`],
+    /// see [`set_antialiasing_support`](Self::set_antialiasing_support).
+    ///
+    /// [`PaintCtx::set_antialiasing`]: crate::core::PaintCtx::set_antialiasing
+    pub fn set_antialiasing(&mut self, method: AaConfig) {
+        if self.global_state.antialiasing != method {
+            self.global_state.antialiasing = method;
+            self.request_render_all();
+        }
+    }
+
+    /// Returns the anti-aliasing method platforms should render the window with.
+    ///
+    /// This is the method set with [`set_antialiasing`](Self::set_antialiasing) if the renderer
+    /// supports it, and [`AaConfig::Area`] otherwise.
+    pub fn antialiasing(&self) -> AaConfig {
+        self.global_state
+            .supported_antialiasing(self.global_state.antialiasing)
+    }
+
+    /// Sets the anti-aliasing methods supported by the renderer passed to
+    /// [`render_wgpu_surfaces`](Self::render_wgpu_surfaces) and
+    /// [`render_to_image`](Self::render_to_image).
+    ///
+    /// Platforms should call this with the `antialiasing_support` of their [`Renderer`].
+    /// Only area anti-aliasing is assumed to be supported by default.
+    pub fn set_antialiasing_support(&mut self, support: AaSupport) {
+        // `AaSupport` doesn't implement `PartialEq`.
+        let methods = |support: AaSupport| (support.area, support.msaa8, support.msaa16);
+        if methods(support) != methods(self.global_state.antialiasing_support) {
+            self.global_state.antialiasing_support = support;
+            self.request_render_all();
+        }
+    }
+
+    /// Renders the subtree rooted at the given widget into an image.
+    ///
+    /// The image covers the widget's [bounding box], which includes its descendants and
+    /// anything painted outside of its layout box, such as shadows.
+    /// It has `scale` pixels per logical pixel, and a transparent background.
+    /// The clip paths of the widget's ancestors aren't applied, so a widget partially
+    /// scrolled out of view is rendered in full.
+    ///
+    /// This is useful for e.g. drag images, minimaps, or exporting part of the UI as a picture.
+    /// Since `RenderRoot` doesn't own any GPU resources, the caller provides the Vello
+    /// renderer and the `wgpu` device it was created with.
+    ///
+    /// Widgets which don't have access to these can call [`request_render_to_image`] instead.
+    ///
+    /// Returns `None` if the widget isn't in the tree or is stashed.
+    ///
+    /// This will run [rewrite passes](crate::doc::pass_system#rewrite-passes) first.
+    ///
+    /// [bounding box]: crate::core::QueryCtx::bounding_box
+    /// [`request_render_to_image`]: crate::core::EventCtx::request_render_to_image
+    pub fn render_to_image(
+        &mut self,
+        widget_id: WidgetId,
+        scale: f64,
+        device: &Device,
+        queue: &Queue,
+        renderer: &mut Renderer,
+    ) -> Option<ImageData> {
+        self.run_rewrite_passes();
+
+        if !self.has_widget(widget_id) {
+            return None;
+        }
+        let state = self.widget_arena.get_state(widget_id);
+        if state.is_stashed {
+            return None;
+        }
+        let bounds = state.bounding_box;
+
+        let scene = run_paint_pass_for_subtree(self, widget_id);
+        // The effects within the subtree are drawn from their result.
+        self.render_wgpu_surfaces(device, queue, renderer);
+        match rasterize(&scene, bounds, scale, device, queue, renderer) {
+            Ok(image) => Some(image),
+            Err(err) => {
+                warn!("Couldn't render widget {widget_id} to an image: {err}");
+                None
+            }
+        }
+    }
+
+    /// Renders the [`WgpuSurface`]s and [`WgpuEffect`]s painted since the last call, and
+    /// makes `renderer` draw their textures.
+    ///
+    /// Platforms should call this after [`redraw`](Self::redraw) and before rendering the
+    /// scene, with the Vello renderer and the `wgpu` device it was created with.
+    /// Surfaces and effects which aren't painted anymore because their widget was removed
+    /// stop being drawn by `renderer`.
+    pub fn render_wgpu_surfaces(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        renderer: &mut Renderer,
+    ) {
+        let state = &mut self.global_state;
+        for surface in std::mem::take(&mut state.painted_wgpu_surfaces) {
+            surface.render(device, queue, renderer);
+            if !state
+                .active_wgpu_surfaces
+                .iter()
+                .any(|active| active.ptr_eq(&surface))
+            {
+                state.active_wgpu_surfaces.push(surface);
+            }
+        }
+        state.active_wgpu_surfaces.retain(|surface| {
+            let orphaned = surface.is_orphaned();
+            if orphaned {
+                surface.clear_override(renderer);
+            }
+            !orphaned
+        });
+
+        // Effects render their subtree, which may contain the surfaces rendered above.
+        for effect in std::mem::take(&mut state.painted_wgpu_effects) {
+            effect.render(device, queue, renderer);
+            if !state
+                .active_wgpu_effects
+                .iter()
+                .any(|active| active.ptr_eq(&effect))
+            {
+                state.active_wgpu_effects.push(effect);
+            }
+        }
+        state.active_wgpu_effects.retain(|effect| {
+            let orphaned = effect.is_orphaned();
+            if orphaned {
+                effect.clear_override(renderer);
+            }
+            !orphaned
+        });
     }
 
     /// Returns the current icon that the mouse should display.
@@ -762,6 +1422,7 @@ impl RenderRoot {
             run_layout_pass(self);
             run_update_scroll_pass(self);
             run_compose_pass(self);
+            run_edge_auto_scroll_pass(self);
             run_update_pointer_pass(self);
 
             if !self.needs_rewrite_passes() {
@@ -778,7 +1439,7 @@ impl RenderRoot {
                 .emit_signal(RenderRootSignal::RequestRedraw);
         }
 
-        if self.root_state().needs_anim {
+        if self.needs_anim() {
             self.global_state
                 .emit_signal(RenderRootSignal::RequestAnimFrame);
         }
@@ -786,7 +1447,10 @@ impl RenderRoot {
         // We request a redraw if either the render tree or the accessibility
         // tree needs to be rebuilt. Usually both are rebuilt at the same time.
         // A redraw will trigger a rebuild of the accessibility tree.
-        if self.root_state().needs_paint || self.needs_accessibility() {
+        if self.root_state().needs_paint
+            || self.global_state.overlay.needs_paint
+            || self.needs_accessibility()
+        {
             self.global_state
                 .emit_signal(RenderRootSignal::RequestRedraw);
         }
@@ -858,6 +1522,30 @@ impl RenderRoot {
             .emit_signal(RenderRootSignal::RequestRedraw);
     }
 
+    /// Requires that each widget gets composed again.
+    ///
+    /// This also runs the rewrite passes, so that animations started in
+    /// [`Widget::compose`] get an animation frame.
+    pub(crate) fn request_compose_all(&mut self) {
+        fn request_compose_all_in(node: ArenaMut<'_, WidgetArenaNode>) {
+            let children = node.children;
+            let widget = &mut *node.item.widget;
+            let state = &mut node.item.state;
+
+            state.needs_compose = true;
+            state.request_compose = true;
+
+            let id = state.id;
+            recurse_on_children(id, widget, children, |node| {
+                request_compose_all_in(node);
+            });
+        }
+
+        let root_node = self.widget_arena.get_node_mut(self.root_id());
+        request_compose_all_in(root_node);
+        self.run_rewrite_passes();
+    }
+
     /// Requires that each widget gets relayouted.
     ///
     /// This is used if something ambient changes and we expect that
@@ -940,7 +1628,37 @@ impl RenderRoot {
 
     /// Returns true if the widget tree is waiting for an animation frame.
     pub fn needs_anim(&self) -> bool {
-        self.root_state().needs_anim
+        self.root_state().needs_anim || self.global_state.overlay.needs_anim()
+    }
+
+    /// Returns when the next timer requested by a widget is due, if any.
+    ///
+    /// Timers can be requested while handling any event, so the platform should check this
+    /// after each one, and call [`run_timers`](Self::run_timers) at that time.
+    pub fn next_timer_deadline(&self) -> Option<Instant> {
+        self.global_state
+            .timers
+            .iter()
+            .map(|timer| timer.deadline)
+            .min()
+    }
+
+    /// Returns true if widgets have queued callbacks to run when the app is idle.
+    ///
+    /// See [`run_idle_callbacks`](Self::run_idle_callbacks).
+    pub fn has_idle_callbacks(&self) -> bool {
+        !self.global_state.idle_callbacks.is_empty()
+    }
+
+    /// Makes screen readers announce `text`, e.g. "3 results found".
+    ///
+    /// See [`EventCtx::announce`](crate::core::EventCtx::announce).
+    pub fn announce(&mut self, text: impl Into<String>, priority: AnnouncementPriority) {
+        self.global_state
+            .pending_announcements
+            .push((text.into(), priority));
+        self.global_state
+            .emit_signal(RenderRootSignal::RequestRedraw);
     }
 
     /// Returns true if the accessibility tree needs to be rebuilt.
@@ -963,6 +1681,11 @@ impl RenderRoot {
 }
 
 impl RenderRootState {
+    /// Returns the factor font sizes are multiplied by.
+    pub(crate) fn text_scale(&self) -> f64 {
+        self.app_text_scale * self.system_text_scale
+    }
+
     /// Sends a signal to the runner of this app, which allows global actions to be triggered by a widget.
     pub(crate) fn emit_signal(&mut self, signal: RenderRootSignal) {
         (self.signal_sink)(signal);
@@ -978,6 +1701,17 @@ impl RenderRootState {
             || self.focused_widget != self.next_focused_widget
             || !self.mutate_callbacks.is_empty()
     }
+
+    /// Returns `method` if the renderer supports it, and [`AaConfig::Area`] otherwise.
+    pub(crate) fn supported_antialiasing(&self, method: AaConfig) -> AaConfig {
+        let support = &self.antialiasing_support;
+        let supported = match method {
+            AaConfig::Area => support.area,
+            AaConfig::Msaa8 => support.msaa8,
+            AaConfig::Msaa16 => support.msaa16,
+        };
+        if supported { method } else { AaConfig::Area }
+    }
 }
 
 impl RenderRootSignal {
@@ -0,0 +1,134 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::mpsc;
+
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Color, ImageAlphaType, ImageData, ImageFormat};
+use vello::wgpu::{
+    BufferDescriptor, BufferUsages, CommandEncoderDescriptor, Device, Extent3d, MapMode, PollType,
+    Queue, TexelCopyBufferInfo, TexelCopyBufferLayout, TextureDescriptor, TextureDimension,
+    TextureFormat, TextureUsages, TextureViewDescriptor,
+};
+use vello::{AaConfig, RenderParams, Renderer, Scene};
+
+/// The result of a [`request_render_to_image`] call, sent as an action of the requesting widget.
+///
+/// [`request_render_to_image`]: crate::core::EventCtx::request_render_to_image
+#[derive(Clone, Debug)]
+pub struct RenderedImage {
+    /// The pixels of the widget's subtree, as unpremultiplied RGBA.
+    pub image: ImageData,
+    /// The number of image pixels per logical pixel.
+    pub scale: f64,
+}
+
+/// Returns the size in pixels of an image of `bounds` rendered at `scale`.
+pub(crate) fn image_size(bounds: Rect, scale: f64) -> (u32, u32) {
+    let size = bounds.size() * scale;
+    // Avoid having a zero-sized image
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "Widget bounds are far below u32::MAX pixels"
+    )]
+    let (width, height) = (size.width.ceil() as u32, size.height.ceil() as u32);
+    (width.max(1), height.max(1))
+}
+
+/// Renders the part of `scene` within `bounds`, scaled by `scale`, on a transparent background.
+pub(crate) fn rasterize(
+    scene: &Scene,
+    bounds: Rect,
+    scale: f64,
+    device: &Device,
+    queue: &Queue,
+    renderer: &mut Renderer,
+) -> Result<ImageData, vello::Error> {
+    let (width, height) = image_size(bounds, scale);
+
+    let mut transformed_scene = Scene::new();
+    transformed_scene.append(
+        scene,
+        Some(Affine::scale(scale) * Affine::translate(-bounds.origin().to_vec2())),
+    );
+
+    let render_params = RenderParams {
+        base_color: Color::TRANSPARENT,
+        width,
+        height,
+        antialiasing_method: AaConfig::Area,
+    };
+
+    let size = Extent3d {
+        width,
+        height,
+        depth_or_array_layers: 1,
+    };
+    let target = device.create_texture(&TextureDescriptor {
+        label: Some("Render to image target"),
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: TextureDimension::D2,
+        format: TextureFormat::Rgba8Unorm,
+        usage: TextureUsages::STORAGE_BINDING | TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = target.create_view(&TextureViewDescriptor::default());
+
+    renderer.render_to_texture(device, queue, &transformed_scene, &view, &render_params)?;
+
+    let padded_byte_width = (width * 4).next_multiple_of(256);
+    let buffer = device.create_buffer(&BufferDescriptor {
+        label: Some("Render to image buffer"),
+        size: u64::from(padded_byte_width) * u64::from(height),
+        usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor {
+        label: Some("Copy out render to image buffer"),
+    });
+    encoder.copy_texture_to_buffer(
+        target.as_image_copy(),
+        TexelCopyBufferInfo {
+            buffer: &buffer,
+            layout: TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(padded_byte_width),
+                rows_per_image: None,
+            },
+        },
+        size,
+    );
+    queue.submit([encoder.finish()]);
+
+    let buf_slice = buffer.slice(..);
+    let (sender, receiver) = mpsc::channel();
+    buf_slice.map_async(MapMode::Read, move |result| {
+        let _ = sender.send(result);
+    });
+    device
+        .poll(PollType::wait_indefinitely())
+        .expect("failed to poll device");
+    receiver
+        .recv()
+        .expect("map_async callback was dropped")
+        .expect("failed to map buffer");
+
+    let data = buf_slice.get_mapped_range();
+    let byte_width = (width * 4) as usize;
+    let mut pixels = Vec::with_capacity(byte_width * height as usize);
+    for row in 0..height as usize {
+        let start = row * padded_byte_width as usize;
+        pixels.extend_from_slice(&data[start..start + byte_width]);
+    }
+
+    Ok(ImageData {
+        data: pixels.into(),
+        format: ImageFormat::Rgba8,
+        alpha_type: ImageAlphaType::Alpha,
+        width,
+        height,
+    })
+}
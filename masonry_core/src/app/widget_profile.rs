@@ -0,0 +1,147 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::core::WidgetId;
+use crate::util::Duration;
+
+/// A pass whose time is attributed to widgets by [`WidgetProfile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProfiledPass {
+    /// The [`measure`](crate::core::Widget::measure) and [`layout`](crate::core::Widget::layout)
+    /// methods.
+    Layout,
+    /// The [`pre_paint`](crate::core::Widget::pre_paint), [`paint`](crate::core::Widget::paint)
+    /// and [`post_paint`](crate::core::Widget::post_paint) methods.
+    Paint,
+}
+
+/// The time spent in a widget's own methods, excluding the time spent in its children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WidgetTiming {
+    /// The widget.
+    pub widget: WidgetId,
+    /// The time spent in the widget's layout methods.
+    pub layout: Duration,
+    /// The time spent in the widget's paint methods.
+    pub paint: Duration,
+}
+
+impl WidgetTiming {
+    fn new(widget: WidgetId) -> Self {
+        Self {
+            widget,
+            layout: Duration::ZERO,
+            paint: Duration::ZERO,
+        }
+    }
+
+    /// Returns the total time spent in the widget's methods.
+    pub fn total(&self) -> Duration {
+        self.layout + self.paint
+    }
+
+    fn add(&mut self, pass: ProfiledPass, time: Duration) {
+        match pass {
+            ProfiledPass::Layout => self.layout += time,
+            ProfiledPass::Paint => self.paint += time,
+        }
+    }
+}
+
+/// Records how much time each widget takes in the layout and paint passes.
+///
+/// The time of a widget's methods is attributed to the widget itself, minus the time its
+/// children took when they were laid out from within these methods.
+/// This helps find which widgets make frames slow, e.g. because of an oversized blur.
+///
+/// See [`RenderRoot::set_widget_profiling`](crate::app::RenderRoot::set_widget_profiling).
+#[derive(Debug, Default)]
+pub struct WidgetProfile {
+    /// For each widget method being timed, the time spent in the methods of its children.
+    stack: Vec<Duration>,
+    /// The timings since the last frame.
+    current: HashMap<WidgetId, WidgetTiming>,
+    /// The timings of the last frame, most expensive first.
+    last_frame: Vec<WidgetTiming>,
+    /// The timings summed over the recorded frames.
+    totals: HashMap<WidgetId, WidgetTiming>,
+    frame_count: u64,
+}
+
+impl WidgetProfile {
+    /// Starts timing a widget method.
+    pub(crate) fn enter(&mut self) {
+        self.stack.push(Duration::ZERO);
+    }
+
+    /// Stops timing the widget method started by the matching [`enter`](Self::enter),
+    /// which took `elapsed` in total.
+    pub(crate) fn exit(&mut self, widget: WidgetId, pass: ProfiledPass, elapsed: Duration) {
+        let children_time = self.stack.pop().unwrap_or_default();
+        if let Some(parent_children_time) = self.stack.last_mut() {
+            *parent_children_time += elapsed;
+        }
+        let own_time = elapsed.saturating_sub(children_time);
+        self.current
+            .entry(widget)
+            .or_insert_with(|| WidgetTiming::new(widget))
+            .add(pass, own_time);
+    }
+
+    pub(crate) fn finish_frame(&mut self) {
+        self.frame_count += 1;
+        for timing in self.current.values() {
+            let total = self
+                .totals
+                .entry(timing.widget)
+                .or_insert_with(|| WidgetTiming::new(timing.widget));
+            total.layout += timing.layout;
+            total.paint += timing.paint;
+        }
+        self.last_frame = self.current.drain().map(|(_, timing)| timing).collect();
+        sort_timings(&mut self.last_frame);
+    }
+
+    /// Returns the number of frames recorded.
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Returns the timings of the widgets which did work in the last frame, most expensive first.
+    pub fn last_frame(&self) -> &[WidgetTiming] {
+        &self.last_frame
+    }
+
+    /// Returns the timings of the `n` most expensive widgets in the last frame.
+    pub fn top(&self, n: usize) -> &[WidgetTiming] {
+        &self.last_frame[..n.min(self.last_frame.len())]
+    }
+
+    /// Returns the timings summed over the recorded frames, most expensive first.
+    pub fn report(&self) -> Vec<WidgetTiming> {
+        let mut report: Vec<_> = self.totals.values().copied().collect();
+        sort_timings(&mut report);
+        report
+    }
+
+    /// Returns the timings of the given widget summed over the recorded frames.
+    ///
+    /// Returns `None` if the widget didn't do any work in these frames.
+    pub fn widget(&self, widget: WidgetId) -> Option<WidgetTiming> {
+        self.totals.get(&widget).copied()
+    }
+
+    /// Discards the recorded frames.
+    pub fn clear(&mut self) {
+        self.last_frame.clear();
+        self.totals.clear();
+        self.frame_count = 0;
+    }
+}
+
+fn sort_timings(timings: &mut [WidgetTiming]) {
+    timings.sort_by_key(|timing| (Reverse(timing.total()), timing.widget.to_raw()));
+}
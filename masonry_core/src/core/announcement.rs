@@ -0,0 +1,31 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::Live;
+
+/// How urgently screen readers make an announcement.
+///
+/// See [`EventCtx::announce`](crate::core::EventCtx::announce).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AnnouncementPriority {
+    /// The announcement is made once the screen reader is done speaking.
+    #[default]
+    Polite,
+    /// The announcement interrupts what the screen reader is saying.
+    ///
+    /// This should be kept for urgent information, such as errors.
+    Assertive,
+}
+
+impl AnnouncementPriority {
+    /// All priorities, in the order of their live regions in the accessibility tree.
+    pub(crate) const ALL: [Self; 2] = [Self::Polite, Self::Assertive];
+
+    /// Returns the politeness of the live region announcements with this priority are made in.
+    pub(crate) fn live(self) -> Live {
+        match self {
+            Self::Polite => Live::Polite,
+            Self::Assertive => Live::Assertive,
+        }
+    }
+}
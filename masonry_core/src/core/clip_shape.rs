@@ -0,0 +1,189 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::{Add, Sub};
+use std::sync::Arc;
+
+use crate::kurbo::{
+    Affine, BezPath, Circle, Ellipse, PathEl, Point, Rect, RoundedRect, Shape, Vec2,
+};
+
+/// The shape of a widget's clip path.
+///
+/// See [`LayoutCtx::set_clip_path`](crate::core::LayoutCtx::set_clip_path) for details.
+///
+/// Kurbo doesn't provide a type that can efficiently hold an arbitrary shape,
+/// so this enum lists the shapes that are commonly used for clipping.
+/// Arbitrary paths are stored behind an [`Arc`] to keep this type cheap to clone.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClipShape {
+    /// An axis-aligned rectangle.
+    Rect(Rect),
+    /// An axis-aligned rectangle with rounded corners.
+    RoundedRect(RoundedRect),
+    /// An axis-aligned ellipse, which includes circles.
+    Ellipse(Ellipse),
+    /// An arbitrary Bézier path, filled with the non-zero fill rule.
+    Path(Arc<BezPath>),
+}
+
+impl Add<Vec2> for ClipShape {
+    type Output = Self;
+
+    fn add(self, v: Vec2) -> Self {
+        match self {
+            Self::Rect(rect) => Self::Rect(rect + v),
+            Self::RoundedRect(rect) => Self::RoundedRect(rect + v),
+            Self::Ellipse(ellipse) => Self::Ellipse(Affine::translate(v) * ellipse),
+            Self::Path(mut path) => {
+                Arc::make_mut(&mut path).apply_affine(Affine::translate(v));
+                Self::Path(path)
+            }
+        }
+    }
+}
+
+impl Sub<Vec2> for ClipShape {
+    type Output = Self;
+
+    fn sub(self, v: Vec2) -> Self {
+        self + (-v)
+    }
+}
+
+/// The [`Shape::PathElementsIter`] of [`ClipShape`].
+#[expect(
+    clippy::large_enum_variant,
+    reason = "Path iterators are short-lived, boxing them would cost more than it saves"
+)]
+pub enum ClipShapePathIter<'a> {
+    /// Path elements of a [`ClipShape::Rect`].
+    Rect(<Rect as Shape>::PathElementsIter<'a>),
+    /// Path elements of a [`ClipShape::RoundedRect`].
+    RoundedRect(<RoundedRect as Shape>::PathElementsIter<'a>),
+    /// Path elements of a [`ClipShape::Ellipse`].
+    Ellipse(<Ellipse as Shape>::PathElementsIter<'a>),
+    /// Path elements of a [`ClipShape::Path`].
+    Path(<BezPath as Shape>::PathElementsIter<'a>),
+}
+
+impl Iterator for ClipShapePathIter<'_> {
+    type Item = PathEl;
+
+    fn next(&mut self) -> Option<PathEl> {
+        match self {
+            Self::Rect(iter) => iter.next(),
+            Self::RoundedRect(iter) => iter.next(),
+            Self::Ellipse(iter) => iter.next(),
+            Self::Path(iter) => iter.next(),
+        }
+    }
+}
+
+impl Shape for ClipShape {
+    type PathElementsIter<'iter> = ClipShapePathIter<'iter>;
+
+    fn path_elements(&self, tolerance: f64) -> ClipShapePathIter<'_> {
+        match self {
+            Self::Rect(rect) => ClipShapePathIter::Rect(rect.path_elements(tolerance)),
+            Self::RoundedRect(rect) => {
+                ClipShapePathIter::RoundedRect(rect.path_elements(tolerance))
+            }
+            Self::Ellipse(ellipse) => ClipShapePathIter::Ellipse(ellipse.path_elements(tolerance)),
+            Self::Path(path) => ClipShapePathIter::Path(path.path_elements(tolerance)),
+        }
+    }
+
+    fn area(&self) -> f64 {
+        match self {
+            Self::Rect(rect) => rect.area(),
+            Self::RoundedRect(rect) => rect.area(),
+            Self::Ellipse(ellipse) => ellipse.area(),
+            Self::Path(path) => path.area(),
+        }
+    }
+
+    fn perimeter(&self, accuracy: f64) -> f64 {
+        match self {
+            Self::Rect(rect) => rect.perimeter(accuracy),
+            Self::RoundedRect(rect) => rect.perimeter(accuracy),
+            Self::Ellipse(ellipse) => ellipse.perimeter(accuracy),
+            Self::Path(path) => path.perimeter(accuracy),
+        }
+    }
+
+    fn winding(&self, pt: Point) -> i32 {
+        match self {
+            Self::Rect(rect) => rect.winding(pt),
+            Self::RoundedRect(rect) => rect.winding(pt),
+            Self::Ellipse(ellipse) => ellipse.winding(pt),
+            Self::Path(path) => path.winding(pt),
+        }
+    }
+
+    fn bounding_box(&self) -> Rect {
+        match self {
+            Self::Rect(rect) => rect.bounding_box(),
+            Self::RoundedRect(rect) => rect.bounding_box(),
+            Self::Ellipse(ellipse) => ellipse.bounding_box(),
+            Self::Path(path) => path.bounding_box(),
+        }
+    }
+
+    fn as_rect(&self) -> Option<Rect> {
+        match self {
+            Self::Rect(rect) => Some(*rect),
+            _ => None,
+        }
+    }
+
+    fn as_rounded_rect(&self) -> Option<RoundedRect> {
+        match self {
+            Self::RoundedRect(rect) => Some(*rect),
+            _ => None,
+        }
+    }
+
+    fn as_path_slice(&self) -> Option<&[PathEl]> {
+        match self {
+            Self::Path(path) => Some(path.elements()),
+            _ => None,
+        }
+    }
+}
+
+impl From<Rect> for ClipShape {
+    fn from(rect: Rect) -> Self {
+        Self::Rect(rect)
+    }
+}
+
+impl From<RoundedRect> for ClipShape {
+    fn from(rect: RoundedRect) -> Self {
+        Self::RoundedRect(rect)
+    }
+}
+
+impl From<Ellipse> for ClipShape {
+    fn from(ellipse: Ellipse) -> Self {
+        Self::Ellipse(ellipse)
+    }
+}
+
+impl From<Circle> for ClipShape {
+    fn from(circle: Circle) -> Self {
+        Self::Ellipse(circle.into())
+    }
+}
+
+impl From<BezPath> for ClipShape {
+    fn from(path: BezPath) -> Self {
+        Self::Path(Arc::new(path))
+    }
+}
+
+impl From<Arc<BezPath>> for ClipShape {
+    fn from(path: Arc<BezPath>) -> Self {
+        Self::Path(path)
+    }
+}
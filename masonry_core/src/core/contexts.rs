@@ -5,25 +5,31 @@
 
 use std::any::Any;
 use std::collections::hash_map::Entry;
+use std::panic::Location;
 
 use accesskit::{NodeId, TreeUpdate};
 use anymore::AnyDebug;
-use dpi::{LogicalPosition, PhysicalPosition};
+use dpi::{LogicalPosition, PhysicalPosition, PhysicalSize};
 use parley::{FontContext, LayoutContext};
 use tracing::{trace, warn};
 use tree_arena::{ArenaMut, ArenaMutList, ArenaRefList};
+use vello::{AaConfig, Scene};
 
-use crate::app::{MutateCallback, RenderRootSignal, RenderRootState};
+use crate::app::{
+    EdgeAutoScroll, FrameRequest, FrameRequestKind, IdleCallback, MutateCallback, OverlayId,
+    OverlayItem, RenderRootSignal, RenderRootState, Timer,
+};
 use crate::core::{
-    AllowRawMut, BrushIndex, DefaultProperties, ErasedAction, FromDynWidget, LayerType, NewWidget,
-    PropertiesMut, PropertiesRef, ResizeDirection, Widget, WidgetArenaNode, WidgetId, WidgetMut,
-    WidgetPod, WidgetRef, WidgetState,
+    AllowRawMut, AnnouncementPriority, BrushIndex, ClipShape, DefaultProperties, ErasedAction,
+    FromDynWidget, LayerType, NewWidget, PropertiesMut, PropertiesRef, ResizeDirection,
+    ScrollConfig, TimerToken, WgpuEffect, WgpuSurface, Widget, WidgetArenaNode, WidgetId,
+    WidgetMut, WidgetPod, WidgetRef, WidgetState,
 };
 use crate::kurbo::{Affine, Axis, Insets, Point, Rect, Size, Vec2};
-use crate::layout::{LayoutSize, LenDef, SizeDef};
+use crate::layout::{LayoutSize, LenDef, SizeDef, UnitContext};
 use crate::passes::layout::{place_widget, resolve_length, resolve_size, run_layout_on};
-use crate::peniko::Color;
-use crate::util::{TypeSet, get_debug_color};
+use crate::peniko::{Color, ImageBrush};
+use crate::util::{Duration, Instant, TypeSet, get_debug_color};
 
 // Note - Most methods defined in this file revolve around `WidgetState` fields.
 // Consider reading `WidgetState` documentation (especially the documented naming scheme)
@@ -137,7 +143,7 @@ pub struct ComposeCtx<'a> {
 /// A context passed to [`Widget::paint`] method.
 pub struct PaintCtx<'a> {
     pub(crate) global_state: &'a mut RenderRootState,
-    pub(crate) widget_state: &'a WidgetState,
+    pub(crate) widget_state: &'a mut WidgetState,
     pub(crate) children: ArenaMutList<'a, WidgetArenaNode>,
 }
 
@@ -319,12 +325,13 @@ impl<'w> QueryCtx<'w> {
             .children
             .into_item(child)
             .expect("get_mut: child not found");
+        let child_widget = &*child_node.item.widget;
         let child_ctx = QueryCtx {
             global_state: self.global_state,
             widget_state: &child_node.item.state,
             properties: PropertiesRef {
                 map: &child_node.item.properties,
-                default_map: self.properties.default_map,
+                default_map: self.default_properties.for_widget(child_widget.type_id()),
             },
             children: child_node.children,
             default_properties: self.default_properties,
@@ -364,12 +371,23 @@ impl_context_method!(
             )
         }
 
-        /// Whether the set of loaded fonts has changed since layout was most recently called.
+        /// Whether the set of loaded fonts or the [text scale] has changed since layout
+        /// was most recently called.
         ///
         /// Any cached text layouts should be invalidated in the layout pass when this is `true`.
+        ///
+        /// [text scale]: Self::text_scale
         pub fn fonts_changed(&mut self) -> bool {
             self.global_state.fonts_changed
         }
+
+        /// Returns the factor font sizes are multiplied by.
+        ///
+        /// This is the app's text scale multiplied by the user's text size preference.
+        /// Widgets building text layouts should pass it as the layout scale.
+        pub fn text_scale(&self) -> f64 {
+            self.global_state.text_scale()
+        }
     }
 );
 
@@ -435,6 +453,39 @@ impl EventCtx<'_> {
         self.global_state.needs_pointer_pass = true;
     }
 
+    /// Asks the scroll containers around this widget to scroll while the pointer is near
+    /// their edges, until the pointer is released.
+    ///
+    /// This is meant for drags which can go past the visible area, such as text selection,
+    /// drag-and-drop or marquee selection, and must be called while this widget has
+    /// [captured] the pointer, usually right after [`capture_pointer`].
+    ///
+    /// The ancestors of this widget receive the position of the pointer with
+    /// [`Update::EdgeAutoScroll`].
+    /// When one of them scrolls this widget under a pointer which doesn't move,
+    /// this widget receives the last [`Move`] event of the drag again, so that the drag
+    /// can follow the content.
+    ///
+    /// [captured]: crate::doc::masonry_concepts#pointer-capture
+    /// [`capture_pointer`]: EventCtx::capture_pointer
+    /// [`Update::EdgeAutoScroll`]: crate::core::Update::EdgeAutoScroll
+    /// [`Move`]: ui_events::pointer::PointerEvent::Move
+    pub fn request_edge_auto_scroll(&mut self) {
+        let id = self.widget_id();
+        if self.global_state.pointer_capture_target != Some(id) {
+            warn!("request_edge_auto_scroll - '{id}': widget does not have pointer capture");
+            return;
+        }
+        self.global_state.edge_auto_scroll = Some(EdgeAutoScroll {
+            source: id,
+            position: None,
+            ended: false,
+            needs_update: false,
+            last_move: None,
+            source_transform: self.widget_state.window_transform,
+        });
+    }
+
     /// Sends a signal to parent widgets to scroll this widget's border-box into view.
     pub fn request_scroll_to_this(&mut self) {
         let rect = self.widget_state.border_box_size().to_rect();
@@ -490,14 +541,6 @@ impl EventCtx<'_> {
         self.global_state.next_focused_widget = Some(id);
     }
 
-    /// Transfers [text focus] to the widget with the given `WidgetId`.
-    ///
-    /// [text focus]: crate::doc::masonry_concepts#text-focus
-    pub fn set_focus(&mut self, target: WidgetId) {
-        trace!("set_focus target={:?}", target);
-        self.global_state.next_focused_widget = Some(target);
-    }
-
     /// Gives up [text focus].
     ///
     /// This should only be called by a widget that currently has focus.
@@ -526,6 +569,67 @@ impl EventCtx<'_> {
     }
 }
 
+impl_context_method!(MutateCtx<'_>, EventCtx<'_>, UpdateCtx<'_>, {
+    /// Transfers [text focus] to the widget with the given `WidgetId`.
+    ///
+    /// This can be used outside of event handling, such as to focus the text input of
+    /// a widget which was just shown.
+    ///
+    /// [text focus]: crate::doc::masonry_concepts#text-focus
+    pub fn set_focus(&mut self, target: WidgetId) {
+        trace!("set_focus target={:?}", target);
+        self.global_state.next_focused_widget = Some(target);
+    }
+
+    /// Adds an item to the window overlay, painted above every widget, and returns its id.
+    ///
+    /// The item stays until it's removed with [`remove_overlay`](Self::remove_overlay),
+    /// its lifetime ends, or this widget is removed.
+    /// This is meant for transient visuals which aren't clipped by any ancestor, such as
+    /// drag previews.
+    pub fn add_overlay(&mut self, item: OverlayItem) -> OverlayId {
+        trace!("add_overlay");
+        self.global_state.overlay.add(self.widget_state.id, item)
+    }
+
+    /// Moves the overlay item `id` to `bounds`, in window coordinates.
+    ///
+    /// Does nothing if the item was removed.
+    pub fn set_overlay_bounds(&mut self, id: OverlayId, bounds: Rect) {
+        self.global_state.overlay.set_bounds(id, bounds);
+    }
+
+    /// Removes the overlay item `id`.
+    ///
+    /// Does nothing if the item was already removed.
+    pub fn remove_overlay(&mut self, id: OverlayId) {
+        trace!("remove_overlay");
+        self.global_state.overlay.remove(id);
+    }
+
+    /// Requests a timer which fires after `duration`, and returns its token.
+    ///
+    /// When it fires, this widget gets [`Update::Timer`] with the token.
+    /// This is meant for delays measured in wall-clock time, such as showing a tooltip,
+    /// detecting double-clicks or dismissing a notification, which don't need to run
+    /// an animation frame in the meantime.
+    ///
+    /// Timers can't be cancelled: widgets should ignore the tokens they no longer expect.
+    /// The timers of a widget are dropped when it's removed.
+    ///
+    /// [`Update::Timer`]: crate::core::Update::Timer
+    pub fn request_timer(&mut self, duration: Duration) -> TimerToken {
+        trace!("request_timer({duration:?})");
+        let token = TimerToken::next();
+        self.global_state.timers.push(Timer {
+            widget: self.widget_state.id,
+            token,
+            deadline: Instant::now() + duration,
+        });
+        token
+    }
+});
+
 // --- MARK: ACCESSIBILITY
 impl AccessCtx<'_> {
     // TODO - We need access to the TreeUpdate to create sub-nodes for text runs,
@@ -608,6 +712,16 @@ impl_context_method!(MeasureCtx<'_>, LayoutCtx<'_>, {
             cross_length,
         )
     }
+
+    /// Returns the values relative length units are resolved against.
+    ///
+    /// Widgets reading properties in relative units, such as [`Padding`], use this
+    /// to resolve them to logical pixels.
+    ///
+    /// [`Padding`]: crate::properties::Padding
+    pub fn unit_context(&self) -> UnitContext {
+        self.global_state.units
+    }
 });
 
 // --- MARK: MEASURE
@@ -943,19 +1057,25 @@ impl LayoutCtx<'_> {
 
     /// Sets the widget's clip path in the widget's content-box coordinate space.
     ///
+    /// The path can be any [`ClipShape`], e.g. a [`Rect`], a [`RoundedRect`],
+    /// a [`Circle`], or an arbitrary [`BezPath`].
+    ///
     /// A widget's clip path will have two effects:
     /// - It serves as a mask for painting operations of this widget and its children.
     ///   Note that while all painting done by children will be clipped by this path,
     ///   only the painting done in [`paint`] by this widget itself will be clipped.
     ///   The remaining painting done in [`pre_paint`] and [`post_paint`] will not be clipped.
-    /// - Pointer events must be inside this path to reach the widget's children.
+    /// - Pointer events must be inside this path to reach the widget and its children.
     ///
     /// [`paint`]: Widget::paint
     /// [`pre_paint`]: Widget::pre_paint
     /// [`post_paint`]: Widget::post_paint
-    pub fn set_clip_path(&mut self, path: Rect) {
+    /// [`RoundedRect`]: crate::kurbo::RoundedRect
+    /// [`Circle`]: crate::kurbo::Circle
+    /// [`BezPath`]: crate::kurbo::BezPath
+    pub fn set_clip_path(&mut self, path: impl Into<ClipShape>) {
         // Translate the clip path to the widget's border-box coordinate space.
-        let path = path + self.widget_state.border_box_translation();
+        let path = path.into() + self.widget_state.border_box_translation();
         // We intentionally always log this because clip paths are:
         // 1) Relatively rare in the tree
         // 2) An easy potential source of items not being visible when expected
@@ -980,6 +1100,24 @@ impl LayoutCtx<'_> {
         self.widget_state.needs_accessibility = true;
         self.widget_state.needs_paint = true;
     }
+
+    /// Sets the area of this widget that descendants are visible through,
+    /// in the widget's content-box coordinate space.
+    ///
+    /// Scrolling containers such as `Portal` set this to their visible area.
+    /// Descendants can then check whether they intersect it in [`Widget::compose`]
+    /// with [`ComposeCtx::viewport`].
+    pub fn set_viewport(&mut self, viewport: Rect) {
+        let viewport = viewport + self.widget_state.border_box_translation();
+        self.widget_state.child_viewport = Some(viewport);
+    }
+
+    /// Removes the widget's viewport.
+    ///
+    /// See [`LayoutCtx::set_viewport`] for details.
+    pub fn clear_viewport(&mut self) {
+        self.widget_state.child_viewport = None;
+    }
 }
 
 impl ComposeCtx<'_> {
@@ -989,6 +1127,57 @@ impl ComposeCtx<'_> {
         self.widget_state.needs_compose
     }
 
+    /// Requests an animation frame.
+    ///
+    /// This can be used to start work when a widget enters the [viewport](Self::viewport),
+    /// such as polling for content which is loaded in the background.
+    #[track_caller]
+    pub fn request_anim_frame(&mut self) {
+        trace!("request_anim_frame");
+        record_frame_request(
+            self.global_state,
+            self.widget_state.id,
+            FrameRequestKind::AnimFrame,
+        );
+        self.widget_state.request_anim = true;
+        self.widget_state.needs_anim = true;
+    }
+
+    /// Returns the visible part of the viewports of this widget's ancestors,
+    /// in the window's coordinate space.
+    ///
+    /// This is the intersection of all the areas set with [`LayoutCtx::set_viewport`]
+    /// by ancestors, such as scrolling containers.
+    /// Returns `None` if no ancestor has a viewport.
+    ///
+    /// [`Widget::compose`] is called when this changes.
+    pub fn viewport(&self) -> Option<Rect> {
+        self.widget_state.viewport
+    }
+
+    /// Returns whether part of this widget's border-box is in the
+    /// [viewport](Self::viewport) of its ancestors.
+    ///
+    /// Returns `true` if no ancestor has a viewport, and `false` if the widget is stashed.
+    pub fn is_in_viewport(&self) -> bool {
+        if self.widget_state.is_stashed {
+            return false;
+        }
+        let Some(viewport) = self.widget_state.viewport else {
+            return true;
+        };
+        let border_box = self.widget_state.border_box_size().to_rect();
+        let window_rect = self
+            .widget_state
+            .window_transform
+            .transform_rect_bbox(border_box);
+        // Unlike `Rect::overlaps`, we don't count widgets which only touch the viewport's edge.
+        window_rect.x0 < viewport.x1
+            && window_rect.x1 > viewport.x0
+            && window_rect.y0 < viewport.y1
+            && window_rect.y1 > viewport.y0
+    }
+
     /// Sets the scroll translation for the child widget.
     ///
     /// The translation is applied on top of the position from [`LayoutCtx::place_child`].
@@ -1054,6 +1243,32 @@ impl ComposeCtx<'_> {
             child.transform_changed = true;
         }
     }
+
+    /// Sets the opacity the child widget and its descendants are painted with.
+    ///
+    /// The opacity is clamped between 0 (fully transparent) and 1 (fully opaque, the default).
+    /// This is meant for fading animations, e.g. when children enter or leave.
+    pub fn set_child_opacity(&mut self, child: &mut WidgetPod<impl Widget + ?Sized>, opacity: f32) {
+        if opacity.is_nan() {
+            debug_panic!(
+                "Error in {}: trying to call 'set_child_opacity' with child '{}' {} with NaN opacity",
+                self.widget_id(),
+                self.get_child_dyn(child).short_type_name(),
+                child.id(),
+            );
+            return;
+        }
+
+        let opacity = opacity.clamp(0.0, 1.0);
+
+        let child = self.get_child_state_mut(child);
+        if opacity != child.opacity {
+            child.opacity = opacity;
+            // Repainting the child marks its bounding box as damaged.
+            child.request_paint = true;
+            child.needs_paint = true;
+        }
+    }
 }
 
 // --- MARK: GET LAYOUT
@@ -1145,10 +1360,13 @@ impl_context_method!(
         ///
         /// For more information, see
         /// [`LayoutCtx::set_clip_path`](crate::core::LayoutCtx::set_clip_path).
-        pub fn clip_path(&self) -> Option<Rect> {
+        pub fn clip_path(&self) -> Option<ClipShape> {
             // Translate the clip path to the widget's content-box coordinate space.
             let translation = self.widget_state.border_box_translation();
-            self.widget_state.clip_path.map(|path| path - translation)
+            self.widget_state
+                .clip_path
+                .clone()
+                .map(|path| path - translation)
         }
 
         /// Returns the [`Vec2`] for translating between this widget's
@@ -1327,6 +1545,17 @@ impl_context_method!(
             self.global_state.focused_widget
         }
 
+        /// Whether the widget which has [text focus] [accepts text input], such as a text box.
+        ///
+        /// Containers can use this to leave the keys used for editing text, such as Space
+        /// or the arrow keys, to a focused descendant.
+        ///
+        /// [text focus]: crate::doc::masonry_concepts#text-focus
+        /// [accepts text input]: Widget::accepts_text_input
+        pub fn focus_target_accepts_text_input(&self) -> bool {
+            self.global_state.is_ime_active
+        }
+
         /// Whether this widget gets pointer events and hovered status.
         pub fn accepts_pointer_interaction(&self) -> bool {
             self.widget_state.accepts_pointer_interaction
@@ -1359,15 +1588,68 @@ impl_context_method!(
         pub fn is_stashed(&self) -> bool {
             self.widget_state.is_stashed
         }
+
+        /// Whether the user asked for non-essential motion to be minimized.
+        ///
+        /// Widgets should skip or replace decorative animations (such as bounces,
+        /// parallax or sliding transitions) when this returns `true`.
+        ///
+        /// This is set with [`WindowEvent::ReducedMotionChanged`](crate::core::WindowEvent::ReducedMotionChanged).
+        pub fn prefers_reduced_motion(&self) -> bool {
+            self.global_state.prefers_reduced_motion
+        }
+
+        /// How long a blinking caret stays shown, then hidden, following the user's settings.
+        ///
+        /// This is `None` if carets shouldn't blink, and half a second by default.
+        ///
+        /// This is set with [`WindowEvent::CaretBlinkChanged`](crate::core::WindowEvent::CaretBlinkChanged).
+        pub fn caret_blink_interval(&self) -> Option<Duration> {
+            self.global_state.caret_blink_interval
+        }
+
+        /// How scroll events from mouse wheels and touchpads should move scrollable widgets.
+        ///
+        /// Widgets should convert the deltas of [scroll events] with
+        /// [`ScrollConfig::logical_delta`].
+        ///
+        /// This is set with [`RenderRoot::set_scroll_config`](crate::app::RenderRoot::set_scroll_config).
+        ///
+        /// [scroll events]: crate::core::PointerEvent::Scroll
+        pub fn scroll_config(&self) -> ScrollConfig {
+            self.global_state.scroll_config
+        }
     }
 );
 
 // --- MARK: UPDATE FLAGS
+/// Records a request made by `widget` in the frame diagnostics, if they're enabled.
+#[track_caller]
+fn record_frame_request(
+    global_state: &mut RenderRootState,
+    widget: WidgetId,
+    kind: FrameRequestKind,
+) {
+    if let Some(diagnostics) = &mut global_state.frame_diagnostics {
+        diagnostics.record(FrameRequest {
+            widget,
+            kind,
+            location: Some(Location::caller()),
+        });
+    }
+}
+
 impl_context_method!(MutateCtx<'_>, EventCtx<'_>, UpdateCtx<'_>, RawCtx<'_>, {
     /// Requests a [`paint`](crate::core::Widget::paint) and an
     /// [`accessibility`](crate::core::Widget::accessibility) pass.
+    #[track_caller]
     pub fn request_render(&mut self) {
         trace!("request_render");
+        record_frame_request(
+            self.global_state,
+            self.widget_state.id,
+            FrameRequestKind::Paint,
+        );
         self.widget_state.request_pre_paint = true;
         self.widget_state.request_paint = true;
         self.widget_state.request_post_paint = true;
@@ -1377,8 +1659,14 @@ impl_context_method!(MutateCtx<'_>, EventCtx<'_>, UpdateCtx<'_>, RawCtx<'_>, {
     }
 
     /// Requests a paint pass for the [`pre_paint`](crate::core::Widget::pre_paint) method.
+    #[track_caller]
     pub fn request_pre_paint(&mut self) {
         trace!("request_pre_paint");
+        record_frame_request(
+            self.global_state,
+            self.widget_state.id,
+            FrameRequestKind::Paint,
+        );
         self.widget_state.request_pre_paint = true;
         self.widget_state.needs_paint = true;
     }
@@ -1395,15 +1683,27 @@ impl_context_method!(MutateCtx<'_>, EventCtx<'_>, UpdateCtx<'_>, RawCtx<'_>, {
     /// [`accessibility`]: crate::core::Widget::accessibility
     /// [`pre_paint`]: crate::core::Widget::post_paint
     /// [`post_paint`]: crate::core::Widget::post_paint
+    #[track_caller]
     pub fn request_paint_only(&mut self) {
         trace!("request_paint_only");
+        record_frame_request(
+            self.global_state,
+            self.widget_state.id,
+            FrameRequestKind::Paint,
+        );
         self.widget_state.request_paint = true;
         self.widget_state.needs_paint = true;
     }
 
     /// Requests a paint pass for the [`post_paint`](crate::core::Widget::post_paint) method.
+    #[track_caller]
     pub fn request_post_paint(&mut self) {
         trace!("request_post_paint");
+        record_frame_request(
+            self.global_state,
+            self.widget_state.id,
+            FrameRequestKind::Paint,
+        );
         self.widget_state.request_post_paint = true;
         self.widget_state.needs_paint = true;
     }
@@ -1413,8 +1713,14 @@ impl_context_method!(MutateCtx<'_>, EventCtx<'_>, UpdateCtx<'_>, RawCtx<'_>, {
     /// This doesn't request a [`paint`](crate::core::Widget::paint) pass.
     /// If you want to request both an accessibility pass and a paint pass,
     /// use [`request_render`](Self::request_render).
+    #[track_caller]
     pub fn request_accessibility_update(&mut self) {
         trace!("request_accessibility_update");
+        record_frame_request(
+            self.global_state,
+            self.widget_state.id,
+            FrameRequestKind::Accessibility,
+        );
         self.widget_state.needs_accessibility = true;
         self.widget_state.request_accessibility = true;
     }
@@ -1424,8 +1730,14 @@ impl_context_method!(MutateCtx<'_>, EventCtx<'_>, UpdateCtx<'_>, RawCtx<'_>, {
     /// Call this method if the widget has changed in a way that requires a layout pass.
     ///
     /// [`layout`]: crate::core::Widget::layout
+    #[track_caller]
     pub fn request_layout(&mut self) {
         trace!("request_layout");
+        record_frame_request(
+            self.global_state,
+            self.widget_state.id,
+            FrameRequestKind::Layout,
+        );
         self.widget_state.request_layout = true;
         self.widget_state.set_needs_layout(true);
     }
@@ -1437,19 +1749,47 @@ impl_context_method!(MutateCtx<'_>, EventCtx<'_>, UpdateCtx<'_>, RawCtx<'_>, {
     /// because it can only transform individual widgets' position.
     ///
     /// [`compose`]: crate::core::Widget::compose
+    #[track_caller]
     pub fn request_compose(&mut self) {
         trace!("request_compose");
+        record_frame_request(
+            self.global_state,
+            self.widget_state.id,
+            FrameRequestKind::Compose,
+        );
         self.widget_state.needs_compose = true;
         self.widget_state.request_compose = true;
     }
 
     /// Requests an animation frame.
+    #[track_caller]
     pub fn request_anim_frame(&mut self) {
         trace!("request_anim_frame");
+        record_frame_request(
+            self.global_state,
+            self.widget_state.id,
+            FrameRequestKind::AnimFrame,
+        );
         self.widget_state.request_anim = true;
         self.widget_state.needs_anim = true;
     }
 
+    /// Sets whether this widget and its descendants are painted on every animation frame.
+    ///
+    /// This is meant for content which changes without Masonry knowing, such as a game or
+    /// a video drawn by the widget.
+    /// Animation frames keep coming while it's set, so the app doesn't go idle: prefer
+    /// [`request_paint_only`](Self::request_paint_only) for content which changes rarely.
+    ///
+    /// The subtree isn't painted while it's [stashed](crate::doc::masonry_concepts#stashed).
+    pub fn set_continuous_redraw(&mut self, continuous: bool) {
+        trace!("set_continuous_redraw({continuous})");
+        self.widget_state.redraws_continuously = continuous;
+        if continuous {
+            self.widget_state.needs_anim = true;
+        }
+    }
+
     /// Notifies Masonry that the cursor returned by [`Widget::get_cursor`] has changed.
     ///
     /// This is mostly meant for cases where the cursor changes even if the pointer doesn't
@@ -1507,6 +1847,10 @@ impl_context_method!(MutateCtx<'_>, EventCtx<'_>, UpdateCtx<'_>, RawCtx<'_>, {
             }
 
             global_state.scene_cache.remove(&state.id);
+            global_state.overlay.remove_owned_by(state.id);
+            if let Some(painted_box) = global_state.painted_bounds.remove(&state.id) {
+                global_state.damage.push(painted_box);
+            }
         }
 
         let id = child.id();
@@ -1589,6 +1933,32 @@ impl_context_method!(
             self.global_state.mutate_callbacks.push(callback);
         }
 
+        /// Queues a callback that will be called with a [`WidgetMut`] for this widget
+        /// once the app is idle.
+        ///
+        /// This is meant for work which isn't needed for the next frame, such as decoding
+        /// images ahead of time, warming up caches or shaping text before it's shown.
+        /// The callbacks run in the order they were queued, when the platform has time left
+        /// before the next frame.
+        /// If the app stays busy, the callback runs anyway once `timeout` has elapsed.
+        ///
+        /// The callback is dropped if this widget is removed before it runs.
+        pub fn run_when_idle(
+            &mut self,
+            timeout: Duration,
+            f: impl FnOnce(WidgetMut<'_, dyn Widget>) + Send + 'static,
+        ) {
+            trace!("run_when_idle({timeout:?})");
+            let callback = MutateCallback {
+                id: self.widget_state.id,
+                callback: Box::new(f),
+            };
+            self.global_state.idle_callbacks.push(IdleCallback {
+                timeout: Instant::now() + timeout,
+                callback,
+            });
+        }
+
         /// Queues a callback that will be called with a [`WidgetMut`] for the given child widget.
         ///
         /// The callbacks will be run in the order they were submitted during the mutate pass.
@@ -1735,6 +2105,44 @@ impl_context_method!(
                 .emit_signal(RenderRootSignal::ClipboardStore(contents));
         }
 
+        /// Opens `url` with the platform's default handler, usually a web browser.
+        ///
+        /// Masonry doesn't check the URL, so widgets should make sure that it comes from
+        /// a trusted source.
+        pub fn open_url(&mut self, url: String) {
+            trace!("open_url");
+            self.global_state
+                .emit_signal(RenderRootSignal::OpenUrl(url));
+        }
+
+        /// Makes screen readers announce `text`, e.g. "3 results found".
+        ///
+        /// The announcement doesn't need a widget showing the text: it is made in a hidden
+        /// live region of the window, when the accessibility tree is next updated.
+        pub fn announce(&mut self, text: impl Into<String>, priority: AnnouncementPriority) {
+            trace!("announce");
+            self.global_state
+                .pending_announcements
+                .push((text.into(), priority));
+            self.global_state
+                .emit_signal(RenderRootSignal::RequestRedraw);
+        }
+
+        /// Requests that this widget's subtree be rendered into an image.
+        ///
+        /// The image has `scale` pixels per logical pixel.
+        /// Once it's rendered, the platform sends it back as a [`RenderedImage`] action of this widget.
+        ///
+        /// See [`RenderRoot::render_to_image`] for details.
+        ///
+        /// [`RenderedImage`]: crate::app::RenderedImage
+        /// [`RenderRoot::render_to_image`]: crate::app::RenderRoot::render_to_image
+        pub fn request_render_to_image(&mut self, scale: f64) {
+            trace!("request_render_to_image");
+            self.global_state
+                .emit_signal(RenderRootSignal::RenderToImage(self.widget_state.id, scale));
+        }
+
         /// Starts a window drag.
         ///
         /// Moves the window with the left mouse button until the button is released.
@@ -1911,4 +2319,103 @@ impl PaintCtx<'_> {
     pub fn debug_color(&self) -> Color {
         get_debug_color(self.widget_id().to_raw())
     }
+
+    /// Declares that `rect`, in the widget's coordinate space, is entirely covered by opaque paint.
+    ///
+    /// Masonry then skips encoding the widgets painted before this one which are fully
+    /// behind `rect`, e.g. the page beneath a full-window modal.
+    /// Widgets which [redraw continuously] are never skipped.
+    ///
+    /// The declaration is cleared whenever the widget is repainted, and is ignored for
+    /// widgets with a clip path or a rotated or skewed transform.
+    /// The default [`pre_paint`](crate::core::Widget::pre_paint) declares the area of an opaque
+    /// background color.
+    ///
+    /// [redraw continuously]: EventCtx::set_continuous_redraw
+    pub fn set_opaque_rect(&mut self, rect: Rect) {
+        self.widget_state.opaque_rect = (rect.width() > 0. && rect.height() > 0.).then_some(rect);
+    }
+
+    /// Returns the color of the backgrounds painted below the widget's content by the widget
+    /// and its ancestors, over the window's base color.
+    ///
+    /// Widgets painting text or icons can use it to pick a color which contrasts with
+    /// whatever they're placed on.
+    /// The backgrounds are those of the [`Background`], [`DisabledBackground`] and
+    /// [`ActiveBackground`] properties, so custom paint code is not accounted for.
+    /// Returns `None` if one of them is a gradient, or if the platform didn't set the
+    /// [base color] and none of them is opaque.
+    ///
+    /// Widgets are repainted when this changes.
+    ///
+    /// [`Background`]: crate::properties::Background
+    /// [`DisabledBackground`]: crate::properties::DisabledBackground
+    /// [`ActiveBackground`]: crate::properties::ActiveBackground
+    /// [base color]: crate::app::RenderRoot::set_base_color
+    pub fn effective_background(&self) -> Option<Color> {
+        self.widget_state.effective_background
+    }
+
+    /// Paints `surface` in `rect`, in the widget's coordinate space.
+    ///
+    /// The surface's render function is called with a texture of `rect`'s size in physical
+    /// pixels before this frame is rendered.
+    /// To render the surface again, e.g. on each animation frame, request a paint.
+    pub fn paint_wgpu_surface(&mut self, scene: &mut Scene, surface: &WgpuSurface, rect: Rect) {
+        let scale_factor = self.global_state.scale_factor;
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "Widget sizes are far below u32::MAX pixels"
+        )]
+        let size = PhysicalSize::new(
+            ((rect.width() * scale_factor).ceil() as u32).max(1),
+            ((rect.height() * scale_factor).ceil() as u32).max(1),
+        );
+        let image = surface.image(size, scale_factor);
+        let transform = Affine::translate(rect.origin().to_vec2())
+            * Affine::scale_non_uniform(
+                rect.width() / f64::from(size.width),
+                rect.height() / f64::from(size.height),
+            );
+        scene.draw_image(&ImageBrush::new(image), transform);
+
+        let painted = &mut self.global_state.painted_wgpu_surfaces;
+        if !painted.iter().any(|painted| painted.ptr_eq(surface)) {
+            painted.push(surface.clone());
+        }
+    }
+
+    /// Applies `effect` to everything this widget and its descendants paint, or removes
+    /// the current effect if `None`.
+    ///
+    /// The effect is kept until it's changed, and runs on each frame while the widget is
+    /// visible: to update its parameters, e.g. on each animation frame, request a paint.
+    /// A widget with an effect never occludes the widgets behind it.
+    ///
+    /// See [`WgpuEffect`] for details.
+    pub fn set_wgpu_effect(&mut self, effect: Option<WgpuEffect>) {
+        self.widget_state.wgpu_effect = effect;
+    }
+
+    /// Renders this widget and its descendants with the given anti-aliasing method, or with
+    /// the method of the parent if `None`.
+    ///
+    /// This is meant for subtrees needing crisper geometry than the rest of the window,
+    /// such as charts rendered with MSAA.
+    /// The subtree is then rendered separately, like with a [`WgpuEffect`].
+    /// Methods which the renderer doesn't support fall back to [`AaConfig::Area`].
+    ///
+    /// The method is kept until it's changed.
+    /// See [`RenderRoot::set_antialiasing`] for the method of the whole window.
+    ///
+    /// [`RenderRoot::set_antialiasing`]: crate::app::RenderRoot::set_antialiasing
+    pub fn set_antialiasing(&mut self, method: Option<AaConfig>) {
+        self.widget_state.antialiasing = method;
+        if method.is_none() {
+            self.widget_state.antialiasing_layer = None;
+        } else if self.widget_state.antialiasing_layer.is_none() {
+            self.widget_state.antialiasing_layer = Some(WgpuEffect::passthrough());
+        }
+    }
 }
@@ -3,8 +3,11 @@
 
 //! Events.
 
+use std::num::NonZeroU64;
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use ui_events::keyboard::{Code, Key, KeyState, KeyboardEvent};
-use vello::kurbo::Rect;
+use vello::kurbo::{Point, Rect};
 
 use crate::dpi::PhysicalSize;
 use crate::util::Duration;
@@ -24,6 +27,28 @@ pub enum WindowEvent {
     EnableAccessTree,
     /// The accessibility tree is no longer updated when rendering the app.
     DisableAccessTree,
+    /// The user's text size preference changed.
+    ///
+    /// The value is the factor text should be scaled by, e.g. `1.5` for text 50% larger
+    /// than normal. It's multiplied by the app's text scale, and every widget is laid out
+    /// again with the new font sizes.
+    ///
+    /// See [`RenderRoot::set_app_text_scale`](crate::app::RenderRoot::set_app_text_scale).
+    TextScaleChanged(f64),
+    /// The user's preference for reduced motion changed.
+    ///
+    /// When the preference changes, every widget is [composed](crate::core::Widget::compose)
+    /// again, so that widgets can start or stop their animations.
+    ///
+    /// See [`EventCtx::prefers_reduced_motion`](crate::core::EventCtx::prefers_reduced_motion).
+    ReducedMotionChanged(bool),
+    /// The user's caret blink setting changed.
+    ///
+    /// The duration is how long a blinking caret stays shown, then hidden.
+    /// `None` means that carets shouldn't blink.
+    ///
+    /// See [`EventCtx::caret_blink_interval`](crate::core::EventCtx::caret_blink_interval).
+    CaretBlinkChanged(Option<Duration>),
 }
 
 /// A text-related event.
@@ -108,6 +133,34 @@ pub enum Update {
     /// [`EventCtx::request_scroll_to`]: crate::core::EventCtx::request_scroll_to
     RequestPanToChild(Rect),
 
+    /// Called when a descendant widget receives focus through keyboard navigation.
+    ///
+    /// The included [`Rect`] is the descendant's border-box, in the receiving widget's
+    /// content-box coordinate space.
+    ///
+    /// Scroll containers should scroll it into view like for [`Update::RequestPanToChild`],
+    /// but may animate the scrolling.
+    RequestPanToFocusedChild(Rect),
+
+    /// Called during a drag which [requested edge auto-scroll], with the position of the
+    /// pointer in the receiving widget's content-box coordinate space, or `None` once the
+    /// drag has ended.
+    ///
+    /// This is sent to the ancestors of the dragged widget whenever the pointer moves.
+    /// Scroll containers should scroll while the pointer is near their edges, or past them,
+    /// faster the closer it gets.
+    ///
+    /// [requested edge auto-scroll]: crate::core::EventCtx::request_edge_auto_scroll
+    EdgeAutoScroll(Option<Point>),
+
+    /// Called when a timer requested by this widget fires.
+    ///
+    /// Widgets which request several timers can tell them apart with the token returned
+    /// by [`request_timer`], and should ignore the tokens they no longer care about.
+    ///
+    /// [`request_timer`]: crate::core::EventCtx::request_timer
+    Timer(TimerToken),
+
     /// Called when the [hovered] status of the current widget changes.
     ///
     /// [hovered]: crate::doc::masonry_concepts#widget-status
@@ -145,6 +198,12 @@ pub enum Update {
     ChildFocusChanged(bool),
 }
 
+/// The identifier of a timer, returned by [`EventCtx::request_timer`].
+///
+/// [`EventCtx::request_timer`]: crate::core::EventCtx::request_timer
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct TimerToken(NonZeroU64);
+
 /// An enum for specifying whether an event was handled.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Handled {
@@ -279,10 +338,22 @@ impl Update {
             Self::FocusChanged(true) => "FocusChanged(true)",
             Self::ChildFocusChanged(true) => "ChildFocusChanged(true)",
             Self::RequestPanToChild(_) => "RequestPanToChild(_)",
+            Self::RequestPanToFocusedChild(_) => "RequestPanToFocusedChild(_)",
+            Self::EdgeAutoScroll(_) => "EdgeAutoScroll(_)",
+            Self::Timer(_) => "Timer(_)",
         }
     }
 }
 
+impl TimerToken {
+    /// Allocates a new, unique token.
+    pub(crate) fn next() -> Self {
+        static TIMER_TOKEN_COUNTER: AtomicU64 = AtomicU64::new(1);
+        let id = TIMER_TOKEN_COUNTER.fetch_add(1, Ordering::Relaxed);
+        Self(id.try_into().unwrap())
+    }
+}
+
 impl Handled {
     /// Has the event been handled yet?
     pub fn is_handled(self) -> bool {
@@ -373,6 +444,24 @@ pub enum Ime {
     Disabled,
 }
 
+/// The kind of text accepted by a widget, which lets the platform's IME adapt its behavior.
+///
+/// This is returned by [`Widget::ime_purpose`](crate::core::Widget::ime_purpose).
+///
+/// Mirrors [`winit::window::ImePurpose`](https://docs.rs/winit/latest/winit/window/enum.ImePurpose.html).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ImePurpose {
+    /// No special hints for the IME.
+    #[default]
+    Normal,
+    /// The input is a password.
+    ///
+    /// The IME should avoid showing the text, predictions, or learning from it.
+    Password,
+    /// The input is a terminal, which may rely on keys that IMEs usually intercept.
+    Terminal,
+}
+
 /// Defines the orientation that a window resize will be performed.
 ///
 /// Mirrors [`winit::window::ResizeDirection`](https://docs.rs/winit/latest/x86_64-unknown-linux-gnu/winit/window/enum.ResizeDirection.html).
@@ -3,11 +3,17 @@
 
 //! Basic types and traits Masonry is built on.
 
+mod announcement;
+mod clip_shape;
 mod contexts;
 mod events;
 mod layer;
 mod properties;
+mod roving_focus;
+mod scroll_config;
 mod text;
+mod wgpu_effect;
+mod wgpu_surface;
 mod widget;
 mod widget_arena;
 mod widget_mut;
@@ -17,11 +23,17 @@ mod widget_ref;
 mod widget_state;
 mod widget_tag;
 
+pub use announcement::*;
+pub use clip_shape::*;
 pub use contexts::*;
 pub use events::*;
 pub use layer::*;
 pub use properties::*;
+pub use roving_focus::*;
+pub use scroll_config::*;
 pub use text::*;
+pub use wgpu_effect::*;
+pub use wgpu_surface::*;
 pub use widget::*;
 pub use widget_mut::*;
 pub use widget_paint::*;
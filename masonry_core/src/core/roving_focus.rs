@@ -0,0 +1,182 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{EventCtx, TextEvent, WidgetId};
+
+/// How the items of a [`RovingFocus`] group are arranged, which decides the arrow keys
+/// moving between them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RovingLayout {
+    /// Items in a row, such as a toolbar: Left and Right move to the previous and next items.
+    Horizontal,
+    /// Items in a column, such as a menu: Up and Down move to the previous and next items.
+    Vertical,
+    /// Items in a line which any arrow key moves through, such as a radio group:
+    /// Left and Up move to the previous item, Right and Down to the next one.
+    Both,
+    /// Items filling rows of `columns` items from left to right, such as the days of a calendar:
+    /// Left and Right move to the previous and next items, Up and Down to the items
+    /// of the previous and next rows.
+    Grid {
+        /// The number of items in each row.
+        columns: usize,
+    },
+}
+
+/// Moves the focus between the items of a composite widget with the arrow keys,
+/// for widgets which are a single Tab stop.
+///
+/// Widgets using this return true from [`Widget::is_roving_focus_group`], so that Tab
+/// only stops at one of their items, and call [`on_text_event`](Self::on_text_event)
+/// from [`Widget::on_text_event`].
+/// Home and End move to the first and last items.
+///
+/// The items are children of the widget which accept focus.
+/// Disabled and stashed items are skipped.
+///
+/// See [roving focus] for details.
+///
+/// [`Widget::is_roving_focus_group`]: crate::core::Widget::is_roving_focus_group
+/// [`Widget::on_text_event`]: crate::core::Widget::on_text_event
+/// [roving focus]: crate::doc::masonry_concepts#roving-focus
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RovingFocus {
+    layout: RovingLayout,
+    wrap: bool,
+    right_to_left: bool,
+}
+
+impl RovingFocus {
+    /// Creates a roving focus for items arranged with the given layout.
+    ///
+    /// The focus stops at the first and last items, see [`with_wrap`](Self::with_wrap).
+    pub fn new(layout: RovingLayout) -> Self {
+        Self {
+            layout,
+            wrap: false,
+            right_to_left: false,
+        }
+    }
+
+    /// Builder-style method to move the focus from the last item to the first one,
+    /// and the other way around.
+    ///
+    /// In a [`Grid`](RovingLayout::Grid), this only applies to Left and Right.
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Builder-style method to swap Left and Right, for items laid out from right to left.
+    pub fn with_rtl(mut self, right_to_left: bool) -> Self {
+        self.right_to_left = right_to_left;
+        self
+    }
+
+    /// Returns how the items are arranged.
+    pub fn layout(&self) -> RovingLayout {
+        self.layout
+    }
+
+    /// Moves the focus between `items` if `event` is an arrow key, Home or End.
+    ///
+    /// `items` are the ids of the children which form the group, in order.
+    /// This does nothing if the event was handled, for instance by a text input,
+    /// or if none of `items` is focused.
+    /// Otherwise, the next focused item is returned and the event is set as handled.
+    pub fn on_text_event(
+        &self,
+        ctx: &mut EventCtx<'_>,
+        event: &TextEvent,
+        items: &[WidgetId],
+    ) -> Option<WidgetId> {
+        if ctx.is_handled() {
+            return None;
+        }
+        let TextEvent::Keyboard(key_event) = event else {
+            return None;
+        };
+        if key_event.state.is_up() {
+            return None;
+        }
+        let current = items.iter().position(|&id| {
+            ctx.children
+                .item(id)
+                .is_some_and(|node| node.item.state.has_focus_target)
+        })?;
+        let focusable = |idx: usize| {
+            ctx.children.item(items[idx]).is_some_and(|node| {
+                let state = &node.item.state;
+                state.accepts_focus && !state.is_disabled && !state.is_stashed
+            })
+        };
+        let target = self.target(&key_event.key, current, items.len(), focusable)?;
+        if target != current {
+            ctx.set_focus(items[target]);
+        }
+        ctx.set_handled();
+        Some(items[target])
+    }
+
+    /// Returns the index of the item `key` moves to from `current`, skipping the items
+    /// for which `focusable` is false, or `None` if `key` isn't a navigation key.
+    fn target(
+        &self,
+        key: &Key,
+        current: usize,
+        len: usize,
+        focusable: impl Fn(usize) -> bool,
+    ) -> Option<usize> {
+        let (left, right) = if self.right_to_left {
+            (NamedKey::ArrowRight, NamedKey::ArrowLeft)
+        } else {
+            (NamedKey::ArrowLeft, NamedKey::ArrowRight)
+        };
+        let Key::Named(key) = key else {
+            return None;
+        };
+        // Only steps of one item wrap around.
+        let (step, backward, wrap) = match (self.layout, *key) {
+            (_, NamedKey::Home) => return (0..len).find(|&idx| focusable(idx)).or(Some(current)),
+            (_, NamedKey::End) => {
+                return (0..len).rev().find(|&idx| focusable(idx)).or(Some(current));
+            }
+            (RovingLayout::Horizontal | RovingLayout::Both | RovingLayout::Grid { .. }, key)
+                if key == left =>
+            {
+                (1, true, self.wrap)
+            }
+            (RovingLayout::Horizontal | RovingLayout::Both | RovingLayout::Grid { .. }, key)
+                if key == right =>
+            {
+                (1, false, self.wrap)
+            }
+            (RovingLayout::Vertical | RovingLayout::Both, NamedKey::ArrowUp) => {
+                (1, true, self.wrap)
+            }
+            (RovingLayout::Vertical | RovingLayout::Both, NamedKey::ArrowDown) => {
+                (1, false, self.wrap)
+            }
+            (RovingLayout::Grid { columns }, NamedKey::ArrowUp) => (columns.max(1), true, false),
+            (RovingLayout::Grid { columns }, NamedKey::ArrowDown) => (columns.max(1), false, false),
+            _ => return None,
+        };
+
+        let mut idx = current;
+        for _ in 0..len {
+            idx = match (backward, wrap) {
+                (true, _) if idx >= step => idx - step,
+                (false, _) if idx + step < len => idx + step,
+                (true, true) => len - 1,
+                (false, true) => 0,
+                _ => break,
+            };
+            if focusable(idx) {
+                return Some(idx);
+            }
+        }
+        // There is no focusable item in that direction, so the focus stays.
+        Some(current)
+    }
+}
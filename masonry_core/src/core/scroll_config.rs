@@ -0,0 +1,86 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use dpi::{LogicalPosition, PhysicalPosition};
+
+use crate::core::{Modifiers, ScrollDelta};
+use crate::kurbo::{Size, Vec2};
+
+/// How scroll events from mouse wheels and touchpads move scrollable widgets,
+/// such as portals.
+///
+/// The configuration of a window is set with [`RenderRoot::set_scroll_config`], and widgets
+/// read it with [`scroll_config`](crate::core::EventCtx::scroll_config).
+///
+/// [`RenderRoot::set_scroll_config`]: crate::app::RenderRoot::set_scroll_config
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollConfig {
+    /// The distance scrolled by one line of a mouse wheel, in logical pixels.
+    ///
+    /// This only applies to wheels which report their deltas in lines.
+    /// Touchpads and high-resolution wheels usually report them in pixels.
+    pub line_length: f64,
+    /// Whether vertical scrolling with Shift held scrolls horizontally instead.
+    ///
+    /// This is `false` on macOS, where the platform already does it.
+    pub shift_scrolls_horizontally: bool,
+    /// Whether to scroll in the opposite direction vertically.
+    pub invert_vertical: bool,
+    /// Whether to scroll in the opposite direction horizontally.
+    pub invert_horizontal: bool,
+}
+
+impl ScrollConfig {
+    /// The default configuration.
+    pub const DEFAULT: Self = Self {
+        line_length: 120.,
+        shift_scrolls_horizontally: !cfg!(target_os = "macos"),
+        invert_vertical: false,
+        invert_horizontal: false,
+    };
+
+    /// Converts the `delta` of a scroll event to logical pixels, applying this configuration.
+    ///
+    /// `page_size` is the size of the scrolled viewport, used for deltas reported in pages,
+    /// and `modifiers` are the modifiers held during the event.
+    ///
+    /// As with [`ScrollDelta`], positive values scroll towards the top left.
+    pub fn logical_delta(
+        &self,
+        delta: ScrollDelta,
+        page_size: Size,
+        scale_factor: f64,
+        modifiers: Modifiers,
+    ) -> Vec2 {
+        // TODO - Remove reference to scale factor.
+        // See https://github.com/linebender/xilem/issues/1264
+        let line_px = PhysicalPosition {
+            x: self.line_length * scale_factor,
+            y: self.line_length * scale_factor,
+        };
+        let page_px = PhysicalPosition {
+            x: page_size.width * scale_factor,
+            y: page_size.height * scale_factor,
+        };
+        let delta_px = delta.to_pixel_delta(line_px, page_px);
+        let LogicalPosition { x, y } = delta_px.to_logical::<f64>(scale_factor);
+        let mut delta = Vec2::new(x, y);
+
+        if self.shift_scrolls_horizontally && modifiers.shift() && delta.x == 0. {
+            delta = Vec2::new(delta.y, 0.);
+        }
+        if self.invert_horizontal {
+            delta.x = -delta.x;
+        }
+        if self.invert_vertical {
+            delta.y = -delta.y;
+        }
+        delta
+    }
+}
+
+impl Default for ScrollConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,295 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use dpi::PhysicalSize;
+use tracing::warn;
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Blob, Color, ImageAlphaType, ImageData, ImageFormat};
+use vello::wgpu::{
+    CommandEncoderDescriptor, Device, Extent3d, Origin3d, Queue, TexelCopyTextureInfoBase, Texture,
+    TextureAspect, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages, TextureView,
+    TextureViewDescriptor,
+};
+use vello::{AaConfig, RenderParams, Renderer, Scene};
+
+use crate::app::image_size;
+
+/// The textures a [`WgpuEffect`] reads the widget's subtree from and writes its result to,
+/// with the device they belong to.
+pub struct WgpuEffectTarget<'a> {
+    /// The device of Masonry's renderer.
+    pub device: &'a Device,
+    /// The queue of Masonry's renderer.
+    pub queue: &'a Queue,
+    /// The texture the widget and its descendants were rendered to.
+    ///
+    /// It has the `Rgba8Unorm` format with unpremultiplied alpha, and can be used as a
+    /// texture binding or a copy source.
+    pub source: &'a Texture,
+    /// A view of the whole source texture.
+    pub source_view: &'a TextureView,
+    /// The texture to write the result of the effect to, which is drawn instead of the subtree.
+    ///
+    /// It has the same size and format as the source texture, with unpremultiplied alpha,
+    /// and can be used as a render attachment, a storage binding, a texture binding, or
+    /// a copy destination.
+    pub texture: &'a Texture,
+    /// A view of the whole texture.
+    pub view: &'a TextureView,
+    /// The size of both textures, in physical pixels.
+    pub size: PhysicalSize<u32>,
+    /// The number of physical pixels per logical pixel.
+    pub scale_factor: f64,
+}
+
+/// A function applying a [`WgpuEffect`].
+type ApplyFn = Box<dyn FnMut(&WgpuEffectTarget<'_>)>;
+
+/// A custom image filter, written with `wgpu`, applied to everything a widget and its
+/// descendants paint, such as a directional blur, chromatic aberration or film grain.
+///
+/// Widgets apply an effect with [`PaintCtx::set_wgpu_effect`].
+/// Each frame, their subtree is rendered to a texture the size of their bounding box,
+/// then the effect's apply function writes the result to another texture, which is drawn
+/// in place of the subtree.
+/// Effects can't draw outside of that bounding box, so widgets applying e.g. a blur
+/// should have paint insets covering it.
+///
+/// This is a cheap handle: clones share the same apply function and textures.
+/// An effect should only be applied to one widget at a time.
+///
+/// Effects are rendered by platforms with [`RenderRoot::render_wgpu_surfaces`], after
+/// the [`WgpuSurface`](crate::core::WgpuSurface)s, and nested effects are rendered before
+/// the effects containing them.
+///
+/// [`PaintCtx::set_wgpu_effect`]: crate::core::PaintCtx::set_wgpu_effect
+/// [`RenderRoot::render_wgpu_surfaces`]: crate::app::RenderRoot::render_wgpu_surfaces
+#[derive(Clone)]
+pub struct WgpuEffect {
+    inner: Rc<RefCell<WgpuEffectInner>>,
+}
+
+struct WgpuEffectInner {
+    apply: ApplyFn,
+    /// The subtree painted last, in window coordinates.
+    scene: Scene,
+    /// The area of `scene` the effect applies to, in window coordinates.
+    bounds: Rect,
+    /// The anti-aliasing method `scene` is rendered with.
+    antialiasing: AaConfig,
+    /// The image the subtree is painted as, which is overridden by the result when rendering.
+    image: Option<ImageData>,
+    /// The image whose override was set in the renderer, if any.
+    overridden: Option<ImageData>,
+    /// The source and result textures.
+    textures: Option<[(Texture, TextureView); 2]>,
+    scale_factor: f64,
+}
+
+impl WgpuEffect {
+    /// Creates an effect applied by `apply`.
+    pub fn new(apply: impl FnMut(&WgpuEffectTarget<'_>) + 'static) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(WgpuEffectInner {
+                apply: Box::new(apply),
+                scene: Scene::new(),
+                bounds: Rect::ZERO,
+                antialiasing: AaConfig::Area,
+                image: None,
+                overridden: None,
+                textures: None,
+                scale_factor: 1.,
+            })),
+        }
+    }
+
+    /// Creates an effect which leaves the subtree as is.
+    pub(crate) fn passthrough() -> Self {
+        Self::new(|target| {
+            let mut encoder = target
+                .device
+                .create_command_encoder(&CommandEncoderDescriptor {
+                    label: Some("WgpuEffect passthrough"),
+                });
+            encoder.copy_texture_to_texture(
+                target.source.as_image_copy(),
+                target.texture.as_image_copy(),
+                target.source.size(),
+            );
+            target.queue.submit([encoder.finish()]);
+        })
+    }
+
+    /// Replaces the function applying the effect.
+    pub fn set_apply(&self, apply: impl FnMut(&WgpuEffectTarget<'_>) + 'static) {
+        self.inner.borrow_mut().apply = Box::new(apply);
+    }
+
+    /// Stores the `scene` of the subtree the effect applies to, and returns the image
+    /// to draw in its place within `bounds`, in window coordinates.
+    ///
+    /// The scene is rendered with the `antialiasing` method.
+    pub(crate) fn image(
+        &self,
+        scene: Scene,
+        bounds: Rect,
+        scale_factor: f64,
+        antialiasing: AaConfig,
+    ) -> ImageData {
+        let mut inner = self.inner.borrow_mut();
+        inner.scene = scene;
+        inner.bounds = bounds;
+        inner.antialiasing = antialiasing;
+        inner.scale_factor = scale_factor;
+        let (width, height) = image_size(bounds, scale_factor);
+        if let Some(image) = &inner.image
+            && image.width == width
+            && image.height == height
+        {
+            return image.clone();
+        }
+        // The pixels are never read: the image is always overridden by the result.
+        let pixels = vec![0; width as usize * height as usize * 4];
+        let image = ImageData {
+            data: Blob::new(Arc::new(pixels)),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width,
+            height,
+        };
+        inner.image = Some(image.clone());
+        image
+    }
+
+    pub(crate) fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Returns `true` if this handle is the last one, i.e. if the widget applying the
+    /// effect was removed.
+    pub(crate) fn is_orphaned(&self) -> bool {
+        Rc::strong_count(&self.inner) == 1
+    }
+
+    /// Renders the subtree and applies the effect, and makes `renderer` draw the result
+    /// instead of the effect's image.
+    pub(crate) fn render(&self, device: &Device, queue: &Queue, renderer: &mut Renderer) {
+        let mut inner = self.inner.borrow_mut();
+        let inner = &mut *inner;
+        let Some(image) = inner.image.clone() else {
+            return;
+        };
+        let size = PhysicalSize::new(image.width, image.height);
+
+        let outdated = inner.textures.as_ref().is_none_or(|[(texture, _), _]| {
+            texture.width() != size.width || texture.height() != size.height
+        });
+        if outdated {
+            let create_texture = |label, usage| {
+                let texture = device.create_texture(&TextureDescriptor {
+                    label: Some(label),
+                    size: Extent3d {
+                        width: size.width,
+                        height: size.height,
+                        depth_or_array_layers: 1,
+                    },
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: TextureDimension::D2,
+                    format: TextureFormat::Rgba8Unorm,
+                    usage,
+                    view_formats: &[],
+                });
+                let view = texture.create_view(&TextureViewDescriptor::default());
+                (texture, view)
+            };
+            inner.textures = Some([
+                create_texture(
+                    "WgpuEffect source texture",
+                    TextureUsages::STORAGE_BINDING
+                        | TextureUsages::TEXTURE_BINDING
+                        | TextureUsages::COPY_SRC,
+                ),
+                create_texture(
+                    "WgpuEffect texture",
+                    TextureUsages::RENDER_ATTACHMENT
+                        | TextureUsages::STORAGE_BINDING
+                        | TextureUsages::TEXTURE_BINDING
+                        | TextureUsages::COPY_SRC
+                        | TextureUsages::COPY_DST,
+                ),
+            ]);
+        }
+        let [(source, source_view), (texture, view)] = inner.textures.as_ref().unwrap();
+
+        let mut scene = Scene::new();
+        scene.append(
+            &inner.scene,
+            Some(
+                Affine::scale(inner.scale_factor)
+                    * Affine::translate(-inner.bounds.origin().to_vec2()),
+            ),
+        );
+        let render_params = RenderParams {
+            base_color: Color::TRANSPARENT,
+            width: size.width,
+            height: size.height,
+            antialiasing_method: inner.antialiasing,
+        };
+        if let Err(err) =
+            renderer.render_to_texture(device, queue, &scene, source_view, &render_params)
+        {
+            warn!("Couldn't render the subtree of a WgpuEffect: {err}");
+            return;
+        }
+
+        (inner.apply)(&WgpuEffectTarget {
+            device,
+            queue,
+            source,
+            source_view,
+            texture,
+            view,
+            size,
+            scale_factor: inner.scale_factor,
+        });
+
+        let overridden_id = inner.overridden.as_ref().map(|image| image.data.id());
+        if outdated || overridden_id != Some(image.data.id()) {
+            if let Some(previous) = inner.overridden.take() {
+                renderer.override_image(&previous, None);
+            }
+            renderer.override_image(
+                &image,
+                Some(TexelCopyTextureInfoBase {
+                    texture: texture.clone(),
+                    mip_level: 0,
+                    origin: Origin3d::ZERO,
+                    aspect: TextureAspect::All,
+                }),
+            );
+            inner.overridden = Some(image);
+        }
+    }
+
+    /// Removes the override set by [`render`](Self::render).
+    pub(crate) fn clear_override(&self, renderer: &mut Renderer) {
+        if let Some(image) = self.inner.borrow_mut().overridden.take() {
+            renderer.override_image(&image, None);
+        }
+    }
+}
+
+impl Debug for WgpuEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let inner = self.inner.borrow();
+        f.debug_struct("WgpuEffect")
+            .field("bounds", &inner.bounds)
+            .finish_non_exhaustive()
+    }
+}
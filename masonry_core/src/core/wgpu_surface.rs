@@ -0,0 +1,207 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use dpi::PhysicalSize;
+use vello::Renderer;
+use vello::peniko::{Blob, ImageAlphaType, ImageData, ImageFormat};
+use vello::wgpu::{
+    Device, Extent3d, Origin3d, Queue, TexelCopyTextureInfoBase, Texture, TextureAspect,
+    TextureDescriptor, TextureDimension, TextureFormat, TextureUsages, TextureView,
+    TextureViewDescriptor,
+};
+
+/// The texture a [`WgpuSurface`] renders its content to, with the device it belongs to.
+pub struct WgpuSurfaceTarget<'a> {
+    /// The device of Masonry's renderer.
+    pub device: &'a Device,
+    /// The queue of Masonry's renderer.
+    pub queue: &'a Queue,
+    /// The texture to render to.
+    ///
+    /// It has the `Rgba8Unorm` format, and can be used as a render attachment, a storage
+    /// binding, or a texture binding.
+    /// Its content is kept between frames until it is resized.
+    pub texture: &'a Texture,
+    /// A view of the whole texture.
+    pub view: &'a TextureView,
+    /// The size of the texture, in physical pixels.
+    pub size: PhysicalSize<u32>,
+    /// The number of physical pixels per logical pixel.
+    pub scale_factor: f64,
+}
+
+/// A function rendering the content of a [`WgpuSurface`].
+type RenderFn = Box<dyn FnMut(&WgpuSurfaceTarget<'_>)>;
+
+/// Content rendered by app code with `wgpu`, such as a 3D scene, and painted as part of
+/// the widget tree with [`PaintCtx::paint_wgpu_surface`].
+///
+/// Each time a widget paints the surface, its render function is called before the frame
+/// is rendered, with a texture of the size the surface was painted at.
+/// The texture is then drawn like an image, so the content is clipped and layered like the
+/// rest of the widget tree.
+///
+/// This is a cheap handle: clones share the same render function and texture.
+///
+/// Surfaces are rendered by platforms with [`RenderRoot::render_wgpu_surfaces`].
+///
+/// [`RenderRoot::render_wgpu_surfaces`]: crate::app::RenderRoot::render_wgpu_surfaces
+///
+/// [`PaintCtx::paint_wgpu_surface`]: crate::core::PaintCtx::paint_wgpu_surface
+#[derive(Clone)]
+pub struct WgpuSurface {
+    inner: Rc<RefCell<WgpuSurfaceInner>>,
+}
+
+struct WgpuSurfaceInner {
+    render: RenderFn,
+    /// The image the surface is painted as, which is overridden by the texture when rendering.
+    image: Option<ImageData>,
+    /// The image whose override was set in the renderer, if any.
+    overridden: Option<ImageData>,
+    texture: Option<(Texture, TextureView)>,
+    scale_factor: f64,
+}
+
+impl WgpuSurface {
+    /// Creates a surface whose content is rendered by `render`.
+    pub fn new(render: impl FnMut(&WgpuSurfaceTarget<'_>) + 'static) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(WgpuSurfaceInner {
+                render: Box::new(render),
+                image: None,
+                overridden: None,
+                texture: None,
+                scale_factor: 1.,
+            })),
+        }
+    }
+
+    /// Replaces the function rendering the content.
+    pub fn set_render(&self, render: impl FnMut(&WgpuSurfaceTarget<'_>) + 'static) {
+        self.inner.borrow_mut().render = Box::new(render);
+    }
+
+    /// Returns the image to draw for this surface at the given size, in physical pixels.
+    pub(crate) fn image(&self, size: PhysicalSize<u32>, scale_factor: f64) -> ImageData {
+        let mut inner = self.inner.borrow_mut();
+        inner.scale_factor = scale_factor;
+        if let Some(image) = &inner.image
+            && image.width == size.width
+            && image.height == size.height
+        {
+            return image.clone();
+        }
+        // The image is always overridden by the texture, so it has no pixels.
+        // Overrides are keyed on the blob's id, so each surface and size needs its own blob.
+        let image = ImageData {
+            data: Blob::new(Arc::new(Vec::<u8>::new())),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::AlphaPremultiplied,
+            width: size.width,
+            height: size.height,
+        };
+        inner.image = Some(image.clone());
+        image
+    }
+
+    pub(crate) fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Returns `true` if this handle is the last one, i.e. if the widget painting the
+    /// surface was removed.
+    pub(crate) fn is_orphaned(&self) -> bool {
+        Rc::strong_count(&self.inner) == 1
+    }
+
+    /// Renders the content, and makes `renderer` draw it instead of the surface's image.
+    pub(crate) fn render(&self, device: &Device, queue: &Queue, renderer: &mut Renderer) {
+        let mut inner = self.inner.borrow_mut();
+        let inner = &mut *inner;
+        let Some(image) = inner.image.clone() else {
+            return;
+        };
+        let size = PhysicalSize::new(image.width, image.height);
+
+        let outdated = inner.texture.as_ref().is_none_or(|(texture, _)| {
+            texture.width() != size.width || texture.height() != size.height
+        });
+        if outdated {
+            let texture = device.create_texture(&TextureDescriptor {
+                label: Some("WgpuSurface texture"),
+                size: Extent3d {
+                    width: size.width,
+                    height: size.height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format: TextureFormat::Rgba8Unorm,
+                usage: TextureUsages::RENDER_ATTACHMENT
+                    | TextureUsages::STORAGE_BINDING
+                    | TextureUsages::TEXTURE_BINDING
+                    | TextureUsages::COPY_SRC
+                    | TextureUsages::COPY_DST,
+                view_formats: &[],
+            });
+            let view = texture.create_view(&TextureViewDescriptor::default());
+            inner.texture = Some((texture, view));
+        }
+        let (texture, view) = inner.texture.as_ref().unwrap();
+
+        (inner.render)(&WgpuSurfaceTarget {
+            device,
+            queue,
+            texture,
+            view,
+            size,
+            scale_factor: inner.scale_factor,
+        });
+
+        let overridden_id = inner.overridden.as_ref().map(|image| image.data.id());
+        if outdated || overridden_id != Some(image.data.id()) {
+            if let Some(previous) = inner.overridden.take() {
+                renderer.override_image(&previous, None);
+            }
+            renderer.override_image(
+                &image,
+                Some(TexelCopyTextureInfoBase {
+                    texture: texture.clone(),
+                    mip_level: 0,
+                    origin: Origin3d::ZERO,
+                    aspect: TextureAspect::All,
+                }),
+            );
+            inner.overridden = Some(image);
+        }
+    }
+
+    /// Removes the override set by [`render`](Self::render).
+    pub(crate) fn clear_override(&self, renderer: &mut Renderer) {
+        if let Some(image) = self.inner.borrow_mut().overridden.take() {
+            renderer.override_image(&image, None);
+        }
+    }
+}
+
+impl Debug for WgpuSurface {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let inner = self.inner.borrow();
+        f.debug_struct("WgpuSurface")
+            .field(
+                "size",
+                &inner
+                    .image
+                    .as_ref()
+                    .map(|image| (image.width, image.height)),
+            )
+            .finish_non_exhaustive()
+    }
+}
@@ -11,14 +11,15 @@ use smallvec::SmallVec;
 use tracing::field::DisplayValue;
 use tracing::{Span, trace_span};
 use vello::Scene;
-use vello::kurbo::{Axis, Point, Size};
+use vello::kurbo::{Axis, Point, Shape as _, Size};
 
 use crate::core::{
-    AccessCtx, AccessEvent, ComposeCtx, CursorIcon, EventCtx, Layer, LayoutCtx, MeasureCtx,
-    NewWidget, PaintCtx, PointerEvent, Properties, PropertiesMut, PropertiesRef, QueryCtx,
-    RegisterCtx, TextEvent, Update, UpdateCtx, WidgetMut, WidgetRef, pre_paint,
+    AccessCtx, AccessEvent, ComposeCtx, CursorIcon, EventCtx, ImePurpose, Layer, LayoutCtx,
+    MeasureCtx, NewWidget, PaintCtx, PointerEvent, Properties, PropertiesMut, PropertiesRef,
+    QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, WidgetMut, WidgetRef, pre_paint,
 };
 use crate::layout::LenReq;
+use crate::properties::z_order_key;
 
 /// A unique identifier for a single [`Widget`].
 ///
@@ -361,6 +362,10 @@ pub trait Widget: AsDynWidget + Any {
     fn layout(&mut self, ctx: &mut LayoutCtx<'_>, props: &PropertiesRef<'_>, size: Size);
 
     /// Runs after the widget's final transform has been computed.
+    ///
+    /// This is called after the widget has been laid out, when it has
+    /// [requested](EventCtx::request_compose) it, and whenever its window transform
+    /// or [viewport](ComposeCtx::viewport) changed, including because an ancestor moved or scrolled.
     fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {}
 
     /// Paints the widget's background.
@@ -449,6 +454,21 @@ pub trait Widget: AsDynWidget + Any {
         false
     }
 
+    /// Whether the focusable descendants of this widget form a single Tab stop,
+    /// also known as a [roving focus] group. False by default.
+    ///
+    /// If true, pressing Tab focuses the descendant of this widget which was focused last,
+    /// or its first focusable descendant, and pressing Tab again leaves this widget.
+    /// The widget moves the focus between its descendants itself, usually with the arrow keys
+    /// handled by a [`RovingFocus`](crate::core::RovingFocus).
+    ///
+    /// **Note:** The value returned by this method is cached at widget creation and can't be changed.
+    ///
+    /// [roving focus]: crate::doc::masonry_concepts#roving-focus
+    fn is_roving_focus_group(&self) -> bool {
+        false
+    }
+
     /// Whether this widget gets IME events. False by default.
     ///
     /// If true, focusing this widget will start an IME session.
@@ -458,6 +478,14 @@ pub trait Widget: AsDynWidget + Any {
         false
     }
 
+    /// The kind of text this widget accepts, which is passed to the platform's IME.
+    ///
+    /// This is only used for widgets which [accept text input](Self::accepts_text_input),
+    /// and is queried when the widget gains focus.
+    fn ime_purpose(&self) -> ImePurpose {
+        ImePurpose::Normal
+    }
+
     // TODO - Write a generic default implementation once
     // `const std::any::type_name` is stable.
     // See https://github.com/rust-lang/rust/issues/63084
@@ -568,10 +596,16 @@ pub fn find_widget_under_pointer<'c>(
         return None;
     }
 
-    // Assumes `Self::children_ids` is in increasing "z-order", picking the last child in case
-    // of overlapping children.
-    for child_id in widget.children_ids().iter().rev() {
-        let child_ref = ctx.get(*child_id);
+    // Children are hit-tested in reverse paint order, picking the last painted child in case
+    // of overlapping children. See `ZIndex` for details.
+    let mut children: SmallVec<[_; 16]> = widget
+        .children_ids()
+        .iter()
+        .map(|child_id| ctx.get(*child_id))
+        .collect();
+    // The sort is stable, so children with the same key keep their `children_ids` order.
+    children.sort_by_key(|child_ref| z_order_key(child_ref.ctx.properties));
+    for child_ref in children.into_iter().rev() {
         if let Some(child) = child_ref
             .widget
             .find_widget_under_pointer(child_ref.ctx, pos)
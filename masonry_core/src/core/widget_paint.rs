@@ -7,18 +7,27 @@ use crate::core::{PaintCtx, PropertiesRef};
 use crate::kurbo::{Affine, Join, Rect, Stroke};
 use crate::peniko::Fill;
 use crate::properties::{
-    ActiveBackground, Background, BorderColor, BorderWidth, BoxShadow, CornerRadius,
-    DisabledBackground, FocusedBorderColor, HoveredBorderColor,
+    ActiveBackground, ActiveElevation, Background, BackgroundImage, BorderColor, BorderWidth,
+    BoxShadow, CornerRadius, DisabledBackground, Elevation, ElevationShadows, FocusedBorderColor,
+    HoveredBorderColor, HoveredElevation, PressFeedback,
 };
 
 /// References to common pre-paint properties.
 pub struct PrePaintProps<'a> {
+    /// Elevation.
+    ///
+    /// Considers active and hovered state.
+    pub elevation: Elevation,
+    /// Elevation shadows.
+    pub elevation_shadows: &'a ElevationShadows,
     /// Box shadow.
     pub box_shadow: &'a BoxShadow,
     /// Background.
     ///
     /// Considers disabled and active state.
     pub background: &'a Background,
+    /// Background image.
+    pub background_image: &'a BackgroundImage,
     /// Border width.
     pub border_width: &'a BorderWidth,
     /// Border color.
@@ -32,18 +41,20 @@ pub struct PrePaintProps<'a> {
 impl<'a> PrePaintProps<'a> {
     /// Returns common pre-paint properties based on widget state.
     pub fn fetch(ctx: &mut PaintCtx<'_>, props: &'a PropertiesRef<'_>) -> Self {
-        let box_shadow = props.get::<BoxShadow>();
-        let background = if ctx.is_disabled()
-            && let Some(db) = props.get_defined::<DisabledBackground>()
+        let elevation = if ctx.is_active()
+            && let Some(ae) = props.get_defined::<ActiveElevation>()
         {
-            &db.0
-        } else if ctx.is_active()
-            && let Some(ab) = props.get_defined::<ActiveBackground>()
+            ae.0
+        } else if ctx.is_hovered()
+            && let Some(he) = props.get_defined::<HoveredElevation>()
         {
-            &ab.0
+            he.0
         } else {
-            props.get::<Background>()
+            *props.get::<Elevation>()
         };
+        let elevation_shadows = props.get::<ElevationShadows>();
+        let box_shadow = props.get::<BoxShadow>();
+        let background = current_background(ctx.is_disabled(), ctx.is_active(), props);
         let border_color = if ctx.is_focus_target()
             && let Some(fb) = props.get_defined::<FocusedBorderColor>()
         {
@@ -55,12 +66,16 @@ impl<'a> PrePaintProps<'a> {
         } else {
             props.get::<BorderColor>()
         };
+        let background_image = props.get::<BackgroundImage>();
         let border_width = props.get::<BorderWidth>();
         let corner_radius = props.get::<CornerRadius>();
 
         Self {
+            elevation,
+            elevation_shadows,
             box_shadow,
             background,
+            background_image,
             border_width,
             border_color,
             corner_radius,
@@ -68,16 +83,73 @@ impl<'a> PrePaintProps<'a> {
     }
 }
 
-/// Paints the widget's box shadow, background, and border.
+/// Returns the background of a widget, which depends on whether it's disabled or active.
+pub(crate) fn current_background<'a>(
+    is_disabled: bool,
+    is_active: bool,
+    props: &'a PropertiesRef<'_>,
+) -> &'a Background {
+    if is_disabled && let Some(db) = props.get_defined::<DisabledBackground>() {
+        &db.0
+    } else if is_active && let Some(ab) = props.get_defined::<ActiveBackground>() {
+        &ab.0
+    } else {
+        props.get::<Background>()
+    }
+}
+
+/// Paints the widget's elevation shadows, box shadow, background, background image,
+/// press feedback, and border.
 pub fn pre_paint(ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
     let bbox = ctx.border_box();
     let p = PrePaintProps::fetch(ctx, props);
 
+    paint_elevation(
+        scene,
+        bbox,
+        p.elevation,
+        p.elevation_shadows,
+        p.corner_radius,
+    );
     paint_box_shadow(scene, bbox, p.box_shadow, p.corner_radius);
     paint_background(scene, bbox, p.background, p.border_width, p.corner_radius);
+    if p.background.is_opaque() {
+        // The rounded corners cut at most the largest radius off each side.
+        let bg_rect = p.border_width.bg_rect(bbox, p.corner_radius);
+        let radii = bg_rect.radii();
+        let max_radius = radii
+            .top_left
+            .max(radii.top_right)
+            .max(radii.bottom_right)
+            .max(radii.bottom_left);
+        ctx.set_opaque_rect(bg_rect.rect().inset(-max_radius));
+    }
+    paint_background_image(
+        scene,
+        bbox,
+        p.background_image,
+        p.border_width,
+        p.corner_radius,
+    );
+    paint_press_feedback(ctx, props, scene);
     paint_border(scene, bbox, p.border_color, p.border_width, p.corner_radius);
 }
 
+/// Paints the shadows of the widget's elevation.
+pub fn paint_elevation(
+    scene: &mut Scene,
+    border_box: Rect,
+    elevation: Elevation,
+    elevation_shadows: &ElevationShadows,
+    corner_radius: &CornerRadius,
+) {
+    let Some(level) = elevation_shadows.get(elevation) else {
+        return;
+    };
+    let shadow_rect = border_box.to_rounded_rect(corner_radius.radius);
+    level.paint(scene, Affine::IDENTITY, shadow_rect);
+}
+
 /// Paints the widget's box shadow.
 pub fn paint_box_shadow(
     scene: &mut Scene,
@@ -111,6 +183,40 @@ pub fn paint_background(
     scene.fill(Fill::NonZero, Affine::IDENTITY, &bg_brush, None, &bg_rect);
 }
 
+/// Paints the widget's background image.
+pub fn paint_background_image(
+    scene: &mut Scene,
+    border_box: Rect,
+    background_image: &BackgroundImage,
+    border_width: &BorderWidth,
+    corner_radius: &CornerRadius,
+) {
+    if !background_image.is_visible() {
+        return;
+    }
+    let bg_rect = border_width.bg_rect(border_box, corner_radius);
+    background_image.paint(scene, Affine::IDENTITY, bg_rect);
+}
+
+/// Paints the widget's [`PressFeedback`], if a press is being animated.
+///
+/// The feedback is clipped to the widget's clip path, or to its background if it has none.
+pub fn paint_press_feedback(ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+    let Some(state) = ctx.widget_state.press_feedback else {
+        return;
+    };
+    let bbox = ctx.border_box();
+    let clip = ctx.clip_path().unwrap_or_else(|| {
+        let border_width = props.get::<BorderWidth>();
+        border_width
+            .bg_rect(bbox, props.get::<CornerRadius>())
+            .into()
+    });
+    props
+        .get::<PressFeedback>()
+        .paint(scene, bbox, &clip, &state, ctx.prefers_reduced_motion());
+}
+
 /// Paints the widget's border.
 pub fn paint_border(
     scene: &mut Scene,
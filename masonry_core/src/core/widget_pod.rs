@@ -65,6 +65,10 @@ pub struct WidgetOptions {
     pub transform: Affine,
     /// The disabled state the widget will be created with.
     pub disabled: bool,
+    /// Whether the widget and its descendants are painted on every animation frame.
+    ///
+    /// See [`EventCtx::set_continuous_redraw`](crate::core::EventCtx::set_continuous_redraw).
+    pub continuous_redraw: bool,
 }
 
 // TODO - This is a simple state machine that lets users create WidgetPods
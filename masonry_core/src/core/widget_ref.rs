@@ -149,10 +149,7 @@ impl<'w, W: Widget> WidgetRef<'w, W> {
     /// useful for specific implementors of [`Widget::find_widget_under_pointer`],
     /// to allow for returning the `WidgetRef` for self.
     pub fn remake(ctx: QueryCtx<'w>, widget: &'w W) -> Self {
-        WidgetRef {
-            ctx: ctx,
-            widget: widget,
-        }
+        WidgetRef { ctx, widget }
     }
 
     /// Returns a type-erased `WidgetRef`.
@@ -4,10 +4,13 @@
 use std::any::TypeId;
 
 use tracing::Span;
-use vello::kurbo::{Affine, Insets, Point, Rect, Size, Vec2};
+use vello::AaConfig;
+use vello::kurbo::{Affine, Insets, Point, Rect, Shape as _, Size, Vec2};
+use vello::peniko::Color;
 
-use crate::core::{WidgetId, WidgetOptions};
+use crate::core::{ClipShape, WgpuEffect, WidgetId, WidgetOptions};
 use crate::layout::MeasurementCache;
+use crate::properties::PressFeedbackState;
 
 // TODO - Reduce WidgetState size.
 // See https://github.com/linebender/xilem/issues/706
@@ -119,14 +122,30 @@ pub(crate) struct WidgetState {
     /// The pixel-snapped position of the baseline in the parent's border-box coordinate space.
     pub(crate) baseline_y: f64,
 
-    // TODO - Use general Shape
-    // Currently Kurbo doesn't really provide a type that lets us
-    // efficiently hold an arbitrary shape.
     /// The widget's clip path in the widget's border-box coordinate space.
     ///
     /// This clips the painting of `Widget::paint` and all the painting of children.
     /// It does not clip this widget's `Widget::pre_paint` nor `Widget::post_paint`.
-    pub(crate) clip_path: Option<Rect>,
+    pub(crate) clip_path: Option<ClipShape>,
+    /// A rect fully covered by opaque paint of this widget,
+    /// in the widget's content-box coordinate space.
+    ///
+    /// Widgets painted earlier which are entirely behind this rect are culled.
+    /// This is cleared whenever the widget is repainted.
+    pub(crate) opaque_rect: Option<Rect>,
+    /// The color of the backgrounds painted below the widget's content by the widget and
+    /// its ancestors, over the window's base color.
+    ///
+    /// This is `None` if one of them is a gradient, or if the base color is unknown and
+    /// none of them is opaque.
+    pub(crate) effective_background: Option<Color>,
+    /// The area of this widget exposed as a viewport to its descendants,
+    /// in the widget's border-box coordinate space.
+    pub(crate) child_viewport: Option<Rect>,
+    /// The visible part of the viewports of this widget's ancestors, in the window's coordinate space.
+    ///
+    /// This is the intersection of the bounding boxes of those viewports.
+    pub(crate) viewport: Option<Rect>,
 
     /// Local transform used during the mapping of this widget's border-box coordinate space
     /// to the parent's border-box coordinate space.
@@ -147,6 +166,16 @@ pub(crate) struct WidgetState {
     pub(crate) scroll_translation: Vec2,
     /// The `transform` or `scroll_translation` has changed.
     pub(crate) transform_changed: bool,
+    /// The opacity the widget and its descendants are painted with, from 0 to 1.
+    pub(crate) opacity: f32,
+    /// The effect applied to everything the widget and its descendants paint.
+    pub(crate) wgpu_effect: Option<WgpuEffect>,
+    /// The anti-aliasing method the widget and its descendants are rendered with,
+    /// if it overrides the one of its parent.
+    pub(crate) antialiasing: Option<AaConfig>,
+    /// The effect rendering the widget's subtree separately, when it's anti-aliased
+    /// with a different method than its parent.
+    pub(crate) antialiasing_layer: Option<WgpuEffect>,
 
     // --- INTERACTIONS ---
     /// The `TypeId` of the widget's `Widget::Action` type.
@@ -158,6 +187,11 @@ pub(crate) struct WidgetState {
     /// Tracks whether widget gets text focus.
     /// Should be immutable after `WidgetAdded` event.
     pub(crate) accepts_focus: bool,
+    /// Tracks whether the focusable descendants of the widget form a single Tab stop.
+    /// Should be immutable after `WidgetAdded` event.
+    pub(crate) is_roving_focus_group: bool,
+    /// If this widget is a roving focus group, its descendant which was focused last.
+    pub(crate) roving_focus_target: Option<WidgetId>,
 
     /// Tracks whether widget is eligible for IME events.
     /// Should be immutable after `WidgetAdded` event.
@@ -227,6 +261,9 @@ pub(crate) struct WidgetState {
     /// This widget or an ancestor has been stashed.
     pub(crate) is_stashed: bool,
 
+    /// This widget and its descendants are painted on every animation frame.
+    pub(crate) redraws_continuously: bool,
+
     /// In the hovered path, starting from window and ending at the hovered widget.
     /// Descendants of the hovered widget are not in the hovered path.
     pub(crate) has_hovered: bool,
@@ -238,6 +275,8 @@ pub(crate) struct WidgetState {
     pub(crate) has_active: bool,
     /// This specific widget is active.
     pub(crate) is_active: bool,
+    /// The [`PressFeedback`](crate::properties::PressFeedback) being animated, if any.
+    pub(crate) press_feedback: Option<PressFeedbackState>,
 
     /// In the focused path, starting from window and ending at the focused widget.
     /// Descendants of the focused widget are not in the focused path.
@@ -276,14 +315,24 @@ impl WidgetState {
             layout_baseline_offset: 0.0,
             baseline_y: 0.0,
             clip_path: Option::default(),
+            opaque_rect: None,
+            effective_background: None,
+            child_viewport: None,
+            viewport: None,
             transform: options.transform,
             window_transform: Affine::IDENTITY,
             scroll_translation: Vec2::ZERO,
             transform_changed: false,
+            opacity: 1.0,
+            wgpu_effect: None,
+            antialiasing: None,
+            antialiasing_layer: None,
 
             action_type,
             accepts_pointer_interaction: true,
             accepts_focus: false,
+            is_roving_focus_group: false,
+            roving_focus_target: None,
             accepts_text_input: false,
             ime_area: None,
 
@@ -312,10 +361,12 @@ impl WidgetState {
             is_disabled: false,
             is_explicitly_stashed: false,
             is_stashed: false,
+            redraws_continuously: options.continuous_redraw,
             has_hovered: false,
             is_hovered: false,
             has_active: false,
             is_active: false,
+            press_feedback: None,
             has_focus_target: false,
 
             trace_span: Span::none(),
@@ -411,8 +462,11 @@ impl WidgetState {
     ///
     /// Returns `None` if the given rect is clipped out.
     pub(crate) fn clip_child(&self, child_rect: Rect) -> Option<Rect> {
-        if let Some(clip_path) = self.clip_path {
-            let clip_path_global = self.window_transform.transform_rect_bbox(clip_path);
+        if let Some(clip_path) = &self.clip_path {
+            // We use the clip path's bounding box, which is conservative for non-rect shapes.
+            let clip_path_global = self
+                .window_transform
+                .transform_rect_bbox(clip_path.bounding_box());
             if clip_path_global.overlaps(child_rect) {
                 Some(clip_path_global.intersect(child_rect))
             } else {
@@ -1,7 +1,7 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::layout::{LenDef, Length};
+use crate::layout::{LenDef, Length, LengthUnit, UnitContext};
 
 /// Specifies how a widget dimension's length is derived.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
@@ -17,6 +17,31 @@ pub enum Dim {
     ///
     /// The value is in logical pixels, represented by a [`Length`].
     Fixed(Length),
+    /// Multiple of the root font size.
+    ///
+    /// For example, `Rem(2.)` will result in twice the root font size,
+    /// which grows with the user's text size setting.
+    ///
+    /// The value must be finite and non-negative.
+    Rem(f64),
+    /// Percentage of the viewport width.
+    ///
+    /// For example, `Vw(50.)` will result in half the window width.
+    ///
+    /// The value must be finite and non-negative.
+    Vw(f64),
+    /// Percentage of the viewport height.
+    ///
+    /// The value must be finite and non-negative.
+    Vh(f64),
+    /// Percentage of the smaller of the viewport width and height.
+    ///
+    /// The value must be finite and non-negative.
+    Vmin(f64),
+    /// Percentage of the larger of the viewport width and height.
+    ///
+    /// The value must be finite and non-negative.
+    Vmax(f64),
     /// Multiple of context length.
     ///
     /// For example, `Ratio(0.5)` will result in 50% of the context length.
@@ -70,12 +95,44 @@ impl From<Length> for Dim {
 }
 
 impl Dim {
+    /// Percentage of context length.
+    ///
+    /// This is a shorthand for [`Ratio`] with the percentage divided by 100,
+    /// e.g. `Dim::percent(50.)` is `Dim::Ratio(0.5)`.
+    ///
+    /// [`Ratio`]: Self::Ratio
+    pub const fn percent(percent: f64) -> Self {
+        Self::Ratio(percent / 100.)
+    }
+
+    /// Returns `true` if this dimension depends on the size of the viewport.
+    pub fn is_viewport_relative(&self) -> bool {
+        matches!(
+            self,
+            Self::Vw(_) | Self::Vh(_) | Self::Vmin(_) | Self::Vmax(_)
+        )
+    }
+
     /// Resolves, if possible, into a [`LenDef`].
     ///
+    /// Relative units are resolved with `units`.
+    ///
     /// If `context_length` is provided, it must be in device pixels.
-    pub fn resolve(&self, scale: f64, context_length: Option<f64>) -> Option<LenDef> {
+    pub fn resolve(
+        &self,
+        scale: f64,
+        units: &UnitContext,
+        context_length: Option<f64>,
+    ) -> Option<LenDef> {
+        let relative =
+            |value: f64, unit: LengthUnit| Some(LenDef::Fixed(unit.to_px(value, units) * scale));
         match self {
             Self::Fixed(length) => Some(LenDef::Fixed(length.dp(scale))),
+            Self::Rem(value) => relative(*value, LengthUnit::Rem),
+            Self::Vw(value) => relative(*value, LengthUnit::Vw),
+            Self::Vh(value) => relative(*value, LengthUnit::Vh),
+            Self::Vmin(value) => relative(*value, LengthUnit::Vmin),
+            Self::Vmax(value) => relative(*value, LengthUnit::Vmax),
             Self::Ratio(mul) => context_length.map(|cl| LenDef::Fixed(cl * *mul)),
             Self::Stretch => context_length.map(LenDef::Fixed),
             Self::MinContent => Some(LenDef::MinContent),
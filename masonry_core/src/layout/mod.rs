@@ -12,6 +12,7 @@ mod length;
 mod measurement_cache;
 mod size_def;
 mod unit_point;
+mod units;
 
 pub use as_unit::*;
 pub use dim::*;
@@ -22,3 +23,4 @@ pub use length::*;
 pub(crate) use measurement_cache::*;
 pub use size_def::*;
 pub use unit_point::*;
+pub use units::*;
@@ -0,0 +1,72 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::kurbo::Size;
+
+/// The unit a length is expressed in.
+///
+/// Relative units are resolved to logical pixels during layout, with the [`UnitContext`]
+/// of the window.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LengthUnit {
+    /// Logical pixels.
+    #[default]
+    Px,
+    /// Multiples of the root font size.
+    ///
+    /// Lengths in `rem` grow with the user's text size setting.
+    Rem,
+    /// Percentage of the viewport width.
+    Vw,
+    /// Percentage of the viewport height.
+    Vh,
+    /// Percentage of the smaller of the viewport width and height.
+    Vmin,
+    /// Percentage of the larger of the viewport width and height.
+    Vmax,
+}
+
+impl LengthUnit {
+    /// Converts `value`, expressed in this unit, to logical pixels.
+    ///
+    /// The returned value is finite and non-negative if `value` is.
+    pub fn to_px(self, value: f64, units: &UnitContext) -> f64 {
+        let viewport = units.viewport;
+        match self {
+            Self::Px => value,
+            Self::Rem => value * units.rem,
+            Self::Vw => value * viewport.width / 100.,
+            Self::Vh => value * viewport.height / 100.,
+            Self::Vmin => value * viewport.width.min(viewport.height) / 100.,
+            Self::Vmax => value * viewport.width.max(viewport.height) / 100.,
+        }
+    }
+
+    /// Returns `true` if lengths in this unit depend on the size of the viewport.
+    pub fn is_viewport_relative(self) -> bool {
+        matches!(self, Self::Vw | Self::Vh | Self::Vmin | Self::Vmax)
+    }
+}
+
+/// The values relative [`LengthUnit`]s are resolved against.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UnitContext {
+    /// The root font size, in logical pixels.
+    pub rem: f64,
+    /// The size of the viewport, i.e. the window, in logical pixels.
+    pub viewport: Size,
+}
+
+impl UnitContext {
+    /// The default context, with a root font size of 16 logical pixels and an empty viewport.
+    pub const DEFAULT: Self = Self {
+        rem: 16.,
+        viewport: Size::ZERO,
+    };
+}
+
+impl Default for UnitContext {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
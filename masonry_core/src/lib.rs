@@ -81,6 +81,10 @@ pub use vello::peniko::color::palette;
 pub use vello::{kurbo, peniko};
 pub use {accesskit, anymore, dpi, parley, ui_events, vello};
 
+// `keyboard-types` is only a dependency to enable its `serde` feature for the keys re-exported
+// by `ui-events`, which event traces serialize.
+use keyboard_types as _;
+
 // TODO - re-add #[doc(hidden)]
 pub mod doc;
 
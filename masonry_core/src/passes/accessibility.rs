@@ -7,7 +7,10 @@ use tree_arena::ArenaMut;
 use vello::kurbo::Rect;
 
 use crate::app::{RenderRoot, RenderRootState};
-use crate::core::{AccessCtx, DefaultProperties, PropertiesRef, Widget, WidgetArenaNode, WidgetId};
+use crate::core::{
+    AccessCtx, AnnouncementPriority, DefaultProperties, PropertiesRef, Widget, WidgetArenaNode,
+    WidgetId,
+};
 use crate::passes::{enter_span_if, recurse_on_children};
 
 // --- MARK: BUILD TREE
@@ -140,6 +143,39 @@ fn to_accesskit_rect(r: Rect) -> accesskit::Rect {
     accesskit::Rect::new(r.x0, r.y0, r.x1, r.y1)
 }
 
+// --- MARK: ANNOUNCEMENTS
+/// Adds the pending announcements to the live regions, and returns the regions' ids.
+///
+/// Each announcement is a new node, so that announcing the same text twice is noticed
+/// by screen readers.
+fn build_live_regions(root: &mut RenderRoot, tree_update: &mut TreeUpdate) -> [NodeId; 2] {
+    let announcements = std::mem::take(&mut root.global_state.pending_announcements);
+    for (index, priority) in AnnouncementPriority::ALL.into_iter().enumerate() {
+        let mut new_ids = announcements
+            .iter()
+            .filter(|(_, announcement_priority)| *announcement_priority == priority)
+            .map(|(text, _)| {
+                let id = AccessCtx::next_node_id();
+                let mut node = Node::new(Role::Label);
+                node.set_value(text.as_str());
+                tree_update.nodes.push((id, node));
+                id
+            })
+            .peekable();
+        if new_ids.peek().is_some() {
+            root.announcement_ids[index] = new_ids.collect();
+        }
+
+        let mut region = Node::new(Role::GenericContainer);
+        region.set_live(priority.live());
+        region.set_children(root.announcement_ids[index].clone());
+        tree_update
+            .nodes
+            .push((root.live_region_ids[index], region));
+    }
+    root.live_region_ids
+}
+
 // --- MARK: ROOT
 /// See the [passes documentation](crate::doc::pass_system#render-passes).
 pub(crate) fn run_accessibility_pass(root: &mut RenderRoot, scale_factor: f64) -> TreeUpdate {
@@ -171,8 +207,10 @@ pub(crate) fn run_accessibility_pass(root: &mut RenderRoot, scale_factor: f64) -
 
     // TODO: make root node type customizable to support Dialog/AlertDialog roles
     // (should go hand in hand with introducing support for modal windows?)
+    let mut children = vec![root.root_id().into()];
+    children.extend(build_live_regions(root, &mut tree_update));
     let mut window_node = Node::new(Role::Window);
-    window_node.set_children(vec![root.root_id().into()]);
+    window_node.set_children(children);
     tree_update.nodes.push((root.window_node_id, window_node));
 
     tree_update
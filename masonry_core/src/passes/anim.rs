@@ -4,9 +4,10 @@
 use tracing::info_span;
 use tree_arena::ArenaMut;
 
-use crate::app::{RenderRoot, RenderRootState};
+use crate::app::{FrameRequest, FrameRequestKind, RenderRoot, RenderRootState};
 use crate::core::{DefaultProperties, PropertiesMut, UpdateCtx, WidgetArenaNode};
 use crate::passes::{enter_span_if, recurse_on_children};
+use crate::util::Duration;
 
 // --- MARK: UPDATE ANIM
 fn update_anim_for_widget(
@@ -14,6 +15,7 @@ fn update_anim_for_widget(
     default_properties: &DefaultProperties,
     node: ArenaMut<'_, WidgetArenaNode>,
     elapsed_ns: u64,
+    repaint: bool,
 ) {
     let mut children = node.children;
     let widget = &mut *node.item.widget;
@@ -22,11 +24,39 @@ fn update_anim_for_widget(
     let id = state.id;
     let _span = enter_span_if(global_state.trace.anim, state);
 
-    if !state.needs_anim {
+    let repaint = (repaint || state.redraws_continuously) && !state.is_stashed;
+    if !state.needs_anim && !repaint {
         return;
     }
     state.needs_anim = false;
 
+    if state.redraws_continuously
+        && !state.is_stashed
+        && let Some(diagnostics) = &mut global_state.frame_diagnostics
+    {
+        diagnostics.record(FrameRequest {
+            widget: id,
+            kind: FrameRequestKind::ContinuousRedraw,
+            location: None,
+        });
+    }
+
+    if let Some(feedback) = &mut state.press_feedback {
+        feedback.age += Duration::from_nanos(elapsed_ns);
+        if feedback.age >= feedback.duration {
+            state.press_feedback = None;
+        }
+        state.request_pre_paint = true;
+        state.needs_paint = true;
+    }
+
+    if repaint {
+        state.request_pre_paint = true;
+        state.request_paint = true;
+        state.request_post_paint = true;
+        state.needs_paint = true;
+    }
+
     // Most passes reset their `needs` and `request` flags after the call to
     // the widget method, but it's valid and expected for `request_anim` to be
     // set in response to `AnimFrame`.
@@ -52,9 +82,17 @@ fn update_anim_for_widget(
             default_properties,
             node.reborrow_mut(),
             elapsed_ns,
+            repaint,
         );
         parent_state.merge_up(&mut node.item.state);
     });
+
+    // Keeps animation frames coming, without calling `on_anim_frame`.
+    if (parent_state.redraws_continuously || parent_state.press_feedback.is_some())
+        && !parent_state.is_stashed
+    {
+        parent_state.needs_anim = true;
+    }
 }
 
 // TODO - switch anim frames to being about age / an absolute timestamp
@@ -73,5 +111,10 @@ pub(crate) fn run_update_anim_pass(root: &mut RenderRoot, elapsed_ns: u64) {
         &root.default_properties,
         root_node,
         elapsed_ns,
+        false,
     );
+
+    root.global_state
+        .overlay
+        .advance(Duration::from_nanos(elapsed_ns));
 }
@@ -3,7 +3,7 @@
 
 use tracing::info_span;
 use tree_arena::ArenaMut;
-use vello::kurbo::Affine;
+use vello::kurbo::{Affine, Rect};
 
 use crate::app::{RenderRoot, RenderRootState};
 use crate::core::{ComposeCtx, DefaultProperties, WidgetArenaNode};
@@ -16,6 +16,7 @@ fn compose_widget(
     node: ArenaMut<'_, WidgetArenaNode>,
     parent_transformed: bool,
     parent_window_transform: Affine,
+    parent_viewport: Option<Rect>,
 ) {
     let mut children = node.children;
     let widget = &mut *node.item.widget;
@@ -24,10 +25,12 @@ fn compose_widget(
     let _span = enter_span_if(global_state.trace.compose, state);
 
     let transformed = parent_transformed || state.transform_changed;
+    let viewport_changed = state.viewport != parent_viewport;
 
-    if !transformed && !state.needs_compose {
+    if !transformed && !viewport_changed && !state.needs_compose {
         return;
     }
+    state.viewport = parent_viewport;
 
     // The translation needs to be applied *after* applying the transform,
     // as translation by scrolling should be within the transformed coordinate space.
@@ -46,7 +49,9 @@ fn compose_widget(
         children: children.reborrow_mut(),
         default_properties,
     };
-    if ctx.widget_state.request_compose {
+    // Widgets can rely on the final transform and viewport in `compose`, so we also call it
+    // when these changed because of an ancestor, e.g. when an ancestor scrolled.
+    if ctx.widget_state.request_compose || transformed || viewport_changed {
         widget.compose(&mut ctx);
     }
 
@@ -60,6 +65,14 @@ fn compose_widget(
     state.transform_changed = false;
 
     let parent_transform = state.window_transform;
+    // The viewport is set during layout, so we compute its window position here.
+    let child_viewport = match state.child_viewport {
+        Some(viewport) => {
+            let viewport = state.window_transform.transform_rect_bbox(viewport);
+            Some(parent_viewport.map_or(viewport, |parent| parent.intersect(viewport)))
+        }
+        None => parent_viewport,
+    };
     let parent_state = state;
     recurse_on_children(id, widget, children, |mut node| {
         compose_widget(
@@ -68,6 +81,7 @@ fn compose_widget(
             node.reborrow_mut(),
             transformed,
             parent_transform,
+            child_viewport,
         );
         let parent_bounding_box = parent_state.bounding_box;
 
@@ -97,5 +111,6 @@ pub(crate) fn run_compose_pass(root: &mut RenderRoot) {
         root_node,
         false,
         Affine::IDENTITY,
+        None,
     );
 }
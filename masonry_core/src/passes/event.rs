@@ -11,6 +11,7 @@ use crate::core::{
     WidgetId,
 };
 use crate::dpi::{LogicalPosition, PhysicalPosition};
+use crate::kurbo::Point;
 use crate::passes::update::find_next_focusable;
 use crate::passes::{enter_span, merge_state_up};
 
@@ -242,6 +243,25 @@ pub(crate) fn run_on_pointer_event_pass(root: &mut RenderRoot, event: &PointerEv
         root.global_state.pointer_capture_target = None;
     }
 
+    if let Some(auto_scroll) = &mut root.global_state.edge_auto_scroll {
+        let source = auto_scroll.source;
+        if root.global_state.pointer_capture_target != Some(source)
+            || !root.widget_arena.has(source)
+        {
+            auto_scroll.ended = true;
+            auto_scroll.needs_update = true;
+        } else if let Some(pos) = event_pos
+            && matches!(event, PointerEvent::Down(..) | PointerEvent::Move(..))
+        {
+            auto_scroll.position = Some(Point::new(pos.x, pos.y));
+            auto_scroll.needs_update = true;
+            if matches!(event, PointerEvent::Move(..)) {
+                auto_scroll.last_move = Some(event.clone());
+                auto_scroll.source_transform = root.widget_arena.get_state(source).window_transform;
+            }
+        }
+    }
+
     if !is_very_frequent(event) {
         trace!(
             focused_widget = root.global_state.focused_widget.map(|id| id.0),
@@ -253,6 +273,29 @@ pub(crate) fn run_on_pointer_event_pass(root: &mut RenderRoot, event: &PointerEv
     handled
 }
 
+// --- MARK: EDGE AUTO SCROLL
+/// Sends the last move of a drag with [edge auto-scroll] again if the dragged widget
+/// moved since, e.g. because a scroll container scrolled it under a still pointer.
+///
+/// [edge auto-scroll]: crate::core::EventCtx::request_edge_auto_scroll
+pub(crate) fn run_edge_auto_scroll_pass(root: &mut RenderRoot) {
+    let Some(auto_scroll) = &root.global_state.edge_auto_scroll else {
+        return;
+    };
+    let source = auto_scroll.source;
+    if auto_scroll.ended || !root.widget_arena.has(source) {
+        return;
+    }
+    let Some(last_move) = &auto_scroll.last_move else {
+        return;
+    };
+    if root.widget_arena.get_state(source).window_transform == auto_scroll.source_transform {
+        return;
+    }
+    let last_move = last_move.clone();
+    run_on_pointer_event_pass(root, &last_move);
+}
+
 // --- MARK: TEXT EVENT
 /// See the [passes documentation](crate::doc::pass_system#event-passes).
 pub(crate) fn run_on_text_event_pass(root: &mut RenderRoot, event: &TextEvent) -> Handled {
@@ -307,6 +350,25 @@ pub(crate) fn run_on_text_event_pass(root: &mut RenderRoot, event: &TextEvent) -
             let forward = !key.modifiers.shift();
             let next_focused_widget = find_next_focusable(root, forward);
             root.global_state.next_focused_widget = next_focused_widget;
+            root.global_state.scroll_to_focused = next_focused_widget.is_some();
+            handled = Handled::Yes;
+        }
+
+        if key.key == Key::Named(NamedKey::F9)
+            && key.state == KeyState::Down
+            && handled == Handled::No
+        {
+            let enabled = root.global_state.widget_profile.is_none();
+            root.set_widget_profiling(enabled);
+            handled = Handled::Yes;
+        }
+
+        if key.key == Key::Named(NamedKey::F10)
+            && key.state == KeyState::Down
+            && handled == Handled::No
+        {
+            let enabled = root.global_state.frame_diagnostics.is_none();
+            root.set_frame_diagnostics(enabled);
             handled = Handled::Yes;
         }
 
@@ -365,17 +427,15 @@ pub(crate) fn run_on_access_event_pass(
 
     // Handle focus events
     match event.action {
-        accesskit::Action::Focus if !handled.is_handled() => {
-            if root.is_still_interactive(target) {
-                root.global_state.next_focused_widget = Some(target);
-                handled = Handled::Yes;
-            }
+        accesskit::Action::Focus if !handled.is_handled() && root.is_still_interactive(target) => {
+            root.global_state.next_focused_widget = Some(target);
+            handled = Handled::Yes;
         }
-        accesskit::Action::Blur if !handled.is_handled() => {
-            if root.global_state.next_focused_widget == Some(target) {
-                root.global_state.next_focused_widget = None;
-                handled = Handled::Yes;
-            }
+        accesskit::Action::Blur
+            if !handled.is_handled() && root.global_state.next_focused_widget == Some(target) =>
+        {
+            root.global_state.next_focused_widget = None;
+            handled = Handled::Yes;
         }
         accesskit::Action::ScrollIntoView if !handled.is_handled() => {
             let widget_state = root.widget_arena.get_state(target);
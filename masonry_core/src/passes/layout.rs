@@ -11,19 +11,31 @@ use dpi::LogicalSize;
 use tracing::{info_span, trace};
 use tree_arena::ArenaMut;
 
-use crate::app::{RenderRoot, RenderRootSignal, RenderRootState, WindowSizePolicy};
+use crate::app::{ProfiledPass, RenderRoot, RenderRootSignal, RenderRootState, WindowSizePolicy};
 use crate::core::{
     ChildrenIds, DefaultProperties, LayoutCtx, MeasureCtx, PropertiesRef, Widget, WidgetArenaNode,
     WidgetState,
 };
 use crate::kurbo::{Axis, Insets, Point, Size};
 use crate::layout::{LayoutSize, LenDef, LenReq, MeasurementInputs, SizeDef};
-use crate::passes::{enter_span_if, recurse_on_children};
-use crate::properties::{BorderWidth, BoxShadow, Dimensions, Padding};
+use crate::passes::{enter_span_if, recurse_on_children, start_widget_timer, stop_widget_timer};
+use crate::properties::{
+    ActiveElevation, BorderWidth, BoxShadow, Dimensions, Elevation, ElevationShadows,
+    HoveredElevation, Padding,
+};
 use crate::util::Sanitize;
 
 // --- MARK: COMPUTE SIZE
 
+/// Returns the widget's [`Padding`], resolved to logical pixels.
+fn resolve_padding(global_state: &mut RenderRootState, props: PropertiesRef<'_>) -> Padding {
+    let padding = props.get::<Padding>();
+    if padding.unit.is_viewport_relative() {
+        global_state.uses_viewport_units = true;
+    }
+    padding.resolve(&global_state.units)
+}
+
 /// Measures the preferred border-box length of `widget` on the given `axis`.
 ///
 /// The returned length will be in device pixels.
@@ -52,7 +64,7 @@ fn measure_border_box(
     let scale = 1.0;
 
     let border = props.get::<BorderWidth>();
-    let padding = props.get::<Padding>();
+    let padding = resolve_padding(ctx.global_state, *props);
 
     let border_length = border.length(axis).dp(scale);
     let padding_length = padding.length(axis).dp(scale);
@@ -67,7 +79,10 @@ fn measure_border_box(
     });
 
     // Measure the content-box length.
+    let timer = start_widget_timer(ctx.global_state);
     let content_length = widget.measure(ctx, props, axis, len_req, cross_length);
+    let id = ctx.widget_state.id;
+    stop_widget_timer(ctx.global_state, id, ProfiledPass::Layout, timer);
 
     // Add border and padding to the content-box length to return the border-box length.
     content_length + border_length + padding_length
@@ -195,11 +210,15 @@ pub(crate) fn resolve_length(
         default_map: default_properties.for_widget(widget.type_id()),
     };
     let dims = props.get::<Dimensions>();
+    let units = global_state.units;
+    if dims.dim(axis).is_viewport_relative() || dims.dim(axis.cross()).is_viewport_relative() {
+        global_state.uses_viewport_units = true;
+    }
 
     // Resolve the dimension on the given axis
     let len_def = dims
         .dim(axis)
-        .resolve(scale, context_size.length(axis))
+        .resolve(scale, &units, context_size.length(axis))
         .unwrap_or(auto_length)
         .sanitize("len_def");
 
@@ -224,7 +243,7 @@ pub(crate) fn resolve_length(
     let cross_length = cross_length.or_else(|| {
         let cross = axis.cross();
         dims.dim(cross)
-            .resolve(scale, context_size.length(cross))
+            .resolve(scale, &units, context_size.length(cross))
             .and_then(|cross_len_def| cross_len_def.sanitize("cross_len_def").fixed())
     });
 
@@ -275,18 +294,22 @@ pub(crate) fn resolve_size(
         default_map: default_properties.for_widget(widget.type_id()),
     };
     let dims = props.get::<Dimensions>();
+    let units = global_state.units;
+    if dims.dim(inline).is_viewport_relative() || dims.dim(block).is_viewport_relative() {
+        global_state.uses_viewport_units = true;
+    }
 
     // Resolve the dimensions
     let inline_auto = auto_size.dim(inline);
     let inline_def = dims
         .dim(inline)
-        .resolve(scale, context_size.length(inline))
+        .resolve(scale, &units, context_size.length(inline))
         .unwrap_or(inline_auto)
         .sanitize("inline_def");
     let block_auto = auto_size.dim(block);
     let block_def = dims
         .dim(block)
-        .resolve(scale, context_size.length(block))
+        .resolve(scale, &units, context_size.length(block))
         .unwrap_or(block_auto)
         .sanitize("block_def");
 
@@ -402,7 +425,7 @@ pub(crate) fn run_layout_on(
     };
 
     let border_width = props.get::<BorderWidth>();
-    let padding = props.get::<Padding>();
+    let padding = resolve_padding(global_state, props);
 
     // Force the border-box size to be large enough to actually contain the border and padding.
     let minimum_size = Size::ZERO;
@@ -470,6 +493,7 @@ pub(crate) fn run_layout_on(
     let content_box_size = border_width.size_down(border_box_size, scale);
     let content_box_size = padding.size_down(content_box_size, scale);
 
+    let timer = start_widget_timer(global_state);
     let mut ctx = LayoutCtx {
         global_state,
         widget_state: state,
@@ -479,6 +503,7 @@ pub(crate) fn run_layout_on(
 
     // Run the widget's layout
     widget.layout(&mut ctx, &props, content_box_size);
+    stop_widget_timer(ctx.global_state, id, ProfiledPass::Layout, timer);
 
     // Make sure the paint insets cover the shadow insets
     let shadow = props.get::<BoxShadow>();
@@ -492,6 +517,27 @@ pub(crate) fn run_layout_on(
         };
     }
 
+    // Make sure the paint insets cover the shadows of every elevation the widget can take
+    let elevation_shadows = props.get::<ElevationShadows>();
+    let elevations = [
+        Some(*props.get::<Elevation>()),
+        props.get_defined::<HoveredElevation>().map(|he| he.0),
+        props.get_defined::<ActiveElevation>().map(|ae| ae.0),
+    ];
+    for level in elevations
+        .into_iter()
+        .flatten()
+        .filter_map(|elevation| elevation_shadows.get(elevation))
+    {
+        let shadow_insets = level.get_insets();
+        state.paint_insets = Insets {
+            x0: state.paint_insets.x0.max(shadow_insets.x0),
+            y0: state.paint_insets.y0.max(shadow_insets.y0),
+            x1: state.paint_insets.x1.max(shadow_insets.x1),
+            y1: state.paint_insets.y1.max(shadow_insets.y1),
+        };
+    }
+
     if trace {
         trace!(
             "Computed layout: border-box={}, baseline={}, insets={:?}",
@@ -595,6 +641,7 @@ pub(crate) fn run_layout_pass(root: &mut RenderRoot) {
     root.global_state.needs_pointer_pass = true;
 
     let window_size = root.get_kurbo_size();
+    root.global_state.units.viewport = window_size;
     let mut root_node = root.widget_arena.get_node_mut(root.root_id());
     let root_node_size = match root.size_policy {
         WindowSizePolicy::User => resolve_size(
@@ -611,6 +658,13 @@ pub(crate) fn run_layout_pass(root: &mut RenderRoot) {
             SizeDef::MAX,
             LayoutSize::NONE,
         ),
+        WindowSizePolicy::ContentHeight => resolve_size(
+            &mut root.global_state,
+            &root.default_properties,
+            root_node.reborrow_mut(),
+            SizeDef::fixed(window_size).with_height(LenDef::MaxContent),
+            LayoutSize::one(Axis::Horizontal, window_size.width),
+        ),
     };
 
     run_layout_on(
@@ -623,7 +677,7 @@ pub(crate) fn run_layout_pass(root: &mut RenderRoot) {
 
     root.global_state.fonts_changed = false;
 
-    if let WindowSizePolicy::Content = root.size_policy {
+    if let WindowSizePolicy::Content | WindowSizePolicy::ContentHeight = root.size_policy {
         // We use the aligned border-box size, which means that transforms won't affect window size.
         let size = root_node.item.state.border_box_size();
         // TODO: Remove HACK: Until scale factor rework happens, we still need to scale here.
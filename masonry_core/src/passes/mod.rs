@@ -10,7 +10,9 @@
 use tracing::span::EnteredSpan;
 use tree_arena::{ArenaMut, ArenaMutList};
 
+use crate::app::{ProfiledPass, RenderRootState};
 use crate::core::{Widget, WidgetArena, WidgetArenaNode, WidgetId, WidgetState};
+use crate::util::Instant;
 
 pub(crate) mod accessibility;
 pub(crate) mod anim;
@@ -31,6 +33,27 @@ pub(crate) fn enter_span(state: &WidgetState) -> EnteredSpan {
     state.trace_span.clone().entered()
 }
 
+/// Starts timing a widget method if [widget profiling] is enabled.
+///
+/// [widget profiling]: crate::app::RenderRoot::set_widget_profiling
+pub(crate) fn start_widget_timer(global_state: &mut RenderRootState) -> Option<Instant> {
+    let profile = global_state.widget_profile.as_mut()?;
+    profile.enter();
+    Some(Instant::now())
+}
+
+/// Attributes the time since `start` to the given widget, if it was being timed.
+pub(crate) fn stop_widget_timer(
+    global_state: &mut RenderRootState,
+    id: WidgetId,
+    pass: ProfiledPass,
+    start: Option<Instant>,
+) {
+    if let (Some(profile), Some(start)) = (&mut global_state.widget_profile, start) {
+        profile.exit(id, pass, start.elapsed());
+    }
+}
+
 pub(crate) fn recurse_on_children(
     id: WidgetId,
     widget: &mut dyn Widget,
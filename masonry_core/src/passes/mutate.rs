@@ -6,6 +6,7 @@ use tracing::info_span;
 use crate::app::RenderRoot;
 use crate::core::{MutateCtx, PropertiesMut, Widget, WidgetId, WidgetMut};
 use crate::passes::merge_state_up;
+use crate::util::Instant;
 
 pub(crate) fn mutate_widget<R>(
     root: &mut RenderRoot,
@@ -71,3 +72,29 @@ pub(crate) fn run_mutate_pass(root: &mut RenderRoot) {
         mutate_widget(root, callback.id, callback.callback);
     }
 }
+
+// --- MARK: IDLE
+/// Runs the callbacks queued with `run_when_idle()`, in order, until `deadline`.
+///
+/// Past the deadline, only the callbacks which timed out still run.
+pub(crate) fn run_idle_pass(root: &mut RenderRoot, deadline: Instant) {
+    let _span = info_span!("idle").entered();
+
+    let callbacks = std::mem::take(&mut root.global_state.idle_callbacks);
+    let mut remaining = Vec::new();
+    for idle in callbacks {
+        let now = Instant::now();
+        if now >= deadline && now < idle.timeout {
+            remaining.push(idle);
+            continue;
+        }
+        // Skip callbacks whose target was removed since they were queued.
+        if !root.widget_arena.has(idle.callback.id) {
+            continue;
+        }
+        mutate_widget(root, idle.callback.id, idle.callback.callback);
+    }
+    // Callbacks queued by the ones which ran come after the ones left.
+    remaining.append(&mut root.global_state.idle_callbacks);
+    root.global_state.idle_callbacks = remaining;
+}
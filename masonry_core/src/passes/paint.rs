@@ -4,23 +4,28 @@
 use std::collections::HashMap;
 
 use tracing::{info_span, trace};
-use tree_arena::ArenaMut;
-use vello::Scene;
+use tree_arena::{ArenaMut, ArenaMutList};
 use vello::kurbo::{Affine, Rect};
-use vello::peniko::{Color, Fill};
+use vello::peniko::{Color, Fill, ImageBrush, Mix};
+use vello::{AaConfig, Scene};
 
-use crate::app::{RenderRoot, RenderRootState};
-use crate::core::{DefaultProperties, PaintCtx, PropertiesRef, WidgetArenaNode, WidgetId};
-use crate::passes::{enter_span_if, recurse_on_children};
-use crate::util::{get_debug_color, stroke};
+use crate::app::{FrameRequestKind, ProfiledPass, RenderRoot, RenderRootState};
+use crate::core::{
+    ChildrenIds, DefaultProperties, PaintCtx, PropertiesRef, Widget, WidgetArenaNode, WidgetId,
+    WidgetState, current_background,
+};
+use crate::passes::{enter_span_if, start_widget_timer, stop_widget_timer};
+use crate::properties::{Background, z_order_key};
+use crate::util::{composite_over, get_debug_color, stroke};
 
 // --- MARK: PAINT WIDGET
+/// Paints the widget and its descendants, painted over `parent_background`.
 fn paint_widget(
     global_state: &mut RenderRootState,
     default_properties: &DefaultProperties,
-    complete_scene: &mut Scene,
     scene_cache: &mut HashMap<WidgetId, (Scene, Scene, Scene)>,
     node: ArenaMut<'_, WidgetArenaNode>,
+    parent_background: Option<Color>,
 ) {
     let mut children = node.children;
     let widget = &mut *node.item.widget;
@@ -36,40 +41,60 @@ fn paint_widget(
     // (See WidgetState doc.)
     let is_stashed = state.is_stashed;
 
-    // TODO - Handle damage regions
-    // https://github.com/linebender/xilem/issues/789
+    let props = PropertiesRef {
+        map: properties,
+        default_map: default_properties.for_widget(widget.type_id()),
+    };
+    let background = current_background(state.is_disabled, state.is_active, &props);
+    let effective_background = match background {
+        Background::Color(color) if color.components[3] >= 1. => Some(*color),
+        Background::Color(color) => parent_background.map(|below| composite_over(*color, below)),
+        Background::Gradient(_) => None,
+    };
+    if effective_background != state.effective_background {
+        state.effective_background = effective_background;
+        state.request_paint = true;
+    }
 
-    if (state.request_pre_paint || state.request_paint || state.request_post_paint) && !is_stashed {
+    let repaint =
+        (state.request_pre_paint || state.request_paint || state.request_post_paint) && !is_stashed;
+    if repaint {
         if trace {
             trace!("Painting widget '{}' {}", widget.short_type_name(), id);
         }
+        if state.request_pre_paint || state.request_paint {
+            state.opaque_rect = None;
+        }
 
+        let timer = start_widget_timer(global_state);
+        let (request_pre_paint, request_paint, request_post_paint) = (
+            state.request_pre_paint,
+            state.request_paint,
+            state.request_post_paint,
+        );
         let mut ctx = PaintCtx {
             global_state,
             widget_state: state,
             children: children.reborrow_mut(),
         };
-        let props = PropertiesRef {
-            map: properties,
-            default_map: default_properties.for_widget(widget.type_id()),
-        };
 
         // TODO - Reserve scene
         // https://github.com/linebender/xilem/issues/524
         let (pre_scene, scene, post_scene) = scene_cache.entry(id).or_default();
 
-        if state.request_pre_paint {
+        if request_pre_paint {
             pre_scene.reset();
             widget.pre_paint(&mut ctx, &props, pre_scene);
         }
-        if state.request_paint {
+        if request_paint {
             scene.reset();
             widget.paint(&mut ctx, &props, scene);
         }
-        if state.request_post_paint {
+        if request_post_paint {
             post_scene.reset();
             widget.post_paint(&mut ctx, &props, post_scene);
         }
+        stop_widget_timer(ctx.global_state, id, ProfiledPass::Paint, timer);
     }
 
     state.request_pre_paint = false;
@@ -77,71 +102,296 @@ fn paint_widget(
     state.request_post_paint = false;
     state.needs_paint = false;
 
+    // A widget looks different if it was repainted, moved, resized, or stashed.
+    // The bounding box includes the widget's children, so this is conservative.
+    let painted_box = (!is_stashed).then_some(state.bounding_box);
+    let previous_box = match painted_box {
+        Some(painted_box) => global_state.painted_bounds.insert(id, painted_box),
+        None => global_state.painted_bounds.remove(&id),
+    };
+    if repaint || previous_box != painted_box {
+        global_state.damage.extend(previous_box);
+        global_state.damage.extend(painted_box);
+    }
+
+    let parent_state = &mut *state;
+    for child_id in widget.children_ids() {
+        let Some(mut node) = children.item_mut(child_id) else {
+            panic!(
+                "Error in '{}' {id}: cannot find child {child_id} returned by children_ids()",
+                widget.short_type_name()
+            );
+        };
+        paint_widget(
+            global_state,
+            default_properties,
+            scene_cache,
+            node.reborrow_mut(),
+            effective_background,
+        );
+        parent_state.merge_up(&mut node.item.state);
+    }
+}
+
+// --- MARK: ENCODE WIDGET
+/// Appends the cached scenes of the widget and its descendants to `complete_scene`.
+///
+/// Widgets entirely behind one of the `occluders`, in window coordinates, are skipped
+/// along with their descendants.
+/// `complete_scene` is rendered with the `antialiasing` method.
+fn encode_widget(
+    global_state: &mut RenderRootState,
+    default_properties: &DefaultProperties,
+    complete_scene: &mut Scene,
+    scene_cache: &HashMap<WidgetId, (Scene, Scene, Scene)>,
+    node: ArenaMut<'_, WidgetArenaNode>,
+    occluders: &[Rect],
+    antialiasing: AaConfig,
+) {
+    let mut children = node.children;
+    let widget = &*node.item.widget;
+    let state = &node.item.state;
+    let id = state.id;
+
+    if state.is_stashed || state.opacity == 0.0 || is_occluded(state, occluders) {
+        return;
+    }
+
+    let Some((pre_scene, scene, post_scene)) = scene_cache.get(&id) else {
+        debug_panic!("Error in paint pass: scene should have been cached by paint_widget.");
+        return;
+    };
+
+    // The opacity applies to everything the widget and its descendants paint.
+    let translucent = state.opacity < 1.0;
+    if translucent {
+        complete_scene.push_layer(
+            Fill::NonZero,
+            Mix::Normal,
+            state.opacity,
+            Affine::IDENTITY,
+            &state.bounding_box,
+        );
+    }
+
+    // A widget with an effect is encoded into its own scene, which the effect is applied to.
+    // So is one anti-aliased with a different method than its parent.
+    let parent_antialiasing = antialiasing;
+    let antialiasing = state.antialiasing.map_or(antialiasing, |method| {
+        global_state.supported_antialiasing(method)
+    });
+    let effect = state.wgpu_effect.as_ref().or(state
+        .antialiasing_layer
+        .as_ref()
+        .filter(|_| antialiasing != parent_antialiasing));
+    let parent_scene = complete_scene;
+    let mut effect_scene = effect.is_some().then(Scene::new);
+    let complete_scene = match &mut effect_scene {
+        Some(effect_scene) => effect_scene,
+        None => &mut *parent_scene,
+    };
+
     let transform = state
         .window_transform
         .pre_translate(state.border_box_translation());
-    let has_clip = state.clip_path.is_some();
-    if !is_stashed {
-        let Some((pre_scene, scene, _)) = &mut scene_cache.get(&id) else {
-            debug_panic!(
-                "Error in paint pass: scene should have been cached earlier in this function."
-            );
-            return;
-        };
+    complete_scene.append(pre_scene, Some(transform));
 
-        complete_scene.append(pre_scene, Some(transform));
+    if let Some(clip) = &state.clip_path {
+        // The clip path is stored in border-box space, so need just window transform.
+        complete_scene.push_clip_layer(Fill::NonZero, state.window_transform, clip);
+    }
 
-        if let Some(clip) = state.clip_path {
-            // The clip path is stored in border-box space, so need just window transform.
-            complete_scene.push_clip_layer(Fill::NonZero, state.window_transform, &clip);
-        }
+    complete_scene.append(scene, Some(transform));
 
-        complete_scene.append(scene, Some(transform));
+    let child_ids = paint_order(widget, &children, default_properties);
+    // Each child is occluded by the children painted after it, so we collect
+    // their opaque rects in reverse paint order: the occluders of the child at
+    // `idx` are then a prefix of `child_occluders`.
+    let mut child_occluders = occluders.to_vec();
+    let mut later_occluders = Vec::with_capacity(child_ids.len());
+    for child_id in child_ids.iter().rev() {
+        later_occluders.push(child_occluders.len());
+        if let Some(node) = children.item(*child_id)
+            && let Some(rect) = occluder_rect(&node.item.state)
+        {
+            child_occluders.push(rect);
+        }
     }
+    later_occluders.reverse();
 
-    let parent_state = &mut *state;
-    recurse_on_children(id, widget, children, |mut node| {
+    for (child_id, occluder_count) in child_ids.iter().zip(later_occluders) {
+        let Some(node) = children.item_mut(*child_id) else {
+            panic!(
+                "Error in '{}' {id}: cannot find child {child_id} returned by children_ids()",
+                widget.short_type_name()
+            );
+        };
         // TODO: We could skip painting children outside the parent clip path.
         // There's a few things to consider if we do:
         // - Some widgets can paint outside of their layout box.
         // - Once we implement compositor layers, we may want to paint outside of the clip path anyway in anticipation of user scrolling.
-        // - We still want to reset needs_paint and request_paint flags.
-        paint_widget(
+        encode_widget(
             global_state,
             default_properties,
             complete_scene,
             scene_cache,
-            node.reborrow_mut(),
+            node,
+            &child_occluders[..occluder_count],
+            antialiasing,
         );
-        parent_state.merge_up(&mut node.item.state);
-    });
+    }
 
-    if !is_stashed {
-        let bounding_box = state.bounding_box;
+    // draw the global axis aligned bounding rect of the widget
+    if global_state.debug_paint {
+        const BORDER_WIDTH: f64 = 1.0;
+        let color = get_debug_color(id.to_raw());
+        let rect = state.bounding_box.inset(BORDER_WIDTH / -2.0);
+        stroke(complete_scene, &rect, color, BORDER_WIDTH);
+    }
 
-        // draw the global axis aligned bounding rect of the widget
-        if global_state.debug_paint {
-            const BORDER_WIDTH: f64 = 1.0;
-            let color = get_debug_color(id.to_raw());
-            let rect = bounding_box.inset(BORDER_WIDTH / -2.0);
-            stroke(complete_scene, &rect, color, BORDER_WIDTH);
-        }
+    if state.clip_path.is_some() {
+        complete_scene.pop_layer();
+    }
 
-        if has_clip {
-            complete_scene.pop_layer();
-        }
+    complete_scene.append(post_scene, Some(transform));
 
-        let Some((_, _, post_scene)) = &mut scene_cache.get(&id) else {
-            debug_panic!(
-                "Error in paint pass: scene should have been cached earlier in this function."
+    if let (Some(effect), Some(effect_scene)) = (effect, effect_scene) {
+        let bounds = state.bounding_box;
+        let image = effect.image(
+            effect_scene,
+            bounds,
+            global_state.scale_factor,
+            antialiasing,
+        );
+        let transform = Affine::translate(bounds.origin().to_vec2())
+            * Affine::scale_non_uniform(
+                bounds.width() / f64::from(image.width),
+                bounds.height() / f64::from(image.height),
             );
-            return;
-        };
+        parent_scene.draw_image(&ImageBrush::new(image), transform);
+
+        let painted = &mut global_state.painted_wgpu_effects;
+        if !painted.iter().any(|painted| painted.ptr_eq(effect)) {
+            painted.push(effect.clone());
+        }
+    }
 
-        complete_scene.append(post_scene, Some(transform));
+    if translucent {
+        parent_scene.pop_layer();
     }
 }
 
+/// Returns the area fully covered by the widget's opaque paint, in window coordinates.
+///
+/// See [`PaintCtx::set_opaque_rect`].
+fn occluder_rect(state: &WidgetState) -> Option<Rect> {
+    // The clip path applies to the widget's paint, so the opaque rect might be clipped out.
+    // A translucent widget doesn't hide what's behind it, and neither may one with an effect.
+    if state.is_stashed
+        || state.clip_path.is_some()
+        || state.opacity < 1.0
+        || state.wgpu_effect.is_some()
+    {
+        return None;
+    }
+    let opaque_rect = state.opaque_rect?;
+    let transform = state
+        .window_transform
+        .pre_translate(state.border_box_translation());
+    // The bounding box of a rotated or skewed rect covers more than the rect.
+    let [_, b, c, _, _, _] = transform.as_coeffs();
+    if b != 0. || c != 0. {
+        return None;
+    }
+    Some(transform.transform_rect_bbox(opaque_rect))
+}
+
+/// Returns `true` if everything the widget and its descendants paint is behind one of the `occluders`.
+fn is_occluded(state: &WidgetState, occluders: &[Rect]) -> bool {
+    // Continuously redrawing widgets may be e.g. video, where skipping frames is noticeable.
+    if state.redraws_continuously {
+        return false;
+    }
+    let bbox = state.bounding_box;
+    occluders.iter().any(|occluder| {
+        occluder.x0 <= bbox.x0
+            && occluder.y0 <= bbox.y0
+            && occluder.x1 >= bbox.x1
+            && occluder.y1 >= bbox.y1
+    })
+}
+
+/// Returns the ids of the widget's children, sorted in the order they should be painted.
+///
+/// See [`ZIndex`](crate::properties::ZIndex) for details.
+fn paint_order(
+    widget: &dyn Widget,
+    children: &ArenaMutList<'_, WidgetArenaNode>,
+    default_properties: &DefaultProperties,
+) -> ChildrenIds {
+    let mut child_ids = widget.children_ids();
+    let key = |child_id: &WidgetId| {
+        children.item(*child_id).map(|node| {
+            let widget = &*node.item.widget;
+            z_order_key(PropertiesRef {
+                map: &node.item.properties,
+                default_map: default_properties.for_widget(widget.type_id()),
+            })
+        })
+    };
+    // The sort is stable, so children with the same key keep their `children_ids` order.
+    if child_ids
+        .iter()
+        .any(|child_id| key(child_id) != key(&child_ids[0]))
+    {
+        child_ids.sort_by_key(key);
+    }
+    child_ids
+}
+
+// --- MARK: SUBTREE
+/// Paints the subtree rooted at `widget_id` into a new scene, in window coordinates.
+///
+/// Unlike [`run_paint_pass`], this doesn't apply the clip paths of the widget's ancestors,
+/// and doesn't draw inspector overlays.
+///
+/// Used by [`RenderRoot::render_to_image`].
+pub(crate) fn run_paint_pass_for_subtree(root: &mut RenderRoot, widget_id: WidgetId) -> Scene {
+    let _span = info_span!("paint_subtree").entered();
+
+    let mut subtree_scene = Scene::new();
+
+    let parent_background = match root.widget_arena.parent_of(widget_id) {
+        Some(parent_id) => root.widget_arena.get_state(parent_id).effective_background,
+        None => root.global_state.base_color,
+    };
+    let mut node = root.widget_arena.get_node_mut(widget_id);
+
+    // See `run_paint_pass`.
+    let mut scene_cache = std::mem::take(&mut root.global_state.scene_cache);
+
+    paint_widget(
+        &mut root.global_state,
+        &root.default_properties,
+        &mut scene_cache,
+        node.reborrow_mut(),
+        parent_background,
+    );
+    encode_widget(
+        &mut root.global_state,
+        &root.default_properties,
+        &mut subtree_scene,
+        &scene_cache,
+        node,
+        &[],
+        // `render_to_image` uses area anti-aliasing.
+        AaConfig::Area,
+    );
+    root.global_state.scene_cache = scene_cache;
+
+    subtree_scene
+}
+
 // --- MARK: ROOT
 /// See the [passes documentation](crate::doc::pass_system#render-passes).
 pub(crate) fn run_paint_pass(root: &mut RenderRoot) -> Scene {
@@ -151,28 +401,55 @@ pub(crate) fn run_paint_pass(root: &mut RenderRoot) -> Scene {
     // https://github.com/linebender/xilem/issues/524
     let mut complete_scene = Scene::new();
 
-    let root_node = root.widget_arena.get_node_mut(root.root_id());
+    let mut root_node = root.widget_arena.get_node_mut(root.root_id());
 
     // TODO - This is a bit of a hack until we refactor widget tree mutation.
     // This should be removed once remove_child is exclusive to MutateCtx.
     let mut scene_cache = std::mem::take(&mut root.global_state.scene_cache);
 
+    let base_color = root.global_state.base_color;
     paint_widget(
         &mut root.global_state,
         &root.default_properties,
-        &mut complete_scene,
         &mut scene_cache,
+        root_node.reborrow_mut(),
+        base_color,
+    );
+    let antialiasing = root
+        .global_state
+        .supported_antialiasing(root.global_state.antialiasing);
+    encode_widget(
+        &mut root.global_state,
+        &root.default_properties,
+        &mut complete_scene,
+        &scene_cache,
         root_node,
+        &[],
+        antialiasing,
     );
     root.global_state.scene_cache = scene_cache;
 
+    // Paint the window overlay above every widget.
+    let global_state = &mut root.global_state;
+    global_state
+        .overlay
+        .paint(&mut complete_scene, &mut global_state.damage);
+
+    if let Some(profile) = &mut root.global_state.widget_profile {
+        profile.finish_frame();
+    }
+
     // Display a rectangle over the hovered widget
-    if let Some(hovered_widget) = root.global_state.inspector_state.hovered_widget {
+    let hover_rect = root
+        .global_state
+        .inspector_state
+        .hovered_widget
+        .map(|hovered_widget| {
+            let state = root.widget_arena.get_state(hovered_widget);
+            Rect::from_origin_size(state.border_box_window_origin(), state.border_box_size())
+        });
+    if let Some(rect) = hover_rect {
         const HOVER_FILL_COLOR: Color = Color::from_rgba8(60, 60, 250, 100);
-        let state = root.widget_arena.get_state(hovered_widget);
-        let rect =
-            Rect::from_origin_size(state.border_box_window_origin(), state.border_box_size());
-
         complete_scene.fill(
             Fill::NonZero,
             Affine::IDENTITY,
@@ -182,5 +459,144 @@ pub(crate) fn run_paint_pass(root: &mut RenderRoot) -> Scene {
         );
     }
 
+    // Outline the widgets which requested the last frame, if frame diagnostics are enabled.
+    let mut request_rects = Vec::new();
+    if root.global_state.inspector_state.is_picking_widget
+        && let Some(diagnostics) = &root.global_state.frame_diagnostics
+    {
+        for request in diagnostics.last_frame() {
+            if !root.widget_arena.has(request.widget) {
+                continue;
+            }
+            let state = root.widget_arena.get_state(request.widget);
+            let rect =
+                Rect::from_origin_size(state.border_box_window_origin(), state.border_box_size());
+            let color = match request.kind {
+                FrameRequestKind::Layout => Color::from_rgb8(250, 60, 60),
+                FrameRequestKind::Compose => Color::from_rgb8(250, 150, 30),
+                FrameRequestKind::Paint => Color::from_rgb8(240, 220, 40),
+                FrameRequestKind::Accessibility => Color::from_rgb8(60, 200, 60),
+                FrameRequestKind::AnimFrame | FrameRequestKind::ContinuousRedraw => {
+                    Color::from_rgb8(60, 160, 250)
+                }
+            };
+            stroke(&mut complete_scene, &rect, color, REQUEST_OUTLINE_WIDTH);
+            request_rects.push(rect.inflate(REQUEST_OUTLINE_WIDTH, REQUEST_OUTLINE_WIDTH));
+        }
+    }
+
+    // Highlight the most expensive widgets of the last frame, if widget profiling is enabled.
+    let mut profile_rects = Vec::new();
+    if root.global_state.inspector_state.is_picking_widget
+        && let Some(profile) = &root.global_state.widget_profile
+    {
+        for (rank, timing) in profile.top(PROFILE_HIGHLIGHT_COUNT).iter().enumerate() {
+            if !root.widget_arena.has(timing.widget) {
+                continue;
+            }
+            let state = root.widget_arena.get_state(timing.widget);
+            let rect =
+                Rect::from_origin_size(state.border_box_window_origin(), state.border_box_size());
+            // The most expensive widget is the most opaque.
+            let alpha = 160 - 25 * u8::try_from(rank).unwrap();
+            complete_scene.fill(
+                Fill::NonZero,
+                Affine::IDENTITY,
+                Color::from_rgba8(250, 40, 40, alpha),
+                None,
+                &rect,
+            );
+            profile_rects.push(rect);
+        }
+    }
+
+    // Outline the widgets with accessibility issues, if the audit is enabled.
+    let mut audit_rects = Vec::new();
+    if root.global_state.inspector_state.is_picking_widget
+        && let Some(audit) = &root.global_state.accessibility_audit
+    {
+        // The audit is borrowed from the global state, so that the arena can be borrowed too.
+        for issue in audit.audit.issues() {
+            if !root.widget_arena.has(issue.widget) {
+                continue;
+            }
+            let state = root.widget_arena.get_state(issue.widget);
+            let rect =
+                Rect::from_origin_size(state.border_box_window_origin(), state.border_box_size());
+            if audit_rects.contains(&rect.inflate(AUDIT_OUTLINE_WIDTH, AUDIT_OUTLINE_WIDTH)) {
+                continue;
+            }
+            stroke(
+                &mut complete_scene,
+                &rect,
+                Color::from_rgb8(230, 40, 230),
+                AUDIT_OUTLINE_WIDTH,
+            );
+            audit_rects.push(rect.inflate(AUDIT_OUTLINE_WIDTH, AUDIT_OUTLINE_WIDTH));
+        }
+    }
+
+    let global_state = &mut root.global_state;
+    let painted_hover_rect = global_state.inspector_state.painted_hover_rect;
+    if hover_rect != painted_hover_rect {
+        global_state.damage.extend(painted_hover_rect);
+        global_state.damage.extend(hover_rect);
+        global_state.inspector_state.painted_hover_rect = hover_rect;
+    }
+    let painted_request_rects = &mut global_state.inspector_state.painted_request_rects;
+    if request_rects != *painted_request_rects {
+        global_state.damage.append(painted_request_rects);
+        global_state.damage.extend(request_rects.iter().copied());
+        *painted_request_rects = request_rects;
+    }
+    let painted_profile_rects = &mut global_state.inspector_state.painted_profile_rects;
+    if profile_rects != *painted_profile_rects {
+        global_state.damage.append(painted_profile_rects);
+        global_state.damage.extend(profile_rects.iter().copied());
+        *painted_profile_rects = profile_rects;
+    }
+    let painted_audit_rects = &mut global_state.inspector_state.painted_audit_rects;
+    if audit_rects != *painted_audit_rects {
+        global_state.damage.append(painted_audit_rects);
+        global_state.damage.extend(audit_rects.iter().copied());
+        *painted_audit_rects = audit_rects;
+    }
+
+    global_state.frame_damage = coalesce_damage(std::mem::take(&mut global_state.damage));
+
     complete_scene
 }
+
+/// The width of the outlines of the widgets which requested the last frame.
+const REQUEST_OUTLINE_WIDTH: f64 = 2.;
+
+/// The width of the outlines of the widgets with accessibility issues.
+const AUDIT_OUTLINE_WIDTH: f64 = 2.;
+
+/// The number of most expensive widgets highlighted when widget profiling is enabled.
+const PROFILE_HIGHLIGHT_COUNT: usize = 5;
+
+/// The number of damage rects above which they're merged into their union.
+const MAX_DAMAGE_RECTS: usize = 8;
+
+/// Merges overlapping damage rects, and returns them grown to cover antialiased edges.
+fn coalesce_damage(damage: Vec<Rect>) -> Vec<Rect> {
+    let mut merged: Vec<Rect> = Vec::with_capacity(damage.len());
+    for rect in damage {
+        if rect.is_zero_area() {
+            continue;
+        }
+        // Antialiasing and debug paint strokes can spill over by a pixel.
+        let mut rect = rect.inflate(1., 1.);
+        // Merging can make a rect overlap rects it didn't overlap before.
+        while let Some(idx) = merged.iter().position(|other| other.overlaps(rect)) {
+            rect = rect.union(merged.swap_remove(idx));
+        }
+        merged.push(rect);
+    }
+    if merged.len() > MAX_DAMAGE_RECTS {
+        let union = merged.iter().copied().reduce(|a, b| a.union(b)).unwrap();
+        merged = vec![union];
+    }
+    merged
+}
@@ -6,15 +6,18 @@ use std::collections::HashSet;
 use tracing::{info_span, trace};
 use tree_arena::{ArenaMut, ArenaMutList};
 use ui_events::pointer::PointerType;
+use vello::kurbo::Rect;
 
 use crate::app::{RenderRoot, RenderRootSignal, RenderRootState};
 use crate::core::{
-    CursorIcon, DefaultProperties, Ime, PointerEvent, PointerInfo, PropertiesMut, PropertiesRef,
-    QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetArenaNode, WidgetId,
-    WidgetState,
+    CursorIcon, DefaultProperties, Ime, ImePurpose, PointerEvent, PointerInfo, PropertiesMut,
+    PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetArenaNode,
+    WidgetId, WidgetState,
 };
 use crate::passes::event::{run_on_pointer_event_pass, run_on_text_event_pass};
 use crate::passes::{enter_span, enter_span_if, merge_state_up, recurse_on_children};
+use crate::properties::{PressFeedback, PressFeedbackState};
+use crate::util::{Duration, Instant};
 
 // --- MARK: HELPERS
 /// Returns the id path starting from the given widget id and ending at the root.
@@ -44,6 +47,15 @@ fn is_ancestor_of(root: &RenderRoot, ancestor_id: WidgetId, widget_id: Option<Wi
         .any(|id| *id == ancestor_id.to_raw())
 }
 
+/// Returns the [`ImePurpose`] of the given widget, which is about to start an IME session.
+fn ime_purpose(root: &RenderRoot, widget_id: WidgetId) -> ImePurpose {
+    root.widget_arena
+        .get_node(widget_id)
+        .item
+        .widget
+        .ime_purpose()
+}
+
 /// Make a dummy [`PointerEvent::Cancel`].
 fn dummy_pointer_cancel() -> PointerEvent {
     PointerEvent::Cancel(PointerInfo {
@@ -161,6 +173,7 @@ fn update_widget_tree(
         }
         state.accepts_pointer_interaction = widget.accepts_pointer_interaction();
         state.accepts_focus = widget.accepts_focus();
+        state.is_roving_focus_group = widget.is_roving_focus_group();
         state.accepts_text_input = widget.accepts_text_input();
         state.trace_span = widget.make_trace_span(state.id);
         state.is_new = false;
@@ -498,12 +511,35 @@ fn find_first_focusable(
     let widget = &mut *item.item.widget;
     let state = &mut item.item.state;
     let accepts_focus = state.accepts_focus;
+    let roving_focus_target = state
+        .is_roving_focus_group
+        .then_some(state.roving_focus_target);
 
     if !state.descendant_is_focusable {
         return None;
     }
 
     let children = widget.children_ids();
+
+    if let Some(target) = roving_focus_target {
+        // The focusable descendants of a roving focus group are a single Tab stop,
+        // so Tab leaves the group when the anchor is inside it.
+        if !anchor_path.is_empty() {
+            return (!forward && accepts_focus).then_some(node);
+        }
+        if forward && accepts_focus {
+            return Some(node);
+        }
+        // Otherwise, we enter the group through its descendant which was focused last,
+        // if it can still be focused, or through its first or last focusable descendant.
+        if let Some(target) = target
+            && root.has_widget(target)
+            && let Some(found) = find_first_focusable(root, &[], target, true)
+        {
+            return Some(found);
+        }
+    }
+
     let children = if let Some((anchor, anchor_path)) = anchor_path.split_last() {
         let anchor_idx = children.iter().position(|id| *id == *anchor).unwrap();
 
@@ -619,7 +655,11 @@ pub(crate) fn run_update_focus_pass(root: &mut RenderRoot) {
                 id = prev_focused.map(|id| id.trace()),
                 "request_focus called whilst handling Ime::Disabled"
             );
-            root.global_state.emit_signal(RenderRootSignal::StartIme);
+            if let Some(id) = prev_focused {
+                let purpose = ime_purpose(root, id);
+                root.global_state
+                    .emit_signal(RenderRootSignal::StartIme(purpose));
+            }
         }
     }
 
@@ -705,15 +745,25 @@ pub(crate) fn run_update_focus_pass(root: &mut RenderRoot) {
 
             root.global_state.is_ime_active = widget_state.accepts_text_input;
             if widget_state.accepts_text_input {
-                root.global_state.emit_signal(RenderRootSignal::StartIme);
+                let purpose = ime_purpose(root, next_focused);
+                root.global_state
+                    .emit_signal(RenderRootSignal::StartIme(purpose));
             }
         } else {
             root.global_state.is_ime_active = false;
         }
     }
 
-    if next_focused.is_some() {
-        root.global_state.focus_anchor = next_focused;
+    if let Some(next_focused) = next_focused {
+        root.global_state.focus_anchor = Some(next_focused);
+
+        // Roving focus groups remember their descendant which was focused last.
+        for widget_id in next_focused_path.iter().copied() {
+            let state = root.widget_arena.get_state_mut(widget_id);
+            if state.is_roving_focus_group && widget_id != next_focused {
+                state.roving_focus_target = Some(next_focused);
+            }
+        }
     }
 
     root.global_state.focused_widget = next_focused;
@@ -734,32 +784,96 @@ pub(crate) fn run_update_scroll_pass(root: &mut RenderRoot) {
 
     let scroll_request_targets = std::mem::take(&mut root.global_state.scroll_request_targets);
     for (target, rect) in scroll_request_targets {
-        // We start with target_rect being in the target's border-box coordinate space.
-        let mut target_rect = rect;
-
-        // We run the update pass on the target itself and then its ancestors.
-        run_targeted_update_pass(root, Some(target), |widget, ctx, props| {
-            // Convert the target_rect from border-box space to content-box space.
-            let local_rect = target_rect - ctx.widget_state.border_box_translation();
-
-            let event = Update::RequestPanToChild(local_rect);
-            widget.update(ctx, props, &event);
-
-            // TODO - We should run the compose method after this, so
-            // translations are updated and the rect passed to parents
-            // is more accurate. Until then we don't add scroll_translation
-            // at all and only support a single scrolling parent.
-
-            // Before continuing to the parent, we need to convert the target_rect from this
-            // widget's border-box coordinate space to the parent's border-box coordinate space.
-            let state = &ctx.widget_state;
-            target_rect = target_rect + state.origin.to_vec2();
-        });
+        pan_ancestors_to(root, target, rect, Update::RequestPanToChild);
+    }
+
+    if std::mem::take(&mut root.global_state.scroll_to_focused)
+        && let Some(focused) = root.global_state.focused_widget
+    {
+        let rect = root
+            .widget_arena
+            .get_state(focused)
+            .border_box_size()
+            .to_rect();
+        pan_ancestors_to(root, focused, rect, Update::RequestPanToFocusedChild);
     }
+
+    if let Some(auto_scroll) = &mut root.global_state.edge_auto_scroll
+        && std::mem::take(&mut auto_scroll.needs_update)
+    {
+        let source = auto_scroll.source;
+        let position = if auto_scroll.ended {
+            root.global_state.edge_auto_scroll = None;
+            None
+        } else {
+            auto_scroll.position
+        };
+        if root.widget_arena.has(source) {
+            let parent = root.widget_arena.parent_of(source);
+            run_targeted_update_pass(root, parent, |widget, ctx, props| {
+                let position = position.map(|pos| ctx.to_local(pos));
+                widget.update(ctx, props, &Update::EdgeAutoScroll(position));
+            });
+        }
+    }
+}
+
+/// Sends the event created by `make_event` to `target` and its ancestors,
+/// with `rect` converted to the coordinate space of each of them.
+///
+/// `rect` is in the target's border-box coordinate space.
+fn pan_ancestors_to(
+    root: &mut RenderRoot,
+    target: WidgetId,
+    rect: Rect,
+    make_event: fn(Rect) -> Update,
+) {
+    // We start with target_rect being in the target's border-box coordinate space.
+    let mut target_rect = rect;
+
+    // We run the update pass on the target itself and then its ancestors.
+    run_targeted_update_pass(root, Some(target), |widget, ctx, props| {
+        // Convert the target_rect from border-box space to content-box space.
+        let local_rect = target_rect - ctx.widget_state.border_box_translation();
+
+        let event = make_event(local_rect);
+        widget.update(ctx, props, &event);
+
+        // TODO - We should run the compose method after this, so
+        // translations are updated and the rect passed to parents
+        // is more accurate. Until then we don't add scroll_translation
+        // at all and only support a single scrolling parent.
+
+        // Before continuing to the parent, we need to convert the target_rect from this
+        // widget's border-box coordinate space to the parent's border-box coordinate space.
+        let state = &ctx.widget_state;
+        target_rect = target_rect + state.origin.to_vec2();
+    });
 }
 
 // ----------------
 
+// --- MARK: TIMERS
+/// Sends [`Update::Timer`] to the widgets whose timers are due at `now`,
+/// in the order of their deadlines.
+///
+/// The timers of widgets which have been removed are dropped.
+pub(crate) fn run_update_timer_pass(root: &mut RenderRoot, now: Instant) {
+    let _span = info_span!("update_timers").entered();
+
+    let (mut due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut root.global_state.timers)
+        .into_iter()
+        .partition(|timer| timer.deadline <= now);
+    // Timers requested when these fire are added to the pending ones.
+    root.global_state.timers = pending;
+    due.sort_by_key(|timer| timer.deadline);
+    for timer in due {
+        run_single_update_pass(root, Some(timer.widget), |widget, ctx, props| {
+            widget.update(ctx, props, &Update::Timer(timer.token));
+        });
+    }
+}
+
 // --- MARK: POINTER
 /// See the [passes documentation](crate::doc::pass_system#update-passes).
 pub(crate) fn run_update_pointer_pass(root: &mut RenderRoot) {
@@ -869,6 +983,18 @@ pub(crate) fn run_update_pointer_pass(root: &mut RenderRoot) {
             // ActiveBackground needs pre-paint
             ctx.widget_state.request_pre_paint = true;
             ctx.widget_state.needs_paint = true;
+            // Start the press feedback from the pointer.
+            if let Some(pos) = pointer_pos
+                && let Some(duration) = props.get::<PressFeedback>().duration()
+            {
+                ctx.widget_state.press_feedback = Some(PressFeedbackState {
+                    origin: ctx.to_local(pos),
+                    age: Duration::ZERO,
+                    duration,
+                });
+                // The anim pass advances the feedback without calling `on_anim_frame`.
+                ctx.widget_state.needs_anim = true;
+            }
             widget.update(ctx, props, &Update::ActiveChanged(true));
         });
     }
@@ -73,6 +73,16 @@ impl Background {
             Self::Gradient(_) => true,
         }
     }
+
+    /// Returns `true` if the background is a fully opaque color.
+    ///
+    /// Gradients are conservatively considered translucent.
+    pub const fn is_opaque(&self) -> bool {
+        match self {
+            Self::Color(color) => color.components[3] >= 1.0,
+            Self::Gradient(_) => false,
+        }
+    }
 }
 
 // ---
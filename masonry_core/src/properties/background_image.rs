@@ -0,0 +1,189 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use vello::Scene;
+
+use crate::core::{HasProperty, Property, Widget};
+use crate::kurbo::{Affine, Insets, Rect, RoundedRect, Shape};
+use crate::peniko::{Extend, Fill, ImageBrush};
+use crate::properties::types::BackgroundImageMode;
+
+// Every widget has a background image.
+impl<W: Widget> HasProperty<BackgroundImage> for W {}
+
+/// A raster image painted as the background of a widget.
+///
+/// The image is painted on top of the widget's [`Background`], and below its border.
+/// How the image is fitted into the background rect is decided by its [`BackgroundImageMode`],
+/// which notably supports [9-patch] slicing.
+///
+/// [`Background`]: crate::properties::Background
+/// [9-patch]: BackgroundImageMode::NinePatch
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum BackgroundImage {
+    /// No background image.
+    ///
+    /// This matches the CSS default.
+    #[default]
+    None,
+    /// An image, painted according to a mode.
+    Image {
+        /// The image data and sampling parameters.
+        image: ImageBrush,
+        /// How the image is fitted into the background rect.
+        mode: BackgroundImageMode,
+    },
+}
+
+impl Property for BackgroundImage {
+    fn static_default() -> &'static Self {
+        static DEFAULT: BackgroundImage = BackgroundImage::None;
+        &DEFAULT
+    }
+}
+
+impl BackgroundImage {
+    /// Creates a background image stretched to fill the background rect.
+    pub fn stretch(image: impl Into<ImageBrush>) -> Self {
+        Self::Image {
+            image: image.into(),
+            mode: BackgroundImageMode::Stretch,
+        }
+    }
+
+    /// Creates a background image repeated at its natural size.
+    pub fn tile(image: impl Into<ImageBrush>) -> Self {
+        Self::Image {
+            image: image.into(),
+            mode: BackgroundImageMode::Tile,
+        }
+    }
+
+    /// Creates a background image sliced into nine patches by the given `insets`, in image pixels.
+    ///
+    /// See [`BackgroundImageMode::NinePatch`] for details.
+    pub fn nine_patch(image: impl Into<ImageBrush>, insets: impl Into<Insets>) -> Self {
+        Self::Image {
+            image: image.into(),
+            mode: BackgroundImageMode::NinePatch(insets.into()),
+        }
+    }
+
+    /// Returns `false` if the background image can be safely treated as non-existent.
+    ///
+    /// May have false positives.
+    pub fn is_visible(&self) -> bool {
+        match self {
+            Self::None => false,
+            Self::Image { image, .. } => {
+                image.image.width != 0 && image.image.height != 0 && image.sampler.alpha != 0.0
+            }
+        }
+    }
+
+    /// Helper function to paint the background image into a scene.
+    ///
+    /// The image is clipped to `bg_rect`.
+    pub fn paint(&self, scene: &mut Scene, transform: Affine, bg_rect: RoundedRect) {
+        if !self.is_visible() {
+            return;
+        }
+        let Self::Image { image, mode } = self else {
+            return;
+        };
+        let rect = bg_rect.rect();
+        let width = f64::from(image.image.width);
+        let height = f64::from(image.image.height);
+
+        match mode {
+            BackgroundImageMode::Stretch => {
+                let src = Rect::new(0., 0., width, height);
+                fill_slice(scene, transform, image, src, rect, &bg_rect);
+            }
+            BackgroundImageMode::Tile => {
+                let image = image.clone().with_extend(Extend::Repeat);
+                let brush_transform = Affine::translate(rect.origin().to_vec2());
+                scene.fill(
+                    Fill::NonZero,
+                    transform,
+                    &image,
+                    Some(brush_transform),
+                    &bg_rect,
+                );
+            }
+            BackgroundImageMode::NinePatch(insets) => {
+                // Slice insets can't be larger than the image itself.
+                let src_x = slice_stops(width, insets.x0, insets.x1);
+                let src_y = slice_stops(height, insets.y0, insets.y1);
+                let dst_x = fit_stops(rect.x0, rect.x1, src_x[1], width - src_x[2]);
+                let dst_y = fit_stops(rect.y0, rect.y1, src_y[1], height - src_y[2]);
+
+                let clip = bg_rect.radii().as_single_radius() != Some(0.);
+                if clip {
+                    scene.push_clip_layer(Fill::NonZero, transform, &bg_rect);
+                }
+                for row in 0..3 {
+                    for col in 0..3 {
+                        let src = Rect::new(src_x[col], src_y[row], src_x[col + 1], src_y[row + 1]);
+                        let dst = Rect::new(dst_x[col], dst_y[row], dst_x[col + 1], dst_y[row + 1]);
+                        fill_slice(scene, transform, image, src, dst, &dst);
+                    }
+                }
+                if clip {
+                    scene.pop_layer();
+                }
+            }
+        }
+    }
+}
+
+/// Returns the four edges of the three slices of a `length` long image axis.
+fn slice_stops(length: f64, start: f64, end: f64) -> [f64; 4] {
+    let start = start.clamp(0., length);
+    let end = end.clamp(0., length - start);
+    [0., start, length - end, length]
+}
+
+/// Returns the four edges of the three slices of the `min..max` destination axis.
+///
+/// The fixed `start` and `end` slices are shrunk proportionally if they don't fit.
+fn fit_stops(min: f64, max: f64, start: f64, end: f64) -> [f64; 4] {
+    let available = (max - min).max(0.);
+    let fixed = start + end;
+    let scale = if fixed > available && fixed > 0. {
+        available / fixed
+    } else {
+        1.
+    };
+    [min, min + start * scale, max - end * scale, max]
+}
+
+/// Paints the `src` rect of `image` (in image pixels) into the `dst` rect, clipped to `shape`.
+fn fill_slice(
+    scene: &mut Scene,
+    transform: Affine,
+    image: &ImageBrush,
+    src: Rect,
+    dst: Rect,
+    shape: &impl Shape,
+) {
+    if src.is_zero_area() || dst.is_zero_area() {
+        return;
+    }
+    let brush_transform = Affine::translate(dst.origin().to_vec2())
+        * Affine::scale_non_uniform(dst.width() / src.width(), dst.height() / src.height())
+        * Affine::translate(-src.origin().to_vec2());
+    scene.fill(
+        Fill::NonZero,
+        transform,
+        image,
+        Some(brush_transform),
+        shape,
+    );
+}
+
+impl From<ImageBrush> for BackgroundImage {
+    fn from(image: ImageBrush) -> Self {
+        Self::stretch(image)
+    }
+}
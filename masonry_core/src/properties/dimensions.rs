@@ -117,6 +117,30 @@ impl Dimensions {
         }
     }
 
+    /// Creates new [`Dimensions`] in multiples of the root font size.
+    ///
+    /// The values must be finite and non-negative.
+    ///
+    /// See [`Dim::Rem`] for details.
+    pub const fn rem(width: f64, height: f64) -> Self {
+        Self {
+            width: Dim::Rem(width),
+            height: Dim::Rem(height),
+        }
+    }
+
+    /// Creates new [`Dimensions`] as percentages of the context size.
+    ///
+    /// The percentages must be finite and non-negative.
+    ///
+    /// See [`Dim::percent`] for details.
+    pub const fn percent(width_percent: f64, height_percent: f64) -> Self {
+        Self {
+            width: Dim::percent(width_percent),
+            height: Dim::percent(height_percent),
+        }
+    }
+
     /// Creates new [`Dimensions`] with width set to `dim`.
     ///
     /// Height is [`Dim::Auto`].
@@ -0,0 +1,174 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::borrow::Cow;
+
+use vello::Scene;
+
+use crate::core::{HasProperty, Property, Widget};
+use crate::kurbo::{Affine, Insets, Point, RoundedRect};
+use crate::peniko::color::AlphaColor;
+use crate::properties::BoxShadow;
+
+// Every widget has an elevation.
+impl<W: Widget> HasProperty<Elevation> for W {}
+impl<W: Widget> HasProperty<HoveredElevation> for W {}
+impl<W: Widget> HasProperty<ActiveElevation> for W {}
+impl<W: Widget> HasProperty<ElevationShadows> for W {}
+
+/// How high a widget is raised above the surface it sits on.
+///
+/// This lets design systems express "a level 2 card" instead of raw shadow parameters.
+/// The shadows actually painted for each level are looked up in the widget's
+/// [`ElevationShadows`] property, which themes can override.
+///
+/// Elevation 0, the default, paints no shadow.
+/// Elevation shadows are painted below the widget's [`BoxShadow`], if any.
+///
+/// Elevation is also a z-ordering hint: among siblings with the same [`ZIndex`],
+/// higher elevations are painted above lower ones.
+///
+/// [`ZIndex`]: crate::properties::ZIndex
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Elevation(pub u8);
+
+/// The elevation of a widget when hovered by a pointer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HoveredElevation(pub Elevation);
+
+/// The elevation of a widget when pressed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ActiveElevation(pub Elevation);
+
+/// The pair of shadows painted for a single [`Elevation`] level.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ElevationLevel {
+    /// A tight, offset shadow, cast by a light above the widget.
+    pub key: BoxShadow,
+    /// A wide, soft shadow, cast by the surrounding light.
+    pub ambient: BoxShadow,
+}
+
+/// The table mapping [`Elevation`] levels to the shadows they paint.
+///
+/// The first entry is used for elevation 1, the second for elevation 2, and so on.
+/// Elevations higher than the number of entries use the last entry.
+///
+/// Themes can provide their own table for each widget type through [`DefaultProperties`].
+///
+/// [`DefaultProperties`]: crate::core::DefaultProperties
+#[derive(Clone, Debug, PartialEq)]
+pub struct ElevationShadows {
+    /// The shadows for each elevation level, starting from elevation 1.
+    pub levels: Cow<'static, [ElevationLevel]>,
+}
+
+// ---
+
+impl Property for Elevation {
+    fn static_default() -> &'static Self {
+        static DEFAULT: Elevation = Elevation(0);
+        &DEFAULT
+    }
+}
+
+impl Property for HoveredElevation {
+    fn static_default() -> &'static Self {
+        static DEFAULT: HoveredElevation = HoveredElevation(Elevation(0));
+        &DEFAULT
+    }
+}
+
+impl Property for ActiveElevation {
+    fn static_default() -> &'static Self {
+        static DEFAULT: ActiveElevation = ActiveElevation(Elevation(0));
+        &DEFAULT
+    }
+}
+
+impl Property for ElevationShadows {
+    fn static_default() -> &'static Self {
+        static DEFAULT: ElevationShadows = ElevationShadows::DEFAULT;
+        &DEFAULT
+    }
+}
+
+impl Default for ElevationShadows {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<u8> for Elevation {
+    fn from(level: u8) -> Self {
+        Self(level)
+    }
+}
+
+// ---
+
+const fn shadow(alpha: u8, offset_y: f64, blur_radius: f64) -> BoxShadow {
+    BoxShadow {
+        color: AlphaColor::from_rgba8(0, 0, 0, alpha),
+        offset: Point::new(0., offset_y),
+        blur_radius,
+    }
+}
+
+const fn level(offset_y: f64, blur_radius: f64) -> ElevationLevel {
+    ElevationLevel {
+        key: shadow(0x4c, offset_y / 2., blur_radius / 3.),
+        ambient: shadow(0x26, offset_y, blur_radius),
+    }
+}
+
+impl ElevationLevel {
+    /// Helper function that returns how much this level's shadows expand the paint rect.
+    pub fn get_insets(&self) -> Insets {
+        let key = self.key.get_insets();
+        let ambient = self.ambient.get_insets();
+        Insets {
+            x0: key.x0.max(ambient.x0),
+            y0: key.y0.max(ambient.y0),
+            x1: key.x1.max(ambient.x1),
+            y1: key.y1.max(ambient.y1),
+        }
+    }
+
+    /// Helper function to paint this level's shadows into a scene.
+    pub fn paint(&self, scene: &mut Scene, transform: Affine, rect: RoundedRect) {
+        self.ambient.paint(scene, transform, rect);
+        self.key.paint(scene, transform, rect);
+    }
+}
+
+impl ElevationShadows {
+    /// Five levels of shadows, growing softer and further offset with each level.
+    pub const DEFAULT: Self = Self {
+        levels: Cow::Borrowed(&[
+            level(2., 3.),
+            level(4., 6.),
+            level(6., 9.),
+            level(8., 12.),
+            level(12., 16.),
+        ]),
+    };
+
+    /// A table with no shadows, which disables elevation shadows.
+    pub const NONE: Self = Self {
+        levels: Cow::Borrowed(&[]),
+    };
+
+    /// Creates a new table from the shadows of each level, starting from elevation 1.
+    pub fn new(levels: impl Into<Cow<'static, [ElevationLevel]>>) -> Self {
+        Self {
+            levels: levels.into(),
+        }
+    }
+
+    /// Returns the shadows painted for `elevation`, if any.
+    pub fn get(&self, elevation: Elevation) -> Option<&ElevationLevel> {
+        let index = usize::from(elevation.0).min(self.levels.len());
+        index.checked_sub(1).map(|index| &self.levels[index])
+    }
+}
@@ -4,33 +4,45 @@
 //! Core properties.
 
 mod background;
+mod background_image;
 mod border_color;
 mod border_width;
 mod box_shadow;
 mod corner_radius;
 mod dimensions;
+mod elevation;
 mod padding;
+mod press_feedback;
+mod z_index;
 
 pub mod types;
 
 use std::any::TypeId;
 
 pub use background::*;
+pub use background_image::*;
 pub use border_color::*;
 pub use border_width::*;
 pub use box_shadow::*;
 pub use corner_radius::*;
 pub use dimensions::*;
+pub use elevation::*;
 pub use padding::*;
+pub use press_feedback::*;
+pub use z_index::*;
 
 use crate::core::{Property, UpdateCtx};
 
 /// Handles core property changes.
 pub(crate) fn core_property_changed(ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
-    // TODO: For BoxShadow we'd like to request a mere pre-paint pass.
+    // TODO: For BoxShadow and elevations we'd like to request a mere pre-paint pass.
     //       However, it affects the size of the paint rect, which is handled in layout.
     if Dimensions::matches(property_type)
         || BoxShadow::matches(property_type)
+        || Elevation::matches(property_type)
+        || HoveredElevation::matches(property_type)
+        || ActiveElevation::matches(property_type)
+        || ElevationShadows::matches(property_type)
         || BorderWidth::matches(property_type)
         || CornerRadius::matches(property_type)
         || Padding::matches(property_type)
@@ -39,6 +51,7 @@ pub(crate) fn core_property_changed(ctx: &mut UpdateCtx<'_>, property_type: Type
     } else if DisabledBackground::matches(property_type)
         || ActiveBackground::matches(property_type)
         || Background::matches(property_type)
+        || BackgroundImage::matches(property_type)
         || FocusedBorderColor::matches(property_type)
         || HoveredBorderColor::matches(property_type)
         || BorderColor::matches(property_type)
@@ -46,5 +59,8 @@ pub(crate) fn core_property_changed(ctx: &mut UpdateCtx<'_>, property_type: Type
         || CornerRadius::matches(property_type)
     {
         ctx.request_pre_paint();
+    } else if ZIndex::matches(property_type) {
+        // The parent's paint order is recomputed on every paint pass.
+        ctx.request_paint_only();
     }
 }
@@ -3,22 +3,27 @@
 
 use crate::core::{HasProperty, Property, Widget};
 use crate::kurbo::{Axis, Insets, Point, Size, Vec2};
-use crate::layout::Length;
+use crate::layout::{Length, LengthUnit, UnitContext};
 
 // Every widget has padding.
 impl<W: Widget> HasProperty<Padding> for W {}
 
 /// The width of padding between a widget's border and its contents.
+///
+/// The amounts are in the padding's [`unit`](Self::unit), which is logical pixels by default.
+/// Padding in relative units is [resolved](Self::resolve) to logical pixels during layout.
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
 pub struct Padding {
-    /// The amount of padding in logical pixels for the left edge.
+    /// The amount of padding for the left edge.
     pub left: f64,
-    /// The amount of padding in logical pixels for the right edge.
+    /// The amount of padding for the right edge.
     pub right: f64,
-    /// The amount of padding in logical pixels for the top edge.
+    /// The amount of padding for the top edge.
     pub top: f64,
-    /// The amount of padding in logical pixels for the bottom edge.
+    /// The amount of padding for the bottom edge.
     pub bottom: f64,
+    /// The unit of the amounts of padding.
+    pub unit: LengthUnit,
 }
 
 impl Property for Padding {
@@ -46,6 +51,7 @@ impl Padding {
             bottom: padding,
             left: padding,
             right: padding,
+            unit: LengthUnit::Px,
         }
     }
 
@@ -57,6 +63,7 @@ impl Padding {
             bottom: 0.,
             left: padding,
             right: padding,
+            unit: LengthUnit::Px,
         }
     }
 
@@ -68,6 +75,7 @@ impl Padding {
             bottom: padding,
             left: 0.,
             right: 0.,
+            unit: LengthUnit::Px,
         }
     }
 
@@ -78,6 +86,7 @@ impl Padding {
             bottom: vertical,
             left: horizontal,
             right: horizontal,
+            unit: LengthUnit::Px,
         }
     }
 
@@ -88,6 +97,7 @@ impl Padding {
             bottom: 0.,
             left: 0.,
             right: 0.,
+            unit: LengthUnit::Px,
         }
     }
 
@@ -98,6 +108,7 @@ impl Padding {
             bottom: padding,
             left: 0.,
             right: 0.,
+            unit: LengthUnit::Px,
         }
     }
 
@@ -108,6 +119,7 @@ impl Padding {
             bottom: 0.,
             left: padding,
             right: 0.,
+            unit: LengthUnit::Px,
         }
     }
 
@@ -118,6 +130,32 @@ impl Padding {
             bottom: 0.,
             left: 0.,
             right: padding,
+            unit: LengthUnit::Px,
+        }
+    }
+
+    /// Returns the padding with its amounts in `unit`.
+    ///
+    /// For example, `Padding::all(0.5).with_unit(LengthUnit::Rem)` is half the root font size
+    /// on every edge.
+    pub const fn with_unit(mut self, unit: LengthUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Resolves the padding to logical pixels, using `units` for relative units.
+    ///
+    /// The other methods of [`Padding`] treat the amounts as logical pixels,
+    /// so padding must be resolved before calling them.
+    /// Masonry does it during the layout pass.
+    pub fn resolve(&self, units: &UnitContext) -> Self {
+        let to_px = |value| self.unit.to_px(value, units);
+        Self {
+            left: to_px(self.left),
+            right: to_px(self.right),
+            top: to_px(self.top),
+            bottom: to_px(self.bottom),
+            unit: LengthUnit::Px,
         }
     }
 }
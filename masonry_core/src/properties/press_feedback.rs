@@ -0,0 +1,155 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use vello::Scene;
+
+use crate::core::{HasProperty, Property, Widget};
+use crate::kurbo::{Affine, Circle, Point, Rect, Shape};
+use crate::peniko::{Color, Fill};
+use crate::util::Duration;
+
+// Every widget can show press feedback.
+impl<W: Widget> HasProperty<PressFeedback> for W {}
+
+/// The visual feedback painted when a widget is pressed by a pointer, such as a ripple.
+///
+/// The feedback starts from the pointer position when the widget becomes [active], and is
+/// animated for its whole duration, even if the press ends sooner.
+/// It's painted by [`pre_paint`] above the widget's background and below its border and
+/// content, clipped to the widget's clip path, or to its background if it has none.
+///
+/// There is no feedback by default. Themes enable it for buttons.
+///
+/// If the user [prefers reduced motion], ripples are shown as flashes.
+///
+/// [active]: crate::core::EventCtx::is_active
+/// [`pre_paint`]: crate::core::pre_paint
+/// [prefers reduced motion]: crate::core::PaintCtx::prefers_reduced_motion
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum PressFeedback {
+    /// No feedback.
+    #[default]
+    None,
+    /// A circle of `color` growing from the pointer until it covers the widget,
+    /// while fading out.
+    Ripple {
+        /// The color of the circle when the press starts.
+        color: Color,
+        /// The time it takes to cover the widget and fade out.
+        duration: Duration,
+    },
+    /// The widget covered with `color`, fading out.
+    Flash {
+        /// The color covering the widget when the press starts.
+        color: Color,
+        /// The time it takes to fade out.
+        duration: Duration,
+    },
+}
+
+/// The press feedback of a widget being animated.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PressFeedbackState {
+    /// Where the press started, in the widget's content-box coordinate space.
+    pub(crate) origin: Point,
+    /// The time elapsed since the press started.
+    pub(crate) age: Duration,
+    /// The duration of the feedback.
+    pub(crate) duration: Duration,
+}
+
+impl Property for PressFeedback {
+    fn static_default() -> &'static Self {
+        static DEFAULT: PressFeedback = PressFeedback::None;
+        &DEFAULT
+    }
+}
+
+impl PressFeedback {
+    /// The duration of the feedback created by [`ripple`](Self::ripple) and
+    /// [`flash`](Self::flash).
+    pub const DEFAULT_DURATION: Duration = Duration::from_millis(450);
+
+    /// Creates a ripple of the given color, lasting [`DEFAULT_DURATION`](Self::DEFAULT_DURATION).
+    pub const fn ripple(color: Color) -> Self {
+        Self::Ripple {
+            color,
+            duration: Self::DEFAULT_DURATION,
+        }
+    }
+
+    /// Creates a flash of the given color, lasting [`DEFAULT_DURATION`](Self::DEFAULT_DURATION).
+    pub const fn flash(color: Color) -> Self {
+        Self::Flash {
+            color,
+            duration: Self::DEFAULT_DURATION,
+        }
+    }
+
+    /// Returns how long the feedback lasts, or `None` if there is no visible feedback.
+    pub fn duration(&self) -> Option<Duration> {
+        match *self {
+            Self::None => None,
+            Self::Ripple { duration, .. } | Self::Flash { duration, .. } => {
+                (duration > Duration::ZERO).then_some(duration)
+            }
+        }
+    }
+
+    /// Paints the feedback of a press started from `origin` `age` ago, clipped to `clip`.
+    ///
+    /// Everything is in the widget's content-box coordinate space.
+    pub(crate) fn paint(
+        &self,
+        scene: &mut Scene,
+        border_box: Rect,
+        clip: &impl Shape,
+        state: &PressFeedbackState,
+        reduced_motion: bool,
+    ) {
+        let Some(duration) = self.duration() else {
+            return;
+        };
+        let progress = (state.age.as_secs_f64() / duration.as_secs_f64()).min(1.);
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "The progress is between 0 and 1"
+        )]
+        let fade = 1. - progress as f32;
+
+        match *self {
+            Self::None => {}
+            Self::Ripple { color, .. } if !reduced_motion => {
+                // The circle grows quickly at first, and slows down as it covers the widget.
+                let max_radius = [
+                    border_box.origin(),
+                    Point::new(border_box.x1, border_box.y0),
+                    Point::new(border_box.x0, border_box.y1),
+                    Point::new(border_box.x1, border_box.y1),
+                ]
+                .into_iter()
+                .map(|corner| corner.distance(state.origin))
+                .fold(0., f64::max);
+                let radius = max_radius * (1. - (1. - progress).powi(2));
+                scene.push_clip_layer(Fill::NonZero, Affine::IDENTITY, clip);
+                scene.fill(
+                    Fill::NonZero,
+                    Affine::IDENTITY,
+                    color.multiply_alpha(fade),
+                    None,
+                    &Circle::new(state.origin, radius),
+                );
+                scene.pop_layer();
+            }
+            Self::Ripple { color, .. } | Self::Flash { color, .. } => {
+                scene.fill(
+                    Fill::NonZero,
+                    Affine::IDENTITY,
+                    color.multiply_alpha(fade),
+                    None,
+                    clip,
+                );
+            }
+        }
+    }
+}
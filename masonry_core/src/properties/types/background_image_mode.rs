@@ -0,0 +1,29 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::kurbo::Insets;
+
+/// Strategies for painting a [`BackgroundImage`] into a widget's background rect.
+///
+/// [`BackgroundImage`]: crate::properties::BackgroundImage
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BackgroundImageMode {
+    /// The image is stretched to exactly fill the background rect.
+    ///
+    /// The image's aspect ratio is not maintained.
+    #[default]
+    Stretch,
+    /// The image is repeated at its natural size, starting from the top-left corner
+    /// of the background rect.
+    Tile,
+    /// The image is split into nine slices by the given insets, in image pixels.
+    ///
+    /// The four corners are painted at their natural size, the four edges are stretched
+    /// along their one axis, and the center is stretched along both axes.
+    /// This is often called "9-patch" or "9-slice" scaling, and is what lets a single
+    /// image of a chat bubble or a skinned button frame fit content of any size.
+    ///
+    /// If the background rect is too small to fit the corners at their natural size,
+    /// they are scaled down proportionally.
+    NinePatch(Insets),
+}
@@ -12,6 +12,8 @@
 //! [`Background`]: crate::properties::Background
 //! [`Background::Gradient`]: crate::properties::Background::Gradient
 
+mod background_image_mode;
 mod gradient;
 
+pub use background_image_mode::*;
 pub use gradient::*;
@@ -0,0 +1,48 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::core::{HasProperty, PropertiesRef, Property, Widget};
+use crate::properties::Elevation;
+
+// Every widget has a z-index.
+impl<W: Widget> HasProperty<ZIndex> for W {}
+
+/// The order in which a widget is painted and hit-tested relative to its siblings.
+///
+/// Widgets are normally painted in the order of their parent's [`children_ids`],
+/// so that later siblings are painted above earlier ones.
+/// A widget with a higher z-index is instead painted above all of its siblings with a
+/// lower z-index, and receives pointer events before them where they overlap.
+/// Siblings with the same z-index are ordered by their [`Elevation`], and then by
+/// their order in `children_ids`.
+///
+/// This lets e.g. a dragged card or an open dropdown appear on top of its neighbors
+/// without restructuring the widget tree.
+///
+/// Z-index only affects the order among siblings: a widget is still painted within its
+/// parent, and clipped by its parent's clip path.
+/// It doesn't affect the order of accessibility nodes or of keyboard focus.
+///
+/// The default z-index is 0. Negative values are painted below default siblings.
+///
+/// [`children_ids`]: Widget::children_ids
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZIndex(pub i32);
+
+impl Property for ZIndex {
+    fn static_default() -> &'static Self {
+        static DEFAULT: ZIndex = ZIndex(0);
+        &DEFAULT
+    }
+}
+
+impl From<i32> for ZIndex {
+    fn from(z_index: i32) -> Self {
+        Self(z_index)
+    }
+}
+
+/// Returns the key by which siblings are sorted for painting and hit-testing.
+pub(crate) fn z_order_key(props: PropertiesRef<'_>) -> (ZIndex, Elevation) {
+    (*props.get::<ZIndex>(), *props.get::<Elevation>())
+}
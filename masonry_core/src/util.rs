@@ -5,6 +5,7 @@
 
 use vello::Scene;
 use vello::kurbo::{Affine, Join, Shape, Stroke};
+use vello::peniko::color::LinearSrgb;
 use vello::peniko::{BrushRef, Color, Fill};
 
 /// Panic in debug and `tracing::error` in release mode.
@@ -161,6 +162,34 @@ pub fn bounding_box_to_rect(bb: parley::BoundingBox) -> vello::kurbo::Rect {
     }
 }
 
+// --- MARK: COLOR HELPERS
+
+/// Returns the relative luminance of `color`, from 0 for black to 1 for white, as defined
+/// by the [WCAG](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance).
+///
+/// The alpha of `color` is ignored.
+pub fn relative_luminance(color: Color) -> f64 {
+    let [r, g, b, _] = color.convert::<LinearSrgb>().components;
+    f64::from(0.2126 * r + 0.7152 * g + 0.0722 * b)
+}
+
+/// Returns the contrast ratio between two opaque colors, from 1 to 21, as defined by
+/// the [WCAG](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio).
+///
+/// Text should have a contrast ratio of at least 4.5 with its background to be readable.
+pub fn contrast_ratio(a: Color, b: Color) -> f64 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Returns `top` painted over the opaque color `bottom`, blending in sRGB like the renderer.
+pub fn composite_over(top: Color, bottom: Color) -> Color {
+    let [r, g, b, alpha] = top.components;
+    let [bottom_r, bottom_g, bottom_b, _] = bottom.components;
+    let mix = |top: f32, bottom: f32| top * alpha + bottom * (1. - alpha);
+    Color::new([mix(r, bottom_r), mix(g, bottom_g), mix(b, bottom_b), 1.])
+}
+
 // ---
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -169,6 +198,36 @@ pub use std::time::{Duration, Instant};
 #[cfg(target_arch = "wasm32")]
 pub use web_time::{Duration, Instant};
 
+/// A curve which maps the linear progress of an animation to the progress of the animated value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Easing {
+    /// The value changes at a constant speed.
+    Linear,
+    /// The value starts slowly and speeds up until the end.
+    EaseIn,
+    /// The value starts quickly and slows down towards the end.
+    EaseOut,
+    /// The value starts slowly, speeds up, and slows down towards the end.
+    #[default]
+    EaseInOut,
+}
+
+impl Easing {
+    /// Returns the eased progress for the linear `progress`, which is clamped between 0 and 1.
+    ///
+    /// The eased progress is 0 at the start and 1 at the end.
+    pub fn apply(self, progress: f64) -> f64 {
+        let t = progress.clamp(0., 1.);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1. - (1. - t).powi(3),
+            Self::EaseInOut if t < 0.5 => 4. * t * t * t,
+            Self::EaseInOut => 1. - (2. - 2. * t).powi(3) / 2.,
+        }
+    }
+}
+
 // ---
 
 static DEBUG_COLOR: &[Color] = &[
@@ -0,0 +1,45 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compares a directory of PNG screenshots against a baseline directory, and saves images
+//! highlighting their differences.
+//!
+//! ```text
+//! Usage: masonry_visual_diff <SNAPSHOTS_DIR> [--baseline <DIR>] [--output <DIR>] [--filter <TEXT>]
+//!     [--bless] [--tolerance <N>] [--perceptual-tolerance <N>] [--max-different-pixels <N>]
+//! ```
+//!
+//! Screenshots are usually captured by running a `VisualSuite` of the app's screens.
+//! The command fails if a screenshot differs from the baseline or has no baseline,
+//! unless `--bless` is passed, in which case the screenshots replace the baseline.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use masonry_testing::{RegressionOptions, diff_directory};
+
+const USAGE: &str = "Usage: masonry_visual_diff <SNAPSHOTS_DIR> [--baseline <DIR>] [--output <DIR>] \
+    [--filter <TEXT>] [--bless] [--tolerance <N>] [--perceptual-tolerance <N>] [--max-different-pixels <N>]";
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let Some(snapshots_dir) = args.next().filter(|arg| !arg.starts_with("--")) else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+    let options = match RegressionOptions::from_args(args) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{err}. {USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match diff_directory(&PathBuf::from(snapshots_dir), &options) {
+        Ok(report) => report.print_and_exit_code(),
+        Err(err) => {
+            eprintln!("Failed to compare the screenshots: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
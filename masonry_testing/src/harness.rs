@@ -12,26 +12,27 @@ use std::path::PathBuf;
 use std::sync::{Arc, mpsc};
 use std::time::UNIX_EPOCH;
 
-use image::{DynamicImage, ImageFormat, ImageReader, Rgba, RgbaImage};
-use oxipng::{Options, optimize_from_memory};
+use image::{DynamicImage, ImageFormat, ImageReader, Rgb, RgbImage, Rgba, RgbaImage};
 use tracing::debug;
 
 use masonry_core::accesskit::{Action, ActionRequest, Node, Role, Tree, TreeUpdate};
 use masonry_core::anymore::AnyDebug;
 use masonry_core::app::{
-    RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy, try_init_test_tracing,
+    AccessibilityAudit, AuditOptions, EventTrace, FrameDiagnostics, RenderRoot, RenderRootOptions,
+    RenderRootSignal, RenderedImage, WidgetProfile, WindowSizePolicy, try_init_test_tracing,
 };
 use masonry_core::core::keyboard::{Code, Key, KeyState, NamedKey};
 use masonry_core::core::{
-    CursorIcon, DefaultProperties, ErasedAction, FromDynWidget, Handled, Ime, KeyboardEvent,
-    Modifiers, NewWidget, PointerButton, PointerButtonEvent, PointerEvent, PointerId, PointerInfo,
-    PointerScrollEvent, PointerState, PointerType, PointerUpdate, ScrollDelta, TextEvent, Widget,
-    WidgetId, WidgetMut, WidgetRef, WidgetTag, WindowEvent,
+    CursorIcon, DefaultProperties, ErasedAction, FromDynWidget, Handled, Ime, ImePurpose,
+    KeyboardEvent, Modifiers, NewWidget, PointerButton, PointerButtonEvent, PointerEvent,
+    PointerId, PointerInfo, PointerScrollEvent, PointerState, PointerType, PointerUpdate,
+    ScrollConfig, ScrollDelta, TextEvent, Widget, WidgetId, WidgetMut, WidgetRef, WidgetTag,
+    WindowEvent,
 };
 use masonry_core::dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize};
 use masonry_core::kurbo::{Affine, Point, Rect, Size, Vec2};
-use masonry_core::peniko::{Blob, Color};
-use masonry_core::util::Duration;
+use masonry_core::peniko::{Blob, Color, ImageData};
+use masonry_core::util::{Duration, Instant};
 use masonry_core::vello::peniko::Fill;
 use masonry_core::vello::util::{RenderContext, block_on_wgpu};
 use masonry_core::vello::wgpu::{
@@ -41,7 +42,7 @@ use masonry_core::vello::wgpu::{
 };
 use masonry_core::vello::{self, Scene};
 
-use crate::screenshots::get_image_diff;
+use crate::screenshots::{encode_png, get_image_diff};
 use crate::{Record, Recorder};
 
 /// A [`PointerInfo`] for a primary mouse, for testing.
@@ -143,12 +144,17 @@ pub struct TestHarness<W: Widget> {
     root_padding: u32,
     background_color: Color,
     panic_on_rewrite_saturation: bool,
+    scale_factor: f64,
     screenshot_tolerance: u32,
+    perceptual_tolerance: u32,
+    max_different_pixels: u32,
     max_screenshot_size: u32,
     action_queue: VecDeque<(ErasedAction, WidgetId)>,
     has_ime_session: bool,
+    ime_purpose: ImePurpose,
     ime_rect: (LogicalPosition<f64>, LogicalSize<f64>),
     clipboard: String,
+    opened_urls: Vec<String>,
     title: String,
     _marker: PhantomData<W>,
 }
@@ -176,9 +182,30 @@ pub struct TestHarnessParams {
     ///
     /// [`assert_render_snapshot`]: crate::assert_render_snapshot
     pub root_padding: u32,
-    /// The maximum difference between two pixel channels before the harness will fail a screenshot test.
+    /// The maximum difference in any color channel between two pixels before they count as different in a screenshot test.
     /// Defaults to [`TestHarnessParams::DEFAULT_SCREENSHOT_TOLERANCE`].
     pub screenshot_tolerance: u32,
+    /// Overrides of [`screenshot_tolerance`](Self::screenshot_tolerance) on specific platforms,
+    /// as pairs of a [target OS](std::env::consts::OS) name and a tolerance.
+    /// Defaults to no overrides.
+    ///
+    /// Rendering can differ slightly between platforms, so screenshots saved on one platform
+    /// may need a higher tolerance on others.
+    pub platform_screenshot_tolerances: &'static [(&'static str, u32)],
+    /// The maximum perceptual difference between two pixels before they count as different in a screenshot test,
+    /// in addition to [`screenshot_tolerance`](Self::screenshot_tolerance).
+    /// Defaults to 0.
+    ///
+    /// Differences are measured in the YIQ color space, which weighs luma more than chroma,
+    /// and scaled so that changing every channel of a grey pixel by `n` gives a difference of `n`.
+    /// This lets tests ignore changes in hue which are hard to see, while still catching
+    /// changes in brightness.
+    pub perceptual_tolerance: u32,
+    /// The number of pixels which can differ before the harness will fail a screenshot test.
+    /// Defaults to 0.
+    ///
+    /// This lets tests ignore isolated differences, e.g. in anti-aliasing.
+    pub max_different_pixels: u32,
     /// The scale factor widgets are rendered at.
     /// Defaults to 1.0.
     pub scale_factor: f64,
@@ -211,7 +238,10 @@ pub struct TestHarnessParams {
 /// `<CRATE-ROOT>/screenshots/<TEST-NAME>.new.png`, and must be renamed before the assert will pass.
 ///
 /// You can also run tests with the `MASONRY_TEST_BLESS` flag set to `1` to assume all
-/// differences are intended and overwrite all the screenshots with new values.
+/// differences are intended and overwrite all the screenshots with new values,
+/// or set to `missing` to only save the screenshots which don't exist yet.
+///
+/// Images are compared using the tolerances set in [`TestHarnessParams`], which can include a perceptual metric.
 #[macro_export]
 macro_rules! assert_render_snapshot {
     ($test_harness:expr, $name:expr) => {
@@ -219,6 +249,21 @@ macro_rules! assert_render_snapshot {
     };
 }
 
+/// Assert a snapshot of a single widget.
+///
+/// This macro takes a test harness, a [`WidgetId`](masonry_core::core::WidgetId) and a name,
+/// and does the same thing as [`assert_render_snapshot`], but only renders the subtree rooted
+/// at the given widget, using [`TestHarness::render_widget_to_image`].
+/// The image covers the widget's bounding box, on the harness' background color.
+///
+/// [`assert_render_snapshot`]: crate::assert_render_snapshot
+#[macro_export]
+macro_rules! assert_widget_snapshot {
+    ($test_harness:expr, $widget_id:expr, $name:expr) => {
+        $test_harness.check_widget_snapshot(env!("CARGO_MANIFEST_DIR"), $widget_id, $name)
+    };
+}
+
 /// Assert a snapshot of a rendered frame of your app, expecting it to fail.
 ///
 /// This macro does essentially the same thing as [`assert_render_snapshot`], but
@@ -245,6 +290,9 @@ impl TestHarnessParams {
         background_color: Self::DEFAULT_BACKGROUND_COLOR,
         root_padding: 0,
         screenshot_tolerance: Self::DEFAULT_SCREENSHOT_TOLERANCE,
+        platform_screenshot_tolerances: &[],
+        perceptual_tolerance: 0,
+        max_different_pixels: 0,
         scale_factor: 1.0,
         panic_on_rewrite_saturation: true,
         max_screenshot_size: 8 * Self::KIBIBYTE,
@@ -362,18 +410,28 @@ impl<W: Widget> TestHarness<W> {
             window_size,
             background_color: params.background_color,
             root_padding: params.root_padding,
-            screenshot_tolerance: params.screenshot_tolerance,
+            scale_factor: params.scale_factor,
+            screenshot_tolerance: params
+                .platform_screenshot_tolerances
+                .iter()
+                .find(|(os, _)| *os == std::env::consts::OS)
+                .map_or(params.screenshot_tolerance, |(_, tolerance)| *tolerance),
+            perceptual_tolerance: params.perceptual_tolerance,
+            max_different_pixels: params.max_different_pixels,
             panic_on_rewrite_saturation: params.panic_on_rewrite_saturation,
             max_screenshot_size: params.max_screenshot_size,
             action_queue: VecDeque::new(),
             has_ime_session: false,
+            ime_purpose: ImePurpose::Normal,
             ime_rect: Default::default(),
             clipboard: String::new(),
+            opened_urls: Vec::new(),
             title: String::new(),
             _marker: PhantomData,
         };
 
         // Set up the initial state, and clear invalidation flags.
+        harness.render_root.set_base_color(harness.background_color);
         harness.process_window_event(WindowEvent::EnableAccessTree);
         harness.animate_ms(0);
 
@@ -423,6 +481,30 @@ impl<W: Widget> TestHarness<W> {
         self.process_signals();
     }
 
+    /// Starts recording the events sent to the simulated window.
+    ///
+    /// See [`RenderRoot::start_recording_events`].
+    pub fn start_recording_events(&mut self) {
+        self.render_root.start_recording_events();
+    }
+
+    /// Stops recording events, and returns the recorded trace.
+    ///
+    /// Returns `None` if no recording was ongoing.
+    pub fn stop_recording_events(&mut self) -> Option<EventTrace> {
+        self.render_root.stop_recording_events()
+    }
+
+    /// Sends every event of `trace` to the simulated window, in order.
+    ///
+    /// This will run [rewrite passes](masonry_core::doc::pass_system#rewrite-passes) after each event is processed.
+    pub fn replay_events(&mut self, trace: &EventTrace) {
+        for traced in &trace.events {
+            self.render_root.replay_event(traced.event.clone());
+            self.process_signals();
+        }
+    }
+
     // This should be ran after any operation which runs the rewrite passes
     // (i.e. processing an event, etc.)
     fn process_signals(&mut self) {
@@ -434,8 +516,9 @@ impl<W: Widget> TestHarness<W> {
                 RenderRootSignal::Action(action, widget_id) => {
                     self.action_queue.push_back((action, widget_id));
                 }
-                RenderRootSignal::StartIme => {
+                RenderRootSignal::StartIme(purpose) => {
                     self.has_ime_session = true;
+                    self.ime_purpose = purpose;
                 }
                 RenderRootSignal::EndIme => {
                     self.has_ime_session = false;
@@ -446,6 +529,9 @@ impl<W: Widget> TestHarness<W> {
                 RenderRootSignal::ClipboardStore(text) => {
                     self.clipboard = text;
                 }
+                RenderRootSignal::OpenUrl(url) => {
+                    self.opened_urls.push(url);
+                }
                 RenderRootSignal::RequestRedraw => (),
                 RenderRootSignal::RequestAnimFrame => (),
                 RenderRootSignal::TakeFocus => (),
@@ -471,11 +557,29 @@ impl<W: Widget> TestHarness<W> {
                 RenderRootSignal::RepositionLayer(root_id, new_pos) => {
                     self.render_root.reposition_layer(root_id, new_pos);
                 }
+                RenderRootSignal::RenderToImage(widget_id, scale) => {
+                    if let Some(image) = self.render_widget_to_image(widget_id, scale) {
+                        let rendered = RenderedImage { image, scale };
+                        self.action_queue.push_back((Box::new(rendered), widget_id));
+                    }
+                }
             }
         }
     }
 
     // --- MARK: RENDER
+    /// Runs the paint and accessibility passes and updates the `accesskit_consumer` tree,
+    /// without rendering the resulting scene.
+    ///
+    /// This is much cheaper than [`render`](Self::render), and is useful to measure
+    /// the cost of the passes themselves.
+    pub fn redraw(&mut self) {
+        let (_, tree_update) = self.render_root.redraw();
+        let tree_update = tree_update.unwrap();
+        self.access_tree
+            .update_and_process_changes(tree_update, &mut NoOpTreeChangeHandler);
+    }
+
     // TODO - We add way too many dependencies in this code
     // TODO - Should be async?
     /// Renders the window into an image and updates the `accesskit_consumer` tree.
@@ -495,32 +599,11 @@ impl<W: Widget> TestHarness<W> {
             return RgbaImage::from_pixel(1, 1, Rgba([255, 255, 255, 255]));
         }
 
-        let mut context = self
-            .render_context
-            .take()
-            .unwrap_or_else(RenderContext::new);
-
-        let device_id =
-            pollster::block_on(context.device(None)).expect("No compatible device found");
-        let device_handle = &mut context.devices[device_id];
+        let (context, device_id, mut renderer) = self.take_renderer();
+        let device_handle = &context.devices[device_id];
         let device = &device_handle.device;
         let queue = &device_handle.queue;
 
-        let mut renderer = self.vello_renderer.take().unwrap_or_else(|| {
-            vello::Renderer::new(
-                device,
-                vello::RendererOptions {
-                    // TODO - Examine this value
-                    use_cpu: true,
-                    num_init_threads: NonZeroUsize::new(1),
-                    // TODO - Examine this value
-                    antialiasing_support: vello::AaSupport::area_only(),
-                    ..Default::default()
-                },
-            )
-            .expect("Got non-Send/Sync error from creating renderer")
-        });
-
         let (width, height) = (self.window_size.width, self.window_size.height);
 
         let padding = self.root_padding;
@@ -532,7 +615,7 @@ impl<W: Widget> TestHarness<W> {
             base_color: self.background_color,
             width,
             height,
-            antialiasing_method: vello::AaConfig::Area,
+            antialiasing_method: self.render_root.antialiasing(),
         };
 
         let size = Extent3d {
@@ -580,6 +663,8 @@ impl<W: Widget> TestHarness<W> {
         } else {
             contents_scene
         };
+        self.render_root
+            .render_wgpu_surfaces(device, queue, &mut renderer);
         renderer
             .render_to_texture(device, queue, &scene, &view, &render_params)
             .expect("Got non-Send/Sync error from rendering");
@@ -629,6 +714,54 @@ impl<W: Widget> TestHarness<W> {
         RgbaImage::from_vec(width, height, result_unpadded).expect("failed to create image")
     }
 
+    /// Renders the subtree rooted at the given widget into an image.
+    ///
+    /// See [`RenderRoot::render_to_image`] for details.
+    pub fn render_widget_to_image(&mut self, id: WidgetId, scale: f64) -> Option<ImageData> {
+        let (context, device_id, mut renderer) = self.take_renderer();
+        let device_handle = &context.devices[device_id];
+        let image = self.render_root.render_to_image(
+            id,
+            scale,
+            &device_handle.device,
+            &device_handle.queue,
+            &mut renderer,
+        );
+        self.render_context = Some(context);
+        self.vello_renderer = Some(renderer);
+        image
+    }
+
+    /// Takes the cached render context and Vello renderer, or creates them.
+    ///
+    /// Callers should put them back once they're done.
+    fn take_renderer(&mut self) -> (RenderContext, usize, vello::Renderer) {
+        let mut context = self
+            .render_context
+            .take()
+            .unwrap_or_else(RenderContext::new);
+
+        let device_id =
+            pollster::block_on(context.device(None)).expect("No compatible device found");
+        let device = &context.devices[device_id].device;
+
+        let renderer = self.vello_renderer.take().unwrap_or_else(|| {
+            vello::Renderer::new(
+                device,
+                vello::RendererOptions {
+                    // TODO - Examine this value
+                    use_cpu: true,
+                    num_init_threads: NonZeroUsize::new(1),
+                    // TODO - Examine this value
+                    antialiasing_support: vello::AaSupport::area_only(),
+                    ..Default::default()
+                },
+            )
+            .expect("Got non-Send/Sync error from creating renderer")
+        });
+        (context, device_id, renderer)
+    }
+
     /// Returns a reference to the current state of the accessibility tree.
     pub fn access_tree(&self) -> &accesskit_consumer::Tree {
         &self.access_tree
@@ -875,6 +1008,24 @@ impl<W: Widget> TestHarness<W> {
         self.process_signals();
     }
 
+    /// Fires the timers requested by widgets which are due in the next `ms` milliseconds.
+    ///
+    /// Timers use the wall clock, so tests should pick `ms` well apart from the durations
+    /// of the timers.
+    pub fn run_timers_ms(&mut self, ms: u64) {
+        self.render_root
+            .run_timers(Instant::now() + Duration::from_millis(ms));
+        self.process_signals();
+    }
+
+    /// Runs the idle callbacks queued by widgets, as if the app had `budget` time to spare.
+    ///
+    /// With a zero budget, only the callbacks which timed out run.
+    pub fn run_idle_callbacks(&mut self, budget: Duration) {
+        self.render_root.run_idle_callbacks(Instant::now() + budget);
+        self.process_signals();
+    }
+
     /// Helper method to directly enable/disable a widget.
     pub fn set_disabled(&mut self, widget: WidgetTag<impl Widget>, disabled: bool) {
         self.edit_widget(widget, |mut target| {
@@ -1055,6 +1206,78 @@ impl<W: Widget> TestHarness<W> {
         self.render_root.cursor_icon()
     }
 
+    /// Returns the regions of the window which changed in the last [render](Self::render).
+    ///
+    /// See [`RenderRoot::frame_damage`] for details.
+    pub fn damage(&self) -> &[Rect] {
+        self.render_root.frame_damage()
+    }
+
+    /// Sets whether to record which widgets request work for each frame.
+    ///
+    /// See [`RenderRoot::set_frame_diagnostics`] for details.
+    pub fn set_frame_diagnostics(&mut self, enabled: bool) {
+        self.render_root.set_frame_diagnostics(enabled);
+    }
+
+    /// Returns the recorded frame diagnostics, or `None` if they're disabled.
+    pub fn frame_diagnostics(&self) -> Option<&FrameDiagnostics> {
+        self.render_root.frame_diagnostics()
+    }
+
+    /// Sets whether to record how much time each widget takes in the layout and paint passes.
+    ///
+    /// See [`RenderRoot::set_widget_profiling`] for details.
+    pub fn set_widget_profiling(&mut self, enabled: bool) {
+        self.render_root.set_widget_profiling(enabled);
+    }
+
+    /// Returns the recorded widget timings, or `None` if widget profiling is disabled.
+    pub fn widget_profile(&self) -> Option<&WidgetProfile> {
+        self.render_root.widget_profile()
+    }
+
+    /// Enables the accessibility audit with the given options, or disables it if `None`.
+    ///
+    /// See [`RenderRoot::set_accessibility_audit`] for details.
+    pub fn set_accessibility_audit(&mut self, options: Option<AuditOptions>) {
+        self.render_root.set_accessibility_audit(options);
+    }
+
+    /// Returns the issues found by the last accessibility audit, or `None` if it's disabled.
+    pub fn accessibility_audit(&self) -> Option<&AccessibilityAudit> {
+        self.render_root.accessibility_audit()
+    }
+
+    /// Sets how scroll events move scrollable widgets.
+    ///
+    /// See [`RenderRoot::set_scroll_config`] for details.
+    pub fn set_scroll_config(&mut self, config: ScrollConfig) {
+        self.render_root.set_scroll_config(config);
+    }
+
+    /// Sets the root font size, in logical pixels, which `rem` lengths are multiples of.
+    ///
+    /// See [`RenderRoot::set_root_font_size`] for details.
+    pub fn set_root_font_size(&mut self, root_font_size: f64) {
+        self.render_root.set_root_font_size(root_font_size);
+    }
+
+    /// Sets the factor the app scales text by.
+    ///
+    /// See [`RenderRoot::set_app_text_scale`] for details.
+    pub fn set_app_text_scale(&mut self, app_text_scale: f64) {
+        self.render_root.set_app_text_scale(app_text_scale);
+    }
+
+    /// Sets the anti-aliasing method the window is rendered with.
+    ///
+    /// The harness only supports area anti-aliasing, which other methods fall back to.
+    /// See [`RenderRoot::set_antialiasing`] for details.
+    pub fn set_antialiasing(&mut self, method: vello::AaConfig) {
+        self.render_root.set_antialiasing(method);
+    }
+
     /// Returns whether the app has an IME session in progress.
     ///
     /// This usually means that a widget which [accepts text input](Widget::accepts_text_input) is focused.
@@ -1062,6 +1285,11 @@ impl<W: Widget> TestHarness<W> {
         self.has_ime_session
     }
 
+    /// Returns the [`ImePurpose`] of the last IME session which was started.
+    pub fn ime_purpose(&self) -> ImePurpose {
+        self.ime_purpose
+    }
+
     /// Returns the rectangle of the IME session.
     ///
     /// This is usually the effective border-box rectangle of the focused widget.
@@ -1076,6 +1304,12 @@ impl<W: Widget> TestHarness<W> {
         self.clipboard.clone()
     }
 
+    /// Returns the URLs which widgets have asked to [open](masonry_core::core::EventCtx::open_url),
+    /// in order, and clears them.
+    pub fn take_opened_urls(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.opened_urls)
+    }
+
     /// Returns the size of the simulated window.
     pub fn window_size(&self) -> PhysicalSize<u32> {
         self.window_size
@@ -1130,20 +1364,68 @@ impl<W: Widget> TestHarness<W> {
 
             return;
         }
+
+        let new_image: DynamicImage = self.render().into();
+        self.check_snapshot_image(manifest_dir, test_name, new_image, expect_failure);
+    }
+
+    /// Method used by [`assert_widget_snapshot`]. Use this macro, not this method.
+    ///
+    /// Renders the subtree rooted at the given widget to a pixmap, and compares the pixmap
+    /// against the snapshot stored in `<CRATE ROOT>/screenshots/<test_name>.png`.
+    ///
+    /// [`assert_widget_snapshot`]: crate::assert_widget_snapshot
+    #[doc(hidden)]
+    #[track_caller]
+    pub fn check_widget_snapshot(&mut self, manifest_dir: &str, id: WidgetId, test_name: &str) {
+        if std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            // We still redraw to get some coverage in the paint code.
+            let _ = self.render_root.redraw();
+
+            return;
+        }
+
+        let Some(image) = self.render_widget_to_image(id, self.scale_factor) else {
+            panic!(
+                "Snapshot test '{test_name}' failed: widget {id} is not in the tree or is stashed"
+            );
+        };
+
+        // The widget is rendered on a transparent background, which we replace with the
+        // harness' background color.
+        let background = self.background_color.to_rgba8().to_u8_array();
+        let new_image = RgbImage::from_fn(image.width, image.height, |x, y| {
+            let idx = (y * image.width + x) as usize * 4;
+            let pixel = &image.data.data()[idx..idx + 4];
+            let alpha = u32::from(pixel[3]);
+            Rgb(std::array::from_fn(|c| {
+                let blended =
+                    (u32::from(pixel[c]) * alpha + u32::from(background[c]) * (255 - alpha)) / 255;
+                u8::try_from(blended).unwrap()
+            }))
+        });
+
+        self.check_snapshot_image(manifest_dir, test_name, new_image.into(), false);
+    }
+
+    /// Compares `new_image` against the snapshot stored in `<CRATE ROOT>/screenshots/<test_name>.png`.
+    #[track_caller]
+    fn check_snapshot_image(
+        &self,
+        manifest_dir: &str,
+        test_name: &str,
+        new_image: DynamicImage,
+        expect_failure: bool,
+    ) {
         let max_size = Some(usize::try_from(self.max_screenshot_size).unwrap());
 
         #[track_caller]
         fn save_image(image: &DynamicImage, path: &PathBuf, max_size: Option<usize>) {
-            let mut buffer = Cursor::new(Vec::new());
-            image.write_to(&mut buffer, ImageFormat::Png).unwrap();
-
-            let image_data = buffer.into_inner();
             // Whenever we save a file, we optimise it.
             // This avoids cases where people copy the `new` file to the reference path, thus avoiding optimisation
             // (We could skip this for diff images, but that is so far off the hot path that it's not
             // worth a different code path for it.)
-            let data =
-                optimize_from_memory(image_data.as_slice(), &Options::from_preset(5)).unwrap();
+            let data = encode_png(image);
             let saved_len = data.len();
             std::fs::write(path, data).unwrap();
             if let Some(max_size) = max_size
@@ -1156,8 +1438,6 @@ impl<W: Widget> TestHarness<W> {
             }
         }
 
-        let new_image: DynamicImage = self.render().into();
-
         let screenshots_folder = PathBuf::from(manifest_dir).join("screenshots");
         std::fs::create_dir_all(&screenshots_folder).unwrap();
 
@@ -1165,10 +1445,14 @@ impl<W: Widget> TestHarness<W> {
         let new_path = screenshots_folder.join(format!("{test_name}.new.png"));
         let diff_path = screenshots_folder.join(format!("{test_name}.diff.png"));
 
-        let bless_test = std::env::var_os("MASONRY_TEST_BLESS").is_some_and(|it| !it.is_empty());
+        // `MASONRY_TEST_BLESS=missing` only saves new screenshots, any other value
+        // also overwrites the screenshots which changed.
+        let bless = std::env::var("MASONRY_TEST_BLESS").unwrap_or_default();
+        let bless_missing = !bless.is_empty();
+        let bless_changed = bless_missing && bless != "missing";
 
         let Ok(reference_file) = File::open(&reference_path) else {
-            if bless_test && !expect_failure {
+            if bless_missing && !expect_failure {
                 let _ = std::fs::remove_file(&new_path);
                 let _ = std::fs::remove_file(&diff_path);
                 save_image(&new_image, &reference_path, max_size);
@@ -1186,10 +1470,16 @@ impl<W: Widget> TestHarness<W> {
             ImageReader::with_format(BufReader::new(reference_file), ImageFormat::Png);
 
         let ref_image = reference_file.decode().unwrap().to_rgb8();
+        let image_diff = get_image_diff(
+            &ref_image,
+            &new_image.to_rgb8(),
+            self.screenshot_tolerance,
+            self.perceptual_tolerance,
+            self.max_different_pixels,
+        );
 
         if expect_failure {
-            if get_image_diff(&ref_image, &new_image.to_rgb8(), self.screenshot_tolerance).is_some()
-            {
+            if image_diff.is_some() {
                 return;
             } else {
                 panic!(
@@ -1198,10 +1488,8 @@ impl<W: Widget> TestHarness<W> {
             }
         }
 
-        if let Some(diff_image) =
-            get_image_diff(&ref_image, &new_image.to_rgb8(), self.screenshot_tolerance)
-        {
-            if bless_test {
+        if let Some(diff_image) = image_diff {
+            if bless_changed {
                 let _ = std::fs::remove_file(&new_path);
                 let _ = std::fs::remove_file(&diff_path);
                 save_image(&new_image, &reference_path, max_size);
@@ -1209,7 +1497,10 @@ impl<W: Widget> TestHarness<W> {
                 save_image(&new_image, &new_path, max_size);
                 // Don't fail if the diff file is too big!
                 save_image(&diff_image.into(), &diff_path, None);
-                panic!("Snapshot test '{test_name}' failed: Images are different");
+                panic!(
+                    "Snapshot test '{test_name}' failed: Images are different (see {})",
+                    diff_path.display()
+                );
             }
         } else {
             // Remove the vestigial new and diff images
@@ -32,9 +32,15 @@
 //! Tests using `TestHarness` can include snapshot steps by using the [`assert_render_snapshot`][] macro.
 //! This renders the application being tested, then compares it against the png file with the given name
 //! from the `screenshots` folder (in the package being tested, i.e. adjacent to its `Cargo.toml` file).
+//! The [`assert_widget_snapshot`][] macro does the same for a single widget.
+//!
+//! Images are compared with a tolerance, so that small differences in anti-aliasing don't fail tests.
+//! The tolerance can be set per test and per platform in `TestHarnessParams`, along with a perceptual tolerance which ignores changes that are hard to see.
+//! When a snapshot doesn't match, the new image and an image highlighting the differences are saved next to the reference file.
 //!
 //! Masonry Testing will update the reference file when the `MASONRY_TEST_BLESS` environment variable has a value of `1`.
 //! This can be used if the file doesn't exist, or there's an expected difference.
+//! With a value of `missing`, only reference files which don't exist yet are created.
 //! The screenshots are losslessly compressed (using [Oxipng][oxipng]) and limited to a small maximum file size (this
 //! limit has an escape hatch).
 //! This ensures that the screenshots are small enough to embed in a git repository with limited risk
@@ -44,6 +50,13 @@
 //! For repositories hosted on GitHub, this scheme also allows for including screenshots of your app or
 //! widgets in hosted documentation, although we haven't documented this publicly yet.
 //!
+//! # Visual regression testing
+//!
+//! Apps can check all their screens at once with a [`VisualSuite`][], which renders each registered screen
+//! with several themes and at several window sizes, and compares the screenshots against a baseline directory.
+//! Its `run_cli` method makes a small command line tool out of it, with a `--bless` flag to update the baseline.
+//! Screenshots captured elsewhere can be compared with the `masonry_visual_diff` binary, enabled by the `cli` feature.
+//!
 //! # Examples
 //!
 //! For examples of this crate in use
@@ -61,6 +74,7 @@ mod harness;
 mod modular_widget;
 mod recorder_widget;
 mod screenshots;
+mod visual_regression;
 mod wrapper_widget;
 
 pub use assert_any::{assert_all, assert_any, assert_none};
@@ -69,6 +83,9 @@ pub use debug_name::DebugName;
 pub use harness::{PRIMARY_MOUSE, TestHarness, TestHarnessParams};
 pub use modular_widget::ModularWidget;
 pub use recorder_widget::{Record, Recorder, Recording};
+pub use visual_regression::{
+    RegressionOptions, RegressionReport, SnapshotStatus, VisualSuite, diff_directory,
+};
 pub use wrapper_widget::WrapperWidget;
 
 use masonry_core::core::Widget;
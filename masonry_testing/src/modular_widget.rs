@@ -5,8 +5,8 @@ use std::any::TypeId;
 
 use masonry_core::accesskit::{Node, Role};
 use masonry_core::core::{
-    AccessCtx, AccessEvent, ChildrenIds, ComposeCtx, CursorIcon, EventCtx, Layer, LayoutCtx,
-    MeasureCtx, NewWidget, NoAction, PaintCtx, PointerEvent, Properties, PropertiesMut,
+    AccessCtx, AccessEvent, ChildrenIds, ComposeCtx, CursorIcon, EventCtx, ImePurpose, Layer,
+    LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx, PointerEvent, Properties, PropertiesMut,
     PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId,
     WidgetPod, WidgetRef, find_widget_under_pointer, pre_paint,
 };
@@ -44,7 +44,9 @@ pub struct ModularWidget<S> {
     icon: CursorIcon,
     accepts_pointer_interaction: bool,
     accepts_focus: bool,
+    is_roving_focus_group: bool,
     accepts_text_input: bool,
+    ime_purpose: ImePurpose,
     on_pointer_event: Option<Box<PointerEventFn<S>>>,
     on_text_event: Option<Box<TextEventFn<S>>>,
     on_access_event: Option<Box<AccessEventFn<S>>>,
@@ -74,7 +76,9 @@ impl<S> ModularWidget<S> {
             icon: CursorIcon::Default,
             accepts_pointer_interaction: true,
             accepts_focus: false,
+            is_roving_focus_group: false,
             accepts_text_input: false,
+            ime_purpose: ImePurpose::Normal,
             on_pointer_event: None,
             on_text_event: None,
             on_access_event: None,
@@ -179,11 +183,23 @@ impl<S> ModularWidget<S> {
         self
     }
 
+    /// See [`Widget::is_roving_focus_group`]
+    pub fn roving_focus_group(mut self, flag: bool) -> Self {
+        self.is_roving_focus_group = flag;
+        self
+    }
+
     /// See [`Widget::accepts_text_input`]
     pub fn accepts_text_input(mut self, flag: bool) -> Self {
         self.accepts_text_input = flag;
         self
     }
+
+    /// See [`Widget::ime_purpose`]
+    pub fn ime_purpose(mut self, purpose: ImePurpose) -> Self {
+        self.ime_purpose = purpose;
+        self
+    }
 }
 
 /// Builder methods.
@@ -479,10 +495,18 @@ impl<S: 'static> Widget for ModularWidget<S> {
         self.accepts_focus
     }
 
+    fn is_roving_focus_group(&self) -> bool {
+        self.is_roving_focus_group
+    }
+
     fn accepts_text_input(&self) -> bool {
         self.accepts_text_input
     }
 
+    fn ime_purpose(&self) -> ImePurpose {
+        self.ime_purpose
+    }
+
     fn make_trace_span(&self, id: WidgetId) -> tracing::Span {
         trace_span!("ModularWidget", id = id.trace())
     }
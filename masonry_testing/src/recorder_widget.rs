@@ -15,9 +15,10 @@ use std::rc::Rc;
 
 use masonry_core::accesskit::{Node, Role};
 use masonry_core::core::{
-    AccessCtx, AccessEvent, ChildrenIds, ComposeCtx, CursorIcon, EventCtx, Layer, LayoutCtx,
-    MeasureCtx, NewWidget, PaintCtx, PointerEvent, Properties, PropertiesMut, PropertiesRef,
-    QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetRef,
+    AccessCtx, AccessEvent, ChildrenIds, ComposeCtx, CursorIcon, EventCtx, ImePurpose, Layer,
+    LayoutCtx, MeasureCtx, NewWidget, PaintCtx, PointerEvent, Properties, PropertiesMut,
+    PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId,
+    WidgetRef,
 };
 use masonry_core::kurbo::{Axis, Point, Size};
 use masonry_core::layout::LenReq;
@@ -278,10 +279,18 @@ impl<W: Widget> Widget for Recorder<W> {
         self.child.accepts_focus()
     }
 
+    fn is_roving_focus_group(&self) -> bool {
+        self.child.is_roving_focus_group()
+    }
+
     fn accepts_text_input(&self) -> bool {
         self.child.accepts_text_input()
     }
 
+    fn ime_purpose(&self) -> ImePurpose {
+        self.child.ime_purpose()
+    }
+
     fn make_trace_span(&self, id: WidgetId) -> tracing::Span {
         self.child.make_trace_span(id)
     }
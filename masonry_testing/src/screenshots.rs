@@ -3,7 +3,10 @@
 
 //! Helper functions for writing snapshot tests and comparing images.
 
-use image::{GenericImageView as _, Pixel as _, Rgb, RgbImage};
+use std::io::Cursor;
+
+use image::{DynamicImage, GenericImageView as _, ImageFormat, Pixel as _, Rgb, RgbImage};
+use oxipng::{Options, optimize_from_memory};
 
 // Copy-pasted from kompari
 fn pixel_min_max_distance(left: Rgb<u8>, right: Rgb<u8>) -> (u8, u8) {
@@ -19,22 +22,61 @@ fn pixel_min_max_distance(left: Rgb<u8>, right: Rgb<u8>) -> (u8, u8) {
         })
 }
 
+/// Returns the perceptual distance between two pixels.
+///
+/// The difference is measured in the YIQ color space, which weighs changes in luma more than
+/// changes in chroma, as in "Measuring perceived color difference using YIQ NTSC transmission
+/// color space in mobile applications" by Kotsarenko and Ramos.
+/// It's scaled so that changing every channel of a grey pixel by `n` gives a distance of `n`.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "The distance is at most a few hundred"
+)]
+fn perceptual_distance(left: Rgb<u8>, right: Rgb<u8>) -> u32 {
+    let [r, g, b] = [0, 1, 2].map(|c| f32::from(left[c]) - f32::from(right[c]));
+
+    let y = 0.298_895_3 * r + 0.586_622_5 * g + 0.114_482_23 * b;
+    let i = 0.595_978 * r - 0.274_176_9 * g - 0.321_801_1 * b;
+    let q = 0.211_470_17 * r - 0.522_617_2 * g + 0.311_147_07 * b;
+    let delta = 0.5053 * y * y + 0.299 * i * i + 0.1957 * q * q;
+
+    // A grey change of `n` gives a delta of `0.5053 * n * n`.
+    (delta / 0.5053).sqrt().round() as u32
+}
+
+/// Returns whether two pixels count as different.
+///
+/// They do if one of their channels differs by more than `tolerance`, and their
+/// [perceptual distance](perceptual_distance) is above `perceptual_tolerance`.
+fn pixels_differ(left: Rgb<u8>, right: Rgb<u8>, tolerance: u32, perceptual_tolerance: u32) -> bool {
+    let (min, max) = pixel_min_max_distance(left, right);
+    u32::from(min.max(max)) > tolerance && perceptual_distance(left, right) > perceptual_tolerance
+}
+
+/// Compares two images, and returns an image highlighting their differences if they don't match.
+///
+/// The images match if they have the same size, and at most `max_different_pixels` pixels
+/// [differ](pixels_differ) given `tolerance` and `perceptual_tolerance`.
+///
+/// In the diff image, different pixels are red, and other pixels show a faded version
+/// of the reference image for context.
 pub(crate) fn get_image_diff(
     ref_image: &RgbImage,
     new_image: &RgbImage,
-    max_screenshot_tolerance: u32,
+    tolerance: u32,
+    perceptual_tolerance: u32,
+    max_different_pixels: u32,
 ) -> Option<RgbImage> {
     let ref_size = (ref_image.width(), ref_image.height());
     let new_size = (new_image.width(), new_image.height());
 
-    let mut max_distance: u32 = 0;
-    for (p1, p2) in ref_image.pixels().zip(new_image.pixels()) {
-        let (diff_min, diff_max) = pixel_min_max_distance(*p1, *p2);
-        let new_max = std::cmp::max(diff_max, diff_min) as u32;
-        max_distance = std::cmp::max(max_distance, new_max);
-    }
+    let different_pixels = ref_image
+        .pixels()
+        .zip(new_image.pixels())
+        .filter(|(p1, p2)| pixels_differ(**p1, **p2, tolerance, perceptual_tolerance))
+        .count();
 
-    if max_distance <= max_screenshot_tolerance && ref_size == new_size {
+    if ref_size == new_size && different_pixels <= max_different_pixels as usize {
         return None;
     }
 
@@ -42,26 +84,29 @@ pub(crate) fn get_image_diff(
     let height = std::cmp::max(ref_image.height(), new_image.height());
 
     let diff_image = RgbImage::from_fn(width, height, |x, y| {
-        let ref_pixel = if ref_image.in_bounds(x, y) {
-            *ref_image.get_pixel(x, y)
-        } else {
-            [0, 0, 0].into()
-        };
-        let new_pixel = if new_image.in_bounds(x, y) {
-            *new_image.get_pixel(x, y)
-        } else {
-            [255, 255, 255].into()
-        };
-
-        let (diff_min, diff_max) = pixel_min_max_distance(ref_pixel, new_pixel);
-        let diff_abs = std::cmp::max(diff_min, diff_max);
+        if !ref_image.in_bounds(x, y) || !new_image.in_bounds(x, y) {
+            return [255, 0, 0].into();
+        }
+        let ref_pixel = *ref_image.get_pixel(x, y);
+        let new_pixel = *new_image.get_pixel(x, y);
 
-        if diff_abs as u32 > max_screenshot_tolerance {
-            new_pixel
+        if pixels_differ(ref_pixel, new_pixel, tolerance, perceptual_tolerance) {
+            [255, 0, 0].into()
         } else {
-            [0, 0, 0].into()
+            // Blend the reference's luma with white, so the differences stand out.
+            let [r, g, b] = ref_pixel.0.map(u32::from);
+            let luma = (r * 299 + g * 587 + b * 114) / 1000;
+            let faded = 255 - (255 - luma) / 4;
+            [u8::try_from(faded).unwrap(); 3].into()
         }
     });
 
     Some(diff_image)
 }
+
+/// Encodes `image` as a PNG file, losslessly compressed with [Oxipng](oxipng).
+pub(crate) fn encode_png(image: &DynamicImage) -> Vec<u8> {
+    let mut buffer = Cursor::new(Vec::new());
+    image.write_to(&mut buffer, ImageFormat::Png).unwrap();
+    optimize_from_memory(buffer.get_ref(), &Options::from_preset(5)).unwrap()
+}
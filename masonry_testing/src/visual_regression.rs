@@ -0,0 +1,426 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Visual regression testing of an app's screens against a baseline directory.
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use image::{DynamicImage, ImageReader, RgbImage};
+
+use masonry_core::core::{DefaultProperties, NewWidget, Widget};
+use masonry_core::kurbo::Size;
+
+use crate::TestHarness;
+use crate::harness::TestHarnessParams;
+use crate::screenshots::{encode_png, get_image_diff};
+
+/// The command line usage of [`VisualSuite::run_cli`].
+const USAGE: &str = "[--baseline <DIR>] [--output <DIR>] [--filter <TEXT>] [--bless] \
+    [--tolerance <N>] [--perceptual-tolerance <N>] [--max-different-pixels <N>]";
+
+type RenderFn = Box<dyn Fn(DefaultProperties, TestHarnessParams) -> RgbImage>;
+type ThemeFn = Box<dyn Fn() -> DefaultProperties>;
+
+/// A set of an app's screens, rendered headlessly at several window sizes and with several
+/// themes, and compared against a baseline directory of screenshots.
+///
+/// This brings the screenshot tests Masonry uses for its own widgets to apps, without writing
+/// a test per screen, size and theme.
+/// Each screen is rendered in a [`TestHarness`] for each combination of theme and window size,
+/// and saved as `<screen>-<theme>-<width>x<height>.png`.
+///
+/// ```no_run
+/// # use masonry_core::core::{DefaultProperties, NewWidget, Widget};
+/// # use masonry_testing::{ModularWidget, VisualSuite};
+/// # use masonry_core::kurbo::Size;
+/// # fn home_screen() -> NewWidget<impl Widget> { NewWidget::new(ModularWidget::new(())) }
+/// fn main() -> std::process::ExitCode {
+///     VisualSuite::new()
+///         .with_theme("default", DefaultProperties::new)
+///         .with_window_size(Size::new(360., 640.))
+///         .with_window_size(Size::new(1280., 800.))
+///         .with_screen("home", home_screen)
+///         .run_cli()
+/// }
+/// ```
+///
+/// Screenshots of screens which don't have a baseline yet, or which differ from it,
+/// make the suite fail, as in snapshot tests.
+/// Run it with `--bless` to save the screenshots as the new baseline.
+pub struct VisualSuite {
+    screens: Vec<(String, RenderFn)>,
+    themes: Vec<(String, ThemeFn)>,
+    window_sizes: Vec<Size>,
+    params: TestHarnessParams,
+}
+
+impl VisualSuite {
+    /// Creates an empty suite.
+    pub fn new() -> Self {
+        Self {
+            screens: Vec::new(),
+            themes: Vec::new(),
+            window_sizes: Vec::new(),
+            params: TestHarnessParams::DEFAULT,
+        }
+    }
+
+    /// Adds a screen, built by `build` for each theme and window size.
+    pub fn with_screen<W: Widget>(
+        mut self,
+        name: impl Into<String>,
+        build: impl Fn() -> NewWidget<W> + 'static,
+    ) -> Self {
+        let render: RenderFn = Box::new(move |default_props, params| {
+            let image = TestHarness::create_with(default_props, build(), params).render();
+            DynamicImage::ImageRgba8(image).to_rgb8()
+        });
+        self.screens.push((name.into(), render));
+        self
+    }
+
+    /// Adds a theme, whose default properties are created by `default_props`.
+    ///
+    /// Without themes, screens are rendered with empty [`DefaultProperties`].
+    pub fn with_theme(
+        mut self,
+        name: impl Into<String>,
+        default_props: impl Fn() -> DefaultProperties + 'static,
+    ) -> Self {
+        self.themes.push((name.into(), Box::new(default_props)));
+        self
+    }
+
+    /// Adds a window size the screens are rendered at.
+    ///
+    /// Without window sizes, screens are rendered at the `window_size` of the
+    /// [harness parameters](Self::with_params).
+    pub fn with_window_size(mut self, size: Size) -> Self {
+        self.window_sizes.push(size);
+        self
+    }
+
+    /// Sets the parameters of the harnesses the screens are rendered in, such as their
+    /// background color and scale factor.
+    ///
+    /// Their screenshot tolerances are ignored, in favor of those of the [`RegressionOptions`].
+    pub fn with_params(mut self, params: TestHarnessParams) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Renders every screen with every theme at every window size, and compares the
+    /// screenshots against the baseline.
+    ///
+    /// The screenshots are saved in the output directory, along with images highlighting
+    /// their differences from the baseline.
+    pub fn run(&self, options: &RegressionOptions) -> io::Result<RegressionReport> {
+        std::fs::create_dir_all(&options.output_dir)?;
+        let default_theme: (String, ThemeFn) = ("default".into(), Box::new(DefaultProperties::new));
+        let themes = if self.themes.is_empty() {
+            std::slice::from_ref(&default_theme)
+        } else {
+            &self.themes[..]
+        };
+        let window_sizes = if self.window_sizes.is_empty() {
+            std::slice::from_ref(&self.params.window_size)
+        } else {
+            &self.window_sizes[..]
+        };
+
+        let mut report = RegressionReport::default();
+        for (screen, render) in &self.screens {
+            for (theme, default_props) in themes {
+                for size in window_sizes {
+                    let name = format!("{screen}-{theme}-{}x{}", size.width, size.height);
+                    if !options.matches(&name) {
+                        continue;
+                    }
+                    let params = TestHarnessParams {
+                        window_size: *size,
+                        ..self.params
+                    };
+                    let image = render(default_props(), params);
+                    let path = options.output_dir.join(format!("{name}.png"));
+                    std::fs::write(path, encode_png(&image.clone().into()))?;
+                    let status = compare_snapshot(&name, &image, options)?;
+                    report.snapshots.push((name, status));
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Runs the suite with options parsed from the command line arguments, prints the report,
+    /// and returns whether it passed.
+    ///
+    /// This is meant to be returned from the `main` function of a binary or example
+    /// registering the app's screens.
+    /// The arguments are those of [`RegressionOptions::from_args`].
+    pub fn run_cli(&self) -> ExitCode {
+        let options = match RegressionOptions::from_args(std::env::args().skip(1)) {
+            Ok(options) => options,
+            Err(err) => {
+                eprintln!("{err}. Usage: {USAGE}");
+                return ExitCode::FAILURE;
+            }
+        };
+        match self.run(&options) {
+            Ok(report) => report.print_and_exit_code(),
+            Err(err) => {
+                eprintln!("Visual regression testing failed: {err}");
+                ExitCode::FAILURE
+            }
+        }
+    }
+}
+
+impl Default for VisualSuite {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for VisualSuite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let screens: Vec<&str> = self.screens.iter().map(|(name, _)| name.as_str()).collect();
+        let themes: Vec<&str> = self.themes.iter().map(|(name, _)| name.as_str()).collect();
+        f.debug_struct("VisualSuite")
+            .field("screens", &screens)
+            .field("themes", &themes)
+            .field("window_sizes", &self.window_sizes)
+            .field("params", &self.params)
+            .finish()
+    }
+}
+
+/// Where the screenshots of a visual regression run are read from and saved to, and how
+/// they are compared.
+#[derive(Clone, Debug)]
+pub struct RegressionOptions {
+    /// The directory of the reference screenshots.
+    pub baseline_dir: PathBuf,
+    /// The directory the new screenshots and the diff images are saved to.
+    pub output_dir: PathBuf,
+    /// Whether the new screenshots replace the baseline instead of being compared with it.
+    pub bless: bool,
+    /// If set, only the screenshots whose name contains this text are checked.
+    pub filter: Option<String>,
+    /// The maximum difference in any color channel between two pixels before they count
+    /// as different.
+    ///
+    /// See [`TestHarnessParams::screenshot_tolerance`].
+    pub tolerance: u32,
+    /// The maximum perceptual difference between two pixels before they count as different.
+    ///
+    /// See [`TestHarnessParams::perceptual_tolerance`].
+    pub perceptual_tolerance: u32,
+    /// The number of pixels which can differ before a screenshot counts as changed.
+    pub max_different_pixels: u32,
+}
+
+impl RegressionOptions {
+    /// Creates options comparing screenshots in `output_dir` with those in `baseline_dir`,
+    /// with the default tolerances of screenshot tests.
+    pub fn new(baseline_dir: impl Into<PathBuf>, output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            baseline_dir: baseline_dir.into(),
+            output_dir: output_dir.into(),
+            bless: false,
+            filter: None,
+            tolerance: TestHarnessParams::DEFAULT_SCREENSHOT_TOLERANCE,
+            perceptual_tolerance: 0,
+            max_different_pixels: 0,
+        }
+    }
+
+    /// Parses options from command line arguments.
+    ///
+    /// ```text
+    /// [--baseline <DIR>] [--output <DIR>] [--filter <TEXT>] [--bless]
+    /// [--tolerance <N>] [--perceptual-tolerance <N>] [--max-different-pixels <N>]
+    /// ```
+    ///
+    /// The baseline directory defaults to `visual_baseline`, and the output directory
+    /// to `target/visual_regression`.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::new("visual_baseline", "target/visual_regression");
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = |what: &str| args.next().ok_or_else(|| format!("{arg} expects {what}"));
+            let number = |value: String| {
+                value
+                    .parse()
+                    .map_err(|_| format!("{arg} expects a number, not '{value}'"))
+            };
+            match arg.as_str() {
+                "--baseline" => options.baseline_dir = value("a directory")?.into(),
+                "--output" => options.output_dir = value("a directory")?.into(),
+                "--filter" => options.filter = Some(value("some text")?),
+                "--bless" => options.bless = true,
+                "--tolerance" => options.tolerance = number(value("a number")?)?,
+                "--perceptual-tolerance" => {
+                    options.perceptual_tolerance = number(value("a number")?)?;
+                }
+                "--max-different-pixels" => {
+                    options.max_different_pixels = number(value("a number")?)?;
+                }
+                _ => return Err(format!("Unknown argument '{arg}'")),
+            }
+        }
+        Ok(options)
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        self.filter
+            .as_ref()
+            .is_none_or(|filter| name.contains(filter))
+    }
+}
+
+/// The result of comparing a screenshot with the baseline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnapshotStatus {
+    /// The screenshot matches the baseline.
+    Matched,
+    /// The screenshot differs from the baseline.
+    Changed,
+    /// There is no baseline for the screenshot.
+    Missing,
+    /// The screenshot was saved as the baseline, because the options bless new screenshots.
+    Blessed,
+}
+
+/// The results of a visual regression run, from [`VisualSuite::run`] or [`diff_directory`].
+#[derive(Clone, Debug, Default)]
+pub struct RegressionReport {
+    /// The name of each screenshot, with the result of its comparison.
+    pub snapshots: Vec<(String, SnapshotStatus)>,
+}
+
+impl RegressionReport {
+    /// Returns `true` if no screenshot changed or is missing from the baseline.
+    pub fn passed(&self) -> bool {
+        self.snapshots
+            .iter()
+            .all(|(_, status)| matches!(status, SnapshotStatus::Matched | SnapshotStatus::Blessed))
+    }
+
+    /// Prints the report to stdout, and returns the exit code of a command line tool
+    /// running the comparison.
+    pub fn print_and_exit_code(&self) -> ExitCode {
+        println!("{self}");
+        if self.passed() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        }
+    }
+}
+
+impl fmt::Display for RegressionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, status) in &self.snapshots {
+            if *status != SnapshotStatus::Matched {
+                writeln!(f, "{name}: {status:?}")?;
+            }
+        }
+        let count = |status| self.snapshots.iter().filter(|(_, s)| *s == status).count();
+        write!(
+            f,
+            "{} screenshots: {} matched, {} changed, {} missing, {} blessed",
+            self.snapshots.len(),
+            count(SnapshotStatus::Matched),
+            count(SnapshotStatus::Changed),
+            count(SnapshotStatus::Missing),
+            count(SnapshotStatus::Blessed),
+        )
+    }
+}
+
+/// Compares the PNG screenshots in `snapshots_dir` with those of the same name in the
+/// baseline directory.
+///
+/// This checks screenshots captured elsewhere, e.g. by the CI runs of an app, and is what
+/// the `masonry_visual_diff` binary does.
+/// The diff images are saved in the output directory.
+pub fn diff_directory(
+    snapshots_dir: &Path,
+    options: &RegressionOptions,
+) -> io::Result<RegressionReport> {
+    std::fs::create_dir_all(&options.output_dir)?;
+    let mut names = Vec::new();
+    for entry in std::fs::read_dir(snapshots_dir)? {
+        let file_name = entry?.file_name();
+        let Some(name) = file_name
+            .to_str()
+            .and_then(|name| name.strip_suffix(".png"))
+        else {
+            continue;
+        };
+        if !name.ends_with(".diff") && options.matches(name) {
+            names.push(name.to_string());
+        }
+    }
+    names.sort();
+
+    let mut report = RegressionReport::default();
+    for name in names {
+        let image = read_png(&snapshots_dir.join(format!("{name}.png")))?;
+        let status = compare_snapshot(&name, &image, options)?;
+        report.snapshots.push((name, status));
+    }
+    Ok(report)
+}
+
+fn read_png(path: &Path) -> io::Result<RgbImage> {
+    let image = ImageReader::open(path)?
+        .decode()
+        .map_err(io::Error::other)?;
+    Ok(image.to_rgb8())
+}
+
+/// Compares the screenshot `image` with the baseline of the same `name`, saving a diff image
+/// in the output directory if they differ, or saving it as the baseline when blessing.
+fn compare_snapshot(
+    name: &str,
+    image: &RgbImage,
+    options: &RegressionOptions,
+) -> io::Result<SnapshotStatus> {
+    let baseline_path = options.baseline_dir.join(format!("{name}.png"));
+    let diff_path = options.output_dir.join(format!("{name}.diff.png"));
+    // Remove the diff image of a previous run.
+    let _ = std::fs::remove_file(&diff_path);
+
+    let diff = if baseline_path.exists() {
+        let baseline = read_png(&baseline_path)?;
+        match get_image_diff(
+            &baseline,
+            image,
+            options.tolerance,
+            options.perceptual_tolerance,
+            options.max_different_pixels,
+        ) {
+            Some(diff) => Some(diff),
+            None => return Ok(SnapshotStatus::Matched),
+        }
+    } else {
+        None
+    };
+
+    if options.bless {
+        std::fs::create_dir_all(&options.baseline_dir)?;
+        std::fs::write(&baseline_path, encode_png(&image.clone().into()))?;
+        return Ok(SnapshotStatus::Blessed);
+    }
+    match diff {
+        Some(diff) => {
+            std::fs::write(&diff_path, encode_png(&diff.into()))?;
+            Ok(SnapshotStatus::Changed)
+        }
+        None => Ok(SnapshotStatus::Missing),
+    }
+}
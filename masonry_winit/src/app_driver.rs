@@ -125,6 +125,18 @@ pub trait AppDriver {
         ctx.exit();
     }
 
+    /// A hook called when a window entered or left fullscreen mode, whether the app, the user
+    /// or the platform changed it.
+    ///
+    /// See [`Window::set_fullscreen`](crate::app::Window::set_fullscreen).
+    fn on_fullscreen_changed(
+        &mut self,
+        window_id: WindowId,
+        ctx: &mut DriverCtx<'_, '_>,
+        fullscreen: bool,
+    ) {
+    }
+
     /// Called when Masonry has created its WGPU device.
     fn on_wgpu_ready(&mut self, _wgpu: &WgpuContext<'_>) {}
 }
@@ -1,40 +1,56 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::Debug;
+use std::path::PathBuf;
 use std::sync::mpsc::Sender;
 use std::sync::{Arc, mpsc};
 
 use accesskit_winit::Adapter;
 use copypasta::nop_clipboard::NopClipboardContext;
 use copypasta::{ClipboardContext, ClipboardProvider};
-use masonry_core::app::{RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy};
-use masonry_core::core::keyboard::{Key, KeyState};
+use masonry_core::app::{
+    EventTrace, RenderRoot, RenderRootOptions, RenderRootSignal, RenderedImage, TracedEvent,
+    WindowSizePolicy,
+};
+use masonry_core::core::keyboard::{Key, KeyState, NamedKey};
 use masonry_core::core::{
-    DefaultProperties, ErasedAction, NewWidget, TextEvent, Widget, WindowEvent,
+    DefaultProperties, ErasedAction, ImePurpose, NewWidget, TextEvent, Widget, WindowEvent,
 };
-use masonry_core::kurbo::Affine;
+use masonry_core::kurbo::{Affine, Rect};
 use masonry_core::peniko::Color;
-use masonry_core::util::Instant;
+use masonry_core::util::{Duration, Instant};
 use masonry_core::vello::{
     AaConfig, AaSupport, RenderParams, Renderer, RendererOptions, Scene, wgpu,
 };
 use tracing::{info, info_span, trace};
 use ui_events_winit::{WindowEventReducer, WindowEventTranslation};
 use winit::application::ApplicationHandler;
-use winit::dpi::PhysicalSize;
+use winit::dpi::{LogicalSize, PhysicalPosition, PhysicalSize, Size};
 use winit::error::EventLoopError;
 use winit::event::{DeviceEvent as WinitDeviceEvent, DeviceId, WindowEvent as WinitWindowEvent};
-use winit::event_loop::ActiveEventLoop;
-use winit::window::{Window as WindowHandle, WindowAttributes, WindowId as HandleId};
+use winit::event_loop::{ActiveEventLoop, ControlFlow};
+use winit::window::{
+    CursorGrabMode, Fullscreen, ImePurpose as WinitImePurpose, Window as WindowHandle,
+    WindowAttributes, WindowId as HandleId,
+};
 
 use crate::app::{
-    AppDriver, DriverCtx, WgpuContext, WgpuLimits, masonry_resize_direction_to_winit,
-    winit_ime_to_masonry,
+    AppDriver, DriverCtx, OutputColorSpace, WgpuContext, WgpuLimits,
+    masonry_resize_direction_to_winit, winit_ime_to_masonry,
 };
 use crate::app_driver::WindowId;
-use crate::vello_util::{RenderContext, RenderSurface};
+use crate::vello_util::{RenderContext, RenderSurface, create_damage_target};
+use crate::warm_up::{WARM_UP_SIZE, WARM_UP_TEXT, WarmUp, WarmUpStage, shapes_scene};
+
+/// The longest time idle callbacks run for at once, so that events are still handled promptly.
+const IDLE_SLICE: Duration = Duration::from_millis(8);
+
+/// The text scales the zoom shortcuts step through, like the zoom levels of web browsers.
+const ZOOM_LEVELS: [f64; 13] = [
+    0.5, 0.67, 0.75, 0.8, 0.9, 1.0, 1.1, 1.25, 1.5, 1.75, 2.0, 2.5, 3.0,
+];
 
 /// The custom event type that we inject into winit's [`EventLoop`](winit::event_loop::EventLoop).
 ///
@@ -78,6 +94,32 @@ pub struct NewWindow {
     pub root_widget: NewWidget<dyn Widget>,
     /// The base color of the window.
     pub base_color: Color,
+    /// How often the window renders animation frames.
+    pub frame_pacing: FramePacing,
+    /// When the window renders frames at a reduced resolution.
+    pub render_scale: RenderScale,
+    /// The color space the window's frames are presented in.
+    ///
+    /// See [`NewWindow::with_output_color_space`].
+    pub output_color_space: OutputColorSpace,
+    /// The monitor the window opens on, if any.
+    ///
+    /// See [`NewWindow::with_monitor`].
+    pub monitor: Option<usize>,
+    /// Whether pressing Escape makes the window leave fullscreen mode.
+    ///
+    /// See [`NewWindow::with_exit_fullscreen_on_escape`].
+    pub exit_fullscreen_on_escape: bool,
+    /// How the window behaves as a kiosk.
+    pub kiosk_mode: KioskMode,
+    /// The file the window's input events are recorded to, if any.
+    ///
+    /// See [`NewWindow::with_event_recording`].
+    pub event_recording: Option<PathBuf>,
+    /// The input events replayed in the window, if any.
+    ///
+    /// See [`NewWindow::with_event_replay`].
+    pub event_replay: Option<EventTrace>,
 }
 
 impl NewWindow {
@@ -105,6 +147,14 @@ impl NewWindow {
             attributes,
             root_widget,
             base_color: Color::BLACK,
+            frame_pacing: FramePacing::DEFAULT,
+            render_scale: RenderScale::DEFAULT,
+            output_color_space: OutputColorSpace::Srgb,
+            monitor: None,
+            exit_fullscreen_on_escape: true,
+            kiosk_mode: KioskMode::DEFAULT,
+            event_recording: None,
+            event_replay: None,
         }
     }
 
@@ -120,6 +170,226 @@ impl NewWindow {
         self.base_color = base_color;
         self
     }
+
+    /// Sets how often the new window renders animation frames.
+    pub fn with_frame_pacing(mut self, frame_pacing: FramePacing) -> Self {
+        self.frame_pacing = frame_pacing;
+        self
+    }
+
+    /// Sets when the new window renders frames at a reduced resolution.
+    pub fn with_render_scale(mut self, render_scale: RenderScale) -> Self {
+        self.render_scale = render_scale;
+        self
+    }
+
+    /// Sets the color space the new window's frames are presented in.
+    ///
+    /// Apps should pick [`OutputColorSpace::DisplayP3`] or
+    /// [`OutputColorSpace::ExtendedLinearSrgb`] for windows on wide-gamut or HDR displays,
+    /// so that sRGB colors aren't oversaturated.
+    pub fn with_output_color_space(mut self, output_color_space: OutputColorSpace) -> Self {
+        self.output_color_space = output_color_space;
+        self
+    }
+
+    /// Opens the new window on the monitor at `index` in
+    /// [`ActiveEventLoop::available_monitors`], instead of the one the platform picks.
+    ///
+    /// The window is centered on the monitor, unless its attributes have a position.
+    /// A borderless fullscreen window without a monitor goes fullscreen on that monitor.
+    /// If there is no such monitor, the platform picks one and a warning is logged.
+    pub fn with_monitor(mut self, index: usize) -> Self {
+        self.monitor = Some(index);
+        self
+    }
+
+    /// Sets whether pressing Escape makes the new window leave fullscreen mode.
+    ///
+    /// This is `true` by default.
+    /// Media players and kiosk apps which handle Escape themselves can set this to `false`.
+    pub fn with_exit_fullscreen_on_escape(mut self, exit_fullscreen_on_escape: bool) -> Self {
+        self.exit_fullscreen_on_escape = exit_fullscreen_on_escape;
+        self
+    }
+
+    /// Sets how the new window behaves as a kiosk.
+    pub fn with_kiosk_mode(mut self, kiosk_mode: KioskMode) -> Self {
+        self.kiosk_mode = kiosk_mode;
+        self
+    }
+
+    /// Records the input events of the new window, and writes them to `path` when it closes
+    /// or the app exits.
+    ///
+    /// The file contains an [`EventTrace`] serialized as JSON lines, which can be replayed
+    /// with [`with_event_replay`](Self::with_event_replay) or
+    /// [`RenderRoot::replay_events`](masonry_core::app::RenderRoot::replay_events).
+    pub fn with_event_recording(mut self, path: impl Into<PathBuf>) -> Self {
+        self.event_recording = Some(path.into());
+        self
+    }
+
+    /// Replays `trace` in the new window, with the timing the events were recorded with.
+    ///
+    /// While replaying, the window ignores live pointer, keyboard and IME input, and
+    /// animation frames are driven by the trace instead of the wall clock.
+    pub fn with_event_replay(mut self, trace: EventTrace) -> Self {
+        self.event_replay = Some(trace);
+        self
+    }
+}
+
+/// How often a window renders [animation frames](masonry_core::core::Widget::on_anim_frame).
+///
+/// Masonry only renders a window when its content changed, so an idle window doesn't use
+/// any power.
+/// While widgets are animated, frames are rendered at the display's refresh rate by default:
+/// these settings lower that rate, e.g. to save battery in background windows.
+///
+/// Frames requested for other reasons, such as user input, are never delayed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FramePacing {
+    /// The maximum animation frame rate while the window is focused, in frames per second.
+    ///
+    /// If `None`, animation frames are rendered at the display's refresh rate.
+    pub focused_fps: Option<f64>,
+    /// The maximum animation frame rate while the window isn't focused, in frames per second.
+    ///
+    /// If `None`, animation frames are rendered at the display's refresh rate.
+    pub background_fps: Option<f64>,
+    /// Whether animation frames are paused while the window is hidden, e.g. minimized
+    /// or fully covered by other windows.
+    pub pause_when_occluded: bool,
+}
+
+impl FramePacing {
+    /// The default frame pacing: animations run at the refresh rate while the window is
+    /// focused, at 30 frames per second otherwise, and are paused while it's hidden.
+    pub const DEFAULT: Self = Self {
+        focused_fps: None,
+        background_fps: Some(30.),
+        pause_when_occluded: true,
+    };
+
+    /// Frame pacing which never throttles or pauses animations.
+    pub const UNLIMITED: Self = Self {
+        focused_fps: None,
+        background_fps: None,
+        pause_when_occluded: false,
+    };
+
+    /// Returns the minimum time between two animation frames, if any.
+    fn frame_interval(&self, focused: bool) -> Option<Duration> {
+        let fps = if focused {
+            self.focused_fps
+        } else {
+            self.background_fps
+        };
+        fps.filter(|fps| *fps > 0.)
+            .map(|fps| Duration::from_secs_f64(fps.recip()))
+    }
+}
+
+impl Default for FramePacing {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// How a window behaves as a kiosk, such as a point-of-sale terminal or digital signage.
+///
+/// Kiosk apps usually also make their window [fullscreen](Window::set_fullscreen), without
+/// [leaving it on Escape](Window::set_exit_fullscreen_on_escape).
+/// Requests to close the window always go to [`AppDriver::on_close_requested`], which can
+/// ignore them until the app's logic confirms them.
+///
+/// Winit doesn't let apps intercept the platform's own shortcuts, such as switching
+/// windows, so these still work: platforms with a kiosk feature, such as Windows'
+/// assigned access, should be configured to disable them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KioskMode {
+    /// How long the pointer has to be idle for the cursor to be hidden.
+    ///
+    /// If `None`, the cursor is never hidden.
+    /// The cursor is shown again as soon as the pointer is moved or used.
+    pub hide_cursor_after: Option<Duration>,
+    /// Whether the pointer is kept within the window.
+    ///
+    /// This isn't supported on every platform, in which case a warning is logged.
+    pub confine_pointer: bool,
+}
+
+impl KioskMode {
+    /// The default: the window doesn't behave as a kiosk.
+    pub const DEFAULT: Self = Self {
+        hide_cursor_after: None,
+        confine_pointer: false,
+    };
+}
+
+impl Default for KioskMode {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// When a window renders frames at a reduced resolution, which is upscaled when presented.
+///
+/// This keeps heavy pages interactive on weak GPUs, at the cost of blurrier content.
+/// The resolution is reduced while the window is in [power-saving mode], and automatically
+/// while frames take longer to render than the [`frame_budget`](Self::frame_budget).
+///
+/// [power-saving mode]: Window::set_power_saving
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RenderScale {
+    /// The fraction of the window's resolution frames are rendered at in power-saving mode,
+    /// from 0 to 1.
+    pub power_saving_scale: f64,
+    /// The longest time rendering a frame should take.
+    ///
+    /// If `Some`, the resolution is lowered while frames take longer than this to render,
+    /// down to [`min_scale`](Self::min_scale), and raised back once they're well within it.
+    pub frame_budget: Option<Duration>,
+    /// The lowest fraction of the window's resolution frames are automatically rendered at,
+    /// from 0 to 1.
+    pub min_scale: f64,
+}
+
+impl RenderScale {
+    /// The default render scale: frames are rendered at half the resolution in power-saving
+    /// mode, and the resolution isn't lowered automatically.
+    pub const DEFAULT: Self = Self {
+        power_saving_scale: 0.5,
+        frame_budget: None,
+        min_scale: 0.5,
+    };
+
+    /// The amount the automatic scale changes by between frames.
+    const STEP: f64 = 0.1;
+
+    /// Returns the automatic scale following one of `frame_time`, from the current `scale`.
+    fn adjust(&self, scale: f64, frame_time: Duration) -> f64 {
+        let Some(budget) = self.frame_budget else {
+            return 1.;
+        };
+        let min_scale = self.min_scale.clamp(Self::STEP, 1.);
+        // Frames rendered well within the budget leave room to raise the resolution,
+        // without it going back and forth between two scales.
+        if frame_time > budget {
+            (scale - Self::STEP).max(min_scale)
+        } else if frame_time < budget.mul_f64(0.5) {
+            (scale + Self::STEP).min(1.)
+        } else {
+            scale
+        }
+    }
+}
+
+impl Default for RenderScale {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
 }
 
 /// Per-Window state
@@ -130,6 +400,31 @@ pub struct Window {
     event_reducer: WindowEventReducer,
     pub(crate) render_root: RenderRoot,
     pub(crate) base_color: Color,
+    frame_pacing: FramePacing,
+    render_scale: RenderScale,
+    /// Whether the window is in power-saving mode.
+    power_saving: bool,
+    /// The scale frames are rendered at to keep within the frame budget.
+    automatic_scale: f64,
+    output_color_space: OutputColorSpace,
+    is_focused: bool,
+    is_occluded: bool,
+    /// Whether the window was fullscreen when its size last changed.
+    is_fullscreen: bool,
+    exit_fullscreen_on_escape: bool,
+    kiosk_mode: KioskMode,
+    /// When the pointer last moved or was used in the window.
+    last_pointer_activity: Instant,
+    /// Whether the cursor was hidden after the pointer was idle.
+    is_cursor_hidden: bool,
+    /// When the window was last rendered.
+    last_frame: Option<Instant>,
+    /// Whether an animation frame was requested since the window was last rendered.
+    anim_frame_requested: bool,
+    /// The file the window's input events are written to when it closes.
+    event_recording: Option<PathBuf>,
+    /// When the replay started, and the events left to replay.
+    event_replay: Option<(Instant, VecDeque<TracedEvent>)>,
 }
 
 impl Window {
@@ -141,6 +436,7 @@ impl Window {
         signal_sender: Sender<(WindowId, RenderRootSignal)>,
         default_properties: Arc<DefaultProperties>,
         base_color: Color,
+        frame_pacing: FramePacing,
         size: PhysicalSize<u32>,
         scale_factor: f64,
     ) -> Self {
@@ -164,6 +460,22 @@ impl Window {
                 },
             ),
             base_color,
+            frame_pacing,
+            render_scale: RenderScale::DEFAULT,
+            power_saving: false,
+            automatic_scale: 1.,
+            output_color_space: OutputColorSpace::Srgb,
+            is_focused: true,
+            is_occluded: false,
+            is_fullscreen: false,
+            exit_fullscreen_on_escape: true,
+            kiosk_mode: KioskMode::DEFAULT,
+            last_pointer_activity: Instant::now(),
+            is_cursor_hidden: false,
+            last_frame: None,
+            anim_frame_requested: false,
+            event_recording: None,
+            event_replay: None,
         }
     }
 
@@ -181,6 +493,254 @@ impl Window {
     pub fn base_color(&mut self) -> &mut Color {
         &mut self.base_color
     }
+
+    /// Returns how often this window renders animation frames.
+    pub fn frame_pacing(&self) -> FramePacing {
+        self.frame_pacing
+    }
+
+    /// Sets how often this window renders animation frames.
+    pub fn set_frame_pacing(&mut self, frame_pacing: FramePacing) {
+        self.frame_pacing = frame_pacing;
+    }
+
+    /// Returns when this window renders frames at a reduced resolution.
+    pub fn render_scale(&self) -> RenderScale {
+        self.render_scale
+    }
+
+    /// Sets when this window renders frames at a reduced resolution.
+    pub fn set_render_scale(&mut self, render_scale: RenderScale) {
+        self.render_scale = render_scale;
+        if render_scale.frame_budget.is_none() {
+            self.automatic_scale = 1.;
+        }
+        self.handle.request_redraw();
+    }
+
+    /// Returns `true` if the window is in power-saving mode.
+    pub fn is_power_saving(&self) -> bool {
+        self.power_saving
+    }
+
+    /// Sets whether the window is in power-saving mode, where frames are rendered at the
+    /// [`power_saving_scale`](RenderScale::power_saving_scale) of its resolution.
+    pub fn set_power_saving(&mut self, power_saving: bool) {
+        if self.power_saving != power_saving {
+            self.power_saving = power_saving;
+            self.handle.request_redraw();
+        }
+    }
+
+    /// Returns the fraction of the window's resolution frames are currently rendered at.
+    pub fn current_render_scale(&self) -> f64 {
+        let scale = if self.power_saving {
+            self.render_scale
+                .power_saving_scale
+                .min(self.automatic_scale)
+        } else {
+            self.automatic_scale
+        };
+        scale.clamp(RenderScale::STEP, 1.)
+    }
+
+    /// Returns the size frames are rendered at, for a window of the given size.
+    fn render_size(&self, size: PhysicalSize<u32>) -> PhysicalSize<u32> {
+        let scale = self.current_render_scale();
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "The scale is at most 1, so the result fits in u32"
+        )]
+        let scaled = |length: u32| ((f64::from(length) * scale).ceil() as u32).max(1);
+        PhysicalSize::new(scaled(size.width), scaled(size.height))
+    }
+
+    /// Returns the color space this window's frames are presented in.
+    pub fn output_color_space(&self) -> OutputColorSpace {
+        self.output_color_space
+    }
+
+    /// Sets the color space this window's frames are presented in.
+    ///
+    /// This recreates the window's surface when it is next rendered, for instance after
+    /// the window moved to a display with a different gamut.
+    pub fn set_output_color_space(&mut self, output_color_space: OutputColorSpace) {
+        if self.output_color_space != output_color_space {
+            self.output_color_space = output_color_space;
+            self.handle.request_redraw();
+        }
+    }
+
+    /// Returns `true` if the window is in fullscreen mode.
+    pub fn is_fullscreen(&self) -> bool {
+        self.handle.fullscreen().is_some()
+    }
+
+    /// Makes the window enter or leave fullscreen mode.
+    ///
+    /// [`Fullscreen::Borderless`] covers a monitor with the window, while
+    /// [`Fullscreen::Exclusive`] also changes the monitor's video mode, which suits games
+    /// and media players.
+    /// [`AppDriver::on_fullscreen_changed`] is called once the window's mode changed,
+    /// which is also the case when the user or the platform changes it.
+    pub fn set_fullscreen(&mut self, fullscreen: Option<Fullscreen>) {
+        self.handle.set_fullscreen(fullscreen);
+    }
+
+    /// Returns `true` if pressing Escape makes the window leave fullscreen mode.
+    pub fn exits_fullscreen_on_escape(&self) -> bool {
+        self.exit_fullscreen_on_escape
+    }
+
+    /// Sets whether pressing Escape makes the window leave fullscreen mode.
+    ///
+    /// When it does, the key press isn't sent to widgets.
+    pub fn set_exit_fullscreen_on_escape(&mut self, exit_fullscreen_on_escape: bool) {
+        self.exit_fullscreen_on_escape = exit_fullscreen_on_escape;
+    }
+
+    /// Returns how this window behaves as a kiosk.
+    pub fn kiosk_mode(&self) -> KioskMode {
+        self.kiosk_mode
+    }
+
+    /// Sets how this window behaves as a kiosk.
+    pub fn set_kiosk_mode(&mut self, kiosk_mode: KioskMode) {
+        let previous = std::mem::replace(&mut self.kiosk_mode, kiosk_mode);
+        if previous.confine_pointer != kiosk_mode.confine_pointer {
+            self.update_pointer_confinement();
+        }
+        if kiosk_mode.hide_cursor_after.is_none() {
+            self.show_cursor();
+        }
+    }
+
+    /// Confines the pointer to the window if its kiosk mode requires it, or releases it.
+    fn update_pointer_confinement(&self) {
+        let mode = if self.kiosk_mode.confine_pointer {
+            CursorGrabMode::Confined
+        } else {
+            CursorGrabMode::None
+        };
+        if let Err(err) = self.handle.set_cursor_grab(mode) {
+            tracing::warn!(
+                window_id = self.id.trace(),
+                "Couldn't confine the pointer: {err}"
+            );
+        }
+    }
+
+    /// Records that the pointer was used, and shows the cursor if it was hidden.
+    fn on_pointer_activity(&mut self, now: Instant) {
+        self.last_pointer_activity = now;
+        self.show_cursor();
+    }
+
+    fn show_cursor(&mut self) {
+        if self.is_cursor_hidden {
+            self.is_cursor_hidden = false;
+            self.handle.set_cursor_visible(true);
+        }
+    }
+
+    /// Hides the cursor if the pointer has been idle for long enough, and returns when it
+    /// will have been otherwise.
+    fn hide_idle_cursor(&mut self, now: Instant) -> Option<Instant> {
+        let delay = self.kiosk_mode.hide_cursor_after?;
+        if self.is_cursor_hidden {
+            return None;
+        }
+        let due = self.last_pointer_activity + delay;
+        if due > now {
+            return Some(due);
+        }
+        self.is_cursor_hidden = true;
+        self.handle.set_cursor_visible(false);
+        None
+    }
+
+    /// Returns `true` if the window is replaying an [`EventTrace`].
+    pub fn is_replaying_events(&self) -> bool {
+        self.event_replay.is_some()
+    }
+
+    /// Handles the replayed events which are due, and returns when the next one is.
+    fn replay_due_events(&mut self, now: Instant) -> Option<Instant> {
+        let (start, events) = self.event_replay.as_mut()?;
+        let mut replayed_any = false;
+        while let Some(traced) = events.front()
+            && *start + traced.time <= now
+        {
+            let traced = events.pop_front().unwrap();
+            self.render_root.replay_event(traced.event);
+            replayed_any = true;
+        }
+        if replayed_any {
+            // Signals emitted by the replayed events are handled along with the redraw.
+            self.handle.request_redraw();
+        }
+        let next = events.front().map(|traced| *start + traced.time);
+        if next.is_none() {
+            info!(window_id = self.id.trace(), "finished replaying events");
+            self.event_replay = None;
+        }
+        next
+    }
+
+    /// Fires the widget timers which are due at `now`, and returns when the next one is.
+    fn run_due_timers(&mut self, now: Instant) -> Option<Instant> {
+        if self
+            .render_root
+            .next_timer_deadline()
+            .is_some_and(|due| due <= now)
+        {
+            self.render_root.run_timers(now);
+            // Signals emitted by the timers are handled along with the redraw.
+            self.handle.request_redraw();
+        }
+        self.render_root.next_timer_deadline()
+    }
+
+    /// Runs the idle callbacks of the widgets until `deadline`, and returns whether some are left.
+    fn run_idle_callbacks(&mut self, deadline: Instant) -> bool {
+        if !self.render_root.has_idle_callbacks() {
+            return false;
+        }
+        self.render_root.run_idle_callbacks(deadline);
+        // Signals emitted by the callbacks are handled along with the redraw.
+        self.handle.request_redraw();
+        self.render_root.has_idle_callbacks()
+    }
+
+    /// Writes the recorded events to the recording file, if the window is recording.
+    fn save_event_recording(&mut self) {
+        let Some(path) = self.event_recording.take() else {
+            return;
+        };
+        let Some(trace) = self.render_root.stop_recording_events() else {
+            return;
+        };
+        match std::fs::write(&path, trace.to_json_lines()) {
+            Ok(()) => info!(path = %path.display(), "saved event recording"),
+            Err(err) => {
+                tracing::error!(path = %path.display(), "failed to save event recording: {err}");
+            }
+        }
+    }
+
+    /// Returns when the requested animation frame should be rendered, or `None` if
+    /// animations are paused.
+    fn next_anim_frame(&self, now: Instant) -> Option<Instant> {
+        if self.frame_pacing.pause_when_occluded && self.is_occluded {
+            return None;
+        }
+        let interval = self.frame_pacing.frame_interval(self.is_focused);
+        match (self.last_frame, interval) {
+            (Some(last_frame), Some(interval)) => Some(last_frame + interval),
+            _ => Some(now),
+        }
+    }
 }
 
 /// The state of the Masonry application.
@@ -195,6 +755,18 @@ pub struct MasonryState<'a> {
     is_suspended: bool,
     render_cx: RenderContext,
     renderer: Option<Renderer>,
+    /// The anti-aliasing methods the renderer supports.
+    antialiasing_support: AaSupport,
+    /// The anti-aliasing method of all windows.
+    antialiasing: AaConfig,
+    /// The factor the app scales the text of all windows by.
+    text_scale: f64,
+    /// The user's text size preference, as a factor.
+    system_text_scale: f64,
+    /// Whether Ctrl+= and Ctrl+- change the text scale.
+    zoom_shortcuts: bool,
+    /// The work to do before showing the first windows, if any.
+    warm_up: Option<WarmUp>,
     image_overrides: HashMap<u64, ImageOverrideState>,
     // TODO: Winit doesn't seem to let us create these proxies from within the loop
     // The reasons for this are unclear
@@ -202,7 +774,7 @@ pub struct MasonryState<'a> {
     #[cfg(feature = "tracy")]
     frame: Option<tracing_tracy::client::Frame>,
 
-    pub window_id_to_handle_id: HashMap<WindowId, HandleId>,
+    window_id_to_handle_id: HashMap<WindowId, HandleId>,
 
     surfaces: HashMap<HandleId, RenderSurface<'a>>,
     windows: HashMap<HandleId, Window>,
@@ -381,6 +953,12 @@ impl MasonryState<'_> {
             is_suspended: true,
             render_cx,
             renderer: None,
+            antialiasing_support: AaSupport::area_only(),
+            antialiasing: AaConfig::Area,
+            text_scale: 1.,
+            system_text_scale: 1.,
+            zoom_shortcuts: false,
+            warm_up: None,
             image_overrides: HashMap::new(),
             event_loop_proxy,
             #[cfg(feature = "tracy")]
@@ -442,6 +1020,106 @@ impl MasonryState<'_> {
         self.render_cx.set_wgpu_limits(limits)
     }
 
+    /// Configure the anti-aliasing methods the renderer supports.
+    ///
+    /// Each supported method makes creating the renderer slower, so only area anti-aliasing
+    /// is supported by default.
+    /// Windows and widgets using other methods fall back to area anti-aliasing.
+    ///
+    /// This must be called before the renderer is created (i.e. before the first redraw).
+    /// A good place is [`AppDriver::on_start`].
+    ///
+    /// This method will return `false` if it was called too late and the renderer had already
+    /// been created.
+    pub fn set_antialiasing_support(&mut self, support: AaSupport) -> bool {
+        if self.renderer.is_some() {
+            return false;
+        }
+        self.antialiasing_support = support;
+        for window in self.windows.values_mut() {
+            window.render_root.set_antialiasing_support(support);
+        }
+        true
+    }
+
+    /// Sets the anti-aliasing method all windows are rendered with, and re-renders them.
+    ///
+    /// The method should be supported by the renderer, see
+    /// [`set_antialiasing_support`](Self::set_antialiasing_support).
+    /// A window's method can be changed with [`RenderRoot::set_antialiasing`], and widgets
+    /// can override it for their subtree.
+    pub fn set_antialiasing(&mut self, method: AaConfig) {
+        self.antialiasing = method;
+        for window in self.windows.values_mut() {
+            window.render_root.set_antialiasing(method);
+        }
+    }
+
+    /// Sets the factor the text of all windows is scaled by, such as a zoom level chosen
+    /// by the user, and lays them out again.
+    ///
+    /// Font sizes and `rem` lengths are multiplied by this factor and by the
+    /// [user's text size preference](Self::set_system_text_scale).
+    /// See [`RenderRoot::set_app_text_scale`] for details.
+    pub fn set_text_scale(&mut self, text_scale: f64) {
+        self.text_scale = text_scale;
+        for window in self.windows.values_mut() {
+            window.render_root.set_app_text_scale(text_scale);
+        }
+    }
+
+    /// Returns the factor the text of all windows is scaled by.
+    ///
+    /// This changes when the user presses the [zoom shortcuts](Self::set_zoom_shortcuts).
+    pub fn text_scale(&self) -> f64 {
+        self.text_scale
+    }
+
+    /// Sets the user's text size preference, as a factor, and lays out all windows again.
+    ///
+    /// Winit doesn't report this preference, so apps which read it from the platform,
+    /// such as from the text scaling setting of the desktop environment, pass it here.
+    /// See [`WindowEvent::TextScaleChanged`] for details.
+    pub fn set_system_text_scale(&mut self, system_text_scale: f64) {
+        self.system_text_scale = system_text_scale;
+        for window in self.windows.values_mut() {
+            window
+                .render_root
+                .handle_window_event(WindowEvent::TextScaleChanged(system_text_scale));
+        }
+    }
+
+    /// Sets whether Ctrl+= and Ctrl+- (Cmd on macOS) make the text of all windows larger
+    /// and smaller, stepping through zoom levels like web browsers, and Ctrl+0 resets it.
+    ///
+    /// This is disabled by default, so that apps can handle these shortcuts themselves.
+    /// The chosen scale is returned by [`text_scale`](Self::text_scale), which apps can
+    /// save to restore it with [`set_text_scale`](Self::set_text_scale).
+    pub fn set_zoom_shortcuts(&mut self, zoom_shortcuts: bool) {
+        self.zoom_shortcuts = zoom_shortcuts;
+    }
+
+    /// Returns `true` if Ctrl+= and Ctrl+- change the text scale.
+    pub fn zoom_shortcuts(&self) -> bool {
+        self.zoom_shortcuts
+    }
+
+    /// Sets the work to do before showing the windows created at startup, so that the first
+    /// interactions with the app don't stutter.
+    ///
+    /// This must be called before the first window is shown.
+    /// A good place is [`AppDriver::on_start`].
+    ///
+    /// This method will return `false` if it was called too late and the renderer had already
+    /// been created.
+    pub fn set_warm_up(&mut self, warm_up: WarmUp) -> bool {
+        if self.renderer.is_some() {
+            return false;
+        }
+        self.warm_up = Some(warm_up);
+        true
+    }
+
     // --- MARK: RESUMED
     /// Delegate method for [`ApplicationHandler::resumed()`].
     pub fn handle_resumed(&mut self, event_loop: &ActiveEventLoop, app_driver: &mut dyn AppDriver) {
@@ -484,7 +1162,11 @@ impl MasonryState<'_> {
         self.surfaces.clear();
     }
 
-    pub(crate) fn create_window(&mut self, event_loop: &ActiveEventLoop, new_window: NewWindow) {
+    pub(crate) fn create_window(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        mut new_window: NewWindow,
+    ) {
         if self.window_id_to_handle_id.contains_key(&new_window.id) {
             panic!(
                 "attempted to create a window with id {:?} but a window with that id already exists",
@@ -508,6 +1190,10 @@ impl MasonryState<'_> {
             );
         }
 
+        if let Some(index) = new_window.monitor {
+            place_on_monitor(event_loop, &mut new_window.attributes, index);
+        }
+
         let visible = new_window.attributes.visible;
         // We always create the window as invisible so that we can
         // render the first frame before showing it to avoid flashing.
@@ -533,7 +1219,7 @@ impl MasonryState<'_> {
         #[cfg(not(target_os = "ios"))]
         let size = handle.inner_size();
 
-        let window = Window::new(
+        let mut window = Window::new(
             new_window.id,
             handle,
             adapter,
@@ -541,9 +1227,30 @@ impl MasonryState<'_> {
             self.signal_sender.clone(),
             self.default_properties.clone(),
             new_window.base_color,
+            new_window.frame_pacing,
             size,
             scale_factor,
         );
+        window.output_color_space = new_window.output_color_space;
+        window.render_scale = new_window.render_scale;
+        window.is_fullscreen = window.handle.fullscreen().is_some();
+        window.exit_fullscreen_on_escape = new_window.exit_fullscreen_on_escape;
+        window.set_kiosk_mode(new_window.kiosk_mode);
+        window
+            .render_root
+            .set_antialiasing_support(self.antialiasing_support);
+        window.render_root.set_antialiasing(self.antialiasing);
+        window.render_root.set_app_text_scale(self.text_scale);
+        window
+            .render_root
+            .handle_window_event(WindowEvent::TextScaleChanged(self.system_text_scale));
+        if let Some(path) = new_window.event_recording {
+            window.render_root.start_recording_events();
+            window.event_recording = Some(path);
+        }
+        window.event_replay = new_window
+            .event_replay
+            .map(|trace| (Instant::now(), trace.events.into()));
 
         tracing::debug!(window_id = window.id.trace(), handle=?handle_id, "creating window");
         self.window_id_to_handle_id.insert(window.id, handle_id);
@@ -557,7 +1264,8 @@ impl MasonryState<'_> {
             .remove(&window_id)
             .unwrap_or_else(|| panic!("could not found find window for id {window_id:?}"));
         self.surfaces.remove(&window_id);
-        let window = self.windows.remove(&window_id).unwrap();
+        let mut window = self.windows.remove(&window_id).unwrap();
+        window.save_event_recording();
 
         // HACK: When we exit, on some systems (known to happen with Wayland on KDE),
         // the IME state gets preserved until the app next opens. We work around this by force-deleting
@@ -638,6 +1346,14 @@ impl MasonryState<'_> {
             self.surfaces.remove(&handle_id);
             return;
         }
+        // The surface is recreated for a new color space, keeping its present mode.
+        let mut present_mode = wgpu::PresentMode::AutoVsync;
+        if let Some(surface) = self.surfaces.get(&handle_id)
+            && surface.color_space != window.output_color_space
+        {
+            present_mode = surface.config.present_mode;
+            self.surfaces.remove(&handle_id);
+        }
 
         // Get the existing surface or create a new one
         let surface = if let Some(surface) = self.surfaces.get_mut(&handle_id) {
@@ -649,7 +1365,13 @@ impl MasonryState<'_> {
             surface
         } else {
             let devices_before = self.render_cx.devices.len();
-            let surface = create_surface(&mut self.render_cx, window.handle.clone(), size);
+            let surface = create_surface(
+                &mut self.render_cx,
+                window.handle.clone(),
+                size,
+                present_mode,
+                window.output_color_space,
+            );
             let dev_id = surface.dev_id;
             self.surfaces.insert(handle_id, surface);
             let surface = self.surfaces.get_mut(&handle_id).unwrap();
@@ -667,6 +1389,14 @@ impl MasonryState<'_> {
 
             surface
         };
+        // Frames are rendered at the window's render scale, then upscaled to the surface.
+        let render_size = window.render_size(size);
+        if surface.target_texture.width() != render_size.width
+            || surface.target_texture.height() != render_size.height
+        {
+            self.render_cx
+                .resize_target(surface, render_size.width, render_size.height);
+        }
 
         let now = Instant::now();
         // TODO: this calculation uses wall-clock time of the paint call, which
@@ -676,15 +1406,23 @@ impl MasonryState<'_> {
         let last = self.last_anim.take();
         let elapsed = last.map(|t| now.duration_since(t)).unwrap_or_default();
 
-        window
-            .render_root
-            .handle_window_event(WindowEvent::AnimFrame(elapsed));
+        window.last_frame = Some(now);
+        window.anim_frame_requested = false;
+        // While replaying, animation frames come from the trace, so that they don't depend
+        // on the wall clock.
+        if !window.is_replaying_events() {
+            window
+                .render_root
+                .handle_window_event(WindowEvent::AnimFrame(elapsed));
+        }
 
         // If this animation will continue, store the time.
         // If a new animation starts, then it will have zero reported elapsed time.
         let animation_continues = window.render_root.needs_anim();
         self.last_anim = animation_continues.then_some(now);
 
+        // The base color can be changed at any time through `Window::base_color`.
+        window.render_root.set_base_color(window.base_color);
         let (scene, tree_update) = window.render_root.redraw();
         Self::render(
             surface,
@@ -692,6 +1430,7 @@ impl MasonryState<'_> {
             scene,
             &self.render_cx,
             &mut self.renderer,
+            self.antialiasing_support,
             &mut self.image_overrides,
         );
         #[cfg(feature = "tracy")]
@@ -708,33 +1447,32 @@ impl MasonryState<'_> {
         scene: Scene,
         render_cx: &RenderContext,
         renderer: &mut Option<Renderer>,
+        antialiasing_support: AaSupport,
         image_overrides: &mut HashMap<u64, ImageOverrideState>,
     ) {
-        let size = window.render_root.size();
-        let scale_factor = window.handle.scale_factor();
+        let start = Instant::now();
+        let size = PhysicalSize::new(
+            surface.target_texture.width(),
+            surface.target_texture.height(),
+        );
+        let scale_factor = window.handle.scale_factor() * window.current_render_scale();
 
-        let transformed_scene = if scale_factor == 1.0 {
-            None
+        // Image overrides can change their content without any widget being repainted.
+        let full_render = surface.rendered_with != Some((window.base_color, scale_factor))
+            || !image_overrides.is_empty();
+        let region = if full_render {
+            Some(Rect::new(0., 0., size.width.into(), size.height.into()))
         } else {
-            let mut new_scene = Scene::new();
-            new_scene.append(&scene, Some(Affine::scale(scale_factor)));
-            Some(new_scene)
+            damage_region(window.render_root.frame_damage(), scale_factor, size)
         };
-        let scene_ref = transformed_scene.as_ref().unwrap_or(&scene);
 
         let dev_id = surface.dev_id;
         let device = &render_cx.devices[dev_id].device;
         let queue = &render_cx.devices[dev_id].queue;
         let renderer_options = RendererOptions {
-            antialiasing_support: AaSupport::area_only(),
+            antialiasing_support,
             ..Default::default()
         };
-        let render_params = RenderParams {
-            base_color: window.base_color,
-            width: size.width,
-            height: size.height,
-            antialiasing_method: AaConfig::Area,
-        };
 
         let surface_texture = match surface.surface.get_current_texture() {
             Ok(texture) => texture,
@@ -750,12 +1488,15 @@ impl MasonryState<'_> {
                         tracing::error!(
                             "Couldn't get swap chain texture after configuring. Cause: '{err}'"
                         );
+                        // The damage of this frame is lost, so the next one is rendered in full.
+                        surface.rendered_with = None;
                         return;
                     }
                 }
             }
             Err(err) => {
                 tracing::error!("Couldn't get swap chain texture, operation unrecoverable: {err}");
+                surface.rendered_with = None;
                 return;
             }
         };
@@ -801,20 +1542,84 @@ impl MasonryState<'_> {
             ovr.applied = true;
         }
 
-        renderer
-            .render_to_texture(
-                device,
-                queue,
-                scene_ref,
-                &surface.target_view,
-                &render_params,
-            )
-            .expect("failed to render to surface");
+        window
+            .render_root
+            .render_wgpu_surfaces(device, queue, renderer);
 
-        // Copy the new surface content to the surface.
         let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
             label: Some("Surface Blit"),
         });
+        // The intermediate texture keeps the last frame, so only the damaged region is
+        // rendered again. If nothing changed, the last frame is presented as is.
+        if let Some(region) = region {
+            #[expect(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                reason = "The region is within the window, in whole physical pixels"
+            )]
+            let (x, y, width, height) = (
+                region.x0 as u32,
+                region.y0 as u32,
+                region.width() as u32,
+                region.height() as u32,
+            );
+            let transform =
+                Affine::translate((-region.x0, -region.y0)) * Affine::scale(scale_factor);
+            let transformed_scene = if transform == Affine::IDENTITY {
+                None
+            } else {
+                let mut new_scene = Scene::new();
+                new_scene.append(&scene, Some(transform));
+                Some(new_scene)
+            };
+            let scene_ref = transformed_scene.as_ref().unwrap_or(&scene);
+            let render_params = RenderParams {
+                base_color: window.base_color,
+                width,
+                height,
+                antialiasing_method: window.render_root.antialiasing(),
+            };
+
+            if width == size.width && height == size.height {
+                renderer
+                    .render_to_texture(
+                        device,
+                        queue,
+                        scene_ref,
+                        &surface.target_view,
+                        &render_params,
+                    )
+                    .expect("failed to render to surface");
+            } else {
+                let fits = surface.damage_target.as_ref().is_some_and(|(texture, _)| {
+                    texture.width() >= width && texture.height() >= height
+                });
+                if !fits {
+                    surface.damage_target = Some(create_damage_target(width, height, device));
+                }
+                let (damage_texture, damage_view) = surface.damage_target.as_ref().unwrap();
+                renderer
+                    .render_to_texture(device, queue, scene_ref, damage_view, &render_params)
+                    .expect("failed to render to surface");
+                encoder.copy_texture_to_texture(
+                    damage_texture.as_image_copy(),
+                    wgpu::TexelCopyTextureInfo {
+                        texture: &surface.target_texture,
+                        mip_level: 0,
+                        origin: wgpu::Origin3d { x, y, z: 0 },
+                        aspect: wgpu::TextureAspect::All,
+                    },
+                    wgpu::Extent3d {
+                        width,
+                        height,
+                        depth_or_array_layers: 1,
+                    },
+                );
+            }
+            surface.rendered_with = Some((window.base_color, scale_factor));
+        }
+
+        // Copy the new surface content to the surface.
         surface.blitter.copy(
             device,
             &mut encoder,
@@ -831,6 +1636,67 @@ impl MasonryState<'_> {
                 tracing::info_span!("Waiting for GPU to finish rendering").entered();
             device.poll(wgpu::PollType::wait_indefinitely()).unwrap();
         }
+        if region.is_some() {
+            window.automatic_scale = window
+                .render_scale
+                .adjust(window.automatic_scale, start.elapsed());
+        }
+    }
+
+    // --- MARK: WARM UP
+    /// Shapes the warm-up text in every window, then renders it along with shapes using every
+    /// kind of drawing, once for each supported anti-aliasing method.
+    fn run_warm_up(&mut self, handle_id: HandleId, mut warm_up: WarmUp) {
+        let _span = info_span!("warm_up").entered();
+        let (Some(surface), Some(renderer)) = (self.surfaces.get(&handle_id), &mut self.renderer)
+        else {
+            return;
+        };
+
+        warm_up.report(WarmUpStage::ShapingText);
+        let mut scene = shapes_scene();
+        for window in self.windows.values_mut() {
+            for styles in &warm_up.text_styles {
+                let text = window.render_root.preshape_text(styles, WARM_UP_TEXT);
+                scene.append(&text, None);
+            }
+        }
+
+        warm_up.report(WarmUpStage::Rendering);
+        let device_handle = &self.render_cx.devices[surface.dev_id];
+        let (_texture, view) =
+            create_damage_target(WARM_UP_SIZE, WARM_UP_SIZE, &device_handle.device);
+        let support = self.antialiasing_support;
+        let methods = [
+            (support.area, AaConfig::Area),
+            (support.msaa8, AaConfig::Msaa8),
+            (support.msaa16, AaConfig::Msaa16),
+        ];
+        let supported = methods
+            .into_iter()
+            .filter_map(|(supported, method)| supported.then_some(method));
+        for antialiasing_method in supported {
+            let render_params = RenderParams {
+                base_color: Color::TRANSPARENT,
+                width: WARM_UP_SIZE,
+                height: WARM_UP_SIZE,
+                antialiasing_method,
+            };
+            if let Err(err) = renderer.render_to_texture(
+                &device_handle.device,
+                &device_handle.queue,
+                &scene,
+                &view,
+                &render_params,
+            ) {
+                tracing::warn!("Couldn't render the warm-up scene: {err}");
+            }
+        }
+        device_handle
+            .device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .unwrap();
+        warm_up.report(WarmUpStage::Done);
     }
 
     // --- MARK: WINDOW_EVENT
@@ -868,15 +1734,19 @@ impl MasonryState<'_> {
             .accesskit_adapter
             .process_event(&window.handle, &event);
 
-        if !matches!(
-            event,
-            WinitWindowEvent::KeyboardInput {
-                is_synthetic: true,
-                ..
-            }
-        ) && let Some(wet) = window
-            .event_reducer
-            .reduce(window.handle.scale_factor(), &event)
+        let is_replaying = window.is_replaying_events();
+        let mut zoomed_text_scale = None;
+        if !is_replaying
+            && !matches!(
+                event,
+                WinitWindowEvent::KeyboardInput {
+                    is_synthetic: true,
+                    ..
+                }
+            )
+            && let Some(wet) = window
+                .event_reducer
+                .reduce(window.handle.scale_factor(), &event)
         {
             match wet {
                 WindowEventTranslation::Keyboard(k) => {
@@ -886,7 +1756,20 @@ impl MasonryState<'_> {
                     } else {
                         k.modifiers.ctrl()
                     };
-                    if let Key::Character(c) = &k.key
+                    if k.key == Key::Named(NamedKey::Escape)
+                        && k.state == KeyState::Down
+                        && window.exit_fullscreen_on_escape
+                        && window.is_fullscreen()
+                    {
+                        window.set_fullscreen(None);
+                    } else if let Key::Character(c) = &k.key
+                        && self.zoom_shortcuts
+                        && action_mod
+                        && k.state == KeyState::Down
+                        && let Some(text_scale) = zoom_text_scale(self.text_scale, c)
+                    {
+                        zoomed_text_scale = Some(text_scale);
+                    } else if let Key::Character(c) = &k.key
                         && c.as_str().eq_ignore_ascii_case("v")
                         && action_mod
                         && k.state == KeyState::Down
@@ -922,19 +1805,50 @@ impl MasonryState<'_> {
                 window
                     .render_root
                     .handle_window_event(WindowEvent::Resize(size));
+                // Winit has no event for fullscreen changes, but they always resize the window.
+                let is_fullscreen = window.is_fullscreen();
+                if window.is_fullscreen != is_fullscreen {
+                    window.is_fullscreen = is_fullscreen;
+                    app_driver.on_fullscreen_changed(
+                        window.id,
+                        &mut DriverCtx::new(self, event_loop),
+                        is_fullscreen,
+                    );
+                }
             }
+            WinitWindowEvent::Ime(_) if is_replaying => {}
             WinitWindowEvent::Ime(ime) => {
                 let ime = winit_ime_to_masonry(ime);
                 window.render_root.handle_text_event(TextEvent::Ime(ime));
             }
+            WinitWindowEvent::CursorMoved { .. }
+            | WinitWindowEvent::MouseInput { .. }
+            | WinitWindowEvent::MouseWheel { .. }
+            | WinitWindowEvent::Touch(_) => {
+                window.on_pointer_activity(Instant::now());
+            }
             WinitWindowEvent::Focused(new_focus) => {
-                window
-                    .render_root
-                    .handle_text_event(TextEvent::WindowFocusChange(new_focus));
+                window.is_focused = new_focus;
+                // Some platforms release the pointer when the window loses focus.
+                if new_focus && window.kiosk_mode.confine_pointer {
+                    window.update_pointer_confinement();
+                }
+                if !is_replaying {
+                    window
+                        .render_root
+                        .handle_text_event(TextEvent::WindowFocusChange(new_focus));
+                }
+            }
+            WinitWindowEvent::Occluded(occluded) => {
+                window.is_occluded = occluded;
             }
             _ => (),
         }
 
+        if let Some(text_scale) = zoomed_text_scale {
+            self.set_text_scale(text_scale);
+        }
+
         self.handle_signals(event_loop, app_driver);
         if self.exit {
             event_loop.exit();
@@ -1008,15 +1922,72 @@ impl MasonryState<'_> {
         self.handle_signals(event_loop, app_driver);
     }
 
-    // --- MARK: EMPTY WINIT HANDLERS
+    // --- MARK: ABOUT_TO_WAIT
     /// Delegate method for [`ApplicationHandler::about_to_wait()`].
-    pub fn handle_about_to_wait(&mut self, _: &ActiveEventLoop) {}
+    ///
+    /// Fires the widget timers which are due and renders the requested animation frames
+    /// which are due according to each window's [`FramePacing`], and wakes the event loop
+    /// up when the next timer or frame is.
+    ///
+    /// The time left until then is used to run idle callbacks.
+    pub fn handle_about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        let now = Instant::now();
+        let mut next_wake_up: Option<Instant> = None;
+        let mut frame_due = false;
+        for window in self.windows.values_mut() {
+            if let Some(due) = window.replay_due_events(now) {
+                next_wake_up = Some(next_wake_up.map_or(due, |wake_up| wake_up.min(due)));
+            }
+            if let Some(due) = window.run_due_timers(now) {
+                next_wake_up = Some(next_wake_up.map_or(due, |wake_up| wake_up.min(due)));
+            }
+            if let Some(due) = window.hide_idle_cursor(now) {
+                next_wake_up = Some(next_wake_up.map_or(due, |wake_up| wake_up.min(due)));
+            }
+            if !window.anim_frame_requested {
+                continue;
+            }
+            let Some(due) = window.next_anim_frame(now) else {
+                // Paused: rendered once the window is shown again.
+                continue;
+            };
+            if due <= now {
+                window.anim_frame_requested = false;
+                window.handle.request_redraw();
+                frame_due = true;
+            } else {
+                next_wake_up = Some(next_wake_up.map_or(due, |wake_up| wake_up.min(due)));
+            }
+        }
+        let idle_deadline = if frame_due {
+            // Only the idle callbacks which timed out run.
+            now
+        } else {
+            let slice_end = now + IDLE_SLICE;
+            next_wake_up.map_or(slice_end, |wake_up| wake_up.min(slice_end))
+        };
+        for window in self.windows.values_mut() {
+            if window.run_idle_callbacks(idle_deadline) {
+                next_wake_up = Some(now);
+            }
+        }
+        event_loop.set_control_flow(match next_wake_up {
+            Some(wake_up) => ControlFlow::WaitUntil(wake_up),
+            None => ControlFlow::Wait,
+        });
+    }
+
+    // --- MARK: EMPTY WINIT HANDLERS
 
     /// Delegate method for [`ApplicationHandler::new_events()`].
     pub fn handle_new_events(&mut self, _: &ActiveEventLoop, _: winit::event::StartCause) {}
 
     /// Delegate method for [`ApplicationHandler::exiting()`].
-    pub fn handle_exiting(&mut self, _: &ActiveEventLoop) {}
+    pub fn handle_exiting(&mut self, _: &ActiveEventLoop) {
+        for window in self.windows.values_mut() {
+            window.save_event_recording();
+        }
+    }
 
     /// Delegate method for [`ApplicationHandler::memory_warning()`].
     pub fn handle_memory_warning(&mut self, _: &ActiveEventLoop) {}
@@ -1058,8 +2029,13 @@ impl MasonryState<'_> {
                         action,
                     );
                 }
-                RenderRootSignal::StartIme => {
+                RenderRootSignal::StartIme(purpose) => {
                     handle.set_ime_allowed(true);
+                    handle.set_ime_purpose(match purpose {
+                        ImePurpose::Normal => WinitImePurpose::Normal,
+                        ImePurpose::Password => WinitImePurpose::Password,
+                        ImePurpose::Terminal => WinitImePurpose::Terminal,
+                    });
                 }
                 RenderRootSignal::EndIme => {
                     handle.set_ime_allowed(false);
@@ -1070,12 +2046,15 @@ impl MasonryState<'_> {
                 RenderRootSignal::ClipboardStore(text) => {
                     self.clipboard_cx.set_contents(text).unwrap();
                 }
+                RenderRootSignal::OpenUrl(url) => {
+                    open_url(&url);
+                }
                 RenderRootSignal::RequestRedraw => {
                     need_redraw.insert(*handle_id);
                 }
                 RenderRootSignal::RequestAnimFrame => {
-                    // TODO
-                    need_redraw.insert(*handle_id);
+                    // Scheduled in `handle_about_to_wait`, according to the frame pacing.
+                    window.anim_frame_requested = true;
                 }
                 RenderRootSignal::TakeFocus => {
                     handle.focus_window();
@@ -1122,6 +2101,22 @@ impl MasonryState<'_> {
                         widget_name.into()
                     };
                     info!("Widget selected in inspector: {widget_id} - {display_name}");
+                    if let Some(diagnostics) = window.render_root.frame_diagnostics() {
+                        let frame_count = diagnostics.frame_count();
+                        info!(
+                            "Work requested by {widget_id} in the {frame_count} recorded frames:"
+                        );
+                        for summary in diagnostics.report_for(widget_id) {
+                            info!("  {summary}");
+                        }
+                    }
+                    if let Some(profile) = window.render_root.widget_profile()
+                        && let Some(timing) = profile.widget(widget_id)
+                    {
+                        let frame_count = profile.frame_count();
+                        info!("Time spent in {widget_id} in the {frame_count} recorded frames:");
+                        info!("  layout {:?}, paint {:?}", timing.layout, timing.paint);
+                    }
                 }
                 RenderRootSignal::NewLayer(_type, root, pos) => {
                     window.render_root.add_layer(root, pos);
@@ -1130,13 +2125,55 @@ impl MasonryState<'_> {
                 RenderRootSignal::RepositionLayer(root_id, new_pos) => {
                     window.render_root.reposition_layer(root_id, new_pos);
                 }
+                RenderRootSignal::RenderToImage(widget_id, scale) => {
+                    let window_id = window.id;
+                    let Some(surface) = self.surfaces.get(handle_id) else {
+                        tracing::warn!(
+                            id = ?window_id,
+                            "Tried to render to an image before the window has a surface"
+                        );
+                        continue;
+                    };
+                    let device_handle = &self.render_cx.devices[surface.dev_id];
+                    let renderer = self.renderer.get_or_insert_with(|| {
+                        let renderer_options = RendererOptions {
+                            antialiasing_support: self.antialiasing_support,
+                            ..Default::default()
+                        };
+                        Renderer::new(&device_handle.device, renderer_options).unwrap()
+                    });
+                    let Some(image) = window.render_root.render_to_image(
+                        widget_id,
+                        scale,
+                        &device_handle.device,
+                        &device_handle.queue,
+                        renderer,
+                    ) else {
+                        continue;
+                    };
+                    app_driver.on_action(
+                        window_id,
+                        &mut DriverCtx::new(self, event_loop),
+                        widget_id,
+                        Box::new(RenderedImage { image, scale }),
+                    );
+                }
             }
         }
 
         // If an app creates a visible window, we firstly create it as invisible
         // and then render the first frame before making it visible to avoid flashing.
+        let mut warm_up = if self.need_first_frame.is_empty() {
+            None
+        } else {
+            self.warm_up.take()
+        };
         for handle_id in std::mem::take(&mut self.need_first_frame) {
             self.redraw(handle_id, app_driver);
+            // The first frame created the renderer the warm-up uses.
+            if let Some(warm_up) = warm_up.take() {
+                self.run_warm_up(handle_id, warm_up);
+            }
             let window = self.windows.get_mut(&handle_id).unwrap();
             window.handle.set_visible(true);
         }
@@ -1191,10 +2228,60 @@ impl MasonryState<'_> {
     }
 }
 
+/// Returns the region of the window to render again, in physical pixels.
+///
+/// The region covers all of `damage`, and is `None` if nothing changed.
+/// Damage covering most of the window is rendered in full, to avoid the cost of the copy.
+fn damage_region(damage: &[Rect], scale_factor: f64, size: PhysicalSize<u32>) -> Option<Rect> {
+    let window_rect = Rect::new(0., 0., size.width.into(), size.height.into());
+    let region = damage
+        .iter()
+        .map(|rect| rect.scale_from_origin(scale_factor).expand())
+        .reduce(|a, b| a.union(b))?
+        .intersect(window_rect);
+    if region.is_zero_area() {
+        None
+    } else if region.area() > window_rect.area() * 0.75 {
+        Some(window_rect)
+    } else {
+        Some(region)
+    }
+}
+
+/// Moves a window with the given `attributes` to the monitor at `index`, centering it
+/// unless it has a position.
+fn place_on_monitor(event_loop: &ActiveEventLoop, attributes: &mut WindowAttributes, index: usize) {
+    let Some(monitor) = event_loop.available_monitors().nth(index) else {
+        tracing::warn!(index, "Couldn't find the monitor to open a window on");
+        return;
+    };
+    if attributes.position.is_none() {
+        // This is winit's default size on most platforms.
+        let size = attributes
+            .inner_size
+            .unwrap_or(Size::Logical(LogicalSize::new(800., 600.)))
+            .to_physical::<i32>(monitor.scale_factor());
+        let monitor_size = monitor.size().cast::<i32>();
+        let origin = monitor.position();
+        attributes.position = Some(
+            PhysicalPosition::new(
+                origin.x + (monitor_size.width - size.width).max(0) / 2,
+                origin.y + (monitor_size.height - size.height).max(0) / 2,
+            )
+            .into(),
+        );
+    }
+    if let Some(Fullscreen::Borderless(None)) = attributes.fullscreen {
+        attributes.fullscreen = Some(Fullscreen::Borderless(Some(monitor)));
+    }
+}
+
 fn create_surface<'s>(
     render_cx: &mut RenderContext,
     handle: Arc<WindowHandle>,
     size: PhysicalSize<u32>,
+    present_mode: wgpu::PresentMode,
+    color_space: OutputColorSpace,
 ) -> RenderSurface<'s> {
     assert!(
         size.width != 0 && size.height != 0,
@@ -1204,7 +2291,64 @@ fn create_surface<'s>(
         handle,
         size.width,
         size.height,
-        wgpu::PresentMode::AutoVsync,
+        present_mode,
+        color_space,
     ))
     .unwrap()
 }
+
+/// Returns the text scale the zoom shortcut typing `key` changes `text_scale` to,
+/// or `None` if `key` isn't a zoom shortcut.
+fn zoom_text_scale(text_scale: f64, key: &str) -> Option<f64> {
+    match key {
+        // Shift is needed to type "+" on many layouts, so "=" is accepted as well.
+        "=" | "+" => Some(
+            ZOOM_LEVELS
+                .into_iter()
+                .find(|level| *level > text_scale)
+                .unwrap_or(text_scale),
+        ),
+        "-" => Some(
+            ZOOM_LEVELS
+                .into_iter()
+                .rev()
+                .find(|level| *level < text_scale)
+                .unwrap_or(text_scale),
+        ),
+        "0" => Some(1.),
+        _ => None,
+    }
+}
+
+/// Opens `url` with the platform's default handler, without waiting for it to finish.
+fn open_url(url: &str) {
+    let program = if cfg!(target_os = "windows") {
+        "explorer"
+    } else if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+    // The child process is detached, as we don't need its exit status.
+    if let Err(err) = std::process::Command::new(program).arg(url).spawn() {
+        tracing::warn!(url, %err, "Failed to open URL");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zoom_shortcuts_step_through_levels() {
+        assert_eq!(zoom_text_scale(1., "="), Some(1.1));
+        assert_eq!(zoom_text_scale(1., "+"), Some(1.1));
+        assert_eq!(zoom_text_scale(1., "-"), Some(0.9));
+        assert_eq!(zoom_text_scale(1.2, "-"), Some(1.1));
+        assert_eq!(zoom_text_scale(1.2, "="), Some(1.25));
+        assert_eq!(zoom_text_scale(3., "="), Some(3.));
+        assert_eq!(zoom_text_scale(0.5, "-"), Some(0.5));
+        assert_eq!(zoom_text_scale(2., "0"), Some(1.));
+        assert_eq!(zoom_text_scale(1., "a"), None);
+    }
+}
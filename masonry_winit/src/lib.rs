@@ -89,7 +89,9 @@
 mod app_driver;
 mod convert_winit_event;
 mod event_loop_runner;
+mod output_color_space;
 mod vello_util;
+mod warm_up;
 
 pub use winit;
 
@@ -97,9 +99,11 @@ pub use winit;
 pub mod app {
     pub use super::app_driver::{AppDriver, DriverCtx, WgpuContext, WgpuLimits, WindowId};
     pub use super::event_loop_runner::{
-        EventLoop, EventLoopBuilder, EventLoopProxy, MasonryState, MasonryUserEvent, NewWindow,
-        Window, run, run_with,
+        EventLoop, EventLoopBuilder, EventLoopProxy, FramePacing, KioskMode, MasonryState,
+        MasonryUserEvent, NewWindow, RenderScale, Window, run, run_with,
     };
+    pub use super::output_color_space::OutputColorSpace;
+    pub use super::warm_up::{WarmUp, WarmUpStage};
 
     pub(crate) use super::convert_winit_event::{
         masonry_resize_direction_to_winit, winit_ime_to_masonry,
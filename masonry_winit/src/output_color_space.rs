@@ -0,0 +1,295 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversion of rendered frames to the color space of the display.
+
+use masonry_core::vello::wgpu::{self, BlendState, CommandEncoder, Device, TextureFormat};
+use wgpu::util::{TextureBlitter, TextureBlitterBuilder};
+
+/// The color space a window's frames are presented in.
+///
+/// Widgets paint in sRGB, and the [`Color`](masonry_core::peniko::Color) type is tagged with it:
+/// frames are converted to the window's output color space when they are presented.
+/// Without this, displays with a wider gamut than sRGB show sRGB colors stretched to their
+/// gamut, so saturated colors look more saturated than on an sRGB display.
+///
+/// Winit doesn't report the gamut of monitors, so apps pick the color space of each window,
+/// with [`NewWindow::with_output_color_space`] and [`Window::set_output_color_space`], for
+/// instance from a user setting or from the monitor the window is on.
+/// If the surface doesn't support the color space, the window falls back to [`Srgb`](Self::Srgb).
+///
+/// [`NewWindow::with_output_color_space`]: crate::app::NewWindow::with_output_color_space
+/// [`Window::set_output_color_space`]: crate::app::Window::set_output_color_space
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OutputColorSpace {
+    /// sRGB, which is what most displays expect.
+    #[default]
+    Srgb,
+    /// Display P3, the color space of wide-gamut displays such as those of recent Macs
+    /// and phones.
+    DisplayP3,
+    /// Linear extended sRGB (scRGB) on a 16-bit floating point surface, which is how
+    /// Windows and macOS composite windows on HDR displays.
+    ExtendedLinearSrgb,
+}
+
+impl OutputColorSpace {
+    /// Returns the surface format for this color space among the `supported` ones, if any.
+    pub(crate) fn surface_format(self, supported: &[TextureFormat]) -> Option<TextureFormat> {
+        supported.iter().copied().find(|format| match self {
+            Self::Srgb | Self::DisplayP3 => {
+                matches!(
+                    format,
+                    TextureFormat::Rgba8Unorm | TextureFormat::Bgra8Unorm
+                )
+            }
+            Self::ExtendedLinearSrgb => *format == TextureFormat::Rgba16Float,
+        })
+    }
+
+    /// The entry point of the fragment shader converting sRGB to this color space.
+    fn fragment_entry_point(self) -> Option<&'static str> {
+        match self {
+            Self::Srgb => None,
+            Self::DisplayP3 => Some("fs_display_p3"),
+            Self::ExtendedLinearSrgb => Some("fs_extended_linear_srgb"),
+        }
+    }
+}
+
+const SHADER: &str = r"
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    // A triangle covering the whole target.
+    let x = f32((vertex_index << 1u) & 2u);
+    let y = f32(vertex_index & 2u);
+    var out: VertexOutput;
+    out.position = vec4(x * 2.0 - 1.0, 1.0 - y * 2.0, 0.0, 1.0);
+    out.uv = vec2(x, y);
+    return out;
+}
+
+@group(0) @binding(0) var source: texture_2d<f32>;
+@group(0) @binding(1) var source_sampler: sampler;
+
+fn srgb_to_linear(color: vec3<f32>) -> vec3<f32> {
+    let low = color / 12.92;
+    let high = pow((color + 0.055) / 1.055, vec3(2.4));
+    return select(high, low, color <= vec3(0.04045));
+}
+
+fn linear_to_srgb(color: vec3<f32>) -> vec3<f32> {
+    let low = color * 12.92;
+    let high = 1.055 * pow(color, vec3(1.0 / 2.4)) - 0.055;
+    return select(high, low, color <= vec3(0.0031308));
+}
+
+// Linear sRGB to linear Display P3, which share their white point.
+const SRGB_TO_DISPLAY_P3 = mat3x3<f32>(
+    vec3(0.8224621, 0.0331941, 0.0170827),
+    vec3(0.1775380, 0.9668058, 0.0723974),
+    vec3(0.0, 0.0, 0.9105199),
+);
+
+// Vello renders straight alpha, which is kept as is.
+@fragment
+fn fs_display_p3(in: VertexOutput) -> @location(0) vec4<f32> {
+    let pixel = textureSample(source, source_sampler, in.uv);
+    // Display P3 uses the sRGB transfer function.
+    let color = linear_to_srgb(SRGB_TO_DISPLAY_P3 * srgb_to_linear(pixel.rgb));
+    return vec4(color, pixel.a);
+}
+
+@fragment
+fn fs_extended_linear_srgb(in: VertexOutput) -> @location(0) vec4<f32> {
+    let pixel = textureSample(source, source_sampler, in.uv);
+    return vec4(srgb_to_linear(pixel.rgb), pixel.a);
+}
+";
+
+/// Copies the rendered frames to the surface, converting them to its color space.
+///
+/// Frames rendered at a lower resolution than the surface are upscaled with linear filtering.
+pub(crate) enum OutputBlitter {
+    /// The surface is sRGB, so frames are copied as is.
+    Copy(TextureBlitter),
+    /// Frames are converted by a shader.
+    Convert {
+        pipeline: wgpu::RenderPipeline,
+        bind_group_layout: wgpu::BindGroupLayout,
+        sampler: wgpu::Sampler,
+    },
+}
+
+impl OutputBlitter {
+    /// Creates a blitter to a surface with the given format and color space, blending
+    /// with `blend_state` if any.
+    pub(crate) fn new(
+        device: &Device,
+        format: TextureFormat,
+        color_space: OutputColorSpace,
+        blend_state: Option<BlendState>,
+    ) -> Self {
+        let Some(entry_point) = color_space.fragment_entry_point() else {
+            let mut builder =
+                TextureBlitterBuilder::new(device, format).sample_type(wgpu::FilterMode::Linear);
+            if let Some(blend_state) = blend_state {
+                builder = builder.blend_state(blend_state);
+            }
+            return Self::Copy(builder.build());
+        };
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Output color space conversion"),
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Output color space conversion"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Output color space conversion"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Output color space conversion"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Output color space conversion"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some(entry_point),
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: blend_state,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            multiview: None,
+            cache: None,
+        });
+        Self::Convert {
+            pipeline,
+            bind_group_layout,
+            sampler,
+        }
+    }
+
+    /// Copies `source` to `target`, scaling it to the size of `target`.
+    pub(crate) fn copy(
+        &self,
+        device: &Device,
+        encoder: &mut CommandEncoder,
+        source: &wgpu::TextureView,
+        target: &wgpu::TextureView,
+    ) {
+        let (pipeline, bind_group_layout, sampler) = match self {
+            Self::Copy(blitter) => {
+                blitter.copy(device, encoder, source, target);
+                return;
+            }
+            Self::Convert {
+                pipeline,
+                bind_group_layout,
+                sampler,
+            } => (pipeline, bind_group_layout, sampler),
+        };
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Output color space conversion"),
+            layout: bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(source),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(sampler),
+                },
+            ],
+        });
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Output color space conversion"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: target,
+                depth_slice: None,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        pass.set_pipeline(pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.draw(0..3, 0..1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn surface_format_matches_color_space() {
+        let supported = [TextureFormat::Bgra8UnormSrgb, TextureFormat::Bgra8Unorm];
+        assert_eq!(
+            OutputColorSpace::DisplayP3.surface_format(&supported),
+            Some(TextureFormat::Bgra8Unorm)
+        );
+        assert_eq!(
+            OutputColorSpace::ExtendedLinearSrgb.surface_format(&supported),
+            None
+        );
+
+        let supported = [TextureFormat::Rgba16Float, TextureFormat::Rgba8Unorm];
+        assert_eq!(
+            OutputColorSpace::ExtendedLinearSrgb.surface_format(&supported),
+            Some(TextureFormat::Rgba16Float)
+        );
+        assert_eq!(
+            OutputColorSpace::Srgb.surface_format(&supported),
+            Some(TextureFormat::Rgba8Unorm)
+        );
+    }
+}
@@ -6,15 +6,16 @@
 //! This module is based on [`vello::util`](masonry_core::vello::util) module
 //! with modifications for transparent surfaces.
 
+use masonry_core::peniko::Color;
 use masonry_core::vello::Error;
 use masonry_core::vello::wgpu::{self, MemoryBudgetThresholds, MemoryHints};
-use wgpu::util::{TextureBlitter, TextureBlitterBuilder};
 use wgpu::{
     BlendComponent, BlendFactor, BlendState, CompositeAlphaMode, Device, Instance, PresentMode,
     Surface, SurfaceConfiguration, Texture, TextureFormat, TextureUsages, TextureView,
 };
 
 use crate::app_driver::WgpuLimits;
+use crate::output_color_space::{OutputBlitter, OutputColorSpace};
 
 /// Simple render context that maintains wgpu state for rendering the pipeline.
 pub(crate) struct RenderContext {
@@ -93,12 +94,14 @@ impl RenderContext {
         width: u32,
         height: u32,
         present_mode: PresentMode,
+        color_space: OutputColorSpace,
     ) -> Result<RenderSurface<'w>, Error> {
         self.create_render_surface(
             self.instance.create_surface(window.into())?,
             width,
             height,
             present_mode,
+            color_space,
         )
         .await
     }
@@ -110,6 +113,7 @@ impl RenderContext {
         width: u32,
         height: u32,
         present_mode: PresentMode,
+        color_space: OutputColorSpace,
     ) -> Result<RenderSurface<'w>, Error> {
         let dev_id = self
             .device(Some(&surface))
@@ -118,11 +122,18 @@ impl RenderContext {
 
         let device_handle = &self.devices[dev_id];
         let capabilities = surface.get_capabilities(&device_handle.adapter);
-        let format = capabilities
-            .formats
-            .into_iter()
-            .find(|it| matches!(it, TextureFormat::Rgba8Unorm | TextureFormat::Bgra8Unorm))
-            .ok_or(Error::UnsupportedSurfaceFormat)?;
+        let (format, output_color_space) = match color_space.surface_format(&capabilities.formats) {
+            Some(format) => (format, color_space),
+            None => {
+                tracing::warn!(
+                    "surface doesn't support the {color_space:?} output color space, falling back to sRGB"
+                );
+                let format = OutputColorSpace::Srgb
+                    .surface_format(&capabilities.formats)
+                    .ok_or(Error::UnsupportedSurfaceFormat)?;
+                (format, OutputColorSpace::Srgb)
+            }
+        };
 
         const PREMUL_BLEND_STATE: BlendState = BlendState {
             alpha: BlendComponent::REPLACE,
@@ -135,40 +146,36 @@ impl RenderContext {
         // TODO: check if the window is transparent then set alpha_mode accordingly
         // also, Opaque mode may help in saving power.
         // blocked on winit not exposing a way to check for transparency
-        let (alpha_mode, blitter) = if capabilities
+        let (alpha_mode, blend_state) = if capabilities
             .alpha_modes
             .contains(&CompositeAlphaMode::PostMultiplied)
         {
-            (
-                CompositeAlphaMode::PostMultiplied,
-                TextureBlitter::new(&device_handle.device, format),
-            )
+            (CompositeAlphaMode::PostMultiplied, None)
         } else if capabilities
             .alpha_modes
             .contains(&CompositeAlphaMode::PreMultiplied)
         {
-            (
-                CompositeAlphaMode::PreMultiplied,
-                TextureBlitterBuilder::new(&device_handle.device, format)
-                    .blend_state(PREMUL_BLEND_STATE)
-                    .build(),
-            )
+            (CompositeAlphaMode::PreMultiplied, Some(PREMUL_BLEND_STATE))
         } else {
             // TODO: check if the only available mode is Inherit then log info that postmultipled blit is being used
             // TODO: check if non-opaque base color is used on unsupported device then warn
-            let texture_blitter =
+            let blend_state =
                 if cfg!(windows) && device_handle.adapter.get_info().name.contains("AMD") {
                     tracing::info!(
                         "on Windows with AMD GPUs use premultiplied blitting even on opaque surface"
                     );
-                    TextureBlitterBuilder::new(&device_handle.device, format)
-                        .blend_state(PREMUL_BLEND_STATE)
-                        .build()
+                    Some(PREMUL_BLEND_STATE)
                 } else {
-                    TextureBlitter::new(&device_handle.device, format)
+                    None
                 };
-            (CompositeAlphaMode::Auto, texture_blitter)
+            (CompositeAlphaMode::Auto, blend_state)
         };
+        let blitter = OutputBlitter::new(
+            &device_handle.device,
+            format,
+            output_color_space,
+            blend_state,
+        );
 
         let config = SurfaceConfiguration {
             usage: TextureUsages::RENDER_ATTACHMENT,
@@ -189,6 +196,9 @@ impl RenderContext {
             format,
             target_texture,
             target_view,
+            rendered_with: None,
+            damage_target: None,
+            color_space,
             blitter,
         };
         self.configure_surface(&surface);
@@ -196,15 +206,24 @@ impl RenderContext {
     }
 
     /// Resizes the surface to the new dimensions.
+    ///
+    /// This doesn't resize the texture frames are rendered to,
+    /// see [`resize_target`](Self::resize_target).
     pub(crate) fn resize_surface(&self, surface: &mut RenderSurface<'_>, width: u32, height: u32) {
+        surface.config.width = width;
+        surface.config.height = height;
+        self.configure_surface(surface);
+    }
+
+    /// Resizes the texture frames are rendered to, which is scaled to the size of the
+    /// surface when presented.
+    pub(crate) fn resize_target(&self, surface: &mut RenderSurface<'_>, width: u32, height: u32) {
         let (texture, view) = create_targets(width, height, &self.devices[surface.dev_id].device);
         // TODO: Use clever resize semantics to avoid thrashing the memory allocator during a resize
         // especially important on metal.
         surface.target_texture = texture;
         surface.target_view = view;
-        surface.config.width = width;
-        surface.config.height = height;
-        self.configure_surface(surface);
+        surface.rendered_with = None;
     }
 
     pub(crate) fn set_present_mode(
@@ -297,7 +316,9 @@ fn create_targets(width: u32, height: u32, device: &Device) -> (Texture, Texture
         mip_level_count: 1,
         sample_count: 1,
         dimension: wgpu::TextureDimension::D2,
-        usage: TextureUsages::STORAGE_BINDING | TextureUsages::TEXTURE_BINDING,
+        usage: TextureUsages::STORAGE_BINDING
+            | TextureUsages::TEXTURE_BINDING
+            | TextureUsages::COPY_DST,
         format: TextureFormat::Rgba8Unorm,
         view_formats: &[],
     });
@@ -305,6 +326,31 @@ fn create_targets(width: u32, height: u32, device: &Device) -> (Texture, Texture
     (target_texture, target_view)
 }
 
+/// Creates the texture damaged regions are rendered to, before being copied to the
+/// intermediate texture.
+pub(crate) fn create_damage_target(
+    width: u32,
+    height: u32,
+    device: &Device,
+) -> (Texture, TextureView) {
+    let damage_texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("Damage texture"),
+        size: wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        usage: TextureUsages::STORAGE_BINDING | TextureUsages::COPY_SRC,
+        format: TextureFormat::Rgba8Unorm,
+        view_formats: &[],
+    });
+    let damage_view = damage_texture.create_view(&wgpu::TextureViewDescriptor::default());
+    (damage_texture, damage_view)
+}
+
 /// Combination of surface and its configuration.
 pub(crate) struct RenderSurface<'s> {
     pub surface: Surface<'s>,
@@ -313,7 +359,17 @@ pub(crate) struct RenderSurface<'s> {
     pub format: TextureFormat,
     pub target_texture: Texture,
     pub target_view: TextureView,
-    pub blitter: TextureBlitter,
+    /// The base color and scale factor `target_texture` was last rendered with.
+    ///
+    /// `None` if its content is stale, in which case the next frame is rendered in full.
+    pub rendered_with: Option<(Color, f64)>,
+    /// The texture damaged regions are rendered to, if any were rendered yet.
+    pub damage_target: Option<(Texture, TextureView)>,
+    /// The color space requested for this surface.
+    ///
+    /// [`OutputColorSpace::Srgb`] is used instead if the surface doesn't support it.
+    pub color_space: OutputColorSpace,
+    pub blitter: OutputBlitter,
 }
 
 impl std::fmt::Debug for RenderSurface<'_> {
@@ -325,6 +381,9 @@ impl std::fmt::Debug for RenderSurface<'_> {
             .field("format", &self.format)
             .field("target_texture", &self.target_texture)
             .field("target_view", &self.target_view)
+            .field("rendered_with", &self.rendered_with)
+            .field("damage_target", &self.damage_target)
+            .field("color_space", &self.color_space)
             .field("blitter", &"(Not Debug)")
             .finish()
     }
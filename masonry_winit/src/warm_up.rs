@@ -0,0 +1,145 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Work done before the first windows are shown.
+
+use std::fmt;
+use std::sync::Arc;
+
+use masonry_core::core::StyleSet;
+use masonry_core::kurbo::{Affine, Circle, Rect, RoundedRect, Stroke};
+use masonry_core::peniko::{
+    Blob, Color, Fill, Gradient, ImageAlphaType, ImageBrush, ImageData, ImageFormat, Mix,
+};
+use masonry_core::vello::Scene;
+
+/// The text laid out with each style set, covering the characters most UIs start with.
+pub(crate) const WARM_UP_TEXT: &str = "The quick brown fox jumps over the lazy dog. \
+    THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG! 0123456789 ,;:?'\"()[]{}<>+-*/=_@#$%&…";
+
+/// The width and height of the texture the warm-up scene is rendered to, in pixels.
+pub(crate) const WARM_UP_SIZE: u32 = 256;
+
+/// A stage of the [`WarmUp`], reported to its [splash callback](WarmUp::with_splash).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WarmUpStage {
+    /// The fonts are loaded and the text styles are shaped.
+    ShapingText,
+    /// Scenes using every kind of drawing are rendered, so that the GPU driver finishes
+    /// preparing the renderer's pipelines.
+    Rendering,
+    /// The warm-up is done, and the windows are about to be shown.
+    Done,
+}
+
+/// Work done before the first windows are shown, so that the first interactions with the app
+/// don't stutter.
+///
+/// Rendering text for the first time loads fonts and fills shaping caches, and some GPU
+/// drivers only finish compiling the renderer's pipelines when they're first used.
+/// Both can take hundreds of milliseconds, which users otherwise notice as jank the first
+/// time they hover a button or open a menu.
+///
+/// The warm-up runs once, before the windows created at startup are shown.
+/// It's set with [`MasonryState::set_warm_up`](crate::app::MasonryState::set_warm_up).
+pub struct WarmUp {
+    pub(crate) text_styles: Vec<StyleSet>,
+    pub(crate) splash: Option<Box<dyn FnMut(WarmUpStage)>>,
+}
+
+impl WarmUp {
+    /// Creates a warm-up which shapes text with each of the `text_styles`, such as those
+    /// of the app's theme.
+    pub fn new(text_styles: impl IntoIterator<Item = StyleSet>) -> Self {
+        Self {
+            text_styles: text_styles.into_iter().collect(),
+            splash: None,
+        }
+    }
+
+    /// Calls `splash` before each stage of the warm-up.
+    ///
+    /// The windows are hidden during the warm-up, so apps can use this to show progress
+    /// in a splash screen of their own, or to log how long the warm-up takes.
+    pub fn with_splash(mut self, splash: impl FnMut(WarmUpStage) + 'static) -> Self {
+        self.splash = Some(Box::new(splash));
+        self
+    }
+
+    pub(crate) fn report(&mut self, stage: WarmUpStage) {
+        if let Some(splash) = &mut self.splash {
+            splash(stage);
+        }
+    }
+}
+
+impl fmt::Debug for WarmUp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WarmUp")
+            .field("text_styles", &self.text_styles.len())
+            .field("splash", &self.splash.is_some())
+            .finish()
+    }
+}
+
+/// Returns a scene using every kind of drawing widgets commonly do, other than text.
+pub(crate) fn shapes_scene() -> Scene {
+    let mut scene = Scene::new();
+    let rect = Rect::new(8., 8., 120., 64.);
+    let rounded = RoundedRect::from_rect(rect, 6.);
+    scene.fill(
+        Fill::NonZero,
+        Affine::IDENTITY,
+        Color::WHITE,
+        None,
+        &rounded,
+    );
+    scene.stroke(
+        &Stroke::new(1.5),
+        Affine::IDENTITY,
+        Color::BLACK,
+        None,
+        &rounded,
+    );
+
+    let gradient = Gradient::new_linear((8., 72.), (120., 72.))
+        .with_stops([(0., Color::WHITE), (1., Color::BLACK)]);
+    scene.fill(
+        Fill::EvenOdd,
+        Affine::IDENTITY,
+        &gradient,
+        None,
+        &Circle::new((64., 100.), 24.),
+    );
+
+    scene.draw_blurred_rounded_rect(
+        Affine::IDENTITY,
+        Rect::new(136., 8., 248., 64.),
+        Color::BLACK.with_alpha(0.5),
+        6.,
+        4.,
+    );
+
+    // A translucent layer, as used by widgets with an opacity.
+    let image_rect = Rect::new(136., 72., 168., 104.);
+    scene.push_layer(
+        Fill::NonZero,
+        Mix::Normal,
+        0.5,
+        Affine::IDENTITY,
+        &image_rect,
+    );
+    let image = ImageData {
+        data: Blob::new(Arc::new(vec![0x80; 4 * 4 * 4])),
+        format: ImageFormat::Rgba8,
+        alpha_type: ImageAlphaType::Alpha,
+        width: 4,
+        height: 4,
+    };
+    scene.draw_image(
+        &ImageBrush::new(image),
+        Affine::translate(image_rect.origin().to_vec2()) * Affine::scale(8.),
+    );
+    scene.pop_layer();
+    scene
+}
@@ -197,6 +197,7 @@ impl Status {
                     right: 42.,
                     top: 30.,
                     bottom: 0.,
+                    ..Padding::ZERO
                 });
                 OneOf3::C(zstack((
                     image(image_data.clone()),
@@ -106,6 +106,7 @@ impl VirtualCats {
                     right: 42.,
                     top: 30.,
                     bottom: 0.,
+                    ..Padding::ZERO
                 });
                 let imgview = zstack((
                     image(img.clone()).fit(ObjectFit::FitWidth),
@@ -4,14 +4,18 @@
 use std::iter::Once;
 use std::sync::Arc;
 
-use masonry::core::DefaultProperties;
+use masonry::core::{DefaultProperties, StyleProperty, StyleSet};
+use masonry::parley::FontWeight;
 use masonry::peniko::Blob;
-use masonry::theme::default_property_set;
-use masonry_winit::app::{EventLoopBuilder, MasonryUserEvent, NewWindow, WindowId};
+use masonry::theme::{self, default_property_set, default_text_styles};
+use masonry_winit::app::{
+    EventLoopBuilder, MasonryUserEvent, NewWindow, WarmUp, WarmUpStage, WindowId,
+};
 use tokio::runtime::Runtime as TokioRuntime;
 use winit::error::EventLoopError;
 
 use crate::core::{Edit, map_state};
+use crate::persistence::Persistence;
 use crate::window_options::WindowCallbacks;
 use crate::{MasonryDriver, WidgetView, WindowOptions, WindowView};
 
@@ -26,6 +30,8 @@ pub struct Xilem<State, Logic> {
     default_properties: Option<DefaultProperties>,
     // Font data to include in loading.
     fonts: Vec<Blob<u8>>,
+    persistence: Option<Persistence>,
+    warm_up: Option<WarmUp>,
 }
 
 /// State type used by [`Xilem::new_simple`].
@@ -85,13 +91,19 @@ impl<State>
                 running: true,
             },
             Box::new(move |ExitOnClose { state, .. }| {
-                let callbacks = callbacks.clone();
+                let close_callbacks = callbacks.clone();
                 let on_close = move |wrapper: &mut ExitOnClose<_>| {
                     wrapper.running = false;
-                    if let Some(on_close) = &callbacks.on_close {
+                    if let Some(on_close) = &close_callbacks.on_close {
                         on_close(&mut wrapper.state);
                     }
                 };
+                let callbacks = callbacks.clone();
+                let on_fullscreen_changed = move |wrapper: &mut ExitOnClose<_>, fullscreen| {
+                    if let Some(on_fullscreen_changed) = &callbacks.on_fullscreen_changed {
+                        on_fullscreen_changed(&mut wrapper.state, fullscreen);
+                    }
+                };
                 std::iter::once(
                     crate::window(
                         window_id,
@@ -106,6 +118,7 @@ impl<State>
                         initial: window_options.initial.clone(),
                         callbacks: WindowCallbacks {
                             on_close: Some(Box::new(on_close)),
+                            on_fullscreen_changed: Some(Box::new(on_fullscreen_changed)),
                         },
                     }),
                 )
@@ -147,6 +160,8 @@ where
             runtime,
             default_properties: None,
             fonts: Vec::new(),
+            persistence: None,
+            warm_up: None,
         }
     }
 
@@ -158,6 +173,45 @@ where
         self
     }
 
+    /// Persists view state across app restarts in the given store.
+    ///
+    /// Views declare which state to persist with [`persist`](crate::view::persist).
+    /// The store is saved when the app exits.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use xilem::persistence::{FileStorage, Persistence};
+    /// # use xilem::{WindowOptions, Xilem, view::label};
+    ///
+    /// # let app = Xilem::new_simple((), |_| label("Hello"), WindowOptions::new("App"));
+    /// app.with_persistence(Persistence::new(FileStorage::new("view_state.json")));
+    /// ```
+    pub fn with_persistence(mut self, persistence: Persistence) -> Self {
+        self.persistence = Some(persistence);
+        self
+    }
+
+    /// Warms up the renderer and the theme's fonts before showing the first windows, so that
+    /// the first interactions with the app don't stutter.
+    ///
+    /// This delays showing the windows, usually by a few hundred milliseconds.
+    /// `splash` is called before each stage of the warm-up, see [`WarmUp::with_splash`].
+    pub fn with_warm_up(mut self, splash: impl FnMut(WarmUpStage) + 'static) -> Self {
+        let theme_styles = |weight| {
+            let mut styles = StyleSet::new(theme::TEXT_SIZE_NORMAL);
+            default_text_styles(&mut styles);
+            styles.insert(StyleProperty::FontWeight(weight));
+            styles
+        };
+        let text_styles = [
+            theme_styles(FontWeight::NORMAL),
+            theme_styles(FontWeight::BOLD),
+        ];
+        self.warm_up = Some(WarmUp::new(text_styles).with_splash(splash));
+        self
+    }
+
     // TODO: Find better ways to customize default property set.
     /// Sets default properties of widget tree.
     pub fn with_default_properties(mut self, default_properties: DefaultProperties) -> Self {
@@ -186,6 +240,14 @@ where
         self,
         proxy: impl Fn(MasonryUserEvent) -> Result<(), MasonryUserEvent> + Send + Sync + 'static,
     ) -> (MasonryDriver<State, Logic>, Vec<NewWindow>) {
-        MasonryDriver::new(self.state, self.logic, proxy, self.runtime, self.fonts)
+        MasonryDriver::new(
+            self.state,
+            self.logic,
+            proxy,
+            self.runtime,
+            self.fonts,
+            self.persistence,
+            self.warm_up,
+        )
     }
 }
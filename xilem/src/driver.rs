@@ -8,13 +8,14 @@ use std::sync::Arc;
 use masonry::core::{ErasedAction, WidgetId};
 use masonry::peniko::Blob;
 use masonry_winit::app::{
-    AppDriver, DriverCtx, MasonryState, MasonryUserEvent, NewWindow, WindowId,
+    AppDriver, DriverCtx, MasonryState, MasonryUserEvent, NewWindow, WarmUp, WindowId,
 };
 
 use crate::core::{
     DynMessage, MessageCtx, MessageResult, ProxyError, RawProxy, SendMessage, View, ViewId,
     ViewPathTracker,
 };
+use crate::persistence::Persistence;
 use crate::window_view::{WindowView, WindowViewState};
 use crate::{AppState, ViewCtx};
 
@@ -30,6 +31,9 @@ pub struct MasonryDriver<State: 'static, Logic> {
     runtime: Arc<tokio::runtime::Runtime>,
     // Fonts which will be registered on startup.
     fonts: Vec<Blob<u8>>,
+    persistence: Option<Persistence>,
+    // The work to do before showing the first windows.
+    warm_up: Option<WarmUp>,
 }
 
 struct Window<State: 'static> {
@@ -52,6 +56,8 @@ where
         event_sink: impl Fn(MasonryUserEvent) -> Result<(), MasonryUserEvent> + Send + Sync + 'static,
         runtime: Arc<tokio::runtime::Runtime>,
         fonts: Vec<Blob<u8>>,
+        persistence: Option<Persistence>,
+        warm_up: Option<WarmUp>,
     ) -> (Self, Vec<NewWindow>) {
         let mut driver = Self {
             state,
@@ -60,6 +66,8 @@ where
             proxy: Arc::new(MasonryProxy(Box::new(event_sink))),
             runtime,
             fonts,
+            persistence,
+            warm_up,
         };
         let windows: Vec<_> = (driver.logic)(&mut driver.state)
             .map(|view| driver.build_window(view))
@@ -134,6 +142,7 @@ where
             Arc::new(WindowProxy(window_view.id, self.proxy.clone())),
             self.runtime.clone(),
         );
+        view_ctx.set_persistence(self.persistence.clone());
         let (new_window, view_state) = window_view.build(&mut view_ctx, &mut self.state);
         self.windows.insert(
             window_view.id,
@@ -182,6 +191,7 @@ where
                     view_ctx,
                     view_state,
                 }) => {
+                    start_rebuild_diff(view_ctx);
                     next_view.rebuild(
                         view,
                         view_state,
@@ -189,6 +199,7 @@ where
                         driver_ctx.window(next_view.id),
                         &mut self.state,
                     );
+                    log_rebuild_diff(next_view.id, view_ctx);
                     *view = next_view;
                 }
                 None => self.create_window(driver_ctx, next_view),
@@ -262,6 +273,7 @@ where
                 self.run_logic(masonry_ctx);
             }
             MessageResult::RequestRebuild => {
+                start_rebuild_diff(&mut window.view_ctx);
                 window.view.masonry_root.rebuild(
                     &window.view.masonry_root,
                     &mut window.view_state,
@@ -269,6 +281,7 @@ where
                     masonry_ctx.render_root(window_id),
                     &mut self.state,
                 );
+                log_rebuild_diff(window_id, &mut window.view_ctx);
             }
             MessageResult::Nop => {}
             MessageResult::Stale => {
@@ -278,6 +291,22 @@ where
     }
 }
 
+/// Discards the views recorded before a rebuild, e.g. while building the window.
+fn start_rebuild_diff(view_ctx: &mut ViewCtx) {
+    if let Some(diff) = view_ctx.rebuild_diff() {
+        diff.clear();
+    }
+}
+
+/// Logs the views recorded during a rebuild, if rebuild diffs are enabled.
+///
+/// See [`ViewCtx::set_rebuild_diff`].
+fn log_rebuild_diff(window_id: WindowId, view_ctx: &mut ViewCtx) {
+    if let Some(diff) = view_ctx.rebuild_diff() {
+        tracing::info!(window_id = window_id.trace(), "Rebuild diff:\n{diff}");
+    }
+}
+
 impl<State, Logic, WindowIter> AppDriver for MasonryDriver<State, Logic>
 where
     State: AppState + 'static,
@@ -356,6 +385,27 @@ where
                 drop(root.register_fonts(font.clone()));
             }
         }
+
+        if let Some(warm_up) = self.warm_up.take() {
+            state.set_warm_up(warm_up);
+        }
+    }
+
+    fn on_fullscreen_changed(
+        &mut self,
+        window_id: WindowId,
+        ctx: &mut DriverCtx<'_, '_>,
+        fullscreen: bool,
+    ) {
+        let Some(window) = self.windows.get(&window_id) else {
+            return;
+        };
+        if window
+            .view
+            .on_fullscreen_changed(&mut self.state, fullscreen)
+        {
+            self.run_logic(ctx);
+        }
     }
 
     fn on_close_requested(&mut self, window_id: WindowId, ctx: &mut DriverCtx<'_, '_>) {
@@ -365,6 +415,11 @@ where
 
         if !self.state.keep_running() {
             // TODO: we should probably call teardown for all windows before exiting => introduce AppDriver::on_exit
+            if let Some(persistence) = &self.persistence
+                && let Err(err) = persistence.save()
+            {
+                tracing::error!("Failed to save persisted state: {err}");
+            }
             ctx.exit();
         }
     }
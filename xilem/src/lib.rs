@@ -159,6 +159,7 @@ pub use tokio;
 pub use vello;
 pub use winit;
 pub use xilem_core as core;
+pub use xilem_masonry::persistence;
 pub use xilem_masonry::style;
 pub use xilem_masonry::view;
 
@@ -166,7 +167,7 @@ pub use masonry::parley::Alignment as TextAlign;
 pub use masonry::parley::style::FontWeight;
 pub use masonry::peniko::{Blob, Color, ImageBrush, ImageFormat};
 pub use masonry::widgets::InsertNewline;
-pub use masonry_winit::app::{EventLoop, EventLoopBuilder, WindowId};
+pub use masonry_winit::app::{EventLoop, EventLoopBuilder, FramePacing, WindowId};
 
 pub use xilem_masonry::{
     AnyWidgetView, InitialRootWidget, MasonryRoot, Pod, ViewCtx, WidgetView, WidgetViewSequence,
@@ -1,8 +1,13 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::sync::Arc;
+
+use masonry_winit::app::Window as MasonryWindow;
 use winit::dpi::{Position, Size};
-use winit::window::{Cursor, Icon, Window, WindowAttributes, WindowButtons, WindowLevel};
+use winit::window::{
+    Cursor, Fullscreen, Icon, Window, WindowAttributes, WindowButtons, WindowLevel,
+};
 
 // TODO: make this a type-state builder to force Xilem::new apps to define on_close?
 /// Attributes and callbacks of a window.
@@ -29,6 +34,10 @@ pub(crate) struct ReactiveWindowAttrs {
     max_inner_size: Option<Size>,
     window_level: WindowLevel,
     decorations: bool,
+    window_icon: Option<IconPixels>,
+    // The user can leave fullscreen mode too, but apps are told with `on_fullscreen_changed`.
+    fullscreen: Option<Fullscreen>,
+    pub(crate) exit_fullscreen_on_escape: bool,
     platform_specific: PlatformSpecificReactiveWindowAttrs,
 }
 
@@ -37,18 +46,40 @@ pub(crate) struct ReactiveWindowAttrs {
 pub(crate) struct InitialAttrs {
     inner_size: Option<Size>,
     position: Option<Position>,
+    pub(crate) monitor: Option<usize>,
+    maximized: bool,
     transparent: bool,
     // TODO: move window_icon to ReactiveWindowAttrs once the winit type implements PartialEq
     window_icon: Option<Icon>,
     platform_specific: PlatformSpecificInitialWindowAttrs,
 }
 
+/// The pixels of a window icon, which unlike [`Icon`] can be compared.
+#[derive(Clone, Debug, PartialEq)]
+struct IconPixels {
+    rgba: Arc<[u8]>,
+    width: u32,
+    height: u32,
+}
+
+impl IconPixels {
+    fn to_icon(&self) -> Option<Icon> {
+        Icon::from_rgba(self.rgba.to_vec(), self.width, self.height)
+            .inspect_err(|err| tracing::warn!("invalid window icon: {err}"))
+            .ok()
+    }
+}
+
 pub(crate) struct WindowCallbacks<State> {
     pub(crate) on_close: Option<Box<dyn Fn(&mut State)>>,
+    pub(crate) on_fullscreen_changed: Option<Box<dyn Fn(&mut State, bool)>>,
 }
 impl<S> Default for WindowCallbacks<S> {
     fn default() -> Self {
-        Self { on_close: None }
+        Self {
+            on_close: None,
+            on_fullscreen_changed: None,
+        }
     }
 }
 
@@ -65,11 +96,16 @@ impl<State> WindowOptions<State> {
                 max_inner_size: None,
                 window_level: WindowLevel::default(),
                 decorations: true,
+                window_icon: None,
+                fullscreen: None,
+                exit_fullscreen_on_escape: true,
                 platform_specific: PlatformSpecificReactiveWindowAttrs::default(),
             },
             initial: InitialAttrs {
                 inner_size: None,
                 position: None,
+                monitor: None,
+                maximized: false,
                 transparent: false,
                 window_icon: None,
                 platform_specific: PlatformSpecificInitialWindowAttrs::default(),
@@ -84,6 +120,17 @@ impl<State> WindowOptions<State> {
         self
     }
 
+    /// Sets a callback to execute when the window entered (`true`) or left (`false`)
+    /// fullscreen mode.
+    ///
+    /// This is also called when the user or the platform changes the mode, e.g. when the
+    /// user presses Escape, so apps setting [`with_fullscreen`](Self::with_fullscreen)
+    /// from their state should update it here.
+    pub fn on_fullscreen_changed(mut self, callback: impl Fn(&mut State, bool) + 'static) -> Self {
+        self.callbacks.on_fullscreen_changed = Some(Box::new(callback));
+        self
+    }
+
     /// Sets whether the window is resizable or not.
     ///
     /// The default is `true`.
@@ -128,6 +175,18 @@ impl<State> WindowOptions<State> {
         self
     }
 
+    /// Sets whether the window stays above other windows.
+    ///
+    /// This is a shorthand for [`with_window_level`](Self::with_window_level) with
+    /// [`WindowLevel::AlwaysOnTop`] or [`WindowLevel::Normal`].
+    pub fn with_always_on_top(self, always_on_top: bool) -> Self {
+        self.with_window_level(if always_on_top {
+            WindowLevel::AlwaysOnTop
+        } else {
+            WindowLevel::Normal
+        })
+    }
+
     /// Sets whether the window should have a border, a title bar, etc.
     ///
     /// The default is `true`.
@@ -148,6 +207,48 @@ impl<State> WindowOptions<State> {
         self
     }
 
+    /// Opens the window on the monitor at `index` among the available monitors.
+    ///
+    /// The window is centered on that monitor unless it has an
+    /// [initial position](Self::with_initial_position).
+    /// See [`NewWindow::with_monitor`](masonry_winit::app::NewWindow::with_monitor).
+    pub fn with_initial_monitor(mut self, index: usize) -> Self {
+        self.initial.monitor = Some(index);
+        self
+    }
+
+    /// Sets whether the window starts maximized.
+    ///
+    /// The default is `false`.
+    pub fn with_initial_maximized(mut self, maximized: bool) -> Self {
+        self.initial.maximized = maximized;
+        self
+    }
+
+    /// Sets whether the window is in fullscreen mode.
+    ///
+    /// [`Fullscreen::Borderless`] covers a monitor with the window, and
+    /// [`Fullscreen::Exclusive`] also changes its video mode.
+    /// When the window opens, `Fullscreen::Borderless(None)` goes fullscreen on the
+    /// [initial monitor](Self::with_initial_monitor), if any.
+    ///
+    /// The user can also leave fullscreen mode, which is reported to
+    /// [`on_fullscreen_changed`](Self::on_fullscreen_changed).
+    ///
+    /// The default is `None`.
+    pub fn with_fullscreen(mut self, fullscreen: Option<Fullscreen>) -> Self {
+        self.reactive.fullscreen = fullscreen;
+        self
+    }
+
+    /// Sets whether pressing Escape makes the window leave fullscreen mode.
+    ///
+    /// The default is `true`.
+    pub fn with_exit_fullscreen_on_escape(mut self, exit_fullscreen_on_escape: bool) -> Self {
+        self.reactive.exit_fullscreen_on_escape = exit_fullscreen_on_escape;
+        self
+    }
+
     /// Sets the window icon.
     ///
     /// The default is `None`.
@@ -156,6 +257,20 @@ impl<State> WindowOptions<State> {
         self
     }
 
+    /// Sets the window icon from its pixels, in row-major RGBA order with 8 bits per channel.
+    ///
+    /// Unlike [`with_initial_window_icon`](Self::with_initial_window_icon), the icon can be
+    /// changed while the window is open, and takes precedence over the initial icon.
+    /// If the length of `rgba` doesn't match the size, no icon is set and a warning is logged.
+    pub fn with_window_icon(mut self, rgba: impl Into<Arc<[u8]>>, width: u32, height: u32) -> Self {
+        self.reactive.window_icon = Some(IconPixels {
+            rgba: rgba.into(),
+            width,
+            height,
+        });
+        self
+    }
+
     /// Sets whether the background of the window should be transparent.
     ///
     /// If this is `true`, writing colors with alpha values different than
@@ -177,9 +292,14 @@ impl<State> WindowOptions<State> {
             .with_cursor(self.reactive.cursor.clone())
             .with_window_level(self.reactive.window_level)
             .with_decorations(self.reactive.decorations)
+            .with_maximized(self.initial.maximized)
+            .with_fullscreen(self.reactive.fullscreen.clone())
             .with_transparent(self.initial.transparent)
             .with_window_icon(self.initial.window_icon.clone());
 
+        if let Some(window_icon) = &self.reactive.window_icon {
+            attrs = attrs.with_window_icon(window_icon.to_icon());
+        }
         if let Some(min_inner_size) = self.reactive.min_inner_size {
             attrs = attrs.with_min_inner_size(min_inner_size);
         }
@@ -197,8 +317,11 @@ impl<State> WindowOptions<State> {
             .build(self.reactive.platform_specific.build(attrs))
     }
 
-    pub(crate) fn rebuild(&self, prev: &Self, window: &Window) {
-        self.rebuild_reactive_window_attributes(prev, window);
+    pub(crate) fn rebuild(&self, prev: &Self, window: &mut MasonryWindow) {
+        self.rebuild_reactive_window_attributes(prev, window.handle());
+        if self.reactive.exit_fullscreen_on_escape != prev.reactive.exit_fullscreen_on_escape {
+            window.set_exit_fullscreen_on_escape(self.reactive.exit_fullscreen_on_escape);
+        }
         self.warn_for_changed_initial_attributes(prev);
     }
 
@@ -230,6 +353,12 @@ impl<State> WindowOptions<State> {
         if current.decorations != prev.decorations {
             window.set_decorations(current.decorations);
         }
+        if current.window_icon != prev.window_icon {
+            window.set_window_icon(current.window_icon.as_ref().and_then(IconPixels::to_icon));
+        }
+        if current.fullscreen != prev.fullscreen {
+            window.set_fullscreen(current.fullscreen.clone());
+        }
 
         current
             .platform_specific
@@ -250,6 +379,16 @@ impl<State> WindowOptions<State> {
                 "attempted to change position attribute after window creation, this is not supported"
             );
         }
+        if current.monitor != prev.monitor {
+            tracing::warn!(
+                "attempted to change monitor attribute after window creation, this is not supported"
+            );
+        }
+        if current.maximized != prev.maximized {
+            tracing::warn!(
+                "attempted to change maximized attribute after window creation, this is not supported"
+            );
+        }
         if current.transparent != prev.transparent {
             tracing::warn!(
                 "attempted to change transparent attribute after window creation, this is not supported"
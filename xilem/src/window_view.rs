@@ -1,9 +1,10 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use masonry::core::AnnouncementPriority;
 use masonry::peniko::Color;
 use masonry::theme::BACKGROUND_COLOR;
-use masonry_winit::app::{NewWindow, Window, WindowId};
+use masonry_winit::app::{FramePacing, KioskMode, NewWindow, Window, WindowId};
 
 use crate::core::{Arg, Edit, MessageCtx, Mut, View, ViewElement, ViewMarker};
 use crate::{AnyWidgetView, InitialRootWidget, MasonryRoot, ViewCtx, WidgetView, WindowOptions};
@@ -15,6 +16,12 @@ pub struct WindowView<State: 'static> {
     pub(crate) masonry_root: MasonryRoot<State>,
     /// The base color of the window.
     pub(crate) base_color: Color,
+    /// How often the window renders animation frames.
+    pub(crate) frame_pacing: FramePacing,
+    /// How the window behaves as a kiosk.
+    pub(crate) kiosk_mode: KioskMode,
+    /// The announcements screen readers make when the window is rebuilt.
+    pub(crate) announcements: Vec<(String, AnnouncementPriority)>,
 }
 
 pub(crate) type WindowViewState = <Box<AnyWidgetView<(), ()>> as View<(), (), ViewCtx>>::ViewState;
@@ -36,6 +43,9 @@ pub fn window<V: WidgetView<Edit<State>>, State: 'static>(
         options: WindowOptions::new(title),
         masonry_root: MasonryRoot::new(root_view),
         base_color: BACKGROUND_COLOR,
+        frame_pacing: FramePacing::DEFAULT,
+        kiosk_mode: KioskMode::DEFAULT,
+        announcements: Vec::new(),
     }
 }
 
@@ -56,6 +66,37 @@ impl<State> WindowView<State> {
         self.base_color = color;
         self
     }
+
+    /// Set how often the window renders animation frames.
+    ///
+    /// This is [`FramePacing::DEFAULT`] by default, which renders animations at 30 frames
+    /// per second while the window isn't focused, and pauses them while it's hidden.
+    pub fn with_frame_pacing(mut self, frame_pacing: FramePacing) -> Self {
+        self.frame_pacing = frame_pacing;
+        self
+    }
+
+    /// Set how the window behaves as a kiosk, e.g. hiding the cursor while it's idle.
+    ///
+    /// Kiosk apps can ignore requests to close the window in
+    /// [`WindowOptions::on_close`] until they're confirmed.
+    pub fn with_kiosk_mode(mut self, kiosk_mode: KioskMode) -> Self {
+        self.kiosk_mode = kiosk_mode;
+        self
+    }
+
+    /// Makes screen readers announce `text` when the window is rebuilt with this view,
+    /// e.g. "3 results found".
+    ///
+    /// The app logic runs after each callback, so callbacks can queue announcements in
+    /// the app state, for the logic to take them when returning the window.
+    /// Announcements made when the window is created are dropped.
+    ///
+    /// See [`EventCtx::announce`](masonry::core::EventCtx::announce).
+    pub fn announce(mut self, text: impl Into<String>, priority: AnnouncementPriority) -> Self {
+        self.announcements.push((text.into(), priority));
+        self
+    }
 }
 
 /// A newtype wrapper around [`NewWindow`] for implementing [`ViewElement`].
@@ -81,17 +122,14 @@ impl<State> View<Edit<State>, (), ViewCtx> for WindowView<State> {
     ) -> (Self::Element, Self::ViewState) {
         let (InitialRootWidget(root_widget), view_state) = self.masonry_root.build(ctx, app_state);
         let initial_attributes = self.options.build_initial_attrs();
-        (
-            PodWindow(
-                NewWindow::new_with_id(
-                    self.id,
-                    initial_attributes,
-                    root_widget.new_widget.erased(),
-                )
-                .with_base_color(self.base_color),
-            ),
-            view_state,
-        )
+        let mut new_window =
+            NewWindow::new_with_id(self.id, initial_attributes, root_widget.new_widget.erased())
+                .with_base_color(self.base_color)
+                .with_frame_pacing(self.frame_pacing)
+                .with_exit_fullscreen_on_escape(self.options.reactive.exit_fullscreen_on_escape)
+                .with_kiosk_mode(self.kiosk_mode);
+        new_window.monitor = self.options.initial.monitor;
+        (PodWindow(new_window), view_state)
     }
 
     fn rebuild(
@@ -102,10 +140,19 @@ impl<State> View<Edit<State>, (), ViewCtx> for WindowView<State> {
         window: Mut<'_, Self::Element>,
         app_state: Arg<'_, Edit<State>>,
     ) {
-        self.options.rebuild(&prev.options, window.handle());
+        self.options.rebuild(&prev.options, window);
         if self.base_color != prev.base_color {
             *window.base_color() = self.base_color;
         }
+        if self.frame_pacing != prev.frame_pacing {
+            window.set_frame_pacing(self.frame_pacing);
+        }
+        if self.kiosk_mode != prev.kiosk_mode {
+            window.set_kiosk_mode(self.kiosk_mode);
+        }
+        for (text, priority) in &self.announcements {
+            window.render_root().announce(text.clone(), *priority);
+        }
 
         self.masonry_root.rebuild(
             &prev.masonry_root,
@@ -147,4 +194,13 @@ where
             on_close(state);
         }
     }
+
+    /// Returns `false` if the window has no fullscreen callback.
+    pub(crate) fn on_fullscreen_changed(&self, state: &mut State, fullscreen: bool) -> bool {
+        let Some(on_fullscreen_changed) = &self.options.callbacks.on_fullscreen_changed else {
+            return false;
+        };
+        on_fullscreen_changed(state, fullscreen);
+        true
+    }
 }
@@ -28,6 +28,16 @@ pub trait ElementSplice<Element: ViewElement> {
     fn index(&self) -> usize;
     /// Delete the next existing element, after running a function on it.
     fn delete<R>(&mut self, f: impl FnOnce(Element::Mut<'_>) -> R) -> R;
+    /// Move the existing element `n` elements after the next one, so that it becomes the next
+    /// element, keeping the order of the elements in between.
+    ///
+    /// Returns `false` without making any changes if this splice can't move elements,
+    /// in which case the element has to be deleted and a new one inserted instead.
+    /// This is used by [`keyed`](crate::keyed) sequences to keep the state of reordered elements.
+    fn move_from(&mut self, n: usize) -> bool {
+        let _ = n;
+        false
+    }
 }
 
 /// An append only `Vec`.
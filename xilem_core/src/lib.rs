@@ -56,6 +56,7 @@ mod environment;
 mod message;
 mod message_context;
 mod message_proxy;
+mod rebuild_diff;
 mod view;
 mod view_argument;
 mod view_ctx;
@@ -74,11 +75,12 @@ pub use self::environment::{
 pub use self::message::{DynMessage, MessageResult, SendMessage};
 pub use self::message_context::MessageCtx;
 pub use self::message_proxy::{MessageProxy, ProxyError, RawProxy};
+pub use self::rebuild_diff::{RebuildDiff, RebuildDiffNode, ViewChange};
 pub use self::view::{View, ViewMarker};
 pub use self::view_argument::{Arg, Edit, Read, ViewArgument};
 pub use self::view_ctx::{ViewId, ViewPathTracker};
 pub use self::view_sequence::{Count, ViewSequence};
-pub use self::view_sequences::{WithoutElements, without_elements};
+pub use self::view_sequences::{Keyed, WithoutElements, keyed, without_elements};
 pub use self::views::{
     Fork, Frozen, Lens, MapMessage, MapState, Memoize, OrphanView, RunOnce, fork, frozen, lens,
     map_action, map_message_result, map_state, memoize, one_of, run_once, run_once_raw,
@@ -0,0 +1,161 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Recording of what changed during a rebuild, for debugging.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::ViewPathTracker;
+
+/// What happened to a view and its element during a rebuild.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ViewChange {
+    /// The view was rebuilt against the previous view, and its element was updated in place.
+    Rebuilt,
+    /// The view was skipped, because it was [memoized](crate::memoize) and its inputs
+    /// didn't change.
+    Skipped,
+    /// The view was built, and its element was added.
+    Built,
+    /// The view had a different type than the previous view, so the previous element
+    /// was torn down and replaced with a newly built one.
+    Replaced,
+    /// The view was torn down, and its element was removed.
+    TornDown,
+}
+
+/// A view in a [`RebuildDiff`], with the views recorded while it was processed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RebuildDiffNode {
+    /// The name of the view's type, without its module path or generic parameters.
+    pub view: &'static str,
+    /// What happened to the view.
+    pub change: ViewChange,
+    /// The views recorded while this one was processed.
+    pub children: Vec<Self>,
+}
+
+/// A tree of the views which were rebuilt, skipped, built or torn down during a rebuild.
+///
+/// This helps debug rebuild churn, e.g. a list which flickers because its items are replaced
+/// instead of rebuilt, or state which resets because a view's type changes.
+///
+/// Views are recorded where the shape of the element tree can change: the items of
+/// [view sequences](crate::ViewSequence), [type-erased views](crate::AnyView), and
+/// memoized views (such as [`memoize`](crate::memoize) or [`Arc`](alloc::sync::Arc)).
+/// Other views are part of the node of their closest recorded ancestor.
+///
+/// A rebuild diff is only recorded if the context returns one from
+/// [`ViewPathTracker::rebuild_diff`].
+/// Its [`Display`](fmt::Display) implementation prints the tree, one view per line.
+#[derive(Clone, Debug, Default)]
+pub struct RebuildDiff {
+    /// The views recorded at the top level.
+    nodes: Vec<RebuildDiffNode>,
+    /// The views being processed, innermost last.
+    open: Vec<RebuildDiffNode>,
+}
+
+impl RebuildDiff {
+    /// Creates an empty diff.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts recording a view, which will be the parent of the views recorded until the
+    /// matching [`exit`](Self::exit).
+    pub fn enter(&mut self, view: &'static str, change: ViewChange) {
+        self.open.push(RebuildDiffNode {
+            view,
+            change,
+            children: Vec::new(),
+        });
+    }
+
+    /// Finishes recording the view started by the last unmatched [`enter`](Self::enter).
+    pub fn exit(&mut self) {
+        if let Some(node) = self.open.pop() {
+            match self.open.last_mut() {
+                Some(parent) => parent.children.push(node),
+                None => self.nodes.push(node),
+            }
+        }
+    }
+
+    /// Records a view with no children.
+    pub fn record(&mut self, view: &'static str, change: ViewChange) {
+        self.enter(view, change);
+        self.exit();
+    }
+
+    /// Returns the views recorded at the top level.
+    pub fn nodes(&self) -> &[RebuildDiffNode] {
+        &self.nodes
+    }
+
+    /// Returns `true` if no views were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Discards the recorded views.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.open.clear();
+    }
+}
+
+impl fmt::Display for RebuildDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_node(
+            f: &mut fmt::Formatter<'_>,
+            node: &RebuildDiffNode,
+            depth: usize,
+        ) -> fmt::Result {
+            let change = match node.change {
+                ViewChange::Rebuilt => "rebuilt",
+                ViewChange::Skipped => "skipped",
+                ViewChange::Built => "built",
+                ViewChange::Replaced => "replaced",
+                ViewChange::TornDown => "torn down",
+            };
+            let indent = depth * 2;
+            let view = node.view;
+            writeln!(f, "{:indent$}{change} {view}", "")?;
+            for child in &node.children {
+                write_node(f, child, depth + 1)?;
+            }
+            Ok(())
+        }
+
+        for node in &self.nodes {
+            write_node(f, node, 0)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the name of `V`, without its module path or generic parameters.
+fn view_name<V: ?Sized>() -> &'static str {
+    let name = core::any::type_name::<V>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Runs `f`, recording the view `V` around it if the context records a [`RebuildDiff`].
+pub(crate) fn record_view<V: ?Sized, Context: ViewPathTracker, R>(
+    ctx: &mut Context,
+    change: ViewChange,
+    f: impl FnOnce(&mut Context) -> R,
+) -> R {
+    let Some(diff) = ctx.rebuild_diff() else {
+        return f(ctx);
+    };
+    diff.enter(view_name::<V>(), change);
+    let result = f(ctx);
+    if let Some(diff) = ctx.rebuild_diff() {
+        diff.exit();
+    }
+    result
+}
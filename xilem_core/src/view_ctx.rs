@@ -1,6 +1,7 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::RebuildDiff;
 use crate::environment::Environment;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -61,6 +62,15 @@ pub trait ViewPathTracker {
     /// The path to the current view in the view tree
     fn view_path(&mut self) -> &[ViewId];
 
+    /// Returns the [`RebuildDiff`] recording the current rebuild, if any.
+    ///
+    /// Contexts which support debugging rebuilds can return a diff here, which views
+    /// then record what happens to them into.
+    /// The default implementation doesn't record anything.
+    fn rebuild_diff(&mut self) -> Option<&mut RebuildDiff> {
+        None
+    }
+
     /// Runs `builder` in a context with `id` pushed to the current view path
     fn with_id<R>(&mut self, id: ViewId, builder: impl FnOnce(&mut Self) -> R) -> R {
         self.push_id(id);
@@ -3,9 +3,10 @@
 
 //! Support for sequences of views with a shared element type.
 
+use crate::rebuild_diff::record_view;
 use crate::{
     AppendVec, Arg, ElementSplice, MessageCtx, MessageResult, SuperElement, View, ViewArgument,
-    ViewElement, ViewMarker, ViewPathTracker,
+    ViewChange, ViewElement, ViewMarker, ViewPathTracker,
 };
 
 /// Classes that a [`ViewSequence`] can be a member of, grouped based on the number
@@ -184,7 +185,8 @@ where
         elements: &mut AppendVec<Element>,
         app_state: Arg<'_, State>,
     ) -> Self::SeqState {
-        let (element, view_state) = self.build(ctx, app_state);
+        let (element, view_state) =
+            record_view::<V, _, _>(ctx, ViewChange::Built, |ctx| self.build(ctx, app_state));
         elements.push(Element::upcast(ctx, element));
         view_state
     }
@@ -199,7 +201,9 @@ where
         // Mutate the item we added in `seq_build`
         elements.mutate(|this_element| {
             Element::with_downcast(this_element, |element| {
-                self.rebuild(prev, seq_state, ctx, element, app_state);
+                record_view::<V, _, _>(ctx, ViewChange::Rebuilt, |ctx| {
+                    self.rebuild(prev, seq_state, ctx, element, app_state);
+                });
             });
         });
     }
@@ -211,7 +215,9 @@ where
     ) {
         elements.delete(|this_element| {
             Element::with_downcast(this_element, |element| {
-                self.teardown(seq_state, ctx, element);
+                record_view::<V, _, _>(ctx, ViewChange::TornDown, |ctx| {
+                    self.teardown(seq_state, ctx, element);
+                });
             });
         });
     }
@@ -0,0 +1,244 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use crate::{
+    AppendVec, Arg, Count, ElementSplice, MessageCtx, MessageResult, ViewArgument, ViewElement,
+    ViewId, ViewPathTracker, ViewSequence,
+};
+
+/// The [`ViewSequence`] for [`keyed`], see its documentation for more context.
+#[derive(Debug)]
+pub struct Keyed<K, V> {
+    items: Vec<(K, V)>,
+}
+
+/// A sequence of views which is diffed by key, instead of by index.
+///
+/// `key` returns a stable key for each of the `items`, and `view` the view for the item.
+/// When the sequence is rebuilt, each view is rebuilt against the previous view with the same key.
+/// If the items were reordered, the existing elements are moved instead of being rebuilt from
+/// another item's view, so they keep their state (such as scroll positions or text selections).
+///
+/// This is unlike a `Vec` of views, which matches views by index: removing the first
+/// item of a `Vec` rebuilds every other element from the view of the next item.
+///
+/// Each view must have exactly one element.
+/// Keys should be unique; an item with the key of an earlier item is built as a new view.
+/// If the container can't move its elements (see [`ElementSplice::move_from`]), moved
+/// items are torn down and built again.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_core::docs::{DocsViewSequence as WidgetViewSequence, some_component_generic};
+/// use xilem_core::{Edit, keyed, lens};
+///
+/// struct Todo {
+///     id: u64,
+///     text: String,
+/// }
+///
+/// fn todo_list(todos: &mut Vec<Todo>) -> impl WidgetViewSequence<Edit<Vec<Todo>>> + use<> {
+///     keyed(
+///         todos.iter().enumerate(),
+///         |(_, todo)| todo.id,
+///         |(idx, _)| {
+///             lens(some_component_generic::<Todo, ()>, move |todos: &mut Vec<Todo>, ()| {
+///                 &mut todos[idx]
+///             })
+///         },
+///     )
+/// }
+/// ```
+pub fn keyed<T, K, V>(
+    items: impl IntoIterator<Item = T>,
+    key: impl Fn(&T) -> K,
+    view: impl Fn(T) -> V,
+) -> Keyed<K, V>
+where
+    K: Hash + Eq + 'static,
+{
+    let items = items
+        .into_iter()
+        .map(|item| (key(&item), view(item)))
+        .collect();
+    Keyed { items }
+}
+
+/// The state used to implement `ViewSequence` for [`Keyed`].
+#[doc(hidden)]
+#[expect(
+    unnameable_types,
+    reason = "Implementation detail, public because of trait visibility rules"
+)]
+#[derive(Debug)]
+pub struct KeyedState<InnerState> {
+    /// The id and state of each item, in the order of the items.
+    ///
+    /// Each item gets a new id when it is built, so that messages for a torn down item are
+    /// never routed to a new item with the same key.
+    inner_states: Vec<(ViewId, InnerState)>,
+    next_id: u64,
+}
+
+impl<InnerState> KeyedState<InnerState> {
+    fn new_id(&mut self) -> ViewId {
+        let id = ViewId::new(self.next_id);
+        self.next_id += 1;
+        id
+    }
+}
+
+impl<State, Action, Context, Element, K, V> ViewSequence<State, Action, Context, Element>
+    for Keyed<K, V>
+where
+    State: ViewArgument,
+    Context: ViewPathTracker,
+    K: Hash + Eq + 'static,
+    V: ViewSequence<State, Action, Context, Element>,
+    Element: ViewElement,
+{
+    type SeqState = KeyedState<V::SeqState>;
+
+    const ELEMENTS_COUNT: Count = Count::Many;
+
+    fn seq_build(
+        &self,
+        ctx: &mut Context,
+        elements: &mut AppendVec<Element>,
+        mut app_state: Arg<'_, State>,
+    ) -> Self::SeqState {
+        const {
+            assert!(
+                matches!(V::ELEMENTS_COUNT, Count::One),
+                "Each view in a `keyed` sequence must have exactly one element."
+            );
+        };
+        let mut seq_state = KeyedState {
+            inner_states: Vec::with_capacity(self.items.len()),
+            next_id: 0,
+        };
+        for (_, view) in &self.items {
+            let id = seq_state.new_id();
+            let inner_state = ctx.with_id(id, |ctx| {
+                view.seq_build(ctx, elements, State::reborrow_mut(&mut app_state))
+            });
+            seq_state.inner_states.push((id, inner_state));
+        }
+        seq_state
+    }
+
+    fn seq_rebuild(
+        &self,
+        prev: &Self,
+        seq_state: &mut Self::SeqState,
+        ctx: &mut Context,
+        elements: &mut impl ElementSplice<Element>,
+        mut app_state: Arg<'_, State>,
+    ) {
+        let mut prev_indices: HashMap<&K, usize> = HashMap::with_capacity(prev.items.len());
+        for (idx, (key, _)) in prev.items.iter().enumerate().rev() {
+            prev_indices.insert(key, idx);
+        }
+        let mut prev_states: Vec<_> = seq_state.inner_states.drain(..).map(Some).collect();
+        // The index of the previous item of each element, in the order of the elements.
+        // The elements of the items already processed come first, followed by the elements
+        // of the previous items which haven't been reused yet.
+        let mut order: Vec<usize> = (0..prev.items.len()).collect();
+
+        for (idx, (key, view)) in self.items.iter().enumerate() {
+            let reused = prev_indices.remove(key).and_then(|prev_idx| {
+                let offset = order[idx..].iter().position(|it| *it == prev_idx)?;
+                if offset != 0 && !elements.move_from(offset) {
+                    return None;
+                }
+                order.remove(idx + offset);
+                order.insert(idx, prev_idx);
+                Some(prev_idx)
+            });
+            match reused {
+                Some(prev_idx) => {
+                    let (id, mut inner_state) = prev_states[prev_idx]
+                        .take()
+                        .expect("Each previous item is reused at most once");
+                    ctx.with_id(id, |ctx| {
+                        view.seq_rebuild(
+                            &prev.items[prev_idx].1,
+                            &mut inner_state,
+                            ctx,
+                            elements,
+                            State::reborrow_mut(&mut app_state),
+                        );
+                    });
+                    seq_state.inner_states.push((id, inner_state));
+                }
+                None => {
+                    // The new element is inserted before the remaining previous elements.
+                    order.insert(idx, usize::MAX);
+                    let id = seq_state.new_id();
+                    let inner_state = elements.with_scratch(|elements| {
+                        ctx.with_id(id, |ctx| {
+                            view.seq_build(ctx, elements, State::reborrow_mut(&mut app_state))
+                        })
+                    });
+                    seq_state.inner_states.push((id, inner_state));
+                }
+            }
+        }
+
+        for prev_idx in order.drain(self.items.len()..) {
+            let (id, mut inner_state) = prev_states[prev_idx]
+                .take()
+                .expect("Each previous item is torn down at most once");
+            ctx.with_id(id, |ctx| {
+                prev.items[prev_idx]
+                    .1
+                    .seq_teardown(&mut inner_state, ctx, elements);
+            });
+        }
+    }
+
+    fn seq_teardown(
+        &self,
+        seq_state: &mut Self::SeqState,
+        ctx: &mut Context,
+        elements: &mut impl ElementSplice<Element>,
+    ) {
+        for ((_, view), (id, inner_state)) in self.items.iter().zip(&mut seq_state.inner_states) {
+            ctx.with_id(*id, |ctx| view.seq_teardown(inner_state, ctx, elements));
+        }
+    }
+
+    fn seq_message(
+        &self,
+        seq_state: &mut Self::SeqState,
+        message: &mut MessageCtx,
+        elements: &mut impl ElementSplice<Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        let start = message
+            .take_first()
+            .expect("Id path has elements for Keyed");
+        let Some(idx) = seq_state
+            .inner_states
+            .iter()
+            .position(|(id, _)| *id == start)
+        else {
+            // The item has been torn down
+            return MessageResult::Stale;
+        };
+        // Each item has exactly one element.
+        elements.skip(idx);
+        self.items[idx].1.seq_message(
+            &mut seq_state.inner_states[idx].1,
+            message,
+            elements,
+            app_state,
+        )
+    }
+}
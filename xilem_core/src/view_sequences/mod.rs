@@ -5,7 +5,9 @@ mod impl_array;
 mod impl_option;
 mod impl_tuples;
 mod impl_vec;
+mod keyed;
 mod without_elements;
 
+pub use self::keyed::{Keyed, keyed};
 pub(crate) use self::without_elements::NoElements;
 pub use self::without_elements::{WithoutElements, without_elements};
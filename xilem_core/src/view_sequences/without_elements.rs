@@ -36,6 +36,10 @@ impl ElementSplice<NoElement> for NoElements {
     fn delete<R>(&mut self, f: impl FnOnce(<NoElement as ViewElement>::Mut<'_>) -> R) -> R {
         f(())
     }
+
+    fn move_from(&mut self, _: usize) -> bool {
+        true
+    }
 }
 
 /// The [`ViewSequence`] for [`without_elements`], see its documentation for more context.
@@ -6,9 +6,10 @@
 use alloc::boxed::Box;
 use core::any::Any;
 
+use crate::rebuild_diff::record_view;
 use crate::{
-    AnyElement, Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewElement, ViewId,
-    ViewMarker, ViewPathTracker,
+    AnyElement, Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewChange, ViewElement,
+    ViewId, ViewMarker, ViewPathTracker,
 };
 
 /// A view which can have any view type where the [`View::Element`] is compatible with
@@ -110,24 +111,29 @@ where
                     .expect("build or rebuild always set the correct corresponding state type");
 
                 ctx.with_id(ViewId::new(dyn_state.generation), move |ctx| {
-                    self.rebuild(prev, state, ctx, element, app_state);
+                    record_view::<V, _, _>(ctx, ViewChange::Rebuilt, |ctx| {
+                        self.rebuild(prev, state, ctx, element, app_state);
+                    });
                 });
             });
         } else {
-            // Otherwise, teardown the old element, then replace the value
-            // Note that we need to use `dyn_teardown` here, because `prev`
-            // is of a different type.
-            element = prev.dyn_teardown(dyn_state, ctx, element);
-
-            // Increase the generation, because the underlying widget has been swapped out.
-            // Overflow condition: Impossible to overflow, as u64 only ever incremented by 1
-            // and starting at 0.
-            dyn_state.generation = dyn_state.generation.wrapping_add(1);
-            let (new_element, view_state) = ctx.with_id(ViewId::new(dyn_state.generation), |ctx| {
-                self.build(ctx, app_state)
+            record_view::<V, _, _>(ctx, ViewChange::Replaced, |ctx| {
+                // Otherwise, teardown the old element, then replace the value
+                // Note that we need to use `dyn_teardown` here, because `prev`
+                // is of a different type.
+                element = prev.dyn_teardown(dyn_state, ctx, element);
+
+                // Increase the generation, because the underlying widget has been swapped out.
+                // Overflow condition: Impossible to overflow, as u64 only ever incremented by 1
+                // and starting at 0.
+                dyn_state.generation = dyn_state.generation.wrapping_add(1);
+                let (new_element, view_state) = ctx
+                    .with_id(ViewId::new(dyn_state.generation), |ctx| {
+                        self.build(ctx, app_state)
+                    });
+                dyn_state.inner_state = Box::new(view_state);
+                DynamicElement::replace_inner(element, new_element);
             });
-            dyn_state.inner_state = Box::new(view_state);
-            DynamicElement::replace_inner(element, new_element);
         }
     }
     fn dyn_teardown<'el>(
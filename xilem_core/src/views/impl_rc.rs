@@ -6,7 +6,8 @@ use alloc::sync::Arc;
 use core::ops::Deref;
 
 use crate::message::MessageResult;
-use crate::{Arg, MessageCtx, Mut, View, ViewArgument, ViewMarker, ViewPathTracker};
+use crate::rebuild_diff::record_view;
+use crate::{Arg, MessageCtx, Mut, View, ViewArgument, ViewChange, ViewMarker, ViewPathTracker};
 
 #[expect(
     unnameable_types,
@@ -60,6 +61,8 @@ where
         if core::mem::take(&mut view_state.dirty) || !Rc::ptr_eq(self, prev) {
             self.deref()
                 .rebuild(prev, &mut view_state.view_state, ctx, element, app_state);
+        } else {
+            record_view::<V, _, _>(ctx, ViewChange::Skipped, |_| {});
         }
     }
 
@@ -128,6 +131,8 @@ where
         if core::mem::take(&mut view_state.dirty) || !Arc::ptr_eq(self, prev) {
             self.deref()
                 .rebuild(prev, &mut view_state.view_state, ctx, element, app_state);
+        } else {
+            record_view::<V, _, _>(ctx, ViewChange::Skipped, |_| {});
         }
     }
 
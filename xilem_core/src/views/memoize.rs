@@ -5,7 +5,11 @@ use core::fmt::Debug;
 use core::marker::PhantomData;
 use core::mem::size_of;
 
-use crate::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker, ViewPathTracker};
+use crate::rebuild_diff::record_view;
+use crate::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewChange, ViewMarker,
+    ViewPathTracker,
+};
 
 /// A view which supports Memoization.
 ///
@@ -137,6 +141,8 @@ where
                 app_state,
             );
             view_state.view = view;
+        } else {
+            record_view::<V, _, _>(ctx, ViewChange::Skipped, |_| {});
         }
     }
 
@@ -259,6 +265,8 @@ where
                 app_state,
             );
             view_state.view = view;
+        } else {
+            record_view::<V, _, _>(ctx, ViewChange::Skipped, |_| {});
         }
     }
 
@@ -62,6 +62,76 @@ pub type OneOf8<A, B, C, D, E, F, G, H> = OneOf<A, B, C, D, E, F, G, H, N>;
 /// A [`View`] which can be any one of nine inner view types.
 pub type OneOf9<A, B, C, D, E, F, G, H, I> = OneOf<A, B, C, D, E, F, G, H, I>;
 
+/// A `match` expression whose arms return different view types, as a [`OneOf`] view.
+///
+/// Each arm is wrapped in the next variant of `OneOf`, in order, and the result has the type
+/// of the [`OneOfN`](OneOf3) alias for the number of arms.
+/// This avoids [boxing](crate::AnyView) the views of the arms.
+///
+/// The arms must be separated by commas, even if their body is a block.
+/// Up to nine arms are supported; for more, use another `one_of_match!` in the last arm.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_core::docs::{State, some_component, some_component_generic, stateless_component};
+/// use xilem_core::one_of::OneOf;
+/// use xilem_core::one_of_match;
+///
+/// enum Page {
+///     Home,
+///     Settings { advanced: bool },
+///     About,
+/// }
+///
+/// let page = Page::Settings { advanced: false };
+/// let view = one_of_match!(page;
+///     Page::Home => stateless_component::<()>(),
+///     Page::Settings { advanced: true } | Page::About => some_component::<()>(&mut State),
+///     Page::Settings { .. } => some_component_generic::<u32, ()>(&mut 0),
+/// );
+/// assert!(matches!(view, OneOf::C(_)));
+/// ```
+#[macro_export]
+macro_rules! one_of_match {
+    (@arms $scrutinee:tt [$variant:ident $($variants:ident)*] [$($arms:tt)*]
+        $pat:pat $(if $guard:expr)? => $view:expr, $($rest:tt)*
+    ) => {
+        $crate::one_of_match!(
+            @arms $scrutinee [$($variants)*]
+            [$($arms)* $pat $(if $guard)? => $crate::one_of::OneOf::$variant($view),]
+            $($rest)*
+        )
+    };
+    (@arms $scrutinee:tt [] [$($arms:tt)*] $($rest:tt)+) => {
+        ::core::compile_error!(
+            "`one_of_match!` supports at most nine arms, use another `one_of_match!` in the last arm"
+        )
+    };
+    (@arms ($scrutinee:expr) [$($variants:ident)*] [$($arms:tt)*]) => {{
+        let view: $crate::one_of_match!(@type [$($variants)*]) = match $scrutinee {
+            $($arms)*
+        };
+        view
+    }};
+    (@type [B C D E F G H I]) => {
+        ::core::compile_error!("`one_of_match!` needs at least two arms")
+    };
+    (@type [C D E F G H I]) => { $crate::one_of::OneOf2<_, _> };
+    (@type [D E F G H I]) => { $crate::one_of::OneOf3<_, _, _> };
+    (@type [E F G H I]) => { $crate::one_of::OneOf4<_, _, _, _> };
+    (@type [F G H I]) => { $crate::one_of::OneOf5<_, _, _, _, _> };
+    (@type [G H I]) => { $crate::one_of::OneOf6<_, _, _, _, _, _> };
+    (@type [H I]) => { $crate::one_of::OneOf7<_, _, _, _, _, _, _> };
+    (@type [I]) => { $crate::one_of::OneOf8<_, _, _, _, _, _, _, _> };
+    (@type []) => { $crate::one_of::OneOf9<_, _, _, _, _, _, _, _, _> };
+    ($scrutinee:expr; $($pat:pat $(if $guard:expr)? => $view:expr),+ $(,)?) => {
+        $crate::one_of_match!(
+            @arms ($scrutinee) [A B C D E F G H I] [] $($pat $(if $guard)? => $view,)+
+        )
+    };
+}
+
 impl<T, A, B, C, D, E, F, G, H, I> AsRef<T> for OneOf<A, B, C, D, E, F, G, H, I>
 where
     A: AsRef<T>,
@@ -11,7 +11,7 @@
 use xilem_core::*;
 
 #[derive(Default)]
-pub(super) struct TestCtx(Vec<ViewId>, Environment);
+pub(super) struct TestCtx(Vec<ViewId>, Environment, Option<RebuildDiff>);
 
 impl ViewPathTracker for TestCtx {
     fn environment(&mut self) -> &mut Environment {
@@ -28,6 +28,9 @@ impl ViewPathTracker for TestCtx {
     fn view_path(&mut self) -> &[ViewId] {
         &self.0
     }
+    fn rebuild_diff(&mut self) -> Option<&mut RebuildDiff> {
+        self.2.as_mut()
+    }
 }
 
 impl TestCtx {
@@ -37,6 +40,14 @@ impl TestCtx {
             "Views should always match push_ids and pop_ids"
         );
     }
+    pub(super) fn start_rebuild_diff(&mut self) {
+        self.2 = Some(RebuildDiff::new());
+    }
+    pub(super) fn take_rebuild_diff(&mut self) -> RebuildDiff {
+        self.2
+            .take()
+            .expect("Rebuild diffs should have been started")
+    }
     pub(super) fn with_message_context(
         &mut self,
         target_id_path: Vec<ViewId>,
@@ -316,4 +327,9 @@ impl ElementSplice<TestElement> for SeqTracker<'_> {
         self.inner.deleted.push((self.ix, val));
         ret
     }
+    fn move_from(&mut self, n: usize) -> bool {
+        let element = self.inner.active.remove(self.ix + n);
+        self.inner.active.insert(self.ix, element);
+        true
+    }
 }
@@ -0,0 +1,80 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for [`keyed`] sequences.
+
+mod common;
+use common::*;
+use xilem_core::{View, keyed};
+
+/// Creates a keyed sequence from pairs of a key and the id of its [`OperationView`].
+fn keyed_ops(items: &[(u32, u32)]) -> xilem_core::Keyed<u32, OperationView<0>> {
+    keyed(
+        items.iter().copied(),
+        |(key, _)| *key,
+        |(_, id)| OperationView(id),
+    )
+}
+
+#[test]
+fn reorder_moves_elements() {
+    let view = sequence(0, keyed_ops(&[(10, 0), (11, 1), (12, 2)]));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, ());
+    ctx.assert_empty();
+
+    let view2 = sequence(3, keyed_ops(&[(12, 4), (10, 5)]));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+
+    let seq_children = element.children.as_ref().unwrap();
+    assert_eq!(seq_children.active.len(), 2);
+    // The elements are rebuilt from the view with the same key, instead of the same index.
+    assert_eq!(
+        seq_children.active[0].operations,
+        &[Operation::Build(2), Operation::Rebuild { from: 2, to: 4 }]
+    );
+    assert_eq!(
+        seq_children.active[1].operations,
+        &[Operation::Build(0), Operation::Rebuild { from: 0, to: 5 }]
+    );
+    assert_eq!(seq_children.deleted.len(), 1);
+    let (child_idx, child) = &seq_children.deleted[0];
+    assert_eq!(*child_idx, 2);
+    assert_eq!(
+        child.operations,
+        &[Operation::Build(1), Operation::Teardown(1)]
+    );
+
+    view2.teardown(&mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    let seq_children = element.children.as_ref().unwrap();
+    assert!(seq_children.active.is_empty());
+    assert_eq!(seq_children.deleted.len(), 3);
+}
+
+#[test]
+fn new_key_builds_element() {
+    let view = sequence(0, keyed_ops(&[(10, 0)]));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, ());
+    ctx.assert_empty();
+
+    let view2 = sequence(2, keyed_ops(&[(10, 1), (11, 3)]));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+
+    let seq_children = element.children.as_ref().unwrap();
+    assert!(seq_children.deleted.is_empty());
+    assert_eq!(seq_children.active.len(), 2);
+    assert_eq!(
+        seq_children.active[0].operations,
+        &[Operation::Build(0), Operation::Rebuild { from: 0, to: 1 }]
+    );
+    assert_eq!(seq_children.active[1].operations, &[Operation::Build(3)]);
+    // The new item doesn't reuse the view path of any previous item.
+    assert_ne!(
+        seq_children.active[0].view_path,
+        seq_children.active[1].view_path
+    );
+}
@@ -8,7 +8,7 @@
 #![expect(clippy::missing_assert_message, reason = "Deferred: Noisy")]
 
 use xilem_core::one_of::{OneOf, OneOf2, OneOfCtx, PhantomElementCtx};
-use xilem_core::{DynMessage, MessageResult, Mut, View, ViewId};
+use xilem_core::{DynMessage, MessageResult, Mut, View, ViewId, one_of_match};
 
 mod common;
 use common::*;
@@ -302,3 +302,32 @@ fn one_of_no_message_after_stale_then_same_type() {
         assert!(matches!(result, MessageResult::Stale));
     });
 }
+
+#[test]
+fn one_of_match_type_change_rebuild() {
+    let view = |id: u32| {
+        one_of_match!(id;
+            0 => record_ops_0(id),
+            _ => record_ops_1(id),
+        )
+    };
+    let view1 = view(0);
+    assert!(matches!(view1, OneOf::A(_)));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view1.build(&mut ctx, ());
+    ctx.assert_empty();
+    assert_eq!(element.operations, &[Operation::Build(0)]);
+
+    let view2 = view(1);
+    assert!(matches!(view2, OneOf::B(_)));
+    view2.rebuild(&view1, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+    assert_eq!(
+        element.operations,
+        &[
+            Operation::Build(0),
+            Operation::Teardown(0),
+            Operation::Replace(1)
+        ]
+    );
+}
@@ -0,0 +1,82 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the [`RebuildDiff`](xilem_core::RebuildDiff) recorded by views.
+//!
+//! This is an integration test so that it can use the infrastructure in [`common`].
+
+use std::sync::Arc;
+
+use xilem_core::{AnyView, View, ViewChange};
+
+mod common;
+use common::*;
+
+type AnyNoopView = dyn AnyView<(), Action, TestCtx, TestElement>;
+
+fn record_ops(id: u32) -> OperationView<0> {
+    OperationView(id)
+}
+
+#[test]
+fn sequence_diff() {
+    let shared = Arc::new(record_ops(2));
+    let view = sequence(0, (vec![record_ops(0), record_ops(1)], Arc::clone(&shared)));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, ());
+
+    ctx.start_rebuild_diff();
+    let view2 = sequence(1, (vec![record_ops(3)], Arc::clone(&shared)));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+
+    let diff = ctx.take_rebuild_diff();
+    let changes: Vec<_> = diff
+        .nodes()
+        .iter()
+        .map(|node| (node.view, node.change))
+        .collect();
+    assert_eq!(
+        changes,
+        [
+            ("OperationView", ViewChange::Rebuilt),
+            ("OperationView", ViewChange::TornDown),
+            ("Arc", ViewChange::Rebuilt),
+        ]
+    );
+    assert_eq!(
+        diff.to_string(),
+        "rebuilt OperationView\n\
+         torn down OperationView\n\
+         rebuilt Arc\n  \
+         skipped OperationView\n"
+    );
+}
+
+#[test]
+fn any_view_diff() {
+    let view: Box<AnyNoopView> = Box::new(OperationView::<0>(0));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, ());
+
+    ctx.start_rebuild_diff();
+    let view2: Box<AnyNoopView> = Box::new(OperationView::<0>(1));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, ());
+    let view3: Box<AnyNoopView> = Box::new(OperationView::<1>(2));
+    view3.rebuild(&view2, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+
+    let diff = ctx.take_rebuild_diff();
+    let changes: Vec<_> = diff
+        .nodes()
+        .iter()
+        .map(|node| (node.view, node.change))
+        .collect();
+    assert_eq!(
+        changes,
+        [
+            ("OperationView", ViewChange::Rebuilt),
+            ("OperationView", ViewChange::Replaced),
+        ]
+    );
+}
@@ -0,0 +1,223 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Persisting view state, such as scroll offsets and split positions, across app restarts.
+//!
+//! Persistence is opt-in: an app creates a [`Persistence`] store with a [`StorageBackend`],
+//! and gives it to Xilem, which loads it on startup and saves it on exit.
+//! Views then declare which parts of the app state should be persisted with the
+//! [`persist`](crate::view::persist) view, under a stable key.
+//!
+//! Values are stored as JSON, using their [serde] implementations.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// Where a [`Persistence`] store is loaded from and saved to.
+pub trait StorageBackend: Send + 'static {
+    /// Loads the data saved by the last call to [`store`](Self::store).
+    ///
+    /// Returns `None` if nothing was saved yet.
+    fn load(&mut self) -> io::Result<Option<String>>;
+
+    /// Saves `data`, replacing the data saved before.
+    fn store(&mut self, data: &str) -> io::Result<()>;
+}
+
+/// A [`StorageBackend`] which saves the data in a file.
+#[derive(Clone, Debug)]
+pub struct FileStorage {
+    path: PathBuf,
+}
+
+impl FileStorage {
+    /// Creates a backend saving the data in the file at `path`.
+    ///
+    /// The parent directory is created when saving if needed.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl StorageBackend for FileStorage {
+    fn load(&mut self) -> io::Result<Option<String>> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(data) => Ok(Some(data)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn store(&mut self, data: &str) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Write to a temporary file first, so that the data isn't lost if the app
+        // is killed while saving.
+        let temp_path = self.path.with_extension("tmp");
+        std::fs::write(&temp_path, data)?;
+        std::fs::rename(&temp_path, &self.path)
+    }
+}
+
+/// A [`StorageBackend`] which keeps the data in memory.
+///
+/// Clones share the same data, which makes this useful to test persistence.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage {
+    data: Arc<Mutex<Option<String>>>,
+}
+
+impl MemoryStorage {
+    /// Creates an empty backend.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the data saved last.
+    pub fn data(&self) -> Option<String> {
+        self.data.lock().unwrap().clone()
+    }
+}
+
+impl StorageBackend for MemoryStorage {
+    fn load(&mut self) -> io::Result<Option<String>> {
+        Ok(self.data())
+    }
+
+    fn store(&mut self, data: &str) -> io::Result<()> {
+        *self.data.lock().unwrap() = Some(data.to_string());
+        Ok(())
+    }
+}
+
+/// A store of persisted values, keyed by strings.
+///
+/// This is a cheap handle: clones share the same values.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::persistence::{MemoryStorage, Persistence};
+///
+/// let storage = MemoryStorage::new();
+/// let persistence = Persistence::new(storage.clone());
+/// persistence.set("sidebar_width", &240.);
+/// persistence.save().unwrap();
+///
+/// // On the next run of the app:
+/// let persistence = Persistence::new(storage);
+/// assert_eq!(persistence.get::<f64>("sidebar_width"), Some(240.));
+/// ```
+#[derive(Clone)]
+pub struct Persistence {
+    inner: Arc<Mutex<PersistenceInner>>,
+}
+
+struct PersistenceInner {
+    values: BTreeMap<String, Value>,
+    backend: Box<dyn StorageBackend>,
+    /// Whether values changed since they were loaded or saved.
+    dirty: bool,
+}
+
+impl Persistence {
+    /// Creates a store, and loads the values saved in `backend`.
+    ///
+    /// If the saved values can't be loaded, the store starts empty, and the error is logged.
+    pub fn new(mut backend: impl StorageBackend) -> Self {
+        let values = match backend.load() {
+            Ok(Some(data)) => serde_json::from_str(&data).unwrap_or_else(|err| {
+                tracing::warn!("Ignoring invalid persisted state: {err}");
+                BTreeMap::new()
+            }),
+            Ok(None) => BTreeMap::new(),
+            Err(err) => {
+                tracing::warn!("Failed to load persisted state: {err}");
+                BTreeMap::new()
+            }
+        };
+        Self {
+            inner: Arc::new(Mutex::new(PersistenceInner {
+                values,
+                backend: Box::new(backend),
+                dirty: false,
+            })),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, PersistenceInner> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Returns the value persisted under `key`.
+    ///
+    /// Returns `None` if there is no such value, or if it can't be deserialized as a `T`,
+    /// e.g. because it was saved by an older version of the app.
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let value = self.lock().values.get(key)?.clone();
+        serde_json::from_value(value)
+            .inspect_err(|err| tracing::warn!(key, "Ignoring invalid persisted value: {err}"))
+            .ok()
+    }
+
+    /// Persists `value` under `key`, replacing the previous value.
+    ///
+    /// The value is saved with the next call to [`save`](Self::save).
+    pub fn set<T: Serialize + ?Sized>(&self, key: &str, value: &T) {
+        let value = match serde_json::to_value(value) {
+            Ok(value) => value,
+            Err(err) => {
+                tracing::error!(key, "Failed to serialize persisted value: {err}");
+                return;
+            }
+        };
+        let mut inner = self.lock();
+        if inner.values.get(key) != Some(&value) {
+            inner.values.insert(key.to_string(), value);
+            inner.dirty = true;
+        }
+    }
+
+    /// Removes the value persisted under `key`.
+    pub fn remove(&self, key: &str) {
+        let mut inner = self.lock();
+        if inner.values.remove(key).is_some() {
+            inner.dirty = true;
+        }
+    }
+
+    /// Saves the values to the backend, if they changed since they were last saved.
+    ///
+    /// Xilem calls this when the app exits.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the backend.
+    pub fn save(&self) -> io::Result<()> {
+        let mut inner = self.lock();
+        if !inner.dirty {
+            return Ok(());
+        }
+        let data = serde_json::to_string_pretty(&inner.values)?;
+        inner.backend.store(&data)?;
+        inner.dirty = false;
+        Ok(())
+    }
+}
+
+impl Debug for Persistence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Persistence")
+            .field("values", &self.lock().values)
+            .finish_non_exhaustive()
+    }
+}
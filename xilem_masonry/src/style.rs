@@ -5,10 +5,11 @@
 
 use masonry::core::HasProperty;
 use masonry::layout::Dim;
-pub use masonry::properties::types::{Gradient, GradientShape};
+pub use masonry::properties::types::{BackgroundImageMode, Gradient, GradientShape};
 pub use masonry::properties::{
-    ActiveBackground, Background, BorderColor, BorderWidth, BoxShadow, CornerRadius,
-    DisabledBackground, HoveredBorderColor, Padding,
+    ActiveBackground, ActiveElevation, Background, BackgroundImage, BorderColor, BorderWidth,
+    BoxShadow, CornerRadius, DisabledBackground, Elevation, ElevationLevel, ElevationShadows,
+    HoveredBorderColor, HoveredElevation, Padding, PressFeedback, ZIndex,
 };
 use masonry::properties::{
     ContentColor, Dimensions, DisabledContentColor, FocusedBorderColor, Gap, LineBreaking,
@@ -100,6 +101,20 @@ pub trait Style<State: ViewArgument, Action: 'static>: WidgetView<State, Action>
         self.prop(Background::Gradient(gradient))
     }
 
+    /// Sets the element's background image.
+    ///
+    /// The image is painted on top of the element's [`Background`].
+    /// See [`BackgroundImageMode`] for the supported ways to fit it, including 9-patch slicing.
+    fn background_image(
+        self,
+        background_image: impl Into<BackgroundImage>,
+    ) -> Prop<BackgroundImage, Self, State, Action>
+    where
+        Self::Widget: HasProperty<BackgroundImage>,
+    {
+        self.prop(background_image.into())
+    }
+
     /// Sets the element's background when pressed to a color/gradient.
     fn active_background(
         self,
@@ -215,6 +230,57 @@ pub trait Style<State: ViewArgument, Action: 'static>: WidgetView<State, Action>
         self.prop(box_shadow)
     }
 
+    /// Sets the element's elevation, which paints the matching shadows of its [`ElevationShadows`].
+    fn elevation(self, elevation: u8) -> Prop<Elevation, Self, State, Action>
+    where
+        Self::Widget: HasProperty<Elevation>,
+    {
+        self.prop(Elevation(elevation))
+    }
+
+    /// Sets the element's elevation when hovered.
+    fn hovered_elevation(self, elevation: u8) -> Prop<HoveredElevation, Self, State, Action>
+    where
+        Self::Widget: HasProperty<HoveredElevation>,
+    {
+        self.prop(HoveredElevation(Elevation(elevation)))
+    }
+
+    /// Sets the element's elevation when pressed.
+    fn active_elevation(self, elevation: u8) -> Prop<ActiveElevation, Self, State, Action>
+    where
+        Self::Widget: HasProperty<ActiveElevation>,
+    {
+        self.prop(ActiveElevation(Elevation(elevation)))
+    }
+
+    /// Sets the shadows the element paints for each elevation level.
+    fn elevation_shadows(
+        self,
+        elevation_shadows: ElevationShadows,
+    ) -> Prop<ElevationShadows, Self, State, Action>
+    where
+        Self::Widget: HasProperty<ElevationShadows>,
+    {
+        self.prop(elevation_shadows)
+    }
+
+    /// Sets the feedback painted when the element is pressed, such as a ripple.
+    fn press_feedback(self, feedback: PressFeedback) -> Prop<PressFeedback, Self, State, Action>
+    where
+        Self::Widget: HasProperty<PressFeedback>,
+    {
+        self.prop(feedback)
+    }
+
+    /// Sets the element's z-index, which orders its painting and hit-testing among its siblings.
+    fn z_index(self, z_index: i32) -> Prop<ZIndex, Self, State, Action>
+    where
+        Self::Widget: HasProperty<ZIndex>,
+    {
+        self.prop(ZIndex(z_index))
+    }
+
     /// Sets the element's corner radius.
     fn corner_radius(self, radius: f64) -> Prop<CornerRadius, Self, State, Action>
     where
@@ -0,0 +1,279 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tokio::task::JoinHandle;
+
+use crate::ViewCtx;
+use crate::core::{
+    Arg, MessageCtx, MessageProxy, MessageResult, Mut, NoElement, View, ViewArgument, ViewId,
+    ViewMarker, ViewPathTracker,
+};
+
+/// A sound provided by the platform, rather than by the app.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SystemSound {
+    /// The sound used to get the user's attention, e.g. when an action isn't allowed.
+    Alert,
+    /// The sound used to signal that something went wrong, e.g. when a form is invalid.
+    Error,
+    /// The sound used for incoming notifications.
+    Notification,
+}
+
+/// A sound which can be played by an [`AudioPlayer`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Sound {
+    /// One of the platform's sounds.
+    System(SystemSound),
+    /// A short sample, in an encoded format the [`AudioBackend`] understands (e.g. WAV or Ogg Vorbis).
+    ///
+    /// The whole sample is kept in memory, so this isn't suitable for music or other long tracks.
+    Sample(Arc<[u8]>),
+}
+
+impl From<SystemSound> for Sound {
+    fn from(sound: SystemSound) -> Self {
+        Self::System(sound)
+    }
+}
+
+/// An error returned by an [`AudioBackend`] when a sound couldn't be played.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AudioError(pub String);
+
+impl fmt::Display for AudioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not play sound: {}", self.0)
+    }
+}
+
+impl std::error::Error for AudioError {}
+
+/// The part of the [`audio`] view which actually outputs sound.
+///
+/// Xilem doesn't bundle an audio engine, so apps which want audible feedback implement this
+/// trait on top of the library of their choice, or of the platform's APIs.
+pub trait AudioBackend: Send + Sync + 'static {
+    /// Plays `sound`, blocking until it has finished playing.
+    ///
+    /// This is always called on a thread where blocking is allowed, and several sounds
+    /// may be played at the same time from different threads.
+    fn play(&self, sound: &Sound) -> Result<(), AudioError>;
+}
+
+/// An [`AudioBackend`] which doesn't output anything.
+///
+/// Every sound finishes immediately and successfully.
+/// This is useful in tests, and on platforms where no backend is available.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SilentBackend;
+
+impl AudioBackend for SilentBackend {
+    fn play(&self, sound: &Sound) -> Result<(), AudioError> {
+        tracing::debug!(?sound, "Not playing sound with `SilentBackend`.");
+        Ok(())
+    }
+}
+
+/// Identifies a sound started by [`AudioPlayer::play`], so that its [`SoundFinished`]
+/// notification can be matched with it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SoundId(u64);
+
+/// The notification passed to the `on_finished` callback of [`audio`].
+#[derive(Debug)]
+pub struct SoundFinished {
+    /// The id returned by [`AudioPlayer::play`] when this sound was started.
+    pub id: SoundId,
+    /// Whether the sound was played successfully.
+    pub result: Result<(), AudioError>,
+}
+
+/// A handle for playing sounds through an [`audio`] view.
+///
+/// This is cheap to clone, and is meant to be stored in the app's state so that
+/// it can be used from any callback.
+/// Sounds played after the `audio` view has been removed from the tree are ignored.
+#[derive(Clone, Debug)]
+pub struct AudioPlayer {
+    sender: UnboundedSender<(SoundId, Sound)>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl AudioPlayer {
+    /// Starts playing `sound`, and returns immediately.
+    ///
+    /// Once the sound has finished playing, the `on_finished` callback of the [`audio`]
+    /// view is called with the returned id.
+    pub fn play(&self, sound: impl Into<Sound>) -> SoundId {
+        let id = SoundId(self.next_id.fetch_add(1, Ordering::Relaxed));
+        if self.sender.send((id, sound.into())).is_err() {
+            tracing::warn!("Tried to play a sound after its `audio` view was removed.");
+        }
+        id
+    }
+
+    /// Plays the platform's [alert sound](SystemSound::Alert).
+    pub fn alert(&self) -> SoundId {
+        self.play(SystemSound::Alert)
+    }
+}
+
+/// Play sounds from callbacks, and get notified when they finish.
+///
+/// When this view is built, `store_player` is given an [`AudioPlayer`], which should be kept
+/// in the app's state.
+/// Callbacks can then call [`AudioPlayer::play`] to play a sound through `backend`,
+/// without blocking the UI.
+/// When a sound has finished playing (or failed to), `on_finished` is called with its [`SoundFinished`]
+/// notification.
+///
+/// Playback stops being tracked when the view is removed from the tree.
+/// Note that `backend` will not be replaced if the view is rebuilt.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// use xilem::core::{Edit, fork};
+/// use xilem::view::{AudioError, AudioPlayer, SilentBackend, SystemSound, audio, text_button};
+/// use xilem::WidgetView;
+///
+/// struct State {
+///     player: Option<AudioPlayer>,
+///     audio_error: Option<AudioError>,
+/// }
+///
+/// fn app_logic(state: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+///     fork(
+///         text_button("Submit", |state: &mut State| {
+///             if let Some(player) = &state.player {
+///                 player.play(SystemSound::Error);
+///             }
+///         }),
+///         audio(
+///             SilentBackend,
+///             |state: &mut State, player| state.player = Some(player),
+///             |state: &mut State, finished| state.audio_error = finished.result.err(),
+///         ),
+///     )
+/// }
+/// ```
+pub fn audio<B, S, H, State, Action>(
+    backend: B,
+    store_player: S,
+    on_finished: H,
+) -> Audio<B, S, H, State, Action>
+where
+    B: AudioBackend,
+    S: Fn(Arg<'_, State>, AudioPlayer) + 'static,
+    H: Fn(Arg<'_, State>, SoundFinished) -> Action + 'static,
+    State: ViewArgument,
+{
+    Audio {
+        backend: Arc::new(backend),
+        store_player,
+        on_finished,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`audio`].
+///
+/// See `audio` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Audio<B, S, H, State, Action = ()> {
+    backend: Arc<B>,
+    store_player: S,
+    on_finished: H,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+async fn play_sounds<B: AudioBackend>(
+    backend: Arc<B>,
+    proxy: MessageProxy<SoundFinished>,
+    mut receiver: UnboundedReceiver<(SoundId, Sound)>,
+) {
+    while let Some((id, sound)) = receiver.recv().await {
+        let backend = backend.clone();
+        let proxy = proxy.clone();
+        // Each sound gets its own thread, so that short feedback sounds can overlap.
+        tokio::spawn(async move {
+            let result = tokio::task::spawn_blocking(move || backend.play(&sound))
+                .await
+                .unwrap_or_else(|err| Err(AudioError(err.to_string())));
+            drop(proxy.message(SoundFinished { id, result }));
+        });
+    }
+}
+
+impl<B, S, H, State, Action> ViewMarker for Audio<B, S, H, State, Action> {}
+impl<B, S, H, State, Action> View<State, Action, ViewCtx> for Audio<B, S, H, State, Action>
+where
+    B: AudioBackend,
+    S: Fn(Arg<'_, State>, AudioPlayer) + 'static,
+    H: Fn(Arg<'_, State>, SoundFinished) -> Action + 'static,
+    State: ViewArgument,
+    Action: 'static,
+{
+    type Element = NoElement;
+
+    type ViewState = JoinHandle<()>;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let path: Arc<[ViewId]> = ctx.view_path().into();
+        let proxy = MessageProxy::new(ctx.proxy(), path);
+
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+        (self.store_player)(
+            app_state,
+            AudioPlayer {
+                sender,
+                next_id: Arc::new(AtomicU64::new(0)),
+            },
+        );
+        let handle = ctx
+            .runtime()
+            .spawn(play_sounds(self.backend.clone(), proxy, receiver));
+        (NoElement, handle)
+    }
+
+    fn rebuild(
+        &self,
+        _prev: &Self,
+        _view_state: &mut Self::ViewState,
+        _: &mut ViewCtx,
+        (): Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+    }
+
+    fn teardown(&self, handle: &mut Self::ViewState, _: &mut ViewCtx, _: Mut<'_, Self::Element>) {
+        handle.abort();
+    }
+
+    fn message(
+        &self,
+        _: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        debug_assert!(
+            message.remaining_path().is_empty(),
+            "id path should be empty in Audio::message"
+        );
+        let message = message.take_message::<SoundFinished>().unwrap();
+        MessageResult::Action((self.on_finished)(app_state, *message))
+    }
+}
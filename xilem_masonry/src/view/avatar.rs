@@ -0,0 +1,208 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::{ArcStr, NewWidget, Widget, WidgetMut};
+pub use masonry::widgets::AvatarStatus;
+use masonry::widgets::{self, Passthrough};
+
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{AnyWidgetView, Pod, ViewCtx, WidgetView};
+
+type Picture<State, Action> = Box<AnyWidgetView<State, Action>>;
+type PictureState<State, Action> =
+    <Picture<State, Action> as View<State, Action, ViewCtx>>::ViewState;
+
+/// A circular picture of the person called `name`, showing their initials until a
+/// [picture](Avatar::picture) is loaded.
+///
+/// See [`masonry::widgets::Avatar`] for more details.
+///
+/// # Examples
+///
+/// ```ignore
+/// # use xilem_masonry as xilem;
+/// use xilem::view::{AvatarStatus, avatar, image_source};
+///
+/// avatar(&user.name)
+///     .picture(image_source(&state.image_cache, user.picture.clone()))
+///     .status(AvatarStatus::Online)
+/// ```
+pub fn avatar<State: ViewArgument, Action>(name: impl Into<ArcStr>) -> Avatar<State, Action> {
+    Avatar {
+        name: name.into(),
+        picture: None,
+        status: None,
+        diameter: masonry::theme::AVATAR_DIAMETER,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`avatar`].
+///
+/// See `avatar` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Avatar<State: ViewArgument, Action> {
+    name: ArcStr,
+    picture: Option<Picture<State, Action>>,
+    status: Option<AvatarStatus>,
+    diameter: f64,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<State: ViewArgument, Action> Avatar<State, Action> {
+    /// Show a picture, such as an [`image`](crate::view::image), instead of the initials.
+    ///
+    /// The picture should cover the avatar, which is the default for images.
+    pub fn picture(mut self, picture: impl WidgetView<State, Action>) -> Self
+    where
+        Action: 'static,
+    {
+        self.picture = Some(picture.boxed());
+        self
+    }
+
+    /// Show the status of the person as a dot in the corner of the avatar.
+    pub fn status(mut self, status: AvatarStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Set the diameter of the avatar, in logical pixels.
+    pub fn diameter(mut self, diameter: f64) -> Self {
+        self.diameter = diameter;
+        self
+    }
+}
+
+/// This is a randomly generated 32 bit number - 3367871841 in decimal.
+const PICTURE_VIEW_ID: ViewId = ViewId::new(0xc8bd5361);
+
+impl<State: ViewArgument, Action> ViewMarker for Avatar<State, Action> {}
+impl<State, Action> View<State, Action, ViewCtx> for Avatar<State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Avatar>;
+    type ViewState = Option<PictureState<State, Action>>;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let mut widget = widgets::Avatar::new(self.name.clone()).with_diameter(self.diameter);
+        if let Some(status) = self.status {
+            widget = widget.with_status(status);
+        }
+        let mut picture_state = None;
+        if let Some(picture) = &self.picture {
+            let (picture, state) = ctx.with_id(PICTURE_VIEW_ID, |ctx| {
+                View::<State, Action, _>::build(picture, ctx, app_state)
+            });
+            widget = widget.with_picture(picture.new_widget);
+            picture_state = Some(state);
+        }
+        (ctx.create_pod(widget), picture_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        picture_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        if prev.name != self.name {
+            widgets::Avatar::set_name(&mut element, self.name.clone());
+        }
+        if prev.status != self.status {
+            widgets::Avatar::set_status(&mut element, self.status);
+        }
+        if prev.diameter != self.diameter {
+            widgets::Avatar::set_diameter(&mut element, self.diameter);
+        }
+        ctx.with_id(PICTURE_VIEW_ID, |ctx| {
+            match (&self.picture, &prev.picture, picture_state.as_mut()) {
+                (Some(picture), Some(prev), Some(state)) => {
+                    View::<State, Action, _>::rebuild(
+                        picture,
+                        prev,
+                        state,
+                        ctx,
+                        picture_mut(&mut element).downcast(),
+                        app_state,
+                    );
+                }
+                (Some(picture), _, _) => {
+                    let (picture, state) = View::<State, Action, _>::build(picture, ctx, app_state);
+                    widgets::Avatar::set_picture(&mut element, Some(picture.new_widget));
+                    *picture_state = Some(state);
+                }
+                (None, Some(prev), Some(state)) => {
+                    View::<State, Action, _>::teardown(
+                        prev,
+                        state,
+                        ctx,
+                        picture_mut(&mut element).downcast(),
+                    );
+                    widgets::Avatar::set_picture(&mut element, None::<NewWidget<Passthrough>>);
+                    *picture_state = None;
+                }
+                (None, _, _) => {}
+            }
+        });
+    }
+
+    fn teardown(
+        &self,
+        picture_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        if let (Some(picture), Some(state)) = (&self.picture, picture_state.as_mut()) {
+            ctx.with_id(PICTURE_VIEW_ID, |ctx| {
+                let mut picture_element = picture_mut(&mut element);
+                View::<State, Action, _>::teardown(picture, state, ctx, picture_element.downcast());
+            });
+        }
+    }
+
+    fn message(
+        &self,
+        picture_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match (message.take_first(), &self.picture, picture_state.as_mut()) {
+            (Some(PICTURE_VIEW_ID), Some(picture), Some(state)) => picture.message(
+                state,
+                message,
+                picture_mut(&mut element).downcast(),
+                app_state,
+            ),
+            (Some(PICTURE_VIEW_ID), _, _) => {
+                tracing::warn!("Got message for a removed picture in Avatar::message");
+                MessageResult::Stale
+            }
+            (view_id, _, _) => {
+                tracing::error!(
+                    ?message,
+                    "Invalid message arrived in Avatar::message, got {:?}. This is a bug.",
+                    view_id
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
+
+fn picture_mut<'t>(element: &'t mut WidgetMut<'_, widgets::Avatar>) -> WidgetMut<'t, dyn Widget> {
+    widgets::Avatar::picture_mut(element).expect("The avatar has a picture for the previous view")
+}
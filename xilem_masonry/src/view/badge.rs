@@ -0,0 +1,126 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use masonry::core::ArcStr;
+use masonry::layout::UnitPoint;
+use masonry::widgets;
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::view::{ZStack, ZStackExt, ZStackItem, zstack};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A small pill showing a short label.
+///
+/// See [`badged`] to show it in a corner of another view.
+pub fn badge(text: impl Into<ArcStr>) -> Badge {
+    Badge {
+        content: BadgeContent::Text(text.into()),
+    }
+}
+
+/// A small pill showing `count`, or "99+" if it's larger than 99.
+///
+/// See [`badged`] to show it in a corner of another view.
+pub fn badge_count(count: u64) -> Badge {
+    Badge {
+        content: BadgeContent::Count(count),
+    }
+}
+
+/// Shows `badge` on top of `child`, in its `corner`.
+///
+/// This is a [`zstack`] where the badge is aligned to the corner.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::masonry::layout::UnitPoint;
+/// use xilem::view::{badge_count, badged, text_button};
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct State {
+///     unread: u64,
+/// }
+///
+/// # fn view(state: &mut State) -> impl WidgetView<Edit<State>> {
+/// badged(
+///     text_button("Inbox", |_: &mut State| {}),
+///     badge_count(state.unread),
+///     UnitPoint::TOP_RIGHT,
+/// )
+/// # }
+/// ```
+pub fn badged<State, Action, V>(
+    child: V,
+    badge: Badge,
+    corner: UnitPoint,
+) -> ZStack<(V, ZStackItem<Badge, State, Action>)>
+where
+    State: ViewArgument,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    zstack((child, badge.alignment(corner)))
+}
+
+/// The [`View`] created by [`badge`] or [`badge_count`].
+///
+/// See `badge` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Badge {
+    content: BadgeContent,
+}
+
+#[derive(PartialEq)]
+enum BadgeContent {
+    Text(ArcStr),
+    Count(u64),
+}
+
+impl ViewMarker for Badge {}
+impl<State: ViewArgument, Action> View<State, Action, ViewCtx> for Badge {
+    type Element = Pod<widgets::Badge>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let widget = match &self.content {
+            BadgeContent::Text(text) => widgets::Badge::new(text.clone()),
+            BadgeContent::Count(count) => widgets::Badge::count(*count),
+        };
+        (ctx.create_pod(widget), ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        if prev.content != self.content {
+            match &self.content {
+                BadgeContent::Text(text) => widgets::Badge::set_text(&mut element, text.clone()),
+                BadgeContent::Count(count) => widgets::Badge::set_count(&mut element, *count),
+            }
+        }
+    }
+
+    fn teardown(&self, (): &mut Self::ViewState, _: &mut ViewCtx, _: Mut<'_, Self::Element>) {}
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        tracing::error!(
+            ?message,
+            "Message arrived in Badge::message, but Badge doesn't consume any messages, this is a bug."
+        );
+        MessageResult::Stale
+    }
+}
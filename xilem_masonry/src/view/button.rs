@@ -4,15 +4,18 @@
 use std::any::type_name;
 use std::marker::PhantomData;
 
-use masonry::core::ArcStr;
 pub use masonry::core::PointerButton;
-use masonry::widgets::{self, ButtonPress};
+use masonry::core::{ArcStr, NewWidget, Widget, WidgetMut};
+use masonry::widgets::{self, ButtonPress, ButtonVariant, Passthrough};
 
 use crate::core::{
     Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
 };
 use crate::view::{Label, label};
-use crate::{Pod, ViewCtx, WidgetView};
+use crate::{AnyWidgetView, Pod, ViewCtx, WidgetView};
+
+type Icon<State, Action> = Box<AnyWidgetView<State, Action>>;
+type IconState<State, Action> = <Icon<State, Action> as View<State, Action, ViewCtx>>::ViewState;
 
 /// A button which calls `callback` when the primary mouse button (normally left) is pressed.
 ///
@@ -93,6 +96,10 @@ pub fn button<
             _ => MessageResult::Nop,
         },
         disabled: false,
+        loading: false,
+        variant: ButtonVariant::default(),
+        leading_icon: None,
+        trailing_icon: None,
         phantom: PhantomData,
     }
 }
@@ -145,6 +152,10 @@ pub fn button_any_pointer<State: ViewArgument, Action, V: WidgetView<State, Acti
             MessageResult::Action(callback(state, button))
         },
         disabled: false,
+        loading: false,
+        variant: ButtonVariant::default(),
+        leading_icon: None,
+        trailing_icon: None,
         phantom: PhantomData,
     }
 }
@@ -153,27 +164,202 @@ pub fn button_any_pointer<State: ViewArgument, Action, V: WidgetView<State, Acti
 ///
 /// See `button`'s documentation for more context.
 #[must_use = "View values do nothing unless provided to Xilem."]
-pub struct Button<State, Action, F, V> {
+pub struct Button<State: ViewArgument, Action, F, V> {
     child: V,
     callback: F,
     disabled: bool,
+    loading: bool,
+    variant: ButtonVariant,
+    leading_icon: Option<Icon<State, Action>>,
+    trailing_icon: Option<Icon<State, Action>>,
     phantom: PhantomData<fn(State) -> Action>,
 }
 
-impl<State, Action, F, V> Button<State, Action, F, V> {
+impl<State: ViewArgument, Action, F, V> Button<State, Action, F, V> {
     /// Set the disabled state of the widget.
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
     }
+
+    /// Set whether the button is loading.
+    ///
+    /// A loading button shows a spinner instead of its child, and its callback isn't called.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Set the style variant of the button, such as [`ButtonVariant::Primary`] for the main
+    /// action of a view.
+    ///
+    /// The colors of each variant come from the theme.
+    /// They replace the background and border colors set with [`Prop`](crate::view::Prop)
+    /// views when the variant changes.
+    pub fn variant(mut self, variant: ButtonVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Add an icon before the child, such as an [`image`](crate::view::image).
+    ///
+    /// Like the child, the icon should be a non-interactive view.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// # use xilem_masonry as xilem;
+    /// use xilem::masonry::widgets::ButtonVariant;
+    /// use xilem::view::{image, text_button};
+    ///
+    /// text_button("Save", |state: &mut State| state.save())
+    ///     .leading_icon(image(&state.save_icon))
+    ///     .variant(ButtonVariant::Primary)
+    ///     .loading(state.is_saving)
+    /// ```
+    pub fn leading_icon(mut self, icon: impl WidgetView<State, Action>) -> Self
+    where
+        Action: 'static,
+    {
+        self.leading_icon = Some(icon.boxed());
+        self
+    }
+
+    /// Add an icon after the child.
+    ///
+    /// Like the child, the icon should be a non-interactive view.
+    pub fn trailing_icon(mut self, icon: impl WidgetView<State, Action>) -> Self
+    where
+        Action: 'static,
+    {
+        self.trailing_icon = Some(icon.boxed());
+        self
+    }
 }
 
 /// Use a distinctive number here, to be able to catch bugs.
 /// In case the generational-id view path in `View::Message` leads to the wrong view.
 /// This is a randomly generated 32 bit number - 3502359742 in decimal.
 const BUTTON_CONTENT_VIEW_ID: ViewId = ViewId::new(0xd0c1c4be);
+/// This is a randomly generated 32 bit number - 1528704775 in decimal.
+const LEADING_ICON_VIEW_ID: ViewId = ViewId::new(0x5b1e2f07);
+/// This is a randomly generated 32 bit number - 2588133073 in decimal.
+const TRAILING_ICON_VIEW_ID: ViewId = ViewId::new(0x9a43c6d1);
+
+/// One of the two icons of a button.
+#[derive(Clone, Copy)]
+enum IconSlot {
+    Leading,
+    Trailing,
+}
+
+impl IconSlot {
+    fn view_id(self) -> ViewId {
+        match self {
+            Self::Leading => LEADING_ICON_VIEW_ID,
+            Self::Trailing => TRAILING_ICON_VIEW_ID,
+        }
+    }
+
+    fn icon_mut<'t>(
+        self,
+        button: &'t mut WidgetMut<'_, widgets::Button>,
+    ) -> Option<WidgetMut<'t, dyn Widget>> {
+        match self {
+            Self::Leading => widgets::Button::leading_icon_mut(button),
+            Self::Trailing => widgets::Button::trailing_icon_mut(button),
+        }
+    }
+
+    fn set_icon(
+        self,
+        button: &mut WidgetMut<'_, widgets::Button>,
+        icon: Option<NewWidget<Passthrough>>,
+    ) {
+        match self {
+            Self::Leading => widgets::Button::set_leading_icon(button, icon),
+            Self::Trailing => widgets::Button::set_trailing_icon(button, icon),
+        }
+    }
+
+    fn rebuild<State: ViewArgument, Action: 'static>(
+        self,
+        icon: Option<&Icon<State, Action>>,
+        prev: Option<&Icon<State, Action>>,
+        icon_state: &mut Option<IconState<State, Action>>,
+        ctx: &mut ViewCtx,
+        button: &mut WidgetMut<'_, widgets::Button>,
+        app_state: Arg<'_, State>,
+    ) {
+        ctx.with_id(self.view_id(), |ctx| {
+            match (icon, prev, icon_state.as_mut()) {
+                (Some(icon), Some(prev), Some(state)) => {
+                    let mut element = self
+                        .icon_mut(button)
+                        .expect("The button has an icon for the previous view");
+                    View::<State, Action, _>::rebuild(
+                        icon,
+                        prev,
+                        state,
+                        ctx,
+                        element.downcast(),
+                        app_state,
+                    );
+                }
+                (Some(icon), _, _) => {
+                    let (element, state) = View::<State, Action, _>::build(icon, ctx, app_state);
+                    self.set_icon(button, Some(element.new_widget));
+                    *icon_state = Some(state);
+                }
+                (None, Some(prev), Some(state)) => {
+                    if let Some(mut element) = self.icon_mut(button) {
+                        View::<State, Action, _>::teardown(prev, state, ctx, element.downcast());
+                    }
+                    self.set_icon(button, None);
+                    *icon_state = None;
+                }
+                (None, _, _) => {}
+            }
+        });
+    }
+
+    fn teardown<State: ViewArgument, Action: 'static>(
+        self,
+        icon: Option<&Icon<State, Action>>,
+        icon_state: &mut Option<IconState<State, Action>>,
+        ctx: &mut ViewCtx,
+        button: &mut WidgetMut<'_, widgets::Button>,
+    ) {
+        ctx.with_id(self.view_id(), |ctx| {
+            if let (Some(icon), Some(state), Some(mut element)) =
+                (icon, icon_state.as_mut(), self.icon_mut(button))
+            {
+                View::<State, Action, _>::teardown(icon, state, ctx, element.downcast());
+            }
+        });
+    }
 
-impl<State, Action, F, V> ViewMarker for Button<State, Action, F, V> {}
+    fn message<State: ViewArgument, Action: 'static>(
+        self,
+        icon: Option<&Icon<State, Action>>,
+        icon_state: &mut Option<IconState<State, Action>>,
+        message: &mut MessageCtx,
+        button: &mut WidgetMut<'_, widgets::Button>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match (icon, icon_state.as_mut(), self.icon_mut(button)) {
+            (Some(icon), Some(state), Some(mut element)) => {
+                icon.message(state, message, element.downcast(), app_state)
+            }
+            _ => {
+                tracing::warn!("Got message for a removed icon in Button::message");
+                MessageResult::Stale
+            }
+        }
+    }
+}
+
+impl<State: ViewArgument, Action, F, V> ViewMarker for Button<State, Action, F, V> {}
 impl<F, V, State, Action> View<State, Action, ViewCtx> for Button<State, Action, F, V>
 where
     State: ViewArgument,
@@ -182,80 +368,144 @@ where
     F: Fn(Arg<'_, State>, Option<PointerButton>) -> MessageResult<Action> + Send + Sync + 'static,
 {
     type Element = Pod<widgets::Button>;
-    type ViewState = V::ViewState;
+    type ViewState = (
+        V::ViewState,
+        Option<IconState<State, Action>>,
+        Option<IconState<State, Action>>,
+    );
 
     fn build(
         &self,
         ctx: &mut ViewCtx,
-        app_state: Arg<'_, State>,
+        mut app_state: Arg<'_, State>,
     ) -> (Self::Element, Self::ViewState) {
         let (child, child_state) = ctx.with_id(BUTTON_CONTENT_VIEW_ID, |ctx| {
-            View::<State, Action, _>::build(&self.child, ctx, app_state)
+            View::<State, Action, _>::build(&self.child, ctx, State::reborrow_mut(&mut app_state))
         });
+        let mut widget = widgets::Button::new(child.new_widget).with_loading(self.loading);
+        let mut leading_state = None;
+        if let Some(icon) = &self.leading_icon {
+            let (icon, state) = ctx.with_id(LEADING_ICON_VIEW_ID, |ctx| {
+                View::<State, Action, _>::build(icon, ctx, State::reborrow_mut(&mut app_state))
+            });
+            widget = widget.with_leading_icon(icon.new_widget);
+            leading_state = Some(state);
+        }
+        let mut trailing_state = None;
+        if let Some(icon) = &self.trailing_icon {
+            let (icon, state) = ctx.with_id(TRAILING_ICON_VIEW_ID, |ctx| {
+                View::<State, Action, _>::build(icon, ctx, State::reborrow_mut(&mut app_state))
+            });
+            widget = widget.with_trailing_icon(icon.new_widget);
+            trailing_state = Some(state);
+        }
         (
-            ctx.with_action_widget(|ctx| {
-                let mut pod = ctx.create_pod(widgets::Button::new(child.new_widget));
+            ctx.with_action_widget(|_| {
+                let mut pod = Pod::new_with_props(widget, self.variant.properties());
                 pod.new_widget.options.disabled = self.disabled;
                 pod
             }),
-            child_state,
+            (child_state, leading_state, trailing_state),
         )
     }
 
     fn rebuild(
         &self,
         prev: &Self,
-        state: &mut Self::ViewState,
+        (child_state, leading_state, trailing_state): &mut Self::ViewState,
         ctx: &mut ViewCtx,
         mut element: Mut<'_, Self::Element>,
-        app_state: Arg<'_, State>,
+        mut app_state: Arg<'_, State>,
     ) {
         if prev.disabled != self.disabled {
             element.ctx.set_disabled(self.disabled);
         }
+        if prev.loading != self.loading {
+            widgets::Button::set_loading(&mut element, self.loading);
+        }
+        if prev.variant != self.variant {
+            widgets::Button::set_variant(&mut element, self.variant);
+        }
         ctx.with_id(BUTTON_CONTENT_VIEW_ID, |ctx| {
             View::<State, Action, _>::rebuild(
                 &self.child,
                 &prev.child,
-                state,
+                child_state,
                 ctx,
                 widgets::Button::child_mut(&mut element).downcast(),
-                app_state,
+                State::reborrow_mut(&mut app_state),
             );
         });
+        IconSlot::Leading.rebuild(
+            self.leading_icon.as_ref(),
+            prev.leading_icon.as_ref(),
+            leading_state,
+            ctx,
+            &mut element,
+            State::reborrow_mut(&mut app_state),
+        );
+        IconSlot::Trailing.rebuild(
+            self.trailing_icon.as_ref(),
+            prev.trailing_icon.as_ref(),
+            trailing_state,
+            ctx,
+            &mut element,
+            app_state,
+        );
     }
 
     fn teardown(
         &self,
-        view_state: &mut Self::ViewState,
+        (child_state, leading_state, trailing_state): &mut Self::ViewState,
         ctx: &mut ViewCtx,
         mut element: Mut<'_, Self::Element>,
     ) {
         ctx.with_id(BUTTON_CONTENT_VIEW_ID, |ctx| {
             View::<State, Action, _>::teardown(
                 &self.child,
-                view_state,
+                child_state,
                 ctx,
                 widgets::Button::child_mut(&mut element).downcast(),
             );
         });
+        IconSlot::Leading.teardown(self.leading_icon.as_ref(), leading_state, ctx, &mut element);
+        IconSlot::Trailing.teardown(
+            self.trailing_icon.as_ref(),
+            trailing_state,
+            ctx,
+            &mut element,
+        );
         ctx.teardown_action_source(element);
     }
 
     fn message(
         &self,
-        view_state: &mut Self::ViewState,
+        (child_state, leading_state, trailing_state): &mut Self::ViewState,
         message: &mut MessageCtx,
         mut element: Mut<'_, Self::Element>,
         app_state: Arg<'_, State>,
     ) -> MessageResult<Action> {
         match message.take_first() {
             Some(BUTTON_CONTENT_VIEW_ID) => self.child.message(
-                view_state,
+                child_state,
                 message,
                 widgets::Button::child_mut(&mut element).downcast(),
                 app_state,
             ),
+            Some(LEADING_ICON_VIEW_ID) => IconSlot::Leading.message(
+                self.leading_icon.as_ref(),
+                leading_state,
+                message,
+                &mut element,
+                app_state,
+            ),
+            Some(TRAILING_ICON_VIEW_ID) => IconSlot::Trailing.message(
+                self.trailing_icon.as_ref(),
+                trailing_state,
+                message,
+                &mut element,
+                app_state,
+            ),
             None => match message.take_message::<ButtonPress>() {
                 Some(press) => (self.callback)(app_state, press.button),
                 None => {
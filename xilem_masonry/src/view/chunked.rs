@@ -0,0 +1,422 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::ViewCtx;
+use crate::core::{
+    AppendVec, Arg, Count, ElementSplice, MessageCtx, MessageProxy, MessageResult, ViewArgument,
+    ViewElement, ViewId, ViewPathTracker, ViewSequence,
+};
+
+type Callback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, RebuildProgress) -> Action + Send + Sync + 'static>;
+
+/// How long each rebuild of a [`chunked`] sequence may take, by default.
+///
+/// This is half of a frame at 60Hz.
+const DEFAULT_BUDGET: Duration = Duration::from_millis(8);
+
+/// A sequence of views which is built and rebuilt in chunks, over several rebuilds.
+///
+/// Rebuilding a sequence with many thousands of views can take longer than a frame, even if
+/// few of them changed, which makes the app unresponsive.
+/// If the sequence can't be virtualized (see [`virtual_scroll`](crate::view::virtual_scroll)),
+/// `chunked` spreads this work out instead: each rebuild only processes as many views as fit
+/// in the [budget](Chunked::budget), and then schedules another rebuild for the remaining views.
+/// In between, the event loop handles other events, such as input and redraws.
+///
+/// This means that the elements can lag behind the app state while a rebuild is in progress.
+/// The elements are updated in order, starting with the first one.
+/// If the views change while a rebuild is in progress, the rebuild continues with the new
+/// views, and then starts over to update the elements it already processed.
+///
+/// The [`on_progress`](Chunked::on_progress) callback is called after each chunk,
+/// for example to show a progress bar.
+///
+/// Each view must have exactly one element.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::WidgetView;
+/// use xilem::core::Edit;
+/// use xilem::view::{chunked, flex_col, label, progress_bar};
+///
+/// struct AppState {
+///     rows: Vec<String>,
+///     progress: f64,
+/// }
+///
+/// fn app_logic(state: &mut AppState) -> impl WidgetView<Edit<AppState>> + use<> {
+///     let rows = state.rows.iter().map(|row| label(row.clone()));
+///     flex_col((
+///         progress_bar(Some(state.progress)),
+///         flex_col(chunked(rows).on_progress(|state: &mut AppState, progress| {
+///             state.progress = progress.fraction();
+///         })),
+///     ))
+/// }
+/// ```
+pub fn chunked<State, Action, V>(views: impl IntoIterator<Item = V>) -> Chunked<V, State, Action>
+where
+    State: ViewArgument,
+{
+    Chunked {
+        views: views.into_iter().collect(),
+        budget: DEFAULT_BUDGET,
+        on_progress: None,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`ViewSequence`] created by [`chunked`].
+///
+/// See its documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Chunked<V, State: ViewArgument, Action = ()> {
+    views: Arc<[V]>,
+    budget: Duration,
+    on_progress: Option<Callback<State, Action>>,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State: ViewArgument, Action> Chunked<V, State, Action> {
+    /// Sets how long each rebuild may spend on the views of this sequence.
+    ///
+    /// At least one view is processed in each rebuild, even if it takes longer.
+    /// The default is 8 milliseconds.
+    pub fn budget(mut self, budget: Duration) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// Sets a callback which is called after each chunk of views was processed.
+    ///
+    /// The callback is also called once the last chunk was processed, with a complete progress.
+    /// The rebuild caused by its action continues with the next chunk, so the callback must
+    /// not change the data the views of the sequence are created from: the elements
+    /// processed before wouldn't be updated.
+    /// If a parent ignores the action, the next chunk is still processed.
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Arg<'_, State>, RebuildProgress) -> Action + Send + Sync + 'static,
+    {
+        self.on_progress = Some(Box::new(callback));
+        self
+    }
+}
+
+/// How far the rebuild of a [`chunked`] sequence is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RebuildProgress {
+    /// The number of views whose elements are up to date.
+    pub done: usize,
+    /// The number of views in the sequence.
+    pub total: usize,
+}
+
+impl RebuildProgress {
+    /// Returns `true` if all the elements are up to date.
+    pub fn is_complete(&self) -> bool {
+        self.done == self.total
+    }
+
+    /// Returns how far the rebuild is, from 0 to 1.
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            1.
+        } else {
+            self.done as f64 / self.total as f64
+        }
+    }
+}
+
+/// The messages a [`Chunked`] sequence sends itself.
+///
+/// Each carries the token of the chunk it is about, so that messages about earlier chunks
+/// are ignored.
+#[derive(Debug)]
+enum ChunkMessage {
+    /// A chunk was processed, and the progress should be reported or the next chunk processed.
+    Processed(RebuildProgress, u64),
+    /// The action of the progress callback was handled, and the next chunk should be
+    /// processed if that didn't rebuild the sequence, e.g. because a parent ignored the action.
+    Continue(u64),
+}
+
+mod hidden {
+    use std::sync::Arc;
+
+    #[doc(hidden)]
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub struct ChunkedState<V, InnerState> {
+        /// The views each element was last built or rebuilt from, and its state.
+        ///
+        /// Elements can be out of date, so these aren't always the views of the previous rebuild.
+        pub(super) children: Vec<(Arc<[V]>, InnerState)>,
+        /// The generation of each index, used to mark messages to removed elements as stale.
+        pub(super) generations: Vec<u32>,
+        /// The index of the next element to process.
+        pub(super) cursor: usize,
+        /// Whether the views changed during this rebuild, so that it has to start over.
+        pub(super) restart: bool,
+        /// The token of the last chunk message sent.
+        pub(super) token: u64,
+        /// The token of the chunk whose progress callback was called, until the sequence
+        /// is rebuilt.
+        ///
+        /// That rebuild continues with the next chunk, even though it has new views.
+        pub(super) reported: Option<u64>,
+    }
+}
+
+use hidden::ChunkedState;
+
+/// Turns an index and a generation into a [`ViewId`], in the same way as `Vec` sequences.
+fn child_id(index: usize, generation: u32) -> ViewId {
+    let index: u32 = index
+        .try_into()
+        .expect("Views in a chunked sequence must be indexable by u32");
+    ViewId::new((u64::from(generation) << 32) | u64::from(index))
+}
+
+/// Undoes [`child_id`].
+fn index_generation(id: ViewId) -> (usize, u32) {
+    #![expect(
+        clippy::cast_possible_truncation,
+        reason = "Explicitly splits u64 into two u32s"
+    )]
+    let id = id.routing_id();
+    (id as u32 as usize, (id >> 32) as u32)
+}
+
+impl<V, State, Action> Chunked<V, State, Action>
+where
+    State: ViewArgument,
+{
+    /// Tells the sequence that a chunk was processed, to report the progress and
+    /// process the next chunk if needed.
+    fn chunk_processed<InnerState>(
+        &self,
+        ctx: &mut ViewCtx,
+        seq_state: &mut ChunkedState<V, InnerState>,
+        progress: RebuildProgress,
+        worked: bool,
+    ) {
+        if progress.is_complete() && !(worked && self.on_progress.is_some()) {
+            return;
+        }
+        seq_state.token = seq_state.token.wrapping_add(1);
+        let token = seq_state.token;
+        let proxy = MessageProxy::<ChunkMessage>::new(ctx.proxy(), ctx.view_path().into());
+        drop(proxy.message(ChunkMessage::Processed(progress, token)));
+        if self.on_progress.is_some() {
+            // If a parent ignores the action of the progress callback, nothing rebuilds the
+            // sequence, so this processes the next chunk instead.
+            drop(proxy.message(ChunkMessage::Continue(token)));
+        }
+    }
+}
+
+impl<V, State, Action, Element> ViewSequence<State, Action, ViewCtx, Element>
+    for Chunked<V, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    V: ViewSequence<State, Action, ViewCtx, Element>,
+    Element: ViewElement,
+{
+    type SeqState = ChunkedState<V, V::SeqState>;
+
+    const ELEMENTS_COUNT: Count = Count::Many;
+
+    fn seq_build(
+        &self,
+        ctx: &mut ViewCtx,
+        elements: &mut AppendVec<Element>,
+        mut app_state: Arg<'_, State>,
+    ) -> Self::SeqState {
+        const {
+            assert!(
+                matches!(V::ELEMENTS_COUNT, Count::One),
+                "Each view in a `chunked` sequence must have exactly one element."
+            );
+        };
+        let start = Instant::now();
+        let mut children = Vec::new();
+        for (idx, view) in self.views.iter().enumerate() {
+            if idx > 0 && start.elapsed() >= self.budget {
+                break;
+            }
+            let inner_state = ctx.with_id(child_id(idx, 0), |ctx| {
+                view.seq_build(ctx, elements, State::reborrow_mut(&mut app_state))
+            });
+            children.push((self.views.clone(), inner_state));
+        }
+
+        let done = children.len();
+        let progress = RebuildProgress {
+            done,
+            total: self.views.len(),
+        };
+        let mut seq_state = ChunkedState {
+            children,
+            generations: vec![0; done],
+            cursor: done,
+            restart: false,
+            token: 0,
+            reported: None,
+        };
+        self.chunk_processed(ctx, &mut seq_state, progress, done > 0);
+        seq_state
+    }
+
+    fn seq_rebuild(
+        &self,
+        prev: &Self,
+        seq_state: &mut Self::SeqState,
+        ctx: &mut ViewCtx,
+        elements: &mut impl ElementSplice<Element>,
+        mut app_state: Arg<'_, State>,
+    ) {
+        // Rebuilds requested by this sequence reuse the same views, and the rebuild caused
+        // by the progress callback is expected not to change them.
+        let reported = seq_state.reported.take() == Some(seq_state.token);
+        if !Arc::ptr_eq(&self.views, &prev.views) && !reported {
+            if seq_state.cursor == prev.views.len() {
+                seq_state.cursor = 0;
+            } else {
+                seq_state.restart = true;
+            }
+        }
+
+        let start = Instant::now();
+        let n = self.views.len();
+        let first = seq_state.cursor.min(n);
+        // Each view has exactly one element.
+        elements.skip(first);
+        let mut idx = first;
+        while idx < n && (idx == first || start.elapsed() < self.budget) {
+            let view = &self.views[idx];
+            if let Some((prev_views, inner_state)) = seq_state.children.get_mut(idx) {
+                let id = child_id(idx, seq_state.generations[idx]);
+                ctx.with_id(id, |ctx| {
+                    view.seq_rebuild(
+                        &prev_views[idx],
+                        inner_state,
+                        ctx,
+                        elements,
+                        State::reborrow_mut(&mut app_state),
+                    );
+                });
+                *prev_views = self.views.clone();
+            } else {
+                if seq_state.generations.len() == idx {
+                    seq_state.generations.push(0);
+                }
+                let id = child_id(idx, seq_state.generations[idx]);
+                let inner_state = elements.with_scratch(|elements| {
+                    ctx.with_id(id, |ctx| {
+                        view.seq_build(ctx, elements, State::reborrow_mut(&mut app_state))
+                    })
+                });
+                seq_state.children.push((self.views.clone(), inner_state));
+            }
+            idx += 1;
+        }
+
+        let mut worked = idx != first;
+        if idx == n {
+            // Tear down the elements of the removed views.
+            for ((prev_views, mut inner_state), index) in seq_state.children.drain(n..).zip(n..) {
+                let generation = &mut seq_state.generations[index];
+                ctx.with_id(child_id(index, *generation), |ctx| {
+                    prev_views[index].seq_teardown(&mut inner_state, ctx, elements);
+                });
+                *generation = generation.wrapping_add(1);
+                worked = true;
+            }
+            if std::mem::take(&mut seq_state.restart) {
+                idx = 0;
+            }
+        } else {
+            elements.skip(seq_state.children.len() - idx);
+        }
+        seq_state.cursor = idx;
+
+        let progress = RebuildProgress {
+            done: idx,
+            total: n,
+        };
+        self.chunk_processed(ctx, seq_state, progress, worked);
+    }
+
+    fn seq_teardown(
+        &self,
+        seq_state: &mut Self::SeqState,
+        ctx: &mut ViewCtx,
+        elements: &mut impl ElementSplice<Element>,
+    ) {
+        for (index, ((views, inner_state), generation)) in seq_state
+            .children
+            .iter_mut()
+            .zip(&seq_state.generations)
+            .enumerate()
+        {
+            ctx.with_id(child_id(index, *generation), |ctx| {
+                views[index].seq_teardown(inner_state, ctx, elements);
+            });
+        }
+    }
+
+    fn seq_message(
+        &self,
+        seq_state: &mut Self::SeqState,
+        message: &mut MessageCtx,
+        elements: &mut impl ElementSplice<Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        let Some(first) = message.take_first() else {
+            let Some(chunk_message) = message.take_message::<ChunkMessage>() else {
+                tracing::error!(?message, "Wrong message type in Chunked::seq_message");
+                return MessageResult::Stale;
+            };
+            return match (*chunk_message, &self.on_progress) {
+                (ChunkMessage::Processed(_, token) | ChunkMessage::Continue(token), _)
+                    if token != seq_state.token =>
+                {
+                    // The sequence was rebuilt since, e.g. by the action of the progress callback.
+                    MessageResult::Nop
+                }
+                (ChunkMessage::Processed(progress, token), Some(on_progress)) => {
+                    seq_state.reported = Some(token);
+                    MessageResult::Action(on_progress(app_state, progress))
+                }
+                (ChunkMessage::Continue(token), _) if seq_state.reported != Some(token) => {
+                    // The action of the progress callback rebuilt the sequence.
+                    MessageResult::Nop
+                }
+                // Process the next chunk.
+                _ => {
+                    seq_state.reported = None;
+                    MessageResult::RequestRebuild
+                }
+            };
+        };
+        let (index, generation) = index_generation(first);
+        if index >= seq_state.children.len() || seq_state.generations[index] != generation {
+            // The element has been removed.
+            return MessageResult::Stale;
+        }
+        let (views, inner_state) = &mut seq_state.children[index];
+        elements.skip(index);
+        views[index].seq_message(inner_state, message, elements, app_state)
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::ClipShape;
+use masonry::properties::Dimensions;
+use masonry::widgets;
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A view which clips its child to a [`ClipShape`].
+///
+/// Both painting and pointer hit-testing are clipped to the shape, which is in the
+/// child's coordinate space.
+/// This is useful for e.g. circular avatars, or custom masks made from a `BezPath`.
+///
+/// See also [`WidgetView::clip`], which is often more convenient.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// # use xilem::{WidgetView, view::{clip, sized_box, label}, masonry::kurbo::Circle};
+/// # use xilem::masonry::layout::AsUnit;
+/// # fn avatar<State: xilem::core::ViewArgument>() -> impl WidgetView<State> + use<State> {
+/// clip(
+///     Circle::new((24., 24.), 24.),
+///     sized_box(label("AZ")).fixed_width(48.px()).fixed_height(48.px()),
+/// )
+/// # }
+/// ```
+pub fn clip<State, Action, V>(shape: impl Into<ClipShape>, inner: V) -> Clip<V, State, Action>
+where
+    State: ViewArgument,
+    V: WidgetView<State, Action>,
+{
+    Clip {
+        inner,
+        shape: shape.into(),
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`clip`].
+///
+/// See `clip` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Clip<V, State, Action = ()> {
+    inner: V,
+    shape: ClipShape,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> ViewMarker for Clip<V, State, Action> {}
+impl<V, State, Action> View<State, Action, ViewCtx> for Clip<V, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    type Element = Pod<widgets::Clip>;
+    type ViewState = V::ViewState;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = self.inner.build(ctx, app_state);
+        let widget = widgets::Clip::new(child.new_widget, self.shape.clone());
+
+        (Pod::new_with_props(widget, Dimensions::MAX), child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        if self.shape != prev.shape {
+            widgets::Clip::set_shape(&mut element, self.shape.clone());
+        }
+        let mut child = widgets::Clip::child_mut(&mut element);
+        self.inner
+            .rebuild(&prev.inner, view_state, ctx, child.downcast(), app_state);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        let mut child = widgets::Clip::child_mut(&mut element);
+        self.inner.teardown(view_state, ctx, child.downcast());
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        let mut child = widgets::Clip::child_mut(&mut element);
+        self.inner
+            .message(view_state, message, child.downcast(), app_state)
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use masonry::core::ArcStr;
+use masonry::widgets::{self, CommandPaletteAction, TextAction};
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+type Callback<State, Action> = Box<dyn Fn(Arg<'_, State>) -> Action + Send + Sync + 'static>;
+
+/// A search field over a list of `commands`, shown over the rest of the app while `visible`,
+/// such as the command palette of a code editor.
+///
+/// The commands are fuzzy matched against the typed text, with the matched characters
+/// shown in bold, and the commands chosen recently are listed first.
+/// The user moves through the commands with the arrow keys and presses Enter <kbd>↵</kbd>
+/// or clicks a command to call its callback.
+/// Pressing Escape or clicking outside the palette calls the [`on_close`](CommandPalette::on_close)
+/// callback, which should hide the palette.
+///
+/// The palette covers all the space it is given, so it is usually the last child of a
+/// [`zstack`](crate::view::zstack) holding the rest of the app.
+/// See the underlying [`CommandPalette`](widgets::CommandPalette) widget for more details.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// # use xilem::view::{command, command_palette, label, zstack};
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+/// struct Editor {
+///     palette_open: bool,
+///     dark_theme: bool,
+///     text: String,
+/// }
+///
+/// fn editor(editor: &mut Editor) -> impl WidgetView<Edit<Editor>> + use<> {
+///     zstack((
+///         label(editor.text.clone()),
+///         command_palette(
+///             vec![
+///                 command("Toggle Theme", |editor: &mut Editor| {
+///                     editor.dark_theme = !editor.dark_theme;
+///                 }),
+///                 command("Clear Text", |editor: &mut Editor| editor.text.clear()),
+///             ],
+///             editor.palette_open,
+///         )
+///         .on_close(|editor: &mut Editor| editor.palette_open = false),
+///     ))
+/// }
+/// ```
+pub fn command_palette<State: ViewArgument, Action>(
+    commands: Vec<Command<State, Action>>,
+    visible: bool,
+) -> CommandPalette<State, Action> {
+    CommandPalette {
+        commands,
+        visible,
+        on_close: None,
+    }
+}
+
+/// A command of a [`command_palette`], with the title it is listed and searched by.
+///
+/// Create it with [`command`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Command<State: ViewArgument, Action = ()> {
+    title: ArcStr,
+    callback: Callback<State, Action>,
+}
+
+/// A command of a [`command_palette`] with the given title, calling `callback` when chosen.
+pub fn command<State: ViewArgument, Action>(
+    title: impl Into<ArcStr>,
+    callback: impl Fn(Arg<'_, State>) -> Action + Send + Sync + 'static,
+) -> Command<State, Action> {
+    Command {
+        title: title.into(),
+        callback: Box::new(callback),
+    }
+}
+
+/// The [`View`] created by [`command_palette`].
+///
+/// See `command_palette` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct CommandPalette<State: ViewArgument, Action = ()> {
+    commands: Vec<Command<State, Action>>,
+    visible: bool,
+    on_close: Option<Callback<State, Action>>,
+}
+
+impl<State: ViewArgument, Action> CommandPalette<State, Action> {
+    /// Set the callback called when the user dismisses the palette or chooses a command,
+    /// which should hide the palette.
+    ///
+    /// When a command is chosen, this is called before the callback of the command,
+    /// and only the action of the command is returned.
+    pub fn on_close<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Arg<'_, State>) -> Action + Send + Sync + 'static,
+    {
+        self.on_close = Some(Box::new(callback));
+        self
+    }
+
+    fn titles(&self) -> impl Iterator<Item = ArcStr> + '_ {
+        self.commands.iter().map(|command| command.title.clone())
+    }
+}
+
+impl<State: ViewArgument, Action> ViewMarker for CommandPalette<State, Action> {}
+impl<State, Action> View<State, Action, ViewCtx> for CommandPalette<State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+{
+    type Element = Pod<widgets::CommandPalette>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let palette = widgets::CommandPalette::new(self.titles()).with_open(self.visible);
+
+        // The inner TextArea sends text actions, which we ignore.
+        ctx.record_action_source(palette.area_id());
+
+        let element = ctx.with_action_widget(|ctx| ctx.create_pod(palette));
+        (element, ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        if !self.titles().eq(prev.titles()) {
+            widgets::CommandPalette::set_commands(&mut element, self.titles());
+        }
+        if self.visible != prev.visible {
+            widgets::CommandPalette::set_open(&mut element, self.visible);
+        }
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.teardown_action_source(widgets::CommandPalette::text_mut(&mut element));
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        mut app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        if message.take_first().is_some() {
+            tracing::warn!("Got unexpected id path in CommandPalette::message");
+            return MessageResult::Stale;
+        }
+        if message.take_message::<TextAction>().is_some() {
+            return MessageResult::Nop;
+        }
+        match message.take_message::<CommandPaletteAction>() {
+            Some(action) => match *action {
+                CommandPaletteAction::Chosen(index) => {
+                    let Some(command) = self.commands.get(index) else {
+                        tracing::warn!("Got a removed command in CommandPalette::message");
+                        return MessageResult::Stale;
+                    };
+                    if let Some(on_close) = &self.on_close {
+                        on_close(State::reborrow_mut(&mut app_state));
+                    }
+                    MessageResult::Action((command.callback)(app_state))
+                }
+                CommandPaletteAction::Dismissed => match &self.on_close {
+                    Some(on_close) => MessageResult::Action(on_close(app_state)),
+                    None => MessageResult::Nop,
+                },
+            },
+            None => {
+                tracing::error!(
+                    ?message,
+                    "Wrong message type in CommandPalette::message, expected CommandPaletteAction"
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
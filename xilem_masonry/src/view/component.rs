@@ -0,0 +1,220 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use crate::core::{Arg, Edit, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A reusable widget with its own local state, which is kept across rebuilds.
+///
+/// Most components in Xilem are functions of the app state, so all of their state has to be
+/// stored in the app state.
+/// That doesn't work well for reusable widgets with internal state the app doesn't care about,
+/// such as whether a color picker is being dragged.
+/// A `Component` instead owns a [`LocalState`](Component::LocalState), which is created when
+/// the component is built and dropped when it is torn down.
+///
+/// The component's [view](Component::view) is a function of both its local state and the
+/// app state, which are passed to its callbacks as a tuple.
+/// The fields of the component itself are its inputs, like the arguments of a component function.
+///
+/// Use [`component`] to use a component as a child view.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::core::Edit;
+/// use xilem::view::{Component, button, component, label};
+/// use xilem::{AnyWidgetView, WidgetView};
+///
+/// /// A button which counts how many times it was clicked, without storing it in the app state.
+/// struct ClickCounter {
+///     name: &'static str,
+/// }
+///
+/// impl Component<Edit<Vec<String>>> for ClickCounter {
+///     type LocalState = u32;
+///     type View = Box<AnyWidgetView<(Edit<u32>, Edit<Vec<String>>)>>;
+///
+///     fn init(&self, _: &mut Vec<String>) -> u32 {
+///         0
+///     }
+///
+///     fn view(&self, clicks: &mut u32, _: &mut Vec<String>) -> Self::View {
+///         let name = self.name;
+///         button(
+///             label(format!("{name}: {clicks}")),
+///             move |(clicks, log): (&mut u32, &mut Vec<String>)| {
+///                 *clicks += 1;
+///                 log.push(format!("{name} was clicked"));
+///             },
+///         )
+///         .boxed()
+///     }
+/// }
+///
+/// fn view(_: &mut Vec<String>) -> impl WidgetView<Edit<Vec<String>>> + use<> {
+///     component(ClickCounter { name: "Apples" })
+/// }
+/// ```
+pub trait Component<State: ViewArgument, Action = ()>: Send + Sync + 'static {
+    /// The state owned by the component.
+    type LocalState: 'static;
+    /// The type of the view created by [`view`](Component::view).
+    ///
+    /// This can be a [boxed view](crate::AnyWidgetView) if the view's type can't be named.
+    type View: WidgetView<(Edit<Self::LocalState>, State), Action>;
+
+    /// Creates the local state, when the component is built.
+    fn init(&self, app_state: Arg<'_, State>) -> Self::LocalState;
+
+    /// Creates the view showing the component.
+    ///
+    /// This is called when the component is built and whenever it is rebuilt.
+    fn view(&self, local_state: &mut Self::LocalState, app_state: Arg<'_, State>) -> Self::View;
+
+    /// Called once the component's view has been built.
+    fn mounted(&self, local_state: &mut Self::LocalState, app_state: Arg<'_, State>) {
+        let _ = (local_state, app_state);
+    }
+
+    /// Called when the component is rebuilt, before its view is created.
+    ///
+    /// `prev` is the component from the previous rebuild, which can be compared to `self`
+    /// to update the local state when the component's inputs changed.
+    fn update(&self, prev: &Self, local_state: &mut Self::LocalState, app_state: Arg<'_, State>) {
+        let _ = (prev, local_state, app_state);
+    }
+
+    /// Called when the component is torn down, before its local state is dropped.
+    fn unmounted(&self, local_state: &mut Self::LocalState) {
+        let _ = local_state;
+    }
+}
+
+/// Uses the [`Component`] `component` as a view.
+///
+/// See the documentation of `Component` for more details.
+pub fn component<C, State, Action>(component: C) -> ComponentView<C, State, Action>
+where
+    C: Component<State, Action>,
+    State: ViewArgument,
+{
+    ComponentView {
+        component,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`component`].
+///
+/// See its documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct ComponentView<C, State, Action = ()> {
+    component: C,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+mod hidden {
+    #[doc(hidden)]
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub struct ComponentState<LocalState, V, ViewState> {
+        pub(super) local_state: LocalState,
+        pub(super) view: V,
+        pub(super) view_state: ViewState,
+    }
+}
+
+use hidden::ComponentState;
+
+impl<C, State, Action> ViewMarker for ComponentView<C, State, Action> {}
+impl<C, State, Action> View<State, Action, ViewCtx> for ComponentView<C, State, Action>
+where
+    C: Component<State, Action>,
+    State: ViewArgument,
+    Action: 'static,
+{
+    type Element = Pod<<C::View as WidgetView<(Edit<C::LocalState>, State), Action>>::Widget>;
+    type ViewState = ComponentState<
+        C::LocalState,
+        C::View,
+        <C::View as View<(Edit<C::LocalState>, State), Action, ViewCtx>>::ViewState,
+    >;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        mut app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let mut local_state = self.component.init(State::reborrow_mut(&mut app_state));
+        let view = self
+            .component
+            .view(&mut local_state, State::reborrow_mut(&mut app_state));
+        let (element, view_state) =
+            view.build(ctx, (&mut local_state, State::reborrow_mut(&mut app_state)));
+        self.component.mounted(&mut local_state, app_state);
+        let state = ComponentState {
+            local_state,
+            view,
+            view_state,
+        };
+        (element, state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+        mut app_state: Arg<'_, State>,
+    ) {
+        let local_state = &mut state.local_state;
+        self.component.update(
+            &prev.component,
+            local_state,
+            State::reborrow_mut(&mut app_state),
+        );
+        let view = self
+            .component
+            .view(local_state, State::reborrow_mut(&mut app_state));
+        view.rebuild(
+            &state.view,
+            &mut state.view_state,
+            ctx,
+            element,
+            (local_state, State::reborrow_mut(&mut app_state)),
+        );
+        state.view = view;
+    }
+
+    fn teardown(
+        &self,
+        state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        state.view.teardown(&mut state.view_state, ctx, element);
+        self.component.unmounted(&mut state.local_state);
+    }
+
+    fn message(
+        &self,
+        state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        element: Mut<'_, Self::Element>,
+        mut app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        state.view.message(
+            &mut state.view_state,
+            message,
+            element,
+            (&mut state.local_state, State::reborrow_mut(&mut app_state)),
+        )
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A view which repaints the widget created by `child`, and all its descendants,
+/// on every animation frame while `continuous` is true.
+///
+/// This is meant for content which changes every frame without going through Masonry,
+/// e.g. a game view.
+/// Prefer requesting repaints when something changes; the frame rate of continuous
+/// redraws is limited by the frame pacing of the window.
+pub fn continuous_redraw<Child, State, Action>(
+    continuous: bool,
+    child: Child,
+) -> ContinuousRedraw<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: ViewArgument,
+{
+    ContinuousRedraw {
+        child,
+        continuous,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`continuous_redraw`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct ContinuousRedraw<V, State, Action> {
+    child: V,
+    continuous: bool,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> ViewMarker for ContinuousRedraw<V, State, Action> {}
+impl<Child, State, Action> View<State, Action, ViewCtx> for ContinuousRedraw<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: ViewArgument,
+    Action: 'static,
+{
+    type Element = Pod<Child::Widget>;
+    type ViewState = Child::ViewState;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (mut child_pod, child_state) = self.child.build(ctx, app_state);
+        child_pod.new_widget.options.continuous_redraw = self.continuous;
+        (child_pod, child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        self.child.rebuild(
+            &prev.child,
+            view_state,
+            ctx,
+            element.reborrow_mut(),
+            app_state,
+        );
+        if self.continuous != prev.continuous {
+            element.ctx.set_continuous_redraw(self.continuous);
+        }
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        self.child.teardown(view_state, ctx, element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        self.child.message(view_state, message, element, app_state)
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use masonry::core::ArcStr;
+use masonry::kurbo::Axis;
+use masonry::layout::Length;
+use masonry::widgets;
+pub use masonry::widgets::Placement;
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+/// A horizontal line to divide content, such as the items of a list.
+///
+/// Use [`vertical_divider`] to divide content laid out in a row.
+///
+/// The underlying widget is the Masonry [`Divider`](widgets::Divider), which also
+/// supports dashed lines.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// # use xilem::{view::{divider, flex_col, label}, WidgetView};
+/// # use xilem::masonry::layout::AsUnit;
+/// # use xilem::core::Edit;
+/// # struct State;
+/// # fn view(_: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+/// flex_col((
+///     label("Inbox"),
+///     divider().start_inset(24.px()),
+///     label("Archive"),
+///     divider().label("Labels"),
+///     label("Work"),
+/// ))
+/// # }
+/// ```
+pub fn divider() -> Divider {
+    Divider::new(Axis::Horizontal)
+}
+
+/// A vertical line to divide content, such as the items of a toolbar.
+///
+/// See [`divider`] for more details.
+pub fn vertical_divider() -> Divider {
+    Divider::new(Axis::Vertical)
+}
+
+/// The [`View`] created by [`divider`] or [`vertical_divider`].
+///
+/// See `divider` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Divider {
+    axis: Axis,
+    thickness: Option<Length>,
+    start_inset: Length,
+    end_inset: Length,
+    label: Option<ArcStr>,
+    placement: Placement,
+}
+
+impl Divider {
+    fn new(axis: Axis) -> Self {
+        Self {
+            axis,
+            thickness: None,
+            start_inset: Length::ZERO,
+            end_inset: Length::ZERO,
+            label: None,
+            placement: Placement::default(),
+        }
+    }
+
+    /// Set the thickness of the line.
+    ///
+    /// The line is a hairline, i.e. 1 device pixel, by default.
+    pub fn thickness(mut self, thickness: Length) -> Self {
+        self.thickness = Some(thickness);
+        self
+    }
+
+    /// Set the space between the line and both its start and end edges.
+    pub fn inset(mut self, inset: Length) -> Self {
+        self.start_inset = inset;
+        self.end_inset = inset;
+        self
+    }
+
+    /// Set the space between the start edge and the line.
+    pub fn start_inset(mut self, inset: Length) -> Self {
+        self.start_inset = inset;
+        self
+    }
+
+    /// Set the space between the line and the end edge.
+    pub fn end_inset(mut self, inset: Length) -> Self {
+        self.end_inset = inset;
+        self
+    }
+
+    /// Show a text label on the line, in the center unless another [`placement`](Self::placement)
+    /// is chosen.
+    pub fn label(mut self, text: impl Into<ArcStr>) -> Self {
+        self.label = Some(text.into());
+        self
+    }
+
+    /// Set where the label is shown along the line.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+}
+
+impl ViewMarker for Divider {}
+impl<State: ViewArgument, Action> View<State, Action, ViewCtx> for Divider {
+    type Element = Pod<widgets::Divider>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let mut widget = widgets::Divider::new(self.axis)
+            .start_inset(self.start_inset)
+            .end_inset(self.end_inset)
+            .placement(self.placement);
+        if let Some(thickness) = self.thickness {
+            widget = widget.thickness(thickness);
+        }
+        if let Some(label) = &self.label {
+            widget = widget.label(label.clone());
+        }
+        (ctx.create_pod(widget), ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        if prev.axis != self.axis {
+            widgets::Divider::set_axis(&mut element, self.axis);
+        }
+        if prev.thickness != self.thickness {
+            widgets::Divider::set_thickness(&mut element, self.thickness);
+        }
+        if prev.start_inset != self.start_inset {
+            widgets::Divider::set_start_inset(&mut element, self.start_inset);
+        }
+        if prev.end_inset != self.end_inset {
+            widgets::Divider::set_end_inset(&mut element, self.end_inset);
+        }
+        if prev.label != self.label {
+            widgets::Divider::set_label(&mut element, self.label.clone());
+        }
+        if prev.placement != self.placement {
+            widgets::Divider::set_placement(&mut element, self.placement);
+        }
+    }
+
+    fn teardown(&self, (): &mut Self::ViewState, _: &mut ViewCtx, _: Mut<'_, Self::Element>) {}
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        tracing::error!(
+            ?message,
+            "Message arrived in Divider::message, but Divider doesn't consume any messages, this is a bug."
+        );
+        MessageResult::Stale
+    }
+}
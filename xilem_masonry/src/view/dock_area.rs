@@ -0,0 +1,519 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::type_name;
+use std::marker::PhantomData;
+
+use masonry::core::{ArcStr, CollectionWidget, FromDynWidget, Widget, WidgetMut};
+use masonry::widgets::{self, DockLayoutChanged};
+
+use crate::core::{
+    AppendVec, Arg, ElementSplice, MessageCtx, MessageResult, Mut, SuperElement, View,
+    ViewArgument, ViewElement, ViewId, ViewMarker, ViewPathTracker, ViewSequence,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+pub use masonry::widgets::{DockLayout, DockPanel, DockPosition};
+
+type Callback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, DockLayout) -> Action + Send + Sync + 'static>;
+
+/// An area split into groups of panels, which users can rearrange, such as the tool windows
+/// of an IDE.
+///
+/// Each view in the sequence is a panel, given a key and a title with
+/// [`DockAreaExt::dock_panel`].
+/// The `layout` places the panels by their key, in groups shown as tabs.
+/// Users can resize the groups, and drag tabs to other groups or to their edges.
+/// The area rearranges itself as they do, and passes the new layout to the
+/// [`on_layout_change`](DockArea::on_layout_change) callback, so that the app can store it.
+/// [`DockLayout`] can be serialized, to restore the layout when the app is restarted.
+///
+/// The area is reset to `layout` when it changes.
+///
+/// See the documentation on the underlying [`DockArea`](widgets::DockArea) for more information.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::core::Edit;
+/// use xilem::view::{DockAreaExt, DockLayout, DockPosition, dock_area, label, prose};
+///
+/// struct Editor {
+///     layout: DockLayout,
+///     text: String,
+/// }
+///
+/// let mut layout = DockLayout::tabs(["editor", "terminal"]);
+/// layout.dock("files", "editor", DockPosition::Left);
+/// let editor = Editor {
+///     layout,
+///     text: String::new(),
+/// };
+///
+/// dock_area::<Edit<Editor>, _, _>(
+///     editor.layout.clone(),
+///     (
+///         label("src/main.rs").dock_panel("files", "Files"),
+///         prose(editor.text.clone()).dock_panel("editor", "main.rs"),
+///         label("$ cargo run").dock_panel("terminal", "Terminal"),
+///     ),
+/// )
+/// .on_layout_change(|editor: &mut Editor, layout| editor.layout = layout);
+/// ```
+pub fn dock_area<State: ViewArgument, Action, Seq: DockAreaSequence<State, Action>>(
+    layout: DockLayout,
+    sequence: Seq,
+) -> DockArea<Seq, State, Action> {
+    DockArea {
+        sequence,
+        layout,
+        on_layout_change: None,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`dock_area`] from a sequence.
+///
+/// See `dock_area` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct DockArea<Seq, State: ViewArgument, Action = ()> {
+    sequence: Seq,
+    layout: DockLayout,
+    on_layout_change: Option<Callback<State, Action>>,
+
+    /// Used to associate the State and Action in the call to `.dock_area()` with the State
+    /// and Action used in the View implementation, to allow inference to flow backwards,
+    /// allowing State and Action to be inferred properly.
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<Seq, State: ViewArgument, Action> DockArea<Seq, State, Action> {
+    /// Sets a callback which is called with the new layout each time the user
+    /// rearranges the panels.
+    pub fn on_layout_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Arg<'_, State>, DockLayout) -> Action + Send + Sync + 'static,
+    {
+        self.on_layout_change = Some(Box::new(callback));
+        self
+    }
+}
+
+mod hidden {
+    use super::DockAreaElement;
+    use crate::core::AppendVec;
+
+    #[doc(hidden)]
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub struct DockAreaState<SeqState> {
+        pub(crate) seq_state: SeqState,
+        pub(crate) scratch: AppendVec<DockAreaElement>,
+    }
+}
+
+use hidden::DockAreaState;
+
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 2370524186 in decimal.
+const DOCK_PANELS_VIEW_ID: ViewId = ViewId::new(0x8d4b6e1a);
+
+impl<Seq, State: ViewArgument, Action> ViewMarker for DockArea<Seq, State, Action> {}
+
+impl<State, Action, Seq> View<State, Action, ViewCtx> for DockArea<Seq, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    Seq: DockAreaSequence<State, Action>,
+{
+    type Element = Pod<widgets::DockArea>;
+
+    type ViewState = DockAreaState<Seq::SeqState>;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let mut elements = AppendVec::default();
+        let mut widget = widgets::DockArea::new(self.layout.clone());
+        let seq_state = ctx.with_id(DOCK_PANELS_VIEW_ID, |ctx| {
+            self.sequence.seq_build(ctx, &mut elements, app_state)
+        });
+        for element in elements.drain() {
+            widget = widget.with(element.child.new_widget, element.params);
+        }
+        let pod = ctx.with_action_widget(|ctx| ctx.create_pod(widget));
+        (
+            pod,
+            DockAreaState {
+                seq_state,
+                scratch: elements,
+            },
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        DockAreaState { seq_state, scratch }: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        ctx.with_id(DOCK_PANELS_VIEW_ID, |ctx| {
+            let mut splice = DockAreaSplice::new(element.reborrow_mut(), scratch);
+            self.sequence
+                .seq_rebuild(&prev.sequence, seq_state, ctx, &mut splice, app_state);
+            debug_assert!(scratch.is_empty());
+        });
+
+        if self.layout != prev.layout {
+            widgets::DockArea::set_layout(&mut element, self.layout.clone());
+        }
+    }
+
+    fn teardown(
+        &self,
+        DockAreaState { seq_state, scratch }: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(DOCK_PANELS_VIEW_ID, |ctx| {
+            let mut splice = DockAreaSplice::new(element.reborrow_mut(), scratch);
+            self.sequence.seq_teardown(seq_state, ctx, &mut splice);
+            debug_assert!(scratch.is_empty());
+        });
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        DockAreaState { seq_state, scratch }: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(DOCK_PANELS_VIEW_ID) => {
+                let mut splice = DockAreaSplice::new(element, scratch);
+                let result = self
+                    .sequence
+                    .seq_message(seq_state, message, &mut splice, app_state);
+                debug_assert!(scratch.is_empty());
+                result
+            }
+            None => match message.take_message::<DockLayoutChanged>() {
+                Some(changed) => match &self.on_layout_change {
+                    Some(callback) => MessageResult::Action(callback(app_state, changed.0)),
+                    None => MessageResult::Nop,
+                },
+                None => {
+                    tracing::error!(
+                        "Wrong message type in DockArea::message: {message:?} expected {}",
+                        type_name::<DockLayoutChanged>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!(?message, "Got unexpected id path in `DockArea::message`.");
+                MessageResult::Stale
+            }
+        }
+    }
+}
+
+// Used to become a reference form for editing. It's provided to rebuild and teardown.
+impl ViewElement for DockAreaElement {
+    type Mut<'w> = DockAreaElementMut<'w>;
+}
+
+// Used to allow the item to be used as a generic item in ViewSequence.
+impl SuperElement<Self, ViewCtx> for DockAreaElement {
+    fn upcast(_ctx: &mut ViewCtx, child: Self) -> Self {
+        child
+    }
+
+    fn with_downcast_val<R>(
+        mut this: Mut<'_, Self>,
+        f: impl FnOnce(Mut<'_, Self>) -> R,
+    ) -> (Self::Mut<'_>, R) {
+        let r = {
+            let parent = this.parent.reborrow_mut();
+            let reborrow = DockAreaElementMut {
+                idx: this.idx,
+                parent,
+            };
+            f(reborrow)
+        };
+        (this, r)
+    }
+}
+
+impl<W: Widget + FromDynWidget + ?Sized> SuperElement<Pod<W>, ViewCtx> for DockAreaElement {
+    fn upcast(_: &mut ViewCtx, child: Pod<W>) -> Self {
+        // Getting here means that the widget didn't use .dock_panel.
+        // Without a key, the panel isn't in any layout, so it stays hidden.
+        Self {
+            child: child.erased(),
+            params: DockPanel::new("", ""),
+        }
+    }
+
+    fn with_downcast_val<R>(
+        mut this: Mut<'_, Self>,
+        f: impl FnOnce(Mut<'_, Pod<W>>) -> R,
+    ) -> (Mut<'_, Self>, R) {
+        let ret = {
+            let mut child = widgets::DockArea::get_mut(&mut this.parent, this.idx);
+            let downcast = child.downcast();
+            f(downcast)
+        };
+
+        (this, ret)
+    }
+}
+
+// Used for building and rebuilding the ViewSequence
+impl ElementSplice<DockAreaElement> for DockAreaSplice<'_, '_> {
+    fn with_scratch<R>(&mut self, f: impl FnOnce(&mut AppendVec<DockAreaElement>) -> R) -> R {
+        let ret = f(self.scratch);
+        for element in self.scratch.drain() {
+            widgets::DockArea::insert(
+                &mut self.element,
+                self.idx,
+                element.child.new_widget,
+                element.params,
+            );
+            self.idx += 1;
+        }
+        ret
+    }
+
+    fn insert(&mut self, element: DockAreaElement) {
+        widgets::DockArea::insert(
+            &mut self.element,
+            self.idx,
+            element.child.new_widget,
+            element.params,
+        );
+        self.idx += 1;
+    }
+
+    fn mutate<R>(&mut self, f: impl FnOnce(Mut<'_, DockAreaElement>) -> R) -> R {
+        let child = DockAreaElementMut {
+            parent: self.element.reborrow_mut(),
+            idx: self.idx,
+        };
+        let ret = f(child);
+        self.idx += 1;
+        ret
+    }
+
+    fn skip(&mut self, n: usize) {
+        self.idx += n;
+    }
+
+    fn move_from(&mut self, n: usize) -> bool {
+        for idx in (self.idx..self.idx + n).rev() {
+            widgets::DockArea::swap(&mut self.element, idx, idx + 1);
+        }
+        true
+    }
+
+    fn index(&self) -> usize {
+        self.idx
+    }
+
+    fn delete<R>(&mut self, f: impl FnOnce(Mut<'_, DockAreaElement>) -> R) -> R {
+        let ret = {
+            let child = DockAreaElementMut {
+                parent: self.element.reborrow_mut(),
+                idx: self.idx,
+            };
+            f(child)
+        };
+        widgets::DockArea::remove(&mut self.element, self.idx);
+        ret
+    }
+}
+
+/// `DockAreaSequence` is what allows an input to the dock area that contains all the panels.
+pub trait DockAreaSequence<State: ViewArgument, Action = ()>:
+    ViewSequence<State, Action, ViewCtx, DockAreaElement>
+{
+}
+
+impl<Seq, State, Action> DockAreaSequence<State, Action> for Seq
+where
+    Seq: ViewSequence<State, Action, ViewCtx, DockAreaElement>,
+    State: ViewArgument,
+{
+}
+
+/// A trait which extends a [`WidgetView`] with methods to provide parameters for a dock panel.
+pub trait DockAreaExt<State: ViewArgument, Action>: WidgetView<State, Action> {
+    /// Uses this view as a panel of a [`DockArea`], which its layout refers to by `key`
+    /// and whose tab shows `title`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// use xilem::view::{DockAreaExt, DockLayout, dock_area, label};
+    /// # use xilem::WidgetView;
+    /// # use xilem::core::Edit;
+    ///
+    /// # fn view<State: 'static>() -> impl WidgetView<Edit<State>> {
+    /// dock_area(
+    ///     DockLayout::tabs(["outline", "problems"]),
+    ///     (
+    ///         label("fn main()").dock_panel("outline", "Outline"),
+    ///         label("No problems").dock_panel("problems", "Problems"),
+    ///     ),
+    /// )
+    /// # }
+    /// ```
+    fn dock_panel(
+        self,
+        key: impl Into<String>,
+        title: impl Into<ArcStr>,
+    ) -> DockPanelItem<Self, State, Action>
+    where
+        State: 'static,
+        Action: 'static,
+        Self: Sized,
+    {
+        dock_panel(self, key, title)
+    }
+}
+
+impl<State: ViewArgument, Action, V: WidgetView<State, Action>> DockAreaExt<State, Action> for V {}
+
+/// A child widget within a [`DockArea`] view.
+pub struct DockAreaElement {
+    /// The child widget.
+    child: Pod<dyn Widget>,
+    /// The panel parameters of the child widget.
+    params: DockPanel,
+}
+
+/// A mutable reference to a [`DockAreaElement`], used internally by Xilem traits.
+pub struct DockAreaElementMut<'w> {
+    parent: WidgetMut<'w, widgets::DockArea>,
+    idx: usize,
+}
+
+// Used for manipulating the ViewSequence.
+struct DockAreaSplice<'w, 's> {
+    idx: usize,
+    element: WidgetMut<'w, widgets::DockArea>,
+    scratch: &'s mut AppendVec<DockAreaElement>,
+}
+
+impl<'w, 's> DockAreaSplice<'w, 's> {
+    fn new(
+        element: WidgetMut<'w, widgets::DockArea>,
+        scratch: &'s mut AppendVec<DockAreaElement>,
+    ) -> Self {
+        Self {
+            idx: 0,
+            element,
+            scratch,
+        }
+    }
+}
+
+/// A `WidgetView` that can be used as a panel within a [`DockArea`] [`View`].
+pub struct DockPanelItem<V, State, Action> {
+    view: V,
+    params: DockPanel,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+/// Creates a [`DockPanelItem`] from a view, and the key and title of the panel.
+pub fn dock_panel<V, State, Action>(
+    view: V,
+    key: impl Into<String>,
+    title: impl Into<ArcStr>,
+) -> DockPanelItem<V, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    DockPanelItem {
+        view,
+        params: DockPanel::new(key, title),
+        phantom: PhantomData,
+    }
+}
+
+impl<V, State, Action> ViewMarker for DockPanelItem<V, State, Action> {}
+
+impl<State, Action, V> View<State, Action, ViewCtx> for DockPanelItem<V, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    type Element = DockAreaElement;
+
+    type ViewState = V::ViewState;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (pod, state) = self.view.build(ctx, app_state);
+        (
+            DockAreaElement {
+                child: pod.erased(),
+                params: self.params.clone(),
+            },
+            state,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        if self.params != prev.params {
+            widgets::DockArea::set_params(&mut element.parent, element.idx, self.params.clone());
+        }
+        let mut child = widgets::DockArea::get_mut(&mut element.parent, element.idx);
+        self.view
+            .rebuild(&prev.view, view_state, ctx, child.downcast(), app_state);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        let mut child = widgets::DockArea::get_mut(&mut element.parent, element.idx);
+        self.view.teardown(view_state, ctx, child.downcast());
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        let mut child = widgets::DockArea::get_mut(&mut element.parent, element.idx);
+        self.view
+            .message(view_state, message, child.downcast(), app_state)
+    }
+}
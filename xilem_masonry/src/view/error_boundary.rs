@@ -0,0 +1,396 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::Any;
+use std::error::Error;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe};
+
+use masonry::properties::Dimensions;
+use masonry::widgets::Passthrough;
+
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// An error caught by an [`error_boundary`].
+#[derive(Debug)]
+pub enum CaughtError {
+    /// The child panicked, with the given message.
+    ///
+    /// The message is empty if the panic payload wasn't a string.
+    Panic(String),
+    /// The child raised an error with [`raise_error`].
+    Raised(Box<dyn Error + Send + Sync>),
+}
+
+impl CaughtError {
+    fn from_payload(payload: Box<dyn Any + Send>) -> Self {
+        let payload = match payload.downcast::<RaisedError>() {
+            Ok(raised) => return Self::Raised(raised.0),
+            Err(payload) => payload,
+        };
+        let payload = match payload.downcast::<String>() {
+            Ok(message) => return Self::Panic(*message),
+            Err(payload) => payload,
+        };
+        match payload.downcast::<&'static str>() {
+            Ok(message) => Self::Panic((*message).to_string()),
+            Err(_) => Self::Panic(String::new()),
+        }
+    }
+}
+
+impl Display for CaughtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Panic(message) if message.is_empty() => f.write_str("panicked"),
+            Self::Panic(message) => write!(f, "panicked: {message}"),
+            Self::Raised(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+/// The panic payload used by [`raise_error`].
+struct RaisedError(Box<dyn Error + Send + Sync>);
+
+/// Aborts the current build, rebuild or message handling with the given error, which is caught
+/// by the closest [`error_boundary`] ancestor.
+///
+/// This unwinds the stack like a panic, without running the panic hook.
+/// It can be called from views and from their callbacks.
+/// If there is no error boundary, the error is propagated like a panic.
+pub fn raise_error(error: impl Into<Box<dyn Error + Send + Sync>>) -> ! {
+    panic::resume_unwind(Box::new(RaisedError(error.into())))
+}
+
+/// Runs `f`, catching any panic or [raised error](raise_error).
+///
+/// If `f` unwinds, the view path is reset to what it was before `f` ran.
+fn catch<R>(ctx: &mut ViewCtx, f: impl FnOnce(&mut ViewCtx) -> R) -> Result<R, CaughtError> {
+    let depth = ctx.view_path().len();
+    panic::catch_unwind(AssertUnwindSafe(|| f(ctx))).map_err(|payload| {
+        while ctx.view_path().len() > depth {
+            ctx.pop_id();
+        }
+        CaughtError::from_payload(payload)
+    })
+}
+
+/// A view which shows a fallback instead of `child` if `child` panics or
+/// [raises an error](raise_error).
+///
+/// Errors are caught while building and rebuilding `child`, and while it handles messages
+/// (e.g. in the callback of a [`button`](crate::view::button)).
+/// The child's subtree is then replaced by the view returned by `fallback` for the error,
+/// and the error is logged and passed to the [`on_error`](ErrorBoundary::on_error) callback.
+/// This keeps one broken component from taking down the whole app.
+///
+/// Once an error has been caught, the fallback is shown until the boundary is torn down.
+/// The child isn't torn down when it fails, because its state might be inconsistent,
+/// so resources it holds (such as [tasks](crate::view::task)) might outlive it.
+///
+/// Panics are only caught if the app is compiled with `panic = "unwind"`, which is the default.
+/// Panics in `fallback`, and errors raised while the child is torn down, aren't caught.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::view::{button, error_boundary, label, raise_error};
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// # fn view() -> impl WidgetView<Edit<u32>> {
+/// error_boundary(
+///     button(label("Load"), |count: &mut u32| {
+///         *count += 1;
+///         if *count > 3 {
+///             raise_error("Too many attempts");
+///         }
+///     }),
+///     |err| label(format!("Something went wrong: {err}")),
+/// )
+/// .on_error(|err| eprintln!("Error in the load button: {err}"))
+/// # }
+/// ```
+pub fn error_boundary<State, Action, V, F, FV>(
+    child: V,
+    fallback: F,
+) -> ErrorBoundary<V, F, fn(&CaughtError), State, Action>
+where
+    State: ViewArgument,
+    V: WidgetView<State, Action>,
+    F: Fn(&CaughtError) -> FV + Send + Sync + 'static,
+    FV: WidgetView<State, Action>,
+{
+    ErrorBoundary {
+        child,
+        fallback,
+        on_error: |_| {},
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`error_boundary`].
+///
+/// See its documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct ErrorBoundary<V, F, E, State, Action = ()> {
+    child: V,
+    fallback: F,
+    on_error: E,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, F, E, State, Action> ErrorBoundary<V, F, E, State, Action> {
+    /// Sets a callback called with each error caught by the boundary, e.g. to report it.
+    pub fn on_error<E2>(self, on_error: E2) -> ErrorBoundary<V, F, E2, State, Action>
+    where
+        E2: Fn(&CaughtError) + Send + Sync + 'static,
+    {
+        ErrorBoundary {
+            child: self.child,
+            fallback: self.fallback,
+            on_error,
+            phantom: PhantomData,
+        }
+    }
+
+    fn report(&self, error: &CaughtError)
+    where
+        E: Fn(&CaughtError),
+    {
+        tracing::error!("Error boundary caught an error: {error}");
+        (self.on_error)(error);
+    }
+}
+
+// Use distinctive numbers here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 2877361245 in decimal.
+const ERROR_BOUNDARY_CHILD_VIEW_ID: ViewId = ViewId::new(0xab81f35d);
+/// This is a randomly generated 32 bit number - 1046243014 in decimal.
+const ERROR_BOUNDARY_FALLBACK_VIEW_ID: ViewId = ViewId::new(0x3e5c6ec6);
+
+mod hidden {
+    use super::CaughtError;
+
+    #[doc(hidden)]
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub enum ErrorBoundaryState<ChildState, FV, FallbackState> {
+        /// The child is shown.
+        Child(ChildState),
+        /// The child failed while handling a message, and will be replaced by the fallback
+        /// in the next rebuild.
+        Failed(CaughtError),
+        /// The fallback is shown.
+        Fallback {
+            error: CaughtError,
+            view: FV,
+            view_state: FallbackState,
+        },
+    }
+}
+
+use hidden::ErrorBoundaryState;
+
+impl<V, F, E, State, Action> ViewMarker for ErrorBoundary<V, F, E, State, Action> {}
+impl<V, F, E, State, Action, FV> View<State, Action, ViewCtx>
+    for ErrorBoundary<V, F, E, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+    F: Fn(&CaughtError) -> FV + Send + Sync + 'static,
+    FV: WidgetView<State, Action>,
+    E: Fn(&CaughtError) + Send + Sync + 'static,
+{
+    type Element = Pod<Passthrough>;
+    type ViewState = ErrorBoundaryState<V::ViewState, FV, FV::ViewState>;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        mut app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let result = catch(ctx, |ctx| {
+            ctx.with_id(ERROR_BOUNDARY_CHILD_VIEW_ID, |ctx| {
+                self.child.build(ctx, State::reborrow_mut(&mut app_state))
+            })
+        });
+        let (child, view_state) = match result {
+            Ok((child, child_state)) => (
+                child.new_widget.erased(),
+                ErrorBoundaryState::Child(child_state),
+            ),
+            Err(error) => {
+                self.report(&error);
+                let view = (self.fallback)(&error);
+                let (child, view_state) = ctx.with_id(ERROR_BOUNDARY_FALLBACK_VIEW_ID, |ctx| {
+                    view.build(ctx, app_state)
+                });
+                let view_state = ErrorBoundaryState::Fallback {
+                    error,
+                    view,
+                    view_state,
+                };
+                (child.new_widget.erased(), view_state)
+            }
+        };
+        let widget = Passthrough::new(child);
+        (Pod::new_with_props(widget, Dimensions::MAX), view_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        mut app_state: Arg<'_, State>,
+    ) {
+        let error = match view_state {
+            ErrorBoundaryState::Child(child_state) => {
+                let result = catch(ctx, |ctx| {
+                    ctx.with_id(ERROR_BOUNDARY_CHILD_VIEW_ID, |ctx| {
+                        self.child.rebuild(
+                            &prev.child,
+                            child_state,
+                            ctx,
+                            Passthrough::child_mut(&mut element).downcast(),
+                            State::reborrow_mut(&mut app_state),
+                        );
+                    });
+                });
+                let Err(error) = result else {
+                    return;
+                };
+                self.report(&error);
+                error
+            }
+            // The error is moved to the `Fallback` state below.
+            ErrorBoundaryState::Failed(error) => {
+                std::mem::replace(error, CaughtError::Panic(String::new()))
+            }
+            ErrorBoundaryState::Fallback {
+                error,
+                view,
+                view_state,
+            } => {
+                let new_view = (self.fallback)(error);
+                ctx.with_id(ERROR_BOUNDARY_FALLBACK_VIEW_ID, |ctx| {
+                    new_view.rebuild(
+                        view,
+                        view_state,
+                        ctx,
+                        Passthrough::child_mut(&mut element).downcast(),
+                        app_state,
+                    );
+                });
+                *view = new_view;
+                return;
+            }
+        };
+
+        // Replace the failed child with the fallback.
+        let view = (self.fallback)(&error);
+        let (child, fallback_state) = ctx.with_id(ERROR_BOUNDARY_FALLBACK_VIEW_ID, |ctx| {
+            view.build(ctx, app_state)
+        });
+        Passthrough::set_child(&mut element, child.new_widget);
+        *view_state = ErrorBoundaryState::Fallback {
+            error,
+            view,
+            view_state: fallback_state,
+        };
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        match view_state {
+            ErrorBoundaryState::Child(child_state) => {
+                ctx.with_id(ERROR_BOUNDARY_CHILD_VIEW_ID, |ctx| {
+                    self.child.teardown(
+                        child_state,
+                        ctx,
+                        Passthrough::child_mut(&mut element).downcast(),
+                    );
+                });
+            }
+            ErrorBoundaryState::Failed(_) => {}
+            ErrorBoundaryState::Fallback {
+                view, view_state, ..
+            } => {
+                ctx.with_id(ERROR_BOUNDARY_FALLBACK_VIEW_ID, |ctx| {
+                    view.teardown(
+                        view_state,
+                        ctx,
+                        Passthrough::child_mut(&mut element).downcast(),
+                    );
+                });
+            }
+        }
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match (message.take_first(), &mut *view_state) {
+            (Some(ERROR_BOUNDARY_CHILD_VIEW_ID), ErrorBoundaryState::Child(child_state)) => {
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    self.child.message(
+                        child_state,
+                        message,
+                        Passthrough::child_mut(&mut element).downcast(),
+                        app_state,
+                    )
+                }));
+                match result {
+                    Ok(result) => result,
+                    Err(payload) => {
+                        // The fallback can only be built during a rebuild.
+                        let error = CaughtError::from_payload(payload);
+                        self.report(&error);
+                        *view_state = ErrorBoundaryState::Failed(error);
+                        MessageResult::RequestRebuild
+                    }
+                }
+            }
+            (
+                Some(ERROR_BOUNDARY_FALLBACK_VIEW_ID),
+                ErrorBoundaryState::Fallback {
+                    view, view_state, ..
+                },
+            ) => view.message(
+                view_state,
+                message,
+                Passthrough::child_mut(&mut element).downcast(),
+                app_state,
+            ),
+            (Some(ERROR_BOUNDARY_CHILD_VIEW_ID | ERROR_BOUNDARY_FALLBACK_VIEW_ID), _) => {
+                // A message for the child after it failed, or for an old fallback.
+                MessageResult::Stale
+            }
+            _ => {
+                tracing::warn!(
+                    ?message,
+                    "Got unexpected id path in `ErrorBoundary::message`."
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
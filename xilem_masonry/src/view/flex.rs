@@ -435,6 +435,13 @@ impl ElementSplice<FlexElement> for FlexSplice<'_, '_> {
         self.idx += n;
     }
 
+    fn move_from(&mut self, n: usize) -> bool {
+        for idx in (self.idx..self.idx + n).rev() {
+            widgets::Flex::swap(&mut self.element, idx, idx + 1);
+        }
+        true
+    }
+
     fn index(&self) -> usize {
         self.idx
     }
@@ -0,0 +1,267 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::{Widget, WidgetMut};
+use masonry::widgets;
+
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A container whose leading rows and columns stay in view while its body scrolls.
+///
+/// The `corner` is placed at the top left, the frozen `rows` above the `body` and the
+/// frozen `columns` left of it.
+/// The pane should be placed in a [`portal`](crate::view::portal) scrolling along both axes:
+/// when the portal scrolls, the rows and columns follow the body along one axis but stay
+/// at the edge of the portal along the other.
+///
+/// See [`masonry::widgets::FrozenPane`] for more details.
+///
+/// # Examples
+///
+/// ```ignore
+/// # use xilem_masonry as xilem;
+/// use xilem::view::{frozen_pane, label, portal};
+///
+/// portal(frozen_pane(
+///     label("Name"),
+///     header_row(&state.columns),
+///     name_column(&state.rows),
+///     table_body(&state.rows, &state.columns),
+/// ))
+/// ```
+pub fn frozen_pane<State, Action, Corner, Rows, Columns, Body>(
+    corner: Corner,
+    rows: Rows,
+    columns: Columns,
+    body: Body,
+) -> FrozenPane<Corner, Rows, Columns, Body, State, Action>
+where
+    State: ViewArgument,
+    Corner: WidgetView<State, Action>,
+    Rows: WidgetView<State, Action>,
+    Columns: WidgetView<State, Action>,
+    Body: WidgetView<State, Action>,
+{
+    FrozenPane {
+        corner,
+        rows,
+        columns,
+        body,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`frozen_pane`].
+///
+/// See `frozen_pane` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct FrozenPane<Corner, Rows, Columns, Body, State, Action = ()> {
+    corner: Corner,
+    rows: Rows,
+    columns: Columns,
+    body: Body,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+// Use a distinctive number here, to be able to catch bugs.
+// These were selected based on a random multiple (less than 1000) of 40960000.
+// That base is chosen so that there are at least three trailing zeroes in both the hex
+// and decimal forms, making the +1 obvious.
+
+/// This is a randomly generated ID - 21176320000 in decimal.
+const BODY_VIEW_ID: ViewId = ViewId::new(0x4ee350000);
+/// This is a randomly generated ID - 21176320001 in decimal.
+const ROWS_VIEW_ID: ViewId = ViewId::new(0x4ee350001);
+/// This is a randomly generated ID - 21176320002 in decimal.
+const COLUMNS_VIEW_ID: ViewId = ViewId::new(0x4ee350002);
+/// This is a randomly generated ID - 21176320003 in decimal.
+const CORNER_VIEW_ID: ViewId = ViewId::new(0x4ee350003);
+
+impl<Corner, Rows, Columns, Body, State, Action> ViewMarker
+    for FrozenPane<Corner, Rows, Columns, Body, State, Action>
+{
+}
+impl<Corner, Rows, Columns, Body, State, Action> View<State, Action, ViewCtx>
+    for FrozenPane<Corner, Rows, Columns, Body, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    Corner: WidgetView<State, Action>,
+    Rows: WidgetView<State, Action>,
+    Columns: WidgetView<State, Action>,
+    Body: WidgetView<State, Action>,
+{
+    type Element = Pod<widgets::FrozenPane>;
+
+    type ViewState = (
+        Body::ViewState,
+        Rows::ViewState,
+        Columns::ViewState,
+        Corner::ViewState,
+    );
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        mut app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (body, body_state) = ctx.with_id(BODY_VIEW_ID, |ctx| {
+            self.body.build(ctx, State::reborrow_mut(&mut app_state))
+        });
+        let (rows, rows_state) = ctx.with_id(ROWS_VIEW_ID, |ctx| {
+            self.rows.build(ctx, State::reborrow_mut(&mut app_state))
+        });
+        let (columns, columns_state) = ctx.with_id(COLUMNS_VIEW_ID, |ctx| {
+            self.columns.build(ctx, State::reborrow_mut(&mut app_state))
+        });
+        let (corner, corner_state) = ctx.with_id(CORNER_VIEW_ID, |ctx| {
+            self.corner.build(ctx, State::reborrow_mut(&mut app_state))
+        });
+
+        let widget_pod = ctx.create_pod(
+            widgets::FrozenPane::new(body.new_widget)
+                .with_frozen_rows(rows.new_widget)
+                .with_frozen_columns(columns.new_widget)
+                .with_corner(corner.new_widget),
+        );
+
+        (
+            widget_pod,
+            (body_state, rows_state, columns_state, corner_state),
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        mut app_state: Arg<'_, State>,
+    ) {
+        ctx.with_id(BODY_VIEW_ID, |ctx| {
+            self.body.rebuild(
+                &prev.body,
+                &mut view_state.0,
+                ctx,
+                widgets::FrozenPane::body_mut(&mut element).downcast(),
+                State::reborrow_mut(&mut app_state),
+            );
+        });
+        ctx.with_id(ROWS_VIEW_ID, |ctx| {
+            self.rows.rebuild(
+                &prev.rows,
+                &mut view_state.1,
+                ctx,
+                rows_mut(&mut element).downcast(),
+                State::reborrow_mut(&mut app_state),
+            );
+        });
+        ctx.with_id(COLUMNS_VIEW_ID, |ctx| {
+            self.columns.rebuild(
+                &prev.columns,
+                &mut view_state.2,
+                ctx,
+                columns_mut(&mut element).downcast(),
+                State::reborrow_mut(&mut app_state),
+            );
+        });
+        ctx.with_id(CORNER_VIEW_ID, |ctx| {
+            self.corner.rebuild(
+                &prev.corner,
+                &mut view_state.3,
+                ctx,
+                corner_mut(&mut element).downcast(),
+                State::reborrow_mut(&mut app_state),
+            );
+        });
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(BODY_VIEW_ID, |ctx| {
+            let mut body = widgets::FrozenPane::body_mut(&mut element);
+            self.body.teardown(&mut view_state.0, ctx, body.downcast());
+        });
+        ctx.with_id(ROWS_VIEW_ID, |ctx| {
+            let mut rows = rows_mut(&mut element);
+            self.rows.teardown(&mut view_state.1, ctx, rows.downcast());
+        });
+        ctx.with_id(COLUMNS_VIEW_ID, |ctx| {
+            let mut columns = columns_mut(&mut element);
+            self.columns
+                .teardown(&mut view_state.2, ctx, columns.downcast());
+        });
+        ctx.with_id(CORNER_VIEW_ID, |ctx| {
+            let mut corner = corner_mut(&mut element);
+            self.corner
+                .teardown(&mut view_state.3, ctx, corner.downcast());
+        });
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(BODY_VIEW_ID) => {
+                let mut body = widgets::FrozenPane::body_mut(&mut element);
+                self.body
+                    .message(&mut view_state.0, message, body.downcast(), app_state)
+            }
+            Some(ROWS_VIEW_ID) => {
+                let mut rows = rows_mut(&mut element);
+                self.rows
+                    .message(&mut view_state.1, message, rows.downcast(), app_state)
+            }
+            Some(COLUMNS_VIEW_ID) => {
+                let mut columns = columns_mut(&mut element);
+                self.columns
+                    .message(&mut view_state.2, message, columns.downcast(), app_state)
+            }
+            Some(CORNER_VIEW_ID) => {
+                let mut corner = corner_mut(&mut element);
+                self.corner
+                    .message(&mut view_state.3, message, corner.downcast(), app_state)
+            }
+            view_id => {
+                tracing::error!(
+                    ?message,
+                    "Invalid message arrived in FrozenPane::message, got {:?}. This is a bug.",
+                    view_id
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
+
+fn rows_mut<'t>(element: &'t mut WidgetMut<'_, widgets::FrozenPane>) -> WidgetMut<'t, dyn Widget> {
+    widgets::FrozenPane::frozen_rows_mut(element)
+        .expect("We only create FrozenPane with frozen rows")
+}
+
+fn columns_mut<'t>(
+    element: &'t mut WidgetMut<'_, widgets::FrozenPane>,
+) -> WidgetMut<'t, dyn Widget> {
+    widgets::FrozenPane::frozen_columns_mut(element)
+        .expect("We only create FrozenPane with frozen columns")
+}
+
+fn corner_mut<'t>(
+    element: &'t mut WidgetMut<'_, widgets::FrozenPane>,
+) -> WidgetMut<'t, dyn Widget> {
+    widgets::FrozenPane::corner_mut(element).expect("We only create FrozenPane with a corner")
+}
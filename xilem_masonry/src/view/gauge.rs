@@ -0,0 +1,263 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::f64::consts::PI;
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+
+use masonry::core::{NewWidget, Widget, WidgetMut};
+pub use masonry::widgets::GaugeBand;
+use masonry::widgets::{self, DEFAULT_GAUGE_ANGLES, DEFAULT_GAUGE_THICKNESS, Passthrough};
+
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{AnyWidgetView, Color, Pod, ViewCtx, WidgetView};
+
+type CenterLabel<State, Action> = Box<AnyWidgetView<State, Action>>;
+type LabelState<State, Action> =
+    <CenterLabel<State, Action> as View<State, Action, ViewCtx>>::ViewState;
+
+/// An arc showing `value` within `range`, such as a speedometer on a dashboard.
+///
+/// The arc moves smoothly when the value changes.
+/// See [`radial_progress`] for a ring showing the progress of a task, and the underlying
+/// [`Gauge`](widgets::Gauge) widget for more details.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// # use xilem::{WidgetView, masonry::peniko::Color, view::{gauge, label}};
+/// # use xilem::core::Edit;
+/// struct Server {
+///     load: f64,
+/// }
+///
+/// fn load(server: &mut Server) -> impl WidgetView<Edit<Server>> + use<> {
+///     gauge(server.load, 0.0..=100.)
+///         .band(80., Color::from_rgb8(0xdc, 0x26, 0x26))
+///         .needle(true)
+///         .label(label(format!("{:.0}%", server.load)))
+/// }
+/// ```
+pub fn gauge<State: ViewArgument, Action>(
+    value: f64,
+    range: RangeInclusive<f64>,
+) -> Gauge<State, Action> {
+    Gauge {
+        value,
+        range,
+        angles: DEFAULT_GAUGE_ANGLES,
+        thickness: DEFAULT_GAUGE_THICKNESS,
+        needle: false,
+        bands: Vec::new(),
+        label: None,
+        phantom: PhantomData,
+    }
+}
+
+/// A ring which fills clockwise from the top, showing `progress` from 0 to 1.
+///
+/// This is a [`gauge`] with a full circle, so it supports the same options.
+pub fn radial_progress<State: ViewArgument, Action>(progress: f64) -> Gauge<State, Action> {
+    gauge(progress, 0.0..=1.0).angles(-0.5 * PI, 2. * PI)
+}
+
+/// The [`View`] created by [`gauge`] or [`radial_progress`].
+///
+/// See `gauge` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Gauge<State: ViewArgument, Action> {
+    value: f64,
+    range: RangeInclusive<f64>,
+    angles: (f64, f64),
+    thickness: f64,
+    needle: bool,
+    bands: Vec<GaugeBand>,
+    label: Option<CenterLabel<State, Action>>,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<State: ViewArgument, Action> Gauge<State, Action> {
+    /// Set the angle the arc starts at, and the angle it sweeps over.
+    ///
+    /// Angles are in radians, clockwise from the right.
+    /// By default, the arc is open at the bottom.
+    pub fn angles(mut self, start_angle: f64, sweep_angle: f64) -> Self {
+        self.angles = (start_angle, sweep_angle);
+        self
+    }
+
+    /// Set the width of the arc, in logical pixels.
+    pub fn thickness(mut self, thickness: f64) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Set whether a needle points at the value.
+    pub fn needle(mut self, needle: bool) -> Self {
+        self.needle = needle;
+        self
+    }
+
+    /// Add a band of `color` starting at the value `from`, such as a warning zone.
+    ///
+    /// The band ends where the next one starts, or at the end of the range.
+    pub fn band(mut self, from: f64, color: Color) -> Self {
+        self.bands.push(GaugeBand { from, color });
+        self
+    }
+
+    /// Show a label in the center, such as the value as text.
+    pub fn label(mut self, label: impl WidgetView<State, Action>) -> Self
+    where
+        Action: 'static,
+    {
+        self.label = Some(label.boxed());
+        self
+    }
+}
+
+/// This is a randomly generated 32 bit number - 2735311393 in decimal.
+const LABEL_VIEW_ID: ViewId = ViewId::new(0xa30a8c21);
+
+impl<State: ViewArgument, Action> ViewMarker for Gauge<State, Action> {}
+impl<State, Action> View<State, Action, ViewCtx> for Gauge<State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Gauge>;
+    type ViewState = Option<LabelState<State, Action>>;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (start_angle, sweep_angle) = self.angles;
+        let mut widget = widgets::Gauge::new(self.value, self.range.clone())
+            .with_angles(start_angle, sweep_angle)
+            .with_thickness(self.thickness)
+            .with_needle(self.needle);
+        for band in &self.bands {
+            widget = widget.with_band(band.from, band.color);
+        }
+        let mut label_state = None;
+        if let Some(label) = &self.label {
+            let (label, state) = ctx.with_id(LABEL_VIEW_ID, |ctx| {
+                View::<State, Action, _>::build(label, ctx, app_state)
+            });
+            widget = widget.with_label(label.new_widget);
+            label_state = Some(state);
+        }
+        (ctx.create_pod(widget), label_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        label_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        if prev.range != self.range {
+            widgets::Gauge::set_range(&mut element, self.range.clone());
+        }
+        if prev.value != self.value || prev.range != self.range {
+            widgets::Gauge::set_value(&mut element, self.value);
+        }
+        if prev.angles != self.angles {
+            let (start_angle, sweep_angle) = self.angles;
+            widgets::Gauge::set_angles(&mut element, start_angle, sweep_angle);
+        }
+        if prev.thickness != self.thickness {
+            widgets::Gauge::set_thickness(&mut element, self.thickness);
+        }
+        if prev.needle != self.needle {
+            widgets::Gauge::set_needle(&mut element, self.needle);
+        }
+        if prev.bands != self.bands {
+            widgets::Gauge::set_bands(&mut element, self.bands.iter().copied());
+        }
+        ctx.with_id(LABEL_VIEW_ID, |ctx| {
+            match (&self.label, &prev.label, label_state.as_mut()) {
+                (Some(label), Some(prev), Some(state)) => {
+                    View::<State, Action, _>::rebuild(
+                        label,
+                        prev,
+                        state,
+                        ctx,
+                        label_mut(&mut element).downcast(),
+                        app_state,
+                    );
+                }
+                (Some(label), _, _) => {
+                    let (label, state) = View::<State, Action, _>::build(label, ctx, app_state);
+                    widgets::Gauge::set_label(&mut element, Some(label.new_widget));
+                    *label_state = Some(state);
+                }
+                (None, Some(prev), Some(state)) => {
+                    View::<State, Action, _>::teardown(
+                        prev,
+                        state,
+                        ctx,
+                        label_mut(&mut element).downcast(),
+                    );
+                    widgets::Gauge::set_label(&mut element, None::<NewWidget<Passthrough>>);
+                    *label_state = None;
+                }
+                (None, _, _) => {}
+            }
+        });
+    }
+
+    fn teardown(
+        &self,
+        label_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        if let (Some(label), Some(state)) = (&self.label, label_state.as_mut()) {
+            ctx.with_id(LABEL_VIEW_ID, |ctx| {
+                let mut label_element = label_mut(&mut element);
+                View::<State, Action, _>::teardown(label, state, ctx, label_element.downcast());
+            });
+        }
+    }
+
+    fn message(
+        &self,
+        label_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match (message.take_first(), &self.label, label_state.as_mut()) {
+            (Some(LABEL_VIEW_ID), Some(label), Some(state)) => label.message(
+                state,
+                message,
+                label_mut(&mut element).downcast(),
+                app_state,
+            ),
+            (Some(LABEL_VIEW_ID), _, _) => {
+                tracing::warn!("Got message for a removed label in Gauge::message");
+                MessageResult::Stale
+            }
+            (view_id, _, _) => {
+                tracing::error!(
+                    ?message,
+                    "Invalid message arrived in Gauge::message, got {:?}. This is a bug.",
+                    view_id
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
+
+fn label_mut<'t>(element: &'t mut WidgetMut<'_, widgets::Gauge>) -> WidgetMut<'t, dyn Widget> {
+    widgets::Gauge::label_mut(element).expect("The gauge has a label for the previous view")
+}
@@ -0,0 +1,553 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::type_name;
+use std::marker::PhantomData;
+
+use masonry::core::{CollectionWidget, FromDynWidget, Widget, WidgetMut};
+use masonry::kurbo::Vec2;
+use masonry::widgets;
+
+use crate::core::{
+    AppendVec, Arg, ElementSplice, MessageCtx, MessageResult, Mut, SuperElement, View,
+    ViewArgument, ViewElement, ViewId, ViewMarker, ViewPathTracker, ViewSequence,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+pub use masonry::widgets::{GraphAction, GraphEdge, GraphNodeParams, PortId};
+
+type Callback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, GraphAction) -> Action + Send + Sync + 'static>;
+
+/// A canvas of nodes connected by edges, for building node-based editors.
+///
+/// Each view in the sequence is a node, positioned with [`GraphCanvasExt::graph_node`].
+/// The canvas lets users drag nodes around, connect ports, select, pan and zoom.
+/// Other edits, such as connecting and deleting, are passed to the
+/// [`on_action`](GraphCanvas::on_action) callback, which should update the app's graph.
+///
+/// Nodes moved by the user stay where they were dropped, but are moved back to the position
+/// in their parameters if these change, so the app should store the positions from
+/// [`GraphAction::NodesMoved`].
+///
+/// See the documentation on the underlying [`GraphCanvas`](widgets::GraphCanvas) for more information.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::core::Edit;
+/// use xilem::masonry::kurbo::Point;
+/// use xilem::view::{
+///     GraphAction, GraphCanvasExt, GraphEdge, GraphNodeParams, graph_canvas, label,
+/// };
+///
+/// struct State {
+///     positions: Vec<Point>,
+///     edges: Vec<GraphEdge>,
+/// }
+///
+/// let state = State {
+///     positions: vec![Point::new(20., 20.), Point::new(200., 60.)],
+///     edges: vec![],
+/// };
+///
+/// graph_canvas::<Edit<State>, _, _>((
+///     label("Source").graph_node(GraphNodeParams::new(state.positions[0], 0, 1)),
+///     label("Sink").graph_node(GraphNodeParams::new(state.positions[1], 1, 0)),
+/// ))
+/// .edges(state.edges.clone())
+/// .on_action(|state: &mut State, action| match action {
+///     GraphAction::Connect(edge) => state.edges.push(edge),
+///     GraphAction::NodesMoved(moved) => {
+///         for (node, position) in moved {
+///             state.positions[node] = position;
+///         }
+///     }
+///     _ => {}
+/// });
+/// ```
+pub fn graph_canvas<State: ViewArgument, Action, Seq: GraphCanvasSequence<State, Action>>(
+    sequence: Seq,
+) -> GraphCanvas<Seq, State, Action> {
+    GraphCanvas {
+        sequence,
+        edges: Vec::new(),
+        viewport: None,
+        on_action: None,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`graph_canvas`] from a sequence.
+///
+/// See `graph_canvas` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct GraphCanvas<Seq, State: ViewArgument, Action = ()> {
+    sequence: Seq,
+    edges: Vec<GraphEdge>,
+    viewport: Option<(Vec2, f64)>,
+    on_action: Option<Callback<State, Action>>,
+
+    /// Used to associate the State and Action in the call to `.graph_canvas()` with the State
+    /// and Action used in the View implementation, to allow inference to flow backwards,
+    /// allowing State and Action to be inferred properly.
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<Seq, State: ViewArgument, Action> GraphCanvas<Seq, State, Action> {
+    /// Sets the edges between the nodes' ports.
+    ///
+    /// Nodes are referred to by their index in the sequence.
+    pub fn edges(mut self, edges: Vec<GraphEdge>) -> Self {
+        self.edges = edges;
+        self
+    }
+
+    /// Sets the pan offset and zoom level of the canvas.
+    ///
+    /// When this changes, the canvas jumps to the new viewport.
+    /// Users can still pan and zoom afterwards, so this is mostly useful
+    /// for an initial viewport or a "reset view" button.
+    pub fn viewport(mut self, pan: Vec2, zoom: f64) -> Self {
+        self.viewport = Some((pan, zoom));
+        self
+    }
+
+    /// Sets a callback which is called with each edit requested by the user.
+    pub fn on_action<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Arg<'_, State>, GraphAction) -> Action + Send + Sync + 'static,
+    {
+        self.on_action = Some(Box::new(callback));
+        self
+    }
+}
+
+mod hidden {
+    use super::GraphCanvasElement;
+    use crate::core::AppendVec;
+
+    #[doc(hidden)]
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub struct GraphCanvasState<SeqState> {
+        pub(crate) seq_state: SeqState,
+        pub(crate) scratch: AppendVec<GraphCanvasElement>,
+    }
+}
+
+use hidden::GraphCanvasState;
+
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 1538237343 in decimal.
+const GRAPH_NODES_VIEW_ID: ViewId = ViewId::new(0x5baf2a9f);
+
+impl<Seq, State: ViewArgument, Action> ViewMarker for GraphCanvas<Seq, State, Action> {}
+
+impl<State, Action, Seq> View<State, Action, ViewCtx> for GraphCanvas<Seq, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    Seq: GraphCanvasSequence<State, Action>,
+{
+    type Element = Pod<widgets::GraphCanvas>;
+
+    type ViewState = GraphCanvasState<Seq::SeqState>;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let mut elements = AppendVec::default();
+        let mut widget = widgets::GraphCanvas::new();
+        let seq_state = ctx.with_id(GRAPH_NODES_VIEW_ID, |ctx| {
+            self.sequence.seq_build(ctx, &mut elements, app_state)
+        });
+        for element in elements.drain() {
+            widget = widget.with(element.child.new_widget, element.params);
+        }
+        for edge in &self.edges {
+            widget = widget.with_edge(*edge);
+        }
+        if let Some((pan, zoom)) = self.viewport {
+            widget = widget.with_viewport(pan, zoom);
+        }
+        let pod = ctx.with_action_widget(|ctx| ctx.create_pod(widget));
+        (
+            pod,
+            GraphCanvasState {
+                seq_state,
+                scratch: elements,
+            },
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        GraphCanvasState { seq_state, scratch }: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        ctx.with_id(GRAPH_NODES_VIEW_ID, |ctx| {
+            let mut splice = GraphCanvasSplice::new(element.reborrow_mut(), scratch);
+            self.sequence
+                .seq_rebuild(&prev.sequence, seq_state, ctx, &mut splice, app_state);
+            debug_assert!(scratch.is_empty());
+        });
+
+        // Set the edges after updating the sequence. We compare with the widget's edges,
+        // as removing nodes also removes the edges connected to them.
+        if self.edges != element.widget.edges() {
+            widgets::GraphCanvas::set_edges(&mut element, self.edges.clone());
+        }
+        if self.viewport != prev.viewport
+            && let Some((pan, zoom)) = self.viewport
+        {
+            widgets::GraphCanvas::set_viewport(&mut element, pan, zoom);
+        }
+    }
+
+    fn teardown(
+        &self,
+        GraphCanvasState { seq_state, scratch }: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(GRAPH_NODES_VIEW_ID, |ctx| {
+            let mut splice = GraphCanvasSplice::new(element.reborrow_mut(), scratch);
+            self.sequence.seq_teardown(seq_state, ctx, &mut splice);
+            debug_assert!(scratch.is_empty());
+        });
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        GraphCanvasState { seq_state, scratch }: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(GRAPH_NODES_VIEW_ID) => {
+                let mut splice = GraphCanvasSplice::new(element, scratch);
+                let result = self
+                    .sequence
+                    .seq_message(seq_state, message, &mut splice, app_state);
+                debug_assert!(scratch.is_empty());
+                result
+            }
+            None => match message.take_message::<GraphAction>() {
+                Some(action) => match &self.on_action {
+                    Some(callback) => MessageResult::Action(callback(app_state, *action)),
+                    None => MessageResult::Nop,
+                },
+                None => {
+                    tracing::error!(
+                        "Wrong message type in GraphCanvas::message: {message:?} expected {}",
+                        type_name::<GraphAction>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!(
+                    ?message,
+                    "Got unexpected id path in `GraphCanvas::message`."
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
+
+// Used to become a reference form for editing. It's provided to rebuild and teardown.
+impl ViewElement for GraphCanvasElement {
+    type Mut<'w> = GraphCanvasElementMut<'w>;
+}
+
+// Used to allow the item to be used as a generic item in ViewSequence.
+impl SuperElement<Self, ViewCtx> for GraphCanvasElement {
+    fn upcast(_ctx: &mut ViewCtx, child: Self) -> Self {
+        child
+    }
+
+    fn with_downcast_val<R>(
+        mut this: Mut<'_, Self>,
+        f: impl FnOnce(Mut<'_, Self>) -> R,
+    ) -> (Self::Mut<'_>, R) {
+        let r = {
+            let parent = this.parent.reborrow_mut();
+            let reborrow = GraphCanvasElementMut {
+                idx: this.idx,
+                parent,
+            };
+            f(reborrow)
+        };
+        (this, r)
+    }
+}
+
+impl<W: Widget + FromDynWidget + ?Sized> SuperElement<Pod<W>, ViewCtx> for GraphCanvasElement {
+    fn upcast(_: &mut ViewCtx, child: Pod<W>) -> Self {
+        // Getting here means that the widget didn't use .graph_node.
+        // This places the node at the origin, without any ports.
+        Self {
+            child: child.erased(),
+            params: GraphNodeParams::default(),
+        }
+    }
+
+    fn with_downcast_val<R>(
+        mut this: Mut<'_, Self>,
+        f: impl FnOnce(Mut<'_, Pod<W>>) -> R,
+    ) -> (Mut<'_, Self>, R) {
+        let ret = {
+            let mut child = widgets::GraphCanvas::get_mut(&mut this.parent, this.idx);
+            let downcast = child.downcast();
+            f(downcast)
+        };
+
+        (this, ret)
+    }
+}
+
+// Used for building and rebuilding the ViewSequence
+impl ElementSplice<GraphCanvasElement> for GraphCanvasSplice<'_, '_> {
+    fn with_scratch<R>(&mut self, f: impl FnOnce(&mut AppendVec<GraphCanvasElement>) -> R) -> R {
+        let ret = f(self.scratch);
+        for element in self.scratch.drain() {
+            widgets::GraphCanvas::insert(
+                &mut self.element,
+                self.idx,
+                element.child.new_widget,
+                element.params,
+            );
+            self.idx += 1;
+        }
+        ret
+    }
+
+    fn insert(&mut self, element: GraphCanvasElement) {
+        widgets::GraphCanvas::insert(
+            &mut self.element,
+            self.idx,
+            element.child.new_widget,
+            element.params,
+        );
+        self.idx += 1;
+    }
+
+    fn mutate<R>(&mut self, f: impl FnOnce(Mut<'_, GraphCanvasElement>) -> R) -> R {
+        let child = GraphCanvasElementMut {
+            parent: self.element.reborrow_mut(),
+            idx: self.idx,
+        };
+        let ret = f(child);
+        self.idx += 1;
+        ret
+    }
+
+    fn skip(&mut self, n: usize) {
+        self.idx += n;
+    }
+
+    fn move_from(&mut self, n: usize) -> bool {
+        for idx in (self.idx..self.idx + n).rev() {
+            widgets::GraphCanvas::swap(&mut self.element, idx, idx + 1);
+        }
+        true
+    }
+
+    fn index(&self) -> usize {
+        self.idx
+    }
+
+    fn delete<R>(&mut self, f: impl FnOnce(Mut<'_, GraphCanvasElement>) -> R) -> R {
+        let ret = {
+            let child = GraphCanvasElementMut {
+                parent: self.element.reborrow_mut(),
+                idx: self.idx,
+            };
+            f(child)
+        };
+        widgets::GraphCanvas::remove(&mut self.element, self.idx);
+        ret
+    }
+}
+
+/// `GraphCanvasSequence` is what allows an input to the graph canvas that contains all the nodes.
+pub trait GraphCanvasSequence<State: ViewArgument, Action = ()>:
+    ViewSequence<State, Action, ViewCtx, GraphCanvasElement>
+{
+}
+
+impl<Seq, State, Action> GraphCanvasSequence<State, Action> for Seq
+where
+    Seq: ViewSequence<State, Action, ViewCtx, GraphCanvasElement>,
+    State: ViewArgument,
+{
+}
+
+/// A trait which extends a [`WidgetView`] with methods to provide parameters for a graph node.
+pub trait GraphCanvasExt<State: ViewArgument, Action>: WidgetView<State, Action> {
+    /// Applies [`GraphNodeParams`] to this view, to use it as a node in a [`GraphCanvas`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// use xilem::masonry::kurbo::Point;
+    /// use xilem::view::{GraphCanvasExt, GraphNodeParams, graph_canvas, label};
+    /// # use xilem::WidgetView;
+    /// # use xilem::core::Edit;
+    ///
+    /// # fn view<State: 'static>() -> impl WidgetView<Edit<State>> {
+    /// graph_canvas((
+    ///     label("Add").graph_node(GraphNodeParams::new(Point::new(40., 40.), 2, 1)),
+    ///     label("Note").graph_node(Point::new(40., 160.)),
+    /// ))
+    /// # }
+    /// ```
+    fn graph_node(self, params: impl Into<GraphNodeParams>) -> GraphNodeItem<Self, State, Action>
+    where
+        State: 'static,
+        Action: 'static,
+        Self: Sized,
+    {
+        graph_node(self, params)
+    }
+}
+
+impl<State: ViewArgument, Action, V: WidgetView<State, Action>> GraphCanvasExt<State, Action>
+    for V
+{
+}
+
+/// A child widget within a [`GraphCanvas`] view.
+pub struct GraphCanvasElement {
+    /// The child widget.
+    child: Pod<dyn Widget>,
+    /// The node parameters of the child widget.
+    params: GraphNodeParams,
+}
+
+/// A mutable reference to a [`GraphCanvasElement`], used internally by Xilem traits.
+pub struct GraphCanvasElementMut<'w> {
+    parent: WidgetMut<'w, widgets::GraphCanvas>,
+    idx: usize,
+}
+
+// Used for manipulating the ViewSequence.
+struct GraphCanvasSplice<'w, 's> {
+    idx: usize,
+    element: WidgetMut<'w, widgets::GraphCanvas>,
+    scratch: &'s mut AppendVec<GraphCanvasElement>,
+}
+
+impl<'w, 's> GraphCanvasSplice<'w, 's> {
+    fn new(
+        element: WidgetMut<'w, widgets::GraphCanvas>,
+        scratch: &'s mut AppendVec<GraphCanvasElement>,
+    ) -> Self {
+        Self {
+            idx: 0,
+            element,
+            scratch,
+        }
+    }
+}
+
+/// A `WidgetView` that can be used as a node within a [`GraphCanvas`] [`View`].
+pub struct GraphNodeItem<V, State, Action> {
+    view: V,
+    params: GraphNodeParams,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+/// Creates a [`GraphNodeItem`] from a view and [`GraphNodeParams`].
+pub fn graph_node<V, State, Action>(
+    view: V,
+    params: impl Into<GraphNodeParams>,
+) -> GraphNodeItem<V, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    GraphNodeItem {
+        view,
+        params: params.into(),
+        phantom: PhantomData,
+    }
+}
+
+impl<V, State, Action> ViewMarker for GraphNodeItem<V, State, Action> {}
+
+impl<State, Action, V> View<State, Action, ViewCtx> for GraphNodeItem<V, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    type Element = GraphCanvasElement;
+
+    type ViewState = V::ViewState;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (pod, state) = self.view.build(ctx, app_state);
+        (
+            GraphCanvasElement {
+                child: pod.erased(),
+                params: self.params,
+            },
+            state,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        if self.params != prev.params {
+            widgets::GraphCanvas::set_params(&mut element.parent, element.idx, self.params);
+        }
+        let mut child = widgets::GraphCanvas::get_mut(&mut element.parent, element.idx);
+        self.view
+            .rebuild(&prev.view, view_state, ctx, child.downcast(), app_state);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        let mut child = widgets::GraphCanvas::get_mut(&mut element.parent, element.idx);
+        self.view.teardown(view_state, ctx, child.downcast());
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        let mut child = widgets::GraphCanvas::get_mut(&mut element.parent, element.idx);
+        self.view
+            .message(view_state, message, child.downcast(), app_state)
+    }
+}
@@ -267,6 +267,13 @@ impl ElementSplice<GridElement> for GridSplice<'_, '_> {
         self.idx += n;
     }
 
+    fn move_from(&mut self, n: usize) -> bool {
+        for idx in (self.idx..self.idx + n).rev() {
+            widgets::Grid::swap(&mut self.element, idx, idx + 1);
+        }
+        true
+    }
+
     fn index(&self) -> usize {
         self.idx
     }
@@ -0,0 +1,191 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::RangeInclusive;
+
+pub use masonry::widgets::ColorScale;
+use masonry::widgets::{self, DEFAULT_HEATMAP_CELL_SIZE, DEFAULT_HEATMAP_GAP, HeatmapCellClicked};
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+type Callback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, usize, usize) -> Action + Send + Sync + 'static>;
+
+/// A grid of small cells colored by `values`, given by row, such as a contribution graph.
+///
+/// Resting the pointer on a cell shows its value in a tooltip.
+/// Missing or NaN values leave their cells empty.
+/// See the underlying [`Heatmap`](widgets::Heatmap) widget for more details.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// # use xilem::{WidgetView, masonry::peniko::Color, view::{ColorScale, heatmap}};
+/// # use xilem::core::Edit;
+/// struct Activity {
+///     /// The number of commits of each day, by weekday and week.
+///     commits: Vec<Vec<f64>>,
+///     day: Option<(usize, usize)>,
+/// }
+///
+/// fn graph(activity: &mut Activity) -> impl WidgetView<Edit<Activity>> + use<> {
+///     heatmap(activity.commits.clone())
+///         .scale(ColorScale::steps([
+///             Color::from_rgb8(0x16, 0x1b, 0x22),
+///             Color::from_rgb8(0x0e, 0x44, 0x29),
+///             Color::from_rgb8(0x00, 0x6d, 0x32),
+///             Color::from_rgb8(0x26, 0xa6, 0x41),
+///             Color::from_rgb8(0x39, 0xd3, 0x53),
+///         ]))
+///         .on_cell_click(|activity: &mut Activity, weekday, week| {
+///             activity.day = Some((weekday, week));
+///         })
+/// }
+/// ```
+pub fn heatmap<State: ViewArgument, Action>(values: Vec<Vec<f64>>) -> Heatmap<State, Action> {
+    Heatmap {
+        values,
+        range: None,
+        scale: ColorScale::default(),
+        cell_size: DEFAULT_HEATMAP_CELL_SIZE,
+        gap: DEFAULT_HEATMAP_GAP,
+        on_cell_click: None,
+    }
+}
+
+/// The [`View`] created by [`heatmap`].
+///
+/// See `heatmap` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Heatmap<State: ViewArgument, Action = ()> {
+    values: Vec<Vec<f64>>,
+    range: Option<RangeInclusive<f64>>,
+    scale: ColorScale,
+    cell_size: f64,
+    gap: f64,
+    on_cell_click: Option<Callback<State, Action>>,
+}
+
+impl<State: ViewArgument, Action> Heatmap<State, Action> {
+    /// Spread the colors of the scale over `range` instead of the range of the values,
+    /// such as to compare several heatmaps.
+    pub fn range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Set the colors of the values, from the lowest to the highest.
+    pub fn scale(mut self, scale: ColorScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set the width and height of the cells, in logical pixels.
+    pub fn cell_size(mut self, cell_size: f64) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Set the space between cells, in logical pixels.
+    pub fn gap(mut self, gap: f64) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the callback called with the row and column of a cell with a value when the
+    /// user clicks it.
+    pub fn on_cell_click<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Arg<'_, State>, usize, usize) -> Action + Send + Sync + 'static,
+    {
+        self.on_cell_click = Some(Box::new(callback));
+        self
+    }
+}
+
+impl<State: ViewArgument, Action> ViewMarker for Heatmap<State, Action> {}
+impl<State, Action> View<State, Action, ViewCtx> for Heatmap<State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Heatmap>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let element = ctx.with_action_widget(|ctx| {
+            let mut widget = widgets::Heatmap::new(self.values.clone())
+                .with_scale(self.scale.clone())
+                .with_cell_size(self.cell_size)
+                .with_gap(self.gap);
+            if let Some(range) = &self.range {
+                widget = widget.with_range(range.clone());
+            }
+            ctx.create_pod(widget)
+        });
+        (element, ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        if prev.values != self.values {
+            widgets::Heatmap::set_values(&mut element, self.values.clone());
+        }
+        if prev.range != self.range {
+            widgets::Heatmap::set_range(&mut element, self.range.clone());
+        }
+        if prev.scale != self.scale {
+            widgets::Heatmap::set_scale(&mut element, self.scale.clone());
+        }
+        if prev.cell_size != self.cell_size {
+            widgets::Heatmap::set_cell_size(&mut element, self.cell_size);
+        }
+        if prev.gap != self.gap {
+            widgets::Heatmap::set_gap(&mut element, self.gap);
+        }
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        if message.take_first().is_some() {
+            tracing::warn!("Got unexpected id path in Heatmap::message");
+            return MessageResult::Stale;
+        }
+        match message.take_message::<HeatmapCellClicked>() {
+            Some(clicked) => match &self.on_cell_click {
+                Some(callback) => {
+                    MessageResult::Action(callback(app_state, clicked.row, clicked.column))
+                }
+                None => MessageResult::Nop,
+            },
+            None => {
+                tracing::error!(
+                    "Wrong message type in Heatmap::message: {message:?}, expected HeatmapCellClicked"
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
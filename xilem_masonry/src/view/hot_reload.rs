@@ -0,0 +1,309 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use serde::de::DeserializeOwned;
+use tokio::task::JoinHandle;
+
+use crate::core::{
+    Arg, MessageCtx, MessageProxy, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker,
+    ViewPathTracker,
+};
+use crate::{ViewCtx, WidgetView};
+
+/// How often the file is checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 3297710686 in decimal.
+const HOT_RELOAD_CHILD_VIEW_ID: ViewId = ViewId::new(0xc48f5c5e);
+
+/// Why the file of a [`hot_reload`] view couldn't be loaded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReloadError {
+    message: String,
+}
+
+impl ReloadError {
+    fn new(path: &Path, error: impl Display) -> Self {
+        Self {
+            message: format!("{}: {error}", path.display()),
+        }
+    }
+}
+
+impl Display for ReloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ReloadError {}
+
+/// A view whose content is described by a JSON file, which is reloaded when the file changes.
+///
+/// This is a development tool: the parts of the UI which designers iterate on (such as
+/// texts, colors, spacing or whole layouts) can be moved into a file, which is deserialized
+/// as a `T` and given to `view_fn` to create the child view.
+/// When the file is saved, the child view is rebuilt with the new description, and the app
+/// state is preserved, without restarting the app.
+///
+/// If the file can't be read or parsed, `view_fn` is called with the error on startup,
+/// and the last valid description is kept if the file is broken later on.
+///
+/// The file is checked for changes a few times per second.
+/// Apps usually only use this view in debug builds, and include the description in
+/// release builds, e.g. with [`include_str!`].
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::view::{flex_col, hot_reload, label};
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Texts {
+///     title: String,
+///     subtitle: String,
+/// }
+///
+/// struct State {
+///     name: String,
+/// }
+///
+/// # fn view() -> impl WidgetView<Edit<State>> {
+/// hot_reload("ui/texts.json", |state: &mut State, texts: Result<&Texts, _>| match texts {
+///     Ok(texts) => flex_col((
+///         label(texts.title.replace("{name}", &state.name)),
+///         label(texts.subtitle.clone()),
+///     ))
+///     .boxed(),
+///     Err(err) => label(format!("Invalid UI description: {err}")).boxed(),
+/// })
+/// # }
+/// ```
+pub fn hot_reload<State, Action, T, V, F>(
+    path: impl Into<PathBuf>,
+    view_fn: F,
+) -> HotReload<T, F, State, Action>
+where
+    State: ViewArgument,
+    T: DeserializeOwned + Send + 'static,
+    F: Fn(Arg<'_, State>, Result<&T, &ReloadError>) -> V + 'static,
+    V: WidgetView<State, Action>,
+{
+    HotReload {
+        path: path.into(),
+        view_fn,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`hot_reload`].
+///
+/// See its documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct HotReload<T, F, State, Action> {
+    path: PathBuf,
+    view_fn: F,
+    phantom: PhantomData<fn(State) -> (T, Action)>,
+}
+
+/// The message sent by the file watcher.
+struct Reloaded<T>(Result<T, ReloadError>);
+
+impl<T> Debug for Reloaded<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Ok(_) => f.write_str("Reloaded(Ok(..))"),
+            Err(err) => f.debug_tuple("Reloaded").field(err).finish(),
+        }
+    }
+}
+
+mod hidden {
+    use tokio::task::JoinHandle;
+
+    use super::ReloadError;
+
+    #[doc(hidden)]
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub struct HotReloadState<T, V, ViewState> {
+        pub(crate) description: Result<T, ReloadError>,
+        pub(crate) view: V,
+        pub(crate) view_state: ViewState,
+        pub(crate) watcher: JoinHandle<()>,
+    }
+}
+
+use hidden::HotReloadState;
+
+/// Reads and parses the file, returning its modification time.
+fn load<T: DeserializeOwned>(path: &Path) -> (Result<T, ReloadError>, Option<SystemTime>) {
+    let modified = std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok();
+    let description = std::fs::read_to_string(path)
+        .map_err(|err| ReloadError::new(path, err))
+        .and_then(|data| serde_json::from_str(&data).map_err(|err| ReloadError::new(path, err)));
+    (description, modified)
+}
+
+/// Spawns a task which sends [`Reloaded`] to the current view when the file changes.
+fn watch<T: DeserializeOwned + Send + 'static>(
+    ctx: &mut ViewCtx,
+    file_path: PathBuf,
+    mut last_modified: Option<SystemTime>,
+) -> JoinHandle<()> {
+    let path: Arc<[ViewId]> = ctx.view_path().into();
+    let proxy = MessageProxy::<Reloaded<T>>::new(ctx.proxy(), path);
+    ctx.runtime().spawn(async move {
+        let mut interval = tokio::time::interval(POLL_INTERVAL);
+        loop {
+            interval.tick().await;
+            let modified = std::fs::metadata(&file_path)
+                .and_then(|metadata| metadata.modified())
+                .ok();
+            if modified == last_modified {
+                continue;
+            }
+            let (description, modified) = load::<T>(&file_path);
+            last_modified = modified;
+            if proxy.message(Reloaded(description)).is_err() {
+                break;
+            }
+        }
+    })
+}
+
+impl<T, F, State, Action> ViewMarker for HotReload<T, F, State, Action> {}
+impl<T, F, State, Action, V> View<State, Action, ViewCtx> for HotReload<T, F, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    T: DeserializeOwned + Send + 'static,
+    F: Fn(Arg<'_, State>, Result<&T, &ReloadError>) -> V + 'static,
+    V: WidgetView<State, Action>,
+{
+    type Element = V::Element;
+    type ViewState = HotReloadState<T, V, V::ViewState>;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        mut app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (description, last_modified) = load::<T>(&self.path);
+        if let Err(err) = &description {
+            tracing::warn!("Failed to load UI description: {err}");
+        }
+
+        let watcher = watch::<T>(ctx, self.path.clone(), last_modified);
+
+        let view = (self.view_fn)(State::reborrow_mut(&mut app_state), description.as_ref());
+        let (element, view_state) =
+            ctx.with_id(HOT_RELOAD_CHILD_VIEW_ID, |ctx| view.build(ctx, app_state));
+        (
+            element,
+            HotReloadState {
+                description,
+                view,
+                view_state,
+                watcher,
+            },
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+        mut app_state: Arg<'_, State>,
+    ) {
+        if self.path != prev.path {
+            view_state.watcher.abort();
+            let (description, last_modified) = load::<T>(&self.path);
+            view_state.description = description;
+            view_state.watcher = watch::<T>(ctx, self.path.clone(), last_modified);
+        }
+        let view = (self.view_fn)(
+            State::reborrow_mut(&mut app_state),
+            view_state.description.as_ref(),
+        );
+        ctx.with_id(HOT_RELOAD_CHILD_VIEW_ID, |ctx| {
+            view.rebuild(
+                &view_state.view,
+                &mut view_state.view_state,
+                ctx,
+                element,
+                app_state,
+            );
+        });
+        view_state.view = view;
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        view_state.watcher.abort();
+        ctx.with_id(HOT_RELOAD_CHILD_VIEW_ID, |ctx| {
+            view_state
+                .view
+                .teardown(&mut view_state.view_state, ctx, element);
+        });
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(HOT_RELOAD_CHILD_VIEW_ID) => {
+                view_state
+                    .view
+                    .message(&mut view_state.view_state, message, element, app_state)
+            }
+            None => {
+                let Some(reloaded) = message.take_message::<Reloaded<T>>() else {
+                    tracing::error!(?message, "Wrong message type in HotReload::message");
+                    return MessageResult::Stale;
+                };
+                match reloaded.0 {
+                    Ok(description) => {
+                        tracing::info!("Reloaded {}", self.path.display());
+                        view_state.description = Ok(description);
+                    }
+                    // Keep showing the last valid description while the file is being edited.
+                    Err(err) if view_state.description.is_ok() => {
+                        tracing::warn!("Failed to reload UI description: {err}");
+                        return MessageResult::Nop;
+                    }
+                    Err(err) => view_state.description = Err(err),
+                }
+                MessageResult::RequestRebuild
+            }
+            _ => {
+                tracing::warn!(?message, "Got unexpected id path in `HotReload::message`.");
+                MessageResult::Stale
+            }
+        }
+    }
+}
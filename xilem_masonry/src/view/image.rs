@@ -3,8 +3,12 @@
 
 //! The bitmap image widget.
 
+use std::sync::Arc;
+
 use masonry::core::ArcStr;
-use masonry::widgets;
+use masonry::widgets::{
+    self, AnimationDecoder, AnimationFrame, FrameDecoder, ImageCache, ImageDecodeError, ImageSource,
+};
 use vello::peniko::ImageBrush;
 
 use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
@@ -25,22 +29,169 @@ pub use masonry::properties::ObjectFit;
 /// for discussion.
 pub fn image(image: impl Into<ImageBrush>) -> Image {
     Image {
-        image: image.into(),
+        content: ImageContent::Brush(image.into()),
+        playing: true,
+        decorative: false,
+        alt_text: None,
+    }
+}
+
+/// Displays the image loaded from `source` through `cache`.
+///
+/// The image is decoded on the cache's threads when it first becomes visible, such as when it is
+/// scrolled into view in a [`portal`](crate::view::portal), and is down-sampled to its displayed size.
+/// Until then, nothing is drawn and its preferred size is zero, so giving it a fixed size avoids
+/// layout shifts.
+///
+/// The cache is usually created once, with an [`ImageDecoder`](widgets::ImageDecoder), and stored in
+/// the app's state to share decoded images between views.
+/// See [`ImageCache`] for more details.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// use xilem::masonry::peniko::ImageData;
+/// use xilem::masonry::widgets::{ImageCache, ImageDecodeError, ImageDecoder, ImageSource};
+/// use xilem::view::{flex_col, image_source, portal};
+/// use xilem::{WidgetView, core::Edit};
+///
+/// struct MyDecoder;
+///
+/// impl ImageDecoder for MyDecoder {
+///     fn decode(&self, bytes: &[u8]) -> Result<ImageData, ImageDecodeError> {
+///         // Decode with the codec of your choice.
+///         # unimplemented!()
+///     }
+/// }
+///
+/// struct State {
+///     cache: ImageCache,
+///     photos: Vec<std::path::PathBuf>,
+/// }
+///
+/// fn gallery(state: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+///     portal(flex_col(
+///         state
+///             .photos
+///             .iter()
+///             .map(|path| image_source(&state.cache, ImageSource::file(path)))
+///             .collect::<Vec<_>>(),
+///     ))
+/// }
+///
+/// let state = State {
+///     cache: ImageCache::new(MyDecoder),
+///     photos: vec!["cat.png".into(), "dog.png".into()],
+/// };
+/// ```
+pub fn image_source(cache: &ImageCache, source: ImageSource) -> Image {
+    Image {
+        content: ImageContent::Source(cache.clone(), source),
+        playing: true,
+        decorative: false,
+        alt_text: None,
+    }
+}
+
+/// Plays the animated image, such as a GIF or an animated WebP, loaded from `source`.
+///
+/// `decoder` opens the animation, whose frames are then decoded as they are first shown.
+/// The animation loops, and is paused while it isn't visible or when the user prefers
+/// reduced motion.
+/// It can be paused with [`playing`](Image::playing).
+///
+/// The animation is restarted when `source` changes.
+/// As sources are compared by key, changing only `decoder` doesn't restart it.
+///
+/// Files are read when the view is built.
+///
+/// Corresponds to [`Image::animated`](widgets::Image::animated).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// use std::sync::Arc;
+///
+/// use xilem::masonry::widgets::{AnimationDecoder, FrameDecoder, ImageDecodeError, ImageSource};
+/// use xilem::view::animated_image;
+/// use xilem::{WidgetView, core::Edit};
+///
+/// struct MyGifDecoder;
+///
+/// impl AnimationDecoder for MyGifDecoder {
+///     fn open(&self, bytes: Arc<[u8]>) -> Result<Box<dyn FrameDecoder>, ImageDecodeError> {
+///         // Decode with the codec of your choice.
+///         # unimplemented!()
+///     }
+/// }
+///
+/// struct State {
+///     is_playing: bool,
+/// }
+///
+/// fn spinner(state: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+///     animated_image(MyGifDecoder, ImageSource::file("spinner.gif")).playing(state.is_playing)
+/// }
+/// ```
+pub fn animated_image(decoder: impl AnimationDecoder, source: ImageSource) -> Image {
+    Image {
+        content: ImageContent::Animated(Arc::new(decoder), source),
+        playing: true,
         decorative: false,
         alt_text: None,
     }
 }
 
-/// The [`View`] created by [`image`].
+/// The [`View`] created by [`image`], [`image_source`] or [`animated_image`].
 ///
 /// See `image`'s docs for more details.
 #[must_use = "View values do nothing unless provided to Xilem."]
 pub struct Image {
-    image: ImageBrush,
+    content: ImageContent,
+    playing: bool,
     decorative: bool,
     alt_text: Option<ArcStr>,
 }
 
+enum ImageContent {
+    Brush(ImageBrush),
+    Source(ImageCache, ImageSource),
+    Animated(Arc<dyn AnimationDecoder>, ImageSource),
+}
+
+impl PartialEq for ImageContent {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Brush(a), Self::Brush(b)) => a == b,
+            (Self::Source(a_cache, a), Self::Source(b_cache, b)) => a_cache == b_cache && a == b,
+            (Self::Animated(_, a), Self::Animated(_, b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// Opens the frames of `source`, yielding no frames if it can't be read or opened.
+fn open_animation(decoder: &dyn AnimationDecoder, source: &ImageSource) -> Box<dyn FrameDecoder> {
+    let frames = source
+        .read()
+        .map_err(ImageDecodeError::from)
+        .and_then(|bytes| decoder.open(bytes));
+    frames.unwrap_or_else(|err| {
+        tracing::warn!("Failed to open animated image {source:?}: {err}");
+        Box::new(NoFrames)
+    })
+}
+
+struct NoFrames;
+
+impl FrameDecoder for NoFrames {
+    fn next_frame(&mut self) -> Option<Result<AnimationFrame, ImageDecodeError>> {
+        None
+    }
+}
+
 impl Image {
     // Because this method is image-specific, we don't add it to the Style trait.
     /// Specify the object fit.
@@ -51,6 +202,15 @@ impl Image {
         self.prop(fill)
     }
 
+    /// Sets whether an [animated image](animated_image) is playing.
+    ///
+    /// Animations are playing by default.
+    /// This has no effect on other images.
+    pub fn playing(mut self, is_playing: bool) -> Self {
+        self.playing = is_playing;
+        self
+    }
+
     /// Specifies whether the image is decorative, meaning it doesn't have meaningful content
     /// and is only for visual presentation.
     ///
@@ -79,7 +239,16 @@ impl<State: ViewArgument, Action> View<State, Action, ViewCtx> for Image {
     type ViewState = ();
 
     fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
-        let mut image = widgets::Image::new(self.image.clone()).decorative(self.decorative);
+        let image = match &self.content {
+            ImageContent::Brush(brush) => widgets::Image::new(brush.clone()),
+            ImageContent::Source(cache, source) => {
+                widgets::Image::from_source(cache.clone(), source.clone())
+            }
+            ImageContent::Animated(decoder, source) => {
+                widgets::Image::animated(open_animation(&**decoder, source))
+            }
+        };
+        let mut image = image.with_playing(self.playing).decorative(self.decorative);
         if let Some(alt_text) = &self.alt_text {
             image = image.with_alt_text(alt_text.clone());
         }
@@ -94,8 +263,21 @@ impl<State: ViewArgument, Action> View<State, Action, ViewCtx> for Image {
         mut element: Mut<'_, Self::Element>,
         _: Arg<'_, State>,
     ) {
-        if prev.image != self.image {
-            widgets::Image::set_image_data(&mut element, self.image.clone());
+        if prev.content != self.content {
+            match &self.content {
+                ImageContent::Brush(brush) => {
+                    widgets::Image::set_image_data(&mut element, brush.clone());
+                }
+                ImageContent::Source(cache, source) => {
+                    widgets::Image::set_source(&mut element, cache.clone(), source.clone());
+                }
+                ImageContent::Animated(decoder, source) => {
+                    widgets::Image::set_animation(&mut element, open_animation(&**decoder, source));
+                }
+            }
+        }
+        if self.playing != prev.playing {
+            widgets::Image::set_playing(&mut element, self.playing);
         }
         if self.decorative != prev.decorative {
             widgets::Image::set_decorative(&mut element, self.decorative);
@@ -268,6 +268,13 @@ impl ElementSplice<IndexedStackElement> for IndexedStackSplice<'_, '_> {
         self.idx += n;
     }
 
+    fn move_from(&mut self, n: usize) -> bool {
+        for idx in (self.idx..self.idx + n).rev() {
+            widgets::IndexedStack::swap(&mut self.element, idx, idx + 1);
+        }
+        true
+    }
+
     fn index(&self) -> usize {
         self.idx
     }
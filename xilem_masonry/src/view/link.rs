@@ -0,0 +1,144 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::ArcStr;
+use masonry::widgets::{self, LinkActivated};
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+type NavigateCallback<State> =
+    Box<dyn Fn(Arg<'_, State>, &LinkActivated) -> bool + Send + Sync + 'static>;
+
+/// A hyperlink which opens `url` with the platform's default handler, usually a web browser.
+///
+/// The link is activated by clicking it with the primary or middle mouse button, or by pressing
+/// Enter <kbd>↵</kbd> while it is focused.
+/// Use [`on_navigate`](Link::on_navigate) to handle some URLs in the app instead.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::view::link;
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct State {
+///     page: String,
+/// }
+///
+/// # fn view() -> impl WidgetView<Edit<State>> {
+/// link("Settings", "app://settings").on_navigate(|state: &mut State, activated| {
+///     // Handle the app's own URLs, and let the others open in the browser.
+///     match activated.url.strip_prefix("app://") {
+///         Some(page) => {
+///             state.page = page.to_string();
+///             true
+///         }
+///         None => false,
+///     }
+/// })
+/// # }
+/// ```
+pub fn link<State, Action>(text: impl Into<ArcStr>, url: impl Into<ArcStr>) -> Link<State, Action>
+where
+    State: ViewArgument,
+{
+    Link {
+        text: text.into(),
+        url: url.into(),
+        on_navigate: None,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`link`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Link<State: ViewArgument, Action> {
+    text: ArcStr,
+    url: ArcStr,
+    on_navigate: Option<NavigateCallback<State>>,
+    phantom: PhantomData<fn() -> Action>,
+}
+
+impl<State: ViewArgument, Action> Link<State, Action> {
+    /// Set a callback which is run when the link is activated, before the URL is opened.
+    ///
+    /// The callback returns whether the app handled the navigation itself, e.g. by going
+    /// to a page of the app; if it returns `false`, the URL is opened as usual.
+    /// [`LinkActivated::button`] tells whether the link was middle-clicked, which apps
+    /// often use to open the page in a new window.
+    pub fn on_navigate<F>(mut self, on_navigate: F) -> Self
+    where
+        F: Fn(Arg<'_, State>, &LinkActivated) -> bool + Send + Sync + 'static,
+    {
+        self.on_navigate = Some(Box::new(on_navigate));
+        self
+    }
+}
+
+impl<State: ViewArgument, Action> ViewMarker for Link<State, Action> {}
+impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx> for Link<State, Action> {
+    type Element = Pod<widgets::Link>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let pod = ctx.with_action_widget(|ctx| {
+            ctx.create_pod(widgets::Link::new(self.text.clone(), self.url.clone()))
+        });
+        (pod, ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        if prev.url != self.url {
+            widgets::Link::set_url(&mut element, self.url.clone());
+        }
+        if prev.text != self.text {
+            let mut label = widgets::Link::label_mut(&mut element);
+            widgets::Label::set_text(&mut label, self.text.clone());
+        }
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        debug_assert!(
+            message.remaining_path().is_empty(),
+            "id path should be empty in Link::message"
+        );
+        let Some(activated) = message.take_message::<LinkActivated>() else {
+            tracing::error!(?message, "Wrong message type in Link::message");
+            return MessageResult::Stale;
+        };
+        if let Some(on_navigate) = &self.on_navigate
+            && on_navigate(app_state, &activated)
+        {
+            return MessageResult::RequestRebuild;
+        }
+        element.ctx.open_url(activated.url.to_string());
+        MessageResult::Nop
+    }
+}
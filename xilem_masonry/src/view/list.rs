@@ -0,0 +1,514 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::type_name;
+use std::marker::PhantomData;
+
+use masonry::core::{CollectionWidget, FromDynWidget, Widget, WidgetMut};
+use masonry::widgets::{self, SelectionChanged};
+
+use crate::core::{
+    AppendVec, Arg, ElementSplice, MessageCtx, MessageResult, Mut, SuperElement, View,
+    ViewArgument, ViewElement, ViewId, ViewMarker, ViewPathTracker, ViewSequence,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+pub use masonry::widgets::SelectionMode;
+
+type Callback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, Vec<String>) -> Action + Send + Sync + 'static>;
+
+/// A vertical list of rows which users can select, such as the files of a folder.
+///
+/// Each view in the sequence is a row, given a key with [`ListExt::list_item`].
+/// The `mode` sets how many rows can be selected, and `selected` holds the keys of the
+/// selected rows.
+/// Users select rows by clicking them, with <kbd>Ctrl</kbd> and <kbd>Shift</kbd> clicks,
+/// with a rubber band, and with the keyboard.
+/// The list updates its selection as they do, and passes the keys of the selected rows
+/// to the [`on_selection_change`](List::on_selection_change) callback, so that the app
+/// can store them.
+///
+/// The selection is reset to `selected` when it changes.
+///
+/// See the documentation on the underlying [`List`](widgets::List) for more information.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::core::Edit;
+/// use xilem::view::{ListExt, SelectionMode, label, list};
+///
+/// struct Files {
+///     names: Vec<String>,
+///     selected: Vec<String>,
+/// }
+///
+/// let files = Files {
+///     names: vec!["Cargo.toml".into(), "README.md".into()],
+///     selected: Vec::new(),
+/// };
+///
+/// list::<Edit<Files>, _, _>(
+///     SelectionMode::Multiple,
+///     files.selected.clone(),
+///     files
+///         .names
+///         .iter()
+///         .map(|name| label(name.clone()).list_item(name.clone()))
+///         .collect::<Vec<_>>(),
+/// )
+/// .on_selection_change(|files: &mut Files, selected| files.selected = selected);
+/// ```
+pub fn list<State: ViewArgument, Action, Seq: ListSequence<State, Action>>(
+    mode: SelectionMode,
+    selected: Vec<String>,
+    sequence: Seq,
+) -> List<Seq, State, Action> {
+    List {
+        sequence,
+        mode,
+        selected,
+        on_selection_change: None,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`list`] from a sequence.
+///
+/// See `list` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct List<Seq, State: ViewArgument, Action = ()> {
+    sequence: Seq,
+    mode: SelectionMode,
+    selected: Vec<String>,
+    on_selection_change: Option<Callback<State, Action>>,
+
+    /// Used to associate the State and Action in the call to `.list()` with the State
+    /// and Action used in the View implementation, to allow inference to flow backwards,
+    /// allowing State and Action to be inferred properly.
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<Seq, State: ViewArgument, Action> List<Seq, State, Action> {
+    /// Sets a callback which is called with the keys of the selected rows, in row order,
+    /// each time the user changes the selection.
+    pub fn on_selection_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Arg<'_, State>, Vec<String>) -> Action + Send + Sync + 'static,
+    {
+        self.on_selection_change = Some(Box::new(callback));
+        self
+    }
+}
+
+mod hidden {
+    use super::ListElement;
+    use crate::core::AppendVec;
+
+    #[doc(hidden)]
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub struct ListState<SeqState> {
+        pub(crate) seq_state: SeqState,
+        pub(crate) scratch: AppendVec<ListElement>,
+    }
+}
+
+use hidden::ListState;
+
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 1640354775 in decimal.
+const LIST_ROWS_VIEW_ID: ViewId = ViewId::new(0x61c5d3d7);
+
+impl<Seq, State: ViewArgument, Action> ViewMarker for List<Seq, State, Action> {}
+
+impl<State, Action, Seq> View<State, Action, ViewCtx> for List<Seq, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    Seq: ListSequence<State, Action>,
+{
+    type Element = Pod<widgets::List>;
+
+    type ViewState = ListState<Seq::SeqState>;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let mut elements = AppendVec::default();
+        let mut widget = widgets::List::new(self.mode);
+        let seq_state = ctx.with_id(LIST_ROWS_VIEW_ID, |ctx| {
+            self.sequence.seq_build(ctx, &mut elements, app_state)
+        });
+        for element in elements.drain() {
+            widget = widget.with(element.child.new_widget, element.key);
+        }
+        let widget = widget.with_selected(self.selected.iter().cloned());
+        let pod = ctx.with_action_widget(|ctx| ctx.create_pod(widget));
+        (
+            pod,
+            ListState {
+                seq_state,
+                scratch: elements,
+            },
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        ListState { seq_state, scratch }: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        ctx.with_id(LIST_ROWS_VIEW_ID, |ctx| {
+            let mut splice = ListSplice::new(element.reborrow_mut(), scratch);
+            self.sequence
+                .seq_rebuild(&prev.sequence, seq_state, ctx, &mut splice, app_state);
+            debug_assert!(scratch.is_empty());
+        });
+
+        if self.mode != prev.mode {
+            widgets::List::set_selection_mode(&mut element, self.mode);
+        }
+        if self.selected != prev.selected {
+            widgets::List::set_selected(&mut element, self.selected.iter().cloned());
+        }
+    }
+
+    fn teardown(
+        &self,
+        ListState { seq_state, scratch }: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(LIST_ROWS_VIEW_ID, |ctx| {
+            let mut splice = ListSplice::new(element.reborrow_mut(), scratch);
+            self.sequence.seq_teardown(seq_state, ctx, &mut splice);
+            debug_assert!(scratch.is_empty());
+        });
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        ListState { seq_state, scratch }: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(LIST_ROWS_VIEW_ID) => {
+                let mut splice = ListSplice::new(element, scratch);
+                let result = self
+                    .sequence
+                    .seq_message(seq_state, message, &mut splice, app_state);
+                debug_assert!(scratch.is_empty());
+                result
+            }
+            None => match message.take_message::<SelectionChanged<String>>() {
+                Some(changed) => match &self.on_selection_change {
+                    Some(callback) => MessageResult::Action(callback(app_state, changed.selected)),
+                    None => MessageResult::Nop,
+                },
+                None => {
+                    tracing::error!(
+                        "Wrong message type in List::message: {message:?} expected {}",
+                        type_name::<SelectionChanged<String>>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!(?message, "Got unexpected id path in `List::message`.");
+                MessageResult::Stale
+            }
+        }
+    }
+}
+
+// Used to become a reference form for editing. It's provided to rebuild and teardown.
+impl ViewElement for ListElement {
+    type Mut<'w> = ListElementMut<'w>;
+}
+
+// Used to allow the item to be used as a generic item in ViewSequence.
+impl SuperElement<Self, ViewCtx> for ListElement {
+    fn upcast(_ctx: &mut ViewCtx, child: Self) -> Self {
+        child
+    }
+
+    fn with_downcast_val<R>(
+        mut this: Mut<'_, Self>,
+        f: impl FnOnce(Mut<'_, Self>) -> R,
+    ) -> (Self::Mut<'_>, R) {
+        let r = {
+            let parent = this.parent.reborrow_mut();
+            let reborrow = ListElementMut {
+                idx: this.idx,
+                parent,
+            };
+            f(reborrow)
+        };
+        (this, r)
+    }
+}
+
+impl<W: Widget + FromDynWidget + ?Sized> SuperElement<Pod<W>, ViewCtx> for ListElement {
+    fn upcast(_: &mut ViewCtx, child: Pod<W>) -> Self {
+        // Getting here means that the widget didn't use .list_item.
+        // Rows without a key are selected together, as they can't be told apart.
+        Self {
+            child: child.erased(),
+            key: String::new(),
+        }
+    }
+
+    fn with_downcast_val<R>(
+        mut this: Mut<'_, Self>,
+        f: impl FnOnce(Mut<'_, Pod<W>>) -> R,
+    ) -> (Mut<'_, Self>, R) {
+        let ret = {
+            let mut child = widgets::List::get_mut(&mut this.parent, this.idx);
+            let downcast = child.downcast();
+            f(downcast)
+        };
+
+        (this, ret)
+    }
+}
+
+// Used for building and rebuilding the ViewSequence
+impl ElementSplice<ListElement> for ListSplice<'_, '_> {
+    fn with_scratch<R>(&mut self, f: impl FnOnce(&mut AppendVec<ListElement>) -> R) -> R {
+        let ret = f(self.scratch);
+        for element in self.scratch.drain() {
+            widgets::List::insert(
+                &mut self.element,
+                self.idx,
+                element.child.new_widget,
+                element.key,
+            );
+            self.idx += 1;
+        }
+        ret
+    }
+
+    fn insert(&mut self, element: ListElement) {
+        widgets::List::insert(
+            &mut self.element,
+            self.idx,
+            element.child.new_widget,
+            element.key,
+        );
+        self.idx += 1;
+    }
+
+    fn mutate<R>(&mut self, f: impl FnOnce(Mut<'_, ListElement>) -> R) -> R {
+        let child = ListElementMut {
+            parent: self.element.reborrow_mut(),
+            idx: self.idx,
+        };
+        let ret = f(child);
+        self.idx += 1;
+        ret
+    }
+
+    fn skip(&mut self, n: usize) {
+        self.idx += n;
+    }
+
+    fn move_from(&mut self, n: usize) -> bool {
+        for idx in (self.idx..self.idx + n).rev() {
+            widgets::List::swap(&mut self.element, idx, idx + 1);
+        }
+        true
+    }
+
+    fn index(&self) -> usize {
+        self.idx
+    }
+
+    fn delete<R>(&mut self, f: impl FnOnce(Mut<'_, ListElement>) -> R) -> R {
+        let ret = {
+            let child = ListElementMut {
+                parent: self.element.reborrow_mut(),
+                idx: self.idx,
+            };
+            f(child)
+        };
+        widgets::List::remove(&mut self.element, self.idx);
+        ret
+    }
+}
+
+/// `ListSequence` is what allows an input to the list that contains all the rows.
+pub trait ListSequence<State: ViewArgument, Action = ()>:
+    ViewSequence<State, Action, ViewCtx, ListElement>
+{
+}
+
+impl<Seq, State, Action> ListSequence<State, Action> for Seq
+where
+    Seq: ViewSequence<State, Action, ViewCtx, ListElement>,
+    State: ViewArgument,
+{
+}
+
+/// A trait which extends a [`WidgetView`] with methods to provide parameters for a list row.
+pub trait ListExt<State: ViewArgument, Action>: WidgetView<State, Action> {
+    /// Uses this view as a row of a [`List`], which its selection refers to by `key`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// use xilem::view::{ListExt, SelectionMode, label, list};
+    /// # use xilem::WidgetView;
+    /// # use xilem::core::Edit;
+    ///
+    /// # fn view<State: 'static>() -> impl WidgetView<Edit<State>> {
+    /// list(
+    ///     SelectionMode::Single,
+    ///     vec!["red".into()],
+    ///     (
+    ///         label("Red").list_item("red"),
+    ///         label("Green").list_item("green"),
+    ///     ),
+    /// )
+    /// # }
+    /// ```
+    fn list_item(self, key: impl Into<String>) -> ListItem<Self, State, Action>
+    where
+        State: 'static,
+        Action: 'static,
+        Self: Sized,
+    {
+        list_item(self, key)
+    }
+}
+
+impl<State: ViewArgument, Action, V: WidgetView<State, Action>> ListExt<State, Action> for V {}
+
+/// A child widget within a [`List`] view.
+pub struct ListElement {
+    /// The child widget.
+    child: Pod<dyn Widget>,
+    /// The key of the row.
+    key: String,
+}
+
+/// A mutable reference to a [`ListElement`], used internally by Xilem traits.
+pub struct ListElementMut<'w> {
+    parent: WidgetMut<'w, widgets::List>,
+    idx: usize,
+}
+
+// Used for manipulating the ViewSequence.
+struct ListSplice<'w, 's> {
+    idx: usize,
+    element: WidgetMut<'w, widgets::List>,
+    scratch: &'s mut AppendVec<ListElement>,
+}
+
+impl<'w, 's> ListSplice<'w, 's> {
+    fn new(element: WidgetMut<'w, widgets::List>, scratch: &'s mut AppendVec<ListElement>) -> Self {
+        Self {
+            idx: 0,
+            element,
+            scratch,
+        }
+    }
+}
+
+/// A `WidgetView` that can be used as a row within a [`List`] [`View`].
+pub struct ListItem<V, State, Action> {
+    view: V,
+    key: String,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+/// Creates a [`ListItem`] from a view and the key of the row.
+pub fn list_item<V, State, Action>(view: V, key: impl Into<String>) -> ListItem<V, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    ListItem {
+        view,
+        key: key.into(),
+        phantom: PhantomData,
+    }
+}
+
+impl<V, State, Action> ViewMarker for ListItem<V, State, Action> {}
+
+impl<State, Action, V> View<State, Action, ViewCtx> for ListItem<V, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    type Element = ListElement;
+
+    type ViewState = V::ViewState;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (pod, state) = self.view.build(ctx, app_state);
+        (
+            ListElement {
+                child: pod.erased(),
+                key: self.key.clone(),
+            },
+            state,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        if self.key != prev.key {
+            widgets::List::set_params(&mut element.parent, element.idx, self.key.clone());
+        }
+        let mut child = widgets::List::get_mut(&mut element.parent, element.idx);
+        self.view
+            .rebuild(&prev.view, view_state, ctx, child.downcast(), app_state);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        let mut child = widgets::List::get_mut(&mut element.parent, element.idx);
+        self.view.teardown(view_state, ctx, child.downcast());
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        let mut child = widgets::List::get_mut(&mut element.parent, element.idx);
+        self.view
+            .message(view_state, message, child.downcast(), app_state)
+    }
+}
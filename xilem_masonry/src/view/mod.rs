@@ -3,54 +3,128 @@
 
 //! Views for the widgets which are built-in to Masonry. These are the primitives your Xilem app's view tree will generally be constructed from.
 
+mod audio;
+mod avatar;
+mod badge;
 mod button;
 mod canvas;
 mod checkbox;
+mod chunked;
+mod clip;
+mod command_palette;
+mod component;
+mod continuous_redraw;
+mod divider;
+mod dock_area;
+mod error_boundary;
 mod flex;
+mod frozen_pane;
+mod gauge;
+mod graph_canvas;
 mod grid;
+mod heatmap;
+mod hot_reload;
 mod image;
 mod indexed_stack;
 mod label;
+mod link;
+mod list;
+mod navigation_stack;
+mod pager;
+mod password_input;
+mod persist;
 mod portal;
 mod progress_bar;
 mod prop;
 mod prose;
+mod pull_to_refresh;
+mod rating;
 mod resize_observer;
+mod responsive;
+mod section_header;
+mod segmented_control;
 mod sized_box;
+mod skeleton;
 mod slider;
+mod snapshot;
 mod spinner;
 mod split;
+mod stepper;
+mod suspense;
 mod switch;
 mod task;
 mod text_input;
+mod token_input;
 mod transform;
+mod ui_node;
 mod variable_label;
 mod virtual_scroll;
+mod visibility_observer;
+mod waterfall;
+mod web_view;
+mod wgpu_surface_view;
 mod worker;
 mod zstack;
 
+pub use self::audio::*;
+pub use self::avatar::*;
+pub use self::badge::*;
 pub use self::button::*;
 pub use self::canvas::*;
 pub use self::checkbox::*;
+pub use self::chunked::*;
+pub use self::clip::*;
+pub use self::command_palette::*;
+pub use self::component::*;
+pub use self::continuous_redraw::*;
+pub use self::divider::*;
+pub use self::dock_area::*;
+pub use self::error_boundary::*;
 pub use self::flex::*;
+pub use self::frozen_pane::*;
+pub use self::gauge::*;
+pub use self::graph_canvas::*;
 pub use self::grid::*;
+pub use self::heatmap::*;
+pub use self::hot_reload::*;
 pub use self::image::*;
 pub use self::indexed_stack::*;
 pub use self::label::*;
+pub use self::link::*;
+pub use self::list::*;
+pub use self::navigation_stack::*;
+pub use self::pager::*;
+pub use self::password_input::*;
+pub use self::persist::*;
 pub use self::portal::*;
 pub use self::progress_bar::*;
 pub use self::prop::*;
 pub use self::prose::*;
+pub use self::pull_to_refresh::*;
+pub use self::rating::*;
 pub use self::resize_observer::*;
+pub use self::responsive::*;
+pub use self::section_header::*;
+pub use self::segmented_control::*;
 pub use self::sized_box::*;
+pub use self::skeleton::*;
 pub use self::slider::*;
+pub use self::snapshot::*;
 pub use self::spinner::*;
 pub use self::split::*;
+pub use self::stepper::*;
+pub use self::suspense::*;
 pub use self::switch::*;
 pub use self::task::*;
 pub use self::text_input::*;
+pub use self::token_input::*;
 pub use self::transform::*;
+pub use self::ui_node::*;
 pub use self::variable_label::*;
 pub use self::virtual_scroll::*;
+pub use self::visibility_observer::*;
+pub use self::waterfall::*;
+pub use self::web_view::*;
+pub use self::wgpu_surface_view::*;
 pub use self::worker::*;
 pub use self::zstack::*;
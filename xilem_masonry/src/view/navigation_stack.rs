@@ -0,0 +1,381 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::type_name;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use masonry::widgets::{self, NavigateBack};
+
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+type Callback<State, Action> = Box<dyn Fn(Arg<'_, State>) -> Action + Send + Sync + 'static>;
+
+/// The routes of a [`navigation_stack`], from the root route to the one currently shown.
+///
+/// The root route is always present: [`pop`](Self::pop) never removes it.
+///
+/// A path can be written as and parsed from a deep link like `/settings/account`,
+/// in which each segment is a route above the root; see [`from_deep_link`](Self::from_deep_link).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NavigationPath<R> {
+    routes: Vec<R>,
+}
+
+impl<R> NavigationPath<R> {
+    /// Creates a path with only the given root route.
+    pub fn new(root: R) -> Self {
+        Self { routes: vec![root] }
+    }
+
+    /// Returns the routes, from the root route.
+    pub fn routes(&self) -> &[R] {
+        &self.routes
+    }
+
+    /// Returns the route currently shown.
+    pub fn top(&self) -> &R {
+        self.routes.last().expect("the root route is never popped")
+    }
+
+    /// Returns the number of routes, including the root route.
+    ///
+    /// This is never 0.
+    pub fn depth(&self) -> usize {
+        self.routes.len()
+    }
+
+    /// Returns `true` if the root route is the only route.
+    pub fn is_root(&self) -> bool {
+        self.routes.len() == 1
+    }
+
+    /// Navigates to `route`.
+    pub fn push(&mut self, route: R) {
+        self.routes.push(route);
+    }
+
+    /// Goes back to the previous route, and returns the route which was removed.
+    ///
+    /// Returns `None` without doing anything if only the root route is left.
+    pub fn pop(&mut self) -> Option<R> {
+        if self.is_root() {
+            None
+        } else {
+            self.routes.pop()
+        }
+    }
+
+    /// Goes back to the root route.
+    pub fn pop_to_root(&mut self) {
+        self.routes.truncate(1);
+    }
+
+    /// Parses a deep link such as `/settings/account`, where each non-empty segment
+    /// is parsed as a route pushed on top of `root`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first segment which can't be parsed as a route.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// use xilem::view::NavigationPath;
+    ///
+    /// let path = NavigationPath::from_deep_link("home".to_string(), "/settings/account").unwrap();
+    /// assert_eq!(path.routes(), ["home", "settings", "account"]);
+    /// assert_eq!(path.to_deep_link(), "/settings/account");
+    /// ```
+    pub fn from_deep_link(root: R, link: &str) -> Result<Self, R::Err>
+    where
+        R: FromStr,
+    {
+        let mut path = Self::new(root);
+        for segment in link.split('/').filter(|segment| !segment.is_empty()) {
+            path.push(segment.parse()?);
+        }
+        Ok(path)
+    }
+
+    /// Writes the routes above the root route as a deep link, which can be parsed
+    /// back with [`from_deep_link`](Self::from_deep_link).
+    ///
+    /// This is `/` if only the root route is left.
+    pub fn to_deep_link(&self) -> String
+    where
+        R: Display,
+    {
+        if self.is_root() {
+            return "/".to_string();
+        }
+        self.routes[1..]
+            .iter()
+            .map(|route| format!("/{route}"))
+            .collect()
+    }
+}
+
+/// A stack of full-size pages, one for each route of `path`, of which only the top one is shown.
+///
+/// `route_view` is called with each route to get the view of its page.
+/// When a route is pushed, its page slides in, and when it is popped, its page slides out.
+/// The pages below the top one are kept alive, so they keep their state (such as scroll
+/// positions) while another page is shown.
+/// A page is built anew when its route changes.
+///
+/// When the user asks to go back, with <kbd>Esc</kbd>, the back button of their mouse or
+/// of their Android device, or by swiping right from the left edge, the
+/// [`on_back`](NavigationStack::on_back) callback is called, which usually pops the route.
+///
+/// See the documentation on the underlying [`NavigationStack`](widgets::NavigationStack)
+/// for more information.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::view::{NavigationPath, label, navigation_stack, text_button};
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// #[derive(Clone, PartialEq)]
+/// enum Route {
+///     Inbox,
+///     Message(usize),
+/// }
+///
+/// struct State {
+///     path: NavigationPath<Route>,
+/// }
+///
+/// # fn view(state: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+/// navigation_stack(&state.path, |route| match route {
+///     Route::Inbox => text_button("Open the first message", |state: &mut State| {
+///         state.path.push(Route::Message(0));
+///     })
+///     .boxed(),
+///     Route::Message(idx) => label(format!("Message {idx}")).boxed(),
+/// })
+/// .on_back(|state: &mut State| {
+///     state.path.pop();
+/// })
+/// # }
+/// ```
+pub fn navigation_stack<State, Action, R, V>(
+    path: &NavigationPath<R>,
+    route_view: impl Fn(&R) -> V,
+) -> NavigationStack<R, V, State, Action>
+where
+    State: ViewArgument,
+    R: Clone + PartialEq + Send + Sync + 'static,
+    V: WidgetView<State, Action>,
+{
+    NavigationStack {
+        pages: path
+            .routes()
+            .iter()
+            .map(|route| (route.clone(), route_view(route)))
+            .collect(),
+        on_back: None,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`navigation_stack`].
+///
+/// See `navigation_stack` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct NavigationStack<R, V, State: ViewArgument, Action = ()> {
+    pages: Vec<(R, V)>,
+    on_back: Option<Callback<State, Action>>,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<R, V, State: ViewArgument, Action> NavigationStack<R, V, State, Action> {
+    /// Sets a callback which is called when the user asks to go back.
+    ///
+    /// This isn't called when the root page is shown.
+    pub fn on_back<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Arg<'_, State>) -> Action + Send + Sync + 'static,
+    {
+        self.on_back = Some(Box::new(callback));
+        self
+    }
+}
+
+mod hidden {
+    use crate::core::ViewId;
+
+    #[doc(hidden)]
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub struct NavigationStackState<ViewState> {
+        /// The id and view state of each page.
+        pub(crate) pages: Vec<(ViewId, ViewState)>,
+        /// Used to give each page its own id, so that messages for popped pages are stale
+        /// even if another page was pushed at the same index.
+        pub(crate) next_id: u64,
+    }
+}
+
+use hidden::NavigationStackState;
+
+impl<R, V, State: ViewArgument, Action> ViewMarker for NavigationStack<R, V, State, Action> {}
+
+impl<State, Action, R, V> View<State, Action, ViewCtx> for NavigationStack<R, V, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    R: PartialEq + 'static,
+    V: WidgetView<State, Action>,
+{
+    type Element = Pod<widgets::NavigationStack>;
+
+    type ViewState = NavigationStackState<V::ViewState>;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        mut app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let mut widget = widgets::NavigationStack::new();
+        let mut pages = Vec::with_capacity(self.pages.len());
+        for (idx, (_, view)) in self.pages.iter().enumerate() {
+            let id = ViewId::new(idx as u64);
+            let (child, child_state) = ctx.with_id(id, |ctx| {
+                view.build(ctx, State::reborrow_mut(&mut app_state))
+            });
+            widget = widget.with(child.new_widget);
+            pages.push((id, child_state));
+        }
+        let pod = ctx.with_action_widget(|ctx| ctx.create_pod(widget));
+        (
+            pod,
+            NavigationStackState {
+                pages,
+                next_id: self.pages.len() as u64,
+            },
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        mut app_state: Arg<'_, State>,
+    ) {
+        // Pages are kept as long as the routes up to them are the same.
+        let kept = self
+            .pages
+            .iter()
+            .zip(&prev.pages)
+            .take_while(|((route, _), (prev_route, _))| route == prev_route)
+            .count();
+
+        for (idx, ((_, view), (_, prev_view))) in
+            self.pages.iter().zip(&prev.pages).enumerate().take(kept)
+        {
+            let (id, child_state) = &mut view_state.pages[idx];
+            let mut child = widgets::NavigationStack::page_mut(&mut element, idx);
+            ctx.with_id(*id, |ctx| {
+                view.rebuild(
+                    prev_view,
+                    child_state,
+                    ctx,
+                    child.downcast(),
+                    State::reborrow_mut(&mut app_state),
+                );
+            });
+        }
+
+        if kept < prev.pages.len() {
+            for idx in (kept..prev.pages.len()).rev() {
+                let (id, mut child_state) = view_state.pages.pop().unwrap();
+                let mut child = widgets::NavigationStack::page_mut(&mut element, idx);
+                ctx.with_id(id, |ctx| {
+                    prev.pages[idx]
+                        .1
+                        .teardown(&mut child_state, ctx, child.downcast());
+                });
+            }
+            widgets::NavigationStack::truncate(&mut element, kept);
+        }
+
+        for (_, view) in &self.pages[kept..] {
+            let id = ViewId::new(view_state.next_id);
+            view_state.next_id += 1;
+            let (child, child_state) = ctx.with_id(id, |ctx| {
+                view.build(ctx, State::reborrow_mut(&mut app_state))
+            });
+            widgets::NavigationStack::push(&mut element, child.new_widget);
+            view_state.pages.push((id, child_state));
+        }
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        for (idx, ((id, child_state), (_, view))) in
+            view_state.pages.iter_mut().zip(&self.pages).enumerate()
+        {
+            let mut child = widgets::NavigationStack::page_mut(&mut element, idx);
+            ctx.with_id(*id, |ctx| {
+                view.teardown(child_state, ctx, child.downcast());
+            });
+        }
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(id) => {
+                let Some(idx) = view_state
+                    .pages
+                    .iter()
+                    .position(|(page_id, _)| *page_id == id)
+                else {
+                    // The page was popped.
+                    return MessageResult::Stale;
+                };
+                let child_state = &mut view_state.pages[idx].1;
+                let mut child = widgets::NavigationStack::page_mut(&mut element, idx);
+                self.pages[idx]
+                    .1
+                    .message(child_state, message, child.downcast(), app_state)
+            }
+            None => match message.take_message::<NavigateBack>() {
+                Some(_) => match &self.on_back {
+                    Some(callback) => MessageResult::Action(callback(app_state)),
+                    None => MessageResult::Nop,
+                },
+                None => {
+                    tracing::error!(
+                        "Wrong message type in NavigationStack::message: {message:?} expected {}",
+                        type_name::<NavigateBack>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+        }
+    }
+}
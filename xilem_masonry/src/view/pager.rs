@@ -0,0 +1,400 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::type_name;
+use std::marker::PhantomData;
+
+use masonry::core::{CollectionWidget, FromDynWidget, Widget, WidgetMut};
+use masonry::widgets::{self, PageChanged};
+
+use crate::core::{
+    AppendVec, Arg, ElementSplice, MessageCtx, MessageResult, Mut, SuperElement, View,
+    ViewArgument, ViewElement, ViewId, ViewMarker, ViewPathTracker, ViewSequence,
+};
+use crate::{Pod, ViewCtx};
+
+type Callback<State, Action> = Box<dyn Fn(Arg<'_, State>, usize) -> Action + Send + Sync + 'static>;
+
+/// A `Pager` shows one full-size page at a time, which users can swipe between horizontally.
+///
+/// Pages can also be changed by the app with [`page`](Pager::page), in which case
+/// the pager slides to the new page.
+/// When the user changes the page, the [`on_page_changed`](Pager::on_page_changed)
+/// callback is called with the index of the new page.
+///
+/// See the documentation on the underlying [`Pager`](widgets::Pager) for more information.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::view::{label, pager};
+/// use xilem::core::Edit;
+///
+/// #[derive(Default)]
+/// struct State {
+///     page: usize,
+/// }
+///
+/// let mut state = State::default();
+///
+/// pager::<Edit<State>, _, _>((label("First"), label("Second"), label("Third")))
+///     .page(state.page)
+///     .on_page_changed(|state: &mut State, page| state.page = page)
+///     .page_indicator(true);
+/// ```
+pub fn pager<State: ViewArgument, Action, Seq: PagerSequence<State, Action>>(
+    sequence: Seq,
+) -> Pager<Seq, State, Action> {
+    Pager {
+        sequence,
+        page: 0,
+        peek: 0.,
+        page_indicator: false,
+        on_page_changed: None,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`pager`] from a sequence.
+///
+/// See `pager` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Pager<Seq, State: ViewArgument, Action = ()> {
+    sequence: Seq,
+    page: usize,
+    peek: f64,
+    page_indicator: bool,
+    on_page_changed: Option<Callback<State, Action>>,
+
+    /// Used to associate the State and Action in the call to `.pager()` with the State and Action
+    /// used in the View implementation, to allow inference to flow backwards, allowing State and
+    /// Action to be inferred properly.
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<Seq, State: ViewArgument, Action> Pager<Seq, State, Action> {
+    /// Sets the current page.
+    ///
+    /// When this changes, the pager slides to the new page.
+    /// The pager doesn't go back to this page when the user swipes to another one,
+    /// so use [`on_page_changed`](Self::on_page_changed) to keep it in sync.
+    #[track_caller]
+    pub fn page(mut self, page: usize) -> Self {
+        // TODO: validate this against the sequence. Currently,
+        // the sequence has no way to get the length.
+        self.page = page;
+        self
+    }
+
+    /// Sets how much of the adjacent pages is visible on each side.
+    ///
+    /// The default is 0.
+    pub fn peek(mut self, peek: f64) -> Self {
+        self.peek = peek;
+        self
+    }
+
+    /// Sets whether dots showing the current page are displayed over the bottom edge.
+    ///
+    /// The default is `false`.
+    pub fn page_indicator(mut self, show: bool) -> Self {
+        self.page_indicator = show;
+        self
+    }
+
+    /// Sets a callback which is called with the index of the new page when the user
+    /// changes the page.
+    pub fn on_page_changed<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Arg<'_, State>, usize) -> Action + Send + Sync + 'static,
+    {
+        self.on_page_changed = Some(Box::new(callback));
+        self
+    }
+}
+
+mod hidden {
+    use super::PagerElement;
+    use crate::core::AppendVec;
+
+    #[doc(hidden)]
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub struct PagerState<SeqState> {
+        pub(crate) seq_state: SeqState,
+        pub(crate) scratch: AppendVec<PagerElement>,
+    }
+}
+
+use hidden::PagerState;
+
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 2440335223 in decimal.
+const PAGER_CONTENT_VIEW_ID: ViewId = ViewId::new(0x91748f77);
+
+impl<Seq, State: ViewArgument, Action> ViewMarker for Pager<Seq, State, Action> {}
+
+impl<State, Action, Seq> View<State, Action, ViewCtx> for Pager<Seq, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    Seq: PagerSequence<State, Action>,
+{
+    type Element = Pod<widgets::Pager>;
+
+    type ViewState = PagerState<Seq::SeqState>;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let mut elements = AppendVec::default();
+        let mut widget = widgets::Pager::new()
+            .peek(self.peek)
+            .show_indicator(self.page_indicator);
+        let seq_state = ctx.with_id(PAGER_CONTENT_VIEW_ID, |ctx| {
+            self.sequence.seq_build(ctx, &mut elements, app_state)
+        });
+        for element in elements.drain() {
+            widget = widget.with(element.child.new_widget);
+        }
+        widget = widget.with_current_page(self.page);
+        let pod = ctx.with_action_widget(|ctx| ctx.create_pod(widget));
+        (
+            pod,
+            PagerState {
+                seq_state,
+                scratch: elements,
+            },
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        PagerState { seq_state, scratch }: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        ctx.with_id(PAGER_CONTENT_VIEW_ID, |ctx| {
+            let mut splice = PagerSplice::new(element.reborrow_mut(), scratch);
+            self.sequence
+                .seq_rebuild(&prev.sequence, seq_state, ctx, &mut splice, app_state);
+            debug_assert!(scratch.is_empty());
+        });
+
+        if self.peek != prev.peek {
+            widgets::Pager::set_peek(&mut element, self.peek);
+        }
+        if self.page_indicator != prev.page_indicator {
+            widgets::Pager::set_show_indicator(&mut element, self.page_indicator);
+        }
+        // Set the page after updating the sequence to ensure the index remains
+        // consistent with the pages list.
+        // The user changes pages on their own, so we only follow changes of `page`.
+        if self.page != prev.page && self.page != element.widget.current_page() {
+            widgets::Pager::set_current_page(&mut element, self.page);
+        }
+    }
+
+    fn teardown(
+        &self,
+        PagerState { seq_state, scratch }: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(PAGER_CONTENT_VIEW_ID, |ctx| {
+            let mut splice = PagerSplice::new(element.reborrow_mut(), scratch);
+            self.sequence.seq_teardown(seq_state, ctx, &mut splice);
+            debug_assert!(scratch.is_empty());
+        });
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        PagerState { seq_state, scratch }: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(PAGER_CONTENT_VIEW_ID) => {
+                let mut splice = PagerSplice::new(element, scratch);
+                let result = self
+                    .sequence
+                    .seq_message(seq_state, message, &mut splice, app_state);
+                debug_assert!(scratch.is_empty());
+                result
+            }
+            None => match message.take_message::<PageChanged>() {
+                Some(page) => match &self.on_page_changed {
+                    Some(callback) => MessageResult::Action(callback(app_state, page.0)),
+                    None => MessageResult::Nop,
+                },
+                None => {
+                    tracing::error!(
+                        "Wrong message type in Pager::message: {message:?} expected {}",
+                        type_name::<PageChanged>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!(?message, "Got unexpected id path in `Pager::message`.");
+                MessageResult::Stale
+            }
+        }
+    }
+}
+
+// Used to become a reference form for editing. It's provided to rebuild and teardown.
+impl ViewElement for PagerElement {
+    type Mut<'w> = PagerElementMut<'w>;
+}
+
+// Used to allow the item to be used as a generic item in ViewSequence.
+impl SuperElement<Self, ViewCtx> for PagerElement {
+    fn upcast(_ctx: &mut ViewCtx, child: Self) -> Self {
+        child
+    }
+
+    fn with_downcast_val<R>(
+        mut this: Mut<'_, Self>,
+        f: impl FnOnce(Mut<'_, Self>) -> R,
+    ) -> (Self::Mut<'_>, R) {
+        let r = {
+            let parent = this.parent.reborrow_mut();
+            let reborrow = PagerElementMut {
+                idx: this.idx,
+                parent,
+            };
+            f(reborrow)
+        };
+        (this, r)
+    }
+}
+
+impl<W: Widget + FromDynWidget + ?Sized> SuperElement<Pod<W>, ViewCtx> for PagerElement {
+    fn upcast(_: &mut ViewCtx, child: Pod<W>) -> Self {
+        Self {
+            child: child.erased(),
+        }
+    }
+
+    fn with_downcast_val<R>(
+        mut this: Mut<'_, Self>,
+        f: impl FnOnce(Mut<'_, Pod<W>>) -> R,
+    ) -> (Mut<'_, Self>, R) {
+        let ret = {
+            let mut child = widgets::Pager::get_mut(&mut this.parent, this.idx);
+            let downcast = child.downcast();
+            f(downcast)
+        };
+
+        (this, ret)
+    }
+}
+
+// Used for building and rebuilding the ViewSequence
+impl ElementSplice<PagerElement> for PagerSplice<'_, '_> {
+    fn with_scratch<R>(&mut self, f: impl FnOnce(&mut AppendVec<PagerElement>) -> R) -> R {
+        let ret = f(self.scratch);
+        for element in self.scratch.drain() {
+            widgets::Pager::insert(&mut self.element, self.idx, element.child.new_widget, ());
+            self.idx += 1;
+        }
+        ret
+    }
+
+    fn insert(&mut self, element: PagerElement) {
+        widgets::Pager::insert(&mut self.element, self.idx, element.child.new_widget, ());
+        self.idx += 1;
+    }
+
+    fn mutate<R>(&mut self, f: impl FnOnce(Mut<'_, PagerElement>) -> R) -> R {
+        let child = PagerElementMut {
+            parent: self.element.reborrow_mut(),
+            idx: self.idx,
+        };
+        let ret = f(child);
+        self.idx += 1;
+        ret
+    }
+
+    fn skip(&mut self, n: usize) {
+        self.idx += n;
+    }
+
+    fn move_from(&mut self, n: usize) -> bool {
+        for idx in (self.idx..self.idx + n).rev() {
+            widgets::Pager::swap(&mut self.element, idx, idx + 1);
+        }
+        true
+    }
+
+    fn index(&self) -> usize {
+        self.idx
+    }
+
+    fn delete<R>(&mut self, f: impl FnOnce(Mut<'_, PagerElement>) -> R) -> R {
+        let ret = {
+            let child = PagerElementMut {
+                parent: self.element.reborrow_mut(),
+                idx: self.idx,
+            };
+            f(child)
+        };
+        widgets::Pager::remove(&mut self.element, self.idx);
+        ret
+    }
+}
+
+/// `PagerSequence` is what allows an input to the pager that contains all the pages.
+pub trait PagerSequence<State: ViewArgument, Action = ()>:
+    ViewSequence<State, Action, ViewCtx, PagerElement>
+{
+}
+
+impl<Seq, State, Action> PagerSequence<State, Action> for Seq
+where
+    Seq: ViewSequence<State, Action, ViewCtx, PagerElement>,
+    State: ViewArgument,
+{
+}
+
+/// A page within a [`Pager`] view.
+pub struct PagerElement {
+    /// The page widget.
+    child: Pod<dyn Widget>,
+}
+
+/// A mutable reference to a [`PagerElement`], used internally by Xilem traits.
+pub struct PagerElementMut<'w> {
+    parent: WidgetMut<'w, widgets::Pager>,
+    idx: usize,
+}
+
+// Used for manipulating the ViewSequence.
+struct PagerSplice<'w, 's> {
+    idx: usize,
+    element: WidgetMut<'w, widgets::Pager>,
+    scratch: &'s mut AppendVec<PagerElement>,
+}
+
+impl<'w, 's> PagerSplice<'w, 's> {
+    fn new(
+        element: WidgetMut<'w, widgets::Pager>,
+        scratch: &'s mut AppendVec<PagerElement>,
+    ) -> Self {
+        Self {
+            idx: 0,
+            element,
+            scratch,
+        }
+    }
+}
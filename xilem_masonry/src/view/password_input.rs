@@ -0,0 +1,197 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use masonry::core::{ArcStr, Widget as _};
+use masonry::widgets::{self, TextAction};
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+type Callback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, String) -> Action + Send + Sync + 'static>;
+
+/// A view which displays an editable password, with a button to reveal it.
+///
+/// Like [`text_input`](crate::view::text_input), the current password *must* be stored in your
+/// app's state, and the updated value is provided by the `on_changed` callback.
+///
+/// The password is shown as bullets until the user presses the eye button.
+/// Copying the password is disabled unless [`allow_copy`](PasswordInput::allow_copy) is set,
+/// its accessibility node is marked as protected, and the platform's IME is asked not to show
+/// predictions or learn from the text.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// # use xilem::view::password_input;
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct State {
+///     password: String,
+/// }
+///
+/// fn view(state: &mut State) -> impl WidgetView<Edit<State>> {
+///     password_input(state.password.clone(), |state: &mut State, input: String| {
+///         state.password = input;
+///     })
+///     .placeholder("Password")
+/// }
+/// ```
+pub fn password_input<F, State, Action>(
+    contents: String,
+    on_changed: F,
+) -> PasswordInput<State, Action>
+where
+    F: Fn(Arg<'_, State>, String) -> Action + Send + Sync + 'static,
+    State: ViewArgument,
+{
+    PasswordInput {
+        contents,
+        on_changed: Box::new(on_changed),
+        on_enter: None,
+        placeholder: ArcStr::default(),
+        allow_copy: false,
+        disabled: false,
+    }
+}
+
+/// The [`View`] created by [`password_input`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct PasswordInput<State: ViewArgument, Action> {
+    contents: String,
+    on_changed: Callback<State, Action>,
+    on_enter: Option<Callback<State, Action>>,
+    placeholder: ArcStr,
+    allow_copy: bool,
+    disabled: bool,
+}
+
+impl<State: ViewArgument, Action: 'static> PasswordInput<State, Action> {
+    /// Set the string which is shown when the input is empty.
+    pub fn placeholder(mut self, placeholder_text: impl Into<ArcStr>) -> Self {
+        self.placeholder = placeholder_text.into();
+        self
+    }
+
+    /// Set whether the user can copy and cut the password.
+    ///
+    /// The default value is false.
+    pub fn allow_copy(mut self, allow_copy: bool) -> Self {
+        self.allow_copy = allow_copy;
+        self
+    }
+
+    /// Set a callback that will be run when the user presses Enter <kbd>↵</kbd> to submit their input.
+    pub fn on_enter<F>(mut self, on_enter: F) -> Self
+    where
+        F: Fn(Arg<'_, State>, String) -> Action + Send + Sync + 'static,
+    {
+        self.on_enter = Some(Box::new(on_enter));
+        self
+    }
+
+    /// Set the disabled state of the widget.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl<State: ViewArgument, Action> ViewMarker for PasswordInput<State, Action> {}
+impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx>
+    for PasswordInput<State, Action>
+{
+    type Element = Pod<widgets::PasswordInput>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let text_area = widgets::TextArea::new_editable(&self.contents)
+            .with_password(true)
+            .with_password_copy(self.allow_copy);
+        let text_input = widgets::TextInput::from_text_area(text_area.with_auto_id())
+            .with_clip(true)
+            .with_placeholder(self.placeholder.clone());
+        let password_input = widgets::PasswordInput::from_text_input(text_input.with_auto_id());
+
+        // Ensure that the actions from the *inner* TextArea get routed correctly.
+        ctx.record_action_source(password_input.area_id());
+
+        let mut pod = ctx.create_pod(password_input);
+        pod.new_widget.options.disabled = self.disabled;
+        (pod, ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        _: &mut Self::ViewState,
+        _ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        if self.disabled != prev.disabled {
+            element.ctx.set_disabled(self.disabled);
+        }
+
+        let mut text_input = widgets::PasswordInput::input_mut(&mut element);
+        if self.placeholder != prev.placeholder {
+            widgets::TextInput::set_placeholder(&mut text_input, self.placeholder.clone());
+        }
+
+        let mut text_area = widgets::TextInput::text_mut(&mut text_input);
+        // As in `TextInput`, we compare to the element's text rather than to the previous value.
+        if text_area.widget.password() != Some(&self.contents) {
+            widgets::TextArea::reset_text(&mut text_area, &self.contents);
+        }
+        if self.allow_copy != prev.allow_copy {
+            widgets::TextArea::set_password_copy(&mut text_area, self.allow_copy);
+        }
+    }
+
+    fn teardown(
+        &self,
+        _: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        let mut text_input = widgets::PasswordInput::input_mut(&mut element);
+        ctx.teardown_action_source(widgets::TextInput::text_mut(&mut text_input));
+    }
+
+    fn message(
+        &self,
+        _: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        debug_assert!(
+            message.remaining_path().is_empty(),
+            "id path should be empty in PasswordInput::message"
+        );
+        match message.take_message::<TextAction>() {
+            Some(action) => match *action {
+                TextAction::Changed(text) => {
+                    MessageResult::Action((self.on_changed)(app_state, text))
+                }
+                TextAction::Entered(text) if self.on_enter.is_some() => {
+                    MessageResult::Action((self.on_enter.as_ref().unwrap())(app_state, text))
+                }
+                TextAction::Entered(_) => {
+                    tracing::error!("PasswordInput::message: on_enter is not set");
+                    MessageResult::Stale
+                }
+                TextAction::FocusChanged(_) | TextAction::SelectionChanged(_) => {
+                    tracing::error!("PasswordInput::message: focus and selection aren't reported");
+                    MessageResult::Stale
+                }
+            },
+            None => {
+                tracing::error!(?message, "Wrong message type in PasswordInput::message");
+                MessageResult::Stale
+            }
+        }
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::ViewCtx;
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+
+/// A view which persists a part of the app state across app restarts, under a stable `key`.
+///
+/// When this view is built, the value persisted under `key` (if any) is restored into the
+/// part of the state returned by `access_state`, and `component` is called to create the
+/// child view.
+/// After each rebuild, the current value is persisted again, and is saved when the app exits.
+///
+/// This is meant for state which users expect to find as they left it, such as scroll
+/// offsets, split positions, expanded tree nodes or window geometry, and which the app
+/// already keeps in its state (e.g. with the `on_change` callbacks of views).
+/// Keys must be unique within the app: this view doesn't take its position in the view
+/// tree into account, so that moving it doesn't lose the persisted value.
+///
+/// Nothing is persisted unless the app was given a [`Persistence`] store; see the
+/// [`persistence`](crate::persistence) module for details.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::view::{checkbox, persist};
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct State {
+///     show_hidden_files: bool,
+/// }
+///
+/// fn files_view(state: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+///     checkbox(
+///         "Show hidden files",
+///         state.show_hidden_files,
+///         |state: &mut State, checked| state.show_hidden_files = checked,
+///     )
+/// }
+///
+/// # fn view() -> impl WidgetView<Edit<State>> {
+/// persist("show_hidden_files", files_view, |state: &mut State, ()| {
+///     &mut state.show_hidden_files
+/// })
+/// # }
+/// ```
+///
+/// [`Persistence`]: crate::persistence::Persistence
+pub fn persist<State, Action, T, StateF, InnerView, Component>(
+    key: impl Into<String>,
+    component: Component,
+    access_state: StateF,
+) -> Persist<Component, StateF, State, T>
+where
+    State: ViewArgument,
+    T: Serialize + DeserializeOwned + 'static,
+    Component: Fn(Arg<'_, State>) -> InnerView + 'static,
+    StateF: (for<'a> Fn(Arg<'a, State>, &'a ()) -> &'a mut T) + 'static,
+    InnerView: View<State, Action, ViewCtx>,
+{
+    Persist {
+        key: key.into(),
+        component,
+        access_state,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`persist`].
+///
+/// See its documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Persist<Component, StateF, State, T> {
+    key: String,
+    component: Component,
+    access_state: StateF,
+    phantom: PhantomData<fn(State) -> T>,
+}
+
+impl<Component, StateF, State, T> Persist<Component, StateF, State, T>
+where
+    State: ViewArgument,
+    T: Serialize + DeserializeOwned + 'static,
+    StateF: for<'a> Fn(Arg<'a, State>, &'a ()) -> &'a mut T,
+{
+    /// Persists the current value.
+    fn store(&self, ctx: &ViewCtx, app_state: Arg<'_, State>) {
+        if let Some(persistence) = ctx.persistence() {
+            persistence.set(&self.key, &*(self.access_state)(app_state, &()));
+        }
+    }
+}
+
+impl<Component, StateF, State, T> ViewMarker for Persist<Component, StateF, State, T> {}
+impl<Component, StateF, State, Action, T, InnerView> View<State, Action, ViewCtx>
+    for Persist<Component, StateF, State, T>
+where
+    State: ViewArgument,
+    Action: 'static,
+    T: Serialize + DeserializeOwned + 'static,
+    Component: Fn(Arg<'_, State>) -> InnerView + 'static,
+    StateF: (for<'a> Fn(Arg<'a, State>, &'a ()) -> &'a mut T) + 'static,
+    InnerView: View<State, Action, ViewCtx>,
+{
+    type Element = InnerView::Element;
+    type ViewState = (InnerView, InnerView::ViewState);
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        mut app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        if let Some(value) = ctx
+            .persistence()
+            .and_then(|persistence| persistence.get::<T>(&self.key))
+        {
+            *(self.access_state)(State::reborrow_mut(&mut app_state), &()) = value;
+        }
+        let child = (self.component)(State::reborrow_mut(&mut app_state));
+        let (element, child_state) = child.build(ctx, State::reborrow_mut(&mut app_state));
+        self.store(ctx, app_state);
+        (element, (child, child_state))
+    }
+
+    fn rebuild(
+        &self,
+        _prev: &Self,
+        (child, child_state): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+        mut app_state: Arg<'_, State>,
+    ) {
+        let new_child = (self.component)(State::reborrow_mut(&mut app_state));
+        new_child.rebuild(
+            child,
+            child_state,
+            ctx,
+            element,
+            State::reborrow_mut(&mut app_state),
+        );
+        *child = new_child;
+        self.store(ctx, app_state);
+    }
+
+    fn teardown(
+        &self,
+        (child, child_state): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        child.teardown(child_state, ctx, element);
+    }
+
+    fn message(
+        &self,
+        (child, child_state): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        // The new value is persisted in the rebuild which follows the state change.
+        child.message(child_state, message, element, app_state)
+    }
+}
@@ -1,16 +1,31 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::any::type_name;
 use std::marker::PhantomData;
+use std::time::Duration;
 
-use masonry::widgets;
+use masonry::core::ScrollConfig;
+use masonry::kurbo::{Axis, Point};
+use masonry::util::Easing;
+use masonry::widgets::{self, PortalAction, ScrollProgress};
 
-use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
 use crate::{Pod, ViewCtx, WidgetView};
 
+type Callback<State, Action> = Box<dyn Fn(Arg<'_, State>) -> Action + Send + Sync + 'static>;
+type ScrollCallback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, ScrollProgress) -> Action + Send + Sync + 'static>;
+
 /// A view which puts `child` into a scrollable region.
 ///
 /// This corresponds to the Masonry [`Portal`](masonry::widgets::Portal) widget.
+///
+/// To give feedback when the user scrolls past the bounds of `child`, set the
+/// [`OverscrollEffect`](masonry::properties::OverscrollEffect) property with
+/// [`prop`](WidgetView::prop).
 pub fn portal<Child, State, Action>(child: Child) -> Portal<Child, State, Action>
 where
     State: ViewArgument,
@@ -24,13 +39,20 @@ where
         must_fill: false,
         // --- MARK: Modified ---
         right_to_left: false,
+        on_scroll_near_end: None,
+        on_scroll: None,
+        focus_scroll_margin: 0.,
+        smooth_scroll: None,
+        locked_axis: None,
+        page_fraction: 1.,
+        scroll_config: None,
         phantom: PhantomData,
     }
 }
 
 /// The [`View`] created by [`portal`].
 #[must_use = "View values do nothing unless provided to Xilem."]
-pub struct Portal<V, State, Action> {
+pub struct Portal<V, State: ViewArgument, Action> {
     child: V,
     // --- MARK: Modified ---
     constrain_horizontal: bool,
@@ -40,11 +62,27 @@ pub struct Portal<V, State, Action> {
     /// The direction of the app language. If it's right to left,
     /// the vertical scrollbar will be placed at the left side of the portal.
     right_to_left: bool,
+    on_scroll_near_end: Option<(f64, Callback<State, Action>)>,
+    on_scroll: Option<ScrollCallback<State, Action>>,
+    focus_scroll_margin: f64,
+    smooth_scroll: Option<SmoothScroll>,
+    locked_axis: Option<Axis>,
+    page_fraction: f64,
+    scroll_config: Option<ScrollConfig>,
     phantom: PhantomData<fn(State) -> Action>,
 }
 
+/// A request to smoothly scroll a [`Portal`], see [`Portal::smooth_scroll_to`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct SmoothScroll {
+    request: u64,
+    position: Point,
+    duration: Duration,
+    easing: Easing,
+}
+
 // --- MARK: Modified ---
-impl<V, State, Action> Portal<V, State, Action> {
+impl<V, State: ViewArgument, Action> Portal<V, State, Action> {
     /// Builder-style method for deciding whether to constrain the child vertically.
     ///
     /// The default is `false`.
@@ -89,9 +127,162 @@ impl<V, State, Action> Portal<V, State, Action> {
         self.right_to_left = right_to_left;
         self
     }
+
+    /// Locks user scrolling along `axis`.
+    ///
+    /// The default is `None`, which lets the user scroll along both axes at once,
+    /// e.g. to move around a spreadsheet.
+    /// This is useful to temporarily restrict scrolling, e.g. while a row is being dragged.
+    ///
+    /// See [`Portal::lock_axis`](widgets::Portal::lock_axis) for details.
+    pub fn lock_axis(mut self, axis: Option<Axis>) -> Self {
+        self.locked_axis = axis;
+        self
+    }
+
+    /// Sets a callback which is called when the remaining scroll distance drops to
+    /// `threshold` or below.
+    ///
+    /// This is useful for infinite scrolling, e.g. to load the next page of a feed.
+    /// The callback is called once per crossing: it's called again only after the
+    /// remaining distance has grown above `threshold`, typically because more items
+    /// were appended, and then dropped below it again.
+    ///
+    /// See [`Portal::near_end_threshold`](widgets::Portal::near_end_threshold) for details.
+    pub fn on_scroll_near_end<F>(mut self, threshold: f64, callback: F) -> Self
+    where
+        F: Fn(Arg<'_, State>) -> Action + Send + Sync + 'static,
+    {
+        self.on_scroll_near_end = Some((threshold, Box::new(callback)));
+        self
+    }
+
+    /// Sets a callback which is called with the [`ScrollProgress`] whenever the viewport
+    /// moves, or the content is resized.
+    ///
+    /// This makes it possible to drive scroll-linked effects from the app state,
+    /// such as a header which collapses as the user scrolls down, a parallax background
+    /// or a reading progress bar.
+    /// The callback is also called once the portal has been laid out for the first time,
+    /// so that these effects start from the right position.
+    ///
+    /// See [`Portal::report_scroll_progress`](widgets::Portal::report_scroll_progress)
+    /// for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// use xilem::WidgetView;
+    /// use xilem::core::Edit;
+    /// use xilem::view::{flex_col, label, portal, progress_bar};
+    ///
+    /// fn article(reading_progress: &mut f64) -> impl WidgetView<Edit<f64>> + use<> {
+    ///     flex_col((
+    ///         progress_bar(Some(*reading_progress)),
+    ///         portal(label("A very long article...")).on_scroll(
+    ///             |reading_progress: &mut f64, scroll| {
+    ///                 *reading_progress = scroll.vertical();
+    ///             },
+    ///         ),
+    ///     ))
+    /// }
+    /// ```
+    pub fn on_scroll<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Arg<'_, State>, ScrollProgress) -> Action + Send + Sync + 'static,
+    {
+        self.on_scroll = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the space kept around a descendant focused with the keyboard, when the portal
+    /// scrolls it into view.
+    ///
+    /// The default is 0.
+    pub fn focus_scroll_margin(mut self, margin: f64) -> Self {
+        self.focus_scroll_margin = margin;
+        self
+    }
+
+    /// Sets the fraction of the viewport scrolled by a page, with PageUp/PageDown, Space,
+    /// or a click on the track of a scrollbar.
+    ///
+    /// The default is 1, which scrolls by a whole viewport.
+    pub fn page_fraction(mut self, fraction: f64) -> Self {
+        self.page_fraction = fraction;
+        self
+    }
+
+    /// Sets how mouse wheels and touchpads scroll the portal.
+    ///
+    /// The default is `None`, which uses the configuration of the window.
+    pub fn scroll_config(mut self, config: Option<ScrollConfig>) -> Self {
+        self.scroll_config = config;
+        self
+    }
+
+    /// Smoothly scrolls the portal to `position`, over `duration`.
+    ///
+    /// A scroll is started every time `request` differs from the one of the previous rebuild,
+    /// so incrementing a counter in the app state starts one.
+    /// Building the view doesn't scroll, whatever the initial value.
+    ///
+    /// The scroll is canceled when the user scrolls.
+    /// See [`Portal::smooth_scroll_to`](widgets::Portal::smooth_scroll_to) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// use std::time::Duration;
+    ///
+    /// use xilem::WidgetView;
+    /// use xilem::core::Edit;
+    /// use xilem::masonry::kurbo::Point;
+    /// use xilem::masonry::util::Easing;
+    /// use xilem::view::{flex_col, label, portal, text_button};
+    ///
+    /// fn feed(back_to_top: &mut u64) -> impl WidgetView<Edit<u64>> + use<> {
+    ///     flex_col((
+    ///         text_button("Back to top", |back_to_top: &mut u64| *back_to_top += 1),
+    ///         portal(label("A long feed...")).smooth_scroll_to(
+    ///             *back_to_top,
+    ///             Point::ORIGIN,
+    ///             Duration::from_millis(300),
+    ///             Easing::EaseInOut,
+    ///         ),
+    ///     ))
+    /// }
+    /// ```
+    pub fn smooth_scroll_to(
+        mut self,
+        request: u64,
+        position: Point,
+        duration: Duration,
+        easing: Easing,
+    ) -> Self {
+        self.smooth_scroll = Some(SmoothScroll {
+            request,
+            position,
+            duration,
+            easing,
+        });
+        self
+    }
+
+    fn near_end_threshold(&self) -> Option<f64> {
+        self.on_scroll_near_end
+            .as_ref()
+            .map(|(threshold, _)| *threshold)
+    }
 }
 
-impl<V, State, Action> ViewMarker for Portal<V, State, Action> {}
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 3254708128 in decimal.
+const PORTAL_CONTENT_VIEW_ID: ViewId = ViewId::new(0xc1fe3ba0);
+
+impl<V, State: ViewArgument, Action> ViewMarker for Portal<V, State, Action> {}
 impl<Child, State, Action> View<State, Action, ViewCtx> for Portal<Child, State, Action>
 where
     Child: WidgetView<State, Action>,
@@ -106,16 +297,24 @@ where
         ctx: &mut ViewCtx,
         app_state: Arg<'_, State>,
     ) -> (Self::Element, Self::ViewState) {
-        // The Portal `View` doesn't get any messages directly (yet - scroll events?), so doesn't need to
-        // use ctx.with_id.
-        let (child, child_state) = self.child.build(ctx, app_state);
-        let widget_pod = ctx.create_pod(
-            widgets::Portal::new(child.new_widget)
-                .constrain_horizontal(self.constrain_horizontal)
-                .constrain_vertical(self.constrain_vertical)
-                .content_must_fill(self.must_fill)
-                .with_rtl(self.right_to_left)
-        );
+        let (child, child_state) = ctx.with_id(PORTAL_CONTENT_VIEW_ID, |ctx| {
+            self.child.build(ctx, app_state)
+        });
+        let widget_pod = ctx.with_action_widget(|ctx| {
+            ctx.create_pod(
+                widgets::Portal::new(child.new_widget)
+                    .constrain_horizontal(self.constrain_horizontal)
+                    .constrain_vertical(self.constrain_vertical)
+                    .content_must_fill(self.must_fill)
+                    .with_rtl(self.right_to_left)
+                    .near_end_threshold(self.near_end_threshold())
+                    .report_scroll_progress(self.on_scroll.is_some())
+                    .focus_scroll_margin(self.focus_scroll_margin)
+                    .lock_axis(self.locked_axis)
+                    .page_fraction(self.page_fraction)
+                    .scroll_config(self.scroll_config),
+            )
+        });
         (widget_pod, child_state)
     }
 
@@ -136,10 +335,40 @@ where
         if self.must_fill != prev.must_fill {
             widgets::Portal::set_content_must_fill(&mut element, self.must_fill);
         }
-        
-        let child_element = widgets::Portal::child_mut(&mut element);
-        self.child
-            .rebuild(&prev.child, view_state, ctx, child_element, app_state);
+        if self.near_end_threshold() != prev.near_end_threshold() {
+            widgets::Portal::set_near_end_threshold(&mut element, self.near_end_threshold());
+        }
+        if self.on_scroll.is_some() != prev.on_scroll.is_some() {
+            widgets::Portal::set_report_scroll_progress(&mut element, self.on_scroll.is_some());
+        }
+        if self.focus_scroll_margin != prev.focus_scroll_margin {
+            widgets::Portal::set_focus_scroll_margin(&mut element, self.focus_scroll_margin);
+        }
+        if self.locked_axis != prev.locked_axis {
+            widgets::Portal::set_locked_axis(&mut element, self.locked_axis);
+        }
+        if self.page_fraction != prev.page_fraction {
+            widgets::Portal::set_page_fraction(&mut element, self.page_fraction);
+        }
+        if self.scroll_config != prev.scroll_config {
+            widgets::Portal::set_scroll_config(&mut element, self.scroll_config);
+        }
+        if let Some(scroll) = self.smooth_scroll
+            && prev.smooth_scroll.map(|prev| prev.request) != Some(scroll.request)
+        {
+            widgets::Portal::smooth_scroll_to(
+                &mut element,
+                scroll.position,
+                scroll.duration,
+                scroll.easing,
+            );
+        }
+
+        ctx.with_id(PORTAL_CONTENT_VIEW_ID, |ctx| {
+            let child_element = widgets::Portal::child_mut(&mut element);
+            self.child
+                .rebuild(&prev.child, view_state, ctx, child_element, app_state);
+        });
     }
 
     fn teardown(
@@ -148,8 +377,11 @@ where
         ctx: &mut ViewCtx,
         mut element: Mut<'_, Self::Element>,
     ) {
-        let child_element = widgets::Portal::child_mut(&mut element);
-        self.child.teardown(view_state, ctx, child_element);
+        ctx.with_id(PORTAL_CONTENT_VIEW_ID, |ctx| {
+            let child_element = widgets::Portal::child_mut(&mut element);
+            self.child.teardown(view_state, ctx, child_element);
+        });
+        ctx.teardown_action_source(element);
     }
 
     fn message(
@@ -157,10 +389,39 @@ where
         view_state: &mut Self::ViewState,
         message: &mut MessageCtx,
         mut element: Mut<'_, Self::Element>,
-        app_state: Arg<'_, State>,
+        mut app_state: Arg<'_, State>,
     ) -> MessageResult<Action> {
-        let child_element = widgets::Portal::child_mut(&mut element);
-        self.child
-            .message(view_state, message, child_element, app_state)
+        match message.take_first() {
+            Some(PORTAL_CONTENT_VIEW_ID) => {
+                let child_element = widgets::Portal::child_mut(&mut element);
+                self.child.message(
+                    view_state,
+                    message,
+                    child_element,
+                    State::reborrow_mut(&mut app_state),
+                )
+            }
+            None => match message.take_message::<PortalAction>().as_deref() {
+                Some(PortalAction::NearEnd) => match &self.on_scroll_near_end {
+                    Some((_, callback)) => MessageResult::Action(callback(app_state)),
+                    None => MessageResult::Nop,
+                },
+                Some(PortalAction::Scrolled(progress)) => match &self.on_scroll {
+                    Some(callback) => MessageResult::Action(callback(app_state, *progress)),
+                    None => MessageResult::Nop,
+                },
+                None => {
+                    tracing::error!(
+                        "Wrong message type in Portal::message: {message:?} expected {}",
+                        type_name::<PortalAction>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!(?message, "Got unexpected id path in `Portal::message`.");
+                MessageResult::Stale
+            }
+        }
     }
 }
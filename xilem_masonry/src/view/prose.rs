@@ -6,7 +6,7 @@ use std::marker::PhantomData;
 use masonry::core::{ArcStr, NewWidget, Properties, StyleProperty};
 use masonry::parley::FontWeight;
 use masonry::properties::{ContentColor, DisabledContentColor, LineBreaking};
-use masonry::widgets;
+use masonry::widgets::{self, DecorationSpan};
 
 use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
 use crate::{Color, Pod, TextAlign, ViewCtx};
@@ -21,6 +21,7 @@ pub fn prose<State, Action>(content: impl Into<ArcStr>) -> Prose<State, Action>
         text_size: masonry::theme::TEXT_SIZE_NORMAL,
         line_break_mode: LineBreaking::WordWrap,
         weight: FontWeight::NORMAL,
+        decorations: Vec::new(),
         phantom: PhantomData,
     }
 }
@@ -46,6 +47,7 @@ pub struct Prose<State, Action> {
     text_size: f32,
     line_break_mode: LineBreaking,
     weight: FontWeight,
+    decorations: Vec<DecorationSpan>,
     phantom: PhantomData<fn(State) -> Action>,
     // TODO: disabled: bool,
     // TODO: add more attributes of `masonry::widgets::Prose`
@@ -92,6 +94,34 @@ impl<State, Action> Prose<State, Action> {
         self.weight = weight;
         self
     }
+
+    /// Sets the [decorations](widgets::TextDecoration) painted over ranges of the text,
+    /// such as search highlights.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// use xilem::masonry::widgets::{DecorationSpan, TextDecoration};
+    /// use xilem::masonry::palette::css::GOLD;
+    /// use xilem::view::prose;
+    /// # use xilem::WidgetView;
+    /// # use xilem::core::Edit;
+    ///
+    /// # fn view(text: &str, search: &str) -> impl WidgetView<Edit<()>> + use<> {
+    /// let highlights = text
+    ///     .match_indices(search)
+    ///     .map(|(start, found)| {
+    ///         DecorationSpan::new(start..start + found.len(), TextDecoration::Highlight(GOLD))
+    ///     })
+    ///     .collect();
+    /// prose(text.to_string()).decorations(highlights)
+    /// # }
+    /// ```
+    pub fn decorations(mut self, decorations: Vec<DecorationSpan>) -> Self {
+        self.decorations = decorations;
+        self
+    }
 }
 
 fn line_break_clips(linebreaking: LineBreaking) -> bool {
@@ -108,7 +138,8 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx> for Pros
             .with_text_alignment(self.text_alignment)
             .with_style(StyleProperty::FontSize(self.text_size))
             .with_style(StyleProperty::FontWeight(self.weight))
-            .with_word_wrap(self.line_break_mode == LineBreaking::WordWrap);
+            .with_word_wrap(self.line_break_mode == LineBreaking::WordWrap)
+            .with_decorations(self.decorations.clone());
 
         // TODO - Replace this with properties on the Prose view
         // once we implement property inheritance or something like it.
@@ -169,6 +200,9 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx> for Pros
         if prev.weight != self.weight {
             widgets::TextArea::insert_style(&mut text_area, StyleProperty::FontWeight(self.weight));
         }
+        if prev.decorations != self.decorations {
+            widgets::TextArea::set_decorations(&mut text_area, self.decorations.clone());
+        }
         if prev.line_break_mode != self.line_break_mode {
             widgets::TextArea::set_word_wrap(
                 &mut text_area,
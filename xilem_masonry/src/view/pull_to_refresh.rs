@@ -0,0 +1,204 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::type_name;
+use std::marker::PhantomData;
+
+use masonry::properties::Dimensions;
+use masonry::widgets::{self, RefreshRequested};
+
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A view which lets users refresh its content by pulling it down past the top.
+///
+/// `inner` is usually a [`portal`](crate::view::portal).
+/// When the user pulls the content past the threshold, `on_refresh` is called and the
+/// refresh indicator is shown until `refreshing` is `false` again.
+/// The callback should usually set the state `refreshing` is derived from to `true`,
+/// and start loading the new content.
+///
+/// See the documentation on the underlying [`PullToRefresh`](widgets::PullToRefresh) for more information.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// # use xilem::{WidgetView, core::Edit, view::{pull_to_refresh, portal, label}};
+///
+/// struct State {
+///     refreshing: bool,
+/// }
+///
+/// # fn my_component(state: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+/// pull_to_refresh(
+///     state.refreshing,
+///     |state: &mut State| {
+///         state.refreshing = true;
+///         // Start fetching new items, and set `refreshing` back to false once done...
+///     },
+///     portal(label("Feed")),
+/// )
+/// # }
+/// ```
+pub fn pull_to_refresh<State, Action, V, F>(
+    refreshing: bool,
+    on_refresh: F,
+    inner: V,
+) -> PullToRefresh<V, F, State, Action>
+where
+    V: WidgetView<State, Action>,
+    F: Fn(Arg<'_, State>) -> Action,
+    State: ViewArgument,
+    PullToRefresh<V, F, State, Action>: WidgetView<State, Action>,
+{
+    PullToRefresh {
+        inner,
+        on_refresh,
+        refreshing,
+        threshold: None,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`pull_to_refresh`].
+///
+/// See `pull_to_refresh` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct PullToRefresh<V, F, State, Action = ()> {
+    inner: V,
+    on_refresh: F,
+    refreshing: bool,
+    threshold: Option<f64>,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, F, State, Action> PullToRefresh<V, F, State, Action> {
+    /// Sets how far the content must be pulled down to request a refresh.
+    ///
+    /// See [`PullToRefresh::threshold`](widgets::PullToRefresh::threshold) for the default.
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+}
+
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 1186976845 in decimal.
+const PULL_TO_REFRESH_CONTENT_VIEW_ID: ViewId = ViewId::new(0x46bf8a4d);
+
+impl<V, F, State, Action> ViewMarker for PullToRefresh<V, F, State, Action> {}
+impl<V, F, State, Action> View<State, Action, ViewCtx> for PullToRefresh<V, F, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    F: 'static,
+    V: WidgetView<State, Action>,
+    F: Fn(Arg<'_, State>) -> Action,
+{
+    type Element = Pod<widgets::PullToRefresh>;
+    type ViewState = V::ViewState;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = ctx.with_id(PULL_TO_REFRESH_CONTENT_VIEW_ID, |ctx| {
+            self.inner.build(ctx, app_state)
+        });
+        (
+            ctx.with_action_widget(|_| {
+                let mut widget =
+                    widgets::PullToRefresh::new(child.new_widget).refreshing(self.refreshing);
+                if let Some(threshold) = self.threshold {
+                    widget = widget.threshold(threshold);
+                }
+                Pod::new_with_props(widget, Dimensions::MAX)
+            }),
+            child_state,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        ctx.with_id(PULL_TO_REFRESH_CONTENT_VIEW_ID, |ctx| {
+            View::<State, Action, _>::rebuild(
+                &self.inner,
+                &prev.inner,
+                view_state,
+                ctx,
+                widgets::PullToRefresh::child_mut(&mut element).downcast(),
+                app_state,
+            );
+        });
+        if self.threshold != prev.threshold
+            && let Some(threshold) = self.threshold
+        {
+            widgets::PullToRefresh::set_threshold(&mut element, threshold);
+        }
+        // The widget starts refreshing on its own, so we compare with its state.
+        if self.refreshing != element.widget.is_refreshing() {
+            widgets::PullToRefresh::set_refreshing(&mut element, self.refreshing);
+        }
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(PULL_TO_REFRESH_CONTENT_VIEW_ID, |ctx| {
+            View::<State, Action, _>::teardown(
+                &self.inner,
+                view_state,
+                ctx,
+                widgets::PullToRefresh::child_mut(&mut element).downcast(),
+            );
+        });
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        mut app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(PULL_TO_REFRESH_CONTENT_VIEW_ID) => self.inner.message(
+                view_state,
+                message,
+                widgets::PullToRefresh::child_mut(&mut element).downcast(),
+                State::reborrow_mut(&mut app_state),
+            ),
+            None => match message.take_message::<RefreshRequested>() {
+                Some(_) => MessageResult::Action((self.on_refresh)(app_state)),
+                None => {
+                    tracing::error!(
+                        "Wrong message type in PullToRefresh::message: {message:?} expected {}",
+                        type_name::<RefreshRequested>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!(
+                    ?message,
+                    "Got unexpected id path in `PullToRefresh::message`."
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
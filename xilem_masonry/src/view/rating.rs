@@ -0,0 +1,269 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::kurbo::BezPath;
+use masonry::widgets;
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+/// The number of stars of a rating, unless [another count](Rating::count) is chosen.
+const DEFAULT_COUNT: usize = 5;
+
+/// A row of stars for choosing a rating between zero and five.
+///
+/// `on_change` is called with the new rating when the user clicks a star or uses the
+/// arrow keys. Use [`rating_display`] to only show a rating.
+///
+/// The underlying widget is the Masonry [`Rating`](widgets::Rating).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// # use xilem::{view::rating, WidgetView};
+/// # use xilem::core::Edit;
+/// struct Review {
+///     stars: f64,
+/// }
+///
+/// fn stars(review: &mut Review) -> impl WidgetView<Edit<Review>> + use<> {
+///     rating(review.stars, |review: &mut Review, stars| review.stars = stars).half_steps(true)
+/// }
+/// ```
+pub fn rating<State, Action, F>(value: f64, on_change: F) -> Rating<State, Action, F>
+where
+    State: ViewArgument,
+    F: Fn(Arg<'_, State>, f64) -> Action + Send + Sync + 'static,
+{
+    Rating {
+        value,
+        on_change,
+        count: DEFAULT_COUNT,
+        half_steps: false,
+        icon: None,
+        disabled: false,
+        phantom: PhantomData,
+    }
+}
+
+/// A read-only row of stars showing a rating between zero and five, such as an average rating.
+///
+/// Partial stars are shown for fractional ratings.
+pub fn rating_display(value: f64) -> RatingDisplay {
+    RatingDisplay {
+        value,
+        count: DEFAULT_COUNT,
+        icon: None,
+    }
+}
+
+/// The [`View`] created by [`rating`].
+///
+/// See `rating` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Rating<State, Action, F> {
+    value: f64,
+    on_change: F,
+    count: usize,
+    half_steps: bool,
+    icon: Option<BezPath>,
+    disabled: bool,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<State, Action, F> Rating<State, Action, F> {
+    /// Set the number of stars, which is also the highest rating.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Set whether half stars can be chosen.
+    pub fn half_steps(mut self, half_steps: bool) -> Self {
+        self.half_steps = half_steps;
+        self
+    }
+
+    /// Show the given icon instead of stars.
+    ///
+    /// The icon is scaled from a unit square, i.e. from `(0, 0)` to `(1, 1)`.
+    pub fn icon(mut self, icon: BezPath) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Set whether the rating is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// The [`View`] created by [`rating_display`].
+///
+/// See `rating_display` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct RatingDisplay {
+    value: f64,
+    count: usize,
+    icon: Option<BezPath>,
+}
+
+impl RatingDisplay {
+    /// Set the number of stars, which is also the highest rating.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Show the given icon instead of stars.
+    ///
+    /// The icon is scaled from a unit square, i.e. from `(0, 0)` to `(1, 1)`.
+    pub fn icon(mut self, icon: BezPath) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+}
+
+fn new_widget(value: f64, count: usize, icon: Option<&BezPath>) -> widgets::Rating {
+    let widget = widgets::Rating::new(count, value);
+    match icon {
+        Some(icon) => widget.with_icon(icon.clone()),
+        None => widget,
+    }
+}
+
+fn rebuild_widget(
+    element: &mut Mut<'_, Pod<widgets::Rating>>,
+    (value, count, icon): (f64, usize, Option<&BezPath>),
+    (prev_value, prev_count, prev_icon): (f64, usize, Option<&BezPath>),
+) {
+    if prev_count != count {
+        widgets::Rating::set_count(element, count);
+    }
+    if prev_value != value {
+        widgets::Rating::set_value(element, value);
+    }
+    if prev_icon != icon {
+        widgets::Rating::set_icon(element, icon.cloned());
+    }
+}
+
+impl<State, Action, F> ViewMarker for Rating<State, Action, F> {}
+impl<State, Action, F> View<State, Action, ViewCtx> for Rating<State, Action, F>
+where
+    State: ViewArgument,
+    Action: 'static,
+    F: Fn(Arg<'_, State>, f64) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::Rating>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        (
+            ctx.with_action_widget(|ctx| {
+                let widget = new_widget(self.value, self.count, self.icon.as_ref())
+                    .with_half_steps(self.half_steps);
+                let mut pod = ctx.create_pod(widget);
+                pod.new_widget.options.disabled = self.disabled;
+                pod
+            }),
+            (),
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        if prev.disabled != self.disabled {
+            element.ctx.set_disabled(self.disabled);
+        }
+        if prev.half_steps != self.half_steps {
+            widgets::Rating::set_half_steps(&mut element, self.half_steps);
+        }
+        rebuild_widget(
+            &mut element,
+            (self.value, self.count, self.icon.as_ref()),
+            (prev.value, prev.count, prev.icon.as_ref()),
+        );
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        if message.take_first().is_some() {
+            tracing::warn!("Got unexpected id path in Rating::message");
+            return MessageResult::Stale;
+        }
+        match message.take_message::<f64>() {
+            Some(value) => MessageResult::Action((self.on_change)(app_state, *value)),
+            None => {
+                tracing::error!("Wrong message type in Rating::message: {message:?}, expected f64");
+                MessageResult::Stale
+            }
+        }
+    }
+}
+
+impl ViewMarker for RatingDisplay {}
+impl<State: ViewArgument, Action> View<State, Action, ViewCtx> for RatingDisplay {
+    type Element = Pod<widgets::Rating>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let widget = new_widget(self.value, self.count, self.icon.as_ref()).with_read_only(true);
+        (ctx.create_pod(widget), ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        rebuild_widget(
+            &mut element,
+            (self.value, self.count, self.icon.as_ref()),
+            (prev.value, prev.count, prev.icon.as_ref()),
+        );
+    }
+
+    fn teardown(&self, (): &mut Self::ViewState, _: &mut ViewCtx, _: Mut<'_, Self::Element>) {}
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        tracing::error!(
+            ?message,
+            "Message arrived in RatingDisplay::message, but RatingDisplay doesn't consume any messages, this is a bug."
+        );
+        MessageResult::Stale
+    }
+}
@@ -4,7 +4,7 @@
 use std::any::type_name;
 use std::marker::PhantomData;
 
-use masonry::kurbo::Size;
+use masonry::kurbo::{Rect, Size};
 use masonry::properties::Dimensions;
 use masonry::widgets::{self, LayoutChanged};
 
@@ -189,3 +189,168 @@ where
         }
     }
 }
+
+/// A view which calls `on_size_changed` with the layout rectangle of its child in the window,
+/// whenever the child's size or position in the window changes.
+///
+/// `on_size_changed` is a function which takes the app's state and a [`Rect`], whose
+/// size is the size of the view, and whose origin is the view's position in the window's
+/// coordinate space.
+/// It is called at most once per layout pass, including when the view moves because an
+/// ancestor scrolled.
+/// Note that the rectangle doesn't account for ancestors being scaled or rotated.
+///
+/// This is useful for logic which depends on where views end up, such as drawing
+/// connectors between views in an overlay.
+/// This is usually created with [`WidgetView::on_size_changed`].
+///
+/// The same caveats as for [`resize_observer`] apply: using the reported rectangle to change
+/// the view's own size may never reach a steady state.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// # use xilem::{WidgetView, core::Edit, view::label};
+/// # use xilem::masonry::kurbo::Rect;
+/// struct State {
+///     anchor: Rect,
+/// }
+///
+/// # fn my_component(state: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+/// label("Anchor").on_size_changed(|state: &mut State, rect| state.anchor = rect)
+/// # }
+/// ```
+pub fn on_size_changed<State, Action, V, F>(
+    inner: V,
+    on_size_changed: F,
+) -> OnSizeChanged<V, F, State, Action>
+where
+    V: WidgetView<State, Action>,
+    F: Fn(Arg<'_, State>, Rect) -> Action,
+    State: ViewArgument,
+{
+    OnSizeChanged {
+        inner,
+        on_size_changed,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`on_size_changed`].
+///
+/// See `on_size_changed` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct OnSizeChanged<V, F, State, Action = ()> {
+    inner: V,
+    on_size_changed: F,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, F, State, Action> ViewMarker for OnSizeChanged<V, F, State, Action> {}
+impl<V, F, State, Action> View<State, Action, ViewCtx> for OnSizeChanged<V, F, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    F: 'static,
+    V: WidgetView<State, Action>,
+    F: Fn(Arg<'_, State>, Rect) -> Action,
+{
+    type Element = Pod<widgets::ResizeObserver>;
+    type ViewState = V::ViewState;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = ctx.with_id(RESIZE_OBSERVER_CONTENT_VIEW_ID, |ctx| {
+            self.inner.build(ctx, app_state)
+        });
+        (
+            ctx.with_action_widget(|_| {
+                let widget =
+                    widgets::ResizeObserver::new(child.new_widget).with_position_tracking(true);
+                Pod::new_with_props(widget, Dimensions::MAX)
+            }),
+            child_state,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        ctx.with_id(RESIZE_OBSERVER_CONTENT_VIEW_ID, |ctx| {
+            View::<State, Action, _>::rebuild(
+                &self.inner,
+                &prev.inner,
+                view_state,
+                ctx,
+                widgets::ResizeObserver::child_mut(&mut element).downcast(),
+                app_state,
+            );
+        });
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(RESIZE_OBSERVER_CONTENT_VIEW_ID, |ctx| {
+            View::<State, Action, _>::teardown(
+                &self.inner,
+                view_state,
+                ctx,
+                widgets::ResizeObserver::child_mut(&mut element).downcast(),
+            );
+        });
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        mut app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(RESIZE_OBSERVER_CONTENT_VIEW_ID) => self.inner.message(
+                view_state,
+                message,
+                widgets::ResizeObserver::child_mut(&mut element).downcast(),
+                State::reborrow_mut(&mut app_state),
+            ),
+            None => match message.take_message::<LayoutChanged>() {
+                Some(_) => {
+                    let rect = Rect::from_origin_size(
+                        element.ctx.window_origin(),
+                        element.ctx.border_box_size(),
+                    );
+                    MessageResult::Action((self.on_size_changed)(app_state, rect))
+                }
+                None => {
+                    tracing::error!(
+                        "Wrong message type in OnSizeChanged::message: {message:?} expected {}",
+                        type_name::<LayoutChanged>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!(
+                    ?message,
+                    "Got unexpected id path in `OnSizeChanged::message`."
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
@@ -0,0 +1,273 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::type_name;
+use std::marker::PhantomData;
+
+use masonry::layout::Dim;
+use masonry::properties::Dimensions;
+use masonry::widgets::{self, LayoutChanged};
+
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A coarse category for the width available to a [`responsive`] view.
+///
+/// The widths separating these classes are set with [`Breakpoints`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SizeClass {
+    /// A narrow space, such as a phone in portrait orientation.
+    #[default]
+    Compact,
+    /// A medium space, such as a tablet or a phone in landscape orientation.
+    Medium,
+    /// A wide space, such as a desktop window.
+    Expanded,
+}
+
+/// The widths at which a [`responsive`] view switches between [`SizeClass`]es.
+///
+/// The defaults are 600 and 840 logical pixels, following the window size classes of Material Design.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Breakpoints {
+    /// The smallest width which is [`SizeClass::Medium`].
+    pub medium: f64,
+    /// The smallest width which is [`SizeClass::Expanded`].
+    pub expanded: f64,
+}
+
+impl Breakpoints {
+    /// Creates new breakpoints.
+    pub const fn new(medium: f64, expanded: f64) -> Self {
+        Self { medium, expanded }
+    }
+
+    /// Returns the size class of the given width.
+    pub fn size_class(&self, width: f64) -> SizeClass {
+        if width >= self.expanded {
+            SizeClass::Expanded
+        } else if width >= self.medium {
+            SizeClass::Medium
+        } else {
+            SizeClass::Compact
+        }
+    }
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self::new(600., 840.)
+    }
+}
+
+/// A view whose content depends on the [`SizeClass`] of the width available to it.
+///
+/// `view_fn` is called with the current size class to create the child view.
+/// The child is rebuilt with a new view from `view_fn` whenever the app is rebuilt,
+/// and when the available width crosses one of the [`breakpoints`](Responsive::breakpoints).
+/// The size class is [`Compact`](SizeClass::Compact) until the view has been laid out
+/// for the first time.
+///
+/// By default, this view takes all the available width, and its height is that of its child.
+///
+/// As `view_fn` must be `'static`, it can't borrow from the app's state: values it needs should be
+/// cloned into it.
+/// To use different kinds of layouts for different size classes, return an
+/// [`Either`](crate::core::one_of::Either) or [`OneOf`](crate::core::one_of::OneOf) view.
+///
+/// Use [`resize_observer`](crate::view::resize_observer) if you need the exact size instead.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// use xilem::WidgetView;
+/// use xilem::core::Edit;
+/// use xilem::masonry::kurbo::Axis;
+/// use xilem::view::{SizeClass, flex, label, responsive};
+///
+/// struct State {
+///     title: String,
+/// }
+///
+/// fn page(state: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+///     let title = state.title.clone();
+///     responsive(move |size_class| {
+///         // Place the sidebar next to the content when there is enough space.
+///         let axis = match size_class {
+///             SizeClass::Compact => Axis::Vertical,
+///             SizeClass::Medium | SizeClass::Expanded => Axis::Horizontal,
+///         };
+///         flex(axis, (label("Sidebar"), label(title.clone())))
+///     })
+/// }
+/// ```
+pub fn responsive<State, Action, V, F>(view_fn: F) -> Responsive<F, State, Action>
+where
+    State: ViewArgument,
+    F: Fn(SizeClass) -> V + 'static,
+    V: WidgetView<State, Action>,
+{
+    Responsive {
+        view_fn,
+        breakpoints: Breakpoints::default(),
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`responsive`].
+///
+/// See `responsive` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Responsive<F, State, Action = ()> {
+    view_fn: F,
+    breakpoints: Breakpoints,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<F, State, Action> Responsive<F, State, Action> {
+    /// Sets the widths at which the size class changes.
+    pub fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+}
+
+mod hidden {
+    use super::SizeClass;
+
+    #[doc(hidden)]
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub struct ResponsiveState<V, VState> {
+        pub(crate) size_class: SizeClass,
+        pub(crate) view: V,
+        pub(crate) view_state: VState,
+    }
+}
+
+use hidden::ResponsiveState;
+
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 1021948833 in decimal.
+const RESPONSIVE_CONTENT_VIEW_ID: ViewId = ViewId::new(0x3ce9b3a1);
+
+impl<F, State, Action> ViewMarker for Responsive<F, State, Action> {}
+impl<F, V, State, Action> View<State, Action, ViewCtx> for Responsive<F, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    F: Fn(SizeClass) -> V + 'static,
+    V: WidgetView<State, Action>,
+{
+    type Element = Pod<widgets::ResizeObserver>;
+    type ViewState = ResponsiveState<V, V::ViewState>;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let size_class = SizeClass::default();
+        let view = (self.view_fn)(size_class);
+        let (child, view_state) =
+            ctx.with_id(RESPONSIVE_CONTENT_VIEW_ID, |ctx| view.build(ctx, app_state));
+        (
+            ctx.with_action_widget(|_| {
+                let widget = widgets::ResizeObserver::new(child.new_widget);
+                // We measure the available width, rather than that of the child.
+                Pod::new_with_props(widget, Dimensions::new(Dim::Stretch, Dim::MaxContent))
+            }),
+            ResponsiveState {
+                size_class,
+                view,
+                view_state,
+            },
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        if self.breakpoints != prev.breakpoints {
+            // The new breakpoints may put our current width in another class.
+            widgets::ResizeObserver::force_resend(&mut element);
+        }
+        let view = (self.view_fn)(view_state.size_class);
+        ctx.with_id(RESPONSIVE_CONTENT_VIEW_ID, |ctx| {
+            view.rebuild(
+                &view_state.view,
+                &mut view_state.view_state,
+                ctx,
+                widgets::ResizeObserver::child_mut(&mut element).downcast(),
+                app_state,
+            );
+        });
+        view_state.view = view;
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(RESPONSIVE_CONTENT_VIEW_ID, |ctx| {
+            view_state.view.teardown(
+                &mut view_state.view_state,
+                ctx,
+                widgets::ResizeObserver::child_mut(&mut element).downcast(),
+            );
+        });
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(RESPONSIVE_CONTENT_VIEW_ID) => view_state.view.message(
+                &mut view_state.view_state,
+                message,
+                widgets::ResizeObserver::child_mut(&mut element).downcast(),
+                app_state,
+            ),
+            None => match message.take_message::<LayoutChanged>() {
+                Some(_) => {
+                    let width = element.ctx.content_box_size().width;
+                    let size_class = self.breakpoints.size_class(width);
+                    if size_class != view_state.size_class {
+                        view_state.size_class = size_class;
+                        MessageResult::RequestRebuild
+                    } else {
+                        MessageResult::Nop
+                    }
+                }
+                None => {
+                    tracing::error!(
+                        "Wrong message type in Responsive::message: {message:?} expected {}",
+                        type_name::<LayoutChanged>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!(?message, "Got unexpected id path in `Responsive::message`.");
+                MessageResult::Stale
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use masonry::core::ArcStr;
+use masonry::widgets;
+pub use masonry::widgets::SectionSpacing;
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+/// The title of a section of a list or a form, shown as small uppercase text.
+///
+/// The underlying widget is the Masonry [`SectionHeader`](widgets::SectionHeader).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// # use xilem::{view::{flex_col, label, section_header, SectionSpacing}, WidgetView};
+/// # use xilem::core::Edit;
+/// # struct State;
+/// # fn view(_: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+/// flex_col((
+///     section_header("Appearance").spacing(SectionSpacing::Compact),
+///     label("Theme"),
+///     section_header("Privacy"),
+///     label("Telemetry"),
+/// ))
+/// # }
+/// ```
+pub fn section_header(text: impl Into<ArcStr>) -> SectionHeader {
+    SectionHeader {
+        text: text.into(),
+        spacing: SectionSpacing::default(),
+    }
+}
+
+/// The [`View`] created by [`section_header`].
+///
+/// See `section_header` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct SectionHeader {
+    text: ArcStr,
+    spacing: SectionSpacing,
+}
+
+impl SectionHeader {
+    /// Set the space above and below the title.
+    pub fn spacing(mut self, spacing: SectionSpacing) -> Self {
+        self.spacing = spacing;
+        self
+    }
+}
+
+impl ViewMarker for SectionHeader {}
+impl<State: ViewArgument, Action> View<State, Action, ViewCtx> for SectionHeader {
+    type Element = Pod<widgets::SectionHeader>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let widget = widgets::SectionHeader::new(self.text.clone()).with_spacing(self.spacing);
+        (ctx.create_pod(widget), ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        if prev.text != self.text {
+            widgets::SectionHeader::set_text(&mut element, self.text.clone());
+        }
+        if prev.spacing != self.spacing {
+            widgets::SectionHeader::set_spacing(&mut element, self.spacing);
+        }
+    }
+
+    fn teardown(&self, (): &mut Self::ViewState, _: &mut ViewCtx, _: Mut<'_, Self::Element>) {}
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        tracing::error!(
+            ?message,
+            "Message arrived in SectionHeader::message, but SectionHeader doesn't consume any messages, this is a bug."
+        );
+        MessageResult::Stale
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::ArcStr;
+use masonry::widgets::{self, SegmentsSelected};
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+/// A row of segments, of which exactly one is selected.
+///
+/// The `on_change` callback is called with the index of the segment the user selected.
+/// The selection indicator slides to the new segment once `selected` is updated.
+///
+/// See [`segmented_multiple`] for a control where several segments can be selected.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::view::segmented;
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct State {
+///     period: usize,
+/// }
+///
+/// # fn view(app_state: &mut State) -> impl WidgetView<Edit<State>> {
+/// segmented(
+///     ["Day", "Week", "Month"],
+///     app_state.period,
+///     |app_state: &mut State, period| app_state.period = period,
+/// )
+/// # }
+/// ```
+pub fn segmented<State, Action>(
+    options: impl IntoIterator<Item = impl Into<ArcStr>>,
+    selected: usize,
+    on_change: impl Fn(Arg<'_, State>, usize) -> Action + Send + Sync + 'static,
+) -> Segmented<
+    State,
+    Action,
+    impl Fn(Arg<'_, State>, Vec<usize>) -> MessageResult<Action> + Send + Sync + 'static,
+>
+where
+    State: ViewArgument,
+{
+    Segmented {
+        options: options.into_iter().map(Into::into).collect(),
+        selection: vec![selected],
+        multiple: false,
+        callback: move |state: Arg<'_, State>, selection: Vec<usize>| match selection.first() {
+            Some(&selected) => MessageResult::Action(on_change(state, selected)),
+            None => MessageResult::Nop,
+        },
+        disabled: false,
+        phantom: PhantomData,
+    }
+}
+
+/// A row of segments, of which any number can be selected.
+///
+/// The `on_change` callback is called with the sorted indices of the segments the user
+/// wants to be selected.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::view::segmented_multiple;
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct State {
+///     styles: Vec<usize>,
+/// }
+///
+/// # fn view(app_state: &mut State) -> impl WidgetView<Edit<State>> {
+/// segmented_multiple(
+///     ["Bold", "Italic", "Underline"],
+///     app_state.styles.clone(),
+///     |app_state: &mut State, styles| app_state.styles = styles,
+/// )
+/// # }
+/// ```
+pub fn segmented_multiple<State, Action>(
+    options: impl IntoIterator<Item = impl Into<ArcStr>>,
+    selected: Vec<usize>,
+    on_change: impl Fn(Arg<'_, State>, Vec<usize>) -> Action + Send + Sync + 'static,
+) -> Segmented<
+    State,
+    Action,
+    impl Fn(Arg<'_, State>, Vec<usize>) -> MessageResult<Action> + Send + Sync + 'static,
+>
+where
+    State: ViewArgument,
+{
+    Segmented {
+        options: options.into_iter().map(Into::into).collect(),
+        selection: selected,
+        multiple: true,
+        callback: move |state: Arg<'_, State>, selection| {
+            MessageResult::Action(on_change(state, selection))
+        },
+        disabled: false,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`segmented`] or [`segmented_multiple`].
+///
+/// See `segmented` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Segmented<State, Action, F> {
+    options: Vec<ArcStr>,
+    selection: Vec<usize>,
+    multiple: bool,
+    callback: F,
+    disabled: bool,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<State, Action, F> Segmented<State, Action, F> {
+    /// Set the disabled state of the widget.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl<State, Action, F> ViewMarker for Segmented<State, Action, F> {}
+impl<F, State, Action> View<State, Action, ViewCtx> for Segmented<State, Action, F>
+where
+    State: ViewArgument,
+    Action: 'static,
+    F: Fn(Arg<'_, State>, Vec<usize>) -> MessageResult<Action> + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::SegmentedControl>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let element = ctx.with_action_widget(|ctx| {
+            let widget = widgets::SegmentedControl::new(self.options.iter().cloned())
+                .with_multiple(self.multiple)
+                .with_selection(self.selection.iter().copied());
+            let mut pod = ctx.create_pod(widget);
+            pod.new_widget.options.disabled = self.disabled;
+            pod
+        });
+        (element, ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        if prev.disabled != self.disabled {
+            element.ctx.set_disabled(self.disabled);
+        }
+        if prev.options != self.options {
+            widgets::SegmentedControl::set_segments(&mut element, self.options.iter().cloned());
+        }
+        if prev.multiple != self.multiple {
+            widgets::SegmentedControl::set_multiple(&mut element, self.multiple);
+        }
+        // Changing the segments or the selection mode can drop selected segments.
+        if prev.selection != self.selection
+            || prev.options != self.options
+            || prev.multiple != self.multiple
+        {
+            widgets::SegmentedControl::set_selection(&mut element, self.selection.iter().copied());
+        }
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        debug_assert!(
+            message.remaining_path().is_empty(),
+            "id path should be empty in Segmented::message"
+        );
+        match message.take_message::<SegmentsSelected>() {
+            Some(selected) => (self.callback)(app_state, selected.0),
+            None => {
+                tracing::error!("Wrong message type in Segmented::message, got {message:?}.");
+                MessageResult::Stale
+            }
+        }
+    }
+}
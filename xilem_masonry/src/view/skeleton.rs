@@ -0,0 +1,203 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::{Widget, WidgetMut};
+use masonry::widgets;
+pub use masonry::widgets::SkeletonShape;
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A shimmering placeholder with the approximate `shape` of content which is being loaded.
+///
+/// See [`skeleton_of`] to give the placeholder the size of the real content.
+///
+/// The underlying widget is the Masonry [`Skeleton`](widgets::Skeleton).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// # use xilem::{view::{label, skeleton, SkeletonShape}, WidgetView, core::one_of::Either};
+/// # use xilem::core::Edit;
+/// struct Article {
+///     body: Option<String>,
+/// }
+///
+/// fn article_body(article: &mut Article) -> impl WidgetView<Edit<Article>> + use<> {
+///     match &article.body {
+///         Some(body) => Either::A(label(body.clone())),
+///         None => Either::B(skeleton(SkeletonShape::Lines(3))),
+///     }
+/// }
+/// ```
+pub fn skeleton(shape: SkeletonShape) -> Skeleton {
+    Skeleton { shape }
+}
+
+/// A shimmering placeholder which takes the size of `footprint` without showing it.
+///
+/// The footprint is usually the view being loaded, built with placeholder data.
+/// The placeholder is a [rectangle](SkeletonShape::Rect) unless another [shape](SkeletonOf::shape)
+/// is chosen.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// # use xilem::{view::{label, skeleton_of, SkeletonShape}, WidgetView};
+/// # use xilem::core::Edit;
+/// # struct State;
+/// # fn view(_: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+/// skeleton_of(label("Some user name")).shape(SkeletonShape::Lines(1))
+/// # }
+/// ```
+pub fn skeleton_of<State, Action, V>(footprint: V) -> SkeletonOf<V, State, Action>
+where
+    State: ViewArgument,
+    V: WidgetView<State, Action>,
+{
+    SkeletonOf {
+        footprint,
+        shape: SkeletonShape::Rect,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`skeleton`].
+///
+/// See `skeleton` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Skeleton {
+    shape: SkeletonShape,
+}
+
+impl ViewMarker for Skeleton {}
+impl<State: ViewArgument, Action> View<State, Action, ViewCtx> for Skeleton {
+    type Element = Pod<widgets::Skeleton>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        (ctx.create_pod(widgets::Skeleton::new(self.shape)), ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        if prev.shape != self.shape {
+            widgets::Skeleton::set_shape(&mut element, self.shape);
+        }
+    }
+
+    fn teardown(&self, (): &mut Self::ViewState, _: &mut ViewCtx, _: Mut<'_, Self::Element>) {}
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        tracing::error!(
+            ?message,
+            "Message arrived in Skeleton::message, but Skeleton doesn't consume any messages, this is a bug."
+        );
+        MessageResult::Stale
+    }
+}
+
+/// The [`View`] created by [`skeleton_of`].
+///
+/// See `skeleton_of` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct SkeletonOf<V, State, Action = ()> {
+    footprint: V,
+    shape: SkeletonShape,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> SkeletonOf<V, State, Action> {
+    /// Set the shape painted in the footprint.
+    pub fn shape(mut self, shape: SkeletonShape) -> Self {
+        self.shape = shape;
+        self
+    }
+}
+
+impl<V, State, Action> ViewMarker for SkeletonOf<V, State, Action> {}
+impl<V, State, Action> View<State, Action, ViewCtx> for SkeletonOf<V, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    type Element = Pod<widgets::Skeleton>;
+    type ViewState = V::ViewState;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (footprint, footprint_state) = self.footprint.build(ctx, app_state);
+        let widget = widgets::Skeleton::of(footprint.new_widget, self.shape);
+        (ctx.create_pod(widget), footprint_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        if prev.shape != self.shape {
+            widgets::Skeleton::set_shape(&mut element, self.shape);
+        }
+        let mut footprint = footprint_mut(&mut element);
+        self.footprint.rebuild(
+            &prev.footprint,
+            view_state,
+            ctx,
+            footprint.downcast(),
+            app_state,
+        );
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        let mut footprint = footprint_mut(&mut element);
+        self.footprint
+            .teardown(view_state, ctx, footprint.downcast());
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        let mut footprint = footprint_mut(&mut element);
+        self.footprint
+            .message(view_state, message, footprint.downcast(), app_state)
+    }
+}
+
+fn footprint_mut<'t>(
+    element: &'t mut WidgetMut<'_, widgets::Skeleton>,
+) -> WidgetMut<'t, dyn Widget> {
+    widgets::Skeleton::footprint_mut(element).expect("SkeletonOf always has a footprint")
+}
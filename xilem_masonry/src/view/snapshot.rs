@@ -0,0 +1,198 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::type_name;
+use std::marker::PhantomData;
+
+use masonry::app::RenderedImage;
+use masonry::peniko::ImageData;
+use masonry::properties::Dimensions;
+use masonry::widgets;
+
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A view which can render its child into an image on request.
+///
+/// `on_image` is a function which takes the app's state and the rendered image,
+/// as unpremultiplied RGBA pixels.
+/// The image covers the child's bounding box, including e.g. its shadows, on a transparent background.
+///
+/// A render is requested every time the value passed to [`request`](Snapshot::request)
+/// changes, so incrementing a counter in the app state requests one image.
+/// The image is delivered asynchronously, after the next rebuild.
+///
+/// This is useful for drag images, minimaps, or "export as PNG" features.
+///
+/// See [`RenderRoot::render_to_image`](masonry::app::RenderRoot::render_to_image) for more information.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// # use xilem::{WidgetView, core::Edit, view::{snapshot, label}, masonry::peniko::ImageData};
+///
+/// struct State {
+///     export_count: u64,
+///     exported: Option<ImageData>,
+/// }
+///
+/// # fn my_component(state: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+/// snapshot(label("Card"), |state: &mut State, image| state.exported = Some(image))
+///     .request(state.export_count)
+///     .scale(2.0)
+/// # }
+/// ```
+pub fn snapshot<State, Action, V, F>(inner: V, on_image: F) -> Snapshot<V, F, State, Action>
+where
+    V: WidgetView<State, Action>,
+    F: Fn(Arg<'_, State>, ImageData) -> Action,
+    State: ViewArgument,
+    Snapshot<V, F, State, Action>: WidgetView<State, Action>,
+{
+    Snapshot {
+        inner,
+        on_image,
+        request: 0,
+        scale: 1.0,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`snapshot`].
+///
+/// See `snapshot` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Snapshot<V, F, State, Action = ()> {
+    inner: V,
+    on_image: F,
+    request: u64,
+    scale: f64,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, F, State, Action> Snapshot<V, F, State, Action> {
+    /// Sets the request counter.
+    ///
+    /// Whenever this value differs from the one of the previous rebuild, an image is requested.
+    /// Building the view doesn't request an image, whatever the initial value.
+    pub fn request(mut self, request: u64) -> Self {
+        self.request = request;
+        self
+    }
+
+    /// Sets the number of image pixels per logical pixel.
+    ///
+    /// The default is 1.0.
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+}
+
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 1559613532 in decimal.
+const SNAPSHOT_CONTENT_VIEW_ID: ViewId = ViewId::new(0x5cf5a55c);
+
+impl<V, F, State, Action> ViewMarker for Snapshot<V, F, State, Action> {}
+impl<V, F, State, Action> View<State, Action, ViewCtx> for Snapshot<V, F, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    F: 'static,
+    V: WidgetView<State, Action>,
+    F: Fn(Arg<'_, State>, ImageData) -> Action,
+{
+    type Element = Pod<widgets::Passthrough>;
+    type ViewState = V::ViewState;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = ctx.with_id(SNAPSHOT_CONTENT_VIEW_ID, |ctx| {
+            self.inner.build(ctx, app_state)
+        });
+        (
+            ctx.with_action_widget(|_| {
+                let widget = widgets::Passthrough::new(child.new_widget);
+                Pod::new_with_props(widget, Dimensions::MAX)
+            }),
+            child_state,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        ctx.with_id(SNAPSHOT_CONTENT_VIEW_ID, |ctx| {
+            View::<State, Action, _>::rebuild(
+                &self.inner,
+                &prev.inner,
+                view_state,
+                ctx,
+                widgets::Passthrough::child_mut(&mut element).downcast(),
+                app_state,
+            );
+        });
+        if self.request != prev.request {
+            element.ctx.request_render_to_image(self.scale);
+        }
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(SNAPSHOT_CONTENT_VIEW_ID, |ctx| {
+            View::<State, Action, _>::teardown(
+                &self.inner,
+                view_state,
+                ctx,
+                widgets::Passthrough::child_mut(&mut element).downcast(),
+            );
+        });
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        mut app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(SNAPSHOT_CONTENT_VIEW_ID) => self.inner.message(
+                view_state,
+                message,
+                widgets::Passthrough::child_mut(&mut element).downcast(),
+                State::reborrow_mut(&mut app_state),
+            ),
+            None => match message.take_message::<RenderedImage>() {
+                Some(rendered) => MessageResult::Action((self.on_image)(app_state, rendered.image)),
+                None => {
+                    tracing::error!(
+                        "Wrong message type in Snapshot::message: {message:?} expected {}",
+                        type_name::<RenderedImage>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!(?message, "Got unexpected id path in `Snapshot::message`.");
+                MessageResult::Stale
+            }
+        }
+    }
+}
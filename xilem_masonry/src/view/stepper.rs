@@ -0,0 +1,296 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use masonry::core::ArcStr;
+use masonry::properties::types::MainAxisAlignment;
+use masonry::widgets::{self, StepSelected};
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::view::{FlexExt as _, flex_col, flex_row, indexed_stack, text_button};
+use crate::{AnyWidgetView, Pod, ViewCtx, WidgetView};
+
+/// A row of numbered steps with connecting lines, showing the progress through a flow.
+///
+/// The steps before `active` are shown as completed, and clicking one of them calls
+/// `on_select` with its index, to go back to it.
+/// An `active` index equal to the number of steps shows all the steps as completed.
+///
+/// See [`stepper`] for a view which also shows the content of the active step.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::view::step_indicator;
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct State {
+///     step: usize,
+/// }
+///
+/// # fn view(app_state: &mut State) -> impl WidgetView<Edit<State>> {
+/// step_indicator(
+///     ["Cart", "Shipping", "Payment"],
+///     app_state.step,
+///     |app_state: &mut State, step| app_state.step = step,
+/// )
+/// # }
+/// ```
+pub fn step_indicator<State, Action, F>(
+    titles: impl IntoIterator<Item = impl Into<ArcStr>>,
+    active: usize,
+    on_select: F,
+) -> StepIndicator<State, Action, F>
+where
+    State: ViewArgument,
+    F: Fn(Arg<'_, State>, usize) -> Action + Send + Sync + 'static,
+{
+    StepIndicator {
+        titles: titles.into_iter().map(Into::into).collect(),
+        active,
+        disabled_steps: Vec::new(),
+        on_select,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`step_indicator`].
+///
+/// See `step_indicator` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct StepIndicator<State, Action, F> {
+    titles: Vec<ArcStr>,
+    active: usize,
+    disabled_steps: Vec<bool>,
+    on_select: F,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<State, Action, F> StepIndicator<State, Action, F> {
+    /// Set which steps are disabled, by index.
+    ///
+    /// Disabled steps are faded and can't be selected.
+    /// Steps missing from `disabled_steps` are enabled.
+    pub fn disabled_steps(mut self, disabled_steps: Vec<bool>) -> Self {
+        self.disabled_steps = disabled_steps;
+        self
+    }
+
+    fn is_disabled(&self, index: usize) -> bool {
+        self.disabled_steps.get(index).copied().unwrap_or(false)
+    }
+}
+
+impl<State, Action, F> ViewMarker for StepIndicator<State, Action, F> {}
+impl<State, Action, F> View<State, Action, ViewCtx> for StepIndicator<State, Action, F>
+where
+    State: ViewArgument,
+    Action: 'static,
+    F: Fn(Arg<'_, State>, usize) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::StepIndicator>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let element = ctx.with_action_widget(|ctx| {
+            let mut widget =
+                widgets::StepIndicator::new(self.titles.iter().cloned()).with_active(self.active);
+            for index in 0..self.titles.len() {
+                widget = widget.with_disabled(index, self.is_disabled(index));
+            }
+            ctx.create_pod(widget)
+        });
+        (element, ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        if prev.titles != self.titles {
+            widgets::StepIndicator::set_steps(&mut element, self.titles.iter().cloned());
+        }
+        if prev.active != self.active || prev.titles.len() != self.titles.len() {
+            widgets::StepIndicator::set_active(&mut element, self.active);
+        }
+        // Added steps are enabled, so they are checked as well.
+        for index in 0..self.titles.len() {
+            let disabled = self.is_disabled(index);
+            if index >= prev.titles.len() || prev.is_disabled(index) != disabled {
+                widgets::StepIndicator::set_disabled(&mut element, index, disabled);
+            }
+        }
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        if message.take_first().is_some() {
+            tracing::warn!("Got unexpected id path in StepIndicator::message");
+            return MessageResult::Stale;
+        }
+        match message.take_message::<StepSelected>() {
+            Some(selected) => MessageResult::Action((self.on_select)(app_state, selected.0)),
+            None => {
+                tracing::error!(
+                    "Wrong message type in StepIndicator::message: {message:?}, expected StepSelected"
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
+
+/// A step of a [`stepper`], with a title and the content shown while it's active.
+///
+/// Create it with [`step`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Step<State, Action = ()> {
+    title: ArcStr,
+    content: Box<AnyWidgetView<State, Action>>,
+    valid: bool,
+    disabled: bool,
+}
+
+/// A step of a [`stepper`] with the given title and content.
+pub fn step<State, Action>(
+    title: impl Into<ArcStr>,
+    content: impl WidgetView<State, Action>,
+) -> Step<State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+{
+    Step {
+        title: title.into(),
+        content: content.boxed(),
+        valid: true,
+        disabled: false,
+    }
+}
+
+impl<State, Action> Step<State, Action> {
+    /// Set whether the content of the step is valid.
+    ///
+    /// The user can't advance past an invalid step. Steps are valid by default.
+    pub fn valid(mut self, valid: bool) -> Self {
+        self.valid = valid;
+        self
+    }
+
+    /// Set whether the step is disabled.
+    ///
+    /// Disabled steps are skipped when moving back and forth, such as a shipping step
+    /// for an order which doesn't need shipping.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// A multi-step flow, such as an onboarding or a checkout, showing a [`step_indicator`]
+/// above the content of the `active` step, and buttons to go back and forth.
+///
+/// `on_change` is called with the index of the step the user wants to go to.
+/// This is the next enabled step when the "Next" button is pressed, which is only
+/// possible if the active step is [valid](Step::valid).
+/// When the last step is active, the button reads "Finish" instead, and calls `on_change`
+/// with the number of steps.
+/// The user can also go back to any completed step, by clicking it in the indicator.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::view::{label, step, stepper, text_input};
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct State {
+///     step: usize,
+///     email: String,
+/// }
+///
+/// # fn view(app_state: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+/// stepper(
+///     vec![
+///         step(
+///             "Account",
+///             text_input(app_state.email.clone(), |app_state: &mut State, email| {
+///                 app_state.email = email;
+///             }),
+///         )
+///         .valid(app_state.email.contains('@')),
+///         step("Done", label("Welcome aboard!")),
+///     ],
+///     app_state.step,
+///     |app_state: &mut State, step| app_state.step = step,
+/// )
+/// # }
+/// ```
+pub fn stepper<State, Action>(
+    steps: Vec<Step<State, Action>>,
+    active: usize,
+    on_change: impl Fn(Arg<'_, State>, usize) -> Action + Send + Sync + 'static,
+) -> impl WidgetView<State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+{
+    let on_change = Arc::new(on_change);
+    let len = steps.len();
+    let active = active.min(len);
+    let previous = (0..active).rev().find(|&idx| !steps[idx].disabled);
+    let next = (active + 1..len)
+        .find(|&idx| !steps[idx].disabled)
+        .unwrap_or(len);
+    let can_advance = steps.get(active).is_some_and(|step| step.valid);
+    let is_last = next == len;
+
+    let titles: Vec<ArcStr> = steps.iter().map(|step| step.title.clone()).collect();
+    let disabled_steps = steps.iter().map(|step| step.disabled).collect();
+    let pages: Vec<_> = steps.into_iter().map(|step| step.content).collect();
+
+    let indicator = step_indicator(titles, active, {
+        let on_change = on_change.clone();
+        move |state: Arg<'_, State>, step| on_change(state, step)
+    })
+    .disabled_steps(disabled_steps);
+    let back = text_button("Back", {
+        let on_change = on_change.clone();
+        move |state: Arg<'_, State>| on_change(state, previous.unwrap_or(0))
+    })
+    .disabled(previous.is_none());
+    let forward = text_button(
+        if is_last { "Finish" } else { "Next" },
+        move |state: Arg<'_, State>| on_change(state, next),
+    )
+    .disabled(!can_advance);
+
+    flex_col((
+        indicator,
+        indexed_stack(pages)
+            .active(active.min(len.saturating_sub(1)))
+            .flex(1.),
+        flex_row((back, forward)).main_axis_alignment(MainAxisAlignment::SpaceBetween),
+    ))
+}
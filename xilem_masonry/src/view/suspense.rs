@@ -0,0 +1,378 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Debug;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+
+use masonry::widgets::Crossfade;
+
+use crate::core::{
+    Arg, MessageCtx, MessageProxy, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker,
+    ViewPathTracker,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// How long the placeholder and the loaded view take to fade into each other, by default.
+const DEFAULT_TRANSITION: Duration = Duration::from_millis(200);
+
+/// A view which is shown once a future resolves, for use in [`suspense`].
+///
+/// `load` is called when the view is built, and the future it returns is run
+/// like a [`task`](crate::view::task).
+/// Once it resolves, `view` is called with its output to create the view to show.
+///
+/// Note that `load` is not called again when the view is rebuilt, so the value isn't
+/// reloaded when the captured variables of `load` change.
+pub fn async_view<State, T, F, Fut, VF, V>(load: F, view: VF) -> AsyncView<F, VF, State>
+where
+    State: ViewArgument,
+    F: Fn(Arg<'_, State>) -> Fut,
+    Fut: Future<Output = T> + Send + 'static,
+    T: Send + 'static,
+    VF: Fn(&T) -> V,
+{
+    AsyncView {
+        load,
+        view,
+        phantom: PhantomData,
+    }
+}
+
+/// The loading half of a [`suspense`] view, created by [`async_view`].
+pub struct AsyncView<F, VF, State> {
+    load: F,
+    view: VF,
+    phantom: PhantomData<fn(State)>,
+}
+
+/// A view which shows `placeholder` until the future of `async_view` resolves,
+/// then fades to the view created from its output.
+///
+/// The placeholder is shown for at least the [minimum duration](Suspense::min_placeholder_duration),
+/// so that it doesn't flicker when the future resolves right after it appeared.
+/// The placeholder and the loaded view fade into each other, unless the user prefers
+/// reduced motion.
+///
+/// The future is aborted if the view is torn down before it resolves.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// use std::time::Duration;
+///
+/// use xilem::view::{async_view, label, spinner, suspense};
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// # fn view() -> impl WidgetView<Edit<u32>> {
+/// suspense(
+///     async_view(
+///         |_: &mut u32| async {
+///             tokio::time::sleep(Duration::from_secs(1)).await;
+///             String::from("Loaded!")
+///         },
+///         |text: &String| label(text.clone()),
+///     ),
+///     spinner(),
+/// )
+/// .min_placeholder_duration(Duration::from_millis(300))
+/// # }
+/// ```
+pub fn suspense<State, Action, F, Fut, T, VF, V, P>(
+    async_view: AsyncView<F, VF, State>,
+    placeholder: P,
+) -> Suspense<F, VF, P, State, Action>
+where
+    State: ViewArgument,
+    F: Fn(Arg<'_, State>) -> Fut,
+    Fut: Future<Output = T> + Send + 'static,
+    T: Send + 'static,
+    VF: Fn(&T) -> V,
+    V: WidgetView<State, Action>,
+    P: WidgetView<State, Action>,
+{
+    Suspense {
+        async_view,
+        placeholder,
+        min_placeholder_duration: Duration::ZERO,
+        transition_duration: DEFAULT_TRANSITION,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`suspense`].
+///
+/// See its documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Suspense<F, VF, P, State, Action = ()> {
+    async_view: AsyncView<F, VF, State>,
+    placeholder: P,
+    min_placeholder_duration: Duration,
+    transition_duration: Duration,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<F, VF, P, State, Action> Suspense<F, VF, P, State, Action> {
+    /// Sets the minimum duration the placeholder is shown for.
+    ///
+    /// If the future resolves earlier, the loaded view is shown once this duration has
+    /// passed since the placeholder appeared.
+    /// Defaults to zero.
+    pub fn min_placeholder_duration(mut self, duration: Duration) -> Self {
+        self.min_placeholder_duration = duration;
+        self
+    }
+
+    /// Sets how long the placeholder and the loaded view take to fade into each other.
+    pub fn transition_duration(mut self, duration: Duration) -> Self {
+        self.transition_duration = duration;
+        self
+    }
+}
+
+// Use distinctive numbers here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 1528398434 in decimal.
+const SUSPENSE_PLACEHOLDER_VIEW_ID: ViewId = ViewId::new(0x5b19c462);
+/// This is a randomly generated 32 bit number - 3303716957 in decimal.
+const SUSPENSE_CONTENT_VIEW_ID: ViewId = ViewId::new(0xc4eb1b5d);
+
+/// The message sent when the future resolves.
+struct Resolved<T>(T);
+
+impl<T> Debug for Resolved<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Resolved(..)")
+    }
+}
+
+mod hidden {
+    use tokio::task::JoinHandle;
+
+    #[doc(hidden)]
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub enum SuspenseState<T, PlaceholderState, V, ContentState> {
+        /// The placeholder is shown.
+        Loading {
+            placeholder_state: PlaceholderState,
+            task: JoinHandle<()>,
+            /// The output of the future, which replaces the placeholder in the next rebuild.
+            resolved: Option<T>,
+        },
+        /// The loaded view is shown.
+        Loaded {
+            value: T,
+            view: V,
+            view_state: ContentState,
+        },
+    }
+}
+
+use hidden::SuspenseState;
+
+impl<F, VF, P, State, Action> ViewMarker for Suspense<F, VF, P, State, Action> {}
+impl<F, VF, P, State, Action, Fut, T, V> View<State, Action, ViewCtx>
+    for Suspense<F, VF, P, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    F: Fn(Arg<'_, State>) -> Fut + 'static,
+    Fut: Future<Output = T> + Send + 'static,
+    T: Send + 'static,
+    VF: Fn(&T) -> V + 'static,
+    V: WidgetView<State, Action>,
+    P: WidgetView<State, Action>,
+{
+    type Element = Pod<Crossfade>;
+    type ViewState = SuspenseState<T, P::ViewState, V, V::ViewState>;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        mut app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let path: Arc<[ViewId]> = ctx.view_path().into();
+        let proxy = MessageProxy::<Resolved<T>>::new(ctx.proxy(), path);
+        let future = (self.async_view.load)(State::reborrow_mut(&mut app_state));
+        // The placeholder appears now, so it must be shown until then.
+        let reveal_at = tokio::time::Instant::now() + self.min_placeholder_duration;
+        let task = ctx.runtime().spawn(async move {
+            let value = future.await;
+            tokio::time::sleep_until(reveal_at).await;
+            drop(proxy.message(Resolved(value)));
+        });
+
+        let (placeholder, placeholder_state) = ctx.with_id(SUSPENSE_PLACEHOLDER_VIEW_ID, |ctx| {
+            self.placeholder.build(ctx, app_state)
+        });
+        let widget = Crossfade::new(placeholder.new_widget).with_duration(self.transition_duration);
+        let view_state = SuspenseState::Loading {
+            placeholder_state,
+            task,
+            resolved: None,
+        };
+        (ctx.create_pod(widget), view_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        if self.transition_duration != prev.transition_duration {
+            Crossfade::set_duration(&mut element, self.transition_duration);
+        }
+
+        let value = match view_state {
+            SuspenseState::Loading {
+                placeholder_state,
+                resolved,
+                ..
+            } => {
+                let Some(value) = resolved.take() else {
+                    ctx.with_id(SUSPENSE_PLACEHOLDER_VIEW_ID, |ctx| {
+                        self.placeholder.rebuild(
+                            &prev.placeholder,
+                            placeholder_state,
+                            ctx,
+                            Crossfade::child_mut(&mut element).downcast(),
+                            app_state,
+                        );
+                    });
+                    return;
+                };
+                ctx.with_id(SUSPENSE_PLACEHOLDER_VIEW_ID, |ctx| {
+                    prev.placeholder.teardown(
+                        placeholder_state,
+                        ctx,
+                        Crossfade::child_mut(&mut element).downcast(),
+                    );
+                });
+                value
+            }
+            SuspenseState::Loaded {
+                value,
+                view,
+                view_state,
+            } => {
+                let new_view = (self.async_view.view)(value);
+                ctx.with_id(SUSPENSE_CONTENT_VIEW_ID, |ctx| {
+                    new_view.rebuild(
+                        view,
+                        view_state,
+                        ctx,
+                        Crossfade::child_mut(&mut element).downcast(),
+                        app_state,
+                    );
+                });
+                *view = new_view;
+                return;
+            }
+        };
+
+        // Fade from the placeholder to the loaded view.
+        let view = (self.async_view.view)(&value);
+        let (child, content_state) =
+            ctx.with_id(SUSPENSE_CONTENT_VIEW_ID, |ctx| view.build(ctx, app_state));
+        Crossfade::set_child(&mut element, child.new_widget);
+        *view_state = SuspenseState::Loaded {
+            value,
+            view,
+            view_state: content_state,
+        };
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        match view_state {
+            SuspenseState::Loading {
+                placeholder_state,
+                task,
+                ..
+            } => {
+                task.abort();
+                ctx.with_id(SUSPENSE_PLACEHOLDER_VIEW_ID, |ctx| {
+                    self.placeholder.teardown(
+                        placeholder_state,
+                        ctx,
+                        Crossfade::child_mut(&mut element).downcast(),
+                    );
+                });
+            }
+            SuspenseState::Loaded {
+                view, view_state, ..
+            } => {
+                ctx.with_id(SUSPENSE_CONTENT_VIEW_ID, |ctx| {
+                    view.teardown(
+                        view_state,
+                        ctx,
+                        Crossfade::child_mut(&mut element).downcast(),
+                    );
+                });
+            }
+        }
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match (message.take_first(), &mut *view_state) {
+            (None, SuspenseState::Loading { resolved, .. }) => {
+                let Some(value) = message.take_message::<Resolved<T>>() else {
+                    tracing::error!(?message, "Wrong message type in Suspense::message");
+                    return MessageResult::Stale;
+                };
+                // The loaded view can only be built during a rebuild.
+                *resolved = Some(value.0);
+                MessageResult::RequestRebuild
+            }
+            (
+                Some(SUSPENSE_PLACEHOLDER_VIEW_ID),
+                SuspenseState::Loading {
+                    placeholder_state, ..
+                },
+            ) => self.placeholder.message(
+                placeholder_state,
+                message,
+                Crossfade::child_mut(&mut element).downcast(),
+                app_state,
+            ),
+            (
+                Some(SUSPENSE_CONTENT_VIEW_ID),
+                SuspenseState::Loaded {
+                    view, view_state, ..
+                },
+            ) => view.message(
+                view_state,
+                message,
+                Crossfade::child_mut(&mut element).downcast(),
+                app_state,
+            ),
+            (None | Some(SUSPENSE_PLACEHOLDER_VIEW_ID | SUSPENSE_CONTENT_VIEW_ID), _) => {
+                // A message for the placeholder after it was replaced.
+                MessageResult::Stale
+            }
+            _ => {
+                tracing::warn!(?message, "Got unexpected id path in `Suspense::message`.");
+                MessageResult::Stale
+            }
+        }
+    }
+}
@@ -1,14 +1,17 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::ops::Range;
+use std::sync::Arc;
+
 use masonry::core::{ArcStr, NewWidget, Properties};
 use masonry::parley::StyleProperty;
 use masonry::parley::style::{FontStack, FontWeight};
 use masonry::properties::{
-    CaretColor, ContentColor, DisabledContentColor, PlaceholderColor, SelectionColor,
-    UnfocusedSelectionColor,
+    CaretBlink, CaretColor, CaretWidth, ContentColor, DisabledContentColor, PlaceholderColor,
+    SelectionColor, UnfocusedSelectionColor,
 };
-use masonry::widgets::{self, TextAction};
+use masonry::widgets::{self, InputFilter, SpellChecker, TextAction};
 use vello::peniko::Color;
 
 use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
@@ -21,6 +24,10 @@ use crate::{InsertNewline, Pod, TextAlign, ViewCtx, WidgetView as _};
 
 type Callback<State, Action> =
     Box<dyn Fn(Arg<'_, State>, String) -> Action + Send + Sync + 'static>;
+type FocusCallback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, bool) -> Action + Send + Sync + 'static>;
+type SelectionCallback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, Range<usize>) -> Action + Send + Sync + 'static>;
 
 /// A view which displays editable text.
 ///
@@ -89,6 +96,9 @@ where
         contents,
         on_changed: Box::new(on_changed),
         on_enter: None,
+        on_focus_changed: None,
+        on_selection_changed: None,
+        selection: None,
         text_color: None,
         disabled_text_color: None,
         placeholder: ArcStr::default(),
@@ -97,6 +107,8 @@ where
         weight: FontWeight::NORMAL,
         font: FontStack::List(std::borrow::Cow::Borrowed(&[])),
         insert_newline: InsertNewline::default(),
+        input_filter: None,
+        spell_checker: None,
         disabled: false,
         // Since we don't support setting the word wrapping, we can default to
         // not clipping
@@ -110,6 +122,9 @@ pub struct TextInput<State: ViewArgument, Action> {
     contents: String,
     on_changed: Callback<State, Action>,
     on_enter: Option<Callback<State, Action>>,
+    on_focus_changed: Option<FocusCallback<State, Action>>,
+    on_selection_changed: Option<SelectionCallback<State, Action>>,
+    selection: Option<Range<usize>>,
     text_color: Option<Color>,
     disabled_text_color: Option<Color>,
     placeholder: ArcStr,
@@ -118,6 +133,8 @@ pub struct TextInput<State: ViewArgument, Action> {
     weight: FontWeight,
     font: FontStack<'static>,
     insert_newline: InsertNewline,
+    input_filter: Option<InputFilter>,
+    spell_checker: Option<Arc<dyn SpellChecker>>,
     disabled: bool,
     clip: bool,
     // TODO: add more attributes of `masonry::widgets::TextInput`
@@ -147,6 +164,21 @@ impl<State: ViewArgument, Action: 'static> TextInput<State, Action> {
         self.prop(CaretColor { color })
     }
 
+    /// Set the insertion caret's width, in logical pixels.
+    ///
+    /// This overwrites the default `CaretWidth` property for the inner `TextArea` widget.
+    pub fn caret_width(self, width: f64) -> Prop<CaretWidth, Self, State, Action> {
+        self.prop(CaretWidth { width })
+    }
+
+    /// Set whether the insertion caret blinks.
+    ///
+    /// This overwrites the default `CaretBlink` property for the inner `TextArea` widget,
+    /// which follows the user's setting.
+    pub fn caret_blink(self, blink: CaretBlink) -> Prop<CaretBlink, Self, State, Action> {
+        self.prop(blink)
+    }
+
     /// Set the selection's color.
     ///
     /// This overwrites the default `SelectionColor` property for the inner `TextArea` widget.
@@ -212,10 +244,51 @@ impl<State: ViewArgument, Action: 'static> TextInput<State, Action> {
         self
     }
 
+    /// Restricts and reformats the text entered by the user, for example to only accept digits
+    /// or to format phone numbers.
+    ///
+    /// See [`InputFilter`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// use xilem::masonry::widgets::InputFilter;
+    /// use xilem::view::text_input;
+    /// # use xilem::WidgetView;
+    /// # use xilem::core::Edit;
+    ///
+    /// # struct State {
+    /// #    phone_number: String,
+    /// # }
+    ///
+    /// # fn view(state: &mut State) -> impl WidgetView<Edit<State>> {
+    /// text_input(state.phone_number.clone(), |state: &mut State, input: String| {
+    ///     state.phone_number = input;
+    /// })
+    /// .input_filter(InputFilter::digits().with_mask("(###) ###-####"))
+    /// # }
+    /// ```
+    pub fn input_filter(mut self, input_filter: InputFilter) -> Self {
+        self.input_filter = Some(input_filter);
+        self
+    }
+
+    /// Underlines the words which `spell_checker` finds to be misspelled.
+    ///
+    /// See [`SpellChecker`] for details.
+    /// The checker is compared by pointer, so keep the same [`Arc`] between rebuilds to
+    /// avoid checking the text again.
+    pub fn spell_checker(mut self, spell_checker: Arc<dyn SpellChecker>) -> Self {
+        self.spell_checker = Some(spell_checker);
+        self
+    }
+
     /// Set a callback that will be run when the user presses Enter <kbd>↵</kbd> to submit their input.
     ///
     /// Note that if [`insert_newline`](Self::insert_newline) is `InsertNewline::OnEnter`, this
     /// will never be called.
+    #[doc(alias = "on_submit")]
     pub fn on_enter<F>(mut self, on_enter: F) -> Self
     where
         F: Fn(Arg<'_, State>, String) -> Action + Send + Sync + 'static,
@@ -224,6 +297,69 @@ impl<State: ViewArgument, Action: 'static> TextInput<State, Action> {
         self
     }
 
+    /// Set a callback that will be run when the text input gains (`true`) or loses (`false`) focus.
+    #[doc(alias = "on_blur")]
+    pub fn on_focus_changed<F>(mut self, on_focus_changed: F) -> Self
+    where
+        F: Fn(Arg<'_, State>, bool) -> Action + Send + Sync + 'static,
+    {
+        self.on_focus_changed = Some(Box::new(on_focus_changed));
+        self
+    }
+
+    /// Set a callback that will be run when the user changes the selection or moves the caret.
+    ///
+    /// The callback receives the byte range of the selection in the text, which is empty
+    /// when there is only a caret.
+    /// Store it in your app's state and pass it to [`selection`](Self::selection) to keep
+    /// the selection when the text input is recreated.
+    pub fn on_selection_changed<F>(mut self, on_selection_changed: F) -> Self
+    where
+        F: Fn(Arg<'_, State>, Range<usize>) -> Action + Send + Sync + 'static,
+    {
+        self.on_selection_changed = Some(Box::new(on_selection_changed));
+        self
+    }
+
+    /// Set the selection to the given byte range of the text, or place the caret if the
+    /// range is empty.
+    ///
+    /// The selection is applied when the view is created, when the value changes between
+    /// rebuilds, and when the text is replaced by a different `contents`.
+    /// The user can still move the caret in the meantime; use
+    /// [`on_selection_changed`](Self::on_selection_changed) to follow those changes.
+    ///
+    /// Ranges whose ends aren't char boundaries are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// use std::ops::Range;
+    /// use xilem::view::text_input;
+    /// # use xilem::WidgetView;
+    /// # use xilem::core::Edit;
+    ///
+    /// struct State {
+    ///     query: String,
+    ///     selection: Range<usize>,
+    /// }
+    ///
+    /// fn view(state: &mut State) -> impl WidgetView<Edit<State>> {
+    ///     text_input(state.query.clone(), |state: &mut State, input: String| {
+    ///         state.query = input;
+    ///     })
+    ///     .selection(state.selection.clone())
+    ///     .on_selection_changed(|state: &mut State, selection| {
+    ///         state.selection = selection;
+    ///     })
+    /// }
+    /// ```
+    pub fn selection(mut self, selection: Range<usize>) -> Self {
+        self.selection = Some(selection);
+        self
+    }
+
     /// Set the disabled state of the widget.
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -256,12 +392,24 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx>
 
     fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
         // TODO: Maybe we want a shared TextArea View?
-        let text_area = widgets::TextArea::new_editable(&self.contents)
+        let mut text_area = widgets::TextArea::new_editable(&self.contents)
             .with_text_alignment(self.text_alignment)
             .with_insert_newline(self.insert_newline)
             .with_style(StyleProperty::FontSize(self.text_size))
             .with_style(StyleProperty::FontWeight(self.weight))
             .with_style(StyleProperty::FontStack(self.font.clone()));
+        if let Some(input_filter) = &self.input_filter {
+            text_area = text_area.with_input_filter(input_filter.clone());
+        }
+        if let Some(spell_checker) = &self.spell_checker {
+            text_area = text_area.with_spell_checker(spell_checker.clone());
+        }
+        if let Some(selection) = &self.selection {
+            text_area = text_area.with_selection(selection.clone());
+        }
+        text_area = text_area
+            .with_report_focus(self.on_focus_changed.is_some())
+            .with_report_selection(self.on_selection_changed.is_some());
 
         // TODO - Replace this with properties on the TextInput view
         // once we implement property inheritance or something like it.
@@ -336,10 +484,27 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx>
         // without calling `set_text`.
 
         // This is probably not the right behaviour, but determining what is the right behaviour is hard
-        if text_area.widget.text() != &self.contents {
+        let text_reset = text_area.widget.text() != &self.contents;
+        if text_reset {
             widgets::TextArea::reset_text(&mut text_area, &self.contents);
         }
 
+        // Resetting the text moves the caret to the end, so the selection is set again.
+        if let Some(selection) = &self.selection
+            && (text_reset || prev.selection.as_ref() != Some(selection))
+        {
+            widgets::TextArea::select_byte_range(&mut text_area, selection.start, selection.end);
+        }
+        if prev.on_focus_changed.is_some() != self.on_focus_changed.is_some() {
+            widgets::TextArea::set_report_focus(&mut text_area, self.on_focus_changed.is_some());
+        }
+        if prev.on_selection_changed.is_some() != self.on_selection_changed.is_some() {
+            widgets::TextArea::set_report_selection(
+                &mut text_area,
+                self.on_selection_changed.is_some(),
+            );
+        }
+
         if prev.text_size != self.text_size {
             widgets::TextArea::insert_style(
                 &mut text_area,
@@ -358,6 +523,16 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx>
         if prev.insert_newline != self.insert_newline {
             widgets::TextArea::set_insert_newline(&mut text_area, self.insert_newline);
         }
+        if prev.input_filter != self.input_filter {
+            widgets::TextArea::set_input_filter(&mut text_area, self.input_filter.clone());
+        }
+        let same_spell_checker = match (&prev.spell_checker, &self.spell_checker) {
+            (Some(prev), Some(new)) => Arc::ptr_eq(prev, new),
+            (prev, new) => prev.is_none() && new.is_none(),
+        };
+        if !same_spell_checker {
+            widgets::TextArea::set_spell_checker(&mut text_area, self.spell_checker.clone());
+        }
     }
 
     fn teardown(
@@ -393,6 +568,24 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx>
                     tracing::error!("Textbox::message: on_enter is not set");
                     MessageResult::Stale
                 }
+                TextAction::FocusChanged(focused) => match &self.on_focus_changed {
+                    Some(on_focus_changed) => {
+                        MessageResult::Action(on_focus_changed(app_state, focused))
+                    }
+                    None => {
+                        tracing::error!("Textbox::message: on_focus_changed is not set");
+                        MessageResult::Stale
+                    }
+                },
+                TextAction::SelectionChanged(selection) => match &self.on_selection_changed {
+                    Some(on_selection_changed) => {
+                        MessageResult::Action(on_selection_changed(app_state, selection))
+                    }
+                    None => {
+                        tracing::error!("Textbox::message: on_selection_changed is not set");
+                        MessageResult::Stale
+                    }
+                },
             },
             None => {
                 tracing::error!(?message, "Wrong message type in TextInput::message");
@@ -0,0 +1,160 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use masonry::widgets::{self, TextAction, TokensChanged};
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+type Callback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, Vec<String>) -> Action + Send + Sync + 'static>;
+
+/// A view which shows a list of values as removable chips, with a text input to add more.
+///
+/// This is useful for email recipients or tags.
+/// The tokens *must* be stored in your app's state, and the updated tokens are provided
+/// by the `on_changed` callback.
+///
+/// The entered text becomes a token when the user types a separator, which is a comma,
+/// semicolon, or newline by default, or presses Enter <kbd>↵</kbd>.
+/// See [`TokenInput`](widgets::TokenInput) for details.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// # use xilem::view::token_input;
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct State {
+///     recipients: Vec<String>,
+/// }
+///
+/// fn view(state: &mut State) -> impl WidgetView<Edit<State>> {
+///     token_input(
+///         state.recipients.clone(),
+///         |state: &mut State, recipients: Vec<String>| {
+///             state.recipients = recipients;
+///         },
+///     )
+/// }
+/// ```
+pub fn token_input<F, State, Action>(
+    tokens: Vec<String>,
+    on_changed: F,
+) -> TokenInput<State, Action>
+where
+    F: Fn(Arg<'_, State>, Vec<String>) -> Action + Send + Sync + 'static,
+    State: ViewArgument,
+{
+    TokenInput {
+        tokens,
+        on_changed: Box::new(on_changed),
+        separators: widgets::DEFAULT_TOKEN_SEPARATORS.to_vec(),
+        disabled: false,
+    }
+}
+
+/// The [`View`] created by [`token_input`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct TokenInput<State: ViewArgument, Action> {
+    tokens: Vec<String>,
+    on_changed: Callback<State, Action>,
+    separators: Vec<char>,
+    disabled: bool,
+}
+
+impl<State: ViewArgument, Action: 'static> TokenInput<State, Action> {
+    /// Set the characters which turn the entered text into a token.
+    ///
+    /// The default value is [`DEFAULT_TOKEN_SEPARATORS`](widgets::DEFAULT_TOKEN_SEPARATORS).
+    pub fn separators(mut self, separators: impl Into<Vec<char>>) -> Self {
+        self.separators = separators.into();
+        self
+    }
+
+    /// Set the disabled state of the widget.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl<State: ViewArgument, Action> ViewMarker for TokenInput<State, Action> {}
+impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx>
+    for TokenInput<State, Action>
+{
+    type Element = Pod<widgets::TokenInput>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let token_input = widgets::TokenInput::new()
+            .with_tokens(self.tokens.iter().cloned())
+            .with_separators(self.separators.clone());
+
+        // The inner TextArea sends text actions, which we ignore.
+        ctx.record_action_source(token_input.area_id());
+
+        let element = ctx.with_action_widget(|ctx| {
+            let mut pod = ctx.create_pod(token_input);
+            pod.new_widget.options.disabled = self.disabled;
+            pod
+        });
+        (element, ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        _: &mut Self::ViewState,
+        _ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        if self.disabled != prev.disabled {
+            element.ctx.set_disabled(self.disabled);
+        }
+        if self.separators != prev.separators {
+            widgets::TokenInput::set_separators(&mut element, self.separators.clone());
+        }
+        // As in `TextInput`, we compare to the element's tokens rather than to the previous
+        // value, as the user may have changed them.
+        if element.widget.tokens() != self.tokens.as_slice() {
+            widgets::TokenInput::set_tokens(&mut element, self.tokens.iter().cloned());
+        }
+    }
+
+    fn teardown(
+        &self,
+        _: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.teardown_action_source(widgets::TokenInput::text_mut(&mut element));
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        _: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        debug_assert!(
+            message.remaining_path().is_empty(),
+            "id path should be empty in TokenInput::message"
+        );
+        if message.take_message::<TextAction>().is_some() {
+            return MessageResult::Nop;
+        }
+        match message.take_message::<TokensChanged>() {
+            Some(action) => MessageResult::Action((self.on_changed)(app_state, action.0)),
+            None => {
+                tracing::error!(?message, "Wrong message type in TokenInput::message");
+                MessageResult::Stale
+            }
+        }
+    }
+}
@@ -0,0 +1,325 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use masonry::layout::AsUnit;
+use masonry::properties::types::CrossAxisAlignment;
+use masonry::theme;
+use serde::{Deserialize, Serialize};
+
+use crate::core::Edit;
+use crate::masonry::peniko::ImageBrush;
+use crate::style::Style;
+use crate::view::{button, flex_col, flex_row, image, label, sized_box};
+use crate::{AnyWidgetView, Color, WidgetView};
+
+/// A node of a declarative UI description, which can be loaded from data with [serde].
+///
+/// Nodes are turned into views with [`ui_node_view`], which resolves their bindings
+/// into the app state with [`UiBindings`].
+///
+/// Text can contain binding expressions like `{user.name}`, which are replaced by
+/// [`UiBindings::value`]; use `{{` and `}}` for literal braces.
+///
+/// # Examples
+///
+/// In JSON, nodes are objects with a `type` field:
+///
+/// ```json
+/// {
+///     "type": "flex",
+///     "direction": "column",
+///     "gap": 8,
+///     "children": [
+///         { "type": "label", "text": "Hello, {name}!", "style": { "text_size": 24 } },
+///         { "type": "button", "text": "Log out", "action": "log_out" }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum UiNode {
+    /// A [`flex_row`] or [`flex_col`] of nodes.
+    Flex {
+        /// The direction of the children.
+        #[serde(default)]
+        direction: UiDirection,
+        /// The space between children, in logical pixels.
+        #[serde(default)]
+        gap: Option<f64>,
+        /// The children.
+        #[serde(default)]
+        children: Vec<Self>,
+        /// The style of the container.
+        #[serde(default)]
+        style: UiStyle,
+    },
+    /// A [`label`].
+    Label {
+        /// The text, which can contain binding expressions.
+        text: String,
+        /// The style of the label.
+        #[serde(default)]
+        style: UiStyle,
+    },
+    /// A [`button`] with a text.
+    Button {
+        /// The text, which can contain binding expressions.
+        text: String,
+        /// The name of the action passed to [`UiBindings::action`] when the button is pressed.
+        action: String,
+        /// The style of the button text, and of its surroundings.
+        #[serde(default)]
+        style: UiStyle,
+    },
+    /// An [`image`].
+    Image {
+        /// The name of the image passed to [`UiBindings::image`].
+        source: String,
+        /// The style of the image.
+        #[serde(default)]
+        style: UiStyle,
+    },
+}
+
+/// The direction of a [`UiNode::Flex`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UiDirection {
+    /// Children are laid out in a row.
+    Row,
+    /// Children are laid out in a column.
+    #[default]
+    Column,
+}
+
+/// The style of a [`UiNode`].
+///
+/// Fields which aren't set keep the default style of the view.
+/// Colors are CSS colors, such as `"#ff8800"` or `"rebeccapurple"`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiStyle {
+    /// The padding around the content, in logical pixels.
+    pub padding: Option<f64>,
+    /// The background color.
+    pub background: Option<String>,
+    /// The corner radius of the background, in logical pixels.
+    pub corner_radius: Option<f64>,
+    /// The width, in logical pixels.
+    pub width: Option<f64>,
+    /// The height, in logical pixels.
+    pub height: Option<f64>,
+    /// The color of the text.
+    pub color: Option<String>,
+    /// The font size of the text.
+    pub text_size: Option<f32>,
+}
+
+impl UiStyle {
+    fn has_box_style(&self) -> bool {
+        self.padding.is_some()
+            || self.background.is_some()
+            || self.corner_radius.is_some()
+            || self.width.is_some()
+            || self.height.is_some()
+    }
+}
+
+/// The connection between the nodes of a UI description and the app state.
+pub trait UiBindings: 'static {
+    /// Returns the value of the binding expression `{path}`.
+    ///
+    /// If this returns `None`, the expression is kept as is, which makes typos visible.
+    fn value(&self, path: &str) -> Option<String>;
+
+    /// Handles the action `name`, e.g. because a button was pressed.
+    fn action(&mut self, name: &str);
+
+    /// Returns the image called `name`.
+    ///
+    /// The default implementation has no images.
+    fn image(&self, name: &str) -> Option<ImageBrush> {
+        _ = name;
+        None
+    }
+}
+
+/// Creates the view described by `node`, with its bindings resolved in `state`.
+///
+/// This is usually combined with [`hot_reload`](crate::view::hot_reload) to edit the
+/// UI while the app is running, or used to show layouts sent by a server.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::view::{UiBindings, UiNode, ui_node_view};
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct State {
+///     name: String,
+///     logged_in: bool,
+/// }
+///
+/// impl UiBindings for State {
+///     fn value(&self, path: &str) -> Option<String> {
+///         match path {
+///             "name" => Some(self.name.clone()),
+///             _ => None,
+///         }
+///     }
+///
+///     fn action(&mut self, name: &str) {
+///         if name == "log_out" {
+///             self.logged_in = false;
+///         }
+///     }
+/// }
+///
+/// let node: UiNode = serde_json::from_str(r#"{
+///     "type": "flex",
+///     "children": [
+///         { "type": "label", "text": "Hello, {name}!" },
+///         { "type": "button", "text": "Log out", "action": "log_out" }
+///     ]
+/// }"#).unwrap();
+///
+/// # fn view(node: &UiNode, state: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+/// ui_node_view(node, state)
+/// # }
+/// ```
+pub fn ui_node_view<State: UiBindings>(
+    node: &UiNode,
+    state: &State,
+) -> Box<AnyWidgetView<Edit<State>>> {
+    match node {
+        UiNode::Flex {
+            direction,
+            gap,
+            children,
+            style,
+        } => {
+            let children: Vec<_> = children
+                .iter()
+                .map(|child| ui_node_view(child, state))
+                .collect();
+            let gap = gap.unwrap_or(theme::DEFAULT_GAP.get());
+            let flex = match direction {
+                UiDirection::Row => flex_row(children),
+                UiDirection::Column => flex_col(children),
+            };
+            let flex = flex
+                .cross_axis_alignment(CrossAxisAlignment::Start)
+                .gap(gap.px());
+            with_box_style(flex, style)
+        }
+        UiNode::Label { text, style } => with_box_style(styled_label(text, style, state), style),
+        UiNode::Button {
+            text,
+            action,
+            style,
+        } => {
+            let action = action.clone();
+            let button = button(
+                styled_label(text, style, state),
+                move |state: &mut State| state.action(&action),
+            );
+            with_box_style(button, style)
+        }
+        UiNode::Image { source, style } => match state.image(source) {
+            Some(brush) => with_box_style(image(brush), style),
+            None => {
+                tracing::warn!("Unknown image {source:?} in UI description");
+                with_box_style(label(format!("[{source}]")), style)
+            }
+        },
+    }
+}
+
+/// Creates a label for `text`, with its bindings resolved and its text style applied.
+fn styled_label<State: UiBindings>(
+    text: &str,
+    style: &UiStyle,
+    state: &State,
+) -> Box<AnyWidgetView<Edit<State>>> {
+    let label = label(resolve_bindings(text, state))
+        .text_size(style.text_size.unwrap_or(theme::TEXT_SIZE_NORMAL));
+    match style.color.as_deref().and_then(parse_color) {
+        Some(color) => label.color(color).boxed(),
+        None => label.boxed(),
+    }
+}
+
+/// Wraps `view` in a box with the padding, background and size of `style`, if it has any.
+fn with_box_style<State: UiBindings>(
+    view: impl WidgetView<Edit<State>>,
+    style: &UiStyle,
+) -> Box<AnyWidgetView<Edit<State>>> {
+    if !style.has_box_style() {
+        return view.boxed();
+    }
+    let mut view = sized_box(view)
+        .padding(style.padding.unwrap_or(0.))
+        .corner_radius(style.corner_radius.unwrap_or(0.))
+        .background_color(
+            style
+                .background
+                .as_deref()
+                .and_then(parse_color)
+                .unwrap_or(Color::TRANSPARENT),
+        )
+        .boxed();
+    if let Some(width) = style.width {
+        view = sized_box(view).width(width.px()).boxed();
+    }
+    if let Some(height) = style.height {
+        view = sized_box(view).height(height.px()).boxed();
+    }
+    view
+}
+
+fn parse_color(color: &str) -> Option<Color> {
+    color
+        .parse()
+        .inspect_err(|err| tracing::warn!("Invalid color {color:?} in UI description: {err}"))
+        .ok()
+}
+
+/// Replaces the binding expressions of `text` by their values.
+fn resolve_bindings(text: &str, bindings: &impl UiBindings) -> String {
+    let mut resolved = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(['{', '}']) {
+        resolved.push_str(&rest[..start]);
+        let brace = rest.as_bytes()[start];
+        rest = &rest[start + 1..];
+        // Doubled braces are literal braces.
+        if rest.as_bytes().first() == Some(&brace) {
+            resolved.push(brace as char);
+            rest = &rest[1..];
+            continue;
+        }
+        if brace == b'}' {
+            resolved.push('}');
+            continue;
+        }
+        let Some(end) = rest.find('}') else {
+            resolved.push('{');
+            break;
+        };
+        let path = rest[..end].trim();
+        match bindings.value(path) {
+            Some(value) => resolved.push_str(&value),
+            None => {
+                tracing::warn!("Unknown binding {path:?} in UI description");
+                resolved.push('{');
+                resolved.push_str(&rest[..end]);
+                resolved.push('}');
+            }
+        }
+        rest = &rest[end + 1..];
+    }
+    resolved.push_str(rest);
+    resolved
+}
@@ -0,0 +1,212 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::type_name;
+use std::marker::PhantomData;
+
+use masonry::properties::Dimensions;
+use masonry::widgets::{self, VisibilityChanged};
+
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+type Callback<State, Action> = Box<dyn Fn(Arg<'_, State>) -> Action + Send + Sync + 'static>;
+
+/// A view which calls callbacks when its child enters or leaves the viewport
+/// of the nearest [`portal`](crate::view::portal), or of other scrolling ancestors.
+///
+/// This is usually created with [`WidgetView::on_appear`] or [`WidgetView::on_disappear`],
+/// and both callbacks can be set by chaining these methods.
+///
+/// The child starts out hidden, so `on_appear` is called when it first becomes visible,
+/// and `on_disappear` is only called after `on_appear` has been.
+/// The child is visible when part of it is inside the viewport and it isn't stashed,
+/// for example because it is in an inactive tab.
+/// Outside of any portal, it is visible as soon as it is shown.
+///
+/// This can be used to lazy-load images when they are scrolled into view,
+/// to start and stop animations, or to log impressions.
+///
+/// See the documentation on the underlying [`VisibilityObserver`](widgets::VisibilityObserver)
+/// for more information.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// # use xilem::{WidgetView, core::Edit, view::{flex_col, label, portal}};
+/// struct State {
+///     impressions: Vec<usize>,
+///     playing: Option<usize>,
+/// }
+///
+/// # fn my_component(state: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+/// portal(flex_col(
+///     (0..100)
+///         .map(|idx| {
+///             label(format!("Video {idx}"))
+///                 .on_appear(move |state: &mut State| {
+///                     state.impressions.push(idx);
+///                     state.playing = Some(idx);
+///                 })
+///                 .on_disappear(move |state: &mut State| {
+///                     if state.playing == Some(idx) {
+///                         state.playing = None;
+///                     }
+///                 })
+///         })
+///         .collect::<Vec<_>>(),
+/// ))
+/// # }
+/// ```
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct VisibilityObserver<V, State: ViewArgument, Action = ()> {
+    inner: V,
+    on_appear: Option<Callback<State, Action>>,
+    on_disappear: Option<Callback<State, Action>>,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+/// Wraps `inner` in a [`VisibilityObserver`] without any callbacks.
+pub(crate) fn visibility_observer<V, State: ViewArgument, Action>(
+    inner: V,
+) -> VisibilityObserver<V, State, Action> {
+    VisibilityObserver {
+        inner,
+        on_appear: None,
+        on_disappear: None,
+        phantom: PhantomData,
+    }
+}
+
+impl<V, State: ViewArgument, Action> VisibilityObserver<V, State, Action> {
+    /// Sets the callback which is called when the child becomes visible.
+    pub fn on_appear<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Arg<'_, State>) -> Action + Send + Sync + 'static,
+    {
+        self.on_appear = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the callback which is called when the child stops being visible.
+    pub fn on_disappear<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Arg<'_, State>) -> Action + Send + Sync + 'static,
+    {
+        self.on_disappear = Some(Box::new(callback));
+        self
+    }
+}
+
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 2712074517 in decimal.
+const VISIBILITY_OBSERVER_CONTENT_VIEW_ID: ViewId = ViewId::new(0xa1a5c215);
+
+impl<V, State: ViewArgument, Action> ViewMarker for VisibilityObserver<V, State, Action> {}
+impl<V, State, Action> View<State, Action, ViewCtx> for VisibilityObserver<V, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    type Element = Pod<widgets::VisibilityObserver>;
+    type ViewState = V::ViewState;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = ctx.with_id(VISIBILITY_OBSERVER_CONTENT_VIEW_ID, |ctx| {
+            self.inner.build(ctx, app_state)
+        });
+        (
+            ctx.with_action_widget(|_| {
+                let widget = widgets::VisibilityObserver::new(child.new_widget);
+                Pod::new_with_props(widget, Dimensions::MAX)
+            }),
+            child_state,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        ctx.with_id(VISIBILITY_OBSERVER_CONTENT_VIEW_ID, |ctx| {
+            self.inner.rebuild(
+                &prev.inner,
+                view_state,
+                ctx,
+                widgets::VisibilityObserver::child_mut(&mut element).downcast(),
+                app_state,
+            );
+        });
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(VISIBILITY_OBSERVER_CONTENT_VIEW_ID, |ctx| {
+            self.inner.teardown(
+                view_state,
+                ctx,
+                widgets::VisibilityObserver::child_mut(&mut element).downcast(),
+            );
+        });
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(VISIBILITY_OBSERVER_CONTENT_VIEW_ID) => self.inner.message(
+                view_state,
+                message,
+                widgets::VisibilityObserver::child_mut(&mut element).downcast(),
+                app_state,
+            ),
+            None => match message.take_message::<VisibilityChanged>() {
+                Some(visibility) => {
+                    let callback = match *visibility {
+                        VisibilityChanged(true) => &self.on_appear,
+                        VisibilityChanged(false) => &self.on_disappear,
+                    };
+                    match callback {
+                        Some(callback) => MessageResult::Action(callback(app_state)),
+                        None => MessageResult::Nop,
+                    }
+                }
+                None => {
+                    tracing::error!(
+                        "Wrong message type in VisibilityObserver::message: {message:?} expected {}",
+                        type_name::<VisibilityChanged>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!(
+                    ?message,
+                    "Got unexpected id path in `VisibilityObserver::message`."
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
@@ -0,0 +1,387 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::type_name;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use masonry::core::{CollectionWidget, FromDynWidget, Widget, WidgetMut};
+use masonry::kurbo::Rect;
+use masonry::widgets::{self, VisibleItemsChanged};
+
+use crate::core::{
+    AppendVec, Arg, ElementSplice, MessageCtx, MessageResult, Mut, SuperElement, View,
+    ViewArgument, ViewElement, ViewId, ViewMarker, ViewPathTracker, ViewSequence,
+};
+use crate::{Pod, ViewCtx};
+
+type Callback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, Range<usize>) -> Action + Send + Sync + 'static>;
+
+/// A layout which packs items of varying heights into `column_count` columns,
+/// like a "waterfall" or Pinterest-style feed.
+///
+/// Each item is placed in the column which is currently the shortest.
+/// Items appended to the sequence don't move the existing ones.
+///
+/// For very long feeds, a [`viewport`](Waterfall::viewport) can be set, outside of which
+/// items are stashed.
+/// The [`on_visible_items_changed`](Waterfall::on_visible_items_changed) callback is then
+/// called with the range of items in the viewport when it changes, which can be used
+/// to load more items.
+///
+/// See the documentation on the underlying [`Waterfall`](widgets::Waterfall) for more information.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::WidgetView;
+/// use xilem::core::Edit;
+/// use xilem::masonry::layout::AsUnit;
+/// use xilem::style::Style;
+/// use xilem::view::{label, waterfall};
+///
+/// struct State {
+///     posts: Vec<String>,
+/// }
+///
+/// fn feed(state: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+///     let posts = state
+///         .posts
+///         .iter()
+///         .map(|post| label(post.clone()))
+///         .collect::<Vec<_>>();
+///     waterfall(posts, 2).gap(8.px())
+/// }
+/// ```
+pub fn waterfall<State: ViewArgument, Action, Seq: WaterfallSequence<State, Action>>(
+    sequence: Seq,
+    column_count: usize,
+) -> Waterfall<Seq, State, Action> {
+    Waterfall {
+        sequence,
+        column_count,
+        viewport: None,
+        on_visible_items_changed: None,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`waterfall`] from a sequence.
+///
+/// See `waterfall` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Waterfall<Seq, State: ViewArgument, Action = ()> {
+    sequence: Seq,
+    column_count: usize,
+    viewport: Option<Rect>,
+    on_visible_items_changed: Option<Callback<State, Action>>,
+
+    /// Used to associate the State and Action in the call to `.waterfall()` with the State
+    /// and Action used in the View implementation, to allow inference to flow backwards,
+    /// allowing State and Action to be inferred properly.
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<Seq, State: ViewArgument, Action> Waterfall<Seq, State, Action> {
+    /// Sets the visible part of the waterfall, in its own coordinate space.
+    ///
+    /// Items which don't intersect the viewport vertically are stashed.
+    /// See [`Waterfall::set_viewport`](widgets::Waterfall::set_viewport) for details.
+    pub fn viewport(mut self, viewport: impl Into<Option<Rect>>) -> Self {
+        self.viewport = viewport.into();
+        self
+    }
+
+    /// Sets a callback which is called with the range of items in the viewport
+    /// when it changes.
+    ///
+    /// This is only called when a [`viewport`](Self::viewport) is set.
+    pub fn on_visible_items_changed<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Arg<'_, State>, Range<usize>) -> Action + Send + Sync + 'static,
+    {
+        self.on_visible_items_changed = Some(Box::new(callback));
+        self
+    }
+}
+
+mod hidden {
+    use super::WaterfallElement;
+    use crate::core::AppendVec;
+
+    #[doc(hidden)]
+    #[expect(
+        unnameable_types,
+        reason = "Implementation detail, public because of trait visibility rules"
+    )]
+    pub struct WaterfallState<SeqState> {
+        pub(crate) seq_state: SeqState,
+        pub(crate) scratch: AppendVec<WaterfallElement>,
+    }
+}
+
+use hidden::WaterfallState;
+
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 3352151932 in decimal.
+const WATERFALL_CONTENT_VIEW_ID: ViewId = ViewId::new(0xc7ce3a7c);
+
+impl<Seq, State: ViewArgument, Action> ViewMarker for Waterfall<Seq, State, Action> {}
+
+impl<State, Action, Seq> View<State, Action, ViewCtx> for Waterfall<Seq, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    Seq: WaterfallSequence<State, Action>,
+{
+    type Element = Pod<widgets::Waterfall>;
+
+    type ViewState = WaterfallState<Seq::SeqState>;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let mut elements = AppendVec::default();
+        let mut widget = widgets::Waterfall::new(self.column_count);
+        let seq_state = ctx.with_id(WATERFALL_CONTENT_VIEW_ID, |ctx| {
+            self.sequence.seq_build(ctx, &mut elements, app_state)
+        });
+        for element in elements.drain() {
+            widget = widget.with(element.child.new_widget);
+        }
+        if let Some(viewport) = self.viewport {
+            widget = widget.with_viewport(viewport);
+        }
+        let pod = ctx.with_action_widget(|ctx| ctx.create_pod(widget));
+        (
+            pod,
+            WaterfallState {
+                seq_state,
+                scratch: elements,
+            },
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        WaterfallState { seq_state, scratch }: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        ctx.with_id(WATERFALL_CONTENT_VIEW_ID, |ctx| {
+            let mut splice = WaterfallSplice::new(element.reborrow_mut(), scratch);
+            self.sequence
+                .seq_rebuild(&prev.sequence, seq_state, ctx, &mut splice, app_state);
+            debug_assert!(scratch.is_empty());
+        });
+
+        if self.column_count != prev.column_count {
+            widgets::Waterfall::set_column_count(&mut element, self.column_count);
+        }
+        if self.viewport != prev.viewport {
+            widgets::Waterfall::set_viewport(&mut element, self.viewport);
+        }
+    }
+
+    fn teardown(
+        &self,
+        WaterfallState { seq_state, scratch }: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(WATERFALL_CONTENT_VIEW_ID, |ctx| {
+            let mut splice = WaterfallSplice::new(element.reborrow_mut(), scratch);
+            self.sequence.seq_teardown(seq_state, ctx, &mut splice);
+            debug_assert!(scratch.is_empty());
+        });
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        WaterfallState { seq_state, scratch }: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(WATERFALL_CONTENT_VIEW_ID) => {
+                let mut splice = WaterfallSplice::new(element, scratch);
+                let result = self
+                    .sequence
+                    .seq_message(seq_state, message, &mut splice, app_state);
+                debug_assert!(scratch.is_empty());
+                result
+            }
+            None => match message.take_message::<VisibleItemsChanged>() {
+                Some(visible) => match &self.on_visible_items_changed {
+                    Some(callback) => MessageResult::Action(callback(app_state, visible.0.clone())),
+                    None => MessageResult::Nop,
+                },
+                None => {
+                    tracing::error!(
+                        "Wrong message type in Waterfall::message: {message:?} expected {}",
+                        type_name::<VisibleItemsChanged>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!(?message, "Got unexpected id path in `Waterfall::message`.");
+                MessageResult::Stale
+            }
+        }
+    }
+}
+
+// Used to become a reference form for editing. It's provided to rebuild and teardown.
+impl ViewElement for WaterfallElement {
+    type Mut<'w> = WaterfallElementMut<'w>;
+}
+
+// Used to allow the item to be used as a generic item in ViewSequence.
+impl SuperElement<Self, ViewCtx> for WaterfallElement {
+    fn upcast(_ctx: &mut ViewCtx, child: Self) -> Self {
+        child
+    }
+
+    fn with_downcast_val<R>(
+        mut this: Mut<'_, Self>,
+        f: impl FnOnce(Mut<'_, Self>) -> R,
+    ) -> (Self::Mut<'_>, R) {
+        let r = {
+            let parent = this.parent.reborrow_mut();
+            let reborrow = WaterfallElementMut {
+                idx: this.idx,
+                parent,
+            };
+            f(reborrow)
+        };
+        (this, r)
+    }
+}
+
+impl<W: Widget + FromDynWidget + ?Sized> SuperElement<Pod<W>, ViewCtx> for WaterfallElement {
+    fn upcast(_: &mut ViewCtx, child: Pod<W>) -> Self {
+        Self {
+            child: child.erased(),
+        }
+    }
+
+    fn with_downcast_val<R>(
+        mut this: Mut<'_, Self>,
+        f: impl FnOnce(Mut<'_, Pod<W>>) -> R,
+    ) -> (Mut<'_, Self>, R) {
+        let ret = {
+            let mut child = widgets::Waterfall::get_mut(&mut this.parent, this.idx);
+            let downcast = child.downcast();
+            f(downcast)
+        };
+
+        (this, ret)
+    }
+}
+
+// Used for building and rebuilding the ViewSequence
+impl ElementSplice<WaterfallElement> for WaterfallSplice<'_, '_> {
+    fn with_scratch<R>(&mut self, f: impl FnOnce(&mut AppendVec<WaterfallElement>) -> R) -> R {
+        let ret = f(self.scratch);
+        for element in self.scratch.drain() {
+            widgets::Waterfall::insert(&mut self.element, self.idx, element.child.new_widget, ());
+            self.idx += 1;
+        }
+        ret
+    }
+
+    fn insert(&mut self, element: WaterfallElement) {
+        widgets::Waterfall::insert(&mut self.element, self.idx, element.child.new_widget, ());
+        self.idx += 1;
+    }
+
+    fn mutate<R>(&mut self, f: impl FnOnce(Mut<'_, WaterfallElement>) -> R) -> R {
+        let child = WaterfallElementMut {
+            parent: self.element.reborrow_mut(),
+            idx: self.idx,
+        };
+        let ret = f(child);
+        self.idx += 1;
+        ret
+    }
+
+    fn skip(&mut self, n: usize) {
+        self.idx += n;
+    }
+
+    fn move_from(&mut self, n: usize) -> bool {
+        for idx in (self.idx..self.idx + n).rev() {
+            widgets::Waterfall::swap(&mut self.element, idx, idx + 1);
+        }
+        true
+    }
+
+    fn index(&self) -> usize {
+        self.idx
+    }
+
+    fn delete<R>(&mut self, f: impl FnOnce(Mut<'_, WaterfallElement>) -> R) -> R {
+        let ret = {
+            let child = WaterfallElementMut {
+                parent: self.element.reborrow_mut(),
+                idx: self.idx,
+            };
+            f(child)
+        };
+        widgets::Waterfall::remove(&mut self.element, self.idx);
+        ret
+    }
+}
+
+/// `WaterfallSequence` is what allows an input to the waterfall that contains all the items.
+pub trait WaterfallSequence<State: ViewArgument, Action = ()>:
+    ViewSequence<State, Action, ViewCtx, WaterfallElement>
+{
+}
+
+impl<Seq, State, Action> WaterfallSequence<State, Action> for Seq
+where
+    Seq: ViewSequence<State, Action, ViewCtx, WaterfallElement>,
+    State: ViewArgument,
+{
+}
+
+/// An item within a [`Waterfall`] view.
+pub struct WaterfallElement {
+    /// The child widget.
+    child: Pod<dyn Widget>,
+}
+
+/// A mutable reference to a [`WaterfallElement`], used internally by Xilem traits.
+pub struct WaterfallElementMut<'w> {
+    parent: WidgetMut<'w, widgets::Waterfall>,
+    idx: usize,
+}
+
+// Used for manipulating the ViewSequence.
+struct WaterfallSplice<'w, 's> {
+    idx: usize,
+    element: WidgetMut<'w, widgets::Waterfall>,
+    scratch: &'s mut AppendVec<WaterfallElement>,
+}
+
+impl<'w, 's> WaterfallSplice<'w, 's> {
+    fn new(
+        element: WidgetMut<'w, widgets::Waterfall>,
+        scratch: &'s mut AppendVec<WaterfallElement>,
+    ) -> Self {
+        Self {
+            idx: 0,
+            element,
+            scratch,
+        }
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use masonry::widgets::{self, WebViewBackend, WebViewCommand, WebViewEvent};
+
+use crate::core::{
+    Arg, MessageCtx, MessageProxy, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker,
+    ViewPathTracker,
+};
+use crate::{Pod, ViewCtx};
+
+/// A view which embeds the web page at `url`, using a platform web view.
+///
+/// `make_backend` is called once, when the view is built, to create the [`WebViewBackend`]
+/// which shows the page.
+/// When `url` changes, the page navigates to the new URL, unless the page already navigated
+/// there on its own.
+/// Events from the page, such as navigations and messages sent by its scripts,
+/// are passed to `on_event`.
+///
+/// Other commands, such as going back or sending a message to the page, can be
+/// sent with [`WebView::commands`].
+///
+/// See the documentation on the underlying [`WebView`](widgets::WebView) for more information.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// # use xilem::masonry::kurbo::Rect;
+/// # use xilem::masonry::widgets::{WebViewBackend, WebViewCommand, WebViewEventHandler};
+/// # use xilem::{WidgetView, core::Edit, view::web_view};
+/// use xilem::masonry::widgets::WebViewEvent;
+///
+/// # struct MyBackend;
+/// # impl WebViewBackend for MyBackend {
+/// #     fn set_bounds(&mut self, bounds: Rect) {}
+/// #     fn set_visible(&mut self, visible: bool) {}
+/// #     fn set_event_handler(&mut self, handler: WebViewEventHandler) {}
+/// #     fn run_command(&mut self, command: &WebViewCommand) {}
+/// # }
+/// struct State {
+///     url: String,
+///     to_page: Vec<WebViewCommand>,
+/// }
+///
+/// # fn my_component(state: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+/// web_view(
+///     state.url.clone(),
+///     || MyBackend,
+///     |state: &mut State, event| match event {
+///         WebViewEvent::Navigated(url) => state.url = url,
+///         WebViewEvent::Message(message) => {
+///             state.to_page.push(WebViewCommand::PostMessage(format!("echo: {message}")));
+///         }
+///     },
+/// )
+/// .commands(std::mem::take(&mut state.to_page))
+/// # }
+/// ```
+pub fn web_view<B, F, H, State, Action>(
+    url: impl Into<String>,
+    make_backend: F,
+    on_event: H,
+) -> WebView<F, H, State, Action>
+where
+    B: WebViewBackend,
+    F: Fn() -> B + 'static,
+    H: Fn(Arg<'_, State>, WebViewEvent) -> Action + 'static,
+    State: ViewArgument,
+{
+    WebView {
+        url: url.into(),
+        make_backend,
+        on_event,
+        commands: Vec::new(),
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`web_view`].
+///
+/// See `web_view` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct WebView<F, H, State, Action = ()> {
+    url: String,
+    make_backend: F,
+    on_event: H,
+    commands: Vec<WebViewCommand>,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<F, H, State, Action> WebView<F, H, State, Action> {
+    /// Runs these commands on the page when this view is built or rebuilt.
+    ///
+    /// Commands are run again on every rebuild, so they should usually be taken from
+    /// a queue in the app's state, which callbacks push into.
+    pub fn commands(mut self, commands: impl IntoIterator<Item = WebViewCommand>) -> Self {
+        self.commands.extend(commands);
+        self
+    }
+}
+
+impl<F, H, State, Action> ViewMarker for WebView<F, H, State, Action> {}
+impl<B, F, H, State, Action> View<State, Action, ViewCtx> for WebView<F, H, State, Action>
+where
+    B: WebViewBackend,
+    F: Fn() -> B + 'static,
+    H: Fn(Arg<'_, State>, WebViewEvent) -> Action + 'static,
+    State: ViewArgument,
+    Action: 'static,
+{
+    type Element = Pod<widgets::WebView>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let path: Arc<[ViewId]> = ctx.view_path().into();
+        let proxy = MessageProxy::new(ctx.proxy(), path);
+
+        let mut backend = (self.make_backend)();
+        backend.set_event_handler(Box::new(move |event| {
+            drop(proxy.message(event));
+        }));
+        let pod = ctx.create_pod(
+            widgets::WebView::new(backend, self.url.clone())
+                .with_commands(self.commands.iter().cloned()),
+        );
+        (pod, ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        // The page may navigate on its own, so we compare with the widget's URL.
+        if self.url != prev.url && self.url != element.widget.url() {
+            widgets::WebView::load_url(&mut element, self.url.clone());
+        }
+        for command in &self.commands {
+            widgets::WebView::run_command(&mut element, command.clone());
+        }
+    }
+
+    fn teardown(&self, (): &mut Self::ViewState, _: &mut ViewCtx, _: Mut<'_, Self::Element>) {}
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        debug_assert!(
+            message.remaining_path().is_empty(),
+            "id path should be empty in WebView::message"
+        );
+        match message.take_message::<WebViewEvent>() {
+            Some(event) => {
+                if let WebViewEvent::Navigated(url) = &*event {
+                    widgets::WebView::record_navigation(&mut element, url.clone());
+                }
+                MessageResult::Action((self.on_event)(app_state, *event))
+            }
+            None => {
+                tracing::error!(?message, "Wrong message type in WebView::message");
+                MessageResult::Stale
+            }
+        }
+    }
+}
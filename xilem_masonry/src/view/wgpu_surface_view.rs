@@ -0,0 +1,169 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use masonry::core::WgpuSurfaceTarget;
+use masonry::widgets::{self, SurfaceInput};
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+type RenderFn = Arc<dyn Fn(&WgpuSurfaceTarget<'_>) + Send + Sync + 'static>;
+type InputCallback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, SurfaceInput) -> Action + Send + Sync + 'static>;
+
+/// A view showing content rendered with `wgpu` by `render`, such as a 3D scene.
+///
+/// `render` is called with Masonry's device and queue, and a texture the size of the view
+/// in physical pixels, when the view is resized or rebuilt, or on every animation frame if
+/// it is [continuous](WgpuSurfaceView::continuous).
+///
+/// See the documentation on the underlying [`WgpuSurfaceView`](widgets::WgpuSurfaceView)
+/// for more information.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::masonry::vello::wgpu;
+/// use xilem::view::wgpu_surface_view;
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct State {
+///     clear_color: wgpu::Color,
+/// }
+///
+/// # fn view(state: &mut State) -> impl WidgetView<Edit<State>> + use<> {
+/// let clear_color = state.clear_color;
+/// wgpu_surface_view(move |target| {
+///     let mut encoder = target.device.create_command_encoder(&Default::default());
+///     encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+///         color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+///             view: target.view,
+///             depth_slice: None,
+///             resolve_target: None,
+///             ops: wgpu::Operations {
+///                 load: wgpu::LoadOp::Clear(clear_color),
+///                 store: wgpu::StoreOp::Store,
+///             },
+///         })],
+///         ..Default::default()
+///     });
+///     target.queue.submit([encoder.finish()]);
+/// })
+/// # }
+/// ```
+pub fn wgpu_surface_view<State, Action>(
+    render: impl Fn(&WgpuSurfaceTarget<'_>) + Send + Sync + 'static,
+) -> WgpuSurfaceView<State, Action>
+where
+    State: ViewArgument,
+{
+    WgpuSurfaceView {
+        render: Arc::new(render),
+        continuous: false,
+        on_input: None,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`wgpu_surface_view`].
+///
+/// See `wgpu_surface_view` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct WgpuSurfaceView<State: ViewArgument, Action = ()> {
+    render: RenderFn,
+    continuous: bool,
+    on_input: Option<InputCallback<State, Action>>,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<State: ViewArgument, Action> WgpuSurfaceView<State, Action> {
+    /// Sets whether the content is rendered on every animation frame, e.g. for animated scenes.
+    pub fn continuous(mut self, continuous: bool) -> Self {
+        self.continuous = continuous;
+        self
+    }
+
+    /// Sets a callback which is called with the pointer and keyboard events received by the view.
+    pub fn on_input<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Arg<'_, State>, SurfaceInput) -> Action + Send + Sync + 'static,
+    {
+        self.on_input = Some(Box::new(callback));
+        self
+    }
+}
+
+/// Wraps `render` into the render function of the widget.
+fn widget_render(render: &RenderFn) -> impl FnMut(&WgpuSurfaceTarget<'_>) + 'static {
+    let render = render.clone();
+    move |target| render(target)
+}
+
+impl<State: ViewArgument, Action> ViewMarker for WgpuSurfaceView<State, Action> {}
+
+impl<State, Action> View<State, Action, ViewCtx> for WgpuSurfaceView<State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+{
+    type Element = Pod<widgets::WgpuSurfaceView>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let widget =
+            widgets::WgpuSurfaceView::new(widget_render(&self.render)).continuous(self.continuous);
+        (ctx.with_action_widget(|ctx| ctx.create_pod(widget)), ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        // The render function usually captures parts of the app state, which may have changed.
+        widgets::WgpuSurfaceView::set_render(&mut element, widget_render(&self.render));
+        if self.continuous != prev.continuous {
+            widgets::WgpuSurfaceView::set_continuous(&mut element, self.continuous);
+        }
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        debug_assert!(
+            message.remaining_path().is_empty(),
+            "id path should be empty in WgpuSurfaceView::message"
+        );
+        match message.take_message::<SurfaceInput>() {
+            Some(input) => match &self.on_input {
+                Some(callback) => MessageResult::Action(callback(app_state, *input)),
+                None => MessageResult::Nop,
+            },
+            None => {
+                tracing::error!("Wrong message type in WgpuSurfaceView::message, got {message:?}.");
+                MessageResult::Stale
+            }
+        }
+    }
+}
@@ -399,6 +399,13 @@ impl ElementSplice<ZStackElement> for ZStackSplice<'_, '_> {
         self.idx += n;
     }
 
+    fn move_from(&mut self, n: usize) -> bool {
+        for idx in (self.idx..self.idx + n).rev() {
+            widgets::ZStack::swap(&mut self.element, idx, idx + 1);
+        }
+        true
+    }
+
     fn index(&self) -> usize {
         self.idx
     }
@@ -7,7 +7,8 @@ use std::sync::Arc;
 use masonry::core::{FromDynWidget, Widget, WidgetId, WidgetMut};
 
 use crate::Pod;
-use crate::core::{Environment, RawProxy, ViewId, ViewPathTracker};
+use crate::core::{Environment, RawProxy, RebuildDiff, ViewId, ViewPathTracker};
+use crate::persistence::Persistence;
 
 /// A context type passed to various methods of Xilem traits.
 pub struct ViewCtx {
@@ -19,6 +20,8 @@ pub struct ViewCtx {
     proxy: Arc<dyn RawProxy>,
     runtime: Arc<tokio::runtime::Runtime>,
     environment: Environment,
+    persistence: Option<Persistence>,
+    rebuild_diff: Option<RebuildDiff>,
 }
 
 impl ViewPathTracker for ViewCtx {
@@ -37,6 +40,10 @@ impl ViewPathTracker for ViewCtx {
     fn environment(&mut self) -> &mut Environment {
         &mut self.environment
     }
+
+    fn rebuild_diff(&mut self) -> Option<&mut RebuildDiff> {
+        self.rebuild_diff.as_mut()
+    }
 }
 
 impl ViewCtx {
@@ -82,6 +89,32 @@ impl ViewCtx {
         &self.runtime
     }
 
+    /// Returns the store of persisted values, if the app uses persistence.
+    ///
+    /// See the [`persistence`](crate::persistence) module for details.
+    pub fn persistence(&self) -> Option<&Persistence> {
+        self.persistence.as_ref()
+    }
+
+    /// Sets the store of persisted values.
+    ///
+    /// You almost never need to call this method unless you're building your own framework.
+    pub fn set_persistence(&mut self, persistence: Option<Persistence>) {
+        self.persistence = persistence;
+    }
+
+    /// Sets whether to record a [`RebuildDiff`] of each rebuild.
+    ///
+    /// The diff shows which views were rebuilt, skipped, built or torn down, which helps
+    /// find out why a list flickers or why some state is reset unexpectedly.
+    /// Xilem logs the diff of each rebuild when this is enabled.
+    /// It is enabled by default if the `XILEM_REBUILD_DIFF` environment variable is set.
+    pub fn set_rebuild_diff(&mut self, enabled: bool) {
+        if enabled != self.rebuild_diff.is_some() {
+            self.rebuild_diff = enabled.then(RebuildDiff::new);
+        }
+    }
+
     /// Returns an event queue to which [`SendMessage`](crate::core::SendMessage)s can be submitted.
     pub fn proxy(&self) -> Arc<dyn RawProxy + 'static> {
         self.proxy.clone()
@@ -97,6 +130,10 @@ impl ViewCtx {
             proxy,
             runtime,
             environment: Environment::new(),
+            persistence: None,
+            rebuild_diff: std::env::var("XILEM_REBUILD_DIFF")
+                .is_ok_and(|it| !it.is_empty())
+                .then(RebuildDiff::new),
         }
     }
 }
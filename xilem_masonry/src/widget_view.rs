@@ -1,11 +1,16 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use masonry::core::{FromDynWidget, HasProperty, Property, Widget};
-use masonry::kurbo::Affine;
+use masonry::core::{ClipShape, FromDynWidget, HasProperty, Property, Widget};
+use masonry::kurbo::{Affine, Rect};
 
-use crate::core::{View, ViewArgument, ViewSequence};
-use crate::view::{Prop, Transformed, transformed};
+use crate::core::{
+    Arg, MapMessage, MessageResult, View, ViewArgument, ViewSequence, map_message_result,
+};
+use crate::view::{
+    Clip, ContinuousRedraw, OnSizeChanged, Prop, Transformed, VisibilityObserver, clip,
+    continuous_redraw, on_size_changed, transformed, visibility_observer,
+};
 use crate::{AnyWidgetView, Pod, ViewCtx};
 
 /// The trait for views representing the widget tree.
@@ -51,6 +56,185 @@ pub trait WidgetView<State: ViewArgument, Action = ()>:
         transformed(self).transform(by)
     }
 
+    /// This widget clipped to `shape`, for both painting and pointer hit-testing.
+    ///
+    /// The shape is in the coordinate space of this widget, and can be a
+    /// `RoundedRect`, a `Circle`, an `Ellipse`, or an arbitrary `BezPath`.
+    ///
+    /// See [`clip`] for more details.
+    fn clip(self, shape: impl Into<ClipShape>) -> Clip<Self, State, Action>
+    where
+        Self: Sized,
+    {
+        clip(shape, self)
+    }
+
+    /// This widget and its descendants repainted on every animation frame while `continuous` is true.
+    ///
+    /// See [`continuous_redraw`] for more details.
+    fn continuous_redraw(self, continuous: bool) -> ContinuousRedraw<Self, State, Action>
+    where
+        Self: Sized,
+    {
+        continuous_redraw(continuous, self)
+    }
+
+    /// Calls `callback` with this view's layout rectangle in the window, whenever
+    /// its size or position in the window changes.
+    ///
+    /// See [`on_size_changed`] for more details.
+    fn on_size_changed<F>(self, callback: F) -> OnSizeChanged<Self, F, State, Action>
+    where
+        Action: 'static,
+        Self: Sized,
+        F: Fn(Arg<'_, State>, Rect) -> Action + Send + Sync + 'static,
+    {
+        on_size_changed(self, callback)
+    }
+
+    /// Calls `callback` when this view enters the viewport of the nearest
+    /// [`portal`](crate::view::portal), or becomes visible outside of any portal.
+    ///
+    /// Chain [`on_disappear`](VisibilityObserver::on_disappear) on the result to also be notified
+    /// when it leaves.
+    /// See [`VisibilityObserver`] for more details.
+    fn on_appear<F>(self, callback: F) -> VisibilityObserver<Self, State, Action>
+    where
+        Self: Sized,
+        F: Fn(Arg<'_, State>) -> Action + Send + Sync + 'static,
+    {
+        visibility_observer(self).on_appear(callback)
+    }
+
+    /// Calls `callback` when this view leaves the viewport of the nearest
+    /// [`portal`](crate::view::portal), or is stashed.
+    ///
+    /// See [`VisibilityObserver`] for more details.
+    fn on_disappear<F>(self, callback: F) -> VisibilityObserver<Self, State, Action>
+    where
+        Self: Sized,
+        F: Fn(Arg<'_, State>) -> Action + Send + Sync + 'static,
+    {
+        visibility_observer(self).on_disappear(callback)
+    }
+
+    /// Translates the actions of this view into the action type of its parent.
+    ///
+    /// This lets reusable view functions emit their own small action enums, which their
+    /// callers translate, instead of sharing one action type for the whole app.
+    /// Use [`adapt`](WidgetView::adapt) if the translation needs access to the app state.
+    ///
+    /// # Examples
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// use xilem::{view::text_button, WidgetView};
+    ///
+    /// enum StepperAction {
+    ///     Up,
+    ///     Down,
+    /// }
+    ///
+    /// fn stepper<State: xilem::core::ViewArgument>() -> impl WidgetView<State, StepperAction> {
+    ///     // ...
+    /// #   text_button("+", |_| StepperAction::Up)
+    /// }
+    ///
+    /// enum ZoomAction {
+    ///     ZoomIn,
+    ///     ZoomOut,
+    /// }
+    ///
+    /// # fn view<State: xilem::core::ViewArgument>() -> impl WidgetView<State, ZoomAction> + use<State> {
+    /// stepper().map_action(|action| match action {
+    ///     StepperAction::Up => ZoomAction::ZoomIn,
+    ///     StepperAction::Down => ZoomAction::ZoomOut,
+    /// })
+    /// # }
+    /// ```
+    fn map_action<ParentAction, F>(
+        self,
+        map_fn: F,
+    ) -> MapMessage<
+        Self,
+        State,
+        ParentAction,
+        Action,
+        ViewCtx,
+        impl Fn(Arg<'_, State>, MessageResult<Action>) -> MessageResult<ParentAction>
+        + Send
+        + Sync
+        + 'static,
+    >
+    where
+        Self: Sized,
+        Action: 'static,
+        ParentAction: 'static,
+        F: Fn(Action) -> ParentAction + Send + Sync + 'static,
+    {
+        map_message_result(
+            self,
+            move |_: Arg<'_, State>, result: MessageResult<Action>| result.map(&map_fn),
+        )
+    }
+
+    /// Translates the actions of this view into the action type of its parent,
+    /// with access to the app state.
+    ///
+    /// This is [`map_action`](WidgetView::map_action) for translations which also update the
+    /// app state, in the style of the Elm architecture.
+    /// See [`map_message_result`] to also change the kind of [`MessageResult`], e.g. to
+    /// avoid rebuilding for some actions.
+    ///
+    /// # Examples
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// use xilem::{core::Edit, view::text_button, WidgetView};
+    ///
+    /// enum CountAction {
+    ///     Increment,
+    ///     Decrement,
+    /// }
+    ///
+    /// fn counter<State: xilem::core::ViewArgument>() -> impl WidgetView<State, CountAction> {
+    ///     // ...
+    /// #   text_button("+", |_| CountAction::Increment)
+    /// }
+    ///
+    /// # fn view() -> impl WidgetView<Edit<i32>> {
+    /// counter().adapt(|count: &mut i32, action| match action {
+    ///     CountAction::Increment => *count += 1,
+    ///     CountAction::Decrement => *count -= 1,
+    /// })
+    /// # }
+    /// ```
+    fn adapt<ParentAction, F>(
+        self,
+        map_fn: F,
+    ) -> MapMessage<
+        Self,
+        State,
+        ParentAction,
+        Action,
+        ViewCtx,
+        impl Fn(Arg<'_, State>, MessageResult<Action>) -> MessageResult<ParentAction>
+        + Send
+        + Sync
+        + 'static,
+    >
+    where
+        Self: Sized,
+        Action: 'static,
+        ParentAction: 'static,
+        F: Fn(Arg<'_, State>, Action) -> ParentAction + Send + Sync + 'static,
+    {
+        map_message_result(
+            self,
+            move |app_state: Arg<'_, State>, result: MessageResult<Action>| {
+                result.map(|action| map_fn(app_state, action))
+            },
+        )
+    }
+
     /// Set a [`Property`] on this view, when the underlying widget [supports](HasProperty) it.
     ///
     /// This overrides previous set properties of the same type.
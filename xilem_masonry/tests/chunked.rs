@@ -0,0 +1,429 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for [`chunked`] sequences.
+
+#![expect(clippy::missing_assert_message, reason = "Deferred: Noisy")]
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use xilem_masonry::ViewCtx;
+use xilem_masonry::core::{
+    AppendVec, DynMessage, ElementSplice, Environment, MessageCtx, MessageResult, Mut, ProxyError,
+    RawProxy, SendMessage, SuperElement, View, ViewElement, ViewId, ViewMarker, ViewPathTracker,
+    ViewSequence,
+};
+use xilem_masonry::view::{Chunked, chunked};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum Operation {
+    Build(u32),
+    Rebuild { from: u32, to: u32 },
+    Teardown(u32),
+}
+
+struct TestElement {
+    operations: Vec<Operation>,
+    view_path: Vec<ViewId>,
+}
+
+impl ViewElement for TestElement {
+    type Mut<'a> = &'a mut Self;
+}
+
+impl SuperElement<Self, ViewCtx> for TestElement {
+    fn upcast(_ctx: &mut ViewCtx, child: Self) -> Self {
+        child
+    }
+
+    fn with_downcast_val<R>(
+        this: Self::Mut<'_>,
+        f: impl FnOnce(Mut<'_, Self>) -> R,
+    ) -> (Self::Mut<'_>, R) {
+        let ret = f(this);
+        (this, ret)
+    }
+}
+
+/// A view which records all operations which happen on it into the element.
+///
+/// Its messages return its id as the action.
+struct OperationView(u32);
+
+impl ViewMarker for OperationView {}
+impl View<(), u32, ViewCtx> for OperationView {
+    type Element = TestElement;
+
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, (): ()) -> (Self::Element, Self::ViewState) {
+        (
+            TestElement {
+                operations: vec![Operation::Build(self.0)],
+                view_path: ctx.view_path().to_vec(),
+            },
+            (),
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+        (): (),
+    ) {
+        assert_eq!(&*element.view_path, ctx.view_path());
+        element.operations.push(Operation::Rebuild {
+            from: prev.0,
+            to: self.0,
+        });
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        assert_eq!(&*element.view_path, ctx.view_path());
+        element.operations.push(Operation::Teardown(self.0));
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        _: &mut MessageCtx,
+        _: Mut<'_, Self::Element>,
+        (): (),
+    ) -> MessageResult<u32> {
+        MessageResult::Action(self.0)
+    }
+}
+
+/// The elements of the sequence under test.
+#[derive(Default)]
+struct Elements {
+    active: Vec<TestElement>,
+    deleted: Vec<TestElement>,
+    scratch: AppendVec<TestElement>,
+    ix: usize,
+}
+
+impl ElementSplice<TestElement> for Elements {
+    fn with_scratch<R>(&mut self, f: impl FnOnce(&mut AppendVec<TestElement>) -> R) -> R {
+        let ret = f(&mut self.scratch);
+        for element in self.scratch.drain() {
+            self.active.insert(self.ix, element);
+            self.ix += 1;
+        }
+        ret
+    }
+    fn insert(&mut self, element: TestElement) {
+        self.active.insert(self.ix, element);
+        self.ix += 1;
+    }
+    fn mutate<R>(&mut self, f: impl FnOnce(Mut<'_, TestElement>) -> R) -> R {
+        let ix = self.ix;
+        self.ix += 1;
+        f(&mut self.active[ix])
+    }
+    fn skip(&mut self, n: usize) {
+        self.ix += n;
+    }
+    fn index(&self) -> usize {
+        self.ix
+    }
+    fn delete<R>(&mut self, f: impl FnOnce(Mut<'_, TestElement>) -> R) -> R {
+        let ret = f(&mut self.active[self.ix]);
+        self.deleted.push(self.active.remove(self.ix));
+        ret
+    }
+}
+
+/// A proxy which records the messages the sequence sends itself to process the next chunk.
+///
+/// The sequences are built at the root, so the messages are all sent to the empty path.
+#[derive(Debug, Default)]
+struct RecordingProxy(Mutex<Vec<SendMessage>>);
+
+impl RawProxy for RecordingProxy {
+    fn send_message(&self, _path: Arc<[ViewId]>, message: SendMessage) -> Result<(), ProxyError> {
+        self.0.lock().unwrap().push(message);
+        Ok(())
+    }
+    fn dyn_debug(&self) -> &dyn Debug {
+        self
+    }
+}
+
+impl RecordingProxy {
+    fn take_messages(&self) -> Vec<SendMessage> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+
+    fn take_count(&self) -> usize {
+        self.take_messages().len()
+    }
+}
+
+fn test_ctx() -> (ViewCtx, Arc<RecordingProxy>) {
+    let proxy = Arc::new(RecordingProxy::default());
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    (ViewCtx::new(proxy.clone(), Arc::new(runtime)), proxy)
+}
+
+/// Creates a sequence which processes a single view in each rebuild.
+fn one_per_chunk(ids: &[u32]) -> Chunked<OperationView, (), u32> {
+    chunked(ids.iter().map(|id| OperationView(*id))).budget(Duration::ZERO)
+}
+
+/// Creates a sequence which processes all its views in each rebuild.
+fn all_in_one_chunk(ids: &[u32]) -> Chunked<OperationView, (), u32> {
+    chunked(ids.iter().map(|id| OperationView(*id))).budget(Duration::MAX)
+}
+
+/// Creates a sequence which processes a single view in each rebuild, and whose progress
+/// callback returns the number of processed views as its action.
+fn reporting(ids: &[u32]) -> Chunked<OperationView, (), u32> {
+    one_per_chunk(ids).on_progress(|(), progress| u32::try_from(progress.done).unwrap() + 100)
+}
+
+/// Sends `message` to `seq`, which is at the root.
+fn deliver(
+    seq: &Chunked<OperationView, (), u32>,
+    state: &mut <Chunked<OperationView, (), u32> as ViewSequence<(), u32, ViewCtx, TestElement>>::SeqState,
+    elements: &mut Elements,
+    message: SendMessage,
+) -> MessageResult<u32> {
+    let mut message = MessageCtx::new(Environment::new(), Vec::new(), message.into());
+    elements.ix = 0;
+    seq.seq_message(state, &mut message, elements, ())
+}
+
+/// Rebuilds `seq`, with `prev` as the previous sequence.
+///
+/// A sequence which requests to process its next chunk is rebuilt with itself as `prev`.
+fn rebuild(
+    seq: &Chunked<OperationView, (), u32>,
+    prev: &Chunked<OperationView, (), u32>,
+    state: &mut <Chunked<OperationView, (), u32> as ViewSequence<(), u32, ViewCtx, TestElement>>::SeqState,
+    ctx: &mut ViewCtx,
+    elements: &mut Elements,
+) {
+    elements.ix = 0;
+    seq.seq_rebuild(prev, state, ctx, elements, ());
+}
+
+fn operations(elements: &[TestElement]) -> Vec<&[Operation]> {
+    elements
+        .iter()
+        .map(|element| element.operations.as_slice())
+        .collect()
+}
+
+#[test]
+fn build_in_several_chunks() {
+    let (mut ctx, proxy) = test_ctx();
+    let seq = one_per_chunk(&[0, 1, 2]);
+    let mut scratch = AppendVec::default();
+    let mut state = seq.seq_build(&mut ctx, &mut scratch, ());
+    let mut elements = Elements {
+        active: scratch.into_inner(),
+        ..Elements::default()
+    };
+    // Only the first chunk was built, and the sequence asked to process the next one.
+    assert_eq!(operations(&elements.active), [[Operation::Build(0)]]);
+    assert_eq!(proxy.take_count(), 1);
+
+    rebuild(&seq, &seq, &mut state, &mut ctx, &mut elements);
+    assert_eq!(proxy.take_count(), 1);
+    rebuild(&seq, &seq, &mut state, &mut ctx, &mut elements);
+    // The last chunk doesn't request another one.
+    assert_eq!(proxy.take_count(), 0);
+    assert_eq!(
+        operations(&elements.active),
+        [
+            [Operation::Build(0)],
+            [Operation::Build(1)],
+            [Operation::Build(2)]
+        ]
+    );
+    assert_ne!(elements.active[0].view_path, elements.active[1].view_path);
+    assert!(ctx.view_path().is_empty());
+}
+
+#[test]
+fn views_change_mid_rebuild() {
+    let (mut ctx, proxy) = test_ctx();
+    let seq = one_per_chunk(&[0, 1, 2]);
+    let mut scratch = AppendVec::default();
+    let mut state = seq.seq_build(&mut ctx, &mut scratch, ());
+    let mut elements = Elements {
+        active: scratch.into_inner(),
+        ..Elements::default()
+    };
+
+    // The views change after the first chunk, so the rebuild continues with the new views...
+    let seq2 = one_per_chunk(&[3, 4, 5]);
+    rebuild(&seq2, &seq, &mut state, &mut ctx, &mut elements);
+    rebuild(&seq2, &seq2, &mut state, &mut ctx, &mut elements);
+    assert_eq!(
+        operations(&elements.active),
+        [
+            [Operation::Build(0)],
+            [Operation::Build(4)],
+            [Operation::Build(5)]
+        ]
+    );
+    // ...then starts over to update the first element.
+    assert_eq!(proxy.take_count(), 3);
+    rebuild(&seq2, &seq2, &mut state, &mut ctx, &mut elements);
+    assert_eq!(
+        elements.active[0].operations,
+        [Operation::Build(0), Operation::Rebuild { from: 0, to: 3 }]
+    );
+    assert!(elements.deleted.is_empty());
+    assert!(ctx.view_path().is_empty());
+}
+
+#[test]
+fn removed_views_are_torn_down() {
+    let (mut ctx, proxy) = test_ctx();
+    let seq = all_in_one_chunk(&[0, 1, 2]);
+    let mut scratch = AppendVec::default();
+    let mut state = seq.seq_build(&mut ctx, &mut scratch, ());
+    let mut elements = Elements {
+        active: scratch.into_inner(),
+        ..Elements::default()
+    };
+    assert_eq!(elements.active.len(), 3);
+    assert_eq!(proxy.take_count(), 0);
+
+    let seq2 = all_in_one_chunk(&[3]);
+    rebuild(&seq2, &seq, &mut state, &mut ctx, &mut elements);
+    assert_eq!(
+        operations(&elements.active),
+        [[Operation::Build(0), Operation::Rebuild { from: 0, to: 3 }]]
+    );
+    assert_eq!(
+        operations(&elements.deleted),
+        [
+            [Operation::Build(1), Operation::Teardown(1)],
+            [Operation::Build(2), Operation::Teardown(2)]
+        ]
+    );
+    assert_eq!(proxy.take_count(), 0);
+    assert!(ctx.view_path().is_empty());
+}
+
+#[test]
+fn message_to_removed_element_is_stale() {
+    let (mut ctx, _proxy) = test_ctx();
+    let seq = all_in_one_chunk(&[0, 1]);
+    let mut scratch = AppendVec::default();
+    let mut state = seq.seq_build(&mut ctx, &mut scratch, ());
+    let mut elements = Elements {
+        active: scratch.into_inner(),
+        ..Elements::default()
+    };
+
+    // The second element is removed, then a new one is built at the same index.
+    let seq2 = all_in_one_chunk(&[0]);
+    rebuild(&seq2, &seq, &mut state, &mut ctx, &mut elements);
+    let seq3 = all_in_one_chunk(&[0, 7]);
+    rebuild(&seq3, &seq2, &mut state, &mut ctx, &mut elements);
+    let removed_path = elements.deleted[0].view_path.clone();
+    let new_path = elements.active[1].view_path.clone();
+    assert_ne!(removed_path, new_path);
+
+    let mut send_message = |path: Vec<ViewId>| {
+        let mut message = MessageCtx::new(Environment::new(), path, DynMessage::new(()));
+        elements.ix = 0;
+        seq3.seq_message(&mut state, &mut message, &mut elements, ())
+    };
+    assert!(matches!(send_message(removed_path), MessageResult::Stale));
+    assert!(matches!(send_message(new_path), MessageResult::Action(7)));
+}
+
+#[test]
+fn progress_action_continues_rebuild() {
+    let (mut ctx, proxy) = test_ctx();
+    let seq = reporting(&[0, 1, 2]);
+    let mut scratch = AppendVec::default();
+    let mut state = seq.seq_build(&mut ctx, &mut scratch, ());
+    let mut elements = Elements {
+        active: scratch.into_inner(),
+        ..Elements::default()
+    };
+
+    let [processed, continued] = proxy.take_messages().try_into().unwrap();
+    let result = deliver(&seq, &mut state, &mut elements, processed);
+    assert!(matches!(result, MessageResult::Action(101)));
+    // The action reruns the app logic, which creates new views with the same data,
+    // and the rebuild continues with the next chunk.
+    let seq2 = reporting(&[0, 1, 2]);
+    rebuild(&seq2, &seq, &mut state, &mut ctx, &mut elements);
+    assert_eq!(
+        operations(&elements.active),
+        [[Operation::Build(0)], [Operation::Build(1)]]
+    );
+    // The sequence was rebuilt, so it doesn't need to continue.
+    let result = deliver(&seq2, &mut state, &mut elements, continued);
+    assert!(matches!(result, MessageResult::Nop));
+
+    // Other changes to the views start the rebuild over.
+    let [processed, _continued] = proxy.take_messages().try_into().unwrap();
+    let seq3 = reporting(&[3, 4, 5]);
+    rebuild(&seq3, &seq2, &mut state, &mut ctx, &mut elements);
+    let result = deliver(&seq3, &mut state, &mut elements, processed);
+    assert!(matches!(result, MessageResult::Nop));
+    for _ in 0..2 {
+        rebuild(&seq3, &seq3, &mut state, &mut ctx, &mut elements);
+    }
+    assert_eq!(
+        operations(&elements.active)[..2],
+        [
+            &[Operation::Build(0), Operation::Rebuild { from: 0, to: 3 }][..],
+            &[Operation::Build(1), Operation::Rebuild { from: 1, to: 4 }][..]
+        ]
+    );
+}
+
+#[test]
+fn ignored_progress_action_continues_rebuild() {
+    let (mut ctx, proxy) = test_ctx();
+    let seq = reporting(&[0, 1]);
+    let mut scratch = AppendVec::default();
+    let mut state = seq.seq_build(&mut ctx, &mut scratch, ());
+    let mut elements = Elements {
+        active: scratch.into_inner(),
+        ..Elements::default()
+    };
+
+    // A parent ignores the action, so nothing rebuilds the sequence...
+    let [processed, continued] = proxy.take_messages().try_into().unwrap();
+    let result = deliver(&seq, &mut state, &mut elements, processed);
+    assert!(matches!(result, MessageResult::Action(101)));
+    // ...until it asks to continue.
+    let result = deliver(&seq, &mut state, &mut elements, continued);
+    assert!(matches!(result, MessageResult::RequestRebuild));
+    rebuild(&seq, &seq, &mut state, &mut ctx, &mut elements);
+    assert_eq!(
+        operations(&elements.active),
+        [[Operation::Build(0)], [Operation::Build(1)]]
+    );
+
+    // A later rebuild with new views isn't mistaken for the continuation.
+    let seq2 = reporting(&[2, 3]);
+    rebuild(&seq2, &seq, &mut state, &mut ctx, &mut elements);
+    assert_eq!(
+        elements.active[0].operations,
+        [Operation::Build(0), Operation::Rebuild { from: 0, to: 2 }]
+    );
+}